@@ -396,7 +396,7 @@ pub fn decode_mbo_msg(
                 TradeId::new(itoa::Buffer::new().format(msg.sequence))?,
                 msg.ts_recv.into(),
                 ts_init,
-            );
+            )?;
             return Ok((None, Some(trade)));
         }
 
@@ -437,24 +437,43 @@ pub fn decode_trade_msg(
         TradeId::new(itoa::Buffer::new().format(msg.sequence))?,
         msg.ts_recv.into(),
         ts_init,
-    );
+    )?;
 
     Ok(trade)
 }
 
-pub fn decode_tbbo_msg(
-    msg: &dbn::TbboMsg,
+/// Decodes the top level of a Databento MBP record into a [`QuoteTick`].
+///
+/// The quote is constructed unchecked as records with an empty side, or a crossed top level,
+/// are valid market data.
+fn decode_top_level_quote(
+    top_level: &dbn::BidAskPair,
     instrument_id: InstrumentId,
     price_precision: u8,
+    ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> anyhow::Result<(QuoteTick, TradeTick)> {
-    let top_level = &msg.levels[0];
-    let quote = QuoteTick::new(
+) -> anyhow::Result<QuoteTick> {
+    Ok(QuoteTick::new_unchecked(
         instrument_id,
         Price::from_raw(top_level.bid_px, price_precision)?,
         Price::from_raw(top_level.ask_px, price_precision)?,
         Quantity::from_raw(u64::from(top_level.bid_sz) * FIXED_SCALAR as u64, 0)?,
         Quantity::from_raw(u64::from(top_level.ask_sz) * FIXED_SCALAR as u64, 0)?,
+        ts_event,
+        ts_init,
+    ))
+}
+
+pub fn decode_tbbo_msg(
+    msg: &dbn::TbboMsg,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<(QuoteTick, TradeTick)> {
+    let quote = decode_top_level_quote(
+        &msg.levels[0],
+        instrument_id,
+        price_precision,
         msg.ts_recv.into(),
         ts_init,
     )?;
@@ -467,7 +486,7 @@ pub fn decode_tbbo_msg(
         TradeId::new(itoa::Buffer::new().format(msg.sequence))?,
         msg.ts_recv.into(),
         ts_init,
-    );
+    )?;
 
     Ok((quote, trade))
 }
@@ -479,13 +498,10 @@ pub fn decode_mbp1_msg(
     ts_init: UnixNanos,
     include_trades: bool,
 ) -> anyhow::Result<(QuoteTick, Option<TradeTick>)> {
-    let top_level = &msg.levels[0];
-    let quote = QuoteTick::new(
+    let quote = decode_top_level_quote(
+        &msg.levels[0],
         instrument_id,
-        Price::from_raw(top_level.bid_px, price_precision)?,
-        Price::from_raw(top_level.ask_px, price_precision)?,
-        Quantity::from_raw(u64::from(top_level.bid_sz) * FIXED_SCALAR as u64, 0)?,
-        Quantity::from_raw(u64::from(top_level.ask_sz) * FIXED_SCALAR as u64, 0)?,
+        price_precision,
         msg.ts_recv.into(),
         ts_init,
     )?;
@@ -499,7 +515,7 @@ pub fn decode_mbp1_msg(
             TradeId::new(itoa::Buffer::new().format(msg.sequence))?,
             msg.ts_recv.into(),
             ts_init,
-        ))
+        )?)
     } else {
         None
     };
@@ -1078,6 +1094,24 @@ mod tests {
         assert_eq!(quote.ts_init, 0);
     }
 
+    #[rstest]
+    fn test_decode_mbp1_msg_with_empty_and_crossed_top_level() {
+        let path = PathBuf::from(format!("{TEST_DATA_PATH}/test_data.mbp-1.dbn.zst"));
+        let mut dbn_stream = Decoder::from_zstd_file(path)
+            .unwrap()
+            .decode_stream::<dbn::Mbp1Msg>();
+        let mut msg = dbn_stream.next().unwrap().unwrap().clone();
+        msg.levels[0].ask_px = msg.levels[0].bid_px - 250_000_000;
+        msg.levels[0].ask_sz = 0;
+
+        let instrument_id = InstrumentId::from("ESM4.GLBX");
+        let (quote, _) = decode_mbp1_msg(&msg, instrument_id, 2, 0.into(), false).unwrap();
+
+        assert_eq!(quote.bid_price, Price::from("3720.25"));
+        assert_eq!(quote.ask_price, Price::from("3720.00"));
+        assert_eq!(quote.ask_size, Quantity::from("0"));
+    }
+
     #[rstest]
     fn test_decode_mbp10_msg() {
         let path = PathBuf::from(format!("{TEST_DATA_PATH}/test_data.mbp-10.dbn.zst"));
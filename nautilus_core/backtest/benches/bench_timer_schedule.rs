@@ -0,0 +1,100 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use nautilus_backtest::engine::TimeEventAccumulator;
+use nautilus_common::{
+    clock::{Clock, TestClock},
+    handlers::EventHandler,
+};
+use nautilus_core::nanos::UnixNanos;
+use pyo3::{types::PyList, Py, Python};
+
+// One data point per millisecond for 100 seconds, with timers firing every 10 seconds
+const DATA_INTERVAL_NS: u64 = 1_000_000;
+const DATA_POINTS: u64 = 100_000;
+const TIMER_INTERVAL_NS: u64 = 10_000_000_000;
+
+fn sparse_timer_clock(py: Python<'_>) -> TestClock {
+    let py_list = PyList::empty(py);
+    let py_append = Py::from(py_list.getattr("append").unwrap());
+    let mut clock = TestClock::new();
+    clock.register_default_handler(EventHandler::new(py_append));
+    for i in 0..10 {
+        clock
+            .set_timer_ns(
+                &format!("TIMER-{i}"),
+                TIMER_INTERVAL_NS,
+                UnixNanos::from(i * 1_000),
+                None,
+                None,
+            )
+            .unwrap();
+    }
+    clock
+}
+
+fn sparse_timer_bench(c: &mut Criterion) {
+    pyo3::prepare_freethreaded_python();
+
+    let mut group = c.benchmark_group("sparse_timers");
+    group.sample_size(10);
+    let end_ns = UnixNanos::from(DATA_INTERVAL_NS * DATA_POINTS);
+
+    Python::with_gil(|py| {
+        group.bench_function("naive", |b| {
+            b.iter_batched_ref(
+                || (sparse_timer_clock(py), TimeEventAccumulator::new()),
+                |(clock, accumulator)| {
+                    for i in 1..=DATA_POINTS {
+                        accumulator.advance_clock(
+                            clock,
+                            UnixNanos::from(i * DATA_INTERVAL_NS),
+                            true,
+                        );
+                        accumulator.drain();
+                    }
+                },
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_function("scheduled", |b| {
+            b.iter_batched_ref(
+                || {
+                    let mut clock = sparse_timer_clock(py);
+                    clock.set_schedule_end(end_ns);
+                    (clock, TimeEventAccumulator::new())
+                },
+                |(clock, accumulator)| {
+                    for i in 1..=DATA_POINTS {
+                        accumulator.advance_clock(
+                            clock,
+                            UnixNanos::from(i * DATA_INTERVAL_NS),
+                            true,
+                        );
+                        accumulator.drain();
+                    }
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, sparse_timer_bench);
+criterion_main!(benches);
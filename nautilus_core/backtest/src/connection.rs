@@ -0,0 +1,149 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `ConnectionScenario` for simulating venue connectivity outages in a backtest.
+//!
+//! A scenario is a list of outage windows, each a disconnect and reconnect timestamp pair. The
+//! matching engine treats the venue as disconnected from each disconnect time (inclusive) up
+//! to the matching reconnect time (exclusive).
+
+use nautilus_core::nanos::UnixNanos;
+
+/// The reason given for commands rejected while the venue is disconnected.
+pub const DISCONNECTED_REASON: &str = "DISCONNECTED";
+
+/// Represents the venue connectivity outages to simulate over a backtest.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConnectionScenario {
+    outages: Vec<(UnixNanos, UnixNanos)>,
+}
+
+impl ConnectionScenario {
+    /// Creates a new scenario from the given `(disconnect, reconnect)` timestamp pairs.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - A reconnect is not after its disconnect.
+    /// - The outages are not in ascending order, or overlap.
+    pub fn new(outages: Vec<(UnixNanos, UnixNanos)>) -> anyhow::Result<Self> {
+        for (disconnect, reconnect) in &outages {
+            anyhow::ensure!(
+                reconnect > disconnect,
+                "Invalid outage: reconnect {reconnect} not after disconnect {disconnect}"
+            );
+        }
+        for pair in outages.windows(2) {
+            anyhow::ensure!(
+                pair[1].0 >= pair[0].1,
+                "Invalid outages: disconnect {} before prior reconnect {}",
+                pair[1].0,
+                pair[0].1
+            );
+        }
+        Ok(Self { outages })
+    }
+
+    /// Returns the `(disconnect, reconnect)` timestamp pairs of the scenario.
+    #[must_use]
+    pub fn outages(&self) -> &[(UnixNanos, UnixNanos)] {
+        &self.outages
+    }
+
+    /// Returns the connectivity change at `idx` of all changes in time order, as the UNIX
+    /// timestamp (nanoseconds) of the change and whether the venue is connected after it.
+    ///
+    /// Each outage contributes a disconnect followed by a reconnect.
+    #[must_use]
+    pub fn change(&self, idx: usize) -> Option<(UnixNanos, bool)> {
+        let (disconnect, reconnect) = self.outages.get(idx / 2)?;
+        Some(if idx.is_multiple_of(2) {
+            (*disconnect, false)
+        } else {
+            (*reconnect, true)
+        })
+    }
+
+    /// Returns whether the venue is connected at the given UNIX timestamp (nanoseconds).
+    #[must_use]
+    pub fn is_connected(&self, ts: UnixNanos) -> bool {
+        let idx = self
+            .outages
+            .partition_point(|(disconnect, _)| *disconnect <= ts);
+        idx == 0 || self.outages[idx - 1].1 <= ts
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn outage(disconnect: u64, reconnect: u64) -> (UnixNanos, UnixNanos) {
+        (UnixNanos::from(disconnect), UnixNanos::from(reconnect))
+    }
+
+    #[rstest]
+    #[case(0, true)]
+    #[case(99, true)]
+    #[case(100, false)]
+    #[case(199, false)]
+    #[case(200, true)]
+    #[case(300, false)]
+    #[case(400, true)]
+    fn test_is_connected(#[case] ts: u64, #[case] expected: bool) {
+        let scenario = ConnectionScenario::new(vec![outage(100, 200), outage(300, 400)]).unwrap();
+
+        assert_eq!(scenario.is_connected(UnixNanos::from(ts)), expected);
+    }
+
+    #[rstest]
+    fn test_changes_alternate_disconnect_and_reconnect() {
+        let scenario = ConnectionScenario::new(vec![outage(100, 200), outage(300, 400)]).unwrap();
+
+        let changes: Vec<(UnixNanos, bool)> = (0..).map_while(|idx| scenario.change(idx)).collect();
+
+        assert_eq!(
+            changes,
+            vec![
+                (UnixNanos::from(100), false),
+                (UnixNanos::from(200), true),
+                (UnixNanos::from(300), false),
+                (UnixNanos::from(400), true),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_empty_scenario_is_always_connected() {
+        let scenario = ConnectionScenario::default();
+
+        assert!(scenario.is_connected(UnixNanos::default()));
+        assert!(scenario.outages().is_empty());
+    }
+
+    #[rstest]
+    #[case(vec![outage(200, 200)])]
+    #[case(vec![outage(200, 100)])]
+    #[case(vec![outage(300, 400), outage(100, 200)])]
+    #[case(vec![outage(100, 300), outage(200, 400)])]
+    fn test_invalid_outages(#[case] outages: Vec<(UnixNanos, UnixNanos)>) {
+        assert!(ConnectionScenario::new(outages).is_err());
+    }
+}
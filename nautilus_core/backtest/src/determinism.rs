@@ -0,0 +1,401 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a harness for verifying that a backtest run is deterministic.
+//!
+//! The run is executed twice with identical seeds while recording every emitted event in
+//! memory. The two recordings are then compared (after masking fields which are expected to
+//! differ between runs, such as event UUIDs) and the first divergence is reported.
+
+use std::fmt::Display;
+
+use nautilus_model::{
+    data::Data,
+    events::{order::event::OrderEventAny, position::PositionEvent},
+};
+use serde::Serialize;
+use serde_json::Value;
+
+/// The default fields masked from recorded events before comparison.
+pub const DEFAULT_MASKED_FIELDS: [&str; 2] = ["event_id", "ts_init"];
+
+/// Configuration for a determinism verification.
+#[derive(Clone, Debug)]
+pub struct DeterminismConfig {
+    /// The seed passed to both runs.
+    pub seed: u64,
+    /// The field names removed (at any depth) from recorded events before comparison.
+    pub masked_fields: Vec<String>,
+}
+
+impl Default for DeterminismConfig {
+    fn default() -> Self {
+        Self {
+            seed: 42,
+            masked_fields: DEFAULT_MASKED_FIELDS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+}
+
+/// Represents an event emitted during a run, serialized for comparison.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedEvent {
+    pub kind: String,
+    pub payload: Value,
+}
+
+/// Records the events emitted during a backtest run in memory.
+#[derive(Debug, Default)]
+pub struct EventRecorder {
+    events: Vec<RecordedEvent>,
+}
+
+impl EventRecorder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    pub fn record<T: Serialize>(&mut self, kind: &str, value: &T) -> anyhow::Result<()> {
+        self.events.push(RecordedEvent {
+            kind: kind.to_string(),
+            payload: serde_json::to_value(value)?,
+        });
+        Ok(())
+    }
+
+    pub fn record_order_event(&mut self, event: &OrderEventAny) -> anyhow::Result<()> {
+        self.record("OrderEvent", event)
+    }
+
+    pub fn record_position_event(&mut self, event: &PositionEvent) -> anyhow::Result<()> {
+        self.record("PositionEvent", event)
+    }
+
+    pub fn record_data(&mut self, data: &Data) -> anyhow::Result<()> {
+        match data {
+            Data::Delta(delta) => self.record("OrderBookDelta", delta),
+            Data::Deltas(deltas) => self.record("OrderBookDeltas", &deltas.deltas),
+            Data::Depth10(depth) => self.record("OrderBookDepth10", depth),
+            Data::Quote(quote) => self.record("QuoteTick", quote),
+            Data::Trade(trade) => self.record("TradeTick", trade),
+            Data::Bar(bar) => self.record("Bar", bar),
+            Data::Close(close) => self.record("InstrumentClose", close),
+        }
+    }
+}
+
+/// Represents the first divergence found between two runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DivergenceReport {
+    /// The index of the first diverging event.
+    pub index: usize,
+    /// The serialized event from the first run (`None` if the run emitted fewer events).
+    pub first: Option<String>,
+    /// The serialized event from the second run (`None` if the run emitted fewer events).
+    pub second: Option<String>,
+    /// The paths of the fields which differ between the two events.
+    pub differing_fields: Vec<String>,
+}
+
+impl Display for DivergenceReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Runs diverged at event {}: fields=[{}], first={}, second={}",
+            self.index,
+            self.differing_fields.join(", "),
+            self.first.as_deref().unwrap_or("None"),
+            self.second.as_deref().unwrap_or("None"),
+        )
+    }
+}
+
+impl std::error::Error for DivergenceReport {}
+
+/// Verifies the `run_fn` is deterministic using the default [`DeterminismConfig`].
+pub fn verify<F>(run_fn: F) -> Result<(), DivergenceReport>
+where
+    F: FnMut(u64, &mut EventRecorder),
+{
+    verify_with_config(&DeterminismConfig::default(), run_fn)
+}
+
+/// Executes the `run_fn` twice with the configured seed, returning a report of the first
+/// divergence between the recorded events (if any).
+pub fn verify_with_config<F>(
+    config: &DeterminismConfig,
+    mut run_fn: F,
+) -> Result<(), DivergenceReport>
+where
+    F: FnMut(u64, &mut EventRecorder),
+{
+    let mut first = EventRecorder::new();
+    run_fn(config.seed, &mut first);
+
+    let mut second = EventRecorder::new();
+    run_fn(config.seed, &mut second);
+
+    let first = mask_events(first.events, &config.masked_fields);
+    let second = mask_events(second.events, &config.masked_fields);
+
+    match find_divergence(&first, &second) {
+        Some(report) => Err(report),
+        None => Ok(()),
+    }
+}
+
+fn mask_events(events: Vec<RecordedEvent>, masked_fields: &[String]) -> Vec<RecordedEvent> {
+    events
+        .into_iter()
+        .map(|mut event| {
+            mask_value(&mut event.payload, masked_fields);
+            event
+        })
+        .collect()
+}
+
+fn mask_value(value: &mut Value, masked_fields: &[String]) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|key, _| !masked_fields.contains(key));
+            for value in map.values_mut() {
+                mask_value(value, masked_fields);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                mask_value(value, masked_fields);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn find_divergence(first: &[RecordedEvent], second: &[RecordedEvent]) -> Option<DivergenceReport> {
+    let len = first.len().max(second.len());
+
+    for index in 0..len {
+        let a = first.get(index);
+        let b = second.get(index);
+        if a == b {
+            continue;
+        }
+
+        let mut differing_fields = Vec::new();
+        if let (Some(a), Some(b)) = (a, b) {
+            if a.kind != b.kind {
+                differing_fields.push("kind".to_string());
+            }
+            diff_values("", &a.payload, &b.payload, &mut differing_fields);
+        }
+
+        return Some(DivergenceReport {
+            index,
+            first: a.map(|event| format!("{}:{}", event.kind, event.payload)),
+            second: b.map(|event| format!("{}:{}", event.kind, event.payload)),
+            differing_fields,
+        });
+    }
+
+    None
+}
+
+fn diff_values(path: &str, a: &Value, b: &Value, differing_fields: &mut Vec<String>) {
+    match (a, b) {
+        (Value::Object(map_a), Value::Object(map_b)) => {
+            let mut keys: Vec<&String> = map_a.keys().chain(map_b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                match (map_a.get(key), map_b.get(key)) {
+                    (Some(a), Some(b)) => diff_values(&child_path, a, b, differing_fields),
+                    _ => differing_fields.push(child_path),
+                }
+            }
+        }
+        _ if a != b => differing_fields.push(path.to_string()),
+        _ => {}
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        events::order::accepted::OrderAccepted,
+        identifiers::instrument_id::InstrumentId,
+        stubs::{
+            account_id, audusd_sim_id, client_order_id, strategy_id_ema_cross, trader_id,
+            venue_order_id,
+        },
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(instrument_id: InstrumentId, bid: f64, ts: u64) -> Data {
+        Data::Quote(
+            QuoteTick::new(
+                instrument_id,
+                Price::new(bid, 2).unwrap(),
+                Price::new(bid + 0.01, 2).unwrap(),
+                Quantity::from("1"),
+                Quantity::from("1"),
+                ts.into(),
+                ts.into(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn accepted(ts_init: UnixNanos) -> OrderEventAny {
+        OrderEventAny::Accepted(
+            OrderAccepted::new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                audusd_sim_id(),
+                client_order_id(),
+                venue_order_id(),
+                account_id(),
+                UUID4::new(),
+                UnixNanos::from(1),
+                ts_init,
+                false,
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn instrument_ids() -> Vec<InstrumentId> {
+        (0..32)
+            .map(|i| InstrumentId::from(format!("SYM{i}.SIM").as_str()))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_verify_deterministic_run() {
+        let result = verify(|seed, recorder| {
+            let prices: BTreeMap<InstrumentId, f64> = instrument_ids()
+                .into_iter()
+                .enumerate()
+                .map(|(i, id)| (id, seed as f64 + i as f64))
+                .collect();
+            for (id, bid) in &prices {
+                recorder.record_data(&quote(*id, *bid, 1)).unwrap();
+            }
+        });
+
+        assert!(result.is_ok());
+    }
+
+    #[rstest]
+    fn test_verify_ignores_masked_fields() {
+        let mut wall_clock = 0;
+        let result = verify(|_, recorder| {
+            // Each run generates a new `event_id` and a different `ts_init`
+            wall_clock += 1_000;
+            recorder
+                .record_order_event(&accepted(UnixNanos::from(wall_clock)))
+                .unwrap();
+        });
+
+        assert!(result.is_ok());
+    }
+
+    #[rstest]
+    fn test_verify_reports_unmasked_fields() {
+        let config = DeterminismConfig {
+            masked_fields: vec!["event_id".to_string()],
+            ..Default::default()
+        };
+        let mut wall_clock = 0;
+        let report = verify_with_config(&config, |_, recorder| {
+            wall_clock += 1_000;
+            recorder
+                .record_order_event(&accepted(UnixNanos::from(wall_clock)))
+                .unwrap();
+        })
+        .unwrap_err();
+
+        assert_eq!(report.index, 0);
+        assert_eq!(report.differing_fields, vec!["ts_init"]);
+        assert!(report.first.unwrap().contains("\"ts_init\":1000"));
+        assert!(report.second.unwrap().contains("\"ts_init\":2000"));
+    }
+
+    #[rstest]
+    fn test_verify_catches_hash_map_iteration_order() {
+        let report = verify(|seed, recorder| {
+            // Each `HashMap` is randomly seeded, so iteration order differs between runs
+            let prices: HashMap<InstrumentId, f64> = instrument_ids()
+                .into_iter()
+                .enumerate()
+                .map(|(i, id)| (id, seed as f64 + i as f64))
+                .collect();
+            for (id, bid) in &prices {
+                recorder.record_data(&quote(*id, *bid, 1)).unwrap();
+            }
+        })
+        .unwrap_err();
+
+        assert!(report.index < 32);
+        assert!(report
+            .differing_fields
+            .contains(&"instrument_id".to_string()));
+        assert!(report.first.is_some());
+        assert!(report.second.is_some());
+    }
+
+    #[rstest]
+    fn test_verify_reports_missing_events() {
+        let mut run = 0;
+        let report = verify(|_, recorder| {
+            run += 1;
+            for i in 0..run {
+                recorder
+                    .record_data(&quote(audusd_sim_id(), 1.0, i))
+                    .unwrap();
+            }
+        })
+        .unwrap_err();
+
+        assert_eq!(report.index, 1);
+        assert_eq!(report.first, None);
+        assert!(report.second.is_some());
+        assert!(report.differing_fields.is_empty());
+    }
+}
@@ -112,13 +112,14 @@ pub extern "C" fn time_event_accumulator_drain(accumulator: &mut TimeEventAccumu
 mod tests {
     use std::ffi::c_char;
 
-    use nautilus_common::timer::TimeEvent;
+    use nautilus_common::{clock::Clock, handlers::EventHandler, timer::TimeEvent};
     use nautilus_core::uuid::UUID4;
     use pyo3::{types::PyList, Py, Python};
     use rstest::*;
     use ustr::Ustr;
 
     use super::*;
+    use crate::determinism::verify;
 
     #[rstest]
     fn test_accumulator_drain_sorted() {
@@ -184,4 +185,54 @@ mod tests {
             assert_eq!(drained_handlers[2].event.ts_event, time_event2.ts_event);
         });
     }
+
+    #[rstest]
+    fn test_advance_clock_with_schedule_matches_naive_path() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let py_list = PyList::empty(py);
+            let py_append = Py::from(py_list.getattr("append").unwrap());
+
+            // The first run advances the clock naively, the second using the fire schedule
+            let mut run = 0;
+            let result = verify(|_, recorder| {
+                run += 1;
+                let mut clock = TestClock::new();
+                clock.register_default_handler(EventHandler::new(py_append.clone_ref(py)));
+                if run == 2 {
+                    clock.set_schedule_end(UnixNanos::from(10_000));
+                }
+                clock
+                    .set_timer_ns("SPARSE", 1_000, 0.into(), None, None)
+                    .unwrap();
+                clock
+                    .set_timer_ns("BAR", 250, 100.into(), Some(5_000.into()), None)
+                    .unwrap();
+                clock
+                    .set_timer_ns("ALERT", 7_777, 0.into(), Some(7_777.into()), None)
+                    .unwrap();
+
+                let mut accumulator = TimeEventAccumulator::new();
+                // Runs beyond the end of the schedule
+                for ts in (0..=12_000).step_by(7) {
+                    if ts == 3_003 {
+                        clock.cancel_timer("SPARSE");
+                        clock
+                            .set_timer_ns("LATE", 500, ts.into(), None, None)
+                            .unwrap();
+                    }
+                    accumulator.advance_clock(&mut clock, UnixNanos::from(ts), true);
+                    for handler in accumulator.drain() {
+                        let event = handler.event;
+                        let fired = (event.name.to_string(), event.ts_event, clock.get_time_ns());
+                        recorder.record("TimeEvent", &fired).unwrap();
+                    }
+                }
+                recorder.record("ClockTime", &clock.get_time_ns()).unwrap();
+            });
+
+            assert!(result.is_ok(), "{}", result.unwrap_err());
+        });
+    }
 }
@@ -26,6 +26,13 @@
 //!
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`
 //! - `python`: Enables Python bindings from `pyo3`
+//! - `testkit`: Exposes the `ExecutionScenario` harness for scripting matching engine tests
 
+pub mod connection;
+pub mod determinism;
 pub mod engine;
 pub mod matching_engine;
+pub mod models;
+
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
@@ -19,40 +19,90 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use log::{debug, info};
-use nautilus_common::{cache::Cache, msgbus::MessageBus};
-use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
-use nautilus_execution::matching_core::OrderMatchingCore;
+use anyhow::Context;
+use log::{debug, error, info, warn};
+use nautilus_common::{cache::Cache, calendar::TradingCalendar, msgbus::MessageBus};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_execution::{
+    matching_core::OrderMatchingCore,
+    messages::{modify::ModifyOrder, submit::SubmitOrder},
+};
 use nautilus_model::{
     data::{
         bar::{Bar, BarType},
+        close::InstrumentClose,
         delta::OrderBookDelta,
     },
-    enums::{AccountType, BookType, MarketStatus, OmsType},
+    enums::{
+        AccountType, BookType, InstrumentCloseType, LiquiditySide, MarketStatus, OmsType,
+        OrderSide, OrderType, TimeInForce,
+    },
+    events::order::{
+        accepted::OrderAccepted, canceled::OrderCanceled, event::OrderEventAny,
+        filled::OrderFilled, modify_rejected::OrderModifyRejected,
+        pending_update::OrderPendingUpdate, rejected::OrderRejected, submitted::OrderSubmitted,
+        updated::OrderUpdated,
+    },
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
-        trader_id::TraderId, venue::Venue,
+        trade_id::TradeId, trader_id::TraderId, venue::Venue, venue_order_id::VenueOrderId,
     },
     instruments::Instrument,
     orderbook::book::OrderBook,
     orders::{
-        any::{PassiveOrderAny, StopOrderAny},
+        any::{OrderAny, PassiveOrderAny, StopOrderAny},
+        base::{check_expire_time, Order, OrderError},
+        market::MarketOrder,
         trailing_stop_limit::TrailingStopLimitOrder,
         trailing_stop_market::TrailingStopMarketOrder,
     },
-    types::price::Price,
+    polymorphism::{
+        ApplyOrderEventAny, GetAccountId, GetClientOrderId, GetInstrumentId, GetOrderFilledQty,
+        GetOrderQuantity, GetOrderSideSpecified, GetStrategyId, GetTraderId, GetVenueOrderId,
+    },
+    position::Position,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+use ustr::Ustr;
+
+use crate::{
+    connection::{ConnectionScenario, DISCONNECTED_REASON},
+    models::{FeeModel, FeeModelAny, FillModel},
 };
 
 pub struct OrderMatchingEngineConfig {
     pub bar_execution: bool,
     pub reject_stop_orders: bool,
     pub support_gtd_orders: bool,
+    /// The minimum lifetime (nanoseconds) a `GTD` order must have remaining on arrival.
+    pub min_expire_lifetime_ns: u64,
     pub support_contingent_orders: bool,
     pub use_position_ids: bool,
     pub use_random_ids: bool,
     pub use_reduce_only: bool,
+    /// If orders are rejected while the engine trading calendar (if set) is closed.
+    pub reject_when_closed: bool,
+    /// If working orders are canceled when the venue disconnects (see `ConnectionScenario`).
+    pub cancel_on_disconnect: bool,
+}
+
+impl Default for OrderMatchingEngineConfig {
+    fn default() -> Self {
+        Self {
+            bar_execution: true,
+            reject_stop_orders: true,
+            support_gtd_orders: true,
+            min_expire_lifetime_ns: 0,
+            support_contingent_orders: true,
+            use_position_ids: true,
+            use_random_ids: false,
+            use_reduce_only: true,
+            reject_when_closed: true,
+            cancel_on_disconnect: false,
+        }
+    }
 }
 
 /// Provides an order matching engine for a single market.
@@ -73,9 +123,15 @@ pub struct OrderMatchingEngine {
     pub market_status: MarketStatus,
     /// The config for the matching engine.
     pub config: OrderMatchingEngineConfig,
-    clock: &'static AtomicTime,
-    msgbus: &'static MessageBus,
-    cache: &'static Cache,
+    clock: Rc<AtomicTime>,
+    calendar: Option<TradingCalendar>,
+    connection: Option<ConnectionScenario>,
+    /// The index of the next connectivity change of the connection scenario to process.
+    next_connection_change: usize,
+    msgbus: Rc<RefCell<MessageBus>>,
+    cache: Rc<RefCell<Cache>>,
+    fill_model: FillModel,
+    fee_model: FeeModelAny,
     book: OrderBook,
     core: OrderMatchingCore,
     target_bid: Option<Price>,
@@ -91,7 +147,6 @@ pub struct OrderMatchingEngine {
     execution_count: usize,
 }
 
-// Note: we'll probably be changing the `FillModel` (don't add for now)
 impl OrderMatchingEngine {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -100,9 +155,9 @@ impl OrderMatchingEngine {
         book_type: BookType,
         oms_type: OmsType,
         account_type: AccountType,
-        clock: &'static AtomicTime,
-        msgbus: &'static MessageBus,
-        cache: &'static Cache,
+        clock: Rc<AtomicTime>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
         config: OrderMatchingEngineConfig,
     ) -> Self {
         let book = OrderBook::new(book_type, instrument.id());
@@ -121,8 +176,13 @@ impl OrderMatchingEngine {
             oms_type,
             account_type,
             clock,
+            calendar: None,
+            connection: None,
+            next_connection_change: 0,
             msgbus,
             cache,
+            fill_model: FillModel::default(),
+            fee_model: FeeModelAny::default(),
             book,
             core,
             market_status: MarketStatus::Open,
@@ -153,10 +213,62 @@ impl OrderMatchingEngine {
         self.position_count = 0;
         self.order_count = 0;
         self.execution_count = 0;
+        self.next_connection_change = 0;
 
         info!("Reset {}", self.instrument.id());
     }
 
+    /// Sets the trading calendar for the market sessions of the instrument.
+    pub fn set_calendar(&mut self, calendar: TradingCalendar) {
+        self.calendar = Some(calendar);
+    }
+
+    #[must_use]
+    pub fn calendar(&self) -> Option<&TradingCalendar> {
+        self.calendar.as_ref()
+    }
+
+    /// Sets the venue connectivity outages to simulate.
+    ///
+    /// Connectivity changes before the current engine time are not processed.
+    pub fn set_connection_scenario(&mut self, connection: ConnectionScenario) {
+        let ts_now = self.clock.get_time_ns();
+        self.next_connection_change = (0..)
+            .map_while(|idx| connection.change(idx))
+            .take_while(|(ts, _)| *ts < ts_now)
+            .count();
+        self.connection = Some(connection);
+    }
+
+    /// Sets the fill model for the probability of passive fills and slippage.
+    pub fn set_fill_model(&mut self, fill_model: FillModel) {
+        self.fill_model = fill_model;
+    }
+
+    /// Sets the fee model for the commission charged on fills.
+    pub fn set_fee_model(&mut self, fee_model: FeeModelAny) {
+        self.fee_model = fee_model;
+    }
+
+    #[must_use]
+    pub fn fee_model(&self) -> &FeeModelAny {
+        &self.fee_model
+    }
+
+    #[must_use]
+    pub fn connection_scenario(&self) -> Option<&ConnectionScenario> {
+        self.connection.as_ref()
+    }
+
+    /// Returns whether the venue is connected at the current engine time.
+    #[must_use]
+    pub fn is_connected(&self) -> bool {
+        match &self.connection {
+            Some(connection) => connection.is_connected(self.clock.get_time_ns()),
+            None => true,
+        }
+    }
+
     #[must_use]
     pub fn best_bid_price(&self) -> Option<Price> {
         self.book.best_bid_price()
@@ -193,11 +305,627 @@ impl OrderMatchingEngine {
     pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
         debug!("Processing {delta}");
 
-        self.book.apply_delta(delta);
+        if let Err(e) = self.book.apply_delta(delta) {
+            error!("{e}");
+        }
+    }
+
+    /// Processes the given instrument `close`, returning the generated order events.
+    ///
+    /// A `ContractExpired` close for the engine instrument settles the market: working orders
+    /// are canceled, and each open position is flattened by a settlement fill at the close
+    /// price, after which the market is closed. Other closes generate no events.
+    pub fn process_instrument_close(
+        &mut self,
+        close: InstrumentClose,
+    ) -> anyhow::Result<Vec<OrderEventAny>> {
+        if close.instrument_id != self.instrument.id()
+            || close.close_type != InstrumentCloseType::ContractExpired
+        {
+            return Ok(Vec::new());
+        }
+
+        info!(
+            "Settling {} on contract expiry at {}",
+            close.instrument_id, close.close_price
+        );
+        let ts_now = self.clock.get_time_ns();
+        let mut events = Vec::new();
+
+        let orders: Vec<PassiveOrderAny> = self
+            .core
+            .get_orders_bid()
+            .iter()
+            .chain(self.core.get_orders_ask())
+            .cloned()
+            .collect();
+        for order in &orders {
+            self.core.delete_order(order)?;
+            let client_order_id = order.client_order_id();
+            let cache = self.cache.borrow();
+            let Some(order) = cache.order(&client_order_id) else {
+                warn!("Cannot cancel {client_order_id} on expiry: not found in cache");
+                continue;
+            };
+            events.push(OrderEventAny::Canceled(OrderCanceled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                close.instrument_id,
+                client_order_id,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                order.venue_order_id(),
+                order.account_id(),
+            )?));
+        }
+
+        let positions: Vec<Position> = self
+            .cache
+            .borrow()
+            .positions_open(None, Some(&close.instrument_id), None, None)
+            .into_iter()
+            .cloned()
+            .collect();
+        for position in &positions {
+            let fill = self.generate_settlement_fill(position, close.close_price, ts_now)?;
+            events.push(OrderEventAny::Filled(fill));
+        }
+
+        self.market_status = MarketStatus::Closed;
+        Ok(events)
+    }
+
+    /// Generates a fill flattening the given `position` at the settlement `price`.
+    ///
+    /// The fill is for a reduce-only settlement market order, which is added to the cache as
+    /// accepted so the fill can be applied to it.
+    fn generate_settlement_fill(
+        &mut self,
+        position: &Position,
+        price: Price,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<OrderFilled> {
+        self.order_count += 1;
+        self.execution_count += 1;
+        let venue_order_id = VenueOrderId::from(
+            format!("{}-{}-{:03}", self.venue, self.raw_id, self.order_count).as_str(),
+        );
+        let trade_id = TradeId::from(
+            format!("{}-{}-{:03}", self.venue, self.raw_id, self.execution_count).as_str(),
+        );
+        let order_side = if position.is_long() {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        };
+        let client_order_id = ClientOrderId::from(format!("{}-SETTLEMENT", position.id).as_str());
+
+        let mut order = OrderAny::Market(MarketOrder::new(
+            position.trader_id,
+            position.strategy_id,
+            position.instrument_id,
+            client_order_id,
+            order_side,
+            position.quantity,
+            TimeInForce::Ioc,
+            UUID4::new(),
+            ts_now,
+            true,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?);
+        order.apply(OrderEventAny::Submitted(OrderSubmitted::new(
+            position.trader_id,
+            position.strategy_id,
+            position.instrument_id,
+            client_order_id,
+            position.account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            None,
+        )?))?;
+        order.apply(OrderEventAny::Accepted(OrderAccepted::new(
+            position.trader_id,
+            position.strategy_id,
+            position.instrument_id,
+            client_order_id,
+            venue_order_id,
+            position.account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            None,
+        )?))?;
+        self.cache
+            .borrow_mut()
+            .add_order(order, Some(position.id), None, false)?;
+
+        Ok(OrderFilled::new(
+            position.trader_id,
+            position.strategy_id,
+            position.instrument_id,
+            client_order_id,
+            venue_order_id,
+            position.account_id,
+            trade_id,
+            order_side,
+            OrderType::Market,
+            position.quantity,
+            price,
+            position.settlement_currency,
+            LiquiditySide::Taker,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(position.id),
+            Some(Money::new(0.0, position.settlement_currency)?),
+        )?)
+    }
+
+    /// Advances the engine clock to the given UNIX timestamp (nanoseconds), processing every
+    /// venue connectivity change up to it, returning the generated order events.
+    ///
+    /// Each change is processed with the clock at the time it occurs, so an outage lying
+    /// entirely between two advances is still simulated.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `ts` is before the current engine time, or generating
+    /// an event fails.
+    pub fn advance_time(&mut self, ts: UnixNanos) -> anyhow::Result<Vec<OrderEventAny>> {
+        let ts_now = self.clock.get_time_ns();
+        anyhow::ensure!(
+            ts >= ts_now,
+            "Cannot advance time to {ts} before the current time {ts_now}"
+        );
+
+        let mut events = Vec::new();
+        while let Some((ts_change, is_connected)) = self
+            .connection
+            .as_ref()
+            .and_then(|connection| connection.change(self.next_connection_change))
+            .filter(|(ts_change, _)| *ts_change <= ts)
+        {
+            self.next_connection_change += 1;
+            self.clock.set_time(ts_change);
+            events.extend(self.process_connection_change(is_connected)?);
+        }
+
+        self.clock.set_time(ts);
+        Ok(events)
+    }
+
+    /// Processes a change in venue connectivity at the current engine time.
+    ///
+    /// On disconnect, working orders are canceled if `cancel_on_disconnect` is configured.
+    /// Nothing is replayed on reconnect, as reconciling order state is left to the client.
+    fn process_connection_change(
+        &mut self,
+        is_connected: bool,
+    ) -> anyhow::Result<Vec<OrderEventAny>> {
+        let ts_now = self.clock.get_time_ns();
+        if is_connected {
+            info!("Reconnected {} at {ts_now}", self.venue);
+            return Ok(Vec::new());
+        }
+
+        warn!("Disconnected {} at {ts_now}", self.venue);
+        if !self.config.cancel_on_disconnect {
+            return Ok(Vec::new());
+        }
+
+        let orders: Vec<PassiveOrderAny> = self
+            .core
+            .get_orders_bid()
+            .iter()
+            .chain(self.core.get_orders_ask())
+            .cloned()
+            .collect();
+        let mut events = Vec::with_capacity(orders.len());
+        for order in orders {
+            self.core.delete_order(&order)?;
+            let order = OrderAny::from(order);
+            events.push(OrderEventAny::Canceled(OrderCanceled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                order.venue_order_id(),
+                order.account_id(),
+            )?));
+        }
+        Ok(events)
     }
 
     // -- ORDER PROCESSING ----------------------------------------------------
 
+    /// Checks the venue is connected per the connection scenario (if set).
+    pub fn check_connected(&self) -> anyhow::Result<()> {
+        if self.is_connected() {
+            Ok(())
+        } else {
+            anyhow::bail!(DISCONNECTED_REASON)
+        }
+    }
+
+    /// Validates the `expire_time` of the given `order` against the engine clock.
+    pub fn check_expire_time(&self, order: &PassiveOrderAny) -> Result<(), OrderError> {
+        if !self.config.support_gtd_orders {
+            return Ok(());
+        }
+
+        check_expire_time(
+            order.time_in_force(),
+            order.expire_time(),
+            self.clock.get_time_ns(),
+            self.config.min_expire_lifetime_ns,
+        )
+    }
+
+    /// Checks the market is open per the trading calendar, if configured to reject when closed.
+    pub fn check_market_open(&self) -> anyhow::Result<()> {
+        if !self.config.reject_when_closed {
+            return Ok(());
+        }
+
+        let ts_now = self.clock.get_time_ns();
+        match &self.calendar {
+            Some(calendar) if !calendar.is_open(ts_now) => {
+                anyhow::bail!("Market closed for {} at {ts_now}", self.instrument.id())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Processes the given submit `command` for the `order`, returning the generated event.
+    ///
+    /// The order is accepted onto the matching core, or rejected if the venue is disconnected,
+    /// the market is closed, or a `GTD` order would expire within the minimum lifetime. The event
+    /// carries the `correlation_id` of the command.
+    pub fn process_submit(
+        &mut self,
+        command: &SubmitOrder,
+        mut order: PassiveOrderAny,
+        account_id: AccountId,
+    ) -> anyhow::Result<OrderEventAny> {
+        let ts_now = self.clock.get_time_ns();
+        if let Err(e) = self
+            .check_connected()
+            .and_then(|()| self.check_market_open())
+            .and_then(|()| self.check_expire_time(&order).map_err(anyhow::Error::from))
+        {
+            warn!("Order rejected for {}: {e}", command.client_order_id);
+            return Ok(OrderEventAny::Rejected(OrderRejected::new(
+                command.trader_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                account_id,
+                Ustr::from(&e.to_string()),
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                command.correlation_id,
+            )?));
+        }
+
+        self.order_count += 1;
+        let venue_order_id = VenueOrderId::from(
+            format!("{}-{}-{:03}", self.venue, self.raw_id, self.order_count).as_str(),
+        );
+        let event = OrderEventAny::Accepted(OrderAccepted::new(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            venue_order_id,
+            account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            command.correlation_id,
+        )?);
+        order.apply(event.clone())?;
+        self.core.add_order(order)?;
+        Ok(event)
+    }
+
+    /// Fills the leaves quantity of the `order` against the book, returning one `OrderFilled`
+    /// per price level consumed.
+    ///
+    /// The sweep stops at the order's limit price (if any), leaving any remainder unfilled,
+    /// and the consumed liquidity is removed from the book. A stop (or limit) priced order is
+    /// only filled with the fill model probability for stop (or limit) orders, and market
+    /// fills on an `L1_MBP` book may slip by one tick. Commissions are charged by the fee model.
+    pub fn fill_from_book<T: Order>(
+        &mut self,
+        order: &T,
+        account_id: AccountId,
+    ) -> anyhow::Result<Vec<OrderFilled>> {
+        let venue_order_id = order
+            .venue_order_id()
+            .with_context(|| format!("No venue order ID for {}", order.client_order_id()))?;
+        let is_filled = if order.trigger_price().is_some() {
+            self.fill_model.is_stop_filled()
+        } else if order.price().is_some() {
+            self.fill_model.is_limit_filled()
+        } else {
+            true
+        };
+        if !is_filled {
+            debug!("Fill model did not fill {}", order.client_order_id());
+            return Ok(Vec::new());
+        }
+
+        let simulated =
+            self.book
+                .simulate_level_fills(order.side(), order.leaves_qty(), order.price());
+        if !simulated.is_complete {
+            debug!(
+                "Book liquidity insufficient to fully fill {}",
+                order.client_order_id()
+            );
+        }
+
+        let ts_now = self.clock.get_time_ns();
+        self.book
+            .consume_fills(order.side(), &simulated.fills, self.book.sequence, ts_now)?;
+
+        let is_slipped = self.book_type == BookType::L1_MBP
+            && order.price().is_none()
+            && self.fill_model.is_slipped();
+        let tick = self.instrument.price_increment();
+
+        let mut fills = Vec::with_capacity(simulated.fills.len());
+        for (mut last_px, last_qty) in simulated.fills {
+            if is_slipped {
+                last_px = match order.side() {
+                    OrderSide::Buy => last_px + tick,
+                    _ => last_px - tick,
+                };
+            }
+            let commission = self.fee_model.get_commission(
+                self.instrument.as_ref(),
+                LiquiditySide::Taker,
+                last_qty,
+                last_px,
+            )?;
+            self.execution_count += 1;
+            let trade_id = TradeId::from(
+                format!("{}-{}-{:03}", self.venue, self.raw_id, self.execution_count).as_str(),
+            );
+            fills.push(OrderFilled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                venue_order_id,
+                account_id,
+                trade_id,
+                order.side(),
+                order.order_type(),
+                last_qty,
+                last_px,
+                self.instrument.quote_currency(),
+                LiquiditySide::Taker,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                order.position_id(),
+                Some(commission),
+            )?);
+        }
+
+        Ok(fills)
+    }
+
+    /// Processes the given modify `command`, returning the generated order events.
+    ///
+    /// An `OrderPendingUpdate` is generated on receipt, followed by either an `OrderUpdated`
+    /// or an `OrderModifyRejected` once the amendment is applied (see `update_order`).
+    pub fn process_modify(
+        &mut self,
+        command: &ModifyOrder,
+        account_id: AccountId,
+    ) -> anyhow::Result<Vec<OrderEventAny>> {
+        let mut events = Vec::with_capacity(2);
+        if let Some(event) = self.generate_order_pending_update(command, account_id)? {
+            events.push(event);
+        }
+        events.push(self.update_order(command, account_id)?);
+        Ok(events)
+    }
+
+    /// Generates an `OrderPendingUpdate` for the order of the given `command` (if it is
+    /// still working on the matching engine).
+    pub fn generate_order_pending_update(
+        &mut self,
+        command: &ModifyOrder,
+        account_id: AccountId,
+    ) -> anyhow::Result<Option<OrderEventAny>> {
+        if !self.core.order_exists(command.client_order_id) {
+            return Ok(None);
+        }
+
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderEventAny::PendingUpdate(OrderPendingUpdate::new(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(command.venue_order_id),
+        )?);
+        self.apply_order_event(event.clone(), true)?;
+        Ok(Some(event))
+    }
+
+    /// Applies the amendment of the given `command` to its working order.
+    ///
+    /// Price (or trigger price) changes and quantity increases lose queue priority, with the
+    /// order moved to the back of its (new) price level. Pure quantity decreases keep priority.
+    /// The order is looked up again here, so fills which landed after the pending update
+    /// was generated are taken into account.
+    pub fn update_order(
+        &mut self,
+        command: &ModifyOrder,
+        account_id: AccountId,
+    ) -> anyhow::Result<OrderEventAny> {
+        let Some(order) = self.core.get_order(command.client_order_id).cloned() else {
+            let reason = format!("{} not found", command.client_order_id);
+            return self.generate_order_modify_rejected(command, account_id, &reason);
+        };
+
+        let quantity = command.quantity.unwrap_or(order.quantity());
+        let price = order.price().map(|px| command.price.unwrap_or(px));
+        let trigger_price = order
+            .trigger_price()
+            .map(|px| command.trigger_price.unwrap_or(px));
+
+        if let Err(e) = self.check_modify(&order, quantity, price, trigger_price) {
+            return self.generate_order_modify_rejected(command, account_id, &e.to_string());
+        }
+
+        let keep_priority = price == order.price()
+            && trigger_price == order.trigger_price()
+            && quantity <= order.quantity();
+
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderEventAny::Updated(OrderUpdated::new(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            quantity,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(command.venue_order_id),
+            Some(account_id),
+            price,
+            trigger_price,
+            Some(order.quantity()),
+            order.price(),
+            order.trigger_price(),
+        )?);
+        self.apply_order_event(event.clone(), keep_priority)?;
+        Ok(event)
+    }
+
+    fn check_modify(
+        &mut self,
+        order: &PassiveOrderAny,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) -> anyhow::Result<()> {
+        self.check_connected()?;
+        self.check_market_open()?;
+
+        let size_precision = self.instrument.size_precision();
+        if quantity.precision != size_precision {
+            anyhow::bail!(
+                "Invalid quantity precision {}, expected {size_precision}",
+                quantity.precision
+            );
+        }
+        if quantity <= order.filled_qty() {
+            anyhow::bail!(
+                "Modified quantity {quantity} <= filled quantity {}",
+                order.filled_qty()
+            );
+        }
+        if let Some(min_quantity) = self.instrument.min_quantity() {
+            if quantity < min_quantity {
+                anyhow::bail!("Modified quantity {quantity} < minimum quantity {min_quantity}");
+            }
+        }
+        if let Some(max_quantity) = self.instrument.max_quantity() {
+            if quantity > max_quantity {
+                anyhow::bail!("Modified quantity {quantity} > maximum quantity {max_quantity}");
+            }
+        }
+
+        let price_precision = self.instrument.price_precision();
+        for px in price.iter().chain(trigger_price.iter()) {
+            if px.precision != price_precision {
+                anyhow::bail!(
+                    "Invalid price precision {}, expected {price_precision}",
+                    px.precision
+                );
+            }
+        }
+
+        if let (PassiveOrderAny::Limit(_), Some(price)) = (order, price) {
+            self.core.bid = self.book.best_bid_price();
+            self.core.ask = self.book.best_ask_price();
+            if order.is_post_only()
+                && self
+                    .core
+                    .is_limit_price_matched(order.order_side_specified(), price)
+            {
+                anyhow::bail!(
+                    "POST_ONLY order new limit px of {price} would have been a TAKER: bid={:?}, ask={:?}",
+                    self.core.bid,
+                    self.core.ask,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn generate_order_modify_rejected(
+        &mut self,
+        command: &ModifyOrder,
+        account_id: AccountId,
+        reason: &str,
+    ) -> anyhow::Result<OrderEventAny> {
+        warn!("Modify rejected for {}: {reason}", command.client_order_id);
+
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderEventAny::ModifyRejected(OrderModifyRejected::new(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(command.venue_order_id),
+            Some(account_id),
+        )?);
+
+        self.apply_order_event(event.clone(), true)?;
+        Ok(event)
+    }
+
     /// Iterate the matching engine by processing the bid and ask order sides
     /// and advancing time up to the given UNIX `timestamp_ns`.
     pub fn iterate(&mut self, timestamp_ns: UnixNanos) {
@@ -251,6 +979,26 @@ impl OrderMatchingEngine {
         self.target_last = None;
     }
 
+    /// Applies the given `event` to the working order (if any), removing it once closed.
+    pub(crate) fn apply_order_event(
+        &mut self,
+        event: OrderEventAny,
+        keep_priority: bool,
+    ) -> anyhow::Result<()> {
+        let Some(order) = self.core.get_order(event.client_order_id()) else {
+            return Ok(());
+        };
+
+        let mut order = order.clone();
+        order.apply(event)?;
+        if order.is_closed() {
+            self.core.delete_order(&order)?;
+        } else {
+            self.core.update_order(order, keep_priority)?;
+        }
+        Ok(())
+    }
+
     fn expire_order(&mut self, order: &PassiveOrderAny) {
         todo!();
     }
@@ -263,3 +1011,822 @@ impl OrderMatchingEngine {
         todo!()
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveTime, Weekday};
+    use chrono_tz::America::New_York;
+    use nautilus_common::calendar::SessionTemplate;
+    use nautilus_model::{
+        data::order::BookOrder,
+        enums::{BookAction, OrderSide, OrderStatus},
+        events::order::filled::OrderFilled,
+        identifiers::{strategy_id::StrategyId, venue_order_id::VenueOrderId},
+        instruments::stubs::{audusd_sim, futures_contract_es},
+        orders::{
+            any::{LimitOrderAny, OrderAny},
+            limit::LimitOrder,
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+        },
+        polymorphism::{GetClientOrderId, GetOrderStatus},
+        types::{currency::Currency, money::Money},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{
+        models::FixedFeeModel,
+        testkit::{ExecutionScenario, ScenarioStep},
+    };
+
+    #[fixture]
+    fn engine() -> OrderMatchingEngine {
+        engine_for(
+            Box::new(audusd_sim()),
+            Rc::new(RefCell::new(Cache::default())),
+        )
+    }
+
+    fn engine_for(
+        instrument: Box<dyn Instrument>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> OrderMatchingEngine {
+        let clock = Rc::new(AtomicTime::new(false, UnixNanos::default()));
+        let msgbus = Rc::new(RefCell::new(
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap(),
+        ));
+        let config = OrderMatchingEngineConfig {
+            bar_execution: false,
+            reject_stop_orders: true,
+            support_gtd_orders: true,
+            min_expire_lifetime_ns: 0,
+            support_contingent_orders: true,
+            use_position_ids: true,
+            use_random_ids: false,
+            use_reduce_only: true,
+            reject_when_closed: true,
+            cancel_on_disconnect: false,
+        };
+        OrderMatchingEngine::new(
+            instrument,
+            1,
+            BookType::L2_MBP,
+            OmsType::Netting,
+            AccountType::Margin,
+            clock,
+            msgbus,
+            cache,
+            config,
+        )
+    }
+
+    fn account_id() -> AccountId {
+        AccountId::from("SIM-001")
+    }
+
+    fn accepted_limit_order(client_order_id: &str, side: OrderSide, price: &str) -> LimitOrder {
+        let mut order = TestOrderStubs::limit_order(
+            audusd_sim().id,
+            side,
+            Price::from(price),
+            Quantity::from(100_000),
+            Some(ClientOrderId::from(client_order_id)),
+            None,
+        );
+        let submitted = TestOrderEventStubs::order_submitted(&order, account_id()).unwrap();
+        let accepted = TestOrderEventStubs::order_accepted(
+            &order,
+            account_id(),
+            VenueOrderId::from(client_order_id),
+        )
+        .unwrap();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order
+    }
+
+    fn limit_order(client_order_id: &str, price: &str) -> OrderAny {
+        OrderAny::Limit(TestOrderStubs::limit_order(
+            audusd_sim().id,
+            OrderSide::Buy,
+            Price::from(price),
+            Quantity::from(100_000),
+            Some(ClientOrderId::from(client_order_id)),
+            None,
+        ))
+    }
+
+    fn add_order(engine: &mut OrderMatchingEngine, order: LimitOrder) {
+        engine
+            .core
+            .add_order(PassiveOrderAny::Limit(LimitOrderAny::Limit(order)))
+            .unwrap();
+    }
+
+    fn modify_command(
+        client_order_id: &str,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+    ) -> ModifyOrder {
+        ModifyOrder {
+            trader_id: TraderId::from("TRADER-001"),
+            strategy_id: StrategyId::from("EMACross-001"),
+            instrument_id: audusd_sim().id,
+            client_order_id: ClientOrderId::from(client_order_id),
+            venue_order_id: VenueOrderId::from(client_order_id),
+            quantity,
+            price,
+            ..Default::default()
+        }
+    }
+
+    fn fill(order: &PassiveOrderAny, last_qty: Quantity) -> OrderFilled {
+        let PassiveOrderAny::Limit(LimitOrderAny::Limit(order)) = order else {
+            panic!("expected limit order");
+        };
+        TestOrderEventStubs::order_filled(
+            order,
+            &audusd_sim(),
+            None,
+            None,
+            None,
+            None,
+            Some(last_qty),
+            None,
+            None,
+            Some(account_id()),
+        )
+        .unwrap()
+    }
+
+    fn bid_order_ids(engine: &OrderMatchingEngine) -> Vec<ClientOrderId> {
+        engine
+            .get_open_bid_orders()
+            .iter()
+            .map(GetClientOrderId::client_order_id)
+            .collect()
+    }
+
+    fn order_ids(ids: &[&str]) -> Vec<ClientOrderId> {
+        ids.iter().map(|id| ClientOrderId::from(*id)).collect()
+    }
+
+    #[rstest]
+    fn test_modify_quantity_decrease_keeps_priority(mut engine: OrderMatchingEngine) {
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-1", OrderSide::Buy, "1.00000"),
+        );
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-2", OrderSide::Buy, "1.00000"),
+        );
+
+        let command = modify_command("O-1", Some(Quantity::from(50_000)), None);
+        let events = engine.process_modify(&command, account_id()).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEventAny::PendingUpdate(_)));
+        assert!(matches!(events[1], OrderEventAny::Updated(_)));
+        assert_eq!(bid_order_ids(&engine), order_ids(&["O-1", "O-2"]));
+        let order = engine.core.get_order(ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(order.quantity(), Quantity::from(50_000));
+    }
+
+    #[rstest]
+    fn test_modify_quantity_increase_loses_priority(mut engine: OrderMatchingEngine) {
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-1", OrderSide::Buy, "1.00000"),
+        );
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-2", OrderSide::Buy, "1.00000"),
+        );
+
+        let command = modify_command("O-1", Some(Quantity::from(150_000)), None);
+        let events = engine.process_modify(&command, account_id()).unwrap();
+
+        assert!(matches!(events[1], OrderEventAny::Updated(_)));
+        assert_eq!(bid_order_ids(&engine), order_ids(&["O-2", "O-1"]));
+    }
+
+    #[rstest]
+    fn test_modify_price_loses_priority_at_new_level(mut engine: OrderMatchingEngine) {
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-1", OrderSide::Buy, "1.00000"),
+        );
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-2", OrderSide::Buy, "0.99990"),
+        );
+
+        let command = modify_command("O-1", None, Some(Price::from("0.99990")));
+        let events = engine.process_modify(&command, account_id()).unwrap();
+
+        let OrderEventAny::Updated(updated) = events[1] else {
+            panic!("expected `OrderUpdated`, was {:?}", events[1]);
+        };
+        assert_eq!(updated.price, Some(Price::from("0.99990")));
+        assert_eq!(updated.prev_price, Some(Price::from("1.00000")));
+        assert_eq!(bid_order_ids(&engine), order_ids(&["O-2", "O-1"]));
+        let order = engine.core.get_order(ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(order.price(), Some(Price::from("0.99990")));
+    }
+
+    #[rstest]
+    fn test_modify_post_only_to_crossing_price_rejected(mut engine: OrderMatchingEngine) {
+        engine.book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("1.00010"),
+                Quantity::from(1_000_000),
+                1,
+            ),
+            0,
+            1,
+            UnixNanos::default(),
+        );
+        let mut order = accepted_limit_order("O-1", OrderSide::Buy, "1.00000");
+        order.is_post_only = true;
+        add_order(&mut engine, order);
+
+        let command = modify_command("O-1", None, Some(Price::from("1.00010")));
+        let events = engine.process_modify(&command, account_id()).unwrap();
+
+        assert!(matches!(events[1], OrderEventAny::ModifyRejected(_)));
+        let order = engine.core.get_order(ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(order.price(), Some(Price::from("1.00000")));
+        let PassiveOrderAny::Limit(LimitOrderAny::Limit(order)) = order else {
+            panic!("expected limit order");
+        };
+        assert_eq!(order.status, OrderStatus::Accepted);
+    }
+
+    #[rstest]
+    fn test_modify_invalid_quantity_precision_rejected(mut engine: OrderMatchingEngine) {
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-1", OrderSide::Buy, "1.00000"),
+        );
+
+        let command = modify_command("O-1", Some(Quantity::from("50000.5")), None);
+        let events = engine.process_modify(&command, account_id()).unwrap();
+
+        assert!(matches!(events[0], OrderEventAny::PendingUpdate(_)));
+        assert!(matches!(events[1], OrderEventAny::ModifyRejected(_)));
+    }
+
+    #[rstest]
+    fn test_modify_unknown_order_rejected(mut engine: OrderMatchingEngine) {
+        let command = modify_command("O-1", Some(Quantity::from(50_000)), None);
+        let events = engine.process_modify(&command, account_id()).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::ModifyRejected(_)));
+    }
+
+    #[rstest]
+    fn test_modify_when_filled_after_pending_update_rejected(mut engine: OrderMatchingEngine) {
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-1", OrderSide::Buy, "1.00000"),
+        );
+        let command = modify_command("O-1", Some(Quantity::from(50_000)), None);
+
+        let pending = engine
+            .generate_order_pending_update(&command, account_id())
+            .unwrap();
+        let order = engine.core.get_order(command.client_order_id).unwrap();
+        let fill = fill(order, Quantity::from(100_000));
+        engine
+            .apply_order_event(OrderEventAny::Filled(fill), true)
+            .unwrap();
+        let event = engine.update_order(&command, account_id()).unwrap();
+
+        assert!(matches!(pending, Some(OrderEventAny::PendingUpdate(_))));
+        assert!(matches!(event, OrderEventAny::ModifyRejected(_)));
+        assert!(!engine.order_exists(command.client_order_id));
+    }
+
+    #[rstest]
+    fn test_modify_below_fill_after_pending_update_rejected(mut engine: OrderMatchingEngine) {
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-1", OrderSide::Buy, "1.00000"),
+        );
+        let command = modify_command("O-1", Some(Quantity::from(50_000)), None);
+
+        engine
+            .generate_order_pending_update(&command, account_id())
+            .unwrap();
+        let order = engine.core.get_order(command.client_order_id).unwrap();
+        let fill = fill(order, Quantity::from(60_000));
+        engine
+            .apply_order_event(OrderEventAny::PartiallyFilled(fill), true)
+            .unwrap();
+        let event = engine.update_order(&command, account_id()).unwrap();
+
+        assert!(matches!(event, OrderEventAny::ModifyRejected(_)));
+        let order = engine.core.get_order(command.client_order_id).unwrap();
+        assert_eq!(order.quantity(), Quantity::from(100_000));
+        assert_eq!(order.filled_qty(), Quantity::from(60_000));
+    }
+
+    fn equities_calendar() -> TradingCalendar {
+        let templates = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]
+        .into_iter()
+        .map(|weekday| {
+            SessionTemplate::new(
+                weekday,
+                NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+                NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            )
+        })
+        .collect();
+        TradingCalendar::new(New_York, templates, vec![])
+    }
+
+    #[rstest]
+    fn test_check_market_open_with_no_calendar(engine: OrderMatchingEngine) {
+        assert!(engine.check_market_open().is_ok());
+    }
+
+    #[rstest]
+    fn test_check_market_open_with_calendar(mut engine: OrderMatchingEngine) {
+        engine.set_calendar(equities_calendar());
+
+        // 1970-01-01 is a Thursday, with 00:00 UTC being 19:00 EST the prior evening
+        let closed = engine.check_market_open();
+        engine
+            .clock
+            .set_time(UnixNanos::from(15 * 3_600_000_000_000)); // 10:00 EST
+        let open = engine.check_market_open();
+
+        assert!(closed.is_err());
+        assert!(open.is_ok());
+    }
+
+    #[rstest]
+    fn test_submit_events_carry_command_correlation_id() {
+        let correlation_id = UUID4::new();
+        let other_correlation_id = UUID4::new();
+        let mut scenario = ExecutionScenario::builder(audusd_sim()).build().unwrap();
+
+        let uncorrelated = scenario.submit(limit_order("O-1", "1.00000")).unwrap();
+        let accepted = scenario
+            .submit_with_correlation(limit_order("O-2", "1.00000"), Some(correlation_id))
+            .unwrap();
+        // Market closed at 1970-01-01 00:00 UTC
+        scenario.engine_mut().set_calendar(equities_calendar());
+        let rejected = scenario
+            .submit_with_correlation(limit_order("O-3", "1.00000"), Some(other_correlation_id))
+            .unwrap();
+
+        scenario.assert_event_sequence(&["Accepted", "Accepted", "Rejected"]);
+        scenario.assert_order_status("O-2", OrderStatus::Accepted);
+        scenario.assert_order_status("O-3", OrderStatus::Rejected);
+        assert_eq!(uncorrelated.correlation_id(), None);
+        assert_eq!(accepted.correlation_id(), Some(correlation_id));
+        assert_eq!(
+            accepted.venue_order_id(),
+            Some(VenueOrderId::from("SIM-1-002"))
+        );
+        assert_eq!(rejected.correlation_id(), Some(other_correlation_id));
+        assert_eq!(bid_order_ids(scenario.engine()), order_ids(&["O-1", "O-2"]));
+    }
+
+    fn gtd_limit_order(expire_time: u64) -> OrderAny {
+        OrderAny::Limit(
+            TestOrderStubs::limit_order_gtd(
+                audusd_sim().id,
+                OrderSide::Buy,
+                Price::from("1.00000"),
+                Quantity::from(100_000),
+                Some(UnixNanos::from(expire_time)),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[rstest]
+    #[case(2_000, "Order `expire_time` 2000 is not after the current time 2000")]
+    #[case(
+        2_500,
+        "Order `expire_time` 2500 is less than the minimum lifetime of 1000ns from the current time 2000"
+    )]
+    fn test_submit_gtd_order_expiring_too_soon_rejected(
+        #[case] expire_time: u64,
+        #[case] expected: &str,
+    ) {
+        let config = OrderMatchingEngineConfig {
+            min_expire_lifetime_ns: 1_000,
+            ..Default::default()
+        };
+        let mut scenario = ExecutionScenario::builder(audusd_sim())
+            .with_config(config)
+            .with_start_time(UnixNanos::from(2_000))
+            .build()
+            .unwrap();
+
+        let event = scenario.submit(gtd_limit_order(expire_time)).unwrap();
+
+        let OrderEventAny::Rejected(rejected) = event else {
+            panic!("expected rejection, was {event:?}");
+        };
+        assert_eq!(rejected.reason.as_str(), expected);
+        assert!(bid_order_ids(scenario.engine()).is_empty());
+    }
+
+    #[rstest]
+    fn test_submit_gtd_order_beyond_minimum_lifetime_accepted() {
+        let config = OrderMatchingEngineConfig {
+            min_expire_lifetime_ns: 1_000,
+            ..Default::default()
+        };
+        let mut scenario = ExecutionScenario::builder(audusd_sim())
+            .with_config(config)
+            .with_start_time(UnixNanos::from(2_000))
+            .build()
+            .unwrap();
+
+        scenario.submit(gtd_limit_order(3_001)).unwrap();
+
+        scenario.assert_event_sequence(&["Accepted"]);
+        assert_eq!(bid_order_ids(scenario.engine()).len(), 1);
+    }
+
+    #[rstest]
+    fn test_check_market_open_when_not_rejecting(mut engine: OrderMatchingEngine) {
+        engine.config.reject_when_closed = false;
+        engine.set_calendar(equities_calendar());
+
+        assert!(engine.check_market_open().is_ok());
+    }
+
+    #[rstest]
+    fn test_modify_when_market_closed_rejected(mut engine: OrderMatchingEngine) {
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-1", OrderSide::Buy, "1.00000"),
+        );
+        engine.set_calendar(equities_calendar());
+        let command = modify_command("O-1", Some(Quantity::from(50_000)), None);
+
+        let event = engine.update_order(&command, account_id()).unwrap();
+
+        assert!(matches!(event, OrderEventAny::ModifyRejected(_)));
+    }
+
+    fn es_position() -> Position {
+        let instrument = futures_contract_es();
+        let order = TestOrderStubs::market_order(
+            instrument.id,
+            OrderSide::Buy,
+            Quantity::from(10),
+            Some(ClientOrderId::from("O-1")),
+            None,
+        );
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            None,
+            Some(Price::from("5000.00")),
+            None,
+            None,
+            None,
+            Some(account_id()),
+        )
+        .unwrap();
+        Position::new(instrument, fill).unwrap()
+    }
+
+    fn es_close(close_type: InstrumentCloseType) -> InstrumentClose {
+        InstrumentClose::new(
+            futures_contract_es().id,
+            Price::from("5010.25"),
+            close_type,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+    }
+
+    #[rstest]
+    fn test_contract_expired_close_settles_open_position() {
+        let mut position = es_position();
+        let mut scenario = ExecutionScenario::builder(futures_contract_es())
+            .with_position(position.clone())
+            .with_steps([ScenarioStep::Close(es_close(
+                InstrumentCloseType::ContractExpired,
+            ))])
+            .build()
+            .unwrap();
+
+        scenario.run().unwrap();
+
+        scenario.assert_event_sequence(&["Filled"]);
+        scenario.assert_position_qty(0.0);
+        let OrderEventAny::Filled(fill) = scenario.events()[0] else {
+            panic!("expected settlement fill");
+        };
+        assert_eq!(fill.order_side, OrderSide::Sell);
+        assert_eq!(fill.last_qty, Quantity::from(10));
+        assert_eq!(fill.last_px, Price::from("5010.25"));
+        assert_eq!(fill.position_id, Some(position.id));
+        assert_eq!(fill.currency, position.settlement_currency);
+        assert_eq!(scenario.engine().market_status, MarketStatus::Closed);
+
+        let cache = scenario.cache();
+        let cache = cache.borrow();
+        let order = cache.order(&fill.client_order_id).unwrap();
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.venue_order_id(), Some(fill.venue_order_id));
+        assert_eq!(cache.position_id(&fill.client_order_id), Some(&position.id));
+
+        position.apply(&fill);
+        assert!(position.is_closed());
+        // 10 contracts * 10.25 less the 2 USD opening commission
+        assert_eq!(position.realized_pnl, Some(Money::from("100.50 USD")));
+    }
+
+    fn add_ask(engine: &mut OrderMatchingEngine, price: &str, size: i64, order_id: u64) {
+        let order = BookOrder::new(
+            OrderSide::Sell,
+            Price::from(price),
+            Quantity::from(size),
+            order_id,
+        );
+        engine.book.add(order, 0, order_id, UnixNanos::default());
+    }
+
+    fn add_asks(engine: &mut OrderMatchingEngine) {
+        add_ask(engine, "1.00000", 100_000, 1);
+        add_ask(engine, "1.00001", 100_000, 2);
+        add_ask(engine, "1.00003", 200_000, 3);
+    }
+
+    fn ask_delta(price: &str, size: i64, order_id: u64) -> ScenarioStep {
+        let order = BookOrder::new(
+            OrderSide::Sell,
+            Price::from(price),
+            Quantity::from(size),
+            order_id,
+        );
+        ScenarioStep::Delta(OrderBookDelta::new(
+            audusd_sim().id,
+            BookAction::Add,
+            order,
+            0,
+            order_id,
+            UnixNanos::from(order_id),
+            UnixNanos::from(order_id),
+        ))
+    }
+
+    #[rstest]
+    fn test_fill_from_book_generates_fill_per_level() {
+        let mut scenario = ExecutionScenario::builder(audusd_sim())
+            .with_steps([
+                ask_delta("1.00000", 100_000, 1),
+                ask_delta("1.00001", 100_000, 2),
+                ask_delta("1.00003", 200_000, 3),
+            ])
+            .build()
+            .unwrap();
+        scenario.run().unwrap();
+        let mut order = limit_order("O-1", "1.00003");
+        if let OrderAny::Limit(order) = &mut order {
+            order.quantity = Quantity::from(200_000);
+            order.leaves_qty = Quantity::from(200_000);
+        }
+        scenario.submit(order).unwrap();
+
+        let events = scenario.fill_from_book(ClientOrderId::from("O-1")).unwrap();
+
+        let fills: Vec<OrderFilled> = events
+            .iter()
+            .filter_map(|event| match event {
+                OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => Some(*fill),
+                _ => None,
+            })
+            .collect();
+        let levels: Vec<(Price, Quantity)> = fills
+            .iter()
+            .map(|fill| (fill.last_px, fill.last_qty))
+            .collect();
+        assert_eq!(
+            levels,
+            vec![
+                (Price::from("1.00000"), Quantity::from(100_000)),
+                (Price::from("1.00001"), Quantity::from(100_000)),
+            ]
+        );
+        assert_ne!(fills[0].trade_id, fills[1].trade_id);
+        scenario.assert_event_sequence(&["Accepted", "PartiallyFilled", "Filled"]);
+        scenario.assert_order_status("O-1", OrderStatus::Filled);
+        scenario.assert_position_qty(200_000.0);
+        assert!(!scenario.engine().order_exists(ClientOrderId::from("O-1")));
+        assert_eq!(
+            scenario.engine().best_ask_price(),
+            Some(Price::from("1.00003"))
+        );
+    }
+
+    #[rstest]
+    fn test_fill_from_book_stops_at_limit_price(mut engine: OrderMatchingEngine) {
+        add_asks(&mut engine);
+        let mut order = accepted_limit_order("O-1", OrderSide::Buy, "1.00002");
+        order.quantity = Quantity::from(300_000);
+        order.leaves_qty = Quantity::from(300_000);
+
+        let fills = engine.fill_from_book(&order, account_id()).unwrap();
+
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[1].last_px, Price::from("1.00001"));
+        let total: u64 = fills.iter().map(|fill| fill.last_qty.raw).sum();
+        assert_eq!(total, Quantity::from(200_000).raw);
+        let remaining = engine
+            .get_book()
+            .asks(None)
+            .map(|(_, size, _)| size.as_f64())
+            .sum::<f64>();
+        assert_eq!(remaining, 200_000.0);
+    }
+
+    #[rstest]
+    fn test_fill_model_without_limit_fill_does_not_fill(mut engine: OrderMatchingEngine) {
+        add_asks(&mut engine);
+        engine.set_fill_model(FillModel::new(0.0, 1.0, 0.0, Some(1)).unwrap());
+        let order = accepted_limit_order("O-1", OrderSide::Buy, "1.00003");
+
+        let fills = engine.fill_from_book(&order, account_id()).unwrap();
+
+        assert!(fills.is_empty());
+        assert_eq!(engine.best_ask_price(), Some(Price::from("1.00000")));
+    }
+
+    #[rstest]
+    fn test_fee_model_commission_charged_per_fill(mut engine: OrderMatchingEngine) {
+        add_asks(&mut engine);
+        let commission = Money::new(2.0, Currency::USD()).unwrap();
+        engine.set_fee_model(FeeModelAny::Fixed(FixedFeeModel::new(commission).unwrap()));
+        let mut order = accepted_limit_order("O-1", OrderSide::Buy, "1.00001");
+        order.quantity = Quantity::from(200_000);
+        order.leaves_qty = Quantity::from(200_000);
+
+        let fills = engine.fill_from_book(&order, account_id()).unwrap();
+
+        assert_eq!(fills.len(), 2);
+        assert!(fills.iter().all(|fill| fill.commission == Some(commission)));
+    }
+
+    #[rstest]
+    fn test_end_of_session_close_does_not_settle() {
+        let close = InstrumentClose::new(
+            audusd_sim().id,
+            Price::from("1.00010"),
+            InstrumentCloseType::EndOfSession,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        let mut scenario = ExecutionScenario::builder(audusd_sim())
+            .with_steps([ScenarioStep::Close(close)])
+            .build()
+            .unwrap();
+        scenario.submit(limit_order("O-1", "1.00000")).unwrap();
+
+        let events = scenario.run().unwrap();
+
+        assert!(events.is_empty());
+        scenario.assert_event_sequence(&["Accepted"]);
+        scenario.assert_order_status("O-1", OrderStatus::Accepted);
+        assert_eq!(bid_order_ids(scenario.engine()), order_ids(&["O-1"]));
+    }
+
+    #[rstest]
+    fn test_contract_expired_close_removes_working_orders(mut engine: OrderMatchingEngine) {
+        add_order(
+            &mut engine,
+            accepted_limit_order("O-1", OrderSide::Buy, "1.00000"),
+        );
+        let close = InstrumentClose::new(
+            audusd_sim().id,
+            Price::from("1.00010"),
+            InstrumentCloseType::ContractExpired,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        engine.process_instrument_close(close).unwrap();
+
+        assert!(bid_order_ids(&engine).is_empty());
+        assert_eq!(engine.market_status, MarketStatus::Closed);
+    }
+
+    fn outage_scenario(cancel_on_disconnect: bool) -> ExecutionScenario {
+        let connection =
+            ConnectionScenario::new(vec![(UnixNanos::from(100), UnixNanos::from(200))]).unwrap();
+        ExecutionScenario::builder(audusd_sim())
+            .with_config(OrderMatchingEngineConfig {
+                cancel_on_disconnect,
+                ..Default::default()
+            })
+            .with_connection_scenario(connection)
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_cancel_on_disconnect_across_outage() {
+        let mut scenario = outage_scenario(true);
+        scenario.submit(limit_order("O-1", "1.00000")).unwrap();
+
+        let disconnect_events = scenario.run_until(UnixNanos::from(100)).unwrap();
+        let rejected = scenario.submit(limit_order("O-2", "1.00000")).unwrap();
+        let reconnect_events = scenario.run_until(UnixNanos::from(200)).unwrap();
+        let accepted = scenario.submit(limit_order("O-3", "1.00000")).unwrap();
+
+        assert_eq!(disconnect_events.len(), 1);
+        assert!(reconnect_events.is_empty());
+        scenario.assert_event_sequence(&["Accepted", "Canceled", "Rejected", "Accepted"]);
+        scenario.assert_order_status("O-1", OrderStatus::Canceled);
+        scenario.assert_order_status("O-2", OrderStatus::Rejected);
+        scenario.assert_order_status("O-3", OrderStatus::Accepted);
+        let OrderEventAny::Rejected(rejected) = rejected else {
+            panic!("expected rejection, was {rejected}");
+        };
+        assert_eq!(rejected.reason, Ustr::from(DISCONNECTED_REASON));
+        assert!(matches!(accepted, OrderEventAny::Accepted(_)));
+        assert_eq!(bid_order_ids(scenario.engine()), order_ids(&["O-3"]));
+    }
+
+    #[rstest]
+    fn test_cancel_on_disconnect_for_outage_between_steps() {
+        let mut scenario = outage_scenario(true);
+        scenario.submit(limit_order("O-1", "1.00000")).unwrap();
+        scenario.run_until(UnixNanos::from(50)).unwrap();
+
+        let events = scenario.run_until(UnixNanos::from(250)).unwrap();
+        let accepted = scenario.submit(limit_order("O-2", "1.00000")).unwrap();
+
+        let [OrderEventAny::Canceled(canceled)] = events.as_slice() else {
+            panic!("expected a single cancel, was {events:?}");
+        };
+        assert_eq!(canceled.client_order_id, ClientOrderId::from("O-1"));
+        assert_eq!(canceled.ts_event, UnixNanos::from(100));
+        assert_eq!(canceled.ts_init, UnixNanos::from(100));
+        assert!(matches!(accepted, OrderEventAny::Accepted(_)));
+        assert_eq!(scenario.clock().get_time_ns(), UnixNanos::from(250));
+        assert_eq!(bid_order_ids(scenario.engine()), order_ids(&["O-2"]));
+    }
+
+    #[rstest]
+    fn test_disconnect_without_cancel_keeps_working_orders() {
+        let mut scenario = outage_scenario(false);
+        scenario.submit(limit_order("O-1", "1.00000")).unwrap();
+
+        let disconnect_events = scenario.run_until(UnixNanos::from(150)).unwrap();
+        let modify_events = scenario
+            .modify(
+                ClientOrderId::from("O-1"),
+                Some(Quantity::from(50_000)),
+                None,
+            )
+            .unwrap();
+        scenario.run_until(UnixNanos::from(250)).unwrap();
+        scenario
+            .modify(
+                ClientOrderId::from("O-1"),
+                Some(Quantity::from(50_000)),
+                None,
+            )
+            .unwrap();
+
+        assert!(disconnect_events.is_empty());
+        let Some(OrderEventAny::ModifyRejected(rejected)) = modify_events.last() else {
+            panic!("expected modify rejection, was {modify_events:?}");
+        };
+        assert_eq!(rejected.reason, Ustr::from(DISCONNECTED_REASON));
+        scenario.assert_event_sequence(&[
+            "Accepted",
+            "PendingUpdate",
+            "ModifyRejected",
+            "PendingUpdate",
+            "Updated",
+        ]);
+        assert_eq!(bid_order_ids(scenario.engine()), order_ids(&["O-1"]));
+    }
+}
@@ -0,0 +1,349 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the fill, fee and latency models for simulated venue execution.
+
+use nautilus_core::correctness::check_in_range_inclusive_f64;
+use nautilus_model::{
+    enums::LiquiditySide,
+    instruments::Instrument,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Provides probabilistic modeling of order fill dynamics, including the probability of
+/// passive orders being filled when the market touches their price, and of slippage.
+#[derive(Clone, Debug)]
+pub struct FillModel {
+    /// The probability of limit order filling if the market rests on its price.
+    pub prob_fill_on_limit: f64,
+    /// The probability of stop orders filling if the market rests on its price.
+    pub prob_fill_on_stop: f64,
+    /// The probability of order fill prices slipping by one tick.
+    pub prob_slippage: f64,
+    rng: StdRng,
+}
+
+impl FillModel {
+    /// Creates a new `FillModel` instance.
+    ///
+    /// The model is deterministic for a given `random_seed`, otherwise it is seeded from entropy.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any probability is not in the range [0, 1].
+    pub fn new(
+        prob_fill_on_limit: f64,
+        prob_fill_on_stop: f64,
+        prob_slippage: f64,
+        random_seed: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        check_in_range_inclusive_f64(prob_fill_on_limit, 0.0, 1.0, "prob_fill_on_limit")?;
+        check_in_range_inclusive_f64(prob_fill_on_stop, 0.0, 1.0, "prob_fill_on_stop")?;
+        check_in_range_inclusive_f64(prob_slippage, 0.0, 1.0, "prob_slippage")?;
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Ok(Self {
+            prob_fill_on_limit,
+            prob_fill_on_stop,
+            prob_slippage,
+            rng,
+        })
+    }
+
+    /// Returns whether a limit order is filled when the market rests on its price.
+    pub fn is_limit_filled(&mut self) -> bool {
+        self.event_success(self.prob_fill_on_limit)
+    }
+
+    /// Returns whether a stop order is filled when the market rests on its price.
+    pub fn is_stop_filled(&mut self) -> bool {
+        self.event_success(self.prob_fill_on_stop)
+    }
+
+    /// Returns whether the fill price of an order slips by one tick.
+    pub fn is_slipped(&mut self) -> bool {
+        self.event_success(self.prob_slippage)
+    }
+
+    fn event_success(&mut self, probability: f64) -> bool {
+        match probability {
+            p if p <= 0.0 => false,
+            p if p >= 1.0 => true,
+            p => self.rng.gen_bool(p),
+        }
+    }
+}
+
+impl Default for FillModel {
+    /// Creates a new default `FillModel` instance, which always fills and never slips.
+    fn default() -> Self {
+        Self::new(1.0, 1.0, 0.0, Some(0)).expect("Default probabilities are valid")
+    }
+}
+
+/// Provides the latency of commands travelling from the client to the venue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LatencyModel {
+    /// The base latency (nanoseconds) added to every command.
+    pub base_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order inserts.
+    pub insert_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order updates.
+    pub update_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order cancels.
+    pub cancel_latency_nanos: u64,
+}
+
+impl LatencyModel {
+    /// Creates a new `LatencyModel` instance.
+    #[must_use]
+    pub fn new(
+        base_latency_nanos: u64,
+        insert_latency_nanos: u64,
+        update_latency_nanos: u64,
+        cancel_latency_nanos: u64,
+    ) -> Self {
+        Self {
+            base_latency_nanos,
+            insert_latency_nanos,
+            update_latency_nanos,
+            cancel_latency_nanos,
+        }
+    }
+
+    /// Returns the total latency (nanoseconds) of an order insert.
+    #[must_use]
+    pub fn insert_latency(&self) -> u64 {
+        self.base_latency_nanos + self.insert_latency_nanos
+    }
+
+    /// Returns the total latency (nanoseconds) of an order update.
+    #[must_use]
+    pub fn update_latency(&self) -> u64 {
+        self.base_latency_nanos + self.update_latency_nanos
+    }
+
+    /// Returns the total latency (nanoseconds) of an order cancel.
+    #[must_use]
+    pub fn cancel_latency(&self) -> u64 {
+        self.base_latency_nanos + self.cancel_latency_nanos
+    }
+}
+
+pub trait FeeModel {
+    /// Returns the commission for a fill of `fill_qty` at `fill_px` on the `instrument`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the commission cannot be represented as `Money`.
+    fn get_commission(
+        &self,
+        instrument: &dyn Instrument,
+        liquidity_side: LiquiditySide,
+        fill_qty: Quantity,
+        fill_px: Price,
+    ) -> anyhow::Result<Money>;
+}
+
+/// Provides a fee model which charges the maker or taker fee rate of the instrument on the
+/// notional value of each fill.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MakerTakerFeeModel;
+
+impl FeeModel for MakerTakerFeeModel {
+    fn get_commission(
+        &self,
+        instrument: &dyn Instrument,
+        liquidity_side: LiquiditySide,
+        fill_qty: Quantity,
+        fill_px: Price,
+    ) -> anyhow::Result<Money> {
+        let fee = match liquidity_side {
+            LiquiditySide::Maker => instrument.maker_fee(),
+            LiquiditySide::Taker => instrument.taker_fee(),
+            LiquiditySide::NoLiquiditySide => anyhow::bail!("Invalid liquidity side"),
+        };
+        let notional = instrument.calculate_notional_value(fill_qty, fill_px, None);
+        Money::from_decimal(notional.as_decimal() * fee, notional.currency)
+    }
+}
+
+/// Provides a fee model which charges a fixed commission on each fill.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedFeeModel {
+    /// The commission charged per fill.
+    pub commission: Money,
+}
+
+impl FixedFeeModel {
+    /// Creates a new `FixedFeeModel` instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `commission` is negative.
+    pub fn new(commission: Money) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            commission.raw >= 0,
+            "Invalid commission {commission}: must not be negative"
+        );
+        Ok(Self { commission })
+    }
+}
+
+impl FeeModel for FixedFeeModel {
+    fn get_commission(
+        &self,
+        _instrument: &dyn Instrument,
+        _liquidity_side: LiquiditySide,
+        _fill_qty: Quantity,
+        _fill_px: Price,
+    ) -> anyhow::Result<Money> {
+        Ok(self.commission)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeModelAny {
+    MakerTaker(MakerTakerFeeModel),
+    Fixed(FixedFeeModel),
+}
+
+impl FeeModel for FeeModelAny {
+    fn get_commission(
+        &self,
+        instrument: &dyn Instrument,
+        liquidity_side: LiquiditySide,
+        fill_qty: Quantity,
+        fill_px: Price,
+    ) -> anyhow::Result<Money> {
+        match self {
+            Self::MakerTaker(model) => {
+                model.get_commission(instrument, liquidity_side, fill_qty, fill_px)
+            }
+            Self::Fixed(model) => {
+                model.get_commission(instrument, liquidity_side, fill_qty, fill_px)
+            }
+        }
+    }
+}
+
+impl Default for FeeModelAny {
+    fn default() -> Self {
+        Self::MakerTaker(MakerTakerFeeModel)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{instruments::stubs::audusd_sim, types::currency::Currency};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(-0.1)]
+    #[case(1.1)]
+    #[case(f64::NAN)]
+    fn test_fill_model_invalid_probability_errors(#[case] probability: f64) {
+        assert!(FillModel::new(probability, 1.0, 0.0, None).is_err());
+        assert!(FillModel::new(1.0, probability, 0.0, None).is_err());
+        assert!(FillModel::new(1.0, 1.0, probability, None).is_err());
+    }
+
+    #[rstest]
+    fn test_fill_model_default_always_fills_without_slippage() {
+        let mut model = FillModel::default();
+
+        assert!((0..100).all(|_| model.is_limit_filled() && model.is_stop_filled()));
+        assert!((0..100).all(|_| !model.is_slipped()));
+    }
+
+    #[rstest]
+    fn test_fill_model_is_deterministic_for_seed() {
+        let mut first = FillModel::new(0.5, 0.5, 0.5, Some(42)).unwrap();
+        let mut second = FillModel::new(0.5, 0.5, 0.5, Some(42)).unwrap();
+
+        let first: Vec<bool> = (0..64).map(|_| first.is_limit_filled()).collect();
+        let second: Vec<bool> = (0..64).map(|_| second.is_limit_filled()).collect();
+
+        assert_eq!(first, second);
+        assert!(first.contains(&true) && first.contains(&false));
+    }
+
+    #[rstest]
+    fn test_latency_model_adds_base_latency() {
+        let model = LatencyModel::new(100, 10, 20, 30);
+
+        assert_eq!(model.insert_latency(), 110);
+        assert_eq!(model.update_latency(), 120);
+        assert_eq!(model.cancel_latency(), 130);
+    }
+
+    #[rstest]
+    #[case(LiquiditySide::Maker)]
+    #[case(LiquiditySide::Taker)]
+    fn test_maker_taker_fee_model_charges_fee_rate_on_notional(
+        #[case] liquidity_side: LiquiditySide,
+    ) {
+        let instrument = audusd_sim();
+        let fee = match liquidity_side {
+            LiquiditySide::Maker => instrument.maker_fee(),
+            _ => instrument.taker_fee(),
+        };
+
+        let commission = FeeModelAny::default()
+            .get_commission(
+                &instrument,
+                liquidity_side,
+                Quantity::from(100_000),
+                Price::from("1.00000"),
+            )
+            .unwrap();
+
+        assert_eq!(
+            commission,
+            Money::from_decimal(fee * Quantity::from(100_000).as_decimal(), Currency::USD())
+                .unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_fixed_fee_model_charges_commission_per_fill() {
+        let commission = Money::new(2.0, Currency::USD()).unwrap();
+        let model = FeeModelAny::Fixed(FixedFeeModel::new(commission).unwrap());
+
+        let charged = model
+            .get_commission(
+                &audusd_sim(),
+                LiquiditySide::Taker,
+                Quantity::from(1),
+                Price::from("1.00000"),
+            )
+            .unwrap();
+
+        assert_eq!(charged, commission);
+    }
+
+    #[rstest]
+    fn test_fixed_fee_model_negative_commission_errors() {
+        assert!(FixedFeeModel::new(Money::new(-1.0, Currency::USD()).unwrap()).is_err());
+    }
+}
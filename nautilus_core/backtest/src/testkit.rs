@@ -0,0 +1,842 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides an `ExecutionScenario` harness for scripting order matching engine tests.
+//!
+//! A scenario wires a test clock, cache and message bus to an `OrderMatchingEngine` configured
+//! with the chosen book, OMS and account models. Scripted market data steps are then played
+//! through the engine, orders are submitted, amended and filled through the public engine API,
+//! and every generated order event is recorded for assertion.
+//!
+//! # Determinism
+//!
+//! For the same scenario script, a run produces the same event sequence:
+//!
+//! - The clock is a static (non-realtime) `AtomicTime` which only moves when a scripted step
+//!   is played (or a command is delayed by the latency model), and it may never move backwards.
+//! - The fill model is seeded (see `FillModel::new`), so its outcomes repeat for each run.
+//! - Steps are played strictly in script order, one at a time, with no timers or threads.
+//! - Venue order IDs and trade IDs are sequential per engine (`use_random_ids` is respected,
+//!   and is off by default).
+//!
+//! Event UUIDs are generated randomly, so compare recordings with these masked (see the
+//! `determinism` module and `ExecutionScenario::record_into`).
+//!
+//! Any positions or orders the engine should see in the cache must be seeded through the
+//! builder, as the scenario only tracks the orders it submits itself.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    rc::Rc,
+};
+
+use nautilus_common::{cache::Cache, calendar::TradingCalendar, msgbus::MessageBus};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_execution::messages::{modify::ModifyOrder, submit::SubmitOrder};
+use nautilus_model::{
+    data::{close::InstrumentClose, delta::OrderBookDelta},
+    enums::{AccountType, BookType, OmsType, OrderSide, OrderStatus},
+    events::order::{event::OrderEventAny, filled::OrderFilled, submitted::OrderSubmitted},
+    identifiers::{account_id::AccountId, client_order_id::ClientOrderId, trader_id::TraderId},
+    instruments::Instrument,
+    orders::any::{LimitOrderAny, OrderAny, PassiveOrderAny, StopOrderAny},
+    polymorphism::{
+        ApplyOrderEventAny, GetClientOrderId, GetInstrumentId, GetOrderLeavesQty, GetOrderStatus,
+        GetStrategyId, GetTraderId, GetVenueOrderId,
+    },
+    position::Position,
+    types::{price::Price, quantity::Quantity},
+};
+
+use crate::{
+    connection::ConnectionScenario,
+    determinism::EventRecorder,
+    matching_engine::{OrderMatchingEngine, OrderMatchingEngineConfig},
+    models::{FeeModelAny, FillModel, LatencyModel},
+};
+
+/// Represents a scripted step played through an `ExecutionScenario`.
+#[derive(Clone, Debug)]
+pub enum ScenarioStep {
+    /// Applies the order book delta to the engine book at the delta `ts_init`.
+    Delta(OrderBookDelta),
+    /// Processes the instrument close at the close `ts_init`.
+    Close(InstrumentClose),
+    /// Advances the clock to the given UNIX timestamp (nanoseconds).
+    AdvanceTime(UnixNanos),
+}
+
+impl ScenarioStep {
+    /// Returns the UNIX timestamp (nanoseconds) the step is played at.
+    #[must_use]
+    pub fn ts(&self) -> UnixNanos {
+        match self {
+            Self::Delta(delta) => delta.ts_init,
+            Self::Close(close) => close.ts_init,
+            Self::AdvanceTime(ts) => *ts,
+        }
+    }
+}
+
+/// Builds an `ExecutionScenario` for a single instrument.
+pub struct ExecutionScenarioBuilder {
+    instrument: Box<dyn Instrument>,
+    raw_id: u32,
+    book_type: BookType,
+    oms_type: OmsType,
+    account_type: AccountType,
+    config: OrderMatchingEngineConfig,
+    calendar: Option<TradingCalendar>,
+    connection: Option<ConnectionScenario>,
+    fill_model: FillModel,
+    fee_model: FeeModelAny,
+    latency_model: Option<LatencyModel>,
+    trader_id: TraderId,
+    account_id: AccountId,
+    start_time: UnixNanos,
+    positions: Vec<Position>,
+    orders: Vec<OrderAny>,
+    steps: Vec<ScenarioStep>,
+}
+
+impl ExecutionScenarioBuilder {
+    /// Sets the order book type (default `L2_MBP`).
+    #[must_use]
+    pub fn with_book_type(mut self, book_type: BookType) -> Self {
+        self.book_type = book_type;
+        self
+    }
+
+    /// Sets the order management system type (default `Netting`).
+    #[must_use]
+    pub fn with_oms_type(mut self, oms_type: OmsType) -> Self {
+        self.oms_type = oms_type;
+        self
+    }
+
+    /// Sets the account type (default `Margin`).
+    #[must_use]
+    pub fn with_account_type(mut self, account_type: AccountType) -> Self {
+        self.account_type = account_type;
+        self
+    }
+
+    /// Sets the matching engine config.
+    #[must_use]
+    pub fn with_config(mut self, config: OrderMatchingEngineConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the trading calendar for the market sessions of the instrument.
+    #[must_use]
+    pub fn with_calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// Sets the venue connectivity outages to simulate.
+    #[must_use]
+    pub fn with_connection_scenario(mut self, connection: ConnectionScenario) -> Self {
+        self.connection = Some(connection);
+        self
+    }
+
+    /// Sets the fill model (default always fills without slippage).
+    #[must_use]
+    pub fn with_fill_model(mut self, fill_model: FillModel) -> Self {
+        self.fill_model = fill_model;
+        self
+    }
+
+    /// Sets the fee model (default `MakerTakerFeeModel`).
+    #[must_use]
+    pub fn with_fee_model(mut self, fee_model: FeeModelAny) -> Self {
+        self.fee_model = fee_model;
+        self
+    }
+
+    /// Sets the latency model delaying commands to the venue (default no latency).
+    #[must_use]
+    pub fn with_latency_model(mut self, latency_model: LatencyModel) -> Self {
+        self.latency_model = Some(latency_model);
+        self
+    }
+
+    /// Sets the account ID events are generated for (default `SIM-001`).
+    #[must_use]
+    pub fn with_account_id(mut self, account_id: AccountId) -> Self {
+        self.account_id = account_id;
+        self
+    }
+
+    /// Sets the initial clock time (default the UNIX epoch).
+    #[must_use]
+    pub fn with_start_time(mut self, start_time: UnixNanos) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    /// Seeds the cache with the open `position`, which also counts towards the net position.
+    #[must_use]
+    pub fn with_position(mut self, position: Position) -> Self {
+        self.positions.push(position);
+        self
+    }
+
+    /// Seeds the cache with the `order`.
+    #[must_use]
+    pub fn with_order(mut self, order: OrderAny) -> Self {
+        self.orders.push(order);
+        self
+    }
+
+    /// Appends the scripted `steps`.
+    #[must_use]
+    pub fn with_steps(mut self, steps: impl IntoIterator<Item = ScenarioStep>) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    /// Builds the scenario, with the clock, cache and message bus shared with the engine.
+    ///
+    /// # Errors
+    ///
+    /// If a seeded position or order cannot be added to the cache.
+    pub fn build(self) -> anyhow::Result<ExecutionScenario> {
+        let mut cache = Cache::default();
+        let mut net_qty = 0.0;
+        for position in self.positions {
+            if position.instrument_id == self.instrument.id() {
+                net_qty += position.signed_qty;
+            }
+            cache.add_position(position, self.oms_type)?;
+        }
+        for order in self.orders {
+            cache.add_order(order, None, None, false)?;
+        }
+
+        let clock = Rc::new(AtomicTime::new(false, self.start_time));
+        let msgbus = Rc::new(RefCell::new(MessageBus::new(
+            self.trader_id,
+            UUID4::new(),
+            None,
+            None,
+        )?));
+        let cache = Rc::new(RefCell::new(cache));
+
+        let mut engine = OrderMatchingEngine::new(
+            self.instrument,
+            self.raw_id,
+            self.book_type,
+            self.oms_type,
+            self.account_type,
+            clock.clone(),
+            msgbus.clone(),
+            cache.clone(),
+            self.config,
+        );
+        engine.set_fill_model(self.fill_model);
+        engine.set_fee_model(self.fee_model);
+        if let Some(calendar) = self.calendar {
+            engine.set_calendar(calendar);
+        }
+        if let Some(connection) = self.connection {
+            engine.set_connection_scenario(connection);
+        }
+
+        Ok(ExecutionScenario {
+            engine,
+            clock,
+            cache,
+            msgbus,
+            latency_model: self.latency_model,
+            account_id: self.account_id,
+            steps: self.steps.into(),
+            orders: HashMap::new(),
+            events: Vec::new(),
+            net_qty,
+        })
+    }
+}
+
+/// Provides a scripted, deterministic harness around an `OrderMatchingEngine`.
+pub struct ExecutionScenario {
+    engine: OrderMatchingEngine,
+    clock: Rc<AtomicTime>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    latency_model: Option<LatencyModel>,
+    account_id: AccountId,
+    steps: VecDeque<ScenarioStep>,
+    orders: HashMap<ClientOrderId, OrderAny>,
+    events: Vec<OrderEventAny>,
+    net_qty: f64,
+}
+
+impl ExecutionScenario {
+    /// Creates a new builder for a scenario trading the given `instrument`.
+    #[must_use]
+    pub fn builder<T: Instrument + 'static>(instrument: T) -> ExecutionScenarioBuilder {
+        ExecutionScenarioBuilder {
+            instrument: Box::new(instrument),
+            raw_id: 1,
+            book_type: BookType::L2_MBP,
+            oms_type: OmsType::Netting,
+            account_type: AccountType::Margin,
+            config: OrderMatchingEngineConfig::default(),
+            calendar: None,
+            connection: None,
+            fill_model: FillModel::default(),
+            fee_model: FeeModelAny::default(),
+            latency_model: None,
+            trader_id: TraderId::from("TRADER-001"),
+            account_id: AccountId::from("SIM-001"),
+            start_time: UnixNanos::default(),
+            positions: Vec::new(),
+            orders: Vec::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn engine(&self) -> &OrderMatchingEngine {
+        &self.engine
+    }
+
+    #[must_use]
+    pub fn engine_mut(&mut self) -> &mut OrderMatchingEngine {
+        &mut self.engine
+    }
+
+    #[must_use]
+    pub fn clock(&self) -> Rc<AtomicTime> {
+        self.clock.clone()
+    }
+
+    #[must_use]
+    pub fn cache(&self) -> Rc<RefCell<Cache>> {
+        self.cache.clone()
+    }
+
+    #[must_use]
+    pub fn msgbus(&self) -> Rc<RefCell<MessageBus>> {
+        self.msgbus.clone()
+    }
+
+    /// Returns all order events generated so far, in the order they were generated.
+    #[must_use]
+    pub fn events(&self) -> &[OrderEventAny] {
+        &self.events
+    }
+
+    /// Returns the number of scripted steps not yet played.
+    #[must_use]
+    pub fn pending_steps(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns the order tracked by the scenario for the given `client_order_id` (if found).
+    #[must_use]
+    pub fn order(&self, client_order_id: &ClientOrderId) -> Option<&OrderAny> {
+        self.orders.get(client_order_id)
+    }
+
+    /// Returns the signed net position quantity for the instrument (positive when long).
+    #[must_use]
+    pub fn net_position_qty(&self) -> f64 {
+        self.net_qty
+    }
+
+    /// Plays the next scripted step, returning the order events it generated.
+    ///
+    /// Returns `None` once all steps have been played.
+    ///
+    /// # Errors
+    ///
+    /// If the step would move the clock backwards, or the engine fails to process it.
+    pub fn step(&mut self) -> Option<anyhow::Result<Vec<OrderEventAny>>> {
+        let step = self.steps.pop_front()?;
+        Some(self.play(step))
+    }
+
+    /// Plays all remaining scripted steps, returning the order events they generated.
+    ///
+    /// # Errors
+    ///
+    /// If any step fails to play (see `step`).
+    pub fn run(&mut self) -> anyhow::Result<Vec<OrderEventAny>> {
+        let mut events = Vec::new();
+        while let Some(result) = self.step() {
+            events.extend(result?);
+        }
+        Ok(events)
+    }
+
+    /// Plays all scripted steps at or before the given UNIX timestamp (nanoseconds), then
+    /// advances the clock to it.
+    ///
+    /// # Errors
+    ///
+    /// If any step fails to play (see `step`).
+    pub fn run_until(&mut self, ts: UnixNanos) -> anyhow::Result<Vec<OrderEventAny>> {
+        let mut events = Vec::new();
+        while self.steps.front().is_some_and(|step| step.ts() <= ts) {
+            if let Some(result) = self.step() {
+                events.extend(result?);
+            }
+        }
+        events.extend(self.play(ScenarioStep::AdvanceTime(ts))?);
+        Ok(events)
+    }
+
+    fn play(&mut self, step: ScenarioStep) -> anyhow::Result<Vec<OrderEventAny>> {
+        let ts = step.ts();
+        let ts_now = self.clock.get_time_ns();
+        anyhow::ensure!(
+            ts >= ts_now,
+            "Scenario step at {ts} would move the clock backwards from {ts_now}"
+        );
+
+        let mut events = self.engine.advance_time(ts)?;
+        match step {
+            ScenarioStep::Delta(delta) => self.engine.process_order_book_delta(delta),
+            ScenarioStep::Close(close) => {
+                events.extend(self.engine.process_instrument_close(close)?);
+            }
+            ScenarioStep::AdvanceTime(_) => {}
+        }
+        for event in &events {
+            self.record(event.clone())?;
+        }
+        Ok(events)
+    }
+
+    /// Submits the `order` to the engine, returning the generated event.
+    ///
+    /// An order which has not yet been submitted is first marked as submitted by the scenario.
+    /// With a latency model, the command reaches the engine after the insert latency (see
+    /// `deliver`).
+    ///
+    /// # Errors
+    ///
+    /// If the order is not a passive order type, or the engine fails to process it.
+    pub fn submit(&mut self, order: OrderAny) -> anyhow::Result<OrderEventAny> {
+        self.submit_with_correlation(order, None)
+    }
+
+    /// Submits the `order` to the engine with the given command `correlation_id`, returning
+    /// the generated event.
+    ///
+    /// # Errors
+    ///
+    /// If the order is not a passive order type, or the engine fails to process it.
+    pub fn submit_with_correlation(
+        &mut self,
+        mut order: OrderAny,
+        correlation_id: Option<UUID4>,
+    ) -> anyhow::Result<OrderEventAny> {
+        let ts_now = self.clock.get_time_ns();
+        if order.status() == OrderStatus::Initialized {
+            let submitted = OrderSubmitted::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                self.account_id,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                correlation_id,
+            )?;
+            order.apply(OrderEventAny::Submitted(submitted))?;
+        }
+
+        let command = SubmitOrder {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            command_id: UUID4::new(),
+            correlation_id,
+            ts_init: ts_now,
+            ..Default::default()
+        };
+        let passive = passive_order(order.clone())?;
+        self.orders.insert(order.client_order_id(), order);
+
+        let latency = self.latency_model.map_or(0, |model| model.insert_latency());
+        self.deliver(latency)?;
+        let event = self
+            .engine
+            .process_submit(&command, passive, self.account_id)?;
+        self.record(event.clone())?;
+        Ok(event)
+    }
+
+    /// Amends the working order for the given `client_order_id`, returning the generated
+    /// events.
+    ///
+    /// With a latency model, the command reaches the engine after the update latency (see
+    /// `deliver`).
+    ///
+    /// # Errors
+    ///
+    /// If the engine fails to process the amendment.
+    pub fn modify(
+        &mut self,
+        client_order_id: ClientOrderId,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+    ) -> anyhow::Result<Vec<OrderEventAny>> {
+        let latency = self.latency_model.map_or(0, |model| model.update_latency());
+        self.deliver(latency)?;
+        let command = match self.orders.get(&client_order_id) {
+            Some(order) => ModifyOrder {
+                trader_id: order.trader_id(),
+                strategy_id: order.strategy_id(),
+                instrument_id: order.instrument_id(),
+                client_order_id,
+                venue_order_id: order.venue_order_id().unwrap_or_default(),
+                quantity,
+                price,
+                command_id: UUID4::new(),
+                ts_init: self.clock.get_time_ns(),
+                ..Default::default()
+            },
+            None => ModifyOrder {
+                instrument_id: self.engine.instrument.id(),
+                client_order_id,
+                quantity,
+                price,
+                command_id: UUID4::new(),
+                ts_init: self.clock.get_time_ns(),
+                ..Default::default()
+            },
+        };
+
+        let events = self.engine.process_modify(&command, self.account_id)?;
+        for event in &events {
+            self.record(event.clone())?;
+        }
+        Ok(events)
+    }
+
+    /// Fills the leaves quantity of the order for the given `client_order_id` against the
+    /// book, returning one fill event per price level consumed.
+    ///
+    /// # Errors
+    ///
+    /// If the order is not tracked by the scenario, or the engine fails to fill it.
+    pub fn fill_from_book(
+        &mut self,
+        client_order_id: ClientOrderId,
+    ) -> anyhow::Result<Vec<OrderEventAny>> {
+        let order = self
+            .orders
+            .get(&client_order_id)
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} not found in scenario"))?;
+        let mut leaves_qty = order.leaves_qty();
+        let fills = match order {
+            OrderAny::Limit(order) => self.engine.fill_from_book(order, self.account_id),
+            OrderAny::LimitIfTouched(order) => self.engine.fill_from_book(order, self.account_id),
+            OrderAny::Market(order) => self.engine.fill_from_book(order, self.account_id),
+            OrderAny::MarketIfTouched(order) => self.engine.fill_from_book(order, self.account_id),
+            OrderAny::MarketToLimit(order) => self.engine.fill_from_book(order, self.account_id),
+            OrderAny::StopLimit(order) => self.engine.fill_from_book(order, self.account_id),
+            OrderAny::StopMarket(order) => self.engine.fill_from_book(order, self.account_id),
+            OrderAny::TrailingStopLimit(order) => {
+                self.engine.fill_from_book(order, self.account_id)
+            }
+            OrderAny::TrailingStopMarket(order) => {
+                self.engine.fill_from_book(order, self.account_id)
+            }
+        }?;
+
+        let mut events = Vec::with_capacity(fills.len());
+        for fill in fills {
+            leaves_qty -= fill.last_qty;
+            let event = if leaves_qty.raw == 0 {
+                OrderEventAny::Filled(fill)
+            } else {
+                OrderEventAny::PartiallyFilled(fill)
+            };
+            self.engine.apply_order_event(event.clone(), true)?;
+            self.record(event.clone())?;
+            events.push(event);
+        }
+        Ok(events)
+    }
+
+    /// Delivers a command sent now to the venue after `latency_ns`, by playing the scripted
+    /// steps due before it arrives and advancing the clock to its arrival time.
+    fn deliver(&mut self, latency_ns: u64) -> anyhow::Result<()> {
+        if latency_ns > 0 {
+            let arrival = self.clock.get_time_ns() + latency_ns;
+            self.run_until(arrival)?;
+        }
+        Ok(())
+    }
+
+    /// Records all events generated so far into the `recorder` (for determinism checks).
+    ///
+    /// # Errors
+    ///
+    /// If an event fails to serialize.
+    pub fn record_into(&self, recorder: &mut EventRecorder) -> anyhow::Result<()> {
+        for event in &self.events {
+            recorder.record_order_event(event)?;
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, event: OrderEventAny) -> anyhow::Result<()> {
+        if let OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) = &event {
+            self.apply_fill(fill);
+        }
+        if let Some(order) = self.orders.get_mut(&event.client_order_id()) {
+            order.apply(event.clone())?;
+        }
+        self.events.push(event);
+        Ok(())
+    }
+
+    fn apply_fill(&mut self, fill: &OrderFilled) {
+        if fill.instrument_id != self.engine.instrument.id() {
+            return;
+        }
+        match fill.order_side {
+            OrderSide::Buy => self.net_qty += fill.last_qty.as_f64(),
+            OrderSide::Sell => self.net_qty -= fill.last_qty.as_f64(),
+            OrderSide::NoOrderSide => {}
+        }
+    }
+
+    // -- ASSERTIONS ----------------------------------------------------------
+
+    /// Asserts the order for the given `client_order_id` has the `expected` status.
+    ///
+    /// # Panics
+    ///
+    /// If the order is not tracked by the scenario, or its status differs.
+    #[track_caller]
+    pub fn assert_order_status(&self, client_order_id: &str, expected: OrderStatus) {
+        let client_order_id = ClientOrderId::from(client_order_id);
+        let order = self
+            .orders
+            .get(&client_order_id)
+            .unwrap_or_else(|| panic!("Order {client_order_id} not found in scenario"));
+        assert_eq!(
+            order.status(),
+            expected,
+            "Unexpected status for {client_order_id}"
+        );
+    }
+
+    /// Asserts the signed net position quantity for the instrument (positive when long).
+    ///
+    /// # Panics
+    ///
+    /// If the net position quantity differs.
+    #[track_caller]
+    pub fn assert_position_qty(&self, expected: f64) {
+        assert!(
+            (self.net_qty - expected).abs() < f64::EPSILON * expected.abs().max(1.0),
+            "Unexpected net position quantity: expected {expected}, was {}",
+            self.net_qty
+        );
+    }
+
+    /// Asserts the kinds of all events generated so far, e.g. `["Accepted", "Filled"]`.
+    ///
+    /// # Panics
+    ///
+    /// If the event kinds differ.
+    #[track_caller]
+    pub fn assert_event_sequence(&self, expected: &[&str]) {
+        let kinds: Vec<String> = self.events.iter().map(ToString::to_string).collect();
+        assert_eq!(kinds, expected, "Unexpected event sequence");
+    }
+}
+
+/// Converts the `order` into its passive form for the matching core.
+fn passive_order(order: OrderAny) -> anyhow::Result<PassiveOrderAny> {
+    Ok(match order {
+        OrderAny::Limit(order) => PassiveOrderAny::Limit(LimitOrderAny::Limit(order)),
+        OrderAny::MarketToLimit(order) => {
+            PassiveOrderAny::Limit(LimitOrderAny::MarketToLimit(order))
+        }
+        OrderAny::StopLimit(order) => PassiveOrderAny::Stop(StopOrderAny::StopLimit(order)),
+        OrderAny::StopMarket(order) => PassiveOrderAny::Stop(StopOrderAny::StopMarket(order)),
+        OrderAny::LimitIfTouched(order) => {
+            PassiveOrderAny::Stop(StopOrderAny::LimitIfTouched(order))
+        }
+        OrderAny::MarketIfTouched(order) => {
+            PassiveOrderAny::Stop(StopOrderAny::MarketIfTouched(order))
+        }
+        OrderAny::TrailingStopLimit(order) => {
+            PassiveOrderAny::Stop(StopOrderAny::TrailingStopLimit(order))
+        }
+        OrderAny::TrailingStopMarket(order) => {
+            PassiveOrderAny::Stop(StopOrderAny::TrailingStopMarket(order))
+        }
+        OrderAny::Market(order) => {
+            anyhow::bail!(
+                "Market order {} cannot rest on the book",
+                order.client_order_id
+            )
+        }
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::InstrumentCloseType, instruments::stubs::audusd_sim, orders::stubs::TestOrderStubs,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::determinism::verify;
+
+    fn close(ts: u64, close_type: InstrumentCloseType) -> ScenarioStep {
+        ScenarioStep::Close(InstrumentClose::new(
+            audusd_sim().id,
+            Price::from("1.00010"),
+            close_type,
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        ))
+    }
+
+    fn limit_order(client_order_id: &str) -> OrderAny {
+        OrderAny::Limit(TestOrderStubs::limit_order(
+            audusd_sim().id,
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            Some(ClientOrderId::from(client_order_id)),
+            None,
+        ))
+    }
+
+    #[rstest]
+    fn test_steps_advance_clock_in_script_order() {
+        let mut scenario = ExecutionScenario::builder(audusd_sim())
+            .with_steps([
+                ScenarioStep::AdvanceTime(UnixNanos::from(10)),
+                close(20, InstrumentCloseType::EndOfSession),
+                ScenarioStep::AdvanceTime(UnixNanos::from(30)),
+            ])
+            .build()
+            .unwrap();
+
+        scenario.step().unwrap().unwrap();
+        let first = scenario.clock().get_time_ns();
+        scenario.run_until(UnixNanos::from(25)).unwrap();
+        let second = scenario.clock().get_time_ns();
+
+        assert_eq!(first, UnixNanos::from(10));
+        assert_eq!(second, UnixNanos::from(25));
+        assert_eq!(scenario.pending_steps(), 1);
+    }
+
+    #[rstest]
+    fn test_step_moving_clock_backwards_errors() {
+        let mut scenario = ExecutionScenario::builder(audusd_sim())
+            .with_start_time(UnixNanos::from(100))
+            .with_steps([ScenarioStep::AdvanceTime(UnixNanos::from(50))])
+            .build()
+            .unwrap();
+
+        assert!(scenario.run().is_err());
+        assert_eq!(scenario.clock().get_time_ns(), UnixNanos::from(100));
+    }
+
+    #[rstest]
+    fn test_modify_unknown_order_records_rejection() {
+        let mut scenario = ExecutionScenario::builder(audusd_sim()).build().unwrap();
+
+        scenario
+            .modify(
+                ClientOrderId::from("O-1"),
+                Some(Quantity::from(50_000)),
+                None,
+            )
+            .unwrap();
+
+        scenario.assert_event_sequence(&["ModifyRejected"]);
+    }
+
+    #[rstest]
+    fn test_submit_market_order_errors() {
+        let mut scenario = ExecutionScenario::builder(audusd_sim()).build().unwrap();
+        let order = OrderAny::Market(TestOrderStubs::market_order(
+            audusd_sim().id,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            None,
+            None,
+        ));
+
+        assert!(scenario.submit(order).is_err());
+        assert!(scenario.events().is_empty());
+    }
+
+    #[rstest]
+    fn test_latency_model_delays_command_past_scripted_steps() {
+        let mut scenario = ExecutionScenario::builder(audusd_sim())
+            .with_latency_model(LatencyModel::new(40, 10, 0, 0))
+            .with_steps([
+                close(20, InstrumentCloseType::EndOfSession),
+                close(80, InstrumentCloseType::EndOfSession),
+            ])
+            .build()
+            .unwrap();
+
+        let event = scenario.submit(limit_order("O-1")).unwrap();
+
+        assert_eq!(event.ts_event(), UnixNanos::from(50));
+        assert_eq!(scenario.clock().get_time_ns(), UnixNanos::from(50));
+        assert_eq!(scenario.pending_steps(), 1);
+        scenario.assert_order_status("O-1", OrderStatus::Accepted);
+    }
+
+    #[rstest]
+    fn test_scenario_run_is_deterministic() {
+        let result = verify(|_seed, recorder| {
+            let mut scenario = ExecutionScenario::builder(audusd_sim())
+                .with_steps([close(1, InstrumentCloseType::EndOfSession)])
+                .build()
+                .unwrap();
+            scenario.submit(limit_order("O-1")).unwrap();
+            scenario
+                .modify(
+                    ClientOrderId::from("O-1"),
+                    Some(Quantity::from(50_000)),
+                    None,
+                )
+                .unwrap();
+            scenario.run().unwrap();
+            scenario.record_into(recorder).unwrap();
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+}
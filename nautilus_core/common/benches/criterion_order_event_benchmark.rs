@@ -0,0 +1,413 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Benchmarks the order event hot path, with an audit of heap allocations per operation.
+//!
+//! Covers constructing events via their builders and FFI constructors, JSON and MessagePack
+//! serialization, `OrderEventAny` clones, and updating orders in the cache. Allocations are
+//! counted by a global allocator for this benchmark binary, and reported before the timings.
+//!
+//! Baseline allocations per operation (construction includes the identifiers and `UUID4`):
+//!
+//! | Operation                         | Allocs/op |
+//! |-----------------------------------|-----------|
+//! | OrderFilled clone                 | 0         |
+//! | OrderEventAny clone               | 0         |
+//! | build OrderSubmitted              | 4         |
+//! | build OrderFilled                 | 12        |
+//! | FFI new OrderSubmitted            | 2         |
+//! | JSON serialize OrderSubmitted     | 6         |
+//! | JSON serialize OrderFilled        | 13        |
+//! | JSON deserialize OrderFilled      | 14        |
+//! | msgpack serialize OrderFilled     | 17        |
+//! | msgpack deserialize OrderFilled   | 14        |
+//! | Cache::update_order               | 0         |
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use nautilus_common::cache::Cache;
+use nautilus_core::{nanos::UnixNanos, serialization::SerializationFormat, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderType},
+    events::order::{
+        accepted::{OrderAccepted, OrderAcceptedBuilder},
+        canceled::{OrderCanceled, OrderCanceledBuilder},
+        event::OrderEventAny,
+        filled::{OrderFilled, OrderFilledBuilder},
+        header::peek_event_header,
+        submitted::{OrderSubmitted, OrderSubmittedBuilder},
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
+        venue_order_id::VenueOrderId,
+    },
+    orders::{
+        any::OrderAny,
+        stubs::{TestOrderEventStubs, TestOrderStubs},
+    },
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+const AUDIT_ITERATIONS: usize = 1_000;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the mean allocations per call of `f`, over the audit iterations.
+fn allocs_per_op<R>(mut f: impl FnMut() -> R) -> usize {
+    black_box(f()); // Warm up any lazily initialized state
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..AUDIT_ITERATIONS {
+        black_box(f());
+    }
+    (ALLOCATIONS.load(Ordering::Relaxed) - before) / AUDIT_ITERATIONS
+}
+
+fn trader_id() -> TraderId {
+    TraderId::from("TRADER-001")
+}
+
+fn strategy_id() -> StrategyId {
+    StrategyId::from("S-001")
+}
+
+fn instrument_id() -> InstrumentId {
+    InstrumentId::from("AUD/USD.SIM")
+}
+
+fn client_order_id() -> ClientOrderId {
+    ClientOrderId::from("O-20240101-000000-001-001-1")
+}
+
+fn venue_order_id() -> VenueOrderId {
+    VenueOrderId::from("V-001")
+}
+
+fn account_id() -> AccountId {
+    AccountId::from("SIM-001")
+}
+
+fn build_submitted() -> OrderSubmitted {
+    OrderSubmittedBuilder::default()
+        .trader_id(trader_id())
+        .strategy_id(strategy_id())
+        .instrument_id(instrument_id())
+        .client_order_id(client_order_id())
+        .account_id(account_id())
+        .event_id(UUID4::new())
+        .ts_event(UnixNanos::from(1))
+        .ts_init(UnixNanos::from(2))
+        .build()
+        .unwrap()
+}
+
+fn build_accepted() -> OrderAccepted {
+    OrderAcceptedBuilder::default()
+        .trader_id(trader_id())
+        .strategy_id(strategy_id())
+        .instrument_id(instrument_id())
+        .client_order_id(client_order_id())
+        .venue_order_id(venue_order_id())
+        .account_id(account_id())
+        .event_id(UUID4::new())
+        .ts_event(UnixNanos::from(1))
+        .ts_init(UnixNanos::from(2))
+        .build()
+        .unwrap()
+}
+
+fn build_canceled() -> OrderCanceled {
+    OrderCanceledBuilder::default()
+        .trader_id(trader_id())
+        .strategy_id(strategy_id())
+        .instrument_id(instrument_id())
+        .client_order_id(client_order_id())
+        .venue_order_id(Some(venue_order_id()))
+        .account_id(Some(account_id()))
+        .event_id(UUID4::new())
+        .ts_event(UnixNanos::from(1))
+        .ts_init(UnixNanos::from(2))
+        .build()
+        .unwrap()
+}
+
+fn build_filled() -> OrderFilled {
+    OrderFilledBuilder::default()
+        .trader_id(trader_id())
+        .strategy_id(strategy_id())
+        .instrument_id(instrument_id())
+        .client_order_id(client_order_id())
+        .venue_order_id(venue_order_id())
+        .account_id(account_id())
+        .trade_id(TradeId::from("T-001"))
+        .order_side(OrderSide::Buy)
+        .order_type(OrderType::Limit)
+        .last_qty(Quantity::from(100_000))
+        .last_px(Price::from("1.00000"))
+        .currency(Currency::USD())
+        .liquidity_side(LiquiditySide::Maker)
+        .event_id(UUID4::new())
+        .ts_event(UnixNanos::from(1))
+        .ts_init(UnixNanos::from(2))
+        .position_id(Some(PositionId::from("P-001")))
+        .commission(Some(Money::from("2.00 USD")))
+        .build()
+        .unwrap()
+}
+
+#[cfg(feature = "ffi")]
+fn ffi_submitted() -> OrderSubmitted {
+    nautilus_model::ffi::events::order::order_submitted_new(
+        trader_id(),
+        strategy_id(),
+        instrument_id(),
+        client_order_id(),
+        account_id(),
+        UUID4::new(),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
+#[cfg(feature = "ffi")]
+fn ffi_accepted() -> OrderAccepted {
+    nautilus_model::ffi::events::order::order_accepted_new(
+        trader_id(),
+        strategy_id(),
+        instrument_id(),
+        client_order_id(),
+        venue_order_id(),
+        account_id(),
+        UUID4::new(),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+        0,
+    )
+}
+
+/// Returns a cache holding an accepted limit order, and the order.
+fn cache_with_accepted_order() -> (Cache, OrderAny) {
+    let order = TestOrderStubs::limit_order(
+        instrument_id(),
+        OrderSide::Buy,
+        Price::from("1.00000"),
+        Quantity::from(100_000),
+        Some(client_order_id()),
+        None,
+    );
+    let mut cache = Cache::default();
+    cache
+        .add_order(OrderAny::Limit(order.clone()), None, None, false)
+        .unwrap();
+    let submitted = TestOrderEventStubs::order_submitted(&order, account_id()).unwrap();
+    let accepted =
+        TestOrderEventStubs::order_accepted(&order, account_id(), venue_order_id()).unwrap();
+    cache
+        .apply_order_event(OrderEventAny::Submitted(submitted))
+        .unwrap();
+    cache
+        .apply_order_event(OrderEventAny::Accepted(accepted))
+        .unwrap();
+    let order = cache.order(&client_order_id()).unwrap().clone();
+    (cache, order)
+}
+
+fn serde_allocs<T: Serialize + DeserializeOwned>(name: &str, event: &T) {
+    let json = serde_json::to_vec(event).unwrap();
+    let msgpack = rmp_serde::to_vec_named(event).unwrap();
+    println!(
+        "{:<40}{:>4}",
+        format!("JSON serialize {name}"),
+        allocs_per_op(|| serde_json::to_vec(event).unwrap())
+    );
+    println!(
+        "{:<40}{:>4}",
+        format!("JSON deserialize {name}"),
+        allocs_per_op(|| serde_json::from_slice::<T>(&json).unwrap())
+    );
+    println!(
+        "{:<40}{:>4}",
+        format!("msgpack serialize {name}"),
+        allocs_per_op(|| rmp_serde::to_vec_named(event).unwrap())
+    );
+    println!(
+        "{:<40}{:>4}",
+        format!("msgpack deserialize {name}"),
+        allocs_per_op(|| rmp_serde::from_slice::<T>(&msgpack).unwrap())
+    );
+}
+
+/// Reports allocations per operation, failing if an `OrderFilled` clone allocates.
+fn audit_allocations() {
+    let filled = build_filled();
+    let event = OrderEventAny::Filled(filled);
+
+    println!("{:<40}{:>4}", "Operation", "Allocs/op");
+    let filled_clone = allocs_per_op(|| Clone::clone(black_box(&filled)));
+    println!("{:<40}{:>4}", "OrderFilled clone", filled_clone);
+    println!(
+        "{:<40}{:>4}",
+        "OrderEventAny clone",
+        allocs_per_op(|| black_box(&event).clone())
+    );
+    println!(
+        "{:<40}{:>4}",
+        "build OrderSubmitted",
+        allocs_per_op(build_submitted)
+    );
+    println!(
+        "{:<40}{:>4}",
+        "build OrderFilled",
+        allocs_per_op(build_filled)
+    );
+    #[cfg(feature = "ffi")]
+    println!(
+        "{:<40}{:>4}",
+        "FFI new OrderSubmitted",
+        allocs_per_op(ffi_submitted)
+    );
+    serde_allocs("OrderSubmitted", &build_submitted());
+    serde_allocs("OrderAccepted", &build_accepted());
+    serde_allocs("OrderCanceled", &build_canceled());
+    serde_allocs("OrderFilled", &filled);
+
+    let (mut cache, order) = cache_with_accepted_order();
+    println!(
+        "{:<40}{:>4}",
+        "Cache::update_order",
+        allocs_per_op(|| cache.update_order(&order).unwrap())
+    );
+
+    // An `OrderFilled` clone is expected to be a plain copy
+    assert_eq!(
+        filled_clone, 0,
+        "OrderFilled clone made {filled_clone} allocations, expected none"
+    );
+}
+
+fn bench_construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("order_event_construction");
+    group.bench_function("builder_submitted", |b| b.iter(build_submitted));
+    group.bench_function("builder_accepted", |b| b.iter(build_accepted));
+    group.bench_function("builder_filled", |b| b.iter(build_filled));
+    #[cfg(feature = "ffi")]
+    {
+        group.bench_function("ffi_submitted", |b| b.iter(ffi_submitted));
+        group.bench_function("ffi_accepted", |b| b.iter(ffi_accepted));
+    }
+    group.finish();
+}
+
+fn bench_serde_event<T: Serialize + DeserializeOwned>(c: &mut Criterion, name: &str, event: &T) {
+    let json = serde_json::to_vec(event).unwrap();
+    let msgpack = rmp_serde::to_vec_named(event).unwrap();
+
+    let mut group = c.benchmark_group(format!("order_event_serde/{name}"));
+    group.bench_function("json_serialize", |b| {
+        b.iter(|| serde_json::to_vec(black_box(event)).unwrap());
+    });
+    group.bench_function("json_deserialize", |b| {
+        b.iter(|| serde_json::from_slice::<T>(black_box(&json)).unwrap());
+    });
+    group.bench_function("msgpack_serialize", |b| {
+        b.iter(|| rmp_serde::to_vec_named(black_box(event)).unwrap());
+    });
+    group.bench_function("msgpack_deserialize", |b| {
+        b.iter(|| rmp_serde::from_slice::<T>(black_box(&msgpack)).unwrap());
+    });
+    group.finish();
+}
+
+fn bench_serde(c: &mut Criterion) {
+    bench_serde_event(c, "submitted", &build_submitted());
+    bench_serde_event(c, "accepted", &build_accepted());
+    bench_serde_event(c, "canceled", &build_canceled());
+    bench_serde_event(c, "filled", &build_filled());
+}
+
+fn bench_peek_header(c: &mut Criterion) {
+    let event = OrderEventAny::Filled(build_filled());
+    let json = serde_json::to_vec(&event).unwrap();
+    let msgpack = rmp_serde::to_vec_named(&event).unwrap();
+
+    let mut group = c.benchmark_group("order_event_peek_header");
+    group.bench_function("json_full_decode", |b| {
+        b.iter(|| serde_json::from_slice::<OrderEventAny>(black_box(&json)).unwrap());
+    });
+    group.bench_function("json_peek", |b| {
+        b.iter(|| peek_event_header(black_box(&json), SerializationFormat::Json).unwrap());
+    });
+    group.bench_function("msgpack_full_decode", |b| {
+        b.iter(|| rmp_serde::from_slice::<OrderEventAny>(black_box(&msgpack)).unwrap());
+    });
+    group.bench_function("msgpack_peek", |b| {
+        b.iter(|| peek_event_header(black_box(&msgpack), SerializationFormat::MsgPack).unwrap());
+    });
+    group.finish();
+}
+
+fn bench_clone(c: &mut Criterion) {
+    let events = [
+        OrderEventAny::Submitted(build_submitted()),
+        OrderEventAny::Accepted(build_accepted()),
+        OrderEventAny::Filled(build_filled()),
+    ];
+
+    let mut group = c.benchmark_group("order_event_clone");
+    for event in &events {
+        group.bench_function(event.to_string(), |b| b.iter(|| black_box(event).clone()));
+    }
+    group.finish();
+}
+
+fn bench_cache_update_order(c: &mut Criterion) {
+    let (mut cache, order) = cache_with_accepted_order();
+    c.bench_function("cache_update_order", |b| {
+        b.iter(|| cache.update_order(black_box(&order)).unwrap());
+    });
+}
+
+fn order_event_benchmark(c: &mut Criterion) {
+    audit_allocations();
+    bench_construction(c);
+    bench_serde(c);
+    bench_peek_header(c);
+    bench_clone(c);
+    bench_cache_update_order(c);
+}
+
+criterion_group!(benches, order_event_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,93 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Benchmarks moving `Data` values from a decoding thread to an engine thread.
+//!
+//! Compares the SPSC ring buffer, draining with `pop_batch`, against the bounded channel
+//! from the standard library (which is a port of the `crossbeam-channel` implementation),
+//! at the same capacity.
+
+use std::{sync::mpsc, thread};
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use nautilus_common::spsc::spsc_ring;
+use nautilus_model::data::{stubs::quote_tick_audusd_sim, Data};
+
+const COUNT: usize = 100_000;
+const CAPACITY: usize = 1024;
+const BATCH: usize = 64;
+
+fn messages() -> Vec<Data> {
+    vec![Data::Quote(quote_tick_audusd_sim()); COUNT]
+}
+
+fn bench_data_transfer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("data_transfer");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.sample_size(20);
+
+    group.bench_function("spsc_ring_pop_batch", |b| {
+        b.iter_batched(
+            messages,
+            |messages| {
+                let (mut producer, mut consumer) = spsc_ring(CAPACITY);
+                let handle = thread::spawn(move || {
+                    for mut data in messages {
+                        while let Err(rejected) = producer.push(data) {
+                            data = rejected;
+                            thread::yield_now();
+                        }
+                    }
+                });
+                let mut buffer = Vec::with_capacity(BATCH);
+                let mut received = 0;
+                while received < COUNT {
+                    buffer.clear();
+                    match consumer.pop_batch(&mut buffer, BATCH) {
+                        0 => thread::yield_now(),
+                        count => received += count,
+                    }
+                    black_box(&buffer);
+                }
+                handle.join().unwrap();
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function("std_sync_channel", |b| {
+        b.iter_batched(
+            messages,
+            |messages| {
+                let (sender, receiver) = mpsc::sync_channel(CAPACITY);
+                let handle = thread::spawn(move || {
+                    for data in messages {
+                        sender.send(data).unwrap();
+                    }
+                });
+                for data in receiver.iter().take(COUNT) {
+                    black_box(data);
+                }
+                handle.join().unwrap();
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_data_transfer);
+criterion_main!(benches);
@@ -15,39 +15,59 @@
 
 use std::{
     collections::{HashMap, HashSet, VecDeque},
+    mem::{size_of, size_of_val},
+    sync::Arc,
     time::{SystemTime, UNIX_EPOCH},
 };
 
+use indexmap::IndexMap;
 use log::{debug, error, info, warn};
-use nautilus_core::correctness::{check_key_not_in_map, check_slice_not_empty, check_valid_string};
+use nautilus_core::{
+    correctness::{check_key_not_in_map, check_slice_not_empty, check_valid_string},
+    nanos::UnixNanos,
+    uuid::UUID4,
+};
 use nautilus_model::{
     data::{
         bar::{Bar, BarType},
+        close::InstrumentClose,
         quote::QuoteTick,
         trade::TradeTick,
     },
-    enums::{AggregationSource, OmsType, OrderSide, PositionSide, PriceType, TriggerType},
+    enums::{
+        AggregationSource, OmsType, OrderSide, PositionSide, PriceType, TimeInForce, TriggerType,
+    },
+    events::order::{event::OrderEventAny, filled::OrderFilled},
     identifiers::{
         account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
         component_id::ComponentId, exec_algorithm_id::ExecAlgorithmId, instrument_id::InstrumentId,
-        order_list_id::OrderListId, position_id::PositionId, strategy_id::StrategyId, venue::Venue,
-        venue_order_id::VenueOrderId,
+        order_list_id::OrderListId, position_id::PositionId, strategy_id::StrategyId,
+        trade_id::TradeId, venue::Venue, venue_order_id::VenueOrderId,
     },
     instruments::{any::InstrumentAny, synthetic::SyntheticInstrument},
     orderbook::book::OrderBook,
     orders::{any::OrderAny, list::OrderList},
     polymorphism::{
-        GetClientOrderId, GetEmulationTrigger, GetExecAlgorithmId, GetExecSpawnId, GetInstrumentId,
-        GetOrderFilledQty, GetOrderLeavesQty, GetOrderQuantity, GetOrderSide, GetPositionId,
-        GetStrategyId, GetVenueOrderId, IsClosed, IsInflight, IsOpen,
+        ApplyOrderEventAny, GetClientOrderId, GetEmulationTrigger, GetExecAlgorithmId,
+        GetExecSpawnId, GetInstrumentId, GetOrderEvents, GetOrderFilledQty, GetOrderLeavesQty,
+        GetOrderQuantity, GetOrderSide, GetPositionId, GetStrategyId, GetTimeInForce,
+        GetVenueOrderId, IsClosed, IsInflight, IsOpen,
     },
     position::Position,
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
+use rust_decimal::Decimal;
 use ustr::Ustr;
 
 use super::database::CacheDatabaseAdapter;
-use crate::{enums::SerializationEncoding, interface::account::Account};
+use crate::{
+    enums::{DuplicateEventPolicy, SerializationEncoding},
+    interface::account::Account,
+    messages::InstrumentUpdated,
+};
+
+/// The handler called with each `InstrumentUpdated` event (e.g. to publish on the message bus).
+pub type InstrumentUpdatedHandler = Box<dyn Fn(&InstrumentUpdated)>;
 
 /// The configuration for `Cache` instances.
 pub struct CacheConfig {
@@ -59,6 +79,7 @@ pub struct CacheConfig {
     pub drop_instruments_on_reset: bool,
     pub tick_capacity: usize,
     pub bar_capacity: usize,
+    pub duplicate_event_policy: DuplicateEventPolicy,
 }
 
 impl CacheConfig {
@@ -73,6 +94,7 @@ impl CacheConfig {
         drop_instruments_on_reset: bool,
         tick_capacity: usize,
         bar_capacity: usize,
+        duplicate_event_policy: DuplicateEventPolicy,
     ) -> Self {
         Self {
             encoding,
@@ -83,6 +105,7 @@ impl CacheConfig {
             drop_instruments_on_reset,
             tick_capacity,
             bar_capacity,
+            duplicate_event_policy,
         }
     }
 }
@@ -98,13 +121,49 @@ impl Default for CacheConfig {
             true,
             10_000,
             10_000,
+            DuplicateEventPolicy::DropAndWarn,
         )
     }
 }
 
+/// An estimate of the memory held by a `Cache`, in bytes per category.
+///
+/// Figures are derived from container capacities and type sizes, so they measure what the
+/// cache itself retains rather than exact allocator usage. Order events shared between
+/// orders (via `Arc`) are counted once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheMemoryStats {
+    pub general: usize,
+    pub quotes: usize,
+    pub trades: usize,
+    pub bars: usize,
+    pub books: usize,
+    pub instruments: usize,
+    pub orders: usize,
+    pub order_events: usize,
+    pub positions: usize,
+}
+
+impl CacheMemoryStats {
+    /// Returns the total estimated bytes across all categories.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.general
+            + self.quotes
+            + self.trades
+            + self.bars
+            + self.books
+            + self.instruments
+            + self.orders
+            + self.order_events
+            + self.positions
+    }
+}
+
 /// A key-value lookup index for a `Cache`.
 pub struct CacheIndex {
     venue_account: HashMap<Venue, AccountId>,
+    venue_accounts: HashMap<Venue, Vec<AccountId>>,
     venue_orders: HashMap<Venue, HashSet<ClientOrderId>>,
     venue_positions: HashMap<Venue, HashSet<PositionId>>,
     venue_order_ids: HashMap<VenueOrderId, ClientOrderId>,
@@ -120,6 +179,7 @@ pub struct CacheIndex {
     strategy_positions: HashMap<StrategyId, HashSet<PositionId>>,
     exec_algorithm_orders: HashMap<ExecAlgorithmId, HashSet<ClientOrderId>>,
     exec_spawn_orders: HashMap<ClientOrderId, HashSet<ClientOrderId>>,
+    correlation_orders: HashMap<UUID4, HashSet<ClientOrderId>>,
     orders: HashSet<ClientOrderId>,
     orders_open: HashSet<ClientOrderId>,
     orders_closed: HashSet<ClientOrderId>,
@@ -138,6 +198,7 @@ impl CacheIndex {
     /// Clear the index which will clear/reset all internal state.
     pub fn clear(&mut self) {
         self.venue_account.clear();
+        self.venue_accounts.clear();
         self.venue_orders.clear();
         self.venue_positions.clear();
         self.venue_order_ids.clear();
@@ -153,6 +214,7 @@ impl CacheIndex {
         self.strategy_positions.clear();
         self.exec_algorithm_orders.clear();
         self.exec_spawn_orders.clear();
+        self.correlation_orders.clear();
         self.orders.clear();
         self.orders_open.clear();
         self.orders_closed.clear();
@@ -178,14 +240,19 @@ pub struct Cache {
     trades: HashMap<InstrumentId, VecDeque<TradeTick>>,
     books: HashMap<InstrumentId, OrderBook>,
     bars: HashMap<BarType, VecDeque<Bar>>,
+    closes: HashMap<InstrumentId, InstrumentClose>,
     currencies: HashMap<Ustr, Currency>,
     instruments: HashMap<InstrumentId, InstrumentAny>,
+    instrument_versions: HashMap<InstrumentId, Vec<InstrumentAny>>,
+    instrument_updated_handler: Option<InstrumentUpdatedHandler>,
     synthetics: HashMap<InstrumentId, SyntheticInstrument>,
     accounts: HashMap<AccountId, Box<dyn Account>>,
     orders: HashMap<ClientOrderId, OrderAny>,
     order_lists: HashMap<OrderListId, OrderList>,
     positions: HashMap<PositionId, Position>,
     position_snapshots: HashMap<PositionId, Vec<u8>>,
+    quarantined_fills: IndexMap<TradeId, OrderFilled>,
+    reduce_only_strategies: HashSet<StrategyId>,
 }
 
 impl Default for Cache {
@@ -199,6 +266,7 @@ impl Cache {
     pub fn new(config: CacheConfig, database: Option<CacheDatabaseAdapter>) -> Self {
         let index = CacheIndex {
             venue_account: HashMap::new(),
+            venue_accounts: HashMap::new(),
             venue_orders: HashMap::new(),
             venue_positions: HashMap::new(),
             venue_order_ids: HashMap::new(),
@@ -214,6 +282,7 @@ impl Cache {
             strategy_positions: HashMap::new(),
             exec_algorithm_orders: HashMap::new(),
             exec_spawn_orders: HashMap::new(),
+            correlation_orders: HashMap::new(),
             orders: HashSet::new(),
             orders_open: HashSet::new(),
             orders_closed: HashSet::new(),
@@ -237,14 +306,19 @@ impl Cache {
             trades: HashMap::new(),
             books: HashMap::new(),
             bars: HashMap::new(),
+            closes: HashMap::new(),
             currencies: HashMap::new(),
             instruments: HashMap::new(),
+            instrument_versions: HashMap::new(),
+            instrument_updated_handler: None,
             synthetics: HashMap::new(),
             accounts: HashMap::new(),
             orders: HashMap::new(),
             order_lists: HashMap::new(),
             positions: HashMap::new(),
             position_snapshots: HashMap::new(),
+            quarantined_fills: IndexMap::new(),
+            reduce_only_strategies: HashSet::new(),
         }
     }
 
@@ -281,6 +355,11 @@ impl Cache {
             Some(db) => db.load_instruments()?,
             None => HashMap::new(),
         };
+        self.instrument_versions = self
+            .instruments
+            .iter()
+            .map(|(instrument_id, instrument)| (*instrument_id, vec![instrument.clone()]))
+            .collect();
 
         info!("Cached {} instruments from database", self.general.len());
         Ok(())
@@ -339,14 +418,25 @@ impl Cache {
 
     /// Clear the current cache index and re-build.
     pub fn build_index(&mut self) {
+        // Keep the order accounts were added in, so each venue keeps its default account
+        let indexed_account_ids: Vec<AccountId> = std::mem::take(&mut self.index.venue_accounts)
+            .into_values()
+            .flatten()
+            .filter(|account_id| self.accounts.contains_key(account_id))
+            .collect();
         self.index.clear();
         debug!("Building index");
 
-        // Index accounts
-        for account_id in self.accounts.keys() {
-            self.index
-                .venue_account
-                .insert(account_id.get_issuer(), *account_id);
+        // Index accounts, then any not yet indexed (sorted so the venue default is deterministic)
+        let mut new_account_ids: Vec<AccountId> = self
+            .accounts
+            .keys()
+            .filter(|account_id| !indexed_account_ids.contains(account_id))
+            .copied()
+            .collect();
+        new_account_ids.sort();
+        for account_id in indexed_account_ids.into_iter().chain(new_account_ids) {
+            self.index_account(account_id);
         }
 
         // Index orders
@@ -918,13 +1008,17 @@ impl Cache {
         self.trades.clear();
         self.books.clear();
         self.bars.clear();
+        self.closes.clear();
         self.instruments.clear();
+        self.instrument_versions.clear();
         self.synthetics.clear();
         self.accounts.clear();
         self.orders.clear();
         // self.order_lists.clear();  // TODO
         self.positions.clear();
         self.position_snapshots.clear();
+        self.quarantined_fills.clear();
+        self.reduce_only_strategies.clear();
 
         self.clear_index();
 
@@ -1057,6 +1151,21 @@ impl Cache {
         Ok(())
     }
 
+    /// Add the given instrument `close` to the cache.
+    ///
+    /// Only the latest close per instrument is kept, so a close with an earlier `ts_event` than
+    /// the cached close is ignored.
+    pub fn add_instrument_close(&mut self, close: InstrumentClose) -> anyhow::Result<()> {
+        debug!("Adding `InstrumentClose` {}", close.instrument_id);
+        match self.closes.get(&close.instrument_id) {
+            Some(existing) if existing.ts_event > close.ts_event => {}
+            _ => {
+                self.closes.insert(close.instrument_id, close);
+            }
+        }
+        Ok(())
+    }
+
     /// Add the given `currency` to the cache.
     pub fn add_currency(&mut self, currency: Currency) -> anyhow::Result<()> {
         debug!("Adding `Currency` {}", currency.code);
@@ -1070,14 +1179,129 @@ impl Cache {
     }
 
     /// Add the given `instrument` to the cache.
+    ///
+    /// Instruments are versioned by `ts_init`, with a version replacing any existing version
+    /// with the same `ts_init`. The latest version is returned from `instrument`, and earlier
+    /// versions remain available from `instrument_as_of`.
     pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
-        debug!("Adding `Instrument` {}", instrument.id());
+        let instrument_id = instrument.id();
+        debug!("Adding `Instrument` {instrument_id}");
 
         if let Some(database) = &self.database {
             database.add_instrument(&instrument)?;
         }
 
-        self.instruments.insert(instrument.id(), instrument);
+        let versions = self.instrument_versions.entry(instrument_id).or_default();
+        // Diff against the version in effect at `ts_init`, which is not the latest version
+        // when versions are added out of order
+        if let Some(effective) = effective_version(versions, instrument.ts_init()) {
+            for change in InstrumentAny::diff(effective, &instrument) {
+                info!(
+                    "Updated {instrument_id} {}: {} -> {}",
+                    change.field, change.old, change.new
+                );
+            }
+        }
+        match versions.binary_search_by_key(&instrument.ts_init(), InstrumentAny::ts_init) {
+            Ok(index) => versions[index] = instrument,
+            Err(index) => versions.insert(index, instrument),
+        }
+
+        // SAFETY: At least one version was inserted above
+        let latest = versions.last().unwrap().clone();
+        self.instruments.insert(instrument_id, latest);
+        Ok(())
+    }
+
+    /// Add a new version of the instrument with the given fee rates, initialized at `ts_init`.
+    ///
+    /// The new version replaces the latest instrument, and an `InstrumentUpdated` event is passed
+    /// to the handler if the fees changed.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the instrument is not found in the cache.
+    /// - If `ts_init` is earlier than the latest version of the instrument.
+    /// - If the instrument type does not apply fee rates.
+    pub fn update_instrument_fees(
+        &mut self,
+        instrument_id: &InstrumentId,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let instrument = self.latest_instrument_for_update(instrument_id, ts_init)?;
+        let updated = instrument.with_fees(maker_fee, taker_fee, ts_init)?;
+        self.update_instrument(&instrument, updated)
+    }
+
+    /// Add a new version of the instrument with the given margin rates, initialized at `ts_init`.
+    ///
+    /// The new version replaces the latest instrument, and an `InstrumentUpdated` event is passed
+    /// to the handler if the margins changed.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the instrument is not found in the cache.
+    /// - If `ts_init` is earlier than the latest version of the instrument.
+    pub fn update_margins(
+        &mut self,
+        instrument_id: &InstrumentId,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let instrument = self.latest_instrument_for_update(instrument_id, ts_init)?;
+        let updated = instrument.with_margins(margin_init, margin_maint, ts_init);
+        self.update_instrument(&instrument, updated)
+    }
+
+    /// Sets the handler called with each `InstrumentUpdated` event.
+    pub fn set_instrument_updated_handler(&mut self, handler: InstrumentUpdatedHandler) {
+        self.instrument_updated_handler = Some(handler);
+    }
+
+    fn latest_instrument_for_update(
+        &self,
+        instrument_id: &InstrumentId,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<InstrumentAny> {
+        let Some(instrument) = self.instruments.get(instrument_id) else {
+            anyhow::bail!("Instrument {instrument_id} not found in cache");
+        };
+        if ts_init < instrument.ts_init() {
+            anyhow::bail!(
+                "Cannot update {instrument_id} at {ts_init}: before latest version at {}",
+                instrument.ts_init()
+            );
+        }
+        Ok(instrument.clone())
+    }
+
+    fn update_instrument(
+        &mut self,
+        previous: &InstrumentAny,
+        instrument: InstrumentAny,
+    ) -> anyhow::Result<()> {
+        let instrument_id = instrument.id();
+        let ts_init = instrument.ts_init();
+        let changes = InstrumentAny::diff(previous, &instrument);
+        self.add_instrument(instrument)?;
+
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let event = InstrumentUpdated::new(
+            instrument_id,
+            changes,
+            self.instrument_versions(&instrument_id).len(),
+            ts_init,
+        );
+        if let Some(handler) = &self.instrument_updated_handler {
+            handler(&event);
+        }
         Ok(())
     }
 
@@ -1094,6 +1318,8 @@ impl Cache {
     }
 
     /// Add the given `account` to the cache.
+    ///
+    /// A venue may have several accounts, the first account added for a venue is its default.
     pub fn add_account(&mut self, account: Box<dyn Account>) -> anyhow::Result<()> {
         debug!("Adding `Account` {}", account.id());
 
@@ -1101,10 +1327,21 @@ impl Cache {
             database.add_account(account.as_ref())?;
         }
 
-        self.accounts.insert(account.id(), account);
+        let account_id = account.id();
+        self.index_account(account_id);
+        self.accounts.insert(account_id, account);
         Ok(())
     }
 
+    fn index_account(&mut self, account_id: AccountId) {
+        let venue = account_id.get_issuer();
+        self.index.venue_account.entry(venue).or_insert(account_id);
+        let venue_accounts = self.index.venue_accounts.entry(venue).or_default();
+        if !venue_accounts.contains(&account_id) {
+            venue_accounts.push(account_id);
+        }
+    }
+
     /// Index the given client order ID with the given venue order ID.
     pub fn add_venue_order_id(
         &mut self,
@@ -1360,6 +1597,43 @@ impl Cache {
         Ok(())
     }
 
+    /// Apply the given `event` to its cached order, then update the cache indexes.
+    ///
+    /// Events duplicating one already applied to the order (see
+    /// [`OrderAny::is_duplicate_event`]) are handled according to the configured
+    /// `duplicate_event_policy`. Returns whether the event was applied.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the order is not found in the cache.
+    /// - If the event is a duplicate and the policy is `Error`.
+    /// - If the event is not a valid transition for the order.
+    pub fn apply_order_event(&mut self, event: OrderEventAny) -> anyhow::Result<bool> {
+        let client_order_id = event.client_order_id();
+        let Some(order) = self.orders.get_mut(&client_order_id) else {
+            anyhow::bail!("Order {client_order_id} not found in cache");
+        };
+
+        if order.is_duplicate_event(&event) {
+            match self.config.duplicate_event_policy {
+                DuplicateEventPolicy::Drop => {}
+                DuplicateEventPolicy::DropAndWarn => {
+                    warn!("Dropping duplicate event for {client_order_id}: {event}");
+                }
+                DuplicateEventPolicy::Error => {
+                    anyhow::bail!("Duplicate event for {client_order_id}: {event}");
+                }
+            }
+            return Ok(false);
+        }
+
+        order.apply(event)?;
+        let order = order.clone();
+        self.update_order(&order)?;
+        Ok(true)
+    }
+
     /// Update the given `order` in the cache.
     pub fn update_order(&mut self, order: &OrderAny) -> anyhow::Result<()> {
         let client_order_id = order.client_order_id();
@@ -1391,6 +1665,19 @@ impl Cache {
             self.index.orders_closed.insert(client_order_id);
         }
 
+        // Update correlation
+        for correlation_id in order
+            .order_events()
+            .iter()
+            .filter_map(|event| event.correlation_id())
+        {
+            self.index
+                .correlation_orders
+                .entry(correlation_id)
+                .or_default()
+                .insert(client_order_id);
+        }
+
         // Update emulation
         if let Some(emulation_trigger) = order.emulation_trigger() {
             match emulation_trigger {
@@ -1419,6 +1706,8 @@ impl Cache {
 
     /// Update the given `position` in the cache.
     pub fn update_position(&mut self, position: &Position) -> anyhow::Result<()> {
+        self.positions.insert(position.id, position.clone());
+
         // Update open/closed state
         if position.is_open() {
             self.index.positions_open.insert(position.id);
@@ -1438,6 +1727,44 @@ impl Cache {
         Ok(())
     }
 
+    /// Quarantine the given `fill` which will be held apart from order, position and account
+    /// state until released.
+    ///
+    /// # Errors
+    ///
+    /// If a fill with the same `trade_id` is already quarantined.
+    pub fn quarantine_fill(&mut self, fill: OrderFilled) -> anyhow::Result<()> {
+        if self.quarantined_fills.contains_key(&fill.trade_id) {
+            anyhow::bail!("Fill with trade ID {} already quarantined", fill.trade_id);
+        }
+
+        warn!("Quarantining {fill}");
+        self.quarantined_fills.insert(fill.trade_id, fill);
+        Ok(())
+    }
+
+    /// Release the quarantined fill for the given `trade_id`, returning it to be applied by the
+    /// caller.
+    pub fn release_quarantined_fill(&mut self, trade_id: &TradeId) -> Option<OrderFilled> {
+        let fill = self.quarantined_fills.shift_remove(trade_id);
+        if let Some(fill) = &fill {
+            info!("Released {fill}");
+        }
+        fill
+    }
+
+    /// Set whether the given `strategy_id` is restricted to orders which reduce its positions.
+    pub fn set_strategy_reduce_only(&mut self, strategy_id: StrategyId, reduce_only: bool) {
+        let changed = if reduce_only {
+            self.reduce_only_strategies.insert(strategy_id)
+        } else {
+            self.reduce_only_strategies.remove(&strategy_id)
+        };
+        if changed {
+            info!("Set {strategy_id} reduce-only={reduce_only}");
+        }
+    }
+
     // -- IDENTIFIER QUERIES ----------------------------------------------------------------------
 
     fn build_order_query_filter_set(
@@ -1741,6 +2068,12 @@ impl Cache {
         self.index.actors.clone()
     }
 
+    /// Returns whether the given `strategy_id` is restricted to orders which reduce its positions.
+    #[must_use]
+    pub fn is_strategy_reduce_only(&self, strategy_id: &StrategyId) -> bool {
+        self.reduce_only_strategies.contains(strategy_id)
+    }
+
     #[must_use]
     pub fn strategy_ids(&self) -> HashSet<StrategyId> {
         self.index.strategies.clone()
@@ -1751,6 +2084,74 @@ impl Cache {
         self.index.exec_algorithms.clone()
     }
 
+    // -- MEMORY ----------------------------------------------------------------------------------
+
+    /// Returns an estimate of the memory currently held by the cache per category.
+    #[must_use]
+    pub fn memory_usage(&self) -> CacheMemoryStats {
+        let general = self
+            .general
+            .iter()
+            .map(|(key, value)| key.capacity() + value.capacity())
+            .sum();
+        let quotes = self
+            .quotes
+            .values()
+            .map(|deque| deque.capacity() * size_of::<QuoteTick>())
+            .sum();
+        let trades = self
+            .trades
+            .values()
+            .map(|deque| deque.capacity() * size_of::<TradeTick>())
+            .sum();
+        let bars = self
+            .bars
+            .values()
+            .map(|deque| deque.capacity() * size_of::<Bar>())
+            .sum();
+        let books = self.books.len() * size_of::<OrderBook>();
+        let instrument_versions: usize = self.instrument_versions.values().map(Vec::len).sum();
+        let instruments = (self.instruments.len() + instrument_versions)
+            * size_of::<InstrumentAny>()
+            + self.synthetics.len() * size_of::<SyntheticInstrument>();
+
+        let mut orders = 0;
+        let mut order_events = 0;
+        let mut seen_events: HashSet<*const OrderEventAny> = HashSet::new();
+        for order in self.orders.values() {
+            let events = order.order_events();
+            orders += size_of::<OrderAny>() + size_of_val(events);
+            for event in events {
+                if seen_events.insert(Arc::as_ptr(event)) {
+                    // Arc allocation holds the strong and weak counts alongside the event
+                    order_events += size_of::<OrderEventAny>() + 2 * size_of::<usize>();
+                }
+            }
+        }
+
+        let positions = self
+            .positions
+            .values()
+            .map(|position| {
+                size_of::<Position>()
+                    + position.events.capacity() * size_of::<OrderFilled>()
+                    + position.trade_ids.capacity() * size_of::<TradeId>()
+            })
+            .sum();
+
+        CacheMemoryStats {
+            general,
+            quotes,
+            trades,
+            bars,
+            books,
+            instruments,
+            orders,
+            order_events,
+            positions,
+        }
+    }
+
     // -- ORDER QUERIES ---------------------------------------------------------------------------
 
     #[must_use]
@@ -1758,6 +2159,55 @@ impl Cache {
         self.orders.get(client_order_id)
     }
 
+    /// Returns shared references to the events for the order with the given `client_order_id`.
+    ///
+    /// The returned `Arc` clones point at the same events held by the cached order,
+    /// so no event data is copied.
+    #[must_use]
+    pub fn order_events(&self, client_order_id: &ClientOrderId) -> Vec<Arc<OrderEventAny>> {
+        self.orders
+            .get(client_order_id)
+            .map(|order| order.order_events().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Returns the order events carrying the given `correlation_id`, ordered by `ts_event`.
+    #[must_use]
+    pub fn events_for_correlation(&self, correlation_id: &UUID4) -> Vec<Arc<OrderEventAny>> {
+        let Some(client_order_ids) = self.index.correlation_orders.get(correlation_id) else {
+            return Vec::new();
+        };
+
+        let mut client_order_ids: Vec<&ClientOrderId> = client_order_ids.iter().collect();
+        client_order_ids.sort();
+        let mut events: Vec<Arc<OrderEventAny>> = client_order_ids
+            .into_iter()
+            .filter_map(|client_order_id| self.orders.get(client_order_id))
+            .flat_map(|order| order.order_events().iter())
+            .filter(|event| event.correlation_id() == Some(*correlation_id))
+            .cloned()
+            .collect();
+        events.sort_by_key(|event| event.ts_event());
+        events
+    }
+
+    #[must_use]
+    pub fn quarantined_fill(&self, trade_id: &TradeId) -> Option<&OrderFilled> {
+        self.quarantined_fills.get(trade_id)
+    }
+
+    /// Returns the quarantined fills in the order they were received, optionally filtered
+    /// for the given `client_order_id`.
+    #[must_use]
+    pub fn quarantined_fills(&self, client_order_id: Option<&ClientOrderId>) -> Vec<&OrderFilled> {
+        self.quarantined_fills
+            .values()
+            .filter(|fill| {
+                client_order_id.is_none() || client_order_id == Some(&fill.client_order_id)
+            })
+            .collect()
+    }
+
     #[must_use]
     pub fn client_order_id(&self, venue_order_id: &VenueOrderId) -> Option<&ClientOrderId> {
         self.index.venue_order_ids.get(venue_order_id)
@@ -1844,6 +2294,21 @@ impl Cache {
         }
     }
 
+    /// Returns the client order IDs of all open `DAY` orders, optionally filtered by `venue`.
+    ///
+    /// The IDs are sorted so that end-of-day expiry is processed in a deterministic order.
+    #[must_use]
+    pub fn day_orders_open(&self, venue: Option<&Venue>) -> Vec<ClientOrderId> {
+        let mut client_order_ids: Vec<ClientOrderId> = self
+            .orders_open(venue, None, None, None)
+            .into_iter()
+            .filter(|order| order.time_in_force() == TimeInForce::Day)
+            .map(GetClientOrderId::client_order_id)
+            .collect();
+        client_order_ids.sort();
+        client_order_ids
+    }
+
     #[must_use]
     pub fn order_exists(&self, client_order_id: &ClientOrderId) -> bool {
         self.index.orders.contains(client_order_id)
@@ -2275,6 +2740,12 @@ impl Cache {
         self.bars.get(bar_type).and_then(|bars| bars.front())
     }
 
+    /// Returns the latest close for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn instrument_close(&self, instrument_id: &InstrumentId) -> Option<&InstrumentClose> {
+        self.closes.get(instrument_id)
+    }
+
     #[must_use]
     pub fn book_update_count(&self, instrument_id: &InstrumentId) -> u64 {
         self.books.get(instrument_id).map_or(0, |book| book.count)
@@ -2328,6 +2799,25 @@ impl Cache {
         self.instruments.get(instrument_id)
     }
 
+    /// Returns the version of the instrument effective at `ts`, being the latest version with a
+    /// `ts_init` at or before `ts`.
+    #[must_use]
+    pub fn instrument_as_of(
+        &self,
+        instrument_id: &InstrumentId,
+        ts: UnixNanos,
+    ) -> Option<&InstrumentAny> {
+        effective_version(self.instrument_versions.get(instrument_id)?, ts)
+    }
+
+    /// Returns all versions of the instrument in ascending `ts_init` order.
+    #[must_use]
+    pub fn instrument_versions(&self, instrument_id: &InstrumentId) -> &[InstrumentAny] {
+        self.instrument_versions
+            .get(instrument_id)
+            .map_or(&[], Vec::as_slice)
+    }
+
     #[must_use]
     pub fn instrument_ids(&self, venue: &Venue) -> Vec<&InstrumentId> {
         self.instruments
@@ -2394,6 +2884,7 @@ impl Cache {
             .map(std::convert::AsRef::as_ref)
     }
 
+    /// Returns the default account for the given `venue` (the first account added for it).
     #[must_use]
     pub fn account_for_venue(&self, venue: &Venue) -> Option<&dyn Account> {
         self.index
@@ -2403,13 +2894,30 @@ impl Cache {
             .map(std::convert::AsRef::as_ref)
     }
 
+    /// Returns all accounts for the given `venue`, in the order they were added.
+    #[must_use]
+    pub fn accounts_for_venue(&self, venue: &Venue) -> Vec<&dyn Account> {
+        self.index
+            .venue_accounts
+            .get(venue)
+            .map(|account_ids| {
+                account_ids
+                    .iter()
+                    .filter_map(|account_id| self.accounts.get(account_id))
+                    .map(std::convert::AsRef::as_ref)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the default account ID for the given `venue`.
     #[must_use]
     pub fn account_id(&self, venue: &Venue) -> Option<&AccountId> {
         self.index.venue_account.get(venue)
     }
 
     #[must_use]
-    pub fn accounts(&self, account_id: &AccountId) -> Vec<&dyn Account> {
+    pub fn accounts(&self) -> Vec<&dyn Account> {
         self.accounts
             .values()
             .map(std::convert::AsRef::as_ref)
@@ -2417,34 +2925,57 @@ impl Cache {
     }
 }
 
+/// Returns the latest of the `versions` (in ascending `ts_init` order) with a `ts_init` at or
+/// before `ts`.
+fn effective_version(versions: &[InstrumentAny], ts: UnixNanos) -> Option<&InstrumentAny> {
+    let index = versions.partition_point(|instrument| instrument.ts_init() <= ts);
+    index.checked_sub(1).map(|index| &versions[index])
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::{cell::RefCell, rc::Rc, sync::Arc};
+
     use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
     use nautilus_model::{
-        data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
-        enums::OrderSide,
-        events::order::{accepted::OrderAccepted, event::OrderEventAny, submitted::OrderSubmitted},
+        data::{
+            bar::Bar, close::InstrumentClose, quote::QuoteTick, stubs::stub_instrument_close,
+            trade::TradeTick,
+        },
+        enums::{OmsType, OrderSide},
+        events::order::{
+            accepted::OrderAccepted, canceled::OrderCanceled, event::OrderEventAny,
+            modify_rejected::OrderModifyRejected, submitted::OrderSubmitted,
+        },
         identifiers::{
             account_id::AccountId, client_order_id::ClientOrderId, position_id::PositionId,
-            venue_order_id::VenueOrderId,
+            trade_id::TradeId, venue::Venue, venue_order_id::VenueOrderId,
         },
         instruments::{
             any::InstrumentAny, currency_pair::CurrencyPair, stubs::*,
             synthetic::SyntheticInstrument,
         },
-        orders::{any::OrderAny, stubs::TestOrderStubs},
+        orders::{
+            any::OrderAny,
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+        },
         polymorphism::{
-            ApplyOrderEventAny, GetAccountId, GetClientOrderId, GetInstrumentId, GetStrategyId,
-            GetTraderId, GetVenueOrderId, IsOpen,
+            ApplyOrderEventAny, GetAccountId, GetClientOrderId, GetInstrumentId, GetOrderEvents,
+            GetOrderFilledQty, GetStrategyId, GetTraderId, GetVenueOrderId, IsClosed, IsOpen,
         },
+        position::Position,
         types::{price::Price, quantity::Quantity},
     };
     use rstest::*;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use ustr::Ustr;
 
-    use super::Cache;
+    use super::{Cache, CacheConfig};
+    use crate::{enums::DuplicateEventPolicy, messages::InstrumentUpdated, stubs::StubAccount};
 
     #[fixture]
     fn cache() -> Cache {
@@ -2592,6 +3123,7 @@ mod tests {
             UUID4::new(),
             UnixNanos::default(),
             UnixNanos::default(),
+            None,
         )
         .unwrap(); // TODO: Should event generation be fallible?
         order.apply(OrderEventAny::Submitted(submitted)).unwrap();
@@ -2641,6 +3173,7 @@ mod tests {
             UUID4::new(),
             UnixNanos::default(),
             UnixNanos::default(),
+            None,
         )
         .unwrap(); // TODO: Should event generation be fallible?
         order.apply(OrderEventAny::Submitted(submitted)).unwrap();
@@ -2657,6 +3190,7 @@ mod tests {
             UnixNanos::default(),
             UnixNanos::default(),
             false,
+            None,
         )
         .unwrap();
         order.apply(OrderEventAny::Accepted(accepted)).unwrap();
@@ -2712,6 +3246,165 @@ mod tests {
         assert_eq!(cache.orders_for_position(&position_id), vec![&order]);
     }
 
+    #[rstest]
+    fn test_update_position_stores_updated_position(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let order = TestOrderStubs::market_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let mut position = Position::new(audusd_sim, fill).unwrap();
+        cache
+            .add_position(position.clone(), OmsType::Netting)
+            .unwrap();
+
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            Some(TradeId::from("E-2")),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        position.apply(&fill);
+        cache.update_position(&position).unwrap();
+
+        let result = cache.position(&position.id).unwrap();
+        assert_eq!(result.quantity, Quantity::from(200_000));
+        assert!(cache.is_position_open(&position.id));
+    }
+
+    #[rstest]
+    fn test_order_events_when_order_not_found(cache: Cache) {
+        assert!(cache.order_events(&ClientOrderId::default()).is_empty());
+    }
+
+    #[rstest]
+    fn test_order_events_are_shared_with_order_clones(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+        let mut order = OrderAny::Limit(order);
+        let submitted = OrderSubmitted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            AccountId::default(),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        )
+        .unwrap();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        cache.add_order(order.clone(), None, None, false).unwrap();
+
+        let events = cache.order_events(&order.client_order_id());
+        let strategy_copy = cache.order(&order.client_order_id()).unwrap().clone();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events.as_slice(), order.order_events());
+        for (event, copied) in events.iter().zip(strategy_copy.order_events()) {
+            assert!(Arc::ptr_eq(event, copied));
+        }
+    }
+
+    #[rstest]
+    fn test_memory_usage_when_empty(cache: Cache) {
+        let stats = cache.memory_usage();
+        assert_eq!(stats.total(), 0);
+    }
+
+    #[rstest]
+    fn test_memory_usage_one_million_fills_within_budget(
+        mut cache: Cache,
+        audusd_sim: CurrencyPair,
+    ) {
+        // Budget per cached event: the `OrderEventAny` itself plus `Arc` counts and the
+        // pointer held in the order's event list. Exceeding this indicates events are
+        // being duplicated (or the event enum has grown) and should be investigated.
+        const FILL_COUNT: i64 = 1_000_000;
+        const BYTES_PER_EVENT_BUDGET: usize = 600;
+
+        let mut order = TestOrderStubs::market_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Quantity::from(FILL_COUNT),
+            None,
+            None,
+        );
+        let account_id = AccountId::default();
+        let submitted = TestOrderEventStubs::order_submitted(&order, account_id).unwrap();
+        let accepted =
+            TestOrderEventStubs::order_accepted(&order, account_id, VenueOrderId::default())
+                .unwrap();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            None,
+            None,
+            None,
+            Some(Quantity::from(1)),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        for _ in 1..FILL_COUNT {
+            order.apply(OrderEventAny::PartiallyFilled(fill)).unwrap();
+        }
+        order.apply(OrderEventAny::Filled(fill)).unwrap();
+
+        let order = OrderAny::Market(order);
+        let client_order_id = order.client_order_id();
+        cache.add_order(order, None, None, false).unwrap();
+
+        // Holding strategy-side copies must not grow the event footprint
+        let strategy_copy = cache.order(&client_order_id).unwrap().clone();
+        let shared_events = cache.order_events(&client_order_id);
+
+        let stats = cache.memory_usage();
+        let event_count = strategy_copy.order_events().len();
+        assert_eq!(event_count as i64, FILL_COUNT + 3);
+        assert_eq!(shared_events.len(), event_count);
+        assert!(
+            stats.order_events + stats.orders <= event_count * BYTES_PER_EVENT_BUDGET,
+            "order event memory {} bytes exceeded budget of {} bytes",
+            stats.order_events + stats.orders,
+            event_count * BYTES_PER_EVENT_BUDGET,
+        );
+    }
+
     #[rstest]
     fn test_instrument_when_empty(cache: Cache, audusd_sim: CurrencyPair) {
         let result = cache.instrument(&audusd_sim.id);
@@ -2731,6 +3424,195 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_instrument_versions_with_tick_size_change(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let updated = CurrencyPair {
+            price_precision: 4,
+            price_increment: Price::from("0.0001"),
+            ts_event: UnixNanos::from(1_000),
+            ts_init: UnixNanos::from(1_000),
+            ..audusd_sim
+        };
+        // Add out of order to check versions are ordered by `ts_init`
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(updated))
+            .unwrap();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+
+        let before = cache
+            .instrument_as_of(&audusd_sim.id, UnixNanos::from(999))
+            .unwrap();
+        let after = cache
+            .instrument_as_of(&audusd_sim.id, UnixNanos::from(1_000))
+            .unwrap();
+        let latest = cache.instrument(&audusd_sim.id).unwrap();
+
+        assert_eq!(cache.instrument_versions(&audusd_sim.id).len(), 2);
+        assert_eq!(
+            before.make_price(1.234_567).unwrap(),
+            Price::from("1.23457")
+        );
+        assert_eq!(after.make_price(1.234_567).unwrap(), Price::from("1.2346"));
+        assert_eq!(latest.price_increment(), Price::from("0.0001"));
+    }
+
+    #[rstest]
+    fn test_instrument_as_of_with_version_inserted_between(
+        mut cache: Cache,
+        audusd_sim: CurrencyPair,
+    ) {
+        let version = |ts_init: u64, price_precision: u8, price_increment: &str| {
+            InstrumentAny::CurrencyPair(CurrencyPair {
+                price_precision,
+                price_increment: Price::from(price_increment),
+                ts_event: UnixNanos::from(ts_init),
+                ts_init: UnixNanos::from(ts_init),
+                ..audusd_sim
+            })
+        };
+        cache.add_instrument(version(0, 5, "0.00001")).unwrap();
+        cache.add_instrument(version(2_000, 3, "0.001")).unwrap();
+        cache.add_instrument(version(1_000, 4, "0.0001")).unwrap();
+
+        let increments: Vec<Price> = [0, 999, 1_000, 1_999, 2_000]
+            .into_iter()
+            .map(|ts| {
+                cache
+                    .instrument_as_of(&audusd_sim.id, UnixNanos::from(ts))
+                    .unwrap()
+                    .price_increment()
+            })
+            .collect();
+
+        assert_eq!(
+            increments,
+            vec![
+                Price::from("0.00001"),
+                Price::from("0.00001"),
+                Price::from("0.0001"),
+                Price::from("0.0001"),
+                Price::from("0.001"),
+            ]
+        );
+        assert_eq!(
+            cache.instrument(&audusd_sim.id).unwrap().price_increment(),
+            Price::from("0.001")
+        );
+    }
+
+    #[rstest]
+    fn test_instrument_as_of_before_first_version(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let instrument = CurrencyPair {
+            ts_init: UnixNanos::from(1_000),
+            ..audusd_sim
+        };
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(instrument))
+            .unwrap();
+
+        assert!(cache
+            .instrument_as_of(&audusd_sim.id, UnixNanos::from(999))
+            .is_none());
+    }
+
+    #[rstest]
+    fn test_account_when_empty(cache: Cache) {
+        let venue = Venue::from("BINANCE");
+
+        assert!(cache.account(&AccountId::from("BINANCE-001")).is_none());
+        assert!(cache.account_for_venue(&venue).is_none());
+        assert!(cache.accounts_for_venue(&venue).is_empty());
+    }
+
+    #[rstest]
+    fn test_multiple_accounts_per_venue(mut cache: Cache) {
+        let venue = Venue::from("BINANCE");
+        cache
+            .add_account(StubAccount::boxed("BINANCE-002"))
+            .unwrap();
+        cache
+            .add_account(StubAccount::boxed("BINANCE-001"))
+            .unwrap();
+        cache.add_account(StubAccount::boxed("SIM-001")).unwrap();
+
+        let accounts: Vec<AccountId> = cache
+            .accounts_for_venue(&venue)
+            .iter()
+            .map(|account| account.id())
+            .collect();
+
+        assert_eq!(
+            accounts,
+            vec![
+                AccountId::from("BINANCE-002"),
+                AccountId::from("BINANCE-001")
+            ]
+        );
+        assert_eq!(
+            cache.account_for_venue(&venue).unwrap().id(),
+            AccountId::from("BINANCE-002")
+        );
+        assert_eq!(
+            cache.account(&AccountId::from("BINANCE-001")).unwrap().id(),
+            AccountId::from("BINANCE-001")
+        );
+        assert_eq!(cache.accounts_for_venue(&Venue::from("SIM")).len(), 1);
+        assert_eq!(cache.accounts().len(), 3);
+    }
+
+    #[rstest]
+    fn test_re_adding_account_keeps_single_index_entry(mut cache: Cache) {
+        let venue = Venue::from("BINANCE");
+        cache
+            .add_account(StubAccount::boxed("BINANCE-001"))
+            .unwrap();
+        cache
+            .add_account(StubAccount::boxed("BINANCE-002"))
+            .unwrap();
+        cache
+            .add_account(StubAccount::boxed("BINANCE-001"))
+            .unwrap();
+
+        assert_eq!(cache.accounts_for_venue(&venue).len(), 2);
+        assert_eq!(
+            cache.account_id(&venue),
+            Some(&AccountId::from("BINANCE-001"))
+        );
+    }
+
+    #[rstest]
+    fn test_build_index_restores_venue_accounts(mut cache: Cache) {
+        let venue = Venue::from("BINANCE");
+        cache
+            .add_account(StubAccount::boxed("BINANCE-002"))
+            .unwrap();
+        cache
+            .add_account(StubAccount::boxed("BINANCE-001"))
+            .unwrap();
+
+        cache.build_index();
+
+        let accounts: Vec<AccountId> = cache
+            .accounts_for_venue(&venue)
+            .iter()
+            .map(|account| account.id())
+            .collect();
+        assert_eq!(
+            accounts,
+            vec![
+                AccountId::from("BINANCE-002"),
+                AccountId::from("BINANCE-001")
+            ]
+        );
+        assert_eq!(
+            cache.account_id(&venue),
+            Some(&AccountId::from("BINANCE-002"))
+        );
+        assert!(cache.check_integrity());
+    }
+
     #[rstest]
     fn test_synthetic_when_empty(cache: Cache) {
         let synth = SyntheticInstrument::default();
@@ -2840,4 +3722,360 @@ mod tests {
         let result = cache.bars(&bars[0].bar_type);
         assert_eq!(result, Some(bars));
     }
+
+    #[rstest]
+    fn test_instrument_close_keeps_latest(
+        mut cache: Cache,
+        stub_instrument_close: InstrumentClose,
+    ) {
+        let instrument_id = stub_instrument_close.instrument_id;
+        assert!(cache.instrument_close(&instrument_id).is_none());
+
+        let mut earlier = stub_instrument_close;
+        earlier.ts_event = UnixNanos::default();
+        cache.add_instrument_close(stub_instrument_close).unwrap();
+        cache.add_instrument_close(earlier).unwrap();
+
+        assert_eq!(
+            cache.instrument_close(&instrument_id),
+            Some(&stub_instrument_close)
+        );
+    }
+
+    fn cached_limit_order(cache: &mut Cache, audusd_sim: &CurrencyPair) -> OrderAny {
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+        let order = OrderAny::Limit(order);
+        cache.add_order(order.clone(), None, None, false).unwrap();
+        order
+    }
+
+    fn resent(fill: &OrderEventAny) -> OrderEventAny {
+        // A venue resend after a reconnect carries a new `event_id` but the same `trade_id`
+        let mut fill = fill.clone();
+        if let OrderEventAny::PartiallyFilled(e) | OrderEventAny::Filled(e) = &mut fill {
+            e.event_id = UUID4::new();
+        }
+        fill
+    }
+
+    #[rstest]
+    fn test_apply_order_event_drops_duplicate_accepts_and_fills(
+        mut cache: Cache,
+        audusd_sim: CurrencyPair,
+    ) {
+        let order = cached_limit_order(&mut cache, &audusd_sim);
+        let OrderAny::Limit(limit) = &order else {
+            panic!("expected limit order")
+        };
+        let account_id = AccountId::default();
+        let venue_order_id = VenueOrderId::default();
+        let submitted = OrderEventAny::Submitted(
+            TestOrderEventStubs::order_submitted(limit, account_id).unwrap(),
+        );
+        let accepted = OrderEventAny::Accepted(
+            TestOrderEventStubs::order_accepted(limit, account_id, venue_order_id).unwrap(),
+        );
+        let fill = |trade_id: &str| {
+            TestOrderEventStubs::order_filled(
+                limit,
+                &audusd_sim,
+                None,
+                Some(TradeId::from(trade_id)),
+                None,
+                None,
+                Some(Quantity::from(50_000)),
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+        };
+        let fill_1 = OrderEventAny::PartiallyFilled(fill("T-1"));
+        let fill_2 = OrderEventAny::Filled(fill("T-2"));
+
+        let stream = vec![
+            (submitted, true),
+            (accepted.clone(), true),
+            (accepted, false),
+            (fill_1.clone(), true),
+            (resent(&fill_1), false),
+            (fill_2.clone(), true),
+            (fill_2, false),
+        ];
+        for (event, expected) in stream {
+            assert_eq!(cache.apply_order_event(event).unwrap(), expected);
+        }
+
+        let cached = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(cached.order_events().len(), 5);
+        assert_eq!(cached.filled_qty(), Quantity::from(100_000));
+        assert!(cached.is_closed());
+        assert!(cache.is_order_closed(&order.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_apply_order_event_drops_duplicate_cancel(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let order = cached_limit_order(&mut cache, &audusd_sim);
+        let OrderAny::Limit(limit) = &order else {
+            panic!("expected limit order")
+        };
+        let account_id = AccountId::default();
+        let venue_order_id = VenueOrderId::default();
+        cache
+            .apply_order_event(OrderEventAny::Submitted(
+                TestOrderEventStubs::order_submitted(limit, account_id).unwrap(),
+            ))
+            .unwrap();
+        cache
+            .apply_order_event(OrderEventAny::Accepted(
+                TestOrderEventStubs::order_accepted(limit, account_id, venue_order_id).unwrap(),
+            ))
+            .unwrap();
+        let canceled = OrderEventAny::Canceled(
+            OrderCanceled::new(
+                limit.trader_id,
+                limit.strategy_id,
+                limit.instrument_id,
+                limit.client_order_id,
+                UUID4::new(),
+                UnixNanos::from(10),
+                UnixNanos::from(10),
+                false,
+                Some(venue_order_id),
+                Some(account_id),
+            )
+            .unwrap(),
+        );
+
+        assert!(cache.apply_order_event(canceled.clone()).unwrap());
+        assert!(!cache.apply_order_event(canceled).unwrap());
+
+        let cached = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(cached.order_events().len(), 4);
+        assert!(cached.is_closed());
+    }
+
+    #[rstest]
+    fn test_apply_order_event_keeps_distinct_events_with_same_ts_event(
+        mut cache: Cache,
+        audusd_sim: CurrencyPair,
+    ) {
+        let order = cached_limit_order(&mut cache, &audusd_sim);
+        let OrderAny::Limit(limit) = &order else {
+            panic!("expected limit order")
+        };
+        let account_id = AccountId::default();
+        let venue_order_id = VenueOrderId::default();
+        cache
+            .apply_order_event(OrderEventAny::Submitted(
+                TestOrderEventStubs::order_submitted(limit, account_id).unwrap(),
+            ))
+            .unwrap();
+        cache
+            .apply_order_event(OrderEventAny::Accepted(
+                TestOrderEventStubs::order_accepted(limit, account_id, venue_order_id).unwrap(),
+            ))
+            .unwrap();
+        let modify_rejected = |reason: &str| {
+            OrderEventAny::ModifyRejected(
+                OrderModifyRejected::new(
+                    limit.trader_id,
+                    limit.strategy_id,
+                    limit.instrument_id,
+                    limit.client_order_id,
+                    Ustr::from(reason),
+                    UUID4::new(),
+                    UnixNanos::from(10),
+                    UnixNanos::from(10),
+                    false,
+                    Some(venue_order_id),
+                    Some(account_id),
+                )
+                .unwrap(),
+            )
+        };
+
+        assert!(cache.apply_order_event(modify_rejected("PRICE")).unwrap());
+        assert!(cache
+            .apply_order_event(modify_rejected("QUANTITY"))
+            .unwrap());
+        assert_eq!(
+            cache
+                .order(&order.client_order_id())
+                .unwrap()
+                .order_events()
+                .len(),
+            5
+        );
+    }
+
+    #[rstest]
+    fn test_apply_order_event_duplicate_with_error_policy(audusd_sim: CurrencyPair) {
+        let config = CacheConfig {
+            duplicate_event_policy: DuplicateEventPolicy::Error,
+            ..Default::default()
+        };
+        let mut cache = Cache::new(config, None);
+        let order = cached_limit_order(&mut cache, &audusd_sim);
+        let OrderAny::Limit(limit) = &order else {
+            panic!("expected limit order")
+        };
+        let submitted = OrderEventAny::Submitted(
+            TestOrderEventStubs::order_submitted(limit, AccountId::default()).unwrap(),
+        );
+
+        assert!(cache.apply_order_event(submitted.clone()).unwrap());
+        assert!(cache.apply_order_event(submitted).is_err());
+        assert_eq!(
+            cache
+                .order(&order.client_order_id())
+                .unwrap()
+                .order_events()
+                .len(),
+            2
+        );
+    }
+
+    #[rstest]
+    fn test_apply_order_event_when_order_not_found(mut cache: Cache) {
+        let event = OrderEventAny::Submitted(OrderSubmitted::default());
+        assert!(cache.apply_order_event(event).is_err());
+    }
+
+    #[rstest]
+    fn test_update_instrument_fees_keeps_earlier_snapshots(
+        mut cache: Cache,
+        audusd_sim: CurrencyPair,
+    ) {
+        let instrument_id = audusd_sim.id;
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+        let snapshot = cache.instrument(&instrument_id).unwrap().clone();
+
+        for i in 1..=100_u64 {
+            let maker_fee = dec!(0.0001) * Decimal::from(i);
+            cache
+                .update_instrument_fees(&instrument_id, maker_fee, maker_fee * dec!(2), i.into())
+                .unwrap();
+        }
+
+        assert_eq!(snapshot.maker_fee(), audusd_sim.maker_fee);
+        assert_eq!(snapshot.taker_fee(), audusd_sim.taker_fee);
+        let latest = cache.instrument(&instrument_id).unwrap();
+        assert_eq!(latest.maker_fee(), dec!(0.0100));
+        assert_eq!(latest.taker_fee(), dec!(0.0200));
+        assert_eq!(cache.instrument_versions(&instrument_id).len(), 101);
+    }
+
+    #[rstest]
+    fn test_update_instrument_fees_records_versions_and_emits_event(
+        mut cache: Cache,
+        audusd_sim: CurrencyPair,
+    ) {
+        let instrument_id = audusd_sim.id;
+        let old_maker_fee = audusd_sim.maker_fee;
+        let events: Rc<RefCell<Vec<InstrumentUpdated>>> = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = events.clone();
+        cache.set_instrument_updated_handler(Box::new(move |event| {
+            events_clone.borrow_mut().push(event.clone());
+        }));
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+
+        cache
+            .update_instrument_fees(
+                &instrument_id,
+                dec!(0.00001),
+                dec!(0.00003),
+                UnixNanos::from(1_000),
+            )
+            .unwrap();
+        // Updating to the same fees adds a version but no event
+        cache
+            .update_instrument_fees(
+                &instrument_id,
+                dec!(0.00001),
+                dec!(0.00003),
+                UnixNanos::from(2_000),
+            )
+            .unwrap();
+
+        let versions = cache.instrument_versions(&instrument_id);
+        assert_eq!(versions.len(), 3);
+        assert_eq!(versions[0].maker_fee(), old_maker_fee);
+        assert_eq!(versions[1].maker_fee(), dec!(0.00001));
+        assert_eq!(
+            cache
+                .instrument_as_of(&instrument_id, UnixNanos::from(999))
+                .unwrap()
+                .maker_fee(),
+            old_maker_fee
+        );
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].instrument_id, instrument_id);
+        assert_eq!(events[0].version_count, 2);
+        assert!(events[0]
+            .changes
+            .iter()
+            .any(|change| change.field == "taker_fee"));
+    }
+
+    #[rstest]
+    fn test_update_margins(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+        let before = cache.instrument(&instrument_id).unwrap().clone();
+
+        cache
+            .update_margins(
+                &instrument_id,
+                dec!(0.05),
+                dec!(0.03),
+                UnixNanos::from(1_000),
+            )
+            .unwrap();
+
+        assert_eq!(cache.instrument_versions(&instrument_id).len(), 2);
+        assert_eq!(before.ts_init(), audusd_sim.ts_init);
+        let latest = cache.instrument(&instrument_id).unwrap();
+        assert_eq!(latest.ts_init(), UnixNanos::from(1_000));
+        assert_ne!(InstrumentAny::diff(&before, latest), Vec::new());
+    }
+
+    #[rstest]
+    fn test_update_instrument_fees_before_latest_version_errors(
+        mut cache: Cache,
+        audusd_sim: CurrencyPair,
+    ) {
+        let instrument = CurrencyPair {
+            ts_init: UnixNanos::from(1_000),
+            ..audusd_sim
+        };
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(instrument))
+            .unwrap();
+
+        let result = cache.update_instrument_fees(
+            &audusd_sim.id,
+            dec!(0.0001),
+            dec!(0.0002),
+            UnixNanos::from(999),
+        );
+
+        assert!(result.is_err());
+        assert_eq!(cache.instrument_versions(&audusd_sim.id).len(), 1);
+    }
 }
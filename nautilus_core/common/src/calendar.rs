@@ -0,0 +1,353 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `TradingCalendar` of weekly trading sessions in a venue timezone.
+
+use std::collections::BTreeSet;
+
+use chrono::{
+    DateTime, Datelike, Days, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    Utc, Weekday,
+};
+use chrono_tz::Tz;
+use nautilus_core::nanos::UnixNanos;
+
+/// The maximum number of days searched for a session, to bound calendars with no sessions.
+const MAX_SEARCH_DAYS: u64 = 366;
+
+/// Represents a weekly session which opens on `weekday` at the local `open` time.
+///
+/// A `close` at or before `open` is on the following day (an overnight session).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SessionTemplate {
+    pub weekday: Weekday,
+    pub open: NaiveTime,
+    pub close: NaiveTime,
+}
+
+impl SessionTemplate {
+    #[must_use]
+    pub fn new(weekday: Weekday, open: NaiveTime, close: NaiveTime) -> Self {
+        Self {
+            weekday,
+            open,
+            close,
+        }
+    }
+
+    #[must_use]
+    pub fn is_overnight(&self) -> bool {
+        self.close <= self.open
+    }
+}
+
+/// Represents a single trading session from a `TradingCalendar`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TradingSession {
+    /// The UNIX timestamp (nanoseconds) when the session opens (inclusive).
+    pub open: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the session closes (exclusive).
+    pub close: UnixNanos,
+    /// The local date the session closes on, which holidays are matched against.
+    pub trading_date: NaiveDate,
+}
+
+impl TradingSession {
+    #[must_use]
+    pub fn contains(&self, ts: UnixNanos) -> bool {
+        self.open <= ts && ts < self.close
+    }
+}
+
+/// Provides trading sessions from weekly templates in a timezone, excluding holidays.
+///
+/// Session times are local to the calendar timezone, so follow daylight saving transitions.
+/// Local times which are skipped by a transition are shifted forward by the one hour gap,
+/// and ambiguous local times resolve to the earliest instant.
+#[derive(Clone, Debug)]
+pub struct TradingCalendar {
+    pub tz: Tz,
+    templates: Vec<SessionTemplate>,
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl TradingCalendar {
+    #[must_use]
+    pub fn new(tz: Tz, mut templates: Vec<SessionTemplate>, holidays: Vec<NaiveDate>) -> Self {
+        templates.sort_by_key(|t| (t.weekday.num_days_from_monday(), t.open));
+        Self {
+            tz,
+            templates,
+            holidays: holidays.into_iter().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn templates(&self) -> &[SessionTemplate] {
+        &self.templates
+    }
+
+    pub fn add_holiday(&mut self, date: NaiveDate) {
+        self.holidays.insert(date);
+    }
+
+    #[must_use]
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    /// Returns whether a session is open at the given `ts`.
+    #[must_use]
+    pub fn is_open(&self, ts: UnixNanos) -> bool {
+        self.session_for(ts).is_some()
+    }
+
+    /// Returns the session open at the given `ts` (if any).
+    #[must_use]
+    pub fn session_for(&self, ts: UnixNanos) -> Option<TradingSession> {
+        self.sessions_from(self.local_date(ts) - Days::new(7))
+            .take_while(|session| session.open <= ts)
+            .find(|session| session.contains(ts))
+    }
+
+    /// Returns the next session open strictly after the given `ts` (if any within a year).
+    #[must_use]
+    pub fn next_open(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.sessions_from(self.local_date(ts) - Days::new(1))
+            .map(|session| session.open)
+            .find(|open| *open > ts)
+    }
+
+    /// Returns the next session close strictly after the given `ts` (if any within a year).
+    ///
+    /// When a session is open at `ts` this is the close of that session.
+    #[must_use]
+    pub fn next_close(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.sessions_from(self.local_date(ts) - Days::new(7))
+            .map(|session| session.close)
+            .find(|close| *close > ts)
+    }
+
+    /// Returns the non-holiday sessions in order of open, for sessions opening from `start`.
+    fn sessions_from(&self, start: NaiveDate) -> impl Iterator<Item = TradingSession> + '_ {
+        (0..MAX_SEARCH_DAYS)
+            .filter_map(move |i| start.checked_add_days(Days::new(i)))
+            .flat_map(move |date| {
+                self.templates
+                    .iter()
+                    .filter(move |template| template.weekday == date.weekday())
+                    .map(move |template| self.session(template, date))
+            })
+            .filter(|session| !self.is_holiday(session.trading_date))
+    }
+
+    fn session(&self, template: &SessionTemplate, date: NaiveDate) -> TradingSession {
+        let close_date = if template.is_overnight() {
+            date + Days::new(1)
+        } else {
+            date
+        };
+
+        TradingSession {
+            open: self.to_unix_nanos(date.and_time(template.open)),
+            close: self.to_unix_nanos(close_date.and_time(template.close)),
+            trading_date: close_date,
+        }
+    }
+
+    fn local_date(&self, ts: UnixNanos) -> NaiveDate {
+        DateTime::<Utc>::from_timestamp_nanos(ts.as_u64() as i64)
+            .with_timezone(&self.tz)
+            .date_naive()
+    }
+
+    fn to_unix_nanos(&self, local: NaiveDateTime) -> UnixNanos {
+        let datetime = match self.tz.from_local_datetime(&local) {
+            LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => datetime,
+            LocalResult::None => self
+                .tz
+                .from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+                .expect("local time after a daylight saving gap should exist"),
+        };
+        // Clamp instants before the UNIX epoch (or out of range) to zero
+        let nanos = datetime.timestamp_nanos_opt().unwrap_or_default();
+        UnixNanos::from(u64::try_from(nanos).unwrap_or_default())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono_tz::America::{Chicago, New_York};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    fn time(hour: u32, min: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, min, 0).unwrap()
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn utc(s: &str) -> UnixNanos {
+        let datetime = DateTime::parse_from_rfc3339(s).unwrap();
+        UnixNanos::from(datetime.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    /// CME Globex: Sunday to Thursday 17:00 CT until 16:00 CT the following day.
+    #[fixture]
+    fn cme() -> TradingCalendar {
+        let templates = [
+            Weekday::Sun,
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+        ]
+        .into_iter()
+        .map(|weekday| SessionTemplate::new(weekday, time(17, 0), time(16, 0)))
+        .collect();
+        TradingCalendar::new(Chicago, templates, vec![])
+    }
+
+    /// NYSE: Monday to Friday 09:30 ET until 16:00 ET, with Martin Luther King Jr. Day 2024.
+    #[fixture]
+    fn nyse() -> TradingCalendar {
+        let templates = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]
+        .into_iter()
+        .map(|weekday| SessionTemplate::new(weekday, time(9, 30), time(16, 0)))
+        .collect();
+        TradingCalendar::new(New_York, templates, vec![date(2024, 1, 15)])
+    }
+
+    #[rstest]
+    #[case("2024-03-02T23:00:00Z", false)] // Saturday
+    #[case("2024-03-03T22:59:59Z", false)] // Sunday 16:59:59 CST
+    #[case("2024-03-03T23:00:00Z", true)] // Sunday 17:00 CST
+    #[case("2024-03-04T21:59:59Z", true)] // Monday 15:59:59 CST
+    #[case("2024-03-04T22:00:00Z", false)] // Monday 16:00 CST (maintenance break)
+    #[case("2024-03-08T22:00:00Z", false)] // Friday 16:00 CST
+    fn test_cme_is_open(cme: TradingCalendar, #[case] ts: &str, #[case] expected: bool) {
+        assert_eq!(cme.is_open(utc(ts)), expected);
+    }
+
+    #[rstest]
+    fn test_cme_sunday_open(cme: TradingCalendar) {
+        let saturday = utc("2024-03-02T12:00:00Z");
+        let sunday_open = utc("2024-03-03T23:00:00Z");
+
+        let session = cme.session_for(sunday_open).unwrap();
+
+        assert_eq!(cme.next_open(saturday), Some(sunday_open));
+        assert_eq!(cme.next_close(saturday), Some(utc("2024-03-04T22:00:00Z")));
+        assert!(cme.session_for(saturday).is_none());
+        assert_eq!(session.open, sunday_open);
+        assert_eq!(session.close, utc("2024-03-04T22:00:00Z"));
+        assert_eq!(session.trading_date, date(2024, 3, 4));
+    }
+
+    #[rstest]
+    fn test_holiday_is_closed(nyse: TradingCalendar) {
+        let holiday_morning = utc("2024-01-15T15:00:00Z"); // Monday 10:00 EST
+
+        assert!(!nyse.is_open(holiday_morning));
+        assert_eq!(
+            nyse.next_open(holiday_morning),
+            Some(utc("2024-01-16T14:30:00Z"))
+        );
+        assert_eq!(
+            nyse.next_close(utc("2024-01-12T21:00:00Z")), // Friday close
+            Some(utc("2024-01-16T21:00:00Z"))
+        );
+        assert!(nyse.is_open(utc("2024-01-16T15:00:00Z")));
+    }
+
+    #[rstest]
+    fn test_holiday_skips_overnight_session_by_trading_date(mut cme: TradingCalendar) {
+        cme.add_holiday(date(2024, 3, 4));
+
+        // The Sunday evening session trades for Monday, so is skipped
+        assert!(!cme.is_open(utc("2024-03-04T00:00:00Z")));
+        assert_eq!(
+            cme.next_open(utc("2024-03-02T12:00:00Z")),
+            Some(utc("2024-03-04T23:00:00Z"))
+        );
+    }
+
+    #[rstest]
+    fn test_dst_spring_forward(nyse: TradingCalendar, cme: TradingCalendar) {
+        // US daylight saving starts Sunday 2024-03-10
+        let friday_close = utc("2024-03-08T21:00:00Z"); // 16:00 EST
+
+        assert_eq!(
+            nyse.next_close(utc("2024-03-08T15:00:00Z")),
+            Some(friday_close)
+        );
+        assert_eq!(
+            nyse.next_open(friday_close),
+            Some(utc("2024-03-11T13:30:00Z")) // 09:30 EDT
+        );
+        assert_eq!(
+            cme.next_open(friday_close),
+            Some(utc("2024-03-10T22:00:00Z")) // 17:00 CDT
+        );
+        assert!(cme.is_open(utc("2024-03-10T22:30:00Z"))); // Would be closed at 16:30 CST
+    }
+
+    #[rstest]
+    fn test_dst_fall_back(nyse: TradingCalendar) {
+        // US daylight saving ends Sunday 2024-11-03
+        let friday_open = utc("2024-11-01T13:30:00Z"); // 09:30 EDT
+        let monday_open = utc("2024-11-04T14:30:00Z"); // 09:30 EST
+
+        assert_eq!(nyse.session_for(friday_open).unwrap().open, friday_open);
+        assert_eq!(nyse.next_open(friday_open), Some(monday_open));
+        assert!(!nyse.is_open(utc("2024-11-04T13:30:00Z")));
+        assert!(nyse.is_open(monday_open));
+    }
+
+    #[rstest]
+    fn test_local_time_in_dst_gap_shifts_forward() {
+        let templates = vec![SessionTemplate::new(Weekday::Sun, time(2, 30), time(4, 0))];
+        let calendar = TradingCalendar::new(New_York, templates, vec![]);
+
+        // 02:30 does not exist on 2024-03-10, so opens at 03:30 EDT
+        assert_eq!(
+            calendar.next_open(utc("2024-03-09T00:00:00Z")),
+            Some(utc("2024-03-10T07:30:00Z"))
+        );
+    }
+
+    #[rstest]
+    fn test_empty_calendar_is_never_open() {
+        let calendar = TradingCalendar::new(New_York, vec![], vec![]);
+        let ts = utc("2024-03-08T15:00:00Z");
+
+        assert!(!calendar.is_open(ts));
+        assert!(calendar.next_open(ts).is_none());
+        assert!(calendar.next_close(ts).is_none());
+    }
+}
@@ -27,7 +27,7 @@ use ustr::Ustr;
 
 use crate::{
     handlers::EventHandler,
-    timer::{LiveTimer, TestTimer, TimeEvent, TimeEventHandler},
+    timer::{LiveTimer, TestTimer, TimeEvent, TimeEventHandler, TimerFireSchedule},
 };
 
 /// Represents a type of clock.
@@ -76,6 +76,7 @@ pub struct TestClock {
     timers: HashMap<Ustr, TestTimer>,
     default_callback: Option<EventHandler>,
     callbacks: HashMap<Ustr, EventHandler>,
+    schedule: Option<TimerFireSchedule>,
 }
 
 impl TestClock {
@@ -86,6 +87,7 @@ impl TestClock {
             timers: HashMap::new(),
             default_callback: None,
             callbacks: HashMap::new(),
+            schedule: None,
         }
     }
 
@@ -94,6 +96,20 @@ impl TestClock {
         &self.timers
     }
 
+    /// Precomputes the fire times of the timers up to `end_ns`, so that advancing the clock
+    /// only checks the timers when a fire time is crossed.
+    ///
+    /// The schedule is rebuilt whenever a timer is set or canceled.
+    pub fn set_schedule_end(&mut self, end_ns: UnixNanos) {
+        self.schedule = Some(TimerFireSchedule::from_timers(self.timers.values(), end_ns));
+    }
+
+    fn rebuild_schedule(&mut self) {
+        if let Some(schedule) = &self.schedule {
+            self.set_schedule_end(schedule.end_ns());
+        }
+    }
+
     pub fn advance_time(&mut self, to_time_ns: UnixNanos, set_time: bool) -> Vec<TimeEvent> {
         // Time should increase monotonically
         assert!(
@@ -105,6 +121,12 @@ impl TestClock {
             self.time.set_time(to_time_ns);
         }
 
+        if let Some(schedule) = &mut self.schedule {
+            if !schedule.is_due(to_time_ns) {
+                return Vec::new();
+            }
+        }
+
         let mut timers: Vec<TimeEvent> = self
             .timers
             .iter_mut()
@@ -208,6 +230,7 @@ impl Clock for TestClock {
             Some(alert_time_ns),
         )?;
         self.timers.insert(name_ustr, timer);
+        self.rebuild_schedule();
         Ok(())
     }
 
@@ -234,6 +257,7 @@ impl Clock for TestClock {
 
         let timer = TestTimer::new(name, interval_ns, start_time_ns, stop_time_ns)?;
         self.timers.insert(name_ustr, timer);
+        self.rebuild_schedule();
         Ok(())
     }
 
@@ -251,6 +275,7 @@ impl Clock for TestClock {
             None => {}
             Some(mut timer) => timer.cancel(),
         }
+        self.rebuild_schedule();
     }
 
     fn cancel_timers(&mut self) {
@@ -258,6 +283,7 @@ impl Clock for TestClock {
             timer.cancel();
         }
         self.timers = HashMap::new();
+        self.rebuild_schedule();
     }
 }
 
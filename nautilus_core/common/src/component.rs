@@ -0,0 +1,318 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the lifecycle of system components and the transitions between their states.
+
+use nautilus_model::identifiers::component_id::ComponentId;
+
+use crate::enums::{ComponentState, ComponentTrigger};
+
+impl ComponentState {
+    /// Returns the state reached by applying the given `trigger` to this state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the trigger is not valid from this state.
+    pub fn transition(self, trigger: ComponentTrigger) -> anyhow::Result<Self> {
+        let next = match (self, trigger) {
+            (Self::PreInitialized, ComponentTrigger::Initialize) => Self::Ready,
+            (Self::Ready, ComponentTrigger::Reset) => Self::Resetting,
+            (Self::Ready, ComponentTrigger::Start) => Self::Starting,
+            (Self::Ready, ComponentTrigger::Dispose) => Self::Disposing,
+            (Self::Resetting, ComponentTrigger::ResetCompleted) => Self::Ready,
+            (Self::Starting, ComponentTrigger::StartCompleted) => Self::Running,
+            (Self::Starting, ComponentTrigger::Stop) => Self::Stopping,
+            (Self::Starting, ComponentTrigger::Fault) => Self::Faulting,
+            (Self::Running, ComponentTrigger::Stop) => Self::Stopping,
+            (Self::Running, ComponentTrigger::Degrade) => Self::Degrading,
+            (Self::Running, ComponentTrigger::Fault) => Self::Faulting,
+            (Self::Resuming, ComponentTrigger::Stop) => Self::Stopping,
+            (Self::Resuming, ComponentTrigger::ResumeCompleted) => Self::Running,
+            (Self::Resuming, ComponentTrigger::Fault) => Self::Faulting,
+            (Self::Stopping, ComponentTrigger::StopCompleted) => Self::Stopped,
+            (Self::Stopping, ComponentTrigger::Fault) => Self::Faulting,
+            (Self::Stopped, ComponentTrigger::Reset) => Self::Resetting,
+            (Self::Stopped, ComponentTrigger::Resume) => Self::Resuming,
+            (Self::Stopped, ComponentTrigger::Dispose) => Self::Disposing,
+            (Self::Stopped, ComponentTrigger::Fault) => Self::Faulting,
+            (Self::Degrading, ComponentTrigger::DegradeCompleted) => Self::Degraded,
+            (Self::Degraded, ComponentTrigger::Resume) => Self::Resuming,
+            (Self::Degraded, ComponentTrigger::Stop) => Self::Stopping,
+            (Self::Degraded, ComponentTrigger::Fault) => Self::Faulting,
+            (Self::Disposing, ComponentTrigger::DisposeCompleted) => Self::Disposed,
+            (Self::Faulting, ComponentTrigger::FaultCompleted) => Self::Faulted,
+            _ => anyhow::bail!("Invalid state trigger {self} -> {trigger}"),
+        };
+        Ok(next)
+    }
+}
+
+/// A system component with a managed lifecycle.
+///
+/// Implementors provide storage for their state and may override the `on_*` hooks, while
+/// the provided `start`, `stop` and `dispose` methods drive the state transitions around them.
+/// A hook returning an error faults the component where the current state allows it.
+pub trait Component {
+    /// Returns the ID of the component.
+    fn component_id(&self) -> ComponentId;
+
+    /// Returns the current state of the component.
+    fn state(&self) -> ComponentState;
+
+    /// Sets the state of the component, following a valid transition.
+    fn set_state(&mut self, state: ComponentState);
+
+    /// Actions to be performed when the component starts.
+    fn on_start(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when the component stops.
+    fn on_stop(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when the component is disposed.
+    fn on_dispose(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Returns whether the component is running.
+    fn is_running(&self) -> bool {
+        self.state() == ComponentState::Running
+    }
+
+    /// Applies the given `trigger` to the component state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the trigger is not valid from the current state.
+    fn transition_state(&mut self, trigger: ComponentTrigger) -> anyhow::Result<()> {
+        let state = self.state().transition(trigger)?;
+        self.set_state(state);
+        Ok(())
+    }
+
+    /// Initializes the component, making it ready to start.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the component has already been initialized.
+    fn initialize(&mut self) -> anyhow::Result<()> {
+        self.transition_state(ComponentTrigger::Initialize)
+    }
+
+    /// Starts the component.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the component cannot start from its current state,
+    /// or if its `on_start` hook fails.
+    fn start(&mut self) -> anyhow::Result<()> {
+        self.transition_state(ComponentTrigger::Start)?;
+        if let Err(e) = self.on_start() {
+            self.fault();
+            return Err(e);
+        }
+        self.transition_state(ComponentTrigger::StartCompleted)
+    }
+
+    /// Stops the component.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the component cannot stop from its current state,
+    /// or if its `on_stop` hook fails.
+    fn stop(&mut self) -> anyhow::Result<()> {
+        self.transition_state(ComponentTrigger::Stop)?;
+        if let Err(e) = self.on_stop() {
+            self.fault();
+            return Err(e);
+        }
+        self.transition_state(ComponentTrigger::StopCompleted)
+    }
+
+    /// Disposes of the component, releasing its resources.
+    ///
+    /// A running component is stopped first.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the component cannot be disposed from its current
+    /// state, or if its `on_stop` or `on_dispose` hooks fail.
+    fn dispose(&mut self) -> anyhow::Result<()> {
+        if self.is_running() {
+            self.stop()?;
+        }
+        self.transition_state(ComponentTrigger::Dispose)?;
+        self.on_dispose()?;
+        self.transition_state(ComponentTrigger::DisposeCompleted)
+    }
+
+    /// Moves the component to `Faulted`, if a fault is valid from its current state.
+    fn fault(&mut self) {
+        if self.transition_state(ComponentTrigger::Fault).is_ok() {
+            let _ = self.transition_state(ComponentTrigger::FaultCompleted);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    struct StubComponent {
+        state: ComponentState,
+        fail_on_stop: bool,
+        disposed: bool,
+    }
+
+    impl StubComponent {
+        fn new(fail_on_stop: bool) -> Self {
+            Self {
+                state: ComponentState::PreInitialized,
+                fail_on_stop,
+                disposed: false,
+            }
+        }
+    }
+
+    impl Component for StubComponent {
+        fn component_id(&self) -> ComponentId {
+            ComponentId::from("StubComponent")
+        }
+
+        fn state(&self) -> ComponentState {
+            self.state
+        }
+
+        fn set_state(&mut self, state: ComponentState) {
+            self.state = state;
+        }
+
+        fn on_stop(&mut self) -> anyhow::Result<()> {
+            if self.fail_on_stop {
+                anyhow::bail!("Stop failed");
+            }
+            Ok(())
+        }
+
+        fn on_dispose(&mut self) -> anyhow::Result<()> {
+            self.disposed = true;
+            Ok(())
+        }
+    }
+
+    #[rstest]
+    #[case(
+        ComponentState::PreInitialized,
+        ComponentTrigger::Initialize,
+        ComponentState::Ready
+    )]
+    #[case(
+        ComponentState::Ready,
+        ComponentTrigger::Start,
+        ComponentState::Starting
+    )]
+    #[case(
+        ComponentState::Running,
+        ComponentTrigger::Stop,
+        ComponentState::Stopping
+    )]
+    #[case(
+        ComponentState::Stopped,
+        ComponentTrigger::Resume,
+        ComponentState::Resuming
+    )]
+    #[case(
+        ComponentState::Stopped,
+        ComponentTrigger::Dispose,
+        ComponentState::Disposing
+    )]
+    #[case(
+        ComponentState::Faulting,
+        ComponentTrigger::FaultCompleted,
+        ComponentState::Faulted
+    )]
+    fn test_valid_transitions(
+        #[case] state: ComponentState,
+        #[case] trigger: ComponentTrigger,
+        #[case] expected: ComponentState,
+    ) {
+        assert_eq!(state.transition(trigger).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(ComponentState::PreInitialized, ComponentTrigger::Start)]
+    #[case(ComponentState::Running, ComponentTrigger::Dispose)]
+    #[case(ComponentState::Disposed, ComponentTrigger::Start)]
+    #[case(ComponentState::Stopped, ComponentTrigger::Stop)]
+    fn test_invalid_transitions(#[case] state: ComponentState, #[case] trigger: ComponentTrigger) {
+        assert!(state.transition(trigger).is_err());
+    }
+
+    #[rstest]
+    fn test_lifecycle_start_stop_dispose() {
+        let mut component = StubComponent::new(false);
+        component.initialize().unwrap();
+        component.start().unwrap();
+        assert!(component.is_running());
+
+        component.stop().unwrap();
+        assert_eq!(component.state(), ComponentState::Stopped);
+
+        component.dispose().unwrap();
+        assert_eq!(component.state(), ComponentState::Disposed);
+        assert!(component.disposed);
+    }
+
+    #[rstest]
+    fn test_dispose_stops_running_component() {
+        let mut component = StubComponent::new(false);
+        component.initialize().unwrap();
+        component.start().unwrap();
+
+        component.dispose().unwrap();
+
+        assert_eq!(component.state(), ComponentState::Disposed);
+    }
+
+    #[rstest]
+    fn test_failed_stop_faults_component() {
+        let mut component = StubComponent::new(true);
+        component.initialize().unwrap();
+        component.start().unwrap();
+
+        let result = component.stop();
+
+        assert_eq!(result.unwrap_err().to_string(), "Stop failed");
+        assert_eq!(component.state(), ComponentState::Faulted);
+    }
+
+    #[rstest]
+    fn test_start_before_initialize_fails() {
+        let mut component = StubComponent::new(false);
+
+        let result = component.start();
+
+        assert!(result.is_err());
+        assert_eq!(component.state(), ComponentState::PreInitialized);
+    }
+}
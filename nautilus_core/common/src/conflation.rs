@@ -0,0 +1,527 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides conflation of quote ticks for slow consumers (such as dashboards).
+//!
+//! A `QuoteConflator` accumulates quotes per instrument and emits at most one output per
+//! instrument per interval. Emission is driven by a clock timer, so pending state is flushed on
+//! every interval boundary whether or not new quotes have arrived.
+//!
+//! A `ConflatedQuoteSubscription` sits a conflator on a message bus subscription, receiving the
+//! quotes published on a topic and republishing the conflated outputs on another.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use log::error;
+
+use nautilus_core::{correctness::check_positive_u64, nanos::UnixNanos};
+use nautilus_model::{
+    data::quote::QuoteTick,
+    identifiers::instrument_id::InstrumentId,
+    types::{fixed::FIXED_PRECISION, price::Price},
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    clock::Clock,
+    handlers::{MessageHandler, SafeBusMessageCallback},
+    msgbus::{BusMessage, MessageBus},
+    timer::TimeEvent,
+};
+
+/// The policy for conflating the quotes received for an instrument within an interval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConflationPolicy {
+    /// Emit the latest quote, dropping intermediate quotes.
+    Latest,
+    /// Emit a summary of the quotes, tracking the bid and ask extremes.
+    Ohlc,
+    /// Emit the latest quote only when its mid has moved more than `min_ticks` ticks (at the
+    /// quote price precision) from the last emitted mid.
+    Significant { min_ticks: u32 },
+}
+
+/// Represents a summary of the quotes for an instrument over an interval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuoteSummary {
+    pub instrument_id: InstrumentId,
+    pub open: QuoteTick,
+    pub close: QuoteTick,
+    pub bid_high: Price,
+    pub bid_low: Price,
+    pub ask_high: Price,
+    pub ask_low: Price,
+    pub count: u64,
+}
+
+impl QuoteSummary {
+    fn new(quote: QuoteTick) -> Self {
+        Self {
+            instrument_id: quote.instrument_id,
+            open: quote,
+            close: quote,
+            bid_high: quote.bid_price,
+            bid_low: quote.bid_price,
+            ask_high: quote.ask_price,
+            ask_low: quote.ask_price,
+            count: 1,
+        }
+    }
+
+    fn update(&mut self, quote: QuoteTick) {
+        self.close = quote;
+        self.bid_high = self.bid_high.max(quote.bid_price);
+        self.bid_low = self.bid_low.min(quote.bid_price);
+        self.ask_high = self.ask_high.max(quote.ask_price);
+        self.ask_low = self.ask_low.min(quote.ask_price);
+        self.count += 1;
+    }
+}
+
+/// Represents the output of a conflator for an instrument and interval.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConflatedQuote {
+    Quote(QuoteTick),
+    Summary(Box<QuoteSummary>),
+}
+
+/// The counts of quotes received and outputs emitted by a conflator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConflationStats {
+    pub received: u64,
+    pub emitted: u64,
+}
+
+impl ConflationStats {
+    /// Returns the number of quotes received per output emitted (`None` if nothing emitted).
+    #[must_use]
+    pub fn ratio(&self) -> Option<f64> {
+        (self.emitted > 0).then(|| self.received as f64 / self.emitted as f64)
+    }
+}
+
+/// Conflates quotes per instrument, emitting at most one output per instrument per interval.
+///
+/// The owner sets the conflator timer on its clock (see [`QuoteConflator::start`]) and passes the
+/// resulting time events to [`QuoteConflator::on_time_event`].
+#[derive(Clone, Debug)]
+pub struct QuoteConflator {
+    timer_name: Ustr,
+    interval_ns: u64,
+    policy: ConflationPolicy,
+    pending: HashMap<InstrumentId, QuoteSummary>,
+    last_emitted_mid: HashMap<InstrumentId, i64>,
+    stats: HashMap<InstrumentId, ConflationStats>,
+}
+
+impl QuoteConflator {
+    /// Creates a new [`QuoteConflator`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `interval_ns` is not positive.
+    pub fn new(name: &str, interval_ns: u64, policy: ConflationPolicy) -> anyhow::Result<Self> {
+        check_positive_u64(interval_ns, stringify!(interval_ns))?;
+        Ok(Self {
+            timer_name: Ustr::from(&format!("{name}-CONFLATION")),
+            interval_ns,
+            policy,
+            pending: HashMap::new(),
+            last_emitted_mid: HashMap::new(),
+            stats: HashMap::new(),
+        })
+    }
+
+    #[must_use]
+    pub fn timer_name(&self) -> Ustr {
+        self.timer_name
+    }
+
+    #[must_use]
+    pub fn interval_ns(&self) -> u64 {
+        self.interval_ns
+    }
+
+    #[must_use]
+    pub fn policy(&self) -> ConflationPolicy {
+        self.policy
+    }
+
+    /// Sets the interval timer on the given `clock`, handled by its default handler.
+    pub fn start(&self, clock: &mut dyn Clock, start_time_ns: UnixNanos) -> anyhow::Result<()> {
+        clock.set_timer_ns(
+            &self.timer_name,
+            self.interval_ns,
+            start_time_ns,
+            None,
+            None,
+        )
+    }
+
+    pub fn stop(&self, clock: &mut dyn Clock) {
+        clock.cancel_timer(&self.timer_name);
+    }
+
+    /// Accumulates the given `quote` until the next interval boundary.
+    pub fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.stats.entry(quote.instrument_id).or_default().received += 1;
+        self.pending
+            .entry(quote.instrument_id)
+            .and_modify(|summary| summary.update(*quote))
+            .or_insert_with(|| QuoteSummary::new(*quote));
+    }
+
+    /// Handles the interval boundary `event`, flushing the pending quotes for all instruments.
+    ///
+    /// Outputs are returned in instrument ID order. Events for other timers are ignored.
+    pub fn on_time_event(&mut self, event: &TimeEvent) -> Vec<ConflatedQuote> {
+        if event.name != self.timer_name {
+            return Vec::new();
+        }
+        self.flush()
+    }
+
+    /// Flushes the pending quotes for all instruments, returning any outputs.
+    pub fn flush(&mut self) -> Vec<ConflatedQuote> {
+        let mut pending: Vec<QuoteSummary> = self.pending.drain().map(|(_, s)| s).collect();
+        pending.sort_by_key(|summary| summary.instrument_id.to_string());
+
+        let mut outputs = Vec::with_capacity(pending.len());
+        for summary in pending {
+            let output = match self.policy {
+                ConflationPolicy::Latest => Some(ConflatedQuote::Quote(summary.close)),
+                ConflationPolicy::Ohlc => Some(ConflatedQuote::Summary(Box::new(summary))),
+                ConflationPolicy::Significant { min_ticks } => self
+                    .is_significant(&summary.close, min_ticks)
+                    .then_some(ConflatedQuote::Quote(summary.close)),
+            };
+
+            if let Some(output) = output {
+                if let ConflatedQuote::Quote(quote) = &output {
+                    self.last_emitted_mid
+                        .insert(quote.instrument_id, mid_raw(quote));
+                }
+                self.stats.entry(summary.instrument_id).or_default().emitted += 1;
+                outputs.push(output);
+            }
+        }
+        outputs
+    }
+
+    /// Returns the stats for the given `instrument_id`.
+    #[must_use]
+    pub fn stats(&self, instrument_id: &InstrumentId) -> ConflationStats {
+        self.stats.get(instrument_id).copied().unwrap_or_default()
+    }
+
+    /// Returns the stats over all instruments.
+    #[must_use]
+    pub fn total_stats(&self) -> ConflationStats {
+        self.stats
+            .values()
+            .fold(ConflationStats::default(), |total, stats| ConflationStats {
+                received: total.received + stats.received,
+                emitted: total.emitted + stats.emitted,
+            })
+    }
+
+    fn is_significant(&self, quote: &QuoteTick, min_ticks: u32) -> bool {
+        let Some(last_mid) = self.last_emitted_mid.get(&quote.instrument_id) else {
+            return true; // Nothing emitted yet
+        };
+        let tick_raw = 10_i64.pow(u32::from(
+            FIXED_PRECISION.saturating_sub(quote.bid_price.precision),
+        ));
+        (mid_raw(quote) - last_mid).abs() > i64::from(min_ticks) * tick_raw
+    }
+}
+
+/// Conflates the quotes published on a message bus topic, republishing the outputs on each
+/// interval boundary.
+///
+/// Quotes are received as JSON payloads, and the outputs are published as JSON serialized
+/// [`ConflatedQuote`]s on the output topic.
+pub struct ConflatedQuoteSubscription {
+    topic: Ustr,
+    output_topic: Ustr,
+    handler: MessageHandler,
+    conflator: Arc<Mutex<QuoteConflator>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl ConflatedQuoteSubscription {
+    /// Subscribes the `conflator` to the quotes published on `topic` (which may contain
+    /// wildcards), with the outputs to be published on `output_topic`.
+    ///
+    /// The `output_topic` should not match `topic`, otherwise outputs are fed back as quotes.
+    pub fn subscribe(
+        msgbus: Rc<RefCell<MessageBus>>,
+        topic: &str,
+        output_topic: &str,
+        conflator: QuoteConflator,
+    ) -> Self {
+        let conflator = Arc::new(Mutex::new(conflator));
+        let handler_conflator = conflator.clone();
+        let handler = MessageHandler::with_bus_callback(
+            conflator.lock().unwrap().timer_name(),
+            SafeBusMessageCallback {
+                callback: Arc::new(move |message: &BusMessage| {
+                    match serde_json::from_slice::<QuoteTick>(&message.payload) {
+                        Ok(quote) => handler_conflator.lock().unwrap().handle_quote(&quote),
+                        Err(e) => error!("Cannot conflate message on {}: {e}", message.topic),
+                    }
+                }),
+            },
+        );
+        msgbus.borrow_mut().subscribe(topic, handler.clone(), None);
+
+        Self {
+            topic: Ustr::from(topic),
+            output_topic: Ustr::from(output_topic),
+            handler,
+            conflator,
+            msgbus,
+        }
+    }
+
+    pub fn conflator(&self) -> MutexGuard<'_, QuoteConflator> {
+        self.conflator.lock().unwrap()
+    }
+
+    /// Handles the interval boundary `event`, publishing the outputs flushed from the conflator
+    /// and returning their count.
+    pub fn on_time_event(&self, event: &TimeEvent) -> usize {
+        let outputs = self.conflator().on_time_event(event);
+        let mut msgbus = self.msgbus.borrow_mut();
+        for output in &outputs {
+            match serde_json::to_vec(output) {
+                Ok(payload) => msgbus.publish(&self.output_topic, payload),
+                Err(e) => error!("Cannot serialize conflated quote: {e}"),
+            }
+        }
+        outputs.len()
+    }
+
+    /// Unsubscribes the conflator from the quote topic.
+    pub fn unsubscribe(self) {
+        self.msgbus
+            .borrow_mut()
+            .unsubscribe(&self.topic, self.handler);
+    }
+}
+
+fn mid_raw(quote: &QuoteTick) -> i64 {
+    (quote.bid_price.raw + quote.ask_price.raw) / 2
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        identifiers::{instrument_id::InstrumentId, trader_id::TraderId},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(bid: f64, ask: f64, ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::new(bid, 5).unwrap(),
+            Price::new(ask, 5).unwrap(),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+        .unwrap()
+    }
+
+    fn boundary(conflator: &QuoteConflator, ts: u64) -> TimeEvent {
+        TimeEvent::new(
+            conflator.timer_name(),
+            UUID4::new(),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    /// Handles a burst of 10k quotes oscillating around 0.80000 within one interval, with a bid
+    /// low of 0.79000 and an ask high of 0.81050 in the middle of the burst.
+    fn handle_burst(conflator: &mut QuoteConflator) {
+        for i in 0..10_000_u64 {
+            let offset = (i % 7) as f64 * 0.00001;
+            let (bid, ask) = match i {
+                4_000 => (0.79000, 0.79002),
+                6_000 => (0.81048, 0.81050),
+                _ => (0.80000 + offset, 0.80002 + offset),
+            };
+            conflator.handle_quote(&quote(bid, ask, i));
+        }
+    }
+
+    #[rstest]
+    fn test_new_with_zero_interval() {
+        assert!(QuoteConflator::new("DASHBOARD", 0, ConflationPolicy::Latest).is_err());
+    }
+
+    #[rstest]
+    fn test_latest_emits_once_per_interval() {
+        let mut conflator =
+            QuoteConflator::new("DASHBOARD", 1_000_000, ConflationPolicy::Latest).unwrap();
+        handle_burst(&mut conflator);
+
+        let outputs = conflator.on_time_event(&boundary(&conflator, 1_000_000));
+
+        assert_eq!(
+            outputs,
+            vec![ConflatedQuote::Quote(quote(0.80003, 0.80005, 9_999))]
+        );
+        let stats = conflator.total_stats();
+        assert_eq!(stats.received, 10_000);
+        assert_eq!(stats.emitted, 1);
+        assert_eq!(stats.ratio(), Some(10_000.0));
+    }
+
+    #[rstest]
+    fn test_ohlc_tracks_extremes() {
+        let mut conflator =
+            QuoteConflator::new("DASHBOARD", 1_000_000, ConflationPolicy::Ohlc).unwrap();
+        handle_burst(&mut conflator);
+
+        let outputs = conflator.on_time_event(&boundary(&conflator, 1_000_000));
+
+        assert_eq!(outputs.len(), 1);
+        let ConflatedQuote::Summary(summary) = &outputs[0] else {
+            panic!("Expected summary, was {:?}", outputs[0]);
+        };
+        assert_eq!(summary.open, quote(0.80000, 0.80002, 0));
+        assert_eq!(summary.close, quote(0.80003, 0.80005, 9_999));
+        assert_eq!(summary.bid_high, Price::from("0.81048"));
+        assert_eq!(summary.bid_low, Price::from("0.79000"));
+        assert_eq!(summary.ask_high, Price::from("0.81050"));
+        assert_eq!(summary.ask_low, Price::from("0.79002"));
+        assert_eq!(summary.count, 10_000);
+    }
+
+    #[rstest]
+    fn test_significant_emits_only_on_mid_move() {
+        let policy = ConflationPolicy::Significant { min_ticks: 5 };
+        let mut conflator = QuoteConflator::new("DASHBOARD", 1_000_000, policy).unwrap();
+        handle_burst(&mut conflator);
+
+        let first = conflator.on_time_event(&boundary(&conflator, 1_000_000));
+        conflator.handle_quote(&quote(0.80008, 0.80010, 1_000_001));
+        let within_threshold = conflator.on_time_event(&boundary(&conflator, 2_000_000));
+        conflator.handle_quote(&quote(0.80010, 0.80012, 2_000_001));
+        let beyond_threshold = conflator.on_time_event(&boundary(&conflator, 3_000_000));
+
+        assert_eq!(first.len(), 1);
+        assert!(within_threshold.is_empty());
+        assert_eq!(
+            beyond_threshold,
+            vec![ConflatedQuote::Quote(quote(0.80010, 0.80012, 2_000_001))]
+        );
+        assert_eq!(conflator.total_stats().emitted, 2);
+    }
+
+    #[rstest]
+    fn test_boundary_with_no_input_flushes_nothing() {
+        let mut conflator =
+            QuoteConflator::new("DASHBOARD", 1_000_000, ConflationPolicy::Latest).unwrap();
+        conflator.handle_quote(&quote(0.80000, 0.80002, 1));
+
+        let first = conflator.on_time_event(&boundary(&conflator, 1_000_000));
+        let second = conflator.on_time_event(&boundary(&conflator, 2_000_000));
+
+        assert_eq!(first.len(), 1);
+        assert!(second.is_empty());
+        assert_eq!(conflator.total_stats().ratio(), Some(1.0));
+    }
+
+    #[rstest]
+    fn test_other_timer_events_are_ignored() {
+        let mut conflator =
+            QuoteConflator::new("DASHBOARD", 1_000_000, ConflationPolicy::Latest).unwrap();
+        conflator.handle_quote(&quote(0.80000, 0.80002, 1));
+        let event = TimeEvent::new(
+            Ustr::from("OTHER"),
+            UUID4::new(),
+            UnixNanos::from(1_000_000),
+            UnixNanos::from(1_000_000),
+        );
+
+        assert!(conflator.on_time_event(&event).is_empty());
+        assert_eq!(conflator.flush().len(), 1);
+    }
+
+    #[rstest]
+    fn test_subscription_conflates_quotes_published_on_msgbus() {
+        let msgbus = Rc::new(RefCell::new(
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap(),
+        ));
+        let published = Arc::new(Mutex::new(Vec::new()));
+        let published_clone = published.clone();
+        msgbus.borrow_mut().subscribe(
+            "conflated.quotes",
+            MessageHandler::with_bus_callback(
+                Ustr::from("dashboard"),
+                SafeBusMessageCallback {
+                    callback: Arc::new(move |message: &BusMessage| {
+                        published_clone.lock().unwrap().push(message.clone());
+                    }),
+                },
+            ),
+            None,
+        );
+        let conflator =
+            QuoteConflator::new("DASHBOARD", 1_000_000, ConflationPolicy::Latest).unwrap();
+        let subscription = ConflatedQuoteSubscription::subscribe(
+            msgbus.clone(),
+            "data.quotes.*",
+            "conflated.quotes",
+            conflator,
+        );
+
+        for i in 0..1_000_u64 {
+            let payload = serde_json::to_vec(&quote(0.80000, 0.80002, i)).unwrap();
+            msgbus
+                .borrow_mut()
+                .publish("data.quotes.SIM.AUD/USD", payload);
+        }
+        let event = boundary(&subscription.conflator(), 1_000_000);
+        let count = subscription.on_time_event(&event);
+
+        assert_eq!(count, 1);
+        let published = published.lock().unwrap();
+        assert_eq!(published.len(), 1);
+        let output: ConflatedQuote = serde_json::from_slice(&published[0].payload).unwrap();
+        assert_eq!(output, ConflatedQuote::Quote(quote(0.80000, 0.80002, 999)));
+        assert_eq!(subscription.conflator().total_stats().received, 1_000);
+
+        subscription.unsubscribe();
+        assert_eq!(msgbus.borrow().topics(), vec!["conflated.quotes"]);
+    }
+}
@@ -318,3 +318,33 @@ pub enum SerializationEncoding {
     #[serde(rename = "json")]
     Json = 1,
 }
+
+/// The policy for handling an order event which duplicates an event already applied.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    FromRepr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum DuplicateEventPolicy {
+    /// Drop the duplicate event silently.
+    Drop,
+    /// Drop the duplicate event and log a warning.
+    #[default]
+    DropAndWarn,
+    /// Return an error.
+    Error,
+}
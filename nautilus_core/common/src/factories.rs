@@ -25,8 +25,9 @@ use nautilus_model::{
         instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
         trader_id::TraderId,
     },
+    instruments::any::InstrumentAny,
     orders::market::MarketOrder,
-    types::quantity::Quantity,
+    types::{money::Money, price::Price, quantity::Quantity},
 };
 use ustr::Ustr;
 
@@ -135,6 +136,43 @@ impl OrderFactory {
         )
         .unwrap()
     }
+
+    /// Creates a market order sized to the given `notional` at the given reference `price`.
+    ///
+    /// The quantity is rounded down to the instrument size increment, and the requested
+    /// notional and reference price are recorded in the order tags.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the notional cannot be converted to a valid quantity
+    /// for the instrument.
+    #[allow(clippy::too_many_arguments)]
+    pub fn market_notional(
+        &mut self,
+        instrument: &InstrumentAny,
+        order_side: OrderSide,
+        notional: Money,
+        price: Price,
+        time_in_force: Option<TimeInForce>,
+        reduce_only: Option<bool>,
+        tags: Option<Vec<Ustr>>,
+    ) -> anyhow::Result<MarketOrder> {
+        let quantity = instrument.calculate_base_quantity(notional, price)?;
+        let mut tags = tags.unwrap_or_default();
+        tags.push(Ustr::from(&format!("notional={notional}")));
+        tags.push(Ustr::from(&format!("notional_px={price}")));
+        Ok(self.market(
+            instrument.id(),
+            order_side,
+            quantity,
+            time_in_force,
+            reduce_only,
+            Some(false),
+            None,
+            None,
+            Some(tags),
+        ))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -147,8 +185,11 @@ pub mod tests {
         identifiers::{
             client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
         },
+        instruments::{any::InstrumentAny, stubs::currency_pair_btcusdt},
+        types::{money::Money, price::Price, quantity::Quantity},
     };
     use rstest::rstest;
+    use ustr::Ustr;
 
     use crate::{factories::OrderFactory, stubs::order_factory};
 
@@ -236,4 +277,51 @@ pub mod tests {
         );
         assert_eq!(market_order.order_list_id, None);
     }
+
+    #[rstest]
+    fn test_market_notional_order(mut order_factory: OrderFactory) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt());
+        let market_order = order_factory
+            .market_notional(
+                &instrument,
+                OrderSide::Buy,
+                Money::from("500 USDT"),
+                Price::from("60000.00"),
+                None,
+                None,
+                Some(vec![Ustr::from("entry")]),
+            )
+            .unwrap();
+        assert_eq!(market_order.instrument_id, instrument.id());
+        assert_eq!(market_order.quantity, Quantity::from("0.008333"));
+        assert!(!market_order.is_quote_quantity);
+        assert_eq!(
+            market_order.tags,
+            Some(vec![
+                Ustr::from("entry"),
+                Ustr::from("notional=500.00000000 USDT"),
+                Ustr::from("notional_px=60000.00"),
+            ])
+        );
+    }
+
+    #[rstest]
+    fn test_market_notional_order_below_min_quantity(mut order_factory: OrderFactory) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt());
+        let result = order_factory.market_notional(
+            &instrument,
+            OrderSide::Sell,
+            Money::from("0.01 USDT"),
+            Price::from("60000.00"),
+            None,
+            None,
+            None,
+        );
+        assert!(result.is_err());
+        // No client order ID is consumed by a rejected conversion
+        assert_eq!(
+            order_factory.generate_client_order_id(),
+            ClientOrderId::new("O-19700101-0000-001-001-1").unwrap()
+        );
+    }
 }
@@ -92,6 +92,11 @@ pub extern "C" fn test_clock_set_time(clock: &TestClock_API, to_time_ns: u64) {
     clock.set_time(to_time_ns.into());
 }
 
+#[no_mangle]
+pub extern "C" fn test_clock_set_schedule_end(clock: &mut TestClock_API, end_ns: u64) {
+    clock.set_schedule_end(end_ns.into());
+}
+
 #[no_mangle]
 pub extern "C" fn test_clock_timestamp(clock: &TestClock_API) -> f64 {
     clock.get_time()
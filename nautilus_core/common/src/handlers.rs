@@ -25,7 +25,7 @@ use nautilus_core::message::Message;
 use pyo3::prelude::*;
 use ustr::Ustr;
 
-use crate::timer::TimeEvent;
+use crate::{messages::DataResponse, msgbus::BusMessage, timer::TimeEvent};
 
 #[allow(dead_code)]
 #[derive(Clone)]
@@ -39,6 +39,22 @@ pub struct SafeMessageCallback {
 unsafe impl Send for SafeMessageCallback {}
 unsafe impl Sync for SafeMessageCallback {}
 
+#[derive(Clone)]
+pub struct SafeBusMessageCallback {
+    pub callback: Arc<dyn Fn(&BusMessage) + Send>,
+}
+
+unsafe impl Send for SafeBusMessageCallback {}
+unsafe impl Sync for SafeBusMessageCallback {}
+
+#[derive(Clone)]
+pub struct SafeDataResponseCallback {
+    pub callback: Arc<dyn Fn(DataResponse) + Send>,
+}
+
+unsafe impl Send for SafeDataResponseCallback {}
+unsafe impl Sync for SafeDataResponseCallback {}
+
 #[derive(Clone)]
 pub struct SafeTimeEventCallback {
     pub callback: Arc<dyn Fn(TimeEvent) + Send>,
@@ -56,6 +72,8 @@ unsafe impl Sync for SafeTimeEventCallback {}
 pub struct MessageHandler {
     pub handler_id: Ustr,
     _callback: Option<SafeMessageCallback>,
+    bus_callback: Option<SafeBusMessageCallback>,
+    response_callback: Option<SafeDataResponseCallback>,
 }
 
 impl MessageHandler {
@@ -64,6 +82,44 @@ impl MessageHandler {
         Self {
             handler_id,
             _callback: callback,
+            bus_callback: None,
+            response_callback: None,
+        }
+    }
+
+    /// Creates a new handler which receives the messages published on the bus in-process.
+    #[must_use]
+    pub fn with_bus_callback(handler_id: Ustr, callback: SafeBusMessageCallback) -> Self {
+        Self {
+            handler_id,
+            _callback: None,
+            bus_callback: Some(callback),
+            response_callback: None,
+        }
+    }
+
+    /// Creates a new handler which receives the data responses delivered on the bus in-process.
+    #[must_use]
+    pub fn with_response_callback(handler_id: Ustr, callback: SafeDataResponseCallback) -> Self {
+        Self {
+            handler_id,
+            _callback: None,
+            bus_callback: None,
+            response_callback: Some(callback),
+        }
+    }
+
+    /// Handles the given published `message` (a no-op if the handler has no bus callback).
+    pub fn handle_bus_message(&self, message: &BusMessage) {
+        if let Some(bus_callback) = &self.bus_callback {
+            (bus_callback.callback)(message);
+        }
+    }
+
+    /// Handles the given data `response` (a no-op if the handler has no response callback).
+    pub fn handle_response(&self, response: DataResponse) {
+        if let Some(response_callback) = &self.response_callback {
+            (response_callback.callback)(response);
         }
     }
 }
@@ -29,17 +29,26 @@
 //! - `stubs`: Enables type stubs for use in testing scenarios
 
 pub mod cache;
+pub mod calendar;
 pub mod clock;
+pub mod component;
+pub mod conflation;
 pub mod enums;
 pub mod factories;
 pub mod generators;
 pub mod handlers;
 pub mod interface;
 pub mod logging;
+pub mod messages;
 pub mod msgbus;
+pub mod replay;
 pub mod runtime;
+pub mod spsc;
+pub mod subscriptions;
+pub mod symbology;
 pub mod testing;
 pub mod timer;
+pub mod volume_profile;
 pub mod xrate;
 
 #[cfg(feature = "stubs")]
@@ -0,0 +1,93 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Defines common message types for requests and responses.
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    data::Data,
+    identifiers::{client_id::ClientId, instrument_id::InstrumentId, venue::Venue},
+    instruments::any::InstrumentFieldChange,
+};
+
+/// Represents a response to a data request, correlated with the request by its ID.
+#[derive(Clone, Debug)]
+pub struct DataResponse {
+    /// The ID of the request the response is for.
+    pub correlation_id: UUID4,
+    /// The data client which serviced the request.
+    pub client_id: ClientId,
+    /// The venue for the response.
+    pub venue: Venue,
+    /// The data returned for the request.
+    pub data: Vec<Data>,
+    /// The unique ID of the response.
+    pub id: UUID4,
+    /// The UNIX timestamp (nanoseconds) when the response was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl DataResponse {
+    #[must_use]
+    pub fn new(
+        correlation_id: UUID4,
+        client_id: ClientId,
+        venue: Venue,
+        data: Vec<Data>,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            correlation_id,
+            client_id,
+            venue,
+            data,
+            id: UUID4::new(),
+            ts_init,
+        }
+    }
+}
+
+/// Represents a system event published when a new version of an instrument is added to the
+/// cache with changed specification fields (e.g. an updated fee or margin schedule).
+#[derive(Clone, Debug)]
+pub struct InstrumentUpdated {
+    pub instrument_id: InstrumentId,
+    /// The fields which changed from the previous latest version.
+    pub changes: Vec<InstrumentFieldChange>,
+    /// The number of versions of the instrument, including the new version.
+    pub version_count: usize,
+    /// The unique ID of the event.
+    pub id: UUID4,
+    /// The UNIX timestamp (nanoseconds) when the new version was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl InstrumentUpdated {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        changes: Vec<InstrumentFieldChange>,
+        version_count: usize,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            changes,
+            version_count,
+            id: UUID4::new(),
+            ts_init,
+        }
+    }
+}
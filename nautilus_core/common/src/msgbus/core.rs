@@ -26,7 +26,7 @@ use nautilus_model::identifiers::trader_id::TraderId;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use crate::handlers::MessageHandler;
+use crate::{handlers::MessageHandler, messages::DataResponse};
 
 pub const CLOSE_TOPIC: &str = "CLOSE";
 
@@ -295,6 +295,29 @@ impl MessageBus {
         self.subscriptions.insert(sub, matches);
     }
 
+    /// Publishes the `payload` on the given `topic`.
+    ///
+    /// The message is delivered in priority order to every subscription whose topic (which
+    /// may contain wildcards) matches the published `topic`.
+    pub fn publish(&mut self, topic: &str, payload: Vec<u8>) {
+        let message = BusMessage {
+            topic: topic.to_string(),
+            payload,
+        };
+        let topic = Ustr::from(topic);
+        let mut matching_subs: Vec<&Subscription> = self
+            .subscriptions
+            .keys()
+            .filter(|sub| is_matching(&topic, &sub.topic))
+            .collect();
+        matching_subs.sort();
+
+        for sub in matching_subs {
+            sub.handler.handle_bus_message(&message);
+        }
+        self.pub_count += 1;
+    }
+
     /// Unsubscribes the given `handler` from the `topic`.
     pub fn unsubscribe(&mut self, topic: &str, handler: MessageHandler) {
         let sub = Subscription::new(Ustr::from(topic), handler, self.subscriptions.len(), None);
@@ -337,6 +360,19 @@ impl MessageBus {
         self.correlation_index.shift_remove(correlation_id)
     }
 
+    /// Delivers the data `response` to the handler registered for its correlation ID by
+    /// [`Self::request_handler`], returning whether a handler was found.
+    ///
+    /// This is the path on which the data engine returns the responses to data requests.
+    pub fn response(&mut self, response: DataResponse) -> bool {
+        let Some(handler) = self.response_handler(&response.correlation_id) else {
+            return false;
+        };
+        handler.handle_response(response);
+        self.res_count += 1;
+        true
+    }
+
     #[must_use]
     pub fn matching_subscriptions<'a>(&'a self, pattern: &'a Ustr) -> Vec<&'a Subscription> {
         let mut matching_subs: Vec<&'a Subscription> = Vec::new();
@@ -20,6 +20,7 @@
 pub mod clock;
 pub mod enums;
 pub mod logging;
+pub mod requests;
 pub mod timer;
 pub mod versioning;
 
@@ -36,6 +37,8 @@ pub fn common(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<crate::logging::logger::LoggerConfig>()?;
     m.add_class::<crate::logging::logger::LogGuard>()?;
     m.add_class::<crate::logging::writer::FileWriterConfig>()?;
+    m.add_class::<requests::DataRequestBridge>()?;
+    m.add_class::<requests::DataRequestFuture>()?;
     m.add_function(wrap_pyfunction!(logging::py_init_tracing, m)?)?;
     m.add_function(wrap_pyfunction!(logging::py_init_logging, m)?)?;
     m.add_function(wrap_pyfunction!(logging::py_logger_log, m)?)?;
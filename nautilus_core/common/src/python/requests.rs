@@ -0,0 +1,656 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a bridge for Python strategies to `await` data requests serviced in Rust.
+//!
+//! A request is registered with a correlation ID and returns a `DataRequestFuture`, which wraps
+//! an `asyncio.Future` on the running event loop. When the `DataResponse` for the correlation ID
+//! arrives it resolves the future from any thread, through the loop's `call_soon_threadsafe`.
+//! Requests which time out raise `TimeoutError`, and requests canceled from either side raise
+//! `asyncio.CancelledError`.
+//!
+//! Requests made through the `MessageBus` are resolved by the responses the data engine returns
+//! with `MessageBus::response`.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use log::error;
+use nautilus_core::{python::to_pyvalue_err, uuid::UUID4};
+use nautilus_model::python::data::data_to_pyobject;
+use pyo3::{
+    exceptions::PyTimeoutError,
+    prelude::*,
+    types::{PyCFunction, PyDict, PyList, PyTuple},
+};
+use ustr::Ustr;
+
+use crate::{
+    handlers::{MessageHandler, SafeDataResponseCallback},
+    messages::DataResponse,
+    msgbus::MessageBus,
+};
+
+struct PendingDataRequest {
+    future: PyObject,
+    event_loop: PyObject,
+}
+
+type PendingDataRequests = Arc<Mutex<HashMap<UUID4, PendingDataRequest>>>;
+
+/// Removes the pending request for the `correlation_id`, if it is still for the given `future`.
+fn take_pending(
+    pending: &PendingDataRequests,
+    correlation_id: &UUID4,
+    future: &PyAny,
+) -> Option<PendingDataRequest> {
+    let mut pending = pending
+        .lock()
+        .expect("Failed to lock pending data requests");
+    let is_same = pending
+        .get(correlation_id)
+        .is_some_and(|request| request.future.as_ptr() == future.as_ptr());
+    if is_same {
+        pending.remove(correlation_id)
+    } else {
+        None
+    }
+}
+
+/// Provides data requests which Python can `await`, resolved by their `DataResponse`.
+///
+/// The bridge is cheaply cloneable, with clones sharing the same pending requests, so the data
+/// layer can resolve requests registered from Python.
+#[derive(Clone, Default)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.common")]
+pub struct DataRequestBridge {
+    pending: PendingDataRequests,
+}
+
+impl DataRequestBridge {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the count of requests awaiting a response.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns whether a request with the given `correlation_id` is awaiting a response.
+    #[must_use]
+    pub fn is_pending(&self, correlation_id: &UUID4) -> bool {
+        self.lock().contains_key(correlation_id)
+    }
+
+    /// Registers a request with the given `correlation_id` on the running event loop, returning
+    /// the future which resolves with the data of its response.
+    ///
+    /// If `timeout_secs` is given, the future raises `TimeoutError` if no response has arrived
+    /// within the timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no event loop is running, or a request with the `correlation_id` is
+    /// already pending.
+    pub fn register(
+        &self,
+        py: Python<'_>,
+        correlation_id: UUID4,
+        timeout_secs: Option<f64>,
+    ) -> PyResult<DataRequestFuture> {
+        let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+        let future = event_loop.call_method0("create_future")?;
+        {
+            let mut pending = self.lock();
+            if pending.contains_key(&correlation_id) {
+                return Err(to_pyvalue_err(format!(
+                    "Data request {correlation_id} already pending"
+                )));
+            }
+            pending.insert(
+                correlation_id,
+                PendingDataRequest {
+                    future: future.into(),
+                    event_loop: event_loop.into(),
+                },
+            );
+        }
+
+        // Discards the request once done, which includes cancellation of the awaiting task
+        let pending = self.pending.clone();
+        let on_done = PyCFunction::new_closure(
+            py,
+            None,
+            None,
+            move |args: &PyTuple, _kwargs: Option<&PyDict>| -> PyResult<()> {
+                take_pending(&pending, &correlation_id, args.get_item(0)?);
+                Ok(())
+            },
+        )?;
+        future.call_method1("add_done_callback", (on_done,))?;
+
+        if let Some(timeout_secs) = timeout_secs {
+            let pending = self.pending.clone();
+            let timeout_future: PyObject = future.into();
+            let on_timeout = PyCFunction::new_closure(
+                py,
+                None,
+                None,
+                move |args: &PyTuple, _kwargs: Option<&PyDict>| -> PyResult<()> {
+                    let future = timeout_future.as_ref(args.py());
+                    if take_pending(&pending, &correlation_id, future).is_some() {
+                        let err = PyTimeoutError::new_err(format!(
+                            "Data request {correlation_id} timed out after {timeout_secs}s"
+                        ));
+                        future.call_method1("set_exception", (err.value(args.py()),))?;
+                    }
+                    Ok(())
+                },
+            )?;
+            event_loop.call_method1("call_later", (timeout_secs, on_timeout))?;
+        }
+
+        Ok(DataRequestFuture {
+            correlation_id,
+            future: future.into(),
+        })
+    }
+
+    /// Registers a request with the given `correlation_id` (as [`Self::register`]) and
+    /// subscribes the bridge on the `msgbus` for its response from the request `endpoint`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no handler is registered for the `endpoint`, or if registering the
+    /// request fails.
+    pub fn request(
+        &self,
+        py: Python<'_>,
+        msgbus: &mut MessageBus,
+        endpoint: &str,
+        correlation_id: UUID4,
+        timeout_secs: Option<f64>,
+    ) -> PyResult<DataRequestFuture> {
+        let endpoint = Ustr::from(endpoint);
+        if msgbus.get_endpoint(&endpoint).is_none() {
+            return Err(to_pyvalue_err(format!(
+                "No handler registered for endpoint {endpoint}"
+            )));
+        }
+
+        let future = self.register(py, correlation_id, timeout_secs)?;
+        let _ = msgbus.request_handler(&endpoint, correlation_id, self.response_handler());
+        Ok(future)
+    }
+
+    /// Returns a message handler which resolves the pending requests with the responses
+    /// delivered to it.
+    #[must_use]
+    pub fn response_handler(&self) -> MessageHandler {
+        let bridge = self.clone();
+        MessageHandler::with_response_callback(
+            Ustr::from(stringify!(DataRequestBridge)),
+            SafeDataResponseCallback {
+                callback: Arc::new(move |response| {
+                    bridge.resolve(response);
+                }),
+            },
+        )
+    }
+
+    /// Resolves the pending request correlated with the given `response`, from any thread.
+    ///
+    /// Returns whether a pending request was resolved, responses for unknown, timed out or
+    /// canceled requests are ignored.
+    pub fn resolve(&self, response: DataResponse) -> bool {
+        let Some(request) = self.lock().remove(&response.correlation_id) else {
+            return false;
+        };
+
+        Python::with_gil(|py| {
+            let data: PyObject = PyList::new(
+                py,
+                response
+                    .data
+                    .into_iter()
+                    .map(|data| data_to_pyobject(py, data)),
+            )
+            .into();
+            let future = request.future.clone_ref(py);
+            let set_result = PyCFunction::new_closure(
+                py,
+                None,
+                None,
+                move |args: &PyTuple, _kwargs: Option<&PyDict>| -> PyResult<()> {
+                    let future = future.as_ref(args.py());
+                    // The awaiting task may have been canceled in the meantime
+                    if !future.call_method0("done")?.is_true()? {
+                        future.call_method1("set_result", (data.clone_ref(args.py()),))?;
+                    }
+                    Ok(())
+                },
+            );
+            match set_result {
+                Ok(set_result) => call_soon_threadsafe(py, &request, set_result),
+                Err(e) => {
+                    error!(
+                        "Error resolving data request {}: {e}",
+                        response.correlation_id
+                    );
+                    false
+                }
+            }
+        })
+    }
+
+    /// Cancels the pending request for the given `correlation_id`, from any thread.
+    ///
+    /// The awaiting task receives `asyncio.CancelledError`. Returns whether a pending request
+    /// was canceled.
+    pub fn cancel(&self, correlation_id: &UUID4) -> bool {
+        let Some(request) = self.lock().remove(correlation_id) else {
+            return false;
+        };
+
+        Python::with_gil(|py| match request.future.getattr(py, "cancel") {
+            Ok(cancel) => call_soon_threadsafe(py, &request, cancel.as_ref(py)),
+            Err(e) => {
+                error!("Error canceling data request {correlation_id}: {e}");
+                false
+            }
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<UUID4, PendingDataRequest>> {
+        self.pending
+            .lock()
+            .expect("Failed to lock pending data requests")
+    }
+}
+
+/// Schedules the `callback` on the event loop of the `request`, returning whether scheduled.
+fn call_soon_threadsafe(py: Python<'_>, request: &PendingDataRequest, callback: &PyAny) -> bool {
+    match request
+        .event_loop
+        .call_method1(py, "call_soon_threadsafe", (callback,))
+    {
+        Ok(_) => true,
+        Err(e) => {
+            error!("Error scheduling on event loop: {e}"); // Event loop may be closed
+            false
+        }
+    }
+}
+
+#[pymethods]
+impl DataRequestBridge {
+    #[new]
+    fn py_new() -> Self {
+        Self::new()
+    }
+
+    #[pyo3(name = "request")]
+    fn py_request(
+        &self,
+        py: Python<'_>,
+        correlation_id: Option<UUID4>,
+        timeout_secs: Option<f64>,
+    ) -> PyResult<DataRequestFuture> {
+        self.register(py, correlation_id.unwrap_or_default(), timeout_secs)
+    }
+
+    #[pyo3(name = "cancel")]
+    fn py_cancel(&self, correlation_id: UUID4) -> bool {
+        self.cancel(&correlation_id)
+    }
+
+    #[pyo3(name = "is_pending")]
+    fn py_is_pending(&self, correlation_id: UUID4) -> bool {
+        self.is_pending(&correlation_id)
+    }
+
+    #[getter]
+    #[pyo3(name = "pending_count")]
+    fn py_pending_count(&self) -> usize {
+        self.pending_count()
+    }
+}
+
+/// Represents an awaitable data request, resolving with the data of its response.
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.common")]
+pub struct DataRequestFuture {
+    correlation_id: UUID4,
+    future: PyObject,
+}
+
+#[pymethods]
+impl DataRequestFuture {
+    #[getter]
+    fn correlation_id(&self) -> UUID4 {
+        self.correlation_id
+    }
+
+    fn done(&self, py: Python<'_>) -> PyResult<bool> {
+        self.future.call_method0(py, "done")?.extract(py)
+    }
+
+    fn cancel(&self, py: Python<'_>) -> PyResult<bool> {
+        self.future.call_method0(py, "cancel")?.extract(py)
+    }
+
+    fn __await__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.future.call_method0(py, "__await__")
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "{}(correlation_id={})",
+            stringify!(DataRequestFuture),
+            self.correlation_id
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::{
+        data::{
+            bar::{Bar, BarType},
+            Data,
+        },
+        identifiers::{client_id::ClientId, trader_id::TraderId, venue::Venue},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const HARNESS: &str = r"
+import asyncio
+
+async def request(bridge, responder, timeout_secs):
+    future = bridge.request(None, timeout_secs)
+    responder(future.correlation_id)
+    try:
+        return await future
+    except asyncio.CancelledError:
+        return 'cancelled'
+    except TimeoutError:
+        return 'timeout'
+
+async def cancel_task(bridge):
+    future = bridge.request()
+
+    async def wait():
+        return await future
+
+    task = asyncio.ensure_future(wait())
+    await asyncio.sleep(0)
+    task.cancel()
+    try:
+        await task
+    except asyncio.CancelledError:
+        pass
+    await asyncio.sleep(0)
+    return future.done()
+
+async def await_request(make_request):
+    return await make_request()
+
+def run(coro):
+    loop = asyncio.new_event_loop()
+    try:
+        return loop.run_until_complete(coro)
+    finally:
+        loop.close()
+";
+
+    fn bars() -> Vec<Data> {
+        let bar_type = BarType::from("AUDUSD.SIM-1-MINUTE-BID-EXTERNAL");
+        (1..=2)
+            .map(|i| {
+                Data::Bar(Bar::new(
+                    bar_type,
+                    Price::from("1.00001"),
+                    Price::from("1.00004"),
+                    Price::from("1.00000"),
+                    Price::from("1.00002"),
+                    Quantity::from(100_000),
+                    UnixNanos::from(i),
+                    UnixNanos::from(i),
+                ))
+            })
+            .collect()
+    }
+
+    fn response(correlation_id: UUID4) -> DataResponse {
+        DataResponse::new(
+            correlation_id,
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            bars(),
+            UnixNanos::default(),
+        )
+    }
+
+    /// Runs the `request` coroutine of the harness, with a responder which calls `respond` with
+    /// the correlation ID from another thread.
+    fn run_request(
+        bridge: &DataRequestBridge,
+        timeout_secs: Option<f64>,
+        respond: fn(&DataRequestBridge, UUID4),
+    ) -> (PyObject, Option<UUID4>) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let harness = PyModule::from_code(py, HARNESS, "", "").unwrap();
+            let correlation_ids = Arc::new(Mutex::new(Vec::new()));
+            let responder_bridge = bridge.clone();
+            let responder_ids = correlation_ids.clone();
+            let responder = PyCFunction::new_closure(
+                py,
+                None,
+                None,
+                move |args: &PyTuple, _kwargs: Option<&PyDict>| -> PyResult<()> {
+                    let correlation_id: UUID4 = args.get_item(0)?.extract()?;
+                    responder_ids.lock().unwrap().push(correlation_id);
+                    let bridge = responder_bridge.clone();
+                    thread::spawn(move || {
+                        thread::sleep(Duration::from_millis(10));
+                        respond(&bridge, correlation_id);
+                    });
+                    Ok(())
+                },
+            )
+            .unwrap();
+
+            let coro = harness
+                .getattr("request")
+                .unwrap()
+                .call1((
+                    Py::new(py, bridge.clone()).unwrap(),
+                    responder,
+                    timeout_secs,
+                ))
+                .unwrap();
+            let result = harness.getattr("run").unwrap().call1((coro,)).unwrap();
+            let correlation_id = correlation_ids.lock().unwrap().first().copied();
+            (result.into(), correlation_id)
+        })
+    }
+
+    #[rstest]
+    fn test_request_resolves_with_response_data() {
+        let bridge = DataRequestBridge::new();
+
+        let (result, correlation_id) = run_request(&bridge, Some(5.0), |bridge, id| {
+            assert!(bridge.resolve(response(id)));
+        });
+
+        Python::with_gil(|py| {
+            let bars: Vec<Bar> = result.extract(py).unwrap();
+            assert_eq!(bars.len(), 2);
+            assert_eq!(bars[1].ts_event, UnixNanos::from(2));
+        });
+        assert!(!bridge.is_pending(&correlation_id.unwrap()));
+        assert_eq!(bridge.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_request_times_out_and_ignores_late_response() {
+        let bridge = DataRequestBridge::new();
+
+        let (result, correlation_id) = run_request(&bridge, Some(0.001), |_, _| {});
+
+        Python::with_gil(|py| {
+            assert_eq!(result.extract::<String>(py).unwrap(), "timeout");
+        });
+        assert_eq!(bridge.pending_count(), 0);
+        assert!(!bridge.resolve(response(correlation_id.unwrap())));
+    }
+
+    #[rstest]
+    fn test_cancel_raises_cancelled_error() {
+        let bridge = DataRequestBridge::new();
+
+        let (result, correlation_id) = run_request(&bridge, None, |bridge, id| {
+            assert!(bridge.cancel(&id));
+        });
+
+        Python::with_gil(|py| {
+            assert_eq!(result.extract::<String>(py).unwrap(), "cancelled");
+        });
+        assert_eq!(bridge.pending_count(), 0);
+        assert!(!bridge.cancel(&correlation_id.unwrap()));
+    }
+
+    #[rstest]
+    fn test_task_cancellation_discards_pending_request() {
+        pyo3::prepare_freethreaded_python();
+        let bridge = DataRequestBridge::new();
+
+        Python::with_gil(|py| {
+            let harness = PyModule::from_code(py, HARNESS, "", "").unwrap();
+            let coro = harness
+                .getattr("cancel_task")
+                .unwrap()
+                .call1((Py::new(py, bridge.clone()).unwrap(),))
+                .unwrap();
+            let done = harness.getattr("run").unwrap().call1((coro,)).unwrap();
+            assert!(done.extract::<bool>().unwrap());
+        });
+        assert_eq!(bridge.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_register_requires_running_event_loop() {
+        pyo3::prepare_freethreaded_python();
+        let bridge = DataRequestBridge::new();
+
+        Python::with_gil(|py| {
+            assert!(bridge.register(py, UUID4::new(), None).is_err());
+        });
+        assert_eq!(bridge.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_request_on_msgbus_resolves_with_data_engine_response() {
+        pyo3::prepare_freethreaded_python();
+        let bridge = DataRequestBridge::new();
+        let endpoint = "DataEngine.request";
+        let mut msgbus =
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap();
+        msgbus.register(
+            endpoint,
+            MessageHandler::new(Ustr::from("DataEngine"), None),
+        );
+        let msgbus = Arc::new(Mutex::new(msgbus));
+
+        let result = Python::with_gil(|py| {
+            let harness = PyModule::from_code(py, HARNESS, "", "").unwrap();
+            let request_bridge = bridge.clone();
+            let request_msgbus = msgbus.clone();
+            let make_request = PyCFunction::new_closure(
+                py,
+                None,
+                None,
+                move |args: &PyTuple, _kwargs: Option<&PyDict>| -> PyResult<DataRequestFuture> {
+                    let correlation_id = UUID4::new();
+                    let future = request_bridge.request(
+                        args.py(),
+                        &mut request_msgbus.lock().unwrap(),
+                        endpoint,
+                        correlation_id,
+                        Some(5.0),
+                    )?;
+                    // The data engine services the request and responds from its own thread
+                    let msgbus = request_msgbus.clone();
+                    thread::spawn(move || {
+                        thread::sleep(Duration::from_millis(10));
+                        assert!(msgbus.lock().unwrap().response(response(correlation_id)));
+                    });
+                    Ok(future)
+                },
+            )
+            .unwrap();
+
+            let coro = harness
+                .getattr("await_request")
+                .unwrap()
+                .call1((make_request,))
+                .unwrap();
+            let result: PyObject = harness
+                .getattr("run")
+                .unwrap()
+                .call1((coro,))
+                .unwrap()
+                .into();
+            result
+        });
+
+        Python::with_gil(|py| {
+            let bars: Vec<Bar> = result.extract(py).unwrap();
+            assert_eq!(bars.len(), 2);
+        });
+        assert_eq!(bridge.pending_count(), 0);
+        assert_eq!(msgbus.lock().unwrap().res_count, 1);
+    }
+
+    #[rstest]
+    fn test_request_on_msgbus_without_endpoint_errors() {
+        pyo3::prepare_freethreaded_python();
+        let bridge = DataRequestBridge::new();
+        let mut msgbus =
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap();
+
+        Python::with_gil(|py| {
+            assert!(bridge
+                .request(py, &mut msgbus, "DataEngine.request", UUID4::new(), None)
+                .is_err());
+        });
+        assert_eq!(bridge.pending_count(), 0);
+    }
+}
@@ -0,0 +1,691 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `ReplayDriver` for replaying recorded data in scaled real time.
+//!
+//! The driver maps wall clock time onto data time from an anchor point, so that at a `speed` of
+//! 10 one second of wall time replays ten seconds of data. Gaps between events are kept
+//! proportional to the recorded gaps, and pausing or seeking re-anchors the mapping so the
+//! schedule continues from the current data time. A `speed` of zero replays as fast as possible.
+//!
+//! The driver is polled: each `poll` emits every event due at the current clock time to the
+//! handler (typically [`msgbus_handler`], publishing onto the message bus), and `next_due_ns`
+//! gives the time the caller should poll again.
+//!
+//! Data is read from a [`ReplaySource`], such as a closure running a `ParquetDataCatalog::query`
+//! from a given data time. Seeking backwards reopens the source from the seek time.
+//!
+//! # Jitter
+//!
+//! The jitter of an emission is how late it is against its scheduled wall time. If a poll is
+//! later than `max_jitter_ns` for an event (for example after a stall), the schedule is rebased
+//! to that event rather than emitting the backlog in a burst, which keeps the jitter bounded and
+//! the gaps proportional from then on. Jitter and rebases are measured in `ReplayStats`.
+
+use std::{cell::RefCell, iter::Peekable, rc::Rc};
+
+use log::error;
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
+use nautilus_model::{data::Data, polymorphism::GetTsInit};
+
+use crate::msgbus::MessageBus;
+
+/// The handler receiving the replayed data, such as a message bus publisher.
+pub type ReplayHandler = Box<dyn FnMut(Data)>;
+
+/// A stream of data sorted by `ts_init`.
+pub type ReplayStream = Box<dyn Iterator<Item = Data>>;
+
+/// Provides the data replayed by a `ReplayDriver`.
+pub trait ReplaySource {
+    /// Opens the stream of data (sorted by `ts_init`) from the data time `start`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the stream cannot be opened.
+    fn open(&mut self, start: UnixNanos) -> anyhow::Result<ReplayStream>;
+}
+
+impl<F, I> ReplaySource for F
+where
+    F: FnMut(UnixNanos) -> anyhow::Result<I>,
+    I: Iterator<Item = Data> + 'static,
+{
+    fn open(&mut self, start: UnixNanos) -> anyhow::Result<ReplayStream> {
+        Ok(Box::new(self(start)?))
+    }
+}
+
+impl ReplaySource for Vec<Data> {
+    fn open(&mut self, start: UnixNanos) -> anyhow::Result<ReplayStream> {
+        let data = self.clone();
+        Ok(Box::new(
+            data.into_iter().filter(move |data| data.ts_init() >= start),
+        ))
+    }
+}
+
+/// Returns a handler publishing the replayed data as JSON on the message bus, on the
+/// [`data_topic`] for each event.
+#[must_use]
+pub fn msgbus_handler(msgbus: Rc<RefCell<MessageBus>>) -> ReplayHandler {
+    Box::new(move |data: Data| {
+        let payload = match &data {
+            Data::Delta(delta) => serde_json::to_vec(delta),
+            Data::Deltas(deltas) => serde_json::to_vec(&**deltas),
+            Data::Depth10(depth) => serde_json::to_vec(depth),
+            Data::Quote(quote) => serde_json::to_vec(quote),
+            Data::Trade(trade) => serde_json::to_vec(trade),
+            Data::Bar(bar) => serde_json::to_vec(bar),
+            Data::Close(close) => serde_json::to_vec(close),
+        };
+        match payload {
+            Ok(payload) => msgbus.borrow_mut().publish(&data_topic(&data), payload),
+            Err(e) => error!("Cannot serialize replayed data: {e}"),
+        }
+    })
+}
+
+/// Returns the message bus topic the given `data` is published on.
+#[must_use]
+pub fn data_topic(data: &Data) -> String {
+    let (kind, instrument_id) = match data {
+        Data::Delta(delta) => ("book.deltas", delta.instrument_id),
+        Data::Deltas(deltas) => ("book.deltas", deltas.instrument_id),
+        Data::Depth10(depth) => ("book.depth", depth.instrument_id),
+        Data::Quote(quote) => ("quotes", quote.instrument_id),
+        Data::Trade(trade) => ("trades", trade.instrument_id),
+        Data::Bar(bar) => return format!("data.bars.{}", bar.bar_type),
+        Data::Close(close) => ("close", close.instrument_id),
+    };
+    format!(
+        "data.{kind}.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    )
+}
+
+/// Configuration for a `ReplayDriver`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReplayConfig {
+    /// The data time replayed per unit of wall time (zero replays as fast as possible).
+    pub speed: f64,
+    /// The maximum lateness (nanoseconds) of an emission before the schedule is rebased.
+    pub max_jitter_ns: u64,
+}
+
+impl Default for ReplayConfig {
+    /// Creates a new default [`ReplayConfig`] instance (real time, 10ms jitter bound).
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            max_jitter_ns: 10_000_000,
+        }
+    }
+}
+
+/// Represents the emission statistics of a replay.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReplayStats {
+    /// The count of events emitted.
+    pub emitted: u64,
+    /// The count of events skipped by seeks.
+    pub skipped: u64,
+    /// The maximum emission jitter (nanoseconds).
+    pub max_jitter_ns: u64,
+    /// The total emission jitter (nanoseconds).
+    pub total_jitter_ns: u64,
+    /// The count of times the schedule was rebased for exceeding the jitter bound.
+    pub rebases: u64,
+    /// The total wall time (nanoseconds) dropped from the schedule by rebases.
+    pub rebased_ns: u64,
+}
+
+impl ReplayStats {
+    /// Returns the mean emission jitter (nanoseconds).
+    #[must_use]
+    pub fn mean_jitter_ns(&self) -> f64 {
+        if self.emitted == 0 {
+            return 0.0;
+        }
+        self.total_jitter_ns as f64 / self.emitted as f64
+    }
+}
+
+/// Maps wall clock time onto data time from an anchor point.
+#[derive(Clone, Copy, Debug)]
+struct Anchor {
+    wall_ns: u64,
+    data_ns: u64,
+}
+
+/// Provides replay of a data source at a scaled clock speed.
+pub struct ReplayDriver {
+    clock: &'static AtomicTime,
+    config: ReplayConfig,
+    source: Box<dyn ReplaySource>,
+    stream: Peekable<ReplayStream>,
+    handler: ReplayHandler,
+    anchor: Option<Anchor>,
+    paused_data_ns: Option<u64>,
+    last_ts_init: Option<u64>,
+    stats: ReplayStats,
+}
+
+impl ReplayDriver {
+    /// Creates a new [`ReplayDriver`] instance replaying the `source` to the `handler`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `speed` is negative or not finite.
+    /// - If the `source` cannot be opened.
+    pub fn new(
+        clock: &'static AtomicTime,
+        config: ReplayConfig,
+        source: impl ReplaySource + 'static,
+        handler: ReplayHandler,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            config.speed.is_finite() && config.speed >= 0.0,
+            "Invalid replay speed {}, must be finite and non-negative",
+            config.speed
+        );
+        let mut source: Box<dyn ReplaySource> = Box::new(source);
+        let stream = source.open(UnixNanos::default())?.peekable();
+        Ok(Self {
+            clock,
+            config,
+            source,
+            stream,
+            handler,
+            anchor: None,
+            paused_data_ns: None,
+            last_ts_init: None,
+            stats: ReplayStats::default(),
+        })
+    }
+
+    #[must_use]
+    pub fn config(&self) -> ReplayConfig {
+        self.config
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> ReplayStats {
+        self.stats
+    }
+
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused_data_ns.is_some()
+    }
+
+    /// Returns whether all data has been replayed.
+    pub fn is_finished(&mut self) -> bool {
+        self.stream.peek().is_none()
+    }
+
+    /// Returns the current data time of the replay (if started).
+    #[must_use]
+    pub fn data_time_ns(&self) -> Option<UnixNanos> {
+        if let Some(data_ns) = self.paused_data_ns {
+            return Some(data_ns.into());
+        }
+        let anchor = self.anchor?;
+        Some(
+            self.data_time_at(anchor, self.clock.get_time_ns().as_u64())
+                .into(),
+        )
+    }
+
+    /// Returns the clock time the next event is due (if any, and not paused).
+    ///
+    /// With a `speed` of zero, the next event is always due now.
+    pub fn next_due_ns(&mut self) -> Option<UnixNanos> {
+        if self.is_paused() {
+            return None;
+        }
+        let ts_init = self.stream.peek()?.ts_init().as_u64();
+        if self.config.speed == 0.0 {
+            return Some(self.clock.get_time_ns());
+        }
+        let anchor = self.anchor.unwrap_or(Anchor {
+            wall_ns: self.clock.get_time_ns().as_u64(),
+            data_ns: ts_init,
+        });
+        Some(self.due_at(anchor, ts_init).into())
+    }
+
+    /// Emits all events due at the current clock time, returning the count emitted.
+    pub fn poll(&mut self) -> usize {
+        if self.is_paused() {
+            return 0;
+        }
+
+        let now_ns = self.clock.get_time_ns().as_u64();
+        let mut count = 0;
+        while let Some(data) = self.stream.peek() {
+            let ts_init = data.ts_init().as_u64();
+            if self.config.speed > 0.0 {
+                let anchor = *self.anchor.get_or_insert(Anchor {
+                    wall_ns: now_ns,
+                    data_ns: ts_init,
+                });
+                let due_ns = self.due_at(anchor, ts_init);
+                if due_ns > now_ns {
+                    break;
+                }
+
+                let mut jitter_ns = now_ns - due_ns;
+                if jitter_ns > self.config.max_jitter_ns {
+                    self.anchor = Some(Anchor {
+                        wall_ns: now_ns,
+                        data_ns: ts_init,
+                    });
+                    self.stats.rebases += 1;
+                    self.stats.rebased_ns += jitter_ns;
+                    jitter_ns = 0;
+                }
+                self.stats.max_jitter_ns = self.stats.max_jitter_ns.max(jitter_ns);
+                self.stats.total_jitter_ns += jitter_ns;
+            }
+
+            if let Some(data) = self.stream.next() {
+                self.last_ts_init = Some(ts_init);
+                (self.handler)(data);
+                self.stats.emitted += 1;
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Pauses the replay at the current data time.
+    pub fn pause(&mut self) {
+        if self.is_paused() {
+            return;
+        }
+        let data_ns = match self.anchor {
+            Some(anchor) => self.data_time_at(anchor, self.clock.get_time_ns().as_u64()),
+            None => self.stream.peek().map_or(0, |data| data.ts_init().as_u64()),
+        };
+        self.paused_data_ns = Some(data_ns);
+    }
+
+    /// Resumes the replay from the data time it was paused at.
+    pub fn resume(&mut self) {
+        if let Some(data_ns) = self.paused_data_ns.take() {
+            self.anchor = Some(Anchor {
+                wall_ns: self.clock.get_time_ns().as_u64(),
+                data_ns,
+            });
+        }
+    }
+
+    /// Seeks the replay to the data time `ts`, skipping all earlier events and returning the
+    /// count skipped.
+    ///
+    /// Seeking back before an event already read reopens the source from `ts`, so those events
+    /// are replayed again. The schedule continues from `ts` at the current clock time (or on
+    /// resume, if paused).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the source cannot be reopened.
+    pub fn seek(&mut self, ts: UnixNanos) -> anyhow::Result<usize> {
+        if self
+            .last_ts_init
+            .is_some_and(|ts_init| ts_init >= ts.as_u64())
+        {
+            self.stream = self.source.open(ts)?.peekable();
+        }
+
+        let mut skipped = 0;
+        while let Some(data) = self.stream.next_if(|data| data.ts_init() < ts) {
+            self.last_ts_init = Some(data.ts_init().as_u64());
+            skipped += 1;
+        }
+        self.stats.skipped += skipped as u64;
+
+        if self.is_paused() {
+            self.paused_data_ns = Some(ts.as_u64());
+        } else {
+            self.anchor = Some(Anchor {
+                wall_ns: self.clock.get_time_ns().as_u64(),
+                data_ns: ts.as_u64(),
+            });
+        }
+        Ok(skipped)
+    }
+
+    fn due_at(&self, anchor: Anchor, data_ns: u64) -> u64 {
+        let offset_ns = data_ns.saturating_sub(anchor.data_ns) as f64 / self.config.speed;
+        anchor.wall_ns.saturating_add(offset_ns.round() as u64)
+    }
+
+    fn data_time_at(&self, anchor: Anchor, wall_ns: u64) -> u64 {
+        let offset_ns = wall_ns.saturating_sub(anchor.wall_ns) as f64 * self.config.speed;
+        anchor.data_ns.saturating_add(offset_ns.round() as u64)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{data::trade::TradeTick, identifiers::trader_id::TraderId};
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::*;
+    use crate::{
+        handlers::{MessageHandler, SafeBusMessageCallback},
+        msgbus::BusMessage,
+    };
+
+    const SECOND: u64 = 1_000_000_000;
+
+    type Emitted = Rc<RefCell<Vec<(u64, u64)>>>;
+
+    fn trade(ts_init: u64) -> Data {
+        Data::Trade(TradeTick {
+            ts_event: ts_init.into(),
+            ts_init: ts_init.into(),
+            ..Default::default()
+        })
+    }
+
+    fn driver(speed: f64, ts_inits: &[u64]) -> (ReplayDriver, &'static AtomicTime, Emitted) {
+        let clock: &'static AtomicTime =
+            Box::leak(Box::new(AtomicTime::new(false, UnixNanos::from(1_000))));
+        let emitted: Emitted = Rc::default();
+        let sink = emitted.clone();
+        let handler: ReplayHandler = Box::new(move |data: Data| {
+            sink.borrow_mut()
+                .push((clock.get_time_ns().as_u64(), data.ts_init().as_u64()));
+        });
+        let config = ReplayConfig {
+            speed,
+            max_jitter_ns: SECOND / 10,
+        };
+        let source: Vec<Data> = ts_inits.iter().map(|ts| trade(*ts)).collect();
+        let driver = ReplayDriver::new(clock, config, source, handler).unwrap();
+        (driver, clock, emitted)
+    }
+
+    /// Polls the driver at each due time until finished.
+    fn run_to_end(driver: &mut ReplayDriver, clock: &AtomicTime) {
+        while let Some(due) = driver.next_due_ns() {
+            clock.set_time(due.max(clock.get_time_ns()));
+            driver.poll();
+        }
+    }
+
+    #[rstest]
+    #[case(1.0)]
+    #[case(10.0)]
+    #[case(0.5)]
+    fn test_replay_keeps_gaps_proportional(#[case] speed: f64) {
+        let ts_inits = [
+            10 * SECOND,
+            11 * SECOND,
+            11 * SECOND,
+            15 * SECOND,
+            35 * SECOND,
+        ];
+        let (mut driver, clock, emitted) = driver(speed, &ts_inits);
+
+        run_to_end(&mut driver, clock);
+
+        let emitted = emitted.borrow();
+        assert_eq!(emitted.len(), ts_inits.len());
+        let (wall_start, data_start) = emitted[0];
+        for (wall, data) in emitted.iter() {
+            let expected = ((data - data_start) as f64 / speed) as u64;
+            assert_eq!(wall - wall_start, expected);
+        }
+        assert_eq!(driver.stats().emitted, 5);
+        assert_eq!(driver.stats().max_jitter_ns, 0);
+        assert!(driver.is_finished());
+    }
+
+    #[rstest]
+    fn test_replay_does_not_emit_early() {
+        let (mut driver, clock, emitted) = driver(2.0, &[0, 4 * SECOND]);
+
+        assert_eq!(driver.poll(), 1);
+        clock.set_time(clock.get_time_ns() + UnixNanos::from(2 * SECOND - 1));
+        assert_eq!(driver.poll(), 0);
+        clock.set_time(clock.get_time_ns() + UnixNanos::from(1));
+        assert_eq!(driver.poll(), 1);
+
+        assert_eq!(emitted.borrow().len(), 2);
+    }
+
+    #[rstest]
+    fn test_replay_as_fast_as_possible() {
+        let (mut driver, _clock, emitted) = driver(0.0, &[0, SECOND, 100 * SECOND]);
+
+        assert_eq!(driver.next_due_ns(), Some(UnixNanos::from(1_000)));
+        assert_eq!(driver.poll(), 3);
+        assert_eq!(emitted.borrow().len(), 3);
+        assert_eq!(driver.next_due_ns(), None);
+    }
+
+    #[rstest]
+    fn test_replay_jitter_is_measured_and_bounded() {
+        let (mut driver, clock, _emitted) = driver(1.0, &[0, SECOND, 2 * SECOND, 3 * SECOND]);
+        driver.poll();
+
+        // Polled 50ms late, within the 100ms bound
+        clock.set_time(UnixNanos::from(1_000 + SECOND + SECOND / 20));
+        driver.poll();
+        // Stalled for a second, so the schedule rebases to the late event
+        clock.set_time(UnixNanos::from(1_000 + 3 * SECOND));
+        driver.poll();
+        let after_stall = driver.stats();
+        clock.set_time(UnixNanos::from(1_000 + 4 * SECOND));
+        driver.poll();
+
+        assert_eq!(after_stall.emitted, 3);
+        assert_eq!(after_stall.rebases, 1);
+        assert_eq!(after_stall.rebased_ns, SECOND);
+        // The remaining event keeps its one second gap from the rebased event
+        let stats = driver.stats();
+        assert_eq!(stats.emitted, 4);
+        assert_eq!(stats.max_jitter_ns, SECOND / 20);
+        assert!(stats.max_jitter_ns <= driver.config().max_jitter_ns);
+        assert_eq!(stats.mean_jitter_ns(), (SECOND / 20) as f64 / 4.0);
+    }
+
+    #[rstest]
+    fn test_pause_and_resume_preserves_gaps() {
+        let (mut driver, clock, emitted) = driver(1.0, &[0, 2 * SECOND]);
+        driver.poll();
+        clock.set_time(UnixNanos::from(1_000 + SECOND));
+
+        driver.pause();
+        clock.set_time(UnixNanos::from(1_000 + 10 * SECOND));
+        assert_eq!(driver.poll(), 0);
+        assert_eq!(driver.next_due_ns(), None);
+        assert_eq!(driver.data_time_ns(), Some(UnixNanos::from(SECOND)));
+        driver.resume();
+
+        assert_eq!(
+            driver.next_due_ns(),
+            Some(UnixNanos::from(1_000 + 11 * SECOND))
+        );
+        run_to_end(&mut driver, clock);
+        assert_eq!(emitted.borrow()[1], (1_000 + 11 * SECOND, 2 * SECOND));
+    }
+
+    #[rstest]
+    fn test_seek_skips_and_reanchors() {
+        let ts_inits = [0, SECOND, 2 * SECOND, 5 * SECOND, 6 * SECOND];
+        let (mut driver, clock, emitted) = driver(1.0, &ts_inits);
+        driver.poll();
+
+        clock.set_time(UnixNanos::from(1_000 + SECOND / 2));
+        let skipped = driver.seek(UnixNanos::from(4 * SECOND)).unwrap();
+        run_to_end(&mut driver, clock);
+
+        assert_eq!(skipped, 2);
+        assert_eq!(driver.stats().skipped, 2);
+        // Data time 4s maps to the seek time, so the 5s event is due a second later
+        assert_eq!(
+            *emitted.borrow(),
+            vec![
+                (1_000, 0),
+                (1_000 + SECOND / 2 + SECOND, 5 * SECOND),
+                (1_000 + SECOND / 2 + 2 * SECOND, 6 * SECOND),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_seek_while_paused_resumes_from_seek_time() {
+        let (mut driver, clock, emitted) = driver(2.0, &[0, SECOND, 10 * SECOND]);
+        driver.poll();
+        driver.pause();
+
+        driver.seek(UnixNanos::from(8 * SECOND)).unwrap();
+        clock.set_time(UnixNanos::from(1_000 + 60 * SECOND));
+        driver.resume();
+        run_to_end(&mut driver, clock);
+
+        assert_eq!(
+            emitted.borrow().last(),
+            Some(&(1_000 + 61 * SECOND, 10 * SECOND))
+        );
+    }
+
+    #[rstest]
+    fn test_seek_backwards_reopens_source() {
+        let ts_inits = [0, SECOND, 2 * SECOND, 3 * SECOND];
+        let (mut driver, clock, emitted) = driver(1.0, &ts_inits);
+        driver.poll();
+        clock.set_time(UnixNanos::from(1_000 + SECOND));
+        driver.poll();
+
+        let skipped = driver.seek(UnixNanos::from(SECOND / 2)).unwrap();
+        run_to_end(&mut driver, clock);
+
+        // The source is reopened from the seek time, so no events are skipped
+        assert_eq!(skipped, 0);
+        // Data time 0.5s maps to the seek time, so the 1s event replays half a second later
+        assert_eq!(
+            *emitted.borrow(),
+            vec![
+                (1_000, 0),
+                (1_000 + SECOND, SECOND),
+                (1_000 + SECOND + SECOND / 2, SECOND),
+                (1_000 + 2 * SECOND + SECOND / 2, 2 * SECOND),
+                (1_000 + 3 * SECOND + SECOND / 2, 3 * SECOND),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_seek_backwards_before_unread_data_does_not_reopen() {
+        let opens: Rc<RefCell<Vec<UnixNanos>>> = Rc::default();
+        let source_opens = opens.clone();
+        let source = move |start: UnixNanos| {
+            source_opens.borrow_mut().push(start);
+            anyhow::Ok(
+                [0, 5 * SECOND]
+                    .map(trade)
+                    .into_iter()
+                    .filter(move |data| data.ts_init() >= start),
+            )
+        };
+        let clock: &'static AtomicTime =
+            Box::leak(Box::new(AtomicTime::new(false, UnixNanos::from(1_000))));
+        let mut driver =
+            ReplayDriver::new(clock, ReplayConfig::default(), source, Box::new(|_| {})).unwrap();
+        driver.poll();
+        clock.set_time(UnixNanos::from(1_000 + 3 * SECOND));
+
+        // Back to 2s from data time 3s, but the 5s event has not been read
+        driver.seek(UnixNanos::from(2 * SECOND)).unwrap();
+        assert_eq!(*opens.borrow(), vec![UnixNanos::default()]);
+
+        // Back to the 0s event already emitted
+        driver.seek(UnixNanos::default()).unwrap();
+        assert_eq!(
+            *opens.borrow(),
+            vec![UnixNanos::default(), UnixNanos::default()]
+        );
+        assert_eq!(driver.poll(), 1);
+    }
+
+    #[rstest]
+    fn test_msgbus_handler_publishes_on_data_topic() {
+        let msgbus = Rc::new(RefCell::new(
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap(),
+        ));
+        let received: Arc<Mutex<Vec<BusMessage>>> = Arc::default();
+        let sink = received.clone();
+        let handler = MessageHandler::with_bus_callback(
+            Ustr::from("replay-test"),
+            SafeBusMessageCallback {
+                callback: Arc::new(move |message: &BusMessage| {
+                    sink.lock().unwrap().push(message.clone());
+                }),
+            },
+        );
+        msgbus
+            .borrow_mut()
+            .subscribe("data.trades.*", handler, None);
+        let clock: &'static AtomicTime =
+            Box::leak(Box::new(AtomicTime::new(false, UnixNanos::from(1_000))));
+        let config = ReplayConfig {
+            speed: 0.0,
+            ..Default::default()
+        };
+        let source = vec![trade(0), trade(SECOND)];
+        let mut driver =
+            ReplayDriver::new(clock, config, source, msgbus_handler(msgbus.clone())).unwrap();
+
+        driver.poll();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        let topic = data_topic(&trade(0));
+        assert!(received.iter().all(|message| message.topic == topic));
+        let trade: TradeTick = serde_json::from_slice(&received[1].payload).unwrap();
+        assert_eq!(trade.ts_init, UnixNanos::from(SECOND));
+    }
+
+    #[rstest]
+    #[case(-1.0)]
+    #[case(f64::NAN)]
+    #[case(f64::INFINITY)]
+    fn test_invalid_speed(#[case] speed: f64) {
+        let clock: &'static AtomicTime =
+            Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+        let config = ReplayConfig {
+            speed,
+            ..Default::default()
+        };
+
+        let result = ReplayDriver::new(clock, config, Vec::new(), Box::new(|_| {}));
+
+        assert!(result.is_err());
+    }
+}
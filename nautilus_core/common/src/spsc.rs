@@ -0,0 +1,419 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A fixed-capacity lock-free single-producer single-consumer ring buffer.
+//!
+//! Used on the hot path from data decoding to the engine, where one thread decodes venue
+//! messages and another consumes them. A full buffer never allocates: `push` hands the value
+//! back so the producer can decide whether to spin, yield or drop. The consumer drains with
+//! `pop_batch`, publishing its progress once per batch rather than once per value.
+//!
+//! The producer and consumer indices live on separate cache lines, and each side keeps a
+//! cached copy of the other's index so the shared line is only read when the cached copy
+//! suggests the buffer is full (or empty).
+
+use std::{
+    cell::UnsafeCell,
+    fmt::Debug,
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use nautilus_model::data::Data;
+
+/// Pads and aligns a value to the length of a cache line, avoiding false sharing.
+#[repr(align(64))]
+struct CachePadded<T>(T);
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+struct Shared<T> {
+    /// The index of the next slot to read, written by the consumer only.
+    head: CachePadded<AtomicUsize>,
+    /// The index of the next slot to write, written by the producer only.
+    tail: CachePadded<AtomicUsize>,
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    mask: usize,
+}
+
+// SAFETY: Each slot is accessed by exactly one side at a time, ownership passing between the
+// producer and consumer through the release/acquire ordering on `head` and `tail`.
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn slot(&self, index: usize) -> *mut MaybeUninit<T> {
+        self.slots[index & self.mask].get()
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let head = *self.head.0.get_mut();
+        let tail = *self.tail.0.get_mut();
+        for offset in 0..tail.wrapping_sub(head) {
+            // SAFETY: Slots between `head` and `tail` were written and never read
+            unsafe { (*self.slot(head.wrapping_add(offset))).assume_init_drop() };
+        }
+    }
+}
+
+/// Creates a ring buffer holding at least `capacity` values, returning its two ends.
+///
+/// The capacity is rounded up to the next power of two.
+///
+/// # Panics
+///
+/// This function panics if `capacity` is zero.
+#[must_use]
+pub fn spsc_ring<T: Send>(capacity: usize) -> (SpscProducer<T>, SpscConsumer<T>) {
+    assert!(capacity > 0, "Ring buffer capacity must be positive");
+    let capacity = capacity.next_power_of_two();
+    let slots = (0..capacity)
+        .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+        .collect();
+    let shared = Arc::new(Shared {
+        head: CachePadded(AtomicUsize::new(0)),
+        tail: CachePadded(AtomicUsize::new(0)),
+        slots,
+        mask: capacity - 1,
+    });
+    let producer = SpscProducer {
+        shared: shared.clone(),
+        tail: 0,
+        cached_head: 0,
+    };
+    let consumer = SpscConsumer {
+        shared,
+        head: 0,
+        cached_tail: 0,
+    };
+    (producer, consumer)
+}
+
+/// The producing end of a ring buffer for `Data` values.
+pub type DataProducer = SpscProducer<Data>;
+
+/// The consuming end of a ring buffer for `Data` values.
+pub type DataConsumer = SpscConsumer<Data>;
+
+/// The producing end of a single-producer single-consumer ring buffer.
+pub struct SpscProducer<T> {
+    shared: Arc<Shared<T>>,
+    tail: usize,
+    cached_head: usize,
+}
+
+impl<T> SpscProducer<T> {
+    /// Pushes the given `value` onto the buffer.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the value back if the buffer is full.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.tail.wrapping_sub(self.cached_head) == self.capacity() {
+            self.cached_head = self.shared.head.load(Ordering::Acquire);
+            if self.tail.wrapping_sub(self.cached_head) == self.capacity() {
+                return Err(value);
+            }
+        }
+
+        // SAFETY: The slot is free, as the consumer has read past it
+        unsafe { (*self.shared.slot(self.tail)).write(value) };
+        self.tail = self.tail.wrapping_add(1);
+        self.shared.tail.store(self.tail, Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns the number of values which can be held.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.shared.mask + 1
+    }
+
+    /// Returns the number of values currently held.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tail
+            .wrapping_sub(self.shared.head.load(Ordering::Acquire))
+    }
+
+    /// Returns whether the buffer holds no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the consumer has been dropped.
+    #[must_use]
+    pub fn is_disconnected(&self) -> bool {
+        Arc::strong_count(&self.shared) == 1
+    }
+}
+
+impl<T> Debug for SpscProducer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(SpscProducer))
+            .field("capacity", &self.capacity())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// The consuming end of a single-producer single-consumer ring buffer.
+pub struct SpscConsumer<T> {
+    shared: Arc<Shared<T>>,
+    head: usize,
+    cached_tail: usize,
+}
+
+impl<T> SpscConsumer<T> {
+    /// Pops the oldest value from the buffer, if any.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.available() == 0 {
+            return None;
+        }
+
+        // SAFETY: The slot was written by the producer before it published `tail`
+        let value = unsafe { (*self.shared.slot(self.head)).assume_init_read() };
+        self.head = self.head.wrapping_add(1);
+        self.shared.head.store(self.head, Ordering::Release);
+        Some(value)
+    }
+
+    /// Pops up to `max` of the oldest values into `buffer`, returning the number popped.
+    ///
+    /// The producer is notified of the freed slots once for the whole batch.
+    pub fn pop_batch(&mut self, buffer: &mut Vec<T>, max: usize) -> usize {
+        let count = self.available().min(max);
+        if count == 0 {
+            return 0;
+        }
+
+        buffer.reserve(count);
+        for offset in 0..count {
+            let index = self.head.wrapping_add(offset);
+            // SAFETY: The slot was written by the producer before it published `tail`
+            buffer.push(unsafe { (*self.shared.slot(index)).assume_init_read() });
+        }
+        self.head = self.head.wrapping_add(count);
+        self.shared.head.store(self.head, Ordering::Release);
+        count
+    }
+
+    /// Returns the number of values which can be held.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.shared.mask + 1
+    }
+
+    /// Returns the number of values currently held.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.shared
+            .tail
+            .load(Ordering::Acquire)
+            .wrapping_sub(self.head)
+    }
+
+    /// Returns whether the buffer holds no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the producer has been dropped.
+    ///
+    /// Values pushed before the producer was dropped can still be popped.
+    #[must_use]
+    pub fn is_disconnected(&self) -> bool {
+        Arc::strong_count(&self.shared) == 1
+    }
+
+    /// Returns the number of values readable without another load of `tail`, refreshing the
+    /// cached copy when it has been exhausted.
+    fn available(&mut self) -> usize {
+        if self.cached_tail == self.head {
+            self.cached_tail = self.shared.tail.load(Ordering::Acquire);
+        }
+        self.cached_tail.wrapping_sub(self.head)
+    }
+}
+
+impl<T> Debug for SpscConsumer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(SpscConsumer))
+            .field("capacity", &self.capacity())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::{
+        data::{quote::QuoteTick, stubs::quote_tick_audusd_sim},
+        polymorphism::GetTsInit,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(ts: u64) -> Data {
+        let quote = quote_tick_audusd_sim();
+        Data::Quote(QuoteTick {
+            ts_init: UnixNanos::from(ts),
+            ..quote
+        })
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(3, 4)]
+    #[case(1024, 1024)]
+    fn test_capacity_rounds_up_to_power_of_two(#[case] capacity: usize, #[case] expected: usize) {
+        let (producer, consumer) = spsc_ring::<u64>(capacity);
+        assert_eq!(producer.capacity(), expected);
+        assert_eq!(consumer.capacity(), expected);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Ring buffer capacity must be positive")]
+    fn test_zero_capacity_panics() {
+        let _ = spsc_ring::<u64>(0);
+    }
+
+    #[rstest]
+    fn test_push_when_full_returns_value() {
+        let (mut producer, mut consumer) = spsc_ring(2);
+        producer.push(1).unwrap();
+        producer.push(2).unwrap();
+
+        assert_eq!(producer.push(3), Err(3));
+        assert_eq!(consumer.pop(), Some(1));
+        assert_eq!(producer.push(3), Ok(()));
+        assert_eq!(producer.len(), 2);
+    }
+
+    #[rstest]
+    fn test_pop_batch_respects_max_and_wraps_around() {
+        let (mut producer, mut consumer) = spsc_ring(4);
+        let mut buffer = Vec::new();
+        for round in 0..5 {
+            for i in 0..3 {
+                producer.push(round * 3 + i).unwrap();
+            }
+            assert_eq!(consumer.pop_batch(&mut buffer, 2), 2);
+            assert_eq!(consumer.pop_batch(&mut buffer, 2), 1);
+            assert_eq!(consumer.pop_batch(&mut buffer, 2), 0);
+        }
+
+        assert_eq!(buffer, (0..15).collect::<Vec<_>>());
+        assert!(consumer.is_empty());
+    }
+
+    #[rstest]
+    fn test_remaining_values_dropped_with_buffer() {
+        let value = Arc::new(());
+        let (mut producer, mut consumer) = spsc_ring(4);
+        for _ in 0..3 {
+            producer.push(value.clone()).unwrap();
+        }
+        drop(consumer.pop());
+
+        drop(producer);
+        assert!(consumer.is_disconnected());
+        assert_eq!(Arc::strong_count(&value), 3);
+
+        drop(consumer);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+
+    #[rstest]
+    fn test_stress_across_threads_preserves_order() {
+        const COUNT: u64 = 200_000;
+        let (mut producer, mut consumer) = spsc_ring::<Data>(64);
+
+        let handle = thread::spawn(move || {
+            for ts in 0..COUNT {
+                let mut data = quote(ts);
+                while let Err(rejected) = producer.push(data) {
+                    data = rejected;
+                    thread::yield_now();
+                }
+            }
+        });
+
+        let mut buffer = Vec::with_capacity(32);
+        let mut expected = 0;
+        while expected < COUNT {
+            buffer.clear();
+            if consumer.pop_batch(&mut buffer, 32) == 0 {
+                thread::yield_now();
+                continue;
+            }
+            for data in &buffer {
+                assert_eq!(data.ts_init().as_u64(), expected);
+                expected += 1;
+            }
+        }
+        handle.join().unwrap();
+
+        assert!(consumer.is_empty());
+        assert!(consumer.is_disconnected());
+    }
+
+    #[rstest]
+    fn test_stress_alternating_pop_and_pop_batch() {
+        const COUNT: usize = 100_000;
+        let (mut producer, mut consumer) = spsc_ring::<usize>(8);
+
+        let handle = thread::spawn(move || {
+            for value in 0..COUNT {
+                while producer.push(value).is_err() {
+                    std::hint::spin_loop();
+                }
+            }
+        });
+
+        let mut received = Vec::with_capacity(COUNT);
+        while received.len() < COUNT {
+            if received.len() % 2 == 0 {
+                consumer.pop_batch(&mut received, 5);
+            } else if let Some(value) = consumer.pop() {
+                received.push(value);
+            }
+        }
+        handle.join().unwrap();
+
+        assert!(received.iter().enumerate().all(|(i, value)| i == *value));
+    }
+}
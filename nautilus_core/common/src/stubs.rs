@@ -15,11 +15,25 @@
 
 //! Type stubs to facilitate testing.
 
+use std::collections::HashMap;
+
 use nautilus_core::time::get_atomic_clock_static;
-use nautilus_model::identifiers::stubs::{strategy_id_ema_cross, trader_id};
+use nautilus_model::{
+    enums::{AccountType, LiquiditySide, OrderSide},
+    events::{account::state::AccountState, order::filled::OrderFilled},
+    identifiers::{
+        account_id::AccountId,
+        stubs::{strategy_id_ema_cross, trader_id},
+    },
+    instruments::any::InstrumentAny,
+    position::Position,
+    types::{
+        balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
+    },
+};
 use rstest::fixture;
 
-use crate::factories::OrderFactory;
+use crate::{factories::OrderFactory, interface::account::Account};
 
 #[fixture]
 pub fn order_factory() -> OrderFactory {
@@ -33,3 +47,126 @@ pub fn order_factory() -> OrderFactory {
         get_atomic_clock_static(),
     )
 }
+
+/// A minimal [`Account`] with no balances, for testing account indexing and routing.
+pub struct StubAccount {
+    pub id: AccountId,
+    pub account_type: AccountType,
+}
+
+impl StubAccount {
+    #[must_use]
+    pub fn boxed(account_id: &str) -> Box<dyn Account> {
+        Box::new(Self {
+            id: AccountId::from(account_id),
+            account_type: AccountType::Margin,
+        })
+    }
+}
+
+impl Account for StubAccount {
+    fn id(&self) -> AccountId {
+        self.id
+    }
+
+    fn account_type(&self) -> AccountType {
+        self.account_type
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        None
+    }
+
+    fn is_cash_account(&self) -> bool {
+        self.account_type == AccountType::Cash
+    }
+
+    fn is_margin_account(&self) -> bool {
+        self.account_type == AccountType::Margin
+    }
+
+    fn calculated_account_state(&self) -> bool {
+        false
+    }
+
+    fn balance_total(&self, _currency: Option<Currency>) -> Option<Money> {
+        None
+    }
+
+    fn balances_total(&self) -> HashMap<Currency, Money> {
+        HashMap::new()
+    }
+
+    fn balance_free(&self, _currency: Option<Currency>) -> Option<Money> {
+        None
+    }
+
+    fn balances_free(&self) -> HashMap<Currency, Money> {
+        HashMap::new()
+    }
+
+    fn balance_locked(&self, _currency: Option<Currency>) -> Option<Money> {
+        None
+    }
+
+    fn balances_locked(&self) -> HashMap<Currency, Money> {
+        HashMap::new()
+    }
+
+    fn last_event(&self) -> Option<AccountState> {
+        None
+    }
+
+    fn events(&self) -> Vec<AccountState> {
+        Vec::new()
+    }
+
+    fn event_count(&self) -> usize {
+        0
+    }
+
+    fn currencies(&self) -> Vec<Currency> {
+        Vec::new()
+    }
+
+    fn starting_balances(&self) -> HashMap<Currency, Money> {
+        HashMap::new()
+    }
+
+    fn balances(&self) -> HashMap<Currency, AccountBalance> {
+        HashMap::new()
+    }
+
+    fn apply(&mut self, _event: AccountState) {}
+
+    fn calculate_balance_locked(
+        &mut self,
+        _instrument: InstrumentAny,
+        _side: OrderSide,
+        _quantity: Quantity,
+        _price: Price,
+        _use_quote_for_inverse: Option<bool>,
+    ) -> anyhow::Result<Money> {
+        anyhow::bail!("Not supported by `StubAccount`")
+    }
+
+    fn calculate_pnls(
+        &self,
+        _instrument: InstrumentAny,
+        _fill: OrderFilled,
+        _position: Option<Position>,
+    ) -> anyhow::Result<Vec<Money>> {
+        anyhow::bail!("Not supported by `StubAccount`")
+    }
+
+    fn calculate_commission(
+        &self,
+        _instrument: InstrumentAny,
+        _last_qty: Quantity,
+        _last_px: Price,
+        _liquidity_side: LiquiditySide,
+        _use_quote_for_inverse: Option<bool>,
+    ) -> anyhow::Result<Money> {
+        anyhow::bail!("Not supported by `StubAccount`")
+    }
+}
@@ -0,0 +1,317 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides negotiation of order book subscriptions against venue capabilities.
+//!
+//! A subscription request may ask for a book type or depth a venue cannot provide. The
+//! resolver downgrades the book type (L3 -> L2 -> L1) and caps the depth to what the venue
+//! supports, recording each adjustment so the data engine can re-request on reconnect.
+
+use log::warn;
+use nautilus_model::{enums::BookType, identifiers::instrument_id::InstrumentId};
+
+/// Represents the order book subscription capabilities of a venue (as declared by its adapter).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookSubscriptionCapability {
+    /// The maximum depth of levels per side the venue can stream (`None` for unlimited).
+    pub max_depth: Option<usize>,
+    /// The book types the venue supports.
+    pub supported_book_types: Vec<BookType>,
+}
+
+impl BookSubscriptionCapability {
+    #[must_use]
+    pub fn new(max_depth: Option<usize>, supported_book_types: Vec<BookType>) -> Self {
+        Self {
+            max_depth,
+            supported_book_types,
+        }
+    }
+
+    #[must_use]
+    pub fn supports(&self, book_type: BookType) -> bool {
+        self.supported_book_types.contains(&book_type)
+    }
+}
+
+/// Represents an adjustment made when resolving an order book subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BookSubscriptionAdjustment {
+    /// The requested book type was not supported and was downgraded.
+    BookTypeDowngraded {
+        requested: BookType,
+        resolved: BookType,
+    },
+    /// The requested depth exceeded the venue maximum and was capped.
+    DepthCapped { requested: usize, resolved: usize },
+}
+
+/// Represents the state of a resolved order book subscription.
+///
+/// The originally requested book type and depth are retained so the subscription can be
+/// resolved again (e.g. on reconnect, after the venue capabilities have changed).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookSubscriptionState {
+    pub instrument_id: InstrumentId,
+    pub requested_book_type: BookType,
+    pub requested_depth: Option<usize>,
+    pub book_type: BookType,
+    pub depth: Option<usize>,
+    pub adjustments: Vec<BookSubscriptionAdjustment>,
+}
+
+impl BookSubscriptionState {
+    /// Returns whether the subscription differs from what was requested.
+    #[must_use]
+    pub fn is_adjusted(&self) -> bool {
+        !self.adjustments.is_empty()
+    }
+
+    /// Resolves the originally requested subscription again against the given `capability`.
+    pub fn resolve_again(
+        &self,
+        capability: &BookSubscriptionCapability,
+        strict: bool,
+    ) -> anyhow::Result<Self> {
+        resolve_book_subscription(
+            self.instrument_id,
+            self.requested_book_type,
+            self.requested_depth,
+            capability,
+            strict,
+        )
+    }
+}
+
+/// Returns the next lower granularity book type, if any.
+#[must_use]
+pub fn downgrade_book_type(book_type: BookType) -> Option<BookType> {
+    match book_type {
+        BookType::L3_MBO => Some(BookType::L2_MBP),
+        BookType::L2_MBP => Some(BookType::L1_MBP),
+        BookType::L1_MBP => None,
+    }
+}
+
+/// Resolves the requested order book subscription against the venue `capability`.
+///
+/// Unsupported book types are downgraded (L3 -> L2 -> L1) and a `depth` above the venue
+/// maximum is capped, with a warning logged for each adjustment. A `depth` of `None`
+/// requests the full book and resolves to the venue maximum without adjustment.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If no supported book type is found at or below the requested book type.
+/// - If `strict` is true and the subscription would need to be adjusted.
+pub fn resolve_book_subscription(
+    instrument_id: InstrumentId,
+    book_type: BookType,
+    depth: Option<usize>,
+    capability: &BookSubscriptionCapability,
+    strict: bool,
+) -> anyhow::Result<BookSubscriptionState> {
+    let mut adjustments = Vec::new();
+
+    let mut resolved_book_type = book_type;
+    while !capability.supports(resolved_book_type) {
+        match downgrade_book_type(resolved_book_type) {
+            Some(lower) => resolved_book_type = lower,
+            None => anyhow::bail!(
+                "No supported book type at or below {book_type} for {instrument_id}, supported: {:?}",
+                capability.supported_book_types
+            ),
+        }
+    }
+    if resolved_book_type != book_type {
+        if strict {
+            anyhow::bail!("Book type {book_type} not supported for {instrument_id} (strict mode)");
+        }
+        warn!("Downgraded book subscription for {instrument_id} from {book_type} to {resolved_book_type}");
+        adjustments.push(BookSubscriptionAdjustment::BookTypeDowngraded {
+            requested: book_type,
+            resolved: resolved_book_type,
+        });
+    }
+
+    let resolved_depth = match (depth, capability.max_depth) {
+        (Some(requested), Some(max_depth)) if requested > max_depth => {
+            if strict {
+                anyhow::bail!(
+                    "Requested depth {requested} exceeds maximum {max_depth} for {instrument_id} (strict mode)"
+                );
+            }
+            warn!("Capped book subscription depth for {instrument_id} from {requested} to {max_depth}");
+            adjustments.push(BookSubscriptionAdjustment::DepthCapped {
+                requested,
+                resolved: max_depth,
+            });
+            Some(max_depth)
+        }
+        (None, max_depth) => max_depth,
+        (depth, _) => depth,
+    };
+
+    Ok(BookSubscriptionState {
+        instrument_id,
+        requested_book_type: book_type,
+        requested_depth: depth,
+        book_type: resolved_book_type,
+        depth: resolved_depth,
+        adjustments,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ETHUSDT-PERP.BINANCE")
+    }
+
+    #[rstest]
+    fn test_resolve_supported_subscription_unchanged() {
+        let capability =
+            BookSubscriptionCapability::new(Some(20), vec![BookType::L2_MBP, BookType::L1_MBP]);
+        let state = resolve_book_subscription(
+            instrument_id(),
+            BookType::L2_MBP,
+            Some(10),
+            &capability,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(state.book_type, BookType::L2_MBP);
+        assert_eq!(state.depth, Some(10));
+        assert!(!state.is_adjusted());
+    }
+
+    #[rstest]
+    fn test_resolve_full_depth_uses_venue_maximum() {
+        let capability = BookSubscriptionCapability::new(Some(20), vec![BookType::L2_MBP]);
+        let state =
+            resolve_book_subscription(instrument_id(), BookType::L2_MBP, None, &capability, true)
+                .unwrap();
+
+        assert_eq!(state.depth, Some(20));
+        assert!(!state.is_adjusted());
+    }
+
+    #[rstest]
+    fn test_resolve_caps_depth() {
+        let capability = BookSubscriptionCapability::new(Some(20), vec![BookType::L2_MBP]);
+        let state = resolve_book_subscription(
+            instrument_id(),
+            BookType::L2_MBP,
+            Some(100),
+            &capability,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(state.depth, Some(20));
+        assert_eq!(state.requested_depth, Some(100));
+        assert_eq!(
+            state.adjustments,
+            vec![BookSubscriptionAdjustment::DepthCapped {
+                requested: 100,
+                resolved: 20
+            }]
+        );
+    }
+
+    #[rstest]
+    #[case(vec![BookType::L2_MBP, BookType::L1_MBP], BookType::L2_MBP)]
+    #[case(vec![BookType::L1_MBP], BookType::L1_MBP)]
+    fn test_resolve_downgrade_chain(
+        #[case] supported_book_types: Vec<BookType>,
+        #[case] expected: BookType,
+    ) {
+        let capability = BookSubscriptionCapability::new(None, supported_book_types);
+        let state =
+            resolve_book_subscription(instrument_id(), BookType::L3_MBO, None, &capability, false)
+                .unwrap();
+
+        assert_eq!(state.book_type, expected);
+        assert_eq!(state.requested_book_type, BookType::L3_MBO);
+        assert_eq!(
+            state.adjustments,
+            vec![BookSubscriptionAdjustment::BookTypeDowngraded {
+                requested: BookType::L3_MBO,
+                resolved: expected
+            }]
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_when_no_supported_book_type() {
+        let capability = BookSubscriptionCapability::new(None, vec![BookType::L3_MBO]);
+        let result =
+            resolve_book_subscription(instrument_id(), BookType::L2_MBP, None, &capability, false);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_resolve_strict_mode_rejects_downgrade() {
+        let capability = BookSubscriptionCapability::new(None, vec![BookType::L2_MBP]);
+        let result =
+            resolve_book_subscription(instrument_id(), BookType::L3_MBO, None, &capability, true);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_resolve_strict_mode_rejects_depth_cap() {
+        let capability = BookSubscriptionCapability::new(Some(5), vec![BookType::L2_MBP]);
+        let result = resolve_book_subscription(
+            instrument_id(),
+            BookType::L2_MBP,
+            Some(10),
+            &capability,
+            true,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_resolve_again_after_capability_change() {
+        let capability = BookSubscriptionCapability::new(Some(10), vec![BookType::L2_MBP]);
+        let state = resolve_book_subscription(
+            instrument_id(),
+            BookType::L3_MBO,
+            Some(50),
+            &capability,
+            false,
+        )
+        .unwrap();
+        assert_eq!(state.adjustments.len(), 2);
+
+        let capability = BookSubscriptionCapability::new(None, vec![BookType::L3_MBO]);
+        let state = state.resolve_again(&capability, true).unwrap();
+
+        assert_eq!(state.book_type, BookType::L3_MBO);
+        assert_eq!(state.depth, Some(50));
+        assert!(!state.is_adjusted());
+    }
+}
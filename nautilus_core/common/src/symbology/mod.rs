@@ -0,0 +1,585 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a rules engine for converting venue-native symbols to Nautilus symbols and back.
+//!
+//! A `SymbolNormalizer` applies an ordered list of `SymbolTransform`s to a venue-native symbol
+//! to produce a `Symbol`, and applies their inverses in reverse order to recover the native
+//! symbol. Every conversion is checked to round trip, so a symbol which cannot be converted
+//! back unchanged is rejected rather than silently mangled.
+//!
+//! Rule sets for some common venues are built in as JSON data (see `builtin_normalizer`).
+
+use std::fmt::Display;
+
+use nautilus_model::identifiers::{symbol::Symbol, venue::Venue};
+use serde::{Deserialize, Serialize};
+
+/// The names and JSON definitions of the built-in rule sets.
+pub const BUILTIN_RULE_SETS: [(&str, &str); 3] = [
+    ("binance_futures", include_str!("binance_futures.json")),
+    ("gateio_spot", include_str!("gateio_spot.json")),
+    ("okx_swap", include_str!("okx_swap.json")),
+];
+
+/// The letter case of venue-native symbols.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SymbolCase {
+    Upper,
+    Lower,
+}
+
+impl SymbolCase {
+    fn apply(self, value: &str) -> String {
+        match self {
+            Self::Upper => value.to_uppercase(),
+            Self::Lower => value.to_lowercase(),
+        }
+    }
+}
+
+/// Represents a single invertible step in converting a venue-native symbol.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SymbolTransform {
+    /// Folds the symbol to upper case, restoring the `native_case` when denormalizing.
+    CaseFold { native_case: SymbolCase },
+    /// Strips the `suffix` (which the native symbol must end with).
+    StripSuffix { suffix: String },
+    /// Appends the `suffix`.
+    AppendSuffix { suffix: String },
+    /// Replaces every occurrence of `from` with `to`.
+    Replace { from: String, to: String },
+    /// Rewrites the `native` pattern into the `normalized` pattern.
+    ///
+    /// Patterns are literal text with numbered captures such as `{1}`, each matching a
+    /// non-empty run of characters, e.g. `{1}-{2}-SWAP` rewritten as `{1}/{2}-PERP`. Both
+    /// patterns must use the same captures, each once, and no two captures may be adjacent.
+    Capture { native: String, normalized: String },
+}
+
+impl Display for SymbolTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CaseFold { native_case } => write!(f, "CaseFold({native_case:?})"),
+            Self::StripSuffix { suffix } => write!(f, "StripSuffix('{suffix}')"),
+            Self::AppendSuffix { suffix } => write!(f, "AppendSuffix('{suffix}')"),
+            Self::Replace { from, to } => write!(f, "Replace('{from}' -> '{to}')"),
+            Self::Capture { native, normalized } => {
+                write!(f, "Capture('{native}' -> '{normalized}')")
+            }
+        }
+    }
+}
+
+impl SymbolTransform {
+    fn forward(&self, value: &str) -> anyhow::Result<String> {
+        match self {
+            Self::CaseFold { .. } => Ok(value.to_uppercase()),
+            Self::StripSuffix { suffix } => value
+                .strip_suffix(suffix.as_str())
+                .map(ToString::to_string)
+                .ok_or_else(|| anyhow::anyhow!("'{value}' does not end with '{suffix}'")),
+            Self::AppendSuffix { suffix } => Ok(format!("{value}{suffix}")),
+            Self::Replace { from, to } => Ok(value.replace(from.as_str(), to)),
+            Self::Capture { native, normalized } => rewrite(native, normalized, value),
+        }
+    }
+
+    fn inverse(&self, value: &str) -> anyhow::Result<String> {
+        match self {
+            Self::CaseFold { native_case } => Ok(native_case.apply(value)),
+            Self::StripSuffix { suffix } => Ok(format!("{value}{suffix}")),
+            Self::AppendSuffix { suffix } => value
+                .strip_suffix(suffix.as_str())
+                .map(ToString::to_string)
+                .ok_or_else(|| anyhow::anyhow!("'{value}' does not end with '{suffix}'")),
+            Self::Replace { from, to } => Ok(value.replace(to.as_str(), from)),
+            Self::Capture { native, normalized } => rewrite(normalized, native, value),
+        }
+    }
+
+    /// Returns the literals the transform matches against its (forward) input.
+    fn matched_literals(&self) -> Vec<&str> {
+        match self {
+            Self::CaseFold { .. } | Self::AppendSuffix { .. } => Vec::new(),
+            Self::StripSuffix { suffix } => vec![suffix.as_str()],
+            Self::Replace { from, .. } => vec![from.as_str()],
+            Self::Capture { native, .. } => parse_pattern(native)
+                .map(|tokens| {
+                    tokens
+                        .into_iter()
+                        .filter_map(|token| match token {
+                            PatternToken::Literal(literal) => Some(literal),
+                            PatternToken::Capture(_) => None,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        match self {
+            Self::CaseFold { .. } => {}
+            Self::StripSuffix { suffix } | Self::AppendSuffix { suffix } => {
+                anyhow::ensure!(!suffix.is_empty(), "{self}: suffix cannot be empty");
+            }
+            Self::Replace { from, to } => {
+                anyhow::ensure!(!from.is_empty(), "{self}: `from` cannot be empty");
+                anyhow::ensure!(!to.is_empty(), "{self}: `to` cannot be empty");
+                anyhow::ensure!(from != to, "{self}: `from` and `to` are equal");
+            }
+            Self::Capture { native, normalized } => {
+                let mut native_captures = pattern_captures(native)?;
+                let mut normalized_captures = pattern_captures(normalized)?;
+                native_captures.sort_unstable();
+                normalized_captures.sort_unstable();
+                anyhow::ensure!(
+                    native_captures == normalized_captures,
+                    "{self}: patterns must use the same captures"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Represents a set of symbol transforms for a venue, as loaded from JSON.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolRuleSet {
+    pub venue: Venue,
+    pub transforms: Vec<SymbolTransform>,
+}
+
+/// Provides conversion between venue-native symbols and Nautilus symbols for a venue.
+#[derive(Clone, Debug)]
+pub struct SymbolNormalizer {
+    venue: Venue,
+    transforms: Vec<SymbolTransform>,
+}
+
+impl SymbolNormalizer {
+    /// Creates a new `SymbolNormalizer` applying the `transforms` in order.
+    ///
+    /// # Errors
+    ///
+    /// If a transform is invalid, or two transforms conflict (one would undo, shadow or never
+    /// match the output of another).
+    pub fn new(venue: Venue, transforms: Vec<SymbolTransform>) -> anyhow::Result<Self> {
+        for transform in &transforms {
+            transform.validate()?;
+        }
+        check_conflicts(&transforms)?;
+        Ok(Self { venue, transforms })
+    }
+
+    /// Creates a new `SymbolNormalizer` from the given `SymbolRuleSet` JSON.
+    ///
+    /// # Errors
+    ///
+    /// If the JSON is not a valid rule set, or the rules are invalid (see `new`).
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let rule_set: SymbolRuleSet = serde_json::from_str(json)?;
+        Self::new(rule_set.venue, rule_set.transforms)
+    }
+
+    #[must_use]
+    pub fn venue(&self) -> Venue {
+        self.venue
+    }
+
+    #[must_use]
+    pub fn transforms(&self) -> &[SymbolTransform] {
+        &self.transforms
+    }
+
+    /// Converts the venue-native symbol to a Nautilus `Symbol`.
+    ///
+    /// # Errors
+    ///
+    /// If a transform does not match, or the result does not convert back to `native`.
+    pub fn normalize(&self, native: &str) -> anyhow::Result<Symbol> {
+        let mut value = native.to_string();
+        for transform in &self.transforms {
+            value = transform
+                .forward(&value)
+                .map_err(|e| anyhow::anyhow!("Cannot normalize '{native}' ({transform}): {e}"))?;
+        }
+
+        let round_trip = self.apply_inverse(&value)?;
+        anyhow::ensure!(
+            round_trip == native,
+            "Cannot normalize '{native}': round trip is unstable ('{value}' denormalizes to '{round_trip}')"
+        );
+        Symbol::new(&value)
+    }
+
+    /// Converts the Nautilus `symbol` back to the venue-native symbol.
+    ///
+    /// # Errors
+    ///
+    /// If a transform does not match, or the result does not normalize back to `symbol`.
+    pub fn denormalize(&self, symbol: &Symbol) -> anyhow::Result<String> {
+        let native = self.apply_inverse(symbol.as_str())?;
+        let round_trip = self.normalize(&native)?;
+        anyhow::ensure!(
+            round_trip == *symbol,
+            "Cannot denormalize '{symbol}': round trip is unstable ('{native}' normalizes to '{round_trip}')"
+        );
+        Ok(native)
+    }
+
+    fn apply_inverse(&self, normalized: &str) -> anyhow::Result<String> {
+        let mut value = normalized.to_string();
+        for transform in self.transforms.iter().rev() {
+            value = transform.inverse(&value).map_err(|e| {
+                anyhow::anyhow!("Cannot denormalize '{normalized}' ({transform}): {e}")
+            })?;
+        }
+        Ok(value)
+    }
+}
+
+/// Returns the built-in normalizer with the given `name` (see `BUILTIN_RULE_SETS`), if any.
+///
+/// # Panics
+///
+/// If the built-in rule set data is invalid.
+#[must_use]
+pub fn builtin_normalizer(name: &str) -> Option<SymbolNormalizer> {
+    BUILTIN_RULE_SETS
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, json)| SymbolNormalizer::from_json(json).expect("Invalid built-in rule set"))
+}
+
+/// Checks that no two of the `transforms` conflict.
+fn check_conflicts(transforms: &[SymbolTransform]) -> anyhow::Result<()> {
+    let mut case_fold: Option<(usize, SymbolCase)> = None;
+    for (index, transform) in transforms.iter().enumerate() {
+        if let SymbolTransform::CaseFold { native_case } = transform {
+            anyhow::ensure!(
+                case_fold.is_none(),
+                "Conflicting rules: more than one case fold"
+            );
+            case_fold = Some((index, *native_case));
+        }
+    }
+
+    // Matched literals must be in the case of the symbol at that point in the rules
+    if let Some((fold_index, native_case)) = case_fold {
+        for (index, transform) in transforms.iter().enumerate() {
+            let case = if index > fold_index {
+                SymbolCase::Upper
+            } else {
+                native_case
+            };
+            for literal in transform.matched_literals() {
+                anyhow::ensure!(
+                    case.apply(literal) == literal,
+                    "Conflicting rules: {transform} can never match after {}",
+                    transforms[fold_index]
+                );
+            }
+        }
+    }
+
+    for (index, first) in transforms.iter().enumerate() {
+        for second in &transforms[index + 1..] {
+            let conflict = match (first, second) {
+                (
+                    SymbolTransform::StripSuffix { suffix: a }
+                    | SymbolTransform::AppendSuffix { suffix: a },
+                    SymbolTransform::StripSuffix { suffix: b }
+                    | SymbolTransform::AppendSuffix { suffix: b },
+                ) => a == b,
+                (
+                    SymbolTransform::Replace { from: a, to: a_to },
+                    SymbolTransform::Replace { from: b, to: b_to },
+                ) => a == b || a_to.contains(b.as_str()) || b_to.contains(a.as_str()),
+                _ => false,
+            };
+            anyhow::ensure!(!conflict, "Conflicting rules: {first} and {second}");
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PatternToken<'a> {
+    Literal(&'a str),
+    Capture(usize),
+}
+
+/// Parses the capture `pattern` into its tokens.
+fn parse_pattern(pattern: &str) -> anyhow::Result<Vec<PatternToken<'_>>> {
+    let mut tokens = Vec::new();
+    let mut rest = pattern;
+    while !rest.is_empty() {
+        match rest.find('{') {
+            Some(0) => {
+                let end = rest
+                    .find('}')
+                    .ok_or_else(|| anyhow::anyhow!("Unclosed capture in '{pattern}'"))?;
+                let index = rest[1..end]
+                    .parse::<usize>()
+                    .map_err(|_| anyhow::anyhow!("Invalid capture in '{pattern}'"))?;
+                if let Some(PatternToken::Capture(_)) = tokens.last() {
+                    anyhow::bail!("Adjacent captures in '{pattern}'");
+                }
+                tokens.push(PatternToken::Capture(index));
+                rest = &rest[end + 1..];
+            }
+            Some(start) => {
+                tokens.push(PatternToken::Literal(&rest[..start]));
+                rest = &rest[start..];
+            }
+            None => {
+                tokens.push(PatternToken::Literal(rest));
+                rest = "";
+            }
+        }
+    }
+    anyhow::ensure!(
+        tokens.iter().all(|token| match token {
+            PatternToken::Literal(literal) => !literal.contains('}'),
+            PatternToken::Capture(_) => true,
+        }),
+        "Unopened capture in '{pattern}'"
+    );
+    Ok(tokens)
+}
+
+/// Returns the capture indexes of the `pattern`, checking each is used once.
+fn pattern_captures(pattern: &str) -> anyhow::Result<Vec<usize>> {
+    let captures: Vec<usize> = parse_pattern(pattern)?
+        .into_iter()
+        .filter_map(|token| match token {
+            PatternToken::Capture(index) => Some(index),
+            PatternToken::Literal(_) => None,
+        })
+        .collect();
+    anyhow::ensure!(!captures.is_empty(), "No captures in '{pattern}'");
+    for (position, index) in captures.iter().enumerate() {
+        anyhow::ensure!(
+            !captures[position + 1..].contains(index),
+            "Capture {{{index}}} used more than once in '{pattern}'"
+        );
+    }
+    Ok(captures)
+}
+
+/// Rewrites the `value` matching the `from` pattern into the `to` pattern.
+fn rewrite(from: &str, to: &str, value: &str) -> anyhow::Result<String> {
+    let mut captures = Vec::new();
+    anyhow::ensure!(
+        match_tokens(&parse_pattern(from)?, value, &mut captures),
+        "'{value}' does not match '{from}'"
+    );
+
+    let mut output = String::with_capacity(value.len());
+    for token in parse_pattern(to)? {
+        match token {
+            PatternToken::Literal(literal) => output.push_str(literal),
+            PatternToken::Capture(index) => {
+                let (_, captured) = captures
+                    .iter()
+                    .find(|(capture, _)| *capture == index)
+                    .ok_or_else(|| anyhow::anyhow!("Capture {{{index}}} not in '{from}'"))?;
+                output.push_str(captured);
+            }
+        }
+    }
+    Ok(output)
+}
+
+/// Matches the `value` against the pattern `tokens`, recording the captured text.
+///
+/// Captures are tried shortest first, backtracking until the whole value matches.
+fn match_tokens<'a>(
+    tokens: &[PatternToken<'_>],
+    value: &'a str,
+    captures: &mut Vec<(usize, &'a str)>,
+) -> bool {
+    match tokens.split_first() {
+        None => value.is_empty(),
+        Some((PatternToken::Literal(literal), rest)) => value
+            .strip_prefix(literal)
+            .is_some_and(|value| match_tokens(rest, value, captures)),
+        Some((PatternToken::Capture(_), _)) if value.is_empty() => false,
+        Some((PatternToken::Capture(index), rest)) => {
+            for (end, _) in value.char_indices().skip(1).chain([(value.len(), ' ')]) {
+                captures.push((*index, &value[..end]));
+                if match_tokens(rest, &value[end..], captures) {
+                    return true;
+                }
+                captures.pop();
+            }
+            false
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn replace(from: &str, to: &str) -> SymbolTransform {
+        SymbolTransform::Replace {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    fn strip(suffix: &str) -> SymbolTransform {
+        SymbolTransform::StripSuffix {
+            suffix: suffix.to_string(),
+        }
+    }
+
+    fn append(suffix: &str) -> SymbolTransform {
+        SymbolTransform::AppendSuffix {
+            suffix: suffix.to_string(),
+        }
+    }
+
+    fn capture(native: &str, normalized: &str) -> SymbolTransform {
+        SymbolTransform::Capture {
+            native: native.to_string(),
+            normalized: normalized.to_string(),
+        }
+    }
+
+    fn fold(native_case: SymbolCase) -> SymbolTransform {
+        SymbolTransform::CaseFold { native_case }
+    }
+
+    fn normalizer(transforms: Vec<SymbolTransform>) -> anyhow::Result<SymbolNormalizer> {
+        SymbolNormalizer::new(Venue::from("SIM"), transforms)
+    }
+
+    #[rstest]
+    #[case("binance_futures", "BINANCE", "BTCUSDT", "BTCUSDT-PERP")]
+    #[case("binance_futures", "BINANCE", "1000PEPEUSDT", "1000PEPEUSDT-PERP")]
+    #[case("gateio_spot", "GATEIO", "BTC_USDT", "BTC/USDT")]
+    #[case("gateio_spot", "GATEIO", "ETH3L_USDT", "ETH3L/USDT")]
+    #[case("okx_swap", "OKX", "BTC-USDT-SWAP", "BTC/USDT-PERP")]
+    #[case("okx_swap", "OKX", "ETH-USD-SWAP", "ETH/USD-PERP")]
+    fn test_builtin_round_trip(
+        #[case] name: &str,
+        #[case] venue: &str,
+        #[case] native: &str,
+        #[case] expected: &str,
+    ) {
+        let normalizer = builtin_normalizer(name).unwrap();
+
+        let symbol = normalizer.normalize(native).unwrap();
+        let round_trip = normalizer.denormalize(&symbol).unwrap();
+
+        assert_eq!(normalizer.venue(), Venue::from(venue));
+        assert_eq!(symbol, Symbol::from(expected));
+        assert_eq!(round_trip, native);
+    }
+
+    #[rstest]
+    fn test_builtin_rule_sets_are_valid() {
+        for (name, _) in BUILTIN_RULE_SETS {
+            assert!(builtin_normalizer(name).is_some(), "{name}");
+        }
+        assert!(builtin_normalizer("unknown").is_none());
+    }
+
+    #[rstest]
+    fn test_normalize_lower_case_venue() {
+        let normalizer = normalizer(vec![fold(SymbolCase::Lower), append("-PERP")]).unwrap();
+
+        let symbol = normalizer.normalize("btcusdt").unwrap();
+
+        assert_eq!(symbol, Symbol::from("BTCUSDT-PERP"));
+        assert_eq!(normalizer.denormalize(&symbol).unwrap(), "btcusdt");
+    }
+
+    #[rstest]
+    fn test_strip_suffix_requires_suffix() {
+        let normalizer = normalizer(vec![strip("-SWAP")]).unwrap();
+
+        assert_eq!(
+            normalizer.normalize("BTC-USDT-SWAP").unwrap(),
+            Symbol::from("BTC-USDT")
+        );
+        assert!(normalizer.normalize("BTC-USDT").is_err());
+    }
+
+    #[rstest]
+    fn test_capture_backtracks_to_match() {
+        let normalizer = normalizer(vec![capture("{1}-{2}-SWAP", "{2}:{1}")]).unwrap();
+
+        let symbol = normalizer.normalize("BTC-USD-T-SWAP").unwrap();
+
+        assert_eq!(symbol, Symbol::from("USD-T:BTC"));
+        assert_eq!(normalizer.denormalize(&symbol).unwrap(), "BTC-USD-T-SWAP");
+        assert!(normalizer.normalize("BTC-SWAP").is_err());
+    }
+
+    #[rstest]
+    fn test_unstable_round_trip_rejected() {
+        let normalizer = builtin_normalizer("gateio_spot").unwrap();
+
+        // The '/' would denormalize to '_'
+        let slash = normalizer.normalize("BTC/USDT");
+        // The native case would be lost
+        let lower = normalizer.normalize("btc_usdt");
+
+        assert!(slash.is_err());
+        assert!(lower.is_err());
+    }
+
+    #[rstest]
+    #[case::two_case_folds(vec![fold(SymbolCase::Upper), fold(SymbolCase::Lower)])]
+    #[case::strip_then_append(vec![strip("-PERP"), append("-PERP")])]
+    #[case::duplicate_append(vec![append("-PERP"), append("-PERP")])]
+    #[case::shadowed_replace(vec![replace("_", "/"), replace("_", "-")])]
+    #[case::chained_replace(vec![replace("_", "/"), replace("/", "-")])]
+    #[case::lower_suffix_after_fold(vec![fold(SymbolCase::Upper), strip("-swap")])]
+    #[case::upper_literal_before_lower_fold(vec![strip("-SWAP"), fold(SymbolCase::Lower)])]
+    fn test_conflicting_rules_rejected(#[case] transforms: Vec<SymbolTransform>) {
+        assert!(normalizer(transforms).is_err());
+    }
+
+    #[rstest]
+    #[case::empty_suffix(vec![strip("")])]
+    #[case::identity_replace(vec![replace("_", "_")])]
+    #[case::adjacent_captures(vec![capture("{1}{2}", "{1}/{2}")])]
+    #[case::mismatched_captures(vec![capture("{1}-{2}", "{1}")])]
+    #[case::repeated_capture(vec![capture("{1}-{1}", "{1}/{1}")])]
+    #[case::unclosed_capture(vec![capture("{1", "{1}")])]
+    #[case::no_captures(vec![capture("BTC", "XBT")])]
+    fn test_invalid_rules_rejected(#[case] transforms: Vec<SymbolTransform>) {
+        assert!(normalizer(transforms).is_err());
+    }
+
+    #[rstest]
+    fn test_from_json_invalid() {
+        let json = r#"{"venue": "SIM", "transforms": [{"type": "Reverse"}]}"#;
+
+        assert!(SymbolNormalizer::from_json(json).is_err());
+    }
+}
@@ -220,6 +220,122 @@ impl Iterator for TestTimer {
     }
 }
 
+/// The sorted set of times at which test timers will fire, up to the end of a time range.
+///
+/// Advancing a `TestClock` checks every timer, even though with sparse timers almost all of
+/// those checks produce no events. With the fire times precomputed, the clock only advances its
+/// timers when a fire time is crossed. The schedule is a snapshot of the timers when it was
+/// built, and must be rebuilt whenever a timer is set or canceled.
+#[derive(Clone, Debug)]
+pub struct TimerFireSchedule {
+    /// The fire times in descending order, so the next is popped from the back.
+    fire_times: Vec<u64>,
+    end_ns: UnixNanos,
+}
+
+impl TimerFireSchedule {
+    /// Creates a new empty schedule for a time range ending at `end_ns`.
+    #[must_use]
+    pub fn new(end_ns: UnixNanos) -> Self {
+        Self {
+            fire_times: Vec::new(),
+            end_ns,
+        }
+    }
+
+    /// Creates a new schedule from the given `timers`.
+    #[must_use]
+    pub fn from_timers<'a>(
+        timers: impl IntoIterator<Item = &'a TestTimer>,
+        end_ns: UnixNanos,
+    ) -> Self {
+        let mut schedule = Self::new(end_ns);
+        for timer in timers {
+            schedule.add_timer(timer);
+        }
+        schedule
+    }
+
+    /// Returns the end of the time range the schedule covers.
+    #[must_use]
+    pub fn end_ns(&self) -> UnixNanos {
+        self.end_ns
+    }
+
+    /// Returns the number of fire times remaining.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.fire_times.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fire_times.is_empty()
+    }
+
+    /// Returns the next fire time (if any).
+    #[must_use]
+    pub fn next_fire_ns(&self) -> Option<UnixNanos> {
+        self.fire_times.last().copied().map(UnixNanos::from)
+    }
+
+    /// Adds the remaining fire times of the given `timer` (unless expired).
+    pub fn add_timer(&mut self, timer: &TestTimer) {
+        if timer.is_expired() {
+            return;
+        }
+
+        self.add_interval(
+            timer.interval_ns.get(),
+            timer.next_time_ns(),
+            timer.stop_time_ns,
+        );
+    }
+
+    /// Adds the fire times of a timer which next fires at `next_time_ns` and then every
+    /// `interval_ns`, until the first fire time at or after `stop_time_ns`.
+    pub fn add_interval(
+        &mut self,
+        interval_ns: u64,
+        next_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    ) {
+        let interval_ns = interval_ns.max(1);
+        let end_ns = self.end_ns.as_u64();
+        let mut next_ns = Some(next_time_ns.as_u64());
+
+        while let Some(time_ns) = next_ns.filter(|time_ns| *time_ns <= end_ns) {
+            self.fire_times.push(time_ns);
+            // Matches `TestTimer`, which fires once at or beyond the stop time then expires
+            if stop_time_ns.is_some_and(|stop_ns| time_ns >= stop_ns.as_u64()) {
+                break;
+            }
+            next_ns = time_ns.checked_add(interval_ns);
+        }
+
+        self.fire_times.sort_unstable_by(|a, b| b.cmp(a));
+        self.fire_times.dedup();
+    }
+
+    /// Returns whether timers must be advanced to reach `to_time_ns`, consuming the fire times
+    /// up to and including it.
+    ///
+    /// Times beyond the end of the schedule are always due, as fire times there are unknown.
+    pub fn is_due(&mut self, to_time_ns: UnixNanos) -> bool {
+        let to_time_ns = to_time_ns.as_u64();
+        let mut is_due = to_time_ns > self.end_ns.as_u64();
+        while self
+            .fire_times
+            .last()
+            .is_some_and(|time_ns| *time_ns <= to_time_ns)
+        {
+            self.fire_times.pop();
+            is_due = true;
+        }
+        is_due
+    }
+}
+
 /// Provides a live timer for use with a `LiveClock`.
 pub struct LiveTimer {
     pub name: Ustr,
@@ -391,7 +507,7 @@ mod tests {
     use rstest::*;
     use tokio::time::Duration;
 
-    use super::{LiveTimer, TestTimer, TimeEvent};
+    use super::{LiveTimer, TestTimer, TimeEvent, TimerFireSchedule};
     use crate::{handlers::EventHandler, testing::wait_until};
 
     #[pyfunction]
@@ -551,4 +667,50 @@ mod tests {
 
         wait_until(|| timer.is_expired(), Duration::from_secs(2));
     }
+
+    fn drain_fire_times(schedule: &mut TimerFireSchedule) -> Vec<u64> {
+        let mut fire_times = Vec::new();
+        while let Some(time_ns) = schedule.next_fire_ns() {
+            assert!(schedule.is_due(time_ns));
+            fire_times.push(time_ns.as_u64());
+        }
+        fire_times
+    }
+
+    #[rstest]
+    fn test_fire_schedule_add_interval_merges_and_dedups() {
+        let mut schedule = TimerFireSchedule::new(UnixNanos::from(60));
+
+        schedule.add_interval(10, UnixNanos::from(10), Some(UnixNanos::from(25)));
+        schedule.add_interval(15, UnixNanos::from(15), None);
+
+        assert_eq!(
+            drain_fire_times(&mut schedule),
+            vec![10, 15, 20, 30, 45, 60]
+        );
+    }
+
+    #[rstest]
+    fn test_fire_schedule_is_due_consumes_fire_times() {
+        let mut schedule = TimerFireSchedule::new(UnixNanos::from(100));
+        schedule.add_interval(50, UnixNanos::from(50), None);
+
+        assert!(!schedule.is_due(UnixNanos::from(49)));
+        assert!(schedule.is_due(UnixNanos::from(99)));
+        assert!(!schedule.is_due(UnixNanos::from(99)));
+        assert!(schedule.is_due(UnixNanos::from(100)));
+        assert!(schedule.is_empty());
+        assert!(schedule.is_due(UnixNanos::from(101))); // Beyond the end of the schedule
+    }
+
+    #[rstest]
+    fn test_fire_schedule_from_timers_skips_expired() {
+        let active = TestTimer::new("ACTIVE", 10, UnixNanos::from(0), None).unwrap();
+        let mut expired = TestTimer::new("EXPIRED", 5, UnixNanos::from(0), None).unwrap();
+        expired.cancel();
+
+        let mut schedule = TimerFireSchedule::from_timers([&active, &expired], UnixNanos::from(30));
+
+        assert_eq!(drain_fire_times(&mut schedule), vec![10, 20, 30]);
+    }
 }
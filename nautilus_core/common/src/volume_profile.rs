@@ -0,0 +1,307 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `VolumeProfileAggregator` which builds `VolumeProfile`s from trade ticks over
+//! fixed time windows, or over the sessions of a `TradingCalendar`.
+
+use nautilus_core::{correctness::check_positive_u64, nanos::UnixNanos};
+use nautilus_model::{
+    data::{trade::TradeTick, volume_profile::VolumeProfile},
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
+
+use crate::calendar::TradingCalendar;
+
+/// The window over which a `VolumeProfileAggregator` accumulates a profile.
+#[derive(Clone, Debug)]
+pub enum ProfileWindow {
+    /// Fixed windows of the given nanoseconds, aligned to multiples from the UNIX epoch.
+    Interval(u64),
+    /// The sessions of the calendar, where trades outside of a session are ignored.
+    Session(TradingCalendar),
+}
+
+/// Aggregates trade ticks into a `VolumeProfile` per window.
+///
+/// A profile is completed when a trade (or time) reaches the end of its window, and only
+/// windows containing at least one trade produce a profile. Trades with a `ts_event` before
+/// the current window are included in the current window.
+#[derive(Clone, Debug)]
+pub struct VolumeProfileAggregator {
+    pub instrument_id: InstrumentId,
+    window: ProfileWindow,
+    current: Option<VolumeProfile>,
+}
+
+impl VolumeProfileAggregator {
+    /// Creates a new [`VolumeProfileAggregator`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if an `Interval` window is not positive.
+    pub fn new(instrument_id: InstrumentId, window: ProfileWindow) -> anyhow::Result<Self> {
+        if let ProfileWindow::Interval(window_ns) = window {
+            check_positive_u64(window_ns, stringify!(window_ns))?;
+        }
+
+        Ok(Self {
+            instrument_id,
+            window,
+            current: None,
+        })
+    }
+
+    #[must_use]
+    pub fn window(&self) -> &ProfileWindow {
+        &self.window
+    }
+
+    /// Returns the profile for the current window (if any trades have been handled).
+    #[must_use]
+    pub fn current(&self) -> Option<&VolumeProfile> {
+        self.current.as_ref()
+    }
+
+    /// Returns the volume at each price level of the current window, in ascending order of
+    /// price.
+    #[must_use]
+    pub fn profile(&self) -> Vec<(Price, Quantity)> {
+        self.current
+            .as_ref()
+            .map(VolumeProfile::profile)
+            .unwrap_or_default()
+    }
+
+    /// Returns the point of control of the current window (if any trades have been handled).
+    #[must_use]
+    pub fn poc(&self) -> Option<(Price, Quantity)> {
+        self.current.as_ref().and_then(VolumeProfile::poc)
+    }
+
+    /// Returns the value area of the current window containing `pct` of the volume traded.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `pct` is not in the range [0, 1].
+    pub fn value_area(&self, pct: f64) -> anyhow::Result<Option<(Price, Price)>> {
+        match &self.current {
+            Some(profile) => profile.value_area(pct),
+            None => Ok(None),
+        }
+    }
+
+    /// Handles the given `trade`, returning the previous profile if the trade is at or after
+    /// the end of its window.
+    pub fn handle_trade(&mut self, trade: &TradeTick) -> Option<VolumeProfile> {
+        let completed = self.handle_time(trade.ts_event);
+
+        match &mut self.current {
+            Some(profile) => profile.update(trade),
+            None => {
+                if let Some((ts_start, ts_end)) = self.window_for(trade.ts_event) {
+                    self.current = Some(VolumeProfile::new(trade, ts_start, ts_end));
+                }
+            }
+        }
+
+        completed
+    }
+
+    /// Completes and returns the current profile if `ts` is at or after the end of its window,
+    /// such as on a timer at the session close.
+    pub fn handle_time(&mut self, ts: UnixNanos) -> Option<VolumeProfile> {
+        match &self.current {
+            Some(profile) if ts >= profile.ts_end => self.flush(),
+            _ => None,
+        }
+    }
+
+    /// Completes and returns the current profile (if any trades have been handled).
+    pub fn flush(&mut self) -> Option<VolumeProfile> {
+        self.current.take()
+    }
+
+    /// Resets the aggregator, discarding the current profile.
+    pub fn reset(&mut self) {
+        self.current = None;
+    }
+
+    fn window_for(&self, ts: UnixNanos) -> Option<(UnixNanos, UnixNanos)> {
+        match &self.window {
+            ProfileWindow::Interval(window_ns) => {
+                let ts_start = ts.as_u64() - ts.as_u64() % window_ns;
+                Some((ts_start.into(), (ts_start + window_ns).into()))
+            }
+            ProfileWindow::Session(calendar) => calendar
+                .session_for(ts)
+                .map(|session| (session.open, session.close)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, NaiveTime, Weekday};
+    use chrono_tz::America::New_York;
+    use nautilus_model::{enums::AggressorSide, identifiers::trade_id::TradeId};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::calendar::SessionTemplate;
+
+    const WINDOW_NS: u64 = 1_000;
+
+    fn utc(s: &str) -> UnixNanos {
+        let datetime = DateTime::parse_from_rfc3339(s).unwrap();
+        UnixNanos::from(datetime.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    fn trade(price: &str, size: i64, ts_event: UnixNanos) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from("AAPL.XNAS"),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            ts_event,
+            ts_event,
+        )
+        .unwrap()
+    }
+
+    /// NYSE: Monday to Friday 09:30 ET until 16:00 ET.
+    #[fixture]
+    fn nyse() -> TradingCalendar {
+        let templates = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]
+        .into_iter()
+        .map(|weekday| {
+            SessionTemplate::new(
+                weekday,
+                NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+                NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            )
+        })
+        .collect();
+        TradingCalendar::new(New_York, templates, vec![])
+    }
+
+    #[rstest]
+    fn test_new_with_zero_interval() {
+        let result = VolumeProfileAggregator::new(
+            InstrumentId::from("AAPL.XNAS"),
+            ProfileWindow::Interval(0),
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_interval_windows() {
+        let mut aggregator = VolumeProfileAggregator::new(
+            InstrumentId::from("AAPL.XNAS"),
+            ProfileWindow::Interval(WINDOW_NS),
+        )
+        .unwrap();
+
+        assert!(aggregator
+            .handle_trade(&trade("150.00", 10, 100.into()))
+            .is_none());
+        assert!(aggregator
+            .handle_trade(&trade("150.01", 30, 200.into()))
+            .is_none());
+        assert_eq!(
+            aggregator.poc(),
+            Some((Price::from("150.01"), Quantity::from(30)))
+        );
+
+        let completed = aggregator
+            .handle_trade(&trade("150.02", 5, 1_500.into()))
+            .unwrap();
+
+        assert_eq!(completed.volume, Quantity::from(40));
+        assert_eq!(completed.ts_start, UnixNanos::from(0));
+        assert_eq!(completed.ts_end, UnixNanos::from(WINDOW_NS));
+        assert_eq!(
+            aggregator.profile(),
+            vec![(Price::from("150.02"), Quantity::from(5))]
+        );
+        assert_eq!(
+            aggregator.current().unwrap().ts_start,
+            UnixNanos::from(1_000)
+        );
+    }
+
+    #[rstest]
+    fn test_session_resets_on_session_boundary(nyse: TradingCalendar) {
+        let mut aggregator = VolumeProfileAggregator::new(
+            InstrumentId::from("AAPL.XNAS"),
+            ProfileWindow::Session(nyse),
+        )
+        .unwrap();
+
+        // Pre-market trade is outside the session
+        assert!(aggregator
+            .handle_trade(&trade("149.00", 100, utc("2024-03-04T14:00:00Z")))
+            .is_none());
+        assert!(aggregator.current().is_none());
+
+        aggregator.handle_trade(&trade("150.00", 10, utc("2024-03-04T14:30:00Z")));
+        aggregator.handle_trade(&trade("150.05", 20, utc("2024-03-04T20:59:59Z")));
+
+        let completed = aggregator
+            .handle_trade(&trade("151.00", 5, utc("2024-03-05T14:31:00Z")))
+            .unwrap();
+
+        assert_eq!(completed.ts_start, utc("2024-03-04T14:30:00Z"));
+        assert_eq!(completed.ts_end, utc("2024-03-04T21:00:00Z"));
+        assert_eq!(completed.volume, Quantity::from(30));
+        assert_eq!(
+            completed.poc(),
+            Some((Price::from("150.05"), Quantity::from(20)))
+        );
+        assert_eq!(
+            aggregator.profile(),
+            vec![(Price::from("151.00"), Quantity::from(5))]
+        );
+    }
+
+    #[rstest]
+    fn test_handle_time_completes_at_session_close(nyse: TradingCalendar) {
+        let mut aggregator = VolumeProfileAggregator::new(
+            InstrumentId::from("AAPL.XNAS"),
+            ProfileWindow::Session(nyse),
+        )
+        .unwrap();
+        aggregator.handle_trade(&trade("150.00", 10, utc("2024-03-04T15:00:00Z")));
+
+        assert!(aggregator
+            .handle_time(utc("2024-03-04T20:59:59Z"))
+            .is_none());
+        assert!(aggregator
+            .handle_time(utc("2024-03-04T21:00:00Z"))
+            .is_some());
+        assert!(aggregator.current().is_none());
+        assert_eq!(aggregator.value_area(0.7).unwrap(), None);
+    }
+}
@@ -21,11 +21,22 @@
 //! Provides exchange rate calculations between currencies.
 //!
 //! An exchange rate is the value of one asset versus that of another.
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+};
 
 use itertools::Itertools;
-use nautilus_core::correctness::{check_equal_usize, check_map_not_empty};
-use nautilus_model::{enums::PriceType, identifiers::symbol::Symbol, types::currency::Currency};
+use nautilus_core::{
+    correctness::{check_equal_usize, check_map_not_empty},
+    nanos::UnixNanos,
+};
+use nautilus_model::{
+    data::quote::QuoteTick,
+    enums::PriceType,
+    identifiers::symbol::Symbol,
+    types::{currency::Currency, fixed::FIXED_PRECISION, price::Price},
+};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use ustr::Ustr;
@@ -148,3 +159,375 @@ pub fn get_exchange_rate(
 
     Ok(quotes.get(&to_currency.code).copied().unwrap_or(dec!(0.0)))
 }
+
+/// Represents an exchange rate along with the timestamp of the oldest quote it was derived from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExchangeRate {
+    pub rate: Decimal,
+    /// UNIX timestamp (nanoseconds) of the oldest component quote.
+    pub ts_event: UnixNanos,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RateQuote {
+    bid: Decimal,
+    ask: Decimal,
+    ts_event: UnixNanos,
+}
+
+impl RateQuote {
+    /// Returns the reciprocal quote for the inverse pair (see `QuoteTick::invert_prices`), at
+    /// the maximum fixed precision.
+    ///
+    /// Returns `None` if either price is not positive or out of range.
+    fn inverted(&self) -> Option<Self> {
+        let bid = Price::from_decimal(self.bid, FIXED_PRECISION).ok()?;
+        let ask = Price::from_decimal(self.ask, FIXED_PRECISION).ok()?;
+        let (bid, ask) = QuoteTick::invert_prices(bid, ask, FIXED_PRECISION).ok()?;
+        Some(Self {
+            bid: bid.as_decimal(),
+            ask: ask.as_decimal(),
+            ts_event: self.ts_event,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CachedRate {
+    rate: ExchangeRate,
+    components: Vec<Symbol>,
+}
+
+#[derive(Debug, Default)]
+struct ExchangeRateCacheInner {
+    quotes: HashMap<Symbol, RateQuote>,
+    rates: HashMap<(Ustr, Ustr, PriceType), CachedRate>,
+}
+
+impl ExchangeRateCacheInner {
+    /// Returns the rate from `lhs` to `rhs` from a direct or inverse quote, with its symbol.
+    fn direct_rate(
+        &self,
+        lhs: Ustr,
+        rhs: Ustr,
+        price_type: PriceType,
+    ) -> Option<(ExchangeRate, Symbol)> {
+        let price = |quote: &RateQuote| match price_type {
+            PriceType::Bid => Some(quote.bid),
+            PriceType::Ask => Some(quote.ask),
+            PriceType::Mid => Some((quote.bid + quote.ask) / DECIMAL_TWO),
+            _ => None,
+        };
+
+        let symbol = Symbol::from(format!("{lhs}/{rhs}").as_str());
+        if let Some(quote) = self.quotes.get(&symbol) {
+            let rate = price(quote)?;
+            return Some((
+                ExchangeRate {
+                    rate,
+                    ts_event: quote.ts_event,
+                },
+                symbol,
+            ));
+        }
+
+        let symbol = Symbol::from(format!("{rhs}/{lhs}").as_str());
+        let quote = self.quotes.get(&symbol)?.inverted()?;
+        let rate = price(&quote)?;
+        Some((
+            ExchangeRate {
+                rate,
+                ts_event: quote.ts_event,
+            },
+            symbol,
+        ))
+    }
+
+    /// Derives the rate from `from` to `to` directly, by inverse, or via one intermediate
+    /// currency.
+    fn derive_rate(&self, from: Ustr, to: Ustr, price_type: PriceType) -> Option<CachedRate> {
+        if let Some((rate, symbol)) = self.direct_rate(from, to, price_type) {
+            return Some(CachedRate {
+                rate,
+                components: vec![symbol],
+            });
+        }
+
+        let mut codes: Vec<Ustr> = self
+            .quotes
+            .keys()
+            .flat_map(|symbol| symbol.as_str().split('/').map(Ustr::from))
+            .filter(|code| *code != from && *code != to)
+            .collect();
+        codes.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str())); // Deterministic path choice
+        codes.dedup();
+
+        codes.into_iter().find_map(|via| {
+            let (first, first_symbol) = self.direct_rate(from, via, price_type)?;
+            let (second, second_symbol) = self.direct_rate(via, to, price_type)?;
+            Some(CachedRate {
+                rate: ExchangeRate {
+                    rate: first.rate * second.rate,
+                    ts_event: first.ts_event.min(second.ts_event),
+                },
+                components: vec![first_symbol, second_symbol],
+            })
+        })
+    }
+}
+
+/// Provides a thread-safe memoizing cache of exchange rates.
+///
+/// Rates are derived from direct or inverse quotes, or crossed through one intermediate
+/// currency, and memoized per (from, to, price type) until a newer quote arrives for one
+/// of the pairs the rate was derived from.
+#[derive(Debug, Default)]
+pub struct ExchangeRateCache {
+    inner: Mutex<ExchangeRateCacheInner>,
+}
+
+impl ExchangeRateCache {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the quote for the given `symbol` (e.g. "AUD/USD").
+    ///
+    /// Returns `false` if the quote is older than the one already held, in which case it is
+    /// ignored.
+    pub fn update_quote(
+        &self,
+        symbol: Symbol,
+        bid: Decimal,
+        ask: Decimal,
+        ts_event: UnixNanos,
+    ) -> bool {
+        let mut inner = self.inner.lock().expect("Failed to acquire lock");
+        let is_new_pair = match inner.quotes.get(&symbol) {
+            Some(existing) if existing.ts_event > ts_event => return false,
+            Some(_) => false,
+            None => true,
+        };
+
+        inner
+            .quotes
+            .insert(symbol, RateQuote { bid, ask, ts_event });
+
+        if is_new_pair {
+            // A new pair may open a more direct path for any rate
+            inner.rates.clear();
+        } else {
+            inner
+                .rates
+                .retain(|_, cached| !cached.components.contains(&symbol));
+        }
+        true
+    }
+
+    /// Returns the exchange rate from `from_currency` to `to_currency` for the given
+    /// `price_type`, or `None` if it cannot be derived from the current quotes.
+    #[must_use]
+    pub fn rate(
+        &self,
+        from_currency: Currency,
+        to_currency: Currency,
+        price_type: PriceType,
+    ) -> Option<Decimal> {
+        self.rate_with_ts(from_currency, to_currency, price_type)
+            .map(|rate| rate.rate)
+    }
+
+    /// Returns the exchange rate from `from_currency` to `to_currency` for the given
+    /// `price_type`, along with the timestamp of the oldest quote it was derived from so
+    /// callers can enforce staleness limits.
+    #[must_use]
+    pub fn rate_with_ts(
+        &self,
+        from_currency: Currency,
+        to_currency: Currency,
+        price_type: PriceType,
+    ) -> Option<ExchangeRate> {
+        let mut inner = self.inner.lock().expect("Failed to acquire lock");
+        if from_currency == to_currency {
+            let ts_event = inner
+                .quotes
+                .values()
+                .map(|quote| quote.ts_event)
+                .max()
+                .unwrap_or_default();
+            return Some(ExchangeRate {
+                rate: DECIMAL_ONE,
+                ts_event,
+            });
+        }
+
+        let key = (from_currency.code, to_currency.code, price_type);
+        if let Some(cached) = inner.rates.get(&key) {
+            return Some(cached.rate);
+        }
+
+        let cached = inner.derive_rate(from_currency.code, to_currency.code, price_type)?;
+        let rate = cached.rate;
+        inner.rates.insert(key, cached);
+        Some(rate)
+    }
+
+    /// Clears all quotes and memoized rates.
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().expect("Failed to acquire lock");
+        inner.quotes.clear();
+        inner.rates.clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, thread};
+
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn xrate_cache() -> ExchangeRateCache {
+        let cache = ExchangeRateCache::new();
+        cache.update_quote(
+            Symbol::from("AUD/USD"),
+            dec!(0.80),
+            dec!(0.82),
+            UnixNanos::from(1),
+        );
+        cache.update_quote(
+            Symbol::from("USD/JPY"),
+            dec!(110.0),
+            dec!(111.0),
+            UnixNanos::from(2),
+        );
+        cache
+    }
+
+    #[rstest]
+    fn test_rate_same_currency(xrate_cache: ExchangeRateCache) {
+        let rate = xrate_cache.rate(Currency::USD(), Currency::USD(), PriceType::Mid);
+        assert_eq!(rate, Some(DECIMAL_ONE));
+    }
+
+    #[rstest]
+    #[case(PriceType::Bid, dec!(0.80))]
+    #[case(PriceType::Ask, dec!(0.82))]
+    #[case(PriceType::Mid, dec!(0.81))]
+    fn test_rate_direct(
+        xrate_cache: ExchangeRateCache,
+        #[case] price_type: PriceType,
+        #[case] expected: Decimal,
+    ) {
+        let rate = xrate_cache.rate(Currency::AUD(), Currency::USD(), price_type);
+        assert_eq!(rate, Some(expected));
+    }
+
+    #[rstest]
+    #[case(PriceType::Bid, dec!(0.009009009))]
+    #[case(PriceType::Ask, dec!(0.009090909))]
+    fn test_rate_inverse(
+        xrate_cache: ExchangeRateCache,
+        #[case] price_type: PriceType,
+        #[case] expected: Decimal,
+    ) {
+        let rate = xrate_cache.rate(Currency::JPY(), Currency::USD(), price_type);
+        assert_eq!(rate, Some(expected));
+    }
+
+    #[rstest]
+    fn test_rate_cross_reports_oldest_component_ts(xrate_cache: ExchangeRateCache) {
+        let rate = xrate_cache
+            .rate_with_ts(Currency::AUD(), Currency::JPY(), PriceType::Bid)
+            .unwrap();
+        assert_eq!(rate.rate, dec!(88.0));
+        assert_eq!(rate.ts_event, UnixNanos::from(1));
+    }
+
+    #[rstest]
+    fn test_rate_when_no_path(xrate_cache: ExchangeRateCache) {
+        let rate = xrate_cache.rate(Currency::AUD(), Currency::GBP(), PriceType::Mid);
+        assert_eq!(rate, None);
+    }
+
+    #[rstest]
+    fn test_rate_invalidated_on_component_quote_update(xrate_cache: ExchangeRateCache) {
+        let before = xrate_cache.rate(Currency::AUD(), Currency::JPY(), PriceType::Bid);
+        assert!(xrate_cache.update_quote(
+            Symbol::from("AUD/USD"),
+            dec!(0.90),
+            dec!(0.92),
+            UnixNanos::from(10),
+        ));
+        let after = xrate_cache
+            .rate_with_ts(Currency::AUD(), Currency::JPY(), PriceType::Bid)
+            .unwrap();
+
+        assert_eq!(before, Some(dec!(88.0)));
+        assert_eq!(after.rate, dec!(99.0));
+        assert_eq!(after.ts_event, UnixNanos::from(2)); // USD/JPY is now the oldest
+    }
+
+    #[rstest]
+    fn test_update_quote_ignores_older_quote(xrate_cache: ExchangeRateCache) {
+        let updated = xrate_cache.update_quote(
+            Symbol::from("AUD/USD"),
+            dec!(0.50),
+            dec!(0.52),
+            UnixNanos::from(0),
+        );
+        let rate = xrate_cache.rate(Currency::AUD(), Currency::USD(), PriceType::Bid);
+
+        assert!(!updated);
+        assert_eq!(rate, Some(dec!(0.80)));
+    }
+
+    #[rstest]
+    fn test_stale_rate_reported_with_true_age(xrate_cache: ExchangeRateCache) {
+        // Memoize, then update an unrelated pair which must not refresh the rate timestamp
+        let rate = xrate_cache
+            .rate_with_ts(Currency::AUD(), Currency::USD(), PriceType::Mid)
+            .unwrap();
+        xrate_cache.update_quote(
+            Symbol::from("USD/JPY"),
+            dec!(112.0),
+            dec!(113.0),
+            UnixNanos::from(1_000),
+        );
+        let cached = xrate_cache
+            .rate_with_ts(Currency::AUD(), Currency::USD(), PriceType::Mid)
+            .unwrap();
+
+        assert_eq!(rate, cached);
+        assert_eq!(cached.ts_event, UnixNanos::from(1));
+    }
+
+    #[rstest]
+    fn test_shared_across_threads(xrate_cache: ExchangeRateCache) {
+        let xrate_cache = Arc::new(xrate_cache);
+        let handles: Vec<_> = (0..4u64)
+            .map(|i| {
+                let xrate_cache = Arc::clone(&xrate_cache);
+                thread::spawn(move || {
+                    xrate_cache.update_quote(
+                        Symbol::from("AUD/USD"),
+                        dec!(0.80),
+                        dec!(0.82),
+                        UnixNanos::from(10 + i),
+                    );
+                    xrate_cache.rate(Currency::AUD(), Currency::JPY(), PriceType::Bid)
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), Some(dec!(88.0)));
+        }
+    }
+}
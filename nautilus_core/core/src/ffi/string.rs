@@ -15,7 +15,8 @@
 
 use std::{
     ffi::{c_char, CStr, CString},
-    str,
+    fmt::{Display, Formatter},
+    ptr, str,
 };
 
 use pyo3::{ffi, types::PyString, FromPyPointer, Python};
@@ -113,6 +114,159 @@ pub unsafe fn optional_cstr_to_str(ptr: *const c_char) -> Option<&'static str> {
     }
 }
 
+/// The maximum length (bytes) of an identifier C string.
+pub const IDENTIFIER_MAX_LEN: usize = 256;
+
+/// The maximum length (bytes) of a free text C string, such as a reason.
+pub const TEXT_MAX_LEN: usize = 4_096;
+
+/// The character set policy for validating a C string received over FFI.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CharsetPolicy {
+    /// Printable ASCII with no whitespace.
+    Identifier = 1,
+    /// Printable ASCII with interior spaces only (such as option symbols).
+    Symbol = 2,
+    /// Any valid UTF-8.
+    Text = 3,
+}
+
+/// Represents a C string which failed validation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CStrError {
+    /// The pointer was NULL.
+    Null,
+    /// The string was empty (for the identifier and symbol policies).
+    Empty,
+    /// The string exceeded the maximum length (bytes).
+    TooLong(usize),
+    /// The string was not valid UTF-8, from the given byte position.
+    InvalidUtf8(usize),
+    /// The string contained a character not allowed by the policy, at the given byte position.
+    InvalidChar(char, usize),
+}
+
+impl Display for CStrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => write!(f, "invalid C string: `ptr` was NULL"),
+            Self::Empty => write!(f, "invalid C string: was empty"),
+            Self::TooLong(max_len) => {
+                write!(
+                    f,
+                    "invalid C string: exceeded maximum length of {max_len} bytes"
+                )
+            }
+            Self::InvalidUtf8(pos) => write!(f, "invalid C string: invalid UTF-8 from byte {pos}"),
+            Self::InvalidChar(c, pos) => {
+                write!(f, "invalid C string: invalid char {c:?} at byte {pos}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CStrError {}
+
+/// Validates the C string at `ptr` against the `max_len` (bytes) and `charset` policy,
+/// returning the string slice.
+///
+/// At most `max_len + 1` bytes are read when searching for the NUL terminator.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer or NULL.
+///
+/// # Errors
+///
+/// - If `ptr` is NULL.
+/// - If the string exceeds `max_len` bytes.
+/// - If the string is not valid UTF-8.
+/// - If the string is empty, or contains a character not allowed, for the `charset` policy.
+pub unsafe fn validate_cstr(
+    ptr: *const c_char,
+    max_len: usize,
+    charset: CharsetPolicy,
+) -> Result<&'static str, CStrError> {
+    if ptr.is_null() {
+        return Err(CStrError::Null);
+    }
+
+    let mut len = 0;
+    while *ptr.add(len) != 0 {
+        if len == max_len {
+            return Err(CStrError::TooLong(max_len));
+        }
+        len += 1;
+    }
+
+    let bytes = std::slice::from_raw_parts(ptr.cast::<u8>(), len);
+    let s = str::from_utf8(bytes).map_err(|e| CStrError::InvalidUtf8(e.valid_up_to()))?;
+    check_charset(s, charset)?;
+    Ok(s)
+}
+
+/// Checks the string `s` against the `charset` policy.
+///
+/// # Errors
+///
+/// - If `s` is empty, or contains a character not allowed, for the `charset` policy.
+pub fn check_charset(s: &str, charset: CharsetPolicy) -> Result<(), CStrError> {
+    if charset == CharsetPolicy::Text {
+        return Ok(());
+    }
+    if s.is_empty() {
+        return Err(CStrError::Empty);
+    }
+
+    let last = s.len() - 1;
+    for (pos, c) in s.char_indices() {
+        let is_valid = match charset {
+            CharsetPolicy::Identifier => c.is_ascii_graphic(),
+            CharsetPolicy::Symbol => c.is_ascii_graphic() || (c == ' ' && pos != 0 && pos != last),
+            CharsetPolicy::Text => true,
+        };
+        if !is_valid {
+            return Err(CStrError::InvalidChar(c, pos));
+        }
+    }
+    Ok(())
+}
+
+/// Writes the value of a successful `result` to `out` and returns a null pointer, otherwise
+/// returns the error as a C string to be freed with `cstr_drop` (leaving `out` unchanged).
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer.
+pub unsafe fn write_result<T, E: Display>(result: Result<T, E>, out: *mut T) -> *const c_char {
+    match result {
+        Ok(value) => {
+            out.write(value);
+            ptr::null()
+        }
+        Err(e) => str_to_cstr(&e.to_string()),
+    }
+}
+
+/// Returns any validation error for the C string pointer against the `max_len` (bytes) and
+/// `charset` policy, otherwise an empty string.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer or NULL.
+#[no_mangle]
+pub unsafe extern "C" fn cstr_check_valid(
+    ptr: *const c_char,
+    max_len: usize,
+    charset: CharsetPolicy,
+) -> *const c_char {
+    match validate_cstr(ptr, max_len, charset) {
+        Ok(_) => str_to_cstr(""),
+        Err(e) => str_to_cstr(&e.to_string()),
+    }
+}
+
 /// Create a C string pointer to newly allocated memory from a [&str].
 #[must_use]
 pub fn str_to_cstr(s: &str) -> *const c_char {
@@ -226,4 +380,98 @@ mod tests {
         let ptr = c_string.into_raw(); // <-- pointer _must_ be obtained this way
         unsafe { cstr_drop(ptr) };
     }
+
+    #[rstest]
+    #[case("TRADER-001", CharsetPolicy::Identifier)]
+    #[case("ESZ3 C4500", CharsetPolicy::Symbol)]
+    #[case("", CharsetPolicy::Text)]
+    #[case(
+        "Order rejected: prix invalide \u{2013} r\u{e9}essayez",
+        CharsetPolicy::Text
+    )]
+    fn test_validate_cstr_valid(#[case] input: &str, #[case] charset: CharsetPolicy) {
+        let c_string = CString::new(input).unwrap();
+        let result = unsafe { validate_cstr(c_string.as_ptr(), TEXT_MAX_LEN, charset) };
+        assert_eq!(result, Ok(input));
+    }
+
+    #[rstest]
+    #[case(b"".as_slice(), CharsetPolicy::Identifier, CStrError::Empty)]
+    #[case(
+        b"TRADER 001",
+        CharsetPolicy::Identifier,
+        CStrError::InvalidChar(' ', 6)
+    )]
+    #[case(b"ID\t1", CharsetPolicy::Identifier, CStrError::InvalidChar('\t', 2))]
+    #[case(
+        b"\xC3\xA9",
+        CharsetPolicy::Identifier,
+        CStrError::InvalidChar('\u{e9}', 0)
+    )]
+    #[case(b" ESZ3", CharsetPolicy::Symbol, CStrError::InvalidChar(' ', 0))]
+    #[case(b"ESZ3 ", CharsetPolicy::Symbol, CStrError::InvalidChar(' ', 4))]
+    #[case(b"ok\xFF", CharsetPolicy::Text, CStrError::InvalidUtf8(2))]
+    fn test_validate_cstr_invalid(
+        #[case] input: &[u8],
+        #[case] charset: CharsetPolicy,
+        #[case] expected: CStrError,
+    ) {
+        let c_string = CString::new(input).unwrap();
+        let result = unsafe { validate_cstr(c_string.as_ptr(), TEXT_MAX_LEN, charset) };
+        assert_eq!(result, Err(expected));
+    }
+
+    #[rstest]
+    #[case(4, Ok("ABCD"))]
+    #[case(3, Err(CStrError::TooLong(3)))]
+    fn test_validate_cstr_max_len(
+        #[case] max_len: usize,
+        #[case] expected: Result<&str, CStrError>,
+    ) {
+        let c_string = CString::new("ABCD").unwrap();
+        let result =
+            unsafe { validate_cstr(c_string.as_ptr(), max_len, CharsetPolicy::Identifier) };
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_validate_cstr_with_null_ptr() {
+        let result = unsafe { validate_cstr(std::ptr::null(), TEXT_MAX_LEN, CharsetPolicy::Text) };
+        assert_eq!(result, Err(CStrError::Null));
+    }
+
+    #[rstest]
+    #[case("SIM-001", "")]
+    #[case("SIM 001", "invalid C string: invalid char ' ' at byte 3")]
+    fn test_cstr_check_valid(#[case] input: &str, #[case] expected: &str) {
+        let c_string = CString::new(input).unwrap();
+        let result = unsafe {
+            let ptr = cstr_check_valid(
+                c_string.as_ptr(),
+                IDENTIFIER_MAX_LEN,
+                CharsetPolicy::Identifier,
+            );
+            let result = cstr_to_str(ptr).to_string();
+            cstr_drop(ptr);
+            result
+        };
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_write_result_ok() {
+        let mut out = 0;
+        let error = unsafe { write_result(Ok::<_, CStrError>(1), &mut out) };
+        assert!(error.is_null());
+        assert_eq!(out, 1);
+    }
+
+    #[rstest]
+    fn test_write_result_err() {
+        let mut out = 0;
+        let error = unsafe { write_result(Err(CStrError::Empty), &mut out) };
+        assert_eq!(unsafe { cstr_to_str(error) }, "invalid C string: was empty");
+        unsafe { cstr_drop(error) };
+        assert_eq!(out, 0);
+    }
 }
@@ -32,6 +32,7 @@ pub mod datetime;
 pub mod deserialization;
 pub mod equality;
 pub mod message;
+pub mod msgpack;
 pub mod nanos;
 pub mod parsing;
 pub mod serialization;
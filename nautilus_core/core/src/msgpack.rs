@@ -0,0 +1,669 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a framed stream format for `MsgPack` encoded records.
+//!
+//! Each record is written as an envelope of a magic marker, the payload length and a checksum of
+//! the payload (all little-endian), followed by the payload. The marker and checksum allow a
+//! tolerant reader to skip a corrupt record and resume at the next valid envelope.
+
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// The marker starting every record envelope.
+pub const RECORD_MAGIC: [u8; 4] = *b"NTMP";
+
+/// The length of a record envelope header (marker, payload length and checksum).
+pub const RECORD_HEADER_LEN: usize = 12;
+
+/// The maximum payload length of a record (16 MiB).
+pub const MAX_RECORD_LEN: usize = 16 * 1024 * 1024;
+
+/// The mode for handling corrupt records when reading a stream.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StreamReadMode {
+    /// The first corrupt record fails the read.
+    #[default]
+    Strict,
+    /// Corrupt records are skipped and reported, with reading resumed at the next valid envelope.
+    Tolerant,
+}
+
+/// Represents a corrupt record which was skipped by a tolerant read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CorruptRecord {
+    /// The byte offset in the stream where the corrupt region starts.
+    pub byte_offset: u64,
+    pub error: String,
+}
+
+/// The records decoded from a stream, along with any corrupt records skipped.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StreamReadResult<T> {
+    pub records: Vec<T>,
+    pub corrupt: Vec<CorruptRecord>,
+}
+
+/// Represents the location of a record in a stream, along with its peeked header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexEntry<H> {
+    /// The byte offset in the stream where the record envelope starts.
+    pub byte_offset: u64,
+    pub header: H,
+}
+
+/// Writes records to a stream in the framed `MsgPack` format.
+///
+/// Payloads are serialized into a buffer reused across records, so writing doesn't allocate
+/// once the buffer has grown to the largest record.
+pub struct MsgPackStreamWriter<W: Write> {
+    writer: W,
+    payload: Vec<u8>,
+}
+
+impl<W: Write> MsgPackStreamWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            payload: Vec::new(),
+        }
+    }
+
+    /// Writes the given `record` as a framed envelope, returning the number of bytes written.
+    ///
+    /// Nothing is written if the record fails to serialize.
+    pub fn write<T: Serialize>(&mut self, record: &T) -> anyhow::Result<usize> {
+        self.payload.clear();
+        rmp_serde::encode::write_named(&mut self.payload, record)?;
+        let len = self.payload.len();
+        if len > MAX_RECORD_LEN {
+            anyhow::bail!("Record length {len} exceeds maximum {MAX_RECORD_LEN}");
+        }
+
+        self.writer.write_all(&RECORD_MAGIC)?;
+        self.writer.write_all(&(len as u32).to_le_bytes())?;
+        self.writer
+            .write_all(&checksum(&self.payload).to_le_bytes())?;
+        self.writer.write_all(&self.payload)?;
+        Ok(RECORD_HEADER_LEN + len)
+    }
+
+    pub fn flush(&mut self) -> anyhow::Result<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads records from a stream in the framed `MsgPack` format.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MsgPackStreamReader {
+    pub mode: StreamReadMode,
+}
+
+impl MsgPackStreamReader {
+    #[must_use]
+    pub fn new(mode: StreamReadMode) -> Self {
+        Self { mode }
+    }
+
+    /// Creates a new reader which skips and reports corrupt records.
+    #[must_use]
+    pub fn tolerant() -> Self {
+        Self::new(StreamReadMode::Tolerant)
+    }
+
+    /// Reads all records from the given `reader`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if reading fails, or in strict mode if any record is corrupt.
+    pub fn read<T: DeserializeOwned, R: Read>(
+        &self,
+        mut reader: R,
+    ) -> anyhow::Result<StreamReadResult<T>> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        self.read_bytes(&data)
+    }
+
+    /// Reads all records from the given `data`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error in strict mode if any record is corrupt.
+    pub fn read_bytes<T: DeserializeOwned>(
+        &self,
+        data: &[u8],
+    ) -> anyhow::Result<StreamReadResult<T>> {
+        self.read_records(data, |_, payload| Ok(rmp_serde::from_slice::<T>(payload)?))
+    }
+
+    /// Builds an index of the records in the given `data`, decoding only a header from each
+    /// payload with the given `peek` function.
+    ///
+    /// Records which fail to peek are treated the same as records which fail to decode.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error in strict mode if any record is corrupt.
+    pub fn read_index<H>(
+        &self,
+        data: &[u8],
+        peek: impl Fn(&[u8]) -> anyhow::Result<H>,
+    ) -> anyhow::Result<StreamReadResult<IndexEntry<H>>> {
+        self.read_records(data, |offset, payload| {
+            Ok(IndexEntry {
+                byte_offset: offset as u64,
+                header: peek(payload)?,
+            })
+        })
+    }
+
+    /// Reads the single record whose envelope starts at `byte_offset` in the given `reader`,
+    /// seeking to it and reading only that record.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there is no valid envelope at `byte_offset`, or the
+    /// payload fails to decode.
+    pub fn read_at<T: DeserializeOwned, R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        byte_offset: u64,
+    ) -> anyhow::Result<T> {
+        let corrupt =
+            |e: String| anyhow::anyhow!("Corrupt record at byte offset {byte_offset}: {e}");
+
+        reader.seek(SeekFrom::Start(byte_offset))?;
+        let mut header = [0; RECORD_HEADER_LEN];
+        read_exact_or(reader, &mut header, "Truncated record header").map_err(corrupt)?;
+        let (len, expected) = parse_header(&header).map_err(corrupt)?;
+
+        let mut payload = vec![0; len];
+        read_exact_or(
+            reader,
+            &mut payload,
+            &format!("Truncated record payload (length {len})"),
+        )
+        .map_err(corrupt)?;
+        check_payload(&payload, expected).map_err(corrupt)?;
+        Ok(rmp_serde::from_slice(&payload)?)
+    }
+
+    /// Returns an iterator lazily reading the record payloads from the given `data`, without
+    /// decoding them.
+    ///
+    /// A corrupt record is yielded as an error, after which a strict read ends while a tolerant
+    /// read resumes at the next valid envelope.
+    #[must_use]
+    pub fn payloads<'a>(&self, data: &'a [u8]) -> PayloadIter<'a> {
+        PayloadIter {
+            data,
+            offset: 0,
+            mode: self.mode,
+        }
+    }
+
+    fn read_records<T>(
+        &self,
+        data: &[u8],
+        mut decode: impl FnMut(usize, &[u8]) -> anyhow::Result<T>,
+    ) -> anyhow::Result<StreamReadResult<T>> {
+        let mut records = Vec::new();
+        let mut corrupt = Vec::new();
+        let mut offset = 0;
+
+        while offset < data.len() {
+            let (error, next_offset) = match parse_envelope(data, offset) {
+                Ok(payload) => {
+                    let next_offset = offset + RECORD_HEADER_LEN + payload.len();
+                    match decode(offset, payload) {
+                        Ok(record) => {
+                            records.push(record);
+                            offset = next_offset;
+                            continue;
+                        }
+                        // The envelope is intact, so resume directly after it
+                        Err(e) => (format!("Failed to decode payload: {e}"), next_offset),
+                    }
+                }
+                Err(e) => (e, next_envelope(data, offset + 1)),
+            };
+
+            if self.mode == StreamReadMode::Strict {
+                anyhow::bail!("Corrupt record at byte offset {offset}: {error}");
+            }
+            corrupt.push(CorruptRecord {
+                byte_offset: offset as u64,
+                error,
+            });
+            offset = next_offset;
+        }
+
+        Ok(StreamReadResult { records, corrupt })
+    }
+}
+
+/// Lazily reads the record payloads from a byte slice, see [`MsgPackStreamReader::payloads`].
+#[derive(Clone, Debug)]
+pub struct PayloadIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+    mode: StreamReadMode,
+}
+
+impl PayloadIter<'_> {
+    /// Returns the byte offset of the next record envelope to be read.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<'a> Iterator for PayloadIter<'a> {
+    type Item = anyhow::Result<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset;
+        if offset >= self.data.len() {
+            return None;
+        }
+
+        match parse_envelope(self.data, offset) {
+            Ok(payload) => {
+                self.offset = offset + RECORD_HEADER_LEN + payload.len();
+                Some(Ok(payload))
+            }
+            Err(e) => {
+                self.offset = match self.mode {
+                    StreamReadMode::Strict => self.data.len(),
+                    StreamReadMode::Tolerant => next_envelope(self.data, offset + 1),
+                };
+                Some(Err(anyhow::anyhow!(
+                    "Corrupt record at byte offset {offset}: {e}"
+                )))
+            }
+        }
+    }
+}
+
+/// Returns the length of the prefix of `data` which ends on a record boundary, excluding a torn
+/// final record whose header or payload was only partially written.
+///
+/// Only the envelope lengths are walked, so a corrupt record before the end is not excluded and
+/// is left for a reader to report.
+#[must_use]
+pub fn complete_records_len(data: &[u8]) -> usize {
+    let mut offset = 0;
+    while offset < data.len() {
+        let Some(header) = data.get(offset..offset + RECORD_HEADER_LEN) else {
+            return offset;
+        };
+        let Ok((len, _)) = parse_header(header) else {
+            return data.len();
+        };
+        if offset + RECORD_HEADER_LEN + len > data.len() {
+            return offset;
+        }
+        offset += RECORD_HEADER_LEN + len;
+    }
+    data.len()
+}
+
+/// Returns the payload of the envelope at `offset`, if valid.
+fn parse_envelope(data: &[u8], offset: usize) -> Result<&[u8], String> {
+    let header = data
+        .get(offset..offset + RECORD_HEADER_LEN)
+        .ok_or_else(|| "Truncated record header".to_string())?;
+    let (len, expected) = parse_header(header)?;
+
+    let start = offset + RECORD_HEADER_LEN;
+    let payload = data
+        .get(start..start + len)
+        .ok_or_else(|| format!("Truncated record payload (length {len})"))?;
+
+    check_payload(payload, expected)?;
+    Ok(payload)
+}
+
+/// Returns the payload length and checksum of the envelope `header`, if valid.
+fn parse_header(header: &[u8]) -> Result<(usize, u32), String> {
+    if header[0..4] != RECORD_MAGIC {
+        return Err("Invalid record marker".to_string());
+    }
+
+    let len = u32::from_le_bytes(header[4..8].try_into().expect("Slice of 4 bytes")) as usize;
+    if len > MAX_RECORD_LEN {
+        return Err(format!(
+            "Record length {len} exceeds maximum {MAX_RECORD_LEN}"
+        ));
+    }
+
+    let checksum = u32::from_le_bytes(header[8..12].try_into().expect("Slice of 4 bytes"));
+    Ok((len, checksum))
+}
+
+fn check_payload(payload: &[u8], expected: u32) -> Result<(), String> {
+    let actual = checksum(payload);
+    if actual != expected {
+        return Err(format!(
+            "Checksum mismatch (expected {expected:#010x}, was {actual:#010x})"
+        ));
+    }
+    Ok(())
+}
+
+/// Fills `buf` from the `reader`, mapping a premature end of input to the `truncated` message.
+fn read_exact_or<R: Read>(reader: &mut R, buf: &mut [u8], truncated: &str) -> Result<(), String> {
+    reader.read_exact(buf).map_err(|e| match e.kind() {
+        std::io::ErrorKind::UnexpectedEof => truncated.to_string(),
+        _ => e.to_string(),
+    })
+}
+
+/// Returns the offset of the next valid envelope at or after `from`, or the end of `data`.
+fn next_envelope(data: &[u8], from: usize) -> usize {
+    (from..data.len())
+        .filter(|&offset| data[offset..].starts_with(&RECORD_MAGIC))
+        .find(|&offset| parse_envelope(data, offset).is_ok())
+        .unwrap_or(data.len())
+}
+
+/// Returns the 32-bit FNV-1a hash of the given `bytes`.
+fn checksum(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811C_9DC5, |hash, &byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct TestEvent {
+        id: u64,
+        name: String,
+        price: f64,
+    }
+
+    /// A stream of five records, with the byte offset of each.
+    #[fixture]
+    fn fixture() -> (Vec<u8>, Vec<usize>) {
+        let mut writer = MsgPackStreamWriter::new(Vec::new());
+        let mut offsets = Vec::new();
+        let mut offset = 0;
+        for id in 0..5 {
+            offsets.push(offset);
+            offset += writer
+                .write(&TestEvent {
+                    id,
+                    name: format!("event-{id}"),
+                    price: 100.5 + id as f64,
+                })
+                .unwrap();
+        }
+        (writer.into_inner(), offsets)
+    }
+
+    fn ids(result: &StreamReadResult<TestEvent>) -> Vec<u64> {
+        result.records.iter().map(|event| event.id).collect()
+    }
+
+    fn corrupt_offsets(result: &StreamReadResult<TestEvent>) -> Vec<u64> {
+        result
+            .corrupt
+            .iter()
+            .map(|record| record.byte_offset)
+            .collect()
+    }
+
+    #[rstest]
+    fn test_round_trip(fixture: (Vec<u8>, Vec<usize>)) {
+        let (data, _) = fixture;
+
+        let result: StreamReadResult<TestEvent> = MsgPackStreamReader::default()
+            .read(data.as_slice())
+            .unwrap();
+
+        assert_eq!(ids(&result), vec![0, 1, 2, 3, 4]);
+        assert_eq!(result.records[4].name, "event-4");
+        assert!(result.corrupt.is_empty());
+    }
+
+    #[rstest]
+    fn test_strict_mode_fails_on_corrupt_record(fixture: (Vec<u8>, Vec<usize>)) {
+        let (mut data, offsets) = fixture;
+        data[offsets[2] + RECORD_HEADER_LEN + 1] ^= 0xFF;
+
+        let result = MsgPackStreamReader::default().read_bytes::<TestEvent>(&data);
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.starts_with(&format!("Corrupt record at byte offset {}", offsets[2])));
+    }
+
+    #[rstest]
+    #[case::marker(0, 0)]
+    #[case::marker_last(4, 2)]
+    #[case::length(2, 5)]
+    #[case::length_high_byte(4, 7)]
+    #[case::checksum(1, 9)]
+    #[case::payload_start(3, RECORD_HEADER_LEN)]
+    #[case::payload_middle(2, RECORD_HEADER_LEN + 10)]
+    fn test_tolerant_mode_skips_flipped_byte(
+        fixture: (Vec<u8>, Vec<usize>),
+        #[case] record: usize,
+        #[case] byte: usize,
+    ) {
+        let (mut data, offsets) = fixture;
+        data[offsets[record] + byte] ^= 0xFF;
+
+        let result: StreamReadResult<TestEvent> =
+            MsgPackStreamReader::tolerant().read_bytes(&data).unwrap();
+
+        let expected: Vec<u64> = (0..5).filter(|id| *id != record as u64).collect();
+        assert_eq!(ids(&result), expected);
+        assert_eq!(corrupt_offsets(&result), vec![offsets[record] as u64]);
+    }
+
+    #[rstest]
+    fn test_tolerant_mode_reports_each_corrupt_region(fixture: (Vec<u8>, Vec<usize>)) {
+        let (mut data, offsets) = fixture;
+        data[offsets[1] + RECORD_HEADER_LEN + 3] ^= 0x01;
+        data[offsets[3]] ^= 0xFF;
+
+        let result: StreamReadResult<TestEvent> =
+            MsgPackStreamReader::tolerant().read_bytes(&data).unwrap();
+
+        assert_eq!(ids(&result), vec![0, 2, 4]);
+        assert_eq!(
+            corrupt_offsets(&result),
+            vec![offsets[1] as u64, offsets[3] as u64]
+        );
+        assert!(result.corrupt[0].error.starts_with("Checksum mismatch"));
+        assert_eq!(result.corrupt[1].error, "Invalid record marker");
+    }
+
+    #[rstest]
+    fn test_tolerant_mode_with_truncated_stream(fixture: (Vec<u8>, Vec<usize>)) {
+        let (mut data, offsets) = fixture;
+        data.truncate(data.len() - 3);
+
+        let result: StreamReadResult<TestEvent> =
+            MsgPackStreamReader::tolerant().read_bytes(&data).unwrap();
+
+        assert_eq!(ids(&result), vec![0, 1, 2, 3]);
+        assert_eq!(corrupt_offsets(&result), vec![offsets[4] as u64]);
+    }
+
+    #[rstest]
+    fn test_tolerant_mode_skips_undecodable_payload() {
+        let mut writer = MsgPackStreamWriter::new(Vec::new());
+        writer.write(&"not an event").unwrap();
+        writer
+            .write(&TestEvent {
+                id: 1,
+                name: "event-1".to_string(),
+                price: 1.0,
+            })
+            .unwrap();
+        let data = writer.into_inner();
+
+        let result: StreamReadResult<TestEvent> =
+            MsgPackStreamReader::tolerant().read_bytes(&data).unwrap();
+
+        assert_eq!(ids(&result), vec![1]);
+        assert_eq!(corrupt_offsets(&result), vec![0]);
+        assert!(result.corrupt[0]
+            .error
+            .starts_with("Failed to decode payload"));
+    }
+
+    #[rstest]
+    fn test_read_index_peeks_each_record(fixture: (Vec<u8>, Vec<usize>)) {
+        let (mut data, offsets) = fixture;
+        data[offsets[2]] ^= 0xFF;
+
+        let result = MsgPackStreamReader::tolerant()
+            .read_index(&data, |payload| {
+                Ok(rmp_serde::from_slice::<TestEvent>(payload)?.id)
+            })
+            .unwrap();
+
+        let entries: Vec<(u64, u64)> = result
+            .records
+            .iter()
+            .map(|entry| (entry.byte_offset, entry.header))
+            .collect();
+        let expected: Vec<(u64, u64)> = [0, 1, 3, 4]
+            .into_iter()
+            .map(|id| (offsets[id as usize] as u64, id))
+            .collect();
+        assert_eq!(entries, expected);
+        assert_eq!(result.corrupt.len(), 1);
+        assert_eq!(result.corrupt[0].byte_offset, offsets[2] as u64);
+    }
+
+    #[rstest]
+    fn test_read_at(fixture: (Vec<u8>, Vec<usize>)) {
+        let (data, offsets) = fixture;
+        let reader = MsgPackStreamReader::default();
+        let mut cursor = std::io::Cursor::new(&data);
+
+        let event: TestEvent = reader.read_at(&mut cursor, offsets[3] as u64).unwrap();
+        let result = reader.read_at::<TestEvent, _>(&mut cursor, offsets[3] as u64 + 1);
+
+        assert_eq!(event.id, 3);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "Corrupt record at byte offset {}: Invalid record marker",
+                offsets[3] + 1
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_read_at_truncated_record_errors(fixture: (Vec<u8>, Vec<usize>)) {
+        let (data, offsets) = fixture;
+        let mut cursor = std::io::Cursor::new(&data[..data.len() - 1]);
+
+        let result =
+            MsgPackStreamReader::default().read_at::<TestEvent, _>(&mut cursor, offsets[4] as u64);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Truncated record payload"));
+    }
+
+    #[rstest]
+    #[case::strict(StreamReadMode::Strict, 3)]
+    #[case::tolerant(StreamReadMode::Tolerant, 5)]
+    fn test_payloads_after_corrupt_record(
+        fixture: (Vec<u8>, Vec<usize>),
+        #[case] mode: StreamReadMode,
+        #[case] expected_len: usize,
+    ) {
+        let (mut data, offsets) = fixture;
+        data[offsets[2] + RECORD_HEADER_LEN] ^= 0xFF;
+
+        let payloads: Vec<_> = MsgPackStreamReader::new(mode).payloads(&data).collect();
+
+        assert_eq!(payloads.len(), expected_len);
+        assert!(payloads[2].is_err());
+        let first: TestEvent = rmp_serde::from_slice(payloads[0].as_ref().unwrap()).unwrap();
+        assert_eq!(first.id, 0);
+    }
+
+    #[rstest]
+    fn test_payloads_is_lazy(fixture: (Vec<u8>, Vec<usize>)) {
+        let (data, offsets) = fixture;
+
+        let mut payloads = MsgPackStreamReader::default().payloads(&data);
+        payloads.next().unwrap().unwrap();
+
+        assert_eq!(payloads.offset(), offsets[1]);
+        assert_eq!(payloads.count(), 4);
+    }
+
+    #[rstest]
+    fn test_complete_records_len_excludes_torn_tail(fixture: (Vec<u8>, Vec<usize>)) {
+        let (data, offsets) = fixture;
+
+        assert_eq!(complete_records_len(&data), data.len());
+        assert_eq!(complete_records_len(&[]), 0);
+        for len in offsets[4]..data.len() {
+            assert_eq!(
+                complete_records_len(&data[..len]),
+                offsets[4],
+                "length {len}"
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_complete_records_len_keeps_corrupt_record(fixture: (Vec<u8>, Vec<usize>)) {
+        let (mut data, offsets) = fixture;
+        data[offsets[2]] ^= 0xFF;
+
+        assert_eq!(complete_records_len(&data), data.len());
+    }
+
+    #[rstest]
+    fn test_read_index_strict_mode_fails_on_peek_error(fixture: (Vec<u8>, Vec<usize>)) {
+        let (data, _) = fixture;
+
+        let result = MsgPackStreamReader::default().read_index(&data, |_| -> anyhow::Result<()> {
+            anyhow::bail!("Bad header")
+        });
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Corrupt record at byte offset 0: Failed to decode payload: Bad header"
+        );
+    }
+}
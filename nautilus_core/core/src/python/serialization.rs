@@ -13,20 +13,357 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use pyo3::{prelude::*, types::PyDict};
-use serde::de::DeserializeOwned;
+//! Conversion of Python dictionaries to and from Rust types via `serde`.
 
+use pyo3::{
+    exceptions::PyTypeError,
+    prelude::*,
+    types::{PyBool, PyDict, PyFloat, PyList, PyLong, PyString, PyTuple},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Number, Value};
+
+use crate::python::to_pyvalue_err;
+
+/// Deserializes an instance of `T` from the given Python dictionary.
+///
+/// The dictionary is converted directly into a JSON value, with the same results as encoding it
+/// with the Python `json` module and deserializing the string.
+///
+/// # Errors
+///
+/// This function returns an error if:
+/// - The dictionary contains a value which is not JSON serializable (a `TypeError`).
+/// - The value does not deserialize as `T` (a `ValueError`).
 pub fn from_dict_pyo3<T>(py: Python<'_>, values: Py<PyDict>) -> Result<T, PyErr>
 where
     T: DeserializeOwned,
 {
-    // Extract to JSON string
-    use crate::python::to_pyvalue_err;
-    let json_str: String = PyModule::import(py, "json")?
-        .call_method("dumps", (values,), None)?
-        .extract()?;
-
-    // Deserialize to object
-    let instance = serde_json::from_slice(&json_str.into_bytes()).map_err(to_pyvalue_err)?;
-    Ok(instance)
+    let value = py_to_json_value(values.as_ref(py))?;
+    serde_json::from_value(value).map_err(to_pyvalue_err)
+}
+
+/// Serializes the given `value` into a Python dictionary.
+///
+/// The value is serialized to a JSON value which is converted directly into native Python
+/// objects, so integers remain `int`, floats remain `float`, nulls become `None` and nested
+/// objects become dictionaries.
+///
+/// # Errors
+///
+/// This function returns an error if:
+/// - The value fails to serialize (a `ValueError`).
+/// - The value does not serialize as a map (a `TypeError`).
+pub fn to_dict_pyo3<T>(py: Python<'_>, value: &T) -> Result<Py<PyDict>, PyErr>
+where
+    T: Serialize,
+{
+    match serde_json::to_value(value).map_err(to_pyvalue_err)? {
+        Value::Object(map) => json_map_to_py_dict(py, &map).map(Into::into),
+        value => Err(PyTypeError::new_err(format!(
+            "Expected value to serialize as a map, was {value}"
+        ))),
+    }
+}
+
+/// Converts the given JSON value into a native Python object.
+///
+/// Integers are converted to `int` and other numbers to `float`, arrays to `list` and objects
+/// to `dict`.
+///
+/// # Errors
+///
+/// This function returns an error if a dictionary item fails to be set.
+pub fn json_value_to_py(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    let obj = match value {
+        Value::Null => py.None(),
+        Value::Bool(b) => (*b).into_py(py),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                i.into_py(py)
+            } else if let Some(u) = n.as_u64() {
+                u.into_py(py)
+            } else {
+                n.as_f64().into_py(py)
+            }
+        }
+        Value::String(s) => s.as_str().into_py(py),
+        Value::Array(values) => {
+            let items: PyResult<Vec<PyObject>> =
+                values.iter().map(|v| json_value_to_py(py, v)).collect();
+            PyList::new(py, items?).into_py(py)
+        }
+        Value::Object(map) => json_map_to_py_dict(py, map)?.into_py(py),
+    };
+    Ok(obj)
+}
+
+fn json_map_to_py_dict<'py>(py: Python<'py>, map: &Map<String, Value>) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    for (key, value) in map {
+        dict.set_item(key, json_value_to_py(py, value)?)?;
+    }
+    Ok(dict)
+}
+
+/// Converts the given Python object into a JSON value.
+///
+/// Supports `None`, `bool`, `int`, `float`, `str`, `dict`, `list` and `tuple`, where dictionary
+/// keys are converted to strings as by `json.dumps`. Integers outside the range of `i64` and
+/// `u64` are converted to floats, as when parsing the equivalent JSON.
+///
+/// # Errors
+///
+/// This function returns an error if the object (or a nested object) is not JSON serializable,
+/// or is a float which is not finite.
+pub fn py_to_json_value(obj: &PyAny) -> PyResult<Value> {
+    if obj.is_none() {
+        return Ok(Value::Null);
+    }
+    // Check `bool` before `int`, as it is a subclass
+    if let Ok(b) = obj.downcast::<PyBool>() {
+        return Ok(Value::Bool(b.is_true()));
+    }
+    if obj.is_instance_of::<PyLong>() {
+        if let Ok(i) = obj.extract::<i64>() {
+            return Ok(Value::from(i));
+        }
+        if let Ok(u) = obj.extract::<u64>() {
+            return Ok(Value::from(u));
+        }
+        return float_to_json_value(obj.extract::<f64>()?);
+    }
+    if let Ok(f) = obj.downcast::<PyFloat>() {
+        return float_to_json_value(f.value());
+    }
+    if let Ok(s) = obj.downcast::<PyString>() {
+        return Ok(Value::String(s.to_str()?.to_string()));
+    }
+    if let Ok(dict) = obj.downcast::<PyDict>() {
+        let mut map = Map::with_capacity(dict.len());
+        for (key, value) in dict {
+            map.insert(py_key_to_string(key)?, py_to_json_value(value)?);
+        }
+        return Ok(Value::Object(map));
+    }
+    if let Ok(list) = obj.downcast::<PyList>() {
+        let values: PyResult<Vec<Value>> = list.iter().map(py_to_json_value).collect();
+        return values.map(Value::Array);
+    }
+    if let Ok(tuple) = obj.downcast::<PyTuple>() {
+        let values: PyResult<Vec<Value>> = tuple.iter().map(py_to_json_value).collect();
+        return values.map(Value::Array);
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "Object of type {} is not JSON serializable",
+        obj.get_type().name()?
+    )))
+}
+
+fn float_to_json_value(f: f64) -> PyResult<Value> {
+    Number::from_f64(f).map(Value::Number).ok_or_else(|| {
+        to_pyvalue_err(format!(
+            "Out of range float value {f} is not JSON compliant"
+        ))
+    })
+}
+
+/// Converts a dictionary key to a string, following the rules of `json.dumps`.
+fn py_key_to_string(key: &PyAny) -> PyResult<String> {
+    match py_to_json_value(key) {
+        Ok(Value::String(s)) => Ok(s),
+        Ok(Value::Null) => Ok("null".to_string()),
+        Ok(Value::Bool(b)) => Ok(b.to_string()),
+        Ok(Value::Number(_)) => Ok(key.str()?.to_str()?.to_string()),
+        _ => Err(PyTypeError::new_err(format!(
+            "Keys must be str, int, float, bool or None, not {}",
+            key.get_type().name()?
+        ))),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::types::IntoPyDict;
+    use rstest::rstest;
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(tag = "type")]
+    struct TestEvent {
+        client_order_id: String,
+        quantity: String,
+        ts_event: u64,
+        post_only: bool,
+        price: Option<String>,
+        linked_order_ids: Option<Vec<String>>,
+        params: Option<std::collections::HashMap<String, String>>,
+    }
+
+    fn json_dumps(obj: &PyAny) -> PyResult<Value> {
+        let json_str: String = PyModule::import(obj.py(), "json")?
+            .call_method1("dumps", (obj,))?
+            .extract()?;
+        Ok(serde_json::from_str(&json_str).unwrap())
+    }
+
+    #[rstest]
+    #[case("None")]
+    #[case("True")]
+    #[case("-12")]
+    #[case("18446744073709551615")]
+    #[case("1.5")]
+    #[case("'O-123'")]
+    #[case("['O-1', 'O-2']")]
+    #[case("('O-1', 2)")]
+    #[case("{'a': {'b': [1, 2.5, None, False]}}")]
+    #[case("{1: 'int', 2.5: 'float', None: 'none'}")]
+    #[case("{True: 'bool'}")]
+    fn test_py_to_json_value_matches_json_dumps(#[case] expr: &str) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let obj = py.eval(expr, None, None).unwrap();
+            assert_eq!(py_to_json_value(obj).unwrap(), json_dumps(obj).unwrap());
+        });
+    }
+
+    #[rstest]
+    #[case("float('nan')")]
+    #[case("object()")]
+    #[case("{(1, 2): 'tuple key'}")]
+    fn test_py_to_json_value_errors(#[case] expr: &str) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let obj = py.eval(expr, None, None).unwrap();
+            assert!(py_to_json_value(obj).is_err());
+        });
+    }
+
+    #[rstest]
+    fn test_from_dict_pyo3() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let dict: Py<PyDict> = py
+                .eval(
+                    "{'type': 'TestEvent', 'client_order_id': 'O-1', 'quantity': '100', \
+                    'ts_event': 1, 'post_only': True, 'price': None, \
+                    'linked_order_ids': ['O-2', 'O-3'], 'params': {'x': '1'}}",
+                    None,
+                    None,
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+
+            let event: TestEvent = from_dict_pyo3(py, dict).unwrap();
+
+            assert_eq!(
+                event,
+                TestEvent {
+                    client_order_id: "O-1".to_string(),
+                    quantity: "100".to_string(),
+                    ts_event: 1,
+                    post_only: true,
+                    price: None,
+                    linked_order_ids: Some(vec!["O-2".to_string(), "O-3".to_string()]),
+                    params: Some([("x".to_string(), "1".to_string())].into()),
+                }
+            );
+        });
+    }
+
+    #[rstest]
+    fn test_from_dict_pyo3_with_invalid_dict() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let dict: Py<PyDict> = [("type", "TestEvent")].into_py_dict(py).into();
+
+            let result: PyResult<TestEvent> = from_dict_pyo3(py, dict);
+
+            assert!(result.is_err());
+        });
+    }
+
+    #[rstest]
+    fn test_to_dict_pyo3_keeps_native_types() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let event = TestEvent {
+                client_order_id: "O-1".to_string(),
+                quantity: "100".to_string(),
+                ts_event: u64::MAX,
+                post_only: true,
+                price: None,
+                linked_order_ids: Some(vec!["O-2".to_string(), "O-3".to_string()]),
+                params: Some([("x".to_string(), "1".to_string())].into()),
+            };
+
+            let dict = to_dict_pyo3(py, &event).unwrap();
+            let dict = dict.as_ref(py);
+
+            let ts_event = dict.get_item("ts_event").unwrap().unwrap();
+            assert!(ts_event.is_exact_instance_of::<PyLong>());
+            assert_eq!(ts_event.extract::<u64>().unwrap(), u64::MAX);
+            assert!(dict
+                .get_item("post_only")
+                .unwrap()
+                .unwrap()
+                .is_exact_instance_of::<PyBool>());
+            assert!(dict.get_item("price").unwrap().unwrap().is_none());
+            assert!(dict
+                .get_item("linked_order_ids")
+                .unwrap()
+                .unwrap()
+                .is_exact_instance_of::<PyList>());
+            assert!(dict
+                .get_item("params")
+                .unwrap()
+                .unwrap()
+                .is_exact_instance_of::<PyDict>());
+            assert_eq!(
+                dict.get_item("type")
+                    .unwrap()
+                    .unwrap()
+                    .extract::<&str>()
+                    .unwrap(),
+                "TestEvent"
+            );
+
+            let round_trip: TestEvent = from_dict_pyo3(py, dict.into()).unwrap();
+            assert_eq!(round_trip, event);
+        });
+    }
+
+    #[rstest]
+    #[case("None")]
+    #[case("-12")]
+    #[case("18446744073709551615")]
+    #[case("1.5")]
+    #[case("{'a': {'b': [1, 2.5, None, False, 'c']}}")]
+    fn test_json_value_to_py_round_trip(#[case] expr: &str) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let obj = py.eval(expr, None, None).unwrap();
+            let value = py_to_json_value(obj).unwrap();
+
+            let converted = json_value_to_py(py, &value).unwrap();
+
+            assert!(converted.as_ref(py).eq(obj).unwrap());
+            assert!(converted.as_ref(py).get_type().is(obj.get_type()));
+        });
+    }
+
+    #[rstest]
+    fn test_to_dict_pyo3_with_non_map_value() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            assert!(to_dict_pyo3(py, &vec![1, 2]).is_err());
+        });
+    }
 }
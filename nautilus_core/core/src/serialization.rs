@@ -15,7 +15,120 @@
 
 //! Defines common serialization traits.
 
-use serde::{Deserialize, Serialize};
+use std::{
+    cell::Cell,
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+/// The encoding of a serialized record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SerializationFormat {
+    Json,
+    MsgPack,
+}
+
+/// The format for serializing fixed precision decimal values, such as prices and quantities.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DecimalFormat {
+    /// A number, e.g. `1.1` (trailing zeros, and so the precision, are not preserved).
+    Number,
+    /// A string with trailing zeros preserved to the precision, e.g. `"1.1000"`.
+    #[default]
+    StringCanonical,
+    /// The raw fixed point value and precision, e.g. `{"raw": 1100000000, "precision": 4}`.
+    RawParts,
+}
+
+impl FromStr for DecimalFormat {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_ascii_uppercase().as_str() {
+            "NUMBER" => Ok(Self::Number),
+            "STRING_CANONICAL" => Ok(Self::StringCanonical),
+            "RAW_PARTS" => Ok(Self::RawParts),
+            _ => Err(format!("Invalid `DecimalFormat`: '{input}'")),
+        }
+    }
+}
+
+/// The configuration applied when serializing values on the current thread.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SerializationConfig {
+    pub decimal_format: DecimalFormat,
+}
+
+impl SerializationConfig {
+    #[must_use]
+    pub fn new(decimal_format: DecimalFormat) -> Self {
+        Self { decimal_format }
+    }
+}
+
+thread_local! {
+    static SERIALIZATION_CONFIG: Cell<SerializationConfig> = Cell::new(SerializationConfig::default());
+}
+
+/// Returns the serialization configuration for the current thread.
+#[must_use]
+pub fn get_serialization_config() -> SerializationConfig {
+    SERIALIZATION_CONFIG.with(Cell::get)
+}
+
+/// Sets the serialization configuration for the current thread.
+pub fn set_serialization_config(config: SerializationConfig) {
+    SERIALIZATION_CONFIG.with(|cell| cell.set(config));
+}
+
+/// Calls `f` with the given serialization `config`, restoring the previous configuration after.
+pub fn with_serialization_config<R>(config: SerializationConfig, f: impl FnOnce() -> R) -> R {
+    let previous = SERIALIZATION_CONFIG.with(|cell| cell.replace(config));
+    let result = f();
+    set_serialization_config(previous);
+    result
+}
+
+struct FromStrVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<T, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+/// Deserializes a `T` by parsing it from a string.
+///
+/// Both borrowed and owned strings are accepted, so the input may come from sources which
+/// cannot lend a `&str`, such as `serde_json::from_value` or escaped JSON.
+///
+/// # Errors
+///
+/// Returns an error if the input is not a string or `T` fails to parse from it.
+pub fn deserialize_from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    deserializer.deserialize_str(FromStrVisitor(PhantomData))
+}
 
 /// Represents types which are serializable for JSON and `MsgPack` specifications.
 pub trait Serializable: Serialize + for<'de> Deserialize<'de> {
@@ -34,8 +147,49 @@ pub trait Serializable: Serialize + for<'de> Deserialize<'de> {
         serde_json::to_vec(self)
     }
 
+    /// Serialize an object to JSON encoded bytes using the given `config`.
+    fn as_json_bytes_with_config(
+        &self,
+        config: SerializationConfig,
+    ) -> Result<Vec<u8>, serde_json::Error> {
+        with_serialization_config(config, || serde_json::to_vec(self))
+    }
+
     /// Serialize an object to `MsgPack` encoded bytes.
     fn as_msgpack_bytes(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
         rmp_serde::to_vec_named(self)
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("NUMBER", DecimalFormat::Number)]
+    #[case("string_canonical", DecimalFormat::StringCanonical)]
+    #[case("RAW_PARTS", DecimalFormat::RawParts)]
+    fn test_decimal_format_from_str(#[case] input: &str, #[case] expected: DecimalFormat) {
+        assert_eq!(DecimalFormat::from_str(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_decimal_format_from_str_invalid() {
+        assert!(DecimalFormat::from_str("DECIMAL").is_err());
+    }
+
+    #[rstest]
+    fn test_with_serialization_config_restores_previous() {
+        let config = SerializationConfig::new(DecimalFormat::RawParts);
+
+        let inner = with_serialization_config(config, get_serialization_config);
+
+        assert_eq!(inner, config);
+        assert_eq!(get_serialization_config(), SerializationConfig::default());
+    }
+}
@@ -26,6 +26,8 @@ use std::{
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
+use crate::serialization::deserialize_from_str;
+
 /// The maximum length of ASCII characters for a `UUID4` string value (includes null terminator).
 pub(crate) const UUID4_LEN: usize = 37;
 
@@ -106,18 +108,16 @@ impl Serialize for UUID4 {
     where
         S: Serializer,
     {
-        self.value.serialize(serializer)
+        serializer.serialize_str(&self.to_string())
     }
 }
 
 impl<'de> Deserialize<'de> for UUID4 {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let uuid4_str: &str = Deserialize::deserialize(_deserializer)?;
-        let uuid4: Self = uuid4_str.into();
-        Ok(uuid4)
+        deserialize_from_str(deserializer)
     }
 }
 
@@ -185,4 +185,25 @@ mod tests {
         let uuid = UUID4::from(uuid_string);
         assert_eq!(format!("{uuid}"), uuid_string);
     }
+
+    #[rstest]
+    fn test_serde_json_round_trip() {
+        let uuid = UUID4::from("2d89666b-1a1e-4a75-b193-4eb3b454c757");
+
+        let json = serde_json::to_string(&uuid).unwrap();
+        let deserialized: UUID4 = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, "\"2d89666b-1a1e-4a75-b193-4eb3b454c757\"");
+        assert_eq!(deserialized, uuid);
+    }
+
+    #[rstest]
+    fn test_deserialize_from_owned_string() {
+        let uuid = UUID4::from("2d89666b-1a1e-4a75-b193-4eb3b454c757");
+        let value = serde_json::to_value(uuid).unwrap();
+
+        let deserialized: UUID4 = serde_json::from_value(value).unwrap();
+
+        assert_eq!(deserialized, uuid);
+    }
 }
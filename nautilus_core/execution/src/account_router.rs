@@ -0,0 +1,193 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides an `AccountRouter` resolving the account an order is submitted under.
+
+use std::collections::HashMap;
+
+use nautilus_common::cache::Cache;
+use nautilus_model::identifiers::{account_id::AccountId, strategy_id::StrategyId, venue::Venue};
+
+/// Resolves the account for orders from strategy to account mappings, falling back to the
+/// default account for the venue in the cache.
+///
+/// A strategy may be mapped to one account per venue, which is the account's issuer.
+#[derive(Clone, Debug, Default)]
+pub struct AccountRouter {
+    strategy_accounts: HashMap<StrategyId, HashMap<Venue, AccountId>>,
+}
+
+impl AccountRouter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps the given `strategy_id` to the `account_id` for the account's venue, returning any
+    /// account previously mapped.
+    pub fn map_strategy(
+        &mut self,
+        strategy_id: StrategyId,
+        account_id: AccountId,
+    ) -> Option<AccountId> {
+        self.strategy_accounts
+            .entry(strategy_id)
+            .or_default()
+            .insert(account_id.get_issuer(), account_id)
+    }
+
+    /// Removes the account mapping for the given `strategy_id` at the `venue`, returning the
+    /// account which was mapped.
+    pub fn unmap_strategy(&mut self, strategy_id: &StrategyId, venue: &Venue) -> Option<AccountId> {
+        self.strategy_accounts
+            .get_mut(strategy_id)
+            .and_then(|accounts| accounts.remove(venue))
+    }
+
+    /// Returns the account mapped for the given `strategy_id` at the `venue` (if any).
+    #[must_use]
+    pub fn mapped_account(&self, strategy_id: &StrategyId, venue: &Venue) -> Option<AccountId> {
+        self.strategy_accounts
+            .get(strategy_id)
+            .and_then(|accounts| accounts.get(venue))
+            .copied()
+    }
+
+    /// Resolves the account for an order from the given `strategy_id` at the `venue`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The strategy is mapped to an account not in the `cache`.
+    /// - The strategy has no mapping and the cache has no account for the venue.
+    pub fn resolve(
+        &self,
+        cache: &Cache,
+        strategy_id: &StrategyId,
+        venue: &Venue,
+    ) -> anyhow::Result<AccountId> {
+        if let Some(account_id) = self.mapped_account(strategy_id, venue) {
+            anyhow::ensure!(
+                cache.account(&account_id).is_some(),
+                "Account {account_id} mapped for {strategy_id} not found in cache"
+            );
+            return Ok(account_id);
+        }
+
+        cache
+            .account_id(venue)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("No account for {strategy_id} at {venue}"))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::stubs::StubAccount;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn cache() -> Cache {
+        let mut cache = Cache::default();
+        cache
+            .add_account(StubAccount::boxed("BINANCE-001"))
+            .unwrap();
+        cache
+            .add_account(StubAccount::boxed("BINANCE-002"))
+            .unwrap();
+        cache
+    }
+
+    #[rstest]
+    fn test_resolve_per_strategy_mapping(cache: Cache) {
+        let venue = Venue::from("BINANCE");
+        let strategy_a = StrategyId::from("S-001");
+        let strategy_b = StrategyId::from("S-002");
+        let mut router = AccountRouter::new();
+        router.map_strategy(strategy_a, AccountId::from("BINANCE-001"));
+        router.map_strategy(strategy_b, AccountId::from("BINANCE-002"));
+
+        assert_eq!(
+            router.resolve(&cache, &strategy_a, &venue).unwrap(),
+            AccountId::from("BINANCE-001")
+        );
+        assert_eq!(
+            router.resolve(&cache, &strategy_b, &venue).unwrap(),
+            AccountId::from("BINANCE-002")
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_falls_back_to_venue_default(cache: Cache) {
+        let mut router = AccountRouter::new();
+        let strategy_id = StrategyId::from("S-001");
+        router.map_strategy(strategy_id, AccountId::from("BINANCE-002"));
+        router.unmap_strategy(&strategy_id, &Venue::from("BINANCE"));
+
+        let account_id = router
+            .resolve(&cache, &strategy_id, &Venue::from("BINANCE"))
+            .unwrap();
+
+        assert_eq!(account_id, AccountId::from("BINANCE-001"));
+    }
+
+    #[rstest]
+    fn test_resolve_errors_when_nothing_resolves(cache: Cache) {
+        let router = AccountRouter::new();
+
+        let result = router.resolve(&cache, &StrategyId::from("S-001"), &Venue::from("SIM"));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_resolve_errors_for_mapped_account_not_in_cache(cache: Cache) {
+        let mut router = AccountRouter::new();
+        let strategy_id = StrategyId::from("S-001");
+        router.map_strategy(strategy_id, AccountId::from("BINANCE-003"));
+
+        let result = router.resolve(&cache, &strategy_id, &Venue::from("BINANCE"));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_map_strategy_replaces_mapping_per_venue() {
+        let mut router = AccountRouter::new();
+        let strategy_id = StrategyId::from("S-001");
+
+        assert_eq!(
+            router.map_strategy(strategy_id, AccountId::from("BINANCE-001")),
+            None
+        );
+        assert_eq!(
+            router.map_strategy(strategy_id, AccountId::from("SIM-001")),
+            None
+        );
+        assert_eq!(
+            router.map_strategy(strategy_id, AccountId::from("BINANCE-002")),
+            Some(AccountId::from("BINANCE-001"))
+        );
+        assert_eq!(
+            router.mapped_account(&strategy_id, &Venue::from("SIM")),
+            Some(AccountId::from("SIM-001"))
+        );
+    }
+}
@@ -25,9 +25,9 @@ use nautilus_model::{
     enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
     events::{account::state::AccountState, order::event::OrderEventAny},
     identifiers::{
-        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
-        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, venue::Venue,
-        venue_order_id::VenueOrderId,
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
+        trade_id::TradeId, venue::Venue, venue_order_id::VenueOrderId,
     },
     types::{
         balance::{AccountBalance, MarginBalance},
@@ -39,11 +39,25 @@ use nautilus_model::{
 };
 
 use crate::messages::{
-    cancel::CancelOrder, cancel_batch::BatchCancelOrders, modify::ModifyOrder, query::QueryOrder,
-    submit::SubmitOrder, submit_list::SubmitOrderList,
+    cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
+    modify::ModifyOrder, query::QueryOrder, submit::SubmitOrder, submit_list::SubmitOrderList,
 };
 
-pub struct ExecutionClient {
+/// The interface for an execution client which trading commands are routed to by the
+/// `ExecutionEngine`, generally one per venue.
+pub trait ExecutionClient {
+    fn client_id(&self) -> ClientId;
+    fn venue(&self) -> Venue;
+    fn submit_order(&self, command: SubmitOrder) -> anyhow::Result<()>;
+    fn submit_order_list(&self, command: SubmitOrderList) -> anyhow::Result<()>;
+    fn modify_order(&self, command: ModifyOrder) -> anyhow::Result<()>;
+    fn cancel_order(&self, command: CancelOrder) -> anyhow::Result<()>;
+    fn cancel_all_orders(&self, command: CancelAllOrders) -> anyhow::Result<()>;
+    fn batch_cancel_orders(&self, command: BatchCancelOrders) -> anyhow::Result<()>;
+    fn query_order(&self, command: QueryOrder) -> anyhow::Result<()>;
+}
+
+pub struct BaseExecutionClient {
     pub venue: Venue,
     pub oms_type: OmsType,
     pub account_id: AccountId,
@@ -53,7 +67,7 @@ pub struct ExecutionClient {
     cache: &'static Cache,
 }
 
-impl ExecutionClient {
+impl BaseExecutionClient {
     // TODO: Polymorphism for `Account` TBD?
     // pub fn get_account(&self) -> Box<dyn Account> {
     //     todo!();
@@ -19,50 +19,129 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet, VecDeque},
+    rc::Rc,
+};
 
-use log::debug;
-use nautilus_common::{cache::Cache, generators::position_id::PositionIdGenerator};
+use indexmap::IndexMap;
+use log::{debug, error, warn};
+use nautilus_common::{
+    cache::Cache, calendar::TradingCalendar, generators::position_id::PositionIdGenerator,
+};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
 use nautilus_model::{
-    enums::{OmsType, OrderSide},
-    events::order::{event::OrderEventAny, filled::OrderFilled},
+    enums::{OmsType, OrderSide, OrderStatus},
+    events::order::{
+        denied::OrderDenied, event::OrderEventAny, expired::OrderExpired, filled::OrderFilled,
+    },
     identifiers::{
-        client_id::ClientId, instrument_id::InstrumentId, strategy_id::StrategyId, venue::Venue,
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        instrument_id::InstrumentId, strategy_id::StrategyId, trader_id::TraderId, venue::Venue,
+        venue_order_id::VenueOrderId,
     },
     instruments::any::InstrumentAny,
     orders::any::OrderAny,
+    polymorphism::{
+        GetAccountId, GetClientOrderId, GetInstrumentId, GetOrderFilledQty, GetOrderQuantity,
+        GetOrderStatus, GetStrategyId, GetTraderId, GetVenueOrderId,
+    },
     position::Position,
     types::quantity::Quantity,
 };
 
 use crate::{
+    account_router::AccountRouter,
     client::ExecutionClient,
     messages::{
         cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
         modify::ModifyOrder, query::QueryOrder, submit::SubmitOrder, submit_list::SubmitOrderList,
         TradingCommand,
     },
+    reconciliation::generate_inferred_fill,
+    shutdown::ActivityNotifier,
 };
 
 pub struct ExecutionEngineConfig {
     pub debug: bool,
+    /// The maximum time (nanoseconds) to buffer events for orders not yet known to the cache.
+    pub pending_event_timeout_ns: u64,
+}
+
+impl Default for ExecutionEngineConfig {
+    fn default() -> Self {
+        Self {
+            debug: false,
+            pending_event_timeout_ns: 10_000_000_000, // 10 seconds
+        }
+    }
+}
+
+/// The handler a registered strategy receives its order events through.
+pub type OrderEventHandler = Box<dyn Fn(&OrderEventAny)>;
+
+/// An event received before its order was known, held until the order is added to the cache.
+struct PendingEvent {
+    event: OrderEventAny,
+    ts_received: UnixNanos,
 }
 
 pub struct ExecutionEngine {
     pub command_count: u64,
     pub event_count: u64,
     pub report_count: u64,
-    cache: &'static Cache,
-    default_client: Option<ExecutionClient>,
+    clock: &'static AtomicTime,
+    cache: Rc<RefCell<Cache>>,
+    default_client: Option<Box<dyn ExecutionClient>>,
     pos_id_generator: PositionIdGenerator,
-    clients: HashMap<ClientId, ExecutionClient>,
+    clients: HashMap<ClientId, Box<dyn ExecutionClient>>,
     routing_map: HashMap<Venue, ClientId>,
+    strategies: HashMap<StrategyId, OrderEventHandler>,
     oms_overrides: HashMap<StrategyId, OmsType>,
     external_order_claims: HashMap<InstrumentId, StrategyId>,
+    pending_events: IndexMap<ClientOrderId, Vec<PendingEvent>>,
+    command_queue: VecDeque<TradingCommand>,
+    notifier: ActivityNotifier,
+    calendars: HashMap<InstrumentId, TradingCalendar>,
+    submit_correlations: HashMap<ClientOrderId, UUID4>,
+    account_router: Option<AccountRouter>,
+    submit_accounts: HashMap<ClientOrderId, AccountId>,
     config: ExecutionEngineConfig,
 }
 
 impl ExecutionEngine {
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+        config: ExecutionEngineConfig,
+    ) -> Self {
+        Self {
+            command_count: 0,
+            event_count: 0,
+            report_count: 0,
+            clock,
+            cache,
+            default_client: None,
+            pos_id_generator: PositionIdGenerator::new(trader_id, clock),
+            clients: HashMap::new(),
+            routing_map: HashMap::new(),
+            strategies: HashMap::new(),
+            oms_overrides: HashMap::new(),
+            external_order_claims: HashMap::new(),
+            pending_events: IndexMap::new(),
+            command_queue: VecDeque::new(),
+            notifier: ActivityNotifier::default(),
+            calendars: HashMap::new(),
+            submit_correlations: HashMap::new(),
+            account_router: None,
+            submit_accounts: HashMap::new(),
+            config,
+        }
+    }
+
     #[must_use]
     pub fn position_id_count(&self, strategy_id: StrategyId) -> u64 {
         todo!();
@@ -90,17 +169,56 @@ impl ExecutionEngine {
 
     #[must_use]
     pub fn get_external_order_claims_instruments(&self) -> HashSet<InstrumentId> {
-        todo!();
+        self.external_order_claims.keys().copied().collect()
+    }
+
+    #[must_use]
+    pub fn get_external_order_claim(&self, instrument_id: &InstrumentId) -> Option<StrategyId> {
+        self.external_order_claims.get(instrument_id).copied()
+    }
+
+    /// Returns the number of events buffered for orders not yet known to the cache.
+    #[must_use]
+    pub fn pending_event_count(&self) -> usize {
+        self.pending_events.values().map(Vec::len).sum()
+    }
+
+    /// Returns the number of commands queued but not yet executed.
+    #[must_use]
+    pub fn pending_command_count(&self) -> usize {
+        self.command_queue.len()
+    }
+
+    /// Returns the notifier signalling activity on the engine, which execution clients use to
+    /// wake a waiting shutdown when venue responses arrive.
+    #[must_use]
+    pub fn notifier(&self) -> ActivityNotifier {
+        self.notifier.clone()
     }
 
     // -- REGISTRATION --------------------------------------------------------
 
-    pub fn register_client(&mut self, client: ExecutionClient) -> anyhow::Result<()> {
-        todo!();
+    /// Registers the given execution `client`, routing commands for its venue to it.
+    pub fn register_client(&mut self, client: Box<dyn ExecutionClient>) -> anyhow::Result<()> {
+        let client_id = client.client_id();
+        if self.clients.contains_key(&client_id) {
+            anyhow::bail!("Execution client {client_id} already registered");
+        }
+
+        self.routing_map.insert(client.venue(), client_id);
+        self.clients.insert(client_id, client);
+        debug!("Registered execution client {client_id}");
+        Ok(())
     }
 
-    pub fn register_default_client(&mut self, client: ExecutionClient) -> anyhow::Result<()> {
-        todo!();
+    /// Registers the given `client` to receive commands which match no other routing.
+    pub fn register_default_client(
+        &mut self,
+        client: Box<dyn ExecutionClient>,
+    ) -> anyhow::Result<()> {
+        debug!("Registered default execution client {}", client.client_id());
+        self.default_client = Some(client);
+        Ok(())
     }
 
     pub fn register_venue_routing(
@@ -108,16 +226,103 @@ impl ExecutionEngine {
         client_id: ClientId,
         venue: Venue,
     ) -> anyhow::Result<()> {
-        todo!();
+        if !self.clients.contains_key(&client_id) {
+            anyhow::bail!("Execution client {client_id} not registered");
+        }
+
+        self.routing_map.insert(venue, client_id);
+        debug!("Set client {client_id} routing for {venue}");
+        Ok(())
     }
 
-    // TODO: Implement `Strategy`
-    // pub fn register_external_order_claims(&mut self, strategy: Strategy) -> anyhow::Result<()> {
-    //     todo!();
-    // }
+    /// Registers the `handler` to receive events for orders owned by the given `strategy_id`.
+    pub fn register_strategy(
+        &mut self,
+        strategy_id: StrategyId,
+        handler: OrderEventHandler,
+    ) -> anyhow::Result<()> {
+        if self.strategies.contains_key(&strategy_id) {
+            anyhow::bail!("Strategy {strategy_id} already registered");
+        }
+
+        self.strategies.insert(strategy_id, handler);
+        debug!("Registered strategy {strategy_id}");
+        Ok(())
+    }
+
+    /// Deregisters the given `strategy_id`, releasing any external order claims it holds.
+    pub fn deregister_strategy(&mut self, strategy_id: StrategyId) -> anyhow::Result<()> {
+        if self.strategies.remove(&strategy_id).is_none() {
+            anyhow::bail!("Strategy {strategy_id} not registered");
+        }
+
+        self.external_order_claims
+            .retain(|_, claimant| *claimant != strategy_id);
+        debug!("Deregistered strategy {strategy_id}");
+        Ok(())
+    }
+
+    /// Claims the `EXTERNAL` orders for each of the given `instrument_ids`, routing their
+    /// events to the given `strategy_id`.
+    ///
+    /// # Errors
+    ///
+    /// If the strategy is not registered, or any instrument is already claimed (in which case no
+    /// claims are registered).
+    pub fn register_external_order_claims(
+        &mut self,
+        strategy_id: StrategyId,
+        instrument_ids: &[InstrumentId],
+    ) -> anyhow::Result<()> {
+        if !self.strategies.contains_key(&strategy_id) {
+            anyhow::bail!("Strategy {strategy_id} not registered");
+        }
+        for instrument_id in instrument_ids {
+            if let Some(existing) = self.external_order_claims.get(instrument_id) {
+                anyhow::bail!(
+                    "External order claim for {instrument_id} already exists for {existing}"
+                );
+            }
+        }
+
+        for instrument_id in instrument_ids {
+            self.external_order_claims
+                .insert(*instrument_id, strategy_id);
+            debug!("Registered external order claim for {instrument_id} by {strategy_id}");
+        }
+        Ok(())
+    }
+
+    /// Registers the trading `calendar` for the given `instrument_id`, used when expiring
+    /// `DAY` orders at the session close.
+    pub fn register_trading_calendar(
+        &mut self,
+        instrument_id: InstrumentId,
+        calendar: TradingCalendar,
+    ) {
+        self.calendars.insert(instrument_id, calendar);
+    }
+
+    /// Sets the `router` resolving the account each submitted order is submitted under.
+    ///
+    /// Once set, orders for which no account resolves are denied rather than submitted.
+    pub fn set_account_router(&mut self, router: AccountRouter) {
+        self.account_router = Some(router);
+    }
+
+    #[must_use]
+    pub fn account_router(&self) -> Option<&AccountRouter> {
+        self.account_router.as_ref()
+    }
 
     pub fn deregister_client(&mut self, client_id: ClientId) -> anyhow::Result<()> {
-        todo!();
+        if self.clients.remove(&client_id).is_none() {
+            anyhow::bail!("Execution client {client_id} not registered");
+        }
+
+        self.routing_map.retain(|_, routed| *routed != client_id);
+        debug!("Deregistered execution client {client_id}");
+        Ok(())
     }
 
     // -- COMMANDS ------------------------------------------------------------
@@ -132,10 +337,269 @@ impl ExecutionEngine {
 
     pub fn execute(&mut self, command: TradingCommand) {
         self.execute_command(command);
+        self.flush_pending_events();
     }
 
-    pub fn process(&self, event: &OrderEventAny) {
-        todo!();
+    /// Queues the `command`, to be executed on the next `process_queued_commands`.
+    pub fn enqueue(&mut self, command: TradingCommand) {
+        self.command_queue.push_back(command);
+        self.notifier.notify();
+    }
+
+    /// Executes all queued commands in the order they were queued.
+    pub fn process_queued_commands(&mut self) {
+        while let Some(command) = self.command_queue.pop_front() {
+            self.execute(command);
+        }
+    }
+
+    pub fn process(&mut self, event: &OrderEventAny) {
+        self.flush_pending_events();
+        self.handle_event(event.clone());
+    }
+
+    /// Applies events buffered for orders which are now known to the cache, and drops those
+    /// buffered for longer than the configured timeout.
+    pub fn flush_pending_events(&mut self) {
+        if self.pending_events.is_empty() {
+            return;
+        }
+
+        let ready: Vec<ClientOrderId> = {
+            let cache = self.cache.borrow();
+            self.pending_events
+                .keys()
+                .filter(|client_order_id| cache.order(client_order_id).is_some())
+                .copied()
+                .collect()
+        };
+        for client_order_id in ready {
+            if let Some(pending) = self.pending_events.shift_remove(&client_order_id) {
+                for pending_event in pending {
+                    self.apply_and_route_event(pending_event.event);
+                }
+            }
+        }
+
+        let now = self.clock.get_time_ns().as_u64();
+        let timeout_ns = self.config.pending_event_timeout_ns;
+        self.pending_events.retain(|client_order_id, pending| {
+            pending.retain(|pending_event| {
+                let is_expired =
+                    now.saturating_sub(pending_event.ts_received.as_u64()) > timeout_ns;
+                if is_expired {
+                    warn!(
+                        "Dropping {} for unknown order {client_order_id} after {timeout_ns}ns",
+                        pending_event.event,
+                    );
+                }
+                !is_expired
+            });
+            !pending.is_empty()
+        });
+    }
+
+    /// Expires all open `DAY` orders at the session close, returning the expired order IDs.
+    ///
+    /// Each order is expired with an `OrderExpired` event timestamped at `session_close_ts`.
+    /// Orders pending cancel are skipped, as are orders for instruments whose registered
+    /// trading calendar still has a session open at the close (such as 24 hour markets).
+    pub fn expire_day_orders(&mut self, session_close_ts: UnixNanos) -> Vec<ClientOrderId> {
+        let ts_init = self.clock.get_time_ns();
+        let events: Vec<OrderEventAny> = {
+            let cache = self.cache.borrow();
+            cache
+                .day_orders_open(None)
+                .into_iter()
+                .filter_map(|client_order_id| cache.order(&client_order_id))
+                .filter(|order| {
+                    order.status() != OrderStatus::PendingCancel
+                        && !cache.is_order_pending_cancel_local(&order.client_order_id())
+                })
+                .filter(|order| {
+                    !self
+                        .calendars
+                        .get(&order.instrument_id())
+                        .is_some_and(|calendar| calendar.is_open(session_close_ts))
+                })
+                .filter_map(|order| {
+                    OrderExpired::new(
+                        order.trader_id(),
+                        order.strategy_id(),
+                        order.instrument_id(),
+                        order.client_order_id(),
+                        UUID4::new(),
+                        session_close_ts,
+                        ts_init,
+                        false,
+                        order.venue_order_id(),
+                        order.account_id(),
+                    )
+                    .map_err(|e| error!("Cannot expire {}: {e}", order.client_order_id()))
+                    .ok()
+                })
+                .map(OrderEventAny::Expired)
+                .collect()
+        };
+
+        events
+            .iter()
+            .filter_map(|event| {
+                self.process(event);
+                let client_order_id = event.client_order_id();
+                let is_expired = self.cache.borrow().is_order_closed(&client_order_id);
+                is_expired.then_some(client_order_id)
+            })
+            .collect()
+    }
+
+    /// Reconciles an order with the `filled_qty` and `avg_px` reported by the venue.
+    ///
+    /// When the venue reports more filled than the fills already applied to the order, a fill
+    /// for the difference is inferred with [`generate_inferred_fill`] (from the latest quote for
+    /// the instrument) and processed like any other event. Returns the inferred fill, or `None`
+    /// if the order is already filled to the reported quantity.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The order or its instrument is not in the cache.
+    /// - The fill can't be inferred from the report.
+    pub fn reconcile_order_fill(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        venue_order_id: VenueOrderId,
+        account_id: AccountId,
+        filled_qty: Quantity,
+        avg_px: f64,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<Option<OrderFilled>> {
+        let ts_init = self.clock.get_time_ns();
+        let (fill, is_filled) = {
+            let cache = self.cache.borrow();
+            let order = cache.order(client_order_id).ok_or_else(|| {
+                anyhow::anyhow!("Cannot reconcile unknown order {client_order_id}")
+            })?;
+            if filled_qty <= order.filled_qty() {
+                return Ok(None);
+            }
+            let instrument_id = order.instrument_id();
+            let instrument = cache.instrument(&instrument_id).ok_or_else(|| {
+                anyhow::anyhow!("Cannot reconcile {client_order_id}: no instrument {instrument_id}")
+            })?;
+            let quote = cache.quote_tick(&instrument_id);
+            let fill = match order {
+                OrderAny::Limit(order) => generate_inferred_fill(
+                    order,
+                    instrument,
+                    venue_order_id,
+                    account_id,
+                    filled_qty,
+                    avg_px,
+                    quote,
+                    ts_event,
+                    ts_init,
+                ),
+                OrderAny::LimitIfTouched(order) => generate_inferred_fill(
+                    order,
+                    instrument,
+                    venue_order_id,
+                    account_id,
+                    filled_qty,
+                    avg_px,
+                    quote,
+                    ts_event,
+                    ts_init,
+                ),
+                OrderAny::Market(order) => generate_inferred_fill(
+                    order,
+                    instrument,
+                    venue_order_id,
+                    account_id,
+                    filled_qty,
+                    avg_px,
+                    quote,
+                    ts_event,
+                    ts_init,
+                ),
+                OrderAny::MarketIfTouched(order) => generate_inferred_fill(
+                    order,
+                    instrument,
+                    venue_order_id,
+                    account_id,
+                    filled_qty,
+                    avg_px,
+                    quote,
+                    ts_event,
+                    ts_init,
+                ),
+                OrderAny::MarketToLimit(order) => generate_inferred_fill(
+                    order,
+                    instrument,
+                    venue_order_id,
+                    account_id,
+                    filled_qty,
+                    avg_px,
+                    quote,
+                    ts_event,
+                    ts_init,
+                ),
+                OrderAny::StopLimit(order) => generate_inferred_fill(
+                    order,
+                    instrument,
+                    venue_order_id,
+                    account_id,
+                    filled_qty,
+                    avg_px,
+                    quote,
+                    ts_event,
+                    ts_init,
+                ),
+                OrderAny::StopMarket(order) => generate_inferred_fill(
+                    order,
+                    instrument,
+                    venue_order_id,
+                    account_id,
+                    filled_qty,
+                    avg_px,
+                    quote,
+                    ts_event,
+                    ts_init,
+                ),
+                OrderAny::TrailingStopLimit(order) => generate_inferred_fill(
+                    order,
+                    instrument,
+                    venue_order_id,
+                    account_id,
+                    filled_qty,
+                    avg_px,
+                    quote,
+                    ts_event,
+                    ts_init,
+                ),
+                OrderAny::TrailingStopMarket(order) => generate_inferred_fill(
+                    order,
+                    instrument,
+                    venue_order_id,
+                    account_id,
+                    filled_qty,
+                    avg_px,
+                    quote,
+                    ts_event,
+                    ts_init,
+                ),
+            }?;
+            (fill, filled_qty >= order.quantity())
+        };
+
+        self.report_count += 1;
+        let event = if is_filled {
+            OrderEventAny::Filled(fill)
+        } else {
+            OrderEventAny::PartiallyFilled(fill)
+        };
+        self.process(&event);
+        Ok(Some(fill))
     }
 
     // -- COMMAND HANDLERS ----------------------------------------------------
@@ -144,18 +608,36 @@ impl ExecutionEngine {
         debug!("<--[CMD] {:?}", command); // TODO: Log constants
         self.command_count += 1;
 
-        // TODO: Refine getting the client (no need for two expects)
-        let client = if let Some(client) = self.clients.get(&command.client_id()) {
-            client
-        } else if let Some(client_id) = self.routing_map.get(&command.instrument_id().venue) {
-            if let Some(client) = self.clients.get(client_id) {
-                client
-            } else {
-                self.default_client.as_ref().expect("No client found")
-            }
-        } else {
-            self.default_client.as_ref().expect("No client found")
+        let command = match command {
+            TradingCommand::SubmitOrder(cmd) => match self.route_account(cmd) {
+                Some(cmd) => TradingCommand::SubmitOrder(cmd),
+                None => return,
+            },
+            command => command,
+        };
+
+        if let Some(correlation_id) = command.correlation_id() {
+            self.register_submit_correlation(&command, correlation_id);
+        }
+
+        let client_id = command.client_id();
+        let venue = command.instrument_id().venue;
+        let Some(client) = self
+            .clients
+            .get(&client_id)
+            .or_else(|| {
+                self.routing_map
+                    .get(&venue)
+                    .and_then(|client_id| self.clients.get(client_id))
+            })
+            .or(self.default_client.as_ref())
+        else {
+            error!(
+                "Cannot execute command: no execution client configured for {venue} or `client_id` {client_id}, {command}"
+            );
+            return;
         };
+        let client = client.as_ref();
 
         match command {
             TradingCommand::SubmitOrder(cmd) => self.handle_submit_order(client, cmd),
@@ -168,38 +650,211 @@ impl ExecutionEngine {
         }
     }
 
-    fn handle_submit_order(&self, client: &ExecutionClient, command: SubmitOrder) {
-        todo!();
+    /// Registers the `correlation_id` of a submit command against the orders it submits, so
+    /// the resulting submission events can be correlated.
+    fn register_submit_correlation(&mut self, command: &TradingCommand, correlation_id: UUID4) {
+        match command {
+            TradingCommand::SubmitOrder(cmd) => {
+                self.submit_correlations
+                    .insert(cmd.client_order_id, correlation_id);
+            }
+            TradingCommand::SubmitOrderList(cmd) => {
+                for order in &cmd.order_list.orders {
+                    self.submit_correlations
+                        .insert(order.client_order_id(), correlation_id);
+                }
+            }
+            _ => {}
+        }
     }
 
-    fn handle_submit_order_list(&self, client: &ExecutionClient, command: SubmitOrderList) {
-        todo!();
+    /// Resolves the account for the submit `command` with the account router (if set),
+    /// denying the order and returning `None` when no account resolves.
+    fn route_account(&mut self, mut command: SubmitOrder) -> Option<SubmitOrder> {
+        let Some(router) = &self.account_router else {
+            return Some(command);
+        };
+
+        let resolved = router.resolve(
+            &self.cache.borrow(),
+            &command.strategy_id,
+            &command.instrument_id.venue,
+        );
+        match resolved {
+            Ok(account_id) => {
+                command.account_id = Some(account_id);
+                self.submit_accounts
+                    .insert(command.client_order_id, account_id);
+                Some(command)
+            }
+            Err(e) => {
+                let order = self.cache.borrow().order(&command.client_order_id).cloned();
+                match order {
+                    Some(order) => self.deny_order(&order, &e.to_string()),
+                    None => error!(
+                        "Cannot submit order: {} not found in cache",
+                        command.client_order_id
+                    ),
+                }
+                None
+            }
+        }
     }
 
-    fn handle_modify_order(&self, client: &ExecutionClient, command: ModifyOrder) {
-        todo!();
+    fn handle_submit_order(&self, client: &dyn ExecutionClient, command: SubmitOrder) {
+        // The strategy adds the order to the cache before submitting, which registers ownership
+        if self
+            .cache
+            .borrow()
+            .order(&command.client_order_id)
+            .is_none()
+        {
+            error!(
+                "Cannot submit order: {} not found in cache",
+                command.client_order_id
+            );
+            return;
+        }
+
+        if let Err(e) = client.submit_order(command) {
+            error!("Error submitting order: {e}");
+        }
     }
 
-    fn handle_cancel_order(&self, client: &ExecutionClient, command: CancelOrder) {
-        todo!();
+    fn handle_submit_order_list(&self, client: &dyn ExecutionClient, command: SubmitOrderList) {
+        if let Err(e) = client.submit_order_list(command) {
+            error!("Error submitting order list: {e}");
+        }
     }
 
-    fn handle_cancel_all_orders(&self, client: &ExecutionClient, command: CancelAllOrders) {
-        todo!();
+    fn handle_modify_order(&self, client: &dyn ExecutionClient, command: ModifyOrder) {
+        if let Err(e) = client.modify_order(command) {
+            error!("Error modifying order: {e}");
+        }
     }
 
-    fn handle_batch_cancel_orders(&self, client: &ExecutionClient, command: BatchCancelOrders) {
-        todo!();
+    fn handle_cancel_order(&self, client: &dyn ExecutionClient, command: CancelOrder) {
+        if let Err(e) = client.cancel_order(command) {
+            error!("Error canceling order: {e}");
+        }
     }
 
-    fn handle_query_order(&self, client: &ExecutionClient, command: QueryOrder) {
-        todo!();
+    fn handle_cancel_all_orders(&self, client: &dyn ExecutionClient, command: CancelAllOrders) {
+        if let Err(e) = client.cancel_all_orders(command) {
+            error!("Error canceling all orders: {e}");
+        }
+    }
+
+    fn handle_batch_cancel_orders(&self, client: &dyn ExecutionClient, command: BatchCancelOrders) {
+        if let Err(e) = client.batch_cancel_orders(command) {
+            error!("Error batch canceling orders: {e}");
+        }
+    }
+
+    fn handle_query_order(&self, client: &dyn ExecutionClient, command: QueryOrder) {
+        if let Err(e) = client.query_order(command) {
+            error!("Error querying order: {e}");
+        }
     }
 
     // -- EVENT HANDLERS ----------------------------------------------------
 
-    fn handle_event(&self, event: OrderEventAny) {
-        todo!();
+    fn handle_event(&mut self, mut event: OrderEventAny) {
+        debug!("<--[EVT] {event:?}");
+        self.event_count += 1;
+
+        self.correlate_event(&mut event);
+        self.stamp_account(&mut event);
+
+        let client_order_id = event.client_order_id();
+        let is_known = self.cache.borrow().order(&client_order_id).is_some();
+        if !is_known || self.pending_events.contains_key(&client_order_id) {
+            debug!("Buffering {event} until order {client_order_id} is known");
+            self.pending_events
+                .entry(client_order_id)
+                .or_default()
+                .push(PendingEvent {
+                    event,
+                    ts_received: self.clock.get_time_ns(),
+                });
+            return;
+        }
+
+        self.apply_and_route_event(event);
+    }
+
+    /// Sets the correlation ID of the originating submit command on submission events which
+    /// don't already carry one. Events generated by reconciliation are left uncorrelated.
+    fn correlate_event(&mut self, event: &mut OrderEventAny) {
+        let client_order_id = event.client_order_id();
+        let (is_correlated, is_resolved) = match event {
+            OrderEventAny::Submitted(e) => (e.correlation_id.is_some(), false),
+            OrderEventAny::Accepted(e) => {
+                (e.correlation_id.is_some() || e.reconciliation != 0, true)
+            }
+            OrderEventAny::Rejected(e) => {
+                (e.correlation_id.is_some() || e.reconciliation != 0, true)
+            }
+            _ => return,
+        };
+
+        let correlation_id = if is_resolved {
+            // The submission is resolved, so no further events are correlated
+            self.submit_correlations.remove(&client_order_id)
+        } else {
+            self.submit_correlations.get(&client_order_id).copied()
+        };
+        if !is_correlated {
+            event.set_correlation_id(correlation_id);
+        }
+    }
+
+    /// Sets the account resolved by the account router on `OrderSubmitted` events, so they
+    /// record the account the order was routed to. Venue events keep the account they report.
+    fn stamp_account(&mut self, event: &mut OrderEventAny) {
+        if let OrderEventAny::Submitted(e) = event {
+            if let Some(account_id) = self.submit_accounts.get(&e.client_order_id) {
+                e.account_id = *account_id;
+            }
+        }
+    }
+
+    fn apply_and_route_event(&mut self, event: OrderEventAny) {
+        match self.cache.borrow_mut().apply_order_event(event.clone()) {
+            Ok(true) => {}
+            Ok(false) => return, // Duplicate
+            Err(e) => {
+                error!("Error applying {event}: {e}");
+                return;
+            }
+        }
+
+        let client_order_id = event.client_order_id();
+        if self.cache.borrow().is_order_closed(&client_order_id) {
+            self.submit_accounts.remove(&client_order_id);
+        }
+
+        let Some(strategy_id) = self.owning_strategy(&client_order_id) else {
+            debug!("No registered owner for {event}");
+            return;
+        };
+        match self.strategies.get(&strategy_id) {
+            Some(handler) => handler(&event),
+            None => warn!("Cannot route {event}: strategy {strategy_id} not registered"),
+        }
+    }
+
+    /// Returns the strategy owning the order according to the cache, or the claiming strategy
+    /// for `EXTERNAL` orders.
+    fn owning_strategy(&self, client_order_id: &ClientOrderId) -> Option<StrategyId> {
+        let cache = self.cache.borrow();
+        let strategy_id = *cache.strategy_id_for_order(client_order_id)?;
+        if !strategy_id.is_external() {
+            return Some(strategy_id);
+        }
+
+        let instrument_id = cache.order(client_order_id)?.instrument_id();
+        self.get_external_order_claim(&instrument_id)
     }
 
     fn determine_oms_type(&self, fill: OrderFilled) {
@@ -282,7 +937,939 @@ impl ExecutionEngine {
         todo!();
     }
 
-    fn deny_order(&self, order: &OrderAny, reason: &str) {
-        todo!();
+    fn deny_order(&mut self, order: &OrderAny, reason: &str) {
+        error!("Order denied: {reason}");
+
+        let ts_now = self.clock.get_time_ns();
+        let denied = OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            reason.into(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        )
+        .expect("Order denied event is valid");
+        self.apply_and_route_event(OrderEventAny::Denied(denied));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveTime, TimeZone, Utc, Weekday};
+    use nautilus_common::{calendar::SessionTemplate, stubs::StubAccount};
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        enums::{LiquiditySide, TimeInForce},
+        events::order::pending_cancel::OrderPendingCancel,
+        instruments::stubs::audusd_sim,
+        orders::{limit::LimitOrder, stubs::TestOrderEventStubs},
+        stubs::{
+            account_id, audusd_sim_id, order_initialized_limit, strategy_id_ema_cross, trader_id,
+        },
+        types::price::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::{cancel::CancelOrderBuilder, submit::SubmitOrderBuilder};
+
+    type CommandLog = Rc<RefCell<Vec<(ClientId, TradingCommand)>>>;
+    type EventLog = Rc<RefCell<Vec<(StrategyId, OrderEventAny)>>>;
+
+    struct StubExecutionClient {
+        client_id: ClientId,
+        venue: Venue,
+        commands: CommandLog,
+    }
+
+    impl StubExecutionClient {
+        fn boxed(client_id: &str, venue: &str, commands: &CommandLog) -> Box<Self> {
+            Box::new(Self {
+                client_id: ClientId::from(client_id),
+                venue: Venue::from(venue),
+                commands: commands.clone(),
+            })
+        }
+
+        fn record(&self, command: TradingCommand) -> anyhow::Result<()> {
+            self.commands.borrow_mut().push((self.client_id, command));
+            Ok(())
+        }
+    }
+
+    impl ExecutionClient for StubExecutionClient {
+        fn client_id(&self) -> ClientId {
+            self.client_id
+        }
+
+        fn venue(&self) -> Venue {
+            self.venue
+        }
+
+        fn submit_order(&self, command: SubmitOrder) -> anyhow::Result<()> {
+            self.record(TradingCommand::SubmitOrder(command))
+        }
+
+        fn submit_order_list(&self, command: SubmitOrderList) -> anyhow::Result<()> {
+            self.record(TradingCommand::SubmitOrderList(command))
+        }
+
+        fn modify_order(&self, command: ModifyOrder) -> anyhow::Result<()> {
+            self.record(TradingCommand::ModifyOrder(command))
+        }
+
+        fn cancel_order(&self, command: CancelOrder) -> anyhow::Result<()> {
+            self.record(TradingCommand::CancelOrder(command))
+        }
+
+        fn cancel_all_orders(&self, command: CancelAllOrders) -> anyhow::Result<()> {
+            self.record(TradingCommand::CancelAllOrders(command))
+        }
+
+        fn batch_cancel_orders(&self, command: BatchCancelOrders) -> anyhow::Result<()> {
+            self.record(TradingCommand::BatchCancelOrders(command))
+        }
+
+        fn query_order(&self, command: QueryOrder) -> anyhow::Result<()> {
+            self.record(TradingCommand::QueryOrder(command))
+        }
+    }
+
+    fn clock() -> &'static AtomicTime {
+        Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())))
+    }
+
+    fn engine(clock: &'static AtomicTime, cache: &Rc<RefCell<Cache>>) -> ExecutionEngine {
+        let config = ExecutionEngineConfig {
+            pending_event_timeout_ns: 1_000,
+            ..Default::default()
+        };
+        ExecutionEngine::new(trader_id(), clock, cache.clone(), config)
+    }
+
+    fn recorder(strategy_id: StrategyId, events: &EventLog) -> OrderEventHandler {
+        let events = events.clone();
+        Box::new(move |event| events.borrow_mut().push((strategy_id, event.clone())))
+    }
+
+    fn limit_order(strategy_id: StrategyId, client_order_id: &str) -> LimitOrder {
+        let mut init = order_initialized_limit(
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Price::from("1.00000"),
+        );
+        init.strategy_id = strategy_id;
+        init.client_order_id = ClientOrderId::from(client_order_id);
+        LimitOrder::from(init)
+    }
+
+    fn submitted(order: &LimitOrder) -> OrderEventAny {
+        OrderEventAny::Submitted(TestOrderEventStubs::order_submitted(order, account_id()).unwrap())
+    }
+
+    fn accepted_order(
+        cache: &Rc<RefCell<Cache>>,
+        client_order_id: &str,
+        instrument_id: &str,
+        time_in_force: TimeInForce,
+    ) -> LimitOrder {
+        let mut init = order_initialized_limit(
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Price::from("1.00000"),
+        );
+        init.client_order_id = ClientOrderId::from(client_order_id);
+        init.instrument_id = InstrumentId::from(instrument_id);
+        init.time_in_force = time_in_force;
+        if time_in_force == TimeInForce::Gtd {
+            init.expire_time = Some(UnixNanos::from(u64::MAX));
+        }
+        let order = LimitOrder::from(init);
+        let venue_order_id = format!("V-{client_order_id}").as_str().into();
+        let mut cache = cache.borrow_mut();
+        cache
+            .add_order(OrderAny::Limit(order.clone()), None, None, false)
+            .unwrap();
+        cache.apply_order_event(submitted(&order)).unwrap();
+        cache
+            .apply_order_event(OrderEventAny::Accepted(
+                TestOrderEventStubs::order_accepted(&order, account_id(), venue_order_id).unwrap(),
+            ))
+            .unwrap();
+        order
+    }
+
+    fn daily_calendar(open: NaiveTime, close: NaiveTime, weekdays: &[Weekday]) -> TradingCalendar {
+        let templates = weekdays
+            .iter()
+            .map(|weekday| SessionTemplate::new(*weekday, open, close))
+            .collect();
+        TradingCalendar::new(chrono_tz::UTC, templates, vec![])
+    }
+
+    fn cancel(client_id: &str, instrument_id: &str) -> TradingCommand {
+        TradingCommand::CancelOrder(
+            CancelOrderBuilder::default()
+                .client_id(ClientId::from(client_id))
+                .instrument_id(InstrumentId::from(instrument_id))
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[rstest]
+    fn test_execute_routes_by_client_id_then_venue_then_default() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let commands = CommandLog::default();
+        engine
+            .register_client(StubExecutionClient::boxed("SIM", "SIM", &commands))
+            .unwrap();
+        engine
+            .register_client(StubExecutionClient::boxed("OTHER", "OTHER", &commands))
+            .unwrap();
+        engine
+            .register_default_client(StubExecutionClient::boxed("DEFAULT", "NONE", &commands))
+            .unwrap();
+
+        engine.execute(cancel("OTHER", "AUD/USD.SIM"));
+        engine.execute(cancel("UNKNOWN", "AUD/USD.SIM"));
+        engine.execute(cancel("UNKNOWN", "ETHUSDT.BINANCE"));
+
+        let routed: Vec<ClientId> = commands.borrow().iter().map(|(id, _)| *id).collect();
+        assert_eq!(
+            routed,
+            vec![
+                ClientId::from("OTHER"),
+                ClientId::from("SIM"),
+                ClientId::from("DEFAULT"),
+            ]
+        );
+        assert_eq!(engine.command_count, 3);
+    }
+
+    #[rstest]
+    fn test_execute_without_matching_client_is_dropped() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let commands = CommandLog::default();
+        engine
+            .register_client(StubExecutionClient::boxed("SIM", "SIM", &commands))
+            .unwrap();
+        engine.deregister_client(ClientId::from("SIM")).unwrap();
+
+        engine.execute(cancel("SIM", "AUD/USD.SIM"));
+
+        assert!(commands.borrow().is_empty());
+        assert!(engine.deregister_client(ClientId::from("SIM")).is_err());
+    }
+
+    #[rstest]
+    fn test_submit_order_requires_order_in_cache() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let commands = CommandLog::default();
+        engine
+            .register_client(StubExecutionClient::boxed("SIM", "SIM", &commands))
+            .unwrap();
+        let order = limit_order(strategy_id_ema_cross(), "O-1");
+        let submit = || {
+            TradingCommand::SubmitOrder(
+                SubmitOrderBuilder::default()
+                    .strategy_id(strategy_id_ema_cross())
+                    .instrument_id(audusd_sim_id())
+                    .client_order_id(ClientOrderId::from("O-1"))
+                    .build()
+                    .unwrap(),
+            )
+        };
+
+        engine.execute(submit());
+        assert!(commands.borrow().is_empty());
+
+        cache
+            .borrow_mut()
+            .add_order(OrderAny::Limit(order), None, None, false)
+            .unwrap();
+        engine.execute(submit());
+        assert_eq!(commands.borrow().len(), 1);
+    }
+
+    fn submit_order(strategy_id: StrategyId, client_order_id: &str) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrderBuilder::default()
+                .strategy_id(strategy_id)
+                .instrument_id(audusd_sim_id())
+                .client_order_id(ClientOrderId::from(client_order_id))
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn submitted_account(commands: &CommandLog) -> Vec<Option<AccountId>> {
+        commands
+            .borrow()
+            .iter()
+            .map(|(_, command)| match command {
+                TradingCommand::SubmitOrder(cmd) => cmd.account_id,
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_account_router_routes_per_strategy_with_venue_fallback() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_account(StubAccount::boxed("SIM-001"))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_account(StubAccount::boxed("SIM-002"))
+            .unwrap();
+        let mut engine = engine(clock(), &cache);
+        let commands = CommandLog::default();
+        let events = EventLog::default();
+        let mapped_id = StrategyId::from("S-002");
+        engine
+            .register_client(StubExecutionClient::boxed("SIM", "SIM", &commands))
+            .unwrap();
+        engine
+            .register_strategy(mapped_id, recorder(mapped_id, &events))
+            .unwrap();
+        let mut router = AccountRouter::new();
+        router.map_strategy(mapped_id, AccountId::from("SIM-002"));
+        engine.set_account_router(router);
+        let mapped_order = limit_order(mapped_id, "O-1");
+        let default_order = limit_order(strategy_id_ema_cross(), "O-2");
+        for order in [&mapped_order, &default_order] {
+            cache
+                .borrow_mut()
+                .add_order(OrderAny::Limit(order.clone()), None, None, false)
+                .unwrap();
+        }
+
+        engine.execute(submit_order(mapped_id, "O-1"));
+        engine.execute(submit_order(strategy_id_ema_cross(), "O-2"));
+        // The client reports the submission under the venue default account
+        engine.process(&submitted(&mapped_order));
+
+        assert_eq!(
+            submitted_account(&commands),
+            vec![
+                Some(AccountId::from("SIM-002")),
+                Some(AccountId::from("SIM-001")),
+            ]
+        );
+        let events = events.borrow();
+        let OrderEventAny::Submitted(event) = &events[0].1 else {
+            panic!("expected submitted event")
+        };
+        assert_eq!(event.account_id, AccountId::from("SIM-002"));
+        assert_eq!(
+            cache
+                .borrow()
+                .order(&ClientOrderId::from("O-1"))
+                .unwrap()
+                .account_id(),
+            Some(AccountId::from("SIM-002"))
+        );
+    }
+
+    #[rstest]
+    fn test_account_router_stamps_only_submitted_and_evicts_closed_orders() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_account(StubAccount::boxed("SIM-001"))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_account(StubAccount::boxed("SIM-002"))
+            .unwrap();
+        let mut engine = engine(clock(), &cache);
+        let commands = CommandLog::default();
+        let events = EventLog::default();
+        engine
+            .register_client(StubExecutionClient::boxed("SIM", "SIM", &commands))
+            .unwrap();
+        engine
+            .register_strategy(
+                strategy_id_ema_cross(),
+                recorder(strategy_id_ema_cross(), &events),
+            )
+            .unwrap();
+        let mut router = AccountRouter::new();
+        router.map_strategy(strategy_id_ema_cross(), AccountId::from("SIM-002"));
+        engine.set_account_router(router);
+        let order = limit_order(strategy_id_ema_cross(), "O-1");
+        cache
+            .borrow_mut()
+            .add_order(OrderAny::Limit(order.clone()), None, None, false)
+            .unwrap();
+
+        engine.execute(submit_order(strategy_id_ema_cross(), "O-1"));
+        engine.process(&submitted(&order));
+        // The venue reports the acceptance under its own account
+        engine.process(&OrderEventAny::Accepted(
+            TestOrderEventStubs::order_accepted(&order, account_id(), "V-1".into()).unwrap(),
+        ));
+        let is_tracked_while_open = engine.submit_accounts.contains_key(&order.client_order_id);
+        let OrderAny::Limit(working) = cache
+            .borrow()
+            .order(&order.client_order_id)
+            .cloned()
+            .unwrap()
+        else {
+            panic!("Expected limit order");
+        };
+        engine.process(&OrderEventAny::Filled(
+            TestOrderEventStubs::order_filled(
+                &working,
+                &audusd_sim(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap(),
+        ));
+
+        let events = events.borrow();
+        let OrderEventAny::Submitted(submitted) = &events[0].1 else {
+            panic!("expected submitted event")
+        };
+        let OrderEventAny::Accepted(accepted) = &events[1].1 else {
+            panic!("expected accepted event")
+        };
+        assert_eq!(submitted.account_id, AccountId::from("SIM-002"));
+        assert_eq!(accepted.account_id, AccountId::from("SIM-001"));
+        assert!(is_tracked_while_open);
+        assert!(cache.borrow().is_order_closed(&order.client_order_id));
+        assert!(engine.submit_accounts.is_empty());
+    }
+
+    #[rstest]
+    fn test_account_router_denies_order_when_no_account_resolves() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let commands = CommandLog::default();
+        let events = EventLog::default();
+        engine
+            .register_client(StubExecutionClient::boxed("SIM", "SIM", &commands))
+            .unwrap();
+        engine
+            .register_strategy(
+                strategy_id_ema_cross(),
+                recorder(strategy_id_ema_cross(), &events),
+            )
+            .unwrap();
+        engine.set_account_router(AccountRouter::new());
+        let order = limit_order(strategy_id_ema_cross(), "O-1");
+        cache
+            .borrow_mut()
+            .add_order(OrderAny::Limit(order), None, None, false)
+            .unwrap();
+
+        engine.execute(submit_order(strategy_id_ema_cross(), "O-1"));
+
+        assert!(commands.borrow().is_empty());
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0].1, OrderEventAny::Denied(_)));
+        assert_eq!(
+            cache
+                .borrow()
+                .order(&ClientOrderId::from("O-1"))
+                .unwrap()
+                .status(),
+            OrderStatus::Denied
+        );
+    }
+
+    #[rstest]
+    fn test_submit_without_account_router_leaves_account_unset() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let commands = CommandLog::default();
+        engine
+            .register_client(StubExecutionClient::boxed("SIM", "SIM", &commands))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_order(
+                OrderAny::Limit(limit_order(strategy_id_ema_cross(), "O-1")),
+                None,
+                None,
+                false,
+            )
+            .unwrap();
+
+        engine.execute(submit_order(strategy_id_ema_cross(), "O-1"));
+
+        assert_eq!(submitted_account(&commands), vec![None]);
+    }
+
+    #[rstest]
+    fn test_process_routes_event_to_owning_strategy() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let events = EventLog::default();
+        let other_id = StrategyId::from("S-002");
+        engine
+            .register_strategy(
+                strategy_id_ema_cross(),
+                recorder(strategy_id_ema_cross(), &events),
+            )
+            .unwrap();
+        engine
+            .register_strategy(other_id, recorder(other_id, &events))
+            .unwrap();
+        let order = limit_order(other_id, "O-1");
+        let event = submitted(&order);
+        cache
+            .borrow_mut()
+            .add_order(OrderAny::Limit(order), None, None, false)
+            .unwrap();
+
+        engine.process(&event);
+        engine.process(&event); // Duplicate is not routed again
+
+        assert_eq!(*events.borrow(), vec![(other_id, event)]);
+        assert_eq!(engine.event_count, 2);
+        assert!(engine
+            .register_strategy(other_id, recorder(other_id, &events))
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_external_order_events_routed_to_claiming_strategy() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let events = EventLog::default();
+        engine
+            .register_strategy(
+                strategy_id_ema_cross(),
+                recorder(strategy_id_ema_cross(), &events),
+            )
+            .unwrap();
+        let order = limit_order(StrategyId::external(), "O-EXT-1");
+        let event = submitted(&order);
+        cache
+            .borrow_mut()
+            .add_order(OrderAny::Limit(order), None, None, false)
+            .unwrap();
+
+        engine
+            .register_external_order_claims(strategy_id_ema_cross(), &[audusd_sim_id()])
+            .unwrap();
+        engine.process(&event);
+
+        assert_eq!(*events.borrow(), vec![(strategy_id_ema_cross(), event)]);
+        assert_eq!(
+            engine.get_external_order_claims_instruments(),
+            HashSet::from([audusd_sim_id()])
+        );
+    }
+
+    #[rstest]
+    fn test_external_order_claims_are_exclusive() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let events = EventLog::default();
+        let other_id = StrategyId::from("S-002");
+        engine
+            .register_strategy(
+                strategy_id_ema_cross(),
+                recorder(strategy_id_ema_cross(), &events),
+            )
+            .unwrap();
+        engine
+            .register_strategy(other_id, recorder(other_id, &events))
+            .unwrap();
+        let ethusdt = InstrumentId::from("ETHUSDT.BINANCE");
+
+        engine
+            .register_external_order_claims(strategy_id_ema_cross(), &[audusd_sim_id()])
+            .unwrap();
+        let result = engine.register_external_order_claims(other_id, &[ethusdt, audusd_sim_id()]);
+
+        assert!(result.is_err());
+        assert_eq!(engine.get_external_order_claim(&ethusdt), None);
+
+        engine.deregister_strategy(strategy_id_ema_cross()).unwrap();
+        engine
+            .register_external_order_claims(other_id, &[ethusdt, audusd_sim_id()])
+            .unwrap();
+        assert_eq!(
+            engine.get_external_order_claim(&audusd_sim_id()),
+            Some(other_id)
+        );
+    }
+
+    #[rstest]
+    fn test_early_events_buffered_until_order_known() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let events = EventLog::default();
+        engine
+            .register_strategy(
+                strategy_id_ema_cross(),
+                recorder(strategy_id_ema_cross(), &events),
+            )
+            .unwrap();
+        let order = limit_order(strategy_id_ema_cross(), "O-1");
+        let submitted = submitted(&order);
+        let accepted = OrderEventAny::Accepted(
+            TestOrderEventStubs::order_accepted(&order, account_id(), "V-1".into()).unwrap(),
+        );
+
+        engine.process(&submitted);
+        engine.process(&accepted);
+        assert_eq!(engine.pending_event_count(), 2);
+        assert!(events.borrow().is_empty());
+
+        cache
+            .borrow_mut()
+            .add_order(OrderAny::Limit(order), None, None, false)
+            .unwrap();
+        engine.flush_pending_events();
+
+        assert_eq!(engine.pending_event_count(), 0);
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                (strategy_id_ema_cross(), submitted),
+                (strategy_id_ema_cross(), accepted)
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_early_events_dropped_after_timeout() {
+        let clock = clock();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock, &cache);
+        let events = EventLog::default();
+        engine
+            .register_strategy(
+                strategy_id_ema_cross(),
+                recorder(strategy_id_ema_cross(), &events),
+            )
+            .unwrap();
+        let order = limit_order(strategy_id_ema_cross(), "O-1");
+
+        engine.process(&submitted(&order));
+        clock.set_time(UnixNanos::from(1_000));
+        engine.flush_pending_events();
+        assert_eq!(engine.pending_event_count(), 1); // Not yet beyond the timeout
+
+        clock.set_time(UnixNanos::from(1_001));
+        engine.flush_pending_events();
+        cache
+            .borrow_mut()
+            .add_order(OrderAny::Limit(order), None, None, false)
+            .unwrap();
+        engine.flush_pending_events();
+
+        assert_eq!(engine.pending_event_count(), 0);
+        assert!(events.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_expire_day_orders_expires_only_day_orders_at_session_close() {
+        let clock = clock();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock, &cache);
+        let events = EventLog::default();
+        engine
+            .register_strategy(
+                strategy_id_ema_cross(),
+                recorder(strategy_id_ema_cross(), &events),
+            )
+            .unwrap();
+
+        let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+        let all_week = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        engine.register_trading_calendar(
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            daily_calendar(midnight, midnight, &all_week),
+        );
+        engine.register_trading_calendar(
+            InstrumentId::from("AAPL.XNAS"),
+            daily_calendar(
+                NaiveTime::from_hms_opt(14, 30, 0).unwrap(),
+                NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+                &all_week[..5],
+            ),
+        );
+
+        accepted_order(&cache, "O-DAY-FX", "AUD/USD.SIM", TimeInForce::Day);
+        accepted_order(&cache, "O-DAY-EQ", "AAPL.XNAS", TimeInForce::Day);
+        accepted_order(&cache, "O-DAY-CRYPTO", "ETHUSDT.BINANCE", TimeInForce::Day);
+        accepted_order(&cache, "O-GTC", "AUD/USD.SIM", TimeInForce::Gtc);
+        accepted_order(&cache, "O-GTD", "AAPL.XNAS", TimeInForce::Gtd);
+        let pending = accepted_order(&cache, "O-DAY-PENDING", "AUD/USD.SIM", TimeInForce::Day);
+        cache
+            .borrow_mut()
+            .apply_order_event(OrderEventAny::PendingCancel(
+                OrderPendingCancel::new(
+                    pending.trader_id,
+                    pending.strategy_id,
+                    pending.instrument_id,
+                    pending.client_order_id,
+                    account_id(),
+                    UUID4::new(),
+                    UnixNanos::default(),
+                    UnixNanos::default(),
+                    false,
+                    pending.venue_order_id,
+                )
+                .unwrap(),
+            ))
+            .unwrap();
+
+        // Tuesday 2024-01-02 21:00 UTC, the equities session close
+        let session_close_ts = UnixNanos::from(
+            Utc.with_ymd_and_hms(2024, 1, 2, 21, 0, 0)
+                .unwrap()
+                .timestamp_nanos_opt()
+                .unwrap() as u64,
+        );
+        clock.set_time(session_close_ts + 1);
+        assert_eq!(cache.borrow().day_orders_open(None).len(), 4);
+
+        let expired = engine.expire_day_orders(session_close_ts);
+
+        assert_eq!(
+            expired,
+            vec![
+                ClientOrderId::from("O-DAY-EQ"),
+                ClientOrderId::from("O-DAY-FX")
+            ]
+        );
+        assert_eq!(
+            cache.borrow().day_orders_open(None),
+            vec![
+                ClientOrderId::from("O-DAY-CRYPTO"),
+                ClientOrderId::from("O-DAY-PENDING"),
+            ]
+        );
+        let routed = events.borrow();
+        assert_eq!(routed.len(), 2);
+        for (_, event) in routed.iter() {
+            let OrderEventAny::Expired(expired) = event else {
+                panic!("Expected OrderExpired, was {event}");
+            };
+            assert_eq!(expired.ts_event, session_close_ts);
+            assert_eq!(expired.ts_init, session_close_ts + 1);
+        }
+    }
+
+    #[rstest]
+    fn test_correlation_id_follows_submit_through_accept_and_fill() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let commands = CommandLog::default();
+        engine
+            .register_client(StubExecutionClient::boxed("SIM", "SIM", &commands))
+            .unwrap();
+        let order = limit_order(strategy_id_ema_cross(), "O-1");
+        let other = limit_order(strategy_id_ema_cross(), "O-2");
+        for order in [&order, &other] {
+            cache
+                .borrow_mut()
+                .add_order(OrderAny::Limit(order.clone()), None, None, false)
+                .unwrap();
+        }
+        let submit = |client_order_id: &str, correlation_id: Option<UUID4>| {
+            TradingCommand::SubmitOrder(
+                SubmitOrderBuilder::default()
+                    .strategy_id(strategy_id_ema_cross())
+                    .instrument_id(audusd_sim_id())
+                    .client_order_id(ClientOrderId::from(client_order_id))
+                    .correlation_id(correlation_id)
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let accepted = |order: &LimitOrder, venue_order_id: &str| {
+            OrderEventAny::Accepted(
+                TestOrderEventStubs::order_accepted(order, account_id(), venue_order_id.into())
+                    .unwrap(),
+            )
+        };
+
+        let correlation_id = UUID4::new();
+        engine.execute(submit("O-1", Some(correlation_id)));
+        engine.execute(submit("O-2", None));
+
+        // Events from the venue arrive uncorrelated and interleaved across both orders
+        engine.process(&submitted(&order));
+        engine.process(&submitted(&other));
+        engine.process(&accepted(&other, "V-2"));
+        engine.process(&accepted(&order, "V-1"));
+        let OrderAny::Limit(working) = cache
+            .borrow()
+            .order(&order.client_order_id)
+            .cloned()
+            .unwrap()
+        else {
+            panic!("Expected limit order");
+        };
+        let filled = OrderEventAny::Filled(
+            TestOrderEventStubs::order_filled(
+                &working,
+                &audusd_sim(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap(),
+        );
+        engine.process(&filled);
+
+        let cache = cache.borrow();
+        let correlated: Vec<&str> = cache
+            .events_for_correlation(&correlation_id)
+            .iter()
+            .map(|event| {
+                assert_eq!(event.client_order_id(), order.client_order_id);
+                match event.as_ref() {
+                    OrderEventAny::Submitted(_) => "Submitted",
+                    OrderEventAny::Accepted(_) => "Accepted",
+                    _ => "Other",
+                }
+            })
+            .collect();
+        assert_eq!(correlated, vec!["Submitted", "Accepted"]);
+        assert!(cache.is_order_closed(&order.client_order_id));
+        assert!(cache
+            .order_events(&other.client_order_id)
+            .iter()
+            .all(|event| event.correlation_id().is_none()));
+        assert_eq!(commands.borrow().len(), 2);
+    }
+
+    #[rstest]
+    fn test_reconciliation_events_are_not_correlated() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        engine
+            .register_client(StubExecutionClient::boxed(
+                "SIM",
+                "SIM",
+                &CommandLog::default(),
+            ))
+            .unwrap();
+        let order = limit_order(strategy_id_ema_cross(), "O-1");
+        cache
+            .borrow_mut()
+            .add_order(OrderAny::Limit(order.clone()), None, None, false)
+            .unwrap();
+        let correlation_id = UUID4::new();
+        engine.execute(TradingCommand::SubmitOrder(
+            SubmitOrderBuilder::default()
+                .instrument_id(audusd_sim_id())
+                .client_order_id(order.client_order_id)
+                .correlation_id(Some(correlation_id))
+                .build()
+                .unwrap(),
+        ));
+
+        let mut accepted =
+            TestOrderEventStubs::order_accepted(&order, account_id(), "V-1".into()).unwrap();
+        accepted.reconciliation = u8::from(true);
+        engine.process(&submitted(&order));
+        engine.process(&OrderEventAny::Accepted(accepted));
+
+        let events = cache.borrow().events_for_correlation(&correlation_id);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0].as_ref(), OrderEventAny::Submitted(_)));
+    }
+
+    #[rstest]
+    fn test_reconcile_order_fill_processes_inferred_fills() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut engine = engine(clock(), &cache);
+        let events = EventLog::default();
+        engine
+            .register_strategy(
+                strategy_id_ema_cross(),
+                recorder(strategy_id_ema_cross(), &events),
+            )
+            .unwrap();
+        {
+            let mut cache = cache.borrow_mut();
+            cache
+                .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+                .unwrap();
+            cache
+                .add_quote(
+                    QuoteTick::new(
+                        audusd_sim_id(),
+                        Price::from("1.00000"),
+                        Price::from("1.00010"),
+                        Quantity::from(1_000_000),
+                        Quantity::from(1_000_000),
+                        UnixNanos::default(),
+                        UnixNanos::default(),
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+        }
+        let order = accepted_order(&cache, "O-1", "AUD/USD.SIM", TimeInForce::Gtc);
+        let venue_order_id = VenueOrderId::from("V-O-1");
+        let mut reconcile = |filled_qty: i64| {
+            engine
+                .reconcile_order_fill(
+                    &order.client_order_id,
+                    venue_order_id,
+                    account_id(),
+                    Quantity::from(filled_qty),
+                    1.0,
+                    UnixNanos::from(1),
+                )
+                .unwrap()
+        };
+
+        let partial = reconcile(40_000).unwrap();
+        let unchanged = reconcile(40_000);
+        let rest = reconcile(100_000).unwrap();
+
+        assert_eq!(partial.last_qty, Quantity::from(40_000));
+        assert_eq!(partial.liquidity_side, LiquiditySide::Maker);
+        assert!(unchanged.is_none());
+        assert_eq!(rest.last_qty, Quantity::from(60_000));
+        assert_eq!(engine.report_count, 2);
+        assert!(cache.borrow().is_order_closed(&order.client_order_id));
+        let events = events.borrow();
+        assert!(matches!(events[0].1, OrderEventAny::PartiallyFilled(_)));
+        assert!(matches!(events[1].1, OrderEventAny::Filled(_)));
     }
 }
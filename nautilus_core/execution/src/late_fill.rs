@@ -0,0 +1,277 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a guard for late fills, such as those replayed by a venue after a reconnect.
+
+use log::warn;
+use nautilus_common::cache::Cache;
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{events::order::filled::OrderFilled, polymorphism::GetTradeIds};
+
+/// The policy for handling fills older than the staleness threshold.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LateFillPolicy {
+    /// Apply late fills as normal.
+    #[default]
+    Apply,
+    /// Apply late fills, logging a warning.
+    ApplyAndWarn,
+    /// Hold late fills in the cache quarantine until manually released.
+    Quarantine,
+}
+
+/// The outcome of checking a fill with a `LateFillGuard`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LateFillAction {
+    /// The fill should be applied to order, position and account state.
+    Apply,
+    /// The fill was quarantined in the cache and must not be applied.
+    Quarantine,
+    /// The fill has a trade ID already applied or quarantined, and must be dropped.
+    Duplicate,
+}
+
+/// Flags `OrderFilled` events with a `ts_event` older than `now - threshold_ns` as late.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LateFillGuard {
+    pub threshold_ns: u64,
+    pub policy: LateFillPolicy,
+}
+
+impl LateFillGuard {
+    #[must_use]
+    pub fn new(threshold_ns: u64, policy: LateFillPolicy) -> Self {
+        Self {
+            threshold_ns,
+            policy,
+        }
+    }
+
+    #[must_use]
+    pub fn is_late(&self, fill: &OrderFilled, now: UnixNanos) -> bool {
+        fill.ts_event < now.as_u64().saturating_sub(self.threshold_ns)
+    }
+
+    /// Checks the given `fill` at time `now`, quarantining it in the `cache` if late and the
+    /// policy requires.
+    ///
+    /// Fills with a trade ID already applied to the order (or already quarantined) are
+    /// reported as duplicates regardless of their age.
+    pub fn check(
+        &self,
+        fill: &OrderFilled,
+        now: UnixNanos,
+        cache: &mut Cache,
+    ) -> anyhow::Result<LateFillAction> {
+        let is_applied = cache
+            .order(&fill.client_order_id)
+            .is_some_and(|order| order.trade_ids().contains(&fill.trade_id));
+        if is_applied || cache.quarantined_fill(&fill.trade_id).is_some() {
+            warn!("Duplicate {fill}");
+            return Ok(LateFillAction::Duplicate);
+        }
+
+        if !self.is_late(fill, now) {
+            return Ok(LateFillAction::Apply);
+        }
+
+        match self.policy {
+            LateFillPolicy::Apply => Ok(LateFillAction::Apply),
+            LateFillPolicy::ApplyAndWarn => {
+                warn!(
+                    "Late fill ({}ns old): {fill}",
+                    now.as_u64().saturating_sub(fill.ts_event.as_u64())
+                );
+                Ok(LateFillAction::Apply)
+            }
+            LateFillPolicy::Quarantine => {
+                cache.quarantine_fill(*fill)?;
+                Ok(LateFillAction::Quarantine)
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderSide,
+        events::order::event::OrderEventAny,
+        identifiers::{
+            account_id::AccountId, client_order_id::ClientOrderId, position_id::PositionId,
+            trade_id::TradeId,
+        },
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::{
+            any::OrderAny,
+            base::Order,
+            market::MarketOrder,
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+        },
+        position::Position,
+        types::{money::Money, price::Price, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const THRESHOLD_NS: u64 = 60_000_000_000; // 1 minute
+    const NOW_NS: u64 = 3_600_000_000_000; // 1 hour
+
+    #[fixture]
+    fn cache() -> Cache {
+        Cache::default()
+    }
+
+    fn market_order(side: OrderSide, client_order_id: &str) -> MarketOrder {
+        TestOrderStubs::market_order(
+            audusd_sim().id,
+            side,
+            Quantity::from(100_000),
+            Some(ClientOrderId::from(client_order_id)),
+            None,
+        )
+    }
+
+    fn fill(order: &MarketOrder, trade_id: &str, last_px: &str, ts_event: u64) -> OrderFilled {
+        TestOrderEventStubs::order_filled(
+            order,
+            &audusd_sim(),
+            None,
+            Some(TradeId::from(trade_id)),
+            Some(PositionId::from("P-1")),
+            Some(Price::from(last_px)),
+            None,
+            None,
+            Some(UnixNanos::from(ts_event)),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case(NOW_NS, false)]
+    #[case(NOW_NS - THRESHOLD_NS, false)]
+    #[case(NOW_NS - THRESHOLD_NS - 1, true)]
+    fn test_is_late(#[case] ts_event: u64, #[case] expected: bool) {
+        let guard = LateFillGuard::new(THRESHOLD_NS, LateFillPolicy::Quarantine);
+        let order = market_order(OrderSide::Buy, "O-1");
+        let fill = fill(&order, "1", "1.00000", ts_event);
+
+        assert_eq!(guard.is_late(&fill, UnixNanos::from(NOW_NS)), expected);
+    }
+
+    #[rstest]
+    #[case(LateFillPolicy::Apply, LateFillAction::Apply)]
+    #[case(LateFillPolicy::ApplyAndWarn, LateFillAction::Apply)]
+    #[case(LateFillPolicy::Quarantine, LateFillAction::Quarantine)]
+    fn test_check_late_fill_by_policy(
+        mut cache: Cache,
+        #[case] policy: LateFillPolicy,
+        #[case] expected: LateFillAction,
+    ) {
+        let guard = LateFillGuard::new(THRESHOLD_NS, policy);
+        let order = market_order(OrderSide::Buy, "O-1");
+        let fill = fill(&order, "1", "1.00000", 0);
+
+        let action = guard
+            .check(&fill, UnixNanos::from(NOW_NS), &mut cache)
+            .unwrap();
+
+        assert_eq!(action, expected);
+        assert_eq!(
+            cache.quarantined_fills(None).len(),
+            usize::from(expected == LateFillAction::Quarantine)
+        );
+    }
+
+    #[rstest]
+    fn test_quarantine_then_release_preserves_pnl(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let guard = LateFillGuard::new(THRESHOLD_NS, LateFillPolicy::Quarantine);
+        let buy = market_order(OrderSide::Buy, "O-1");
+        let sell = market_order(OrderSide::Sell, "O-2");
+        let open_fill = fill(&buy, "1", "1.00000", 1_000_000_000);
+        let close_fill = fill(&sell, "2", "1.00010", 2_000_000_000);
+
+        // Reference position with both fills applied in sequence
+        let mut expected = Position::new(audusd_sim, open_fill).unwrap();
+        expected.apply(&close_fill);
+
+        let mut position = Position::new(audusd_sim, open_fill).unwrap();
+        let action = guard
+            .check(&close_fill, UnixNanos::from(NOW_NS), &mut cache)
+            .unwrap();
+
+        assert_eq!(action, LateFillAction::Quarantine);
+        assert_eq!(
+            cache.quarantined_fills(Some(&sell.client_order_id)),
+            vec![&close_fill]
+        );
+        assert!(position.is_open());
+        assert_eq!(position.realized_pnl, Some(Money::from("-2.00 USD")));
+
+        let released = cache
+            .release_quarantined_fill(&close_fill.trade_id)
+            .unwrap();
+        position.apply(&released);
+
+        assert!(cache.quarantined_fills(None).is_empty());
+        assert!(position.is_closed());
+        assert_eq!(position.realized_pnl, expected.realized_pnl);
+        assert_eq!(position.realized_pnl, Some(Money::from("6.00 USD")));
+    }
+
+    #[rstest]
+    fn test_check_duplicate_of_applied_trade_id(mut cache: Cache) {
+        let guard = LateFillGuard::new(THRESHOLD_NS, LateFillPolicy::Quarantine);
+        let mut order = market_order(OrderSide::Buy, "O-1");
+        let account_id = AccountId::from("SIM-001");
+        let submitted = TestOrderEventStubs::order_submitted(&order, account_id).unwrap();
+        let accepted =
+            TestOrderEventStubs::order_accepted(&order, account_id, "V-1".into()).unwrap();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        let fill = fill(&order, "1", "1.00000", 0);
+        order.apply(OrderEventAny::Filled(fill)).unwrap();
+        cache
+            .add_order(OrderAny::Market(order), None, None, false)
+            .unwrap();
+
+        let action = guard
+            .check(&fill, UnixNanos::from(NOW_NS), &mut cache)
+            .unwrap();
+
+        assert_eq!(action, LateFillAction::Duplicate);
+        assert!(cache.quarantined_fills(None).is_empty());
+    }
+
+    #[rstest]
+    fn test_check_duplicate_of_quarantined_trade_id(mut cache: Cache) {
+        let guard = LateFillGuard::new(THRESHOLD_NS, LateFillPolicy::Quarantine);
+        let order = market_order(OrderSide::Buy, "O-1");
+        let fill = fill(&order, "1", "1.00000", 0);
+        let now = UnixNanos::from(NOW_NS);
+
+        let first = guard.check(&fill, now, &mut cache).unwrap();
+        let second = guard.check(&fill, now, &mut cache).unwrap();
+
+        assert_eq!(first, LateFillAction::Quarantine);
+        assert_eq!(second, LateFillAction::Duplicate);
+        assert_eq!(cache.quarantined_fills(None).len(), 1);
+    }
+}
@@ -27,7 +27,13 @@
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`
 //! - `python`: Enables Python bindings from `pyo3`
 
+pub mod account_router;
 pub mod client;
 pub mod engine;
+pub mod late_fill;
 pub mod matching_core;
 pub mod messages;
+pub mod metrics;
+pub mod protection;
+pub mod reconciliation;
+pub mod shutdown;
@@ -90,6 +90,14 @@ impl OrderMatchingCore {
         self.orders_ask.as_slice()
     }
 
+    #[must_use]
+    pub fn get_order(&self, client_order_id: ClientOrderId) -> Option<&PassiveOrderAny> {
+        self.orders_bid
+            .iter()
+            .chain(self.orders_ask.iter())
+            .find(|o| o.client_order_id() == client_order_id)
+    }
+
     #[must_use]
     pub fn order_exists(&self, client_order_id: ClientOrderId) -> bool {
         self.orders_bid
@@ -147,6 +155,33 @@ impl OrderMatchingCore {
         }
     }
 
+    /// Replaces the existing order with the given updated `order`.
+    ///
+    /// If `keep_priority` is true the order retains its position in the queue, otherwise it
+    /// is moved to the back of the queue (behind all orders at the same price).
+    pub fn update_order(
+        &mut self,
+        order: PassiveOrderAny,
+        keep_priority: bool,
+    ) -> Result<(), OrderError> {
+        let orders = match order.order_side_specified() {
+            OrderSideSpecified::Buy => &mut self.orders_bid,
+            OrderSideSpecified::Sell => &mut self.orders_ask,
+        };
+        let index = orders
+            .iter()
+            .position(|o| o == &order)
+            .ok_or(OrderError::NotFound(order.client_order_id()))?;
+
+        if keep_priority {
+            orders[index] = order;
+        } else {
+            orders.remove(index);
+            orders.push(order);
+        }
+        Ok(())
+    }
+
     pub fn iterate(&self) {
         self.iterate_bids();
         self.iterate_asks();
@@ -193,17 +228,22 @@ impl OrderMatchingCore {
 
     #[must_use]
     pub fn is_limit_matched(&self, order: &LimitOrderAny) -> bool {
-        match order.order_side_specified() {
-            OrderSideSpecified::Buy => self.ask.map_or(false, |a| a <= order.limit_px()),
-            OrderSideSpecified::Sell => self.bid.map_or(false, |b| b >= order.limit_px()),
+        self.is_limit_price_matched(order.order_side_specified(), order.limit_px())
+    }
+
+    #[must_use]
+    pub fn is_limit_price_matched(&self, side: OrderSideSpecified, price: Price) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a <= price),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b >= price),
         }
     }
 
     #[must_use]
     pub fn is_stop_matched(&self, order: &StopOrderAny) -> bool {
         match order.order_side_specified() {
-            OrderSideSpecified::Buy => self.ask.map_or(false, |a| a >= order.stop_px()),
-            OrderSideSpecified::Sell => self.bid.map_or(false, |b| b <= order.stop_px()),
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a >= order.stop_px()),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b <= order.stop_px()),
         }
     }
 }
@@ -357,6 +397,42 @@ mod tests {
         assert!(matching_core.get_orders_bid().is_empty());
     }
 
+    #[rstest]
+    #[case(true, vec!["O-1", "O-2"])]
+    #[case(false, vec!["O-2", "O-1"])]
+    fn test_update_order_queue_priority(#[case] keep_priority: bool, #[case] expected: Vec<&str>) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+
+        for client_order_id in ["O-1", "O-2"] {
+            let order = TestOrderStubs::limit_order(
+                instrument_id,
+                OrderSide::Buy,
+                Price::from("100.00"),
+                Quantity::from("100"),
+                Some(ClientOrderId::from(client_order_id)),
+                None,
+            );
+            matching_core
+                .add_order(PassiveOrderAny::Limit(LimitOrderAny::Limit(order)))
+                .unwrap();
+        }
+
+        let order = matching_core
+            .get_order(ClientOrderId::from("O-1"))
+            .unwrap()
+            .clone();
+        matching_core.update_order(order, keep_priority).unwrap();
+
+        let client_order_ids: Vec<ClientOrderId> = matching_core
+            .get_orders_bid()
+            .iter()
+            .map(GetClientOrderId::client_order_id)
+            .collect();
+        let expected: Vec<ClientOrderId> = expected.into_iter().map(ClientOrderId::from).collect();
+        assert_eq!(client_order_ids, expected);
+    }
+
     #[rstest]
     #[case(None, None, Price::from("100.00"), OrderSide::Buy, false)]
     #[case(None, None, Price::from("100.00"), OrderSide::Sell, false)]
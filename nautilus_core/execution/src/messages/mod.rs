@@ -15,6 +15,7 @@
 
 //! Defines execution specific messages such as order commands.
 
+use nautilus_core::uuid::UUID4;
 use nautilus_model::identifiers::{client_id::ClientId, instrument_id::InstrumentId};
 use strum::Display;
 
@@ -68,4 +69,14 @@ impl TradingCommand {
             Self::QueryOrder(command) => command.instrument_id,
         }
     }
+
+    /// Returns the ID correlating a submit command with the order events it results in.
+    #[must_use]
+    pub fn correlation_id(&self) -> Option<UUID4> {
+        match self {
+            Self::SubmitOrder(command) => command.correlation_id,
+            Self::SubmitOrderList(command) => command.correlation_id,
+            _ => None,
+        }
+    }
 }
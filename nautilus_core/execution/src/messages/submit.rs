@@ -17,10 +17,13 @@ use std::fmt::Display;
 
 use derive_builder::Builder;
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
-use nautilus_model::identifiers::{
-    client_id::ClientId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
-    instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
-    trader_id::TraderId, venue_order_id::VenueOrderId,
+use nautilus_model::{
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        exec_algorithm_id::ExecAlgorithmId, instrument_id::InstrumentId, position_id::PositionId,
+        strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
+    },
+    types::price::Price,
 };
 use serde::{Deserialize, Serialize};
 
@@ -37,7 +40,14 @@ pub struct SubmitOrder {
     // order: OrderAny,  // TODO: Implement Eq
     pub exec_algorith_id: Option<ExecAlgorithmId>,
     pub position_id: Option<PositionId>,
+    pub protection_price: Option<Price>,
     pub command_id: UUID4,
+    /// The ID correlating the command with the order events it results in.
+    #[serde(default)]
+    pub correlation_id: Option<UUID4>,
+    /// The account to submit the order under, as resolved by the execution engine.
+    #[serde(default)]
+    pub account_id: Option<AccountId>,
     pub ts_init: UnixNanos,
 }
 
@@ -52,7 +62,10 @@ impl SubmitOrder {
         venue_order_id: VenueOrderId,
         exec_algorith_id: Option<ExecAlgorithmId>,
         position_id: Option<PositionId>,
+        protection_price: Option<Price>,
         command_id: UUID4,
+        correlation_id: Option<UUID4>,
+        account_id: Option<AccountId>,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
         Ok(Self {
@@ -64,7 +77,10 @@ impl SubmitOrder {
             venue_order_id,
             exec_algorith_id,
             position_id,
+            protection_price,
             command_id,
+            correlation_id,
+            account_id,
             ts_init,
         })
     }
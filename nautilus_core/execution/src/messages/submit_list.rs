@@ -39,6 +39,9 @@ pub struct SubmitOrderList {
     pub exec_algorith_id: Option<ExecAlgorithmId>,
     pub position_id: Option<PositionId>,
     pub command_id: UUID4,
+    /// The ID correlating the command with the order events it results in.
+    #[serde(default)]
+    pub correlation_id: Option<UUID4>,
     pub ts_init: UnixNanos,
 }
 
@@ -55,6 +58,7 @@ impl SubmitOrderList {
         exec_algorith_id: Option<ExecAlgorithmId>,
         position_id: Option<PositionId>,
         command_id: UUID4,
+        correlation_id: Option<UUID4>,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
         Ok(Self {
@@ -68,6 +72,7 @@ impl SubmitOrderList {
             exec_algorith_id,
             position_id,
             command_id,
+            correlation_id,
             ts_init,
         })
     }
@@ -0,0 +1,565 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides order rate and fill metrics collected from the order event stream.
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    fmt::{Display, Write},
+};
+
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
+use nautilus_model::{
+    events::order::event::OrderEventAny,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, strategy_id::StrategyId,
+    },
+    types::quantity::QuantityAccumulator,
+};
+use serde::{Deserialize, Serialize};
+
+/// The order metrics for a single strategy, as at the time of a snapshot.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StrategyMetrics {
+    pub submitted: u64,
+    pub accepted: u64,
+    pub rejected: u64,
+    pub canceled: u64,
+    /// The count of fill events (including partial fills).
+    pub filled: u64,
+    /// The ratio of rejected to acknowledged (accepted or rejected) orders within the window.
+    pub reject_rate: f64,
+    /// The average nanoseconds between `OrderSubmitted` and `OrderAccepted` event timestamps.
+    pub avg_ack_latency_ns: Option<f64>,
+}
+
+/// The fill metrics for a single instrument, as at the time of a snapshot.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InstrumentMetrics {
+    pub filled_qty: f64,
+    /// The sum of `last_qty * last_px` for fills, in the fill currency (not multiplier adjusted).
+    pub filled_notional: f64,
+}
+
+/// A point in time snapshot of `ExecutionMetrics`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionMetricsSnapshot {
+    pub ts_snapshot: UnixNanos,
+    pub window_ns: u64,
+    pub strategies: BTreeMap<StrategyId, StrategyMetrics>,
+    pub instruments: BTreeMap<InstrumentId, InstrumentMetrics>,
+}
+
+impl ExecutionMetricsSnapshot {
+    /// Returns the snapshot formatted in the Prometheus text exposition format.
+    #[must_use]
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let strategies = &self.strategies;
+        let instruments = &self.instruments;
+        let reject_rate_help = format!(
+            "Ratio of rejected to acknowledged orders over the last {}ns.",
+            self.window_ns
+        );
+
+        #[rustfmt::skip]
+        {
+            write_metric(&mut out, "nautilus_orders_submitted_total", "Total submitted orders.", "counter", "strategy_id", strategies, |m| Some(m.submitted));
+            write_metric(&mut out, "nautilus_orders_accepted_total", "Total accepted orders.", "counter", "strategy_id", strategies, |m| Some(m.accepted));
+            write_metric(&mut out, "nautilus_orders_rejected_total", "Total rejected orders.", "counter", "strategy_id", strategies, |m| Some(m.rejected));
+            write_metric(&mut out, "nautilus_orders_canceled_total", "Total canceled orders.", "counter", "strategy_id", strategies, |m| Some(m.canceled));
+            write_metric(&mut out, "nautilus_orders_filled_total", "Total order fill events.", "counter", "strategy_id", strategies, |m| Some(m.filled));
+            write_metric(&mut out, "nautilus_orders_reject_rate", &reject_rate_help, "gauge", "strategy_id", strategies, |m| Some(m.reject_rate));
+            write_metric(&mut out, "nautilus_orders_ack_latency_avg_ns", "Average nanoseconds from order submitted to accepted.", "gauge", "strategy_id", strategies, |m| m.avg_ack_latency_ns);
+            write_metric(&mut out, "nautilus_filled_quantity_total", "Total filled quantity.", "counter", "instrument_id", instruments, |m| Some(m.filled_qty));
+            write_metric(&mut out, "nautilus_filled_notional_total", "Total filled notional value.", "counter", "instrument_id", instruments, |m| Some(m.filled_notional));
+        };
+
+        out
+    }
+}
+
+/// Writes a metric family, skipping samples with no value.
+#[allow(clippy::too_many_arguments)]
+fn write_metric<K: Display, M, V: Display>(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    metric_type: &str,
+    label: &str,
+    samples: &BTreeMap<K, M>,
+    value: impl Fn(&M) -> Option<V>,
+) {
+    writeln!(out, "# HELP {name} {help}").unwrap();
+    writeln!(out, "# TYPE {name} {metric_type}").unwrap();
+    for (key, metrics) in samples {
+        if let Some(value) = value(metrics) {
+            writeln!(out, "{name}{{{label}=\"{key}\"}} {value}").unwrap();
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct StrategyState {
+    metrics: StrategyMetrics,
+    /// The clock time of each acknowledgement, and whether it was a rejection.
+    acks: VecDeque<(UnixNanos, bool)>,
+    ack_latency_total_ns: u128,
+    ack_latency_count: u64,
+}
+
+#[derive(Debug, Default)]
+struct InstrumentState {
+    filled_qty: QuantityAccumulator,
+    filled_notional: f64,
+}
+
+/// Collects per strategy order counts and per instrument fill totals from order events.
+///
+/// Reject rates are calculated over a sliding window of `window_ns` ending at the current
+/// clock time, with acknowledgements timestamped by the clock as they are handled.
+pub struct ExecutionMetrics {
+    clock: &'static AtomicTime,
+    window_ns: u64,
+    strategies: HashMap<StrategyId, StrategyState>,
+    instruments: HashMap<InstrumentId, InstrumentState>,
+    submitted_ts: HashMap<ClientOrderId, UnixNanos>,
+}
+
+impl ExecutionMetrics {
+    #[must_use]
+    pub fn new(clock: &'static AtomicTime, window_ns: u64) -> Self {
+        Self {
+            clock,
+            window_ns,
+            strategies: HashMap::new(),
+            instruments: HashMap::new(),
+            submitted_ts: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn window_ns(&self) -> u64 {
+        self.window_ns
+    }
+
+    /// Updates the metrics from the given order `event`.
+    pub fn handle_event(&mut self, event: &OrderEventAny) {
+        let ts_now = self.clock.get_time_ns();
+        let window_ns = self.window_ns;
+        let state = self.strategies.entry(event.strategy_id()).or_default();
+
+        match event {
+            OrderEventAny::Submitted(submitted) => {
+                state.metrics.submitted += 1;
+                self.submitted_ts
+                    .insert(submitted.client_order_id, submitted.ts_event);
+            }
+            OrderEventAny::Accepted(accepted) => {
+                state.metrics.accepted += 1;
+                state.acks.push_back((ts_now, false));
+                if let Some(ts_submitted) = self.submitted_ts.remove(&accepted.client_order_id) {
+                    let latency = accepted
+                        .ts_event
+                        .as_u64()
+                        .saturating_sub(ts_submitted.as_u64());
+                    state.ack_latency_total_ns += u128::from(latency);
+                    state.ack_latency_count += 1;
+                }
+            }
+            OrderEventAny::Rejected(rejected) => {
+                state.metrics.rejected += 1;
+                state.acks.push_back((ts_now, true));
+                self.submitted_ts.remove(&rejected.client_order_id);
+            }
+            OrderEventAny::Canceled(_) => {
+                state.metrics.canceled += 1;
+            }
+            OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => {
+                state.metrics.filled += 1;
+                let instrument = self.instruments.entry(fill.instrument_id).or_default();
+                instrument.filled_qty.add(&fill.last_qty);
+                instrument.filled_notional += fill.last_qty.as_f64() * fill.last_px.as_f64();
+            }
+            _ => {}
+        }
+
+        while let Some((ts, _)) = state.acks.front() {
+            if is_in_window(*ts, ts_now, window_ns) {
+                break;
+            }
+            state.acks.pop_front();
+        }
+    }
+
+    /// Returns a snapshot of the current metrics, with reject rates as at the current clock time.
+    #[must_use]
+    pub fn snapshot(&self) -> ExecutionMetricsSnapshot {
+        let ts_now = self.clock.get_time_ns();
+
+        let strategies = self
+            .strategies
+            .iter()
+            .map(|(strategy_id, state)| {
+                let (acks, rejects) = state
+                    .acks
+                    .iter()
+                    .filter(|(ts, _)| is_in_window(*ts, ts_now, self.window_ns))
+                    .fold((0_u64, 0_u64), |(acks, rejects), (_, is_reject)| {
+                        (acks + 1, rejects + u64::from(*is_reject))
+                    });
+                let reject_rate = if acks == 0 {
+                    0.0
+                } else {
+                    rejects as f64 / acks as f64
+                };
+                let avg_ack_latency_ns = (state.ack_latency_count > 0)
+                    .then(|| state.ack_latency_total_ns as f64 / state.ack_latency_count as f64);
+
+                let metrics = StrategyMetrics {
+                    reject_rate,
+                    avg_ack_latency_ns,
+                    ..state.metrics.clone()
+                };
+                (*strategy_id, metrics)
+            })
+            .collect();
+
+        let instruments = self
+            .instruments
+            .iter()
+            .map(|(instrument_id, state)| {
+                let metrics = InstrumentMetrics {
+                    filled_qty: state.filled_qty.total_f64(),
+                    filled_notional: state.filled_notional,
+                };
+                (*instrument_id, metrics)
+            })
+            .collect();
+
+        ExecutionMetricsSnapshot {
+            ts_snapshot: ts_now,
+            window_ns: self.window_ns,
+            strategies,
+            instruments,
+        }
+    }
+
+    /// Resets all metrics to their initial state.
+    pub fn reset(&mut self) {
+        self.strategies.clear();
+        self.instruments.clear();
+        self.submitted_ts.clear();
+    }
+}
+
+/// Returns whether `ts` falls within the window of `window_ns` ending at (and including) `ts_now`.
+fn is_in_window(ts: UnixNanos, ts_now: UnixNanos, window_ns: u64) -> bool {
+    ts_now.as_u64().saturating_sub(ts.as_u64()) < window_ns
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        enums::{LiquiditySide, OrderSide, OrderType},
+        events::order::{
+            accepted::OrderAccepted, canceled::OrderCanceled, filled::OrderFilled,
+            rejected::OrderRejected, submitted::OrderSubmitted,
+        },
+        identifiers::{
+            account_id::AccountId, trade_id::TradeId, trader_id::TraderId,
+            venue_order_id::VenueOrderId,
+        },
+        types::{currency::Currency, price::Price, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::*;
+
+    const WINDOW_NS: u64 = 1_000_000_000;
+
+    #[fixture]
+    fn clock() -> &'static AtomicTime {
+        Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())))
+    }
+
+    fn strategy_id() -> StrategyId {
+        StrategyId::from("S-001")
+    }
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AUD/USD.SIM")
+    }
+
+    fn submitted(client_order_id: &str, ts_event: u64) -> OrderEventAny {
+        OrderEventAny::Submitted(
+            OrderSubmitted::new(
+                TraderId::from("TRADER-001"),
+                strategy_id(),
+                instrument_id(),
+                ClientOrderId::from(client_order_id),
+                AccountId::from("SIM-001"),
+                UUID4::new(),
+                ts_event.into(),
+                ts_event.into(),
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn accepted(client_order_id: &str, ts_event: u64) -> OrderEventAny {
+        OrderEventAny::Accepted(
+            OrderAccepted::new(
+                TraderId::from("TRADER-001"),
+                strategy_id(),
+                instrument_id(),
+                ClientOrderId::from(client_order_id),
+                VenueOrderId::from("V-1"),
+                AccountId::from("SIM-001"),
+                UUID4::new(),
+                ts_event.into(),
+                ts_event.into(),
+                false,
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn rejected(client_order_id: &str, ts_event: u64) -> OrderEventAny {
+        OrderEventAny::Rejected(
+            OrderRejected::new(
+                TraderId::from("TRADER-001"),
+                strategy_id(),
+                instrument_id(),
+                ClientOrderId::from(client_order_id),
+                AccountId::from("SIM-001"),
+                Ustr::from("INSUFFICIENT_MARGIN"),
+                UUID4::new(),
+                ts_event.into(),
+                ts_event.into(),
+                false,
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn canceled(client_order_id: &str) -> OrderEventAny {
+        OrderEventAny::Canceled(
+            OrderCanceled::new(
+                TraderId::from("TRADER-001"),
+                strategy_id(),
+                instrument_id(),
+                ClientOrderId::from(client_order_id),
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                false,
+                None,
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn filled(
+        client_order_id: &str,
+        trade_id: &str,
+        last_qty: &str,
+        last_px: &str,
+    ) -> OrderEventAny {
+        OrderEventAny::Filled(
+            OrderFilled::new(
+                TraderId::from("TRADER-001"),
+                strategy_id(),
+                instrument_id(),
+                ClientOrderId::from(client_order_id),
+                VenueOrderId::from("V-1"),
+                AccountId::from("SIM-001"),
+                TradeId::from(trade_id),
+                OrderSide::Buy,
+                OrderType::Market,
+                Quantity::from(last_qty),
+                Price::from(last_px),
+                Currency::USD(),
+                LiquiditySide::Taker,
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                false,
+                None,
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partially_filled(
+        client_order_id: &str,
+        trade_id: &str,
+        last_qty: &str,
+        last_px: &str,
+    ) -> OrderEventAny {
+        let OrderEventAny::Filled(fill) = filled(client_order_id, trade_id, last_qty, last_px)
+        else {
+            unreachable!()
+        };
+        OrderEventAny::PartiallyFilled(fill)
+    }
+
+    #[rstest]
+    fn test_snapshot_when_empty(clock: &'static AtomicTime) {
+        let metrics = ExecutionMetrics::new(clock, WINDOW_NS);
+
+        let snapshot = metrics.snapshot();
+
+        assert!(snapshot.strategies.is_empty());
+        assert!(snapshot.instruments.is_empty());
+        assert_eq!(snapshot.to_prometheus().lines().count(), 18); // Headers only
+    }
+
+    #[rstest]
+    fn test_counts_latency_and_fill_totals(clock: &'static AtomicTime) {
+        let mut metrics = ExecutionMetrics::new(clock, WINDOW_NS);
+        let events = [
+            submitted("O-1", 100),
+            accepted("O-1", 400),
+            submitted("O-2", 1_000),
+            accepted("O-2", 1_200),
+            submitted("O-3", 2_000),
+            rejected("O-3", 2_100),
+            filled("O-1", "E-1", "100", "1.50"),
+            filled("O-1", "E-2", "50", "1.60"),
+            canceled("O-2"),
+        ];
+        for event in &events {
+            metrics.handle_event(event);
+        }
+
+        let snapshot = metrics.snapshot();
+        let strategy = &snapshot.strategies[&strategy_id()];
+        let instrument = &snapshot.instruments[&instrument_id()];
+
+        assert_eq!(strategy.submitted, 3);
+        assert_eq!(strategy.accepted, 2);
+        assert_eq!(strategy.rejected, 1);
+        assert_eq!(strategy.canceled, 1);
+        assert_eq!(strategy.filled, 2);
+        assert_eq!(strategy.avg_ack_latency_ns, Some(250.0));
+        assert_eq!(instrument.filled_qty, 150.0);
+        assert_eq!(instrument.filled_notional, 230.0);
+    }
+
+    #[rstest]
+    fn test_partial_then_final_fill_totals(clock: &'static AtomicTime) {
+        let mut metrics = ExecutionMetrics::new(clock, WINDOW_NS);
+        let events = [
+            submitted("O-1", 100),
+            accepted("O-1", 200),
+            partially_filled("O-1", "E-1", "60", "1.50"),
+            filled("O-1", "E-2", "40", "2.00"),
+        ];
+        for event in &events {
+            metrics.handle_event(event);
+        }
+
+        let snapshot = metrics.snapshot();
+        let strategy = &snapshot.strategies[&strategy_id()];
+        let instrument = &snapshot.instruments[&instrument_id()];
+
+        assert_eq!(strategy.filled, 2);
+        assert_eq!(instrument.filled_qty, 100.0);
+        assert_eq!(instrument.filled_notional, 170.0);
+    }
+
+    #[rstest]
+    fn test_reject_rate_window_boundaries(clock: &'static AtomicTime) {
+        let mut metrics = ExecutionMetrics::new(clock, WINDOW_NS);
+
+        clock.set_time(0.into());
+        metrics.handle_event(&rejected("O-1", 0));
+        clock.set_time(500_000_000.into());
+        metrics.handle_event(&accepted("O-2", 0));
+
+        assert_eq!(
+            metrics.snapshot().strategies[&strategy_id()].reject_rate,
+            0.5
+        );
+
+        // Rejection is still within the window on its last nanosecond
+        clock.set_time((WINDOW_NS - 1).into());
+        assert_eq!(
+            metrics.snapshot().strategies[&strategy_id()].reject_rate,
+            0.5
+        );
+
+        // Rejection has exactly aged out of the window
+        clock.set_time(WINDOW_NS.into());
+        assert_eq!(
+            metrics.snapshot().strategies[&strategy_id()].reject_rate,
+            0.0
+        );
+
+        // Acceptance ages out, leaving no acknowledgements in the window
+        clock.set_time((500_000_000 + WINDOW_NS).into());
+        metrics.handle_event(&rejected("O-3", 0));
+        let strategy = &metrics.snapshot().strategies[&strategy_id()];
+
+        assert_eq!(strategy.reject_rate, 1.0);
+        assert_eq!(strategy.rejected, 2);
+        assert_eq!(strategy.accepted, 1);
+    }
+
+    #[rstest]
+    fn test_snapshot_serde_round_trip(clock: &'static AtomicTime) {
+        let mut metrics = ExecutionMetrics::new(clock, WINDOW_NS);
+        metrics.handle_event(&submitted("O-1", 0));
+        metrics.handle_event(&filled("O-1", "E-1", "100", "1.50"));
+        let snapshot = metrics.snapshot();
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let deserialized: ExecutionMetricsSnapshot = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, snapshot);
+    }
+
+    #[rstest]
+    fn test_to_prometheus(clock: &'static AtomicTime) {
+        let mut metrics = ExecutionMetrics::new(clock, WINDOW_NS);
+        metrics.handle_event(&submitted("O-1", 100));
+        metrics.handle_event(&accepted("O-1", 400));
+        metrics.handle_event(&filled("O-1", "E-1", "100", "1.50"));
+
+        let text = metrics.snapshot().to_prometheus();
+
+        assert!(text.contains("# TYPE nautilus_orders_submitted_total counter\n"));
+        assert!(text.contains("nautilus_orders_submitted_total{strategy_id=\"S-001\"} 1\n"));
+        assert!(text.contains("nautilus_orders_reject_rate{strategy_id=\"S-001\"} 0\n"));
+        assert!(text.contains("nautilus_orders_ack_latency_avg_ns{strategy_id=\"S-001\"} 300\n"));
+        assert!(
+            text.contains("nautilus_filled_quantity_total{instrument_id=\"AUD/USD.SIM\"} 100\n")
+        );
+        assert!(
+            text.contains("nautilus_filled_notional_total{instrument_id=\"AUD/USD.SIM\"} 150\n")
+        );
+    }
+}
@@ -0,0 +1,175 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides market order protection prices for venues which require a limit cap.
+
+use nautilus_model::{
+    enums::{OrderSide, OrderType},
+    orderbook::{analysis::derive_protection_price, book::OrderBook},
+    tick_scheme::TickScheme,
+    types::quantity::Quantity,
+};
+
+use crate::messages::submit::SubmitOrder;
+
+/// Represents the market order protection requirements of a venue (as declared by its adapter).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MarketOrderProtection {
+    /// If market orders must carry a protection (limit cap) price.
+    pub requires_protection_price: bool,
+    /// The maximum slippage allowed from the expected fill price (basis points).
+    pub max_slippage_bps: u32,
+}
+
+impl MarketOrderProtection {
+    #[must_use]
+    pub fn new(requires_protection_price: bool, max_slippage_bps: u32) -> Self {
+        Self {
+            requires_protection_price,
+            max_slippage_bps,
+        }
+    }
+
+    /// Attaches a protection price to the `command` if the venue requires one for the order.
+    ///
+    /// An existing protection price is never overwritten, and no price is attached when the
+    /// opposite side of the `book` is empty.
+    pub fn attach(
+        &self,
+        command: &mut SubmitOrder,
+        order_type: OrderType,
+        order_side: OrderSide,
+        quantity: Quantity,
+        book: &OrderBook,
+        tick_scheme: &dyn TickScheme,
+    ) {
+        if !self.requires_protection_price
+            || order_type != OrderType::Market
+            || command.protection_price.is_some()
+        {
+            return;
+        }
+
+        command.protection_price = derive_protection_price(
+            order_side,
+            quantity,
+            book,
+            self.max_slippage_bps,
+            tick_scheme,
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::order::BookOrder, enums::BookType, identifiers::instrument_id::InstrumentId,
+        tick_scheme::FixedTickScheme, types::price::Price,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn tick_scheme() -> FixedTickScheme {
+        FixedTickScheme::new(
+            Price::from("0.01"),
+            Price::from("0.01"),
+            Price::from("10000.00"),
+        )
+        .unwrap()
+    }
+
+    #[fixture]
+    fn book() -> OrderBook {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.00"),
+            Quantity::from("10.0"),
+            0,
+        );
+        book.add(ask, 0, 1, 1.into());
+        book
+    }
+
+    #[rstest]
+    fn test_attach_when_required(book: OrderBook, tick_scheme: FixedTickScheme) {
+        let protection = MarketOrderProtection::new(true, 100);
+        let mut command = SubmitOrder::default();
+
+        protection.attach(
+            &mut command,
+            OrderType::Market,
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            &book,
+            &tick_scheme,
+        );
+
+        assert_eq!(command.protection_price, Some(Price::from("101.00")));
+    }
+
+    #[rstest]
+    #[case(false, OrderType::Market)]
+    #[case(true, OrderType::Limit)]
+    fn test_attach_when_not_applicable(
+        book: OrderBook,
+        tick_scheme: FixedTickScheme,
+        #[case] requires_protection_price: bool,
+        #[case] order_type: OrderType,
+    ) {
+        let protection = MarketOrderProtection::new(requires_protection_price, 100);
+        let mut command = SubmitOrder::default();
+
+        protection.attach(
+            &mut command,
+            order_type,
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            &book,
+            &tick_scheme,
+        );
+
+        assert_eq!(command.protection_price, None);
+    }
+
+    #[rstest]
+    fn test_attach_does_not_overwrite_existing_price(
+        book: OrderBook,
+        tick_scheme: FixedTickScheme,
+    ) {
+        let protection = MarketOrderProtection::new(true, 100);
+        let mut command = SubmitOrder {
+            protection_price: Some(Price::from("105.00")),
+            ..Default::default()
+        };
+
+        protection.attach(
+            &mut command,
+            OrderType::Market,
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            &book,
+            &tick_scheme,
+        );
+
+        assert_eq!(command.protection_price, Some(Price::from("105.00")));
+    }
+}
@@ -0,0 +1,440 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the generation of inferred fills when reconciling orders with venue reports.
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    data::quote::QuoteTick,
+    enums::{LiquiditySide, OrderSide, OrderType},
+    events::order::filled::OrderFilled,
+    identifiers::{
+        account_id::AccountId, position_id::PositionId, trade_id::TradeId,
+        venue_order_id::VenueOrderId,
+    },
+    instruments::any::InstrumentAny,
+    orders::base::Order,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Infers the liquidity side of a fill for venues which don't report maker/taker.
+///
+/// Market style orders always take liquidity, and post-only orders can only make it. For
+/// other orders the limit `price` is compared with the `fill_px` and the prevailing `quote`:
+///
+/// - A fill better than the limit price can only come from crossing resting liquidity (taker).
+/// - A fill at the limit price is a maker fill if the limit was at or behind the near touch,
+///   and a taker fill if the limit was at or through the far touch.
+///
+/// Returns [`LiquiditySide::NoLiquiditySide`] when the side is ambiguous (e.g. a fill inside
+/// the spread, or at the limit price with no quote), rather than guessing.
+#[must_use]
+pub fn infer_liquidity_side(
+    order_type: OrderType,
+    order_side: OrderSide,
+    price: Option<Price>,
+    is_post_only: bool,
+    fill_px: Price,
+    quote: Option<&QuoteTick>,
+) -> LiquiditySide {
+    match order_type {
+        OrderType::Market
+        | OrderType::StopMarket
+        | OrderType::MarketIfTouched
+        | OrderType::TrailingStopMarket => return LiquiditySide::Taker,
+        _ => {}
+    }
+    if is_post_only {
+        return LiquiditySide::Maker;
+    }
+    let Some(price) = price else {
+        return LiquiditySide::NoLiquiditySide;
+    };
+
+    // Normalize so that a "better" price is always lower, as for a buy
+    let (limit, fill, near, far) = match order_side {
+        OrderSide::Buy => (
+            price.raw,
+            fill_px.raw,
+            quote.map(|q| q.bid_price.raw),
+            quote.map(|q| q.ask_price.raw),
+        ),
+        OrderSide::Sell => (
+            -price.raw,
+            -fill_px.raw,
+            quote.map(|q| -q.ask_price.raw),
+            quote.map(|q| -q.bid_price.raw),
+        ),
+        OrderSide::NoOrderSide => return LiquiditySide::NoLiquiditySide,
+    };
+
+    if fill < limit {
+        return LiquiditySide::Taker;
+    }
+    if fill > limit {
+        // Filled through the limit price, the report is inconsistent with the order
+        return LiquiditySide::NoLiquiditySide;
+    }
+    match (near, far) {
+        (Some(near), _) if limit <= near => LiquiditySide::Maker,
+        (_, Some(far)) if limit >= far => LiquiditySide::Taker,
+        _ => LiquiditySide::NoLiquiditySide,
+    }
+}
+
+/// Returns a fill for the difference between the `filled_qty` and `avg_px` reported by the
+/// venue and the fills already applied to the `order`.
+///
+/// The liquidity side is inferred with [`infer_liquidity_side`] from the `quote` prevailing
+/// at the time of the report, and the commission is calculated by the instrument with the
+/// matching fee (the taker fee when the liquidity side can't be inferred), so it is in the
+/// settlement currency of inverse instruments.
+///
+/// # Errors
+///
+/// This function returns an error if:
+/// - The reported `filled_qty` is not greater than the order filled quantity.
+/// - The fill quantity or price can't be made for the `instrument`.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_inferred_fill<T: Order>(
+    order: &T,
+    instrument: &InstrumentAny,
+    venue_order_id: VenueOrderId,
+    account_id: AccountId,
+    filled_qty: Quantity,
+    avg_px: f64,
+    quote: Option<&QuoteTick>,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderFilled> {
+    if filled_qty <= order.filled_qty() {
+        anyhow::bail!(
+            "Reported filled_qty {filled_qty} is not greater than the filled_qty {} of {}",
+            order.filled_qty(),
+            order.client_order_id()
+        );
+    }
+
+    let last_qty = instrument.make_qty(filled_qty.as_f64() - order.filled_qty().as_f64())?;
+    let last_px = match order.avg_px() {
+        Some(order_avg_px) => {
+            let report_cost = avg_px * filled_qty.as_f64();
+            let filled_cost = order_avg_px * order.filled_qty().as_f64();
+            instrument.make_price((report_cost - filled_cost) / last_qty.as_f64())?
+        }
+        None => instrument.make_price(avg_px)?,
+    };
+
+    let liquidity_side = infer_liquidity_side(
+        order.order_type(),
+        order.side(),
+        order.price(),
+        order.is_post_only(),
+        last_px,
+        quote,
+    );
+    let commission_side = match liquidity_side {
+        LiquiditySide::Maker => LiquiditySide::Maker,
+        _ => LiquiditySide::Taker,
+    };
+    let commission = instrument.calculate_commission(last_qty, last_px, commission_side, None)?;
+
+    Ok(OrderFilled::new(
+        order.trader_id(),
+        order.strategy_id(),
+        instrument.id(),
+        order.client_order_id(),
+        venue_order_id,
+        account_id,
+        TradeId::new(&UUID4::new().to_string())?,
+        order.side(),
+        order.order_type(),
+        last_qty,
+        last_px,
+        instrument.quote_currency(),
+        liquidity_side,
+        UUID4::new(),
+        ts_event,
+        ts_init,
+        true,
+        Some(PositionId::new(&format!("{}-EXTERNAL", instrument.id()))?),
+        Some(commission),
+    )?)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        identifiers::instrument_id::InstrumentId,
+        instruments::{
+            crypto_perpetual::CryptoPerpetual,
+            currency_pair::CurrencyPair,
+            stubs::{audusd_sim, xbtusd_bitmex},
+        },
+        orders::stubs::TestOrderStubs,
+        types::{currency::Currency, money::Money},
+    };
+    use rstest::rstest;
+    use rust_decimal::prelude::ToPrimitive;
+
+    use super::*;
+
+    fn quote(bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    // Market style orders always take
+    #[case(
+        OrderType::Market,
+        OrderSide::Buy,
+        None,
+        false,
+        "1.00010",
+        None,
+        LiquiditySide::Taker
+    )]
+    #[case(
+        OrderType::StopMarket,
+        OrderSide::Sell,
+        None,
+        false,
+        "0.99990",
+        None,
+        LiquiditySide::Taker
+    )]
+    // Post-only orders always make
+    #[case(
+        OrderType::Limit,
+        OrderSide::Buy,
+        Some("1.00000"),
+        true,
+        "1.00000",
+        None,
+        LiquiditySide::Maker
+    )]
+    // Passive fill at the limit price, resting at or behind the near touch
+    #[case(OrderType::Limit, OrderSide::Buy, Some("1.00000"), false, "1.00000", Some(("1.00000", "1.00010")), LiquiditySide::Maker)]
+    #[case(OrderType::Limit, OrderSide::Buy, Some("0.99990"), false, "0.99990", Some(("1.00000", "1.00010")), LiquiditySide::Maker)]
+    #[case(OrderType::Limit, OrderSide::Sell, Some("1.00010"), false, "1.00010", Some(("1.00000", "1.00010")), LiquiditySide::Maker)]
+    // Aggressive cross with price improvement
+    #[case(OrderType::Limit, OrderSide::Buy, Some("1.00020"), false, "1.00010", Some(("1.00000", "1.00010")), LiquiditySide::Taker)]
+    #[case(
+        OrderType::Limit,
+        OrderSide::Sell,
+        Some("0.99990"),
+        false,
+        "1.00000",
+        None,
+        LiquiditySide::Taker
+    )]
+    // Aggressive cross at the limit price, marketable against the far touch
+    #[case(OrderType::StopLimit, OrderSide::Buy, Some("1.00010"), false, "1.00010", Some(("1.00000", "1.00010")), LiquiditySide::Taker)]
+    #[case(OrderType::Limit, OrderSide::Sell, Some("1.00000"), false, "1.00000", Some(("1.00000", "1.00010")), LiquiditySide::Taker)]
+    // Ambiguous: fill at a mid-price limit inside the spread
+    #[case(OrderType::Limit, OrderSide::Buy, Some("1.00005"), false, "1.00005", Some(("1.00000", "1.00010")), LiquiditySide::NoLiquiditySide)]
+    #[case(OrderType::Limit, OrderSide::Sell, Some("1.00005"), false, "1.00005", Some(("1.00000", "1.00010")), LiquiditySide::NoLiquiditySide)]
+    // Ambiguous: fill at the limit price with no quote
+    #[case(
+        OrderType::Limit,
+        OrderSide::Buy,
+        Some("1.00000"),
+        false,
+        "1.00000",
+        None,
+        LiquiditySide::NoLiquiditySide
+    )]
+    // Ambiguous: no limit price, or filled through the limit price
+    #[case(
+        OrderType::MarketToLimit,
+        OrderSide::Buy,
+        None,
+        false,
+        "1.00010",
+        None,
+        LiquiditySide::NoLiquiditySide
+    )]
+    #[case(OrderType::Limit, OrderSide::Buy, Some("1.00000"), false, "1.00010", Some(("1.00000", "1.00010")), LiquiditySide::NoLiquiditySide)]
+    fn test_infer_liquidity_side(
+        #[case] order_type: OrderType,
+        #[case] order_side: OrderSide,
+        #[case] price: Option<&str>,
+        #[case] is_post_only: bool,
+        #[case] fill_px: &str,
+        #[case] quote_prices: Option<(&str, &str)>,
+        #[case] expected: LiquiditySide,
+    ) {
+        let quote = quote_prices.map(|(bid, ask)| quote(bid, ask));
+
+        let liquidity_side = infer_liquidity_side(
+            order_type,
+            order_side,
+            price.map(Price::from),
+            is_post_only,
+            Price::from(fill_px),
+            quote.as_ref(),
+        );
+
+        assert_eq!(liquidity_side, expected);
+    }
+
+    #[rstest]
+    fn test_generate_inferred_fill_passive_limit(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = TestOrderStubs::limit_order(
+            instrument.id(),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+        let quote = quote("1.00000", "1.00010");
+
+        let fill = generate_inferred_fill(
+            &order,
+            &instrument,
+            VenueOrderId::from("V-1"),
+            AccountId::from("SIM-001"),
+            Quantity::from(100_000),
+            1.0,
+            Some(&quote),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        )
+        .unwrap();
+
+        assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+        assert_eq!(fill.last_qty, Quantity::from(100_000));
+        assert_eq!(fill.last_px, Price::from("1.00000"));
+        assert_eq!(
+            fill.commission,
+            Some(
+                Money::new(
+                    100_000.0 * instrument.maker_fee().to_f64().unwrap(),
+                    instrument.quote_currency()
+                )
+                .unwrap()
+            )
+        );
+        assert!(fill.reconciliation);
+    }
+
+    #[rstest]
+    fn test_generate_inferred_fill_market_is_taker(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = TestOrderStubs::market_order(
+            instrument.id(),
+            OrderSide::Sell,
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+
+        let fill = generate_inferred_fill(
+            &order,
+            &instrument,
+            VenueOrderId::from("V-1"),
+            AccountId::from("SIM-001"),
+            Quantity::from(50_000),
+            0.99995,
+            None,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        )
+        .unwrap();
+
+        assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+        assert_eq!(fill.last_qty, Quantity::from(50_000));
+        assert_eq!(fill.last_px, Price::from("0.99995"));
+    }
+
+    #[rstest]
+    fn test_generate_inferred_fill_inverse_commission_in_base_currency(
+        xbtusd_bitmex: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(xbtusd_bitmex);
+        let order = TestOrderStubs::market_order(
+            instrument.id(),
+            OrderSide::Buy,
+            Quantity::from(10_000),
+            None,
+            None,
+        );
+
+        let fill = generate_inferred_fill(
+            &order,
+            &instrument,
+            VenueOrderId::from("V-1"),
+            AccountId::from("BITMEX-001"),
+            Quantity::from(10_000),
+            50_000.0,
+            None,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        )
+        .unwrap();
+
+        // Notional of 10,000 USD contracts at 50,000 is 0.2 BTC
+        assert_eq!(
+            fill.commission,
+            Some(
+                Money::new(
+                    0.2 * instrument.taker_fee().to_f64().unwrap(),
+                    Currency::BTC()
+                )
+                .unwrap()
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_generate_inferred_fill_nothing_to_fill_errors(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = TestOrderStubs::market_order(
+            instrument.id(),
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+
+        let result = generate_inferred_fill(
+            &order,
+            &instrument,
+            VenueOrderId::from("V-1"),
+            AccountId::from("SIM-001"),
+            Quantity::from(0),
+            1.0,
+            None,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        assert!(result.is_err());
+    }
+}
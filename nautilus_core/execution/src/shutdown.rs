@@ -0,0 +1,954 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the graceful shutdown sequence for the engine components of a trading node.
+//!
+//! Shutdown stops data intake first, so no new signals can generate commands, then drains the
+//! execution path before optionally cancelling open orders. Anything which could not be
+//! completed within the configured timeouts is recorded on the returned [`ShutdownReport`].
+
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    rc::Rc,
+    sync::{Arc, Condvar, Mutex},
+    time::Duration,
+};
+
+use log::{info, warn};
+use nautilus_common::{cache::Cache, component::Component};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_model::{
+    enums::OrderStatus,
+    identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, strategy_id::StrategyId,
+        trader_id::TraderId,
+    },
+    orders::any::OrderAny,
+    polymorphism::{
+        GetClientOrderId, GetInstrumentId, GetOrderStatus, GetStrategyId, GetVenueOrderId, IsOpen,
+    },
+};
+
+use crate::{
+    engine::ExecutionEngine,
+    messages::{cancel::CancelOrder, TradingCommand},
+};
+
+/// The policy for cancelling open orders when the system stops.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum CancelOrdersOnStop {
+    /// Leave all open orders working at their venues.
+    #[default]
+    Never,
+    /// Cancel all open orders.
+    All,
+    /// Cancel the open orders of the given strategies only.
+    Strategies(Vec<StrategyId>),
+}
+
+impl CancelOrdersOnStop {
+    /// Returns whether open orders of the given `strategy_id` should be cancelled.
+    #[must_use]
+    pub fn applies_to(&self, strategy_id: &StrategyId) -> bool {
+        match self {
+            Self::Never => false,
+            Self::All => true,
+            Self::Strategies(strategy_ids) => strategy_ids.contains(strategy_id),
+        }
+    }
+}
+
+pub struct ShutdownConfig {
+    /// The maximum time to wait for the execution path to drain, on the timeout clock.
+    pub drain_timeout: Duration,
+    /// The maximum time to wait for cancels of open orders to be acknowledged, on the timeout
+    /// clock.
+    pub cancel_timeout: Duration,
+    /// The policy for cancelling open orders once the execution path has drained.
+    pub cancel_orders_on_stop: CancelOrdersOnStop,
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self {
+            drain_timeout: Duration::from_secs(10),
+            cancel_timeout: Duration::from_secs(5),
+            cancel_orders_on_stop: CancelOrdersOnStop::default(),
+        }
+    }
+}
+
+/// Signals activity on the execution path, such as a venue response, waking a waiting shutdown.
+///
+/// Notifications are not lost: a notify with no waiter wakes the next wait immediately.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityNotifier {
+    inner: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl ActivityNotifier {
+    /// Signals activity, waking any waiter.
+    pub fn notify(&self) {
+        let (notified, condvar) = &*self.inner;
+        *notified.lock().unwrap() = true;
+        condvar.notify_all();
+    }
+
+    /// Blocks until activity is signalled (since the last wait) or the `timeout` elapses,
+    /// returning whether activity was signalled.
+    pub fn wait(&self, timeout: Duration) -> bool {
+        let (notified, condvar) = &*self.inner;
+        let guard = notified.lock().unwrap();
+        let (mut notified, _) = condvar
+            .wait_timeout_while(guard, timeout, |notified| !*notified)
+            .unwrap();
+        std::mem::take(&mut *notified)
+    }
+}
+
+/// The execution path from strategies through to the venues, drained during shutdown.
+pub trait ExecutionPath {
+    /// Returns the number of commands queued but not yet handed to an execution client.
+    fn pending_command_count(&self) -> usize;
+
+    /// Processes queued commands and any responses received from the venues.
+    fn poll(&mut self);
+
+    /// Sends the given cancel command towards the venue.
+    fn cancel_order(&mut self, command: CancelOrder);
+
+    /// Blocks until the path signals activity or the `timeout` elapses.
+    fn wait(&mut self, timeout: Duration);
+}
+
+impl ExecutionPath for ExecutionEngine {
+    fn pending_command_count(&self) -> usize {
+        Self::pending_command_count(self)
+    }
+
+    fn poll(&mut self) {
+        self.process_queued_commands();
+        self.flush_pending_events();
+    }
+
+    fn cancel_order(&mut self, command: CancelOrder) {
+        self.enqueue(TradingCommand::CancelOrder(command));
+    }
+
+    fn wait(&mut self, timeout: Duration) {
+        self.notifier().wait(timeout);
+    }
+}
+
+/// A stage of the shutdown sequence.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShutdownStage {
+    StopDataIntake,
+    StopExecution,
+    Flush,
+}
+
+/// An action of the shutdown sequence which failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShutdownFailure {
+    pub stage: ShutdownStage,
+    /// The name of the component or flush target the action was performed on.
+    pub target: String,
+    pub message: String,
+}
+
+impl Display for ShutdownFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} {}: {}", self.stage, self.target, self.message)
+    }
+}
+
+/// The outcome of a shutdown, listing everything which could not be completed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    pub ts_started: UnixNanos,
+    pub ts_completed: UnixNanos,
+    /// Whether the execution path drained before the drain timeout.
+    pub drained: bool,
+    /// The number of commands still queued when the execution path was abandoned.
+    pub pending_command_count: usize,
+    /// The orders still pending a cancel or modify acknowledgement from their venue.
+    pub unresolved_orders: Vec<ClientOrderId>,
+    /// The orders a cancel was sent for under the `CancelOrdersOnStop` policy.
+    pub cancels_sent: Vec<ClientOrderId>,
+    /// The orders the policy required cancelling which were still open at the cancel timeout.
+    pub orders_left_open: Vec<ClientOrderId>,
+    pub failures: Vec<ShutdownFailure>,
+}
+
+impl ShutdownReport {
+    /// Returns whether every stage of the shutdown completed.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.drained
+            && self.pending_command_count == 0
+            && self.unresolved_orders.is_empty()
+            && self.orders_left_open.is_empty()
+            && self.failures.is_empty()
+    }
+}
+
+/// The handler flushing buffered state to storage, such as a streaming writer or cache database.
+pub type FlushHandler = Box<dyn FnMut() -> anyhow::Result<()>>;
+
+/// Coordinates the shutdown of the engine components in dependency order.
+pub struct ShutdownCoordinator {
+    trader_id: TraderId,
+    clock: &'static AtomicTime,
+    timeout_clock: Rc<AtomicTime>,
+    cache: Rc<RefCell<Cache>>,
+    data_components: Vec<Box<dyn Component>>,
+    execution_components: Vec<Box<dyn Component>>,
+    flush_targets: Vec<(String, FlushHandler)>,
+    config: ShutdownConfig,
+}
+
+impl ShutdownCoordinator {
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+        config: ShutdownConfig,
+    ) -> Self {
+        Self {
+            trader_id,
+            clock,
+            timeout_clock: Rc::new(AtomicTime::new(true, UnixNanos::default())),
+            cache,
+            data_components: Vec::new(),
+            execution_components: Vec::new(),
+            flush_targets: Vec::new(),
+            config,
+        }
+    }
+
+    /// Sets the clock the drain and cancel timeouts are measured on (default the wall clock).
+    ///
+    /// The system clock is not used, as it may be static (or only advanced by the events being
+    /// waited on).
+    pub fn set_timeout_clock(&mut self, clock: Rc<AtomicTime>) {
+        self.timeout_clock = clock;
+    }
+
+    /// Registers a component feeding data into the system, stopped first on shutdown.
+    pub fn register_data_component(&mut self, component: Box<dyn Component>) {
+        self.data_components.push(component);
+    }
+
+    /// Registers a component of the execution path, stopped once it has drained.
+    pub fn register_execution_component(&mut self, component: Box<dyn Component>) {
+        self.execution_components.push(component);
+    }
+
+    /// Registers a flush target, flushed in registration order as the last stage of shutdown.
+    pub fn register_flush_target(&mut self, name: &str, handler: FlushHandler) {
+        self.flush_targets.push((name.to_string(), handler));
+    }
+
+    /// Returns the registered data and execution components.
+    pub fn components(&self) -> impl Iterator<Item = &dyn Component> {
+        self.data_components
+            .iter()
+            .chain(self.execution_components.iter())
+            .map(AsRef::as_ref)
+    }
+
+    /// Shuts down the system, draining the given execution `path`.
+    ///
+    /// Every stage runs even if an earlier stage could not complete, so that as much state
+    /// as possible is persisted before the process exits.
+    pub fn shutdown(&mut self, path: &mut dyn ExecutionPath) -> ShutdownReport {
+        let mut report = ShutdownReport {
+            ts_started: self.clock.get_time_ns(),
+            ..Default::default()
+        };
+        info!("Shutting down");
+
+        stop_components(
+            &mut self.data_components,
+            ShutdownStage::StopDataIntake,
+            &mut report,
+        );
+
+        let cache = self.cache.clone();
+        report.drained = self.poll_until(path, self.config.drain_timeout, |path| {
+            path.pending_command_count() == 0 && unresolved_orders(&cache.borrow()).is_empty()
+        });
+        if !report.drained {
+            warn!(
+                "Execution path not drained after {:?}",
+                self.config.drain_timeout
+            );
+        }
+
+        let cancels = self.cancel_open_orders(path);
+        if !cancels.is_empty() {
+            let acknowledged = self.poll_until(path, self.config.cancel_timeout, |path| {
+                path.pending_command_count() == 0
+                    && open_orders(&cache.borrow(), &cancels).is_empty()
+            });
+            if !acknowledged {
+                warn!(
+                    "Cancels not acknowledged after {:?}",
+                    self.config.cancel_timeout
+                );
+            }
+            report.orders_left_open = open_orders(&cache.borrow(), &cancels);
+        }
+        report.cancels_sent = cancels;
+        report.pending_command_count = path.pending_command_count();
+        report.unresolved_orders = unresolved_orders(&cache.borrow());
+
+        stop_components(
+            &mut self.execution_components,
+            ShutdownStage::StopExecution,
+            &mut report,
+        );
+
+        for (name, handler) in &mut self.flush_targets {
+            if let Err(e) = handler() {
+                report.failures.push(ShutdownFailure {
+                    stage: ShutdownStage::Flush,
+                    target: name.clone(),
+                    message: e.to_string(),
+                });
+            }
+        }
+
+        report.ts_completed = self.clock.get_time_ns();
+        for failure in &report.failures {
+            warn!("Shutdown failure: {failure}");
+        }
+        info!("Shut down (clean={})", report.is_clean());
+        report
+    }
+
+    /// Polls the execution `path` until `is_done` holds or the `timeout` elapses on the
+    /// timeout clock, returning whether it completed.
+    ///
+    /// Between polls the path is waited on until it signals activity, rather than sleeping.
+    fn poll_until(
+        &self,
+        path: &mut dyn ExecutionPath,
+        timeout: Duration,
+        is_done: impl Fn(&dyn ExecutionPath) -> bool,
+    ) -> bool {
+        let timeout_ns = u64::try_from(timeout.as_nanos()).unwrap_or(u64::MAX);
+        let deadline = self
+            .timeout_clock
+            .get_time_ns()
+            .as_u64()
+            .saturating_add(timeout_ns);
+        loop {
+            path.poll();
+            if is_done(path) {
+                return true;
+            }
+            let now = self.timeout_clock.get_time_ns().as_u64();
+            if now >= deadline {
+                return false;
+            }
+            path.wait(Duration::from_nanos(deadline - now));
+        }
+    }
+
+    /// Sends a cancel for each open order covered by the policy, returning their IDs.
+    ///
+    /// Orders already pending cancel are skipped, as a cancel is in flight for them.
+    fn cancel_open_orders(&self, path: &mut dyn ExecutionPath) -> Vec<ClientOrderId> {
+        let policy = &self.config.cancel_orders_on_stop;
+        if *policy == CancelOrdersOnStop::Never {
+            return Vec::new();
+        }
+
+        let mut commands: Vec<CancelOrder> = {
+            let cache = self.cache.borrow();
+            cache
+                .orders_open(None, None, None, None)
+                .into_iter()
+                .filter(|order| {
+                    order.status() != OrderStatus::PendingCancel
+                        && policy.applies_to(&order.strategy_id())
+                })
+                .map(|order| self.cancel_command(&cache, order))
+                .collect()
+        };
+        commands.sort_unstable_by_key(|command| command.client_order_id);
+
+        commands
+            .into_iter()
+            .map(|command| {
+                let client_order_id = command.client_order_id;
+                path.cancel_order(command);
+                client_order_id
+            })
+            .collect()
+    }
+
+    fn cancel_command(&self, cache: &Cache, order: &OrderAny) -> CancelOrder {
+        let client_order_id = order.client_order_id();
+        let instrument_id = order.instrument_id();
+        // Without a recorded client the engine routes by venue
+        let client_id = cache
+            .client_id(&client_order_id)
+            .copied()
+            .unwrap_or_else(|| ClientId::from(instrument_id.venue.as_str()));
+        CancelOrder {
+            trader_id: self.trader_id,
+            client_id,
+            strategy_id: order.strategy_id(),
+            instrument_id,
+            client_order_id,
+            venue_order_id: order.venue_order_id().unwrap_or_default(),
+            command_id: UUID4::new(),
+            ts_init: self.clock.get_time_ns(),
+        }
+    }
+}
+
+fn stop_components(
+    components: &mut [Box<dyn Component>],
+    stage: ShutdownStage,
+    report: &mut ShutdownReport,
+) {
+    for component in components.iter_mut().filter(|c| c.is_running()) {
+        if let Err(e) = component.stop() {
+            report.failures.push(ShutdownFailure {
+                stage,
+                target: component.component_id().to_string(),
+                message: e.to_string(),
+            });
+        }
+    }
+}
+
+/// Returns the orders awaiting a cancel or modify acknowledgement from their venue.
+fn unresolved_orders(cache: &Cache) -> Vec<ClientOrderId> {
+    let mut client_order_ids: Vec<ClientOrderId> = cache
+        .orders_open(None, None, None, None)
+        .into_iter()
+        .filter(|order| {
+            matches!(
+                order.status(),
+                OrderStatus::PendingCancel | OrderStatus::PendingUpdate
+            )
+        })
+        .map(GetClientOrderId::client_order_id)
+        .collect();
+    client_order_ids.sort_unstable();
+    client_order_ids
+}
+
+/// Returns those of the given orders which are still open.
+fn open_orders(cache: &Cache, client_order_ids: &[ClientOrderId]) -> Vec<ClientOrderId> {
+    client_order_ids
+        .iter()
+        .filter(|client_order_id| cache.order(client_order_id).is_some_and(IsOpen::is_open))
+        .copied()
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use nautilus_common::enums::ComponentState;
+    use nautilus_model::{
+        enums::OrderSide,
+        events::order::{
+            canceled::OrderCanceled, event::OrderEventAny, pending_cancel::OrderPendingCancel,
+        },
+        identifiers::component_id::ComponentId,
+        orders::{limit::LimitOrder, stubs::TestOrderEventStubs},
+        polymorphism::GetTraderId,
+        stubs::{account_id, order_initialized_limit, trader_id},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    type ActionLog = Rc<RefCell<Vec<String>>>;
+
+    struct StubComponent {
+        name: &'static str,
+        state: ComponentState,
+        fail_on_stop: bool,
+        log: ActionLog,
+    }
+
+    impl StubComponent {
+        fn boxed(name: &'static str, log: &ActionLog) -> Box<Self> {
+            let mut component = Self {
+                name,
+                state: ComponentState::PreInitialized,
+                fail_on_stop: false,
+                log: log.clone(),
+            };
+            component.initialize().unwrap();
+            component.start().unwrap();
+            Box::new(component)
+        }
+    }
+
+    impl Component for StubComponent {
+        fn component_id(&self) -> ComponentId {
+            ComponentId::from(self.name)
+        }
+
+        fn state(&self) -> ComponentState {
+            self.state
+        }
+
+        fn set_state(&mut self, state: ComponentState) {
+            self.state = state;
+        }
+
+        fn on_stop(&mut self) -> anyhow::Result<()> {
+            if self.fail_on_stop {
+                anyhow::bail!("Connection lost");
+            }
+            self.log.borrow_mut().push(format!("stop {}", self.name));
+            Ok(())
+        }
+    }
+
+    /// A venue behind a command queue, handling one queued command on each poll.
+    ///
+    /// Waiting returns at once while commands are queued, otherwise it advances the timeout
+    /// clock by the full timeout, as no response ever arrives on its own.
+    struct StubVenue {
+        clock: &'static AtomicTime,
+        timeout_clock: Rc<AtomicTime>,
+        cache: Rc<RefCell<Cache>>,
+        queued: VecDeque<CancelOrder>,
+        acks_cancels: bool,
+        poll_count: usize,
+    }
+
+    impl StubVenue {
+        fn new(
+            clock: &'static AtomicTime,
+            timeout_clock: &Rc<AtomicTime>,
+            cache: &Rc<RefCell<Cache>>,
+            acks_cancels: bool,
+        ) -> Self {
+            Self {
+                clock,
+                timeout_clock: timeout_clock.clone(),
+                cache: cache.clone(),
+                queued: VecDeque::new(),
+                acks_cancels,
+                poll_count: 0,
+            }
+        }
+    }
+
+    impl ExecutionPath for StubVenue {
+        fn pending_command_count(&self) -> usize {
+            self.queued.len()
+        }
+
+        fn poll(&mut self) {
+            self.poll_count += 1;
+            let now = self.clock.get_time_ns();
+            let Some(command) = self.queued.pop_front() else {
+                return;
+            };
+            let mut cache = self.cache.borrow_mut();
+            let order = cache.order(&command.client_order_id).unwrap().clone();
+            cache.apply_order_event(pending_cancel(&order)).unwrap();
+            if self.acks_cancels {
+                let canceled = OrderCanceled::new(
+                    order.trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    order.client_order_id(),
+                    UUID4::new(),
+                    now,
+                    now,
+                    false,
+                    order.venue_order_id(),
+                    Some(account_id()),
+                )
+                .unwrap();
+                cache
+                    .apply_order_event(OrderEventAny::Canceled(canceled))
+                    .unwrap();
+            }
+        }
+
+        fn cancel_order(&mut self, command: CancelOrder) {
+            self.queued.push_back(command);
+        }
+
+        fn wait(&mut self, timeout: Duration) {
+            if self.queued.is_empty() {
+                let now = self.timeout_clock.get_time_ns();
+                self.timeout_clock
+                    .set_time(now + u64::try_from(timeout.as_nanos()).unwrap());
+            }
+        }
+    }
+
+    /// Returns a static clock, which is never advanced during shutdown.
+    fn clock() -> &'static AtomicTime {
+        Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())))
+    }
+
+    /// Returns a test clock for the timeouts, only advanced by the stub venue.
+    fn timeout_clock() -> Rc<AtomicTime> {
+        Rc::new(AtomicTime::new(false, UnixNanos::default()))
+    }
+
+    fn config(cancel_orders_on_stop: CancelOrdersOnStop) -> ShutdownConfig {
+        ShutdownConfig {
+            drain_timeout: Duration::from_millis(100),
+            cancel_timeout: Duration::from_millis(50),
+            cancel_orders_on_stop,
+        }
+    }
+
+    fn coordinator(
+        clock: &'static AtomicTime,
+        timeout_clock: &Rc<AtomicTime>,
+        cache: &Rc<RefCell<Cache>>,
+        config: ShutdownConfig,
+        log: &ActionLog,
+    ) -> ShutdownCoordinator {
+        let mut coordinator = ShutdownCoordinator::new(trader_id(), clock, cache.clone(), config);
+        coordinator.set_timeout_clock(timeout_clock.clone());
+        coordinator.register_data_component(StubComponent::boxed("DataEngine", log));
+        coordinator.register_execution_component(StubComponent::boxed("ExecEngine", log));
+        for name in ["writer", "cache"] {
+            let log = log.clone();
+            coordinator.register_flush_target(
+                name,
+                Box::new(move || {
+                    log.borrow_mut().push(format!("flush {name}"));
+                    Ok(())
+                }),
+            );
+        }
+        coordinator
+    }
+
+    fn pending_cancel(order: &OrderAny) -> OrderEventAny {
+        OrderEventAny::PendingCancel(
+            OrderPendingCancel::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                account_id(),
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                false,
+                order.venue_order_id(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn accepted_order(
+        cache: &Rc<RefCell<Cache>>,
+        client_order_id: &str,
+        strategy_id: &str,
+    ) -> OrderAny {
+        let mut init = order_initialized_limit(
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Price::from("1.00000"),
+        );
+        init.client_order_id = ClientOrderId::from(client_order_id);
+        init.strategy_id = StrategyId::from(strategy_id);
+        let order = LimitOrder::from(init);
+        let venue_order_id = format!("V-{client_order_id}").as_str().into();
+        let mut cache = cache.borrow_mut();
+        cache
+            .add_order(OrderAny::Limit(order.clone()), None, None, false)
+            .unwrap();
+        cache
+            .apply_order_event(OrderEventAny::Submitted(
+                TestOrderEventStubs::order_submitted(&order, account_id()).unwrap(),
+            ))
+            .unwrap();
+        cache
+            .apply_order_event(OrderEventAny::Accepted(
+                TestOrderEventStubs::order_accepted(&order, account_id(), venue_order_id).unwrap(),
+            ))
+            .unwrap();
+        cache.order(&order.client_order_id).unwrap().clone()
+    }
+
+    #[rstest]
+    fn test_shutdown_with_responsive_venue_is_clean() {
+        let clock = clock();
+        let timeout_clock = timeout_clock();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let log = ActionLog::default();
+        accepted_order(&cache, "O-2", "S-001");
+        accepted_order(&cache, "O-1", "S-001");
+        let mut coordinator = coordinator(
+            clock,
+            &timeout_clock,
+            &cache,
+            config(CancelOrdersOnStop::All),
+            &log,
+        );
+        let mut venue = StubVenue::new(clock, &timeout_clock, &cache, true);
+
+        let report = coordinator.shutdown(&mut venue);
+
+        assert!(report.is_clean());
+        assert!(report.drained);
+        assert_eq!(
+            report.cancels_sent,
+            vec![ClientOrderId::from("O-1"), ClientOrderId::from("O-2")]
+        );
+        assert_eq!(cache.borrow().orders_open_count(None, None, None, None), 0);
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                "stop DataEngine",
+                "stop ExecEngine",
+                "flush writer",
+                "flush cache"
+            ]
+        );
+        assert!(coordinator
+            .components()
+            .all(|component| component.state() == ComponentState::Stopped));
+    }
+
+    #[rstest]
+    fn test_shutdown_with_never_policy_leaves_orders_open() {
+        let clock = clock();
+        let timeout_clock = timeout_clock();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let log = ActionLog::default();
+        accepted_order(&cache, "O-1", "S-001");
+        let mut coordinator = coordinator(
+            clock,
+            &timeout_clock,
+            &cache,
+            config(CancelOrdersOnStop::Never),
+            &log,
+        );
+        let mut venue = StubVenue::new(clock, &timeout_clock, &cache, true);
+
+        let report = coordinator.shutdown(&mut venue);
+
+        assert!(report.is_clean());
+        assert!(report.cancels_sent.is_empty());
+        assert_eq!(cache.borrow().orders_open_count(None, None, None, None), 1);
+        // Already drained on the first poll
+        assert_eq!(venue.poll_count, 1);
+    }
+
+    #[rstest]
+    fn test_shutdown_cancels_only_selected_strategies() {
+        let clock = clock();
+        let timeout_clock = timeout_clock();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let log = ActionLog::default();
+        accepted_order(&cache, "O-1", "S-001");
+        accepted_order(&cache, "O-2", "S-002");
+        let policy = CancelOrdersOnStop::Strategies(vec![StrategyId::from("S-002")]);
+        let mut coordinator = coordinator(clock, &timeout_clock, &cache, config(policy), &log);
+        let mut venue = StubVenue::new(clock, &timeout_clock, &cache, true);
+
+        let report = coordinator.shutdown(&mut venue);
+
+        assert!(report.is_clean());
+        assert_eq!(report.cancels_sent, vec![ClientOrderId::from("O-2")]);
+        let cache = cache.borrow();
+        assert!(cache.is_order_open(&ClientOrderId::from("O-1")));
+        assert!(!cache.is_order_open(&ClientOrderId::from("O-2")));
+    }
+
+    #[rstest]
+    fn test_shutdown_with_static_clock_and_unresponsive_venue_times_out() {
+        let clock = clock();
+        let timeout_clock = timeout_clock();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let log = ActionLog::default();
+        // A cancel sent before shutdown which the venue never acknowledges
+        let order = accepted_order(&cache, "O-1", "S-001");
+        cache
+            .borrow_mut()
+            .apply_order_event(pending_cancel(&order))
+            .unwrap();
+        accepted_order(&cache, "O-2", "S-001");
+        let mut coordinator = coordinator(
+            clock,
+            &timeout_clock,
+            &cache,
+            config(CancelOrdersOnStop::All),
+            &log,
+        );
+        let mut venue = StubVenue::new(clock, &timeout_clock, &cache, false);
+
+        let report = coordinator.shutdown(&mut venue);
+
+        assert!(!report.is_clean());
+        assert!(!report.drained);
+        // Gave up after the drain timeout, then again after the cancel timeout, even though
+        // the system clock never advanced
+        assert_eq!(
+            timeout_clock.get_time_ns(),
+            UnixNanos::from(150 * 1_000_000)
+        );
+        assert_eq!(report.ts_started, report.ts_completed);
+        // The order already pending cancel is not cancelled again
+        assert_eq!(report.cancels_sent, vec![ClientOrderId::from("O-2")]);
+        assert_eq!(report.orders_left_open, vec![ClientOrderId::from("O-2")]);
+        assert_eq!(
+            report.unresolved_orders,
+            vec![ClientOrderId::from("O-1"), ClientOrderId::from("O-2")]
+        );
+        assert_eq!(report.pending_command_count, 0);
+        assert!(report.failures.is_empty());
+        // The remaining stages still run after the timeouts
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                "stop DataEngine",
+                "stop ExecEngine",
+                "flush writer",
+                "flush cache"
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_shutdown_reports_failures_and_continues() {
+        let clock = clock();
+        let timeout_clock = timeout_clock();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let log = ActionLog::default();
+        let mut coordinator = ShutdownCoordinator::new(
+            trader_id(),
+            clock,
+            cache.clone(),
+            config(CancelOrdersOnStop::All),
+        );
+        coordinator.set_timeout_clock(timeout_clock.clone());
+        let mut data_engine = StubComponent::boxed("DataEngine", &log);
+        data_engine.fail_on_stop = true;
+        coordinator.register_data_component(data_engine);
+        coordinator.register_execution_component(StubComponent::boxed("ExecEngine", &log));
+        coordinator.register_flush_target("writer", Box::new(|| anyhow::bail!("Disk full")));
+        let mut venue = StubVenue::new(clock, &timeout_clock, &cache, true);
+
+        let report = coordinator.shutdown(&mut venue);
+
+        assert!(!report.is_clean());
+        assert!(report.drained);
+        assert_eq!(
+            report.failures,
+            vec![
+                ShutdownFailure {
+                    stage: ShutdownStage::StopDataIntake,
+                    target: "DataEngine".to_string(),
+                    message: "Connection lost".to_string(),
+                },
+                ShutdownFailure {
+                    stage: ShutdownStage::Flush,
+                    target: "writer".to_string(),
+                    message: "Disk full".to_string(),
+                },
+            ]
+        );
+        assert_eq!(*log.borrow(), vec!["stop ExecEngine"]);
+    }
+
+    #[rstest]
+    fn test_shutdown_with_responsive_venue_does_not_wait() {
+        let clock = clock();
+        let timeout_clock = timeout_clock();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let log = ActionLog::default();
+        accepted_order(&cache, "O-1", "S-001");
+        let mut coordinator = coordinator(
+            clock,
+            &timeout_clock,
+            &cache,
+            config(CancelOrdersOnStop::All),
+            &log,
+        );
+        let mut venue = StubVenue::new(clock, &timeout_clock, &cache, true);
+
+        let report = coordinator.shutdown(&mut venue);
+
+        assert!(report.is_clean());
+        assert_eq!(timeout_clock.get_time_ns(), UnixNanos::default());
+    }
+
+    #[rstest]
+    fn test_notifier_wakes_waiter_on_other_thread() {
+        let notifier = ActivityNotifier::default();
+        let handle = {
+            let notifier = notifier.clone();
+            std::thread::spawn(move || notifier.notify())
+        };
+
+        let notified = notifier.wait(Duration::from_secs(60));
+        handle.join().unwrap();
+
+        assert!(notified);
+        // The notification is consumed by the wait
+        assert!(!notifier.wait(Duration::ZERO));
+    }
+
+    #[rstest]
+    fn test_engine_path_reports_queued_commands() {
+        let clock = clock();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let order = accepted_order(&cache, "O-1", "S-001");
+        let mut engine = ExecutionEngine::new(
+            trader_id(),
+            clock,
+            cache.clone(),
+            crate::engine::ExecutionEngineConfig::default(),
+        );
+        let coordinator =
+            ShutdownCoordinator::new(trader_id(), clock, cache, config(CancelOrdersOnStop::All));
+
+        ExecutionPath::cancel_order(
+            &mut engine,
+            coordinator.cancel_command(&coordinator.cache.borrow(), &order),
+        );
+        let queued = ExecutionPath::pending_command_count(&engine);
+        let notified = engine.notifier().wait(Duration::ZERO);
+        ExecutionPath::poll(&mut engine);
+
+        assert_eq!(queued, 1);
+        assert!(notified);
+        assert_eq!(ExecutionPath::pending_command_count(&engine), 0);
+        assert_eq!(engine.command_count, 1);
+    }
+}
@@ -0,0 +1,534 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides zstd compressed frames of serialized message bus messages for external streams.
+//!
+//! A frame batches the already serialized (e.g. `MsgPack` or JSON) payloads of several messages,
+//! so the compressor can exploit the redundancy between them. Each frame starts with a header
+//! of the format, the number of messages and the uncompressed body length (all little-endian),
+//! followed by the (possibly compressed) body:
+//!
+//! | Offset | Size | Field                                  |
+//! |--------|------|----------------------------------------|
+//! | 0      | 1    | format (see [`FrameFormat`])           |
+//! | 1      | 4    | message count                          |
+//! | 5      | 4    | uncompressed body length               |
+//! | 9      | ..   | body                                   |
+//!
+//! The uncompressed body is each message in turn as a topic length (`u16`), the topic, a payload
+//! length (`u32`) and the payload.
+//!
+//! Frames compressed with a dictionary can only be decoded with the same dictionary, which can
+//! be trained with [`train_dictionary`] from a sample of raw frames.
+
+use std::{borrow::Borrow, path::Path};
+
+use nautilus_common::msgbus::BusMessage;
+use zstd::bulk::{Compressor, Decompressor};
+
+/// The length of a frame header (format, message count and uncompressed body length).
+pub const FRAME_HEADER_LEN: usize = 9;
+
+/// The maximum uncompressed body length of a frame (256 MiB).
+pub const MAX_FRAME_BODY_LEN: usize = 256 * 1024 * 1024;
+
+/// The default maximum size of a trained dictionary (16 KiB).
+pub const DEFAULT_DICTIONARY_MAX_SIZE: usize = 16 * 1024;
+
+/// The format of a frame body.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FrameFormat {
+    /// The body is not compressed.
+    Raw = 0,
+    /// The body is zstd compressed.
+    Zstd = 1,
+    /// The body is zstd compressed with a dictionary.
+    ZstdDictionary = 2,
+}
+
+impl TryFrom<u8> for FrameFormat {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Raw),
+            1 => Ok(Self::Zstd),
+            2 => Ok(Self::ZstdDictionary),
+            _ => anyhow::bail!("Invalid frame format {value}"),
+        }
+    }
+}
+
+/// Represents the header of a frame.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FrameHeader {
+    pub format: FrameFormat,
+    /// The number of messages in the frame.
+    pub count: u32,
+    /// The length of the body once decompressed.
+    pub uncompressed_len: u32,
+}
+
+impl FrameHeader {
+    /// Parses the header at the start of the given `frame`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `frame` is shorter than a header.
+    /// - If the format is invalid.
+    /// - If the uncompressed body length exceeds [`MAX_FRAME_BODY_LEN`].
+    pub fn parse(frame: &[u8]) -> anyhow::Result<Self> {
+        if frame.len() < FRAME_HEADER_LEN {
+            anyhow::bail!(
+                "Frame too short for header: {} < {FRAME_HEADER_LEN} bytes",
+                frame.len()
+            );
+        }
+
+        let format = FrameFormat::try_from(frame[0])?;
+        let count = u32::from_le_bytes(frame[1..5].try_into()?);
+        let uncompressed_len = u32::from_le_bytes(frame[5..9].try_into()?);
+        if uncompressed_len as usize > MAX_FRAME_BODY_LEN {
+            anyhow::bail!(
+                "Frame body length {uncompressed_len} exceeds maximum {MAX_FRAME_BODY_LEN}"
+            );
+        }
+
+        Ok(Self {
+            format,
+            count,
+            uncompressed_len,
+        })
+    }
+
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(self.format as u8);
+        buf.extend_from_slice(&self.count.to_le_bytes());
+        buf.extend_from_slice(&self.uncompressed_len.to_le_bytes());
+    }
+}
+
+/// Encodes batches of messages into frames.
+pub struct FrameEncoder {
+    compressor: Option<Compressor<'static>>,
+    format: FrameFormat,
+}
+
+impl FrameEncoder {
+    /// Creates a new encoder of uncompressed frames.
+    #[must_use]
+    pub fn raw() -> Self {
+        Self {
+            compressor: None,
+            format: FrameFormat::Raw,
+        }
+    }
+
+    /// Creates a new encoder of frames compressed at the given zstd `level` (0 selects the zstd
+    /// default), with an optional pre-trained `dictionary`.
+    ///
+    /// # Errors
+    ///
+    /// If the zstd compression context cannot be created (e.g. an invalid dictionary).
+    pub fn zstd(level: i32, dictionary: Option<&[u8]>) -> anyhow::Result<Self> {
+        let (compressor, format) = match dictionary {
+            Some(dictionary) => (
+                Compressor::with_dictionary(level, dictionary)?,
+                FrameFormat::ZstdDictionary,
+            ),
+            None => (Compressor::new(level)?, FrameFormat::Zstd),
+        };
+
+        Ok(Self {
+            compressor: Some(compressor),
+            format,
+        })
+    }
+
+    #[must_use]
+    pub fn format(&self) -> FrameFormat {
+        self.format
+    }
+
+    /// Encodes the given `messages` into a single frame.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a topic is longer than `u16::MAX` bytes or a payload longer than `u32::MAX` bytes.
+    /// - If the body exceeds [`MAX_FRAME_BODY_LEN`].
+    /// - If compression fails.
+    pub fn encode<M: Borrow<BusMessage>>(&mut self, messages: &[M]) -> anyhow::Result<Vec<u8>> {
+        let body = encode_body(messages)?;
+        let header = FrameHeader {
+            format: self.format,
+            count: u32::try_from(messages.len())?,
+            uncompressed_len: u32::try_from(body.len())?,
+        };
+
+        let body = match &mut self.compressor {
+            Some(compressor) => compressor.compress(&body)?,
+            None => body,
+        };
+
+        let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + body.len());
+        header.write(&mut frame);
+        frame.extend_from_slice(&body);
+        Ok(frame)
+    }
+}
+
+/// Decodes frames back into messages.
+pub struct FrameDecoder {
+    decompressor: Decompressor<'static>,
+    has_dictionary: bool,
+}
+
+impl FrameDecoder {
+    /// Creates a new decoder, with the `dictionary` the frames were compressed with (if any).
+    ///
+    /// Raw frames are decoded with or without a dictionary.
+    ///
+    /// # Errors
+    ///
+    /// If the zstd decompression context cannot be created (e.g. an invalid dictionary).
+    pub fn new(dictionary: Option<&[u8]>) -> anyhow::Result<Self> {
+        let decompressor = match dictionary {
+            Some(dictionary) => Decompressor::with_dictionary(dictionary)?,
+            None => Decompressor::new()?,
+        };
+
+        Ok(Self {
+            decompressor,
+            has_dictionary: dictionary.is_some(),
+        })
+    }
+
+    /// Decodes the messages of the given `frame`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the header is invalid.
+    /// - If the frame was compressed with a dictionary and this decoder has none.
+    /// - If decompression fails, or the body does not match its header.
+    pub fn decode(&mut self, frame: &[u8]) -> anyhow::Result<Vec<BusMessage>> {
+        let header = FrameHeader::parse(frame)?;
+        let body = &frame[FRAME_HEADER_LEN..];
+
+        match header.format {
+            FrameFormat::Raw => decode_body(body, &header),
+            FrameFormat::Zstd => self.decompress(body, &header),
+            FrameFormat::ZstdDictionary => {
+                if !self.has_dictionary {
+                    anyhow::bail!("Frame was compressed with a dictionary, but no dictionary set");
+                }
+                self.decompress(body, &header)
+            }
+        }
+    }
+
+    fn decompress(&mut self, body: &[u8], header: &FrameHeader) -> anyhow::Result<Vec<BusMessage>> {
+        let body = self
+            .decompressor
+            .decompress(body, header.uncompressed_len as usize)?;
+        decode_body(&body, header)
+    }
+}
+
+/// Trains a zstd dictionary of at most `max_size` bytes from the payloads of the given raw
+/// `frames`.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If a frame is not a valid raw frame.
+/// - If zstd cannot train a dictionary from the samples (e.g. too few samples).
+pub fn train_dictionary<F: AsRef<[u8]>>(frames: &[F], max_size: usize) -> anyhow::Result<Vec<u8>> {
+    let mut samples = Vec::new();
+    for frame in frames {
+        let frame = frame.as_ref();
+        let header = FrameHeader::parse(frame)?;
+        if header.format != FrameFormat::Raw {
+            anyhow::bail!(
+                "Expected raw frame for dictionary sample, was {:?}",
+                header.format
+            );
+        }
+        let messages = decode_body(&frame[FRAME_HEADER_LEN..], &header)?;
+        samples.extend(messages.into_iter().map(|msg| msg.payload));
+    }
+
+    Ok(zstd::dict::from_samples(&samples, max_size)?)
+}
+
+/// Trains a zstd dictionary of at most `max_size` bytes from the sample file at `path`, which
+/// holds consecutive raw frames (e.g. captured from a stream without compression).
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the file cannot be read.
+/// - If the file does not consist of whole raw frames.
+/// - If zstd cannot train a dictionary from the samples.
+pub fn train_dictionary_from_file<P: AsRef<Path>>(
+    path: P,
+    max_size: usize,
+) -> anyhow::Result<Vec<u8>> {
+    let data = std::fs::read(path)?;
+    let mut frames = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        let header = FrameHeader::parse(&data[offset..])?;
+        let frame_len = FRAME_HEADER_LEN + header.uncompressed_len as usize;
+        if offset + frame_len > data.len() {
+            anyhow::bail!("Truncated frame at byte offset {offset}");
+        }
+        frames.push(&data[offset..offset + frame_len]);
+        offset += frame_len;
+    }
+
+    train_dictionary(&frames, max_size)
+}
+
+fn encode_body<M: Borrow<BusMessage>>(messages: &[M]) -> anyhow::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    for msg in messages.iter().map(Borrow::borrow) {
+        let topic_len = u16::try_from(msg.topic.len())?;
+        let payload_len = u32::try_from(msg.payload.len())?;
+        body.extend_from_slice(&topic_len.to_le_bytes());
+        body.extend_from_slice(msg.topic.as_bytes());
+        body.extend_from_slice(&payload_len.to_le_bytes());
+        body.extend_from_slice(&msg.payload);
+    }
+
+    if body.len() > MAX_FRAME_BODY_LEN {
+        anyhow::bail!(
+            "Frame body length {} exceeds maximum {MAX_FRAME_BODY_LEN}",
+            body.len()
+        );
+    }
+    Ok(body)
+}
+
+fn decode_body(body: &[u8], header: &FrameHeader) -> anyhow::Result<Vec<BusMessage>> {
+    if body.len() != header.uncompressed_len as usize {
+        anyhow::bail!(
+            "Frame body length {} does not match header {}",
+            body.len(),
+            header.uncompressed_len
+        );
+    }
+
+    let mut messages = Vec::with_capacity(header.count as usize);
+    let mut offset = 0;
+    while offset < body.len() {
+        let topic_len = u16::from_le_bytes(take(body, &mut offset, 2)?.try_into()?) as usize;
+        let topic = String::from_utf8(take(body, &mut offset, topic_len)?.to_vec())?;
+        let payload_len = u32::from_le_bytes(take(body, &mut offset, 4)?.try_into()?) as usize;
+        let payload = take(body, &mut offset, payload_len)?.to_vec();
+        messages.push(BusMessage { topic, payload });
+    }
+
+    if messages.len() != header.count as usize {
+        anyhow::bail!(
+            "Frame holds {} messages, header count was {}",
+            messages.len(),
+            header.count
+        );
+    }
+    Ok(messages)
+}
+
+fn take<'a>(body: &'a [u8], offset: &mut usize, len: usize) -> anyhow::Result<&'a [u8]> {
+    let end = *offset + len;
+    if end > body.len() {
+        anyhow::bail!("Truncated frame body at byte offset {}", *offset);
+    }
+    let bytes = &body[*offset..end];
+    *offset = end;
+    Ok(bytes)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        events::order::filled::OrderFilledBuilder,
+        identifiers::{
+            client_order_id::ClientOrderId, instrument_id::InstrumentId, trade_id::TradeId,
+        },
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Returns batches of a fixture stream of JSON serialized fill events.
+    fn event_batches(batch_count: usize, batch_size: usize) -> Vec<Vec<BusMessage>> {
+        (0..batch_count)
+            .map(|batch| {
+                (0..batch_size)
+                    .map(|i| {
+                        let n = batch * batch_size + i;
+                        let fill = OrderFilledBuilder::default()
+                            .instrument_id(InstrumentId::from("ETHUSDT.BINANCE"))
+                            .client_order_id(ClientOrderId::from(format!("O-{}", n / 3).as_str()))
+                            .trade_id(TradeId::from(format!("T-{n}").as_str()))
+                            .last_qty(Quantity::from((n % 7 + 1) as i64))
+                            .last_px(Price::from(format!("2500.{:02}", n % 100).as_str()))
+                            .ts_event((1_700_000_000_000_000_000 + n as u64 * 1_000).into())
+                            .build()
+                            .unwrap();
+                        BusMessage {
+                            topic: "events.order.S-001".to_string(),
+                            payload: serde_json::to_vec(&fill).unwrap(),
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn payload_len(batches: &[Vec<BusMessage>]) -> usize {
+        batches.iter().flatten().map(|msg| msg.payload.len()).sum()
+    }
+
+    fn assert_round_trip(
+        encoder: &mut FrameEncoder,
+        decoder: &mut FrameDecoder,
+        batches: &[Vec<BusMessage>],
+    ) -> usize {
+        let mut frames_len = 0;
+        for batch in batches {
+            let frame = encoder.encode(batch).unwrap();
+            frames_len += frame.len();
+
+            let header = FrameHeader::parse(&frame).unwrap();
+            assert_eq!(header.format, encoder.format());
+            assert_eq!(header.count as usize, batch.len());
+
+            let decoded = decoder.decode(&frame).unwrap();
+            assert_eq!(decoded.len(), batch.len());
+            for (decoded, msg) in decoded.iter().zip(batch) {
+                assert_eq!(decoded.topic, msg.topic);
+                assert_eq!(decoded.payload, msg.payload);
+            }
+        }
+        frames_len
+    }
+
+    #[rstest]
+    fn test_round_trip_raw() {
+        let batches = event_batches(2, 10);
+        let mut encoder = FrameEncoder::raw();
+        let mut decoder = FrameDecoder::new(None).unwrap();
+
+        let frames_len = assert_round_trip(&mut encoder, &mut decoder, &batches);
+
+        assert_eq!(
+            frames_len,
+            payload_len(&batches) + 2 * FRAME_HEADER_LEN + 20 * (2 + 18 + 4)
+        );
+    }
+
+    #[rstest]
+    fn test_round_trip_zstd_without_dictionary() {
+        let batches = event_batches(5, 20);
+        let mut encoder = FrameEncoder::zstd(3, None).unwrap();
+        let mut decoder = FrameDecoder::new(None).unwrap();
+
+        let frames_len = assert_round_trip(&mut encoder, &mut decoder, &batches);
+
+        let ratio = payload_len(&batches) as f64 / frames_len as f64;
+        println!("Compression ratio without dictionary: {ratio:.2}");
+    }
+
+    #[rstest]
+    fn test_round_trip_zstd_with_trained_dictionary() {
+        let mut raw = FrameEncoder::raw();
+        let samples: Vec<Vec<u8>> = event_batches(50, 20)
+            .iter()
+            .map(|batch| raw.encode(batch).unwrap())
+            .collect();
+        let dictionary = train_dictionary(&samples, DEFAULT_DICTIONARY_MAX_SIZE).unwrap();
+
+        // Small batches are where a dictionary helps the most
+        let batches = event_batches(20, 2);
+        let mut encoder = FrameEncoder::zstd(3, Some(&dictionary)).unwrap();
+        let mut decoder = FrameDecoder::new(Some(&dictionary)).unwrap();
+        let frames_len = assert_round_trip(&mut encoder, &mut decoder, &batches);
+
+        let mut plain_encoder = FrameEncoder::zstd(3, None).unwrap();
+        let mut plain_decoder = FrameDecoder::new(None).unwrap();
+        let plain_frames_len = assert_round_trip(&mut plain_encoder, &mut plain_decoder, &batches);
+
+        let payload_len = payload_len(&batches) as f64;
+        println!(
+            "Compression ratio with dictionary: {:.2}, without: {:.2}",
+            payload_len / frames_len as f64,
+            payload_len / plain_frames_len as f64,
+        );
+    }
+
+    #[rstest]
+    fn test_train_dictionary_from_file() {
+        let mut raw = FrameEncoder::raw();
+        let mut data = Vec::new();
+        for batch in event_batches(50, 20) {
+            data.extend(raw.encode(&batch).unwrap());
+        }
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), &data).unwrap();
+
+        let dictionary = train_dictionary_from_file(file.path(), 4 * 1024).unwrap();
+
+        assert!(!dictionary.is_empty());
+        assert!(dictionary.len() <= 4 * 1024);
+    }
+
+    #[rstest]
+    fn test_dictionary_frame_without_dictionary_errors() {
+        let mut raw = FrameEncoder::raw();
+        let samples: Vec<Vec<u8>> = event_batches(50, 20)
+            .iter()
+            .map(|batch| raw.encode(batch).unwrap())
+            .collect();
+        let dictionary = train_dictionary(&samples, DEFAULT_DICTIONARY_MAX_SIZE).unwrap();
+        let mut encoder = FrameEncoder::zstd(3, Some(&dictionary)).unwrap();
+        let frame = encoder.encode(&event_batches(1, 2)[0]).unwrap();
+
+        let result = FrameDecoder::new(None).unwrap().decode(&frame);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("no dictionary set"));
+    }
+
+    #[rstest]
+    fn test_invalid_header_errors() {
+        let mut decoder = FrameDecoder::new(None).unwrap();
+
+        assert!(decoder.decode(&[0, 1, 0]).is_err());
+        assert!(decoder.decode(&[9, 0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+        // Count of one message but an empty body
+        assert!(decoder.decode(&[0, 1, 0, 0, 0, 0, 0, 0, 0]).is_err());
+    }
+}
@@ -27,12 +27,21 @@ use redis::*;
 use serde_json::Value;
 use tracing::{debug, error};
 
-use crate::redis::{create_redis_connection, get_buffer_interval, get_stream_name};
+use crate::{
+    compression::{FrameDecoder, FrameEncoder},
+    redis::{create_redis_connection, get_buffer_interval, get_stream_name},
+};
 
 const XTRIM: &str = "XTRIM";
 const MINID: &str = "MINID";
 const TRIM_BUFFER_SECONDS: u64 = 60;
 
+/// The stream entry field holding the payload of a single uncompressed message.
+pub const PAYLOAD_FIELD: &str = "payload";
+
+/// The stream entry field holding a frame of messages (see [`crate::compression`]).
+pub const FRAME_FIELD: &str = "frame";
+
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
@@ -104,6 +113,9 @@ pub fn handle_messages(
 
     let stream_name = get_stream_name(trader_id, instance_id, &config);
 
+    // Compression
+    let mut encoder = get_frame_encoder(&config)?;
+
     // Autotrimming
     let autotrim_mins = config
         .get("autotrim_mins")
@@ -130,7 +142,8 @@ pub fn handle_messages(
                 autotrim_duration,
                 &mut last_trim_index,
                 &mut buffer,
-            )?;
+                encoder.as_mut(),
+            );
             last_drain = Instant::now();
         } else {
             // Continue to receive and handle messages until channel is hung up
@@ -157,53 +170,215 @@ pub fn handle_messages(
             autotrim_duration,
             &mut last_trim_index,
             &mut buffer,
-        )?;
+            encoder.as_mut(),
+        );
     }
 
     Ok(())
 }
 
+/// Returns the frame encoder for the `compression_level` in the given `config`, with the
+/// dictionary at `compression_dictionary_path` (if any), or `None` if compression is disabled.
+///
+/// # Errors
+///
+/// If the dictionary cannot be read or the encoder cannot be created.
+pub fn get_frame_encoder(config: &HashMap<String, Value>) -> anyhow::Result<Option<FrameEncoder>> {
+    let Some(level) = config.get("compression_level").and_then(Value::as_i64) else {
+        return Ok(None);
+    };
+    let dictionary = get_compression_dictionary(config)?;
+    let encoder = FrameEncoder::zstd(i32::try_from(level)?, dictionary.as_deref())?;
+    Ok(Some(encoder))
+}
+
+/// Returns the dictionary at `compression_dictionary_path` in the given `config`, if any.
+///
+/// # Errors
+///
+/// If the dictionary file cannot be read.
+pub fn get_compression_dictionary(
+    config: &HashMap<String, Value>,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    match config
+        .get("compression_dictionary_path")
+        .and_then(Value::as_str)
+    {
+        Some(path) => Ok(Some(std::fs::read(path)?)),
+        None => Ok(None),
+    }
+}
+
+/// Decodes the messages of an entry read from the stream for `topic`, whether written as a
+/// single uncompressed message or as a frame of messages.
+///
+/// This is the consumer side of the stream: a consumer reads entries with `XREAD` and passes
+/// the field values of each entry here, with a decoder holding the producer's dictionary.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the entry has neither a payload nor a frame field.
+/// - If the frame cannot be decoded.
+pub fn decode_stream_entry(
+    topic: &str,
+    fields: &HashMap<String, Vec<u8>>,
+    decoder: &mut FrameDecoder,
+) -> anyhow::Result<Vec<BusMessage>> {
+    if let Some(frame) = fields.get(FRAME_FIELD) {
+        return decoder.decode(frame);
+    }
+
+    match fields.get(PAYLOAD_FIELD) {
+        Some(payload) => Ok(vec![BusMessage {
+            topic: topic.to_string(),
+            payload: payload.clone(),
+        }]),
+        None => anyhow::bail!("Stream entry for '{topic}' has no payload or frame"),
+    }
+}
+
 fn drain_buffer(
     conn: &mut Connection,
     stream_name: &str,
     autotrim_duration: Option<Duration>,
     last_trim_index: &mut HashMap<String, usize>,
     buffer: &mut VecDeque<BusMessage>,
-) -> anyhow::Result<()> {
+    mut encoder: Option<&mut FrameEncoder>,
+) {
     let mut pipe = redis::pipe();
     pipe.atomic();
 
-    for msg in buffer.drain(..) {
+    // Group the messages for each stream, preserving their order
+    let mut batches: Vec<(String, Vec<&BusMessage>)> = Vec::new();
+    for msg in buffer.iter() {
         let key = format!("{stream_name}{}", &msg.topic);
-        let items: Vec<(&str, &Vec<u8>)> = vec![("payload", &msg.payload)];
-        pipe.xadd(&key, "*", &items);
-
-        if autotrim_duration.is_none() {
-            continue; // Nothing else to do
+        match batches.iter_mut().find(|(batch_key, _)| *batch_key == key) {
+            Some((_, batch)) => batch.push(msg),
+            None => batches.push((key, vec![msg])),
         }
+    }
+
+    for (key, batch) in &batches {
+        let frame = encoder.as_deref_mut().and_then(|encoder| {
+            encoder
+                .encode(batch)
+                .map_err(|e| error!("Error encoding frame for '{key}', writing uncompressed: {e}"))
+                .ok()
+        });
 
-        // Autotrim stream
-        let last_trim_ms = last_trim_index.entry(key.clone()).or_insert(0); // Remove clone
-        let unix_duration_now = duration_since_unix_epoch();
-        let trim_buffer = Duration::from_secs(TRIM_BUFFER_SECONDS);
-
-        // Improve efficiency of this by batching
-        if *last_trim_ms < (unix_duration_now - trim_buffer).as_millis() as usize {
-            let min_timestamp_ms =
-                (unix_duration_now - autotrim_duration.unwrap()).as_millis() as usize;
-            let result: Result<(), redis::RedisError> = redis::cmd(XTRIM)
-                .arg(&key)
-                .arg(MINID)
-                .arg(min_timestamp_ms)
-                .query(conn);
-
-            if let Err(e) = result {
-                error!("Error trimming stream '{key}': {e}");
-            } else {
-                last_trim_index.insert(key, unix_duration_now.as_millis() as usize);
+        match frame {
+            Some(frame) => {
+                let items: Vec<(&str, &Vec<u8>)> = vec![(FRAME_FIELD, &frame)];
+                pipe.xadd(key, "*", &items);
             }
+            None => {
+                for msg in batch {
+                    let items: Vec<(&str, &Vec<u8>)> = vec![(PAYLOAD_FIELD, &msg.payload)];
+                    pipe.xadd(key, "*", &items);
+                }
+            }
+        }
+    }
+
+    if let Some(autotrim_duration) = autotrim_duration {
+        for (key, _) in batches {
+            trim_stream(conn, key, autotrim_duration, last_trim_index);
+        }
+    }
+    buffer.clear();
+
+    if let Err(e) = pipe.query::<()>(conn) {
+        error!("Error writing to streams '{stream_name}': {e}");
+    }
+}
+
+fn trim_stream(
+    conn: &mut Connection,
+    key: String,
+    autotrim_duration: Duration,
+    last_trim_index: &mut HashMap<String, usize>,
+) {
+    let last_trim_ms = last_trim_index.entry(key.clone()).or_insert(0); // Remove clone
+    let unix_duration_now = duration_since_unix_epoch();
+    let trim_buffer = Duration::from_secs(TRIM_BUFFER_SECONDS);
+
+    // Improve efficiency of this by batching
+    if *last_trim_ms < (unix_duration_now - trim_buffer).as_millis() as usize {
+        let min_timestamp_ms = (unix_duration_now - autotrim_duration).as_millis() as usize;
+        let result: Result<(), redis::RedisError> = redis::cmd(XTRIM)
+            .arg(&key)
+            .arg(MINID)
+            .arg(min_timestamp_ms)
+            .query(conn);
+
+        if let Err(e) = result {
+            error!("Error trimming stream '{key}': {e}");
+        } else {
+            last_trim_index.insert(key, unix_duration_now.as_millis() as usize);
         }
     }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    fn messages() -> Vec<BusMessage> {
+        (0..3)
+            .map(|i| BusMessage {
+                topic: "events.order.S-001".to_string(),
+                payload: format!("payload-{i}").into_bytes(),
+            })
+            .collect()
+    }
 
-    pipe.query::<()>(conn).map_err(anyhow::Error::from)
+    #[rstest]
+    fn test_get_frame_encoder_disabled_by_default() {
+        assert!(get_frame_encoder(&HashMap::new()).unwrap().is_none());
+    }
+
+    #[rstest]
+    fn test_decode_stream_entry_frame() {
+        let mut config = HashMap::new();
+        config.insert("compression_level".to_string(), json!(3));
+        let mut encoder = get_frame_encoder(&config).unwrap().unwrap();
+        let mut fields = HashMap::new();
+        fields.insert(
+            FRAME_FIELD.to_string(),
+            encoder.encode(&messages()).unwrap(),
+        );
+
+        let mut decoder = FrameDecoder::new(None).unwrap();
+        let decoded = decode_stream_entry("events.order.S-001", &fields, &mut decoder).unwrap();
+
+        assert_eq!(decoded.len(), 3);
+        assert_eq!(decoded[2].payload, b"payload-2");
+    }
+
+    #[rstest]
+    fn test_decode_stream_entry_uncompressed_payload() {
+        let mut fields = HashMap::new();
+        fields.insert(PAYLOAD_FIELD.to_string(), b"payload-0".to_vec());
+
+        let mut decoder = FrameDecoder::new(None).unwrap();
+        let decoded = decode_stream_entry("events.order.S-001", &fields, &mut decoder).unwrap();
+
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].topic, "events.order.S-001");
+        assert_eq!(decoded[0].payload, b"payload-0");
+    }
+
+    #[rstest]
+    fn test_decode_stream_entry_without_fields_errors() {
+        let mut decoder = FrameDecoder::new(None).unwrap();
+
+        assert!(decode_stream_entry("events", &HashMap::new(), &mut decoder).is_err());
+    }
 }
@@ -299,6 +299,7 @@ impl<'r> FromRow<'r, PgRow> for OrderAcceptedModel {
             ts_event,
             ts_init,
             false,
+            None,
         )
         .unwrap();
         Ok(OrderAcceptedModel(order_event))
@@ -460,6 +461,7 @@ impl<'r> FromRow<'r, PgRow> for OrderSubmittedModel {
             event_id,
             ts_event,
             ts_init,
+            None,
         )
         .unwrap();
         Ok(OrderSubmittedModel(order_event))
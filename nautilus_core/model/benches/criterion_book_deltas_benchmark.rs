@@ -0,0 +1,101 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fs;
+
+use criterion::{black_box, criterion_group, BatchSize, Criterion};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{delta::OrderBookDelta, deltas::OrderBookDeltas, order::BookOrder},
+    enums::{BookAction, BookType, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+    orderbook::book::OrderBook,
+    types::{price::Price, quantity::Quantity},
+};
+
+const TEST_DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../tests/test_data/binance");
+
+/// The number of recorded diff messages merged into each batch.
+const MESSAGES_PER_BATCH: u64 = 20;
+
+/// Returns the deltas for the rows of a recorded Binance depth CSV.
+///
+/// Snapshot rows become adds, and update rows become updates (or deletes for a zero quantity).
+fn load_depth_csv(file_name: &str, sequence: u64) -> Vec<OrderBookDelta> {
+    let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+    let csv = fs::read_to_string(format!("{TEST_DATA_DIR}/{file_name}")).unwrap();
+    csv.lines()
+        .skip(1)
+        .map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            let side = match fields[4] {
+                "b" => OrderSide::Buy,
+                _ => OrderSide::Sell,
+            };
+            let size = Quantity::from(fields[7]);
+            let action = match (fields[5], size.raw) {
+                ("snap", _) => BookAction::Add,
+                (_, 0) => BookAction::Delete,
+                _ => BookAction::Update,
+            };
+            let ts_event = UnixNanos::from(fields[1].parse::<u64>().unwrap() * 1_000_000);
+            OrderBookDelta::new(
+                instrument_id,
+                action,
+                BookOrder::new(side, Price::from(fields[6]), size, 0),
+                0,
+                sequence,
+                ts_event,
+                ts_event,
+            )
+        })
+        .collect()
+}
+
+pub fn criterion_book_deltas_benchmark(c: &mut Criterion) {
+    let snapshot = load_depth_csv("btcusdt-depth-snap.csv", 0);
+    let updates: Vec<OrderBookDelta> = (1..=MESSAGES_PER_BATCH)
+        .flat_map(|sequence| load_depth_csv("btcusdt-depth-update.csv", sequence))
+        .collect();
+    let mut book = OrderBook::new(BookType::L2_MBP, snapshot[0].instrument_id);
+    for delta in &snapshot {
+        book.apply_delta(*delta).unwrap();
+    }
+    let deltas = OrderBookDeltas::new(book.instrument_id, updates);
+
+    let mut group = c.benchmark_group("book_deltas");
+    group.bench_function("apply_delta_sequential", |b| {
+        b.iter_batched_ref(
+            || book.clone(),
+            |book| {
+                for delta in black_box(&deltas.deltas) {
+                    book.apply_delta(*delta).unwrap();
+                }
+            },
+            BatchSize::SmallInput,
+        );
+    });
+    group.bench_function("apply_deltas_coalesced", |b| {
+        b.iter_batched_ref(
+            || book.clone(),
+            |book| black_box(&deltas).apply_to(book),
+            BatchSize::SmallInput,
+        );
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_book_deltas_benchmark);
+criterion::criterion_main!(benches);
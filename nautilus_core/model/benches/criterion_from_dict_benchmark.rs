@@ -0,0 +1,82 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, Criterion};
+use nautilus_core::python::serialization::from_dict_pyo3;
+use nautilus_model::{
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce},
+    events::order::initialized::OrderInitialized,
+    identifiers::{client_order_id::ClientOrderId, order_list_id::OrderListId},
+    types::{price::Price, quantity::Quantity},
+};
+use pyo3::{prelude::*, types::PyDict};
+
+/// Returns a contingent limit order event, with nested lists of linked order IDs and tags.
+fn order_initialized() -> OrderInitialized {
+    let mut event = OrderInitialized {
+        order_side: OrderSide::Buy,
+        order_type: OrderType::Limit,
+        quantity: Quantity::from(100_000),
+        time_in_force: TimeInForce::Gtc,
+        price: Some(Price::from("1.00000")),
+        linked_order_ids: Some(
+            vec![ClientOrderId::from("O-002"), ClientOrderId::from("O-003")].into(),
+        ),
+        ..Default::default()
+    };
+    event.ext_mut().contingency_type = Some(ContingencyType::Oco);
+    event.ext_mut().order_list_id = Some(OrderListId::from("OL-001"));
+    event
+}
+
+/// The previous implementation, encoding the dict to JSON with the Python `json` module.
+fn from_dict_json_dumps(py: Python<'_>, values: &Py<PyDict>) -> OrderInitialized {
+    let json_str: String = PyModule::import(py, "json")
+        .unwrap()
+        .call_method1("dumps", (values,))
+        .unwrap()
+        .extract()
+        .unwrap();
+    serde_json::from_str(&json_str).unwrap()
+}
+
+pub fn criterion_from_dict_benchmark(c: &mut Criterion) {
+    pyo3::prepare_freethreaded_python();
+    let event = order_initialized();
+
+    Python::with_gil(|py| {
+        let dict: Py<PyDict> = PyModule::import(py, "json")
+            .unwrap()
+            .call_method1("loads", (serde_json::to_string(&event).unwrap(),))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(from_dict_json_dumps(py, &dict), event);
+
+        let mut group = c.benchmark_group("from_dict_order_initialized");
+        group.bench_function("json_dumps", |b| {
+            b.iter(|| from_dict_json_dumps(py, black_box(&dict)));
+        });
+        group.bench_function("native", |b| {
+            b.iter(|| {
+                from_dict_pyo3::<OrderInitialized>(py, black_box(dict.clone_ref(py))).unwrap()
+            });
+        });
+        group.finish();
+    });
+}
+
+criterion_group!(benches, criterion_from_dict_benchmark);
+criterion::criterion_main!(benches);
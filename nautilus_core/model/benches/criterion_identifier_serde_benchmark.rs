@@ -0,0 +1,76 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, Criterion};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderType},
+    events::order::filled::OrderFilled,
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
+        venue_order_id::VenueOrderId,
+    },
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+const NUM_FILLS: usize = 1_000_000;
+const FILLS_PER_ORDER: usize = 10;
+
+/// Returns 1M fills as JSON, spread over 10 strategies, 200 instruments and 100k orders.
+fn fills_json() -> String {
+    let usd = Currency::USD();
+    let fills: Vec<OrderFilled> = (0..NUM_FILLS)
+        .map(|i| {
+            let order = i / FILLS_PER_ORDER;
+            OrderFilled::new(
+                TraderId::from("TRADER-001"),
+                StrategyId::from(format!("S-{:03}", order % 10).as_str()),
+                InstrumentId::from(format!("SYM{}.SIM", order % 200).as_str()),
+                ClientOrderId::from(format!("O-{order}").as_str()),
+                VenueOrderId::from(format!("V-{order}").as_str()),
+                AccountId::from("SIM-001"),
+                TradeId::from(format!("T-{i}").as_str()),
+                OrderSide::Buy,
+                OrderType::Limit,
+                Quantity::from(1),
+                Price::from("1.00000"),
+                usd,
+                LiquiditySide::Taker,
+                UUID4::new(),
+                UnixNanos::from(i as u64),
+                UnixNanos::from(i as u64),
+                false,
+                Some(PositionId::from(format!("P-{}", order % 1_000).as_str())),
+                Some(Money::new(0.01, usd).unwrap()),
+            )
+            .unwrap()
+        })
+        .collect();
+    serde_json::to_string(&fills).unwrap()
+}
+
+pub fn criterion_identifier_serde_benchmark(c: &mut Criterion) {
+    let json = fills_json();
+    let mut group = c.benchmark_group("identifier_serde");
+    group.sample_size(10);
+    group.bench_function("deserialize_1m_fills", |b| {
+        b.iter(|| serde_json::from_str::<Vec<OrderFilled>>(black_box(&json)).unwrap());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_identifier_serde_benchmark);
+criterion::criterion_main!(benches);
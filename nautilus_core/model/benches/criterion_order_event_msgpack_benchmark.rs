@@ -0,0 +1,92 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, Criterion};
+use nautilus_model::{
+    events::order::{
+        accepted::OrderAccepted, event::OrderEventAny, filled::OrderFilled,
+        submitted::OrderSubmitted,
+    },
+    identifiers::client_order_id::ClientOrderId,
+    serialization::msgpack::{decode_events, encode_events},
+};
+
+const NUM_EVENTS: usize = 100_000;
+
+/// Returns 100k events, cycling through submitted, accepted and filled for each order.
+fn events() -> Vec<OrderEventAny> {
+    (0..NUM_EVENTS)
+        .map(|i| {
+            let client_order_id = ClientOrderId::from(format!("O-{}", i / 3).as_str());
+            match i % 3 {
+                0 => OrderEventAny::Submitted(OrderSubmitted {
+                    client_order_id,
+                    ..Default::default()
+                }),
+                1 => OrderEventAny::Accepted(OrderAccepted {
+                    client_order_id,
+                    ..Default::default()
+                }),
+                _ => OrderEventAny::Filled(OrderFilled {
+                    client_order_id,
+                    ..Default::default()
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn criterion_order_event_msgpack_benchmark(c: &mut Criterion) {
+    let events = events();
+    let per_event: Vec<Vec<u8>> = events
+        .iter()
+        .map(|event| rmp_serde::to_vec_named(event).unwrap())
+        .collect();
+    let mut batched = Vec::new();
+    encode_events(&events, &mut batched).unwrap();
+
+    let mut group = c.benchmark_group("order_event_msgpack");
+    group.sample_size(10);
+    group.bench_function("encode_100k_per_event", |b| {
+        b.iter(|| {
+            black_box(&events)
+                .iter()
+                .map(|event| rmp_serde::to_vec_named(event).unwrap())
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("encode_100k_batched", |b| {
+        let mut buf = Vec::new();
+        b.iter(|| {
+            buf.clear();
+            encode_events(black_box(&events), &mut buf).unwrap();
+        });
+    });
+    group.bench_function("decode_100k_per_event", |b| {
+        b.iter(|| {
+            black_box(&per_event)
+                .iter()
+                .map(|bytes| rmp_serde::from_slice::<OrderEventAny>(bytes).unwrap())
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("decode_100k_batched", |b| {
+        b.iter(|| decode_events(black_box(&batched)).unwrap());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_order_event_msgpack_benchmark);
+criterion::criterion_main!(benches);
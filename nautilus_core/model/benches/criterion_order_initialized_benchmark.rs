@@ -0,0 +1,138 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, Criterion};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TrailingOffsetType, TriggerType},
+    events::order::initialized::OrderInitialized,
+    identifiers::{
+        client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+        instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
+        trader_id::TraderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+use serde::Deserialize;
+use ustr::Ustr;
+
+const NUM_EVENTS: usize = 10_000;
+
+/// Every n-th event is part of a contingent order list.
+const CONTINGENT_EVERY: usize = 10;
+
+/// The flat `OrderInitialized` layout prior to boxing the rarely-used fields, as a baseline.
+#[allow(dead_code)]
+#[derive(Clone, Deserialize)]
+struct FlatOrderInitialized {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    quantity: Quantity,
+    time_in_force: TimeInForce,
+    post_only: bool,
+    reduce_only: bool,
+    quote_quantity: bool,
+    reconciliation: bool,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    trigger_type: Option<TriggerType>,
+    limit_offset: Option<Price>,
+    trailing_offset: Option<Price>,
+    trailing_offset_type: Option<TrailingOffsetType>,
+    expire_time: Option<UnixNanos>,
+    display_qty: Option<Quantity>,
+    emulation_trigger: Option<TriggerType>,
+    trigger_instrument_id: Option<InstrumentId>,
+    contingency_type: Option<ContingencyType>,
+    order_list_id: Option<OrderListId>,
+    linked_order_ids: Option<Vec<ClientOrderId>>,
+    parent_order_id: Option<ClientOrderId>,
+    exec_algorithm_id: Option<ExecAlgorithmId>,
+    exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+    exec_spawn_id: Option<ClientOrderId>,
+    tags: Option<Vec<Ustr>>,
+}
+
+/// Returns 10k limit order events, every 10th of which is a contingent order with tags.
+fn events() -> Vec<OrderInitialized> {
+    (0..NUM_EVENTS)
+        .map(|i| {
+            let is_contingent = i % CONTINGENT_EVERY == 0;
+            OrderInitialized::new(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                InstrumentId::from("AUD/USD.SIM"),
+                ClientOrderId::from(format!("O-{i}").as_str()),
+                OrderSide::Buy,
+                OrderType::Limit,
+                Quantity::from(100_000),
+                TimeInForce::Gtc,
+                false,
+                false,
+                false,
+                false,
+                UUID4::new(),
+                UnixNanos::from(i as u64),
+                UnixNanos::from(i as u64),
+                Some(Price::from("1.00000")),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                is_contingent.then_some(ContingencyType::Oco),
+                is_contingent.then(|| OrderListId::from(format!("OL-{i}").as_str())),
+                is_contingent.then(|| vec![ClientOrderId::from(format!("O-{i}-TP").as_str())]),
+                None,
+                None,
+                None,
+                None,
+                is_contingent.then(|| vec![Ustr::from("ENTRY")]),
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+pub fn criterion_order_initialized_benchmark(c: &mut Criterion) {
+    let events = events();
+    let flat_events: Vec<FlatOrderInitialized> =
+        serde_json::from_str(&serde_json::to_string(&events).unwrap()).unwrap();
+
+    let mut group = c.benchmark_group("order_initialized");
+    group.bench_function("clone_10k_flat", |b| {
+        b.iter(|| black_box(&flat_events).clone());
+    });
+    group.bench_function("clone_10k_boxed_ext", |b| {
+        b.iter(|| black_box(&events).clone());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_order_initialized_benchmark);
+criterion::criterion_main!(benches);
@@ -0,0 +1,485 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Renko and range bar aggregators, which close bars on price movement rather than time
+//! or activity, building them from trade ticks.
+//!
+//! When a single trade gaps across more than one bar, the bars between the last close and the
+//! trade are synthesized on the price grid with zero volume, all sharing the `ts_event` of the
+//! trade. These are flagged with `AdaptiveBar::is_synthetic`. The size of each trade is counted
+//! in the bar its price falls in, so a trade which closes a bar is counted in the next one.
+
+use anyhow::ensure;
+
+use super::{
+    bar::{Bar, BarType},
+    trade::TradeTick,
+};
+use crate::{
+    enums::BarAggregation,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// A bar emitted by a `RenkoBarAggregator` or `RangeBarAggregator`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AdaptiveBar {
+    pub bar: Bar,
+    /// Whether the bar was synthesized to fill a price gap rather than formed from trades.
+    pub is_synthetic: bool,
+}
+
+/// Returns the raw price size of one step of the given `bar_type`.
+fn step_size(
+    bar_type: &BarType,
+    aggregation: BarAggregation,
+    price_increment: Price,
+) -> anyhow::Result<Price> {
+    ensure!(
+        bar_type.spec.aggregation == aggregation,
+        "Invalid bar aggregation {} for {aggregation} bars",
+        bar_type.spec.aggregation,
+    );
+    ensure!(
+        bar_type.spec.step > 0,
+        "Invalid step {} for {aggregation} bars",
+        bar_type.spec.step,
+    );
+    ensure!(
+        price_increment.raw > 0,
+        "Invalid price increment {price_increment}",
+    );
+    Ok(Price {
+        raw: price_increment.raw * bar_type.spec.step as i64,
+        precision: price_increment.precision,
+    })
+}
+
+/// Builds fixed size price bricks from trade ticks.
+///
+/// A brick is closed each time the price moves a full brick size away from the close of the
+/// previous brick (or the first trade), in either direction. The brick size is the bar step
+/// in ticks of the instrument price increment.
+#[derive(Clone, Debug)]
+pub struct RenkoBarAggregator {
+    pub bar_type: BarType,
+    pub brick_size: Price,
+    pub size_precision: u8,
+    anchor: Option<i64>,
+    high: i64,
+    low: i64,
+    volume: Quantity,
+}
+
+impl RenkoBarAggregator {
+    pub fn new(
+        bar_type: BarType,
+        price_increment: Price,
+        size_precision: u8,
+    ) -> anyhow::Result<Self> {
+        let brick_size = step_size(&bar_type, BarAggregation::Renko, price_increment)?;
+
+        Ok(Self {
+            bar_type,
+            brick_size,
+            size_precision,
+            anchor: None,
+            high: 0,
+            low: 0,
+            volume: Quantity::zero(size_precision),
+        })
+    }
+
+    /// Handles the given `trade`, returning any bricks it completes in order.
+    pub fn handle_trade(&mut self, trade: &TradeTick) -> Vec<AdaptiveBar> {
+        let price = trade.price.raw;
+        let Some(anchor) = self.anchor else {
+            self.anchor = Some(price);
+            self.start_brick(trade);
+            return Vec::new();
+        };
+
+        let brick = self.brick_size.raw;
+        let count = (price - anchor).abs() / brick;
+        if count == 0 {
+            self.high = self.high.max(price);
+            self.low = self.low.min(price);
+            self.volume += trade.size;
+            return Vec::new();
+        }
+
+        let step = brick * (price - anchor).signum();
+        let zero = Quantity::zero(self.size_precision);
+        let bars = (0..count)
+            .map(|i| {
+                let open = anchor + step * i;
+                let close = open + step;
+                let (high, low, volume) = if i == 0 {
+                    (self.high.max(open), self.low.min(open), self.volume)
+                } else {
+                    (open, open, zero)
+                };
+                AdaptiveBar {
+                    bar: self.bar(open, high.max(close), low.min(close), close, volume, trade),
+                    is_synthetic: i > 0,
+                }
+            })
+            .collect();
+
+        self.anchor = Some(anchor + step * count);
+        self.start_brick(trade);
+        bars
+    }
+
+    /// Resets the aggregator, discarding the partial brick and the previous close.
+    pub fn reset(&mut self) {
+        self.anchor = None;
+        self.volume = Quantity::zero(self.size_precision);
+    }
+
+    fn start_brick(&mut self, trade: &TradeTick) {
+        self.high = trade.price.raw;
+        self.low = trade.price.raw;
+        self.volume = trade.size;
+    }
+
+    fn bar(
+        &self,
+        open: i64,
+        high: i64,
+        low: i64,
+        close: i64,
+        volume: Quantity,
+        trade: &TradeTick,
+    ) -> Bar {
+        build_bar(
+            self.bar_type,
+            self.brick_size.precision,
+            [open, high, low, close],
+            volume,
+            trade,
+        )
+    }
+}
+
+/// A range bar being built from trades.
+#[derive(Clone, Copy, Debug)]
+struct PartialRangeBar {
+    open: i64,
+    high: i64,
+    low: i64,
+    volume: Quantity,
+}
+
+/// Builds bars of a fixed high-low price range from trade ticks.
+///
+/// A bar is closed when a trade would extend its range beyond the range size, with the bar
+/// capped at exactly the range size and the next bar opening at its close. The range size is
+/// the bar step in ticks of the instrument price increment.
+#[derive(Clone, Debug)]
+pub struct RangeBarAggregator {
+    pub bar_type: BarType,
+    pub range_size: Price,
+    pub size_precision: u8,
+    current: Option<PartialRangeBar>,
+}
+
+impl RangeBarAggregator {
+    pub fn new(
+        bar_type: BarType,
+        price_increment: Price,
+        size_precision: u8,
+    ) -> anyhow::Result<Self> {
+        let range_size = step_size(&bar_type, BarAggregation::Range, price_increment)?;
+
+        Ok(Self {
+            bar_type,
+            range_size,
+            size_precision,
+            current: None,
+        })
+    }
+
+    /// Handles the given `trade`, returning any bars it completes in order.
+    pub fn handle_trade(&mut self, trade: &TradeTick) -> Vec<AdaptiveBar> {
+        let price = trade.price.raw;
+        let Some(current) = self.current.as_mut() else {
+            self.current = Some(PartialRangeBar {
+                open: price,
+                high: price,
+                low: price,
+                volume: trade.size,
+            });
+            return Vec::new();
+        };
+
+        let range = self.range_size.raw;
+        let (close, step) = if price > current.low + range {
+            (current.low + range, range)
+        } else if price < current.high - range {
+            (current.high - range, -range)
+        } else {
+            current.high = current.high.max(price);
+            current.low = current.low.min(price);
+            current.volume += trade.size;
+            return Vec::new();
+        };
+
+        let completed = *current;
+        let mut bars = vec![AdaptiveBar {
+            bar: self.bar(
+                completed.open,
+                completed.high.max(close),
+                completed.low.min(close),
+                close,
+                completed.volume,
+                trade,
+            ),
+            is_synthetic: false,
+        }];
+
+        let zero = Quantity::zero(self.size_precision);
+        let mut level = close;
+        while (price - level).abs() > range {
+            let next = level + step;
+            bars.push(AdaptiveBar {
+                bar: self.bar(level, level.max(next), level.min(next), next, zero, trade),
+                is_synthetic: true,
+            });
+            level = next;
+        }
+
+        self.current = Some(PartialRangeBar {
+            open: level,
+            high: level.max(price),
+            low: level.min(price),
+            volume: trade.size,
+        });
+        bars
+    }
+
+    /// Resets the aggregator, discarding the partial bar.
+    pub fn reset(&mut self) {
+        self.current = None;
+    }
+
+    fn bar(
+        &self,
+        open: i64,
+        high: i64,
+        low: i64,
+        close: i64,
+        volume: Quantity,
+        trade: &TradeTick,
+    ) -> Bar {
+        build_bar(
+            self.bar_type,
+            self.range_size.precision,
+            [open, high, low, close],
+            volume,
+            trade,
+        )
+    }
+}
+
+fn build_bar(
+    bar_type: BarType,
+    precision: u8,
+    ohlc: [i64; 4],
+    volume: Quantity,
+    trade: &TradeTick,
+) -> Bar {
+    let [open, high, low, close] = ohlc.map(|raw| Price { raw, precision });
+    Bar::new(
+        bar_type,
+        open,
+        high,
+        low,
+        close,
+        volume,
+        trade.ts_event,
+        trade.ts_init,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::bar::BarSpecification,
+        enums::{AggregationSource, AggressorSide, PriceType},
+        identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    };
+
+    /// The expected open, high, low, close, volume, `ts_event` and synthetic flag of a bar.
+    type ExpectedBar = (
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        u64,
+        bool,
+    );
+
+    fn bar_type(aggregation: BarAggregation, step: usize) -> BarType {
+        BarType::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            BarSpecification::new(step, aggregation, PriceType::Last),
+            AggregationSource::Internal,
+        )
+    }
+
+    fn trade(price: &str, size: &str, ts_event: u64) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            UnixNanos::from(ts_event),
+            UnixNanos::from(ts_event),
+        )
+        .unwrap()
+    }
+
+    /// A volatile path, with gaps spanning two bars in each direction.
+    fn trades() -> Vec<TradeTick> {
+        [
+            ("100.00", "1"),
+            ("100.40", "2"),
+            ("99.70", "1"),
+            ("101.00", "1"),
+            ("101.50", "1"),
+            ("103.20", "3"),
+            ("102.10", "1"),
+            ("101.90", "1"),
+            ("99.80", "2"),
+        ]
+        .iter()
+        .zip(1..)
+        .map(|((price, size), ts)| trade(price, size, ts))
+        .collect()
+    }
+
+    fn assert_bars(actual: &[AdaptiveBar], expected: &[ExpectedBar]) {
+        let actual: Vec<_> = actual
+            .iter()
+            .map(|b| {
+                let bar = b.bar;
+                let ohlc = [bar.open, bar.high, bar.low, bar.close];
+                (ohlc, bar.volume, bar.ts_event.as_u64(), b.is_synthetic)
+            })
+            .collect();
+        let expected: Vec<_> = expected
+            .iter()
+            .map(
+                |&(open, high, low, close, volume, ts_event, is_synthetic)| {
+                    let ohlc = [open, high, low, close].map(Price::from);
+                    (ohlc, Quantity::from(volume), ts_event, is_synthetic)
+                },
+            )
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(BarAggregation::Renko, 0)]
+    #[case(BarAggregation::Range, 100)]
+    #[case(BarAggregation::Minute, 100)]
+    fn test_renko_new_with_invalid_bar_type(
+        #[case] aggregation: BarAggregation,
+        #[case] step: usize,
+    ) {
+        let result = RenkoBarAggregator::new(bar_type(aggregation, step), Price::from("0.01"), 0);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_range_new_with_invalid_bar_type() {
+        let result =
+            RangeBarAggregator::new(bar_type(BarAggregation::Renko, 100), Price::from("0.01"), 0);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_renko_bricks_for_volatile_path() {
+        let mut aggregator =
+            RenkoBarAggregator::new(bar_type(BarAggregation::Renko, 100), Price::from("0.01"), 0)
+                .unwrap();
+        assert_eq!(aggregator.brick_size, Price::from("1.00"));
+
+        let bars: Vec<AdaptiveBar> = trades()
+            .iter()
+            .flat_map(|trade| aggregator.handle_trade(trade))
+            .collect();
+
+        assert_bars(
+            &bars,
+            &[
+                ("100.00", "101.00", "99.70", "101.00", "4", 4, false),
+                ("101.00", "102.00", "101.00", "102.00", "2", 6, false),
+                ("102.00", "103.00", "102.00", "103.00", "0", 6, true),
+                ("103.00", "103.20", "102.00", "102.00", "4", 8, false),
+                ("102.00", "102.00", "101.00", "101.00", "1", 9, false),
+                ("101.00", "101.00", "100.00", "100.00", "0", 9, true),
+            ],
+        );
+    }
+
+    #[rstest]
+    fn test_range_bars_for_volatile_path() {
+        let mut aggregator =
+            RangeBarAggregator::new(bar_type(BarAggregation::Range, 100), Price::from("0.01"), 0)
+                .unwrap();
+        assert_eq!(aggregator.range_size, Price::from("1.00"));
+
+        let bars: Vec<AdaptiveBar> = trades()
+            .iter()
+            .flat_map(|trade| aggregator.handle_trade(trade))
+            .collect();
+
+        // 101.00 extends the range 1.30 beyond the 99.70 low, capping the bar at 100.70,
+        // while 103.20 and 99.80 gap far enough past their caps to need a synthetic bar
+        assert_bars(
+            &bars,
+            &[
+                ("100.00", "100.70", "99.70", "100.70", "4", 4, false),
+                ("100.70", "101.70", "100.70", "101.70", "2", 6, false),
+                ("101.70", "102.70", "101.70", "102.70", "0", 6, true),
+                ("102.70", "103.20", "102.20", "102.20", "3", 7, false),
+                ("102.20", "102.20", "101.20", "101.20", "2", 9, false),
+                ("101.20", "101.20", "100.20", "100.20", "0", 9, true),
+            ],
+        );
+    }
+
+    #[rstest]
+    fn test_reset_discards_partial_bars() {
+        let bar_type = bar_type(BarAggregation::Renko, 100);
+        let mut aggregator = RenkoBarAggregator::new(bar_type, Price::from("0.01"), 0).unwrap();
+        aggregator.handle_trade(&trade("100.00", "1", 1));
+        aggregator.reset();
+
+        // The next trade becomes the new anchor
+        assert!(aggregator.handle_trade(&trade("105.00", "1", 2)).is_empty());
+        assert_eq!(aggregator.handle_trade(&trade("106.00", "1", 3)).len(), 1);
+    }
+}
@@ -26,6 +26,7 @@ use derive_builder::Builder;
 use indexmap::IndexMap;
 use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use strum::IntoEnumIterator;
 
 use crate::{
     enums::{AggregationSource, BarAggregation, PriceType},
@@ -37,7 +38,7 @@ use crate::{
 /// Represents a bar aggregation specification including a step, aggregation
 /// method/rule and price type.
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize, Builder)]
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug, Builder)]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -63,12 +64,62 @@ impl BarSpecification {
     }
 }
 
+impl FromStr for BarSpecification {
+    type Err = BarTypeParseError;
+
+    /// Parses a bar specification from the canonical `{step}-{aggregation}-{price_type}` form,
+    /// such as `1-MINUTE-BID`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split('-').collect();
+        let [step, aggregation, price_type] = tokens[..] else {
+            return Err(BarTypeParseError::Segments {
+                type_name: stringify!(BarSpecification),
+                input: s.to_string(),
+                format: BAR_SPECIFICATION_FORMAT,
+                expected: 3,
+                segments: tokens.len(),
+            });
+        };
+
+        let parser = TokenParser {
+            type_name: stringify!(BarSpecification),
+            input: s,
+        };
+        parser.parse_spec([step, aggregation, price_type], 0)
+    }
+}
+
+impl From<&str> for BarSpecification {
+    fn from(input: &str) -> Self {
+        Self::from_str(input).unwrap()
+    }
+}
+
 impl Display for BarSpecification {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}-{}-{}", self.step, self.aggregation, self.price_type)
     }
 }
 
+impl Serialize for BarSpecification {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BarSpecification {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 /// Represents a bar type including the instrument ID, bar specification and
 /// aggregation source.
 #[repr(C)]
@@ -101,67 +152,120 @@ impl BarType {
     }
 }
 
-#[derive(thiserror::Error, Debug)]
-#[error("Error parsing `BarType` from '{input}', invalid token: '{token}' at position {position}")]
-pub struct BarTypeParseError {
-    input: String,
-    token: String,
-    position: usize,
+const BAR_SPECIFICATION_FORMAT: &str = "{step}-{aggregation}-{price_type}";
+const BAR_TYPE_FORMAT: &str =
+    "{instrument_id}-{step}-{aggregation}-{price_type}-{aggregation_source}";
+
+/// An error from parsing a [`BarType`] or [`BarSpecification`] from its canonical string form.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum BarTypeParseError {
+    #[error("Error parsing `{type_name}` from '{input}', expected {expected} '-' separated segments `{format}`, was {segments}")]
+    Segments {
+        type_name: &'static str,
+        input: String,
+        format: &'static str,
+        expected: usize,
+        segments: usize,
+    },
+    #[error("Error parsing `{type_name}` from '{input}', invalid token: '{token}' at position {position}, expected {expected}")]
+    InvalidToken {
+        type_name: &'static str,
+        input: String,
+        token: String,
+        position: usize,
+        expected: String,
+    },
+}
+
+/// Parses the tokens of a bar type string, tracking the input for error messages.
+struct TokenParser<'a> {
+    type_name: &'static str,
+    input: &'a str,
+}
+
+impl TokenParser<'_> {
+    fn invalid(&self, token: &str, position: usize, expected: String) -> BarTypeParseError {
+        BarTypeParseError::InvalidToken {
+            type_name: self.type_name,
+            input: self.input.to_string(),
+            token: token.to_string(),
+            position,
+            expected,
+        }
+    }
+
+    /// Parses an enum variant from its uppercase name, as enums parse case-insensitively.
+    fn parse_enum<E>(&self, token: &str, position: usize) -> Result<E, BarTypeParseError>
+    where
+        E: FromStr + IntoEnumIterator + Display,
+    {
+        match E::from_str(token) {
+            Ok(value) if value.to_string() == token => Ok(value),
+            _ => {
+                let names: Vec<String> = E::iter().map(|value| value.to_string()).collect();
+                Err(self.invalid(token, position, format!("one of {}", names.join(", "))))
+            }
+        }
+    }
+
+    fn parse_spec(
+        &self,
+        [step, aggregation, price_type]: [&str; 3],
+        offset: usize,
+    ) -> Result<BarSpecification, BarTypeParseError> {
+        let step = match step.parse::<usize>() {
+            Ok(value) if value > 0 && value.to_string() == step => value,
+            _ => {
+                return Err(self.invalid(step, offset, "a positive integer step".to_string()));
+            }
+        };
+
+        Ok(BarSpecification {
+            step,
+            aggregation: self.parse_enum(aggregation, offset + 1)?,
+            price_type: self.parse_enum(price_type, offset + 2)?,
+        })
+    }
 }
 
 impl FromStr for BarType {
     type Err = BarTypeParseError;
 
+    /// Parses a bar type from the canonical
+    /// `{instrument_id}-{step}-{aggregation}-{price_type}-{aggregation_source}` form, such as
+    /// `AUD/USD.SIM-1-MINUTE-BID-INTERNAL`.
+    ///
+    /// The instrument ID may itself contain '-', so the string is split from the right.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // TODO: Requires handling some trait related thing
-        #[allow(clippy::needless_collect)]
-        let pieces: Vec<&str> = s.rsplitn(5, '-').collect();
-        let rev_pieces: Vec<&str> = pieces.into_iter().rev().collect();
-        if rev_pieces.len() != 5 {
-            return Err(BarTypeParseError {
+        let mut tokens: Vec<&str> = s.rsplitn(5, '-').collect();
+        tokens.reverse();
+        let [instrument_id, step, aggregation, price_type, aggregation_source] = tokens[..] else {
+            return Err(BarTypeParseError::Segments {
+                type_name: stringify!(BarType),
                 input: s.to_string(),
-                token: String::new(),
-                position: 0,
+                format: BAR_TYPE_FORMAT,
+                expected: 5,
+                segments: tokens.len(),
             });
-        }
+        };
 
-        let instrument_id =
-            InstrumentId::from_str(rev_pieces[0]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
-                token: rev_pieces[0].to_string(),
-                position: 0,
-            })?;
-
-        let step = rev_pieces[1].parse().map_err(|_| BarTypeParseError {
-            input: s.to_string(),
-            token: rev_pieces[1].to_string(),
-            position: 1,
-        })?;
-        let aggregation =
-            BarAggregation::from_str(rev_pieces[2]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
-                token: rev_pieces[2].to_string(),
-                position: 2,
-            })?;
-        let price_type = PriceType::from_str(rev_pieces[3]).map_err(|_| BarTypeParseError {
-            input: s.to_string(),
-            token: rev_pieces[3].to_string(),
-            position: 3,
+        let parser = TokenParser {
+            type_name: stringify!(BarType),
+            input: s,
+        };
+        let instrument_id = InstrumentId::from_str(instrument_id).map_err(|_| {
+            parser.invalid(
+                instrument_id,
+                0,
+                "an instrument ID `{symbol}.{venue}`".to_string(),
+            )
         })?;
-        let aggregation_source =
-            AggregationSource::from_str(rev_pieces[4]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
-                token: rev_pieces[4].to_string(),
-                position: 4,
-            })?;
+        let spec = parser.parse_spec([step, aggregation, price_type], 1)?;
+        let aggregation_source = parser.parse_enum(aggregation_source, 4)?;
 
         Ok(Self {
             instrument_id,
-            spec: BarSpecification {
-                step,
-                aggregation,
-                price_type,
-            },
+            spec,
             aggregation_source,
         })
     }
@@ -349,66 +453,147 @@ mod tests {
     }
 
     #[rstest]
-    fn test_bar_type_parse_invalid_token_pos_0() {
-        let input = "BTCUSDT-PERP-1-MINUTE-LAST-INTERNAL";
+    #[case(
+        "AUD/USD.-0-0-0-0",
+        "Error parsing `BarType` from 'AUD/USD.-0-0-0-0', invalid token: 'AUD/USD.' at position 0, expected an instrument ID `{symbol}.{venue}`"
+    )]
+    #[case(
+        "AUD/USD.SIM-a-MINUTE-BID-INTERNAL",
+        "Error parsing `BarType` from 'AUD/USD.SIM-a-MINUTE-BID-INTERNAL', invalid token: 'a' at position 1, expected a positive integer step"
+    )]
+    #[case(
+        "AUD/USD.SIM-1-MINUTE-BID",
+        "Error parsing `BarType` from 'AUD/USD.SIM-1-MINUTE-BID', expected 5 '-' separated segments `{instrument_id}-{step}-{aggregation}-{price_type}-{aggregation_source}`, was 4"
+    )]
+    #[case(
+        "AUD/USD.SIM-1-MINUTE-MID-a",
+        "Error parsing `BarType` from 'AUD/USD.SIM-1-MINUTE-MID-a', invalid token: 'a' at position 4, expected one of EXTERNAL, INTERNAL"
+    )]
+    fn test_bar_type_parse_error_messages(#[case] input: &str, #[case] expected: &str) {
         let result = BarType::from_str(input);
-
-        assert_eq!(
-            result.unwrap_err().to_string(),
-            format!("Error parsing `BarType` from '{input}', invalid token: 'BTCUSDT-PERP' at position 0")
-        );
+        assert_eq!(result.unwrap_err().to_string(), expected);
     }
 
     #[rstest]
-    fn test_bar_type_parse_invalid_token_pos_1() {
-        let input = "BTCUSDT-PERP.BINANCE-INVALID-MINUTE-LAST-INTERNAL";
-        let result = BarType::from_str(input);
-
-        assert_eq!(
-            result.unwrap_err().to_string(),
-            format!(
-                "Error parsing `BarType` from '{input}', invalid token: 'INVALID' at position 1"
-            )
-        );
+    #[case("", 0)] // Empty
+    #[case("AUD/USD.SIM", 0)] // Missing all specification segments
+    #[case("AUD/USD.SIM-1-MINUTE-BID", 0)] // Missing aggregation source
+    #[case("AUD/USD.SIM-1-MINUTE-INTERNAL", 0)] // Missing price type
+    #[case("-1-MINUTE-BID-INTERNAL", 0)] // Empty instrument ID
+    #[case("AUD/USD-1-MINUTE-BID-INTERNAL", 0)] // Missing venue
+    #[case("AUD/USD.SIM--MINUTE-BID-INTERNAL", 1)] // Empty step
+    #[case("AUD/USD.SIM-0-MINUTE-BID-INTERNAL", 1)] // Zero step
+    #[case("AUD/USD.SIM-01-MINUTE-BID-INTERNAL", 1)] // Non-canonical step
+    #[case("AUD/USD.SIM-1.5-MINUTE-BID-INTERNAL", 1)] // Fractional step
+    #[case("AUD/USD.SIM-1-MINUTES-BID-INTERNAL", 2)] // Bad aggregation
+    #[case("AUD/USD.SIM-1-minute-BID-INTERNAL", 2)] // Lowercase aggregation
+    #[case("AUD/USD.SIM-1-Minute-BID-INTERNAL", 2)] // Mixed case aggregation
+    #[case("AUD/USD.SIM-1-MINUTE-BIDS-INTERNAL", 3)] // Bad price type
+    #[case("AUD/USD.SIM-1-MINUTE-bid-INTERNAL", 3)] // Lowercase price type
+    #[case("AUD/USD.SIM-1-MINUTE-BID-internal", 4)] // Lowercase aggregation source
+    #[case("AUD/USD.SIM-1-MINUTE-BID-INTERNAL ", 4)] // Trailing whitespace
+    fn test_bar_type_parse_malformed(#[case] input: &str, #[case] position: usize) {
+        match BarType::from_str(input).unwrap_err() {
+            BarTypeParseError::Segments { segments, .. } => {
+                assert_eq!(position, 0);
+                assert!(segments < 5);
+            }
+            BarTypeParseError::InvalidToken {
+                position: actual, ..
+            } => assert_eq!(actual, position, "{input}"),
+        }
     }
 
     #[rstest]
-    fn test_bar_type_parse_invalid_token_pos_2() {
-        let input = "BTCUSDT-PERP.BINANCE-1-INVALID-LAST-INTERNAL";
-        let result = BarType::from_str(input);
-
+    #[case("1-MINUTE-BID")]
+    #[case("100-TICK-LAST")]
+    #[case("1000-VALUE_IMBALANCE-MID")]
+    #[case("5-MILLISECOND-ASK")]
+    fn test_bar_spec_string_round_trip(#[case] input: &str) {
+        let spec = BarSpecification::from_str(input).unwrap();
+
+        assert_eq!(spec.to_string(), input);
         assert_eq!(
-            result.unwrap_err().to_string(),
-            format!(
-                "Error parsing `BarType` from '{input}', invalid token: 'INVALID' at position 2"
-            )
+            serde_json::to_string(&spec).unwrap(),
+            format!("\"{input}\"")
+        );
+        assert_eq!(
+            serde_json::from_str::<BarSpecification>(&format!("\"{input}\"")).unwrap(),
+            spec
         );
     }
 
     #[rstest]
-    fn test_bar_type_parse_invalid_token_pos_3() {
-        let input = "BTCUSDT-PERP.BINANCE-1-MINUTE-INVALID-INTERNAL";
-        let result = BarType::from_str(input);
+    #[case(
+        "1-MINUTE",
+        "expected 3 '-' separated segments `{step}-{aggregation}-{price_type}`, was 2"
+    )]
+    #[case(
+        "1-MINUTE-BID-INTERNAL",
+        "expected 3 '-' separated segments `{step}-{aggregation}-{price_type}`, was 4"
+    )]
+    #[case(
+        "x-MINUTE-BID",
+        "invalid token: 'x' at position 0, expected a positive integer step"
+    )]
+    #[case("1-minute-BID", "invalid token: 'minute' at position 1")]
+    #[case(
+        "1-MINUTE-LAST_TRADE",
+        "invalid token: 'LAST_TRADE' at position 2, expected one of BID, ASK, MID, LAST"
+    )]
+    fn test_bar_spec_parse_malformed(#[case] input: &str, #[case] expected: &str) {
+        let message = BarSpecification::from_str(input).unwrap_err().to_string();
+
+        assert!(message.starts_with(&format!("Error parsing `BarSpecification` from '{input}'")));
+        assert!(message.contains(expected), "{message}");
+    }
 
-        assert_eq!(
-            result.unwrap_err().to_string(),
-            format!(
-                "Error parsing `BarType` from '{input}', invalid token: 'INVALID' at position 3"
-            )
+    #[rstest]
+    fn test_bar_type_serde_round_trip() {
+        let bar_type = BarType::from("BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL");
+        let json = serde_json::to_string(&bar_type).unwrap();
+
+        assert_eq!(json, "\"BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL\"");
+        assert_eq!(serde_json::from_str::<BarType>(&json).unwrap(), bar_type);
+        assert!(
+            serde_json::from_str::<BarType>("\"BTCUSDT-PERP.BINANCE-1-minute-LAST-EXTERNAL\"")
+                .is_err()
         );
     }
 
     #[rstest]
-    fn test_bar_type_parse_invalid_token_pos_4() {
-        let input = "BTCUSDT-PERP.BINANCE-1-MINUTE-BID-INVALID";
-        let result = BarType::from_str(input);
-
-        assert!(result.is_err());
+    fn test_bar_type_sort_is_deterministic() {
+        let mut bar_types: Vec<BarType> = [
+            "GBP/USD.SIM-1-MINUTE-BID-EXTERNAL",
+            "AUD/USD.SIM-5-MINUTE-BID-EXTERNAL",
+            "AUD/USD.SIM-1-MINUTE-ASK-EXTERNAL",
+            "AUD/USD.SIM-1-MINUTE-BID-INTERNAL",
+            "AUD/USD.SIM-1-MINUTE-BID-EXTERNAL",
+            "AUD/USD.SIM-1-SECOND-BID-EXTERNAL",
+        ]
+        .into_iter()
+        .map(BarType::from)
+        .collect();
+        let mut reversed = bar_types.clone();
+        reversed.reverse();
+
+        bar_types.sort();
+        reversed.sort();
+
+        assert_eq!(bar_types, reversed);
         assert_eq!(
-            result.unwrap_err().to_string(),
-            format!(
-                "Error parsing `BarType` from '{input}', invalid token: 'INVALID' at position 4"
-            )
+            bar_types
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "AUD/USD.SIM-1-SECOND-BID-EXTERNAL",
+                "AUD/USD.SIM-1-MINUTE-BID-EXTERNAL",
+                "AUD/USD.SIM-1-MINUTE-BID-INTERNAL",
+                "AUD/USD.SIM-1-MINUTE-ASK-EXTERNAL",
+                "AUD/USD.SIM-5-MINUTE-BID-EXTERNAL",
+                "GBP/USD.SIM-1-MINUTE-BID-EXTERNAL",
+            ]
         );
     }
 
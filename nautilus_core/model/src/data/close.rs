@@ -0,0 +1,166 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An `InstrumentClose` data type representing an official close price for an instrument.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::InstrumentCloseType, identifiers::instrument_id::InstrumentId, polymorphism::GetTsInit,
+    types::price::Price,
+};
+
+/// Represents an official close price for an instrument, such as a daily settlement price or
+/// the final settlement price at contract expiry (distinct from the last traded price).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct InstrumentClose {
+    /// The instrument ID for the close.
+    pub instrument_id: InstrumentId,
+    /// The close price for the instrument.
+    pub close_price: Price,
+    /// The type of the close.
+    pub close_type: InstrumentCloseType,
+    /// The UNIX timestamp (nanoseconds) when the close event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl InstrumentClose {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        close_price: Price,
+        close_type: InstrumentCloseType,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            close_price,
+            close_type,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("close_price".to_string(), "Int64".to_string());
+        metadata.insert("close_type".to_string(), "UInt8".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for InstrumentClose {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.close_price, self.close_type, self.ts_event,
+        )
+    }
+}
+
+impl Serializable for InstrumentClose {}
+
+impl GetTsInit for InstrumentClose {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use crate::{
+        data::{close::InstrumentClose, stubs::stub_instrument_close},
+        enums::InstrumentCloseType,
+    };
+
+    #[rstest]
+    fn test_to_string(stub_instrument_close: InstrumentClose) {
+        assert_eq!(
+            stub_instrument_close.to_string(),
+            "ESZ4.GLBX,5000.25,CONTRACT_EXPIRED,1"
+        );
+    }
+
+    #[rstest]
+    fn test_deserialize_raw_string() {
+        let raw_string = r#"{
+            "type": "InstrumentClose",
+            "instrument_id": "ESZ4.GLBX",
+            "close_price": "5000.25",
+            "close_type": "END_OF_SESSION",
+            "ts_event": 0,
+            "ts_init": 1
+        }"#;
+
+        let close: InstrumentClose = serde_json::from_str(raw_string).unwrap();
+
+        assert_eq!(close.close_type, InstrumentCloseType::EndOfSession);
+        assert_eq!(close.close_price.to_string(), "5000.25");
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_instrument_close: InstrumentClose) {
+        let serialized = stub_instrument_close.as_json_bytes().unwrap();
+        let deserialized = InstrumentClose::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, stub_instrument_close);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization(stub_instrument_close: InstrumentClose) {
+        let serialized = stub_instrument_close.as_msgpack_bytes().unwrap();
+        let deserialized = InstrumentClose::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, stub_instrument_close);
+    }
+}
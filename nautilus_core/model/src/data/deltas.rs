@@ -21,15 +21,22 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use nautilus_core::nanos::UnixNanos;
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
+use serde::{Deserialize, Serialize};
 
 use super::delta::OrderBookDelta;
-use crate::{identifiers::instrument_id::InstrumentId, polymorphism::GetTsInit};
+use crate::{
+    enums::RecordFlag,
+    identifiers::instrument_id::InstrumentId,
+    orderbook::{book::OrderBook, coalesce::apply_coalesced},
+    polymorphism::GetTsInit,
+};
 
 /// Represents a grouped batch of `OrderBookDelta` updates for an `OrderBook`.
 ///
 /// This type cannot be `repr(C)` due to the `deltas` vec.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", try_from = "OrderBookDeltasRepr")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -49,12 +56,48 @@ pub struct OrderBookDeltas {
     pub ts_init: UnixNanos,
 }
 
+/// The serde representation of [`OrderBookDeltas`].
+///
+/// Deserialization goes through this struct so the deltas are checked with [`check_deltas`].
+#[derive(Deserialize)]
+struct OrderBookDeltasRepr {
+    instrument_id: InstrumentId,
+    deltas: Vec<OrderBookDelta>,
+    flags: u8,
+    sequence: u64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+}
+
+impl TryFrom<OrderBookDeltasRepr> for OrderBookDeltas {
+    type Error = anyhow::Error;
+
+    fn try_from(repr: OrderBookDeltasRepr) -> anyhow::Result<Self> {
+        check_deltas(repr.instrument_id, &repr.deltas)?;
+        Ok(Self {
+            instrument_id: repr.instrument_id,
+            deltas: repr.deltas,
+            flags: repr.flags,
+            sequence: repr.sequence,
+            ts_event: repr.ts_event,
+            ts_init: repr.ts_init,
+        })
+    }
+}
+
 impl OrderBookDeltas {
-    #[allow(clippy::too_many_arguments)]
+    /// Creates a new [`OrderBookDeltas`] instance, taking the flags, sequence and timestamps
+    /// from the last delta.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `deltas` is empty or any delta is for another instrument.
     #[must_use]
     pub fn new(instrument_id: InstrumentId, deltas: Vec<OrderBookDelta>) -> Self {
-        assert!(!deltas.is_empty(), "`deltas` cannot be empty");
-        // SAFETY: We asserted `deltas` is not empty
+        if let Err(e) = check_deltas(instrument_id, &deltas) {
+            panic!("{e}");
+        }
+        // SAFETY: We checked `deltas` is not empty
         let last = deltas.last().unwrap();
         let flags = last.flags;
         let sequence = last.sequence;
@@ -69,6 +112,27 @@ impl OrderBookDeltas {
             ts_init,
         }
     }
+
+    /// Returns whether the batch is (part of) a snapshot, i.e. the `F_SNAPSHOT` flag is set.
+    #[must_use]
+    pub fn is_snapshot(&self) -> bool {
+        RecordFlag::F_SNAPSHOT.matches(self.flags)
+    }
+
+    /// Returns whether the batch completes an event, i.e. the `F_LAST` flag is set.
+    #[must_use]
+    pub fn is_last(&self) -> bool {
+        RecordFlag::F_LAST.matches(self.flags)
+    }
+
+    /// Applies the deltas to the given `book`.
+    ///
+    /// Runs of updates to the same order (for MBP books, the same price level) are coalesced
+    /// so only their net effect is applied, where this is indistinguishable from applying
+    /// each delta in turn.
+    pub fn apply_to(&self, book: &mut OrderBook) {
+        apply_coalesced(book, &self.deltas);
+    }
 }
 
 impl PartialEq<Self> for OrderBookDeltas {
@@ -86,8 +150,7 @@ impl Hash for OrderBookDeltas {
     }
 }
 
-// TODO: Implement
-// impl Serializable for OrderBookDeltas {}
+impl Serializable for OrderBookDeltas {}
 
 // TODO: Exact format for Debug and Display TBD
 impl Display for OrderBookDeltas {
@@ -111,6 +174,26 @@ impl GetTsInit for OrderBookDeltas {
     }
 }
 
+/// Checks the `deltas` are not empty and are all for the given `instrument_id`.
+///
+/// # Errors
+///
+/// This function returns an error if `deltas` is empty or any delta is for another instrument.
+pub fn check_deltas(instrument_id: InstrumentId, deltas: &[OrderBookDelta]) -> anyhow::Result<()> {
+    anyhow::ensure!(!deltas.is_empty(), "`deltas` cannot be empty");
+    if let Some((index, delta)) = deltas
+        .iter()
+        .enumerate()
+        .find(|(_, delta)| delta.instrument_id != instrument_id)
+    {
+        anyhow::bail!(
+            "Instrument ID mismatch: delta {index} was for {}, expected {instrument_id}",
+            delta.instrument_id
+        );
+    }
+    Ok(())
+}
+
 /// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`OrderBookDeltas`].
 ///
 /// This struct wraps `OrderBookDeltas` in a way that makes it compatible with C function
@@ -150,6 +233,7 @@ impl DerefMut for OrderBookDeltas_API {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::serialization::Serializable;
     use rstest::rstest;
 
     use super::*;
@@ -276,4 +360,70 @@ mod tests {
             "AAPL.XNAS,len=7,flags=32,sequence=0,ts_event=1,ts_init=2".to_string()
         );
     }
+
+    #[rstest]
+    fn test_is_snapshot(stub_deltas: OrderBookDeltas) {
+        assert!(stub_deltas.is_snapshot());
+        assert!(!stub_deltas.is_last());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`deltas` cannot be empty")]
+    fn test_new_with_empty_deltas() {
+        let _ = OrderBookDeltas::new(InstrumentId::from("AAPL.XNAS"), vec![]);
+    }
+
+    #[rstest]
+    #[should_panic(
+        expected = "Instrument ID mismatch: delta 1 was for MSFT.XNAS, expected AAPL.XNAS"
+    )]
+    fn test_new_with_mismatched_instrument(stub_deltas: OrderBookDeltas) {
+        let mut deltas = stub_deltas.deltas;
+        deltas[1].instrument_id = InstrumentId::from("MSFT.XNAS");
+        let _ = OrderBookDeltas::new(InstrumentId::from("AAPL.XNAS"), deltas);
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_deltas: OrderBookDeltas) {
+        let serialized = stub_deltas.as_json_bytes().unwrap();
+        let deserialized = OrderBookDeltas::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, stub_deltas);
+        assert_eq!(deserialized.deltas, stub_deltas.deltas);
+        assert_eq!(deserialized.flags, stub_deltas.flags);
+    }
+
+    #[rstest]
+    fn test_json_deserialization_with_empty_deltas_returns_error(stub_deltas: OrderBookDeltas) {
+        let mut deltas = stub_deltas;
+        deltas.deltas.clear();
+        let serialized = deltas.as_json_bytes().unwrap();
+        let result = OrderBookDeltas::from_json_bytes(serialized);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("`deltas` cannot be empty"));
+    }
+
+    #[rstest]
+    fn test_json_deserialization_with_mismatched_instrument_returns_error(
+        stub_deltas: OrderBookDeltas,
+    ) {
+        let mut deltas = stub_deltas;
+        deltas.deltas[1].instrument_id = InstrumentId::from("MSFT.XNAS");
+        let serialized = deltas.as_json_bytes().unwrap();
+        let result = OrderBookDeltas::from_json_bytes(serialized);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Instrument ID mismatch: delta 1 was for MSFT.XNAS"));
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization(stub_deltas: OrderBookDeltas) {
+        let serialized = stub_deltas.as_msgpack_bytes().unwrap();
+        let deserialized = OrderBookDeltas::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized.deltas, stub_deltas.deltas);
+    }
 }
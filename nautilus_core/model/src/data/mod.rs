@@ -15,23 +15,31 @@
 
 //! Defines `Data` types for the trading domain model.
 
+pub mod adaptive_bar;
 pub mod bar;
+pub mod close;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
 pub mod order;
+pub mod order_flow;
 pub mod quote;
 #[cfg(feature = "stubs")]
 pub mod stubs;
 pub mod trade;
+pub mod volume_profile;
 
 use nautilus_core::nanos::UnixNanos;
+use thiserror::Error;
 
 use self::{
-    bar::Bar, delta::OrderBookDelta, deltas::OrderBookDeltas_API, depth::OrderBookDepth10,
-    quote::QuoteTick, trade::TradeTick,
+    bar::Bar, close::InstrumentClose, delta::OrderBookDelta, deltas::OrderBookDeltas_API,
+    depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
+};
+use crate::{
+    polymorphism::GetTsInit,
+    types::{price::Price, quantity::Quantity},
 };
-use crate::polymorphism::GetTsInit;
 
 /// A built-in Nautilus data type.
 ///
@@ -47,6 +55,7 @@ pub enum Data {
     Quote(QuoteTick),
     Trade(TradeTick),
     Bar(Bar),
+    Close(InstrumentClose),
 }
 
 impl GetTsInit for Data {
@@ -58,6 +67,53 @@ impl GetTsInit for Data {
             Self::Quote(q) => q.ts_init,
             Self::Trade(t) => t.ts_init,
             Self::Bar(b) => b.ts_init,
+            Self::Close(c) => c.ts_init,
+        }
+    }
+}
+
+/// An error from constructing a tick which violates its invariants.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum TickError {
+    #[error("Precision mismatch: `{left}` precision {left_precision} != `{right}` precision {right_precision}")]
+    PrecisionMismatch {
+        left: &'static str,
+        right: &'static str,
+        left_precision: u8,
+        right_precision: u8,
+    },
+    #[error("Crossed quote: bid price {bid_price} > ask price {ask_price}")]
+    CrossedQuote { bid_price: Price, ask_price: Price },
+    #[error("Invalid size: `{field}` was {size}, expected positive")]
+    NonPositiveSize { field: &'static str, size: Quantity },
+    #[error("Invalid trade ID: was empty")]
+    EmptyTradeId,
+}
+
+impl TickError {
+    pub(crate) fn check_precision(
+        left: &'static str,
+        right: &'static str,
+        left_precision: u8,
+        right_precision: u8,
+    ) -> Result<(), Self> {
+        if left_precision == right_precision {
+            Ok(())
+        } else {
+            Err(Self::PrecisionMismatch {
+                left,
+                right,
+                left_precision,
+                right_precision,
+            })
+        }
+    }
+
+    pub(crate) fn check_positive_size(field: &'static str, size: Quantity) -> Result<(), Self> {
+        if size.raw > 0 {
+            Ok(())
+        } else {
+            Err(Self::NonPositiveSize { field, size })
         }
     }
 }
@@ -103,6 +159,12 @@ impl From<Bar> for Data {
     }
 }
 
+impl From<InstrumentClose> for Data {
+    fn from(value: InstrumentClose) -> Self {
+        Self::Close(value)
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn data_clone(data: &Data) -> Data {
     data.clone()
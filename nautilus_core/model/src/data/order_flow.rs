@@ -0,0 +1,310 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An `OrderFlowImbalance` data type representing the net order flow for a time bucket,
+//! and an `OrderFlowAggregator` to build them from trade ticks.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{correctness::check_positive_u64, nanos::UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use super::trade::TradeTick;
+use crate::{
+    enums::AggressorSide, identifiers::instrument_id::InstrumentId, polymorphism::GetTsInit,
+    types::quantity::Quantity,
+};
+
+/// Represents the aggressor volumes and net order flow (delta) for a single time bucket.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub struct OrderFlowImbalance {
+    /// The instrument ID for the trades.
+    pub instrument_id: InstrumentId,
+    /// The volume traded with a buyer aggressor.
+    pub buy_volume: Quantity,
+    /// The volume traded with a seller aggressor.
+    pub sell_volume: Quantity,
+    /// The volume traded with no specific aggressor (not included in the delta).
+    pub no_aggressor_volume: Quantity,
+    /// The net order flow for the bucket (buy volume less sell volume).
+    pub delta: f64,
+    /// The running total of `delta` since the aggregator was started or reset.
+    pub cumulative_delta: f64,
+    /// The count of trades in the bucket.
+    pub trade_count: u64,
+    /// The UNIX timestamp (nanoseconds) for the start of the bucket.
+    pub ts_bucket: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the struct was initialized (the bucket close).
+    pub ts_init: UnixNanos,
+}
+
+impl OrderFlowImbalance {
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("size_precision".to_string(), size_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("buy_volume".to_string(), "UInt64".to_string());
+        metadata.insert("sell_volume".to_string(), "UInt64".to_string());
+        metadata.insert("no_aggressor_volume".to_string(), "UInt64".to_string());
+        metadata.insert("delta".to_string(), "Float64".to_string());
+        metadata.insert("cumulative_delta".to_string(), "Float64".to_string());
+        metadata.insert("trade_count".to_string(), "UInt64".to_string());
+        metadata.insert("ts_bucket".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for OrderFlowImbalance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{}",
+            self.instrument_id,
+            self.buy_volume,
+            self.sell_volume,
+            self.no_aggressor_volume,
+            self.delta,
+            self.cumulative_delta,
+            self.trade_count,
+            self.ts_bucket,
+        )
+    }
+}
+
+impl GetTsInit for OrderFlowImbalance {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Aggregates trade ticks into `OrderFlowImbalance` records per fixed time bucket.
+///
+/// Buckets are aligned to multiples of `bucket_ns` from the UNIX epoch, and only buckets
+/// containing at least one trade are emitted.
+#[derive(Clone, Debug)]
+pub struct OrderFlowAggregator {
+    pub instrument_id: InstrumentId,
+    pub bucket_ns: u64,
+    pub size_precision: u8,
+    current: Option<OrderFlowImbalance>,
+    cumulative_delta: f64,
+}
+
+impl OrderFlowAggregator {
+    pub fn new(
+        instrument_id: InstrumentId,
+        bucket_ns: u64,
+        size_precision: u8,
+    ) -> anyhow::Result<Self> {
+        check_positive_u64(bucket_ns, stringify!(bucket_ns))?;
+
+        Ok(Self {
+            instrument_id,
+            bucket_ns,
+            size_precision,
+            current: None,
+            cumulative_delta: 0.0,
+        })
+    }
+
+    /// Handles the given `trade`, returning the previous bucket if the trade starts a new one.
+    ///
+    /// Trades with a `ts_event` before the current bucket are included in the current bucket.
+    pub fn handle_trade(&mut self, trade: &TradeTick) -> Option<OrderFlowImbalance> {
+        let ts_bucket = trade.ts_event.as_u64() - trade.ts_event.as_u64() % self.bucket_ns;
+
+        let completed = match self.current {
+            Some(current) if ts_bucket > current.ts_bucket.as_u64() => self.flush(),
+            _ => None,
+        };
+
+        let zero = Quantity::zero(self.size_precision);
+        let bucket = self.current.get_or_insert(OrderFlowImbalance {
+            instrument_id: self.instrument_id,
+            buy_volume: zero,
+            sell_volume: zero,
+            no_aggressor_volume: zero,
+            delta: 0.0,
+            cumulative_delta: self.cumulative_delta,
+            trade_count: 0,
+            ts_bucket: UnixNanos::from(ts_bucket),
+            ts_init: UnixNanos::from(ts_bucket + self.bucket_ns),
+        });
+
+        match trade.aggressor_side {
+            AggressorSide::Buyer => {
+                bucket.buy_volume += trade.size;
+                bucket.delta += trade.size.as_f64();
+            }
+            AggressorSide::Seller => {
+                bucket.sell_volume += trade.size;
+                bucket.delta -= trade.size.as_f64();
+            }
+            AggressorSide::NoAggressor => {
+                bucket.no_aggressor_volume += trade.size;
+            }
+        }
+        bucket.cumulative_delta = self.cumulative_delta + bucket.delta;
+        bucket.trade_count += 1;
+
+        completed
+    }
+
+    /// Completes and returns the current bucket (if any trades have been handled).
+    pub fn flush(&mut self) -> Option<OrderFlowImbalance> {
+        let completed = self.current.take()?;
+        self.cumulative_delta = completed.cumulative_delta;
+        Some(completed)
+    }
+
+    /// Resets the aggregator, discarding the current bucket and cumulative delta.
+    pub fn reset(&mut self) {
+        self.current = None;
+        self.cumulative_delta = 0.0;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{identifiers::trade_id::TradeId, types::price::Price};
+
+    const BUCKET_NS: u64 = 1_000_000_000;
+
+    #[fixture]
+    fn aggregator() -> OrderFlowAggregator {
+        OrderFlowAggregator::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), BUCKET_NS, 3).unwrap()
+    }
+
+    fn trade(size: &str, aggressor_side: AggressorSide, ts_event: u64) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Price::from("10000.00"),
+            Quantity::from(size),
+            aggressor_side,
+            TradeId::from("1"),
+            UnixNanos::from(ts_event),
+            UnixNanos::from(ts_event),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_new_with_zero_bucket() {
+        let result = OrderFlowAggregator::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), 0, 3);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_flush_when_empty(mut aggregator: OrderFlowAggregator) {
+        assert!(aggregator.flush().is_none());
+    }
+
+    #[rstest]
+    fn test_single_bucket(mut aggregator: OrderFlowAggregator) {
+        assert!(aggregator
+            .handle_trade(&trade("1.500", AggressorSide::Buyer, 100))
+            .is_none());
+        assert!(aggregator
+            .handle_trade(&trade("0.500", AggressorSide::Seller, 200))
+            .is_none());
+
+        let bucket = aggregator.flush().unwrap();
+
+        assert_eq!(bucket.buy_volume, Quantity::from("1.500"));
+        assert_eq!(bucket.sell_volume, Quantity::from("0.500"));
+        assert_eq!(bucket.delta, 1.0);
+        assert_eq!(bucket.cumulative_delta, 1.0);
+        assert_eq!(bucket.trade_count, 2);
+        assert_eq!(bucket.ts_bucket, UnixNanos::from(0));
+        assert_eq!(bucket.ts_init, UnixNanos::from(BUCKET_NS));
+    }
+
+    #[rstest]
+    fn test_cumulative_delta_continuity_across_buckets(mut aggregator: OrderFlowAggregator) {
+        let trades = [
+            trade("2.000", AggressorSide::Buyer, 0),
+            trade("0.500", AggressorSide::Seller, BUCKET_NS + 1),
+            trade("1.000", AggressorSide::Seller, BUCKET_NS + 2),
+            // Gap with no trades for the third bucket
+            trade("0.250", AggressorSide::Buyer, 3 * BUCKET_NS),
+        ];
+        let mut buckets: Vec<OrderFlowImbalance> = trades
+            .iter()
+            .filter_map(|trade| aggregator.handle_trade(trade))
+            .collect();
+        buckets.extend(aggregator.flush());
+
+        let deltas: Vec<f64> = buckets.iter().map(|b| b.delta).collect();
+        let ts_buckets: Vec<u64> = buckets.iter().map(|b| b.ts_bucket.as_u64()).collect();
+        assert_eq!(deltas, vec![2.0, -1.5, 0.25]);
+        assert_eq!(ts_buckets, vec![0, BUCKET_NS, 3 * BUCKET_NS]);
+        for window in buckets.windows(2) {
+            assert_eq!(
+                window[1].cumulative_delta,
+                window[0].cumulative_delta + window[1].delta
+            );
+        }
+        assert_eq!(buckets.last().unwrap().cumulative_delta, 0.75);
+    }
+
+    #[rstest]
+    fn test_no_aggressor_accounted_separately(mut aggregator: OrderFlowAggregator) {
+        aggregator.handle_trade(&trade("1.000", AggressorSide::Buyer, 0));
+        aggregator.handle_trade(&trade("3.000", AggressorSide::NoAggressor, 1));
+
+        let bucket = aggregator.flush().unwrap();
+
+        assert_eq!(bucket.no_aggressor_volume, Quantity::from("3.000"));
+        assert_eq!(bucket.buy_volume, Quantity::from("1.000"));
+        assert_eq!(bucket.delta, 1.0);
+        assert_eq!(bucket.trade_count, 2);
+    }
+
+    #[rstest]
+    fn test_reset(mut aggregator: OrderFlowAggregator) {
+        aggregator.handle_trade(&trade("1.000", AggressorSide::Buyer, 0));
+        aggregator.flush();
+        aggregator.reset();
+        aggregator.handle_trade(&trade("1.000", AggressorSide::Seller, BUCKET_NS));
+
+        let bucket = aggregator.flush().unwrap();
+
+        assert_eq!(bucket.cumulative_delta, -1.0);
+    }
+}
@@ -24,12 +24,16 @@ use std::{
 
 use derive_builder::Builder;
 use indexmap::IndexMap;
-use nautilus_core::{correctness::check_equal_u8, nanos::UnixNanos, serialization::Serializable};
+use nautilus_core::{
+    correctness::check_positive_i64, nanos::UnixNanos, serialization::Serializable,
+};
 use serde::{Deserialize, Serialize};
 
+use super::TickError;
 use crate::{
     enums::PriceType,
     identifiers::instrument_id::InstrumentId,
+    instruments::currency_pair::CurrencyPair,
     polymorphism::GetTsInit,
     types::{fixed::FIXED_PRECISION, price::Price, quantity::Quantity},
 };
@@ -61,6 +65,14 @@ pub struct QuoteTick {
 }
 
 impl QuoteTick {
+    /// Creates a new [`QuoteTick`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The bid and ask prices (or sizes) have different precisions.
+    /// - The bid price is greater than the ask price.
+    /// - Either size is not positive.
     pub fn new(
         instrument_id: InstrumentId,
         bid_price: Price,
@@ -69,20 +81,54 @@ impl QuoteTick {
         ask_size: Quantity,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
-    ) -> anyhow::Result<Self> {
-        check_equal_u8(
+    ) -> Result<Self, TickError> {
+        TickError::check_precision(
+            "bid_price",
+            "ask_price",
             bid_price.precision,
             ask_price.precision,
-            "bid_price.precision",
-            "ask_price.precision",
         )?;
-        check_equal_u8(
+        TickError::check_precision(
+            "bid_size",
+            "ask_size",
             bid_size.precision,
             ask_size.precision,
-            "bid_size.precision",
-            "ask_size.precision",
         )?;
-        Ok(Self {
+        if bid_price > ask_price {
+            return Err(TickError::CrossedQuote {
+                bid_price,
+                ask_price,
+            });
+        }
+        TickError::check_positive_size("bid_size", bid_size)?;
+        TickError::check_positive_size("ask_size", ask_size)?;
+
+        Ok(Self::new_unchecked(
+            instrument_id,
+            bid_price,
+            ask_price,
+            bid_size,
+            ask_size,
+            ts_event,
+            ts_init,
+        ))
+    }
+
+    /// Creates a new [`QuoteTick`] instance without validating its invariants.
+    ///
+    /// Use this for quotes received from a venue or loaded from storage, where an empty side
+    /// or a crossed top level is valid market data.
+    #[must_use]
+    pub fn new_unchecked(
+        instrument_id: InstrumentId,
+        bid_price: Price,
+        ask_price: Price,
+        bid_size: Quantity,
+        ask_size: Quantity,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
             instrument_id,
             bid_price,
             ask_price,
@@ -90,7 +136,7 @@ impl QuoteTick {
             ask_size,
             ts_event,
             ts_init,
-        })
+        }
     }
 
     /// Returns the metadata for the type, for use with serialization formats.
@@ -120,6 +166,12 @@ impl QuoteTick {
         metadata
     }
 
+    /// Returns the price for the given `price_type`, where `PriceType::Mid` is the midpoint of
+    /// the bid and ask at one more decimal of precision.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `price_type` is `PriceType::Last`.
     #[must_use]
     pub fn extract_price(&self, price_type: PriceType) -> Price {
         match price_type {
@@ -134,6 +186,12 @@ impl QuoteTick {
         }
     }
 
+    /// Returns the size for the given `price_type`, where `PriceType::Mid` is the average of the
+    /// bid and ask sizes at one more decimal of precision.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `price_type` is `PriceType::Last`.
     #[must_use]
     pub fn extract_volume(&self, price_type: PriceType) -> Quantity {
         match price_type {
@@ -147,6 +205,70 @@ impl QuoteTick {
             _ => panic!("Cannot extract with price type {price_type}"),
         }
     }
+
+    /// Returns the reciprocal bid and ask prices for the inverse pair of a `bid_price` and
+    /// `ask_price` quote, rounded to the given `precision`.
+    ///
+    /// The sides swap, so the new bid is the reciprocal of the ask (and vice versa). Where
+    /// rounding collapses a tight spread, the ask is widened by one tick to keep the bid below.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if either price is not positive, or `precision` is
+    /// invalid.
+    pub fn invert_prices(
+        bid_price: Price,
+        ask_price: Price,
+        precision: u8,
+    ) -> anyhow::Result<(Price, Price)> {
+        check_positive_i64(bid_price.raw, stringify!(bid_price.raw))?;
+        check_positive_i64(ask_price.raw, stringify!(ask_price.raw))?;
+
+        let inverted_bid = Price::new(1.0 / ask_price.as_f64(), precision)?;
+        let mut inverted_ask = Price::new(1.0 / bid_price.as_f64(), precision)?;
+        if bid_price < ask_price && inverted_bid >= inverted_ask {
+            let tick = Price::from_raw(
+                10_i64.pow(u32::from(FIXED_PRECISION - precision)),
+                precision,
+            )?;
+            inverted_ask = inverted_bid + tick;
+        }
+        Ok((inverted_bid, inverted_ask))
+    }
+
+    /// Returns the reciprocal of this quote for the `target` pair, which is the inverse of the
+    /// quoted pair (e.g. a USD/AUD quote for an AUD/USD instrument).
+    ///
+    /// The sides swap, so the new bid is the reciprocal of the ask (and vice versa), with prices
+    /// rounded to the target price precision. Sizes are converted into the new base currency at
+    /// the price of their side.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if either price is not positive.
+    pub fn invert(&self, target: &CurrencyPair) -> anyhow::Result<Self> {
+        let (bid_price, ask_price) =
+            Self::invert_prices(self.bid_price, self.ask_price, target.price_precision)?;
+
+        let bid_size = Quantity::new(
+            self.ask_size.as_f64() * self.ask_price.as_f64(),
+            target.size_precision,
+        )?;
+        let ask_size = Quantity::new(
+            self.bid_size.as_f64() * self.bid_price.as_f64(),
+            target.size_precision,
+        )?;
+
+        Ok(Self::new(
+            target.id,
+            bid_price,
+            ask_price,
+            bid_size,
+            ask_size,
+            self.ts_event,
+            self.ts_init,
+        )?)
+    }
 }
 
 impl Display for QuoteTick {
@@ -177,15 +299,34 @@ impl GetTsInit for QuoteTick {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use nautilus_core::serialization::Serializable;
+    use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
     use pyo3::{IntoPy, Python};
     use rstest::rstest;
 
     use crate::{
-        data::{quote::QuoteTick, stubs::quote_tick_ethusdt_binance},
+        data::{quote::QuoteTick, stubs::quote_tick_ethusdt_binance, TickError},
         enums::PriceType,
+        identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue},
+        instruments::{
+            currency_pair::CurrencyPair,
+            stubs::{audusd_sim, default_fx_ccy},
+        },
+        types::{price::Price, quantity::Quantity},
     };
 
+    fn usdaud_quote(bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("USD/AUD.SIM"),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(200_000),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        )
+        .unwrap()
+    }
+
     #[rstest]
     fn test_to_string(quote_tick_ethusdt_binance: QuoteTick) {
         let tick = quote_tick_ethusdt_binance;
@@ -209,6 +350,124 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    fn test_new_with_crossed_quote() {
+        let result = QuoteTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::from("1.00001"),
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+
+        assert_eq!(
+            result,
+            Err(TickError::CrossedQuote {
+                bid_price: Price::from("1.00001"),
+                ask_price: Price::from("1.00000"),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_new_with_locked_quote() {
+        let quote = usdaud_quote("1.50371", "1.50371");
+        assert_eq!(quote.bid_price, quote.ask_price);
+    }
+
+    #[rstest]
+    #[case("1.0000", "1.00001", "100", "100", "bid_price")]
+    #[case("1.00000", "1.00001", "100", "100.0", "bid_size")]
+    fn test_new_with_precision_mismatch(
+        #[case] bid_price: &str,
+        #[case] ask_price: &str,
+        #[case] bid_size: &str,
+        #[case] ask_size: &str,
+        #[case] expected_left: &str,
+    ) {
+        let result = QuoteTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::from(bid_price),
+            Price::from(ask_price),
+            Quantity::from(bid_size),
+            Quantity::from(ask_size),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+
+        match result {
+            Err(TickError::PrecisionMismatch { left, .. }) => assert_eq!(left, expected_left),
+            _ => panic!("Expected precision mismatch, was {result:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(0, 100, "bid_size")]
+    #[case(100, 0, "ask_size")]
+    fn test_new_with_zero_size(
+        #[case] bid_size: i64,
+        #[case] ask_size: i64,
+        #[case] expected_field: &str,
+    ) {
+        let result = QuoteTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::from("1.00000"),
+            Price::from("1.00001"),
+            Quantity::from(bid_size),
+            Quantity::from(ask_size),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+
+        match result {
+            Err(TickError::NonPositiveSize { field, size }) => {
+                assert_eq!(field, expected_field);
+                assert_eq!(size, Quantity::from(0));
+            }
+            _ => panic!("Expected non-positive size, was {result:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_extract_mid_price_precision() {
+        let quote = usdaud_quote("1.50371", "1.50376");
+
+        let mid = quote.extract_price(PriceType::Mid);
+
+        assert_eq!(mid, Price::from("1.503735"));
+        assert_eq!(mid.precision, 6);
+    }
+
+    #[rstest]
+    fn test_extract_mid_price_at_max_precision() {
+        let quote = usdaud_quote("1.000000001", "1.000000002");
+
+        let mid = quote.extract_price(PriceType::Mid);
+
+        assert_eq!(mid.precision, 9);
+        assert_eq!(mid.raw, 1_000_000_001);
+    }
+
+    #[rstest]
+    #[case(PriceType::Bid, Quantity::from(100_000))]
+    #[case(PriceType::Ask, Quantity::from(200_000))]
+    #[case(PriceType::Mid, Quantity::from("150000.0"))]
+    fn test_extract_volume(#[case] input: PriceType, #[case] expected: Quantity) {
+        let quote = usdaud_quote("1.50371", "1.50376");
+        let result = quote.extract_volume(input);
+        assert_eq!(result, expected);
+        assert_eq!(result.precision, expected.precision);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Cannot extract with price type LAST")]
+    fn test_extract_price_with_last() {
+        let quote = usdaud_quote("1.50371", "1.50376");
+        let _ = quote.extract_price(PriceType::Last);
+    }
+
     #[rstest]
     fn test_from_pyobject(quote_tick_ethusdt_binance: QuoteTick) {
         pyo3::prepare_freethreaded_python();
@@ -236,4 +495,69 @@ mod tests {
         let deserialized = QuoteTick::from_msgpack_bytes(serialized).unwrap();
         assert_eq!(deserialized, tick);
     }
+
+    #[rstest]
+    fn test_invert(audusd_sim: CurrencyPair) {
+        let quote = usdaud_quote("1.50371", "1.50376");
+
+        let inverted = quote.invert(&audusd_sim).unwrap();
+
+        assert_eq!(inverted.instrument_id, audusd_sim.id);
+        assert_eq!(inverted.bid_price, Price::from("0.66500"));
+        assert_eq!(inverted.ask_price, Price::from("0.66502"));
+        assert_eq!(inverted.bid_price.precision, audusd_sim.price_precision);
+        assert_eq!(inverted.bid_size, Quantity::from(300_752));
+        assert_eq!(inverted.ask_size, Quantity::from(150_371));
+        assert_eq!(inverted.ts_event, quote.ts_event);
+        assert_eq!(inverted.ts_init, quote.ts_init);
+    }
+
+    #[rstest]
+    fn test_invert_preserves_bid_below_ask(audusd_sim: CurrencyPair) {
+        // Both reciprocals round to 0.66502
+        let quote = usdaud_quote("1.50371", "1.50372");
+
+        let inverted = quote.invert(&audusd_sim).unwrap();
+
+        assert_eq!(inverted.bid_price, Price::from("0.66502"));
+        assert_eq!(inverted.ask_price, Price::from("0.66503"));
+    }
+
+    #[rstest]
+    #[case("0.66500", "0.66502")]
+    #[case("0.71234", "0.71239")]
+    #[case("0.99999", "1.00001")]
+    fn test_invert_twice_within_one_tick(
+        audusd_sim: CurrencyPair,
+        #[case] bid: &str,
+        #[case] ask: &str,
+    ) {
+        let usdaud = default_fx_ccy(Symbol::from("USD/AUD"), Some(Venue::from("SIM")));
+        let quote = QuoteTick::new(
+            audusd_sim.id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        let inverted = quote.invert(&usdaud).unwrap();
+        let round_trip = inverted.invert(&audusd_sim).unwrap();
+
+        let tick = 10_000; // Raw value of 0.00001
+        assert!(inverted.bid_price < inverted.ask_price);
+        assert_eq!(round_trip.instrument_id, audusd_sim.id);
+        assert!((round_trip.bid_price.raw - quote.bid_price.raw).abs() <= tick);
+        assert!((round_trip.ask_price.raw - quote.ask_price.raw).abs() <= tick);
+    }
+
+    #[rstest]
+    fn test_invert_with_zero_price(audusd_sim: CurrencyPair) {
+        let quote = usdaud_quote("0.00000", "1.50376");
+
+        assert!(quote.invert(&audusd_sim).is_err());
+    }
 }
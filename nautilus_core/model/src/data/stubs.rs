@@ -20,6 +20,7 @@ use rstest::fixture;
 
 use super::{
     bar::{Bar, BarSpecification, BarType},
+    close::InstrumentClose,
     deltas::OrderBookDeltas,
     depth::DEPTH10_LEN,
     quote::QuoteTick,
@@ -28,7 +29,10 @@ use super::{
 };
 use crate::{
     data::order::BookOrder,
-    enums::{AggregationSource, AggressorSide, BarAggregation, BookAction, OrderSide, PriceType},
+    enums::{
+        AggregationSource, AggressorSide, BarAggregation, BookAction, InstrumentCloseType,
+        OrderSide, PriceType,
+    },
     identifiers::{instrument_id::InstrumentId, symbol::Symbol, trade_id::TradeId, venue::Venue},
     types::{price::Price, quantity::Quantity},
 };
@@ -342,3 +346,14 @@ pub fn stub_bar() -> Bar {
         ts_init: UnixNanos::from(1),
     }
 }
+
+#[fixture]
+pub fn stub_instrument_close() -> InstrumentClose {
+    InstrumentClose {
+        instrument_id: InstrumentId::from("ESZ4.GLBX"),
+        close_price: Price::from("5000.25"),
+        close_type: InstrumentCloseType::ContractExpired,
+        ts_event: UnixNanos::from(1),
+        ts_init: UnixNanos::from(2),
+    }
+}
@@ -26,6 +26,7 @@ use indexmap::IndexMap;
 use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
 use serde::{Deserialize, Serialize};
 
+use super::TickError;
 use crate::{
     enums::AggressorSide,
     identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
@@ -60,7 +61,11 @@ pub struct TradeTick {
 }
 
 impl TradeTick {
-    #[must_use]
+    /// Creates a new [`TradeTick`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `trade_id` is empty or `size` is not positive.
     pub fn new(
         instrument_id: InstrumentId,
         price: Price,
@@ -69,6 +74,35 @@ impl TradeTick {
         trade_id: TradeId,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
+    ) -> Result<Self, TickError> {
+        if trade_id.to_cstr().is_empty() {
+            return Err(TickError::EmptyTradeId);
+        }
+        TickError::check_positive_size("size", size)?;
+
+        Ok(Self::new_unchecked(
+            instrument_id,
+            price,
+            size,
+            aggressor_side,
+            trade_id,
+            ts_event,
+            ts_init,
+        ))
+    }
+
+    /// Creates a new [`TradeTick`] instance without validating its invariants.
+    ///
+    /// Use this for trades loaded from storage, which were validated when first recorded.
+    #[must_use]
+    pub fn new_unchecked(
+        instrument_id: InstrumentId,
+        price: Price,
+        size: Quantity,
+        aggressor_side: AggressorSide,
+        trade_id: TradeId,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
     ) -> Self {
         Self {
             instrument_id,
@@ -141,11 +175,52 @@ mod tests {
     use pyo3::{IntoPy, Python};
     use rstest::rstest;
 
+    use nautilus_core::nanos::UnixNanos;
+
     use crate::{
-        data::{stubs::stub_trade_tick_ethusdt_buyer, trade::TradeTick},
+        data::{stubs::stub_trade_tick_ethusdt_buyer, trade::TradeTick, TickError},
         enums::AggressorSide,
+        identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+        types::{price::Price, quantity::Quantity},
     };
 
+    fn new_trade(size: Quantity, trade_id: TradeId) -> Result<TradeTick, TickError> {
+        TradeTick::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Price::from("10000.0000"),
+            size,
+            AggressorSide::Buyer,
+            trade_id,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_new() {
+        let trade = new_trade(Quantity::from("1.00000000"), TradeId::from("123456789")).unwrap();
+        assert_eq!(trade.size, Quantity::from("1.00000000"));
+    }
+
+    #[rstest]
+    fn test_new_with_zero_size() {
+        let result = new_trade(Quantity::from("0.00000000"), TradeId::from("123456789"));
+        assert_eq!(
+            result,
+            Err(TickError::NonPositiveSize {
+                field: "size",
+                size: Quantity::from("0.00000000"),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_new_with_empty_trade_id() {
+        let trade_id = TradeId { value: [0; 37] };
+        let result = new_trade(Quantity::from("1.00000000"), trade_id);
+        assert_eq!(result, Err(TickError::EmptyTradeId));
+    }
+
     #[rstest]
     fn test_to_string(stub_trade_tick_ethusdt_buyer: TradeTick) {
         let trade = stub_trade_tick_ethusdt_buyer;
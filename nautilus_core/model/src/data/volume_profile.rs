@@ -0,0 +1,343 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `VolumeProfile` data type representing the OHLCV of a window of trade ticks, together
+//! with the traded volume at each price level (volume-at-price).
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+};
+
+use nautilus_core::{correctness::check_in_range_inclusive_f64, nanos::UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use super::trade::TradeTick;
+use crate::{
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents the OHLCV and the volume per price level for the trades in a window.
+///
+/// Levels are keyed by the raw fixed-point price, so every distinct traded price (at the
+/// instrument price precision) is a level.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct VolumeProfile {
+    /// The instrument ID for the trades.
+    pub instrument_id: InstrumentId,
+    /// The price of the first trade in the window.
+    pub open: Price,
+    /// The highest traded price in the window.
+    pub high: Price,
+    /// The lowest traded price in the window.
+    pub low: Price,
+    /// The price of the last trade in the window.
+    pub close: Price,
+    /// The total volume traded in the window.
+    pub volume: Quantity,
+    /// The count of trades in the window.
+    pub trade_count: u64,
+    /// The UNIX timestamp (nanoseconds) for the start of the window (inclusive).
+    pub ts_start: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) for the end of the window (exclusive).
+    pub ts_end: UnixNanos,
+    levels: BTreeMap<i64, u64>,
+}
+
+impl VolumeProfile {
+    /// Creates a new [`VolumeProfile`] for the window from `ts_start` to `ts_end`, starting
+    /// with the given `trade`.
+    #[must_use]
+    pub fn new(trade: &TradeTick, ts_start: UnixNanos, ts_end: UnixNanos) -> Self {
+        let mut profile = Self {
+            instrument_id: trade.instrument_id,
+            open: trade.price,
+            high: trade.price,
+            low: trade.price,
+            close: trade.price,
+            volume: Quantity::zero(trade.size.precision),
+            trade_count: 0,
+            ts_start,
+            ts_end,
+            levels: BTreeMap::new(),
+        };
+        profile.update(trade);
+        profile
+    }
+
+    /// Updates the profile with the given `trade`.
+    pub fn update(&mut self, trade: &TradeTick) {
+        if trade.price > self.high {
+            self.high = trade.price;
+        }
+        if trade.price < self.low {
+            self.low = trade.price;
+        }
+        self.close = trade.price;
+        self.volume += trade.size;
+        self.trade_count += 1;
+        *self.levels.entry(trade.price.raw).or_default() += trade.size.raw;
+    }
+
+    /// Returns the number of price levels in the profile.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Returns whether the profile has no price levels.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// Returns the volume traded at each price level, in ascending order of price.
+    #[must_use]
+    pub fn profile(&self) -> Vec<(Price, Quantity)> {
+        self.levels
+            .iter()
+            .map(|(raw, volume)| (self.price(*raw), self.quantity(*volume)))
+            .collect()
+    }
+
+    /// Returns the point of control, the price level with the most volume traded.
+    ///
+    /// When several levels share the most volume the lowest price is returned.
+    #[must_use]
+    pub fn poc(&self) -> Option<(Price, Quantity)> {
+        self.levels
+            .iter()
+            .fold(None, |poc: Option<(&i64, &u64)>, level| match poc {
+                Some((_, volume)) if volume >= level.1 => poc,
+                _ => Some(level),
+            })
+            .map(|(raw, volume)| (self.price(*raw), self.quantity(*volume)))
+    }
+
+    /// Returns the value area, the `(low, high)` price range containing at least `pct` of the
+    /// volume traded.
+    ///
+    /// The area starts at the point of control and is extended one level at a time towards
+    /// whichever adjacent level has more volume, preferring the higher level on a tie.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `pct` is not in the range [0, 1].
+    pub fn value_area(&self, pct: f64) -> anyhow::Result<Option<(Price, Price)>> {
+        check_in_range_inclusive_f64(pct, 0.0, 1.0, stringify!(pct))?;
+
+        let levels: Vec<(i64, u64)> = self.levels.iter().map(|(k, v)| (*k, *v)).collect();
+        let Some((poc_raw, _)) = self.poc() else {
+            return Ok(None);
+        };
+        let poc_index = levels
+            .iter()
+            .position(|(raw, _)| *raw == poc_raw.raw)
+            .expect("point of control should be a level");
+
+        let target = pct * self.volume.raw as f64;
+        let (mut lo, mut hi) = (poc_index, poc_index);
+        let mut area_volume = levels[poc_index].1;
+        while (area_volume as f64) < target {
+            let below = lo.checked_sub(1).map(|i| levels[i].1);
+            let above = levels.get(hi + 1).map(|(_, volume)| *volume);
+            match (below, above) {
+                (Some(below), Some(above)) if below > above => {
+                    lo -= 1;
+                    area_volume += below;
+                }
+                (_, Some(above)) => {
+                    hi += 1;
+                    area_volume += above;
+                }
+                (Some(below), None) => {
+                    lo -= 1;
+                    area_volume += below;
+                }
+                (None, None) => break,
+            }
+        }
+
+        Ok(Some((self.price(levels[lo].0), self.price(levels[hi].0))))
+    }
+
+    fn price(&self, raw: i64) -> Price {
+        Price {
+            raw,
+            precision: self.open.precision,
+        }
+    }
+
+    fn quantity(&self, raw: u64) -> Quantity {
+        Quantity {
+            raw,
+            precision: self.volume.precision,
+        }
+    }
+}
+
+impl Display for VolumeProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},{}",
+            self.instrument_id,
+            self.open,
+            self.high,
+            self.low,
+            self.close,
+            self.volume,
+            self.levels.len(),
+            self.ts_start,
+            self.ts_end,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{enums::AggressorSide, identifiers::trade_id::TradeId};
+
+    fn trade(price: &str, size: i64, ts_event: u64) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from("ESZ4.GLBX"),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            UnixNanos::from(ts_event),
+            UnixNanos::from(ts_event),
+        )
+        .unwrap()
+    }
+
+    /// A tape with 100 contracts traded over six levels, with the most at 100.03.
+    #[fixture]
+    fn profile() -> VolumeProfile {
+        let tape = [
+            ("100.02", 20),
+            ("100.03", 25),
+            ("100.00", 5),
+            ("100.01", 10),
+            ("100.04", 15),
+            ("100.03", 15),
+            ("100.05", 10),
+        ];
+        let mut trades = tape
+            .iter()
+            .enumerate()
+            .map(|(i, (price, size))| trade(price, *size, i as u64));
+        let mut profile = VolumeProfile::new(&trades.next().unwrap(), 0.into(), 100.into());
+        trades.for_each(|trade| profile.update(&trade));
+        profile
+    }
+
+    #[rstest]
+    fn test_ohlcv(profile: VolumeProfile) {
+        assert_eq!(profile.open, Price::from("100.02"));
+        assert_eq!(profile.high, Price::from("100.05"));
+        assert_eq!(profile.low, Price::from("100.00"));
+        assert_eq!(profile.close, Price::from("100.05"));
+        assert_eq!(profile.volume, Quantity::from(100));
+        assert_eq!(profile.trade_count, 7);
+        assert_eq!(profile.len(), 6);
+    }
+
+    #[rstest]
+    fn test_profile_sorted_by_price(profile: VolumeProfile) {
+        assert_eq!(
+            profile.profile(),
+            vec![
+                (Price::from("100.00"), Quantity::from(5)),
+                (Price::from("100.01"), Quantity::from(10)),
+                (Price::from("100.02"), Quantity::from(20)),
+                (Price::from("100.03"), Quantity::from(40)),
+                (Price::from("100.04"), Quantity::from(15)),
+                (Price::from("100.05"), Quantity::from(10)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_poc(profile: VolumeProfile) {
+        assert_eq!(
+            profile.poc(),
+            Some((Price::from("100.03"), Quantity::from(40)))
+        );
+    }
+
+    #[rstest]
+    fn test_poc_tie_returns_lowest_price() {
+        let mut profile = VolumeProfile::new(&trade("100.01", 10, 0), 0.into(), 100.into());
+        profile.update(&trade("100.00", 10, 1));
+
+        assert_eq!(
+            profile.poc(),
+            Some((Price::from("100.00"), Quantity::from(10)))
+        );
+    }
+
+    #[rstest]
+    fn test_value_area_70_pct(profile: VolumeProfile) {
+        // POC 100.03 (40), then 100.02 (20 > 15) for 60, then 100.04 (15 > 10) for 75 >= 70
+        assert_eq!(
+            profile.value_area(0.70).unwrap(),
+            Some((Price::from("100.02"), Price::from("100.04")))
+        );
+    }
+
+    #[rstest]
+    #[case(0.0, "100.03", "100.03")]
+    #[case(0.40, "100.03", "100.03")]
+    #[case(1.0, "100.00", "100.05")]
+    fn test_value_area_bounds(
+        profile: VolumeProfile,
+        #[case] pct: f64,
+        #[case] low: &str,
+        #[case] high: &str,
+    ) {
+        assert_eq!(
+            profile.value_area(pct).unwrap(),
+            Some((Price::from(low), Price::from(high)))
+        );
+    }
+
+    #[rstest]
+    #[case(-0.1)]
+    #[case(1.1)]
+    fn test_value_area_with_invalid_pct(profile: VolumeProfile, #[case] pct: f64) {
+        assert!(profile.value_area(pct).is_err());
+    }
+
+    #[rstest]
+    fn test_json_round_trip(profile: VolumeProfile) {
+        let json = serde_json::to_string(&profile).unwrap();
+        let deserialized: VolumeProfile = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, profile);
+    }
+}
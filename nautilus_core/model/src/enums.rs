@@ -20,7 +20,10 @@ use std::str::FromStr;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use strum::{AsRefStr, Display, EnumIter, EnumString, FromRepr};
 
-use crate::enum_strum_serde;
+use crate::{
+    enum_strum_serde,
+    types::{fixed::FIXED_SCALAR, quantity::Quantity},
+};
 
 pub trait FromU8 {
     fn from_u8(value: u8) -> Option<Self>
@@ -280,6 +283,10 @@ pub enum BarAggregation {
     Week = 15,
     /// Based on time intervals with month granularity.
     Month = 16,
+    /// Based on fixed size price bricks, with the step as the brick size in ticks.
+    Renko = 17,
+    /// Based on the high-low price range, with the step as the range size in ticks.
+    Range = 18,
 }
 
 /// The type of order book action for an order book event.
@@ -679,16 +686,31 @@ pub enum OrderSide {
 }
 
 impl OrderSide {
-    #[must_use]
-    pub fn as_specified(&self) -> OrderSideSpecified {
+    /// Returns the specified side, or an error for `NoOrderSide`.
+    pub fn as_specified(&self) -> Result<OrderSideSpecified, NoSideError> {
         match &self {
-            Self::Buy => OrderSideSpecified::Buy,
-            Self::Sell => OrderSideSpecified::Sell,
-            _ => panic!("Order invariant failed: side must be 'Buy' or 'Sell'"),
+            Self::Buy => Ok(OrderSideSpecified::Buy),
+            Self::Sell => Ok(OrderSideSpecified::Sell),
+            Self::NoOrderSide => Err(NoSideError),
         }
     }
+
+    /// Returns the specified side for an order or book order, which is always BUY or SELL.
+    ///
+    /// # Panics
+    ///
+    /// If the side is `NoOrderSide`.
+    pub(crate) fn specified(self) -> OrderSideSpecified {
+        self.as_specified()
+            .expect("Order invariant failed: side must be 'Buy' or 'Sell'")
+    }
 }
 
+/// The error returned when a BUY or SELL side is required but `NoOrderSide` was given.
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq, Eq)]
+#[error("Invalid `NoOrderSide`: side must be 'Buy' or 'Sell'")]
+pub struct NoSideError;
+
 /// Convert the given `value` to an [`OrderSide`].
 impl FromU8 for OrderSide {
     fn from_u8(value: u8) -> Option<Self> {
@@ -702,6 +724,7 @@ impl FromU8 for OrderSide {
 }
 
 /// The specified order side (BUY or SELL).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum OrderSideSpecified {
     /// The order is a BUY.
     Buy = 1,
@@ -717,6 +740,14 @@ impl OrderSideSpecified {
             Self::Sell => OrderSide::Sell,
         }
     }
+
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        match &self {
+            Self::Buy => Self::Sell,
+            Self::Sell => Self::Buy,
+        }
+    }
 }
 
 /// The status for a specific order.
@@ -873,6 +904,56 @@ pub enum PositionSide {
     Short = 3,
 }
 
+impl PositionSide {
+    /// Returns the position side for the given signed quantity, where zero is `Flat`.
+    #[must_use]
+    pub fn from_signed_qty(signed_qty: f64) -> Self {
+        if signed_qty > 0.0 {
+            Self::Long
+        } else if signed_qty < 0.0 {
+            Self::Short
+        } else {
+            Self::Flat
+        }
+    }
+}
+
+/// Returns the position side and signed quantity after applying a fill of `fill_qty` on
+/// `fill_side` to a `current` position of `current_qty` (unsigned).
+///
+/// The quantities are combined as raw fixed-point values, so a fill opposing the position
+/// flattens it to `Flat` with a signed quantity of exactly zero when the quantities are equal,
+/// and flips the side when the fill is larger. A `NoPositionSide` position is treated as flat.
+///
+/// # Errors
+///
+/// Returns an error if `fill_side` is `NoOrderSide`.
+pub fn apply_fill_side(
+    current: PositionSide,
+    current_qty: Quantity,
+    fill_side: OrderSide,
+    fill_qty: Quantity,
+) -> Result<(PositionSide, f64), NoSideError> {
+    let fill_side = fill_side.as_specified()?;
+    let current_raw = match current {
+        PositionSide::Long | PositionSide::Short => i128::from(current_qty.raw),
+        PositionSide::Flat | PositionSide::NoPositionSide => 0,
+    };
+    let fill_raw = i128::from(fill_qty.raw);
+
+    let signed_raw = match (current, fill_side) {
+        (PositionSide::Long, OrderSideSpecified::Buy) => current_raw + fill_raw,
+        (PositionSide::Long, OrderSideSpecified::Sell) => current_raw - fill_raw,
+        (PositionSide::Short, OrderSideSpecified::Buy) => fill_raw - current_raw,
+        (PositionSide::Short, OrderSideSpecified::Sell) => -(current_raw + fill_raw),
+        (_, OrderSideSpecified::Buy) => fill_raw,
+        (_, OrderSideSpecified::Sell) => -fill_raw,
+    };
+    let signed_qty = signed_raw as f64 / FIXED_SCALAR;
+
+    Ok((PositionSide::from_signed_qty(signed_qty), signed_qty))
+}
+
 /// The type of price for an instrument in a market.
 #[repr(C)]
 #[derive(
@@ -907,6 +988,44 @@ pub enum PriceType {
     Last = 4,
 }
 
+/// The rounding mode applied when snapping a value onto a fixed-point grid.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum RoundingMode {
+    /// Round to the nearest value, with a value exactly halfway rounded away from zero.
+    HalfUp = 1,
+    /// Round to the nearest value, with a value exactly halfway rounded to the even neighbor.
+    HalfEven = 2,
+    /// Round towards negative infinity.
+    Floor = 3,
+    /// Round towards positive infinity.
+    Ceiling = 4,
+    /// Round towards zero.
+    Down = 5,
+    /// Round away from zero.
+    Up = 6,
+}
+
 /// A record flag bit field, indicating event end and data information.
 #[repr(C)]
 #[derive(
@@ -1131,7 +1250,142 @@ enum_strum_serde!(OrderType);
 enum_strum_serde!(PositionSide);
 enum_strum_serde!(PriceType);
 enum_strum_serde!(RecordFlag);
+enum_strum_serde!(RoundingMode);
 enum_strum_serde!(TimeInForce);
 enum_strum_serde!(TradingState);
 enum_strum_serde!(TrailingOffsetType);
 enum_strum_serde!(TriggerType);
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(OrderSide::Buy, Ok(OrderSideSpecified::Buy))]
+    #[case(OrderSide::Sell, Ok(OrderSideSpecified::Sell))]
+    #[case(OrderSide::NoOrderSide, Err(NoSideError))]
+    fn test_order_side_as_specified(
+        #[case] side: OrderSide,
+        #[case] expected: Result<OrderSideSpecified, NoSideError>,
+    ) {
+        assert_eq!(side.as_specified(), expected);
+    }
+
+    #[rstest]
+    #[case(100.0, PositionSide::Long)]
+    #[case(-100.0, PositionSide::Short)]
+    #[case(0.0, PositionSide::Flat)]
+    #[case(-0.0, PositionSide::Flat)]
+    fn test_position_side_from_signed_qty(#[case] signed_qty: f64, #[case] expected: PositionSide) {
+        assert_eq!(PositionSide::from_signed_qty(signed_qty), expected);
+    }
+
+    #[rstest]
+    // Opening from flat
+    #[case(
+        PositionSide::Flat,
+        0.0,
+        OrderSide::Buy,
+        10.0,
+        PositionSide::Long,
+        10.0
+    )]
+    #[case(PositionSide::Flat, 0.0, OrderSide::Sell, 10.0, PositionSide::Short, -10.0)]
+    #[case(
+        PositionSide::NoPositionSide,
+        0.0,
+        OrderSide::Buy,
+        10.0,
+        PositionSide::Long,
+        10.0
+    )]
+    #[case(PositionSide::NoPositionSide, 0.0, OrderSide::Sell, 10.0, PositionSide::Short, -10.0)]
+    // Increasing
+    #[case(
+        PositionSide::Long,
+        10.0,
+        OrderSide::Buy,
+        5.0,
+        PositionSide::Long,
+        15.0
+    )]
+    #[case(PositionSide::Short, 10.0, OrderSide::Sell, 5.0, PositionSide::Short, -15.0)]
+    // Reducing
+    #[case(
+        PositionSide::Long,
+        10.0,
+        OrderSide::Sell,
+        4.0,
+        PositionSide::Long,
+        6.0
+    )]
+    #[case(PositionSide::Short, 10.0, OrderSide::Buy, 4.0, PositionSide::Short, -6.0)]
+    // Exact flatten
+    #[case(
+        PositionSide::Long,
+        10.0,
+        OrderSide::Sell,
+        10.0,
+        PositionSide::Flat,
+        0.0
+    )]
+    #[case(
+        PositionSide::Short,
+        10.0,
+        OrderSide::Buy,
+        10.0,
+        PositionSide::Flat,
+        0.0
+    )]
+    #[case(PositionSide::Long, 0.3, OrderSide::Sell, 0.3, PositionSide::Flat, 0.0)]
+    #[case(PositionSide::Long, 0.3, OrderSide::Sell, 0.1, PositionSide::Long, 0.2)]
+    // Flipping
+    #[case(PositionSide::Long, 10.0, OrderSide::Sell, 15.0, PositionSide::Short, -5.0)]
+    #[case(
+        PositionSide::Short,
+        10.0,
+        OrderSide::Buy,
+        15.0,
+        PositionSide::Long,
+        5.0
+    )]
+    fn test_apply_fill_side(
+        #[case] current: PositionSide,
+        #[case] current_qty: f64,
+        #[case] fill_side: OrderSide,
+        #[case] fill_qty: f64,
+        #[case] expected_side: PositionSide,
+        #[case] expected_qty: f64,
+    ) {
+        let (side, signed_qty) = apply_fill_side(
+            current,
+            Quantity::new(current_qty, 1).unwrap(),
+            fill_side,
+            Quantity::new(fill_qty, 1).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(side, expected_side);
+        assert_eq!(signed_qty, expected_qty);
+        assert!(signed_qty.is_sign_positive() || side != PositionSide::Flat);
+    }
+
+    #[rstest]
+    #[case(PositionSide::Flat)]
+    #[case(PositionSide::Long)]
+    #[case(PositionSide::Short)]
+    #[case(PositionSide::NoPositionSide)]
+    fn test_apply_fill_side_without_side_errors(#[case] current: PositionSide) {
+        let result = apply_fill_side(
+            current,
+            Quantity::from(10),
+            OrderSide::NoOrderSide,
+            Quantity::from(10),
+        );
+        assert_eq!(result, Err(NoSideError));
+    }
+}
@@ -15,7 +15,8 @@
 
 use std::fmt::{Display, Formatter};
 
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use derive_builder::Builder;
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -24,11 +25,14 @@ use crate::{
     types::{
         balance::{AccountBalance, MarginBalance},
         currency::Currency,
+        fixed::FIXED_PRECISION,
     },
 };
 
+/// Represents an event which includes information on the state of the account.
 #[repr(C)]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -36,16 +40,27 @@ use crate::{
 pub struct AccountState {
     pub account_id: AccountId,
     pub account_type: AccountType,
+    #[builder(default)]
     pub base_currency: Option<Currency>,
     pub balances: Vec<AccountBalance>,
+    #[builder(default)]
     pub margins: Vec<MarginBalance>,
+    #[builder(default)]
     pub is_reported: bool,
+    #[builder(default)]
     pub event_id: UUID4,
+    #[builder(default)]
     pub ts_event: UnixNanos,
+    #[builder(default)]
     pub ts_init: UnixNanos,
 }
 
 impl AccountState {
+    /// Creates a new [`AccountState`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any balance is invalid (see [`check_balances`]).
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         account_id: AccountId,
@@ -58,6 +73,8 @@ impl AccountState {
         ts_init: UnixNanos,
         base_currency: Option<Currency>,
     ) -> anyhow::Result<Self> {
+        check_balances(&balances)?;
+
         Ok(Self {
             account_id,
             account_type,
@@ -72,6 +89,49 @@ impl AccountState {
     }
 }
 
+impl AccountStateBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match &self.balances {
+            Some(balances) => check_balances(balances).map_err(|e| e.to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Checks the given account `balances` are internally consistent.
+///
+/// # Errors
+///
+/// This function returns an error if:
+/// - A balance has `total`, `locked` and `free` amounts in different currencies.
+/// - A balance `total` is not equal to `locked` + `free` within the precision of its currency.
+pub fn check_balances(balances: &[AccountBalance]) -> anyhow::Result<()> {
+    for balance in balances {
+        let AccountBalance {
+            currency,
+            total,
+            locked,
+            free,
+        } = balance;
+        anyhow::ensure!(
+            total.currency == *currency
+                && locked.currency == *currency
+                && free.currency == *currency,
+            "Mixed currencies in balance: total={total}, locked={locked}, free={free}"
+        );
+
+        // Amounts are equal within the precision if they differ by less than half a unit
+        let unit = 10_i128.pow(u32::from(FIXED_PRECISION - currency.precision));
+        let diff = i128::from(total.raw) - i128::from(locked.raw) - i128::from(free.raw);
+        anyhow::ensure!(
+            diff.abs() * 2 < unit,
+            "Total balance not equal to locked + free for {}: {total} != {locked} + {free}",
+            currency.code
+        );
+    }
+    Ok(())
+}
+
 impl Display for AccountState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -97,18 +157,27 @@ impl PartialEq for AccountState {
     }
 }
 
+impl Serializable for AccountState {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::serialization::Serializable;
     use rstest::rstest;
 
-    use crate::events::account::{
-        state::AccountState,
-        stubs::{cash_account_state, margin_account_state},
+    use super::*;
+    use crate::{
+        events::account::stubs::{cash_account_state, margin_account_state},
+        identifiers::instrument_id::InstrumentId,
+        types::money::Money,
     };
 
+    fn balance(total: &str, locked: &str, free: &str) -> AccountBalance {
+        AccountBalance::new(Money::from(total), Money::from(locked), Money::from(free)).unwrap()
+    }
+
     #[rstest]
     fn test_equality() {
         let cash_account_state_1 = cash_account_state();
@@ -138,4 +207,157 @@ mod tests {
             event_id=16578139-a945-4b65-b46c-bc131a15d8e7)"
         );
     }
+
+    #[rstest]
+    fn test_builder_multi_currency_cash_account() {
+        let state = AccountStateBuilder::default()
+            .account_id(AccountId::from("BINANCE-001"))
+            .account_type(AccountType::Cash)
+            .balances(vec![
+                balance("10000.00 USD", "2500.00 USD", "7500.00 USD"),
+                balance("1.50000000 BTC", "0.25000000 BTC", "1.25000000 BTC"),
+                balance("20.00000000 ETH", "0 ETH", "20.00000000 ETH"),
+            ])
+            .is_reported(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(state.base_currency, None);
+        assert_eq!(state.balances.len(), 3);
+        assert!(state.margins.is_empty());
+
+        let json = state.as_json_bytes().unwrap();
+        let msgpack = state.as_msgpack_bytes().unwrap();
+        for deserialized in [
+            AccountState::from_json_bytes(json).unwrap(),
+            AccountState::from_msgpack_bytes(msgpack).unwrap(),
+        ] {
+            assert_eq!(deserialized, state);
+            assert_eq!(deserialized.base_currency, None);
+            assert_eq!(deserialized.balances, state.balances);
+            assert!(deserialized.is_reported);
+        }
+    }
+
+    #[rstest]
+    fn test_builder_margin_account_with_instrument_margins() {
+        let ethusdt = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let btcusdt = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let state = AccountStateBuilder::default()
+            .account_id(AccountId::from("BINANCE-001"))
+            .account_type(AccountType::Margin)
+            .base_currency(Some(Currency::USDT()))
+            .balances(vec![balance(
+                "100000.00000000 USDT",
+                "6000.00000000 USDT",
+                "94000.00000000 USDT",
+            )])
+            .margins(vec![
+                MarginBalance::new(
+                    Money::from("1000.00000000 USDT"),
+                    Money::from("500.00000000 USDT"),
+                    ethusdt,
+                )
+                .unwrap(),
+                MarginBalance::new(
+                    Money::from("5000.00000000 USDT"),
+                    Money::from("2500.00000000 USDT"),
+                    btcusdt,
+                )
+                .unwrap(),
+            ])
+            .ts_event(1.into())
+            .ts_init(2.into())
+            .build()
+            .unwrap();
+
+        let json = state.as_json_bytes().unwrap();
+        let msgpack = state.as_msgpack_bytes().unwrap();
+        for deserialized in [
+            AccountState::from_json_bytes(json).unwrap(),
+            AccountState::from_msgpack_bytes(msgpack).unwrap(),
+        ] {
+            assert_eq!(deserialized.base_currency, Some(Currency::USDT()));
+            assert_eq!(deserialized.margins, state.margins);
+            assert_eq!(deserialized.margins[0].instrument_id, ethusdt);
+            assert_eq!(deserialized.margins[1].instrument_id, btcusdt);
+            assert_eq!(deserialized.ts_event, 1);
+            assert_eq!(deserialized.ts_init, 2);
+        }
+    }
+
+    #[rstest]
+    fn test_builder_requires_balances() {
+        let result = AccountStateBuilder::default()
+            .account_id(AccountId::from("SIM-001"))
+            .account_type(AccountType::Cash)
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_invalid_balance_total_errors() {
+        let invalid = AccountBalance {
+            currency: Currency::USD(),
+            total: Money::from("100.00 USD"),
+            locked: Money::from("10.00 USD"),
+            free: Money::from("80.00 USD"),
+        };
+
+        let built = AccountStateBuilder::default()
+            .account_id(AccountId::from("SIM-001"))
+            .account_type(AccountType::Cash)
+            .balances(vec![invalid])
+            .build();
+        let new = AccountState::new(
+            AccountId::from("SIM-001"),
+            AccountType::Cash,
+            vec![invalid],
+            vec![],
+            true,
+            UUID4::new(),
+            0.into(),
+            0.into(),
+            None,
+        );
+
+        assert!(built
+            .unwrap_err()
+            .to_string()
+            .contains("Total balance not equal to locked + free for USD"));
+        assert!(new.is_err());
+    }
+
+    #[rstest]
+    fn test_balance_total_within_currency_precision() {
+        let total = Money::from("100.00 USD");
+        let locked = Money::from("10.00 USD");
+        let free = Money::from_raw(Money::from("90.00 USD").raw + 4_999_999, Currency::USD());
+        let within = AccountBalance {
+            currency: Currency::USD(),
+            total,
+            locked,
+            free,
+        };
+        let outside = AccountBalance {
+            free: Money::from_raw(free.raw + 1, Currency::USD()),
+            ..within
+        };
+
+        assert!(check_balances(&[within]).is_ok());
+        assert!(check_balances(&[outside]).is_err());
+    }
+
+    #[rstest]
+    fn test_mixed_currency_balance_errors() {
+        let mixed = AccountBalance {
+            currency: Currency::USD(),
+            total: Money::from("100.00 USD"),
+            locked: Money::from("0 USDT"),
+            free: Money::from("100.00 USD"),
+        };
+
+        assert!(check_balances(&[mixed]).is_err());
+    }
 }
@@ -16,7 +16,9 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{deserialization::from_bool_as_u8, nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{
+    deserialization::from_bool_as_u8, nanos::UnixNanos, serialization::Serializable, uuid::UUID4,
+};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -25,7 +27,7 @@ use crate::{
         ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
         TriggerType,
     },
-    events::order::OrderEvent,
+    events::order::{correlation::CorrelationId, OrderEvent},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, position_id::PositionId,
@@ -38,7 +40,6 @@ use crate::{
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Builder)]
 #[builder(default)]
-#[serde(tag = "type")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -55,6 +56,9 @@ pub struct OrderAccepted {
     pub ts_init: UnixNanos,
     #[serde(deserialize_with = "from_bool_as_u8")]
     pub reconciliation: u8, // TODO: Change to bool once Cython removed
+    /// The ID correlating the event with the command which originated it.
+    #[serde(default)]
+    pub correlation_id: CorrelationId,
 }
 
 impl OrderAccepted {
@@ -70,6 +74,7 @@ impl OrderAccepted {
         ts_event: UnixNanos,
         ts_init: UnixNanos,
         reconciliation: bool,
+        correlation_id: Option<UUID4>,
     ) -> anyhow::Result<Self> {
         Ok(Self {
             trader_id,
@@ -82,6 +87,7 @@ impl OrderAccepted {
             ts_event,
             ts_init,
             reconciliation: u8::from(reconciliation),
+            correlation_id: correlation_id.into(),
         })
     }
 }
@@ -189,7 +195,7 @@ impl OrderEvent for OrderAccepted {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation != 0
     }
 
     fn price(&self) -> Option<Price> {
@@ -289,6 +295,8 @@ impl OrderEvent for OrderAccepted {
     }
 }
 
+impl Serializable for OrderAccepted {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -16,7 +16,9 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{deserialization::from_bool_as_u8, nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{
+    deserialization::from_bool_as_u8, nanos::UnixNanos, serialization::Serializable, uuid::UUID4,
+};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -35,10 +37,8 @@ use crate::{
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
-#[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Builder)]
 #[builder(default)]
-#[serde(tag = "type")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -189,7 +189,7 @@ impl OrderEvent for OrderCanceled {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation != 0
     }
 
     fn price(&self) -> Option<Price> {
@@ -289,6 +289,8 @@ impl OrderEvent for OrderCanceled {
     }
 }
 
+impl Serializable for OrderCanceled {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
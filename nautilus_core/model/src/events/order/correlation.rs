@@ -0,0 +1,171 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    sync::OnceLock,
+};
+
+use nautilus_core::uuid::UUID4;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The placeholder UUID held by an unset `CorrelationId`.
+static UNSET_UUID: OnceLock<UUID4> = OnceLock::new();
+
+/// Represents an optional ID correlating an order event with the command which originated it.
+///
+/// `Option<UUID4>` has no C layout, so the value is held alongside an explicit flag which
+/// allows the order event structs carrying it to cross the FFI boundary.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct CorrelationId {
+    /// The correlation ID value (only meaningful when `is_set` is true).
+    value: UUID4,
+    /// If the correlation ID is set.
+    is_set: bool,
+}
+
+impl CorrelationId {
+    /// Returns the correlation ID value (if set).
+    #[must_use]
+    pub fn get(&self) -> Option<UUID4> {
+        self.is_set.then_some(self.value)
+    }
+
+    #[must_use]
+    pub fn is_some(&self) -> bool {
+        self.is_set
+    }
+
+    #[must_use]
+    pub fn is_none(&self) -> bool {
+        !self.is_set
+    }
+}
+
+impl From<Option<UUID4>> for CorrelationId {
+    fn from(value: Option<UUID4>) -> Self {
+        match value {
+            Some(value) => Self {
+                value,
+                is_set: true,
+            },
+            None => Self {
+                value: *UNSET_UUID
+                    .get_or_init(|| UUID4::from("00000000-0000-4000-8000-000000000000")),
+                is_set: false,
+            },
+        }
+    }
+}
+
+impl From<UUID4> for CorrelationId {
+    fn from(value: UUID4) -> Self {
+        Some(value).into()
+    }
+}
+
+impl From<CorrelationId> for Option<UUID4> {
+    fn from(value: CorrelationId) -> Self {
+        value.get()
+    }
+}
+
+impl Default for CorrelationId {
+    fn default() -> Self {
+        None.into()
+    }
+}
+
+impl PartialEq for CorrelationId {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for CorrelationId {}
+
+impl Hash for CorrelationId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get().hash(state);
+    }
+}
+
+impl Debug for CorrelationId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.get())
+    }
+}
+
+impl Serialize for CorrelationId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CorrelationId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<UUID4>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_default_is_unset() {
+        let correlation_id = CorrelationId::default();
+        assert!(correlation_id.is_none());
+        assert_eq!(correlation_id.get(), None);
+        assert_eq!(correlation_id, CorrelationId::from(None));
+    }
+
+    #[rstest]
+    fn test_round_trips_option() {
+        let uuid = UUID4::new();
+        let correlation_id = CorrelationId::from(uuid);
+        assert!(correlation_id.is_some());
+        assert_eq!(Option::<UUID4>::from(correlation_id), Some(uuid));
+        assert_ne!(correlation_id, CorrelationId::default());
+    }
+
+    #[rstest]
+    fn test_serializes_as_option() {
+        let uuid = UUID4::new();
+        let json = serde_json::to_string(&CorrelationId::from(uuid)).unwrap();
+        assert_eq!(json, serde_json::to_string(&Some(uuid)).unwrap());
+        assert_eq!(
+            serde_json::to_string(&CorrelationId::default()).unwrap(),
+            "null"
+        );
+
+        let deserialized: CorrelationId = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.get(), Some(uuid));
+    }
+}
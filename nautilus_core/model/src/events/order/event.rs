@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::nanos::UnixNanos;
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use serde::{Deserialize, Serialize};
 use strum::Display;
 
@@ -24,29 +24,54 @@ use crate::{
         initialized::OrderInitialized, modify_rejected::OrderModifyRejected,
         pending_cancel::OrderPendingCancel, pending_update::OrderPendingUpdate,
         rejected::OrderRejected, released::OrderReleased, submitted::OrderSubmitted,
-        triggered::OrderTriggered, updated::OrderUpdated,
+        triggered::OrderTriggered, updated::OrderUpdated, OrderEvent,
+    },
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, strategy_id::StrategyId,
+        trader_id::TraderId, venue_order_id::VenueOrderId,
     },
-    identifiers::{client_order_id::ClientOrderId, strategy_id::StrategyId},
 };
 
+/// Wraps every order event.
+///
+/// Serializes internally tagged with a `"type"` field naming the event, e.g.
+/// `{"type": "OrderAccepted", ...}`, so a heterogeneous stream of events decodes directly.
 #[derive(Clone, PartialEq, Eq, Display, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
 pub enum OrderEventAny {
+    #[serde(rename = "OrderInitialized")]
     Initialized(OrderInitialized),
+    #[serde(rename = "OrderDenied")]
     Denied(OrderDenied),
+    #[serde(rename = "OrderEmulated")]
     Emulated(OrderEmulated),
+    #[serde(rename = "OrderReleased")]
     Released(OrderReleased),
+    #[serde(rename = "OrderSubmitted")]
     Submitted(OrderSubmitted),
+    #[serde(rename = "OrderAccepted")]
     Accepted(OrderAccepted),
+    #[serde(rename = "OrderRejected")]
     Rejected(OrderRejected),
+    #[serde(rename = "OrderCanceled")]
     Canceled(OrderCanceled),
+    #[serde(rename = "OrderExpired")]
     Expired(OrderExpired),
+    #[serde(rename = "OrderTriggered")]
     Triggered(OrderTriggered),
+    #[serde(rename = "OrderPendingUpdate")]
     PendingUpdate(OrderPendingUpdate),
+    #[serde(rename = "OrderPendingCancel")]
     PendingCancel(OrderPendingCancel),
+    #[serde(rename = "OrderModifyRejected")]
     ModifyRejected(OrderModifyRejected),
+    #[serde(rename = "OrderCancelRejected")]
     CancelRejected(OrderCancelRejected),
+    #[serde(rename = "OrderUpdated")]
     Updated(OrderUpdated),
+    #[serde(rename = "OrderPartiallyFilled")]
     PartiallyFilled(OrderFilled),
+    #[serde(rename = "OrderFilled")]
     Filled(OrderFilled),
 }
 
@@ -97,6 +122,52 @@ impl OrderEventAny {
         }
     }
 
+    #[must_use]
+    pub fn trader_id(&self) -> TraderId {
+        match self {
+            Self::Initialized(event) => event.trader_id,
+            Self::Denied(event) => event.trader_id,
+            Self::Emulated(event) => event.trader_id,
+            Self::Released(event) => event.trader_id,
+            Self::Submitted(event) => event.trader_id,
+            Self::Accepted(event) => event.trader_id,
+            Self::Rejected(event) => event.trader_id,
+            Self::Canceled(event) => event.trader_id,
+            Self::Expired(event) => event.trader_id,
+            Self::Triggered(event) => event.trader_id,
+            Self::PendingUpdate(event) => event.trader_id,
+            Self::PendingCancel(event) => event.trader_id,
+            Self::ModifyRejected(event) => event.trader_id,
+            Self::CancelRejected(event) => event.trader_id,
+            Self::Updated(event) => event.trader_id,
+            Self::PartiallyFilled(event) => event.trader_id,
+            Self::Filled(event) => event.trader_id,
+        }
+    }
+
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        match self {
+            Self::Initialized(event) => event.instrument_id,
+            Self::Denied(event) => event.instrument_id,
+            Self::Emulated(event) => event.instrument_id,
+            Self::Released(event) => event.instrument_id,
+            Self::Submitted(event) => event.instrument_id,
+            Self::Accepted(event) => event.instrument_id,
+            Self::Rejected(event) => event.instrument_id,
+            Self::Canceled(event) => event.instrument_id,
+            Self::Expired(event) => event.instrument_id,
+            Self::Triggered(event) => event.instrument_id,
+            Self::PendingUpdate(event) => event.instrument_id,
+            Self::PendingCancel(event) => event.instrument_id,
+            Self::ModifyRejected(event) => event.instrument_id,
+            Self::CancelRejected(event) => event.instrument_id,
+            Self::Updated(event) => event.instrument_id,
+            Self::PartiallyFilled(event) => event.instrument_id,
+            Self::Filled(event) => event.instrument_id,
+        }
+    }
+
     #[must_use]
     pub fn ts_event(&self) -> UnixNanos {
         match self {
@@ -119,4 +190,399 @@ impl OrderEventAny {
             Self::Filled(event) => event.ts_event,
         }
     }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Initialized(event) => event.ts_init,
+            Self::Denied(event) => event.ts_init,
+            Self::Emulated(event) => event.ts_init,
+            Self::Released(event) => event.ts_init,
+            Self::Submitted(event) => event.ts_init,
+            Self::Accepted(event) => event.ts_init,
+            Self::Rejected(event) => event.ts_init,
+            Self::Canceled(event) => event.ts_init,
+            Self::Expired(event) => event.ts_init,
+            Self::Triggered(event) => event.ts_init,
+            Self::PendingUpdate(event) => event.ts_init,
+            Self::PendingCancel(event) => event.ts_init,
+            Self::ModifyRejected(event) => event.ts_init,
+            Self::CancelRejected(event) => event.ts_init,
+            Self::Updated(event) => event.ts_init,
+            Self::PartiallyFilled(event) => event.ts_init,
+            Self::Filled(event) => event.ts_init,
+        }
+    }
+
+    #[must_use]
+    pub fn event_id(&self) -> UUID4 {
+        match self {
+            Self::Initialized(event) => event.event_id,
+            Self::Denied(event) => event.event_id,
+            Self::Emulated(event) => event.event_id,
+            Self::Released(event) => event.event_id,
+            Self::Submitted(event) => event.event_id,
+            Self::Accepted(event) => event.event_id,
+            Self::Rejected(event) => event.event_id,
+            Self::Canceled(event) => event.event_id,
+            Self::Expired(event) => event.event_id,
+            Self::Triggered(event) => event.event_id,
+            Self::PendingUpdate(event) => event.event_id,
+            Self::PendingCancel(event) => event.event_id,
+            Self::ModifyRejected(event) => event.event_id,
+            Self::CancelRejected(event) => event.event_id,
+            Self::Updated(event) => event.event_id,
+            Self::PartiallyFilled(event) => event.event_id,
+            Self::Filled(event) => event.event_id,
+        }
+    }
+
+    #[must_use]
+    pub fn venue_order_id(&self) -> Option<VenueOrderId> {
+        match self {
+            Self::Initialized(event) => event.venue_order_id(),
+            Self::Denied(event) => event.venue_order_id(),
+            Self::Emulated(event) => event.venue_order_id(),
+            Self::Released(event) => event.venue_order_id(),
+            Self::Submitted(event) => event.venue_order_id(),
+            Self::Accepted(event) => event.venue_order_id(),
+            Self::Rejected(event) => event.venue_order_id(),
+            Self::Canceled(event) => event.venue_order_id(),
+            Self::Expired(event) => event.venue_order_id(),
+            Self::Triggered(event) => event.venue_order_id(),
+            Self::PendingUpdate(event) => event.venue_order_id(),
+            Self::PendingCancel(event) => event.venue_order_id(),
+            Self::ModifyRejected(event) => event.venue_order_id(),
+            Self::CancelRejected(event) => event.venue_order_id(),
+            Self::Updated(event) => event.venue_order_id(),
+            Self::PartiallyFilled(event) => event.venue_order_id(),
+            Self::Filled(event) => event.venue_order_id(),
+        }
+    }
+
+    /// Returns the ID correlating the event with its originating command (if any).
+    #[must_use]
+    pub fn correlation_id(&self) -> Option<UUID4> {
+        match self {
+            Self::Submitted(event) => event.correlation_id.get(),
+            Self::Accepted(event) => event.correlation_id.get(),
+            Self::Rejected(event) => event.correlation_id.get(),
+            _ => None,
+        }
+    }
+
+    /// Sets the `correlation_id` for events which carry one, otherwise does nothing.
+    pub fn set_correlation_id(&mut self, correlation_id: Option<UUID4>) {
+        match self {
+            Self::Submitted(event) => event.correlation_id = correlation_id.into(),
+            Self::Accepted(event) => event.correlation_id = correlation_id.into(),
+            Self::Rejected(event) => event.correlation_id = correlation_id.into(),
+            _ => {}
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        events::order::stubs::*,
+        identifiers::{
+            account_id::AccountId,
+            stubs::{
+                account_id, client_order_id, instrument_id_btc_usdt, strategy_id_ema_cross,
+                trader_id, uuid4, venue_order_id,
+            },
+        },
+        types::price::Price,
+    };
+
+    fn all_events() -> Vec<OrderEventAny> {
+        vec![
+            OrderEventAny::Initialized(OrderInitialized::default()),
+            OrderEventAny::Denied(OrderDenied::default()),
+            OrderEventAny::Emulated(OrderEmulated::default()),
+            OrderEventAny::Released(OrderReleased::default()),
+            OrderEventAny::Submitted(OrderSubmitted::default()),
+            OrderEventAny::Accepted(OrderAccepted::default()),
+            OrderEventAny::Rejected(OrderRejected::default()),
+            OrderEventAny::Canceled(OrderCanceled::default()),
+            OrderEventAny::Expired(OrderExpired::default()),
+            OrderEventAny::Triggered(OrderTriggered::default()),
+            OrderEventAny::PendingUpdate(OrderPendingUpdate::default()),
+            OrderEventAny::PendingCancel(OrderPendingCancel::default()),
+            OrderEventAny::ModifyRejected(OrderModifyRejected::default()),
+            OrderEventAny::CancelRejected(OrderCancelRejected::default()),
+            OrderEventAny::Updated(OrderUpdated {
+                price: Some(Price::from("1.00000")),
+                ..Default::default()
+            }),
+            OrderEventAny::PartiallyFilled(OrderFilled::default()),
+            OrderEventAny::Filled(OrderFilled::default()),
+        ]
+    }
+
+    #[rstest]
+    fn test_serde_json_round_trip_every_variant() {
+        for event in all_events() {
+            let json = serde_json::to_string(&event).unwrap();
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            let decoded: OrderEventAny = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(value["type"], format!("Order{event}"), "{event}");
+            assert_eq!(decoded, event, "{event}");
+        }
+    }
+
+    #[rstest]
+    fn test_serde_msgpack_round_trip_every_variant() {
+        for event in all_events() {
+            let bytes = rmp_serde::to_vec_named(&event).unwrap();
+            let decoded: OrderEventAny = rmp_serde::from_slice(&bytes).unwrap();
+
+            assert_eq!(decoded, event, "{event}");
+        }
+    }
+
+    #[rstest]
+    fn test_serde_decodes_heterogeneous_stream() {
+        let events = all_events();
+        let json = serde_json::to_string(&events).unwrap();
+
+        let decoded: Vec<OrderEventAny> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, events);
+    }
+
+    #[rstest]
+    fn test_serde_decodes_flat_struct_json_with_type() {
+        let accepted = OrderAccepted::default();
+        let mut value = serde_json::to_value(accepted).unwrap();
+        value["type"] = "OrderAccepted".into();
+
+        let decoded: OrderEventAny = serde_json::from_value(value).unwrap();
+
+        assert_eq!(decoded, OrderEventAny::Accepted(accepted));
+    }
+
+    #[rstest]
+    fn test_serde_unknown_type_errors() {
+        let result = serde_json::from_str::<OrderEventAny>(r#"{"type": "OrderExploded"}"#);
+
+        assert!(result.unwrap_err().to_string().contains("unknown variant"));
+    }
+
+    #[rstest]
+    fn test_serde_missing_type_errors() {
+        let result = serde_json::from_str::<OrderEventAny>(r#"{"client_order_id": "O-1"}"#);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("missing field `type`"));
+    }
+
+    #[rstest]
+    #[allow(clippy::too_many_arguments)]
+    fn test_order_event_any_accessors(
+        trader_id: TraderId,
+        strategy_id_ema_cross: StrategyId,
+        instrument_id_btc_usdt: InstrumentId,
+        client_order_id: ClientOrderId,
+        account_id: AccountId,
+        venue_order_id: VenueOrderId,
+        uuid4: UUID4,
+    ) {
+        let order_filled = order_filled(
+            trader_id,
+            strategy_id_ema_cross,
+            instrument_id_btc_usdt,
+            client_order_id,
+            uuid4,
+        );
+        let filled_venue_order_id = order_filled.venue_order_id;
+        let events = vec![
+            (
+                OrderEventAny::Initialized(order_initialized_buy_limit(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    uuid4,
+                )),
+                None,
+            ),
+            (
+                OrderEventAny::Denied(order_denied_max_submitted_rate(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    uuid4,
+                )),
+                None,
+            ),
+            (
+                OrderEventAny::Emulated(order_emulated(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    uuid4,
+                )),
+                None,
+            ),
+            (
+                OrderEventAny::Released(order_released(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    uuid4,
+                )),
+                None,
+            ),
+            (
+                OrderEventAny::Submitted(order_submitted(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    account_id,
+                    uuid4,
+                )),
+                None,
+            ),
+            (
+                OrderEventAny::Accepted(order_accepted(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    account_id,
+                    venue_order_id,
+                    uuid4,
+                )),
+                Some(venue_order_id),
+            ),
+            (
+                OrderEventAny::Rejected(order_rejected_insufficient_margin(
+                    trader_id,
+                    account_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    uuid4,
+                )),
+                None,
+            ),
+            (
+                OrderEventAny::Expired(order_expired(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    venue_order_id,
+                    account_id,
+                    uuid4,
+                )),
+                Some(venue_order_id),
+            ),
+            (
+                OrderEventAny::Triggered(order_triggered(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    venue_order_id,
+                    account_id,
+                    uuid4,
+                )),
+                Some(venue_order_id),
+            ),
+            (
+                OrderEventAny::PendingUpdate(order_pending_update(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    account_id,
+                    venue_order_id,
+                    uuid4,
+                )),
+                Some(venue_order_id),
+            ),
+            (
+                OrderEventAny::PendingCancel(order_pending_cancel(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    account_id,
+                    venue_order_id,
+                    uuid4,
+                )),
+                Some(venue_order_id),
+            ),
+            (
+                OrderEventAny::ModifyRejected(order_modify_rejected(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    venue_order_id,
+                    account_id,
+                    uuid4,
+                )),
+                Some(venue_order_id),
+            ),
+            (
+                OrderEventAny::CancelRejected(order_cancel_rejected(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    venue_order_id,
+                    account_id,
+                    uuid4,
+                )),
+                Some(venue_order_id),
+            ),
+            (
+                OrderEventAny::Updated(order_updated(
+                    trader_id,
+                    strategy_id_ema_cross,
+                    instrument_id_btc_usdt,
+                    client_order_id,
+                    venue_order_id,
+                    account_id,
+                    uuid4,
+                )),
+                Some(venue_order_id),
+            ),
+            (
+                OrderEventAny::PartiallyFilled(order_filled),
+                Some(filled_venue_order_id),
+            ),
+            (
+                OrderEventAny::Filled(order_filled),
+                Some(filled_venue_order_id),
+            ),
+        ];
+
+        for (event, expected_venue_order_id) in &events {
+            assert_eq!(event.trader_id(), trader_id, "{event}");
+            assert_eq!(event.strategy_id(), strategy_id_ema_cross, "{event}");
+            assert_eq!(event.instrument_id(), instrument_id_btc_usdt, "{event}");
+            assert_eq!(event.client_order_id(), client_order_id, "{event}");
+            assert_eq!(event.event_id(), uuid4, "{event}");
+            assert_eq!(event.venue_order_id(), *expected_venue_order_id, "{event}");
+            assert_eq!(event.ts_event(), UnixNanos::default(), "{event}");
+        }
+    }
 }
@@ -16,7 +16,7 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -32,13 +32,12 @@ use crate::{
         strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
         venue_order_id::VenueOrderId,
     },
+    orders::base::OrderError,
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
-#[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Builder)]
-#[builder(default)]
-#[serde(tag = "type")]
+#[builder(default, build_fn(private, name = "build_unchecked"))]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -66,6 +65,14 @@ pub struct OrderFilled {
 }
 
 impl OrderFilled {
+    /// Creates a new [`OrderFilled`] instance, validating the fill invariants.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - `last_qty` is not positive.
+    /// - `last_px` is not positive.
+    /// - `liquidity_side` is `NoLiquiditySide` for a fill which is not a reconciliation.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         trader_id: TraderId,
@@ -87,8 +94,8 @@ impl OrderFilled {
         reconciliation: bool,
         position_id: Option<PositionId>,
         commission: Option<Money>,
-    ) -> anyhow::Result<Self> {
-        Ok(Self {
+    ) -> Result<Self, OrderError> {
+        let fill = Self {
             trader_id,
             strategy_id,
             instrument_id,
@@ -108,7 +115,50 @@ impl OrderFilled {
             reconciliation,
             position_id,
             commission,
-        })
+        };
+        fill.validate()?;
+        Ok(fill)
+    }
+
+    /// Checks the invariants enforced by [`OrderFilled::new`].
+    ///
+    /// Reconciliation fills are exempt from the liquidity side check, as the side cannot
+    /// always be inferred from an external order report.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any invariant fails.
+    pub fn validate(&self) -> Result<(), OrderError> {
+        if !self.last_qty.is_positive() {
+            return Err(OrderError::FillQuantityNotPositive(self.last_qty));
+        }
+        if !self.last_px.is_positive() {
+            return Err(OrderError::FillPriceNotPositive(self.last_px));
+        }
+        if self.liquidity_side == LiquiditySide::NoLiquiditySide && !self.reconciliation {
+            return Err(OrderError::FillNoLiquiditySide);
+        }
+        Ok(())
+    }
+
+    /// Checks the `commission` (if any) is denominated in the fill `currency`.
+    ///
+    /// This is not enforced on construction, as commissions on inverse instruments are
+    /// charged in the base currency and some venues charge fees in a separate asset.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the currencies differ.
+    pub fn check_commission_currency(&self) -> Result<(), OrderError> {
+        match self.commission {
+            Some(commission) if commission.currency != self.currency => {
+                Err(OrderError::FillCommissionCurrencyMismatch {
+                    commission: commission.currency,
+                    currency: self.currency,
+                })
+            }
+            _ => Ok(()),
+        }
     }
 
     #[must_use]
@@ -120,6 +170,51 @@ impl OrderFilled {
     pub fn is_sell(&self) -> bool {
         self.order_side == OrderSide::Sell
     }
+
+    /// Returns the value of the fill in the fill `currency`, for an instrument with the given
+    /// contract `multiplier`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the value is outside the range of `Money`.
+    pub fn notional_value(&self, multiplier: Quantity) -> anyhow::Result<Money> {
+        let amount = self.last_qty.as_f64() * multiplier.as_f64() * self.last_px.as_f64();
+        Money::new(amount, self.currency)
+    }
+
+    /// Returns the fill quantity, positive for buys and negative for sells.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `order_side` is `NoOrderSide`.
+    pub fn signed_qty(&self) -> anyhow::Result<f64> {
+        match self.order_side {
+            OrderSide::Buy => Ok(self.last_qty.as_f64()),
+            OrderSide::Sell => Ok(-self.last_qty.as_f64()),
+            OrderSide::NoOrderSide => {
+                anyhow::bail!(
+                    "Cannot sign quantity of fill {}: no order side",
+                    self.trade_id
+                )
+            }
+        }
+    }
+}
+
+impl OrderFilledBuilder {
+    /// Builds a new [`OrderFilled`], validating the fill invariants.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any invariant checked by [`OrderFilled::new`] fails.
+    pub fn build(&self) -> Result<OrderFilled, OrderError> {
+        // All fields have defaults, so building the unchecked struct cannot fail
+        let fill = self
+            .build_unchecked()
+            .expect("`OrderFilledBuilder` fields should all have defaults");
+        fill.validate()?;
+        Ok(fill)
+    }
 }
 
 impl Default for OrderFilled {
@@ -225,13 +320,15 @@ impl Display for OrderFilled {
             self.venue_order_id,
             self.account_id,
             self.trade_id,
-            self.position_id.unwrap_or_default(),
+            self.position_id
+                .map_or("None".to_string(), |position_id| position_id.to_string()),
             self.order_side,
             self.order_type,
             self.last_qty.to_formatted_string(),
             self.last_px.to_formatted_string(),
             self.currency,
-            self.commission.unwrap_or(Money::from("0.0 USD")),
+            self.commission
+                .map_or("None".to_string(), |commission| commission.to_string()),
             self.liquidity_side,
             self.ts_event
         )
@@ -308,7 +405,7 @@ impl OrderEvent for OrderFilled {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation
     }
 
     fn price(&self) -> Option<Price> {
@@ -408,17 +505,61 @@ impl OrderEvent for OrderFilled {
     }
 }
 
+impl Serializable for OrderFilled {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::serialization::{DecimalFormat, Serializable, SerializationConfig};
     use rstest::rstest;
 
-    use crate::events::order::{filled::OrderFilled, stubs::*};
+    use crate::{
+        enums::{LiquiditySide, OrderSide},
+        events::order::{
+            filled::{OrderFilled, OrderFilledBuilder},
+            stubs::*,
+        },
+        identifiers::position_id::PositionId,
+        orders::base::OrderError,
+        types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    };
+
+    fn new_fill(
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+        reconciliation: bool,
+    ) -> Result<OrderFilled, OrderError> {
+        let fill = OrderFilled::default();
+        OrderFilled::new(
+            fill.trader_id,
+            fill.strategy_id,
+            fill.instrument_id,
+            fill.client_order_id,
+            fill.venue_order_id,
+            fill.account_id,
+            fill.trade_id,
+            fill.order_side,
+            fill.order_type,
+            last_qty,
+            last_px,
+            fill.currency,
+            liquidity_side,
+            fill.event_id,
+            fill.ts_event,
+            fill.ts_init,
+            reconciliation,
+            None,
+            None,
+        )
+    }
 
     #[rstest]
-    fn test_order_filled_display(order_filled: OrderFilled) {
+    fn test_order_filled_display(mut order_filled: OrderFilled) {
+        order_filled.position_id = Some(PositionId::from("P-001"));
+
         let display = format!("{order_filled}");
         assert_eq!(
             display,
@@ -428,9 +569,175 @@ mod tests {
             commission=12.20000000 USDT, liquidity_side=TAKER, ts_event=0)");
     }
 
+    #[rstest]
+    fn test_order_filled_display_without_position_id_or_commission(mut order_filled: OrderFilled) {
+        order_filled.position_id = None;
+        order_filled.commission = None;
+
+        let display = format!("{order_filled}");
+        assert_eq!(
+            display,
+            "OrderFilled(instrument_id=BTCUSDT.COINBASE, client_order_id=O-19700101-0000-000-001-1, \
+            venue_order_id=123456, account_id=SIM-001, trade_id=1, position_id=None, \
+            order_side=BUY, order_type=LIMIT, last_qty=0.561, last_px=22_000 USDT, \
+            commission=None, liquidity_side=TAKER, ts_event=0)");
+    }
+
     #[rstest]
     fn test_order_filled_is_buy(order_filled: OrderFilled) {
         assert!(order_filled.is_buy());
         assert!(!order_filled.is_sell());
     }
+
+    #[rstest]
+    fn test_order_filled_notional_value(order_filled: OrderFilled) {
+        let notional = order_filled.notional_value(Quantity::from(1)).unwrap();
+
+        // 0.561 * 22000
+        assert_eq!(notional, Money::from("12342 USDT"));
+    }
+
+    #[rstest]
+    fn test_order_filled_notional_value_with_multiplier(mut order_filled: OrderFilled) {
+        // ES futures style contract with a multiplier of 50
+        order_filled.last_qty = Quantity::from(2);
+        order_filled.last_px = Price::from("4500.25");
+
+        let notional = order_filled.notional_value(Quantity::from(50)).unwrap();
+
+        assert_eq!(notional, Money::from("450025 USDT"));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 3.0)]
+    #[case(OrderSide::Sell, -3.0)]
+    fn test_order_filled_signed_qty(
+        mut order_filled: OrderFilled,
+        #[case] order_side: OrderSide,
+        #[case] expected: f64,
+    ) {
+        order_filled.order_side = order_side;
+        order_filled.last_qty = Quantity::from(3);
+
+        assert_eq!(order_filled.signed_qty().unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_order_filled_signed_qty_with_no_order_side(mut order_filled: OrderFilled) {
+        order_filled.order_side = OrderSide::NoOrderSide;
+
+        assert!(order_filled.signed_qty().is_err());
+    }
+
+    #[rstest]
+    #[case(DecimalFormat::Number)]
+    #[case(DecimalFormat::StringCanonical)]
+    #[case(DecimalFormat::RawParts)]
+    fn test_order_filled_json_round_trip(
+        order_filled: OrderFilled,
+        #[case] decimal_format: DecimalFormat,
+    ) {
+        let config = SerializationConfig::new(decimal_format);
+
+        let bytes = order_filled.as_json_bytes_with_config(config).unwrap();
+        let deserialized = OrderFilled::from_json_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, order_filled);
+    }
+
+    #[rstest]
+    fn test_order_filled_new_valid() {
+        let fill = new_fill(
+            Quantity::from(10),
+            Price::from("1.00010"),
+            LiquiditySide::Maker,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(fill.last_qty, Quantity::from(10));
+        assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+    }
+
+    #[rstest]
+    fn test_order_filled_new_with_zero_quantity_is_rejected() {
+        let result = new_fill(
+            Quantity::from(0),
+            Price::from("1.00010"),
+            LiquiditySide::Taker,
+            false,
+        );
+
+        assert!(matches!(
+            result,
+            Err(OrderError::FillQuantityNotPositive(qty)) if qty.is_zero()
+        ));
+    }
+
+    #[rstest]
+    #[case(Price::from("0.00000"))]
+    #[case(Price::from("-1.00000"))]
+    fn test_order_filled_new_with_non_positive_price_is_rejected(#[case] last_px: Price) {
+        let result = new_fill(Quantity::from(10), last_px, LiquiditySide::Taker, false);
+
+        assert!(matches!(result, Err(OrderError::FillPriceNotPositive(_))));
+    }
+
+    #[rstest]
+    fn test_order_filled_new_with_no_liquidity_side_is_rejected() {
+        let result = new_fill(
+            Quantity::from(10),
+            Price::from("1.00010"),
+            LiquiditySide::NoLiquiditySide,
+            false,
+        );
+
+        assert!(matches!(result, Err(OrderError::FillNoLiquiditySide)));
+    }
+
+    #[rstest]
+    fn test_order_filled_new_reconciliation_allows_no_liquidity_side() {
+        let result = new_fill(
+            Quantity::from(10),
+            Price::from("1.00010"),
+            LiquiditySide::NoLiquiditySide,
+            true,
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[rstest]
+    fn test_order_filled_builder_validates() {
+        let result = OrderFilledBuilder::default()
+            .last_qty(Quantity::from(0))
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(OrderError::FillQuantityNotPositive(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_order_filled_check_commission_currency(mut order_filled: OrderFilled) {
+        assert!(order_filled.check_commission_currency().is_ok());
+
+        order_filled.commission = Some(Money::from("1.00 USD"));
+
+        assert!(matches!(
+            order_filled.check_commission_currency(),
+            Err(OrderError::FillCommissionCurrencyMismatch { commission, currency })
+                if commission == Currency::USD() && currency == Currency::USDT()
+        ));
+    }
+
+    #[rstest]
+    fn test_order_filled_check_commission_currency_without_commission(
+        mut order_filled: OrderFilled,
+    ) {
+        order_filled.commission = None;
+
+        assert!(order_filled.check_commission_currency().is_ok());
+    }
 }
@@ -0,0 +1,555 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides an audit trail export of order events as FIX style `tag=value` text.
+//!
+//! Each event is written as one line of `|` separated fields, modelled on a FIX 4.4 execution
+//! report. This is not a FIX engine: there is no session header, body length or checksum, only a
+//! faithful and parseable record of each order lifecycle.
+//!
+//! # Event mapping
+//!
+//! | Event              | 35 MsgType | 150 ExecType          | 434 CxlRejResponseTo |
+//! |--------------------|------------|-----------------------|----------------------|
+//! | `Initialized`      | `8`        | `A` (Pending New)     |                      |
+//! | `Denied`           | `8`        | `8` (Rejected)        |                      |
+//! | `Emulated`         | `8`        | `A` (Pending New)     |                      |
+//! | `Released`         | `8`        | `A` (Pending New)     |                      |
+//! | `Submitted`        | `8`        | `A` (Pending New)     |                      |
+//! | `Accepted`         | `8`        | `0` (New)             |                      |
+//! | `Rejected`         | `8`        | `8` (Rejected)        |                      |
+//! | `Canceled`         | `8`        | `4` (Canceled)        |                      |
+//! | `Expired`          | `8`        | `C` (Expired)         |                      |
+//! | `Triggered`        | `8`        | `L` (Triggered)       |                      |
+//! | `PendingUpdate`    | `8`        | `E` (Pending Replace) |                      |
+//! | `PendingCancel`    | `8`        | `6` (Pending Cancel)  |                      |
+//! | `ModifyRejected`   | `9`        |                       | `2` (Replace)        |
+//! | `CancelRejected`   | `9`        |                       | `1` (Cancel)         |
+//! | `Updated`          | `8`        | `5` (Replaced)        |                      |
+//! | `PartiallyFilled`  | `8`        | `F` (Trade)           |                      |
+//! | `Filled`           | `8`        | `F` (Trade)           |                      |
+//!
+//! Modify and cancel rejects are written as order cancel reject (`35=9`) messages, as in FIX.
+//!
+//! # Fields
+//!
+//! Fields are written in the order below, and omitted when not known for the order (e.g. the
+//! order fields when the stream has no `OrderInitialized` event) or not applicable to the event.
+//!
+//! | Tag   | Name             | Value                                                             |
+//! |-------|------------------|-------------------------------------------------------------------|
+//! | `35`  | MsgType          | See the event mapping above                                       |
+//! | `150` | ExecType         | See the event mapping above                                       |
+//! | `434` | CxlRejResponseTo | See the event mapping above                                       |
+//! | `39`  | OrdStatus        | The order status after the event                                  |
+//! | `11`  | ClOrdID          | The client order ID                                               |
+//! | `37`  | OrderID          | The venue order ID, or `NONE` before one is assigned              |
+//! | `17`  | ExecID           | The trade ID (fills only)                                         |
+//! | `55`  | Symbol           | The instrument ID                                                 |
+//! | `54`  | Side             | `1` (Buy) or `2` (Sell)                                           |
+//! | `38`  | OrderQty         | The order quantity (as updated)                                   |
+//! | `40`  | OrdType          | `1` Market, `2` Limit, `3` Stop, `4` Stop Limit, `J` MIT, `K` MTL |
+//! | `44`  | Price            | The limit price (as updated)                                      |
+//! | `99`  | StopPx           | The trigger price (as updated)                                    |
+//! | `59`  | TimeInForce      | `0` Day, `1` GTC, `2` OPG, `3` IOC, `4` FOK, `6` GTD, `7` Close   |
+//! | `32`  | LastQty          | The fill quantity (fills only)                                    |
+//! | `31`  | LastPx           | The fill price (fills only)                                       |
+//! | `14`  | CumQty           | The total filled quantity                                         |
+//! | `151` | LeavesQty        | The order quantity less the filled quantity                       |
+//! | `6`   | AvgPx            | The average fill price (fills only)                               |
+//! | `58`  | Text             | The reason, with any field separator replaced by a space          |
+//! | `60`  | TransactTime     | The event time as `YYYYMMDD-HH:MM:SS.nnnnnnnnn` UTC               |
+//!
+//! Order types with no FIX equivalent (limit if touched and trailing stops) omit tag `40`.
+
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    io::Write,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use chrono::{DateTime, Utc};
+use nautilus_core::nanos::UnixNanos;
+
+use super::{
+    event::OrderEventAny,
+    timeline::{as_order_event, next_status},
+};
+use crate::{
+    enums::{OrderSide, OrderStatus, OrderType, TimeInForce},
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, venue_order_id::VenueOrderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+const FIELD_SEPARATOR: &str = "|";
+const UNKNOWN_ORDER_ID: &str = "NONE";
+
+/// The state of a single order, accumulated from its events.
+#[derive(Default)]
+struct OrderState {
+    status: Option<OrderStatus>,
+    previous_status: Option<OrderStatus>,
+    instrument_id: Option<InstrumentId>,
+    venue_order_id: Option<VenueOrderId>,
+    order_side: Option<OrderSide>,
+    order_type: Option<OrderType>,
+    time_in_force: Option<TimeInForce>,
+    quantity: Option<Quantity>,
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    filled_qty: Option<Quantity>,
+    filled_notional: f64,
+}
+
+/// Writes the given order `events` to the `writer` as FIX style execution report lines.
+///
+/// The events may interleave several orders, and are written in the given order, one line per
+/// event. See the [module documentation](self) for the mapping of events and fields.
+///
+/// # Errors
+///
+/// This function returns an error if writing to the `writer` fails.
+pub fn export_fix_audit<E: Borrow<OrderEventAny>, W: Write>(
+    events: &[E],
+    mut writer: W,
+) -> std::io::Result<()> {
+    let mut orders: HashMap<ClientOrderId, OrderState> = HashMap::new();
+
+    for event in events {
+        let event = event.borrow();
+        let state = orders.entry(event.client_order_id()).or_default();
+        apply_event(state, event);
+        writeln!(writer, "{}", format_line(state, event))?;
+    }
+
+    writer.flush()
+}
+
+fn apply_event(state: &mut OrderState, event: &OrderEventAny) {
+    state.instrument_id = Some(event.instrument_id());
+    if let Some(venue_order_id) = event.venue_order_id() {
+        state.venue_order_id = Some(venue_order_id);
+    }
+
+    match event {
+        OrderEventAny::Initialized(init) => {
+            state.order_side = Some(init.order_side);
+            state.order_type = Some(init.order_type);
+            state.time_in_force = Some(init.time_in_force);
+            state.quantity = Some(init.quantity);
+            state.price = init.price;
+            state.trigger_price = init.trigger_price;
+        }
+        OrderEventAny::Updated(updated) => {
+            state.quantity = Some(updated.quantity);
+            state.price = updated.price.or(state.price);
+            state.trigger_price = updated.trigger_price.or(state.trigger_price);
+        }
+        OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => {
+            state.order_side.get_or_insert(fill.order_side);
+            state.order_type.get_or_insert(fill.order_type);
+            state.filled_qty = Some(
+                state
+                    .filled_qty
+                    .map_or(fill.last_qty, |qty| qty + fill.last_qty),
+            );
+            state.filled_notional += fill.last_qty.as_f64() * fill.last_px.as_f64();
+        }
+        _ => {}
+    }
+
+    let status = state.status.unwrap_or(OrderStatus::Initialized);
+    if let Ok(next) = next_status(status, state.previous_status, event) {
+        state.previous_status = Some(status);
+        state.status = Some(next);
+    }
+}
+
+fn format_line(state: &OrderState, event: &OrderEventAny) -> String {
+    let mut fields: Vec<(u16, String)> = Vec::new();
+
+    match event {
+        OrderEventAny::ModifyRejected(_) => {
+            fields.push((35, "9".to_string()));
+            fields.push((434, "2".to_string()));
+        }
+        OrderEventAny::CancelRejected(_) => {
+            fields.push((35, "9".to_string()));
+            fields.push((434, "1".to_string()));
+        }
+        _ => {
+            fields.push((35, "8".to_string()));
+            fields.push((150, fix_exec_type(event).to_string()));
+        }
+    }
+    if let Some(status) = state.status {
+        fields.push((39, fix_ord_status(status).to_string()));
+    }
+    fields.push((11, event.client_order_id().to_string()));
+    fields.push((
+        37,
+        state
+            .venue_order_id
+            .map_or(UNKNOWN_ORDER_ID.to_string(), |id| id.to_string()),
+    ));
+
+    let fill = match event {
+        OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => Some(fill),
+        _ => None,
+    };
+    if let Some(fill) = fill {
+        fields.push((17, fill.trade_id.to_string()));
+    }
+    if let Some(instrument_id) = state.instrument_id {
+        fields.push((55, instrument_id.to_string()));
+    }
+    if let Some(side) = state.order_side.and_then(fix_side) {
+        fields.push((54, side.to_string()));
+    }
+    if let Some(quantity) = state.quantity {
+        fields.push((38, quantity.to_string()));
+    }
+    if let Some(ord_type) = state.order_type.and_then(fix_ord_type) {
+        fields.push((40, ord_type.to_string()));
+    }
+    if let Some(price) = state.price {
+        fields.push((44, price.to_string()));
+    }
+    if let Some(trigger_price) = state.trigger_price {
+        fields.push((99, trigger_price.to_string()));
+    }
+    if let Some(time_in_force) = state.time_in_force {
+        fields.push((59, fix_time_in_force(time_in_force).to_string()));
+    }
+    if let Some(fill) = fill {
+        fields.push((32, fill.last_qty.to_string()));
+        fields.push((31, fill.last_px.to_string()));
+    }
+    if let Some(quantity) = state.quantity {
+        let filled_qty = state
+            .filled_qty
+            .unwrap_or_else(|| Quantity::zero(quantity.precision));
+        let leaves_qty = if filled_qty < quantity {
+            quantity - filled_qty
+        } else {
+            Quantity::zero(quantity.precision)
+        };
+        fields.push((14, filled_qty.to_string()));
+        fields.push((151, leaves_qty.to_string()));
+    }
+    if let (Some(filled_qty), Some(fill)) = (state.filled_qty, fill) {
+        let avg_px = state.filled_notional / filled_qty.as_f64();
+        let precision = usize::from(fill.last_px.precision);
+        fields.push((6, format!("{avg_px:.precision$}")));
+    }
+    if let Some(reason) = as_order_event(event).reason() {
+        fields.push((58, reason.replace(FIELD_SEPARATOR, " ")));
+    }
+    fields.push((60, format_transact_time(event.ts_event())));
+
+    fields
+        .iter()
+        .map(|(tag, value)| format!("{tag}={value}"))
+        .collect::<Vec<String>>()
+        .join(FIELD_SEPARATOR)
+}
+
+/// Returns the FIX `ExecType` (tag 150) for the execution report `event`.
+fn fix_exec_type(event: &OrderEventAny) -> char {
+    match event {
+        OrderEventAny::Initialized(_)
+        | OrderEventAny::Emulated(_)
+        | OrderEventAny::Released(_)
+        | OrderEventAny::Submitted(_) => 'A',
+        OrderEventAny::Accepted(_) => '0',
+        OrderEventAny::Denied(_) | OrderEventAny::Rejected(_) => '8',
+        OrderEventAny::Canceled(_) => '4',
+        OrderEventAny::Expired(_) => 'C',
+        OrderEventAny::Triggered(_) => 'L',
+        OrderEventAny::PendingUpdate(_) | OrderEventAny::ModifyRejected(_) => 'E',
+        OrderEventAny::PendingCancel(_) | OrderEventAny::CancelRejected(_) => '6',
+        OrderEventAny::Updated(_) => '5',
+        OrderEventAny::PartiallyFilled(_) | OrderEventAny::Filled(_) => 'F',
+    }
+}
+
+/// Returns the FIX `OrdStatus` (tag 39) for the order `status`.
+///
+/// Orders not yet acknowledged by the venue are `A` (Pending New), and triggered orders are
+/// `0` (New) as they are still working.
+fn fix_ord_status(status: OrderStatus) -> char {
+    match status {
+        OrderStatus::Initialized
+        | OrderStatus::Emulated
+        | OrderStatus::Released
+        | OrderStatus::Submitted => 'A',
+        OrderStatus::Accepted | OrderStatus::Triggered => '0',
+        OrderStatus::Denied | OrderStatus::Rejected => '8',
+        OrderStatus::Canceled => '4',
+        OrderStatus::Expired => 'C',
+        OrderStatus::PendingUpdate => 'E',
+        OrderStatus::PendingCancel => '6',
+        OrderStatus::PartiallyFilled => '1',
+        OrderStatus::Filled => '2',
+    }
+}
+
+/// Returns the FIX `Side` (tag 54) for the `order_side`.
+fn fix_side(order_side: OrderSide) -> Option<char> {
+    match order_side {
+        OrderSide::Buy => Some('1'),
+        OrderSide::Sell => Some('2'),
+        OrderSide::NoOrderSide => None,
+    }
+}
+
+/// Returns the FIX `OrdType` (tag 40) for the `order_type`, if it has a standard equivalent.
+fn fix_ord_type(order_type: OrderType) -> Option<char> {
+    match order_type {
+        OrderType::Market => Some('1'),
+        OrderType::Limit => Some('2'),
+        OrderType::StopMarket => Some('3'),
+        OrderType::StopLimit => Some('4'),
+        OrderType::MarketIfTouched => Some('J'),
+        OrderType::MarketToLimit => Some('K'),
+        OrderType::LimitIfTouched
+        | OrderType::TrailingStopMarket
+        | OrderType::TrailingStopLimit => None,
+    }
+}
+
+/// Returns the FIX `TimeInForce` (tag 59) for the `time_in_force`.
+fn fix_time_in_force(time_in_force: TimeInForce) -> char {
+    match time_in_force {
+        TimeInForce::Day => '0',
+        TimeInForce::Gtc => '1',
+        TimeInForce::AtTheOpen => '2',
+        TimeInForce::Ioc => '3',
+        TimeInForce::Fok => '4',
+        TimeInForce::Gtd => '6',
+        TimeInForce::AtTheClose => '7',
+    }
+}
+
+fn format_transact_time(ts: UnixNanos) -> String {
+    DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_nanos(ts.as_u64()))
+        .format("%Y%m%d-%H:%M:%S%.9f")
+        .to_string()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::*;
+    use crate::{
+        enums::LiquiditySide,
+        events::order::{
+            accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, filled::OrderFilled,
+            initialized::OrderInitialized, pending_cancel::OrderPendingCancel,
+            submitted::OrderSubmitted,
+        },
+        identifiers::trade_id::TradeId,
+        orders::{base::Order, stubs::TestOrderStubs},
+        stubs::{account_id, audusd_sim_id},
+        types::currency::Currency,
+    };
+
+    const MS: u64 = 1_000_000;
+    const TS_INIT: u64 = 1_700_000_000_000_000_000;
+
+    fn ts(ms: u64) -> UnixNanos {
+        (TS_INIT + ms * MS).into()
+    }
+
+    fn initialized() -> OrderInitialized {
+        let order = TestOrderStubs::limit_order(
+            audusd_sim_id(),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-1")),
+            None,
+        );
+        let OrderEventAny::Initialized(mut init) = order.events()[0].clone() else {
+            panic!("expected `OrderInitialized`");
+        };
+        init.ts_event = ts(0);
+        init.ts_init = ts(0);
+        init
+    }
+
+    fn filled(init: &OrderInitialized, last_qty: &str, last_px: &str, ms: u64) -> OrderFilled {
+        OrderFilled::new(
+            init.trader_id,
+            init.strategy_id,
+            init.instrument_id,
+            init.client_order_id,
+            VenueOrderId::from("V-1"),
+            account_id(),
+            TradeId::from(format!("T-{ms}").as_str()),
+            init.order_side,
+            init.order_type,
+            Quantity::from(last_qty),
+            Price::from(last_px),
+            Currency::USD(),
+            LiquiditySide::Maker,
+            UUID4::new(),
+            ts(ms),
+            ts(ms),
+            false,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn export(events: &[OrderEventAny]) -> String {
+        let mut buffer = Vec::new();
+        export_fix_audit(events, &mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[rstest]
+    fn test_export_empty() {
+        let events: Vec<OrderEventAny> = Vec::new();
+
+        assert_eq!(export(&events), "");
+    }
+
+    #[rstest]
+    fn test_export_lifecycle_with_partial_fill_and_cancel_reject() {
+        let init = initialized();
+        let events = vec![
+            OrderEventAny::Initialized(init.clone()),
+            OrderEventAny::Submitted(
+                OrderSubmitted::new(
+                    init.trader_id,
+                    init.strategy_id,
+                    init.instrument_id,
+                    init.client_order_id,
+                    account_id(),
+                    UUID4::new(),
+                    ts(1),
+                    ts(1),
+                    None,
+                )
+                .unwrap(),
+            ),
+            OrderEventAny::Accepted(
+                OrderAccepted::new(
+                    init.trader_id,
+                    init.strategy_id,
+                    init.instrument_id,
+                    init.client_order_id,
+                    VenueOrderId::from("V-1"),
+                    account_id(),
+                    UUID4::new(),
+                    ts(3),
+                    ts(3),
+                    false,
+                    None,
+                )
+                .unwrap(),
+            ),
+            OrderEventAny::PartiallyFilled(filled(&init, "40000", "1.00000", 1_500)),
+            OrderEventAny::PendingCancel(
+                OrderPendingCancel::new(
+                    init.trader_id,
+                    init.strategy_id,
+                    init.instrument_id,
+                    init.client_order_id,
+                    account_id(),
+                    UUID4::new(),
+                    ts(2_000),
+                    ts(2_000),
+                    false,
+                    None,
+                )
+                .unwrap(),
+            ),
+            OrderEventAny::CancelRejected(
+                OrderCancelRejected::new(
+                    init.trader_id,
+                    init.strategy_id,
+                    init.instrument_id,
+                    init.client_order_id,
+                    Ustr::from("TOO_LATE_TO_CANCEL"),
+                    UUID4::new(),
+                    ts(2_001),
+                    ts(2_001),
+                    false,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            ),
+            OrderEventAny::Filled(filled(&init, "60000", "0.99999", 2_002)),
+        ];
+
+        let expected = "\
+35=8|150=A|39=A|11=O-1|37=NONE|55=AUD/USD.SIM|54=1|38=100000|40=2|44=1.00000|59=1|14=0|151=100000|60=20231114-22:13:20.000000000
+35=8|150=A|39=A|11=O-1|37=NONE|55=AUD/USD.SIM|54=1|38=100000|40=2|44=1.00000|59=1|14=0|151=100000|60=20231114-22:13:20.001000000
+35=8|150=0|39=0|11=O-1|37=V-1|55=AUD/USD.SIM|54=1|38=100000|40=2|44=1.00000|59=1|14=0|151=100000|60=20231114-22:13:20.003000000
+35=8|150=F|39=1|11=O-1|37=V-1|17=T-1500|55=AUD/USD.SIM|54=1|38=100000|40=2|44=1.00000|59=1|32=40000|31=1.00000|14=40000|151=60000|6=1.00000|60=20231114-22:13:21.500000000
+35=8|150=6|39=6|11=O-1|37=V-1|55=AUD/USD.SIM|54=1|38=100000|40=2|44=1.00000|59=1|14=40000|151=60000|60=20231114-22:13:22.000000000
+35=9|434=1|39=1|11=O-1|37=V-1|55=AUD/USD.SIM|54=1|38=100000|40=2|44=1.00000|59=1|14=40000|151=60000|58=TOO_LATE_TO_CANCEL|60=20231114-22:13:22.001000000
+35=8|150=F|39=2|11=O-1|37=V-1|17=T-2002|55=AUD/USD.SIM|54=1|38=100000|40=2|44=1.00000|59=1|32=60000|31=0.99999|14=100000|151=0|6=0.99999|60=20231114-22:13:22.002000000
+";
+        assert_eq!(export(&events), expected);
+    }
+
+    #[rstest]
+    fn test_export_without_initialized_omits_order_fields() {
+        let init = initialized();
+        let events = vec![OrderEventAny::Filled(filled(&init, "100000", "1.00000", 5))];
+
+        let expected = "\
+35=8|150=F|11=O-1|37=V-1|17=T-5|55=AUD/USD.SIM|54=1|40=2|32=100000|31=1.00000|6=1.00000|60=20231114-22:13:20.005000000
+";
+        assert_eq!(export(&events), expected);
+    }
+
+    #[rstest]
+    fn test_reason_separator_is_replaced() {
+        let init = initialized();
+        let event = OrderEventAny::CancelRejected(
+            OrderCancelRejected::new(
+                init.trader_id,
+                init.strategy_id,
+                init.instrument_id,
+                init.client_order_id,
+                Ustr::from("UNKNOWN|ORDER"),
+                UUID4::new(),
+                ts(1),
+                ts(1),
+                false,
+                None,
+                None,
+            )
+            .unwrap(),
+        );
+
+        assert!(export(&[event]).contains("|58=UNKNOWN ORDER|"));
+    }
+}
@@ -0,0 +1,382 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a best-effort flattening of mixed order event streams into a single table schema.
+//!
+//! Column names match the keys of the Python `to_dict` representations where they exist, with
+//! prices and quantities flattened to `f64` for analysis.
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use serde::{Deserialize, Serialize};
+
+use super::event::OrderEventAny;
+use crate::identifiers::{
+    client_order_id::ClientOrderId, instrument_id::InstrumentId, strategy_id::StrategyId,
+    trader_id::TraderId,
+};
+
+/// Represents a single order event as a flat record, with a superset of all event fields.
+///
+/// Fields which are not applicable to the event type are `None`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlatEventRecord {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub trader_id: String,
+    pub strategy_id: String,
+    pub instrument_id: String,
+    pub client_order_id: String,
+    pub venue_order_id: Option<String>,
+    pub account_id: Option<String>,
+    pub trade_id: Option<String>,
+    pub position_id: Option<String>,
+    pub order_side: Option<String>,
+    pub order_type: Option<String>,
+    pub time_in_force: Option<String>,
+    pub quantity: Option<f64>,
+    pub price: Option<f64>,
+    pub trigger_price: Option<f64>,
+    pub released_price: Option<f64>,
+    pub last_qty: Option<f64>,
+    pub last_px: Option<f64>,
+    pub currency: Option<String>,
+    pub commission: Option<String>,
+    pub liquidity_side: Option<String>,
+    pub reason: Option<String>,
+    pub event_id: String,
+    pub ts_event: u64,
+    pub ts_init: u64,
+    pub reconciliation: Option<bool>,
+}
+
+impl FlatEventRecord {
+    #[allow(clippy::too_many_arguments)]
+    fn base(
+        event_type: &str,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        event_id: UUID4,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            event_type: event_type.to_string(),
+            trader_id: trader_id.to_string(),
+            strategy_id: strategy_id.to_string(),
+            instrument_id: instrument_id.to_string(),
+            client_order_id: client_order_id.to_string(),
+            event_id: event_id.to_string(),
+            ts_event: ts_event.as_u64(),
+            ts_init: ts_init.as_u64(),
+            ..Default::default()
+        }
+    }
+}
+
+macro_rules! flat_base {
+    ($event_type:expr, $event:expr) => {
+        FlatEventRecord::base(
+            $event_type,
+            $event.trader_id,
+            $event.strategy_id,
+            $event.instrument_id,
+            $event.client_order_id,
+            $event.event_id,
+            $event.ts_event,
+            $event.ts_init,
+        )
+    };
+}
+
+impl From<&OrderEventAny> for FlatEventRecord {
+    fn from(event: &OrderEventAny) -> Self {
+        match event {
+            OrderEventAny::Initialized(e) => Self {
+                order_side: Some(e.order_side.to_string()),
+                order_type: Some(e.order_type.to_string()),
+                time_in_force: Some(e.time_in_force.to_string()),
+                quantity: Some(e.quantity.as_f64()),
+                price: e.price.map(|price| price.as_f64()),
+                trigger_price: e.trigger_price.map(|price| price.as_f64()),
+                reconciliation: Some(e.reconciliation),
+                ..flat_base!("OrderInitialized", e)
+            },
+            OrderEventAny::Denied(e) => Self {
+                reason: Some(e.reason.to_string()),
+                ..flat_base!("OrderDenied", e)
+            },
+            OrderEventAny::Emulated(e) => flat_base!("OrderEmulated", e),
+            OrderEventAny::Released(e) => Self {
+                released_price: Some(e.released_price.as_f64()),
+                ..flat_base!("OrderReleased", e)
+            },
+            OrderEventAny::Submitted(e) => Self {
+                account_id: Some(e.account_id.to_string()),
+                ..flat_base!("OrderSubmitted", e)
+            },
+            OrderEventAny::Accepted(e) => Self {
+                venue_order_id: Some(e.venue_order_id.to_string()),
+                account_id: Some(e.account_id.to_string()),
+                reconciliation: Some(e.reconciliation != 0),
+                ..flat_base!("OrderAccepted", e)
+            },
+            OrderEventAny::Rejected(e) => Self {
+                account_id: Some(e.account_id.to_string()),
+                reason: Some(e.reason.to_string()),
+                reconciliation: Some(e.reconciliation != 0),
+                ..flat_base!("OrderRejected", e)
+            },
+            OrderEventAny::Canceled(e) => Self {
+                venue_order_id: e.venue_order_id.map(|id| id.to_string()),
+                account_id: e.account_id.map(|id| id.to_string()),
+                reconciliation: Some(e.reconciliation != 0),
+                ..flat_base!("OrderCanceled", e)
+            },
+            OrderEventAny::Expired(e) => Self {
+                venue_order_id: e.venue_order_id.map(|id| id.to_string()),
+                account_id: e.account_id.map(|id| id.to_string()),
+                reconciliation: Some(e.reconciliation != 0),
+                ..flat_base!("OrderExpired", e)
+            },
+            OrderEventAny::Triggered(e) => Self {
+                venue_order_id: e.venue_order_id.map(|id| id.to_string()),
+                account_id: e.account_id.map(|id| id.to_string()),
+                reconciliation: Some(e.reconciliation != 0),
+                ..flat_base!("OrderTriggered", e)
+            },
+            OrderEventAny::PendingUpdate(e) => Self {
+                venue_order_id: e.venue_order_id.map(|id| id.to_string()),
+                account_id: Some(e.account_id.to_string()),
+                reconciliation: Some(e.reconciliation != 0),
+                ..flat_base!("OrderPendingUpdate", e)
+            },
+            OrderEventAny::PendingCancel(e) => Self {
+                venue_order_id: e.venue_order_id.map(|id| id.to_string()),
+                account_id: Some(e.account_id.to_string()),
+                reconciliation: Some(e.reconciliation != 0),
+                ..flat_base!("OrderPendingCancel", e)
+            },
+            OrderEventAny::ModifyRejected(e) => Self {
+                venue_order_id: e.venue_order_id.map(|id| id.to_string()),
+                account_id: e.account_id.map(|id| id.to_string()),
+                reason: Some(e.reason.to_string()),
+                reconciliation: Some(e.reconciliation != 0),
+                ..flat_base!("OrderModifyRejected", e)
+            },
+            OrderEventAny::CancelRejected(e) => Self {
+                venue_order_id: e.venue_order_id.map(|id| id.to_string()),
+                account_id: e.account_id.map(|id| id.to_string()),
+                reason: Some(e.reason.to_string()),
+                reconciliation: Some(e.reconciliation != 0),
+                ..flat_base!("OrderCancelRejected", e)
+            },
+            OrderEventAny::Updated(e) => Self {
+                venue_order_id: e.venue_order_id.map(|id| id.to_string()),
+                account_id: e.account_id.map(|id| id.to_string()),
+                quantity: Some(e.quantity.as_f64()),
+                price: e.price.map(|price| price.as_f64()),
+                trigger_price: e.trigger_price.map(|price| price.as_f64()),
+                reconciliation: Some(e.reconciliation != 0),
+                ..flat_base!("OrderUpdated", e)
+            },
+            OrderEventAny::PartiallyFilled(e) | OrderEventAny::Filled(e) => Self {
+                venue_order_id: Some(e.venue_order_id.to_string()),
+                account_id: Some(e.account_id.to_string()),
+                trade_id: Some(e.trade_id.to_string()),
+                position_id: e.position_id.map(|id| id.to_string()),
+                order_side: Some(e.order_side.to_string()),
+                order_type: Some(e.order_type.to_string()),
+                last_qty: Some(e.last_qty.as_f64()),
+                last_px: Some(e.last_px.as_f64()),
+                currency: Some(e.currency.code.to_string()),
+                commission: e.commission.map(|commission| commission.to_string()),
+                liquidity_side: Some(e.liquidity_side.to_string()),
+                reconciliation: Some(e.reconciliation),
+                ..flat_base!("OrderFilled", e)
+            },
+        }
+    }
+}
+
+/// Flattens the given order `events` into records sharing a single column schema.
+#[must_use]
+pub fn events_to_flat_records(events: &[OrderEventAny]) -> Vec<FlatEventRecord> {
+    events.iter().map(FlatEventRecord::from).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        events::order::{
+            accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, canceled::OrderCanceled,
+            denied::OrderDenied, emulated::OrderEmulated, expired::OrderExpired,
+            filled::OrderFilled, initialized::OrderInitialized,
+            modify_rejected::OrderModifyRejected, pending_cancel::OrderPendingCancel,
+            pending_update::OrderPendingUpdate, rejected::OrderRejected, released::OrderReleased,
+            submitted::OrderSubmitted, triggered::OrderTriggered, updated::OrderUpdated,
+        },
+        types::price::Price,
+    };
+
+    fn all_events() -> Vec<OrderEventAny> {
+        vec![
+            OrderEventAny::Initialized(OrderInitialized::default()),
+            OrderEventAny::Denied(OrderDenied::default()),
+            OrderEventAny::Emulated(OrderEmulated::default()),
+            OrderEventAny::Released(OrderReleased::default()),
+            OrderEventAny::Submitted(OrderSubmitted::default()),
+            OrderEventAny::Accepted(OrderAccepted::default()),
+            OrderEventAny::Rejected(OrderRejected::default()),
+            OrderEventAny::Canceled(OrderCanceled::default()),
+            OrderEventAny::Expired(OrderExpired::default()),
+            OrderEventAny::Triggered(OrderTriggered::default()),
+            OrderEventAny::PendingUpdate(OrderPendingUpdate::default()),
+            OrderEventAny::PendingCancel(OrderPendingCancel::default()),
+            OrderEventAny::ModifyRejected(OrderModifyRejected::default()),
+            OrderEventAny::CancelRejected(OrderCancelRejected::default()),
+            OrderEventAny::Updated(OrderUpdated {
+                price: Some(Price::from("1.00000")),
+                ..Default::default()
+            }),
+            OrderEventAny::PartiallyFilled(OrderFilled::default()),
+            OrderEventAny::Filled(OrderFilled::default()),
+        ]
+    }
+
+    #[rstest]
+    fn test_flatten_every_event_variant() {
+        let events = all_events();
+        let records = events_to_flat_records(&events);
+
+        assert_eq!(records.len(), events.len());
+        for (event, record) in events.iter().zip(&records) {
+            assert_eq!(record.client_order_id, event.client_order_id().to_string());
+            assert_eq!(record.strategy_id, event.strategy_id().to_string());
+            assert!(!record.event_id.is_empty());
+        }
+    }
+
+    #[rstest]
+    fn test_flatten_event_types() {
+        let records = events_to_flat_records(&all_events());
+        let event_types: Vec<&str> = records.iter().map(|r| r.event_type.as_str()).collect();
+
+        assert_eq!(
+            event_types,
+            vec![
+                "OrderInitialized",
+                "OrderDenied",
+                "OrderEmulated",
+                "OrderReleased",
+                "OrderSubmitted",
+                "OrderAccepted",
+                "OrderRejected",
+                "OrderCanceled",
+                "OrderExpired",
+                "OrderTriggered",
+                "OrderPendingUpdate",
+                "OrderPendingCancel",
+                "OrderModifyRejected",
+                "OrderCancelRejected",
+                "OrderUpdated",
+                "OrderFilled",
+                "OrderFilled",
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_flatten_null_patterns() {
+        let records = events_to_flat_records(&all_events());
+
+        for record in &records {
+            let has_reason = matches!(
+                record.event_type.as_str(),
+                "OrderDenied" | "OrderRejected" | "OrderModifyRejected" | "OrderCancelRejected"
+            );
+            let is_fill = record.event_type == "OrderFilled";
+            let has_quantity = matches!(
+                record.event_type.as_str(),
+                "OrderInitialized" | "OrderUpdated"
+            );
+            let has_order_side = has_quantity && record.event_type != "OrderUpdated" || is_fill;
+            let has_reconciliation = !matches!(
+                record.event_type.as_str(),
+                "OrderDenied" | "OrderEmulated" | "OrderReleased" | "OrderSubmitted"
+            );
+
+            assert_eq!(record.reason.is_some(), has_reason, "{record:?}");
+            assert_eq!(record.last_px.is_some(), is_fill, "{record:?}");
+            assert_eq!(record.last_qty.is_some(), is_fill, "{record:?}");
+            assert_eq!(record.trade_id.is_some(), is_fill, "{record:?}");
+            assert_eq!(record.currency.is_some(), is_fill, "{record:?}");
+            assert_eq!(record.liquidity_side.is_some(), is_fill, "{record:?}");
+            assert_eq!(record.quantity.is_some(), has_quantity, "{record:?}");
+            assert_eq!(record.order_side.is_some(), has_order_side, "{record:?}");
+            assert_eq!(
+                record.released_price.is_some(),
+                record.event_type == "OrderReleased",
+                "{record:?}"
+            );
+            assert_eq!(
+                record.reconciliation.is_some(),
+                has_reconciliation,
+                "{record:?}"
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_flatten_filled_matches_python_keys() {
+        let filled = OrderFilled::default();
+        let records = events_to_flat_records(&[OrderEventAny::Filled(filled)]);
+        let value = serde_json::to_value(&records[0]).unwrap();
+
+        for key in [
+            "type",
+            "trader_id",
+            "strategy_id",
+            "instrument_id",
+            "client_order_id",
+            "venue_order_id",
+            "account_id",
+            "trade_id",
+            "position_id",
+            "order_side",
+            "order_type",
+            "last_qty",
+            "last_px",
+            "currency",
+            "commission",
+            "liquidity_side",
+            "event_id",
+            "ts_event",
+            "ts_init",
+            "reconciliation",
+        ] {
+            assert!(value.get(key).is_some(), "missing key {key}");
+        }
+        assert_eq!(records[0].last_px, Some(filled.last_px.as_f64()));
+        assert_eq!(records[0].order_side, Some(filled.order_side.to_string()));
+    }
+}
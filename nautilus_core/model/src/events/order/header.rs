@@ -0,0 +1,609 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides partial decoding of serialized order events.
+//!
+//! Peeking decodes only the event type, client order ID and timestamps from a serialized
+//! [`OrderEventAny`], skipping every other field without decoding it, which makes it much cheaper
+//! than a full decode when indexing large event streams.
+
+use nautilus_core::{
+    msgpack::{IndexEntry, MsgPackStreamReader, StreamReadResult},
+    nanos::UnixNanos,
+    serialization::SerializationFormat,
+};
+use serde::{
+    de::{self, value::BorrowedStrDeserializer},
+    Deserialize,
+};
+
+use super::event::OrderEventAny;
+use crate::identifiers::client_order_id::ClientOrderId;
+
+/// The names of the [`OrderEventAny`] variants.
+const EVENT_TYPE_NAMES: [&str; 17] = [
+    "Initialized",
+    "Denied",
+    "Emulated",
+    "Released",
+    "Submitted",
+    "Accepted",
+    "Rejected",
+    "Canceled",
+    "Expired",
+    "Triggered",
+    "PendingUpdate",
+    "PendingCancel",
+    "ModifyRejected",
+    "CancelRejected",
+    "Updated",
+    "PartiallyFilled",
+    "Filled",
+];
+
+/// Represents the header fields of a serialized order event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventHeader {
+    /// The [`OrderEventAny`] variant name, e.g. `"Accepted"`.
+    pub type_name: &'static str,
+    pub client_order_id: ClientOrderId,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl EventHeader {
+    /// Returns the header of the given `event`.
+    #[must_use]
+    pub fn from_event(event: &OrderEventAny) -> Self {
+        let type_name = event.to_string();
+        Self {
+            type_name: event_type_name(&type_name).expect("Variant name is known"),
+            client_order_id: event.client_order_id(),
+            ts_event: event.ts_event(),
+            ts_init: event.ts_init(),
+        }
+    }
+}
+
+/// Decodes only the [`EventHeader`] from the serialized [`OrderEventAny`] in `bytes`.
+///
+/// # Errors
+///
+/// This function returns an error if `bytes` is not a serialized order event in the given
+/// `format`, or is missing any header field.
+pub fn peek_event_header(bytes: &[u8], format: SerializationFormat) -> anyhow::Result<EventHeader> {
+    match format {
+        SerializationFormat::Json => read_json_event_header(bytes),
+        SerializationFormat::MsgPack => MsgPackCursor::new(bytes).read_event_header(),
+    }
+}
+
+/// Builds an index of the order events in the framed `MsgPack` stream `data`, peeking only the
+/// header of each event.
+///
+/// # Errors
+///
+/// This function returns an error in strict mode if any record is corrupt.
+pub fn build_event_index(
+    reader: &MsgPackStreamReader,
+    data: &[u8],
+) -> anyhow::Result<StreamReadResult<IndexEntry<EventHeader>>> {
+    reader.read_index(data, |payload| {
+        peek_event_header(payload, SerializationFormat::MsgPack)
+    })
+}
+
+fn event_type_name(name: &str) -> Option<&'static str> {
+    EVENT_TYPE_NAMES
+        .iter()
+        .copied()
+        .find(|known| *known == name)
+}
+
+/// Returns the variant name for the serialized `"type"` tag of an event, e.g. `"OrderAccepted"`.
+fn event_type_from_tag(tag: &str) -> anyhow::Result<&'static str> {
+    tag.strip_prefix("Order")
+        .and_then(event_type_name)
+        .ok_or_else(|| anyhow::anyhow!(unknown_event_type(tag)))
+}
+
+fn unknown_event_type(name: &str) -> String {
+    format!("Unknown order event type '{name}'")
+}
+
+/// The header fields collected from an event body, in any order.
+#[derive(Default)]
+struct HeaderFields {
+    type_name: Option<&'static str>,
+    client_order_id: Option<ClientOrderId>,
+    ts_event: Option<UnixNanos>,
+    ts_init: Option<UnixNanos>,
+}
+
+impl HeaderFields {
+    fn into_header(self) -> anyhow::Result<EventHeader> {
+        Ok(EventHeader {
+            type_name: self
+                .type_name
+                .ok_or_else(|| anyhow::anyhow!("Missing field `type`"))?,
+            client_order_id: self
+                .client_order_id
+                .ok_or_else(|| anyhow::anyhow!("Missing field `client_order_id`"))?,
+            ts_event: self
+                .ts_event
+                .ok_or_else(|| anyhow::anyhow!("Missing field `ts_event`"))?,
+            ts_init: self
+                .ts_init
+                .ok_or_else(|| anyhow::anyhow!("Missing field `ts_init`"))?,
+        })
+    }
+}
+
+enum HeaderField {
+    Type,
+    ClientOrderId,
+    TsEvent,
+    TsInit,
+    Other,
+}
+
+impl HeaderField {
+    #[inline]
+    fn from_key(key: &[u8]) -> Self {
+        match key {
+            b"type" => Self::Type,
+            b"client_order_id" => Self::ClientOrderId,
+            b"ts_event" => Self::TsEvent,
+            b"ts_init" => Self::TsInit,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Parses a client order ID through the identifier memo used by full deserialization.
+fn parse_client_order_id(value: &str) -> anyhow::Result<ClientOrderId> {
+    Ok(ClientOrderId::deserialize(BorrowedStrDeserializer::<
+        de::value::Error,
+    >::new(value))?)
+}
+
+/// Represents a malformed input found while peeking, at a byte offset of the input.
+///
+/// Kept to a static message so the scanning hot paths stay small.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("{message} at offset {offset}")]
+struct PeekError {
+    offset: usize,
+    message: &'static str,
+}
+
+type PeekResult<T> = Result<T, PeekError>;
+
+fn error(offset: usize, message: &'static str) -> PeekError {
+    PeekError { offset, message }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// JSON
+////////////////////////////////////////////////////////////////////////////////
+
+/// The header fields of a JSON event, with every other field skipped by `serde_json`.
+#[derive(Deserialize)]
+struct JsonEventHeader {
+    #[serde(rename = "type")]
+    tag: String,
+    client_order_id: ClientOrderId,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+}
+
+/// Decodes the header of an internally tagged `{"type": "Order<Variant>", ...}` JSON event.
+fn read_json_event_header(bytes: &[u8]) -> anyhow::Result<EventHeader> {
+    let event: JsonEventHeader = serde_json::from_slice(bytes)?;
+    Ok(EventHeader {
+        type_name: event_type_from_tag(&event.tag)?,
+        client_order_id: event.client_order_id,
+        ts_event: event.ts_event,
+        ts_init: event.ts_init,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// MsgPack
+////////////////////////////////////////////////////////////////////////////////
+
+/// A cursor over `MsgPack` encoded bytes, which skips values without decoding them.
+struct MsgPackCursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> MsgPackCursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn read_event_header(&mut self) -> anyhow::Result<EventHeader> {
+        let mut fields = HeaderFields::default();
+        for _ in 0..self.read_map_len()? {
+            match HeaderField::from_key(self.read_str_bytes()?) {
+                HeaderField::Type => {
+                    let tag = std::str::from_utf8(self.read_str_bytes()?)?;
+                    fields.type_name = Some(event_type_from_tag(tag)?);
+                }
+                HeaderField::ClientOrderId => {
+                    let value = std::str::from_utf8(self.read_str_bytes()?)?;
+                    fields.client_order_id = Some(parse_client_order_id(value)?);
+                }
+                HeaderField::TsEvent => fields.ts_event = Some(self.read_u64()?.into()),
+                HeaderField::TsInit => fields.ts_init = Some(self.read_u64()?.into()),
+                HeaderField::Other => self.skip_value()?,
+            }
+        }
+
+        if self.pos != self.data.len() {
+            return Err(error(self.pos, "Trailing bytes after order event").into());
+        }
+        fields.into_header()
+    }
+
+    #[inline]
+    fn take(&mut self, len: usize) -> PeekResult<&'a [u8]> {
+        let start = self.pos;
+        if len > self.data.len() - start {
+            return Err(error(start, "Unexpected end of input"));
+        }
+        self.pos += len;
+        Ok(&self.data[start..self.pos])
+    }
+
+    #[inline]
+    fn read_u8(&mut self) -> PeekResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Reads a big-endian unsigned integer of up to 8 bytes.
+    #[inline]
+    fn read_be(&mut self, len: usize) -> PeekResult<u64> {
+        let bytes = self.take(len)?;
+        Ok(bytes
+            .iter()
+            .fold(0, |value, &byte| (value << 8) | u64::from(byte)))
+    }
+
+    fn read_map_len(&mut self) -> PeekResult<u64> {
+        match self.read_u8()? {
+            marker @ 0x80..=0x8f => Ok(u64::from(marker & 0x0f)),
+            0xde => self.read_be(2),
+            0xdf => self.read_be(4),
+            _ => Err(error(self.pos - 1, "Expected a map")),
+        }
+    }
+
+    #[inline]
+    fn read_str_bytes(&mut self) -> PeekResult<&'a [u8]> {
+        let len = match self.read_u8()? {
+            marker @ 0xa0..=0xbf => u64::from(marker & 0x1f),
+            0xd9 => self.read_be(1)?,
+            0xda => self.read_be(2)?,
+            0xdb => self.read_be(4)?,
+            _ => return Err(error(self.pos - 1, "Expected a string")),
+        };
+        self.take(len as usize)
+    }
+
+    fn read_u64(&mut self) -> PeekResult<u64> {
+        match self.read_u8()? {
+            marker @ 0x00..=0x7f => Ok(u64::from(marker)),
+            0xcc => self.read_be(1),
+            0xcd => self.read_be(2),
+            0xce => self.read_be(4),
+            0xcf => self.read_be(8),
+            _ => Err(error(self.pos - 1, "Expected an unsigned integer")),
+        }
+    }
+
+    /// Skips the next value, including all values nested within it.
+    fn skip_value(&mut self) -> PeekResult<()> {
+        let mut remaining: u64 = 1;
+        while remaining > 0 {
+            remaining -= 1;
+            let marker = self.read_u8()?;
+            let skip = match marker {
+                0x00..=0x7f | 0xc0 | 0xc2 | 0xc3 | 0xe0..=0xff => 0,
+                0x80..=0x8f => {
+                    remaining += 2 * u64::from(marker & 0x0f);
+                    0
+                }
+                0x90..=0x9f => {
+                    remaining += u64::from(marker & 0x0f);
+                    0
+                }
+                0xa0..=0xbf => u64::from(marker & 0x1f),
+                0xc4 | 0xd9 => self.read_be(1)?,
+                0xc5 | 0xda => self.read_be(2)?,
+                0xc6 | 0xdb => self.read_be(4)?,
+                0xc7 => self.read_be(1)? + 1,
+                0xc8 => self.read_be(2)? + 1,
+                0xc9 => self.read_be(4)? + 1,
+                0xcc | 0xd0 => 1,
+                0xcd | 0xd1 => 2,
+                0xca | 0xce | 0xd2 => 4,
+                0xcb | 0xcf | 0xd3 => 8,
+                0xd4 => 2,
+                0xd5 => 3,
+                0xd6 => 5,
+                0xd7 => 9,
+                0xd8 => 17,
+                0xdc => {
+                    remaining += self.read_be(2)?;
+                    0
+                }
+                0xdd => {
+                    remaining += self.read_be(4)?;
+                    0
+                }
+                0xde => {
+                    remaining += 2 * self.read_be(2)?;
+                    0
+                }
+                0xdf => {
+                    remaining += 2 * self.read_be(4)?;
+                    0
+                }
+                _ => return Err(error(self.pos - 1, "Invalid marker")),
+            };
+            self.take(skip as usize)?;
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::msgpack::{MsgPackStreamWriter, StreamReadMode, RECORD_HEADER_LEN};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        events::order::{
+            accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, canceled::OrderCanceled,
+            denied::OrderDenied, emulated::OrderEmulated, expired::OrderExpired,
+            filled::OrderFilled, initialized::OrderInitialized,
+            modify_rejected::OrderModifyRejected, pending_cancel::OrderPendingCancel,
+            pending_update::OrderPendingUpdate, rejected::OrderRejected, released::OrderReleased,
+            submitted::OrderSubmitted, triggered::OrderTriggered, updated::OrderUpdated,
+        },
+        types::price::Price,
+    };
+
+    /// One event of every variant, each with a distinct client order ID and timestamps.
+    fn all_events() -> Vec<OrderEventAny> {
+        let events = vec![
+            OrderEventAny::Initialized(OrderInitialized::default()),
+            OrderEventAny::Denied(OrderDenied::default()),
+            OrderEventAny::Emulated(OrderEmulated::default()),
+            OrderEventAny::Released(OrderReleased::default()),
+            OrderEventAny::Submitted(OrderSubmitted::default()),
+            OrderEventAny::Accepted(OrderAccepted::default()),
+            OrderEventAny::Rejected(OrderRejected::default()),
+            OrderEventAny::Canceled(OrderCanceled::default()),
+            OrderEventAny::Expired(OrderExpired::default()),
+            OrderEventAny::Triggered(OrderTriggered::default()),
+            OrderEventAny::PendingUpdate(OrderPendingUpdate::default()),
+            OrderEventAny::PendingCancel(OrderPendingCancel::default()),
+            OrderEventAny::ModifyRejected(OrderModifyRejected::default()),
+            OrderEventAny::CancelRejected(OrderCancelRejected::default()),
+            OrderEventAny::Updated(OrderUpdated {
+                price: Some(Price::from("1.00000")),
+                ..Default::default()
+            }),
+            OrderEventAny::PartiallyFilled(OrderFilled::default()),
+            OrderEventAny::Filled(OrderFilled::default()),
+        ];
+
+        // Rewrite the header fields through JSON, as they are not settable per variant
+        events
+            .into_iter()
+            .enumerate()
+            .map(|(i, event)| {
+                let mut value = serde_json::to_value(&event).unwrap();
+                value["client_order_id"] = format!("O-{i:03}").into();
+                value["ts_event"] = (1_000_000_000 * (i as u64 + 1)).into();
+                value["ts_init"] = (1_000_000_000 * (i as u64 + 1) + 500).into();
+                serde_json::from_str(&value.to_string()).unwrap()
+            })
+            .collect()
+    }
+
+    fn msgpack_payload(event: &OrderEventAny) -> Vec<u8> {
+        let mut writer = MsgPackStreamWriter::new(Vec::new());
+        writer.write(event).unwrap();
+        writer.into_inner().split_off(RECORD_HEADER_LEN)
+    }
+
+    #[rstest]
+    fn test_events_have_distinct_headers() {
+        let headers: Vec<EventHeader> = all_events().iter().map(EventHeader::from_event).collect();
+
+        assert_eq!(headers.len(), EVENT_TYPE_NAMES.len());
+        for (i, header) in headers.iter().enumerate() {
+            assert_eq!(header.type_name, EVENT_TYPE_NAMES[i]);
+            assert_eq!(
+                header.client_order_id,
+                ClientOrderId::from(format!("O-{i:03}").as_str())
+            );
+            assert_eq!(
+                header.ts_event,
+                UnixNanos::from(1_000_000_000 * (i as u64 + 1))
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_peek_json_matches_full_decode() {
+        for event in all_events() {
+            let json = serde_json::to_vec(&event).unwrap();
+            let decoded: OrderEventAny = serde_json::from_slice(&json).unwrap();
+
+            let header = peek_event_header(&json, SerializationFormat::Json).unwrap();
+
+            assert_eq!(header, EventHeader::from_event(&decoded), "{event}");
+        }
+    }
+
+    #[rstest]
+    fn test_peek_msgpack_matches_full_decode() {
+        for event in all_events() {
+            let mut writer = MsgPackStreamWriter::new(Vec::new());
+            writer.write(&event).unwrap();
+            let data = writer.into_inner();
+            let decoded: OrderEventAny = MsgPackStreamReader::default()
+                .read_bytes(&data)
+                .unwrap()
+                .records
+                .remove(0);
+
+            let header =
+                peek_event_header(&data[RECORD_HEADER_LEN..], SerializationFormat::MsgPack)
+                    .unwrap();
+
+            assert_eq!(header, EventHeader::from_event(&decoded), "{event}");
+        }
+    }
+
+    #[rstest]
+    fn test_peek_json_with_reordered_fields() {
+        let json = br#"{"ts_init": 2, "extra": [1, {"a": null}], "client_order_id": "O-1", "type": "OrderAccepted", "ts_event": 1}"#;
+
+        let header = peek_event_header(json, SerializationFormat::Json).unwrap();
+
+        assert_eq!(header.type_name, "Accepted");
+        assert_eq!(header.client_order_id, ClientOrderId::from("O-1"));
+        assert_eq!(header.ts_event, UnixNanos::from(1));
+        assert_eq!(header.ts_init, UnixNanos::from(2));
+    }
+
+    #[rstest]
+    fn test_peek_json_with_escapes_and_whitespace() {
+        let json = br#" { "type" : "OrderAccepted" , "reason" : "a \"quoted\" \\ reason" ,
+            "client_order_id" : "O-1" , "ts_event" : 1 , "ts_init" : 2 } "#;
+
+        let header = peek_event_header(json, SerializationFormat::Json).unwrap();
+
+        assert_eq!(header.client_order_id, ClientOrderId::from("O-1"));
+        assert_eq!(header.ts_event, UnixNanos::from(1));
+        assert_eq!(header.ts_init, UnixNanos::from(2));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::not_an_object("[1, 2]")]
+    #[case::no_event("{}")]
+    #[case::unknown_type(
+        r#"{"type": "OrderOpened", "client_order_id": "O-1", "ts_event": 1, "ts_init": 2}"#
+    )]
+    #[case::untagged_variant(
+        r#"{"type": "Accepted", "client_order_id": "O-1", "ts_event": 1, "ts_init": 2}"#
+    )]
+    #[case::missing_type(r#"{"client_order_id": "O-1", "ts_event": 1, "ts_init": 2}"#)]
+    #[case::missing_field(r#"{"type": "OrderAccepted", "client_order_id": "O-1", "ts_event": 1}"#)]
+    #[case::wrong_type(
+        r#"{"type": "OrderAccepted", "client_order_id": "O-1", "ts_event": "1", "ts_init": 2}"#
+    )]
+    #[case::truncated(r#"{"type": "OrderAccepted", "client_order_id": "O-1", "ts_event": 1"#)]
+    fn test_peek_json_malformed_errors(#[case] json: &str) {
+        assert!(peek_event_header(json.as_bytes(), SerializationFormat::Json).is_err());
+    }
+
+    #[rstest]
+    fn test_peek_msgpack_truncated_errors() {
+        for event in all_events() {
+            let payload = msgpack_payload(&event);
+            for len in 0..payload.len() {
+                assert!(
+                    peek_event_header(&payload[..len], SerializationFormat::MsgPack).is_err(),
+                    "{event} truncated to {len} bytes"
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_peek_msgpack_malformed_errors() {
+        let payload = msgpack_payload(&all_events()[5]);
+        let trailing = [payload.as_slice(), &[0xc0]].concat();
+        // The first field is `type`, so its value follows the fixmap marker and its key
+        let tag_offset = 1 + 1 + "type".len();
+        let mut bad_tag = payload.clone();
+        bad_tag[tag_offset] = 0xc1;
+        // The second field is `trader_id`, which follows the `type` tag value
+        let mut bad_value = payload.clone();
+        let value_offset = tag_offset + 1 + "OrderAccepted".len() + 1 + "trader_id".len();
+        bad_value[value_offset] = 0xc1;
+
+        let error = |bytes: &[u8]| {
+            peek_event_header(bytes, SerializationFormat::MsgPack)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            error(&bad_tag),
+            format!("Expected a string at offset {tag_offset}")
+        );
+        assert_eq!(
+            error(&bad_value),
+            format!("Invalid marker at offset {value_offset}")
+        );
+        assert_eq!(
+            error(&trailing),
+            format!(
+                "Trailing bytes after order event at offset {}",
+                payload.len()
+            )
+        );
+        assert_eq!(
+            error(&[0x93, 0x01, 0x02, 0x03]),
+            "Expected a map at offset 0"
+        );
+    }
+
+    #[rstest]
+    fn test_build_event_index() {
+        let events = all_events();
+        let mut writer = MsgPackStreamWriter::new(Vec::new());
+        let mut offsets = Vec::new();
+        let mut offset = 0;
+        for event in &events {
+            offsets.push(offset as u64);
+            offset += writer.write(event).unwrap();
+        }
+        let data = writer.into_inner();
+
+        let index =
+            build_event_index(&MsgPackStreamReader::new(StreamReadMode::Strict), &data).unwrap();
+
+        assert!(index.corrupt.is_empty());
+        assert_eq!(index.records.len(), events.len());
+        for ((entry, event), offset) in index.records.iter().zip(&events).zip(&offsets) {
+            assert_eq!(entry.byte_offset, *offset);
+            assert_eq!(entry.header, EventHeader::from_event(event));
+        }
+    }
+}
@@ -19,7 +19,7 @@ use std::{
 };
 
 use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -39,10 +39,45 @@ use crate::{
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+/// The rarely-used trailing offset and contingency fields of an [`OrderInitialized`] event.
+///
+/// Kept behind a `Box` so the common case (none of the fields set) costs a single pointer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OrderInitializedExt {
+    pub limit_offset: Option<Price>,
+    pub trailing_offset: Option<Price>,
+    pub trailing_offset_type: Option<TrailingOffsetType>,
+    pub contingency_type: Option<ContingencyType>,
+    pub order_list_id: Option<OrderListId>,
+    pub parent_order_id: Option<ClientOrderId>,
+}
+
+/// The extension returned for events which have none of the extension fields set.
+static EMPTY_EXT: OrderInitializedExt = OrderInitializedExt {
+    limit_offset: None,
+    trailing_offset: None,
+    trailing_offset_type: None,
+    contingency_type: None,
+    order_list_id: None,
+    parent_order_id: None,
+};
+
+impl OrderInitializedExt {
+    /// Returns the boxed extension, or `None` if none of the fields are set.
+    #[must_use]
+    pub fn boxed(self) -> Option<Box<Self>> {
+        if self == EMPTY_EXT {
+            None
+        } else {
+            Some(Box::new(self))
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, PartialEq, Eq, Builder, Serialize, Deserialize)]
-#[builder(default)]
-#[serde(tag = "type")]
+#[builder(default, build_fn(validate = "Self::validate"))]
+#[serde(from = "OrderInitializedRepr", into = "OrderInitializedRepr")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -66,21 +101,143 @@ pub struct OrderInitialized {
     pub price: Option<Price>,
     pub trigger_price: Option<Price>,
     pub trigger_type: Option<TriggerType>,
-    pub limit_offset: Option<Price>,
-    pub trailing_offset: Option<Price>,
-    pub trailing_offset_type: Option<TrailingOffsetType>,
     pub expire_time: Option<UnixNanos>,
     pub display_qty: Option<Quantity>,
     pub emulation_trigger: Option<TriggerType>,
     pub trigger_instrument_id: Option<InstrumentId>,
-    pub contingency_type: Option<ContingencyType>,
-    pub order_list_id: Option<OrderListId>,
-    pub linked_order_ids: Option<Vec<ClientOrderId>>,
-    pub parent_order_id: Option<ClientOrderId>,
+    pub linked_order_ids: Option<Box<[ClientOrderId]>>,
     pub exec_algorithm_id: Option<ExecAlgorithmId>,
     pub exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
     pub exec_spawn_id: Option<ClientOrderId>,
-    pub tags: Option<Vec<Ustr>>,
+    pub tags: Option<Box<[Ustr]>>,
+    /// The trailing offset and contingency fields, `None` when none of them are set.
+    #[builder(setter(custom))]
+    pub ext: Option<Box<OrderInitializedExt>>,
+}
+
+/// The flat serde representation of [`OrderInitialized`].
+///
+/// Persisted events predate [`OrderInitializedExt`], so (de)serialization goes through this
+/// struct to keep the field order and output identical to the original flat layout.
+#[derive(Clone, Serialize, Deserialize)]
+struct OrderInitializedRepr {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    quantity: Quantity,
+    time_in_force: TimeInForce,
+    post_only: bool,
+    reduce_only: bool,
+    quote_quantity: bool,
+    reconciliation: bool,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    trigger_type: Option<TriggerType>,
+    limit_offset: Option<Price>,
+    trailing_offset: Option<Price>,
+    trailing_offset_type: Option<TrailingOffsetType>,
+    expire_time: Option<UnixNanos>,
+    display_qty: Option<Quantity>,
+    emulation_trigger: Option<TriggerType>,
+    trigger_instrument_id: Option<InstrumentId>,
+    contingency_type: Option<ContingencyType>,
+    order_list_id: Option<OrderListId>,
+    linked_order_ids: Option<Box<[ClientOrderId]>>,
+    parent_order_id: Option<ClientOrderId>,
+    exec_algorithm_id: Option<ExecAlgorithmId>,
+    exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+    exec_spawn_id: Option<ClientOrderId>,
+    tags: Option<Box<[Ustr]>>,
+}
+
+impl From<OrderInitialized> for OrderInitializedRepr {
+    fn from(event: OrderInitialized) -> Self {
+        let ext = *event.ext();
+        Self {
+            trader_id: event.trader_id,
+            strategy_id: event.strategy_id,
+            instrument_id: event.instrument_id,
+            client_order_id: event.client_order_id,
+            order_side: event.order_side,
+            order_type: event.order_type,
+            quantity: event.quantity,
+            time_in_force: event.time_in_force,
+            post_only: event.post_only,
+            reduce_only: event.reduce_only,
+            quote_quantity: event.quote_quantity,
+            reconciliation: event.reconciliation,
+            event_id: event.event_id,
+            ts_event: event.ts_event,
+            ts_init: event.ts_init,
+            price: event.price,
+            trigger_price: event.trigger_price,
+            trigger_type: event.trigger_type,
+            limit_offset: ext.limit_offset,
+            trailing_offset: ext.trailing_offset,
+            trailing_offset_type: ext.trailing_offset_type,
+            expire_time: event.expire_time,
+            display_qty: event.display_qty,
+            emulation_trigger: event.emulation_trigger,
+            trigger_instrument_id: event.trigger_instrument_id,
+            contingency_type: ext.contingency_type,
+            order_list_id: ext.order_list_id,
+            linked_order_ids: event.linked_order_ids,
+            parent_order_id: ext.parent_order_id,
+            exec_algorithm_id: event.exec_algorithm_id,
+            exec_algorithm_params: event.exec_algorithm_params,
+            exec_spawn_id: event.exec_spawn_id,
+            tags: event.tags,
+        }
+    }
+}
+
+impl From<OrderInitializedRepr> for OrderInitialized {
+    fn from(repr: OrderInitializedRepr) -> Self {
+        let ext = OrderInitializedExt {
+            limit_offset: repr.limit_offset,
+            trailing_offset: repr.trailing_offset,
+            trailing_offset_type: repr.trailing_offset_type,
+            contingency_type: repr.contingency_type,
+            order_list_id: repr.order_list_id,
+            parent_order_id: repr.parent_order_id,
+        };
+        Self {
+            trader_id: repr.trader_id,
+            strategy_id: repr.strategy_id,
+            instrument_id: repr.instrument_id,
+            client_order_id: repr.client_order_id,
+            order_side: repr.order_side,
+            order_type: repr.order_type,
+            quantity: repr.quantity,
+            time_in_force: repr.time_in_force,
+            post_only: repr.post_only,
+            reduce_only: repr.reduce_only,
+            quote_quantity: repr.quote_quantity,
+            reconciliation: repr.reconciliation,
+            event_id: repr.event_id,
+            ts_event: repr.ts_event,
+            ts_init: repr.ts_init,
+            price: repr.price,
+            trigger_price: repr.trigger_price,
+            trigger_type: repr.trigger_type,
+            expire_time: repr.expire_time,
+            display_qty: repr.display_qty,
+            emulation_trigger: repr.emulation_trigger,
+            trigger_instrument_id: repr.trigger_instrument_id,
+            linked_order_ids: repr.linked_order_ids,
+            exec_algorithm_id: repr.exec_algorithm_id,
+            exec_algorithm_params: repr.exec_algorithm_params,
+            exec_spawn_id: repr.exec_spawn_id,
+            tags: repr.tags,
+            ext: ext.boxed(),
+        }
+    }
 }
 
 impl Default for OrderInitialized {
@@ -102,15 +259,9 @@ impl Default for OrderInitialized {
             reduce_only: Default::default(),
             display_qty: Default::default(),
             quote_quantity: Default::default(),
-            limit_offset: Default::default(),
-            trailing_offset: Default::default(),
-            trailing_offset_type: Default::default(),
             emulation_trigger: Default::default(),
             trigger_instrument_id: Default::default(),
-            contingency_type: Default::default(),
-            order_list_id: Default::default(),
             linked_order_ids: Default::default(),
-            parent_order_id: Default::default(),
             exec_algorithm_id: Default::default(),
             exec_algorithm_params: Default::default(),
             exec_spawn_id: Default::default(),
@@ -119,10 +270,134 @@ impl Default for OrderInitialized {
             ts_event: Default::default(),
             ts_init: Default::default(),
             reconciliation: Default::default(),
+            ext: Default::default(),
         }
     }
 }
 
+impl OrderInitializedBuilder {
+    /// Checks the fields required by the order type and time in force are set, so that
+    /// the event can always be converted into an order.
+    fn validate(&self) -> Result<(), String> {
+        // Unset fields take the values from `OrderInitialized::default`
+        let order_type = self.order_type.unwrap_or(OrderType::Market);
+        let time_in_force = self.time_in_force.unwrap_or(TimeInForce::Day);
+
+        let needs_price = matches!(
+            order_type,
+            OrderType::Limit
+                | OrderType::StopLimit
+                | OrderType::LimitIfTouched
+                | OrderType::TrailingStopLimit
+        );
+        let needs_trigger = matches!(
+            order_type,
+            OrderType::StopMarket
+                | OrderType::StopLimit
+                | OrderType::MarketIfTouched
+                | OrderType::LimitIfTouched
+                | OrderType::TrailingStopMarket
+                | OrderType::TrailingStopLimit
+        );
+        let is_trailing = matches!(
+            order_type,
+            OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
+        );
+
+        let required = [
+            ("price", needs_price, self.price.flatten().is_some()),
+            (
+                "trigger_price",
+                needs_trigger,
+                self.trigger_price.flatten().is_some(),
+            ),
+            (
+                "trigger_type",
+                needs_trigger,
+                self.trigger_type.flatten().is_some(),
+            ),
+            (
+                "limit_offset",
+                order_type == OrderType::TrailingStopLimit,
+                self.ext_field(|ext| ext.limit_offset).is_some(),
+            ),
+            (
+                "trailing_offset",
+                is_trailing,
+                self.ext_field(|ext| ext.trailing_offset).is_some(),
+            ),
+            (
+                "trailing_offset_type",
+                is_trailing,
+                self.ext_field(|ext| ext.trailing_offset_type).is_some(),
+            ),
+        ];
+        for (field, is_required, is_set) in required {
+            if is_required && !is_set {
+                return Err(format!("`{field}` is required for {order_type} orders"));
+            }
+        }
+
+        if time_in_force == TimeInForce::Gtd && order_type == OrderType::Market {
+            return Err(format!(
+                "{time_in_force} time in force is not supported for {order_type} orders"
+            ));
+        }
+        if time_in_force == TimeInForce::Gtd && self.expire_time.flatten().is_none() {
+            return Err(format!(
+                "`expire_time` is required for {time_in_force} time in force"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Sets the trailing offset and contingency fields.
+    pub fn ext(&mut self, value: Option<Box<OrderInitializedExt>>) -> &mut Self {
+        self.ext = Some(value);
+        self
+    }
+
+    pub fn limit_offset(&mut self, value: Option<Price>) -> &mut Self {
+        self.update_ext(|ext| ext.limit_offset = value)
+    }
+
+    pub fn trailing_offset(&mut self, value: Option<Price>) -> &mut Self {
+        self.update_ext(|ext| ext.trailing_offset = value)
+    }
+
+    pub fn trailing_offset_type(&mut self, value: Option<TrailingOffsetType>) -> &mut Self {
+        self.update_ext(|ext| ext.trailing_offset_type = value)
+    }
+
+    pub fn contingency_type(&mut self, value: Option<ContingencyType>) -> &mut Self {
+        self.update_ext(|ext| ext.contingency_type = value)
+    }
+
+    pub fn order_list_id(&mut self, value: Option<OrderListId>) -> &mut Self {
+        self.update_ext(|ext| ext.order_list_id = value)
+    }
+
+    pub fn parent_order_id(&mut self, value: Option<ClientOrderId>) -> &mut Self {
+        self.update_ext(|ext| ext.parent_order_id = value)
+    }
+
+    fn update_ext(&mut self, update: impl FnOnce(&mut OrderInitializedExt)) -> &mut Self {
+        let mut ext = self
+            .ext
+            .take()
+            .flatten()
+            .map(|ext| *ext)
+            .unwrap_or_default();
+        update(&mut ext);
+        self.ext = Some(ext.boxed());
+        self
+    }
+
+    fn ext_field<T>(&self, field: impl FnOnce(&OrderInitializedExt) -> Option<T>) -> Option<T> {
+        self.ext.as_ref().and_then(Option::as_deref).and_then(field)
+    }
+}
+
 impl OrderInitialized {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -160,6 +435,14 @@ impl OrderInitialized {
         exec_spawn_id: Option<ClientOrderId>,
         tags: Option<Vec<Ustr>>,
     ) -> anyhow::Result<Self> {
+        let ext = OrderInitializedExt {
+            limit_offset,
+            trailing_offset,
+            trailing_offset_type,
+            contingency_type,
+            order_list_id,
+            parent_order_id,
+        };
         Ok(Self {
             trader_id,
             strategy_id,
@@ -179,23 +462,30 @@ impl OrderInitialized {
             price,
             trigger_price,
             trigger_type,
-            limit_offset,
-            trailing_offset,
-            trailing_offset_type,
             expire_time,
             display_qty,
             emulation_trigger,
             trigger_instrument_id,
-            contingency_type,
-            order_list_id,
-            linked_order_ids,
-            parent_order_id,
+            linked_order_ids: linked_order_ids.map(Vec::into_boxed_slice),
             exec_algorithm_id,
             exec_algorithm_params,
             exec_spawn_id,
-            tags,
+            tags: tags.map(Vec::into_boxed_slice),
+            ext: ext.boxed(),
         })
     }
+
+    /// Returns the trailing offset and contingency fields (all `None` when not set).
+    #[must_use]
+    pub fn ext(&self) -> &OrderInitializedExt {
+        self.ext.as_deref().unwrap_or(&EMPTY_EXT)
+    }
+
+    /// Returns a mutable reference to the trailing offset and contingency fields,
+    /// allocating them if not yet set.
+    pub fn ext_mut(&mut self) -> &mut OrderInitializedExt {
+        self.ext.get_or_insert_with(Default::default)
+    }
 }
 
 impl Debug for OrderInitialized {
@@ -219,7 +509,7 @@ impl Debug for OrderInitialized {
             trigger_instrument_id={}, \
             contingency_type={}, \
             order_list_id={}, \
-            linked_order_ids=[{}], \
+            linked_order_ids={}, \
             parent_order_id={}, \
             exec_algorithm_id={}, \
             exec_algorithm_params={}, \
@@ -247,22 +537,28 @@ impl Debug for OrderInitialized {
                 .map_or("None".to_string(), |instrument_id| format!(
                     "{instrument_id}"
                 )),
-            self.contingency_type
+            self.ext()
+                .contingency_type
                 .map_or("None".to_string(), |contingency_type| format!(
                     "{contingency_type}"
                 )),
-            self.order_list_id
+            self.ext()
+                .order_list_id
                 .map_or("None".to_string(), |order_list_id| format!(
                     "{order_list_id}"
                 )),
             self.linked_order_ids
                 .as_ref()
-                .map_or("None".to_string(), |linked_order_ids| linked_order_ids
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>()
-                    .join(", ")),
-            self.parent_order_id
+                .map_or("None".to_string(), |linked_order_ids| format!(
+                    "[{}]",
+                    linked_order_ids
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            self.ext()
+                .parent_order_id
                 .map_or("None".to_string(), |parent_order_id| format!(
                     "{parent_order_id}"
                 )),
@@ -309,7 +605,7 @@ impl Display for OrderInitialized {
             trigger_instrument_id={}, \
             contingency_type={}, \
             order_list_id={}, \
-            linked_order_ids=[{}], \
+            linked_order_ids={}, \
             parent_order_id={}, \
             exec_algorithm_id={}, \
             exec_algorithm_params={}, \
@@ -333,22 +629,28 @@ impl Display for OrderInitialized {
                 .map_or("None".to_string(), |instrument_id| format!(
                     "{instrument_id}"
                 )),
-            self.contingency_type
+            self.ext()
+                .contingency_type
                 .map_or("None".to_string(), |contingency_type| format!(
                     "{contingency_type}"
                 )),
-            self.order_list_id
+            self.ext()
+                .order_list_id
                 .map_or("None".to_string(), |order_list_id| format!(
                     "{order_list_id}"
                 )),
             self.linked_order_ids
                 .as_ref()
-                .map_or("None".to_string(), |linked_order_ids| linked_order_ids
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>()
-                    .join(", ")),
-            self.parent_order_id
+                .map_or("None".to_string(), |linked_order_ids| format!(
+                    "[{}]",
+                    linked_order_ids
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            self.ext()
+                .parent_order_id
                 .map_or("None".to_string(), |parent_order_id| format!(
                     "{parent_order_id}"
                 )),
@@ -365,11 +667,15 @@ impl Display for OrderInitialized {
                 .map_or("None".to_string(), |exec_spawn_id| format!(
                     "{exec_spawn_id}"
                 )),
-            self.tags.as_ref().map_or("None".to_string(), |tags| tags
-                .iter()
-                .map(|s| s.to_string())
-                .collect::<Vec<String>>()
-                .join(", ")),
+            self.tags
+                .as_ref()
+                .map_or("None".to_string(), |tags| format!(
+                    "[{}]",
+                    tags.iter()
+                        .map(|s| s.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )),
         )
     }
 }
@@ -444,7 +750,7 @@ impl OrderEvent for OrderInitialized {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation
     }
 
     fn price(&self) -> Option<Price> {
@@ -468,15 +774,15 @@ impl OrderEvent for OrderInitialized {
     }
 
     fn limit_offset(&self) -> Option<Price> {
-        self.limit_offset
+        self.ext().limit_offset
     }
 
     fn trailing_offset(&self) -> Option<Price> {
-        self.trailing_offset
+        self.ext().trailing_offset
     }
 
     fn trailing_offset_type(&self) -> Option<TrailingOffsetType> {
-        self.trailing_offset_type
+        self.ext().trailing_offset_type
     }
 
     fn expire_time(&self) -> Option<UnixNanos> {
@@ -496,19 +802,19 @@ impl OrderEvent for OrderInitialized {
     }
 
     fn contingency_type(&self) -> Option<ContingencyType> {
-        self.contingency_type
+        self.ext().contingency_type
     }
 
     fn order_list_id(&self) -> Option<OrderListId> {
-        self.order_list_id
+        self.ext().order_list_id
     }
 
     fn linked_order_ids(&self) -> Option<Vec<ClientOrderId>> {
-        self.linked_order_ids.clone()
+        self.linked_order_ids.as_deref().map(<[_]>::to_vec)
     }
 
     fn parent_order_id(&self) -> Option<ClientOrderId> {
-        self.parent_order_id
+        self.ext().parent_order_id
     }
 
     fn exec_algorithm_id(&self) -> Option<ExecAlgorithmId> {
@@ -560,6 +866,8 @@ impl From<OrderInitialized> for OrderAny {
     }
 }
 
+impl Serializable for OrderInitialized {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -567,7 +875,130 @@ impl From<OrderInitialized> for OrderAny {
 mod test {
     use rstest::rstest;
 
+    use super::*;
     use crate::events::order::{initialized::OrderInitialized, stubs::*};
+
+    fn valid_builder(order_type: OrderType) -> OrderInitializedBuilder {
+        let time_in_force = if order_type == OrderType::Market {
+            TimeInForce::Gtc
+        } else {
+            TimeInForce::Gtd
+        };
+        let mut builder = OrderInitializedBuilder::default();
+        builder
+            .order_type(order_type)
+            .time_in_force(time_in_force)
+            .expire_time(Some(UnixNanos::from(1_000)))
+            .price(Some(Price::from("1.00010")))
+            .trigger_price(Some(Price::from("1.00000")))
+            .trigger_type(Some(TriggerType::Default))
+            .limit_offset(Some(Price::from("0.00010")))
+            .trailing_offset(Some(Price::from("0.00050")))
+            .trailing_offset_type(Some(TrailingOffsetType::Price));
+        builder
+    }
+
+    #[rstest]
+    fn test_builder_defaults_are_valid() {
+        assert!(OrderInitializedBuilder::default().build().is_ok());
+    }
+
+    #[rstest]
+    fn test_builder_valid_combinations(
+        #[values(
+            OrderType::Market,
+            OrderType::Limit,
+            OrderType::StopMarket,
+            OrderType::StopLimit,
+            OrderType::MarketToLimit,
+            OrderType::MarketIfTouched,
+            OrderType::LimitIfTouched,
+            OrderType::TrailingStopMarket,
+            OrderType::TrailingStopLimit
+        )]
+        order_type: OrderType,
+    ) {
+        let event = valid_builder(order_type).build().unwrap();
+
+        assert_eq!(event.order_type, order_type);
+        // Conversion panics if a required field is missing
+        let _ = OrderAny::from(event);
+    }
+
+    #[rstest]
+    #[case(
+        OrderType::Market,
+        "time_in_force",
+        "GTD time in force is not supported for MARKET orders"
+    )]
+    #[case(OrderType::Limit, "price", "`price` is required for LIMIT orders")]
+    #[case(
+        OrderType::StopMarket,
+        "trigger_price",
+        "`trigger_price` is required for STOP_MARKET orders"
+    )]
+    #[case(
+        OrderType::StopLimit,
+        "trigger_type",
+        "`trigger_type` is required for STOP_LIMIT orders"
+    )]
+    #[case(
+        OrderType::StopLimit,
+        "price",
+        "`price` is required for STOP_LIMIT orders"
+    )]
+    #[case(
+        OrderType::MarketToLimit,
+        "expire_time",
+        "`expire_time` is required for GTD time in force"
+    )]
+    #[case(
+        OrderType::MarketIfTouched,
+        "trigger_price",
+        "`trigger_price` is required for MARKET_IF_TOUCHED orders"
+    )]
+    #[case(
+        OrderType::LimitIfTouched,
+        "price",
+        "`price` is required for LIMIT_IF_TOUCHED orders"
+    )]
+    #[case(
+        OrderType::TrailingStopMarket,
+        "trailing_offset",
+        "`trailing_offset` is required for TRAILING_STOP_MARKET orders"
+    )]
+    #[case(
+        OrderType::TrailingStopLimit,
+        "trailing_offset_type",
+        "`trailing_offset_type` is required for TRAILING_STOP_LIMIT orders"
+    )]
+    #[case(
+        OrderType::TrailingStopLimit,
+        "limit_offset",
+        "`limit_offset` is required for TRAILING_STOP_LIMIT orders"
+    )]
+    fn test_builder_missing_required_field(
+        #[case] order_type: OrderType,
+        #[case] field: &str,
+        #[case] expected: &str,
+    ) {
+        let mut builder = valid_builder(order_type);
+        match field {
+            "time_in_force" => builder.time_in_force(TimeInForce::Gtd),
+            "expire_time" => builder.expire_time(None),
+            "price" => builder.price(None),
+            "trigger_price" => builder.trigger_price(None),
+            "trigger_type" => builder.trigger_type(None),
+            "limit_offset" => builder.limit_offset(None),
+            "trailing_offset" => builder.trailing_offset(None),
+            "trailing_offset_type" => builder.trailing_offset_type(None),
+            _ => panic!("Unexpected field {field}"),
+        };
+
+        let error = builder.build().unwrap_err();
+
+        assert_eq!(error.to_string(), expected);
+    }
     #[rstest]
     fn test_order_initialized(order_initialized_buy_limit: OrderInitialized) {
         let display = format!("{order_initialized_buy_limit}");
@@ -579,4 +1010,76 @@ mod test {
             contingency_type=OTO, order_list_id=1, linked_order_ids=[O-2020872378424], parent_order_id=None, \
             exec_algorithm_id=None, exec_algorithm_params=None, exec_spawn_id=None, tags=None)");
     }
+
+    #[rstest]
+    fn test_order_initialized_display_without_linked_orders_with_tags(
+        mut order_initialized_buy_limit: OrderInitialized,
+    ) {
+        order_initialized_buy_limit.ext = None;
+        order_initialized_buy_limit.linked_order_ids = None;
+        order_initialized_buy_limit.emulation_trigger = None;
+        order_initialized_buy_limit.trigger_instrument_id = None;
+        order_initialized_buy_limit.tags =
+            Some(vec![Ustr::from("ENTRY"), Ustr::from("TEST")].into());
+
+        let display = format!("{order_initialized_buy_limit}");
+        assert_eq!(
+            display,
+            "OrderInitialized(instrument_id=BTCUSDT.COINBASE, client_order_id=O-19700101-0000-000-001-1, \
+            side=BUY, type=LIMIT, quantity=0.561, time_in_force=DAY, post_only=true, reduce_only=true, \
+            quote_quantity=false, price=22000, emulation_trigger=None, trigger_instrument_id=None, \
+            contingency_type=None, order_list_id=None, linked_order_ids=None, parent_order_id=None, \
+            exec_algorithm_id=None, exec_algorithm_params=None, exec_spawn_id=None, tags=[ENTRY, TEST])");
+        assert!(!display.contains('\n'));
+    }
+
+    #[rstest]
+    fn test_ext_is_not_allocated_when_unset() {
+        let event = OrderInitializedBuilder::default()
+            .limit_offset(None)
+            .contingency_type(None)
+            .build()
+            .unwrap();
+
+        assert!(event.ext.is_none());
+        assert_eq!(event.ext(), &OrderInitializedExt::default());
+    }
+
+    #[rstest]
+    fn test_serialization_keeps_flat_layout(order_initialized_buy_limit: OrderInitialized) {
+        let value = serde_json::to_value(&order_initialized_buy_limit).unwrap();
+        let keys: Vec<&str> = value
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+
+        assert_eq!(value["contingency_type"], "OTO");
+        assert_eq!(value["order_list_id"], "1");
+        assert_eq!(value["limit_offset"], serde_json::Value::Null);
+        assert!(!keys.contains(&"ext"));
+        assert_eq!(keys.len(), 33);
+    }
+
+    #[rstest]
+    fn test_serde_round_trip(order_initialized_buy_limit: OrderInitialized) {
+        let json = serde_json::to_string(&order_initialized_buy_limit).unwrap();
+        let decoded: OrderInitialized = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, order_initialized_buy_limit);
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+    }
+
+    #[rstest]
+    fn test_deserialize_without_ext_fields_does_not_allocate(
+        mut order_initialized_buy_limit: OrderInitialized,
+    ) {
+        order_initialized_buy_limit.ext = None;
+        let json = serde_json::to_string(&order_initialized_buy_limit).unwrap();
+        let decoded: OrderInitialized = serde_json::from_str(&json).unwrap();
+
+        assert!(decoded.ext.is_none());
+        assert_eq!(decoded, order_initialized_buy_limit);
+    }
 }
@@ -33,18 +33,26 @@ use crate::{
 pub mod accepted;
 pub mod cancel_rejected;
 pub mod canceled;
+pub mod correlation;
 pub mod denied;
 pub mod emulated;
 pub mod event;
 pub mod expired;
 pub mod filled;
+pub mod fix;
+pub mod flat;
+pub mod header;
 pub mod initialized;
 pub mod modify_rejected;
 pub mod pending_cancel;
 pub mod pending_update;
+pub mod redaction;
 pub mod rejected;
 pub mod released;
+pub mod slippage;
 pub mod submitted;
+pub mod time_index;
+pub mod timeline;
 pub mod triggered;
 pub mod updated;
 
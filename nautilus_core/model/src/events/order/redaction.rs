@@ -0,0 +1,666 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides redaction of order event streams for sharing as debug data.
+//!
+//! Trader, strategy, account and position identifiers, rejection reasons and order tags are
+//! replaced with pseudonyms numbered in order of first appearance, so the same value maps to the
+//! same pseudonym everywhere in the stream.
+//! Quantities can optionally be scaled by an integer factor derived from the policy seed, which
+//! keeps fill quantities summing exactly to the order quantity. Timestamps, event ordering and
+//! event kinds are unchanged, so the redacted stream replays through the order state machine
+//! exactly as the original did.
+//!
+//! Position identifiers are replaced as netting position IDs embed the strategy ID. Client, venue
+//! and trade identifiers are kept as they link events together, as are prices.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::event::OrderEventAny;
+use crate::{
+    identifiers::{
+        account_id::AccountId, position_id::PositionId, strategy_id::StrategyId,
+        trader_id::TraderId,
+    },
+    types::{money::Money, quantity::Quantity},
+};
+
+/// The policy for redacting an order event stream.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RedactionPolicy {
+    /// The seed the quantity scaling factor is derived from.
+    pub seed: u64,
+    /// If quantities (and commissions) should be scaled by the factor.
+    pub scale_quantities: bool,
+}
+
+impl RedactionPolicy {
+    /// Returns the quantity scaling factor for the policy (if scaling).
+    ///
+    /// The factor is an integer in the range [2, 10] so that scaled quantities remain exact.
+    #[must_use]
+    pub fn quantity_factor(&self) -> Option<u64> {
+        self.scale_quantities.then(|| 2 + splitmix64(self.seed) % 9)
+    }
+}
+
+/// Represents the mapping from original to redacted values, for translating a redacted event
+/// stream back.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactionMapping {
+    pub trader_ids: IndexMap<TraderId, TraderId>,
+    pub strategy_ids: IndexMap<StrategyId, StrategyId>,
+    pub account_ids: IndexMap<AccountId, AccountId>,
+    pub position_ids: IndexMap<PositionId, PositionId>,
+    pub reasons: IndexMap<Ustr, Ustr>,
+    pub tags: IndexMap<Ustr, Ustr>,
+    pub quantity_factor: Option<u64>,
+}
+
+impl RedactionMapping {
+    /// Returns the original trader ID for the given pseudonym (if mapped).
+    #[must_use]
+    pub fn original_trader_id(&self, pseudonym: &TraderId) -> Option<TraderId> {
+        original(&self.trader_ids, pseudonym)
+    }
+
+    /// Returns the original strategy ID for the given pseudonym (if mapped).
+    #[must_use]
+    pub fn original_strategy_id(&self, pseudonym: &StrategyId) -> Option<StrategyId> {
+        original(&self.strategy_ids, pseudonym)
+    }
+
+    /// Returns the original account ID for the given pseudonym (if mapped).
+    #[must_use]
+    pub fn original_account_id(&self, pseudonym: &AccountId) -> Option<AccountId> {
+        original(&self.account_ids, pseudonym)
+    }
+
+    /// Returns the original position ID for the given pseudonym (if mapped).
+    #[must_use]
+    pub fn original_position_id(&self, pseudonym: &PositionId) -> Option<PositionId> {
+        original(&self.position_ids, pseudonym)
+    }
+
+    /// Returns the original rejection reason for the given pseudonym (if mapped).
+    #[must_use]
+    pub fn original_reason(&self, pseudonym: &Ustr) -> Option<Ustr> {
+        original(&self.reasons, pseudonym)
+    }
+
+    /// Returns the original order tag for the given pseudonym (if mapped).
+    #[must_use]
+    pub fn original_tag(&self, pseudonym: &Ustr) -> Option<Ustr> {
+        original(&self.tags, pseudonym)
+    }
+
+    /// Returns the original quantity for the given redacted quantity.
+    #[must_use]
+    pub fn original_quantity(&self, quantity: Quantity) -> Quantity {
+        match self.quantity_factor {
+            Some(factor) => Quantity::from_raw(quantity.raw / factor, quantity.precision)
+                .expect("Unscaled quantity is within range"),
+            None => quantity,
+        }
+    }
+
+    fn trader_id(&mut self, trader_id: TraderId) -> TraderId {
+        let next = self.trader_ids.len() + 1;
+        *self
+            .trader_ids
+            .entry(trader_id)
+            .or_insert_with(|| TraderId::from(format!("TRADER-{next:03}").as_str()))
+    }
+
+    fn strategy_id(&mut self, strategy_id: StrategyId) -> StrategyId {
+        if strategy_id.is_external() {
+            return strategy_id;
+        }
+        let next = self.strategy_ids.len() + 1;
+        *self
+            .strategy_ids
+            .entry(strategy_id)
+            .or_insert_with(|| StrategyId::from(format!("STRATEGY-{next:03}").as_str()))
+    }
+
+    fn account_id(&mut self, account_id: AccountId) -> AccountId {
+        let next = self.account_ids.len() + 1;
+        *self.account_ids.entry(account_id).or_insert_with(|| {
+            AccountId::from(format!("{}-{next:03}", account_id.get_issuer()).as_str())
+        })
+    }
+
+    fn position_id(&mut self, position_id: PositionId) -> PositionId {
+        let next = self.position_ids.len() + 1;
+        *self
+            .position_ids
+            .entry(position_id)
+            .or_insert_with(|| PositionId::from(format!("P-{next:03}").as_str()))
+    }
+
+    fn reason(&mut self, reason: Ustr) -> Ustr {
+        let next = self.reasons.len() + 1;
+        *self
+            .reasons
+            .entry(reason)
+            .or_insert_with(|| Ustr::from(&format!("REASON-{next:03}")))
+    }
+
+    fn tag(&mut self, tag: Ustr) -> Ustr {
+        let next = self.tags.len() + 1;
+        *self
+            .tags
+            .entry(tag)
+            .or_insert_with(|| Ustr::from(&format!("TAG-{next:03}")))
+    }
+
+    fn quantity(&self, quantity: Quantity) -> anyhow::Result<Quantity> {
+        match self.quantity_factor {
+            Some(factor) => {
+                let raw = quantity.raw.checked_mul(factor).ok_or_else(|| {
+                    anyhow::anyhow!("Overflow scaling quantity {quantity} by {factor}")
+                })?;
+                Quantity::from_raw(raw, quantity.precision)
+            }
+            None => Ok(quantity),
+        }
+    }
+
+    fn money(&self, money: Money) -> anyhow::Result<Money> {
+        match self.quantity_factor {
+            Some(factor) => {
+                let raw = i64::try_from(factor)
+                    .ok()
+                    .and_then(|factor| money.raw.checked_mul(factor))
+                    .ok_or_else(|| anyhow::anyhow!("Overflow scaling money {money} by {factor}"))?;
+                Ok(Money::from_raw(raw, money.currency))
+            }
+            None => Ok(money),
+        }
+    }
+
+    fn redact(&mut self, event: &mut OrderEventAny) -> anyhow::Result<()> {
+        match event {
+            OrderEventAny::Initialized(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.quantity = self.quantity(event.quantity)?;
+                event.display_qty = event.display_qty.map(|q| self.quantity(q)).transpose()?;
+                if let Some(tags) = &mut event.tags {
+                    for tag in tags.iter_mut() {
+                        *tag = self.tag(*tag);
+                    }
+                }
+            }
+            OrderEventAny::Denied(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.reason = self.reason(event.reason);
+            }
+            OrderEventAny::Emulated(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+            }
+            OrderEventAny::Released(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+            }
+            OrderEventAny::Submitted(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = self.account_id(event.account_id);
+            }
+            OrderEventAny::Accepted(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = self.account_id(event.account_id);
+            }
+            OrderEventAny::Rejected(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = self.account_id(event.account_id);
+                event.reason = self.reason(event.reason);
+            }
+            OrderEventAny::Canceled(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = event.account_id.map(|a| self.account_id(a));
+            }
+            OrderEventAny::Expired(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = event.account_id.map(|a| self.account_id(a));
+            }
+            OrderEventAny::Triggered(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = event.account_id.map(|a| self.account_id(a));
+            }
+            OrderEventAny::PendingUpdate(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = self.account_id(event.account_id);
+            }
+            OrderEventAny::PendingCancel(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = self.account_id(event.account_id);
+            }
+            OrderEventAny::ModifyRejected(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = event.account_id.map(|a| self.account_id(a));
+                event.reason = self.reason(event.reason);
+            }
+            OrderEventAny::CancelRejected(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = event.account_id.map(|a| self.account_id(a));
+                event.reason = self.reason(event.reason);
+            }
+            OrderEventAny::Updated(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = event.account_id.map(|a| self.account_id(a));
+                event.quantity = self.quantity(event.quantity)?;
+            }
+            OrderEventAny::PartiallyFilled(event) | OrderEventAny::Filled(event) => {
+                event.trader_id = self.trader_id(event.trader_id);
+                event.strategy_id = self.strategy_id(event.strategy_id);
+                event.account_id = self.account_id(event.account_id);
+                event.position_id = event.position_id.map(|p| self.position_id(p));
+                event.last_qty = self.quantity(event.last_qty)?;
+                event.commission = event.commission.map(|c| self.money(c)).transpose()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Redacts the given order `events` under the `policy`, returning the redacted events along with
+/// the mapping to translate them back.
+///
+/// # Errors
+///
+/// This function returns an error if scaling a quantity or commission overflows.
+pub fn redact_events(
+    events: &[OrderEventAny],
+    policy: RedactionPolicy,
+) -> anyhow::Result<(Vec<OrderEventAny>, RedactionMapping)> {
+    let mut mapping = RedactionMapping {
+        quantity_factor: policy.quantity_factor(),
+        ..Default::default()
+    };
+    let redacted = events
+        .iter()
+        .map(|event| {
+            let mut event = event.clone();
+            mapping.redact(&mut event)?;
+            Ok(event)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok((redacted, mapping))
+}
+
+fn original<T: Copy + PartialEq>(map: &IndexMap<T, T>, pseudonym: &T) -> Option<T> {
+    map.iter()
+        .find(|(_, value)| *value == pseudonym)
+        .map(|(key, _)| *key)
+}
+
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::OrderStatus,
+        events::order::{
+            accepted::OrderAcceptedBuilder, canceled::OrderCanceledBuilder,
+            filled::OrderFilledBuilder, initialized::OrderInitializedBuilder,
+            rejected::OrderRejectedBuilder, submitted::OrderSubmittedBuilder,
+        },
+        identifiers::client_order_id::ClientOrderId,
+        orders::{base::Order, market::MarketOrder},
+    };
+
+    fn events() -> Vec<OrderEventAny> {
+        let trader_id = TraderId::from("ALICE-001");
+        let account_id = AccountId::from("BINANCE-12345678");
+        let order1 = ClientOrderId::from("O-1");
+        let order2 = ClientOrderId::from("O-2");
+        let strategy1 = StrategyId::from("MEANREV-001");
+        let strategy2 = StrategyId::from("MOMENTUM-002");
+
+        let init = |client_order_id, strategy_id, ts: u64| {
+            OrderEventAny::Initialized(
+                OrderInitializedBuilder::default()
+                    .trader_id(trader_id)
+                    .strategy_id(strategy_id)
+                    .client_order_id(client_order_id)
+                    .quantity(Quantity::from(100))
+                    .ts_event(ts.into())
+                    .ts_init(ts.into())
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let submitted = |client_order_id, strategy_id, ts: u64| {
+            OrderEventAny::Submitted(
+                OrderSubmittedBuilder::default()
+                    .trader_id(trader_id)
+                    .strategy_id(strategy_id)
+                    .client_order_id(client_order_id)
+                    .account_id(account_id)
+                    .ts_event(ts.into())
+                    .ts_init(ts.into())
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let accepted = |client_order_id, strategy_id, ts: u64| {
+            OrderEventAny::Accepted(
+                OrderAcceptedBuilder::default()
+                    .trader_id(trader_id)
+                    .strategy_id(strategy_id)
+                    .client_order_id(client_order_id)
+                    .account_id(account_id)
+                    .ts_event(ts.into())
+                    .ts_init(ts.into())
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let filled = |last_qty: i64, ts: u64| {
+            OrderFilledBuilder::default()
+                .trader_id(trader_id)
+                .strategy_id(strategy1)
+                .client_order_id(order1)
+                .account_id(account_id)
+                .last_qty(Quantity::from(last_qty))
+                .commission(Some(Money::from("0.25 USDT")))
+                .ts_event(ts.into())
+                .ts_init(ts.into())
+                .build()
+                .unwrap()
+        };
+        let canceled = OrderEventAny::Canceled(
+            OrderCanceledBuilder::default()
+                .trader_id(trader_id)
+                .strategy_id(strategy2)
+                .client_order_id(order2)
+                .account_id(Some(account_id))
+                .ts_event(9.into())
+                .ts_init(9.into())
+                .build()
+                .unwrap(),
+        );
+
+        vec![
+            init(order1, strategy1, 1),
+            init(order2, strategy2, 2),
+            submitted(order1, strategy1, 3),
+            submitted(order2, strategy2, 4),
+            accepted(order1, strategy1, 5),
+            accepted(order2, strategy2, 6),
+            OrderEventAny::PartiallyFilled(filled(40, 7)),
+            OrderEventAny::Filled(filled(60, 8)),
+            canceled,
+        ]
+    }
+
+    fn replay(events: &[OrderEventAny], client_order_id: &str) -> MarketOrder {
+        let client_order_id = ClientOrderId::from(client_order_id);
+        let mut events = events
+            .iter()
+            .filter(|event| event.client_order_id() == client_order_id)
+            .cloned();
+        let OrderEventAny::Initialized(init) = events.next().unwrap() else {
+            panic!("first event must be initialized")
+        };
+        let mut order = MarketOrder::from(init);
+        for event in events {
+            order.apply(event).unwrap();
+        }
+        order
+    }
+
+    #[rstest]
+    fn test_redact_events_pseudonymizes_consistently() {
+        let events = events();
+
+        let (redacted, mapping) = redact_events(&events, RedactionPolicy::default()).unwrap();
+
+        assert_eq!(redacted.len(), events.len());
+        for (original, redacted) in events.iter().zip(&redacted) {
+            assert_eq!(
+                redacted.trader_id(),
+                mapping.trader_ids[&original.trader_id()]
+            );
+            assert_eq!(
+                redacted.strategy_id(),
+                mapping.strategy_ids[&original.strategy_id()]
+            );
+            assert_eq!(redacted.client_order_id(), original.client_order_id());
+            assert_eq!(redacted.ts_event(), original.ts_event());
+            assert_eq!(redacted.ts_init(), original.ts_init());
+            assert_eq!(redacted.event_id(), original.event_id());
+        }
+        assert_eq!(redacted[0].trader_id(), TraderId::from("TRADER-001"));
+        assert_eq!(redacted[0].strategy_id(), StrategyId::from("STRATEGY-001"));
+        assert_eq!(redacted[1].strategy_id(), StrategyId::from("STRATEGY-002"));
+        assert_eq!(
+            mapping.account_ids.values().collect::<Vec<_>>(),
+            vec![&AccountId::from("BINANCE-001")]
+        );
+        assert_eq!(mapping.quantity_factor, None);
+    }
+
+    #[rstest]
+    fn test_redacted_events_replay_through_order_state_machine() {
+        let events = events();
+        let policy = RedactionPolicy {
+            seed: 42,
+            scale_quantities: true,
+        };
+
+        let (redacted, mapping) = redact_events(&events, policy).unwrap();
+        let factor = mapping.quantity_factor.unwrap();
+        let filled = replay(&redacted, "O-1");
+        let canceled = replay(&redacted, "O-2");
+
+        assert!((2..=10).contains(&factor));
+        assert_eq!(filled.status(), OrderStatus::Filled);
+        assert_eq!(filled.filled_qty(), Quantity::from(100 * factor as i64));
+        assert_eq!(filled.leaves_qty(), Quantity::from(0));
+        assert_eq!(filled.event_count(), 5);
+        assert_eq!(filled.strategy_id(), StrategyId::from("STRATEGY-001"));
+        assert_eq!(
+            mapping.original_quantity(filled.quantity()),
+            Quantity::from(100)
+        );
+        assert_eq!(canceled.status(), OrderStatus::Canceled);
+        assert_eq!(canceled.strategy_id(), StrategyId::from("STRATEGY-002"));
+        assert_eq!(
+            filled.account_id(),
+            canceled.account_id(),
+            "shared account must map to the same pseudonym"
+        );
+    }
+
+    #[rstest]
+    fn test_redaction_is_deterministic() {
+        let events = events();
+        let policy = RedactionPolicy {
+            seed: 7,
+            scale_quantities: true,
+        };
+
+        let (first, first_mapping) = redact_events(&events, policy).unwrap();
+        let (second, second_mapping) = redact_events(&events, policy).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first_mapping, second_mapping);
+    }
+
+    #[rstest]
+    fn test_mapping_round_trip_translates_back() {
+        let events = events();
+        let (redacted, mapping) = redact_events(&events, RedactionPolicy::default()).unwrap();
+
+        let json = serde_json::to_string(&mapping).unwrap();
+        let mapping: RedactionMapping = serde_json::from_str(&json).unwrap();
+
+        assert!(json.contains("\"ALICE-001\":\"TRADER-001\""));
+        assert_eq!(
+            mapping.original_trader_id(&redacted[0].trader_id()),
+            Some(TraderId::from("ALICE-001"))
+        );
+        assert_eq!(
+            mapping.original_strategy_id(&redacted[1].strategy_id()),
+            Some(StrategyId::from("MOMENTUM-002"))
+        );
+        assert_eq!(
+            mapping.original_account_id(&AccountId::from("BINANCE-001")),
+            Some(AccountId::from("BINANCE-12345678"))
+        );
+        assert_eq!(
+            mapping.original_account_id(&AccountId::from("SIM-001")),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_redaction_scales_commission() {
+        let events = events();
+        let policy = RedactionPolicy {
+            seed: 1,
+            scale_quantities: true,
+        };
+
+        let (redacted, mapping) = redact_events(&events, policy).unwrap();
+        let factor = mapping.quantity_factor.unwrap();
+        let OrderEventAny::Filled(fill) = redacted[7] else {
+            panic!("expected fill")
+        };
+
+        assert_eq!(fill.last_qty, Quantity::from(60 * factor as i64));
+        assert_eq!(fill.commission.unwrap().as_f64(), 0.25 * factor as f64);
+    }
+
+    #[rstest]
+    fn test_external_strategy_id_is_kept() {
+        let event = OrderEventAny::Initialized(
+            OrderInitializedBuilder::default()
+                .strategy_id(StrategyId::external())
+                .ts_init(UnixNanos::from(1))
+                .build()
+                .unwrap(),
+        );
+
+        let (redacted, mapping) = redact_events(&[event], RedactionPolicy::default()).unwrap();
+
+        assert!(redacted[0].strategy_id().is_external());
+        assert!(mapping.strategy_ids.is_empty());
+    }
+
+    #[rstest]
+    fn test_position_ids_reasons_and_tags_are_redacted() {
+        let strategy_id = StrategyId::from("MEANREV-001");
+        let position_id = PositionId::from("BTCUSDT.BINANCE-MEANREV-001");
+        let tags: Box<[Ustr]> = Box::new([Ustr::from("ALPHA-SIGNAL"), Ustr::from("HEDGE")]);
+        let fill = |client_order_id| {
+            OrderFilledBuilder::default()
+                .strategy_id(strategy_id)
+                .client_order_id(ClientOrderId::from(client_order_id))
+                .position_id(Some(position_id))
+                .build()
+                .unwrap()
+        };
+        let rejected = |client_order_id| {
+            OrderEventAny::Rejected(
+                OrderRejectedBuilder::default()
+                    .strategy_id(strategy_id)
+                    .client_order_id(ClientOrderId::from(client_order_id))
+                    .reason(Ustr::from("MEANREV-001 exceeded its margin at BINANCE"))
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let events = vec![
+            OrderEventAny::Initialized(
+                OrderInitializedBuilder::default()
+                    .strategy_id(strategy_id)
+                    .tags(Some(tags))
+                    .build()
+                    .unwrap(),
+            ),
+            OrderEventAny::Filled(fill("O-1")),
+            OrderEventAny::Filled(fill("O-2")),
+            rejected("O-3"),
+            rejected("O-4"),
+        ];
+
+        let (redacted, mapping) = redact_events(&events, RedactionPolicy::default()).unwrap();
+
+        let json = serde_json::to_string(&redacted).unwrap();
+        assert!(!json.contains("MEANREV"));
+        assert!(!json.contains("ALPHA-SIGNAL"));
+        let OrderEventAny::Initialized(init) = &redacted[0] else {
+            panic!("expected initialized")
+        };
+        assert_eq!(
+            init.tags.as_deref(),
+            Some([Ustr::from("TAG-001"), Ustr::from("TAG-002")].as_slice())
+        );
+        for event in &redacted[1..3] {
+            let OrderEventAny::Filled(fill) = event else {
+                panic!("expected fill")
+            };
+            assert_eq!(fill.position_id, Some(PositionId::from("P-001")));
+        }
+        for event in &redacted[3..] {
+            let OrderEventAny::Rejected(rejected) = event else {
+                panic!("expected rejected")
+            };
+            assert_eq!(rejected.reason, Ustr::from("REASON-001"));
+        }
+        assert_eq!(
+            mapping.original_position_id(&PositionId::from("P-001")),
+            Some(position_id)
+        );
+        assert_eq!(
+            mapping.original_reason(&Ustr::from("REASON-001")),
+            Some(Ustr::from("MEANREV-001 exceeded its margin at BINANCE"))
+        );
+        assert_eq!(
+            mapping.original_tag(&Ustr::from("TAG-002")),
+            Some(Ustr::from("HEDGE"))
+        );
+    }
+}
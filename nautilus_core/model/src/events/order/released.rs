@@ -16,7 +16,7 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -38,7 +38,6 @@ use crate::{
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Builder)]
 #[builder(default)]
-#[serde(tag = "type")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -278,14 +277,18 @@ impl OrderEvent for OrderReleased {
     }
 }
 
+impl Serializable for OrderReleased {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::serialization::{DecimalFormat, Serializable, SerializationConfig};
     use rstest::rstest;
 
-    use crate::events::order::{released::OrderReleased, stubs::*};
+    use crate::events::order::{event::OrderEventAny, released::OrderReleased, stubs::*};
+
     #[rstest]
     fn test_order_released_display(order_released: OrderReleased) {
         let display = format!("{order_released}");
@@ -294,4 +297,43 @@ mod tests {
             "OrderReleased(instrument_id=BTCUSDT.COINBASE, client_order_id=O-19700101-0000-000-001-1, released_price=22_000)"
         );
     }
+
+    #[rstest]
+    #[case(DecimalFormat::Number)]
+    #[case(DecimalFormat::StringCanonical)]
+    #[case(DecimalFormat::RawParts)]
+    fn test_order_released_json_round_trip(
+        order_released: OrderReleased,
+        #[case] decimal_format: DecimalFormat,
+    ) {
+        let config = SerializationConfig::new(decimal_format);
+
+        let bytes = order_released.as_json_bytes_with_config(config).unwrap();
+        let deserialized = OrderReleased::from_json_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, order_released);
+    }
+
+    #[rstest]
+    fn test_order_released_json_matches_python_to_dict(order_released: OrderReleased) {
+        // As produced by `OrderReleased.to_dict` in the Python layer
+        let python_json = r#"{
+            "type": "OrderReleased",
+            "trader_id": "TRADER-001",
+            "strategy_id": "EMACross-001",
+            "instrument_id": "BTCUSDT.COINBASE",
+            "client_order_id": "O-19700101-0000-000-001-1",
+            "released_price": "22000",
+            "event_id": "16578139-a945-4b65-b46c-bc131a15d8e7",
+            "ts_event": 0,
+            "ts_init": 0
+        }"#;
+
+        let decoded = OrderReleased::from_json_bytes(python_json.as_bytes().to_vec()).unwrap();
+        let encoded = serde_json::to_value(OrderEventAny::Released(order_released)).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(python_json).unwrap();
+
+        assert_eq!(decoded, order_released);
+        assert_eq!(encoded, expected);
+    }
 }
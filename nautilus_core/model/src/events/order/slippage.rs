@@ -0,0 +1,325 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides implementation shortfall style slippage analysis of historical fills.
+//!
+//! The reference (decision) price of each order is recorded when the trading decision is made,
+//! then fills are joined to it by client order ID. Slippage is signed so that a positive value is
+//! always a cost, i.e. buying above or selling below the reference price.
+
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use nautilus_core::nanos::UnixNanos;
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumString};
+
+use super::filled::OrderFilled;
+use crate::{
+    enums::OrderSide,
+    identifiers::{client_order_id::ClientOrderId, instrument_id::InstrumentId},
+    types::price::Price,
+};
+
+/// A data quality flag raised on a [`SlippageRecord`].
+#[derive(
+    Copy, Clone, Debug, Display, Hash, PartialEq, Eq, AsRefStr, EnumString, Serialize, Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum SlippageFlag {
+    /// No decision was recorded for the order, so slippage cannot be measured.
+    MissingDecision,
+    /// The decision was recorded after the first fill, so the reference price is not a true
+    /// arrival price and the slippage is unreliable.
+    DecisionAfterFill,
+}
+
+/// Represents the slippage of all fills for a single order against its decision price.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SlippageRecord {
+    pub client_order_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub order_side: OrderSide,
+    /// The recorded decision price, if any.
+    pub reference_px: Option<Price>,
+    /// The quantity weighted average price of all fills.
+    pub vwap_fill_px: f64,
+    pub filled_qty: f64,
+    pub fill_count: u64,
+    /// The slippage in price increments (positive is a cost).
+    pub slippage_ticks: Option<f64>,
+    /// The slippage in basis points of the reference price (positive is a cost).
+    pub slippage_bps: Option<f64>,
+    /// The nanoseconds from the decision to the first fill, if the decision preceded it.
+    pub time_to_first_fill_ns: Option<u64>,
+    pub flag: Option<SlippageFlag>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Decision {
+    ts: UnixNanos,
+    reference_price: Price,
+}
+
+/// Joins fills to recorded decision prices to measure execution slippage.
+#[derive(Clone, Debug, Default)]
+pub struct SlippageAnalyzer {
+    decisions: HashMap<ClientOrderId, Decision>,
+    price_increments: HashMap<InstrumentId, Price>,
+}
+
+impl SlippageAnalyzer {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the `reference_price` at the time of the trading decision for the order.
+    ///
+    /// Recording again for the same `client_order_id` replaces the earlier decision.
+    pub fn record_decision(
+        &mut self,
+        client_order_id: ClientOrderId,
+        ts: UnixNanos,
+        reference_price: Price,
+    ) {
+        self.decisions.insert(
+            client_order_id,
+            Decision {
+                ts,
+                reference_price,
+            },
+        );
+    }
+
+    /// Sets the `price_increment` used to express slippage in ticks for the instrument.
+    ///
+    /// Without one, the smallest increment at the reference price precision is used.
+    pub fn set_price_increment(&mut self, instrument_id: InstrumentId, price_increment: Price) {
+        self.price_increments.insert(instrument_id, price_increment);
+    }
+
+    /// Returns one record per order in the given `fills`, in order of each order's first fill.
+    ///
+    /// Orders without a recorded decision are flagged rather than dropped.
+    #[must_use]
+    pub fn analyze(&self, fills: &[OrderFilled]) -> Vec<SlippageRecord> {
+        let mut orders: IndexMap<ClientOrderId, Vec<&OrderFilled>> = IndexMap::new();
+        for fill in fills {
+            orders.entry(fill.client_order_id).or_default().push(fill);
+        }
+
+        orders
+            .into_iter()
+            .map(|(client_order_id, fills)| self.analyze_order(client_order_id, &fills))
+            .collect()
+    }
+
+    fn analyze_order(
+        &self,
+        client_order_id: ClientOrderId,
+        fills: &[&OrderFilled],
+    ) -> SlippageRecord {
+        let first = fills[0];
+        let mut filled_qty = 0.0;
+        let mut notional = 0.0;
+        let mut ts_first_fill = first.ts_event;
+        for fill in fills {
+            let qty = fill.last_qty.as_f64();
+            filled_qty += qty;
+            notional += qty * fill.last_px.as_f64();
+            ts_first_fill = ts_first_fill.min(fill.ts_event);
+        }
+        let vwap_fill_px = if filled_qty > 0.0 {
+            notional / filled_qty
+        } else {
+            first.last_px.as_f64()
+        };
+
+        let mut record = SlippageRecord {
+            client_order_id,
+            instrument_id: first.instrument_id,
+            order_side: first.order_side,
+            reference_px: None,
+            vwap_fill_px,
+            filled_qty,
+            fill_count: fills.len() as u64,
+            slippage_ticks: None,
+            slippage_bps: None,
+            time_to_first_fill_ns: None,
+            flag: None,
+        };
+
+        let Some(decision) = self.decisions.get(&client_order_id) else {
+            record.flag = Some(SlippageFlag::MissingDecision);
+            return record;
+        };
+
+        let reference_px = decision.reference_price.as_f64();
+        let shortfall = match first.order_side {
+            OrderSide::Sell => reference_px - vwap_fill_px,
+            _ => vwap_fill_px - reference_px,
+        };
+        let price_increment = self.price_increments.get(&first.instrument_id).map_or_else(
+            || 10f64.powi(-i32::from(decision.reference_price.precision)),
+            Price::as_f64,
+        );
+
+        record.reference_px = Some(decision.reference_price);
+        record.slippage_ticks = Some(shortfall / price_increment);
+        if reference_px != 0.0 {
+            record.slippage_bps = Some(shortfall / reference_px * 10_000.0);
+        }
+        if decision.ts > ts_first_fill {
+            record.flag = Some(SlippageFlag::DecisionAfterFill);
+        } else {
+            record.time_to_first_fill_ns = Some(ts_first_fill.as_u64() - decision.ts.as_u64());
+        }
+
+        record
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        events::order::filled::OrderFilledBuilder, identifiers::trade_id::TradeId,
+        types::quantity::Quantity,
+    };
+
+    fn fill(client_order_id: &str, side: OrderSide, qty: i64, px: &str, ts: u64) -> OrderFilled {
+        OrderFilledBuilder::default()
+            .instrument_id(InstrumentId::from("ETHUSDT.BINANCE"))
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .trade_id(TradeId::from(format!("T-{ts}").as_str()))
+            .order_side(side)
+            .last_qty(Quantity::from(qty))
+            .last_px(Price::from(px))
+            .ts_event(ts.into())
+            .ts_init(ts.into())
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_two_fill_order_is_quantity_weighted() {
+        let mut analyzer = SlippageAnalyzer::new();
+        analyzer.record_decision(
+            ClientOrderId::from("O-1"),
+            100.into(),
+            Price::from("100.00"),
+        );
+        let fills = vec![
+            fill("O-1", OrderSide::Buy, 1, "100.10", 150),
+            fill("O-1", OrderSide::Buy, 3, "100.30", 200),
+        ];
+
+        let records = analyzer.analyze(&fills);
+
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.reference_px, Some(Price::from("100.00")));
+        assert_eq!(record.fill_count, 2);
+        assert!((record.filled_qty - 4.0).abs() < 1e-9);
+        assert!((record.vwap_fill_px - 100.25).abs() < 1e-9);
+        assert!((record.slippage_ticks.unwrap() - 25.0).abs() < 1e-6);
+        assert!((record.slippage_bps.unwrap() - 25.0).abs() < 1e-6);
+        assert_eq!(record.time_to_first_fill_ns, Some(50));
+        assert_eq!(record.flag, None);
+    }
+
+    #[rstest]
+    fn test_sell_below_reference_is_a_cost() {
+        let mut analyzer = SlippageAnalyzer::new();
+        analyzer.record_decision(
+            ClientOrderId::from("O-1"),
+            100.into(),
+            Price::from("100.00"),
+        );
+        analyzer.set_price_increment(InstrumentId::from("ETHUSDT.BINANCE"), Price::from("0.05"));
+
+        let records = analyzer.analyze(&[fill("O-1", OrderSide::Sell, 1, "99.90", 150)]);
+
+        assert!((records[0].slippage_ticks.unwrap() - 2.0).abs() < 1e-6);
+        assert!(records[0].slippage_bps.unwrap() > 0.0);
+    }
+
+    #[rstest]
+    fn test_decision_recorded_after_first_fill_is_flagged() {
+        let mut analyzer = SlippageAnalyzer::new();
+        let fills = vec![
+            fill("O-1", OrderSide::Buy, 1, "100.10", 150),
+            fill("O-1", OrderSide::Buy, 1, "100.20", 300),
+        ];
+        analyzer.record_decision(
+            ClientOrderId::from("O-1"),
+            200.into(),
+            Price::from("100.00"),
+        );
+
+        let records = analyzer.analyze(&fills);
+
+        assert_eq!(records[0].flag, Some(SlippageFlag::DecisionAfterFill));
+        assert_eq!(records[0].time_to_first_fill_ns, None);
+        assert!(records[0].slippage_ticks.is_some());
+    }
+
+    #[rstest]
+    fn test_missing_decision_is_flagged_not_dropped() {
+        let mut analyzer = SlippageAnalyzer::new();
+        analyzer.record_decision(
+            ClientOrderId::from("O-1"),
+            100.into(),
+            Price::from("100.00"),
+        );
+        let fills = vec![
+            fill("O-1", OrderSide::Buy, 1, "100.10", 150),
+            fill("O-2", OrderSide::Buy, 1, "100.20", 160),
+        ];
+
+        let records = analyzer.analyze(&fills);
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].client_order_id, ClientOrderId::from("O-2"));
+        assert_eq!(records[1].flag, Some(SlippageFlag::MissingDecision));
+        assert_eq!(records[1].reference_px, None);
+        assert_eq!(records[1].slippage_bps, None);
+    }
+
+    #[rstest]
+    fn test_record_json_round_trip() {
+        let mut analyzer = SlippageAnalyzer::new();
+        analyzer.record_decision(
+            ClientOrderId::from("O-1"),
+            100.into(),
+            Price::from("100.00"),
+        );
+        let record = analyzer
+            .analyze(&[fill("O-1", OrderSide::Buy, 1, "100.10", 150)])
+            .remove(0);
+
+        let json = serde_json::to_string(&record).unwrap();
+        let decoded: SlippageRecord = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, record);
+    }
+}
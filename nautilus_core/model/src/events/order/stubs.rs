@@ -22,8 +22,8 @@ use ustr::Ustr;
 use crate::{
     enums::{ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TriggerType},
     events::order::{
-        accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, denied::OrderDenied,
-        emulated::OrderEmulated, expired::OrderExpired, filled::OrderFilled,
+        accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, canceled::OrderCanceled,
+        denied::OrderDenied, emulated::OrderEmulated, expired::OrderExpired, filled::OrderFilled,
         initialized::OrderInitialized, modify_rejected::OrderModifyRejected,
         pending_cancel::OrderPendingCancel, pending_update::OrderPendingUpdate,
         rejected::OrderRejected, released::OrderReleased, submitted::OrderSubmitted,
@@ -119,6 +119,7 @@ pub fn order_rejected_insufficient_margin(
         UnixNanos::default(),
         UnixNanos::default(),
         false,
+        None,
     )
     .unwrap()
 }
@@ -189,6 +190,7 @@ pub fn order_submitted(
         uuid4,
         UnixNanos::default(),
         UnixNanos::default(),
+        None,
     )
     .unwrap()
 }
@@ -283,6 +285,9 @@ pub fn order_updated(
         Some(account_id),
         Some(Price::from("22000")),
         None,
+        None,
+        None,
+        None,
     )
     .unwrap()
 }
@@ -384,6 +389,7 @@ pub fn order_accepted(
         UnixNanos::default(),
         UnixNanos::default(),
         false,
+        None,
     )
     .unwrap()
 }
@@ -438,3 +444,28 @@ pub fn order_expired(
     )
     .unwrap()
 }
+
+#[fixture]
+pub fn order_canceled(
+    trader_id: TraderId,
+    strategy_id_ema_cross: StrategyId,
+    instrument_id_btc_usdt: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: VenueOrderId,
+    account_id: AccountId,
+    uuid4: UUID4,
+) -> OrderCanceled {
+    OrderCanceled::new(
+        trader_id,
+        strategy_id_ema_cross,
+        instrument_id_btc_usdt,
+        client_order_id,
+        uuid4,
+        UnixNanos::default(),
+        UnixNanos::default(),
+        false,
+        Some(venue_order_id),
+        Some(account_id),
+    )
+    .unwrap()
+}
@@ -16,7 +16,7 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -25,7 +25,7 @@ use crate::{
         ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
         TriggerType,
     },
-    events::order::OrderEvent,
+    events::order::{correlation::CorrelationId, OrderEvent},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, position_id::PositionId,
@@ -38,7 +38,6 @@ use crate::{
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Builder)]
 #[builder(default)]
-#[serde(tag = "type")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -52,6 +51,9 @@ pub struct OrderSubmitted {
     pub event_id: UUID4,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
+    /// The ID correlating the event with the command which originated it.
+    #[serde(default)]
+    pub correlation_id: CorrelationId,
 }
 
 impl OrderSubmitted {
@@ -65,6 +67,7 @@ impl OrderSubmitted {
         event_id: UUID4,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
+        correlation_id: Option<UUID4>,
     ) -> anyhow::Result<Self> {
         Ok(Self {
             trader_id,
@@ -75,6 +78,7 @@ impl OrderSubmitted {
             event_id,
             ts_event,
             ts_init,
+            correlation_id: correlation_id.into(),
         })
     }
 }
@@ -280,6 +284,8 @@ impl OrderEvent for OrderSubmitted {
     }
 }
 
+impl Serializable for OrderSubmitted {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -299,4 +305,17 @@ mod tests {
             "OrderSubmitted(instrument_id=BTCUSDT.COINBASE, client_order_id=O-19700101-0000-000-001-1, account_id=SIM-001, ts_event=0)"
         );
     }
+
+    #[rstest]
+    fn test_order_submitted_deserializes_without_correlation_id(order_submitted: OrderSubmitted) {
+        let mut value = serde_json::to_value(order_submitted).unwrap();
+        value.as_object_mut().unwrap().remove("correlation_id");
+
+        let json = serde_json::to_string(&value).unwrap();
+
+        let deserialized: OrderSubmitted = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, order_submitted);
+        assert!(deserialized.correlation_id.is_none());
+    }
 }
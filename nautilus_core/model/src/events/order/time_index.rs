@@ -0,0 +1,531 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a time bucketed index over a framed `MsgPack` order event stream.
+//!
+//! The index maps `ts_event` buckets of a configurable granularity to the byte offsets of the
+//! events in the stream, built by peeking only the event headers. Range queries then seek to and
+//! decode just the events in the matching buckets, on demand, so the stream is never loaded into
+//! memory as a whole.
+//!
+//! The index records the length and a content hash of the stream prefix it covers, so it can be
+//! extended as events are appended, and detected as stale (then rebuilt) if the stream changes.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::{
+    msgpack::{complete_records_len, MsgPackStreamReader},
+    nanos::UnixNanos,
+    serialization::Serializable,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{event::OrderEventAny, header::build_event_index};
+
+/// The default bucket granularity (1 second).
+pub const DEFAULT_GRANULARITY_NS: u64 = 1_000_000_000;
+
+/// The file extension of a persisted index, appended to the stream file name.
+pub const INDEX_FILE_EXTENSION: &str = "tidx";
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// The size of the chunks the covered stream prefix is hashed in.
+const HASH_CHUNK_LEN: usize = 64 * 1024;
+
+/// Represents a time bucketed index of the order events in a framed `MsgPack` stream.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeIndex {
+    granularity_ns: u64,
+    /// The `(ts_event, byte_offset)` of each event, keyed by bucket start.
+    buckets: BTreeMap<u64, Vec<(u64, u64)>>,
+    /// The length of the stream prefix covered by the index.
+    indexed_len: u64,
+    /// The FNV-1a hash of the stream prefix covered by the index.
+    content_hash: u64,
+}
+
+impl TimeIndex {
+    /// Creates a new empty index with the given bucket `granularity_ns`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `granularity_ns` is zero.
+    #[must_use]
+    pub fn new(granularity_ns: u64) -> Self {
+        assert!(granularity_ns > 0, "`granularity_ns` must be positive");
+        Self {
+            granularity_ns,
+            buckets: BTreeMap::new(),
+            indexed_len: 0,
+            content_hash: FNV_OFFSET_BASIS,
+        }
+    }
+
+    /// Builds an index of all events in the stream `reader`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if reading the stream fails, or any record is corrupt.
+    pub fn build<R: Read + Seek>(reader: &mut R, granularity_ns: u64) -> anyhow::Result<Self> {
+        let mut index = Self::new(granularity_ns);
+        index.append(reader)?;
+        Ok(index)
+    }
+
+    #[must_use]
+    pub fn granularity_ns(&self) -> u64 {
+        self.granularity_ns
+    }
+
+    /// Returns the number of indexed events.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.buckets.values().map(Vec::len).sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    /// Returns the length of the stream prefix covered by the index.
+    #[must_use]
+    pub fn indexed_len(&self) -> u64 {
+        self.indexed_len
+    }
+
+    /// Returns whether the index still matches the stream `reader`, i.e. the prefix it covers is
+    /// unchanged (events may have been appended since).
+    ///
+    /// The covered prefix is hashed in fixed size chunks, without loading it into memory.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if reading the stream fails.
+    pub fn is_valid_for<R: Read + Seek>(&self, reader: &mut R) -> anyhow::Result<bool> {
+        reader.seek(SeekFrom::Start(0))?;
+        let mut prefix = reader.take(self.indexed_len);
+        let mut buf = vec![0; HASH_CHUNK_LEN];
+        let mut hash = FNV_OFFSET_BASIS;
+        let mut hashed_len = 0;
+        loop {
+            let read = prefix.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            hash = fnv1a(hash, &buf[..read]);
+            hashed_len += read as u64;
+        }
+        Ok(hashed_len == self.indexed_len && hash == self.content_hash)
+    }
+
+    /// Indexes the events appended to the stream `reader` since the index was last built or
+    /// appended to, reading only the appended tail of the stream.
+    ///
+    /// A torn final record, only partially written, is left unindexed (and outside
+    /// [`Self::indexed_len`]) so it is picked up by a later append once complete.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the index is stale for the stream (see
+    /// [`Self::is_valid_for`]), reading fails, or any appended record is corrupt.
+    pub fn append<R: Read + Seek>(&mut self, reader: &mut R) -> anyhow::Result<()> {
+        if !self.is_valid_for(reader)? {
+            anyhow::bail!("Index is stale for the stream, rebuild required");
+        }
+
+        let start = self.indexed_len;
+        let mut tail = Vec::new();
+        reader.seek(SeekFrom::Start(start))?;
+        reader.read_to_end(&mut tail)?;
+        tail.truncate(complete_records_len(&tail));
+
+        let result = build_event_index(&MsgPackStreamReader::default(), &tail)?;
+        for entry in result.records {
+            let ts_event = entry.header.ts_event.as_u64();
+            self.buckets
+                .entry(self.bucket(ts_event))
+                .or_default()
+                .push((ts_event, start + entry.byte_offset));
+        }
+
+        self.content_hash = fnv1a(self.content_hash, &tail);
+        self.indexed_len += tail.len() as u64;
+        Ok(())
+    }
+
+    /// Returns the byte offsets of the events with `ts_event` in the inclusive range from
+    /// `start` to `end`, ordered by `ts_event` then stream position.
+    #[must_use]
+    pub fn query_offsets(&self, start: UnixNanos, end: UnixNanos) -> Vec<u64> {
+        let (start, end) = (start.as_u64(), end.as_u64());
+        if start > end {
+            return Vec::new();
+        }
+
+        let mut entries: Vec<(u64, u64)> = self
+            .buckets
+            .range(self.bucket(start)..=self.bucket(end))
+            .flat_map(|(_, entries)| entries)
+            .filter(|(ts_event, _)| (start..=end).contains(ts_event))
+            .copied()
+            .collect();
+        entries.sort_unstable();
+        entries.into_iter().map(|(_, offset)| offset).collect()
+    }
+
+    /// Returns the events in the stream `reader` with `ts_event` in the inclusive range from
+    /// `start` to `end`, ordered by `ts_event` then stream position.
+    ///
+    /// Events are read lazily as the iterator advances, seeking to each in turn.
+    pub fn query_range<'a, R: Read + Seek>(
+        &self,
+        reader: &'a mut R,
+        start: UnixNanos,
+        end: UnixNanos,
+    ) -> impl Iterator<Item = anyhow::Result<OrderEventAny>> + 'a {
+        let stream_reader = MsgPackStreamReader::default();
+        self.query_offsets(start, end)
+            .into_iter()
+            .map(move |offset| stream_reader.read_at(reader, offset))
+    }
+
+    /// Loads the persisted index for the stream at `stream_path`, appends any new events, and
+    /// persists it again.
+    ///
+    /// The index is rebuilt from scratch if it is missing, unreadable, stale for the stream or
+    /// has a different `granularity_ns`.
+    ///
+    /// A torn final record left by an interrupted write is truncated from the stream, so events
+    /// appended afterwards start on a record boundary and the stream stays readable in strict
+    /// mode. This assumes no writer is appending to the stream concurrently.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if reading or truncating the stream, or writing the index
+    /// fails, or the stream contains a corrupt record.
+    pub fn open(stream_path: &Path, granularity_ns: u64) -> anyhow::Result<Self> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(stream_path)?;
+        let stream_len = file.metadata()?.len();
+        let index_path = Self::index_path(stream_path);
+
+        let persisted = fs::read(&index_path)
+            .ok()
+            .and_then(|bytes| Self::from_msgpack_bytes(bytes).ok())
+            .filter(|index| index.granularity_ns == granularity_ns);
+        let persisted = match persisted {
+            Some(index) if index.is_valid_for(&mut file)? => Some(index),
+            _ => None,
+        };
+        let index = match persisted {
+            Some(index) if index.indexed_len == stream_len => return Ok(index),
+            Some(mut index) => {
+                index.append(&mut file)?;
+                index
+            }
+            None => Self::build(&mut file, granularity_ns)?,
+        };
+
+        if index.indexed_len < stream_len {
+            file.set_len(index.indexed_len)?;
+        }
+        fs::write(&index_path, index.as_msgpack_bytes()?)?;
+        Ok(index)
+    }
+
+    /// Returns the path of the persisted index for the stream at `stream_path`.
+    #[must_use]
+    pub fn index_path(stream_path: &Path) -> PathBuf {
+        let mut path = stream_path.as_os_str().to_owned();
+        path.push(".");
+        path.push(INDEX_FILE_EXTENSION);
+        path.into()
+    }
+
+    fn bucket(&self, ts: u64) -> u64 {
+        ts - ts % self.granularity_ns
+    }
+}
+
+impl Serializable for TimeIndex {}
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use nautilus_core::msgpack::MsgPackStreamWriter;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        events::order::accepted::OrderAccepted, identifiers::client_order_id::ClientOrderId,
+    };
+
+    const SECOND: u64 = 1_000_000_000;
+
+    fn event(client_order_id: &str, ts_event: u64) -> OrderEventAny {
+        OrderEventAny::Accepted(OrderAccepted {
+            client_order_id: ClientOrderId::from(client_order_id),
+            ts_event: ts_event.into(),
+            ts_init: ts_event.into(),
+            ..Default::default()
+        })
+    }
+
+    fn write_events(data: &mut Vec<u8>, events: &[OrderEventAny]) {
+        let mut writer = MsgPackStreamWriter::new(data);
+        for event in events {
+            writer.write(event).unwrap();
+        }
+    }
+
+    fn ids(events: impl Iterator<Item = anyhow::Result<OrderEventAny>>) -> Vec<String> {
+        events
+            .map(|event| event.unwrap().client_order_id().to_string())
+            .collect()
+    }
+
+    /// Events on and around the bucket boundaries at 1s and 2s, written out of time order.
+    fn boundary_events() -> Vec<OrderEventAny> {
+        vec![
+            event("O-2.0", 2 * SECOND),
+            event("O-0.5", SECOND / 2),
+            event("O-1.0", SECOND),
+            event("O-1.9", 2 * SECOND - 1),
+            event("O-0.9", SECOND - 1),
+            event("O-2.0+", 2 * SECOND + 1),
+            event("O-3.5", 3 * SECOND + SECOND / 2),
+        ]
+    }
+
+    #[rstest]
+    fn test_query_range_inclusive_at_bucket_boundaries() {
+        let mut data = Vec::new();
+        write_events(&mut data, &boundary_events());
+        let index = TimeIndex::build(&mut Cursor::new(&data), SECOND).unwrap();
+
+        let mut stream = Cursor::new(&data);
+        let events = index.query_range(&mut stream, SECOND.into(), (2 * SECOND).into());
+
+        assert_eq!(index.len(), 7);
+        assert_eq!(ids(events), vec!["O-1.0", "O-1.9", "O-2.0"]);
+    }
+
+    #[rstest]
+    fn test_query_range_within_one_bucket() {
+        let mut data = Vec::new();
+        write_events(&mut data, &boundary_events());
+        let index = TimeIndex::build(&mut Cursor::new(&data), SECOND).unwrap();
+
+        let mut stream = Cursor::new(&data);
+        let events = index.query_range(&mut stream, (SECOND - 1).into(), (SECOND - 1).into());
+
+        assert_eq!(ids(events), vec!["O-0.9"]);
+    }
+
+    #[rstest]
+    fn test_query_range_matches_full_scan_for_all_ranges() {
+        let mut data = Vec::new();
+        let events = boundary_events();
+        write_events(&mut data, &events);
+        let index = TimeIndex::build(&mut Cursor::new(&data), SECOND / 3).unwrap();
+
+        let bounds = [
+            0,
+            SECOND - 1,
+            SECOND,
+            2 * SECOND - 1,
+            2 * SECOND,
+            2 * SECOND + 1,
+            4 * SECOND,
+        ];
+        for start in bounds {
+            for end in bounds {
+                let mut expected: Vec<&OrderEventAny> = events
+                    .iter()
+                    .filter(|event| (start..=end).contains(&event.ts_event().as_u64()))
+                    .collect();
+                expected.sort_by_key(|event| event.ts_event());
+                let expected: Vec<String> = expected
+                    .into_iter()
+                    .map(|event| event.client_order_id().to_string())
+                    .collect();
+
+                let actual =
+                    ids(index.query_range(&mut Cursor::new(&data), start.into(), end.into()));
+
+                assert_eq!(actual, expected, "range {start}..={end}");
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_append_indexes_new_events() {
+        let events = boundary_events();
+        let mut data = Vec::new();
+        write_events(&mut data, &events[..3]);
+        let mut index = TimeIndex::build(&mut Cursor::new(&data), SECOND).unwrap();
+
+        write_events(&mut data, &events[3..]);
+        index.append(&mut Cursor::new(&data)).unwrap();
+
+        let mut stream = Cursor::new(&data);
+        let events = index.query_range(&mut stream, SECOND.into(), (2 * SECOND).into());
+        assert_eq!(ids(events), vec!["O-1.0", "O-1.9", "O-2.0"]);
+        assert_eq!(
+            index,
+            TimeIndex::build(&mut Cursor::new(&data), SECOND).unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_append_to_stale_index_errors() {
+        let mut data = Vec::new();
+        write_events(&mut data, &boundary_events());
+        let mut index = TimeIndex::build(&mut Cursor::new(&data), SECOND).unwrap();
+
+        data[20] ^= 0xFF;
+
+        assert!(!index.is_valid_for(&mut Cursor::new(&data)).unwrap());
+        assert!(!index.is_valid_for(&mut Cursor::new(&data[..10])).unwrap());
+        assert!(index.append(&mut Cursor::new(&data)).is_err());
+    }
+
+    #[rstest]
+    fn test_open_persists_appends_and_rebuilds() {
+        let dir = tempfile::tempdir().unwrap();
+        let stream_path = dir.path().join("events.msgpack");
+        let events = boundary_events();
+        let mut data = Vec::new();
+
+        // Missing index is built and persisted
+        write_events(&mut data, &events[..3]);
+        fs::write(&stream_path, &data).unwrap();
+        let index = TimeIndex::open(&stream_path, SECOND).unwrap();
+        assert_eq!(index.len(), 3);
+        assert!(TimeIndex::index_path(&stream_path).exists());
+
+        // Persisted index is extended with appended events
+        write_events(&mut data, &events[3..]);
+        fs::write(&stream_path, &data).unwrap();
+        let index = TimeIndex::open(&stream_path, SECOND).unwrap();
+        assert_eq!(
+            index,
+            TimeIndex::build(&mut Cursor::new(&data), SECOND).unwrap()
+        );
+
+        // Stale index is rebuilt
+        let mut rewritten = Vec::new();
+        write_events(&mut rewritten, &events[4..]);
+        fs::write(&stream_path, &rewritten).unwrap();
+        let index = TimeIndex::open(&stream_path, SECOND).unwrap();
+        assert_eq!(
+            index,
+            TimeIndex::build(&mut Cursor::new(&rewritten), SECOND).unwrap()
+        );
+        assert_eq!(index.len(), 3);
+    }
+
+    #[rstest]
+    fn test_append_leaves_torn_tail_unindexed() {
+        let events = boundary_events();
+        let mut data = Vec::new();
+        write_events(&mut data, &events[..3]);
+        let complete_len = data.len();
+        write_events(&mut data, &events[3..4]);
+        let torn = data.split_off(complete_len + 5);
+
+        let mut index = TimeIndex::build(&mut Cursor::new(&data), SECOND).unwrap();
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.indexed_len(), complete_len as u64);
+
+        // Completing the torn record indexes it on the next append
+        data.extend(torn);
+        index.append(&mut Cursor::new(&data)).unwrap();
+        assert_eq!(index.len(), 4);
+        assert_eq!(
+            index,
+            TimeIndex::build(&mut Cursor::new(&data), SECOND).unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_open_truncates_torn_tail_before_append() {
+        let dir = tempfile::tempdir().unwrap();
+        let stream_path = dir.path().join("events.msgpack");
+        let events = boundary_events();
+        let mut data = Vec::new();
+        write_events(&mut data, &events[..3]);
+        let complete_len = data.len();
+        write_events(&mut data, &events[3..4]);
+        data.truncate(complete_len + 5);
+        fs::write(&stream_path, &data).unwrap();
+
+        let index = TimeIndex::open(&stream_path, SECOND).unwrap();
+        assert_eq!(index.len(), 3);
+        assert_eq!(
+            fs::metadata(&stream_path).unwrap().len(),
+            complete_len as u64
+        );
+
+        // Events appended after recovery leave a stream readable in strict mode
+        let mut file = OpenOptions::new().append(true).open(&stream_path).unwrap();
+        let mut writer = MsgPackStreamWriter::new(&mut file);
+        for event in &events[3..] {
+            writer.write(event).unwrap();
+        }
+        let index = TimeIndex::open(&stream_path, SECOND).unwrap();
+        let data = fs::read(&stream_path).unwrap();
+        let all = MsgPackStreamReader::default()
+            .read_bytes::<OrderEventAny>(&data)
+            .unwrap();
+        assert_eq!(all.records, events);
+        assert_eq!(index.len(), events.len());
+    }
+
+    #[rstest]
+    fn test_query_range_seeks_in_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let stream_path = dir.path().join("events.msgpack");
+        let mut data = Vec::new();
+        write_events(&mut data, &boundary_events());
+        fs::write(&stream_path, &data).unwrap();
+        let index = TimeIndex::open(&stream_path, SECOND).unwrap();
+
+        let mut file = fs::File::open(&stream_path).unwrap();
+        let events = index.query_range(&mut file, SECOND.into(), (2 * SECOND).into());
+
+        assert_eq!(ids(events), vec!["O-1.0", "O-1.9", "O-2.0"]);
+    }
+}
@@ -0,0 +1,484 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a human-readable timeline of the events for a single order, for debugging.
+
+use std::borrow::Borrow;
+
+use nautilus_core::nanos::UnixNanos;
+
+use super::{event::OrderEventAny, OrderEvent};
+use crate::{enums::OrderStatus, orders::base::OrderError, types::quantity::Quantity};
+
+const COLUMN_SEPARATOR: &str = "  ";
+
+/// Formats the given order `events` as an aligned multi-line timeline.
+///
+/// Each line shows the time relative to the `OrderInitialized` event, the event, the order
+/// status after the event, and any relevant details (fill and update deltas, reasons and
+/// reconciliation flags). If the stream has no `OrderInitialized` event, the timeline is
+/// anchored at the first event and a warning line is included.
+#[must_use]
+pub fn format_order_timeline<E: Borrow<OrderEventAny>>(events: &[E]) -> String {
+    let Some(first) = events.first() else {
+        return "No order events".to_string();
+    };
+    let first = first.borrow();
+
+    let mut lines = vec![format!(
+        "Order {} timeline ({} events)",
+        first.client_order_id(),
+        events.len()
+    )];
+
+    let initialized = events.iter().find_map(|event| match event.borrow() {
+        OrderEventAny::Initialized(init) => Some(init),
+        _ => None,
+    });
+    let anchor = match initialized {
+        Some(init) => init.ts_event,
+        None => {
+            lines.push(format!(
+                "WARNING: no OrderInitialized event, times relative to first event ({})",
+                first.ts_event()
+            ));
+            first.ts_event()
+        }
+    };
+
+    let mut status = OrderStatus::Initialized;
+    let mut previous_status: Option<OrderStatus> = None;
+    let mut quantity = initialized.map(|init| init.quantity);
+    let mut price = initialized.and_then(|init| init.price);
+    let mut trigger_price = initialized.and_then(|init| init.trigger_price);
+    let mut filled_qty: Option<Quantity> = None;
+
+    let mut rows: Vec<[String; 4]> = Vec::with_capacity(events.len());
+    for event in events {
+        let event = event.borrow();
+        let mut details: Vec<String> = Vec::new();
+
+        match event {
+            OrderEventAny::Initialized(init) => {
+                details.push(format!(
+                    "{} {} {}{}{}",
+                    init.order_type,
+                    init.order_side,
+                    init.quantity,
+                    init.price.map_or(String::new(), |px| format!(" @ {px}")),
+                    init.trigger_price
+                        .map_or(String::new(), |px| format!(" trigger {px}")),
+                ));
+            }
+            OrderEventAny::Updated(updated) => {
+                push_change(&mut details, "quantity", quantity, Some(updated.quantity));
+                push_change(&mut details, "price", price, updated.price.or(price));
+                push_change(
+                    &mut details,
+                    "trigger_price",
+                    trigger_price,
+                    updated.trigger_price.or(trigger_price),
+                );
+                if details.is_empty() {
+                    details.push("no change".to_string());
+                }
+                quantity = Some(updated.quantity);
+                price = updated.price.or(price);
+                trigger_price = updated.trigger_price.or(trigger_price);
+            }
+            OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => {
+                let total = filled_qty.map_or(fill.last_qty, |qty| qty + fill.last_qty);
+                details.push(format!(
+                    "+{} @ {} (filled {}{})",
+                    fill.last_qty,
+                    fill.last_px,
+                    total,
+                    quantity.map_or(String::new(), |qty| format!("/{qty}")),
+                ));
+                filled_qty = Some(total);
+            }
+            OrderEventAny::Accepted(accepted) => {
+                details.push(format!("venue_order_id={}", accepted.venue_order_id));
+            }
+            _ => {
+                if let Some(reason) = as_order_event(event).reason() {
+                    details.push(format!("reason={reason}"));
+                }
+            }
+        }
+
+        match next_status(status, previous_status, event) {
+            Ok(next_status) => {
+                previous_status = Some(status);
+                status = next_status;
+            }
+            Err(_) => details.push(format!("[INVALID TRANSITION from {status}]")),
+        }
+
+        if as_order_event(event).reconciliation() {
+            details.push("[RECONCILIATION]".to_string());
+        }
+
+        rows.push([
+            format_offset(anchor, event.ts_event()),
+            event.to_string(),
+            status.to_string(),
+            details.join(", "),
+        ]);
+    }
+
+    let mut widths = [0; 4];
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.len());
+        }
+    }
+
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(column, width)| format!("{column:<width$}"))
+            .collect::<Vec<String>>()
+            .join(COLUMN_SEPARATOR);
+        lines.push(line.trim_end().to_string());
+    }
+
+    lines.join("\n")
+}
+
+/// Returns the order status after the `event`, given the current `status` and the status before it.
+pub(super) fn next_status(
+    status: OrderStatus,
+    previous_status: Option<OrderStatus>,
+    event: &OrderEventAny,
+) -> Result<OrderStatus, OrderError> {
+    match event {
+        OrderEventAny::Initialized(_) => Ok(OrderStatus::Initialized),
+        _ => status.next(previous_status, event),
+    }
+}
+
+fn format_offset(anchor: UnixNanos, ts_event: UnixNanos) -> String {
+    let (sign, delta) = if ts_event >= anchor {
+        ('+', ts_event.as_u64() - anchor.as_u64())
+    } else {
+        ('-', anchor.as_u64() - ts_event.as_u64())
+    };
+    format!(
+        "{sign}{}.{:09}s",
+        delta / 1_000_000_000,
+        delta % 1_000_000_000
+    )
+}
+
+fn push_change<T: PartialEq + ToString>(
+    details: &mut Vec<String>,
+    name: &str,
+    old: Option<T>,
+    new: Option<T>,
+) {
+    if old != new {
+        let display = |value: Option<T>| value.map_or("None".to_string(), |v| v.to_string());
+        details.push(format!("{name} {} -> {}", display(old), display(new)));
+    }
+}
+
+pub(super) fn as_order_event(event: &OrderEventAny) -> &dyn OrderEvent {
+    match event {
+        OrderEventAny::Initialized(event) => event,
+        OrderEventAny::Denied(event) => event,
+        OrderEventAny::Emulated(event) => event,
+        OrderEventAny::Released(event) => event,
+        OrderEventAny::Submitted(event) => event,
+        OrderEventAny::Accepted(event) => event,
+        OrderEventAny::Rejected(event) => event,
+        OrderEventAny::Canceled(event) => event,
+        OrderEventAny::Expired(event) => event,
+        OrderEventAny::Triggered(event) => event,
+        OrderEventAny::PendingUpdate(event) => event,
+        OrderEventAny::PendingCancel(event) => event,
+        OrderEventAny::ModifyRejected(event) => event,
+        OrderEventAny::CancelRejected(event) => event,
+        OrderEventAny::Updated(event) => event,
+        OrderEventAny::PartiallyFilled(event) => event,
+        OrderEventAny::Filled(event) => event,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::{LiquiditySide, OrderSide, TriggerType},
+        events::order::{
+            accepted::OrderAccepted, canceled::OrderCanceled, filled::OrderFilled,
+            initialized::OrderInitialized, pending_cancel::OrderPendingCancel,
+            pending_update::OrderPendingUpdate, submitted::OrderSubmitted, updated::OrderUpdated,
+        },
+        identifiers::{
+            client_order_id::ClientOrderId, trade_id::TradeId, venue_order_id::VenueOrderId,
+        },
+        orders::{base::Order, stubs::TestOrderStubs},
+        stubs::{account_id, audusd_sim_id},
+        types::{currency::Currency, price::Price},
+    };
+
+    const MS: u64 = 1_000_000;
+    const TS_INIT: u64 = 1_700_000_000_000_000_000;
+
+    fn initialized<T: Order>(order: &T) -> OrderInitialized {
+        let OrderEventAny::Initialized(mut init) = order.events()[0].clone() else {
+            panic!("expected `OrderInitialized`");
+        };
+        init.ts_event = TS_INIT.into();
+        init.ts_init = TS_INIT.into();
+        init
+    }
+
+    fn ts(ms: u64) -> UnixNanos {
+        (TS_INIT + ms * MS).into()
+    }
+
+    fn submitted(init: &OrderInitialized, ms: u64) -> OrderEventAny {
+        OrderEventAny::Submitted(
+            OrderSubmitted::new(
+                init.trader_id,
+                init.strategy_id,
+                init.instrument_id,
+                init.client_order_id,
+                account_id(),
+                UUID4::new(),
+                ts(ms),
+                ts(ms),
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn accepted(init: &OrderInitialized, ms: u64) -> OrderEventAny {
+        OrderEventAny::Accepted(
+            OrderAccepted::new(
+                init.trader_id,
+                init.strategy_id,
+                init.instrument_id,
+                init.client_order_id,
+                VenueOrderId::from(format!("V-{}", init.client_order_id).as_str()),
+                account_id(),
+                UUID4::new(),
+                ts(ms),
+                ts(ms),
+                false,
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn filled(
+        init: &OrderInitialized,
+        last_qty: &str,
+        last_px: &str,
+        ms: u64,
+        reconciliation: bool,
+    ) -> OrderFilled {
+        OrderFilled::new(
+            init.trader_id,
+            init.strategy_id,
+            init.instrument_id,
+            init.client_order_id,
+            VenueOrderId::from(format!("V-{}", init.client_order_id).as_str()),
+            account_id(),
+            TradeId::from(format!("T-{ms}").as_str()),
+            init.order_side,
+            init.order_type,
+            Quantity::from(last_qty),
+            Price::from(last_px),
+            Currency::USD(),
+            LiquiditySide::Maker,
+            UUID4::new(),
+            ts(ms),
+            ts(ms),
+            reconciliation,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn entry_order() -> OrderInitialized {
+        initialized(&TestOrderStubs::limit_order(
+            audusd_sim_id(),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-ENTRY")),
+            None,
+        ))
+    }
+
+    #[rstest]
+    fn test_format_empty() {
+        let events: Vec<OrderEventAny> = Vec::new();
+
+        assert_eq!(format_order_timeline(&events), "No order events");
+    }
+
+    #[rstest]
+    fn test_format_bracket_entry_timeline() {
+        let init = entry_order();
+        let events = vec![
+            OrderEventAny::Initialized(init.clone()),
+            submitted(&init, 1),
+            accepted(&init, 3),
+            OrderEventAny::PartiallyFilled(filled(&init, "40000", "1.00000", 1_500, false)),
+            OrderEventAny::Filled(filled(&init, "60000", "0.99999", 2_000, false)),
+        ];
+
+        let expected = "\
+Order O-ENTRY timeline (5 events)
++0.000000000s  Initialized      INITIALIZED       LIMIT BUY 100000 @ 1.00000
++0.001000000s  Submitted        SUBMITTED
++0.003000000s  Accepted         ACCEPTED          venue_order_id=V-O-ENTRY
++1.500000000s  PartiallyFilled  PARTIALLY_FILLED  +40000 @ 1.00000 (filled 40000/100000)
++2.000000000s  Filled           FILLED            +60000 @ 0.99999 (filled 100000/100000)";
+        assert_eq!(format_order_timeline(&events), expected);
+    }
+
+    #[rstest]
+    fn test_format_bracket_stop_loss_timeline() {
+        let init = initialized(&TestOrderStubs::stop_market_order(
+            audusd_sim_id(),
+            OrderSide::Sell,
+            Price::from("0.99000"),
+            Quantity::from(100_000),
+            Some(TriggerType::BidAsk),
+            Some(ClientOrderId::from("O-SL")),
+            None,
+        ));
+        let events = vec![
+            OrderEventAny::Initialized(init.clone()),
+            submitted(&init, 1),
+            accepted(&init, 3),
+            OrderEventAny::PendingUpdate(
+                OrderPendingUpdate::new(
+                    init.trader_id,
+                    init.strategy_id,
+                    init.instrument_id,
+                    init.client_order_id,
+                    account_id(),
+                    UUID4::new(),
+                    ts(1_000),
+                    ts(1_000),
+                    false,
+                    None,
+                )
+                .unwrap(),
+            ),
+            OrderEventAny::Updated(
+                OrderUpdated::new(
+                    init.trader_id,
+                    init.strategy_id,
+                    init.instrument_id,
+                    init.client_order_id,
+                    Quantity::from(100_000),
+                    UUID4::new(),
+                    ts(1_002),
+                    ts(1_002),
+                    false,
+                    None,
+                    None,
+                    None,
+                    Some(Price::from("0.99500")),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            ),
+            OrderEventAny::PendingCancel(
+                OrderPendingCancel::new(
+                    init.trader_id,
+                    init.strategy_id,
+                    init.instrument_id,
+                    init.client_order_id,
+                    account_id(),
+                    UUID4::new(),
+                    ts(2_000),
+                    ts(2_000),
+                    false,
+                    None,
+                )
+                .unwrap(),
+            ),
+            OrderEventAny::Canceled(
+                OrderCanceled::new(
+                    init.trader_id,
+                    init.strategy_id,
+                    init.instrument_id,
+                    init.client_order_id,
+                    UUID4::new(),
+                    ts(2_001),
+                    ts(2_001),
+                    false,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            ),
+        ];
+
+        let expected = "\
+Order O-SL timeline (7 events)
++0.000000000s  Initialized    INITIALIZED     STOP_MARKET SELL 100000 trigger 0.99000
++0.001000000s  Submitted      SUBMITTED
++0.003000000s  Accepted       ACCEPTED        venue_order_id=V-O-SL
++1.000000000s  PendingUpdate  PENDING_UPDATE
++1.002000000s  Updated        ACCEPTED        trigger_price 0.99000 -> 0.99500
++2.000000000s  PendingCancel  PENDING_CANCEL
++2.001000000s  Canceled       CANCELED";
+        assert_eq!(format_order_timeline(&events), expected);
+    }
+
+    #[rstest]
+    fn test_format_bracket_take_profit_reconciled_without_initialized() {
+        let init = initialized(&TestOrderStubs::limit_order(
+            audusd_sim_id(),
+            OrderSide::Sell,
+            Price::from("1.01000"),
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-TP")),
+            None,
+        ));
+        let events = vec![
+            accepted(&init, 3),
+            OrderEventAny::Filled(filled(&init, "100000", "1.01000", 5_003, true)),
+        ];
+
+        let expected = "\
+Order O-TP timeline (2 events)
+WARNING: no OrderInitialized event, times relative to first event (1700000000003000000)
++0.000000000s  Accepted  ACCEPTED  venue_order_id=V-O-TP
++5.000000000s  Filled    FILLED    +100000 @ 1.01000 (filled 100000), [RECONCILIATION]";
+        assert_eq!(format_order_timeline(&events), expected);
+    }
+}
@@ -16,7 +16,9 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{deserialization::from_bool_as_u8, nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{
+    deserialization::from_bool_as_u8, nanos::UnixNanos, serialization::Serializable, uuid::UUID4,
+};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -35,10 +37,8 @@ use crate::{
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
-#[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Builder)]
 #[builder(default)]
-#[serde(tag = "type")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -53,6 +53,15 @@ pub struct OrderUpdated {
     pub quantity: Quantity,
     pub price: Option<Price>,
     pub trigger_price: Option<Price>,
+    /// The order quantity before the update, when known.
+    #[serde(default)]
+    pub prev_quantity: Option<Quantity>,
+    /// The order price before the update, when known.
+    #[serde(default)]
+    pub prev_price: Option<Price>,
+    /// The order trigger price before the update, when known.
+    #[serde(default)]
+    pub prev_trigger_price: Option<Price>,
     pub event_id: UUID4,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
@@ -76,6 +85,9 @@ impl OrderUpdated {
         account_id: Option<AccountId>,
         price: Option<Price>,
         trigger_price: Option<Price>,
+        prev_quantity: Option<Quantity>,
+        prev_price: Option<Price>,
+        prev_trigger_price: Option<Price>,
     ) -> anyhow::Result<Self> {
         Ok(Self {
             trader_id,
@@ -91,6 +103,9 @@ impl OrderUpdated {
             account_id,
             price,
             trigger_price,
+            prev_quantity,
+            prev_price,
+            prev_trigger_price,
         })
     }
 }
@@ -99,7 +114,8 @@ impl Debug for OrderUpdated {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f,
             "{}(trader_id={}, strategy_id={}, instrument_id={}, client_order_id={}, \
-            venue_order_id={}, account_id={}, quantity={}, price={}, trigger_price={}, event_id={}, ts_event={}, ts_init={})",
+            venue_order_id={}, account_id={}, quantity={}, price={}, trigger_price={}, \
+            prev_quantity={}, prev_price={}, prev_trigger_price={}, event_id={}, ts_event={}, ts_init={})",
             stringify!(OrderUpdated),
             self.trader_id,
             self.strategy_id,
@@ -110,6 +126,9 @@ impl Debug for OrderUpdated {
             self.quantity,
             self.price.map_or("None".to_string(), |price| price.to_formatted_string()),
             self.trigger_price.map_or("None".to_string(), |trigger_price| trigger_price.to_formatted_string()),
+            self.prev_quantity.map_or("None".to_string(), |prev_quantity| prev_quantity.to_formatted_string()),
+            self.prev_price.map_or("None".to_string(), |prev_price| prev_price.to_formatted_string()),
+            self.prev_trigger_price.map_or("None".to_string(), |prev_trigger_price| prev_trigger_price.to_formatted_string()),
             self.event_id,
             self.ts_event,
             self.ts_init
@@ -205,7 +224,7 @@ impl OrderEvent for OrderUpdated {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation != 0
     }
 
     fn price(&self) -> Option<Price> {
@@ -305,14 +324,20 @@ impl OrderEvent for OrderUpdated {
     }
 }
 
+impl Serializable for OrderUpdated {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::serialization::{DecimalFormat, Serializable, SerializationConfig};
     use rstest::rstest;
 
-    use crate::events::order::{stubs::*, updated::OrderUpdated};
+    use crate::{
+        events::order::{stubs::*, updated::OrderUpdated},
+        types::{price::Price, quantity::Quantity},
+    };
 
     #[rstest]
     fn test_order_updated_display(order_updated: OrderUpdated) {
@@ -322,4 +347,48 @@ mod tests {
             "OrderUpdated(instrument_id=BTCUSDT.COINBASE, client_order_id=O-19700101-0000-000-001-1, venue_order_id=001, account_id=SIM-001, quantity=100, price=22_000, trigger_price=None, ts_event=0)"
         );
     }
+
+    #[rstest]
+    #[case(DecimalFormat::Number)]
+    #[case(DecimalFormat::StringCanonical)]
+    #[case(DecimalFormat::RawParts)]
+    fn test_order_updated_json_round_trip(
+        order_updated: OrderUpdated,
+        #[case] decimal_format: DecimalFormat,
+    ) {
+        let config = SerializationConfig::new(decimal_format);
+
+        let bytes = order_updated.as_json_bytes_with_config(config).unwrap();
+        let deserialized = OrderUpdated::from_json_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, order_updated);
+    }
+
+    #[rstest]
+    fn test_order_updated_json_round_trip_with_prev_values(mut order_updated: OrderUpdated) {
+        order_updated.prev_quantity = Some(Quantity::from(150));
+        order_updated.prev_price = Some(Price::from("21500"));
+        order_updated.prev_trigger_price = Some(Price::from("21000"));
+
+        let bytes = order_updated.as_json_bytes().unwrap();
+        let deserialized = OrderUpdated::from_json_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, order_updated);
+    }
+
+    #[rstest]
+    fn test_order_updated_json_without_prev_values(order_updated: OrderUpdated) {
+        let mut value = serde_json::to_value(order_updated).unwrap();
+        let fields = value.as_object_mut().unwrap();
+        fields.remove("prev_quantity");
+        fields.remove("prev_price");
+        fields.remove("prev_trigger_price");
+
+        let deserialized: OrderUpdated = serde_json::from_str(&value.to_string()).unwrap();
+
+        assert_eq!(deserialized, order_updated);
+        assert_eq!(deserialized.prev_quantity, None);
+        assert_eq!(deserialized.prev_price, None);
+        assert_eq!(deserialized.prev_trigger_price, None);
+    }
 }
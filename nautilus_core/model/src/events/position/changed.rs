@@ -13,7 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::nanos::UnixNanos;
+use derive_builder::Builder;
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     enums::{OrderSide, PositionSide},
@@ -24,8 +26,10 @@ use crate::{
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+/// Represents an event where a position has changed.
 #[repr(C)]
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Builder, Serialize, Deserialize)]
+#[serde(tag = "type")]
 pub struct PositionChanged {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -37,16 +41,21 @@ pub struct PositionChanged {
     pub side: PositionSide,
     pub signed_qty: f64,
     pub quantity: Quantity,
+    #[serde(rename = "peak_qty")]
     pub peak_quantity: Quantity,
     pub last_qty: Quantity,
     pub last_px: Price,
     pub currency: Currency,
     pub avg_px_open: f64,
+    #[serde(rename = "avg_px_close")]
     pub avg_px_closed: f64,
     pub realized_return: f64,
     pub realized_pnl: Money,
     pub unrealized_pnl: Money,
+    pub event_id: UUID4,
     pub ts_opened: UnixNanos,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
 }
+
+impl Serializable for PositionChanged {}
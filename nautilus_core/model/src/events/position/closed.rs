@@ -13,7 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::nanos::{DurationNanos, UnixNanos};
+use derive_builder::Builder;
+use nautilus_core::{
+    nanos::{DurationNanos, UnixNanos},
+    serialization::Serializable,
+    uuid::UUID4,
+};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     enums::{OrderSide, PositionSide},
@@ -23,8 +29,13 @@ use crate::{
     },
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
+
+/// Represents an event where a position has been closed.
+///
+/// A closed position is flat, so there is no unrealized PnL.
 #[repr(C)]
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Builder, Serialize, Deserialize)]
+#[serde(tag = "type")]
 pub struct PositionClosed {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -37,18 +48,24 @@ pub struct PositionClosed {
     pub side: PositionSide,
     pub signed_qty: f64,
     pub quantity: Quantity,
+    #[serde(rename = "peak_qty")]
     pub peak_quantity: Quantity,
     pub last_qty: Quantity,
     pub last_px: Price,
     pub currency: Currency,
     pub avg_px_open: f64,
+    #[serde(rename = "avg_px_close")]
     pub avg_px_closed: f64,
     pub realized_return: f64,
     pub realized_pnl: Money,
-    pub unrealized_pnl: Money,
-    pub duration: DurationNanos,
+    pub event_id: UUID4,
     pub ts_opened: UnixNanos,
-    pub ts_closed: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the position was closed, which is the event time.
+    #[serde(rename = "ts_closed")]
     pub ts_event: UnixNanos,
+    #[serde(rename = "duration_ns")]
+    pub duration: DurationNanos,
     pub ts_init: UnixNanos,
 }
+
+impl Serializable for PositionClosed {}
@@ -13,8 +13,19 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use crate::events::position::{
-    changed::PositionChanged, closed::PositionClosed, opened::PositionOpened,
+//! Position events, serialized in the same flat `"type"` tagged form as the Python
+//! `PositionEvent.to_dict` implementations.
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{
+    events::position::{changed::PositionChanged, closed::PositionClosed, opened::PositionOpened},
+    identifiers::{
+        account_id::AccountId, instrument_id::InstrumentId, position_id::PositionId,
+        strategy_id::StrategyId, trader_id::TraderId,
+    },
 };
 
 pub mod changed;
@@ -23,8 +34,262 @@ pub mod opened;
 
 pub mod state;
 
+/// The `"type"` tags accepted by [`PositionEvent`], in variant order.
+const TAGS: &[&str] = &["PositionOpened", "PositionChanged", "PositionClosed"];
+
+#[derive(Clone, PartialEq, Debug)]
 pub enum PositionEvent {
     PositionOpened(PositionOpened),
     PositionChanged(PositionChanged),
     PositionClosed(PositionClosed),
 }
+
+impl PositionEvent {
+    #[must_use]
+    pub fn trader_id(&self) -> TraderId {
+        match self {
+            Self::PositionOpened(event) => event.trader_id,
+            Self::PositionChanged(event) => event.trader_id,
+            Self::PositionClosed(event) => event.trader_id,
+        }
+    }
+
+    #[must_use]
+    pub fn strategy_id(&self) -> StrategyId {
+        match self {
+            Self::PositionOpened(event) => event.strategy_id,
+            Self::PositionChanged(event) => event.strategy_id,
+            Self::PositionClosed(event) => event.strategy_id,
+        }
+    }
+
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        match self {
+            Self::PositionOpened(event) => event.instrument_id,
+            Self::PositionChanged(event) => event.instrument_id,
+            Self::PositionClosed(event) => event.instrument_id,
+        }
+    }
+
+    #[must_use]
+    pub fn position_id(&self) -> PositionId {
+        match self {
+            Self::PositionOpened(event) => event.position_id,
+            Self::PositionChanged(event) => event.position_id,
+            Self::PositionClosed(event) => event.position_id,
+        }
+    }
+
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        match self {
+            Self::PositionOpened(event) => event.account_id,
+            Self::PositionChanged(event) => event.account_id,
+            Self::PositionClosed(event) => event.account_id,
+        }
+    }
+
+    #[must_use]
+    pub fn event_id(&self) -> UUID4 {
+        match self {
+            Self::PositionOpened(event) => event.event_id,
+            Self::PositionChanged(event) => event.event_id,
+            Self::PositionClosed(event) => event.event_id,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::PositionOpened(event) => event.ts_event,
+            Self::PositionChanged(event) => event.ts_event,
+            Self::PositionClosed(event) => event.ts_event,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::PositionOpened(event) => event.ts_init,
+            Self::PositionChanged(event) => event.ts_init,
+            Self::PositionClosed(event) => event.ts_init,
+        }
+    }
+}
+
+impl From<PositionOpened> for PositionEvent {
+    fn from(event: PositionOpened) -> Self {
+        Self::PositionOpened(event)
+    }
+}
+
+impl From<PositionChanged> for PositionEvent {
+    fn from(event: PositionChanged) -> Self {
+        Self::PositionChanged(event)
+    }
+}
+
+impl From<PositionClosed> for PositionEvent {
+    fn from(event: PositionClosed) -> Self {
+        Self::PositionClosed(event)
+    }
+}
+
+impl Serialize for PositionEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::PositionOpened(event) => event.serialize(serializer),
+            Self::PositionChanged(event) => event.serialize(serializer),
+            Self::PositionClosed(event) => event.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PositionEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let tag = value
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| de::Error::missing_field("type"))?
+            .to_string();
+
+        match tag.as_str() {
+            "PositionOpened" => serde_json::from_value(value).map(Self::PositionOpened),
+            "PositionChanged" => serde_json::from_value(value).map(Self::PositionChanged),
+            "PositionClosed" => serde_json::from_value(value).map(Self::PositionClosed),
+            _ => return Err(de::Error::unknown_variant(&tag, TAGS)),
+        }
+        .map_err(de::Error::custom)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::{OrderSide, PositionSide},
+        events::position::{changed::PositionChangedBuilder, opened::PositionOpenedBuilder},
+        identifiers::client_order_id::ClientOrderId,
+        types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    };
+
+    /// Fixtures exported with `json.dumps(Position*.to_dict(event))` from the Python implementation.
+    const FIXTURES_DIR: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../tests/test_data/nautilus/position_events"
+    );
+
+    fn load_fixture(file_name: &str) -> Value {
+        let json = fs::read_to_string(format!("{FIXTURES_DIR}/{file_name}")).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[rstest]
+    #[case("position_opened.json", "PositionOpened")]
+    #[case("position_changed.json", "PositionChanged")]
+    #[case("position_closed.json", "PositionClosed")]
+    fn test_python_fixture_round_trip(#[case] file_name: &str, #[case] tag: &str) {
+        let fixture = load_fixture(file_name);
+
+        let event: PositionEvent = serde_json::from_value(fixture.clone()).unwrap();
+        let value = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(value["type"], tag);
+        assert_eq!(value, fixture);
+    }
+
+    #[rstest]
+    fn test_opened_fields_from_fixture() {
+        let event: PositionOpened =
+            serde_json::from_value(load_fixture("position_opened.json")).unwrap();
+
+        assert_eq!(event.position_id, PositionId::from("P-123456"));
+        assert_eq!(
+            event.opening_order_id,
+            ClientOrderId::from("O-19700101-000000-001-001-1")
+        );
+        assert_eq!(event.entry, OrderSide::Buy);
+        assert_eq!(event.side, PositionSide::Long);
+        assert_eq!(event.peak_quantity, Quantity::from(100_000));
+        assert_eq!(event.last_px, Price::from("1.00001"));
+        assert_eq!(
+            event.realized_pnl,
+            Money::new(-2.0, Currency::USD()).unwrap()
+        );
+        assert_eq!(event.duration, 0);
+    }
+
+    #[rstest]
+    fn test_closed_ts_event_is_ts_closed() {
+        let event: PositionClosed =
+            serde_json::from_value(load_fixture("position_closed.json")).unwrap();
+
+        assert_eq!(event.ts_event, UnixNanos::from(1_000_000_000));
+        assert_eq!(event.duration, 1_000_000_000);
+        assert_eq!(event.avg_px_closed, 1.0001);
+    }
+
+    #[rstest]
+    fn test_builder_and_accessors() {
+        let event = PositionChangedBuilder::default()
+            .trader_id(TraderId::from("TESTER-000"))
+            .strategy_id(StrategyId::from("S-001"))
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .position_id(PositionId::from("P-123456"))
+            .account_id(AccountId::from("SIM-000"))
+            .opening_order_id(ClientOrderId::from("O-1"))
+            .entry(OrderSide::Sell)
+            .side(PositionSide::Short)
+            .signed_qty(-50_000.0)
+            .quantity(Quantity::from(50_000))
+            .peak_quantity(Quantity::from(100_000))
+            .last_qty(Quantity::from(50_000))
+            .last_px(Price::from("1.00010"))
+            .currency(Currency::USD())
+            .avg_px_open(1.0)
+            .avg_px_closed(1.0001)
+            .realized_return(-0.0001)
+            .realized_pnl(Money::new(-5.0, Currency::USD()).unwrap())
+            .unrealized_pnl(Money::new(-5.0, Currency::USD()).unwrap())
+            .event_id(UUID4::new())
+            .ts_opened(UnixNanos::from(1))
+            .ts_event(UnixNanos::from(2))
+            .ts_init(UnixNanos::from(3))
+            .build()
+            .unwrap();
+        let position_event = PositionEvent::from(event.clone());
+
+        assert_eq!(position_event.position_id(), PositionId::from("P-123456"));
+        assert_eq!(position_event.ts_event(), UnixNanos::from(2));
+        assert_eq!(position_event.event_id(), event.event_id);
+
+        let json = serde_json::to_string(&position_event).unwrap();
+        let decoded: PositionEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, position_event);
+    }
+
+    #[rstest]
+    fn test_builder_missing_field_errors() {
+        let result = PositionOpenedBuilder::default()
+            .trader_id(TraderId::from("TESTER-000"))
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_unknown_type_errors() {
+        let result = serde_json::from_str::<PositionEvent>(r#"{"type": "PositionExploded"}"#);
+
+        assert!(result.unwrap_err().to_string().contains("unknown variant"));
+    }
+}
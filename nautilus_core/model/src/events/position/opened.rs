@@ -13,7 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::nanos::UnixNanos;
+use derive_builder::Builder;
+use nautilus_core::{
+    nanos::{DurationNanos, UnixNanos},
+    serialization::Serializable,
+    uuid::UUID4,
+};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     enums::{OrderSide, PositionSide},
@@ -21,11 +27,13 @@ use crate::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         position_id::PositionId, strategy_id::StrategyId, trader_id::TraderId,
     },
-    types::{currency::Currency, price::Price, quantity::Quantity},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+/// Represents an event where a position has been opened.
 #[repr(C)]
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Builder, Serialize, Deserialize)]
+#[serde(tag = "type")]
 pub struct PositionOpened {
     pub trader_id: TraderId,
     pub strategy_id: StrategyId,
@@ -37,10 +45,20 @@ pub struct PositionOpened {
     pub side: PositionSide,
     pub signed_qty: f64,
     pub quantity: Quantity,
+    #[serde(rename = "peak_qty")]
+    pub peak_quantity: Quantity,
     pub last_qty: Quantity,
     pub last_px: Price,
     pub currency: Currency,
     pub avg_px_open: f64,
+    pub realized_pnl: Money,
+    /// Always zero for an opened position, kept for parity with the other position events.
+    #[builder(default)]
+    #[serde(rename = "duration_ns")]
+    pub duration: DurationNanos,
+    pub event_id: UUID4,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
 }
+
+impl Serializable for PositionOpened {}
@@ -19,7 +19,10 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use nautilus_core::{ffi::string::str_to_cstr, nanos::UnixNanos};
+use nautilus_core::{
+    ffi::string::{str_to_cstr, write_result},
+    nanos::UnixNanos,
+};
 
 use crate::{
     data::quote::QuoteTick,
@@ -27,8 +30,19 @@ use crate::{
     types::{price::Price, quantity::Quantity},
 };
 
+/// Writes a new `QuoteTick` constructed from the given raw values to `out`.
+///
+/// The quote invariants are only checked when `validate` is non-zero, so quotes with an empty
+/// side or a crossed top level can still be loaded from raw data.
+///
+/// Returns null on success, otherwise a C string describing why the quote is invalid, which must
+/// be freed with `cstr_drop`.
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to write a `QuoteTick` to.
 #[no_mangle]
-pub extern "C" fn quote_tick_new(
+pub unsafe extern "C" fn quote_tick_new(
     instrument_id: InstrumentId,
     bid_price_raw: i64,
     ask_price_raw: i64,
@@ -40,17 +54,37 @@ pub extern "C" fn quote_tick_new(
     ask_size_prec: u8,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> QuoteTick {
-    QuoteTick::new(
-        instrument_id,
-        Price::from_raw(bid_price_raw, bid_price_prec).unwrap(),
-        Price::from_raw(ask_price_raw, ask_price_prec).unwrap(),
-        Quantity::from_raw(bid_size_raw, bid_size_prec).unwrap(),
-        Quantity::from_raw(ask_size_raw, ask_size_prec).unwrap(),
-        ts_event,
-        ts_init,
-    )
-    .unwrap()
+    validate: u8,
+    out: *mut QuoteTick,
+) -> *const c_char {
+    let result = (|| -> anyhow::Result<QuoteTick> {
+        let bid_price = Price::from_raw(bid_price_raw, bid_price_prec)?;
+        let ask_price = Price::from_raw(ask_price_raw, ask_price_prec)?;
+        let bid_size = Quantity::from_raw(bid_size_raw, bid_size_prec)?;
+        let ask_size = Quantity::from_raw(ask_size_raw, ask_size_prec)?;
+        if validate != 0 {
+            Ok(QuoteTick::new(
+                instrument_id,
+                bid_price,
+                ask_price,
+                bid_size,
+                ask_size,
+                ts_event,
+                ts_init,
+            )?)
+        } else {
+            Ok(QuoteTick::new_unchecked(
+                instrument_id,
+                bid_price,
+                ask_price,
+                bid_size,
+                ask_size,
+                ts_event,
+                ts_init,
+            ))
+        }
+    })();
+    write_result(result, out)
 }
 
 #[no_mangle]
@@ -78,3 +112,69 @@ pub extern "C" fn quote_tick_hash(delta: &QuoteTick) -> u64 {
 pub extern "C" fn quote_tick_to_cstr(tick: &QuoteTick) -> *const c_char {
     str_to_cstr(&tick.to_string())
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::mem::MaybeUninit;
+
+    use nautilus_core::ffi::string::{cstr_drop, cstr_to_str};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_quote_tick_new_with_crossed_quote_returns_error() {
+        let mut quote = MaybeUninit::uninit();
+        let error = unsafe {
+            quote_tick_new(
+                InstrumentId::from("AUD/USD.SIM"),
+                100_001_000_000,
+                100_000_000_000,
+                5,
+                5,
+                100_000_000_000_000,
+                100_000_000_000_000,
+                0,
+                0,
+                UnixNanos::default(),
+                UnixNanos::default(),
+                1,
+                quote.as_mut_ptr(),
+            )
+        };
+
+        assert!(unsafe { cstr_to_str(error) }.starts_with("Crossed quote"));
+        unsafe { cstr_drop(error) };
+    }
+
+    #[rstest]
+    fn test_quote_tick_new_without_validation_accepts_empty_side() {
+        let mut quote = MaybeUninit::uninit();
+        let error = unsafe {
+            quote_tick_new(
+                InstrumentId::from("AUD/USD.SIM"),
+                100_001_000_000,
+                100_000_000_000,
+                5,
+                5,
+                100_000_000_000_000,
+                0,
+                0,
+                0,
+                UnixNanos::default(),
+                UnixNanos::default(),
+                0,
+                quote.as_mut_ptr(),
+            )
+        };
+
+        assert!(error.is_null());
+        let quote = unsafe { quote.assume_init() };
+        assert_eq!(quote.bid_price, Price::from("100.00100"));
+        assert_eq!(quote.ask_price, Price::from("100.00000"));
+        assert_eq!(quote.ask_size, Quantity::from(0));
+    }
+}
@@ -19,7 +19,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use nautilus_core::ffi::string::str_to_cstr;
+use nautilus_core::ffi::string::{str_to_cstr, write_result};
 
 use crate::{
     data::trade::TradeTick,
@@ -28,8 +28,18 @@ use crate::{
     types::{price::Price, quantity::Quantity},
 };
 
+/// Writes a new `TradeTick` constructed from the given raw values to `out`.
+///
+/// The trade invariants are only checked when `validate` is non-zero.
+///
+/// Returns null on success, otherwise a C string describing why the trade is invalid, which must
+/// be freed with `cstr_drop`.
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to write a `TradeTick` to.
 #[no_mangle]
-pub extern "C" fn trade_tick_new(
+pub unsafe extern "C" fn trade_tick_new(
     instrument_id: InstrumentId,
     price_raw: i64,
     price_prec: u8,
@@ -39,16 +49,35 @@ pub extern "C" fn trade_tick_new(
     trade_id: TradeId,
     ts_event: u64,
     ts_init: u64,
-) -> TradeTick {
-    TradeTick::new(
-        instrument_id,
-        Price::from_raw(price_raw, price_prec).unwrap(),
-        Quantity::from_raw(size_raw, size_prec).unwrap(),
-        aggressor_side,
-        trade_id,
-        ts_event.into(),
-        ts_init.into(),
-    )
+    validate: u8,
+    out: *mut TradeTick,
+) -> *const c_char {
+    let result = (|| -> anyhow::Result<TradeTick> {
+        let price = Price::from_raw(price_raw, price_prec)?;
+        let size = Quantity::from_raw(size_raw, size_prec)?;
+        if validate != 0 {
+            Ok(TradeTick::new(
+                instrument_id,
+                price,
+                size,
+                aggressor_side,
+                trade_id,
+                ts_event.into(),
+                ts_init.into(),
+            )?)
+        } else {
+            Ok(TradeTick::new_unchecked(
+                instrument_id,
+                price,
+                size,
+                aggressor_side,
+                trade_id,
+                ts_event.into(),
+                ts_init.into(),
+            ))
+        }
+    })();
+    write_result(result, out)
 }
 
 #[no_mangle]
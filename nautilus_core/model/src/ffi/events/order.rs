@@ -13,25 +13,45 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::ffi::c_char;
+use std::{
+    ffi::{c_char, CStr},
+    ops::Deref,
+    ptr::null,
+};
 
-use nautilus_core::{ffi::string::cstr_to_ustr, nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{
+    ffi::{
+        cvec::CVec,
+        string::{str_to_cstr, validate_cstr, write_result, CharsetPolicy, TEXT_MAX_LEN},
+    },
+    nanos::UnixNanos,
+    serialization::Serializable,
+    uuid::UUID4,
+};
+use ustr::Ustr;
 
 use crate::{
+    enums::{LiquiditySide, OrderSide, OrderType},
     events::order::{
-        accepted::OrderAccepted, denied::OrderDenied, emulated::OrderEmulated,
+        accepted::OrderAccepted, canceled::OrderCanceled, correlation::CorrelationId,
+        denied::OrderDenied, emulated::OrderEmulated, expired::OrderExpired, filled::OrderFilled,
         rejected::OrderRejected, released::OrderReleased, submitted::OrderSubmitted,
+        triggered::OrderTriggered, updated::OrderUpdated,
     },
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
-        strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
+        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
+        venue_order_id::VenueOrderId,
     },
-    types::price::Price,
+    orders::base::OrderError,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+/// Returns a new `OrderDenied` event, or an error if `reason_ptr` is not a valid reason.
+///
 /// # Safety
 ///
-/// - Assumes `reason_ptr` is a valid C string pointer.
+/// - Assumes `reason_ptr` is a valid C string pointer or null.
 #[no_mangle]
 pub unsafe extern "C" fn order_denied_new(
     trader_id: TraderId,
@@ -42,17 +62,20 @@ pub unsafe extern "C" fn order_denied_new(
     event_id: UUID4,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> OrderDenied {
-    OrderDenied {
-        trader_id,
-        strategy_id,
-        instrument_id,
-        client_order_id,
-        reason: cstr_to_ustr(reason_ptr),
-        event_id,
-        ts_event,
-        ts_init,
-    }
+) -> OrderDeniedResult {
+    validate_cstr(reason_ptr, TEXT_MAX_LEN, CharsetPolicy::Text)
+        .map(|reason| OrderDenied {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            reason: Ustr::from(reason),
+            event_id,
+            ts_event,
+            ts_init,
+        })
+        .map_err(anyhow::Error::from)
+        .into()
 }
 
 #[no_mangle]
@@ -119,6 +142,7 @@ pub extern "C" fn order_submitted_new(
         event_id,
         ts_event,
         ts_init,
+        correlation_id: CorrelationId::default(),
     }
 }
 
@@ -146,12 +170,41 @@ pub extern "C" fn order_accepted_new(
         ts_event,
         ts_init,
         reconciliation,
+        correlation_id: CorrelationId::default(),
     }
 }
 
+/// The result of constructing an `OrderRejected` across the FFI boundary.
+///
+/// On failure `value` is a default event and `error` points to a C string describing the
+/// failure, which must be freed with `cstr_drop`. On success `error` is null.
+#[repr(C)]
+#[derive(Debug)]
+pub struct OrderRejectedResult {
+    pub value: OrderRejected,
+    pub error: *const c_char,
+}
+
+impl From<anyhow::Result<OrderRejected>> for OrderRejectedResult {
+    fn from(result: anyhow::Result<OrderRejected>) -> Self {
+        match result {
+            Ok(value) => Self {
+                value,
+                error: null(),
+            },
+            Err(e) => Self {
+                value: OrderRejected::default(),
+                error: str_to_cstr(&e.to_string()),
+            },
+        }
+    }
+}
+
+/// Returns a new `OrderRejected` event, or an error if `reason_ptr` is not a valid reason.
+///
 /// # Safety
 ///
-/// - Assumes `reason_ptr` is a valid C string pointer.
+/// - Assumes `reason_ptr` is a valid C string pointer or null.
 #[no_mangle]
 pub unsafe extern "C" fn order_rejected_new(
     trader_id: TraderId,
@@ -164,17 +217,1372 @@ pub unsafe extern "C" fn order_rejected_new(
     ts_event: UnixNanos,
     ts_init: UnixNanos,
     reconciliation: u8,
-) -> OrderRejected {
-    OrderRejected {
+) -> OrderRejectedResult {
+    validate_cstr(reason_ptr, TEXT_MAX_LEN, CharsetPolicy::Text)
+        .map(|reason| OrderRejected {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            account_id,
+            reason: Ustr::from(reason),
+            event_id,
+            ts_event,
+            ts_init,
+            reconciliation,
+            correlation_id: CorrelationId::default(),
+        })
+        .map_err(anyhow::Error::from)
+        .into()
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderCanceled`.
+///
+/// The `Option` fields of `OrderCanceled` have no C layout, so the event is held behind a pointer
+/// and must be freed with `order_canceled_drop`.
+#[repr(C)]
+#[derive(Clone)]
+#[allow(non_camel_case_types)]
+pub struct OrderCanceled_API(Box<OrderCanceled>);
+
+impl Deref for OrderCanceled_API {
+    type Target = OrderCanceled;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// # Safety
+///
+/// - Assumes `venue_order_id_ptr` and `account_id_ptr` are either null (for `None`) or valid
+///   pointers.
+#[no_mangle]
+pub unsafe extern "C" fn order_canceled_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id_ptr: *const VenueOrderId,
+    account_id_ptr: *const AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderCanceled_API {
+    OrderCanceled_API(Box::new(OrderCanceled {
         trader_id,
         strategy_id,
         instrument_id,
         client_order_id,
-        account_id,
-        reason: cstr_to_ustr(reason_ptr),
         event_id,
         ts_event,
         ts_init,
         reconciliation,
+        venue_order_id: venue_order_id_ptr.as_ref().copied(),
+        account_id: account_id_ptr.as_ref().copied(),
+    }))
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_drop(event: OrderCanceled_API) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_clone(event: &OrderCanceled_API) -> OrderCanceled_API {
+    event.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_trader_id(event: &OrderCanceled_API) -> TraderId {
+    event.trader_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_strategy_id(event: &OrderCanceled_API) -> StrategyId {
+    event.strategy_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_instrument_id(event: &OrderCanceled_API) -> InstrumentId {
+    event.instrument_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_client_order_id(event: &OrderCanceled_API) -> ClientOrderId {
+    event.client_order_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_event_id(event: &OrderCanceled_API) -> UUID4 {
+    event.event_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_ts_event(event: &OrderCanceled_API) -> UnixNanos {
+    event.ts_event
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_ts_init(event: &OrderCanceled_API) -> UnixNanos {
+    event.ts_init
+}
+
+#[no_mangle]
+pub extern "C" fn order_canceled_reconciliation(event: &OrderCanceled_API) -> u8 {
+    event.reconciliation
+}
+
+/// Returns a pointer to the venue order ID of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_canceled_venue_order_id(event: &OrderCanceled_API) -> *const VenueOrderId {
+    event
+        .venue_order_id
+        .as_ref()
+        .map_or(null(), std::ptr::from_ref)
+}
+
+/// Returns a pointer to the account ID of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_canceled_account_id(event: &OrderCanceled_API) -> *const AccountId {
+    event.account_id.as_ref().map_or(null(), std::ptr::from_ref)
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderExpired`.
+///
+/// The `Option` fields of `OrderExpired` have no C layout, so the event is held behind a pointer
+/// and must be freed with `order_expired_drop`.
+#[repr(C)]
+#[derive(Clone)]
+#[allow(non_camel_case_types)]
+pub struct OrderExpired_API(Box<OrderExpired>);
+
+impl Deref for OrderExpired_API {
+    type Target = OrderExpired;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// # Safety
+///
+/// - Assumes `venue_order_id_ptr` and `account_id_ptr` are either null (for `None`) or valid
+///   pointers.
+#[no_mangle]
+pub unsafe extern "C" fn order_expired_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id_ptr: *const VenueOrderId,
+    account_id_ptr: *const AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderExpired_API {
+    OrderExpired_API(Box::new(OrderExpired {
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        event_id,
+        ts_event,
+        ts_init,
+        reconciliation,
+        venue_order_id: venue_order_id_ptr.as_ref().copied(),
+        account_id: account_id_ptr.as_ref().copied(),
+    }))
+}
+
+#[no_mangle]
+pub extern "C" fn order_expired_drop(event: OrderExpired_API) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_expired_clone(event: &OrderExpired_API) -> OrderExpired_API {
+    event.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn order_expired_trader_id(event: &OrderExpired_API) -> TraderId {
+    event.trader_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_expired_strategy_id(event: &OrderExpired_API) -> StrategyId {
+    event.strategy_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_expired_instrument_id(event: &OrderExpired_API) -> InstrumentId {
+    event.instrument_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_expired_client_order_id(event: &OrderExpired_API) -> ClientOrderId {
+    event.client_order_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_expired_event_id(event: &OrderExpired_API) -> UUID4 {
+    event.event_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_expired_ts_event(event: &OrderExpired_API) -> UnixNanos {
+    event.ts_event
+}
+
+#[no_mangle]
+pub extern "C" fn order_expired_ts_init(event: &OrderExpired_API) -> UnixNanos {
+    event.ts_init
+}
+
+#[no_mangle]
+pub extern "C" fn order_expired_reconciliation(event: &OrderExpired_API) -> u8 {
+    event.reconciliation
+}
+
+/// Returns a pointer to the venue order ID of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_expired_venue_order_id(event: &OrderExpired_API) -> *const VenueOrderId {
+    event
+        .venue_order_id
+        .as_ref()
+        .map_or(null(), std::ptr::from_ref)
+}
+
+/// Returns a pointer to the account ID of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_expired_account_id(event: &OrderExpired_API) -> *const AccountId {
+    event.account_id.as_ref().map_or(null(), std::ptr::from_ref)
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderTriggered`.
+///
+/// The `Option` fields of `OrderTriggered` have no C layout, so the event is held behind a pointer
+/// and must be freed with `order_triggered_drop`.
+#[repr(C)]
+#[derive(Clone)]
+#[allow(non_camel_case_types)]
+pub struct OrderTriggered_API(Box<OrderTriggered>);
+
+impl Deref for OrderTriggered_API {
+    type Target = OrderTriggered;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// # Safety
+///
+/// - Assumes `venue_order_id_ptr` and `account_id_ptr` are either null (for `None`) or valid
+///   pointers.
+#[no_mangle]
+pub unsafe extern "C" fn order_triggered_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id_ptr: *const VenueOrderId,
+    account_id_ptr: *const AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderTriggered_API {
+    OrderTriggered_API(Box::new(OrderTriggered {
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        event_id,
+        ts_event,
+        ts_init,
+        reconciliation,
+        venue_order_id: venue_order_id_ptr.as_ref().copied(),
+        account_id: account_id_ptr.as_ref().copied(),
+    }))
+}
+
+#[no_mangle]
+pub extern "C" fn order_triggered_drop(event: OrderTriggered_API) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_triggered_clone(event: &OrderTriggered_API) -> OrderTriggered_API {
+    event.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn order_triggered_trader_id(event: &OrderTriggered_API) -> TraderId {
+    event.trader_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_triggered_strategy_id(event: &OrderTriggered_API) -> StrategyId {
+    event.strategy_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_triggered_instrument_id(event: &OrderTriggered_API) -> InstrumentId {
+    event.instrument_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_triggered_client_order_id(event: &OrderTriggered_API) -> ClientOrderId {
+    event.client_order_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_triggered_event_id(event: &OrderTriggered_API) -> UUID4 {
+    event.event_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_triggered_ts_event(event: &OrderTriggered_API) -> UnixNanos {
+    event.ts_event
+}
+
+#[no_mangle]
+pub extern "C" fn order_triggered_ts_init(event: &OrderTriggered_API) -> UnixNanos {
+    event.ts_init
+}
+
+#[no_mangle]
+pub extern "C" fn order_triggered_reconciliation(event: &OrderTriggered_API) -> u8 {
+    event.reconciliation
+}
+
+/// Returns a pointer to the venue order ID of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_triggered_venue_order_id(
+    event: &OrderTriggered_API,
+) -> *const VenueOrderId {
+    event
+        .venue_order_id
+        .as_ref()
+        .map_or(null(), std::ptr::from_ref)
+}
+
+/// Returns a pointer to the account ID of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_triggered_account_id(event: &OrderTriggered_API) -> *const AccountId {
+    event.account_id.as_ref().map_or(null(), std::ptr::from_ref)
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderUpdated`.
+///
+/// The `Option` fields of `OrderUpdated` have no C layout, so the event is held behind a pointer
+/// and must be freed with `order_updated_drop`.
+#[repr(C)]
+#[derive(Clone)]
+#[allow(non_camel_case_types)]
+pub struct OrderUpdated_API(Box<OrderUpdated>);
+
+impl Deref for OrderUpdated_API {
+    type Target = OrderUpdated;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// # Safety
+///
+/// - Assumes `venue_order_id_ptr`, `account_id_ptr`, `price_ptr`, `trigger_price_ptr`,
+///   `prev_quantity_ptr`, `prev_price_ptr` and `prev_trigger_price_ptr` are either null
+///   (for `None`) or valid pointers.
+#[no_mangle]
+pub unsafe extern "C" fn order_updated_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id_ptr: *const VenueOrderId,
+    account_id_ptr: *const AccountId,
+    quantity: Quantity,
+    price_ptr: *const Price,
+    trigger_price_ptr: *const Price,
+    prev_quantity_ptr: *const Quantity,
+    prev_price_ptr: *const Price,
+    prev_trigger_price_ptr: *const Price,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderUpdated_API {
+    OrderUpdated_API(Box::new(OrderUpdated {
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        venue_order_id: venue_order_id_ptr.as_ref().copied(),
+        account_id: account_id_ptr.as_ref().copied(),
+        quantity,
+        price: price_ptr.as_ref().copied(),
+        trigger_price: trigger_price_ptr.as_ref().copied(),
+        prev_quantity: prev_quantity_ptr.as_ref().copied(),
+        prev_price: prev_price_ptr.as_ref().copied(),
+        prev_trigger_price: prev_trigger_price_ptr.as_ref().copied(),
+        event_id,
+        ts_event,
+        ts_init,
+        reconciliation,
+    }))
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_drop(event: OrderUpdated_API) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_clone(event: &OrderUpdated_API) -> OrderUpdated_API {
+    event.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_trader_id(event: &OrderUpdated_API) -> TraderId {
+    event.trader_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_strategy_id(event: &OrderUpdated_API) -> StrategyId {
+    event.strategy_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_instrument_id(event: &OrderUpdated_API) -> InstrumentId {
+    event.instrument_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_client_order_id(event: &OrderUpdated_API) -> ClientOrderId {
+    event.client_order_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_quantity(event: &OrderUpdated_API) -> Quantity {
+    event.quantity
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_event_id(event: &OrderUpdated_API) -> UUID4 {
+    event.event_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_ts_event(event: &OrderUpdated_API) -> UnixNanos {
+    event.ts_event
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_ts_init(event: &OrderUpdated_API) -> UnixNanos {
+    event.ts_init
+}
+
+#[no_mangle]
+pub extern "C" fn order_updated_reconciliation(event: &OrderUpdated_API) -> u8 {
+    event.reconciliation
+}
+
+/// Returns a pointer to the venue order ID of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_updated_venue_order_id(event: &OrderUpdated_API) -> *const VenueOrderId {
+    event
+        .venue_order_id
+        .as_ref()
+        .map_or(null(), std::ptr::from_ref)
+}
+
+/// Returns a pointer to the account ID of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_updated_account_id(event: &OrderUpdated_API) -> *const AccountId {
+    event.account_id.as_ref().map_or(null(), std::ptr::from_ref)
+}
+
+/// Returns a pointer to the price of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_updated_price(event: &OrderUpdated_API) -> *const Price {
+    event.price.as_ref().map_or(null(), std::ptr::from_ref)
+}
+
+/// Returns a pointer to the trigger price of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_updated_trigger_price(event: &OrderUpdated_API) -> *const Price {
+    event
+        .trigger_price
+        .as_ref()
+        .map_or(null(), std::ptr::from_ref)
+}
+
+/// Returns a pointer to the prev quantity of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_updated_prev_quantity(event: &OrderUpdated_API) -> *const Quantity {
+    event
+        .prev_quantity
+        .as_ref()
+        .map_or(null(), std::ptr::from_ref)
+}
+
+/// Returns a pointer to the prev price of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_updated_prev_price(event: &OrderUpdated_API) -> *const Price {
+    event.prev_price.as_ref().map_or(null(), std::ptr::from_ref)
+}
+
+/// Returns a pointer to the prev trigger price of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_updated_prev_trigger_price(event: &OrderUpdated_API) -> *const Price {
+    event
+        .prev_trigger_price
+        .as_ref()
+        .map_or(null(), std::ptr::from_ref)
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderFilled`.
+///
+/// The `Option` fields of `OrderFilled` have no C layout, so the event is held behind a pointer
+/// and must be freed with `order_filled_drop`.
+#[repr(C)]
+#[derive(Clone)]
+#[allow(non_camel_case_types)]
+pub struct OrderFilled_API(Box<OrderFilled>);
+
+impl Deref for OrderFilled_API {
+    type Target = OrderFilled;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The result of creating an `OrderFilled` across the FFI boundary.
+///
+/// On failure `value` holds a default event and `error` points to a C string describing the
+/// failure, which must be freed with `cstr_drop`. On success `error` is null. In both cases
+/// `value` must be freed with `order_filled_drop`.
+#[repr(C)]
+pub struct OrderFilledResult {
+    pub value: OrderFilled_API,
+    pub error: *const c_char,
+}
+
+impl From<Result<OrderFilled, OrderError>> for OrderFilledResult {
+    fn from(result: Result<OrderFilled, OrderError>) -> Self {
+        match result {
+            Ok(value) => Self {
+                value: OrderFilled_API(Box::new(value)),
+                error: null(),
+            },
+            Err(e) => Self {
+                value: OrderFilled_API(Box::default()),
+                error: str_to_cstr(&format!("Failed to create `OrderFilled`: {e}")),
+            },
+        }
+    }
+}
+
+/// Returns the result of creating an `OrderFilled`, reporting a fill which fails validation
+/// (see `OrderFilled::new`) rather than panicking.
+///
+/// # Safety
+///
+/// - Assumes `position_id_ptr` and `commission_ptr` are either null (for `None`) or valid
+///   pointers.
+#[no_mangle]
+pub unsafe extern "C" fn order_filled_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: VenueOrderId,
+    account_id: AccountId,
+    trade_id: TradeId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    last_qty: Quantity,
+    last_px: Price,
+    currency: Currency,
+    liquidity_side: LiquiditySide,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+    position_id_ptr: *const PositionId,
+    commission_ptr: *const Money,
+) -> OrderFilledResult {
+    OrderFilled::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        venue_order_id,
+        account_id,
+        trade_id,
+        order_side,
+        order_type,
+        last_qty,
+        last_px,
+        currency,
+        liquidity_side,
+        event_id,
+        ts_event,
+        ts_init,
+        reconciliation != 0,
+        position_id_ptr.as_ref().copied(),
+        commission_ptr.as_ref().copied(),
+    )
+    .into()
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_drop(event: OrderFilled_API) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_clone(event: &OrderFilled_API) -> OrderFilled_API {
+    event.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_trader_id(event: &OrderFilled_API) -> TraderId {
+    event.trader_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_strategy_id(event: &OrderFilled_API) -> StrategyId {
+    event.strategy_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_instrument_id(event: &OrderFilled_API) -> InstrumentId {
+    event.instrument_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_client_order_id(event: &OrderFilled_API) -> ClientOrderId {
+    event.client_order_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_venue_order_id(event: &OrderFilled_API) -> VenueOrderId {
+    event.venue_order_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_account_id(event: &OrderFilled_API) -> AccountId {
+    event.account_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_trade_id(event: &OrderFilled_API) -> TradeId {
+    event.trade_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_order_side(event: &OrderFilled_API) -> OrderSide {
+    event.order_side
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_order_type(event: &OrderFilled_API) -> OrderType {
+    event.order_type
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_last_qty(event: &OrderFilled_API) -> Quantity {
+    event.last_qty
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_last_px(event: &OrderFilled_API) -> Price {
+    event.last_px
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_currency(event: &OrderFilled_API) -> Currency {
+    event.currency
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_liquidity_side(event: &OrderFilled_API) -> LiquiditySide {
+    event.liquidity_side
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_event_id(event: &OrderFilled_API) -> UUID4 {
+    event.event_id
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_ts_event(event: &OrderFilled_API) -> UnixNanos {
+    event.ts_event
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_ts_init(event: &OrderFilled_API) -> UnixNanos {
+    event.ts_init
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_reconciliation(event: &OrderFilled_API) -> u8 {
+    u8::from(event.reconciliation)
+}
+
+/// Returns a pointer to the position ID of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_filled_position_id(event: &OrderFilled_API) -> *const PositionId {
+    event
+        .position_id
+        .as_ref()
+        .map_or(null(), std::ptr::from_ref)
+}
+
+/// Returns a pointer to the commission of the event, or null if it has none.
+///
+/// The pointer is only valid while `event` is alive.
+#[no_mangle]
+pub extern "C" fn order_filled_commission(event: &OrderFilled_API) -> *const Money {
+    event.commission.as_ref().map_or(null(), std::ptr::from_ref)
+}
+
+/// The result of constructing an `OrderDenied` across the FFI boundary.
+///
+/// On failure `value` is a default event and `error` points to a C string describing the
+/// failure, which must be freed with `cstr_drop`. On success `error` is null.
+#[repr(C)]
+#[derive(Debug)]
+pub struct OrderDeniedResult {
+    pub value: OrderDenied,
+    pub error: *const c_char,
+}
+
+impl From<anyhow::Result<OrderDenied>> for OrderDeniedResult {
+    fn from(result: anyhow::Result<OrderDenied>) -> Self {
+        match result {
+            Ok(value) => Self {
+                value,
+                error: null(),
+            },
+            Err(e) => Self {
+                value: OrderDenied::default(),
+                error: str_to_cstr(&e.to_string()),
+            },
+        }
+    }
+}
+
+unsafe fn decode_order_denied_json(ptr: *const c_char) -> anyhow::Result<OrderDenied> {
+    anyhow::ensure!(!ptr.is_null(), "JSON pointer was null");
+    let bytes = CStr::from_ptr(ptr).to_bytes();
+    Ok(OrderDenied::from_json_bytes(bytes.to_vec())?)
+}
+
+unsafe fn decode_order_denied_msgpack(ptr: *const u8, len: usize) -> anyhow::Result<OrderDenied> {
+    anyhow::ensure!(!ptr.is_null(), "MsgPack pointer was null");
+    let bytes = std::slice::from_raw_parts(ptr, len);
+    Ok(OrderDenied::from_msgpack_bytes(bytes.to_vec())?)
+}
+
+/// Returns the given `event` serialized as a JSON C string, to be freed with `cstr_drop`.
+///
+/// # Panics
+///
+/// - If serialization fails.
+#[no_mangle]
+pub extern "C" fn order_denied_to_json(event: &OrderDenied) -> *const c_char {
+    let bytes = event
+        .as_json_bytes()
+        .expect("Failed to serialize `OrderDenied` to JSON");
+    str_to_cstr(&String::from_utf8(bytes).expect("JSON was not valid UTF-8"))
+}
+
+/// Returns the given `event` serialized as `MsgPack` bytes, to be freed with `cvec_drop`.
+///
+/// # Panics
+///
+/// - If serialization fails.
+#[no_mangle]
+pub extern "C" fn order_denied_to_msgpack(event: &OrderDenied) -> CVec {
+    event
+        .as_msgpack_bytes()
+        .expect("Failed to serialize `OrderDenied` to MsgPack")
+        .into()
+}
+
+/// Decodes an `OrderDenied` from the given JSON C string into `out`, returning null on success,
+/// otherwise an error C string to be freed with `cstr_drop` (leaving `out` unchanged).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is null or a valid C string pointer.
+/// - Assumes `out` is a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_denied_from_json(
+    ptr: *const c_char,
+    out: *mut OrderDenied,
+) -> *const c_char {
+    write_result(decode_order_denied_json(ptr), out)
+}
+
+/// Decodes an `OrderDenied` from the given `MsgPack` bytes into `out`, returning null on success,
+/// otherwise an error C string to be freed with `cstr_drop` (leaving `out` unchanged).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is null or points to `len` readable bytes.
+/// - Assumes `out` is a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_denied_from_msgpack(
+    ptr: *const u8,
+    len: usize,
+    out: *mut OrderDenied,
+) -> *const c_char {
+    write_result(decode_order_denied_msgpack(ptr, len), out)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use nautilus_core::ffi::{cvec::cvec_drop, string::cstr_drop};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        events::order::stubs::*,
+        identifiers::stubs::{
+            account_id, client_order_id, instrument_id_btc_usdt, strategy_id_ema_cross, trader_id,
+            uuid4,
+        },
+    };
+
+    #[rstest]
+    fn test_order_submitted_new() {
+        let event = order_submitted_new(
+            trader_id(),
+            strategy_id_ema_cross(),
+            instrument_id_btc_usdt(),
+            client_order_id(),
+            account_id(),
+            uuid4(),
+            1.into(),
+            2.into(),
+        );
+
+        assert_eq!(event.trader_id, trader_id());
+        assert_eq!(event.strategy_id, strategy_id_ema_cross());
+        assert_eq!(event.instrument_id, instrument_id_btc_usdt());
+        assert_eq!(event.client_order_id, client_order_id());
+        assert_eq!(event.account_id, account_id());
+        assert_eq!(event.event_id, uuid4());
+        assert_eq!(event.ts_event, 1);
+        assert_eq!(event.ts_init, 2);
+        assert!(event.correlation_id.is_none());
+    }
+
+    #[rstest]
+    fn test_order_accepted_new() {
+        let venue_order_id = VenueOrderId::from("V-001");
+
+        let event = order_accepted_new(
+            trader_id(),
+            strategy_id_ema_cross(),
+            instrument_id_btc_usdt(),
+            client_order_id(),
+            venue_order_id,
+            account_id(),
+            uuid4(),
+            1.into(),
+            2.into(),
+            1,
+        );
+
+        assert_eq!(event.venue_order_id, venue_order_id);
+        assert_eq!(event.account_id, account_id());
+        assert_eq!(event.reconciliation, 1);
+        assert!(event.correlation_id.is_none());
+    }
+
+    #[rstest]
+    fn test_order_rejected_new() {
+        let reason = CString::new("INSUFFICIENT_MARGIN").unwrap();
+
+        let result = unsafe {
+            order_rejected_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                account_id(),
+                reason.as_ptr(),
+                uuid4(),
+                1.into(),
+                2.into(),
+                0,
+            )
+        };
+
+        assert!(result.error.is_null());
+        let event = result.value;
+        assert_eq!(event.account_id, account_id());
+        assert_eq!(event.reason, Ustr::from("INSUFFICIENT_MARGIN"));
+        assert_eq!(event.reconciliation, 0);
+    }
+
+    #[rstest]
+    fn test_order_rejected_new_with_invalid_reason_returns_error() {
+        let reason = CString::new("x".repeat(TEXT_MAX_LEN + 1)).unwrap();
+
+        let result = unsafe {
+            order_rejected_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                account_id(),
+                reason.as_ptr(),
+                uuid4(),
+                1.into(),
+                2.into(),
+                0,
+            )
+        };
+
+        assert!(!result.error.is_null());
+        unsafe { cstr_drop(result.error) };
+    }
+
+    #[rstest]
+    fn test_order_canceled_expired_triggered_new_with_ids() {
+        let venue_order_id = VenueOrderId::from("V-001");
+        let account_id = account_id();
+
+        let canceled = unsafe {
+            order_canceled_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                &venue_order_id,
+                &account_id,
+                uuid4(),
+                1.into(),
+                2.into(),
+                1,
+            )
+        };
+        let expired = unsafe {
+            order_expired_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                &venue_order_id,
+                &account_id,
+                uuid4(),
+                1.into(),
+                2.into(),
+                1,
+            )
+        };
+        let triggered = unsafe {
+            order_triggered_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                &venue_order_id,
+                &account_id,
+                uuid4(),
+                1.into(),
+                2.into(),
+                1,
+            )
+        };
+
+        assert_eq!(canceled.client_order_id, client_order_id());
+        assert_eq!(canceled.venue_order_id, Some(venue_order_id));
+        assert_eq!(canceled.account_id, Some(account_id));
+        assert_eq!(canceled.reconciliation, 1);
+        assert_eq!(expired.venue_order_id, Some(venue_order_id));
+        assert_eq!(expired.account_id, Some(account_id));
+        assert_eq!(expired.ts_event, 1);
+        assert_eq!(triggered.venue_order_id, Some(venue_order_id));
+        assert_eq!(triggered.account_id, Some(account_id));
+        assert_eq!(triggered.ts_init, 2);
+    }
+
+    #[rstest]
+    fn test_order_canceled_expired_triggered_new_with_null_ids() {
+        let canceled = unsafe {
+            order_canceled_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                null(),
+                null(),
+                uuid4(),
+                1.into(),
+                2.into(),
+                0,
+            )
+        };
+        let expired = unsafe {
+            order_expired_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                null(),
+                null(),
+                uuid4(),
+                1.into(),
+                2.into(),
+                0,
+            )
+        };
+        let triggered = unsafe {
+            order_triggered_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                null(),
+                null(),
+                uuid4(),
+                1.into(),
+                2.into(),
+                0,
+            )
+        };
+
+        assert_eq!(canceled.venue_order_id, None);
+        assert_eq!(canceled.account_id, None);
+        assert_eq!(expired.venue_order_id, None);
+        assert_eq!(expired.account_id, None);
+        assert_eq!(triggered.venue_order_id, None);
+        assert_eq!(triggered.account_id, None);
+    }
+
+    #[rstest]
+    fn test_order_canceled_clone_and_accessors() {
+        let venue_order_id = VenueOrderId::from("V-001");
+        let with_ids = unsafe {
+            order_canceled_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                &venue_order_id,
+                null(),
+                uuid4(),
+                1.into(),
+                2.into(),
+                1,
+            )
+        };
+
+        let cloned = order_canceled_clone(&with_ids);
+        drop(with_ids);
+
+        assert_eq!(order_canceled_client_order_id(&cloned), client_order_id());
+        assert_eq!(
+            order_canceled_instrument_id(&cloned),
+            instrument_id_btc_usdt()
+        );
+        assert_eq!(order_canceled_ts_event(&cloned), 1);
+        assert_eq!(order_canceled_ts_init(&cloned), 2);
+        assert_eq!(order_canceled_reconciliation(&cloned), 1);
+        assert_eq!(
+            unsafe { *order_canceled_venue_order_id(&cloned) },
+            venue_order_id
+        );
+        assert!(order_canceled_account_id(&cloned).is_null());
+    }
+
+    #[rstest]
+    fn test_order_updated_new() {
+        let venue_order_id = VenueOrderId::from("V-001");
+        let price = Price::from("22000.0");
+        let prev_quantity = Quantity::from(10);
+
+        let event = unsafe {
+            order_updated_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                &venue_order_id,
+                null(),
+                Quantity::from(5),
+                &price,
+                null(),
+                &prev_quantity,
+                null(),
+                null(),
+                uuid4(),
+                1.into(),
+                2.into(),
+                0,
+            )
+        };
+
+        assert_eq!(event.venue_order_id, Some(venue_order_id));
+        assert_eq!(event.account_id, None);
+        assert_eq!(event.quantity, Quantity::from(5));
+        assert_eq!(event.price, Some(price));
+        assert_eq!(event.trigger_price, None);
+        assert_eq!(event.prev_quantity, Some(prev_quantity));
+        assert_eq!(event.prev_price, None);
+        assert_eq!(event.prev_trigger_price, None);
+    }
+
+    #[rstest]
+    #[case(true)]
+    #[case(false)]
+    fn test_order_filled_new(#[case] with_options: bool) {
+        let position_id = PositionId::from("P-001");
+        let commission = Money::from("12.20 USDT");
+        let (position_id_ptr, commission_ptr) = if with_options {
+            (
+                &position_id as *const PositionId,
+                &commission as *const Money,
+            )
+        } else {
+            (null(), null())
+        };
+
+        let result = unsafe {
+            order_filled_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                VenueOrderId::from("V-001"),
+                account_id(),
+                TradeId::from("T-001"),
+                OrderSide::Buy,
+                OrderType::Limit,
+                Quantity::from(1),
+                Price::from("22000.0"),
+                Currency::USDT(),
+                LiquiditySide::Taker,
+                uuid4(),
+                1.into(),
+                2.into(),
+                1,
+                position_id_ptr,
+                commission_ptr,
+            )
+        };
+
+        assert!(result.error.is_null());
+        let event = &result.value;
+        assert_eq!(event.venue_order_id, VenueOrderId::from("V-001"));
+        assert_eq!(event.account_id, account_id());
+        assert_eq!(event.trade_id, TradeId::from("T-001"));
+        assert_eq!(event.order_side, OrderSide::Buy);
+        assert_eq!(event.order_type, OrderType::Limit);
+        assert_eq!(event.last_qty, Quantity::from(1));
+        assert_eq!(event.last_px, Price::from("22000.0"));
+        assert_eq!(event.currency, Currency::USDT());
+        assert_eq!(event.liquidity_side, LiquiditySide::Taker);
+        assert!(event.reconciliation);
+        assert_eq!(event.position_id, with_options.then_some(position_id));
+        assert_eq!(event.commission, with_options.then_some(commission));
+    }
+
+    #[rstest]
+    fn test_order_filled_clone_and_accessors() {
+        let commission = Money::from("12.20 USDT");
+        let result = unsafe {
+            order_filled_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                VenueOrderId::from("V-001"),
+                account_id(),
+                TradeId::from("T-001"),
+                OrderSide::Sell,
+                OrderType::Market,
+                Quantity::from(1),
+                Price::from("22000.0"),
+                Currency::USDT(),
+                LiquiditySide::Maker,
+                uuid4(),
+                1.into(),
+                2.into(),
+                0,
+                null(),
+                &commission,
+            )
+        };
+
+        let cloned = order_filled_clone(&result.value);
+        order_filled_drop(result.value);
+
+        assert_eq!(order_filled_trade_id(&cloned), TradeId::from("T-001"));
+        assert_eq!(order_filled_order_side(&cloned), OrderSide::Sell);
+        assert_eq!(order_filled_last_qty(&cloned), Quantity::from(1));
+        assert_eq!(order_filled_last_px(&cloned), Price::from("22000.0"));
+        assert_eq!(order_filled_liquidity_side(&cloned), LiquiditySide::Maker);
+        assert_eq!(order_filled_reconciliation(&cloned), 0);
+        assert!(order_filled_position_id(&cloned).is_null());
+        assert_eq!(unsafe { *order_filled_commission(&cloned) }, commission);
+    }
+
+    #[rstest]
+    fn test_order_filled_new_with_zero_quantity_returns_error() {
+        let result = unsafe {
+            order_filled_new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                instrument_id_btc_usdt(),
+                client_order_id(),
+                VenueOrderId::from("V-001"),
+                account_id(),
+                TradeId::from("T-001"),
+                OrderSide::Buy,
+                OrderType::Limit,
+                Quantity::from(0),
+                Price::from("22000.0"),
+                Currency::USDT(),
+                LiquiditySide::Taker,
+                uuid4(),
+                1.into(),
+                2.into(),
+                0,
+                null(),
+                null(),
+            )
+        };
+
+        let error = unsafe { CStr::from_ptr(result.error) }.to_str().unwrap();
+        assert!(error.starts_with("Failed to create `OrderFilled`"));
+        unsafe { cstr_drop(result.error) };
+        order_filled_drop(result.value);
+    }
+
+    #[rstest]
+    fn test_order_denied_json_round_trip(order_denied_max_submitted_rate: OrderDenied) {
+        let json = order_denied_to_json(&order_denied_max_submitted_rate);
+        let mut decoded = OrderDenied::default();
+
+        let error = unsafe { order_denied_from_json(json, &mut decoded) };
+        unsafe { cstr_drop(json) };
+
+        assert!(error.is_null());
+        assert_eq!(decoded, order_denied_max_submitted_rate);
+    }
+
+    #[rstest]
+    fn test_order_denied_to_msgpack_bytes(order_denied_max_submitted_rate: OrderDenied) {
+        let expected = order_denied_max_submitted_rate.as_msgpack_bytes().unwrap();
+
+        let buffer = order_denied_to_msgpack(&order_denied_max_submitted_rate);
+        let bytes = unsafe { std::slice::from_raw_parts(buffer.ptr.cast::<u8>(), buffer.len) };
+
+        assert_eq!(bytes, expected.as_slice());
+        assert!(bytes.contains(&0)); // Length is not inferred from a terminator
+        cvec_drop(buffer);
+    }
+
+    #[rstest]
+    fn test_order_denied_msgpack_round_trip(order_denied_max_submitted_rate: OrderDenied) {
+        let bytes = order_denied_to_msgpack(&order_denied_max_submitted_rate);
+        let mut decoded = OrderDenied::default();
+
+        let error = unsafe { order_denied_from_msgpack(bytes.ptr.cast(), bytes.len, &mut decoded) };
+        cvec_drop(bytes);
+
+        assert!(error.is_null());
+        assert_eq!(decoded, order_denied_max_submitted_rate);
+    }
+
+    #[rstest]
+    fn test_order_denied_from_json_returns_error() {
+        let json = CString::new(r#"{"type":"OrderDenied"}"#).unwrap();
+        let mut decoded = OrderDenied::default();
+        let original = decoded;
+
+        let error = unsafe { order_denied_from_json(json.as_ptr(), &mut decoded) };
+
+        assert!(!error.is_null());
+        let message = unsafe { CStr::from_ptr(error) }.to_str().unwrap();
+        assert!(
+            message.starts_with("missing field `trader_id`"),
+            "{message}"
+        );
+        assert_eq!(decoded, original);
+        unsafe { cstr_drop(error) };
+    }
+
+    #[rstest]
+    fn test_order_denied_from_msgpack_returns_error() {
+        let bytes = [0x92, 0x01];
+        let mut decoded = OrderDenied::default();
+        let original = decoded;
+
+        let error = unsafe { order_denied_from_msgpack(bytes.as_ptr(), bytes.len(), &mut decoded) };
+
+        assert!(!error.is_null());
+        assert_eq!(decoded, original);
+        unsafe { cstr_drop(error) };
+    }
+
+    #[rstest]
+    fn test_order_denied_from_null_pointer_returns_error() {
+        let mut decoded = OrderDenied::default();
+        let json_error = unsafe { order_denied_from_json(null(), &mut decoded) };
+        let msgpack_error = unsafe { order_denied_from_msgpack(null(), 0, &mut decoded) };
+
+        for error in [json_error, msgpack_error] {
+            let message = unsafe { CStr::from_ptr(error) }.to_str().unwrap();
+            assert!(message.ends_with("pointer was null"), "{message}");
+            unsafe { cstr_drop(error) };
+        }
     }
 }
@@ -15,18 +15,25 @@
 
 use std::ffi::c_char;
 
-use nautilus_core::ffi::string::cstr_to_str;
+use nautilus_core::ffi::string::{validate_cstr, write_result, CharsetPolicy, IDENTIFIER_MAX_LEN};
 
 use crate::identifiers::account_id::AccountId;
 
-/// Returns a Nautilus identifier from a C string pointer.
+/// Writes the Nautilus identifier parsed from a C string pointer to `out`.
+///
+/// Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+/// identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
 ///
 /// # Safety
 ///
-/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `ptr` is a valid C string pointer or NULL.
+/// - Assumes `out` is a valid pointer.
 #[no_mangle]
-pub unsafe extern "C" fn account_id_new(ptr: *const c_char) -> AccountId {
-    AccountId::from(cstr_to_str(ptr))
+pub unsafe extern "C" fn account_id_new(ptr: *const c_char, out: *mut AccountId) -> *const c_char {
+    let result = validate_cstr(ptr, IDENTIFIER_MAX_LEN, CharsetPolicy::Identifier)
+        .map_err(anyhow::Error::from)
+        .and_then(AccountId::new);
+    write_result(result, out)
 }
 
 #[no_mangle]
@@ -44,15 +51,16 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::ffi::identifiers::new_from_cstr;
 
     #[rstest]
     fn test_account_id_round_trip() {
         let s = "IB-U123456789";
         let c_string = CString::new(s).unwrap();
         let ptr = c_string.as_ptr();
-        let account_id = unsafe { account_id_new(ptr) };
+        let account_id = unsafe { new_from_cstr(account_id_new, ptr) };
         let char_ptr = account_id.inner().as_char_ptr();
-        let account_id_2 = unsafe { account_id_new(char_ptr) };
+        let account_id_2 = unsafe { new_from_cstr(account_id_new, char_ptr) };
         assert_eq!(account_id, account_id_2);
     }
 
@@ -61,7 +69,7 @@ mod tests {
         let s = "IB-U123456789";
         let c_string = CString::new(s).unwrap();
         let ptr = c_string.as_ptr();
-        let account_id = unsafe { account_id_new(ptr) };
+        let account_id = unsafe { new_from_cstr(account_id_new, ptr) };
         let cstr_ptr = account_id.inner().as_char_ptr();
         let c_str = unsafe { CStr::from_ptr(cstr_ptr) };
         assert_eq!(c_str.to_str().unwrap(), s);
@@ -72,12 +80,12 @@ mod tests {
         let s1 = "IB-U123456789";
         let c_string1 = CString::new(s1).unwrap();
         let ptr1 = c_string1.as_ptr();
-        let account_id1 = unsafe { account_id_new(ptr1) };
+        let account_id1 = unsafe { new_from_cstr(account_id_new, ptr1) };
 
         let s2 = "IB-U123456789";
         let c_string2 = CString::new(s2).unwrap();
         let ptr2 = c_string2.as_ptr();
-        let account_id2 = unsafe { account_id_new(ptr2) };
+        let account_id2 = unsafe { new_from_cstr(account_id_new, ptr2) };
 
         let hash1 = account_id_hash(&account_id1);
         let hash2 = account_id_hash(&account_id2);
@@ -85,7 +93,7 @@ mod tests {
         let s3 = "IB-U987456789";
         let c_string3 = CString::new(s3).unwrap();
         let ptr3 = c_string3.as_ptr();
-        let account_id3 = unsafe { account_id_new(ptr3) };
+        let account_id3 = unsafe { new_from_cstr(account_id_new, ptr3) };
 
         let hash3 = account_id_hash(&account_id3);
         assert_eq!(hash1, hash2);
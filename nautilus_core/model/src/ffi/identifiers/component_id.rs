@@ -15,18 +15,28 @@
 
 use std::ffi::c_char;
 
-use nautilus_core::ffi::string::cstr_to_str;
+use nautilus_core::ffi::string::{validate_cstr, write_result, CharsetPolicy, IDENTIFIER_MAX_LEN};
 
 use crate::identifiers::component_id::ComponentId;
 
-/// Returns a Nautilus identifier from a C string pointer.
+/// Writes the Nautilus identifier parsed from a C string pointer to `out`.
+///
+/// Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+/// identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
 ///
 /// # Safety
 ///
-/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `ptr` is a valid C string pointer or NULL.
+/// - Assumes `out` is a valid pointer.
 #[no_mangle]
-pub unsafe extern "C" fn component_id_new(ptr: *const c_char) -> ComponentId {
-    ComponentId::from(cstr_to_str(ptr))
+pub unsafe extern "C" fn component_id_new(
+    ptr: *const c_char,
+    out: *mut ComponentId,
+) -> *const c_char {
+    let result = validate_cstr(ptr, IDENTIFIER_MAX_LEN, CharsetPolicy::Identifier)
+        .map_err(anyhow::Error::from)
+        .and_then(ComponentId::new);
+    write_result(result, out)
 }
 
 #[no_mangle]
@@ -20,7 +20,9 @@ use std::{
     str::FromStr,
 };
 
-use nautilus_core::ffi::string::{cstr_to_str, str_to_cstr};
+use nautilus_core::ffi::string::{
+    check_charset, str_to_cstr, validate_cstr, write_result, CharsetPolicy, IDENTIFIER_MAX_LEN,
+};
 
 use crate::identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue};
 
@@ -29,6 +31,16 @@ pub extern "C" fn instrument_id_new(symbol: Symbol, venue: Venue) -> InstrumentI
     InstrumentId::new(symbol, venue)
 }
 
+/// Returns the [`InstrumentId`] parsed from the C string pointer, with the symbol and venue
+/// components validated against their charset policies.
+unsafe fn parse_instrument_id(ptr: *const c_char) -> anyhow::Result<InstrumentId> {
+    let value = validate_cstr(ptr, IDENTIFIER_MAX_LEN, CharsetPolicy::Symbol)?;
+    let instrument_id = InstrumentId::from_str(value)?;
+    check_charset(instrument_id.symbol.as_str(), CharsetPolicy::Symbol)?;
+    check_charset(instrument_id.venue.as_str(), CharsetPolicy::Identifier)?;
+    Ok(instrument_id)
+}
+
 /// Returns any [`InstrumentId`] parsing error from the provided C string pointer.
 ///
 /// # Safety
@@ -36,20 +48,27 @@ pub extern "C" fn instrument_id_new(symbol: Symbol, venue: Venue) -> InstrumentI
 /// - Assumes `ptr` is a valid C string pointer.
 #[no_mangle]
 pub unsafe extern "C" fn instrument_id_check_parsing(ptr: *const c_char) -> *const c_char {
-    match InstrumentId::from_str(cstr_to_str(ptr)) {
+    match parse_instrument_id(ptr) {
         Ok(_) => str_to_cstr(""),
         Err(e) => str_to_cstr(&e.to_string()),
     }
 }
 
-/// Returns a Nautilus identifier from a C string pointer.
+/// Writes the [`InstrumentId`] parsed from a C string pointer to `out`.
+///
+/// Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+/// instrument ID, to be freed with `cstr_drop` (leaving `out` unchanged).
 ///
 /// # Safety
 ///
-/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `ptr` is a valid C string pointer or NULL.
+/// - Assumes `out` is a valid pointer.
 #[no_mangle]
-pub unsafe extern "C" fn instrument_id_from_cstr(ptr: *const c_char) -> InstrumentId {
-    InstrumentId::from(cstr_to_str(ptr))
+pub unsafe extern "C" fn instrument_id_from_cstr(
+    ptr: *const c_char,
+    out: *mut InstrumentId,
+) -> *const c_char {
+    write_result(parse_instrument_id(ptr), out)
 }
 
 /// Returns an [`InstrumentId`] as a C string pointer.
@@ -102,7 +121,10 @@ mod tests {
     use rstest::rstest;
 
     use super::{InstrumentId, *};
-    use crate::identifiers::{symbol::Symbol, venue::Venue};
+    use crate::{
+        ffi::identifiers::new_from_cstr,
+        identifiers::{symbol::Symbol, venue::Venue},
+    };
 
     #[rstest]
     fn test_to_cstr() {
@@ -118,7 +140,7 @@ mod tests {
         unsafe {
             let id = InstrumentId::from("ETH/USDT.BINANCE");
             let result = instrument_id_to_cstr(&id);
-            let id2 = instrument_id_from_cstr(result);
+            let id2 = new_from_cstr(instrument_id_from_cstr, result);
             assert_eq!(id, id2);
         }
     }
@@ -128,7 +150,7 @@ mod tests {
         unsafe {
             let id = InstrumentId::new(Symbol::from("ETH/USDT"), Venue::from("BINANCE"));
             let result = instrument_id_to_cstr(&id);
-            let id2 = instrument_id_from_cstr(result);
+            let id2 = new_from_cstr(instrument_id_from_cstr, result);
             assert_eq!(id, id2);
         }
     }
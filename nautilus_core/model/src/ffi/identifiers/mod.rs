@@ -27,3 +27,176 @@ pub mod trade_id;
 pub mod trader_id;
 pub mod venue;
 pub mod venue_order_id;
+
+/// Returns the value written by the FFI constructor `new` for the C string `ptr`, panicking
+/// with the returned error if construction failed.
+#[cfg(test)]
+pub(crate) unsafe fn new_from_cstr<T>(
+    new: unsafe extern "C" fn(*const std::ffi::c_char, *mut T) -> *const std::ffi::c_char,
+    ptr: *const std::ffi::c_char,
+) -> T {
+    let mut out = std::mem::MaybeUninit::uninit();
+    let error = new(ptr, out.as_mut_ptr());
+    assert!(
+        error.is_null(),
+        "{}",
+        nautilus_core::ffi::string::cstr_to_str(error)
+    );
+    out.assume_init()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::{c_char, CString};
+
+    use nautilus_core::{
+        ffi::string::{
+            cstr_check_valid, cstr_drop, cstr_to_str, validate_cstr, CharsetPolicy,
+            IDENTIFIER_MAX_LEN, TEXT_MAX_LEN,
+        },
+        nanos::UnixNanos,
+        uuid::UUID4,
+    };
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{
+        ffi::events::order::order_denied_new,
+        identifiers::{instrument_id::InstrumentId, stubs},
+    };
+
+    /// Returns byte sequences (without interior NULs), half of them printable ASCII so that a
+    /// useful share of inputs pass validation.
+    fn cstring_input() -> impl Strategy<Value = CString> {
+        prop_oneof![
+            "[ -~]{1,36}".prop_map(|value| CString::new(value).unwrap()),
+            prop::collection::vec(1..=u8::MAX, 0..48)
+                .prop_map(|bytes| CString::new(bytes).unwrap()),
+        ]
+    }
+
+    fn check_valid(ptr: *const c_char, max_len: usize, charset: CharsetPolicy) -> bool {
+        unsafe {
+            let error = cstr_check_valid(ptr, max_len, charset);
+            let is_valid = cstr_to_str(error).is_empty();
+            cstr_drop(error);
+            is_valid
+        }
+    }
+
+    fn assert_interned_valid(value: &str, charset: CharsetPolicy) {
+        let c_string = CString::new(value).unwrap();
+        assert!(
+            unsafe { validate_cstr(c_string.as_ptr(), IDENTIFIER_MAX_LEN, charset) }.is_ok(),
+            "interned invalid identifier {value:?}"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_fuzz_identifier_constructors(input in cstring_input()) {
+            let ptr = input.as_ptr();
+            if !check_valid(ptr, IDENTIFIER_MAX_LEN, CharsetPolicy::Identifier) {
+                return Ok(());
+            }
+            let value = input.to_str().unwrap();
+
+            let ids = unsafe {
+                [
+                    new_from_cstr(client_id::client_id_new, ptr).to_string(),
+                    new_from_cstr(client_order_id::client_order_id_new, ptr).to_string(),
+                    new_from_cstr(component_id::component_id_new, ptr).to_string(),
+                    new_from_cstr(exec_algorithm_id::exec_algorithm_id_new, ptr).to_string(),
+                    new_from_cstr(order_list_id::order_list_id_new, ptr).to_string(),
+                    new_from_cstr(position_id::position_id_new, ptr).to_string(),
+                    new_from_cstr(venue_order_id::venue_order_id_new, ptr).to_string(),
+                    new_from_cstr(venue::venue_new, ptr).to_string(),
+                ]
+            };
+            for id in ids {
+                prop_assert_eq!(&id, value);
+                assert_interned_valid(&id, CharsetPolicy::Identifier);
+            }
+
+            if value.len() < 37 {
+                let trade_id = unsafe { new_from_cstr(trade_id::trade_id_new, ptr) };
+                prop_assert_eq!(trade_id.to_string(), value);
+            }
+
+            // Identifiers requiring a hyphen
+            let hyphenated = CString::new(format!("{value}-001")).unwrap();
+            let ptr = hyphenated.as_ptr();
+            let ids = unsafe {
+                [
+                    new_from_cstr(trader_id::trader_id_new, ptr).to_string(),
+                    new_from_cstr(strategy_id::strategy_id_new, ptr).to_string(),
+                    new_from_cstr(account_id::account_id_new, ptr).to_string(),
+                ]
+            };
+            for id in ids {
+                assert_interned_valid(&id, CharsetPolicy::Identifier);
+            }
+        }
+
+        #[test]
+        fn test_fuzz_symbol_constructors(input in cstring_input()) {
+            let ptr = input.as_ptr();
+            if !check_valid(ptr, IDENTIFIER_MAX_LEN, CharsetPolicy::Symbol) {
+                return Ok(());
+            }
+            let symbol = unsafe { new_from_cstr(symbol::symbol_new, ptr) };
+            prop_assert_eq!(symbol.as_str(), input.to_str().unwrap());
+            assert_interned_valid(symbol.as_str(), CharsetPolicy::Symbol);
+        }
+
+        #[test]
+        fn test_fuzz_instrument_id_check_parsing(input in cstring_input()) {
+            let with_venue = CString::new([input.as_bytes(), b".SIM"].concat()).unwrap();
+            for c_string in [input, with_venue] {
+                let ptr = c_string.as_ptr();
+                let error = unsafe { instrument_id::instrument_id_check_parsing(ptr) };
+                let is_valid = unsafe { cstr_to_str(error) }.is_empty();
+                unsafe { cstr_drop(error) };
+                if is_valid {
+                    let instrument_id =
+                        unsafe { new_from_cstr(instrument_id::instrument_id_from_cstr, ptr) };
+                    prop_assert_eq!(
+                        instrument_id,
+                        InstrumentId::from(c_string.to_str().unwrap())
+                    );
+                    assert_interned_valid(instrument_id.symbol.as_str(), CharsetPolicy::Symbol);
+                    assert_interned_valid(instrument_id.venue.as_str(), CharsetPolicy::Identifier);
+                }
+            }
+        }
+
+        #[test]
+        fn test_fuzz_reason_constructors(input in cstring_input()) {
+            let ptr = input.as_ptr();
+            let is_valid = check_valid(ptr, TEXT_MAX_LEN, CharsetPolicy::Text);
+            prop_assert_eq!(is_valid, input.to_str().is_ok());
+            let result = unsafe {
+                order_denied_new(
+                    stubs::trader_id(),
+                    stubs::strategy_id_ema_cross(),
+                    stubs::instrument_id_btc_usdt(),
+                    stubs::client_order_id(),
+                    ptr,
+                    UUID4::new(),
+                    UnixNanos::default(),
+                    UnixNanos::default(),
+                )
+            };
+            if is_valid {
+                prop_assert!(result.error.is_null());
+                prop_assert_eq!(result.value.reason.as_str(), input.to_str().unwrap());
+            } else {
+                prop_assert!(!result.error.is_null());
+                unsafe { cstr_drop(result.error) };
+            }
+        }
+    }
+}
@@ -15,20 +15,29 @@
 
 use std::{
     collections::hash_map::DefaultHasher,
-    ffi::{c_char, CStr},
+    ffi::c_char,
     hash::{Hash, Hasher},
 };
 
+use nautilus_core::ffi::string::{validate_cstr, write_result, CharsetPolicy, IDENTIFIER_MAX_LEN};
+
 use crate::identifiers::trade_id::TradeId;
 
-/// Returns a Nautilus identifier from a C string pointer.
+/// Writes the Nautilus identifier parsed from a C string pointer to `out`.
+///
+/// Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+/// identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
 ///
 /// # Safety
 ///
-/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `ptr` is a valid C string pointer or NULL.
+/// - Assumes `out` is a valid pointer.
 #[no_mangle]
-pub unsafe extern "C" fn trade_id_new(ptr: *const c_char) -> TradeId {
-    TradeId::from_cstr(CStr::from_ptr(ptr).to_owned()).unwrap()
+pub unsafe extern "C" fn trade_id_new(ptr: *const c_char, out: *mut TradeId) -> *const c_char {
+    let result = validate_cstr(ptr, IDENTIFIER_MAX_LEN, CharsetPolicy::Identifier)
+        .map_err(anyhow::Error::from)
+        .and_then(TradeId::new);
+    write_result(result, out)
 }
 
 #[no_mangle]
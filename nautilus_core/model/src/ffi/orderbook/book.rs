@@ -16,6 +16,7 @@
 use std::{
     ffi::c_char,
     ops::{Deref, DerefMut},
+    ptr,
 };
 
 use nautilus_core::ffi::{cvec::CVec, string::str_to_cstr};
@@ -30,7 +31,7 @@ use crate::{
     identifiers::instrument_id::InstrumentId,
     orderbook::{
         aggregation::{update_book_with_quote_tick, update_book_with_trade_tick},
-        analysis::book_check_integrity,
+        analysis,
         book::OrderBook,
     },
     types::{price::Price, quantity::Quantity},
@@ -87,6 +88,17 @@ pub extern "C" fn orderbook_book_type(book: &OrderBook_API) -> BookType {
     book.book_type
 }
 
+#[no_mangle]
+pub extern "C" fn orderbook_validate(book: &OrderBook_API) -> u8 {
+    u8::from(book.validate)
+}
+
+/// Sets whether the `book` checks its integrity after applying deltas.
+#[no_mangle]
+pub extern "C" fn orderbook_set_validate(book: &mut OrderBook_API, validate: u8) {
+    book.validate = validate != 0;
+}
+
 #[no_mangle]
 pub extern "C" fn orderbook_sequence(book: &OrderBook_API) -> u64 {
     book.sequence
@@ -150,15 +162,35 @@ pub extern "C" fn orderbook_clear_asks(book: &mut OrderBook_API, sequence: u64,
     book.clear_asks(sequence, ts_event.into());
 }
 
+/// Applies the `delta` to the `book`.
+///
+/// Returns null on success, otherwise a C string describing the integrity check failure of a
+/// validating book, which must be freed with `cstr_drop`.
 #[no_mangle]
-pub extern "C" fn orderbook_apply_delta(book: &mut OrderBook_API, delta: OrderBookDelta) {
-    book.apply_delta(delta);
+pub extern "C" fn orderbook_apply_delta(
+    book: &mut OrderBook_API,
+    delta: OrderBookDelta,
+) -> *const c_char {
+    match book.apply_delta(delta) {
+        Ok(_) => ptr::null(),
+        Err(e) => str_to_cstr(&e.to_string()),
+    }
 }
 
+/// Applies the `deltas` to the `book`.
+///
+/// Returns null on success, otherwise a C string describing the integrity check failure of a
+/// validating book, which must be freed with `cstr_drop`.
 #[no_mangle]
-pub extern "C" fn orderbook_apply_deltas(book: &mut OrderBook_API, deltas: &OrderBookDeltas_API) {
+pub extern "C" fn orderbook_apply_deltas(
+    book: &mut OrderBook_API,
+    deltas: &OrderBookDeltas_API,
+) -> *const c_char {
     // Clone will actually copy the contents of the `deltas` vec
-    book.apply_deltas(deltas.deref().clone());
+    match book.apply_deltas(deltas.deref().clone()) {
+        Ok(_) => ptr::null(),
+        Err(e) => str_to_cstr(&e.to_string()),
+    }
 }
 
 #[no_mangle]
@@ -186,6 +218,11 @@ pub extern "C" fn orderbook_asks(book: &mut OrderBook_API) -> CVec {
         .into()
 }
 
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot(book: &OrderBook_API, depth: usize) -> OrderBookDepth10 {
+    book.snapshot(depth)
+}
+
 #[no_mangle]
 pub extern "C" fn orderbook_has_bid(book: &mut OrderBook_API) -> u8 {
     u8::from(book.has_bid())
@@ -232,13 +269,15 @@ pub extern "C" fn orderbook_midpoint(book: &mut OrderBook_API) -> f64 {
         .expect("Error: Unable to calculate `midpoint` (no bid or ask)")
 }
 
+/// Returns the average price for the available portion of `qty`, or 0.0 if the opposite
+/// side of the book is empty (as expected by the Cython `OrderBook`).
 #[no_mangle]
 pub extern "C" fn orderbook_get_avg_px_for_quantity(
     book: &mut OrderBook_API,
     qty: Quantity,
     order_side: OrderSide,
 ) -> f64 {
-    book.get_avg_px_for_quantity(qty, order_side)
+    analysis::get_avg_px_for_quantity(qty, book.opposite_levels(order_side))
 }
 
 #[no_mangle]
@@ -247,7 +286,7 @@ pub extern "C" fn orderbook_get_quantity_for_price(
     price: Price,
     order_side: OrderSide,
 ) -> f64 {
-    book.get_quantity_for_price(price, order_side)
+    analysis::get_quantity_for_price(price, order_side, book.opposite_levels(order_side))
 }
 
 /// Updates the order book with a quote tick.
@@ -277,7 +316,7 @@ pub extern "C" fn orderbook_simulate_fills(book: &OrderBook_API, order: BookOrde
 
 #[no_mangle]
 pub extern "C" fn orderbook_check_integrity(book: &OrderBook_API) -> u8 {
-    u8::from(book_check_integrity(book).is_ok())
+    u8::from(book.check_integrity().is_ok())
 }
 
 // TODO: This struct implementation potentially leaks memory
@@ -299,3 +338,45 @@ pub extern "C" fn orderbook_pprint_to_cstr(
 ) -> *const c_char {
     str_to_cstr(&book.pprint(num_levels))
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::ffi::string::{cstr_drop, cstr_to_str};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::enums::BookAction;
+
+    fn add_delta(side: OrderSide, price: &str, sequence: u64) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            BookAction::Add,
+            BookOrder::new(side, Price::from(price), Quantity::from("10"), 0),
+            0,
+            sequence,
+            sequence.into(),
+            sequence.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_apply_delta_with_validate_set_returns_error_when_crossed() {
+        let mut book = orderbook_new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), BookType::L2_MBP);
+        assert_eq!(orderbook_validate(&book), 0);
+        orderbook_set_validate(&mut book, 1);
+        assert_eq!(orderbook_validate(&book), 1);
+
+        let error = orderbook_apply_delta(&mut book, add_delta(OrderSide::Buy, "100.00", 1));
+        assert!(error.is_null());
+
+        let error = orderbook_apply_delta(&mut book, add_delta(OrderSide::Sell, "99.50", 2));
+        assert!(!error.is_null());
+        assert!(unsafe { cstr_to_str(error) }.starts_with("Integrity error: orders in cross"));
+        unsafe { cstr_drop(error) };
+
+        orderbook_drop(book);
+    }
+}
@@ -15,16 +15,37 @@
 
 use std::{ffi::c_char, str::FromStr};
 
-use nautilus_core::ffi::string::{cstr_to_str, str_to_cstr};
+use nautilus_core::ffi::string::{
+    cstr_to_str, str_to_cstr, validate_cstr, write_result, CharsetPolicy, IDENTIFIER_MAX_LEN,
+    TEXT_MAX_LEN,
+};
 
 use crate::{currencies::CURRENCY_MAP, enums::CurrencyType, types::currency::Currency};
 
-/// Returns a [`Currency`] from pointers and primitives.
+/// Returns the [`Currency`] created from the C string pointers and primitives, with the code
+/// and name validated against their charset policies.
+unsafe fn parse_currency(
+    code_ptr: *const c_char,
+    precision: u8,
+    iso4217: u16,
+    name_ptr: *const c_char,
+    currency_type: CurrencyType,
+) -> anyhow::Result<Currency> {
+    let code = validate_cstr(code_ptr, IDENTIFIER_MAX_LEN, CharsetPolicy::Identifier)?;
+    let name = validate_cstr(name_ptr, TEXT_MAX_LEN, CharsetPolicy::Text)?;
+    Currency::new(code, precision, iso4217, name, currency_type)
+}
+
+/// Writes the [`Currency`] created from pointers and primitives to `out`.
+///
+/// Returns a null pointer on success, otherwise a C string describing why the currency is not
+/// valid, to be freed with `cstr_drop` (leaving `out` unchanged).
 ///
 /// # Safety
 ///
-/// - Assumes `code_ptr` is a valid C string pointer.
-/// - Assumes `name_ptr` is a valid C string pointer.
+/// - Assumes `code_ptr` is a valid C string pointer or NULL.
+/// - Assumes `name_ptr` is a valid C string pointer or NULL.
+/// - Assumes `out` is a valid pointer.
 #[no_mangle]
 pub unsafe extern "C" fn currency_from_py(
     code_ptr: *const c_char,
@@ -32,15 +53,10 @@ pub unsafe extern "C" fn currency_from_py(
     iso4217: u16,
     name_ptr: *const c_char,
     currency_type: CurrencyType,
-) -> Currency {
-    Currency::new(
-        cstr_to_str(code_ptr),
-        precision,
-        iso4217,
-        cstr_to_str(name_ptr),
-        currency_type,
-    )
-    .unwrap()
+    out: *mut Currency,
+) -> *const c_char {
+    let result = parse_currency(code_ptr, precision, iso4217, name_ptr, currency_type);
+    write_result(result, out)
 }
 
 #[no_mangle]
@@ -80,13 +96,24 @@ pub unsafe extern "C" fn currency_exists(code_ptr: *const c_char) -> u8 {
     u8::from(CURRENCY_MAP.lock().unwrap().contains_key(code))
 }
 
+/// Writes the registered [`Currency`] for the code C string pointer to `out`.
+///
+/// Returns a null pointer on success, otherwise a C string describing why the code is not
+/// valid or not registered, to be freed with `cstr_drop` (leaving `out` unchanged).
+///
 /// # Safety
 ///
-/// - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
+/// - Assumes `code_ptr` is a valid C string pointer or NULL.
+/// - Assumes `out` is a valid pointer.
 #[no_mangle]
-pub unsafe extern "C" fn currency_from_cstr(code_ptr: *const c_char) -> Currency {
-    let code = cstr_to_str(code_ptr);
-    Currency::from_str(code).unwrap()
+pub unsafe extern "C" fn currency_from_cstr(
+    code_ptr: *const c_char,
+    out: *mut Currency,
+) -> *const c_char {
+    let result = validate_cstr(code_ptr, IDENTIFIER_MAX_LEN, CharsetPolicy::Identifier)
+        .map_err(anyhow::Error::from)
+        .and_then(Currency::from_str);
+    write_result(result, out)
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -96,6 +123,7 @@ pub unsafe extern "C" fn currency_from_cstr(code_ptr: *const c_char) -> Currency
 mod tests {
     use std::ffi::{CStr, CString};
 
+    use nautilus_core::ffi::string::cstr_drop;
     use rstest::rstest;
 
     use super::*;
@@ -114,9 +142,18 @@ mod tests {
     fn test_currency_from_py() {
         let code = CString::new("MYC").unwrap();
         let name = CString::new("My Currency").unwrap();
-        let currency = unsafe {
-            super::currency_from_py(code.as_ptr(), 4, 0, name.as_ptr(), CurrencyType::Crypto)
+        let mut currency = Currency::USD();
+        let error = unsafe {
+            super::currency_from_py(
+                code.as_ptr(),
+                4,
+                0,
+                name.as_ptr(),
+                CurrencyType::Crypto,
+                &mut currency,
+            )
         };
+        assert!(error.is_null());
         assert_eq!(currency.code.as_str(), "MYC");
         assert_eq!(currency.name.as_str(), "My Currency");
         assert_eq!(currency.currency_type, CurrencyType::Crypto);
@@ -154,25 +191,55 @@ mod tests {
     #[rstest]
     fn test_currency_from_cstr() {
         let code = CString::new("USD").unwrap();
-        let currency = unsafe { currency_from_cstr(code.as_ptr()) };
+        let mut currency = Currency::EUR();
+        let error = unsafe { currency_from_cstr(code.as_ptr(), &mut currency) };
+        assert!(error.is_null());
         assert_eq!(currency, Currency::USD());
     }
 
     #[rstest]
-    #[should_panic(expected = "`ptr` was NULL")]
-    fn test_currency_from_py_null_code_ptr() {
-        let name = CString::new("My Currency").unwrap();
-        let _ = unsafe {
-            currency_from_py(std::ptr::null(), 4, 0, name.as_ptr(), CurrencyType::Crypto)
-        };
+    #[case(CString::new("UNKNOWN").unwrap(), "Unknown currency: UNKNOWN")]
+    #[case(CString::new("").unwrap(), "invalid C string: was empty")]
+    fn test_currency_from_cstr_with_invalid_code_returns_error(
+        #[case] code: CString,
+        #[case] expected: &str,
+    ) {
+        let mut currency = Currency::USD();
+        let error = unsafe { currency_from_cstr(code.as_ptr(), &mut currency) };
+        assert_eq!(unsafe { cstr_to_str(error) }, expected);
+        unsafe { cstr_drop(error) };
+        assert_eq!(currency, Currency::USD());
     }
 
     #[rstest]
-    #[should_panic(expected = "`ptr` was NULL")]
-    fn test_currency_from_py_null_name_ptr() {
+    #[case(true)]
+    #[case(false)]
+    fn test_currency_from_py_with_null_ptr_returns_error(#[case] null_code: bool) {
         let code = CString::new("MYC").unwrap();
-        let _ = unsafe {
-            currency_from_py(code.as_ptr(), 4, 0, std::ptr::null(), CurrencyType::Crypto)
+        let name = CString::new("My Currency").unwrap();
+        let (code_ptr, name_ptr) = if null_code {
+            (std::ptr::null(), name.as_ptr())
+        } else {
+            (code.as_ptr(), std::ptr::null())
+        };
+        let mut currency = Currency::USD();
+
+        let error = unsafe {
+            currency_from_py(
+                code_ptr,
+                4,
+                0,
+                name_ptr,
+                CurrencyType::Crypto,
+                &mut currency,
+            )
         };
+
+        assert_eq!(
+            unsafe { cstr_to_str(error) },
+            "invalid C string: `ptr` was NULL"
+        );
+        unsafe { cstr_drop(error) };
+        assert_eq!(currency, Currency::USD());
     }
 }
@@ -0,0 +1,71 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ffi::c_char;
+
+use nautilus_core::ffi::string::write_result;
+
+use crate::types::fixed;
+
+/// Writes the given `raw` fixed-point value rescaled from `from_precision` to `to_precision`
+/// decimal places to `out`.
+///
+/// Returns null on success, otherwise a C string describing why the rescaling failed (if it would
+/// truncate non-zero digits or overflow, or if either precision is greater than the maximum fixed
+/// precision), which must be freed with `cstr_drop`.
+///
+/// # Safety
+///
+/// - Assumes `out` is a valid pointer to write an `i64` to.
+#[no_mangle]
+pub unsafe extern "C" fn fixed_point_rescale(
+    raw: i64,
+    from_precision: u8,
+    to_precision: u8,
+    out: *mut i64,
+) -> *const c_char {
+    write_result(
+        fixed::fixed_point_rescale(raw, from_precision, to_precision),
+        out,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::ffi::string::cstr_drop;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_fixed_point_rescale() {
+        let mut raw = 0;
+        let error = unsafe { fixed_point_rescale(1_234_560_000, 9, 5, &mut raw) };
+        assert!(error.is_null());
+        assert_eq!(raw, 123_456);
+    }
+
+    #[rstest]
+    fn test_fixed_point_rescale_truncating_returns_error() {
+        let mut raw = 0;
+        let error = unsafe { fixed_point_rescale(1_234_567_890, 9, 5, &mut raw) };
+        assert!(!error.is_null());
+        assert_eq!(raw, 0);
+        unsafe { cstr_drop(error) };
+    }
+}
@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod currency;
+pub mod fixed;
 pub mod money;
 pub mod price;
 pub mod quantity;
@@ -29,6 +29,11 @@ pub extern "C" fn price_from_raw(raw: i64, precision: u8) -> Price {
     Price::from_raw(raw, precision).unwrap()
 }
 
+#[no_mangle]
+pub extern "C" fn price_raw(price: &Price) -> i64 {
+    price.raw()
+}
+
 #[no_mangle]
 pub extern "C" fn price_as_f64(price: &Price) -> f64 {
     price.as_f64()
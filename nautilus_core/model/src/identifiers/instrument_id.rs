@@ -21,7 +21,7 @@ use std::{
 
 use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::identifiers::{symbol::Symbol, venue::Venue};
+use crate::identifiers::{memo::deserialize_identifier, symbol::Symbol, venue::Venue};
 
 /// Represents a valid instrument ID.
 ///
@@ -105,11 +105,12 @@ impl<'de> Deserialize<'de> for InstrumentId {
     where
         D: Deserializer<'de>,
     {
-        let instrument_id_str = String::deserialize(deserializer)?;
-        Self::from_str(&instrument_id_str).map_err(|err| serde::de::Error::custom(err.to_string()))
+        deserialize_identifier(deserializer)
     }
 }
 
+impl_memoized_parse_for_identifier!(InstrumentId);
+
 fn err_message(s: &str, e: String) -> String {
     format!("Error parsing `InstrumentId` from '{s}': {e}")
 }
@@ -29,9 +29,29 @@ macro_rules! impl_serialization_for_identifier {
             where
                 D: Deserializer<'de>,
             {
-                let value_str: &str = Deserialize::deserialize(deserializer)?;
-                let value: $ty = FromStr::from_str(value_str).map_err(serde::de::Error::custom)?;
-                Ok(value)
+                $crate::identifiers::memo::deserialize_identifier(deserializer)
+            }
+        }
+
+        impl_memoized_parse_for_identifier!($ty);
+    };
+}
+
+macro_rules! impl_memoized_parse_for_identifier {
+    ($ty:ty) => {
+        impl $crate::identifiers::memo::ParseIdentifier for $ty {
+            fn parse_identifier(value: &str) -> Result<Self, String> {
+                use $crate::identifiers::memo::IdentifierMemo;
+
+                thread_local! {
+                    static MEMO: std::cell::RefCell<IdentifierMemo<$ty>> =
+                        std::cell::RefCell::new(IdentifierMemo::new());
+                }
+                MEMO.with(|memo| {
+                    memo.borrow_mut().get_or_parse(value, |value| {
+                        <$ty as std::str::FromStr>::from_str(value).map_err(|e| e.to_string())
+                    })
+                })
             }
         }
     };
@@ -0,0 +1,221 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides memoized deserialization for identifiers.
+//!
+//! Bulk deserialization repeatedly parses the same few hundred identifier strings, each time
+//! validating the value and interning it through the global `Ustr` table (which takes a lock).
+//! Identifiers are instead deserialized from a borrowed `&str` where the deserializer allows,
+//! and looked up in a small thread-local memo of recently parsed values per identifier type
+//! before falling back to the normal parse.
+
+use std::{fmt::Formatter, marker::PhantomData};
+
+use serde::{
+    de::{Error, Visitor},
+    Deserializer,
+};
+
+/// The number of slots in each identifier memo.
+const MEMO_SLOTS: usize = 256;
+
+/// Parses an identifier from a string during deserialization.
+pub(crate) trait ParseIdentifier: Sized {
+    fn parse_identifier(value: &str) -> Result<Self, String>;
+}
+
+/// A direct-mapped memo of recently parsed identifiers, keyed by their string value.
+///
+/// Each slot holds the last value which hashed to it, so the memo is bounded and a miss simply
+/// replaces the slot (reusing its key allocation).
+pub(crate) struct IdentifierMemo<T> {
+    slots: Vec<Option<(String, T)>>,
+}
+
+impl<T: Copy> IdentifierMemo<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            slots: (0..MEMO_SLOTS).map(|_| None).collect(),
+        }
+    }
+
+    /// Returns the memoized identifier for the `value`, otherwise parses and memoizes it.
+    ///
+    /// Parse errors are not memoized.
+    pub(crate) fn get_or_parse(
+        &mut self,
+        value: &str,
+        parse: impl FnOnce(&str) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let slot = &mut self.slots[slot_index(value)];
+        match slot {
+            Some((key, id)) if key == value => Ok(*id),
+            Some((key, id)) => {
+                let parsed = parse(value)?;
+                key.clear();
+                key.push_str(value);
+                *id = parsed;
+                Ok(parsed)
+            }
+            None => {
+                let parsed = parse(value)?;
+                *slot = Some((value.to_string(), parsed));
+                Ok(parsed)
+            }
+        }
+    }
+}
+
+/// Returns the memo slot for the `value` using the FNV-1a hash.
+fn slot_index(value: &str) -> usize {
+    let hash = value.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    hash as usize % MEMO_SLOTS
+}
+
+struct IdentifierVisitor<T>(PhantomData<T>);
+
+impl<'de, T: ParseIdentifier> Visitor<'de> for IdentifierVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str("an identifier string")
+    }
+
+    // Borrowed and owned strings both forward here, so no intermediate `String` is allocated
+    fn visit_str<E: Error>(self, value: &str) -> Result<T, E> {
+        T::parse_identifier(value).map_err(E::custom)
+    }
+}
+
+/// Deserializes an identifier from a string, borrowing the input where possible.
+pub(crate) fn deserialize_identifier<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: ParseIdentifier,
+{
+    deserializer.deserialize_str(IdentifierVisitor(PhantomData))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, strategy_id::StrategyId,
+        trade_id::TradeId,
+    };
+
+    #[rstest]
+    fn test_memo_hit_skips_parse() {
+        let mut memo = IdentifierMemo::new();
+        let mut parses = 0;
+        let mut parse = |value: &str| {
+            parses += 1;
+            Ok(value.len())
+        };
+
+        assert_eq!(memo.get_or_parse("ABC", &mut parse), Ok(3));
+        assert_eq!(memo.get_or_parse("ABC", &mut parse), Ok(3));
+        assert_eq!(parses, 1);
+    }
+
+    #[rstest]
+    fn test_memo_does_not_cache_errors() {
+        let mut memo = IdentifierMemo::<usize>::new();
+
+        assert!(memo
+            .get_or_parse("BAD", |_| Err("error".to_string()))
+            .is_err());
+        assert_eq!(memo.get_or_parse("BAD", |_| Ok(1)), Ok(1));
+    }
+
+    #[rstest]
+    fn test_memo_slot_collision_replaces_value() {
+        let mut memo = IdentifierMemo::new();
+        let values: Vec<String> = (0..MEMO_SLOTS * 4).map(|i| format!("ID-{i}")).collect();
+
+        for _ in 0..2 {
+            for value in &values {
+                let parsed = memo.get_or_parse(value, |v| Ok(v.to_string().len()));
+                assert_eq!(parsed, Ok(value.len()));
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_owned_and_borrowed_deserialization_intern_identically() {
+        // Escaped JSON strings cannot be borrowed, so are deserialized from an owned buffer
+        let borrowed = r#"["S-001","AUD/USD.SIM","O-1","T-1"]"#;
+        let escaped = r#"["S-001","AUD\/USD.SIM","O-1","T-1"]"#;
+        type Ids = (StrategyId, InstrumentId, ClientOrderId, TradeId);
+
+        let from_borrowed: Ids = serde_json::from_str(borrowed).unwrap();
+        let from_escaped: Ids = serde_json::from_str(escaped).unwrap();
+        let from_reader: Ids = serde_json::from_reader(borrowed.as_bytes()).unwrap();
+        let value: Value = serde_json::from_str(borrowed).unwrap();
+        let from_value: Ids = serde_json::from_value(value).unwrap();
+
+        let expected = (
+            StrategyId::from("S-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            ClientOrderId::from("O-1"),
+            TradeId::from("T-1"),
+        );
+        for ids in [from_borrowed, from_escaped, from_reader, from_value] {
+            assert_eq!(ids, expected);
+            // Interned to the same `Ustr`
+            assert_eq!(ids.0.inner().as_ptr(), expected.0.inner().as_ptr());
+            assert_eq!(ids.2.inner().as_ptr(), expected.2.inner().as_ptr());
+        }
+    }
+
+    #[rstest]
+    fn test_memoized_deserialization_matches_from_str_across_threads() {
+        let handles: Vec<_> = (0..4)
+            .map(|thread| {
+                std::thread::spawn(move || {
+                    for i in 0..2_000 {
+                        let value = format!("O-{}", (i * 7 + thread) % 500);
+                        let json = format!("\"{value}\"");
+                        let id: ClientOrderId = serde_json::from_str(&json).unwrap();
+                        assert_eq!(id, ClientOrderId::from(value.as_str()));
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[rstest]
+    fn test_invalid_identifier_errors() {
+        for _ in 0..2 {
+            // Errors are not memoized, so fail on every attempt
+            let result: Result<StrategyId, _> = serde_json::from_str("\"NOHYPHEN\"");
+            assert!(result.is_err());
+        }
+        let result: Result<StrategyId, _> = serde_json::from_str("1");
+        assert!(result.is_err());
+    }
+}
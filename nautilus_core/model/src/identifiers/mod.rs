@@ -21,6 +21,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[macro_use]
 mod macros;
+mod memo;
 
 pub mod account_id;
 pub mod client_id;
@@ -22,6 +22,8 @@ use std::{
 use nautilus_core::correctness::{check_in_range_inclusive_usize, check_valid_string};
 use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::identifiers::memo::{deserialize_identifier, ParseIdentifier};
+
 /// The maximum length of ASCII characters for a `TradeId` string value (including null terminator).
 const TRADE_ID_LEN: usize = 37;
 
@@ -100,8 +102,14 @@ impl<'de> Deserialize<'de> for TradeId {
     where
         D: Deserializer<'de>,
     {
-        let value_str = String::deserialize(deserializer)?;
-        Self::new(&value_str).map_err(|err| serde::de::Error::custom(err.to_string()))
+        deserialize_identifier(deserializer)
+    }
+}
+
+// Trade IDs are generally unique, so are parsed without a memo
+impl ParseIdentifier for TradeId {
+    fn parse_identifier(value: &str) -> Result<Self, String> {
+        Self::new(value).map_err(|e| e.to_string())
     }
 }
 
@@ -13,7 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use nautilus_core::nanos::UnixNanos;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
 use super::{
     crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
@@ -21,11 +23,21 @@ use super::{
     options_contract::OptionsContract, options_spread::OptionsSpread, Instrument,
 };
 use crate::{
+    enums::LiquiditySide,
     identifiers::instrument_id::InstrumentId,
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
-#[derive(Clone, Debug)]
+/// Represents a field which differs between two versions of an instrument.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstrumentFieldChange {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
 pub enum InstrumentAny {
     CryptoFuture(CryptoFuture),
     CryptoPerpetual(CryptoPerpetual),
@@ -225,6 +237,239 @@ impl InstrumentAny {
         }
     }
 
+    pub fn calculate_commission(
+        &self,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+        use_quote_for_inverse: Option<bool>,
+    ) -> anyhow::Result<Money> {
+        match self {
+            Self::CryptoFuture(inst) => {
+                inst.calculate_commission(last_qty, last_px, liquidity_side, use_quote_for_inverse)
+            }
+            Self::CryptoPerpetual(inst) => {
+                inst.calculate_commission(last_qty, last_px, liquidity_side, use_quote_for_inverse)
+            }
+            Self::CurrencyPair(inst) => {
+                inst.calculate_commission(last_qty, last_px, liquidity_side, use_quote_for_inverse)
+            }
+            Self::Equity(inst) => {
+                inst.calculate_commission(last_qty, last_px, liquidity_side, use_quote_for_inverse)
+            }
+            Self::FuturesContract(inst) => {
+                inst.calculate_commission(last_qty, last_px, liquidity_side, use_quote_for_inverse)
+            }
+            Self::FuturesSpread(inst) => {
+                inst.calculate_commission(last_qty, last_px, liquidity_side, use_quote_for_inverse)
+            }
+            Self::OptionsContract(inst) => {
+                inst.calculate_commission(last_qty, last_px, liquidity_side, use_quote_for_inverse)
+            }
+            Self::OptionsSpread(inst) => {
+                inst.calculate_commission(last_qty, last_px, liquidity_side, use_quote_for_inverse)
+            }
+        }
+    }
+
+    pub fn calculate_base_quantity(
+        &self,
+        quote_notional: Money,
+        price: Price,
+    ) -> anyhow::Result<Quantity> {
+        match self {
+            Self::CryptoFuture(inst) => inst.calculate_base_quantity(quote_notional, price),
+            Self::CryptoPerpetual(inst) => inst.calculate_base_quantity(quote_notional, price),
+            Self::CurrencyPair(inst) => inst.calculate_base_quantity(quote_notional, price),
+            Self::Equity(inst) => inst.calculate_base_quantity(quote_notional, price),
+            Self::FuturesContract(inst) => inst.calculate_base_quantity(quote_notional, price),
+            Self::FuturesSpread(inst) => inst.calculate_base_quantity(quote_notional, price),
+            Self::OptionsContract(inst) => inst.calculate_base_quantity(quote_notional, price),
+            Self::OptionsSpread(inst) => inst.calculate_base_quantity(quote_notional, price),
+        }
+    }
+
+    #[must_use]
+    pub fn calculate_quote_notional(&self, quantity: Quantity, price: Price) -> Money {
+        match self {
+            Self::CryptoFuture(inst) => inst.calculate_quote_notional(quantity, price),
+            Self::CryptoPerpetual(inst) => inst.calculate_quote_notional(quantity, price),
+            Self::CurrencyPair(inst) => inst.calculate_quote_notional(quantity, price),
+            Self::Equity(inst) => inst.calculate_quote_notional(quantity, price),
+            Self::FuturesContract(inst) => inst.calculate_quote_notional(quantity, price),
+            Self::FuturesSpread(inst) => inst.calculate_quote_notional(quantity, price),
+            Self::OptionsContract(inst) => inst.calculate_quote_notional(quantity, price),
+            Self::OptionsSpread(inst) => inst.calculate_quote_notional(quantity, price),
+        }
+    }
+
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::CryptoFuture(inst) => inst.ts_event,
+            Self::CryptoPerpetual(inst) => inst.ts_event,
+            Self::CurrencyPair(inst) => inst.ts_event,
+            Self::Equity(inst) => inst.ts_event,
+            Self::FuturesContract(inst) => inst.ts_event,
+            Self::FuturesSpread(inst) => inst.ts_event,
+            Self::OptionsContract(inst) => inst.ts_event,
+            Self::OptionsSpread(inst) => inst.ts_event,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::CryptoFuture(inst) => inst.ts_init,
+            Self::CryptoPerpetual(inst) => inst.ts_init,
+            Self::CurrencyPair(inst) => inst.ts_init,
+            Self::Equity(inst) => inst.ts_init,
+            Self::FuturesContract(inst) => inst.ts_init,
+            Self::FuturesSpread(inst) => inst.ts_init,
+            Self::OptionsContract(inst) => inst.ts_init,
+            Self::OptionsSpread(inst) => inst.ts_init,
+        }
+    }
+
+    fn as_instrument(&self) -> &dyn Instrument {
+        match self {
+            Self::CryptoFuture(inst) => inst,
+            Self::CryptoPerpetual(inst) => inst,
+            Self::CurrencyPair(inst) => inst,
+            Self::Equity(inst) => inst,
+            Self::FuturesContract(inst) => inst,
+            Self::FuturesSpread(inst) => inst,
+            Self::OptionsContract(inst) => inst,
+            Self::OptionsSpread(inst) => inst,
+        }
+    }
+
+    /// Returns the specification fields which differ from the `old` to the `new` version
+    /// of an instrument (timestamps are not compared).
+    #[must_use]
+    pub fn diff(old: &Self, new: &Self) -> Vec<InstrumentFieldChange> {
+        let (old, new) = (old.as_instrument(), new.as_instrument());
+        let mut changes = Vec::new();
+
+        macro_rules! diff_fields {
+            ($($field:ident),+ $(,)?) => {
+                $(
+                    let (old_value, new_value) = (old.$field(), new.$field());
+                    if old_value != new_value {
+                        changes.push(InstrumentFieldChange {
+                            field: stringify!($field),
+                            old: format!("{old_value:?}"),
+                            new: format!("{new_value:?}"),
+                        });
+                    }
+                )+
+            };
+        }
+
+        diff_fields!(
+            id,
+            raw_symbol,
+            asset_class,
+            instrument_class,
+            underlying,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            isin,
+            option_kind,
+            exchange,
+            strike_price,
+            activation_ns,
+            expiration_ns,
+            is_inverse,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+        );
+
+        changes
+    }
+
+    /// Returns a new version of the instrument with the given fee rates, initialized at `ts_init`.
+    ///
+    /// # Errors
+    ///
+    /// If the instrument type does not apply fee rates.
+    pub fn with_fees(
+        &self,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        let mut instrument = self.clone();
+        let fees = (maker_fee, taker_fee);
+        match &mut instrument {
+            Self::CryptoFuture(inst) => (inst.maker_fee, inst.taker_fee) = fees,
+            Self::CryptoPerpetual(inst) => (inst.maker_fee, inst.taker_fee) = fees,
+            Self::CurrencyPair(inst) => (inst.maker_fee, inst.taker_fee) = fees,
+            Self::Equity(inst) => (inst.maker_fee, inst.taker_fee) = fees,
+            Self::FuturesContract(_)
+            | Self::FuturesSpread(_)
+            | Self::OptionsContract(_)
+            | Self::OptionsSpread(_) => {
+                anyhow::bail!("Instrument {} does not apply fee rates", self.id())
+            }
+        }
+        instrument.set_timestamps(ts_init);
+        Ok(instrument)
+    }
+
+    /// Returns a new version of the instrument with the given margin rates, initialized at
+    /// `ts_init`.
+    #[must_use]
+    pub fn with_margins(
+        &self,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        ts_init: UnixNanos,
+    ) -> Self {
+        let mut instrument = self.clone();
+        let margins = (margin_init, margin_maint);
+        match &mut instrument {
+            Self::CryptoFuture(inst) => (inst.margin_init, inst.margin_maint) = margins,
+            Self::CryptoPerpetual(inst) => (inst.margin_init, inst.margin_maint) = margins,
+            Self::CurrencyPair(inst) => (inst.margin_init, inst.margin_maint) = margins,
+            Self::Equity(inst) => (inst.margin_init, inst.margin_maint) = margins,
+            Self::FuturesContract(inst) => (inst.margin_init, inst.margin_maint) = margins,
+            Self::FuturesSpread(inst) => (inst.margin_init, inst.margin_maint) = margins,
+            Self::OptionsContract(inst) => (inst.margin_init, inst.margin_maint) = margins,
+            Self::OptionsSpread(inst) => (inst.margin_init, inst.margin_maint) = margins,
+        }
+        instrument.set_timestamps(ts_init);
+        instrument
+    }
+
+    fn set_timestamps(&mut self, ts_init: UnixNanos) {
+        match self {
+            Self::CryptoFuture(inst) => (inst.ts_event, inst.ts_init) = (ts_init, ts_init),
+            Self::CryptoPerpetual(inst) => (inst.ts_event, inst.ts_init) = (ts_init, ts_init),
+            Self::CurrencyPair(inst) => (inst.ts_event, inst.ts_init) = (ts_init, ts_init),
+            Self::Equity(inst) => (inst.ts_event, inst.ts_init) = (ts_init, ts_init),
+            Self::FuturesContract(inst) => (inst.ts_event, inst.ts_init) = (ts_init, ts_init),
+            Self::FuturesSpread(inst) => (inst.ts_event, inst.ts_init) = (ts_init, ts_init),
+            Self::OptionsContract(inst) => (inst.ts_event, inst.ts_init) = (ts_init, ts_init),
+            Self::OptionsSpread(inst) => (inst.ts_event, inst.ts_init) = (ts_init, ts_init),
+        }
+    }
+
     // #[deprecated(since = "0.21.0", note = "Will be removed in a future version")]
     #[must_use]
     pub fn maker_fee(&self) -> Decimal {
@@ -261,3 +506,135 @@ impl PartialEq for InstrumentAny {
         self.id() == other.id()
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::instruments::stubs::*;
+
+    #[rstest]
+    fn test_diff_when_unchanged(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        assert!(InstrumentAny::diff(&instrument, &instrument).is_empty());
+    }
+
+    #[rstest]
+    fn test_diff_reports_changed_fields(audusd_sim: CurrencyPair) {
+        let old = InstrumentAny::CurrencyPair(audusd_sim);
+        let new = InstrumentAny::CurrencyPair(CurrencyPair {
+            price_precision: 4,
+            price_increment: Price::from("0.0001"),
+            taker_fee: dec!(0.0003),
+            ts_init: UnixNanos::from(1),
+            ..audusd_sim
+        });
+
+        let fields: Vec<&str> = InstrumentAny::diff(&old, &new)
+            .iter()
+            .map(|change| change.field)
+            .collect();
+
+        assert_eq!(
+            fields,
+            vec!["price_precision", "price_increment", "taker_fee"]
+        );
+    }
+
+    #[rstest]
+    fn test_serde_json_round_trip(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+
+        let json = serde_json::to_string(&instrument).unwrap();
+        let deserialized: InstrumentAny = serde_json::from_str(&json).unwrap();
+
+        assert!(InstrumentAny::diff(&instrument, &deserialized).is_empty());
+        assert_eq!(deserialized.ts_init(), instrument.ts_init());
+    }
+
+    #[rstest]
+    fn test_with_fees_creates_new_version(audusd_sim: CurrencyPair) {
+        let old = InstrumentAny::CurrencyPair(audusd_sim);
+
+        let new = old
+            .with_fees(dec!(0.0001), dec!(0.0002), UnixNanos::from(1_000))
+            .unwrap();
+
+        assert_eq!(new.maker_fee(), dec!(0.0001));
+        assert_eq!(new.taker_fee(), dec!(0.0002));
+        assert_eq!(new.ts_init(), UnixNanos::from(1_000));
+        assert_eq!(old.ts_init(), audusd_sim.ts_init);
+        assert!(InstrumentAny::diff(&old, &new)
+            .iter()
+            .all(|change| change.field.ends_with("_fee")));
+    }
+
+    #[rstest]
+    fn test_with_margins_creates_new_version(audusd_sim: CurrencyPair) {
+        let old = InstrumentAny::CurrencyPair(audusd_sim);
+
+        let new = old.with_margins(dec!(0.05), dec!(0.03), UnixNanos::from(1_000));
+
+        assert_eq!(new.as_instrument().margin_init(), dec!(0.05));
+        assert_eq!(new.as_instrument().margin_maint(), dec!(0.03));
+        assert_eq!(new.ts_init(), UnixNanos::from(1_000));
+    }
+
+    #[rstest]
+    fn test_with_fees_for_instrument_without_fees_errors(futures_contract_es: FuturesContract) {
+        let instrument = InstrumentAny::FuturesContract(futures_contract_es);
+
+        assert!(instrument
+            .with_fees(dec!(0.0001), dec!(0.0002), UnixNanos::from(1_000))
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_with_fees_for_crypto_future_and_equity(
+        crypto_future_btcusdt: CryptoFuture,
+        equity_aapl: Equity,
+    ) {
+        for old in [
+            InstrumentAny::CryptoFuture(crypto_future_btcusdt),
+            InstrumentAny::Equity(equity_aapl),
+        ] {
+            let new = old
+                .with_fees(dec!(0.0001), dec!(0.0002), UnixNanos::from(1_000))
+                .unwrap();
+
+            assert_eq!(new.maker_fee(), dec!(0.0001));
+            assert_eq!(new.taker_fee(), dec!(0.0002));
+            assert_eq!(new.ts_init(), UnixNanos::from(1_000));
+        }
+    }
+
+    #[rstest]
+    fn test_with_margins_for_every_instrument_type(
+        crypto_future_btcusdt: CryptoFuture,
+        equity_aapl: Equity,
+        futures_contract_es: FuturesContract,
+        futures_spread_es: FuturesSpread,
+        options_contract_appl: OptionsContract,
+        options_spread: OptionsSpread,
+    ) {
+        for old in [
+            InstrumentAny::CryptoFuture(crypto_future_btcusdt),
+            InstrumentAny::Equity(equity_aapl),
+            InstrumentAny::FuturesContract(futures_contract_es),
+            InstrumentAny::FuturesSpread(futures_spread_es),
+            InstrumentAny::OptionsContract(options_contract_appl),
+            InstrumentAny::OptionsSpread(options_spread),
+        ] {
+            let new = old.with_margins(dec!(0.05), dec!(0.03), UnixNanos::from(1_000));
+
+            assert_eq!(new.as_instrument().margin_init(), dec!(0.05));
+            assert_eq!(new.as_instrument().margin_maint(), dec!(0.03));
+            assert_eq!(new.ts_init(), UnixNanos::from(1_000));
+        }
+    }
+}
@@ -247,6 +247,22 @@ impl Instrument for CryptoFuture {
         self.min_price
     }
 
+    fn margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    fn maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    fn taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
     fn ts_event(&self) -> UnixNanos {
         self.ts_event
     }
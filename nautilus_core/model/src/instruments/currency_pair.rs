@@ -127,6 +127,23 @@ impl CurrencyPair {
     }
 }
 
+impl CurrencyPair {
+    /// Returns the instrument ID of the inverse pair (e.g. `USD/AUD.SIM` for `AUD/USD.SIM`).
+    #[must_use]
+    pub fn inverse_id(&self) -> InstrumentId {
+        let separator = if self.id.symbol.as_str().contains('/') {
+            "/"
+        } else {
+            ""
+        };
+        let symbol = format!(
+            "{}{separator}{}",
+            self.quote_currency.code, self.base_currency.code
+        );
+        InstrumentId::new(Symbol::from(symbol.as_str()), self.id.venue)
+    }
+}
+
 impl PartialEq<Self> for CurrencyPair {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -285,11 +302,23 @@ impl Instrument for CurrencyPair {
 mod tests {
     use rstest::rstest;
 
-    use crate::instruments::{currency_pair::CurrencyPair, stubs::*};
+    use crate::{
+        identifiers::instrument_id::InstrumentId,
+        instruments::{currency_pair::CurrencyPair, stubs::*},
+    };
 
     #[rstest]
     fn test_equality(currency_pair_btcusdt: CurrencyPair) {
         let cloned = currency_pair_btcusdt;
         assert_eq!(currency_pair_btcusdt, cloned);
     }
+
+    #[rstest]
+    fn test_inverse_id(audusd_sim: CurrencyPair, currency_pair_btcusdt: CurrencyPair) {
+        assert_eq!(audusd_sim.inverse_id(), InstrumentId::from("USD/AUD.SIM"));
+        assert_eq!(
+            currency_pair_btcusdt.inverse_id(),
+            InstrumentId::from("USDTBTC.BINANCE")
+        );
+    }
 }
@@ -256,6 +256,14 @@ impl Instrument for FuturesSpread {
         self.min_price
     }
 
+    fn margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
     fn ts_event(&self) -> UnixNanos {
         self.ts_event
     }
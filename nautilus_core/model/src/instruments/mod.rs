@@ -30,13 +30,13 @@ pub mod synthetic;
 pub mod stubs;
 
 use nautilus_core::nanos::UnixNanos;
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use rust_decimal_macros::dec;
 use ustr::Ustr;
 
 use self::any::InstrumentAny;
 use crate::{
-    enums::{AssetClass, InstrumentClass, OptionKind},
+    enums::{AssetClass, InstrumentClass, LiquiditySide, OptionKind},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
@@ -137,9 +137,193 @@ pub trait Instrument: 'static + Send {
         Money::new(amount, currency).unwrap() // TODO: Handle error properly
     }
 
-    /// Returns the equivalent quantity of the base asset.
-    fn calculate_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
-        let value = quantity.as_f64() * (1.0 / last_px.as_f64());
-        Quantity::new(value, self.size_precision()).unwrap() // TODO: Handle error properly
+    /// Calculates the commission for a fill of `last_qty` at `last_px` with the given `liquidity_side`.
+    ///
+    /// The commission is in the currency of the notional value, which is the base (settlement)
+    /// currency for inverse instruments unless `use_quote_for_inverse` is set.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The `liquidity_side` is `NoLiquiditySide`.
+    /// - The commission is not a valid `Money` amount.
+    fn calculate_commission(
+        &self,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+        use_quote_for_inverse: Option<bool>,
+    ) -> anyhow::Result<Money> {
+        let fee = match liquidity_side {
+            LiquiditySide::Maker => self.maker_fee(),
+            LiquiditySide::Taker => self.taker_fee(),
+            LiquiditySide::NoLiquiditySide => {
+                anyhow::bail!("Invalid `LiquiditySide` {liquidity_side}")
+            }
+        };
+        let notional = self.calculate_notional_value(last_qty, last_px, use_quote_for_inverse);
+        Money::new(
+            notional.as_f64() * fee.to_f64().unwrap_or_default(),
+            notional.currency,
+        )
+    }
+
+    /// Calculates the order quantity worth the given `quote_notional` at the given `price`.
+    ///
+    /// The notional may be in the quote currency, or in the base currency. The quantity is
+    /// rounded down to the size increment, so an order never exceeds the requested notional.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The `price` or `quote_notional` is not positive.
+    /// - The notional is in neither the quote nor the base currency.
+    /// - The rounded quantity is zero, or outside the instrument quantity limits.
+    fn calculate_base_quantity(
+        &self,
+        quote_notional: Money,
+        price: Price,
+    ) -> anyhow::Result<Quantity> {
+        anyhow::ensure!(price.raw > 0, "Invalid price {price} for {}", self.id());
+        anyhow::ensure!(
+            quote_notional.raw > 0,
+            "Invalid notional {quote_notional} for {}",
+            self.id()
+        );
+        let unit_value = unit_value(self, price, quote_notional.currency)?;
+        let increment = self.size_increment();
+        let steps = (quote_notional.as_decimal() / unit_value / increment.as_decimal())
+            .floor()
+            .to_u64()
+            .ok_or_else(|| anyhow::anyhow!("Notional {quote_notional} is too large"))?;
+        let quantity = Quantity::from_raw(steps * increment.raw, self.size_precision())?;
+
+        let minimum = self.min_quantity().unwrap_or(increment);
+        anyhow::ensure!(
+            quantity >= minimum && quantity.is_positive(),
+            "Quantity {quantity} for notional {quote_notional} is below the minimum {minimum} for {}",
+            self.id()
+        );
+        if let Some(maximum) = self.max_quantity() {
+            anyhow::ensure!(
+                quantity <= maximum,
+                "Quantity {quantity} for notional {quote_notional} is above the maximum {maximum} for {}",
+                self.id()
+            );
+        }
+        Ok(quantity)
+    }
+
+    /// Calculates the notional value in the quote currency of the given `quantity` at the
+    /// given `price`.
+    ///
+    /// # Panics
+    ///
+    /// If the notional value is outside the range of `Money`.
+    fn calculate_quote_notional(&self, quantity: Quantity, price: Price) -> Money {
+        let unit_value = unit_value(self, price, self.quote_currency())
+            .expect("Quote currency can always be valued");
+        let amount = (quantity.as_decimal() * unit_value)
+            .to_f64()
+            .expect("Notional is representable as f64");
+        Money::new(amount, self.quote_currency()).expect("Notional is within range of `Money`")
+    }
+}
+
+/// Returns the value of one unit of quantity at the given `price`, in the given `currency`.
+fn unit_value<T: Instrument + ?Sized>(
+    instrument: &T,
+    price: Price,
+    currency: Currency,
+) -> anyhow::Result<Decimal> {
+    let multiplier = instrument.multiplier().as_decimal();
+    let is_quote = currency == instrument.quote_currency();
+    let is_base = instrument.base_currency() == Some(currency);
+    match (instrument.is_inverse(), is_quote, is_base) {
+        // Linear quantities are denominated in the base, and inverse in the quote currency
+        (false, true, _) => Ok(multiplier * price.as_decimal()),
+        (false, false, true) | (true, true, _) => Ok(multiplier),
+        (true, false, true) => Ok(multiplier / price.as_decimal()),
+        _ => anyhow::bail!("Cannot value {} in {currency}", instrument.id()),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{
+        crypto_perpetual::CryptoPerpetual,
+        currency_pair::CurrencyPair,
+        stubs::{currency_pair_btcusdt, xbtusd_bitmex},
+        *,
+    };
+
+    #[rstest]
+    fn test_calculate_base_quantity_rounds_down_to_size_increment(
+        currency_pair_btcusdt: CurrencyPair,
+    ) {
+        let quantity = currency_pair_btcusdt
+            .calculate_base_quantity(Money::from("500 USDT"), Price::from("60000.00"))
+            .unwrap();
+
+        // 500 / 60000 = 0.0083333.. BTC
+        assert_eq!(quantity, Quantity::from("0.008333"));
+        assert!(
+            currency_pair_btcusdt.calculate_quote_notional(quantity, Price::from("60000.00"))
+                <= Money::from("500 USDT")
+        );
+    }
+
+    #[rstest]
+    fn test_calculate_base_quantity_below_min_quantity(currency_pair_btcusdt: CurrencyPair) {
+        let result = currency_pair_btcusdt
+            .calculate_base_quantity(Money::from("0.01 USDT"), Price::from("60000.00"));
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Quantity 0.000000 for notional 0.01000000 USDT is below the minimum 0.000001 for BTCUSDT.BINANCE"
+        );
+    }
+
+    #[rstest]
+    fn test_calculate_base_quantity_above_max_quantity(currency_pair_btcusdt: CurrencyPair) {
+        let result = currency_pair_btcusdt
+            .calculate_base_quantity(Money::from("1000000 USDT"), Price::from("100.00"));
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("above the maximum 9000"));
+    }
+
+    #[rstest]
+    fn test_calculate_base_quantity_with_unrelated_currency(currency_pair_btcusdt: CurrencyPair) {
+        let result = currency_pair_btcusdt
+            .calculate_base_quantity(Money::from("500 EUR"), Price::from("60000.00"));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_calculate_base_quantity_for_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        // Inverse contracts are denominated in the quote currency, so the price is irrelevant
+        let from_quote = xbtusd_bitmex
+            .calculate_base_quantity(Money::from("500 USD"), Price::from("50000.0"))
+            .unwrap();
+        // 0.01 BTC is worth 500 USD at 50000
+        let from_base = xbtusd_bitmex
+            .calculate_base_quantity(Money::from("0.01 BTC"), Price::from("50000.0"))
+            .unwrap();
+
+        assert_eq!(from_quote, Quantity::from("500"));
+        assert_eq!(from_base, Quantity::from("500"));
+        assert_eq!(
+            xbtusd_bitmex.calculate_quote_notional(from_quote, Price::from("60000.0")),
+            Money::from("500 USD")
+        );
     }
 }
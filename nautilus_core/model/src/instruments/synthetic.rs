@@ -20,13 +20,34 @@ use std::{
 
 use derive_builder::Builder;
 use evalexpr::{ContextWithMutableVariables, HashMapContext, Node, Value};
-use nautilus_core::nanos::UnixNanos;
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 
 use crate::{
-    identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue},
-    types::price::Price,
+    enums::{ContingencyType, OrderType},
+    events::order::initialized::{OrderInitialized, OrderInitializedExt},
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        symbol::Symbol, venue::Venue,
+    },
+    instruments::any::InstrumentAny,
+    types::{price::Price, quantity::Quantity},
 };
 
+/// The relative tolerance for checking the formula of a synthetic instrument is linear.
+const LINEARITY_TOLERANCE: f64 = 1e-9;
+
+/// The rounding applied to leg quantities which are not a multiple of the leg size increment.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RoundingBehavior {
+    /// Round to the nearest size increment.
+    #[default]
+    Nearest,
+    /// Round down to a size increment.
+    Down,
+    /// Round up to a size increment.
+    Up,
+}
+
 /// Represents a synthetic instrument with prices derived from component instruments using a
 /// formula.
 #[derive(Clone, Debug, Builder)]
@@ -132,6 +153,203 @@ impl SyntheticInstrument {
             )),
         }
     }
+
+    /// Returns the coefficient of each component in the formula (in component order).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the formula is not linear in its components.
+    pub fn leg_coefficients(&self) -> anyhow::Result<Vec<f64>> {
+        let n = self.variables.len();
+        let origin = self.evaluate(&vec![0.0; n])?;
+
+        let mut coefficients = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut inputs = vec![0.0; n];
+            inputs[i] = 1.0;
+            let coefficient = self.evaluate(&inputs)? - origin;
+            inputs[i] = 2.0;
+            let doubled = self.evaluate(&inputs)? - origin;
+            if !is_close(doubled, 2.0 * coefficient) {
+                anyhow::bail!(
+                    "Formula '{}' is not linear in {}",
+                    self.formula,
+                    self.variables[i]
+                );
+            }
+            coefficients.push(coefficient);
+        }
+
+        let combined = self.evaluate(&vec![1.0; n])? - origin;
+        if !is_close(combined, coefficients.iter().sum()) {
+            anyhow::bail!("Formula '{}' is not linear", self.formula);
+        }
+
+        Ok(coefficients)
+    }
+
+    fn evaluate(&self, inputs: &[f64]) -> anyhow::Result<f64> {
+        let mut context = HashMapContext::new();
+        for (variable, input) in self.variables.iter().zip(inputs) {
+            context.set_value(variable.clone(), Value::from(*input))?;
+        }
+
+        match self.operator_tree.eval_with_context(&context)? {
+            Value::Float(value) => Ok(value),
+            Value::Int(value) => Ok(value as f64),
+            _ => Err(anyhow::anyhow!("Failed to evaluate formula to a number")),
+        }
+    }
+}
+
+fn is_close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= LINEARITY_TOLERANCE * a.abs().max(b.abs()).max(1.0)
+}
+
+/// Decomposes the given `order` on the `synth` instrument into an order per component leg.
+///
+/// Leg quantities are the order quantity scaled by the absolute coefficient of the component in
+/// the formula, rounded to the leg size increment with `ratio_rounding`. Legs with a negative
+/// coefficient take the opposite side. Legs are linked by an order list ID (the order's own, or
+/// one derived from its client order ID) with the synthetic order as their parent.
+///
+/// Legs keep the order type, time in force and expire time of the order. A price or trigger
+/// price on the synthetic price maps to a leg price only when the synthetic has a single
+/// component, in which case it is scaled by the inverse of the component coefficient (after
+/// removing any constant term) and rounded to the leg price precision.
+///
+/// # Errors
+///
+/// This function returns an error if:
+/// - The order is not for the `synth` instrument, or has no side.
+/// - The order is a trailing stop, or has a price condition on a multi-leg synthetic.
+/// - The formula is not linear in its components.
+/// - An instrument for a component is not in `component_instruments`.
+/// - A leg quantity rounds to zero.
+pub fn decompose_synthetic_order(
+    order: &OrderInitialized,
+    synth: &SyntheticInstrument,
+    component_instruments: &[InstrumentAny],
+    ratio_rounding: RoundingBehavior,
+) -> anyhow::Result<Vec<OrderInitialized>> {
+    if order.instrument_id != synth.id {
+        anyhow::bail!(
+            "Order {} is for {}, not synthetic {}",
+            order.client_order_id,
+            order.instrument_id,
+            synth.id
+        );
+    }
+    let order_side = order.order_side.as_specified()?;
+    if matches!(
+        order.order_type,
+        OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
+    ) {
+        anyhow::bail!(
+            "Cannot decompose {} order {}: trailing offsets do not map to the legs",
+            order.order_type,
+            order.client_order_id
+        );
+    }
+    if (order.price.is_some() || order.trigger_price.is_some()) && synth.components.len() > 1 {
+        anyhow::bail!(
+            "Cannot decompose {} order {}: a synthetic price does not map to {} leg prices",
+            order.order_type,
+            order.client_order_id,
+            synth.components.len()
+        );
+    }
+    let coefficients = synth.leg_coefficients()?;
+    let constant = synth.evaluate(&vec![0.0; synth.components.len()])?;
+
+    let order_list_id = order
+        .ext()
+        .order_list_id
+        .unwrap_or_else(|| OrderListId::from(order.client_order_id.as_str()));
+    let leg_ids = (1..=synth.components.len())
+        .map(|n| ClientOrderId::new(&format!("{}-L{n}", order.client_order_id)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut legs = Vec::with_capacity(synth.components.len());
+    for ((component, coefficient), leg_id) in
+        synth.components.iter().zip(coefficients).zip(&leg_ids)
+    {
+        let instrument = component_instruments
+            .iter()
+            .find(|instrument| instrument.id() == *component)
+            .ok_or_else(|| anyhow::anyhow!("No instrument for synthetic component {component}"))?;
+
+        let quantity = leg_quantity(order.quantity, coefficient, instrument, ratio_rounding)?;
+        if quantity.is_zero() {
+            anyhow::bail!(
+                "Leg {component} of order {} rounds to zero quantity (coefficient {coefficient})",
+                order.client_order_id
+            );
+        }
+
+        let leg_side = if coefficient < 0.0 {
+            order_side.opposite()
+        } else {
+            order_side
+        };
+
+        let leg_price = |price: Option<Price>| {
+            price
+                .map(|price| instrument.make_price((price.as_f64() - constant) / coefficient))
+                .transpose()
+        };
+
+        legs.push(OrderInitialized {
+            instrument_id: *component,
+            client_order_id: *leg_id,
+            order_side: leg_side.as_order_side(),
+            quantity,
+            quote_quantity: false,
+            event_id: UUID4::new(),
+            price: leg_price(order.price)?,
+            trigger_price: leg_price(order.trigger_price)?,
+            display_qty: None,
+            emulation_trigger: None,
+            trigger_instrument_id: None,
+            linked_order_ids: Some(leg_ids.iter().filter(|id| *id != leg_id).copied().collect()),
+            exec_algorithm_id: None,
+            exec_algorithm_params: None,
+            exec_spawn_id: None,
+            ext: OrderInitializedExt {
+                contingency_type: Some(ContingencyType::NoContingency),
+                order_list_id: Some(order_list_id),
+                parent_order_id: Some(order.client_order_id),
+                ..Default::default()
+            }
+            .boxed(),
+            ..order.clone()
+        });
+    }
+
+    Ok(legs)
+}
+
+fn leg_quantity(
+    quantity: Quantity,
+    coefficient: f64,
+    instrument: &InstrumentAny,
+    rounding: RoundingBehavior,
+) -> anyhow::Result<Quantity> {
+    let increment = instrument.size_increment();
+    let units = quantity.raw as f64 * coefficient.abs() / increment.raw as f64;
+
+    // Snap away floating point error (e.g. from a coefficient of 1/3) before rounding
+    let units = if is_close(units, units.round()) {
+        units.round()
+    } else {
+        match rounding {
+            RoundingBehavior::Nearest => units.round(),
+            RoundingBehavior::Down => units.floor(),
+            RoundingBehavior::Up => units.ceil(),
+        }
+    };
+
+    Quantity::from_raw(units as u64 * increment.raw, instrument.size_precision())
 }
 
 impl PartialEq<Self> for SyntheticInstrument {
@@ -156,6 +374,59 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::{
+        enums::{OrderSide, TimeInForce, TriggerType},
+        instruments::stubs::{currency_pair_btcusdt, currency_pair_ethusdt},
+    };
+
+    fn spread(formula: &str) -> SyntheticInstrument {
+        SyntheticInstrument::new(
+            Symbol::from("BTC-ETH"),
+            2,
+            vec![
+                InstrumentId::from("BTCUSDT.BINANCE"),
+                InstrumentId::from("ETHUSDT.BINANCE"),
+            ],
+            formula.to_string(),
+            0.into(),
+            0.into(),
+        )
+        .unwrap()
+    }
+
+    fn spread_order(
+        synth: &SyntheticInstrument,
+        side: OrderSide,
+        quantity: &str,
+    ) -> OrderInitialized {
+        OrderInitialized {
+            instrument_id: synth.id,
+            client_order_id: ClientOrderId::from("O-123"),
+            order_side: side,
+            order_type: OrderType::Market,
+            quantity: Quantity::from(quantity),
+            ..Default::default()
+        }
+    }
+
+    fn btc_synth(formula: &str) -> SyntheticInstrument {
+        SyntheticInstrument::new(
+            Symbol::from("BTC-SCALED"),
+            2,
+            vec![InstrumentId::from("BTCUSDT.BINANCE")],
+            formula.to_string(),
+            0.into(),
+            0.into(),
+        )
+        .unwrap()
+    }
+
+    fn components() -> Vec<InstrumentAny> {
+        vec![
+            InstrumentAny::CurrencyPair(currency_pair_btcusdt()),
+            InstrumentAny::CurrencyPair(currency_pair_ethusdt()),
+        ]
+    }
 
     #[rstest]
     fn test_calculate_from_map() {
@@ -194,4 +465,175 @@ mod tests {
         assert_eq!(price.as_f64(), 75.0);
         assert_eq!(synth.formula, new_formula);
     }
+
+    #[rstest]
+    fn test_leg_coefficients() {
+        let synth = spread("(BTCUSDT.BINANCE - 3 * ETHUSDT.BINANCE) / 2 + 10");
+
+        assert_eq!(synth.leg_coefficients().unwrap(), vec![0.5, -1.5]);
+    }
+
+    #[rstest]
+    fn test_leg_coefficients_when_not_linear() {
+        let synth = spread("BTCUSDT.BINANCE * ETHUSDT.BINANCE");
+
+        assert!(synth.leg_coefficients().is_err());
+    }
+
+    #[rstest]
+    fn test_decompose_two_to_one_ratio_spread() {
+        let synth = spread("2.0 * BTCUSDT.BINANCE + ETHUSDT.BINANCE");
+        let order = spread_order(&synth, OrderSide::Buy, "1.5");
+
+        let legs =
+            decompose_synthetic_order(&order, &synth, &components(), RoundingBehavior::Nearest)
+                .unwrap();
+
+        assert_eq!(legs.len(), 2);
+        assert_eq!(legs[0].instrument_id, InstrumentId::from("BTCUSDT.BINANCE"));
+        assert_eq!(legs[0].quantity, Quantity::from("3.000000"));
+        assert_eq!(legs[1].instrument_id, InstrumentId::from("ETHUSDT.BINANCE"));
+        assert_eq!(legs[1].quantity, Quantity::from("1.50000"));
+        for leg in &legs {
+            assert_eq!(leg.order_side, OrderSide::Buy);
+            assert_eq!(leg.order_type, OrderType::Market);
+            assert_eq!(leg.price, None);
+            assert_eq!(leg.ext().parent_order_id, Some(order.client_order_id));
+            assert_eq!(leg.ext().order_list_id, Some(OrderListId::from("O-123")));
+            assert_eq!(leg.trader_id, order.trader_id);
+        }
+        assert_eq!(legs[0].client_order_id, ClientOrderId::from("O-123-L1"));
+        assert_eq!(
+            legs[0].linked_order_ids.as_deref(),
+            Some([ClientOrderId::from("O-123-L2")].as_slice())
+        );
+    }
+
+    #[rstest]
+    fn test_decompose_negative_coefficient_flips_side() {
+        let synth = spread("BTCUSDT.BINANCE - 2 * ETHUSDT.BINANCE");
+        let order = spread_order(&synth, OrderSide::Sell, "1");
+
+        let legs =
+            decompose_synthetic_order(&order, &synth, &components(), RoundingBehavior::Nearest)
+                .unwrap();
+
+        assert_eq!(legs[0].order_side, OrderSide::Sell);
+        assert_eq!(legs[0].quantity, Quantity::from("1.000000"));
+        assert_eq!(legs[1].order_side, OrderSide::Buy);
+        assert_eq!(legs[1].quantity, Quantity::from("2.00000"));
+    }
+
+    #[rstest]
+    #[case(RoundingBehavior::Nearest, Some("0.00001"))]
+    #[case(RoundingBehavior::Up, Some("0.00001"))]
+    #[case(RoundingBehavior::Down, None)]
+    fn test_decompose_leg_ratio_rounding(
+        #[case] rounding: RoundingBehavior,
+        #[case] expected: Option<&str>,
+    ) {
+        let synth = spread("BTCUSDT.BINANCE - 0.5 * ETHUSDT.BINANCE");
+        let order = spread_order(&synth, OrderSide::Buy, "0.00001");
+
+        let result = decompose_synthetic_order(&order, &synth, &components(), rounding);
+
+        match expected {
+            Some(quantity) => assert_eq!(result.unwrap()[1].quantity, Quantity::from(quantity)),
+            None => assert!(result.unwrap_err().to_string().contains("rounds to zero")),
+        }
+    }
+
+    #[rstest]
+    fn test_decompose_with_missing_component_instrument() {
+        let synth = spread("BTCUSDT.BINANCE - ETHUSDT.BINANCE");
+        let order = spread_order(&synth, OrderSide::Buy, "1");
+
+        let result = decompose_synthetic_order(
+            &order,
+            &synth,
+            &components()[..1],
+            RoundingBehavior::Nearest,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_decompose_keeps_time_in_force_and_expire_time() {
+        let synth = spread("BTCUSDT.BINANCE - ETHUSDT.BINANCE");
+        let order = OrderInitialized {
+            time_in_force: TimeInForce::Gtd,
+            expire_time: Some(UnixNanos::from(1_000)),
+            ..spread_order(&synth, OrderSide::Buy, "1")
+        };
+
+        let legs =
+            decompose_synthetic_order(&order, &synth, &components(), RoundingBehavior::Nearest)
+                .unwrap();
+
+        for leg in &legs {
+            assert_eq!(leg.time_in_force, TimeInForce::Gtd);
+            assert_eq!(leg.expire_time, Some(UnixNanos::from(1_000)));
+        }
+    }
+
+    #[rstest]
+    #[case("2 * BTCUSDT.BINANCE + 10", OrderSide::Buy, "45.00", "40.00")]
+    #[case("-0.5 * BTCUSDT.BINANCE", OrderSide::Sell, "-200.00", "-180.00")]
+    fn test_decompose_single_leg_scales_prices(
+        #[case] formula: &str,
+        #[case] expected_side: OrderSide,
+        #[case] expected_price: &str,
+        #[case] expected_trigger_price: &str,
+    ) {
+        let synth = btc_synth(formula);
+        let order = OrderInitialized {
+            order_type: OrderType::StopLimit,
+            price: Some(Price::from("100.00")),
+            trigger_price: Some(Price::from("90.00")),
+            trigger_type: Some(TriggerType::LastTrade),
+            ..spread_order(&synth, OrderSide::Buy, "1")
+        };
+
+        let legs = decompose_synthetic_order(
+            &order,
+            &synth,
+            &components()[..1],
+            RoundingBehavior::Nearest,
+        )
+        .unwrap();
+
+        assert_eq!(legs.len(), 1);
+        assert_eq!(legs[0].order_type, OrderType::StopLimit);
+        assert_eq!(legs[0].order_side, expected_side);
+        assert_eq!(legs[0].price, Some(Price::from(expected_price)));
+        assert_eq!(
+            legs[0].trigger_price,
+            Some(Price::from(expected_trigger_price))
+        );
+        assert_eq!(legs[0].trigger_type, Some(TriggerType::LastTrade));
+    }
+
+    #[rstest]
+    #[case(OrderType::Limit, Some("100.00"))]
+    #[case(OrderType::TrailingStopMarket, None)]
+    fn test_decompose_rejects_order_not_mapping_to_legs(
+        #[case] order_type: OrderType,
+        #[case] price: Option<&str>,
+    ) {
+        let synth = spread("BTCUSDT.BINANCE - ETHUSDT.BINANCE");
+        let order = OrderInitialized {
+            order_type,
+            price: price.map(Price::from),
+            ..spread_order(&synth, OrderSide::Buy, "1")
+        };
+
+        let result =
+            decompose_synthetic_order(&order, &synth, &components(), RoundingBehavior::Nearest);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with(&format!("Cannot decompose {order_type} order O-123")));
+    }
 }
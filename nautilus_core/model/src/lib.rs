@@ -39,6 +39,8 @@ pub mod orderbook;
 pub mod orders;
 pub mod polymorphism;
 pub mod position;
+pub mod serialization;
+pub mod tick_scheme;
 pub mod types;
 pub mod venues;
 
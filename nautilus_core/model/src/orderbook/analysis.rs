@@ -15,11 +15,19 @@
 
 use std::collections::BTreeMap;
 
-use super::{book::OrderBook, ladder::BookPrice, level::Level};
+use super::{
+    book::OrderBook,
+    ladder::{BookPrice, Ladder},
+    level::Level,
+};
 use crate::{
     enums::{BookType, OrderSide},
     orderbook::error::BookIntegrityError,
-    types::{price::Price, quantity::Quantity},
+    tick_scheme::TickScheme,
+    types::{
+        price::Price,
+        quantity::{Quantity, QuantityAccumulator},
+    },
 };
 
 /// Calculates the estimated fill quantity for a specified price from a set of
@@ -30,7 +38,18 @@ pub fn get_quantity_for_price(
     order_side: OrderSide,
     levels: &BTreeMap<BookPrice, Level>,
 ) -> f64 {
-    let mut matched_size: f64 = 0.0;
+    accumulate_quantity_for_price(price, order_side, levels).total_f64()
+}
+
+/// Accumulates the size of all orders from a set of order book levels at or better than
+/// the specified price for the order side.
+#[must_use]
+pub fn accumulate_quantity_for_price(
+    price: Price,
+    order_side: OrderSide,
+    levels: &BTreeMap<BookPrice, Level>,
+) -> QuantityAccumulator {
+    let mut matched_size = QuantityAccumulator::default();
 
     for (book_price, level) in levels {
         match order_side {
@@ -46,7 +65,7 @@ pub fn get_quantity_for_price(
             }
             _ => panic!("Invalid `OrderSide` {order_side}"),
         }
-        matched_size += level.size();
+        matched_size.extend(level.orders.values().map(|order| &order.size));
     }
 
     matched_size
@@ -54,13 +73,36 @@ pub fn get_quantity_for_price(
 
 /// Calculates the estimated average price for a specified quantity from a set of
 /// order book levels.
+///
+/// If the levels hold less than `qty` then the average is over the available size only,
+/// or 0.0 if the levels are empty.
 #[must_use]
 pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level>) -> f64 {
+    let (avg_px, _) = avg_px_and_filled_raw(qty, levels);
+    avg_px
+}
+
+/// Calculates the estimated average price to fill the whole of a specified quantity from a
+/// set of order book levels.
+///
+/// Returns `None` if the levels hold less than `qty`, or `qty` is zero.
+#[must_use]
+pub fn get_avg_px_for_full_quantity(
+    qty: Quantity,
+    levels: &BTreeMap<BookPrice, Level>,
+) -> Option<f64> {
+    let (avg_px, filled_raw) = avg_px_and_filled_raw(qty, levels);
+    (qty.raw > 0 && filled_raw == qty.raw).then_some(avg_px)
+}
+
+fn avg_px_and_filled_raw(qty: Quantity, levels: &BTreeMap<BookPrice, Level>) -> (f64, u64) {
     let mut cumulative_size_raw = 0u64;
     let mut cumulative_value = 0.0;
 
     for (book_price, level) in levels {
-        let size_this_level = level.size_raw().min(qty.raw - cumulative_size_raw);
+        // Bounded by the remaining quantity, so always fits in a `u64`
+        let remaining = u128::from(qty.raw - cumulative_size_raw);
+        let size_this_level = level.size_accumulated().raw().min(remaining) as u64;
         cumulative_size_raw += size_this_level;
         cumulative_value += book_price.value.as_f64() * size_this_level as f64;
 
@@ -70,12 +112,76 @@ pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level
     }
 
     if cumulative_size_raw == 0 {
-        0.0
+        (0.0, 0)
     } else {
-        cumulative_value / cumulative_size_raw as f64
+        (
+            cumulative_value / cumulative_size_raw as f64,
+            cumulative_size_raw,
+        )
     }
 }
 
+/// Returns the price of the deepest level required to fill the specified quantity from a
+/// set of order book levels, or the deepest available level if the book is too thin.
+#[must_use]
+pub fn get_worst_price_for_quantity(
+    qty: Quantity,
+    levels: &BTreeMap<BookPrice, Level>,
+) -> Option<Price> {
+    let mut cumulative_size = QuantityAccumulator::default();
+    let mut worst_price = None;
+
+    for (book_price, level) in levels {
+        worst_price = Some(book_price.value);
+        cumulative_size.extend(level.orders.values().map(|order| &order.size));
+        if cumulative_size.raw() >= u128::from(qty.raw) {
+            break;
+        }
+    }
+
+    worst_price
+}
+
+/// Derives a protection price for a market order of the given side and quantity, for venues
+/// which require market orders to carry a limit cap.
+///
+/// The opposite side of the book is walked to the expected fill depth, the worst price reached
+/// is padded by `max_slippage_bps` (up for buys, down for sells) and then snapped outwards to a
+/// valid price of the `tick_scheme`.
+///
+/// Returns `None` if the opposite side of the book is empty.
+#[must_use]
+pub fn derive_protection_price(
+    order_side: OrderSide,
+    quantity: Quantity,
+    book: &OrderBook,
+    max_slippage_bps: u32,
+    tick_scheme: &dyn TickScheme,
+) -> Option<Price> {
+    let slippage = f64::from(max_slippage_bps) / 10_000.0;
+
+    match order_side {
+        OrderSide::Buy => {
+            let worst_price = get_worst_price_for_quantity(quantity, &book.asks.levels)?;
+            tick_scheme.next_ask_price(worst_price.as_f64() * (1.0 + slippage), 0)
+        }
+        OrderSide::Sell => {
+            let worst_price = get_worst_price_for_quantity(quantity, &book.bids.levels)?;
+            tick_scheme.next_bid_price(worst_price.as_f64() * (1.0 - slippage), 0)
+        }
+        _ => panic!("Invalid `OrderSide` {order_side}"),
+    }
+}
+
+/// Checks the integrity of the `book`.
+///
+/// # Errors
+///
+/// This function returns an error if:
+/// - An L1 book has more than one level on a side, or an L2 book more than one order at a level.
+/// - The best bid is at or above the best ask.
+/// - The levels on a side are not strictly ordered from the top.
+/// - Any order has a zero size.
 pub fn book_check_integrity(book: &OrderBook) -> Result<(), BookIntegrityError> {
     match book.book_type {
         BookType::L1_MBP => {
@@ -125,5 +231,133 @@ pub fn book_check_integrity(book: &OrderBook) -> Result<(), BookIntegrityError>
         }
     }
 
+    check_ladder_integrity(&book.bids)?;
+    check_ladder_integrity(&book.asks)
+}
+
+/// Checks the levels of the `ladder` are strictly ordered from the top, and each order has a
+/// positive size.
+fn check_ladder_integrity(ladder: &Ladder) -> Result<(), BookIntegrityError> {
+    let mut prev: Option<Price> = None;
+    for level in ladder.levels.values() {
+        let price = level.price.value;
+        if let Some(prev) = prev {
+            let is_ordered = match ladder.side {
+                OrderSide::Buy => price < prev,
+                _ => price > prev,
+            };
+            if !is_ordered {
+                return Err(BookIntegrityError::LevelsOutOfOrder(
+                    ladder.side,
+                    prev,
+                    price,
+                ));
+            }
+        }
+        prev = Some(price);
+
+        if let Some(order) = level.orders.values().find(|order| order.size.raw == 0) {
+            return Err(BookIntegrityError::ZeroSize(
+                ladder.side,
+                price,
+                order.order_id,
+            ));
+        }
+    }
+
     Ok(())
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{
+        data::order::BookOrder, identifiers::instrument_id::InstrumentId,
+        tick_scheme::FixedTickScheme,
+    };
+
+    #[fixture]
+    fn tick_scheme() -> FixedTickScheme {
+        FixedTickScheme::new(
+            Price::from("0.01"),
+            Price::from("0.01"),
+            Price::from("10000.00"),
+        )
+        .unwrap()
+    }
+
+    fn book_with_levels(levels: &[(OrderSide, &str, &str)]) -> OrderBook {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+        for (side, price, size) in levels {
+            let order = BookOrder::new(*side, Price::from(*price), Quantity::from(*size), 0);
+            book.add(order, 0, 1, 1.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_derive_protection_price_thin_book(tick_scheme: FixedTickScheme) {
+        let book = book_with_levels(&[
+            (OrderSide::Buy, "99.00", "1.0"),
+            (OrderSide::Sell, "101.00", "1.0"),
+        ]);
+        let qty = Quantity::from("5.0");
+
+        // Quantity exceeds the book so the deepest level is used: 101.00 * 1.005 = 101.505
+        let buy = derive_protection_price(OrderSide::Buy, qty, &book, 50, &tick_scheme);
+        // 99.00 * 0.995 = 98.505
+        let sell = derive_protection_price(OrderSide::Sell, qty, &book, 50, &tick_scheme);
+
+        assert_eq!(buy, Some(Price::from("101.51")));
+        assert_eq!(sell, Some(Price::from("98.50")));
+    }
+
+    #[rstest]
+    fn test_derive_protection_price_deep_book(tick_scheme: FixedTickScheme) {
+        let book = book_with_levels(&[
+            (OrderSide::Buy, "99.00", "1.0"),
+            (OrderSide::Buy, "98.00", "2.0"),
+            (OrderSide::Buy, "97.00", "10.0"),
+            (OrderSide::Sell, "101.00", "1.0"),
+            (OrderSide::Sell, "102.00", "2.0"),
+            (OrderSide::Sell, "103.00", "10.0"),
+        ]);
+        let qty = Quantity::from("2.5");
+
+        // Filled through the second level: 102.00 * 1.001 = 102.102
+        let buy = derive_protection_price(OrderSide::Buy, qty, &book, 10, &tick_scheme);
+        // 98.00 * 0.999 = 97.902
+        let sell = derive_protection_price(OrderSide::Sell, qty, &book, 10, &tick_scheme);
+
+        assert_eq!(buy, Some(Price::from("102.11")));
+        assert_eq!(sell, Some(Price::from("97.90")));
+    }
+
+    #[rstest]
+    fn test_derive_protection_price_with_zero_slippage_on_tick(tick_scheme: FixedTickScheme) {
+        let book = book_with_levels(&[(OrderSide::Sell, "101.00", "1.0")]);
+        let qty = Quantity::from("1.0");
+
+        let buy = derive_protection_price(OrderSide::Buy, qty, &book, 0, &tick_scheme);
+
+        assert_eq!(buy, Some(Price::from("101.00")));
+    }
+
+    #[rstest]
+    fn test_derive_protection_price_empty_side(tick_scheme: FixedTickScheme) {
+        let book = book_with_levels(&[(OrderSide::Buy, "99.00", "1.0")]);
+        let qty = Quantity::from("1.0");
+
+        let buy = derive_protection_price(OrderSide::Buy, qty, &book, 50, &tick_scheme);
+        let sell = derive_protection_price(OrderSide::Sell, qty, &book, 50, &tick_scheme);
+
+        assert_eq!(buy, None);
+        assert!(sell.is_some());
+    }
+}
@@ -13,19 +13,92 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+};
+
 use nautilus_core::nanos::UnixNanos;
 
-use super::{aggregation::pre_process_order, analysis, display::pprint_book, level::Level};
+use super::{
+    aggregation::pre_process_order,
+    analysis::{self, book_check_integrity},
+    diff::diff_books,
+    display::pprint_book,
+    level::Level,
+};
 use crate::{
     data::{
-        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth10, DEPTH10_LEN},
+        order::{BookOrder, NULL_ORDER},
+        quote::QuoteTick,
     },
-    enums::{BookAction, BookType, OrderSide, OrderSideSpecified},
+    enums::{BookAction, BookType, OrderSide, OrderSideSpecified, RecordFlag},
     identifiers::instrument_id::InstrumentId,
-    orderbook::{error::BookIntegrityError, ladder::Ladder},
-    types::{price::Price, quantity::Quantity},
+    orderbook::{
+        error::{BookIntegrityError, InvalidBookOperation},
+        ladder::{BookPrice, Ladder, SimulatedFills},
+        snapshot::{LadderLevel, LadderSnapshot},
+    },
+    types::{
+        price::Price,
+        quantity::{Quantity, QUANTITY_MAX_RAW},
+    },
 };
 
+/// Represents the aggregated state of a single order book price level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BookLevel {
+    /// The price of the level.
+    pub price: Price,
+    /// The total size of the orders at the level.
+    pub size: Quantity,
+    /// The number of orders at the level.
+    pub order_count: usize,
+}
+
+impl From<&Level> for BookLevel {
+    fn from(level: &Level) -> Self {
+        let (price, size) = level_price_and_size(level);
+        Self {
+            price,
+            size,
+            order_count: level.len(),
+        }
+    }
+}
+
+/// Represents a lightweight snapshot of the best bid and ask levels of an order book.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BookTop {
+    /// The best bid level (if any).
+    pub bid: Option<BookLevel>,
+    /// The best ask level (if any).
+    pub ask: Option<BookLevel>,
+    /// The last event sequence number for the order book.
+    pub sequence: u64,
+    /// The timestamp of the last event applied to the order book.
+    pub ts_last: UnixNanos,
+}
+
+/// Represents the top of an order book, published when an event is complete.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TopOfBookUpdate {
+    /// The instrument ID for the order book.
+    pub instrument_id: InstrumentId,
+    /// The best bid price and the total size at that level (if any).
+    pub bid: Option<(Price, Quantity)>,
+    /// The best ask price and the total size at that level (if any).
+    pub ask: Option<(Price, Quantity)>,
+    /// The sequence number of the last event applied to the order book.
+    pub sequence: u64,
+    /// The timestamp of the last event applied to the order book.
+    pub ts_event: UnixNanos,
+}
+
 /// Provides an order book.
 ///
 /// Can handle the following granularity data:
@@ -48,8 +121,11 @@ pub struct OrderBook {
     pub ts_last: UnixNanos,
     /// The current count of events applied to the order book.
     pub count: u64,
+    /// If the integrity of the order book is checked after every delta applied.
+    pub validate: bool,
     pub(crate) bids: Ladder,
     pub(crate) asks: Ladder,
+    last_top: Option<TopOfBookUpdate>,
 }
 
 impl OrderBook {
@@ -61,8 +137,10 @@ impl OrderBook {
             sequence: 0,
             ts_last: UnixNanos::default(),
             count: 0,
+            validate: false,
             bids: Ladder::new(OrderSide::Buy),
             asks: Ladder::new(OrderSide::Sell),
+            last_top: None,
         }
     }
 
@@ -72,11 +150,12 @@ impl OrderBook {
         self.sequence = 0;
         self.ts_last = UnixNanos::default();
         self.count = 0;
+        self.last_top = None;
     }
 
     pub fn add(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
         let order = pre_process_order(self.book_type, order, flags);
-        match order.side.as_specified() {
+        match order.side.specified() {
             OrderSideSpecified::Buy => self.bids.add(order),
             OrderSideSpecified::Sell => self.asks.add(order),
         }
@@ -86,7 +165,7 @@ impl OrderBook {
 
     pub fn update(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
         let order = pre_process_order(self.book_type, order, flags);
-        match order.side.as_specified() {
+        match order.side.specified() {
             OrderSideSpecified::Buy => self.bids.update(order),
             OrderSideSpecified::Sell => self.asks.update(order),
         }
@@ -96,7 +175,7 @@ impl OrderBook {
 
     pub fn delete(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
         let order = pre_process_order(self.book_type, order, flags);
-        match order.side.as_specified() {
+        match order.side.specified() {
             OrderSideSpecified::Buy => self.bids.delete(order, sequence, ts_event),
             OrderSideSpecified::Sell => self.asks.delete(order, sequence, ts_event),
         }
@@ -120,7 +199,43 @@ impl OrderBook {
         self.increment(sequence, ts_event);
     }
 
-    pub fn apply_delta(&mut self, delta: OrderBookDelta) {
+    /// Applies the `delta`, returning the top of book if the delta completes an event (carries
+    /// the `F_LAST` flag) and the top has changed since the last update returned.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `validate` is set and the book fails the integrity
+    /// check after the delta is applied (see [`OrderBook::check_integrity`]).
+    pub fn apply_delta(
+        &mut self,
+        delta: OrderBookDelta,
+    ) -> Result<Option<TopOfBookUpdate>, BookIntegrityError> {
+        self.apply_delta_inner(delta);
+        self.validate_if_set()?;
+        Ok(self.publish_top(delta.flags))
+    }
+
+    /// Applies the batch of `deltas`, coalescing runs of updates to the same order
+    /// (see [`OrderBookDeltas::apply_to`]).
+    ///
+    /// The top of book is returned as for [`OrderBook::apply_delta`], once the whole batch has
+    /// been applied, so only where the last delta of the batch carries the `F_LAST` flag.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `validate` is set and the book fails the integrity
+    /// check after the batch is applied.
+    pub fn apply_deltas(
+        &mut self,
+        deltas: OrderBookDeltas,
+    ) -> Result<Option<TopOfBookUpdate>, BookIntegrityError> {
+        deltas.apply_to(self);
+        self.validate_if_set()?;
+        Ok(self.publish_top(deltas.flags))
+    }
+
+    /// Applies the `delta` without publishing the top of book.
+    pub(crate) fn apply_delta_inner(&mut self, delta: OrderBookDelta) {
         let order = delta.order;
         let flags = delta.flags;
         let sequence = delta.sequence;
@@ -133,12 +248,6 @@ impl OrderBook {
         }
     }
 
-    pub fn apply_deltas(&mut self, deltas: OrderBookDeltas) {
-        for delta in deltas.deltas {
-            self.apply_delta(delta);
-        }
-    }
-
     pub fn apply_depth(&mut self, depth: OrderBookDepth10) {
         self.bids.clear();
         self.asks.clear();
@@ -152,14 +261,255 @@ impl OrderBook {
         }
     }
 
-    pub fn bids(&self) -> impl Iterator<Item = &Level> {
+    /// Checks the integrity of the book, such as the best bid being below the best ask.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error for the first integrity violation found.
+    pub fn check_integrity(&self) -> Result<(), BookIntegrityError> {
+        book_check_integrity(self)
+    }
+
+    /// Returns a snapshot of the best bid and ask levels, without traversing the book.
+    #[must_use]
+    pub fn top(&self) -> BookTop {
+        let top = |ladder: &Ladder| {
+            ladder
+                .top()
+                .filter(|level| !level.is_empty())
+                .map(BookLevel::from)
+        };
+        BookTop {
+            bid: top(&self.bids),
+            ask: top(&self.asks),
+            sequence: self.sequence,
+            ts_last: self.ts_last,
+        }
+    }
+
+    /// Returns a synthetic quote for the best bid and ask levels, at the price and size
+    /// precisions of the book levels (those of the instrument) and timestamped at `ts_last`.
+    ///
+    /// Returns `None` if either side of the book is empty, or the top of book is not a valid
+    /// quote (such as when the book is crossed).
+    #[must_use]
+    pub fn to_quote_tick(&self) -> Option<QuoteTick> {
+        let top = self.top();
+        let (bid, ask) = (top.bid?, top.ask?);
+        QuoteTick::new(
+            self.instrument_id,
+            bid.price,
+            ask.price,
+            bid.size,
+            ask.size,
+            top.ts_last,
+            top.ts_last,
+        )
+        .ok()
+    }
+
+    pub fn bid_levels(&self) -> impl Iterator<Item = &Level> {
         self.bids.levels.values()
     }
 
-    pub fn asks(&self) -> impl Iterator<Item = &Level> {
+    pub fn ask_levels(&self) -> impl Iterator<Item = &Level> {
         self.asks.levels.values()
     }
 
+    /// Returns an iterator over the price, total size and order count of the bid levels
+    /// (best first), limited to `depth` levels if specified.
+    ///
+    /// The levels are produced lazily without collecting, so taking the top few levels of a
+    /// deep book does not allocate.
+    pub fn bids(
+        &self,
+        depth: Option<usize>,
+    ) -> impl FusedIterator<Item = (Price, Quantity, usize)> + '_ {
+        self.bids
+            .levels
+            .values()
+            .take(depth.unwrap_or(usize::MAX))
+            .map(level_price_size_and_count)
+    }
+
+    /// Returns an iterator over the price, total size and order count of the ask levels
+    /// (best first), limited to `depth` levels if specified.
+    ///
+    /// The levels are produced lazily without collecting, so taking the top few levels of a
+    /// deep book does not allocate.
+    pub fn asks(
+        &self,
+        depth: Option<usize>,
+    ) -> impl FusedIterator<Item = (Price, Quantity, usize)> + '_ {
+        self.asks
+            .levels
+            .values()
+            .take(depth.unwrap_or(usize::MAX))
+            .map(level_price_size_and_count)
+    }
+
+    /// Returns a fixed-size snapshot of the top `depth` levels on each side (capped at
+    /// `DEPTH10_LEN`), for passing over FFI without allocating.
+    ///
+    /// Each level is represented by one aggregated order (with an order ID of zero) and the
+    /// number of orders at the level. Levels beyond the book depth or `depth` are padded
+    /// with `NULL_ORDER` and a zero count. The snapshot carries the sequence and last event
+    /// timestamp of the book, with `F_LAST` set.
+    #[must_use]
+    pub fn snapshot(&self, depth: usize) -> OrderBookDepth10 {
+        let depth = depth.min(DEPTH10_LEN);
+        let mut bids = [NULL_ORDER; DEPTH10_LEN];
+        let mut asks = [NULL_ORDER; DEPTH10_LEN];
+        let mut bid_counts = [0; DEPTH10_LEN];
+        let mut ask_counts = [0; DEPTH10_LEN];
+
+        for (i, (price, size, count)) in self.bids(Some(depth)).enumerate() {
+            bids[i] = BookOrder::new(OrderSide::Buy, price, size, 0);
+            bid_counts[i] = u32::try_from(count).unwrap_or(u32::MAX);
+        }
+        for (i, (price, size, count)) in self.asks(Some(depth)).enumerate() {
+            asks[i] = BookOrder::new(OrderSide::Sell, price, size, 0);
+            ask_counts[i] = u32::try_from(count).unwrap_or(u32::MAX);
+        }
+
+        OrderBookDepth10::new(
+            self.instrument_id,
+            bids,
+            asks,
+            bid_counts,
+            ask_counts,
+            RecordFlag::F_LAST as u8,
+            self.sequence,
+            self.ts_last,
+            self.ts_last,
+        )
+    }
+
+    /// Returns an iterator over the price and total size of the bid levels (best first),
+    /// limited to `depth` levels if specified.
+    ///
+    /// The iterator does not allocate. It borrows the book, so the book cannot be mutated
+    /// while the iterator is alive.
+    pub fn iter_bids(
+        &self,
+        depth: Option<usize>,
+    ) -> impl FusedIterator<Item = (Price, Quantity)> + '_ {
+        self.bids
+            .levels
+            .values()
+            .take(depth.unwrap_or(usize::MAX))
+            .map(level_price_and_size)
+    }
+
+    /// Returns an iterator over the price and total size of the ask levels (best first),
+    /// limited to `depth` levels if specified.
+    ///
+    /// The iterator does not allocate. It borrows the book, so the book cannot be mutated
+    /// while the iterator is alive.
+    pub fn iter_asks(
+        &self,
+        depth: Option<usize>,
+    ) -> impl FusedIterator<Item = (Price, Quantity)> + '_ {
+        self.asks
+            .levels
+            .values()
+            .take(depth.unwrap_or(usize::MAX))
+            .map(level_price_and_size)
+    }
+
+    /// Returns the deltas which turn this book into one equal to `other`.
+    ///
+    /// Each changed order produces one delta (for MBP books each order is a level), with
+    /// `F_LAST` set on the final delta. The deltas carry the sequence and last event timestamp
+    /// of `other`, and are empty if the books are equal.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the books differ in instrument ID or book type.
+    pub fn diff(&self, other: &Self) -> Result<OrderBookDeltas, InvalidBookOperation> {
+        diff_books(self, other)
+    }
+
+    /// Returns a digest of the orders in the book, for checking two books hold the same orders.
+    ///
+    /// The digest covers the price, size and ID of every order on each side, but not the
+    /// sequence, timestamps or event count.
+    #[must_use]
+    pub fn content_digest(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for ladder in [&self.bids, &self.asks] {
+            ladder.levels.len().hash(&mut hasher);
+            for level in ladder.levels.values() {
+                level.price.value.raw.hash(&mut hasher);
+                level.orders.len().hash(&mut hasher);
+                for order in level.orders.values() {
+                    order.order_id.hash(&mut hasher);
+                    order.price.raw.hash(&mut hasher);
+                    order.size.raw.hash(&mut hasher);
+                }
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Returns the number of levels an order of the given side and quantity would sweep
+    /// (all levels on the opposite side if the quantity exceeds the available size).
+    #[must_use]
+    pub fn levels_filled_by(&self, qty: Quantity, order_side: OrderSide) -> usize {
+        let levels = match order_side {
+            OrderSide::Buy => &self.asks.levels,
+            OrderSide::Sell => &self.bids.levels,
+            _ => panic!("Invalid `OrderSide` {order_side}"),
+        };
+
+        let mut remaining = u128::from(qty.raw);
+        let mut count = 0;
+        for level in levels.values() {
+            if remaining == 0 {
+                break;
+            }
+            remaining = remaining.saturating_sub(level.size_accumulated().raw());
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Returns a snapshot of the top `depth` levels on each side (all levels if `None`),
+    /// which can be encoded into a compact binary form for GUI consumers.
+    #[must_use]
+    pub fn to_ladder_snapshot(&self, depth: Option<usize>) -> LadderSnapshot {
+        let depth = depth.unwrap_or(usize::MAX);
+        let ladder_levels = |ladder: &Ladder| -> Vec<LadderLevel> {
+            ladder
+                .levels
+                .values()
+                .take(depth)
+                .map(|level| {
+                    let (price, size) = level_price_and_size(level);
+                    let count = u32::try_from(level.len()).unwrap_or(u32::MAX);
+                    (price.raw, size.raw, count)
+                })
+                .collect()
+        };
+
+        let mut tops = self.bids.top().into_iter().chain(self.asks.top());
+        let (price_precision, size_precision) = tops
+            .find_map(|level| level.first())
+            .map_or((0, 0), |order| {
+                (order.price.precision, order.size.precision)
+            });
+
+        LadderSnapshot {
+            instrument_id: self.instrument_id,
+            ts: self.ts_last,
+            price_precision,
+            size_precision,
+            bids: ladder_levels(&self.bids),
+            asks: ladder_levels(&self.asks),
+        }
+    }
+
     #[must_use]
     pub fn has_bid(&self) -> bool {
         self.bids.top().map_or(false, |top| !top.orders.is_empty())
@@ -210,26 +560,38 @@ impl OrderBook {
         }
     }
 
+    /// Returns the volume-weighted average price to fill `qty` for an `order_side` order
+    /// against the opposite side of the book, consuming levels from the top.
+    ///
+    /// Each level contributes its full size until the remaining quantity is less than the
+    /// level size, in which case only the consumed portion of that level is included.
+    /// Returns `None` if the book is too thin to fill the whole of `qty` (or `qty` is zero).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `order_side` is `NoOrderSide`.
     #[must_use]
-    pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
-        let levels = match order_side {
-            OrderSide::Buy => &self.asks.levels,
-            OrderSide::Sell => &self.bids.levels,
-            _ => panic!("Invalid `OrderSide` {order_side}"),
-        };
-
-        analysis::get_avg_px_for_quantity(qty, levels)
+    pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> Option<f64> {
+        analysis::get_avg_px_for_full_quantity(qty, self.opposite_levels(order_side))
     }
 
+    /// Returns the total size available to an `order_side` order at or better than `price`
+    /// (at or below for a buy, at or above for a sell), saturating at `QUANTITY_MAX`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `order_side` is `NoOrderSide`.
     #[must_use]
-    pub fn get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
-        let levels = match order_side {
-            OrderSide::Buy => &self.asks.levels,
-            OrderSide::Sell => &self.bids.levels,
-            _ => panic!("Invalid `OrderSide` {order_side}"),
-        };
-
-        analysis::get_quantity_for_price(price, order_side, levels)
+    pub fn get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> Quantity {
+        let matched_size = analysis::accumulate_quantity_for_price(
+            price,
+            order_side,
+            self.opposite_levels(order_side),
+        );
+        matched_size.total().unwrap_or_else(|_| Quantity {
+            raw: QUANTITY_MAX_RAW,
+            precision: matched_size.precision(),
+        })
     }
 
     #[must_use]
@@ -241,12 +603,97 @@ impl OrderBook {
         }
     }
 
+    /// Simulates an `order_side` order for `quantity` against the book, returning one fill
+    /// per price level consumed, without modifying the book.
+    ///
+    /// The sweep stops at the first level beyond the `limit_px` (if provided).
+    #[must_use]
+    pub fn simulate_level_fills(
+        &self,
+        order_side: OrderSide,
+        quantity: Quantity,
+        limit_px: Option<Price>,
+    ) -> SimulatedFills {
+        match order_side {
+            OrderSide::Buy => self.asks.simulate_level_fills(quantity, limit_px),
+            OrderSide::Sell => self.bids.simulate_level_fills(quantity, limit_px),
+            _ => panic!("{}", BookIntegrityError::NoOrderSide),
+        }
+    }
+
+    /// Removes the liquidity for the `fills` of an `order_side` order from the book.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the book unmodified, if the book does not hold enough
+    /// liquidity at any of the fill price levels.
+    pub fn consume_fills(
+        &mut self,
+        order_side: OrderSide,
+        fills: &[(Price, Quantity)],
+        sequence: u64,
+        ts_event: UnixNanos,
+    ) -> Result<(), BookIntegrityError> {
+        match order_side {
+            OrderSide::Buy => self.asks.consume_fills(fills, sequence, ts_event)?,
+            OrderSide::Sell => self.bids.consume_fills(fills, sequence, ts_event)?,
+            _ => return Err(BookIntegrityError::NoOrderSide),
+        }
+
+        self.increment(sequence, ts_event);
+        Ok(())
+    }
+
     /// Return a [`String`] representation of the order book in a human-readable table format.
     #[must_use]
     pub fn pprint(&self, num_levels: usize) -> String {
         pprint_book(&self.bids, &self.asks, num_levels)
     }
 
+    /// Returns the levels an `order_side` order would trade against, from the top.
+    pub(crate) fn opposite_levels(&self, order_side: OrderSide) -> &BTreeMap<BookPrice, Level> {
+        match order_side {
+            OrderSide::Buy => &self.asks.levels,
+            OrderSide::Sell => &self.bids.levels,
+            _ => panic!("Invalid `OrderSide` {order_side}"),
+        }
+    }
+
+    fn validate_if_set(&self) -> Result<(), BookIntegrityError> {
+        if self.validate {
+            self.check_integrity()
+        } else {
+            Ok(())
+        }
+    }
+
+    fn publish_top(&mut self, flags: u8) -> Option<TopOfBookUpdate> {
+        if !RecordFlag::F_LAST.matches(flags) {
+            return None;
+        }
+
+        let top = self.top();
+        let price_and_size = |level: BookLevel| (level.price, level.size);
+        let bid = top.bid.map(price_and_size);
+        let ask = top.ask.map(price_and_size);
+        if self
+            .last_top
+            .is_some_and(|last| last.bid == bid && last.ask == ask)
+        {
+            return None;
+        }
+
+        let update = TopOfBookUpdate {
+            instrument_id: self.instrument_id,
+            bid,
+            ask,
+            sequence: self.sequence,
+            ts_event: self.ts_last,
+        };
+        self.last_top = Some(update);
+        Some(update)
+    }
+
     fn increment(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.sequence = sequence;
         self.ts_last = ts_event;
@@ -254,27 +701,84 @@ impl OrderBook {
     }
 }
 
+fn level_price_size_and_count(level: &Level) -> (Price, Quantity, usize) {
+    let (price, size) = level_price_and_size(level);
+    (price, size, level.len())
+}
+
+fn level_price_and_size(level: &Level) -> (Price, Quantity) {
+    // Saturates in the (practically unreachable) case of a level size exceeding `QUANTITY_MAX`
+    let size = level
+        .size_accumulated()
+        .total()
+        .unwrap_or_else(|_| Quantity {
+            raw: QUANTITY_MAX_RAW,
+            precision: level.first().map_or(0, |order| order.size.precision),
+        });
+    (level.price.value, size)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::serialization::Serializable;
     use rstest::rstest;
 
     use crate::{
         data::{
-            depth::OrderBookDepth10, order::BookOrder, quote::QuoteTick, stubs::*, trade::TradeTick,
+            delta::OrderBookDelta,
+            deltas::OrderBookDeltas,
+            depth::{OrderBookDepth10, DEPTH10_LEN},
+            order::{BookOrder, NULL_ORDER},
+            quote::QuoteTick,
+            stubs::*,
+            trade::TradeTick,
         },
-        enums::{AggressorSide, BookType, OrderSide},
+        enums::{AggressorSide, BookAction, BookType, OrderSide, RecordFlag},
         identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
         orderbook::{
             aggregation::{update_book_with_quote_tick, update_book_with_trade_tick},
             analysis::book_check_integrity,
-            book::OrderBook,
+            book::{BookLevel, OrderBook},
+            error::BookIntegrityError,
+            ladder::BookPrice,
+            level::Level,
+        },
+        types::{
+            price::Price,
+            quantity::{Quantity, QUANTITY_MAX_RAW},
         },
-        types::{price::Price, quantity::Quantity},
     };
 
+    /// Five levels a side: bids 100.00 down to 96.00 and asks 101.00 up to 105.00, with
+    /// sizes 1 to 5 away from the top.
+    fn five_level_book() -> OrderBook {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+
+        for i in 0..5 {
+            let size = Quantity::from(i + 1);
+            let bid_price = Price::new(100.0 - i as f64, 2).unwrap();
+            let ask_price = Price::new(101.0 + i as f64, 2).unwrap();
+            book.add(
+                BookOrder::new(OrderSide::Buy, bid_price, size, 0),
+                0,
+                1,
+                1.into(),
+            );
+            book.add(
+                BookOrder::new(OrderSide::Sell, ask_price, size, 0),
+                0,
+                1,
+                1.into(),
+            );
+        }
+
+        book
+    }
+
     #[rstest]
     fn test_best_bid_and_ask_when_nothing_in_book() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -388,8 +892,8 @@ mod tests {
 
         let qty = Quantity::from(1);
 
-        assert_eq!(book.get_avg_px_for_quantity(qty, OrderSide::Buy), 0.0);
-        assert_eq!(book.get_avg_px_for_quantity(qty, OrderSide::Sell), 0.0);
+        assert_eq!(book.get_avg_px_for_quantity(qty, OrderSide::Buy), None);
+        assert_eq!(book.get_avg_px_for_quantity(qty, OrderSide::Sell), None);
     }
 
     #[rstest]
@@ -399,8 +903,14 @@ mod tests {
 
         let price = Price::from("1.0");
 
-        assert_eq!(book.get_quantity_for_price(price, OrderSide::Buy), 0.0);
-        assert_eq!(book.get_quantity_for_price(price, OrderSide::Sell), 0.0);
+        assert_eq!(
+            book.get_quantity_for_price(price, OrderSide::Buy),
+            Quantity::from(0)
+        );
+        assert_eq!(
+            book.get_quantity_for_price(price, OrderSide::Sell),
+            Quantity::from(0)
+        );
     }
 
     #[rstest]
@@ -441,11 +951,11 @@ mod tests {
 
         assert_eq!(
             book.get_avg_px_for_quantity(qty, OrderSide::Buy),
-            2.003_333_333_333_333_4
+            Some(2.003_333_333_333_333_4)
         );
         assert_eq!(
             book.get_avg_px_for_quantity(qty, OrderSide::Sell),
-            0.996_666_666_666_666_7
+            Some(0.996_666_666_666_666_7)
         );
     }
 
@@ -499,11 +1009,115 @@ mod tests {
 
         assert_eq!(
             book.get_quantity_for_price(Price::from("2.010"), OrderSide::Buy),
-            3.0
+            Quantity::from("3.0")
         );
         assert_eq!(
             book.get_quantity_for_price(Price::from("0.990"), OrderSide::Sell),
-            3.0
+            Quantity::from("3.0")
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "1", 101.0)]
+    #[case(OrderSide::Buy, "3", 305.0 / 3.0)]
+    #[case(OrderSide::Buy, "4", 102.0)] // Consumes one of three at the third level
+    #[case(OrderSide::Buy, "15", 1555.0 / 15.0)]
+    #[case(OrderSide::Sell, "1", 100.0)]
+    #[case(OrderSide::Sell, "4", 99.0)]
+    #[case(OrderSide::Sell, "15", 1460.0 / 15.0)]
+    fn test_get_avg_px_for_quantity_five_levels(
+        #[case] order_side: OrderSide,
+        #[case] qty: &str,
+        #[case] expected: f64,
+    ) {
+        let book = five_level_book();
+
+        assert_eq!(
+            book.get_avg_px_for_quantity(Quantity::from(qty), order_side),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "16")]
+    #[case(OrderSide::Buy, "15.5")]
+    #[case(OrderSide::Sell, "16")]
+    #[case(OrderSide::Buy, "0")]
+    #[case(OrderSide::Sell, "0")]
+    fn test_get_avg_px_for_quantity_when_book_too_thin(
+        #[case] order_side: OrderSide,
+        #[case] qty: &str,
+    ) {
+        let book = five_level_book();
+
+        assert_eq!(
+            book.get_avg_px_for_quantity(Quantity::from(qty), order_side),
+            None
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "100.50", 0)]
+    #[case(OrderSide::Buy, "101.00", 1)]
+    #[case(OrderSide::Buy, "102.50", 3)]
+    #[case(OrderSide::Buy, "103.00", 6)]
+    #[case(OrderSide::Buy, "110.00", 15)]
+    #[case(OrderSide::Sell, "100.50", 0)]
+    #[case(OrderSide::Sell, "100.00", 1)]
+    #[case(OrderSide::Sell, "98.50", 3)]
+    #[case(OrderSide::Sell, "98.00", 6)]
+    #[case(OrderSide::Sell, "90.00", 15)]
+    fn test_get_quantity_for_price_five_levels(
+        #[case] order_side: OrderSide,
+        #[case] price: &str,
+        #[case] expected: i64,
+    ) {
+        let book = five_level_book();
+
+        assert_eq!(
+            book.get_quantity_for_price(Price::from(price), order_side),
+            Quantity::from(expected)
+        );
+    }
+
+    #[rstest]
+    fn test_aggregation_when_level_size_exceeds_u64() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id);
+
+        // Each order is representable, however the raw sum of the level overflows a `u64`
+        let size = Quantity::new(10_000_000_000.0, 0).unwrap();
+        for order_id in 1..=2 {
+            let order = BookOrder::new(OrderSide::Sell, Price::from("2.000"), size, order_id);
+            book.add(order, 0, order_id, 1.into());
+        }
+        let ask2 = BookOrder::new(OrderSide::Sell, Price::from("2.010"), size, 3);
+        book.add(ask2, 0, 3, 1.into());
+
+        let qty = Quantity::new(15_000_000_000.0, 0).unwrap();
+
+        assert_eq!(
+            book.get_quantity_for_price(Price::from("2.010"), OrderSide::Buy),
+            Quantity {
+                raw: QUANTITY_MAX_RAW,
+                precision: 0,
+            }
+        );
+        assert_eq!(book.get_avg_px_for_quantity(qty, OrderSide::Buy), Some(2.0));
+        assert_eq!(
+            book.simulate_fills(&BookOrder::new(
+                OrderSide::Buy,
+                Price::from("2.010"),
+                qty,
+                4
+            )),
+            vec![
+                (Price::from("2.000"), size),
+                (
+                    Price::from("2.000"),
+                    Quantity::new(5_000_000_000.0, 0).unwrap()
+                ),
+            ]
         );
     }
 
@@ -587,7 +1201,8 @@ mod tests {
             TradeId::new("123456789").unwrap(),
             0.into(),
             0.into(),
-        );
+        )
+        .unwrap();
 
         update_book_with_trade_tick(&mut book, &trade).unwrap();
 
@@ -685,4 +1300,565 @@ mod tests {
         println!("{pprint_output}");
         assert_eq!(pprint_output, expected_output);
     }
+
+    fn book_with_depth() -> OrderBook {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id);
+        let orders = [
+            (OrderSide::Buy, "1.000", "1.0"),
+            (OrderSide::Buy, "1.000", "2.0"),
+            (OrderSide::Buy, "0.990", "3.0"),
+            (OrderSide::Buy, "0.980", "4.0"),
+            (OrderSide::Sell, "1.010", "1.0"),
+            (OrderSide::Sell, "1.020", "2.0"),
+            (OrderSide::Sell, "1.020", "2.0"),
+            (OrderSide::Sell, "1.030", "5.0"),
+        ];
+        for (i, (side, price, size)) in orders.into_iter().enumerate() {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), i as u64);
+            book.add(order, 0, i as u64, 1.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_iter_bids_and_asks_match_levels() {
+        let book = book_with_depth();
+
+        let bids: Vec<(Price, f64)> = book
+            .iter_bids(None)
+            .map(|(price, size)| (price, size.as_f64()))
+            .collect();
+        let asks: Vec<(Price, f64)> = book
+            .iter_asks(None)
+            .map(|(price, size)| (price, size.as_f64()))
+            .collect();
+        let expected_bids: Vec<(Price, f64)> = book
+            .bid_levels()
+            .map(|level| (level.price.value, level.size()))
+            .collect();
+        let expected_asks: Vec<(Price, f64)> = book
+            .ask_levels()
+            .map(|level| (level.price.value, level.size()))
+            .collect();
+
+        assert_eq!(bids, expected_bids);
+        assert_eq!(asks, expected_asks);
+        assert_eq!(bids[0], (Price::from("1.000"), 3.0));
+        assert_eq!(asks[1], (Price::from("1.020"), 4.0));
+    }
+
+    #[rstest]
+    #[case(Some(0), 0)]
+    #[case(Some(2), 2)]
+    #[case(Some(10), 3)]
+    #[case(None, 3)]
+    fn test_iter_with_depth(#[case] depth: Option<usize>, #[case] expected: usize) {
+        let book = book_with_depth();
+
+        assert_eq!(book.iter_bids(depth).count(), expected);
+        assert_eq!(book.iter_asks(depth).count(), expected);
+    }
+
+    #[rstest]
+    fn test_iter_is_fused() {
+        let book = book_with_depth();
+        let mut iter = book.iter_asks(Some(1));
+
+        assert!(iter.next().is_some());
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+    }
+
+    #[rstest]
+    #[case("0.0", OrderSide::Buy, 0)]
+    #[case("0.5", OrderSide::Buy, 1)]
+    #[case("1.0", OrderSide::Buy, 1)]
+    #[case("1.5", OrderSide::Buy, 2)]
+    #[case("100.0", OrderSide::Buy, 3)]
+    #[case("3.0", OrderSide::Sell, 1)]
+    #[case("3.5", OrderSide::Sell, 2)]
+    fn test_levels_filled_by(
+        #[case] qty: &str,
+        #[case] order_side: OrderSide,
+        #[case] expected: usize,
+    ) {
+        let book = book_with_depth();
+
+        assert_eq!(
+            book.levels_filled_by(Quantity::from(qty), order_side),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_levels_filled_by_empty_book() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let book = OrderBook::new(BookType::L2_MBP, instrument_id);
+
+        assert_eq!(
+            book.levels_filled_by(Quantity::from("1.0"), OrderSide::Buy),
+            0
+        );
+        assert_eq!(book.iter_bids(None).count(), 0);
+    }
+
+    #[rstest]
+    fn test_bids_and_asks_with_order_counts() {
+        let book = book_with_depth();
+
+        let bids: Vec<(Price, Quantity, usize)> = book.bids(None).collect();
+        let asks: Vec<(Price, Quantity, usize)> = book.asks(Some(2)).collect();
+
+        assert_eq!(
+            bids,
+            vec![
+                (Price::from("1.000"), Quantity::from("3.0"), 2),
+                (Price::from("0.990"), Quantity::from("3.0"), 1),
+                (Price::from("0.980"), Quantity::from("4.0"), 1),
+            ]
+        );
+        assert_eq!(
+            asks,
+            vec![
+                (Price::from("1.010"), Quantity::from("1.0"), 1),
+                (Price::from("1.020"), Quantity::from("4.0"), 2),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(Some(0), 0)]
+    #[case(Some(2), 2)]
+    #[case(Some(10), 3)]
+    #[case(None, 3)]
+    fn test_bids_and_asks_with_depth(#[case] depth: Option<usize>, #[case] expected: usize) {
+        let book = book_with_depth();
+
+        assert_eq!(book.bids(depth).count(), expected);
+        assert_eq!(book.asks(depth).count(), expected);
+    }
+
+    // `BookOrder` equality only compares order IDs, which are all zero in a snapshot
+    fn order_fields(orders: &[BookOrder]) -> Vec<(OrderSide, Price, Quantity)> {
+        orders
+            .iter()
+            .map(|order| (order.side, order.price, order.size))
+            .collect()
+    }
+
+    fn null_order_fields(len: usize) -> Vec<(OrderSide, Price, Quantity)> {
+        order_fields(&vec![NULL_ORDER; len])
+    }
+
+    #[rstest]
+    fn test_snapshot_pads_book_shallower_than_depth() {
+        let book = book_with_depth();
+
+        let depth = book.snapshot(10);
+
+        assert_eq!(depth.instrument_id, book.instrument_id);
+        assert_eq!(depth.sequence, book.sequence);
+        assert_eq!(depth.ts_event, book.ts_last);
+        assert_eq!(depth.flags, RecordFlag::F_LAST as u8);
+        assert_eq!(
+            order_fields(&depth.bids[..3]),
+            vec![
+                (OrderSide::Buy, Price::from("1.000"), Quantity::from("3.0")),
+                (OrderSide::Buy, Price::from("0.990"), Quantity::from("3.0")),
+                (OrderSide::Buy, Price::from("0.980"), Quantity::from("4.0")),
+            ]
+        );
+        assert_eq!(
+            order_fields(&depth.asks[..3]),
+            vec![
+                (OrderSide::Sell, Price::from("1.010"), Quantity::from("1.0")),
+                (OrderSide::Sell, Price::from("1.020"), Quantity::from("4.0")),
+                (OrderSide::Sell, Price::from("1.030"), Quantity::from("5.0")),
+            ]
+        );
+        assert_eq!(depth.bid_counts, [2, 1, 1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(depth.ask_counts, [1, 2, 1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(order_fields(&depth.bids[3..]), null_order_fields(7));
+        assert_eq!(order_fields(&depth.asks[3..]), null_order_fields(7));
+    }
+
+    #[rstest]
+    fn test_snapshot_limited_to_depth() {
+        let book = book_with_depth();
+
+        let depth = book.snapshot(1);
+
+        assert_eq!(depth.bid_counts, [2, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(depth.ask_counts, [1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(order_fields(&depth.bids[1..]), null_order_fields(9));
+        assert_eq!(order_fields(&depth.asks[1..]), null_order_fields(9));
+
+        let capped = book.snapshot(DEPTH10_LEN + 1);
+        assert_eq!(
+            order_fields(&capped.bids),
+            order_fields(&book.snapshot(DEPTH10_LEN).bids)
+        );
+    }
+
+    #[rstest]
+    fn test_snapshot_empty_book() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let book = OrderBook::new(BookType::L2_MBP, instrument_id);
+
+        let depth = book.snapshot(10);
+
+        assert_eq!(order_fields(&depth.bids), null_order_fields(DEPTH10_LEN));
+        assert_eq!(order_fields(&depth.asks), null_order_fields(DEPTH10_LEN));
+        assert_eq!(depth.bid_counts, [0; DEPTH10_LEN]);
+        assert_eq!(depth.ask_counts, [0; DEPTH10_LEN]);
+    }
+
+    #[rstest]
+    fn test_snapshot_serde_round_trip() {
+        let book = book_with_depth();
+        let depth = book.snapshot(2);
+
+        let serialized = depth.as_json_bytes().unwrap();
+        let deserialized = OrderBookDepth10::from_json_bytes(serialized).unwrap();
+
+        assert_eq!(deserialized, depth);
+        assert_eq!(order_fields(&deserialized.bids), order_fields(&depth.bids));
+        assert_eq!(order_fields(&deserialized.asks), order_fields(&depth.asks));
+    }
+
+    const SNAPSHOT: u8 = RecordFlag::F_SNAPSHOT as u8;
+    const LAST: u8 = RecordFlag::F_LAST as u8;
+
+    fn book_delta(
+        action: BookAction,
+        side: OrderSide,
+        price: &str,
+        size: &str,
+        flags: u8,
+        sequence: u64,
+    ) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            action,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), 0),
+            flags,
+            sequence,
+            sequence.into(),
+            sequence.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_apply_deltas_snapshot_then_incremental_batches() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+
+        let snapshot = OrderBookDeltas::new(
+            instrument_id,
+            vec![
+                OrderBookDelta::clear(instrument_id, 1, 1.into(), 1.into()),
+                book_delta(BookAction::Add, OrderSide::Buy, "100.00", "10", SNAPSHOT, 1),
+                book_delta(BookAction::Add, OrderSide::Buy, "99.00", "20", SNAPSHOT, 1),
+                book_delta(
+                    BookAction::Add,
+                    OrderSide::Sell,
+                    "101.00",
+                    "5",
+                    SNAPSHOT | LAST,
+                    1,
+                ),
+            ],
+        );
+        assert!(snapshot.is_snapshot());
+        assert!(snapshot.is_last());
+
+        let top = book.apply_deltas(snapshot).unwrap().unwrap();
+        assert_eq!(top.instrument_id, instrument_id);
+        assert_eq!(top.bid, Some((Price::from("100.00"), Quantity::from("10"))));
+        assert_eq!(top.ask, Some((Price::from("101.00"), Quantity::from("5"))));
+        assert_eq!(top.sequence, 1);
+        assert_eq!(top.ts_event, 1);
+
+        // Incremental batch changing the top of book
+        let incremental = OrderBookDeltas::new(
+            instrument_id,
+            vec![
+                book_delta(BookAction::Update, OrderSide::Sell, "101.00", "7", 0, 2),
+                book_delta(BookAction::Add, OrderSide::Buy, "100.50", "3", LAST, 2),
+            ],
+        );
+        assert!(!incremental.is_snapshot());
+
+        let top = book.apply_deltas(incremental).unwrap().unwrap();
+        assert_eq!(top.bid, Some((Price::from("100.50"), Quantity::from("3"))));
+        assert_eq!(top.ask, Some((Price::from("101.00"), Quantity::from("7"))));
+        assert_eq!(top.sequence, 2);
+
+        // Incremental batch below the top of book
+        let incremental = OrderBookDeltas::new(
+            instrument_id,
+            vec![book_delta(
+                BookAction::Add,
+                OrderSide::Buy,
+                "98.00",
+                "1",
+                LAST,
+                3,
+            )],
+        );
+        assert_eq!(book.apply_deltas(incremental).unwrap(), None);
+        assert_eq!(book.sequence, 3);
+        assert_eq!(book.bids(None).count(), 4);
+    }
+
+    #[rstest]
+    fn test_apply_delta_publishes_top_on_last_only() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+
+        let bid = book_delta(BookAction::Add, OrderSide::Buy, "100.00", "10", 0, 1);
+        let ask = book_delta(BookAction::Add, OrderSide::Sell, "101.00", "5", LAST, 1);
+        let delete = book_delta(BookAction::Delete, OrderSide::Buy, "100.00", "0", 0, 2);
+        let update = book_delta(BookAction::Update, OrderSide::Sell, "101.00", "6", LAST, 2);
+
+        assert_eq!(book.apply_delta(bid).unwrap(), None);
+        let top = book.apply_delta(ask).unwrap().unwrap();
+        assert_eq!(top.bid, Some((Price::from("100.00"), Quantity::from("10"))));
+
+        assert_eq!(book.apply_delta(delete).unwrap(), None);
+        assert!(!book.has_bid());
+        let top = book.apply_delta(update).unwrap().unwrap();
+        assert_eq!(top.bid, None);
+        assert_eq!(top.ask, Some((Price::from("101.00"), Quantity::from("6"))));
+
+        book.reset();
+        assert!(book.apply_delta(ask).unwrap().is_some());
+    }
+
+    #[rstest]
+    fn test_apply_delta_with_validate_when_crossed() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+        book.validate = true;
+
+        let bid = book_delta(BookAction::Add, OrderSide::Buy, "100.00", "10", 0, 1);
+        let ask = book_delta(BookAction::Add, OrderSide::Sell, "99.50", "5", LAST, 2);
+
+        assert!(book.apply_delta(bid).is_ok());
+        let result = book.apply_delta(ask);
+
+        match result {
+            Err(BookIntegrityError::OrdersCrossed(best_bid, best_ask)) => {
+                assert_eq!(best_bid.value, Price::from("100.00"));
+                assert_eq!(best_ask.value, Price::from("99.50"));
+            }
+            _ => panic!("Expected crossed orders, was {result:?}"),
+        }
+        // The delta is still applied
+        assert_eq!(book.sequence, 2);
+    }
+
+    #[rstest]
+    fn test_apply_delta_without_validate_when_crossed() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+
+        let bid = book_delta(BookAction::Add, OrderSide::Buy, "100.00", "10", 0, 1);
+        let ask = book_delta(BookAction::Add, OrderSide::Sell, "100.00", "5", LAST, 2);
+
+        assert!(book.apply_delta(bid).is_ok());
+        assert!(book.apply_delta(ask).is_ok());
+        assert!(matches!(
+            book.check_integrity(),
+            Err(BookIntegrityError::OrdersCrossed(_, _))
+        ));
+    }
+
+    #[rstest]
+    fn test_apply_deltas_with_validate_checks_once_per_batch() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+        book.validate = true;
+
+        // Transiently crossed within the batch only
+        let deltas = OrderBookDeltas::new(
+            instrument_id,
+            vec![
+                book_delta(BookAction::Add, OrderSide::Buy, "100.00", "10", 0, 1),
+                book_delta(BookAction::Add, OrderSide::Sell, "99.00", "5", 0, 1),
+                book_delta(BookAction::Delete, OrderSide::Sell, "99.00", "0", 0, 1),
+                book_delta(BookAction::Add, OrderSide::Sell, "101.00", "5", LAST, 1),
+            ],
+        );
+
+        assert!(book.apply_deltas(deltas).is_ok());
+        assert!(book.check_integrity().is_ok());
+    }
+
+    #[rstest]
+    fn test_check_integrity_with_zero_size() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id);
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("101.00"),
+                Quantity::from("1"),
+                1,
+            ),
+            0,
+            1,
+            1.into(),
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("102.00"),
+                Quantity::from("0"),
+                2,
+            ),
+            0,
+            2,
+            2.into(),
+        );
+
+        let result = book.check_integrity();
+
+        match result {
+            Err(BookIntegrityError::ZeroSize(side, price, order_id)) => {
+                assert_eq!(side, OrderSide::Sell);
+                assert_eq!(price, Price::from("102.00"));
+                assert_eq!(order_id, 2);
+            }
+            _ => panic!("Expected zero size, was {result:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_check_integrity_with_levels_out_of_order() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("100.00"),
+                Quantity::from("1"),
+                0,
+            ),
+            0,
+            1,
+            1.into(),
+        );
+        // Corrupt the ladder with a level keyed below the best bid but priced above it
+        let level = Level::from_order(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.50"),
+            Quantity::from("1"),
+            0,
+        ));
+        book.bids
+            .levels
+            .insert(BookPrice::new(Price::from("99.00"), OrderSide::Buy), level);
+
+        let result = book.check_integrity();
+
+        match result {
+            Err(BookIntegrityError::LevelsOutOfOrder(side, prev, price)) => {
+                assert_eq!(side, OrderSide::Buy);
+                assert_eq!(prev, Price::from("100.00"));
+                assert_eq!(price, Price::from("100.50"));
+            }
+            _ => panic!("Expected levels out of order, was {result:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_top_aggregates_best_levels() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id);
+        let orders = [
+            (OrderSide::Buy, "100.00", "1.5", 1),
+            (OrderSide::Buy, "100.00", "2.0", 2),
+            (OrderSide::Buy, "99.00", "5.0", 3),
+            (OrderSide::Sell, "101.00", "3.0", 4),
+        ];
+        for (side, price, size, order_id) in orders {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+            book.add(order, 0, order_id, order_id.into());
+        }
+
+        let top = book.top();
+
+        assert_eq!(
+            top.bid,
+            Some(BookLevel {
+                price: Price::from("100.00"),
+                size: Quantity::from("3.5"),
+                order_count: 2,
+            })
+        );
+        assert_eq!(
+            top.ask,
+            Some(BookLevel {
+                price: Price::from("101.00"),
+                size: Quantity::from("3.0"),
+                order_count: 1,
+            })
+        );
+        assert_eq!(top.sequence, 4);
+        assert_eq!(top.ts_last, 4);
+    }
+
+    #[rstest]
+    fn test_to_quote_tick() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+        book.apply_delta(book_delta(
+            BookAction::Add,
+            OrderSide::Buy,
+            "100.00",
+            "10",
+            0,
+            1,
+        ))
+        .unwrap();
+        book.apply_delta(book_delta(
+            BookAction::Add,
+            OrderSide::Sell,
+            "100.50",
+            "5",
+            0,
+            2,
+        ))
+        .unwrap();
+
+        let quote = book.to_quote_tick().unwrap();
+
+        assert_eq!(quote.instrument_id, instrument_id);
+        assert_eq!(quote.bid_price, Price::from("100.00"));
+        assert_eq!(quote.ask_price, Price::from("100.50"));
+        assert_eq!(quote.bid_size, Quantity::from("10"));
+        assert_eq!(quote.ask_size, Quantity::from("5"));
+        assert_eq!(quote.bid_price.precision, 2);
+        assert_eq!(quote.bid_size.precision, 0);
+        assert_eq!(quote.ts_event, 2);
+        assert_eq!(quote.ts_init, 2);
+    }
+
+    #[rstest]
+    #[case::empty(&[])]
+    #[case::bid_only(&[(OrderSide::Buy, "100.00")])]
+    #[case::ask_only(&[(OrderSide::Sell, "100.50")])]
+    #[case::crossed(&[(OrderSide::Buy, "100.50"), (OrderSide::Sell, "100.00")])]
+    fn test_to_quote_tick_returns_none(#[case] orders: &[(OrderSide, &str)]) {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+        for (side, price) in orders {
+            book.apply_delta(book_delta(BookAction::Add, *side, price, "1", 0, 1))
+                .unwrap();
+        }
+
+        assert_eq!(book.to_quote_tick(), None);
+    }
 }
@@ -0,0 +1,402 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a coalescing pre-pass for applying batches of order book deltas.
+//!
+//! Venue snapshots and merged diff messages often carry several updates to the same order
+//! (for MBP books, the same price level) within a single batch. Only the net effect of such a
+//! run needs to be applied, saving the repeated ladder and level lookups.
+//!
+//! A run of deltas for the same order is coalesced only where this is indistinguishable from
+//! sequential application: every delta for the order (between `Clear` deltas) must be an
+//! `Update` with a non-zero size at the same price. The last update is then applied at the
+//! position of the first, which preserves queue priority within the level. Any run including
+//! an `Add` or `Delete` (e.g. a delete followed by a re-add) is applied as is.
+
+use super::{aggregation::pre_process_order, book::OrderBook};
+use crate::{
+    data::{delta::OrderBookDelta, order::OrderId},
+    enums::{BookAction, BookType},
+};
+
+/// The minimum batch length for which the coalescing pre-pass is run.
+const MIN_COALESCE_LEN: usize = 4;
+
+/// The planned treatment of a delta within a batch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DeltaPlan {
+    /// Apply the delta as is.
+    Apply,
+    /// Apply the order and flags of the delta at the given index in place of this delta.
+    ApplyFrom(usize),
+    /// Skip the delta, as it is superseded by a coalesced update.
+    Skip,
+}
+
+/// Applies the `deltas` to the `book`, coalescing runs of updates to the same order.
+///
+/// The resulting book is identical to applying each delta in turn, including the last
+/// sequence, last event timestamp and event count.
+pub(crate) fn apply_coalesced(book: &mut OrderBook, deltas: &[OrderBookDelta]) {
+    let Some(last) = deltas.last() else {
+        return;
+    };
+    if deltas.len() < MIN_COALESCE_LEN {
+        for delta in deltas {
+            book.apply_delta_inner(*delta);
+        }
+        return;
+    }
+
+    let plan = plan_deltas(book.book_type, deltas);
+    let mut skipped = 0;
+    for (delta, plan) in deltas.iter().zip(&plan) {
+        match plan {
+            DeltaPlan::Apply => book.apply_delta_inner(*delta),
+            DeltaPlan::ApplyFrom(index) => {
+                let latest = deltas[*index];
+                book.update(latest.order, latest.flags, latest.sequence, latest.ts_event);
+            }
+            DeltaPlan::Skip => skipped += 1,
+        }
+    }
+
+    // Account for the superseded deltas as if they had been applied
+    book.count += skipped;
+    book.sequence = last.sequence;
+    book.ts_last = last.ts_event;
+}
+
+/// Returns the plan for each of the `deltas` when applied to a book of the given type.
+///
+/// The deltas are sorted by order (within each segment between `Clear` deltas), keeping batch
+/// order for each order, so every run can be checked with a single scan.
+fn plan_deltas(book_type: BookType, deltas: &[OrderBookDelta]) -> Vec<DeltaPlan> {
+    let mut segment = 0;
+    let mut keys: Vec<(usize, u8, OrderId, usize)> = Vec::with_capacity(deltas.len());
+    for (index, delta) in deltas.iter().enumerate() {
+        if delta.action == BookAction::Clear {
+            segment += 1;
+            continue;
+        }
+        let order = pre_process_order(book_type, delta.order, delta.flags);
+        keys.push((segment, order.side as u8, order.order_id, index));
+    }
+    keys.sort_unstable();
+
+    let mut plan = vec![DeltaPlan::Apply; deltas.len()];
+    for run in keys.chunk_by(|a, b| a.0 == b.0 && a.1 == b.1 && a.2 == b.2) {
+        if run.len() < 2 {
+            continue;
+        }
+        let price = deltas[run[0].3].order.price;
+        let coalescable = run.iter().all(|key| {
+            let delta = &deltas[key.3];
+            delta.action == BookAction::Update
+                && delta.order.size.raw != 0
+                && delta.order.price == price
+        });
+        if !coalescable {
+            continue;
+        }
+
+        plan[run[0].3] = DeltaPlan::ApplyFrom(run[run.len() - 1].3);
+        for key in &run[1..] {
+            plan[key.3] = DeltaPlan::Skip;
+        }
+    }
+
+    plan
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use nautilus_core::nanos::UnixNanos;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::order::BookOrder,
+        enums::OrderSide,
+        identifiers::instrument_id::InstrumentId,
+        orderbook::ladder::Ladder,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ETHUSDT-PERP.BINANCE")
+    }
+
+    fn delta(
+        action: BookAction,
+        side: OrderSide,
+        price: &str,
+        size: &str,
+        order_id: u64,
+        sequence: u64,
+    ) -> OrderBookDelta {
+        OrderBookDelta::new(
+            instrument_id(),
+            action,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), order_id),
+            0,
+            sequence,
+            UnixNanos::from(sequence * 10),
+            UnixNanos::from(sequence * 10 + 1),
+        )
+    }
+
+    type LadderState = (Vec<Vec<(u64, i64, u64)>>, Vec<(u64, i64)>);
+
+    fn ladder_state(ladder: &Ladder) -> LadderState {
+        let levels = ladder
+            .levels
+            .values()
+            .map(|level| {
+                level
+                    .get_orders()
+                    .iter()
+                    .map(|order| (order.order_id, order.price.raw, order.size.raw))
+                    .collect()
+            })
+            .collect();
+        let mut cache: Vec<(u64, i64)> = ladder
+            .cache
+            .iter()
+            .map(|(order_id, price)| (*order_id, price.value.raw))
+            .collect();
+        cache.sort_unstable();
+        (levels, cache)
+    }
+
+    fn assert_books_equal(coalesced: &OrderBook, sequential: &OrderBook) {
+        assert_eq!(coalesced.content_digest(), sequential.content_digest());
+        assert_eq!(
+            ladder_state(&coalesced.bids),
+            ladder_state(&sequential.bids)
+        );
+        assert_eq!(
+            ladder_state(&coalesced.asks),
+            ladder_state(&sequential.asks)
+        );
+        assert_eq!(coalesced.sequence, sequential.sequence);
+        assert_eq!(coalesced.ts_last, sequential.ts_last);
+        assert_eq!(coalesced.count, sequential.count);
+    }
+
+    fn apply_sequential(book: &mut OrderBook, deltas: &[OrderBookDelta]) {
+        for delta in deltas {
+            book.apply_delta(*delta).unwrap();
+        }
+    }
+
+    #[rstest]
+    fn test_plan_collapses_updates_to_same_level() {
+        let deltas = [
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "1", 0, 1),
+            delta(BookAction::Update, OrderSide::Sell, "101.00", "1", 0, 2),
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "2", 0, 3),
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "3", 0, 4),
+        ];
+
+        let plan = plan_deltas(BookType::L2_MBP, &deltas);
+
+        assert_eq!(
+            plan,
+            vec![
+                DeltaPlan::ApplyFrom(3),
+                DeltaPlan::Apply,
+                DeltaPlan::Skip,
+                DeltaPlan::Skip,
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_plan_does_not_collapse_delete_then_add() {
+        let deltas = [
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "1", 0, 1),
+            delta(BookAction::Delete, OrderSide::Buy, "100.00", "0", 0, 2),
+            delta(BookAction::Add, OrderSide::Buy, "100.00", "2", 0, 3),
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "3", 0, 4),
+        ];
+
+        let plan = plan_deltas(BookType::L2_MBP, &deltas);
+
+        assert_eq!(plan, vec![DeltaPlan::Apply; 4]);
+    }
+
+    #[rstest]
+    fn test_plan_does_not_collapse_zero_size_or_price_change() {
+        let deltas = [
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "1", 7, 1),
+            delta(BookAction::Update, OrderSide::Buy, "100.01", "1", 7, 2),
+            delta(BookAction::Update, OrderSide::Sell, "101.00", "1", 8, 3),
+            delta(BookAction::Update, OrderSide::Sell, "101.00", "0", 8, 4),
+        ];
+
+        let plan = plan_deltas(BookType::L3_MBO, &deltas);
+
+        assert_eq!(plan, vec![DeltaPlan::Apply; 4]);
+    }
+
+    #[rstest]
+    fn test_plan_treats_clear_as_barrier() {
+        let deltas = [
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "1", 0, 1),
+            OrderBookDelta::clear(instrument_id(), 2, UnixNanos::from(20), UnixNanos::from(21)),
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "2", 0, 3),
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "3", 0, 4),
+        ];
+
+        let plan = plan_deltas(BookType::L2_MBP, &deltas);
+
+        assert_eq!(
+            plan,
+            vec![
+                DeltaPlan::Apply,
+                DeltaPlan::Apply,
+                DeltaPlan::ApplyFrom(3),
+                DeltaPlan::Skip,
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_apply_coalesced_keeps_queue_priority() {
+        let mut coalesced = OrderBook::new(BookType::L3_MBO, instrument_id());
+        coalesced.add(
+            BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(5), 1),
+            0,
+            0,
+            UnixNanos::default(),
+        );
+        let mut sequential = coalesced.clone();
+        let deltas = [
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "1", 2, 1),
+            delta(BookAction::Add, OrderSide::Buy, "100.00", "1", 3, 2),
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "2", 2, 3),
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "4", 2, 4),
+        ];
+
+        apply_coalesced(&mut coalesced, &deltas);
+        apply_sequential(&mut sequential, &deltas);
+
+        let order_ids: Vec<u64> = coalesced
+            .bid_levels()
+            .next()
+            .unwrap()
+            .get_orders()
+            .iter()
+            .map(|order| order.order_id)
+            .collect();
+        assert_eq!(order_ids, vec![1, 2, 3]);
+        assert_books_equal(&coalesced, &sequential);
+    }
+
+    /// Returns a delta operation, `None` for a clear, otherwise the
+    /// (is_buy, price index, order ID, size, action) of an order delta.
+    fn delta_op() -> impl Strategy<Value = Option<(bool, usize, u64, u64, BookAction)>> {
+        let size = prop_oneof![1 => Just(0_u64), 9 => 1..10_u64];
+        let action = prop_oneof![
+            2 => Just(BookAction::Add),
+            1 => Just(BookAction::Delete),
+            5 => Just(BookAction::Update),
+        ];
+        prop_oneof![
+            1 => Just(None),
+            49 => (any::<bool>(), 0..4_usize, 1..=4_u64, size, action).prop_map(Some),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_apply_coalesced_matches_sequential(
+            book_type in prop_oneof![
+                Just(BookType::L1_MBP),
+                Just(BookType::L2_MBP),
+                Just(BookType::L3_MBO),
+            ],
+            batches in prop::collection::vec(prop::collection::vec(delta_op(), 1..=24), 1..50),
+        ) {
+            let prices = ["100.00", "100.01", "100.02", "100.03"];
+            let mut coalesced = OrderBook::new(book_type, instrument_id());
+            let mut sequential = OrderBook::new(book_type, instrument_id());
+            // Orders whose last update had a zero size, which a delete would not find in the level
+            let mut zeroed: HashSet<(u8, u64)> = HashSet::new();
+            let mut sequence = 0;
+
+            for batch in batches {
+                let mut deltas = Vec::with_capacity(batch.len());
+                for op in batch {
+                    sequence += 1;
+                    let Some((is_buy, price_index, order_id, size, mut action)) = op else {
+                        zeroed.clear();
+                        deltas.push(OrderBookDelta::clear(
+                            instrument_id(),
+                            sequence,
+                            UnixNanos::from(sequence),
+                            UnixNanos::from(sequence),
+                        ));
+                        continue;
+                    };
+
+                    let side = if is_buy { OrderSide::Buy } else { OrderSide::Sell };
+                    let price = prices[price_index];
+                    let order = BookOrder::new(
+                        side,
+                        Price::from(price),
+                        Quantity::from(size as i64),
+                        order_id,
+                    );
+                    let key = {
+                        let order = pre_process_order(book_type, order, 0);
+                        (order.side as u8, order.order_id)
+                    };
+                    if action == BookAction::Delete && zeroed.contains(&key) {
+                        action = BookAction::Update;
+                    }
+                    match (action, size) {
+                        (BookAction::Update, 0) => {
+                            zeroed.insert(key);
+                        }
+                        (BookAction::Add | BookAction::Update, _) => {
+                            zeroed.remove(&key);
+                        }
+                        _ => {}
+                    }
+                    deltas.push(delta(
+                        action,
+                        side,
+                        price,
+                        &size.to_string(),
+                        order_id,
+                        sequence,
+                    ));
+                }
+
+                apply_coalesced(&mut coalesced, &deltas);
+                apply_sequential(&mut sequential, &deltas);
+                assert_books_equal(&coalesced, &sequential);
+            }
+        }
+    }
+}
@@ -0,0 +1,427 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for generating the order book deltas between two states of a book.
+//!
+//! Some feeds only provide periodic depth snapshots, whereas a backtest consumes deltas. The
+//! deltas generated here are minimal: a single Add, Update or Delete per changed order (or
+//! level for MBP data), with unchanged orders omitted.
+
+use std::collections::HashMap;
+
+use nautilus_core::nanos::UnixNanos;
+
+use super::{book::OrderBook, error::InvalidBookOperation, ladder::Ladder};
+use crate::{
+    data::{
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::OrderBookDepth10,
+        order::{BookOrder, OrderId},
+    },
+    enums::{BookAction, BookType, OrderSide, RecordFlag},
+    identifiers::instrument_id::InstrumentId,
+};
+
+/// Returns the deltas which turn a book equal to the `prev` snapshot into one equal to `next`.
+///
+/// Each changed price level produces one aggregated (`F_MBP`) delta, with `F_LAST` set on the
+/// final delta. All deltas carry the sequence and timestamps of the `next` snapshot. Empty
+/// (padding) levels are ignored, and the deltas are empty if no level changed.
+#[must_use]
+pub fn diff_depth_snapshots(prev: &OrderBookDepth10, next: &OrderBookDepth10) -> OrderBookDeltas {
+    let levels = |orders: &[BookOrder]| -> Vec<BookOrder> {
+        orders
+            .iter()
+            .filter(|order| order.side != OrderSide::NoOrderSide && order.size.raw > 0)
+            .map(|order| BookOrder {
+                order_id: order.price.raw as OrderId, // Matches the L2_MBP book order IDs
+                ..*order
+            })
+            .collect()
+    };
+
+    let mut changes = Vec::new();
+    diff_side(&levels(&prev.bids), &levels(&next.bids), &mut changes);
+    diff_side(&levels(&prev.asks), &levels(&next.asks), &mut changes);
+
+    to_deltas(
+        next.instrument_id,
+        changes,
+        RecordFlag::F_MBP as u8,
+        next.sequence,
+        next.ts_event,
+        next.ts_init,
+    )
+}
+
+/// Returns the deltas which turn the `prev` book into one equal to the `next` book.
+pub(crate) fn diff_books(
+    prev: &OrderBook,
+    next: &OrderBook,
+) -> Result<OrderBookDeltas, InvalidBookOperation> {
+    if prev.instrument_id != next.instrument_id {
+        return Err(InvalidBookOperation::DiffInstrumentMismatch(
+            prev.instrument_id,
+            next.instrument_id,
+        ));
+    }
+    if prev.book_type != next.book_type {
+        return Err(InvalidBookOperation::DiffBookTypeMismatch(
+            prev.book_type,
+            next.book_type,
+        ));
+    }
+
+    let mut changes = Vec::new();
+    diff_side(
+        &ladder_orders(&prev.bids),
+        &ladder_orders(&next.bids),
+        &mut changes,
+    );
+    diff_side(
+        &ladder_orders(&prev.asks),
+        &ladder_orders(&next.asks),
+        &mut changes,
+    );
+
+    // Flags which keep the order IDs of the book type when the deltas are pre-processed
+    let flags = match next.book_type {
+        BookType::L1_MBP => RecordFlag::F_TOB as u8,
+        BookType::L2_MBP => RecordFlag::F_MBP as u8,
+        BookType::L3_MBO => 0,
+    };
+
+    Ok(to_deltas(
+        next.instrument_id,
+        changes,
+        flags,
+        next.sequence,
+        next.ts_last,
+        next.ts_last,
+    ))
+}
+
+/// Returns the orders of the `ladder`, best level first.
+fn ladder_orders(ladder: &Ladder) -> Vec<BookOrder> {
+    ladder
+        .levels
+        .values()
+        .flat_map(|level| level.orders.values().copied())
+        .collect()
+}
+
+/// Appends the actions which turn the `prev` orders of one side into the `next` orders.
+///
+/// Deletes come first, so a book never holds an order at both its old and new price.
+fn diff_side(prev: &[BookOrder], next: &[BookOrder], changes: &mut Vec<(BookAction, BookOrder)>) {
+    let prev_by_id: HashMap<OrderId, &BookOrder> =
+        prev.iter().map(|order| (order.order_id, order)).collect();
+    let next_by_id: HashMap<OrderId, &BookOrder> =
+        next.iter().map(|order| (order.order_id, order)).collect();
+
+    for order in prev {
+        if !next_by_id.contains_key(&order.order_id) {
+            changes.push((BookAction::Delete, *order));
+        }
+    }
+
+    for order in next {
+        match prev_by_id.get(&order.order_id) {
+            None => changes.push((BookAction::Add, *order)),
+            Some(prev_order)
+                if prev_order.price != order.price || prev_order.size != order.size =>
+            {
+                changes.push((BookAction::Update, *order));
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+fn to_deltas(
+    instrument_id: InstrumentId,
+    changes: Vec<(BookAction, BookOrder)>,
+    flags: u8,
+    sequence: u64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> OrderBookDeltas {
+    let last_flags = flags | RecordFlag::F_LAST as u8;
+    let last = changes.len().saturating_sub(1);
+    let deltas = changes
+        .into_iter()
+        .enumerate()
+        .map(|(i, (action, order))| {
+            let flags = if i == last { last_flags } else { flags };
+            OrderBookDelta::new(
+                instrument_id,
+                action,
+                order,
+                flags,
+                sequence,
+                ts_event,
+                ts_init,
+            )
+        })
+        .collect();
+
+    OrderBookDeltas {
+        instrument_id,
+        deltas,
+        flags: last_flags,
+        sequence,
+        ts_event,
+        ts_init,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::{depth::DEPTH10_LEN, order::NULL_ORDER},
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AAPL.XNAS")
+    }
+
+    /// Returns (price, size) levels stepping away from `best`, from (jitter, lots) pairs.
+    fn levels(best: f64, step: f64, pairs: &[(u8, u8)]) -> Vec<(f64, f64)> {
+        pairs
+            .iter()
+            .enumerate()
+            .map(|(i, (jitter, lots))| {
+                // Sparse prices, so levels come and go between snapshots
+                let offset = (i as f64).mul_add(step, f64::from(*jitter) * step * 0.25);
+                (best + offset, f64::from(*lots) * 100.0)
+            })
+            .collect()
+    }
+
+    /// Returns a depth snapshot of the given (price, size) levels, padded with empty levels.
+    fn depth(bids: &[(f64, f64)], asks: &[(f64, f64)], sequence: u64) -> OrderBookDepth10 {
+        let side_orders = |side: OrderSide, levels: &[(f64, f64)]| {
+            let mut orders = [NULL_ORDER; DEPTH10_LEN];
+            for (order, (price, size)) in orders.iter_mut().zip(levels) {
+                *order = BookOrder::new(
+                    side,
+                    Price::new(*price, 2).unwrap(),
+                    Quantity::new(*size, 0).unwrap(),
+                    0,
+                );
+            }
+            orders
+        };
+
+        OrderBookDepth10::new(
+            instrument_id(),
+            side_orders(OrderSide::Buy, bids),
+            side_orders(OrderSide::Sell, asks),
+            [1; DEPTH10_LEN],
+            [1; DEPTH10_LEN],
+            RecordFlag::F_SNAPSHOT as u8,
+            sequence,
+            (sequence * 10).into(),
+            (sequence * 10 + 1).into(),
+        )
+    }
+
+    fn book_from_depth(depth: &OrderBookDepth10) -> OrderBook {
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id());
+        for order in depth.bids.iter().chain(&depth.asks) {
+            if order.side != OrderSide::NoOrderSide {
+                book.add(*order, depth.flags, depth.sequence, depth.ts_event);
+            }
+        }
+        book
+    }
+
+    fn actions(deltas: &OrderBookDeltas) -> Vec<(BookAction, OrderSide, f64, f64)> {
+        deltas
+            .deltas
+            .iter()
+            .map(|delta| {
+                let order = delta.order;
+                (
+                    delta.action,
+                    order.side,
+                    order.price.as_f64(),
+                    order.size.as_f64(),
+                )
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_diff_depth_levels_appearing_disappearing_and_resized() {
+        let prev = depth(&[(99.0, 100.0), (98.0, 200.0)], &[(100.0, 100.0)], 1);
+        let next = depth(
+            &[(99.0, 150.0), (97.0, 300.0)],
+            &[(100.0, 100.0), (101.0, 50.0)],
+            2,
+        );
+
+        let deltas = diff_depth_snapshots(&prev, &next);
+
+        assert_eq!(
+            actions(&deltas),
+            vec![
+                (BookAction::Delete, OrderSide::Buy, 98.0, 200.0),
+                (BookAction::Update, OrderSide::Buy, 99.0, 150.0),
+                (BookAction::Add, OrderSide::Buy, 97.0, 300.0),
+                (BookAction::Add, OrderSide::Sell, 101.0, 50.0),
+            ]
+        );
+        let mbp = RecordFlag::F_MBP as u8;
+        let flags: Vec<u8> = deltas.deltas.iter().map(|delta| delta.flags).collect();
+        assert_eq!(flags, vec![mbp, mbp, mbp, mbp | RecordFlag::F_LAST as u8]);
+        assert_eq!(deltas.flags, mbp | RecordFlag::F_LAST as u8);
+        for delta in &deltas.deltas {
+            assert_eq!(delta.sequence, 2);
+            assert_eq!(delta.ts_event, next.ts_event);
+            assert_eq!(delta.ts_init, next.ts_init);
+        }
+        assert_eq!(deltas.sequence, 2);
+        assert_eq!(deltas.ts_event, next.ts_event);
+        assert_eq!(deltas.ts_init, next.ts_init);
+    }
+
+    #[rstest]
+    fn test_diff_depth_applied_to_prev_yields_next() {
+        let prev = depth(&[(99.0, 100.0), (98.0, 200.0)], &[(100.0, 100.0)], 1);
+        let next = depth(&[(99.0, 150.0)], &[(100.5, 100.0), (101.0, 50.0)], 2);
+        let mut book = book_from_depth(&prev);
+
+        book.apply_deltas(diff_depth_snapshots(&prev, &next))
+            .unwrap();
+
+        assert_eq!(
+            book.content_digest(),
+            book_from_depth(&next).content_digest()
+        );
+        assert_ne!(
+            book.content_digest(),
+            book_from_depth(&prev).content_digest()
+        );
+    }
+
+    #[rstest]
+    fn test_diff_identical_depths_is_empty() {
+        let snapshot = depth(&[(99.0, 100.0)], &[(100.0, 100.0)], 1);
+
+        let deltas = diff_depth_snapshots(&snapshot, &snapshot);
+
+        assert!(deltas.deltas.is_empty());
+    }
+
+    #[rstest]
+    fn test_diff_mbo_books() {
+        let order = |side, price: &str, size: i64, order_id| {
+            BookOrder::new(side, Price::from(price), Quantity::from(size), order_id)
+        };
+        let mut prev = OrderBook::new(BookType::L3_MBO, instrument_id());
+        prev.add(order(OrderSide::Buy, "99.00", 100, 1), 0, 1, 1.into());
+        prev.add(order(OrderSide::Buy, "99.00", 200, 2), 0, 2, 2.into());
+        prev.add(order(OrderSide::Sell, "100.00", 100, 3), 0, 3, 3.into());
+        let mut next = OrderBook::new(BookType::L3_MBO, instrument_id());
+        next.add(order(OrderSide::Buy, "99.00", 50, 2), 0, 1, 1.into()); // Resized
+        next.add(order(OrderSide::Sell, "100.50", 100, 3), 0, 2, 2.into()); // Moved
+        next.add(order(OrderSide::Sell, "101.00", 300, 4), 0, 3, 9.into()); // Added
+
+        let deltas = prev.diff(&next).unwrap();
+        let mut book = prev.clone();
+        book.apply_deltas(deltas.clone()).unwrap();
+
+        assert_eq!(
+            actions(&deltas),
+            vec![
+                (BookAction::Delete, OrderSide::Buy, 99.0, 100.0),
+                (BookAction::Update, OrderSide::Buy, 99.0, 50.0),
+                (BookAction::Update, OrderSide::Sell, 100.5, 100.0),
+                (BookAction::Add, OrderSide::Sell, 101.0, 300.0),
+            ]
+        );
+        assert_eq!(deltas.deltas[3].flags, RecordFlag::F_LAST as u8);
+        assert_eq!(deltas.ts_event, next.ts_last);
+        assert_eq!(book.content_digest(), next.content_digest());
+        assert!(prev.diff(&prev).unwrap().deltas.is_empty());
+    }
+
+    #[rstest]
+    fn test_diff_books_of_different_types_errors() {
+        let prev = OrderBook::new(BookType::L2_MBP, instrument_id());
+        let next = OrderBook::new(BookType::L3_MBO, instrument_id());
+
+        assert!(matches!(
+            prev.diff(&next),
+            Err(InvalidBookOperation::DiffBookTypeMismatch(
+                BookType::L2_MBP,
+                BookType::L3_MBO
+            ))
+        ));
+    }
+
+    #[rstest]
+    fn test_diff_books_for_different_instruments_errors() {
+        let prev = OrderBook::new(BookType::L2_MBP, instrument_id());
+        let next = OrderBook::new(BookType::L2_MBP, InstrumentId::from("MSFT.XNAS"));
+
+        assert!(matches!(
+            prev.diff(&next),
+            Err(InvalidBookOperation::DiffInstrumentMismatch(_, _))
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn test_diff_random_depths_applied_to_prev_yields_next(
+            snapshots in prop::collection::vec(
+                (
+                    prop::collection::vec((0..3_u8, 1..6_u8), 0..=DEPTH10_LEN),
+                    prop::collection::vec((0..3_u8, 1..6_u8), 0..=DEPTH10_LEN),
+                ),
+                1..20,
+            ),
+        ) {
+            let mut prev = depth(&[], &[], 0);
+            for (sequence, (bids, asks)) in (1..).zip(snapshots) {
+                let next = depth(
+                    &levels(99.0, -1.0, &bids),
+                    &levels(100.0, 1.0, &asks),
+                    sequence,
+                );
+                let mut book = book_from_depth(&prev);
+
+                book.apply_deltas(diff_depth_snapshots(&prev, &next))
+                    .unwrap();
+
+                prop_assert_eq!(
+                    book.content_digest(),
+                    book_from_depth(&next).content_digest()
+                );
+                prev = next;
+            }
+        }
+    }
+}
@@ -16,7 +16,11 @@
 use nautilus_core::nanos::UnixNanos;
 
 use super::ladder::BookPrice;
-use crate::enums::{BookType, OrderSide};
+use crate::{
+    enums::{BookType, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum InvalidBookOperation {
@@ -26,6 +30,10 @@ pub enum InvalidBookOperation {
     Add(BookType),
     #[error("Invalid book operation: cannot update with tick for {0} book")]
     Update(BookType),
+    #[error("Invalid book operation: cannot diff books for different instruments {0} and {1}")]
+    DiffInstrumentMismatch(InstrumentId, InstrumentId),
+    #[error("Invalid book operation: cannot diff {0} book with {1} book")]
+    DiffBookTypeMismatch(BookType, BookType),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -40,4 +48,28 @@ pub enum BookIntegrityError {
     TooManyOrders(OrderSide, usize),
     #[error("Integrity error: number of {0} levels > 1 for L1_MBP book, was {1}")]
     TooManyLevels(OrderSide, usize),
+    #[error("Integrity error: insufficient liquidity at {0} to consume {1}")]
+    InsufficientLiquidity(Price, Quantity),
+    #[error("Integrity error: {0} order with zero size at {1}, order_id={2}")]
+    ZeroSize(OrderSide, Price, u64),
+    #[error("Integrity error: {0} levels out of order, {1} before {2}")]
+    LevelsOutOfOrder(OrderSide, Price, Price),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum LadderSnapshotError {
+    #[error("Ladder snapshot truncated: needed {needed} bytes, was {available}")]
+    Truncated { needed: usize, available: usize },
+    #[error("Ladder snapshot has {0} trailing bytes")]
+    TrailingBytes(usize),
+    #[error("Ladder snapshot has invalid magic bytes")]
+    InvalidMagic,
+    #[error("Ladder snapshot version {0} not supported")]
+    UnsupportedVersion(u8),
+    #[error("Ladder snapshot has invalid instrument ID: {0}")]
+    InvalidInstrumentId(String),
+    #[error("Ladder snapshot instrument ID too long, was {0} bytes")]
+    InstrumentIdTooLong(usize),
+    #[error("Ladder snapshot has too many levels, was {0}")]
+    TooManyLevels(usize),
 }
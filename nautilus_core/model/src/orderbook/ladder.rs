@@ -26,7 +26,10 @@ use crate::{
     data::order::{BookOrder, OrderId},
     enums::OrderSide,
     orderbook::level::Level,
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::Price,
+        quantity::{Quantity, QuantityAccumulator},
+    },
 };
 
 /// Represents a price level with a specified side in an order books ladder.
@@ -75,6 +78,15 @@ impl Display for BookPrice {
     }
 }
 
+/// Represents the fills simulated for an order against one side of an order book.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedFills {
+    /// The fills per price level consumed, in sweep order.
+    pub fills: Vec<(Price, Quantity)>,
+    /// If the full quantity was satisfiable (within the limit price, if any).
+    pub is_complete: bool,
+}
+
 /// Represents one side of an order book as a ladder of price levels.
 #[derive(Clone, Debug)]
 pub struct Ladder {
@@ -191,7 +203,7 @@ impl Ladder {
     pub fn simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
         let is_reversed = self.side == OrderSide::Buy;
         let mut fills = Vec::new();
-        let mut cumulative_denominator = Quantity::zero(order.size.precision);
+        let mut cumulative_denominator = QuantityAccumulator::new(order.size.precision);
         let target = order.size;
 
         for level in self.levels.values() {
@@ -203,9 +215,14 @@ impl Ladder {
 
             for book_order in level.orders.values() {
                 let current = book_order.size;
-                if cumulative_denominator + current >= target {
+                if cumulative_denominator.raw() + u128::from(current.raw) >= u128::from(target.raw)
+                {
                     // This order has filled us, add fill and return
-                    let remainder = target - cumulative_denominator;
+                    let remainder = Quantity::from_raw(
+                        target.raw - cumulative_denominator.raw() as u64, // Less than target
+                        target.precision,
+                    )
+                    .unwrap();
                     if remainder.is_positive() {
                         fills.push((book_order.price, remainder));
                     }
@@ -214,12 +231,109 @@ impl Ladder {
 
                 // Add this fill and continue
                 fills.push((book_order.price, current));
-                cumulative_denominator += current;
+                cumulative_denominator.add(&current);
             }
         }
 
         fills
     }
+
+    /// Simulates filling `quantity` against the ladder, returning one fill per price level
+    /// consumed, without modifying the ladder.
+    ///
+    /// The sweep stops at the first level beyond the `limit_px` (if provided).
+    #[must_use]
+    pub fn simulate_level_fills(
+        &self,
+        quantity: Quantity,
+        limit_px: Option<Price>,
+    ) -> SimulatedFills {
+        let mut fills = Vec::new();
+        let mut remaining = quantity.raw;
+
+        for level in self.levels.values() {
+            if remaining == 0 || limit_px.is_some_and(|limit_px| !self.is_within(level, limit_px)) {
+                break;
+            }
+
+            let available = level.size_accumulated().raw();
+            let fill_raw = if available < u128::from(remaining) {
+                available as u64 // Less than remaining
+            } else {
+                remaining
+            };
+            if fill_raw > 0 {
+                let fill_qty = Quantity::from_raw(fill_raw, quantity.precision).unwrap();
+                fills.push((level.price.value, fill_qty));
+                remaining -= fill_raw;
+            }
+        }
+
+        SimulatedFills {
+            fills,
+            is_complete: remaining == 0,
+        }
+    }
+
+    /// Removes the liquidity for the given `fills` (per price level) from the ladder,
+    /// consuming the orders at each level in FIFO order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the ladder unmodified, if any level does not hold enough
+    /// liquidity for its fill.
+    pub fn consume_fills(
+        &mut self,
+        fills: &[(Price, Quantity)],
+        sequence: u64,
+        ts_event: UnixNanos,
+    ) -> Result<(), BookIntegrityError> {
+        for (price, qty) in fills {
+            let available = self
+                .levels
+                .get(&BookPrice::new(*price, self.side))
+                .map_or(0, |level| level.size_accumulated().raw());
+            if available < u128::from(qty.raw) {
+                return Err(BookIntegrityError::InsufficientLiquidity(*price, *qty));
+            }
+        }
+
+        for (price, qty) in fills {
+            let level = self
+                .levels
+                .get_mut(&BookPrice::new(*price, self.side))
+                .expect("level checked above");
+            let mut remaining = qty.raw;
+            for mut order in level.get_orders() {
+                if remaining == 0 {
+                    break;
+                }
+                if order.size.raw <= remaining {
+                    remaining -= order.size.raw;
+                    self.cache.remove(&order.order_id);
+                    level.remove_by_id(order.order_id, sequence, ts_event);
+                } else {
+                    order.size.raw -= remaining;
+                    remaining = 0;
+                    level.update(order);
+                }
+            }
+            if level.is_empty() {
+                self.levels.remove(&BookPrice::new(*price, self.side));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_within(&self, level: &Level, limit_px: Price) -> bool {
+        match self.side {
+            // Buying from the asks
+            OrderSide::Sell => level.price.value <= limit_px,
+            // Selling into the bids
+            _ => level.price.value >= limit_px,
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -698,4 +812,137 @@ mod tests {
         assert_eq!(ladder_buy.top().unwrap().price.value, min_price);
         assert_eq!(ladder_sell.top().unwrap().price.value, max_price);
     }
+
+    fn ask_ladder() -> Ladder {
+        let mut ladder = Ladder::new(OrderSide::Sell);
+        ladder.add_bulk(vec![
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("100.00"),
+                Quantity::from(100),
+                1,
+            ),
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("100.00"),
+                Quantity::from(50),
+                2,
+            ),
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("101.00"),
+                Quantity::from(200),
+                3,
+            ),
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("102.00"),
+                Quantity::from(300),
+                4,
+            ),
+        ]);
+        ladder
+    }
+
+    #[rstest]
+    fn test_simulate_level_fills_aggregates_per_level() {
+        let ladder = ask_ladder();
+
+        let simulated = ladder.simulate_level_fills(Quantity::from(400), None);
+
+        assert_eq!(
+            simulated.fills,
+            vec![
+                (Price::from("100.00"), Quantity::from(150)),
+                (Price::from("101.00"), Quantity::from(200)),
+                (Price::from("102.00"), Quantity::from(50)),
+            ]
+        );
+        assert!(simulated.is_complete);
+        assert_eq!(ladder.sizes(), 650.0); // Unmodified
+    }
+
+    #[rstest]
+    #[case(Price::from("99.00"), vec![])]
+    #[case(Price::from("100.00"), vec![(Price::from("100.00"), Quantity::from(150))])]
+    #[case(Price::from("101.50"), vec![(Price::from("100.00"), Quantity::from(150)), (Price::from("101.00"), Quantity::from(200))])]
+    fn test_simulate_level_fills_stops_at_limit_price(
+        #[case] limit_px: Price,
+        #[case] expected: Vec<(Price, Quantity)>,
+    ) {
+        let ladder = ask_ladder();
+
+        let simulated = ladder.simulate_level_fills(Quantity::from(400), Some(limit_px));
+
+        assert_eq!(simulated.fills, expected);
+        assert!(!simulated.is_complete);
+    }
+
+    #[rstest]
+    fn test_simulate_level_fills_selling_into_bids_stops_at_limit_price() {
+        let mut ladder = Ladder::new(OrderSide::Buy);
+        ladder.add_bulk(vec![
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("100.00"),
+                Quantity::from(100),
+                1,
+            ),
+            BookOrder::new(OrderSide::Buy, Price::from("99.00"), Quantity::from(100), 2),
+        ]);
+
+        let simulated =
+            ladder.simulate_level_fills(Quantity::from(100), Some(Price::from("99.50")));
+
+        assert_eq!(
+            simulated.fills,
+            vec![(Price::from("100.00"), Quantity::from(100))]
+        );
+        assert!(simulated.is_complete);
+    }
+
+    #[rstest]
+    fn test_simulate_level_fills_beyond_available_is_incomplete() {
+        let ladder = ask_ladder();
+
+        let simulated = ladder.simulate_level_fills(Quantity::from(1_000), None);
+
+        assert_eq!(simulated.fills.len(), 3);
+        assert!(!simulated.is_complete);
+    }
+
+    #[rstest]
+    fn test_consume_fills_removes_liquidity_in_fifo_order() {
+        let mut ladder = ask_ladder();
+        let fills = vec![
+            (Price::from("100.00"), Quantity::from(120)),
+            (Price::from("101.00"), Quantity::from(200)),
+        ];
+
+        ladder.consume_fills(&fills, 1, 1.into()).unwrap();
+
+        assert_eq!(ladder.len(), 2);
+        let top = ladder.top().unwrap();
+        assert_eq!(top.price.value, Price::from("100.00"));
+        assert_eq!(top.get_orders().len(), 1);
+        assert_eq!(top.first().unwrap().order_id, 2);
+        assert_eq!(top.first().unwrap().size, Quantity::from(30));
+        assert!(!ladder.cache.contains_key(&1));
+        assert!(!ladder.cache.contains_key(&3));
+    }
+
+    #[rstest]
+    fn test_consume_fills_with_insufficient_liquidity_leaves_ladder_unmodified() {
+        let mut ladder = ask_ladder();
+        let fills = vec![
+            (Price::from("100.00"), Quantity::from(150)),
+            (Price::from("101.00"), Quantity::from(250)),
+        ];
+
+        let result = ladder.consume_fills(&fills, 1, 1.into());
+
+        assert!(result.is_err());
+        assert_eq!(ladder.sizes(), 650.0);
+        assert_eq!(ladder.len(), 3);
+    }
 }
@@ -20,7 +20,7 @@ use nautilus_core::nanos::UnixNanos;
 use crate::{
     data::order::{BookOrder, OrderId},
     orderbook::{error::BookIntegrityError, ladder::BookPrice},
-    types::fixed::FIXED_SCALAR,
+    types::{fixed::FIXED_SCALAR, quantity::QuantityAccumulator},
 };
 
 /// Represents a discrete price level in an order book.
@@ -91,6 +91,14 @@ impl Level {
         self.orders.values().map(|o| o.size.as_f64()).sum()
     }
 
+    /// Returns the total size of the orders at the level, accumulated without overflow.
+    #[must_use]
+    pub fn size_accumulated(&self) -> QuantityAccumulator {
+        let mut size = QuantityAccumulator::default();
+        size.extend(self.orders.values().map(|o| &o.size));
+        size
+    }
+
     #[must_use]
     pub fn size_raw(&self) -> u64 {
         self.orders.values().map(|o| o.size.raw).sum()
@@ -0,0 +1,379 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A compact, versioned binary snapshot of the depth-of-market ladder for GUI consumers.
+//!
+//! All integers are little-endian. The encoding is laid out as follows:
+//!
+//! | Offset | Size     | Field                                        |
+//! |--------|----------|----------------------------------------------|
+//! | 0      | 4        | Magic bytes `NTLS`                           |
+//! | 4      | 1        | Format version (currently 1)                 |
+//! | 5      | 1        | Price precision                              |
+//! | 6      | 1        | Size precision                               |
+//! | 7      | 1        | Reserved (zero)                              |
+//! | 8      | 8        | `ts` (UNIX nanoseconds, `u64`)               |
+//! | 16     | 2        | Instrument ID length `n` (`u16`)             |
+//! | 18     | `n`      | Instrument ID (UTF-8)                        |
+//! | 18+n   | 4        | Bid level count `b` (`u32`)                  |
+//! | 22+n   | 4        | Ask level count `a` (`u32`)                  |
+//! | 26+n   | 20 × `b` | Bid levels, best first                       |
+//! | …      | 20 × `a` | Ask levels, best first                       |
+//!
+//! Each level is the raw price (`i64`), raw size (`u64`) and order count (`u32`).
+
+use std::str::FromStr;
+
+use nautilus_core::nanos::UnixNanos;
+
+use super::error::LadderSnapshotError;
+use crate::identifiers::instrument_id::InstrumentId;
+
+/// The magic bytes at the start of every encoded snapshot.
+pub const LADDER_SNAPSHOT_MAGIC: [u8; 4] = *b"NTLS";
+/// The current version of the binary encoding.
+pub const LADDER_SNAPSHOT_VERSION: u8 = 1;
+
+const HEADER_LEN: usize = 18;
+const COUNTS_LEN: usize = 8;
+const LEVEL_LEN: usize = 20;
+
+/// A level of the ladder as (raw price, raw size, order count).
+pub type LadderLevel = (i64, u64, u32);
+
+/// Represents a point-in-time snapshot of the top levels of an order book.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LadderSnapshot {
+    pub instrument_id: InstrumentId,
+    pub ts: UnixNanos,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    /// The bid levels, best first.
+    pub bids: Vec<LadderLevel>,
+    /// The ask levels, best first.
+    pub asks: Vec<LadderLevel>,
+}
+
+impl LadderSnapshot {
+    /// Returns the length in bytes of the encoded snapshot.
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        HEADER_LEN
+            + self.instrument_id.to_string().len()
+            + COUNTS_LEN
+            + (self.bids.len() + self.asks.len()) * LEVEL_LEN
+    }
+
+    /// Encodes the snapshot into the versioned little-endian binary format.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the instrument ID is longer than `u16::MAX` bytes.
+    /// - If either side has more than `u32::MAX` levels.
+    pub fn encode(&self) -> Result<Vec<u8>, LadderSnapshotError> {
+        let instrument_id = self.instrument_id.to_string();
+        let id_len = u16::try_from(instrument_id.len())
+            .map_err(|_| LadderSnapshotError::InstrumentIdTooLong(instrument_id.len()))?;
+        let bid_count = u32::try_from(self.bids.len())
+            .map_err(|_| LadderSnapshotError::TooManyLevels(self.bids.len()))?;
+        let ask_count = u32::try_from(self.asks.len())
+            .map_err(|_| LadderSnapshotError::TooManyLevels(self.asks.len()))?;
+
+        let mut buf = Vec::with_capacity(self.encoded_len());
+        buf.extend_from_slice(&LADDER_SNAPSHOT_MAGIC);
+        buf.push(LADDER_SNAPSHOT_VERSION);
+        buf.push(self.price_precision);
+        buf.push(self.size_precision);
+        buf.push(0);
+        buf.extend_from_slice(&self.ts.as_u64().to_le_bytes());
+        buf.extend_from_slice(&id_len.to_le_bytes());
+        buf.extend_from_slice(instrument_id.as_bytes());
+        buf.extend_from_slice(&bid_count.to_le_bytes());
+        buf.extend_from_slice(&ask_count.to_le_bytes());
+        for (price_raw, size_raw, count) in self.bids.iter().chain(&self.asks) {
+            buf.extend_from_slice(&price_raw.to_le_bytes());
+            buf.extend_from_slice(&size_raw.to_le_bytes());
+            buf.extend_from_slice(&count.to_le_bytes());
+        }
+
+        Ok(buf)
+    }
+
+    /// Decodes a snapshot from the versioned little-endian binary format.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the input is truncated or has trailing bytes.
+    /// - If the magic bytes or version are not recognized.
+    /// - If the instrument ID is not valid.
+    pub fn decode(bytes: &[u8]) -> Result<Self, LadderSnapshotError> {
+        let mut reader = Reader { bytes, pos: 0 };
+
+        if reader.take(4)? != LADDER_SNAPSHOT_MAGIC {
+            return Err(LadderSnapshotError::InvalidMagic);
+        }
+        let version = reader.u8()?;
+        if version != LADDER_SNAPSHOT_VERSION {
+            return Err(LadderSnapshotError::UnsupportedVersion(version));
+        }
+        let price_precision = reader.u8()?;
+        let size_precision = reader.u8()?;
+        let _reserved = reader.u8()?;
+        let ts = UnixNanos::from(reader.u64()?);
+
+        let id_len = usize::from(reader.u16()?);
+        let instrument_id = std::str::from_utf8(reader.take(id_len)?)
+            .map_err(|e| LadderSnapshotError::InvalidInstrumentId(e.to_string()))?;
+        let instrument_id = InstrumentId::from_str(instrument_id)
+            .map_err(|e| LadderSnapshotError::InvalidInstrumentId(e.to_string()))?;
+
+        let bid_count = reader.u32()? as usize;
+        let ask_count = reader.u32()? as usize;
+
+        // Validate the total length up front so a corrupt count cannot trigger a huge allocation
+        let levels_len = bid_count
+            .checked_add(ask_count)
+            .and_then(|count| count.checked_mul(LEVEL_LEN))
+            .ok_or(LadderSnapshotError::Truncated {
+                needed: usize::MAX,
+                available: bytes.len(),
+            })?;
+        reader.require(levels_len)?;
+        if reader.remaining() > levels_len {
+            return Err(LadderSnapshotError::TrailingBytes(
+                reader.remaining() - levels_len,
+            ));
+        }
+
+        let bids = reader.levels(bid_count)?;
+        let asks = reader.levels(ask_count)?;
+
+        Ok(Self {
+            instrument_id,
+            ts,
+            price_precision,
+            size_precision,
+            bids,
+            asks,
+        })
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    fn require(&self, len: usize) -> Result<(), LadderSnapshotError> {
+        if self.remaining() < len {
+            return Err(LadderSnapshotError::Truncated {
+                needed: self.pos.saturating_add(len),
+                available: self.bytes.len(),
+            });
+        }
+        Ok(())
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], LadderSnapshotError> {
+        self.require(len)?;
+        let slice = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], LadderSnapshotError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn u8(&mut self) -> Result<u8, LadderSnapshotError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, LadderSnapshotError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, LadderSnapshotError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, LadderSnapshotError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn levels(&mut self, count: usize) -> Result<Vec<LadderLevel>, LadderSnapshotError> {
+        let mut levels = Vec::with_capacity(count);
+        for _ in 0..count {
+            let price_raw = i64::from_le_bytes(self.array()?);
+            let size_raw = self.u64()?;
+            let order_count = self.u32()?;
+            levels.push((price_raw, size_raw, order_count));
+        }
+        Ok(levels)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::order::BookOrder,
+        enums::{BookType, OrderSide},
+        orderbook::book::OrderBook,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn book() -> OrderBook {
+        let mut book = OrderBook::new(BookType::L3_MBO, InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        let orders = [
+            (OrderSide::Buy, "100.00", "1.5", 1),
+            (OrderSide::Buy, "100.00", "2.0", 2),
+            (OrderSide::Buy, "99.50", "3.0", 3),
+            (OrderSide::Buy, "99.00", "4.0", 4),
+            (OrderSide::Sell, "100.50", "1.0", 5),
+            (OrderSide::Sell, "101.00", "2.5", 6),
+        ];
+        for (side, price, size, order_id) in orders {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+            book.add(order, 0, order_id, 1_000.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_to_ladder_snapshot() {
+        let snapshot = book().to_ladder_snapshot(Some(2));
+
+        assert_eq!(snapshot.ts, UnixNanos::from(1_000));
+        assert_eq!(snapshot.price_precision, 2);
+        assert_eq!(snapshot.size_precision, 1);
+        assert_eq!(
+            snapshot.bids,
+            vec![
+                (Price::from("100.00").raw, Quantity::from("3.5").raw, 2),
+                (Price::from("99.50").raw, Quantity::from("3.0").raw, 1),
+            ]
+        );
+        assert_eq!(snapshot.asks.len(), 2);
+        assert_eq!(snapshot.asks[0].0, Price::from("100.50").raw);
+    }
+
+    #[rstest]
+    fn test_to_ladder_snapshot_empty_book() {
+        let book = OrderBook::new(BookType::L2_MBP, InstrumentId::from("AUD/USD.SIM"));
+        let snapshot = book.to_ladder_snapshot(None);
+
+        assert!(snapshot.bids.is_empty());
+        assert!(snapshot.asks.is_empty());
+        assert_eq!(snapshot.price_precision, 0);
+
+        let decoded = LadderSnapshot::decode(&snapshot.encode().unwrap()).unwrap();
+        assert_eq!(decoded, snapshot);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let snapshot = book().to_ladder_snapshot(None);
+        let bytes = snapshot.encode().unwrap();
+
+        assert_eq!(bytes.len(), snapshot.encoded_len());
+        assert_eq!(&bytes[..4], b"NTLS");
+        assert_eq!(bytes[4], LADDER_SNAPSHOT_VERSION);
+        assert_eq!(LadderSnapshot::decode(&bytes).unwrap(), snapshot);
+    }
+
+    #[rstest]
+    fn test_decode_rejects_unknown_version() {
+        let mut bytes = book().to_ladder_snapshot(None).encode().unwrap();
+        bytes[4] = 2;
+
+        assert!(matches!(
+            LadderSnapshot::decode(&bytes),
+            Err(LadderSnapshotError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[rstest]
+    fn test_decode_rejects_bad_magic_and_trailing_bytes() {
+        let mut bytes = book().to_ladder_snapshot(None).encode().unwrap();
+        bytes.push(0);
+        assert!(matches!(
+            LadderSnapshot::decode(&bytes),
+            Err(LadderSnapshotError::TrailingBytes(1))
+        ));
+
+        bytes[0] = b'X';
+        assert!(matches!(
+            LadderSnapshot::decode(&bytes),
+            Err(LadderSnapshotError::InvalidMagic)
+        ));
+    }
+
+    #[rstest]
+    fn test_decode_rejects_huge_level_counts() {
+        let snapshot = book().to_ladder_snapshot(None);
+        let mut bytes = snapshot.encode().unwrap();
+        let counts_offset = HEADER_LEN + snapshot.instrument_id.to_string().len();
+        bytes[counts_offset..counts_offset + 8].copy_from_slice(&[0xFF; 8]);
+
+        assert!(matches!(
+            LadderSnapshot::decode(&bytes),
+            Err(LadderSnapshotError::Truncated { .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_decode_every_truncated_prefix_errors() {
+        let bytes = book().to_ladder_snapshot(None).encode().unwrap();
+
+        for len in 0..bytes.len() {
+            assert!(LadderSnapshot::decode(&bytes[..len]).is_err(), "len={len}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_decode_corrupted_prefix_never_panics(
+            len in any::<prop::sample::Index>(),
+            corruptions in prop::collection::vec((any::<prop::sample::Index>(), any::<u8>()), 0..4),
+        ) {
+            // Corrupt random bytes of a random prefix of a valid encoding
+            let valid = book().to_ladder_snapshot(None).encode().unwrap();
+            let mut bytes = valid[..len.index(valid.len() + 1)].to_vec();
+            for (index, byte) in corruptions {
+                if !bytes.is_empty() {
+                    let index = index.index(bytes.len());
+                    bytes[index] = byte;
+                }
+            }
+            let _ = LadderSnapshot::decode(&bytes);
+        }
+    }
+}
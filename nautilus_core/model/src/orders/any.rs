@@ -13,6 +13,8 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::sync::Arc;
+
 use nautilus_core::nanos::UnixNanos;
 use serde::{Deserialize, Serialize};
 
@@ -29,18 +31,19 @@ use super::{
     trailing_stop_market::TrailingStopMarketOrder,
 };
 use crate::{
-    enums::{OrderSide, OrderSideSpecified, TriggerType},
+    enums::{OrderSide, OrderSideSpecified, OrderStatus, TimeInForce, TriggerType},
     events::order::event::OrderEventAny,
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
-        trader_id::TraderId, venue_order_id::VenueOrderId,
+        trade_id::TradeId, trader_id::TraderId, venue_order_id::VenueOrderId,
     },
     polymorphism::{
         ApplyOrderEventAny, GetAccountId, GetClientOrderId, GetEmulationTrigger,
-        GetExecAlgorithmId, GetExecSpawnId, GetInstrumentId, GetLimitPrice, GetOrderFilledQty,
-        GetOrderLeavesQty, GetOrderQuantity, GetOrderSide, GetOrderSideSpecified, GetPositionId,
-        GetStopPrice, GetStrategyId, GetTraderId, GetVenueOrderId, IsClosed, IsInflight, IsOpen,
+        GetExecAlgorithmId, GetExecSpawnId, GetInstrumentId, GetLimitPrice, GetOrderEvents,
+        GetOrderFilledQty, GetOrderLeavesQty, GetOrderQuantity, GetOrderSide,
+        GetOrderSideSpecified, GetOrderStatus, GetPositionId, GetStopPrice, GetStrategyId,
+        GetTimeInForce, GetTradeIds, GetTraderId, GetVenueOrderId, IsClosed, IsInflight, IsOpen,
     },
     types::{price::Price, quantity::Quantity},
 };
@@ -104,6 +107,22 @@ impl OrderAny {
         Self::StopMarket(order)
     }
 
+    /// Returns whether the given `event` duplicates an event already applied to the order.
+    #[must_use]
+    pub fn is_duplicate_event(&self, event: &OrderEventAny) -> bool {
+        match self {
+            Self::Limit(order) => order.is_duplicate_event(event),
+            Self::LimitIfTouched(order) => order.is_duplicate_event(event),
+            Self::Market(order) => order.is_duplicate_event(event),
+            Self::MarketIfTouched(order) => order.is_duplicate_event(event),
+            Self::MarketToLimit(order) => order.is_duplicate_event(event),
+            Self::StopLimit(order) => order.is_duplicate_event(event),
+            Self::StopMarket(order) => order.is_duplicate_event(event),
+            Self::TrailingStopLimit(order) => order.is_duplicate_event(event),
+            Self::TrailingStopMarket(order) => order.is_duplicate_event(event),
+        }
+    }
+
     pub fn from_events(events: Vec<OrderEventAny>) -> anyhow::Result<Self> {
         println!("from events");
         println!("events: {:?}", events);
@@ -347,15 +366,79 @@ impl GetOrderLeavesQty for OrderAny {
 impl GetOrderSideSpecified for OrderAny {
     fn order_side_specified(&self) -> OrderSideSpecified {
         match self {
-            Self::Limit(order) => order.side.as_specified(),
-            Self::LimitIfTouched(order) => order.side.as_specified(),
-            Self::Market(order) => order.side.as_specified(),
-            Self::MarketIfTouched(order) => order.side.as_specified(),
-            Self::MarketToLimit(order) => order.side.as_specified(),
-            Self::StopLimit(order) => order.side.as_specified(),
-            Self::StopMarket(order) => order.side.as_specified(),
-            Self::TrailingStopLimit(order) => order.side.as_specified(),
-            Self::TrailingStopMarket(order) => order.side.as_specified(),
+            Self::Limit(order) => order.side.specified(),
+            Self::LimitIfTouched(order) => order.side.specified(),
+            Self::Market(order) => order.side.specified(),
+            Self::MarketIfTouched(order) => order.side.specified(),
+            Self::MarketToLimit(order) => order.side.specified(),
+            Self::StopLimit(order) => order.side.specified(),
+            Self::StopMarket(order) => order.side.specified(),
+            Self::TrailingStopLimit(order) => order.side.specified(),
+            Self::TrailingStopMarket(order) => order.side.specified(),
+        }
+    }
+}
+
+impl GetOrderEvents for OrderAny {
+    fn order_events(&self) -> &[Arc<OrderEventAny>] {
+        match self {
+            Self::Limit(order) => &order.events,
+            Self::LimitIfTouched(order) => &order.events,
+            Self::Market(order) => &order.events,
+            Self::MarketIfTouched(order) => &order.events,
+            Self::MarketToLimit(order) => &order.events,
+            Self::StopLimit(order) => &order.events,
+            Self::StopMarket(order) => &order.events,
+            Self::TrailingStopLimit(order) => &order.events,
+            Self::TrailingStopMarket(order) => &order.events,
+        }
+    }
+}
+
+impl GetTradeIds for OrderAny {
+    fn trade_ids(&self) -> &[TradeId] {
+        match self {
+            Self::Limit(order) => &order.trade_ids,
+            Self::LimitIfTouched(order) => &order.trade_ids,
+            Self::Market(order) => &order.trade_ids,
+            Self::MarketIfTouched(order) => &order.trade_ids,
+            Self::MarketToLimit(order) => &order.trade_ids,
+            Self::StopLimit(order) => &order.trade_ids,
+            Self::StopMarket(order) => &order.trade_ids,
+            Self::TrailingStopLimit(order) => &order.trade_ids,
+            Self::TrailingStopMarket(order) => &order.trade_ids,
+        }
+    }
+}
+
+impl GetOrderStatus for OrderAny {
+    fn status(&self) -> OrderStatus {
+        match self {
+            Self::Limit(order) => order.status(),
+            Self::LimitIfTouched(order) => order.status(),
+            Self::Market(order) => order.status(),
+            Self::MarketIfTouched(order) => order.status(),
+            Self::MarketToLimit(order) => order.status(),
+            Self::StopLimit(order) => order.status(),
+            Self::StopMarket(order) => order.status(),
+            Self::TrailingStopLimit(order) => order.status(),
+            Self::TrailingStopMarket(order) => order.status(),
+        }
+    }
+}
+
+impl GetTimeInForce for OrderAny {
+    fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::Limit(order) => order.time_in_force(),
+            Self::LimitIfTouched(order) => order.time_in_force(),
+            Self::Market(order) => order.time_in_force(),
+            Self::MarketIfTouched(order) => order.time_in_force(),
+            Self::MarketToLimit(order) => order.time_in_force(),
+            Self::StopLimit(order) => order.time_in_force(),
+            Self::StopMarket(order) => order.time_in_force(),
+            Self::TrailingStopLimit(order) => order.time_in_force(),
+            Self::TrailingStopMarket(order) => order.time_in_force(),
         }
     }
 }
@@ -455,6 +538,14 @@ impl PassiveOrderAny {
         }
     }
 
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::Limit(order) => order.time_in_force(),
+            Self::Stop(order) => order.time_in_force(),
+        }
+    }
+
     #[must_use]
     pub fn expire_time(&self) -> Option<UnixNanos> {
         match self {
@@ -462,6 +553,30 @@ impl PassiveOrderAny {
             Self::Stop(order) => order.expire_time(),
         }
     }
+
+    #[must_use]
+    pub fn is_post_only(&self) -> bool {
+        match self {
+            Self::Limit(order) => order.is_post_only(),
+            Self::Stop(order) => order.is_post_only(),
+        }
+    }
+
+    #[must_use]
+    pub fn price(&self) -> Option<Price> {
+        match self {
+            Self::Limit(order) => Some(order.limit_px()),
+            Self::Stop(order) => order.price(),
+        }
+    }
+
+    #[must_use]
+    pub fn trigger_price(&self) -> Option<Price> {
+        match self {
+            Self::Limit(order) => order.trigger_price(),
+            Self::Stop(order) => Some(order.stop_px()),
+        }
+    }
 }
 
 impl PartialEq for PassiveOrderAny {
@@ -492,6 +607,16 @@ impl LimitOrderAny {
         }
     }
 
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::Limit(order) => order.time_in_force,
+            Self::MarketToLimit(order) => order.time_in_force,
+            Self::StopLimit(order) => order.time_in_force,
+            Self::TrailingStopLimit(order) => order.time_in_force,
+        }
+    }
+
     #[must_use]
     pub fn expire_time(&self) -> Option<UnixNanos> {
         match self {
@@ -501,6 +626,25 @@ impl LimitOrderAny {
             Self::TrailingStopLimit(order) => order.expire_time,
         }
     }
+
+    #[must_use]
+    pub fn is_post_only(&self) -> bool {
+        match self {
+            Self::Limit(order) => order.is_post_only(),
+            Self::MarketToLimit(order) => order.is_post_only(),
+            Self::StopLimit(order) => order.is_post_only(),
+            Self::TrailingStopLimit(order) => order.is_post_only(),
+        }
+    }
+
+    #[must_use]
+    pub fn trigger_price(&self) -> Option<Price> {
+        match self {
+            Self::Limit(_) | Self::MarketToLimit(_) => None,
+            Self::StopLimit(order) => Some(order.trigger_price),
+            Self::TrailingStopLimit(order) => Some(order.trigger_price),
+        }
+    }
 }
 
 impl PartialEq for LimitOrderAny {
@@ -537,6 +681,18 @@ impl StopOrderAny {
         }
     }
 
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::LimitIfTouched(order) => order.time_in_force,
+            Self::MarketIfTouched(order) => order.time_in_force,
+            Self::StopLimit(order) => order.time_in_force,
+            Self::StopMarket(order) => order.time_in_force,
+            Self::TrailingStopLimit(order) => order.time_in_force,
+            Self::TrailingStopMarket(order) => order.time_in_force,
+        }
+    }
+
     #[must_use]
     pub fn expire_time(&self) -> Option<UnixNanos> {
         match self {
@@ -548,6 +704,28 @@ impl StopOrderAny {
             Self::TrailingStopMarket(order) => order.expire_time,
         }
     }
+
+    #[must_use]
+    pub fn is_post_only(&self) -> bool {
+        match self {
+            Self::LimitIfTouched(order) => order.is_post_only(),
+            Self::MarketIfTouched(order) => order.is_post_only(),
+            Self::StopLimit(order) => order.is_post_only(),
+            Self::StopMarket(order) => order.is_post_only(),
+            Self::TrailingStopLimit(order) => order.is_post_only(),
+            Self::TrailingStopMarket(order) => order.is_post_only(),
+        }
+    }
+
+    #[must_use]
+    pub fn price(&self) -> Option<Price> {
+        match self {
+            Self::LimitIfTouched(order) => Some(order.price),
+            Self::StopLimit(order) => Some(order.price),
+            Self::TrailingStopLimit(order) => Some(order.price),
+            Self::MarketIfTouched(_) | Self::StopMarket(_) | Self::TrailingStopMarket(_) => None,
+        }
+    }
 }
 
 impl PartialEq for StopOrderAny {
@@ -563,6 +741,39 @@ impl PartialEq for StopOrderAny {
     }
 }
 
+impl From<PassiveOrderAny> for OrderAny {
+    fn from(order: PassiveOrderAny) -> Self {
+        match order {
+            PassiveOrderAny::Limit(order) => order.into(),
+            PassiveOrderAny::Stop(order) => order.into(),
+        }
+    }
+}
+
+impl From<LimitOrderAny> for OrderAny {
+    fn from(order: LimitOrderAny) -> Self {
+        match order {
+            LimitOrderAny::Limit(order) => Self::Limit(order),
+            LimitOrderAny::MarketToLimit(order) => Self::MarketToLimit(order),
+            LimitOrderAny::StopLimit(order) => Self::StopLimit(order),
+            LimitOrderAny::TrailingStopLimit(order) => Self::TrailingStopLimit(order),
+        }
+    }
+}
+
+impl From<StopOrderAny> for OrderAny {
+    fn from(order: StopOrderAny) -> Self {
+        match order {
+            StopOrderAny::LimitIfTouched(order) => Self::LimitIfTouched(order),
+            StopOrderAny::MarketIfTouched(order) => Self::MarketIfTouched(order),
+            StopOrderAny::StopLimit(order) => Self::StopLimit(order),
+            StopOrderAny::StopMarket(order) => Self::StopMarket(order),
+            StopOrderAny::TrailingStopLimit(order) => Self::TrailingStopLimit(order),
+            StopOrderAny::TrailingStopMarket(order) => Self::TrailingStopMarket(order),
+        }
+    }
+}
+
 impl GetClientOrderId for PassiveOrderAny {
     fn client_order_id(&self) -> ClientOrderId {
         match self {
@@ -595,10 +806,10 @@ impl GetClientOrderId for LimitOrderAny {
 impl GetOrderSideSpecified for LimitOrderAny {
     fn order_side_specified(&self) -> OrderSideSpecified {
         match self {
-            Self::Limit(order) => order.side.as_specified(),
-            Self::MarketToLimit(order) => order.side.as_specified(),
-            Self::StopLimit(order) => order.side.as_specified(),
-            Self::TrailingStopLimit(order) => order.side.as_specified(),
+            Self::Limit(order) => order.side.specified(),
+            Self::MarketToLimit(order) => order.side.specified(),
+            Self::StopLimit(order) => order.side.specified(),
+            Self::TrailingStopLimit(order) => order.side.specified(),
         }
     }
 }
@@ -630,12 +841,12 @@ impl GetClientOrderId for StopOrderAny {
 impl GetOrderSideSpecified for StopOrderAny {
     fn order_side_specified(&self) -> OrderSideSpecified {
         match self {
-            Self::LimitIfTouched(order) => order.side.as_specified(),
-            Self::MarketIfTouched(order) => order.side.as_specified(),
-            Self::StopLimit(order) => order.side.as_specified(),
-            Self::StopMarket(order) => order.side.as_specified(),
-            Self::TrailingStopLimit(order) => order.side.as_specified(),
-            Self::TrailingStopMarket(order) => order.side.as_specified(),
+            Self::LimitIfTouched(order) => order.side.specified(),
+            Self::MarketIfTouched(order) => order.side.specified(),
+            Self::StopLimit(order) => order.side.specified(),
+            Self::StopMarket(order) => order.side.specified(),
+            Self::TrailingStopLimit(order) => order.side.specified(),
+            Self::TrailingStopMarket(order) => order.side.specified(),
         }
     }
 }
@@ -652,3 +863,102 @@ impl GetStopPrice for StopOrderAny {
         }
     }
 }
+
+impl GetOrderQuantity for PassiveOrderAny {
+    fn quantity(&self) -> Quantity {
+        match self {
+            Self::Limit(order) => order.quantity(),
+            Self::Stop(order) => order.quantity(),
+        }
+    }
+}
+
+impl GetOrderFilledQty for PassiveOrderAny {
+    fn filled_qty(&self) -> Quantity {
+        match self {
+            Self::Limit(order) => order.filled_qty(),
+            Self::Stop(order) => order.filled_qty(),
+        }
+    }
+}
+
+impl ApplyOrderEventAny for PassiveOrderAny {
+    fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
+        match self {
+            Self::Limit(order) => order.apply(event),
+            Self::Stop(order) => order.apply(event),
+        }
+    }
+}
+
+impl GetOrderQuantity for LimitOrderAny {
+    fn quantity(&self) -> Quantity {
+        match self {
+            Self::Limit(order) => order.quantity,
+            Self::MarketToLimit(order) => order.quantity,
+            Self::StopLimit(order) => order.quantity,
+            Self::TrailingStopLimit(order) => order.quantity,
+        }
+    }
+}
+
+impl GetOrderFilledQty for LimitOrderAny {
+    fn filled_qty(&self) -> Quantity {
+        match self {
+            Self::Limit(order) => order.filled_qty,
+            Self::MarketToLimit(order) => order.filled_qty,
+            Self::StopLimit(order) => order.filled_qty,
+            Self::TrailingStopLimit(order) => order.filled_qty,
+        }
+    }
+}
+
+impl ApplyOrderEventAny for LimitOrderAny {
+    fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
+        match self {
+            Self::Limit(order) => order.apply(event),
+            Self::MarketToLimit(order) => order.apply(event),
+            Self::StopLimit(order) => order.apply(event),
+            Self::TrailingStopLimit(order) => order.apply(event),
+        }
+    }
+}
+
+impl GetOrderQuantity for StopOrderAny {
+    fn quantity(&self) -> Quantity {
+        match self {
+            Self::LimitIfTouched(order) => order.quantity,
+            Self::MarketIfTouched(order) => order.quantity,
+            Self::StopLimit(order) => order.quantity,
+            Self::StopMarket(order) => order.quantity,
+            Self::TrailingStopLimit(order) => order.quantity,
+            Self::TrailingStopMarket(order) => order.quantity,
+        }
+    }
+}
+
+impl GetOrderFilledQty for StopOrderAny {
+    fn filled_qty(&self) -> Quantity {
+        match self {
+            Self::LimitIfTouched(order) => order.filled_qty,
+            Self::MarketIfTouched(order) => order.filled_qty,
+            Self::StopLimit(order) => order.filled_qty,
+            Self::StopMarket(order) => order.filled_qty,
+            Self::TrailingStopLimit(order) => order.filled_qty,
+            Self::TrailingStopMarket(order) => order.filled_qty,
+        }
+    }
+}
+
+impl ApplyOrderEventAny for StopOrderAny {
+    fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
+        match self {
+            Self::LimitIfTouched(order) => order.apply(event),
+            Self::MarketIfTouched(order) => order.apply(event),
+            Self::StopLimit(order) => order.apply(event),
+            Self::StopMarket(order) => order.apply(event),
+            Self::TrailingStopLimit(order) => order.apply(event),
+            Self::TrailingStopMarket(order) => order.apply(event),
+        }
+    }
+}
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use rust_decimal::Decimal;
@@ -27,12 +27,23 @@ use crate::{
         TimeInForce, TrailingOffsetType, TriggerType,
     },
     events::order::{
-        accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, canceled::OrderCanceled,
-        denied::OrderDenied, emulated::OrderEmulated, event::OrderEventAny, expired::OrderExpired,
-        filled::OrderFilled, initialized::OrderInitialized, modify_rejected::OrderModifyRejected,
-        pending_cancel::OrderPendingCancel, pending_update::OrderPendingUpdate,
-        rejected::OrderRejected, released::OrderReleased, submitted::OrderSubmitted,
-        triggered::OrderTriggered, updated::OrderUpdated,
+        accepted::OrderAccepted,
+        cancel_rejected::OrderCancelRejected,
+        canceled::OrderCanceled,
+        denied::OrderDenied,
+        emulated::OrderEmulated,
+        event::OrderEventAny,
+        expired::OrderExpired,
+        filled::OrderFilled,
+        initialized::{OrderInitialized, OrderInitializedExt},
+        modify_rejected::OrderModifyRejected,
+        pending_cancel::OrderPendingCancel,
+        pending_update::OrderPendingUpdate,
+        rejected::OrderRejected,
+        released::OrderReleased,
+        submitted::OrderSubmitted,
+        triggered::OrderTriggered,
+        updated::OrderUpdated,
     },
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
@@ -77,6 +88,74 @@ pub enum OrderError {
     AlreadyInitialized,
     #[error("Order had no previous state")]
     NoPreviousState,
+    #[error("Condition failed: `expire_time` is required for `GTD` order")]
+    GtdWithoutExpireTime,
+    #[error("Order `expire_time` {expire_time} is not after the current time {now}")]
+    ExpireTimeInPast {
+        expire_time: UnixNanos,
+        now: UnixNanos,
+    },
+    #[error(
+        "Order `expire_time` {expire_time} is less than the minimum lifetime of {min_lifetime_ns}ns from the current time {now}"
+    )]
+    ExpireTimeTooSoon {
+        expire_time: UnixNanos,
+        now: UnixNanos,
+        min_lifetime_ns: u64,
+    },
+    #[error("Invalid fill: `last_qty` was not positive, was {0}")]
+    FillQuantityNotPositive(Quantity),
+    #[error("Invalid fill: `last_px` was not positive, was {0}")]
+    FillPriceNotPositive(Price),
+    #[error("Invalid fill: `liquidity_side` was `NO_LIQUIDITY_SIDE`")]
+    FillNoLiquiditySide,
+    #[error("Unsupported trailing offset type {0} (requires instrument data)")]
+    UnsupportedTrailingOffsetType(TrailingOffsetType),
+    #[error("Invalid trailing trigger price {0}")]
+    InvalidTrailingPrice(f64),
+    #[error("Invalid fill: `last_qty` {last_qty} exceeded `leaves_qty` {leaves_qty}")]
+    Overfill {
+        last_qty: Quantity,
+        leaves_qty: Quantity,
+    },
+    #[error(
+        "Invalid fill: commission currency {commission} did not match fill currency {currency}"
+    )]
+    FillCommissionCurrencyMismatch {
+        commission: Currency,
+        currency: Currency,
+    },
+}
+
+/// Validates the `expire_time` of an order with the given `time_in_force` against `now`.
+///
+/// Only `GTD` orders are checked: they require an `expire_time` which must be strictly
+/// greater than `now` plus `min_lifetime_ns`. The caller supplies `now` from its own
+/// clock, so the check is deterministic for backtests and uses the same rules at the
+/// strategy, execution and simulated venue levels.
+pub fn check_expire_time(
+    time_in_force: TimeInForce,
+    expire_time: Option<UnixNanos>,
+    now: UnixNanos,
+    min_lifetime_ns: u64,
+) -> Result<(), OrderError> {
+    if time_in_force != TimeInForce::Gtd {
+        return Ok(());
+    }
+
+    let expire_time = expire_time.ok_or(OrderError::GtdWithoutExpireTime)?;
+    if expire_time <= now {
+        return Err(OrderError::ExpireTimeInPast { expire_time, now });
+    }
+    if expire_time.as_u64() <= now.as_u64().saturating_add(min_lifetime_ns) {
+        return Err(OrderError::ExpireTimeTooSoon {
+            expire_time,
+            now,
+            min_lifetime_ns,
+        });
+    }
+
+    Ok(())
 }
 
 #[must_use]
@@ -94,8 +173,18 @@ pub fn str_hashmap_to_ustr(h: HashMap<String, String>) -> HashMap<Ustr, Ustr> {
 }
 
 impl OrderStatus {
+    /// Returns the status an order in this status moves to on the given `event`, following the
+    /// order state machine of the Python `Order` class.
+    ///
+    /// Modify and cancel rejections and updates leave a working order in its current status.
+    /// When they resolve a pending modify or cancel, the order instead reverts to its previous
+    /// status (see `next`), so these pending cases return an error here.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `event` is not valid in the current status.
     #[rustfmt::skip]
-    pub fn transition(&mut self, event: &OrderEventAny) -> Result<Self, OrderError> {
+    pub fn transition(&self, event: &OrderEventAny) -> Result<Self, OrderError> {
         let new_state = match (self, event) {
             (Self::Initialized, OrderEventAny::Denied(_)) => Self::Denied,
             (Self::Initialized, OrderEventAny::Emulated(_)) => Self::Emulated,  // Emulated orders
@@ -134,6 +223,7 @@ impl OrderStatus {
             (Self::PendingUpdate, OrderEventAny::Canceled(_)) => Self::Canceled,
             (Self::PendingUpdate, OrderEventAny::Expired(_)) => Self::Expired,
             (Self::PendingUpdate, OrderEventAny::Triggered(_)) => Self::Triggered,
+            (Self::PendingUpdate, OrderEventAny::Submitted(_)) => Self::PendingUpdate,  // Real world possibility
             (Self::PendingUpdate, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,  // Allow multiple requests
             (Self::PendingUpdate, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
             (Self::PendingUpdate, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
@@ -158,10 +248,57 @@ impl OrderStatus {
             (Self::PartiallyFilled, OrderEventAny::Expired(_)) => Self::Expired,
             (Self::PartiallyFilled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::PartiallyFilled, OrderEventAny::Filled(_)) => Self::Filled,
+            (Self::Emulated, OrderEventAny::ModifyRejected(_)) => Self::Emulated,
+            (Self::Emulated, OrderEventAny::CancelRejected(_)) => Self::Emulated,
+            (Self::Emulated, OrderEventAny::Updated(_)) => Self::Emulated,
+            (Self::Submitted, OrderEventAny::ModifyRejected(_)) => Self::Submitted,
+            (Self::Submitted, OrderEventAny::CancelRejected(_)) => Self::Submitted,
+            (Self::Submitted, OrderEventAny::Updated(_)) => Self::Submitted,
+            (Self::Accepted, OrderEventAny::ModifyRejected(_)) => Self::Accepted,
+            (Self::Accepted, OrderEventAny::CancelRejected(_)) => Self::Accepted,
+            (Self::Accepted, OrderEventAny::Updated(_)) => Self::Accepted,
+            (Self::Triggered, OrderEventAny::ModifyRejected(_)) => Self::Triggered,
+            (Self::Triggered, OrderEventAny::CancelRejected(_)) => Self::Triggered,
+            (Self::Triggered, OrderEventAny::Updated(_)) => Self::Triggered,
+            (Self::PartiallyFilled, OrderEventAny::ModifyRejected(_)) => Self::PartiallyFilled,
+            (Self::PartiallyFilled, OrderEventAny::CancelRejected(_)) => Self::PartiallyFilled,
+            (Self::PartiallyFilled, OrderEventAny::Updated(_)) => Self::PartiallyFilled,
+            (Self::PendingUpdate, OrderEventAny::CancelRejected(_)) => Self::PendingUpdate,
+            (Self::PendingCancel, OrderEventAny::ModifyRejected(_)) => Self::PendingCancel,
+            (Self::PendingCancel, OrderEventAny::Updated(_)) => Self::PendingCancel,
             _ => return Err(OrderError::InvalidStateTransition),
         };
         Ok(new_state)
     }
+
+    /// Returns the status an order in this status moves to on the given `event`, where
+    /// `previous_status` is the status the order was in before this one.
+    ///
+    /// Modify rejections and updates revert a `PendingUpdate` order to its previous status,
+    /// as do cancel rejections for a `PendingCancel` order. All other events follow `transition`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `event` is not valid in the current status.
+    /// - If a pending status is reverted without a `previous_status`.
+    pub fn next(
+        &self,
+        previous_status: Option<Self>,
+        event: &OrderEventAny,
+    ) -> Result<Self, OrderError> {
+        match event {
+            OrderEventAny::ModifyRejected(_) | OrderEventAny::Updated(_)
+                if *self == Self::PendingUpdate =>
+            {
+                previous_status.ok_or(OrderError::NoPreviousState)
+            }
+            OrderEventAny::CancelRejected(_) if *self == Self::PendingCancel => {
+                previous_status.ok_or(OrderError::NoPreviousState)
+            }
+            _ => self.transition(event),
+        }
+    }
 }
 
 pub trait Order: 'static + Send {
@@ -358,30 +495,38 @@ where
             reduce_only: order.is_reduce_only(),
             quote_quantity: order.is_quote_quantity(),
             display_qty: order.display_qty(),
-            limit_offset: order.limit_offset(),
-            trailing_offset: order.trailing_offset(),
-            trailing_offset_type: order.trailing_offset_type(),
             emulation_trigger: order.emulation_trigger(),
             trigger_instrument_id: order.trigger_instrument_id(),
-            contingency_type: order.contingency_type(),
-            order_list_id: order.order_list_id(),
-            linked_order_ids: order.linked_order_ids().map(|x| x.to_vec()),
-            parent_order_id: order.parent_order_id(),
+            linked_order_ids: order.linked_order_ids().map(Into::into),
             exec_algorithm_id: order.exec_algorithm_id(),
             exec_algorithm_params: order.exec_algorithm_params().map(|x| x.to_owned()),
             exec_spawn_id: order.exec_spawn_id(),
-            tags: order.tags().map(|x| x.to_vec()),
+            tags: order.tags().map(Into::into),
             event_id: order.init_id(),
             ts_event: order.ts_init(),
             ts_init: order.ts_init(),
             reconciliation: false,
+            ext: OrderInitializedExt {
+                limit_offset: order.limit_offset(),
+                trailing_offset: order.trailing_offset(),
+                trailing_offset_type: order.trailing_offset_type(),
+                contingency_type: order.contingency_type(),
+                order_list_id: order.order_list_id(),
+                parent_order_id: order.parent_order_id(),
+            }
+            .boxed(),
         }
     }
 }
 
+/// The shared core of all order types.
+///
+/// Events are held behind an `Arc` so that cloning an order (e.g. when handing a copy
+/// to a strategy while the cache retains the original) shares rather than duplicates
+/// the event history.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OrderCore {
-    pub events: Vec<OrderEventAny>,
+    pub events: Vec<Arc<OrderEventAny>>,
     pub commissions: HashMap<Currency, Money>,
     pub venue_order_ids: Vec<VenueOrderId>,
     pub trade_ids: Vec<TradeId>,
@@ -422,7 +567,8 @@ pub struct OrderCore {
 
 impl OrderCore {
     pub fn new(init: OrderInitialized) -> anyhow::Result<Self> {
-        let events: Vec<OrderEventAny> = vec![OrderEventAny::Initialized(init.clone())];
+        let events = vec![Arc::new(OrderEventAny::Initialized(init.clone()))];
+        let ext = *init.ext();
         Ok(Self {
             events,
             commissions: HashMap::new(),
@@ -446,16 +592,16 @@ impl OrderCore {
             is_reduce_only: init.reduce_only,
             is_quote_quantity: init.quote_quantity,
             emulation_trigger: init.emulation_trigger.or(Some(TriggerType::NoTrigger)),
-            contingency_type: init
+            contingency_type: ext
                 .contingency_type
                 .or(Some(ContingencyType::NoContingency)),
-            order_list_id: init.order_list_id,
-            linked_order_ids: init.linked_order_ids,
-            parent_order_id: init.parent_order_id,
+            order_list_id: ext.order_list_id,
+            linked_order_ids: init.linked_order_ids.map(Vec::from),
+            parent_order_id: ext.parent_order_id,
             exec_algorithm_id: init.exec_algorithm_id,
             exec_algorithm_params: init.exec_algorithm_params,
             exec_spawn_id: init.exec_spawn_id,
-            tags: init.tags,
+            tags: init.tags.map(Vec::from),
             filled_qty: Quantity::zero(init.quantity.precision),
             leaves_qty: init.quantity,
             avg_px: None,
@@ -466,11 +612,50 @@ impl OrderCore {
         })
     }
 
+    /// Returns whether the given `event` duplicates an event already applied to the order.
+    ///
+    /// Fills are keyed strictly on `trade_id`, any other event on its `event_id`.
+    #[must_use]
+    pub fn is_duplicate_event(&self, event: &OrderEventAny) -> bool {
+        match event {
+            OrderEventAny::Initialized(_) => false,
+            OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => {
+                self.trade_ids.contains(&fill.trade_id)
+            }
+            _ => {
+                let event_id = event.event_id();
+                self.events
+                    .iter()
+                    .any(|applied| applied.event_id() == event_id)
+            }
+        }
+    }
+
+    /// Applies the `event` to the order, transitioning its status and updating its state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error (leaving the order unchanged):
+    /// - If the event is not a valid transition from the current status.
+    /// - If the event is a fill for more than the `leaves_qty`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the event is for a different order or strategy.
     pub fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
         assert_eq!(self.client_order_id, event.client_order_id());
         assert_eq!(self.strategy_id, event.strategy_id());
 
-        let new_status = self.status.transition(&event)?;
+        if let OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) = &event {
+            if fill.last_qty > self.leaves_qty {
+                return Err(OrderError::Overfill {
+                    last_qty: fill.last_qty,
+                    leaves_qty: self.leaves_qty,
+                });
+            }
+        }
+
+        let new_status = self.status.next(self.previous_status, &event)?;
         self.previous_status = Some(self.status);
         self.status = new_status;
 
@@ -495,7 +680,7 @@ impl OrderCore {
         }
 
         self.ts_last = event.ts_event();
-        self.events.push(event);
+        self.events.push(Arc::new(event));
         Ok(())
     }
 
@@ -531,16 +716,12 @@ impl OrderCore {
         // Do nothing else
     }
 
-    fn modify_rejected(&mut self, _event: &OrderModifyRejected) {
-        self.status = self
-            .previous_status
-            .unwrap_or_else(|| panic!("{}", OrderError::NoPreviousState));
+    fn modify_rejected(&self, _event: &OrderModifyRejected) {
+        // Do nothing else
     }
 
-    fn cancel_rejected(&mut self, _event: &OrderCancelRejected) {
-        self.status = self
-            .previous_status
-            .unwrap_or_else(|| panic!("{}", OrderError::NoPreviousState));
+    fn cancel_rejected(&self, _event: &OrderCancelRejected) {
+        // Do nothing else
     }
 
     fn triggered(&mut self, _event: &OrderTriggered) {}
@@ -566,10 +747,11 @@ impl OrderCore {
         self.trade_ids.push(event.trade_id);
         self.last_trade_id = Some(event.trade_id);
         self.liquidity_side = Some(event.liquidity_side);
+        // Average over the previously filled quantity, before it includes this fill
+        self.set_avg_px(event.last_qty, event.last_px);
         self.filled_qty += event.last_qty;
         self.leaves_qty -= event.last_qty;
         self.ts_last = event.ts_event;
-        self.set_avg_px(event.last_qty, event.last_px);
     }
 
     fn set_avg_px(&mut self, last_qty: Quantity, last_px: Price) {
@@ -654,7 +836,7 @@ impl OrderCore {
 
     #[must_use]
     pub fn init_event(&self) -> Option<OrderEventAny> {
-        self.events.first().cloned()
+        self.events.first().map(|event| event.as_ref().clone())
     }
 }
 
@@ -663,15 +845,31 @@ impl OrderCore {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::time::AtomicTime;
     use rstest::rstest;
     use rust_decimal_macros::dec;
+    use strum::IntoEnumIterator;
 
     use super::*;
     use crate::{
         enums::{OrderSide, OrderStatus, PositionSide},
         events::order::{
-            accepted::OrderAcceptedBuilder, denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
-            initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            accepted::{OrderAccepted, OrderAcceptedBuilder},
+            cancel_rejected::OrderCancelRejected,
+            canceled::OrderCanceled,
+            denied::{OrderDenied, OrderDeniedBuilder},
+            emulated::OrderEmulated,
+            expired::OrderExpired,
+            filled::{OrderFilled, OrderFilledBuilder},
+            initialized::{OrderInitialized, OrderInitializedBuilder},
+            modify_rejected::{OrderModifyRejected, OrderModifyRejectedBuilder},
+            pending_cancel::OrderPendingCancel,
+            pending_update::{OrderPendingUpdate, OrderPendingUpdateBuilder},
+            rejected::OrderRejected,
+            released::OrderReleased,
+            submitted::{OrderSubmitted, OrderSubmittedBuilder},
+            triggered::OrderTriggered,
+            updated::{OrderUpdated, OrderUpdatedBuilder},
         },
         orders::market::MarketOrder,
     };
@@ -748,6 +946,193 @@ mod tests {
         );
     }
 
+    #[rustfmt::skip]
+    const LEGAL_TRANSITIONS: &[(OrderStatus, &str, OrderStatus)] = &[
+        (OrderStatus::Initialized, "Denied", OrderStatus::Denied),
+        (OrderStatus::Initialized, "Emulated", OrderStatus::Emulated),
+        (OrderStatus::Initialized, "Released", OrderStatus::Released),
+        (OrderStatus::Initialized, "Submitted", OrderStatus::Submitted),
+        (OrderStatus::Initialized, "Rejected", OrderStatus::Rejected),
+        (OrderStatus::Initialized, "Accepted", OrderStatus::Accepted),
+        (OrderStatus::Initialized, "Canceled", OrderStatus::Canceled),
+        (OrderStatus::Initialized, "Expired", OrderStatus::Expired),
+        (OrderStatus::Initialized, "Triggered", OrderStatus::Triggered),
+        (OrderStatus::Emulated, "Canceled", OrderStatus::Canceled),
+        (OrderStatus::Emulated, "Expired", OrderStatus::Expired),
+        (OrderStatus::Emulated, "Released", OrderStatus::Released),
+        (OrderStatus::Released, "Denied", OrderStatus::Denied),
+        (OrderStatus::Released, "Submitted", OrderStatus::Submitted),
+        (OrderStatus::Released, "Canceled", OrderStatus::Canceled),
+        (OrderStatus::Submitted, "PendingUpdate", OrderStatus::PendingUpdate),
+        (OrderStatus::Submitted, "PendingCancel", OrderStatus::PendingCancel),
+        (OrderStatus::Submitted, "Rejected", OrderStatus::Rejected),
+        (OrderStatus::Submitted, "Canceled", OrderStatus::Canceled),
+        (OrderStatus::Submitted, "Accepted", OrderStatus::Accepted),
+        (OrderStatus::Submitted, "PartiallyFilled", OrderStatus::PartiallyFilled),
+        (OrderStatus::Submitted, "Filled", OrderStatus::Filled),
+        (OrderStatus::Accepted, "Rejected", OrderStatus::Rejected),
+        (OrderStatus::Accepted, "PendingUpdate", OrderStatus::PendingUpdate),
+        (OrderStatus::Accepted, "PendingCancel", OrderStatus::PendingCancel),
+        (OrderStatus::Accepted, "Canceled", OrderStatus::Canceled),
+        (OrderStatus::Accepted, "Triggered", OrderStatus::Triggered),
+        (OrderStatus::Accepted, "Expired", OrderStatus::Expired),
+        (OrderStatus::Accepted, "PartiallyFilled", OrderStatus::PartiallyFilled),
+        (OrderStatus::Accepted, "Filled", OrderStatus::Filled),
+        (OrderStatus::Canceled, "PartiallyFilled", OrderStatus::PartiallyFilled),
+        (OrderStatus::Canceled, "Filled", OrderStatus::Filled),
+        (OrderStatus::PendingUpdate, "Rejected", OrderStatus::Rejected),
+        (OrderStatus::PendingUpdate, "Accepted", OrderStatus::Accepted),
+        (OrderStatus::PendingUpdate, "Canceled", OrderStatus::Canceled),
+        (OrderStatus::PendingUpdate, "Expired", OrderStatus::Expired),
+        (OrderStatus::PendingUpdate, "Triggered", OrderStatus::Triggered),
+        (OrderStatus::PendingUpdate, "Submitted", OrderStatus::PendingUpdate),
+        (OrderStatus::PendingUpdate, "PendingUpdate", OrderStatus::PendingUpdate),
+        (OrderStatus::PendingUpdate, "PendingCancel", OrderStatus::PendingCancel),
+        (OrderStatus::PendingUpdate, "PartiallyFilled", OrderStatus::PartiallyFilled),
+        (OrderStatus::PendingUpdate, "Filled", OrderStatus::Filled),
+        (OrderStatus::PendingCancel, "Rejected", OrderStatus::Rejected),
+        (OrderStatus::PendingCancel, "PendingCancel", OrderStatus::PendingCancel),
+        (OrderStatus::PendingCancel, "Canceled", OrderStatus::Canceled),
+        (OrderStatus::PendingCancel, "Expired", OrderStatus::Expired),
+        (OrderStatus::PendingCancel, "Accepted", OrderStatus::Accepted),
+        (OrderStatus::PendingCancel, "PartiallyFilled", OrderStatus::PartiallyFilled),
+        (OrderStatus::PendingCancel, "Filled", OrderStatus::Filled),
+        (OrderStatus::Triggered, "Rejected", OrderStatus::Rejected),
+        (OrderStatus::Triggered, "PendingUpdate", OrderStatus::PendingUpdate),
+        (OrderStatus::Triggered, "PendingCancel", OrderStatus::PendingCancel),
+        (OrderStatus::Triggered, "Canceled", OrderStatus::Canceled),
+        (OrderStatus::Triggered, "Expired", OrderStatus::Expired),
+        (OrderStatus::Triggered, "PartiallyFilled", OrderStatus::PartiallyFilled),
+        (OrderStatus::Triggered, "Filled", OrderStatus::Filled),
+        (OrderStatus::PartiallyFilled, "PendingUpdate", OrderStatus::PendingUpdate),
+        (OrderStatus::PartiallyFilled, "PendingCancel", OrderStatus::PendingCancel),
+        (OrderStatus::PartiallyFilled, "Canceled", OrderStatus::Canceled),
+        (OrderStatus::PartiallyFilled, "Expired", OrderStatus::Expired),
+        (OrderStatus::PartiallyFilled, "PartiallyFilled", OrderStatus::PartiallyFilled),
+        (OrderStatus::PartiallyFilled, "Filled", OrderStatus::Filled),
+        (OrderStatus::Emulated, "ModifyRejected", OrderStatus::Emulated),
+        (OrderStatus::Emulated, "CancelRejected", OrderStatus::Emulated),
+        (OrderStatus::Emulated, "Updated", OrderStatus::Emulated),
+        (OrderStatus::Submitted, "ModifyRejected", OrderStatus::Submitted),
+        (OrderStatus::Submitted, "CancelRejected", OrderStatus::Submitted),
+        (OrderStatus::Submitted, "Updated", OrderStatus::Submitted),
+        (OrderStatus::Accepted, "ModifyRejected", OrderStatus::Accepted),
+        (OrderStatus::Accepted, "CancelRejected", OrderStatus::Accepted),
+        (OrderStatus::Accepted, "Updated", OrderStatus::Accepted),
+        (OrderStatus::Triggered, "ModifyRejected", OrderStatus::Triggered),
+        (OrderStatus::Triggered, "CancelRejected", OrderStatus::Triggered),
+        (OrderStatus::Triggered, "Updated", OrderStatus::Triggered),
+        (OrderStatus::PartiallyFilled, "ModifyRejected", OrderStatus::PartiallyFilled),
+        (OrderStatus::PartiallyFilled, "CancelRejected", OrderStatus::PartiallyFilled),
+        (OrderStatus::PartiallyFilled, "Updated", OrderStatus::PartiallyFilled),
+        (OrderStatus::PendingUpdate, "CancelRejected", OrderStatus::PendingUpdate),
+        (OrderStatus::PendingCancel, "ModifyRejected", OrderStatus::PendingCancel),
+        (OrderStatus::PendingCancel, "Updated", OrderStatus::PendingCancel),
+    ];
+
+    fn all_events() -> Vec<OrderEventAny> {
+        vec![
+            OrderEventAny::Initialized(OrderInitialized::default()),
+            OrderEventAny::Denied(OrderDenied::default()),
+            OrderEventAny::Emulated(OrderEmulated::default()),
+            OrderEventAny::Released(OrderReleased::default()),
+            OrderEventAny::Submitted(OrderSubmitted::default()),
+            OrderEventAny::Accepted(OrderAccepted::default()),
+            OrderEventAny::Rejected(OrderRejected::default()),
+            OrderEventAny::Canceled(OrderCanceled::default()),
+            OrderEventAny::Expired(OrderExpired::default()),
+            OrderEventAny::Triggered(OrderTriggered::default()),
+            OrderEventAny::PendingUpdate(OrderPendingUpdate::default()),
+            OrderEventAny::PendingCancel(OrderPendingCancel::default()),
+            OrderEventAny::ModifyRejected(OrderModifyRejected::default()),
+            OrderEventAny::CancelRejected(OrderCancelRejected::default()),
+            OrderEventAny::Updated(OrderUpdated::default()),
+            OrderEventAny::PartiallyFilled(OrderFilled::default()),
+            OrderEventAny::Filled(OrderFilled::default()),
+        ]
+    }
+
+    fn event(kind: &str) -> OrderEventAny {
+        all_events()
+            .into_iter()
+            .find(|event| event.to_string() == kind)
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_order_status_transitions_match_state_table() {
+        for status in OrderStatus::iter() {
+            for event in all_events() {
+                let kind = event.to_string();
+                let expected = LEGAL_TRANSITIONS
+                    .iter()
+                    .find(|(from, on, _)| *from == status && *on == kind)
+                    .map(|(_, _, to)| *to);
+
+                let result = status.transition(&event);
+
+                match expected {
+                    Some(to) => assert_eq!(result.ok(), Some(to), "{status} on {kind}"),
+                    None => assert!(
+                        matches!(result, Err(OrderError::InvalidStateTransition)),
+                        "{status} on {kind} should be invalid"
+                    ),
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(OrderStatus::Initialized, "Filled")]
+    #[case(OrderStatus::Submitted, "Triggered")]
+    #[case(OrderStatus::Accepted, "Submitted")]
+    #[case(OrderStatus::PendingCancel, "PendingUpdate")]
+    #[case(OrderStatus::PendingCancel, "Triggered")]
+    #[case(OrderStatus::Filled, "Canceled")]
+    #[case(OrderStatus::Filled, "PartiallyFilled")]
+    #[case(OrderStatus::Canceled, "Accepted")]
+    #[case(OrderStatus::Rejected, "Accepted")]
+    #[case(OrderStatus::Denied, "Submitted")]
+    #[case(OrderStatus::Expired, "Canceled")]
+    fn test_order_status_illegal_transition(#[case] status: OrderStatus, #[case] kind: &str) {
+        assert!(status.transition(&event(kind)).is_err());
+    }
+
+    #[rstest]
+    #[case(&["Submitted", "Accepted", "PendingUpdate", "PendingCancel", "Canceled"], OrderStatus::Canceled)]
+    #[case(&["Submitted", "PendingUpdate", "Submitted", "Accepted"], OrderStatus::Accepted)]
+    #[case(&["Submitted", "Accepted", "PendingCancel", "PendingCancel", "Accepted"], OrderStatus::Accepted)]
+    #[case(&["Submitted", "Accepted", "PendingUpdate", "PartiallyFilled", "PendingCancel", "Filled"], OrderStatus::Filled)]
+    #[case(&["Submitted", "Accepted", "PendingCancel", "Canceled", "Filled"], OrderStatus::Filled)]
+    fn test_order_status_pending_interleavings(
+        #[case] kinds: &[&str],
+        #[case] expected: OrderStatus,
+    ) {
+        let mut status = OrderStatus::Initialized;
+        for kind in kinds {
+            status = status.transition(&event(kind)).unwrap();
+        }
+
+        assert_eq!(status, expected);
+    }
+
+    #[rstest]
+    fn test_order_status_pending_cancel_then_pending_update_is_illegal() {
+        let status = [
+            event("Submitted"),
+            event("Accepted"),
+            event("PendingCancel"),
+        ]
+        .iter()
+        .try_fold(OrderStatus::Initialized, |status, event| {
+            status.transition(event)
+        })
+        .unwrap();
+
+        assert_eq!(status, OrderStatus::PendingCancel);
+        assert!(status.transition(&event("PendingUpdate")).is_err());
+    }
+
     #[rstest]
     fn test_order_state_transition_denied() {
         let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
@@ -785,4 +1170,219 @@ mod tests {
         assert_eq!(order.commission(&Currency::USD()), None);
         assert_eq!(order.commissions(), HashMap::new());
     }
+
+    #[rstest]
+    fn test_order_life_cycle_with_partial_fill() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let partially_filled = OrderFilledBuilder::default()
+            .trade_id(TradeId::from("1"))
+            .last_qty(Quantity::from(25_000))
+            .last_px(Price::from("1.00000"))
+            .build()
+            .unwrap();
+        let filled = OrderFilledBuilder::default()
+            .trade_id(TradeId::from("2"))
+            .last_qty(Quantity::from(75_000))
+            .last_px(Price::from("2.00000"))
+            .build()
+            .unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order
+            .apply(OrderEventAny::PartiallyFilled(partially_filled))
+            .unwrap();
+
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.filled_qty(), Quantity::from(25_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(75_000));
+        assert_eq!(order.avg_px(), Some(1.0));
+        assert!(order.is_open());
+
+        order.apply(OrderEventAny::Filled(filled)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.filled_qty(), Quantity::from(100_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(0));
+        assert_eq!(order.avg_px(), Some(1.75));
+        assert_eq!(
+            order.trade_ids(),
+            vec![&TradeId::from("1"), &TradeId::from("2")]
+        );
+        assert_eq!(order.event_count(), 5);
+        assert_eq!(order.last_event(), &OrderEventAny::Filled(filled));
+        assert!(order.is_closed());
+    }
+
+    #[rstest]
+    fn test_order_overfill_rejected() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let partially_filled = OrderFilledBuilder::default()
+            .last_qty(Quantity::from(60_000))
+            .build()
+            .unwrap();
+        let overfill = OrderFilledBuilder::default()
+            .last_qty(Quantity::from(50_000))
+            .build()
+            .unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order
+            .apply(OrderEventAny::PartiallyFilled(partially_filled))
+            .unwrap();
+        let result = order.apply(OrderEventAny::Filled(overfill));
+
+        assert!(matches!(
+            result,
+            Err(OrderError::Overfill { last_qty, leaves_qty })
+                if last_qty == Quantity::from(50_000) && leaves_qty == Quantity::from(40_000)
+        ));
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.filled_qty(), Quantity::from(60_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(40_000));
+        assert_eq!(order.event_count(), 4);
+    }
+
+    #[rstest]
+    fn test_modify_rejected_for_working_order_keeps_status() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let pending_update = OrderPendingUpdateBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default().build().unwrap();
+        let modify_rejected = OrderModifyRejectedBuilder::default().build().unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order
+            .apply(OrderEventAny::PendingUpdate(pending_update))
+            .unwrap();
+        order.apply(OrderEventAny::Updated(updated)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+
+        order
+            .apply(OrderEventAny::ModifyRejected(modify_rejected))
+            .unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.event_count(), 6);
+    }
+
+    #[rstest]
+    fn test_updated_for_filled_order_is_invalid() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let filled = OrderFilledBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default().build().unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order.apply(OrderEventAny::Filled(filled)).unwrap();
+        let result = order.apply(OrderEventAny::Updated(updated));
+
+        assert!(matches!(result, Err(OrderError::InvalidStateTransition)));
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.quantity, Quantity::from(100_000));
+        assert_eq!(order.event_count(), 4);
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Gtc, None)]
+    #[case(TimeInForce::Day, Some(UnixNanos::from(1)))]
+    #[case(TimeInForce::Ioc, None)]
+    fn test_check_expire_time_ignored_when_not_gtd(
+        #[case] time_in_force: TimeInForce,
+        #[case] expire_time: Option<UnixNanos>,
+    ) {
+        let now = UnixNanos::from(2_000_000_000);
+        assert!(check_expire_time(time_in_force, expire_time, now, 1_000_000_000).is_ok());
+    }
+
+    #[rstest]
+    fn test_check_expire_time_gtd_without_expire_time() {
+        let result = check_expire_time(TimeInForce::Gtd, None, UnixNanos::default(), 0);
+        assert!(matches!(result, Err(OrderError::GtdWithoutExpireTime)));
+    }
+
+    #[rstest]
+    #[case(999_999_999)] // Before now
+    #[case(1_000_000_000)] // Exactly now
+    fn test_check_expire_time_in_past(#[case] expire_time: u64) {
+        let now = UnixNanos::from(1_000_000_000);
+        let result = check_expire_time(TimeInForce::Gtd, Some(expire_time.into()), now, 0);
+        assert!(matches!(result, Err(OrderError::ExpireTimeInPast { .. })));
+    }
+
+    #[rstest]
+    #[case(1_000_000_001)] // Just after now
+    #[case(2_000_000_000)] // Exactly now plus minimum lifetime
+    fn test_check_expire_time_too_soon(#[case] expire_time: u64) {
+        let now = UnixNanos::from(1_000_000_000);
+        let result = check_expire_time(
+            TimeInForce::Gtd,
+            Some(expire_time.into()),
+            now,
+            1_000_000_000,
+        );
+        assert!(matches!(
+            result,
+            Err(OrderError::ExpireTimeTooSoon {
+                min_lifetime_ns: 1_000_000_000,
+                ..
+            })
+        ));
+    }
+
+    #[rstest]
+    fn test_check_expire_time_just_beyond_minimum_lifetime() {
+        let now = UnixNanos::from(1_000_000_000);
+        let expire_time = UnixNanos::from(2_000_000_001);
+        assert!(check_expire_time(TimeInForce::Gtd, Some(expire_time), now, 1_000_000_000).is_ok());
+    }
+
+    #[rstest]
+    fn test_check_expire_time_with_minimum_lifetime_overflowing() {
+        let now = UnixNanos::from(u64::MAX - 1);
+        let result = check_expire_time(
+            TimeInForce::Gtd,
+            Some(UnixNanos::from(u64::MAX)),
+            now,
+            1_000_000_000,
+        );
+        assert!(matches!(result, Err(OrderError::ExpireTimeTooSoon { .. })));
+    }
+
+    #[rstest]
+    fn test_check_expire_time_with_backdated_clock() {
+        let clock = AtomicTime::new(false, UnixNanos::from(5_000_000_000));
+        let expire_time = UnixNanos::from(4_000_000_000);
+        assert!(check_expire_time(
+            TimeInForce::Gtd,
+            Some(expire_time),
+            clock.get_time_ns(),
+            1_000_000_000
+        )
+        .is_err());
+
+        // Backdating the clock makes the same expire time valid, independent of wall-clock time
+        clock.set_time(UnixNanos::from(1_000_000_000));
+        assert!(check_expire_time(
+            TimeInForce::Gtd,
+            Some(expire_time),
+            clock.get_time_ns(),
+            1_000_000_000
+        )
+        .is_ok());
+    }
 }
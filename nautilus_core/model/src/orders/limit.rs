@@ -25,7 +25,7 @@ use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore},
+    base::{check_expire_time, Order, OrderCore},
 };
 use crate::{
     enums::{
@@ -90,16 +90,7 @@ impl LimitOrder {
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
         check_quantity_positive(quantity)?;
-        if time_in_force == TimeInForce::Gtd {
-            if expire_time.is_none() {
-                anyhow::bail!("Condition failed: `expire_time` is required for `GTD` order")
-            }
-            if let Some(time) = expire_time {
-                if time == 0 {
-                    anyhow::bail!("`expire_time` for `GTD` Limit order should be higher then 0")
-                }
-            }
-        }
+        check_expire_time(time_in_force, expire_time, ts_init, 0)?;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -350,7 +341,7 @@ impl Order for LimitOrder {
     }
 
     fn events(&self) -> Vec<&OrderEventAny> {
-        self.events.iter().collect()
+        self.events.iter().map(AsRef::as_ref).collect()
     }
 
     fn venue_order_ids(&self) -> Vec<&VenueOrderId> {
@@ -362,13 +353,18 @@ impl Order for LimitOrder {
     }
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        if let OrderEventAny::Updated(ref event) = event {
-            self.update(event);
+        let updated = match &event {
+            OrderEventAny::Updated(updated) => Some(*updated),
+            _ => None,
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
 
         self.core.apply(event)?;
 
+        if let Some(updated) = updated {
+            self.update(&updated);
+        }
+
         if is_order_filled {
             self.core.set_slippage(self.price);
         };
@@ -433,6 +429,7 @@ impl Display for LimitOrder {
 
 impl From<OrderInitialized> for LimitOrder {
     fn from(event: OrderInitialized) -> Self {
+        let ext = *event.ext();
         Self::new(
             event.trader_id,
             event.strategy_id,
@@ -451,14 +448,14 @@ impl From<OrderInitialized> for LimitOrder {
             event.display_qty,
             event.emulation_trigger,
             event.trigger_instrument_id,
-            event.contingency_type,
-            event.order_list_id,
-            event.linked_order_ids,
-            event.parent_order_id,
+            ext.contingency_type,
+            ext.order_list_id,
+            event.linked_order_ids.map(Vec::from),
+            ext.parent_order_id,
             event.exec_algorithm_id,
             event.exec_algorithm_params,
             event.exec_spawn_id,
-            event.tags,
+            event.tags.map(Vec::from),
             event.event_id,
             event.ts_event,
         )
@@ -471,6 +468,7 @@ impl From<OrderInitialized> for LimitOrder {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::nanos::UnixNanos;
     use rstest::rstest;
 
     use crate::{
@@ -524,4 +522,38 @@ mod tests {
             Some(TimeInForce::Gtd),
         );
     }
+
+    #[rstest]
+    #[case(999)]
+    #[case(1_000)]
+    fn test_gtd_expire_time_not_after_ts_init(audusd_sim: CurrencyPair, #[case] expire_time: u64) {
+        let result = TestOrderStubs::limit_order_gtd(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("0.8"),
+            Quantity::from(1),
+            Some(UnixNanos::from(expire_time)),
+            UnixNanos::from(1_000),
+        );
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("Order `expire_time` {expire_time} is not after the current time 1000")
+        );
+    }
+
+    #[rstest]
+    fn test_gtd_expire_time_after_ts_init(audusd_sim: CurrencyPair) {
+        let order = TestOrderStubs::limit_order_gtd(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("0.8"),
+            Quantity::from(1),
+            Some(UnixNanos::from(1_001)),
+            UnixNanos::from(1_000),
+        )
+        .unwrap();
+
+        assert_eq!(order.expire_time, Some(UnixNanos::from(1_001)));
+    }
 }
@@ -24,7 +24,7 @@ use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore, OrderError},
+    base::{check_expire_time, Order, OrderCore, OrderError},
 };
 use crate::{
     enums::{
@@ -90,6 +90,7 @@ impl LimitIfTouchedOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
+        check_expire_time(time_in_force, expire_time, ts_init, 0)?;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -337,7 +338,7 @@ impl Order for LimitIfTouchedOrder {
     }
 
     fn events(&self) -> Vec<&OrderEventAny> {
-        self.events.iter().collect()
+        self.events.iter().map(AsRef::as_ref).collect()
     }
 
     fn venue_order_ids(&self) -> Vec<&VenueOrderId> {
@@ -349,13 +350,18 @@ impl Order for LimitIfTouchedOrder {
     }
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        if let OrderEventAny::Updated(ref event) = event {
-            self.update(event);
+        let updated = match &event {
+            OrderEventAny::Updated(updated) => Some(*updated),
+            _ => None,
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
 
         self.core.apply(event)?;
 
+        if let Some(updated) = updated {
+            self.update(&updated);
+        }
+
         if is_order_filled {
             self.core.set_slippage(self.price);
         };
@@ -379,6 +385,7 @@ impl Order for LimitIfTouchedOrder {
 
 impl From<OrderInitialized> for LimitIfTouchedOrder {
     fn from(event: OrderInitialized) -> Self {
+        let ext = *event.ext();
         Self::new(
             event.trader_id,
             event.strategy_id,
@@ -405,14 +412,14 @@ impl From<OrderInitialized> for LimitIfTouchedOrder {
             event.display_qty,
             event.emulation_trigger,
             event.trigger_instrument_id,
-            event.contingency_type,
-            event.order_list_id,
-            event.linked_order_ids,
-            event.parent_order_id,
+            ext.contingency_type,
+            ext.order_list_id,
+            event.linked_order_ids.map(Vec::from),
+            ext.parent_order_id,
             event.exec_algorithm_id,
             event.exec_algorithm_params,
             event.exec_spawn_id,
-            event.tags,
+            event.tags.map(Vec::from),
             event.event_id,
             event.ts_event,
         )
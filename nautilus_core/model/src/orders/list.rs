@@ -13,19 +13,188 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
 use nautilus_core::{correctness::check_slice_not_empty, nanos::UnixNanos};
 use serde::{Deserialize, Serialize};
 
 use super::any::OrderAny;
 use crate::{
+    enums::ContingencyType,
+    events::order::initialized::OrderInitialized,
     identifiers::{
-        instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId,
     },
     polymorphism::{GetInstrumentId, GetStrategyId},
 };
 
+/// The error returned when the orders of an [`OrderList`] are not consistently linked.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum OrderListError {
+    #[error("Order list {0} has no orders")]
+    Empty(OrderListId),
+    #[error("Duplicate order {0} in order list")]
+    DuplicateOrder(ClientOrderId),
+    #[error("Order {client_order_id} has `order_list_id` {order_list_id:?}, expected {expected}")]
+    OrderListIdMismatch {
+        client_order_id: ClientOrderId,
+        order_list_id: Option<OrderListId>,
+        expected: OrderListId,
+    },
+    #[error("Order {client_order_id} is for {instrument_id}, expected {expected}")]
+    InstrumentMismatch {
+        client_order_id: ClientOrderId,
+        instrument_id: InstrumentId,
+        expected: InstrumentId,
+    },
+    #[error("Order {client_order_id} is for strategy {strategy_id}, expected {expected}")]
+    StrategyMismatch {
+        client_order_id: ClientOrderId,
+        strategy_id: StrategyId,
+        expected: StrategyId,
+    },
+    #[error("Order {client_order_id} links to {linked_order_id} which is not in the order list")]
+    UnknownLinkedOrder {
+        client_order_id: ClientOrderId,
+        linked_order_id: ClientOrderId,
+    },
+    #[error("Order {client_order_id} has parent {parent_order_id} which is not in the order list")]
+    UnknownParentOrder {
+        client_order_id: ClientOrderId,
+        parent_order_id: ClientOrderId,
+    },
+    #[error("Order {client_order_id} has parent {parent_order_id} which does not link to it")]
+    UnlinkedChild {
+        client_order_id: ClientOrderId,
+        parent_order_id: ClientOrderId,
+    },
+    #[error("OTO child {client_order_id} has `parent_order_id` {parent_order_id:?}, expected {expected}")]
+    ParentMismatch {
+        client_order_id: ClientOrderId,
+        parent_order_id: Option<ClientOrderId>,
+        expected: ClientOrderId,
+    },
+    #[error("{contingency_type} order {client_order_id} links to {linked_order_id}, which does not link back as {contingency_type}")]
+    AsymmetricLink {
+        client_order_id: ClientOrderId,
+        linked_order_id: ClientOrderId,
+        contingency_type: ContingencyType,
+    },
+}
+
+/// Checks the `orders` (as initialized) form a consistently linked order list.
+///
+/// All orders must be for the same instrument and strategy as the first order, with the
+/// given `order_list_id` and unique client order IDs. For the contingency linkage:
+/// - Every linked and parent order ID must refer to an order in the list.
+/// - The parent of an order must list it among its linked orders.
+/// - The linked orders of an `OTO` parent must name it as their `parent_order_id`.
+/// - `OCO` and `OUO` links must be symmetric, with the linked order having the same
+///   contingency type and linking back.
+///
+/// # Errors
+///
+/// This function returns the first violation found.
+pub fn check_order_list(
+    order_list_id: OrderListId,
+    orders: &[OrderInitialized],
+) -> Result<(), OrderListError> {
+    let first = orders.first().ok_or(OrderListError::Empty(order_list_id))?;
+
+    let mut by_id = HashMap::with_capacity(orders.len());
+    for order in orders {
+        let client_order_id = order.client_order_id;
+        if by_id.insert(client_order_id, order).is_some() {
+            return Err(OrderListError::DuplicateOrder(client_order_id));
+        }
+        if order.ext().order_list_id != Some(order_list_id) {
+            return Err(OrderListError::OrderListIdMismatch {
+                client_order_id,
+                order_list_id: order.ext().order_list_id,
+                expected: order_list_id,
+            });
+        }
+        if order.instrument_id != first.instrument_id {
+            return Err(OrderListError::InstrumentMismatch {
+                client_order_id,
+                instrument_id: order.instrument_id,
+                expected: first.instrument_id,
+            });
+        }
+        if order.strategy_id != first.strategy_id {
+            return Err(OrderListError::StrategyMismatch {
+                client_order_id,
+                strategy_id: order.strategy_id,
+                expected: first.strategy_id,
+            });
+        }
+    }
+
+    for order in orders {
+        let client_order_id = order.client_order_id;
+        if let Some(parent_order_id) = order.ext().parent_order_id {
+            let parent = by_id
+                .get(&parent_order_id)
+                .ok_or(OrderListError::UnknownParentOrder {
+                    client_order_id,
+                    parent_order_id,
+                })?;
+            if !parent
+                .linked_order_ids
+                .as_deref()
+                .is_some_and(|ids| ids.contains(&client_order_id))
+            {
+                return Err(OrderListError::UnlinkedChild {
+                    client_order_id,
+                    parent_order_id,
+                });
+            }
+        }
+    }
+
+    for order in orders {
+        let client_order_id = order.client_order_id;
+        let contingency_type = order.ext().contingency_type;
+        for linked_order_id in order.linked_order_ids.as_deref().unwrap_or_default() {
+            let linked = by_id
+                .get(linked_order_id)
+                .ok_or(OrderListError::UnknownLinkedOrder {
+                    client_order_id,
+                    linked_order_id: *linked_order_id,
+                })?;
+
+            match contingency_type {
+                Some(ContingencyType::Oto)
+                    if linked.ext().parent_order_id != Some(client_order_id) =>
+                {
+                    return Err(OrderListError::ParentMismatch {
+                        client_order_id: *linked_order_id,
+                        parent_order_id: linked.ext().parent_order_id,
+                        expected: client_order_id,
+                    });
+                }
+                Some(contingency_type @ (ContingencyType::Oco | ContingencyType::Ouo))
+                    if linked.ext().contingency_type != Some(contingency_type)
+                        || !linked
+                            .linked_order_ids
+                            .as_deref()
+                            .is_some_and(|ids| ids.contains(&client_order_id)) =>
+                {
+                    return Err(OrderListError::AsymmetricLink {
+                        client_order_id,
+                        linked_order_id: *linked_order_id,
+                        contingency_type,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "python",
@@ -61,6 +230,32 @@ impl OrderList {
             ts_init,
         })
     }
+
+    /// Creates a new [`OrderList`] from the initialized `orders`, after checking they are
+    /// consistently linked (see [`check_order_list`]).
+    ///
+    /// The instrument and strategy of the list are those of the orders.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the orders are empty or not consistently linked.
+    pub fn from_initialized(
+        order_list_id: OrderListId,
+        orders: Vec<OrderInitialized>,
+        ts_init: UnixNanos,
+    ) -> Result<Self, OrderListError> {
+        check_order_list(order_list_id, &orders)?;
+
+        let instrument_id = orders[0].instrument_id;
+        let strategy_id = orders[0].strategy_id;
+        Ok(Self {
+            id: order_list_id,
+            instrument_id,
+            strategy_id,
+            orders: orders.into_iter().map(OrderAny::from).collect(),
+            ts_init,
+        })
+    }
 }
 
 impl PartialEq for OrderList {
@@ -95,12 +290,61 @@ mod tests {
     use super::*;
     use crate::{
         enums::OrderSide,
+        events::order::initialized::OrderInitializedBuilder,
         identifiers::{order_list_id::OrderListId, strategy_id::StrategyId},
         instruments::{currency_pair::CurrencyPair, stubs::*},
         orders::{any::OrderAny, stubs::TestOrderStubs},
         types::{price::Price, quantity::Quantity},
     };
 
+    fn order_list_id() -> OrderListId {
+        OrderListId::from("OL-001")
+    }
+
+    fn initialized(
+        client_order_id: &str,
+        contingency_type: Option<ContingencyType>,
+        linked_order_ids: &[&str],
+        parent_order_id: Option<&str>,
+    ) -> OrderInitialized {
+        let linked_order_ids: Box<[ClientOrderId]> = linked_order_ids
+            .iter()
+            .map(|id| ClientOrderId::from(*id))
+            .collect();
+        OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .order_list_id(Some(order_list_id()))
+            .contingency_type(contingency_type)
+            .linked_order_ids((!linked_order_ids.is_empty()).then_some(linked_order_ids))
+            .parent_order_id(parent_order_id.map(ClientOrderId::from))
+            .build()
+            .unwrap()
+    }
+
+    /// An entry which triggers a take-profit and stop-loss pair, which update each other.
+    fn bracket() -> Vec<OrderInitialized> {
+        vec![
+            initialized(
+                "O-ENTRY",
+                Some(ContingencyType::Oto),
+                &["O-SL", "O-TP"],
+                None,
+            ),
+            initialized(
+                "O-SL",
+                Some(ContingencyType::Ouo),
+                &["O-TP"],
+                Some("O-ENTRY"),
+            ),
+            initialized(
+                "O-TP",
+                Some(ContingencyType::Ouo),
+                &["O-SL"],
+                Some("O-ENTRY"),
+            ),
+        ]
+    }
+
     #[rstest]
     fn test_new_and_display(audusd_sim: CurrencyPair) {
         let order1 = TestOrderStubs::limit_order(
@@ -147,4 +391,163 @@ mod tests {
             "OrderList(id=OL-001, instrument_id=AUD/USD.SIM, strategy_id=EMACross-001, orders="
         ));
     }
+
+    #[rstest]
+    fn test_from_initialized_bracket() {
+        let orders = bracket();
+
+        let order_list =
+            OrderList::from_initialized(order_list_id(), orders.clone(), UnixNanos::default())
+                .unwrap();
+
+        assert_eq!(order_list.id, order_list_id());
+        assert_eq!(order_list.instrument_id, orders[0].instrument_id);
+        assert_eq!(order_list.strategy_id, orders[0].strategy_id);
+        assert_eq!(order_list.orders.len(), 3);
+    }
+
+    #[rstest]
+    fn test_check_order_list_empty() {
+        assert_eq!(
+            check_order_list(order_list_id(), &[]),
+            Err(OrderListError::Empty(order_list_id()))
+        );
+    }
+
+    #[rstest]
+    fn test_check_order_list_duplicate_order() {
+        let mut orders = bracket();
+        let duplicate = orders[1].clone();
+        orders.push(duplicate);
+
+        assert_eq!(
+            check_order_list(order_list_id(), &orders),
+            Err(OrderListError::DuplicateOrder(ClientOrderId::from("O-SL")))
+        );
+    }
+
+    #[rstest]
+    fn test_check_order_list_order_list_id_mismatch() {
+        let mut orders = bracket();
+        orders[2].ext_mut().order_list_id = None;
+
+        assert_eq!(
+            check_order_list(order_list_id(), &orders),
+            Err(OrderListError::OrderListIdMismatch {
+                client_order_id: ClientOrderId::from("O-TP"),
+                order_list_id: None,
+                expected: order_list_id(),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_check_order_list_instrument_mismatch() {
+        let mut orders = bracket();
+        let expected = orders[0].instrument_id;
+        orders[2].instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+
+        assert_eq!(
+            check_order_list(order_list_id(), &orders),
+            Err(OrderListError::InstrumentMismatch {
+                client_order_id: ClientOrderId::from("O-TP"),
+                instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+                expected,
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_check_order_list_unknown_linked_order() {
+        let mut orders = bracket();
+        orders[0].linked_order_ids = Some(
+            [
+                ClientOrderId::from("O-SL"),
+                ClientOrderId::from("O-TP"),
+                ClientOrderId::from("O-MISSING"),
+            ]
+            .into(),
+        );
+
+        assert_eq!(
+            check_order_list(order_list_id(), &orders),
+            Err(OrderListError::UnknownLinkedOrder {
+                client_order_id: ClientOrderId::from("O-ENTRY"),
+                linked_order_id: ClientOrderId::from("O-MISSING"),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_check_order_list_unknown_parent_order() {
+        let mut orders = bracket();
+        orders[1].ext_mut().parent_order_id = Some(ClientOrderId::from("O-MISSING"));
+
+        assert_eq!(
+            check_order_list(order_list_id(), &orders),
+            Err(OrderListError::UnknownParentOrder {
+                client_order_id: ClientOrderId::from("O-SL"),
+                parent_order_id: ClientOrderId::from("O-MISSING"),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_check_order_list_child_not_linked_by_parent() {
+        let mut orders = bracket();
+        orders[0].linked_order_ids = Some([ClientOrderId::from("O-SL")].into());
+
+        assert_eq!(
+            check_order_list(order_list_id(), &orders),
+            Err(OrderListError::UnlinkedChild {
+                client_order_id: ClientOrderId::from("O-TP"),
+                parent_order_id: ClientOrderId::from("O-ENTRY"),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_check_order_list_oto_child_parent_mismatch() {
+        let mut orders = bracket();
+        orders[2].ext_mut().parent_order_id = Some(ClientOrderId::from("O-SL"));
+
+        assert_eq!(
+            check_order_list(order_list_id(), &orders),
+            Err(OrderListError::ParentMismatch {
+                client_order_id: ClientOrderId::from("O-TP"),
+                parent_order_id: Some(ClientOrderId::from("O-SL")),
+                expected: ClientOrderId::from("O-ENTRY"),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_check_order_list_ouo_not_linked_back() {
+        let mut orders = bracket();
+        orders[2].linked_order_ids = None;
+
+        assert_eq!(
+            check_order_list(order_list_id(), &orders),
+            Err(OrderListError::AsymmetricLink {
+                client_order_id: ClientOrderId::from("O-SL"),
+                linked_order_id: ClientOrderId::from("O-TP"),
+                contingency_type: ContingencyType::Ouo,
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_check_order_list_oco_linked_to_ouo() {
+        let mut orders = bracket();
+        orders[1].ext_mut().contingency_type = Some(ContingencyType::Oco);
+
+        assert_eq!(
+            check_order_list(order_list_id(), &orders),
+            Err(OrderListError::AsymmetricLink {
+                client_order_id: ClientOrderId::from("O-SL"),
+                linked_order_id: ClientOrderId::from("O-TP"),
+                contingency_type: ContingencyType::Oco,
+            })
+        );
+    }
 }
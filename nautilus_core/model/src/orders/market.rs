@@ -327,12 +327,17 @@ impl Order for MarketOrder {
     }
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        if let OrderEventAny::Updated(ref event) = event {
-            self.update(event);
+        let updated = match &event {
+            OrderEventAny::Updated(updated) => Some(*updated),
+            _ => None,
         };
 
         self.core.apply(event)?;
 
+        if let Some(updated) = updated {
+            self.update(&updated);
+        }
+
         Ok(())
     }
 
@@ -349,7 +354,7 @@ impl Order for MarketOrder {
     }
 
     fn events(&self) -> Vec<&OrderEventAny> {
-        self.events.iter().collect()
+        self.events.iter().map(AsRef::as_ref).collect()
     }
 
     fn venue_order_ids(&self) -> Vec<&VenueOrderId> {
@@ -401,6 +406,7 @@ impl Display for MarketOrder {
 
 impl From<OrderInitialized> for MarketOrder {
     fn from(event: OrderInitialized) -> Self {
+        let ext = *event.ext();
         Self::new(
             event.trader_id,
             event.strategy_id,
@@ -413,14 +419,14 @@ impl From<OrderInitialized> for MarketOrder {
             event.ts_event,
             event.reduce_only,
             event.quote_quantity,
-            event.contingency_type,
-            event.order_list_id,
-            event.linked_order_ids,
-            event.parent_order_id,
+            ext.contingency_type,
+            ext.order_list_id,
+            event.linked_order_ids.map(Vec::from),
+            ext.parent_order_id,
             event.exec_algorithm_id,
             event.exec_algorithm_params,
             event.exec_spawn_id,
-            event.tags,
+            event.tags.map(Vec::from),
         )
         .unwrap() // SAFETY: From can panic
     }
@@ -24,7 +24,8 @@ use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore, OrderError},
+    base::{check_expire_time, Order, OrderCore, OrderError},
+    trigger,
 };
 use crate::{
     enums::{
@@ -86,6 +87,7 @@ impl MarketIfTouchedOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
+        check_expire_time(time_in_force, expire_time, ts_init, 0)?;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -133,6 +135,21 @@ impl MarketIfTouchedOrder {
             ts_triggered: None,
         })
     }
+
+    /// Returns whether the order has been triggered by the given market prices, as evaluated
+    /// for its `trigger_type` (see [`trigger::has_triggered`]).
+    #[must_use]
+    pub fn has_triggered(&self, bid: Price, ask: Price, last: Option<Price>) -> bool {
+        trigger::has_triggered(
+            self.order_type,
+            self.side,
+            self.trigger_price,
+            self.trigger_type,
+            bid,
+            ask,
+            last,
+        )
+    }
 }
 
 impl Deref for MarketIfTouchedOrder {
@@ -331,7 +348,7 @@ impl Order for MarketIfTouchedOrder {
     }
 
     fn events(&self) -> Vec<&OrderEventAny> {
-        self.events.iter().collect()
+        self.events.iter().map(AsRef::as_ref).collect()
     }
 
     fn venue_order_ids(&self) -> Vec<&VenueOrderId> {
@@ -343,13 +360,18 @@ impl Order for MarketIfTouchedOrder {
     }
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        if let OrderEventAny::Updated(ref event) = event {
-            self.update(event);
+        let updated = match &event {
+            OrderEventAny::Updated(updated) => Some(*updated),
+            _ => None,
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
 
         self.core.apply(event)?;
 
+        if let Some(updated) = updated {
+            self.update(&updated);
+        }
+
         if is_order_filled {
             self.core.set_slippage(self.trigger_price);
         };
@@ -371,6 +393,7 @@ impl Order for MarketIfTouchedOrder {
 
 impl From<OrderInitialized> for MarketIfTouchedOrder {
     fn from(event: OrderInitialized) -> Self {
+        let ext = *event.ext();
         Self::new(
             event.trader_id,
             event.strategy_id,
@@ -393,14 +416,14 @@ impl From<OrderInitialized> for MarketIfTouchedOrder {
             event.display_qty,
             event.emulation_trigger,
             event.trigger_instrument_id,
-            event.contingency_type,
-            event.order_list_id,
-            event.linked_order_ids,
-            event.parent_order_id,
+            ext.contingency_type,
+            ext.order_list_id,
+            event.linked_order_ids.map(Vec::from),
+            ext.parent_order_id,
             event.exec_algorithm_id,
             event.exec_algorithm_params,
             event.exec_spawn_id,
-            event.tags,
+            event.tags.map(Vec::from),
             event.event_id,
             event.ts_event,
         ).unwrap() // SAFETY: From can panic
@@ -24,7 +24,7 @@ use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore},
+    base::{check_expire_time, Order, OrderCore},
 };
 use crate::{
     enums::{
@@ -81,6 +81,7 @@ impl MarketToLimitOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
+        check_expire_time(time_in_force, expire_time, ts_init, 0)?;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -323,7 +324,7 @@ impl Order for MarketToLimitOrder {
     }
 
     fn events(&self) -> Vec<&OrderEventAny> {
-        self.events.iter().collect()
+        self.events.iter().map(AsRef::as_ref).collect()
     }
 
     fn venue_order_ids(&self) -> Vec<&VenueOrderId> {
@@ -335,13 +336,18 @@ impl Order for MarketToLimitOrder {
     }
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        if let OrderEventAny::Updated(ref event) = event {
-            self.update(event);
+        let updated = match &event {
+            OrderEventAny::Updated(updated) => Some(*updated),
+            _ => None,
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
 
         self.core.apply(event)?;
 
+        if let Some(updated) = updated {
+            self.update(&updated);
+        }
+
         if is_order_filled && self.price.is_some() {
             self.core.set_slippage(self.price.unwrap());
         };
@@ -367,6 +373,7 @@ impl Order for MarketToLimitOrder {
 
 impl From<OrderInitialized> for MarketToLimitOrder {
     fn from(event: OrderInitialized) -> Self {
+        let ext = *event.ext();
         Self::new(
             event.trader_id,
             event.strategy_id,
@@ -380,14 +387,14 @@ impl From<OrderInitialized> for MarketToLimitOrder {
             event.reduce_only,
             event.quote_quantity,
             event.display_qty,
-            event.contingency_type,
-            event.order_list_id,
-            event.linked_order_ids,
-            event.parent_order_id,
+            ext.contingency_type,
+            ext.order_list_id,
+            event.linked_order_ids.map(Vec::from),
+            ext.parent_order_id,
             event.exec_algorithm_id,
             event.exec_algorithm_params,
             event.exec_spawn_id,
-            event.tags,
+            event.tags.map(Vec::from),
             event.event_id,
             event.ts_event,
         )
@@ -63,7 +63,10 @@ impl TestOrderEventStubs {
         let trade_id = trade_id.unwrap_or(
             TradeId::new(order.client_order_id().as_str().replace('O', "E").as_str()).unwrap(),
         );
-        let liquidity_side = order.liquidity_side().unwrap_or(LiquiditySide::Maker);
+        let liquidity_side = order
+            .liquidity_side()
+            .filter(|side| *side != LiquiditySide::NoLiquiditySide)
+            .unwrap_or(LiquiditySide::Maker);
         let event = UUID4::new();
         let position_id = position_id
             .or_else(|| order.position_id())
@@ -112,6 +115,7 @@ impl TestOrderEventStubs {
             UUID4::new(),
             UnixNanos::default(),
             UnixNanos::default(),
+            None,
         )
         .unwrap())
     }
@@ -136,6 +140,7 @@ impl TestOrderEventStubs {
             UnixNanos::default(),
             UnixNanos::default(),
             false,
+            None,
         )
         .unwrap())
     }
@@ -223,6 +228,44 @@ impl TestOrderStubs {
         .unwrap()
     }
 
+    pub fn limit_order_gtd(
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        price: Price,
+        quantity: Quantity,
+        expire_time: Option<UnixNanos>,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<LimitOrder> {
+        let client_order_id = ClientOrderId::default();
+        LimitOrder::new(
+            trader_id(),
+            strategy_id_ema_cross(),
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            price,
+            TimeInForce::Gtd,
+            expire_time,
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(client_order_id),
+            None,
+            UUID4::new(),
+            ts_init,
+        )
+    }
+
     #[must_use]
     pub fn stop_market_order(
         instrument_id: InstrumentId,
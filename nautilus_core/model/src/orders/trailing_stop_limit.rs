@@ -24,7 +24,7 @@ use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore, OrderError},
+    base::{check_expire_time, Order, OrderCore, OrderError},
 };
 use crate::{
     enums::{
@@ -96,6 +96,7 @@ impl TrailingStopLimitOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
+        check_expire_time(time_in_force, expire_time, ts_init, 0)?;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -346,7 +347,7 @@ impl Order for TrailingStopLimitOrder {
     }
 
     fn events(&self) -> Vec<&OrderEventAny> {
-        self.events.iter().collect()
+        self.events.iter().map(AsRef::as_ref).collect()
     }
 
     fn venue_order_ids(&self) -> Vec<&VenueOrderId> {
@@ -358,13 +359,18 @@ impl Order for TrailingStopLimitOrder {
     }
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        if let OrderEventAny::Updated(ref event) = event {
-            self.update(event);
+        let updated = match &event {
+            OrderEventAny::Updated(updated) => Some(*updated),
+            _ => None,
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
 
         self.core.apply(event)?;
 
+        if let Some(updated) = updated {
+            self.update(&updated);
+        }
+
         if is_order_filled {
             self.core.set_slippage(self.price);
         };
@@ -388,6 +394,7 @@ impl Order for TrailingStopLimitOrder {
 
 impl From<OrderInitialized> for TrailingStopLimitOrder {
     fn from(event: OrderInitialized) -> Self {
+        let ext = *event.ext();
         Self::new(
             event.trader_id,
             event.strategy_id,
@@ -406,9 +413,9 @@ impl From<OrderInitialized> for TrailingStopLimitOrder {
             event
                 .trigger_type
                 .expect("Error initializing order: `trigger_type` was `None` for `TrailingStopLimitOrder`"),
-            event.limit_offset.unwrap(),  // TODO
-            event.trailing_offset.unwrap(),  // TODO
-            event.trailing_offset_type.unwrap(),  // TODO
+            ext.limit_offset.unwrap(),  // TODO
+            ext.trailing_offset.unwrap(),  // TODO
+            ext.trailing_offset_type.unwrap(),  // TODO
             event.time_in_force,
             event.expire_time,
             event.post_only,
@@ -417,14 +424,14 @@ impl From<OrderInitialized> for TrailingStopLimitOrder {
             event.display_qty,
             event.emulation_trigger,
             event.trigger_instrument_id,
-            event.contingency_type,
-            event.order_list_id,
-            event.linked_order_ids,
-            event.parent_order_id,
+            ext.contingency_type,
+            ext.order_list_id,
+            event.linked_order_ids.map(Vec::from),
+            ext.parent_order_id,
             event.exec_algorithm_id,
             event.exec_algorithm_params,
             event.exec_spawn_id,
-            event.tags,
+            event.tags.map(Vec::from),
             event.event_id,
             event.ts_event,
         ).unwrap() // SAFETY: From can panic
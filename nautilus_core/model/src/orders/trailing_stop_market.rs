@@ -24,7 +24,8 @@ use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore},
+    base::{check_expire_time, Order, OrderCore},
+    trigger,
 };
 use crate::{
     enums::{
@@ -91,6 +92,7 @@ impl TrailingStopMarketOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
+        check_expire_time(time_in_force, expire_time, ts_init, 0)?;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -140,6 +142,48 @@ impl TrailingStopMarketOrder {
             ts_triggered: None,
         })
     }
+
+    /// Returns whether the order has been triggered by the given market prices, as evaluated
+    /// for its `trigger_type` (see [`trigger::has_triggered`]).
+    #[must_use]
+    pub fn has_triggered(&self, bid: Price, ask: Price, last: Option<Price>) -> bool {
+        trigger::has_triggered(
+            self.order_type,
+            self.side,
+            self.trigger_price,
+            self.trigger_type,
+            bid,
+            ask,
+            last,
+        )
+    }
+
+    /// Ratchets the trigger price to trail the `new_extreme` market price reached since the
+    /// order was placed (the high for a sell, the low for a buy) by the `trailing_offset`.
+    ///
+    /// The trigger price only ever moves towards the market, so an extreme which would
+    /// loosen the stop is ignored. Returns whether the trigger price was updated.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the trailing trigger price cannot be calculated
+    /// for the `trailing_offset_type` (see [`trigger::trailing_trigger_price`]).
+    pub fn update_trail(&mut self, new_extreme: Price) -> Result<bool, OrderError> {
+        let trigger_price = trigger::trailing_trigger_price(
+            self.side,
+            new_extreme,
+            self.trailing_offset,
+            self.trailing_offset_type,
+        )?;
+        let is_tighter = match self.side {
+            OrderSide::Buy => trigger_price < self.trigger_price,
+            _ => trigger_price > self.trigger_price,
+        };
+        if is_tighter {
+            self.trigger_price = trigger_price;
+        }
+        Ok(is_tighter)
+    }
 }
 
 impl Deref for TrailingStopMarketOrder {
@@ -338,7 +382,7 @@ impl Order for TrailingStopMarketOrder {
     }
 
     fn events(&self) -> Vec<&OrderEventAny> {
-        self.events.iter().collect()
+        self.events.iter().map(AsRef::as_ref).collect()
     }
 
     fn venue_order_ids(&self) -> Vec<&VenueOrderId> {
@@ -350,13 +394,18 @@ impl Order for TrailingStopMarketOrder {
     }
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        if let OrderEventAny::Updated(ref event) = event {
-            self.update(event);
+        let updated = match &event {
+            OrderEventAny::Updated(updated) => Some(*updated),
+            _ => None,
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
 
         self.core.apply(event)?;
 
+        if let Some(updated) = updated {
+            self.update(&updated);
+        }
+
         if is_order_filled {
             self.core.set_slippage(self.trigger_price);
         };
@@ -378,6 +427,7 @@ impl Order for TrailingStopMarketOrder {
 
 impl From<OrderInitialized> for TrailingStopMarketOrder {
     fn from(event: OrderInitialized) -> Self {
+        let ext = *event.ext();
         Self::new(
             event.trader_id,
             event.strategy_id,
@@ -393,8 +443,8 @@ impl From<OrderInitialized> for TrailingStopMarketOrder {
             event
                 .trigger_type
                 .expect("Error initializing order: `trigger_type` was `None` for `TrailingStopMarketOrder`"),
-            event.trailing_offset.unwrap(),  // TODO
-            event.trailing_offset_type.unwrap(),  // TODO
+            ext.trailing_offset.unwrap(),  // TODO
+            ext.trailing_offset_type.unwrap(),  // TODO
             event.time_in_force,
             event.expire_time,
             event.reduce_only,
@@ -402,16 +452,98 @@ impl From<OrderInitialized> for TrailingStopMarketOrder {
             event.display_qty,
             event.emulation_trigger,
             event.trigger_instrument_id,
-            event.contingency_type,
-            event.order_list_id,
-            event.linked_order_ids,
-            event.parent_order_id,
+            ext.contingency_type,
+            ext.order_list_id,
+            event.linked_order_ids.map(Vec::from),
+            ext.parent_order_id,
             event.exec_algorithm_id,
             event.exec_algorithm_params,
             event.exec_spawn_id,
-            event.tags,
+            event.tags.map(Vec::from),
             event.event_id,
             event.ts_event,
         ).unwrap() // SAFETY: From can panic
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn trailing_stop(
+        order_side: OrderSide,
+        trigger_price: &str,
+        trailing_offset: &str,
+        trailing_offset_type: TrailingOffsetType,
+    ) -> TrailingStopMarketOrder {
+        let order = TrailingStopMarketOrder::default();
+        TrailingStopMarketOrder {
+            core: OrderCore {
+                side: order_side,
+                ..order.core
+            },
+            trigger_price: Price::from(trigger_price),
+            trailing_offset: Price::from(trailing_offset),
+            trailing_offset_type,
+            ..order
+        }
+    }
+
+    #[rstest]
+    fn test_sell_trailing_stop_ratchets_but_never_loosens() {
+        let mut order = trailing_stop(OrderSide::Sell, "99.00", "1.00", TrailingOffsetType::Price);
+
+        assert!(order.update_trail(Price::from("101.00")).unwrap());
+        assert_eq!(order.trigger_price, Price::from("100.00"));
+
+        assert!(!order.update_trail(Price::from("100.50")).unwrap());
+        assert_eq!(order.trigger_price, Price::from("100.00"));
+
+        assert!(order.update_trail(Price::from("102.50")).unwrap());
+        assert_eq!(order.trigger_price, Price::from("101.50"));
+
+        assert!(!order.update_trail(Price::from("98.00")).unwrap());
+        assert_eq!(order.trigger_price, Price::from("101.50"));
+        assert!(!order.has_triggered(Price::from("101.60"), Price::from("101.70"), None));
+        assert!(order.has_triggered(Price::from("101.50"), Price::from("101.60"), None));
+    }
+
+    #[rstest]
+    fn test_buy_trailing_stop_ratchets_with_basis_points() {
+        let mut order = trailing_stop(
+            OrderSide::Buy,
+            "101.00",
+            "25",
+            TrailingOffsetType::BasisPoints,
+        );
+
+        assert!(order.update_trail(Price::from("100.00")).unwrap());
+        assert_eq!(order.trigger_price, Price::from("100.25"));
+
+        assert!(!order.update_trail(Price::from("102.00")).unwrap());
+        assert_eq!(order.trigger_price, Price::from("100.25"));
+
+        assert!(order.update_trail(Price::from("80.00")).unwrap());
+        assert_eq!(order.trigger_price, Price::from("80.20"));
+    }
+
+    #[rstest]
+    fn test_update_trail_with_ticks_offset() {
+        let mut order = trailing_stop(OrderSide::Sell, "99.00", "10", TrailingOffsetType::Ticks);
+
+        let result = order.update_trail(Price::from("101.00"));
+
+        assert!(matches!(
+            result,
+            Err(OrderError::UnsupportedTrailingOffsetType(
+                TrailingOffsetType::Ticks
+            ))
+        ));
+        assert_eq!(order.trigger_price, Price::from("99.00"));
+    }
+}
@@ -0,0 +1,258 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trigger evaluation and trailing offset calculation shared by the triggered order types.
+
+use super::base::OrderError;
+use crate::{
+    enums::{
+        OrderSide, OrderSideSpecified, OrderType, RoundingMode, TrailingOffsetType, TriggerType,
+    },
+    types::price::Price,
+};
+
+/// Returns whether an order with the given `trigger_price` has been triggered by the market
+/// prices, as evaluated for the `trigger_type`.
+///
+/// Stop orders trigger when the market moves through the trigger price against the order
+/// (at or above for a buy, at or below for a sell), and if-touched orders when it moves
+/// through in favor of the order (at or below for a buy, at or above for a sell).
+///
+/// Quote based triggers use the ask for a buy and the bid for a sell, except `DoubleBidAsk`
+/// which requires both the bid and ask to have reached the trigger price, and `MidPoint`
+/// which uses the mid of the two. `DoubleLast` is evaluated against the single `last` price
+/// given, so confirming the match over consecutive trades is left to the caller.
+///
+/// Returns `false` if the `last` price is required but not given, for `NoOrderSide`, and for
+/// trigger types without market prices here (`NoTrigger`, `MarkPrice` and `IndexPrice`).
+#[must_use]
+pub fn has_triggered(
+    order_type: OrderType,
+    order_side: OrderSide,
+    trigger_price: Price,
+    trigger_type: TriggerType,
+    bid: Price,
+    ask: Price,
+    last: Option<Price>,
+) -> bool {
+    let Ok(order_side) = order_side.as_specified() else {
+        return false;
+    };
+    let is_touch = matches!(
+        order_type,
+        OrderType::MarketIfTouched | OrderType::LimitIfTouched
+    );
+    // Compares a raw market price, scaled by `scale`, against the scaled raw trigger price
+    let reached_raw = |raw: i128, scale: i128| {
+        let trigger_raw = i128::from(trigger_price.raw) * scale;
+        match (order_side, is_touch) {
+            (OrderSideSpecified::Buy, false) | (OrderSideSpecified::Sell, true) => {
+                raw >= trigger_raw
+            }
+            (OrderSideSpecified::Sell, false) | (OrderSideSpecified::Buy, true) => {
+                raw <= trigger_raw
+            }
+        }
+    };
+    let reached = |price: Price| reached_raw(i128::from(price.raw), 1);
+    let quote = match order_side {
+        OrderSideSpecified::Buy => ask,
+        OrderSideSpecified::Sell => bid,
+    };
+
+    match trigger_type {
+        TriggerType::Default | TriggerType::BidAsk => reached(quote),
+        TriggerType::LastTrade | TriggerType::DoubleLast => last.is_some_and(reached),
+        TriggerType::DoubleBidAsk => reached(bid) && reached(ask),
+        TriggerType::LastOrBidAsk => reached(quote) || last.is_some_and(reached),
+        TriggerType::MidPoint => {
+            // Compare the bid and ask sum against twice the trigger price, so the mid is neither
+            // truncated nor built as a price finer than its precision
+            reached_raw(i128::from(bid.raw) + i128::from(ask.raw), 2)
+        }
+        TriggerType::NoTrigger | TriggerType::MarkPrice | TriggerType::IndexPrice => false,
+    }
+}
+
+/// Returns the trigger price trailing the `extreme` market price by the `trailing_offset`,
+/// below for a sell and above for a buy, at the precision of `extreme`.
+///
+/// For `BasisPoints` the `trailing_offset` is the number of basis points of `extreme`.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the `trailing_offset_type` is not `Price` or `BasisPoints`, as tick and price tier
+///   offsets require instrument data.
+/// - If the trailing price is not a valid price.
+/// - If `order_side` is `NoOrderSide`.
+pub fn trailing_trigger_price(
+    order_side: OrderSide,
+    extreme: Price,
+    trailing_offset: Price,
+    trailing_offset_type: TrailingOffsetType,
+) -> Result<Price, OrderError> {
+    let offset = match trailing_offset_type {
+        TrailingOffsetType::Price => trailing_offset.as_f64(),
+        TrailingOffsetType::BasisPoints => extreme.as_f64() * trailing_offset.as_f64() / 10_000.0,
+        _ => {
+            return Err(OrderError::UnsupportedTrailingOffsetType(
+                trailing_offset_type,
+            ))
+        }
+    };
+    let value = match order_side {
+        OrderSide::Buy => extreme.as_f64() + offset,
+        OrderSide::Sell => extreme.as_f64() - offset,
+        OrderSide::NoOrderSide => return Err(OrderError::NoOrderSide),
+    };
+
+    Price::new_with_rounding(value, extreme.precision, RoundingMode::HalfUp)
+        .map_err(|_| OrderError::InvalidTrailingPrice(value))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(TriggerType::BidAsk, "100.00", "100.50", None, false)]
+    #[case(TriggerType::BidAsk, "100.50", "101.00", None, true)]
+    #[case(TriggerType::Default, "100.50", "101.00", None, true)]
+    #[case(TriggerType::LastTrade, "100.50", "101.00", Some("100.90"), false)]
+    #[case(TriggerType::LastTrade, "100.00", "100.50", Some("101.00"), true)]
+    #[case(TriggerType::LastTrade, "100.50", "101.00", None, false)]
+    #[case(TriggerType::DoubleBidAsk, "100.50", "101.00", None, false)]
+    #[case(TriggerType::DoubleBidAsk, "101.00", "101.50", None, true)]
+    #[case(TriggerType::LastOrBidAsk, "100.00", "100.50", Some("101.00"), true)]
+    #[case(TriggerType::LastOrBidAsk, "100.50", "101.00", Some("100.90"), true)]
+    #[case(TriggerType::MidPoint, "100.50", "101.49", None, false)]
+    #[case(TriggerType::MidPoint, "100.50", "101.50", None, true)]
+    #[case(TriggerType::MidPoint, "100.5", "101.49", None, false)]
+    #[case(TriggerType::MidPoint, "100.5", "101.50", None, true)]
+    #[case(TriggerType::MarkPrice, "101.00", "101.50", Some("101.00"), false)]
+    fn test_buy_stop_has_triggered(
+        #[case] trigger_type: TriggerType,
+        #[case] bid: &str,
+        #[case] ask: &str,
+        #[case] last: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let triggered = has_triggered(
+            OrderType::StopMarket,
+            OrderSide::Buy,
+            Price::from("101.00"),
+            trigger_type,
+            Price::from(bid),
+            Price::from(ask),
+            last.map(Price::from),
+        );
+
+        assert_eq!(triggered, expected);
+    }
+
+    #[rstest]
+    #[case(OrderType::StopMarket, OrderSide::Sell, "99.00", true)]
+    #[case(OrderType::StopMarket, OrderSide::Sell, "98.99", false)]
+    #[case(OrderType::MarketIfTouched, OrderSide::Buy, "99.01", true)]
+    #[case(OrderType::MarketIfTouched, OrderSide::Buy, "99.00", false)]
+    #[case(OrderType::MarketIfTouched, OrderSide::Sell, "99.00", true)]
+    #[case(OrderType::MarketIfTouched, OrderSide::Sell, "99.01", false)]
+    #[case(OrderType::StopMarket, OrderSide::NoOrderSide, "99.00", false)]
+    fn test_has_triggered_by_order_type_and_side(
+        #[case] order_type: OrderType,
+        #[case] order_side: OrderSide,
+        #[case] trigger_price: &str,
+        #[case] expected: bool,
+    ) {
+        // A one tick market at 99.00 / 99.01
+        let triggered = has_triggered(
+            order_type,
+            order_side,
+            Price::from(trigger_price),
+            TriggerType::BidAsk,
+            Price::from("99.00"),
+            Price::from("99.01"),
+            None,
+        );
+
+        assert_eq!(triggered, expected);
+    }
+
+    #[rstest]
+    fn test_sell_stop_mid_point_is_not_truncated() {
+        // The mid is half a raw unit above the trigger price
+        let triggered = has_triggered(
+            OrderType::StopMarket,
+            OrderSide::Sell,
+            Price::from("99.000000000"),
+            TriggerType::MidPoint,
+            Price::from("99.000000000"),
+            Price::from("99.000000001"),
+            None,
+        );
+
+        assert!(!triggered);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, "1.50", TrailingOffsetType::Price, "98.50")]
+    #[case(OrderSide::Buy, "1.50", TrailingOffsetType::Price, "101.50")]
+    #[case(OrderSide::Sell, "25", TrailingOffsetType::BasisPoints, "99.75")]
+    #[case(OrderSide::Buy, "25", TrailingOffsetType::BasisPoints, "100.25")]
+    fn test_trailing_trigger_price(
+        #[case] order_side: OrderSide,
+        #[case] trailing_offset: &str,
+        #[case] trailing_offset_type: TrailingOffsetType,
+        #[case] expected: &str,
+    ) {
+        let trigger_price = trailing_trigger_price(
+            order_side,
+            Price::from("100.00"),
+            Price::from(trailing_offset),
+            trailing_offset_type,
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, Price::from(expected));
+        assert_eq!(trigger_price.precision, 2);
+    }
+
+    #[rstest]
+    #[case(TrailingOffsetType::Ticks)]
+    #[case(TrailingOffsetType::PriceTier)]
+    #[case(TrailingOffsetType::NoTrailingOffset)]
+    fn test_trailing_trigger_price_unsupported_offset_type(
+        #[case] trailing_offset_type: TrailingOffsetType,
+    ) {
+        let result = trailing_trigger_price(
+            OrderSide::Sell,
+            Price::from("100.00"),
+            Price::from("1"),
+            trailing_offset_type,
+        );
+
+        assert!(matches!(
+            result,
+            Err(OrderError::UnsupportedTrailingOffsetType(offset_type))
+                if offset_type == trailing_offset_type
+        ));
+    }
+}
@@ -15,15 +15,17 @@
 
 //! Defines traits to faciliate polymorphism.
 
+use std::sync::Arc;
+
 use nautilus_core::nanos::UnixNanos;
 
 use crate::{
-    enums::{OrderSide, OrderSideSpecified, TriggerType},
+    enums::{OrderSide, OrderSideSpecified, OrderStatus, TimeInForce, TriggerType},
     events::order::event::OrderEventAny,
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
-        trader_id::TraderId, venue_order_id::VenueOrderId,
+        trade_id::TradeId, trader_id::TraderId, venue_order_id::VenueOrderId,
     },
     orders::base::OrderError,
     types::{price::Price, quantity::Quantity},
@@ -89,6 +91,22 @@ pub trait GetOrderSideSpecified {
     fn order_side_specified(&self) -> OrderSideSpecified;
 }
 
+pub trait GetOrderEvents {
+    fn order_events(&self) -> &[Arc<OrderEventAny>];
+}
+
+pub trait GetTradeIds {
+    fn trade_ids(&self) -> &[TradeId];
+}
+
+pub trait GetOrderStatus {
+    fn status(&self) -> OrderStatus;
+}
+
+pub trait GetTimeInForce {
+    fn time_in_force(&self) -> TimeInForce;
+}
+
 pub trait GetEmulationTrigger {
     fn emulation_trigger(&self) -> Option<TriggerType>;
 }
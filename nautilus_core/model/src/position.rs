@@ -25,7 +25,7 @@ use nautilus_core::nanos::UnixNanos;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    enums::{OrderSide, PositionSide},
+    enums::{apply_fill_side, OrderSide, PositionSide},
     events::order::filled::OrderFilled,
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
@@ -167,37 +167,36 @@ impl Position {
             }
         }
 
+        let (side, signed_qty) =
+            apply_fill_side(self.side, self.quantity, fill.order_side, fill.last_qty)
+                .unwrap_or_else(|e| panic!("Invalid order side for {}: {e}", fill.trade_id));
+
         // Calculate avg prices, points, return, PnL
-        if fill.order_side == OrderSide::Buy {
-            self.handle_buy_order_fill(fill);
-        } else if fill.order_side == OrderSide::Sell {
-            self.handle_sell_order_fill(fill);
-        } else {
-            panic!("Invalid order side {}", fill.order_side);
+        match fill.order_side {
+            OrderSide::Buy => self.handle_buy_order_fill(fill),
+            _ => self.handle_sell_order_fill(fill),
         }
 
         // Set quantities
-        self.quantity = Quantity::new(self.signed_qty.abs(), self.size_precision).unwrap();
+        self.signed_qty = signed_qty;
+        self.quantity = Quantity::new(signed_qty.abs(), self.size_precision).unwrap();
         if self.quantity > self.peak_qty {
             self.peak_qty.raw = self.quantity.raw;
         }
 
         // Set state
-        if self.signed_qty > 0.0 {
-            self.entry = OrderSide::Buy;
-            self.side = PositionSide::Long;
-        } else if self.signed_qty < 0.0 {
-            self.entry = OrderSide::Sell;
-            self.side = PositionSide::Short;
-        } else {
-            self.side = PositionSide::Flat;
-            self.closing_order_id = Some(fill.client_order_id);
-            self.ts_closed = Some(fill.ts_event);
-            self.duration_ns = if self.ts_closed.is_some() {
-                self.ts_closed.unwrap().as_u64() - self.ts_opened.as_u64()
-            } else {
-                0
-            };
+        self.side = side;
+        match side {
+            PositionSide::Long => self.entry = OrderSide::Buy,
+            PositionSide::Short => self.entry = OrderSide::Sell,
+            PositionSide::Flat | PositionSide::NoPositionSide => {
+                self.closing_order_id = Some(fill.client_order_id);
+                self.ts_closed = Some(fill.ts_event);
+                self.duration_ns = fill
+                    .ts_event
+                    .as_u64()
+                    .saturating_sub(self.ts_opened.as_u64());
+            }
         }
 
         self.ts_last = fill.ts_event;
@@ -234,7 +233,6 @@ impl Position {
             );
         }
 
-        self.signed_qty += last_qty;
         self.buy_qty += last_qty_object;
     }
 
@@ -269,7 +267,6 @@ impl Position {
             );
         }
 
-        self.signed_qty -= last_qty;
         self.sell_qty += last_qty_object;
     }
 
@@ -530,8 +527,8 @@ mod tests {
         enums::{LiquiditySide, OrderSide, OrderType, PositionSide},
         events::order::filled::OrderFilled,
         identifiers::{
-            account_id::AccountId, position_id::PositionId, strategy_id::StrategyId, stubs::uuid4,
-            trade_id::TradeId, venue_order_id::VenueOrderId,
+            account_id::AccountId, client_order_id::ClientOrderId, position_id::PositionId,
+            strategy_id::StrategyId, stubs::uuid4, trade_id::TradeId, venue_order_id::VenueOrderId,
         },
         instruments::{crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, stubs::*},
         orders::{
@@ -2158,4 +2155,44 @@ mod tests {
         let position = Position::new(audusd_sim, fill).unwrap();
         assert_eq!(position.signed_qty, expected);
     }
+
+    #[rstest]
+    fn test_position_exact_flatten_after_fractional_fills_is_flat(
+        currency_pair_ethusdt: CurrencyPair,
+    ) {
+        let fill = |client_order_id: &str, side: OrderSide, quantity: &str| {
+            let order = TestOrderStubs::market_order(
+                currency_pair_ethusdt.id,
+                side,
+                Quantity::from(quantity),
+                Some(ClientOrderId::from(client_order_id)),
+                None,
+            );
+            TestOrderEventStubs::order_filled(
+                &order,
+                &currency_pair_ethusdt,
+                None,
+                None,
+                None,
+                Some(Price::from("2000.00")),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+        };
+        let mut position = Position::new(
+            currency_pair_ethusdt,
+            fill("O-1", OrderSide::Buy, "0.10000"),
+        )
+        .unwrap();
+        position.apply(&fill("O-2", OrderSide::Buy, "0.20000"));
+        position.apply(&fill("O-3", OrderSide::Sell, "0.30000"));
+
+        assert_eq!(position.side, PositionSide::Flat);
+        assert_eq!(position.signed_qty, 0.0);
+        assert_eq!(position.quantity, Quantity::from("0.00000"));
+        assert!(position.is_closed());
+    }
 }
@@ -13,13 +13,18 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
+use nautilus_core::{
+    python::{serialization::json_value_to_py, to_pyvalue_err},
+    serialization::{DecimalFormat, Serializable, SerializationConfig},
+};
 use pyo3::{
     exceptions::PyValueError,
     prelude::*,
     types::{PyDict, PyList, PyNone},
 };
+use rust_decimal::Decimal;
 use serde_json::Value;
 use strum::IntoEnumIterator;
 
@@ -64,6 +69,34 @@ impl EnumIterator {
     }
 }
 
+/// Returns the JSON encoded bytes of the given `value` as a Python `bytes` object, with prices
+/// and quantities written in the given `decimal_format` (or the default format if `None`).
+pub fn as_json_bytes_with_format<T: Serializable>(
+    py: Python<'_>,
+    value: &T,
+    decimal_format: Option<&str>,
+) -> PyResult<Py<PyAny>> {
+    let decimal_format = decimal_format
+        .map(DecimalFormat::from_str)
+        .transpose()
+        .map_err(to_pyvalue_err)?
+        .unwrap_or_default();
+    let config = SerializationConfig::new(decimal_format);
+    let bytes = value
+        .as_json_bytes_with_config(config)
+        .map_err(to_pyvalue_err)?;
+    Ok(bytes.into_py(py))
+}
+
+/// Extracts a `Decimal` from the string representation of the given Python value, such as a
+/// `decimal.Decimal`, `int` or `str`, so no digits are lost to an `f64` conversion.
+pub fn decimal_from_pyobject(value: &PyAny) -> PyResult<Decimal> {
+    let value_str = value.str()?.to_str()?;
+    Decimal::from_str(value_str)
+        .or_else(|_| Decimal::from_scientific(value_str))
+        .map_err(to_pyvalue_err)
+}
+
 pub fn value_to_pydict(py: Python<'_>, val: &Value) -> PyResult<Py<PyDict>> {
     let dict = PyDict::new(py);
 
@@ -82,32 +115,7 @@ pub fn value_to_pydict(py: Python<'_>, val: &Value) -> PyResult<Py<PyDict>> {
 }
 
 pub fn value_to_pyobject(py: Python<'_>, val: &Value) -> PyResult<PyObject> {
-    match val {
-        Value::Null => Ok(py.None()),
-        Value::Bool(b) => Ok(b.into_py(py)),
-        Value::String(s) => Ok(s.into_py(py)),
-        Value::Number(n) => {
-            if n.is_i64() {
-                Ok(n.as_i64().unwrap().into_py(py))
-            } else if n.is_f64() {
-                Ok(n.as_f64().unwrap().into_py(py))
-            } else {
-                Err(PyValueError::new_err("Unsupported JSON number type"))
-            }
-        }
-        Value::Array(arr) => {
-            let py_list = PyList::new(py, &[] as &[PyObject]);
-            for item in arr {
-                let py_item = value_to_pyobject(py, item)?;
-                py_list.append(py_item)?;
-            }
-            Ok(py_list.into())
-        }
-        Value::Object(_) => {
-            let py_dict = value_to_pydict(py, val)?;
-            Ok(py_dict.into())
-        }
-    }
+    json_value_to_py(py, val)
 }
 
 pub fn commissions_from_vec<'py>(py: Python<'py>, commissions: Vec<Money>) -> PyResult<&'py PyAny> {
@@ -236,4 +244,15 @@ mod tests {
             );
         });
     }
+
+    #[rstest]
+    fn test_as_json_bytes_with_invalid_decimal_format_errors() {
+        prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let quote = crate::data::stubs::quote_tick_audusd_sim();
+            let result = as_json_bytes_with_format(py, &quote, Some("invalid"));
+
+            assert!(result.is_err());
+        });
+    }
 }
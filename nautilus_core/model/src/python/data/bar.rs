@@ -33,7 +33,7 @@ use crate::{
     },
     enums::{AggregationSource, BarAggregation, PriceType},
     identifiers::instrument_id::InstrumentId,
-    python::common::PY_MODULE_MODEL,
+    python::common::{as_json_bytes_with_format, PY_MODULE_MODEL},
     types::{price::Price, quantity::Quantity},
 };
 
@@ -75,6 +75,12 @@ impl BarSpecification {
     fn py_fully_qualified_name() -> String {
         format!("{}:{}", PY_MODULE_MODEL, stringify!(BarSpecification))
     }
+
+    #[staticmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(value: &str) -> PyResult<Self> {
+        Self::from_str(value).map_err(to_pyvalue_err)
+    }
 }
 
 #[pymethods]
@@ -354,10 +360,11 @@ impl Bar {
     }
 
     /// Return JSON encoded bytes representation of the object.
-    #[pyo3(name = "as_json")]
-    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
-        // Unwrapping is safe when serializing a valid object
-        self.as_json_bytes().unwrap().into_py(py)
+    ///
+    /// The `decimal_format` is one of 'NUMBER', 'STRING_CANONICAL' (default) or 'RAW_PARTS'.
+    #[pyo3(name = "as_json", signature = (decimal_format=None))]
+    fn py_as_json(&self, py: Python<'_>, decimal_format: Option<&str>) -> PyResult<Py<PyAny>> {
+        as_json_bytes_with_format(py, self, decimal_format)
     }
 
     /// Return MsgPack encoded bytes representation of the object.
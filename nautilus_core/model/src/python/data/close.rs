@@ -0,0 +1,114 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use pyo3::{prelude::*, pyclass::CompareOp};
+
+use super::data_to_pycapsule;
+use crate::{
+    data::{close::InstrumentClose, Data},
+    enums::InstrumentCloseType,
+    identifiers::instrument_id::InstrumentId,
+    python::common::PY_MODULE_MODEL,
+    types::price::Price,
+};
+
+#[pymethods]
+impl InstrumentClose {
+    #[new]
+    fn py_new(
+        instrument_id: InstrumentId,
+        close_price: Price,
+        close_type: InstrumentCloseType,
+        ts_event: u64,
+        ts_init: u64,
+    ) -> Self {
+        Self::new(
+            instrument_id,
+            close_price,
+            close_type,
+            ts_event.into(),
+            ts_init.into(),
+        )
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.hash(&mut h);
+        h.finish() as isize
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}({})", stringify!(InstrumentClose), self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "close_price")]
+    fn py_close_price(&self) -> Price {
+        self.close_price
+    }
+
+    #[getter]
+    #[pyo3(name = "close_type")]
+    fn py_close_type(&self) -> InstrumentCloseType {
+        self.close_type
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> u64 {
+        self.ts_event.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> u64 {
+        self.ts_init.as_u64()
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "fully_qualified_name")]
+    fn py_fully_qualified_name() -> String {
+        format!("{}:{}", PY_MODULE_MODEL, stringify!(InstrumentClose))
+    }
+
+    /// Creates a `PyCapsule` containing a raw pointer to a `Data::Close` object.
+    #[pyo3(name = "as_pycapsule")]
+    fn py_as_pycapsule(&self, py: Python<'_>) -> PyObject {
+        data_to_pycapsule(py, Data::Close(*self))
+    }
+}
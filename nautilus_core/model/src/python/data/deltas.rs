@@ -19,13 +19,18 @@ use std::{
     ops::Deref,
 };
 
-use pyo3::{prelude::*, pyclass::CompareOp, types::PyCapsule};
+use nautilus_core::python::{serialization::from_dict_pyo3, to_pyvalue_err};
+use pyo3::{
+    prelude::*,
+    pyclass::CompareOp,
+    types::{PyCapsule, PyDict},
+};
 
 use super::data_to_pycapsule;
 use crate::{
     data::{
         delta::OrderBookDelta,
-        deltas::{OrderBookDeltas, OrderBookDeltas_API},
+        deltas::{check_deltas, OrderBookDeltas, OrderBookDeltas_API},
         Data,
     },
     identifiers::instrument_id::InstrumentId,
@@ -35,8 +40,9 @@ use crate::{
 #[pymethods]
 impl OrderBookDeltas {
     #[new]
-    fn py_new(instrument_id: InstrumentId, deltas: Vec<OrderBookDelta>) -> Self {
-        Self::new(instrument_id, deltas)
+    fn py_new(instrument_id: InstrumentId, deltas: Vec<OrderBookDelta>) -> PyResult<Self> {
+        check_deltas(instrument_id, &deltas).map_err(to_pyvalue_err)?;
+        Ok(Self::new(instrument_id, deltas))
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
@@ -98,12 +104,41 @@ impl OrderBookDeltas {
         self.ts_init.as_u64()
     }
 
+    #[pyo3(name = "is_snapshot")]
+    fn py_is_snapshot(&self) -> bool {
+        self.is_snapshot()
+    }
+
+    #[pyo3(name = "is_last")]
+    fn py_is_last(&self) -> bool {
+        self.is_last()
+    }
+
     #[staticmethod]
     #[pyo3(name = "fully_qualified_name")]
     fn py_fully_qualified_name() -> String {
         format!("{}:{}", PY_MODULE_MODEL, stringify!(OrderBookDeltas))
     }
 
+    /// Return a dictionary representation of the object.
+    #[pyo3(name = "as_dict")]
+    fn py_as_dict(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        // Serialize object to JSON bytes
+        let json_str = serde_json::to_string(self).map_err(to_pyvalue_err)?;
+        // Parse JSON into a Python dictionary
+        let py_dict: Py<PyDict> = PyModule::import(py, "json")?
+            .call_method("loads", (json_str,), None)?
+            .extract()?;
+        Ok(py_dict)
+    }
+
+    /// Return a new object from the given dictionary representation.
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
     #[staticmethod]
     #[pyo3(name = "from_pycapsule")]
     pub fn py_from_pycapsule(capsule: &PyAny) -> Self {
@@ -136,6 +171,4 @@ impl OrderBookDeltas {
         let deltas = OrderBookDeltas_API::new(self.clone());
         data_to_pycapsule(py, Data::Deltas(deltas))
     }
-
-    // TODO: Implement `Serializable` and the other methods can be added
 }
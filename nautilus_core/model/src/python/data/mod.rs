@@ -16,12 +16,14 @@
 //! Defines data types for the trading domain model.
 
 pub mod bar;
+pub mod close;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
 pub mod order;
 pub mod quote;
 pub mod trade;
+pub mod volume_profile;
 
 #[cfg(feature = "ffi")]
 use nautilus_core::ffi::cvec::CVec;
@@ -53,6 +55,20 @@ pub fn data_to_pycapsule(py: Python, data: Data) -> PyObject {
     capsule.into_py(py)
 }
 
+/// Converts the given `data` into its Python object.
+#[must_use]
+pub fn data_to_pyobject(py: Python, data: Data) -> PyObject {
+    match data {
+        Data::Delta(delta) => delta.into_py(py),
+        Data::Deltas(deltas) => (*deltas).clone().into_py(py),
+        Data::Depth10(depth) => depth.into_py(py),
+        Data::Quote(quote) => quote.into_py(py),
+        Data::Trade(trade) => trade.into_py(py),
+        Data::Bar(bar) => bar.into_py(py),
+        Data::Close(close) => close.into_py(py),
+    }
+}
+
 /// Drops a `PyCapsule` containing a `CVec` structure.
 ///
 /// This function safely extracts and drops the `CVec` instance encapsulated within
@@ -16,6 +16,7 @@
 use std::{
     collections::hash_map::DefaultHasher,
     hash::{Hash, Hasher},
+    str::FromStr,
 };
 
 use nautilus_core::{
@@ -27,7 +28,7 @@ use pyo3::{prelude::*, pyclass::CompareOp, types::PyDict};
 use crate::{
     data::order::{BookOrder, OrderId},
     enums::OrderSide,
-    python::common::PY_MODULE_MODEL,
+    python::common::{as_json_bytes_with_format, PY_MODULE_MODEL},
     types::{price::Price, quantity::Quantity},
 };
 
@@ -132,10 +133,11 @@ impl BookOrder {
     }
 
     /// Return JSON encoded bytes representation of the object.
-    #[pyo3(name = "as_json")]
-    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
-        // Unwrapping is safe when serializing a valid object
-        self.as_json_bytes().unwrap().into_py(py)
+    ///
+    /// The `decimal_format` is one of 'NUMBER', 'STRING_CANONICAL' (default) or 'RAW_PARTS'.
+    #[pyo3(name = "as_json", signature = (decimal_format=None))]
+    fn py_as_json(&self, py: Python<'_>, decimal_format: Option<&str>) -> PyResult<Py<PyAny>> {
+        as_json_bytes_with_format(py, self, decimal_format)
     }
 
     /// Return MsgPack encoded bytes representation of the object.
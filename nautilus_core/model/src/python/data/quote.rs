@@ -35,7 +35,7 @@ use crate::{
     data::{quote::QuoteTick, Data},
     enums::PriceType,
     identifiers::instrument_id::InstrumentId,
-    python::common::PY_MODULE_MODEL,
+    python::common::{as_json_bytes_with_format, PY_MODULE_MODEL},
     types::{price::Price, quantity::Quantity},
 };
 
@@ -69,7 +69,7 @@ impl QuoteTick {
         let ts_event: u64 = obj.getattr("ts_event")?.extract()?;
         let ts_init: u64 = obj.getattr("ts_init")?.extract()?;
 
-        Self::new(
+        Ok(Self::new_unchecked(
             instrument_id,
             bid_price,
             ask_price,
@@ -77,8 +77,7 @@ impl QuoteTick {
             ask_size,
             ts_event.into(),
             ts_init.into(),
-        )
-        .map_err(to_pyvalue_err)
+        ))
     }
 }
 
@@ -133,13 +132,15 @@ impl QuoteTick {
         let ts_event: u64 = tuple.9.extract()?;
         let ts_init: u64 = tuple.10.extract()?;
 
-        self.instrument_id = InstrumentId::from_str(instrument_id_str).map_err(to_pyvalue_err)?;
-        self.bid_price = Price::from_raw(bid_price_raw, bid_price_prec).map_err(to_pyvalue_err)?;
-        self.ask_price = Price::from_raw(ask_price_raw, ask_price_prec).map_err(to_pyvalue_err)?;
-        self.bid_size = Quantity::from_raw(bid_size_raw, bid_size_prec).map_err(to_pyvalue_err)?;
-        self.ask_size = Quantity::from_raw(ask_size_raw, ask_size_prec).map_err(to_pyvalue_err)?;
-        self.ts_event = ts_event.into();
-        self.ts_init = ts_init.into();
+        *self = Self::new_unchecked(
+            InstrumentId::from_str(instrument_id_str).map_err(to_pyvalue_err)?,
+            Price::from_raw(bid_price_raw, bid_price_prec).map_err(to_pyvalue_err)?,
+            Price::from_raw(ask_price_raw, ask_price_prec).map_err(to_pyvalue_err)?,
+            Quantity::from_raw(bid_size_raw, bid_size_prec).map_err(to_pyvalue_err)?,
+            Quantity::from_raw(ask_size_raw, ask_size_prec).map_err(to_pyvalue_err)?,
+            ts_event.into(),
+            ts_init.into(),
+        );
 
         Ok(())
     }
@@ -169,7 +170,8 @@ impl QuoteTick {
 
     #[staticmethod]
     fn _safe_constructor() -> PyResult<Self> {
-        Ok(Self::new(
+        // Safe default, state is restored by `__setstate__`
+        Ok(Self::new_unchecked(
             InstrumentId::from("NULL.NULL"),
             Price::zero(0),
             Price::zero(0),
@@ -177,8 +179,7 @@ impl QuoteTick {
             Quantity::zero(0),
             UnixNanos::default(),
             UnixNanos::default(),
-        )
-        .unwrap()) // Safe default
+        ))
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
@@ -311,7 +312,7 @@ impl QuoteTick {
         ts_event: u64,
         ts_init: u64,
     ) -> PyResult<Self> {
-        Self::new(
+        Ok(Self::new_unchecked(
             instrument_id,
             Price::from_raw(bid_price_raw, bid_price_prec).map_err(to_pyvalue_err)?,
             Price::from_raw(ask_price_raw, ask_price_prec).map_err(to_pyvalue_err)?,
@@ -319,8 +320,7 @@ impl QuoteTick {
             Quantity::from_raw(ask_size_raw, ask_size_prec).map_err(to_pyvalue_err)?,
             ts_event.into(),
             ts_init.into(),
-        )
-        .map_err(to_pyvalue_err)
+        ))
     }
 
     /// Return a new object from the given dictionary representation.
@@ -368,10 +368,11 @@ impl QuoteTick {
     }
 
     /// Return JSON encoded bytes representation of the object.
-    #[pyo3(name = "as_json")]
-    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
-        // Unwrapping is safe when serializing a valid object
-        self.as_json_bytes().unwrap().into_py(py)
+    ///
+    /// The `decimal_format` is one of 'NUMBER', 'STRING_CANONICAL' (default) or 'RAW_PARTS'.
+    #[pyo3(name = "as_json", signature = (decimal_format=None))]
+    fn py_as_json(&self, py: Python<'_>, decimal_format: Option<&str>) -> PyResult<Py<PyAny>> {
+        as_json_bytes_with_format(py, self, decimal_format)
     }
 
     /// Return MsgPack encoded bytes representation of the object.
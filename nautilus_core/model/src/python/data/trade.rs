@@ -35,7 +35,7 @@ use crate::{
     data::{trade::TradeTick, Data},
     enums::{AggressorSide, FromU8},
     identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
-    python::common::PY_MODULE_MODEL,
+    python::common::{as_json_bytes_with_format, PY_MODULE_MODEL},
     types::{price::Price, quantity::Quantity},
 };
 
@@ -67,7 +67,7 @@ impl TradeTick {
         let ts_event: u64 = obj.getattr("ts_event")?.extract()?;
         let ts_init: u64 = obj.getattr("ts_init")?.extract()?;
 
-        Ok(Self::new(
+        Ok(Self::new_unchecked(
             instrument_id,
             price,
             size,
@@ -90,7 +90,7 @@ impl TradeTick {
         trade_id: TradeId,
         ts_event: u64,
         ts_init: u64,
-    ) -> Self {
+    ) -> PyResult<Self> {
         Self::new(
             instrument_id,
             price,
@@ -100,6 +100,7 @@ impl TradeTick {
             ts_event.into(),
             ts_init.into(),
         )
+        .map_err(to_pyvalue_err)
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
@@ -124,13 +125,15 @@ impl TradeTick {
         let ts_event: u64 = tuple.7.extract()?;
         let ts_init: u64 = tuple.8.extract()?;
 
-        self.instrument_id = InstrumentId::from_str(instrument_id_str).map_err(to_pyvalue_err)?;
-        self.price = Price::from_raw(price_raw, price_prec).map_err(to_pyvalue_err)?;
-        self.size = Quantity::from_raw(size_raw, size_prec).map_err(to_pyvalue_err)?;
-        self.aggressor_side = AggressorSide::from_u8(aggressor_side_u8).unwrap();
-        self.trade_id = TradeId::from_str(trade_id_str).map_err(to_pyvalue_err)?;
-        self.ts_event = ts_event.into();
-        self.ts_init = ts_init.into();
+        *self = Self::new_unchecked(
+            InstrumentId::from_str(instrument_id_str).map_err(to_pyvalue_err)?,
+            Price::from_raw(price_raw, price_prec).map_err(to_pyvalue_err)?,
+            Quantity::from_raw(size_raw, size_prec).map_err(to_pyvalue_err)?,
+            AggressorSide::from_u8(aggressor_side_u8).unwrap(),
+            TradeId::from_str(trade_id_str).map_err(to_pyvalue_err)?,
+            ts_event.into(),
+            ts_init.into(),
+        );
 
         Ok(())
     }
@@ -158,7 +161,8 @@ impl TradeTick {
 
     #[staticmethod]
     fn _safe_constructor() -> PyResult<Self> {
-        Ok(Self::new(
+        // Safe default, state is restored by `__setstate__`
+        Ok(Self::new_unchecked(
             InstrumentId::from("NULL.NULL"),
             Price::zero(0),
             Quantity::zero(0),
@@ -167,7 +171,6 @@ impl TradeTick {
             UnixNanos::default(),
             UnixNanos::default(),
         ))
-        // Safe default
     }
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
         match op {
@@ -317,10 +320,11 @@ impl TradeTick {
     }
 
     /// Return JSON encoded bytes representation of the object.
-    #[pyo3(name = "as_json")]
-    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
-        // SAFETY: Unwrap safe when serializing a valid object
-        self.as_json_bytes().unwrap().into_py(py)
+    ///
+    /// The `decimal_format` is one of 'NUMBER', 'STRING_CANONICAL' (default) or 'RAW_PARTS'.
+    #[pyo3(name = "as_json", signature = (decimal_format=None))]
+    fn py_as_json(&self, py: Python<'_>, decimal_format: Option<&str>) -> PyResult<Py<PyAny>> {
+        as_json_bytes_with_format(py, self, decimal_format)
     }
 
     /// Return MsgPack encoded bytes representation of the object.
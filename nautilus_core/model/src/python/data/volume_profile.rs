@@ -0,0 +1,162 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::python::{serialization::from_dict_pyo3, to_pyvalue_err};
+use pyo3::{prelude::*, pyclass::CompareOp, types::PyDict};
+
+use crate::{
+    data::{trade::TradeTick, volume_profile::VolumeProfile},
+    identifiers::instrument_id::InstrumentId,
+    python::common::PY_MODULE_MODEL,
+    types::{price::Price, quantity::Quantity},
+};
+
+#[pymethods]
+impl VolumeProfile {
+    #[new]
+    fn py_new(trade: &TradeTick, ts_start: u64, ts_end: u64) -> Self {
+        Self::new(trade, ts_start.into(), ts_end.into())
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}({})", stringify!(VolumeProfile), self)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "open")]
+    fn py_open(&self) -> Price {
+        self.open
+    }
+
+    #[getter]
+    #[pyo3(name = "high")]
+    fn py_high(&self) -> Price {
+        self.high
+    }
+
+    #[getter]
+    #[pyo3(name = "low")]
+    fn py_low(&self) -> Price {
+        self.low
+    }
+
+    #[getter]
+    #[pyo3(name = "close")]
+    fn py_close(&self) -> Price {
+        self.close
+    }
+
+    #[getter]
+    #[pyo3(name = "volume")]
+    fn py_volume(&self) -> Quantity {
+        self.volume
+    }
+
+    #[getter]
+    #[pyo3(name = "trade_count")]
+    fn py_trade_count(&self) -> u64 {
+        self.trade_count
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_start")]
+    fn py_ts_start(&self) -> u64 {
+        self.ts_start.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_end")]
+    fn py_ts_end(&self) -> u64 {
+        self.ts_end.as_u64()
+    }
+
+    #[pyo3(name = "update")]
+    fn py_update(&mut self, trade: &TradeTick) {
+        self.update(trade);
+    }
+
+    /// Return the volume at each price level as `(price, volume)` tuples, in ascending order
+    /// of price.
+    #[pyo3(name = "profile")]
+    fn py_profile(&self) -> Vec<(Price, Quantity)> {
+        self.profile()
+    }
+
+    /// Return the price levels and volumes as parallel lists of floats, for plotting.
+    #[pyo3(name = "to_histogram")]
+    fn py_to_histogram(&self) -> (Vec<f64>, Vec<f64>) {
+        self.profile()
+            .into_iter()
+            .map(|(price, volume)| (price.as_f64(), volume.as_f64()))
+            .unzip()
+    }
+
+    #[pyo3(name = "poc")]
+    fn py_poc(&self) -> Option<(Price, Quantity)> {
+        self.poc()
+    }
+
+    #[pyo3(name = "value_area")]
+    fn py_value_area(&self, pct: f64) -> PyResult<Option<(Price, Price)>> {
+        self.value_area(pct).map_err(to_pyvalue_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "fully_qualified_name")]
+    fn py_fully_qualified_name() -> String {
+        format!("{}:{}", PY_MODULE_MODEL, stringify!(VolumeProfile))
+    }
+
+    /// Return a dictionary representation of the object.
+    #[pyo3(name = "as_dict")]
+    fn py_as_dict(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        // Serialize object to JSON bytes
+        let json_str = serde_json::to_string(self).map_err(to_pyvalue_err)?;
+        // Parse JSON into a Python dictionary
+        let py_dict: Py<PyDict> = PyModule::import(py, "json")?
+            .call_method("loads", (json_str,), None)?
+            .extract()?;
+        Ok(py_dict)
+    }
+
+    /// Return a new object from the given dictionary representation.
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+}
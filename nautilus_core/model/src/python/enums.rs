@@ -25,8 +25,8 @@ use crate::{
         AccountType, AggregationSource, AggressorSide, AssetClass, BarAggregation, BookAction,
         BookType, ContingencyType, CurrencyType, HaltReason, InstrumentClass, InstrumentCloseType,
         LiquiditySide, MarketStatus, OmsType, OptionKind, OrderSide, OrderStatus, OrderType,
-        PositionSide, PriceType, RecordFlag, TimeInForce, TradingState, TrailingOffsetType,
-        TriggerType,
+        PositionSide, PriceType, RecordFlag, RoundingMode, TimeInForce, TradingState,
+        TrailingOffsetType, TriggerType,
     },
     python::common::EnumIterator,
 };
@@ -574,6 +574,18 @@ impl BarAggregation {
     fn py_month() -> Self {
         Self::Month
     }
+
+    #[classattr]
+    #[pyo3(name = "RENKO")]
+    fn py_renko() -> Self {
+        Self::Renko
+    }
+
+    #[classattr]
+    #[pyo3(name = "RANGE")]
+    fn py_range() -> Self {
+        Self::Range
+    }
 }
 
 #[pymethods]
@@ -1712,6 +1724,93 @@ impl RecordFlag {
     }
 }
 
+#[pymethods]
+impl RoundingMode {
+    #[new]
+    fn py_new(py: Python<'_>, value: &PyAny) -> PyResult<Self> {
+        let t = Self::type_object(py);
+        Self::py_from_str(t, value)
+    }
+
+    fn __hash__(&self) -> isize {
+        *self as isize
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<{}.{}: '{}'>",
+            stringify!(RoundingMode),
+            self.name(),
+            self.value(),
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    #[classmethod]
+    fn variants(_: &PyType, py: Python<'_>) -> EnumIterator {
+        EnumIterator::new::<Self>(py)
+    }
+
+    #[classmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(_: &PyType, data: &PyAny) -> PyResult<Self> {
+        let data_str: &str = data.str().and_then(|s| s.extract())?;
+        let tokenized = data_str.to_uppercase();
+        Self::from_str(&tokenized).map_err(to_pyvalue_err)
+    }
+
+    #[classattr]
+    #[pyo3(name = "HALF_UP")]
+    fn py_half_up() -> Self {
+        Self::HalfUp
+    }
+
+    #[classattr]
+    #[pyo3(name = "HALF_EVEN")]
+    fn py_half_even() -> Self {
+        Self::HalfEven
+    }
+
+    #[classattr]
+    #[pyo3(name = "FLOOR")]
+    fn py_floor() -> Self {
+        Self::Floor
+    }
+
+    #[classattr]
+    #[pyo3(name = "CEILING")]
+    fn py_ceiling() -> Self {
+        Self::Ceiling
+    }
+
+    #[classattr]
+    #[pyo3(name = "DOWN")]
+    fn py_down() -> Self {
+        Self::Down
+    }
+
+    #[classattr]
+    #[pyo3(name = "UP")]
+    fn py_up() -> Self {
+        Self::Up
+    }
+}
+
 #[pymethods]
 impl TimeInForce {
     #[new]
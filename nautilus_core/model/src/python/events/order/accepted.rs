@@ -13,14 +13,27 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
 use nautilus_core::{
-    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    python::{
+        serialization::{from_dict_pyo3, to_dict_pyo3},
+        to_pyvalue_err,
+    },
+    serialization::Serializable,
     uuid::UUID4,
 };
-use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
+use pyo3::{
+    basic::CompareOp,
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
 
 use crate::{
-    events::order::accepted::OrderAccepted,
+    events::order::{accepted::OrderAccepted, event::OrderEventAny},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
@@ -42,6 +55,7 @@ impl OrderAccepted {
         ts_event: u64,
         ts_init: u64,
         reconciliation: bool,
+        correlation_id: Option<UUID4>,
     ) -> PyResult<Self> {
         Self::new(
             trader_id,
@@ -54,6 +68,7 @@ impl OrderAccepted {
             ts_event.into(),
             ts_init.into(),
             reconciliation,
+            correlation_id,
         )
         .map_err(to_pyvalue_err)
     }
@@ -66,6 +81,12 @@ impl OrderAccepted {
         }
     }
 
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.event_id.hash(&mut h);
+        h.finish() as isize
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self)
     }
@@ -74,6 +95,72 @@ impl OrderAccepted {
         self.to_string()
     }
 
+    #[getter]
+    #[pyo3(name = "trader_id")]
+    fn py_trader_id(&self) -> TraderId {
+        self.trader_id
+    }
+
+    #[getter]
+    #[pyo3(name = "strategy_id")]
+    fn py_strategy_id(&self) -> StrategyId {
+        self.strategy_id
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "client_order_id")]
+    fn py_client_order_id(&self) -> ClientOrderId {
+        self.client_order_id
+    }
+
+    #[getter]
+    #[pyo3(name = "venue_order_id")]
+    fn py_venue_order_id(&self) -> VenueOrderId {
+        self.venue_order_id
+    }
+
+    #[getter]
+    #[pyo3(name = "account_id")]
+    fn py_account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    #[getter]
+    #[pyo3(name = "event_id")]
+    fn py_event_id(&self) -> UUID4 {
+        self.event_id
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> u64 {
+        self.ts_event.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> u64 {
+        self.ts_init.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "reconciliation")]
+    fn py_reconciliation(&self) -> bool {
+        self.reconciliation != 0
+    }
+
+    #[getter]
+    #[pyo3(name = "correlation_id")]
+    fn py_correlation_id(&self) -> Option<UUID4> {
+        self.correlation_id.get()
+    }
+
     #[staticmethod]
     #[pyo3(name = "from_dict")]
     fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
@@ -82,18 +169,19 @@ impl OrderAccepted {
 
     #[pyo3(name = "to_dict")]
     fn py_to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
-        let dict = PyDict::new(py);
-        dict.set_item("type", stringify!(OrderAccepted));
-        dict.set_item("trader_id", self.trader_id.to_string())?;
-        dict.set_item("strategy_id", self.strategy_id.to_string())?;
-        dict.set_item("instrument_id", self.instrument_id.to_string())?;
-        dict.set_item("client_order_id", self.client_order_id.to_string())?;
-        dict.set_item("venue_order_id", self.venue_order_id.to_string())?;
-        dict.set_item("account_id", self.account_id.to_string())?;
-        dict.set_item("event_id", self.event_id.to_string())?;
-        dict.set_item("ts_event", self.ts_event.as_u64())?;
-        dict.set_item("ts_init", self.ts_init.as_u64())?;
-        dict.set_item("reconciliation", self.reconciliation)?;
-        Ok(dict.into())
+        to_dict_pyo3(py, &OrderEventAny::Accepted(*self)).map(Into::into)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_msgpack")]
+    fn py_from_msgpack(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_msgpack_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    /// Return MsgPack encoded bytes representation of the object.
+    #[pyo3(name = "to_msgpack")]
+    fn py_to_msgpack(&self, py: Python<'_>) -> PyResult<Py<PyBytes>> {
+        let bytes = self.as_msgpack_bytes().map_err(to_pyvalue_err)?;
+        Ok(PyBytes::new(py, &bytes).into())
     }
 }
@@ -16,14 +16,17 @@
 use std::str::FromStr;
 
 use nautilus_core::{
-    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    python::{
+        serialization::{from_dict_pyo3, to_dict_pyo3},
+        to_pyvalue_err,
+    },
     uuid::UUID4,
 };
 use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 use ustr::Ustr;
 
 use crate::{
-    events::order::cancel_rejected::OrderCancelRejected,
+    events::order::{cancel_rejected::OrderCancelRejected, event::OrderEventAny},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
@@ -88,25 +91,6 @@ impl OrderCancelRejected {
 
     #[pyo3(name = "to_dict")]
     fn py_to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
-        let dict = PyDict::new(py);
-        dict.set_item("type", stringify!(OrderCancelRejected));
-        dict.set_item("trader_id", self.trader_id.to_string())?;
-        dict.set_item("strategy_id", self.strategy_id.to_string())?;
-        dict.set_item("instrument_id", self.instrument_id.to_string())?;
-        dict.set_item("client_order_id", self.client_order_id.to_string())?;
-        dict.set_item("reason", self.reason.as_str())?;
-        dict.set_item("event_id", self.event_id.to_string())?;
-        dict.set_item("ts_event", self.ts_event.as_u64())?;
-        dict.set_item("ts_init", self.ts_init.as_u64())?;
-        dict.set_item("reconciliation", self.reconciliation)?;
-        match self.venue_order_id {
-            Some(venue_order_id) => dict.set_item("venue_order_id", venue_order_id.to_string())?,
-            None => dict.set_item("venue_order_id", py.None())?,
-        }
-        match self.account_id {
-            Some(account_id) => dict.set_item("account_id", account_id.to_string())?,
-            None => dict.set_item("account_id", py.None())?,
-        }
-        Ok(dict.into())
+        to_dict_pyo3(py, &OrderEventAny::CancelRejected(*self)).map(Into::into)
     }
 }
@@ -13,15 +13,28 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
 use nautilus_core::{
-    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    python::{
+        serialization::{from_dict_pyo3, to_dict_pyo3},
+        to_pyvalue_err,
+    },
+    serialization::Serializable,
     uuid::UUID4,
 };
-use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
+use pyo3::{
+    basic::CompareOp,
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
 
 use crate::{
     enums::{LiquiditySide, OrderSide, OrderType},
-    events::order::filled::OrderFilled,
+    events::order::{event::OrderEventAny, filled::OrderFilled},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
@@ -87,6 +100,12 @@ impl OrderFilled {
         }
     }
 
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.event_id.hash(&mut h);
+        h.finish() as isize
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self)
     }
@@ -229,34 +248,67 @@ impl OrderFilled {
 
     #[pyo3(name = "to_dict")]
     pub fn py_to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
-        let dict = PyDict::new(py);
-        dict.set_item("type", stringify!(OrderFilled));
-        dict.set_item("trader_id", self.trader_id.to_string())?;
-        dict.set_item("strategy_id", self.strategy_id.to_string())?;
-        dict.set_item("instrument_id", self.instrument_id.to_string())?;
-        dict.set_item("client_order_id", self.client_order_id.to_string())?;
-        dict.set_item("venue_order_id", self.venue_order_id.to_string())?;
-        dict.set_item("account_id", self.account_id.to_string())?;
-        dict.set_item("trade_id", self.trade_id.to_string())?;
-        dict.set_item("order_side", self.order_side.to_string())?;
-        dict.set_item("order_type", self.order_type.to_string())?;
-        dict.set_item("last_qty", self.last_qty.to_string())?;
-        dict.set_item("last_px", self.last_px.to_string())?;
-        dict.set_item("currency", self.currency.code.to_string())?;
-        dict.set_item("liquidity_side", self.liquidity_side.to_string())?;
-        dict.set_item("event_id", self.event_id.to_string())?;
-        dict.set_item("ts_event", self.ts_event.as_u64())?;
-        dict.set_item("ts_init", self.ts_init.as_u64())?;
-        dict.set_item("reconciliation", self.reconciliation)?;
-        dict.set_item("info", PyDict::new(py))?;
-        match self.position_id {
-            Some(position_id) => dict.set_item("position_id", position_id.to_string())?,
-            None => dict.set_item("position_id", py.None())?,
-        }
-        match self.commission {
-            Some(commission) => dict.set_item("commission", commission.to_string())?,
-            None => dict.set_item("commission", py.None())?,
-        }
+        let dict = to_dict_pyo3(py, &OrderEventAny::Filled(*self))?;
+        // Fill info is only populated by adapters in Cython
+        dict.as_ref(py).set_item("info", PyDict::new(py))?;
         Ok(dict.into())
     }
+
+    #[staticmethod]
+    #[pyo3(name = "from_msgpack")]
+    fn py_from_msgpack(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_msgpack_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    /// Return MsgPack encoded bytes representation of the object.
+    #[pyo3(name = "to_msgpack")]
+    fn py_to_msgpack(&self, py: Python<'_>) -> PyResult<Py<PyBytes>> {
+        let bytes = self.as_msgpack_bytes().map_err(to_pyvalue_err)?;
+        Ok(PyBytes::new(py, &bytes).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::{types::PyLong, Python};
+    use rstest::rstest;
+
+    use crate::events::order::{filled::OrderFilled, stubs::*};
+
+    #[rstest]
+    fn test_to_dict(order_filled: OrderFilled) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let dict = order_filled.py_to_dict(py).unwrap();
+            let dict = dict.as_ref(py);
+            let item = |key: &str| dict.get_item(key).unwrap();
+
+            assert!(item("ts_event").is_exact_instance_of::<PyLong>());
+            assert_eq!(item("ts_event").extract::<u64>().unwrap(), 0);
+            assert!(item("reconciliation").extract::<bool>().is_ok());
+            assert!(item("position_id").is_none());
+            assert_eq!(item("type").extract::<&str>().unwrap(), "OrderFilled");
+            assert_eq!(item("last_qty").extract::<&str>().unwrap(), "0.561");
+            assert_eq!(item("liquidity_side").extract::<&str>().unwrap(), "TAKER");
+            assert_eq!(
+                item("commission").extract::<String>().unwrap(),
+                order_filled.commission.unwrap().to_string()
+            );
+        });
+    }
+
+    #[rstest]
+    fn test_from_dict(order_filled: OrderFilled) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let dict = order_filled.py_to_dict(py).unwrap().extract(py).unwrap();
+            let parsed = OrderFilled::py_from_dict(py, dict).unwrap();
+            assert_eq!(parsed, order_filled);
+        });
+    }
 }
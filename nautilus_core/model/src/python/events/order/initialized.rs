@@ -13,23 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
 
 use nautilus_core::{
     nanos::UnixNanos,
-    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    python::{
+        serialization::{from_dict_pyo3, to_dict_pyo3},
+        to_pyvalue_err,
+    },
+    serialization::Serializable,
     uuid::UUID4,
 };
 use pyo3::{
     basic::CompareOp,
     prelude::*,
-    types::{PyDict, PyList},
+    types::{PyBytes, PyDict},
 };
 use ustr::Ustr;
 
 use crate::{
     enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TrailingOffsetType, TriggerType},
-    events::order::initialized::OrderInitialized,
+    events::order::{event::OrderEventAny, initialized::OrderInitialized},
     identifiers::{
         client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
@@ -124,6 +131,12 @@ impl OrderInitialized {
         }
     }
 
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.event_id.hash(&mut h);
+        h.finish() as isize
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self)
     }
@@ -132,12 +145,213 @@ impl OrderInitialized {
         self.to_string()
     }
 
+    #[getter]
+    #[pyo3(name = "trader_id")]
+    fn py_trader_id(&self) -> TraderId {
+        self.trader_id
+    }
+
+    #[getter]
+    #[pyo3(name = "strategy_id")]
+    fn py_strategy_id(&self) -> StrategyId {
+        self.strategy_id
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "client_order_id")]
+    fn py_client_order_id(&self) -> ClientOrderId {
+        self.client_order_id
+    }
+
+    #[getter]
+    #[pyo3(name = "order_side")]
+    fn py_order_side(&self) -> OrderSide {
+        self.order_side
+    }
+
     #[getter]
     #[pyo3(name = "order_type")]
     fn py_order_type(&self) -> OrderType {
         self.order_type
     }
 
+    #[getter]
+    #[pyo3(name = "quantity")]
+    fn py_quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "time_in_force")]
+    fn py_time_in_force(&self) -> TimeInForce {
+        self.time_in_force
+    }
+
+    #[getter]
+    #[pyo3(name = "post_only")]
+    fn py_post_only(&self) -> bool {
+        self.post_only
+    }
+
+    #[getter]
+    #[pyo3(name = "reduce_only")]
+    fn py_reduce_only(&self) -> bool {
+        self.reduce_only
+    }
+
+    #[getter]
+    #[pyo3(name = "quote_quantity")]
+    fn py_quote_quantity(&self) -> bool {
+        self.quote_quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "reconciliation")]
+    fn py_reconciliation(&self) -> bool {
+        self.reconciliation
+    }
+
+    #[getter]
+    #[pyo3(name = "event_id")]
+    fn py_event_id(&self) -> UUID4 {
+        self.event_id
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> u64 {
+        self.ts_event.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> u64 {
+        self.ts_init.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "price")]
+    fn py_price(&self) -> Option<Price> {
+        self.price
+    }
+
+    #[getter]
+    #[pyo3(name = "trigger_price")]
+    fn py_trigger_price(&self) -> Option<Price> {
+        self.trigger_price
+    }
+
+    #[getter]
+    #[pyo3(name = "trigger_type")]
+    fn py_trigger_type(&self) -> Option<TriggerType> {
+        self.trigger_type
+    }
+
+    #[getter]
+    #[pyo3(name = "limit_offset")]
+    fn py_limit_offset(&self) -> Option<Price> {
+        self.ext().limit_offset
+    }
+
+    #[getter]
+    #[pyo3(name = "trailing_offset")]
+    fn py_trailing_offset(&self) -> Option<Price> {
+        self.ext().trailing_offset
+    }
+
+    #[getter]
+    #[pyo3(name = "trailing_offset_type")]
+    fn py_trailing_offset_type(&self) -> Option<TrailingOffsetType> {
+        self.ext().trailing_offset_type
+    }
+
+    #[getter]
+    #[pyo3(name = "expire_time")]
+    fn py_expire_time(&self) -> Option<u64> {
+        self.expire_time.map(std::convert::Into::into)
+    }
+
+    #[getter]
+    #[pyo3(name = "display_qty")]
+    fn py_display_qty(&self) -> Option<Quantity> {
+        self.display_qty
+    }
+
+    #[getter]
+    #[pyo3(name = "emulation_trigger")]
+    fn py_emulation_trigger(&self) -> Option<TriggerType> {
+        self.emulation_trigger
+    }
+
+    #[getter]
+    #[pyo3(name = "trigger_instrument_id")]
+    fn py_trigger_instrument_id(&self) -> Option<InstrumentId> {
+        self.trigger_instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "contingency_type")]
+    fn py_contingency_type(&self) -> Option<ContingencyType> {
+        self.ext().contingency_type
+    }
+
+    #[getter]
+    #[pyo3(name = "order_list_id")]
+    fn py_order_list_id(&self) -> Option<OrderListId> {
+        self.ext().order_list_id
+    }
+
+    #[getter]
+    #[pyo3(name = "linked_order_ids")]
+    fn py_linked_order_ids(&self) -> Option<Vec<ClientOrderId>> {
+        self.linked_order_ids
+            .as_deref()
+            .map(<[ClientOrderId]>::to_vec)
+    }
+
+    #[getter]
+    #[pyo3(name = "parent_order_id")]
+    fn py_parent_order_id(&self) -> Option<ClientOrderId> {
+        self.ext().parent_order_id
+    }
+
+    #[getter]
+    #[pyo3(name = "exec_algorithm_id")]
+    fn py_exec_algorithm_id(&self) -> Option<ExecAlgorithmId> {
+        self.exec_algorithm_id
+    }
+
+    #[getter]
+    #[pyo3(name = "exec_algorithm_params")]
+    fn py_exec_algorithm_params(&self) -> Option<HashMap<&str, &str>> {
+        self.exec_algorithm_params.as_ref().map(|params| {
+            params
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect()
+        })
+    }
+
+    #[getter]
+    #[pyo3(name = "exec_spawn_id")]
+    fn py_exec_spawn_id(&self) -> Option<ClientOrderId> {
+        self.exec_spawn_id
+    }
+
+    #[getter]
+    #[pyo3(name = "tags")]
+    fn py_tags(&self) -> Option<Vec<&str>> {
+        self.tags
+            .as_ref()
+            .map(|tags| tags.iter().map(Ustr::as_str).collect())
+    }
+
     #[staticmethod]
     #[pyo3(name = "from_dict")]
     fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
@@ -146,20 +360,7 @@ impl OrderInitialized {
 
     #[pyo3(name = "to_dict")]
     fn py_to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
-        let dict = PyDict::new(py);
-        dict.set_item("type", stringify!(OrderInitiliazed));
-        dict.set_item("trader_id", self.trader_id.to_string())?;
-        dict.set_item("strategy_id", self.strategy_id.to_string())?;
-        dict.set_item("instrument_id", self.instrument_id.to_string())?;
-        dict.set_item("client_order_id", self.client_order_id.to_string())?;
-        dict.set_item("order_side", self.order_side.to_string())?;
-        dict.set_item("order_type", self.order_type.to_string())?;
-        dict.set_item("quantity", self.quantity.to_string())?;
-        dict.set_item("time_in_force", self.time_in_force.to_string())?;
-        dict.set_item("post_only", self.post_only)?;
-        dict.set_item("reduce_only", self.reduce_only)?;
-        dict.set_item("quote_quantity", self.quote_quantity)?;
-        dict.set_item("reconciliation", self.reconciliation)?;
+        let dict = to_dict_pyo3(py, &OrderEventAny::Initialized(self.clone()))?;
         // TODO remove options as in legacy cython only
         let options = PyDict::new(py);
         if self.order_type == OrderType::StopMarket {
@@ -167,111 +368,20 @@ impl OrderInitialized {
             options.set_item("trigger_price", self.trigger_price.map(|x| x.to_string()))?;
             options.set_item("expire_time_ns", self.expire_time.map(|x| x.to_string()))?;
         }
-        dict.set_item("options", options)?;
-        dict.set_item("event_id", self.event_id.to_string())?;
-        dict.set_item("ts_event", self.ts_event.as_u64())?;
-        dict.set_item("ts_init", self.ts_init.as_u64())?;
-        match self.price {
-            Some(price) => dict.set_item("price", price.to_string())?,
-            None => dict.set_item("price", py.None())?,
-        }
-        match self.trigger_price {
-            Some(trigger_price) => dict.set_item("trigger_price", trigger_price.to_string())?,
-            None => dict.set_item("trigger_price", py.None())?,
-        }
-        match self.trigger_type {
-            Some(trigger_type) => dict.set_item("trigger_type", trigger_type.to_string())?,
-            None => dict.set_item("trigger_type", py.None())?,
-        }
-        match self.limit_offset {
-            Some(limit_offset) => dict.set_item("limit_offset", limit_offset.to_string())?,
-            None => dict.set_item("limit_offset", py.None())?,
-        }
-        match self.trailing_offset {
-            Some(trailing_offset) => {
-                dict.set_item("trailing_offset", trailing_offset.to_string())?;
-            }
-            None => dict.set_item("trailing_offset", py.None())?,
-        }
-        match self.trailing_offset_type {
-            Some(trailing_offset_type) => {
-                dict.set_item("trailing_offset_type", trailing_offset_type.to_string())?;
-            }
-            None => dict.set_item("trailing_offset_type", py.None())?,
-        }
-        match self.expire_time {
-            Some(expire_time) => dict.set_item("expire_time", expire_time.as_u64())?,
-            None => dict.set_item("expire_time", py.None())?,
-        }
-        match self.display_qty {
-            Some(display_qty) => dict.set_item("display_qty", display_qty.to_string())?,
-            None => dict.set_item("display_qty", py.None())?,
-        }
-        match self.emulation_trigger {
-            Some(emulation_trigger) => {
-                dict.set_item("emulation_trigger", emulation_trigger.to_string())?;
-            }
-            None => dict.set_item("emulation_trigger", py.None())?,
-        }
-        match self.trigger_instrument_id {
-            Some(trigger_instrument_id) => {
-                dict.set_item("trigger_instrument_id", trigger_instrument_id.to_string())?;
-            }
-            None => dict.set_item("trigger_instrument_id", py.None())?,
-        }
-        match self.contingency_type {
-            Some(contingency_type) => {
-                dict.set_item("contingency_type", contingency_type.to_string())?;
-            }
-            None => dict.set_item("contingency_type", py.None())?,
-        }
-        match self.order_list_id {
-            Some(order_list_id) => dict.set_item("order_list_id", order_list_id.to_string())?,
-            None => dict.set_item("order_list_id", py.None())?,
-        }
-        match &self.linked_order_ids {
-            Some(linked_order_ids) => {
-                let py_linked_order_ids = PyList::empty(py);
-                for linked_order_id in linked_order_ids {
-                    py_linked_order_ids.append(linked_order_id.to_string())?;
-                }
-                dict.set_item("linked_order_ids", py_linked_order_ids)?;
-            }
-            None => dict.set_item("linked_order_ids", py.None())?,
-        }
-        match self.parent_order_id {
-            Some(parent_order_id) => {
-                dict.set_item("parent_order_id", parent_order_id.to_string())?;
-            }
-            None => dict.set_item("parent_order_id", py.None())?,
-        }
-        match self.exec_algorithm_id {
-            Some(exec_algorithm_id) => {
-                dict.set_item("exec_algorithm_id", exec_algorithm_id.to_string())?;
-            }
-            None => dict.set_item("exec_algorithm_id", py.None())?,
-        }
-        match &self.exec_algorithm_params {
-            Some(exec_algorithm_params) => {
-                let py_exec_algorithm_params = PyDict::new(py);
-                for (key, value) in exec_algorithm_params {
-                    py_exec_algorithm_params.set_item(key.to_string(), value.to_string())?;
-                }
-                dict.set_item("exec_algorithm_params", py_exec_algorithm_params)?;
-            }
-            None => dict.set_item("exec_algorithm_params", py.None())?,
-        }
-        match self.exec_spawn_id {
-            Some(exec_spawn_id) => dict.set_item("exec_spawn_id", exec_spawn_id.to_string())?,
-            None => dict.set_item("exec_spawn_id", py.None())?,
-        }
-        match &self.tags {
-            Some(tags) => dict.set_item(
-                "tags",
-                tags.iter().map(|x| x.to_string()).collect::<Vec<String>>(),
-            )?,
-            None => dict.set_item("tags", py.None())?,
-        }
+        dict.as_ref(py).set_item("options", options)?;
         Ok(dict.into())
     }
+
+    #[staticmethod]
+    #[pyo3(name = "from_msgpack")]
+    fn py_from_msgpack(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_msgpack_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    /// Return MsgPack encoded bytes representation of the object.
+    #[pyo3(name = "to_msgpack")]
+    fn py_to_msgpack(&self, py: Python<'_>) -> PyResult<Py<PyBytes>> {
+        let bytes = self.as_msgpack_bytes().map_err(to_pyvalue_err)?;
+        Ok(PyBytes::new(py, &bytes).into())
+    }
 }
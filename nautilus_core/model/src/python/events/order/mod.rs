@@ -120,3 +120,150 @@ pub fn pyobject_to_order_event(py: Python, order_event: PyObject) -> PyResult<Or
         )),
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::{types::PyDict, IntoPy, PyObject, Python};
+    use rstest::rstest;
+
+    use crate::events::order::{
+        accepted::OrderAccepted, canceled::OrderCanceled, denied::OrderDenied, filled::OrderFilled,
+        initialized::OrderInitialized, rejected::OrderRejected, stubs::*,
+        submitted::OrderSubmitted,
+    };
+
+    /// Checks the Python protocol of an event class, where `event` and `cython_keys` (the keys
+    /// of the equivalent Cython event dict) are provided as locals.
+    const EVENT_PROTOCOL: &str = r#"
+cls = type(event)
+values = event.to_dict()
+
+assert values["type"] == cls.__name__, values["type"]
+for key in cython_keys:
+    assert key in values, f"missing Cython key {key!r}"
+for key in values:
+    if key not in ("type", "options", "info"):
+        assert hasattr(event, key), f"missing getter {key!r}"
+assert isinstance(values["ts_event"], int)
+assert isinstance(values["ts_init"], int)
+
+assert cls.from_dict(values) == event
+assert cls.from_msgpack(event.to_msgpack()) == event
+assert hash(cls.from_dict(values)) == hash(event)
+assert cls.__name__ in repr(event)
+"#;
+
+    const COMMON_KEYS: [&str; 8] = [
+        "type",
+        "trader_id",
+        "strategy_id",
+        "instrument_id",
+        "client_order_id",
+        "event_id",
+        "ts_event",
+        "ts_init",
+    ];
+
+    fn check_event_protocol<T: IntoPy<PyObject>>(event: T, keys: &[&str]) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let cython_keys: Vec<&str> = COMMON_KEYS.iter().chain(keys).copied().collect();
+            let locals = PyDict::new(py);
+            locals.set_item("event", event.into_py(py)).unwrap();
+            locals.set_item("cython_keys", cython_keys).unwrap();
+
+            if let Err(e) = py.run(EVENT_PROTOCOL, None, Some(locals)) {
+                e.print(py);
+                panic!("Python assertions failed: {e}");
+            }
+        });
+    }
+
+    #[rstest]
+    fn test_order_denied(order_denied_max_submitted_rate: OrderDenied) {
+        check_event_protocol(order_denied_max_submitted_rate, &["reason"]);
+    }
+
+    #[rstest]
+    fn test_order_submitted(order_submitted: OrderSubmitted) {
+        check_event_protocol(order_submitted, &["account_id"]);
+    }
+
+    #[rstest]
+    fn test_order_accepted(order_accepted: OrderAccepted) {
+        check_event_protocol(
+            order_accepted,
+            &["venue_order_id", "account_id", "reconciliation"],
+        );
+    }
+
+    #[rstest]
+    fn test_order_rejected(order_rejected_insufficient_margin: OrderRejected) {
+        check_event_protocol(
+            order_rejected_insufficient_margin,
+            &["account_id", "reason", "reconciliation"],
+        );
+    }
+
+    #[rstest]
+    fn test_order_canceled(order_canceled: OrderCanceled) {
+        check_event_protocol(
+            order_canceled,
+            &["venue_order_id", "account_id", "reconciliation"],
+        );
+    }
+
+    #[rstest]
+    fn test_order_filled(order_filled: OrderFilled) {
+        check_event_protocol(
+            order_filled,
+            &[
+                "venue_order_id",
+                "account_id",
+                "trade_id",
+                "position_id",
+                "order_side",
+                "order_type",
+                "last_qty",
+                "last_px",
+                "currency",
+                "commission",
+                "liquidity_side",
+                "info",
+                "reconciliation",
+            ],
+        );
+    }
+
+    #[rstest]
+    fn test_order_initialized(order_initialized_buy_limit: OrderInitialized) {
+        check_event_protocol(
+            order_initialized_buy_limit,
+            &[
+                "order_side",
+                "order_type",
+                "quantity",
+                "time_in_force",
+                "post_only",
+                "reduce_only",
+                "quote_quantity",
+                "options",
+                "emulation_trigger",
+                "trigger_instrument_id",
+                "contingency_type",
+                "order_list_id",
+                "linked_order_ids",
+                "parent_order_id",
+                "exec_algorithm_id",
+                "exec_algorithm_params",
+                "exec_spawn_id",
+                "tags",
+                "reconciliation",
+            ],
+        );
+    }
+}
@@ -16,14 +16,17 @@
 use std::str::FromStr;
 
 use nautilus_core::{
-    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    python::{
+        serialization::{from_dict_pyo3, to_dict_pyo3},
+        to_pyvalue_err,
+    },
     uuid::UUID4,
 };
 use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 use ustr::Ustr;
 
 use crate::{
-    events::order::modify_rejected::OrderModifyRejected,
+    events::order::{event::OrderEventAny, modify_rejected::OrderModifyRejected},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
@@ -88,29 +91,6 @@ impl OrderModifyRejected {
 
     #[pyo3(name = "to_dict")]
     fn py_to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
-        let dict = PyDict::new(py);
-        dict.set_item("type", stringify!(OrderModifyRejected));
-        dict.set_item("trader_id", self.trader_id.to_string())?;
-        dict.set_item("strategy_id", self.strategy_id.to_string())?;
-        dict.set_item("instrument_id", self.instrument_id.to_string())?;
-        dict.set_item("client_order_id", self.client_order_id.to_string())?;
-        dict.set_item(
-            "venue_order_id",
-            self.venue_order_id.map_or_else(
-                || "None".to_string(),
-                |venue_order_id| format!("{venue_order_id}"),
-            ),
-        )?;
-        dict.set_item(
-            "account_id",
-            self.account_id
-                .map_or_else(|| "None".to_string(), |account_id| format!("{account_id}")),
-        )?;
-        dict.set_item("reason", self.reason.to_string())?;
-        dict.set_item("event_id", self.event_id.to_string())?;
-        dict.set_item("reconciliation", self.reconciliation)?;
-        dict.set_item("ts_event", self.ts_event.as_u64())?;
-        dict.set_item("ts_init", self.ts_init.as_u64())?;
-        Ok(dict.into())
+        to_dict_pyo3(py, &OrderEventAny::ModifyRejected(*self)).map(Into::into)
     }
 }
@@ -14,13 +14,16 @@
 // -------------------------------------------------------------------------------------------------
 
 use nautilus_core::{
-    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    python::{
+        serialization::{from_dict_pyo3, to_dict_pyo3},
+        to_pyvalue_err,
+    },
     uuid::UUID4,
 };
 use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 
 use crate::{
-    events::order::updated::OrderUpdated,
+    events::order::{event::OrderEventAny, updated::OrderUpdated},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
@@ -46,6 +49,9 @@ impl OrderUpdated {
         account_id: Option<AccountId>,
         price: Option<Price>,
         trigger_price: Option<Price>,
+        prev_quantity: Option<Quantity>,
+        prev_price: Option<Price>,
+        prev_trigger_price: Option<Price>,
     ) -> PyResult<Self> {
         Self::new(
             trader_id,
@@ -61,6 +67,9 @@ impl OrderUpdated {
             account_id,
             price,
             trigger_price,
+            prev_quantity,
+            prev_price,
+            prev_trigger_price,
         )
         .map_err(to_pyvalue_err)
     }
@@ -89,33 +98,6 @@ impl OrderUpdated {
 
     #[pyo3(name = "to_dict")]
     fn py_to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
-        let dict = PyDict::new(py);
-        dict.set_item("type", stringify!(OrderUpdated));
-        dict.set_item("trader_id", self.trader_id.to_string())?;
-        dict.set_item("strategy_id", self.strategy_id.to_string())?;
-        dict.set_item("instrument_id", self.instrument_id.to_string())?;
-        dict.set_item("client_order_id", self.client_order_id.to_string())?;
-        dict.set_item("quantity", self.quantity.to_string())?;
-        dict.set_item("event_id", self.event_id.to_string())?;
-        dict.set_item("ts_event", self.ts_event.as_u64())?;
-        dict.set_item("ts_init", self.ts_init.as_u64())?;
-        dict.set_item("reconciliation", self.reconciliation)?;
-        match self.venue_order_id {
-            Some(venue_order_id) => dict.set_item("venue_order_id", venue_order_id.to_string())?,
-            None => dict.set_item("venue_order_id", py.None())?,
-        }
-        match self.account_id {
-            Some(account_id) => dict.set_item("account_id", account_id.to_string())?,
-            None => dict.set_item("account_id", py.None())?,
-        }
-        match self.price {
-            Some(price) => dict.set_item("price", price.to_string())?,
-            None => dict.set_item("price", py.None())?,
-        }
-        match self.trigger_price {
-            Some(trigger_price) => dict.set_item("trigger_price", trigger_price.to_string())?,
-            None => dict.set_item("trigger_price", py.None())?,
-        }
-        Ok(dict.into())
+        to_dict_pyo3(py, &OrderEventAny::Updated(*self)).map(Into::into)
     }
 }
@@ -45,6 +45,8 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<crate::data::depth::OrderBookDepth10>()?;
     m.add_class::<crate::data::quote::QuoteTick>()?;
     m.add_class::<crate::data::trade::TradeTick>()?;
+    m.add_class::<crate::data::close::InstrumentClose>()?;
+    m.add_class::<crate::data::volume_profile::VolumeProfile>()?;
     // Enums
     m.add_class::<crate::enums::AccountType>()?;
     m.add_class::<crate::enums::AggregationSource>()?;
@@ -66,6 +68,7 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<crate::enums::OrderType>()?;
     m.add_class::<crate::enums::PositionSide>()?;
     m.add_class::<crate::enums::PriceType>()?;
+    m.add_class::<crate::enums::RoundingMode>()?;
     m.add_class::<crate::enums::TimeInForce>()?;
     m.add_class::<crate::enums::TradingState>()?;
     m.add_class::<crate::enums::TrailingOffsetType>()?;
@@ -113,6 +116,7 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     // Order book
     m.add_class::<crate::orderbook::book::OrderBook>()?;
     m.add_class::<crate::orderbook::level::Level>()?;
+    m.add_class::<crate::python::orderbook::book::BookLevelIterator>()?;
     m.add_function(wrap_pyfunction!(
         crate::python::orderbook::book::py_update_book_with_quote_tick,
         m
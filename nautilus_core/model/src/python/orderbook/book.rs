@@ -14,7 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyBytes};
 
 use crate::{
     data::{
@@ -25,13 +25,32 @@ use crate::{
     identifiers::instrument_id::InstrumentId,
     orderbook::{
         aggregation::{update_book_with_quote_tick, update_book_with_trade_tick},
-        analysis::book_check_integrity,
         book::OrderBook,
         level::Level,
     },
     types::{price::Price, quantity::Quantity},
 };
 
+/// Python iterator over the price and total size of order book levels.
+///
+/// Python cannot hold a borrow of the book, so the levels are copied when the iterator is
+/// created and later mutations of the book are not reflected.
+#[pyclass]
+pub struct BookLevelIterator {
+    iter: std::vec::IntoIter<(Price, Quantity)>,
+}
+
+#[pymethods]
+impl BookLevelIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(Price, Quantity)> {
+        slf.iter.next()
+    }
+}
+
 #[pymethods]
 impl OrderBook {
     #[new]
@@ -132,13 +151,15 @@ impl OrderBook {
     }
 
     #[pyo3(name = "apply_delta")]
-    fn py_apply_delta(&mut self, delta: OrderBookDelta) {
-        self.apply_delta(delta);
+    fn py_apply_delta(&mut self, delta: OrderBookDelta) -> PyResult<()> {
+        self.apply_delta(delta).map_err(to_pyruntime_err)?;
+        Ok(())
     }
 
     #[pyo3(name = "apply_deltas")]
-    fn py_apply_deltas(&mut self, deltas: OrderBookDeltas) {
-        self.apply_deltas(deltas);
+    fn py_apply_deltas(&mut self, deltas: OrderBookDeltas) -> PyResult<()> {
+        self.apply_deltas(deltas).map_err(to_pyruntime_err)?;
+        Ok(())
     }
 
     #[pyo3(name = "apply_depth")]
@@ -146,21 +167,79 @@ impl OrderBook {
         self.apply_depth(depth);
     }
 
+    #[getter]
+    #[pyo3(name = "validate")]
+    fn py_validate(&self) -> bool {
+        self.validate
+    }
+
+    #[setter]
+    #[pyo3(name = "validate")]
+    fn py_set_validate(&mut self, value: bool) {
+        self.validate = value;
+    }
+
+    #[pyo3(name = "to_quote_tick")]
+    fn py_to_quote_tick(&self) -> Option<QuoteTick> {
+        self.to_quote_tick()
+    }
+
     #[pyo3(name = "check_integrity")]
     fn py_check_integrity(&mut self) -> PyResult<()> {
-        book_check_integrity(self).map_err(to_pyruntime_err)
+        self.check_integrity().map_err(to_pyruntime_err)
     }
 
     #[pyo3(name = "bids")]
     fn py_bids(&self) -> Vec<Level> {
         // TODO: Improve efficiency
-        self.bids().map(|level_ref| (*level_ref).clone()).collect()
+        self.bid_levels()
+            .map(|level_ref| (*level_ref).clone())
+            .collect()
     }
 
     #[pyo3(name = "asks")]
     fn py_asks(&self) -> Vec<Level> {
         // TODO: Improve efficiency
-        self.asks().map(|level_ref| (*level_ref).clone()).collect()
+        self.ask_levels()
+            .map(|level_ref| (*level_ref).clone())
+            .collect()
+    }
+
+    #[pyo3(name = "snapshot")]
+    #[pyo3(signature = (depth=10))]
+    fn py_snapshot(&self, depth: usize) -> OrderBookDepth10 {
+        self.snapshot(depth)
+    }
+
+    #[pyo3(name = "iter_bids")]
+    #[pyo3(signature = (depth=None))]
+    fn py_iter_bids(&self, depth: Option<usize>) -> BookLevelIterator {
+        BookLevelIterator {
+            iter: self.iter_bids(depth).collect::<Vec<_>>().into_iter(),
+        }
+    }
+
+    #[pyo3(name = "iter_asks")]
+    #[pyo3(signature = (depth=None))]
+    fn py_iter_asks(&self, depth: Option<usize>) -> BookLevelIterator {
+        BookLevelIterator {
+            iter: self.iter_asks(depth).collect::<Vec<_>>().into_iter(),
+        }
+    }
+
+    #[pyo3(name = "levels_filled_by")]
+    fn py_levels_filled_by(&self, qty: Quantity, order_side: OrderSide) -> usize {
+        self.levels_filled_by(qty, order_side)
+    }
+
+    #[pyo3(name = "to_ladder_snapshot")]
+    #[pyo3(signature = (depth=None))]
+    fn py_to_ladder_snapshot(&self, py: Python, depth: Option<usize>) -> PyResult<Py<PyBytes>> {
+        let bytes = self
+            .to_ladder_snapshot(depth)
+            .encode()
+            .map_err(to_pyvalue_err)?;
+        Ok(PyBytes::new(py, &bytes).into())
     }
 
     #[pyo3(name = "best_bid_price")]
@@ -194,12 +273,12 @@ impl OrderBook {
     }
 
     #[pyo3(name = "get_avg_px_for_quantity")]
-    fn py_get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
+    fn py_get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> Option<f64> {
         self.get_avg_px_for_quantity(qty, order_side)
     }
 
     #[pyo3(name = "get_quantity_for_price")]
-    fn py_get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
+    fn py_get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> Quantity {
         self.get_quantity_for_price(price, order_side)
     }
 
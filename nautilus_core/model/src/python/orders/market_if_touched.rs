@@ -21,7 +21,7 @@ use ustr::Ustr;
 
 use crate::{
     enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TriggerType},
-    events::order::initialized::OrderInitialized,
+    events::order::{initialized::OrderInitialized, timeline::format_order_timeline},
     identifiers::{
         client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
@@ -123,4 +123,9 @@ impl MarketIfTouchedOrder {
         let event_any = pyobject_to_order_event(py, event).unwrap();
         self.apply(event_any).map(|_| ()).map_err(to_pyruntime_err)
     }
+
+    #[pyo3(name = "timeline")]
+    fn py_timeline(&self) -> String {
+        format_order_timeline(&self.events())
+    }
 }
@@ -29,13 +29,22 @@ use pyo3::{
 };
 use rust_decimal::{Decimal, RoundingStrategy};
 
-use crate::types::{currency::Currency, money::Money};
+use crate::{
+    python::common::decimal_from_pyobject,
+    types::{currency::Currency, money::Money},
+};
 
 #[pymethods]
 impl Money {
+    /// Creates a new instance from a `float`, or exactly from a `Decimal`, `int` or `str`.
     #[new]
-    fn py_new(value: f64, currency: Currency) -> PyResult<Self> {
-        Self::new(value, currency).map_err(to_pyvalue_err)
+    fn py_new(value: &PyAny, currency: Currency) -> PyResult<Self> {
+        if value.is_instance_of::<PyFloat>() {
+            Self::new(value.extract()?, currency)
+        } else {
+            Self::from_decimal(decimal_from_pyobject(value)?, currency)
+        }
+        .map_err(to_pyvalue_err)
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
@@ -66,7 +75,7 @@ impl Money {
             let other_float: f64 = other.extract(py)?;
             Ok((self.as_f64() + other_float).into_py(py))
         } else if let Ok(other_qty) = other.extract::<Self>(py) {
-            Ok((self.as_decimal() + other_qty.as_decimal()).into_py(py))
+            Ok(((*self + other_qty).map_err(to_pyvalue_err)?.as_decimal()).into_py(py))
         } else if let Ok(other_dec) = other.extract::<Decimal>(py) {
             Ok((self.as_decimal() + other_dec).into_py(py))
         } else {
@@ -82,7 +91,7 @@ impl Money {
             let other_float: f64 = other.extract(py)?;
             Ok((other_float + self.as_f64()).into_py(py))
         } else if let Ok(other_qty) = other.extract::<Self>(py) {
-            Ok((other_qty.as_decimal() + self.as_decimal()).into_py(py))
+            Ok(((other_qty + *self).map_err(to_pyvalue_err)?.as_decimal()).into_py(py))
         } else if let Ok(other_dec) = other.extract::<Decimal>(py) {
             Ok((other_dec + self.as_decimal()).into_py(py))
         } else {
@@ -98,7 +107,7 @@ impl Money {
             let other_float: f64 = other.extract(py)?;
             Ok((self.as_f64() - other_float).into_py(py))
         } else if let Ok(other_qty) = other.extract::<Self>(py) {
-            Ok((self.as_decimal() - other_qty.as_decimal()).into_py(py))
+            Ok(((*self - other_qty).map_err(to_pyvalue_err)?.as_decimal()).into_py(py))
         } else if let Ok(other_dec) = other.extract::<Decimal>(py) {
             Ok((self.as_decimal() - other_dec).into_py(py))
         } else {
@@ -114,7 +123,7 @@ impl Money {
             let other_float: f64 = other.extract(py)?;
             Ok((other_float - self.as_f64()).into_py(py))
         } else if let Ok(other_qty) = other.extract::<Self>(py) {
-            Ok((other_qty.as_decimal() - self.as_decimal()).into_py(py))
+            Ok(((other_qty - *self).map_err(to_pyvalue_err)?.as_decimal()).into_py(py))
         } else if let Ok(other_dec) = other.extract::<Decimal>(py) {
             Ok((other_dec - self.as_decimal()).into_py(py))
         } else {
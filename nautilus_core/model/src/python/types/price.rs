@@ -28,13 +28,22 @@ use pyo3::{
 };
 use rust_decimal::{Decimal, RoundingStrategy};
 
-use crate::types::{fixed::fixed_i64_to_f64, price::Price};
+use crate::{
+    python::common::decimal_from_pyobject,
+    types::{fixed::fixed_i64_to_f64, price::Price},
+};
 
 #[pymethods]
 impl Price {
+    /// Creates a new instance from a `float`, or exactly from a `Decimal`, `int` or `str`.
     #[new]
-    fn py_new(value: f64, precision: u8) -> PyResult<Self> {
-        Self::new(value, precision).map_err(to_pyvalue_err)
+    fn py_new(value: &PyAny, precision: u8) -> PyResult<Self> {
+        if value.is_instance_of::<PyFloat>() {
+            Self::new(value.extract()?, precision)
+        } else {
+            Self::from_decimal(decimal_from_pyobject(value)?, precision)
+        }
+        .map_err(to_pyvalue_err)
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
@@ -320,7 +329,8 @@ impl Price {
     }
 
     #[getter]
-    fn raw(&self) -> i64 {
+    #[pyo3(name = "raw")]
+    fn py_raw(&self) -> i64 {
         self.raw
     }
 
@@ -379,3 +389,36 @@ impl Price {
         self.to_formatted_string()
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::{types::PyDict, Python};
+    use rstest::rstest;
+
+    use crate::types::price::Price;
+
+    #[rstest]
+    fn test_decimal_round_trip() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let locals = PyDict::new(py);
+            locals.set_item("Price", py.get_type::<Price>()).unwrap();
+            let script = r#"
+from decimal import Decimal
+
+price = Price(Decimal("-1.23456789"), 8)
+assert price.raw == -1_234_567_890, price.raw
+assert price.as_decimal() == Decimal("-1.23456789"), price.as_decimal()
+assert Price(Decimal("1.005"), 2) == Price("1.01", 2)
+"#;
+            if let Err(e) = py.run(script, None, Some(locals)) {
+                e.print(py);
+                panic!("Python assertions failed: {e}");
+            }
+        });
+    }
+}
@@ -28,13 +28,19 @@ use pyo3::{
 };
 use rust_decimal::{Decimal, RoundingStrategy};
 
-use crate::types::quantity::Quantity;
+use crate::{python::common::decimal_from_pyobject, types::quantity::Quantity};
 
 #[pymethods]
 impl Quantity {
+    /// Creates a new instance from a `float`, or exactly from a `Decimal`, `int` or `str`.
     #[new]
-    fn py_new(value: f64, precision: u8) -> PyResult<Self> {
-        Self::new(value, precision).map_err(to_pyvalue_err)
+    fn py_new(value: &PyAny, precision: u8) -> PyResult<Self> {
+        if value.is_instance_of::<PyFloat>() {
+            Self::new(value.extract()?, precision)
+        } else {
+            Self::from_decimal(decimal_from_pyobject(value)?, precision)
+        }
+        .map_err(to_pyvalue_err)
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
@@ -379,3 +385,42 @@ impl Quantity {
         self.to_formatted_string()
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::{types::PyDict, Python};
+    use rstest::rstest;
+
+    use crate::types::quantity::Quantity;
+
+    #[rstest]
+    fn test_decimal_round_trip() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let locals = PyDict::new(py);
+            locals
+                .set_item("Quantity", py.get_type::<Quantity>())
+                .unwrap();
+            let script = r#"
+from decimal import Decimal
+
+qty = Quantity(Decimal("0.00000001"), 8)
+assert qty.raw == 10, qty.raw
+assert qty.precision == 8
+assert str(qty) == "0.00000001", str(qty)
+assert qty.as_decimal() == Decimal("0.00000001"), qty.as_decimal()
+
+assert Quantity(Decimal("1.5"), 1) == Quantity(1.5, 1) == Quantity("1.5", 1)
+assert Quantity(10, 0).as_decimal() == Decimal(10)
+"#;
+            if let Err(e) = py.run(script, None, Some(locals)) {
+                e.print(py);
+                panic!("Python assertions failed: {e}");
+            }
+        });
+    }
+}
@@ -0,0 +1,268 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides batched `MsgPack` encoding and decoding of order events.
+//!
+//! Events are written as a stream of records in the framed format of `nautilus_core::msgpack`,
+//! with each payload serialized with named fields (as for `Serializable::as_msgpack_bytes`).
+//! Encoding appends directly into a caller owned buffer, so a single allocation can be reused
+//! across batches, and decoding reads payloads in place from the given byte slice.
+
+use anyhow::Context;
+use nautilus_core::msgpack::{MsgPackStreamReader, MsgPackStreamWriter, PayloadIter};
+
+use crate::events::order::event::OrderEventAny;
+
+/// Appends the given `events` to `buf` as framed `MsgPack` records.
+///
+/// Existing contents of `buf` are kept, so clear the buffer to reuse it for a new batch.
+///
+/// # Errors
+///
+/// This function returns an error if an event fails to serialize, in which case `buf` contains
+/// the records for the events before it.
+pub fn encode_events(events: &[OrderEventAny], buf: &mut Vec<u8>) -> anyhow::Result<()> {
+    let mut writer = MsgPackStreamWriter::new(buf);
+    for event in events {
+        writer.write(event)?;
+    }
+    Ok(())
+}
+
+/// Appends the given `event` to `buf` as a framed `MsgPack` record, returning the number of
+/// bytes written.
+///
+/// # Errors
+///
+/// This function returns an error if the event fails to serialize, in which case `buf` is left
+/// unchanged.
+pub fn encode_event(event: &OrderEventAny, buf: &mut Vec<u8>) -> anyhow::Result<usize> {
+    MsgPackStreamWriter::new(buf).write(event)
+}
+
+/// Decodes all events from the given framed `MsgPack` records.
+///
+/// # Errors
+///
+/// This function returns an error if any record is corrupt or fails to decode.
+pub fn decode_events(bytes: &[u8]) -> anyhow::Result<Vec<OrderEventAny>> {
+    Ok(MsgPackStreamReader::default().read_bytes(bytes)?.records)
+}
+
+/// Lazily decodes events from a byte slice of framed `MsgPack` records.
+///
+/// A corrupt record, or an event which fails to decode, is yielded as an error and reading
+/// resumes at the next valid record.
+#[derive(Clone, Debug)]
+pub struct EventStreamReader<'a> {
+    payloads: PayloadIter<'a>,
+}
+
+impl<'a> EventStreamReader<'a> {
+    #[must_use]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            payloads: MsgPackStreamReader::tolerant().payloads(bytes),
+        }
+    }
+
+    /// Returns the byte offset of the next record to be read.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.payloads.offset()
+    }
+
+    /// Returns the `MsgPack` payload of the next record without decoding it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the record is corrupt.
+    pub fn next_payload(&mut self) -> Option<anyhow::Result<&'a [u8]>> {
+        self.payloads.next()
+    }
+}
+
+impl Iterator for EventStreamReader<'_> {
+    type Item = anyhow::Result<OrderEventAny>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset();
+        let payload = match self.next_payload()? {
+            Ok(payload) => payload,
+            Err(e) => return Some(Err(e)),
+        };
+        Some(
+            rmp_serde::from_slice(payload)
+                .with_context(|| format!("Failed to decode event at byte offset {offset}")),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::msgpack::RECORD_HEADER_LEN;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        events::order::{
+            accepted::OrderAccepted, canceled::OrderCanceled, filled::OrderFilled,
+            initialized::OrderInitialized, submitted::OrderSubmitted, updated::OrderUpdated,
+        },
+        identifiers::client_order_id::ClientOrderId,
+        types::price::Price,
+    };
+
+    fn events() -> Vec<OrderEventAny> {
+        vec![
+            OrderEventAny::Initialized(OrderInitialized::default()),
+            OrderEventAny::Submitted(OrderSubmitted::default()),
+            OrderEventAny::Accepted(OrderAccepted::default()),
+            OrderEventAny::Updated(OrderUpdated {
+                price: Some(Price::from("1.00000")),
+                ..Default::default()
+            }),
+            OrderEventAny::PartiallyFilled(OrderFilled::default()),
+            OrderEventAny::Filled(OrderFilled {
+                client_order_id: ClientOrderId::from("O-002"),
+                ..Default::default()
+            }),
+            OrderEventAny::Canceled(OrderCanceled::default()),
+        ]
+    }
+
+    #[rstest]
+    fn test_round_trip() {
+        let events = events();
+        let mut buf = Vec::new();
+
+        encode_events(&events, &mut buf).unwrap();
+        let decoded = decode_events(&buf).unwrap();
+
+        assert_eq!(decoded, events);
+    }
+
+    #[rstest]
+    fn test_decode_empty() {
+        assert!(decode_events(&[]).unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_payload_matches_per_event_serialization() {
+        let events = events();
+        let mut buf = Vec::new();
+        encode_events(&events, &mut buf).unwrap();
+
+        let mut reader = EventStreamReader::new(&buf);
+        for event in &events {
+            let payload = reader.next_payload().unwrap().unwrap();
+            assert_eq!(payload, rmp_serde::to_vec_named(event).unwrap().as_slice());
+        }
+        assert!(reader.next_payload().is_none());
+    }
+
+    #[rstest]
+    fn test_buffer_reused_across_batches() {
+        let events = events();
+        let mut buf = Vec::new();
+        encode_events(&events, &mut buf).unwrap();
+        let len = buf.len();
+        let capacity = buf.capacity();
+
+        buf.clear();
+        encode_events(&events, &mut buf).unwrap();
+
+        assert_eq!(buf.len(), len);
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[rstest]
+    fn test_encode_event_returns_bytes_written() {
+        let event = events().swap_remove(0);
+        let mut buf = vec![0xFF];
+        let written = encode_event(&event, &mut buf).unwrap();
+
+        assert_eq!(written, buf.len() - 1);
+        assert_eq!(decode_events(&buf[1..]).unwrap(), vec![event]);
+    }
+
+    #[rstest]
+    fn test_reader_is_lazy() {
+        let events = events();
+        let mut buf = Vec::new();
+        encode_events(&events, &mut buf).unwrap();
+
+        let mut reader = EventStreamReader::new(&buf);
+        let first = reader.next().unwrap().unwrap();
+
+        assert_eq!(first, events[0]);
+        assert!(reader.offset() > 0 && reader.offset() < buf.len());
+        assert_eq!(reader.count(), events.len() - 1);
+    }
+
+    #[rstest]
+    #[case(6)] // Inside the header of the last record
+    #[case(RECORD_HEADER_LEN + 1)] // Inside the payload of the last record
+    fn test_truncated_stream_errors(#[case] last_record_len: usize) {
+        let events = events();
+        let mut buf = Vec::new();
+        encode_events(&events[..1], &mut buf).unwrap();
+        let complete_len = buf.len();
+        encode_event(&events[1], &mut buf).unwrap();
+        buf.truncate(complete_len + last_record_len);
+
+        let results: Vec<_> = EventStreamReader::new(&buf).collect();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap(), &events[0]);
+        assert!(results[1].is_err());
+        assert!(decode_events(&buf).is_err());
+    }
+
+    #[rstest]
+    fn test_corrupt_record_is_skipped() {
+        let events = events();
+        let mut buf = Vec::new();
+        encode_events(&events[..2], &mut buf).unwrap();
+        // Corrupt the first byte of the first payload (the map marker)
+        buf[RECORD_HEADER_LEN] = 0xC1;
+
+        let results: Vec<_> = EventStreamReader::new(&buf).collect();
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_err());
+        assert_eq!(results[1].as_ref().unwrap(), &events[1]);
+        assert!(decode_events(&buf).is_err());
+    }
+
+    #[rstest]
+    fn test_undecodable_event_is_skipped() {
+        let events = events();
+        let mut buf = Vec::new();
+        MsgPackStreamWriter::new(&mut buf)
+            .write(&"not an event")
+            .unwrap();
+        encode_event(&events[0], &mut buf).unwrap();
+
+        let results: Vec<_> = EventStreamReader::new(&buf).collect();
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_err());
+        assert_eq!(results[1].as_ref().unwrap(), &events[0]);
+    }
+}
@@ -14,14 +14,16 @@
 // -------------------------------------------------------------------------------------------------
 
 //! Type stubs to facilitate testing.
+use nautilus_core::nanos::UnixNanos;
 use rstest::fixture;
 use rust_decimal::prelude::ToPrimitive;
 
 use crate::{
-    data::order::BookOrder,
-    enums::{LiquiditySide, OrderSide},
+    data::{order::BookOrder, quote::QuoteTick},
+    enums::{LiquiditySide, OrderSide, OrderType, TimeInForce},
+    events::order::{filled::OrderFilled, initialized::OrderInitialized},
     identifiers::instrument_id::InstrumentId,
-    instruments::{currency_pair::CurrencyPair, stubs::audusd_sim, Instrument},
+    instruments::{currency_pair::CurrencyPair, Instrument},
     orderbook::book::OrderBook,
     orders::{
         market::MarketOrder,
@@ -30,6 +32,108 @@ use crate::{
     position::Position,
     types::{money::Money, price::Price, quantity::Quantity},
 };
+pub use crate::{
+    identifiers::stubs::{
+        account_id, client_order_id, strategy_id_ema_cross, trade_id, trader_id, uuid4,
+        venue_order_id,
+    },
+    instruments::stubs::audusd_sim,
+};
+
+#[fixture]
+pub fn audusd_sim_id() -> InstrumentId {
+    audusd_sim().id
+}
+
+/// Returns an `OrderInitialized` event for a GTC limit order on AUD/USD.SIM.
+#[must_use]
+pub fn order_initialized_limit(
+    order_side: OrderSide,
+    quantity: Quantity,
+    price: Price,
+) -> OrderInitialized {
+    OrderInitialized::new(
+        trader_id(),
+        strategy_id_ema_cross(),
+        audusd_sim_id(),
+        client_order_id(),
+        order_side,
+        OrderType::Limit,
+        quantity,
+        TimeInForce::Gtc,
+        false,
+        false,
+        false,
+        false,
+        uuid4(),
+        UnixNanos::default(),
+        UnixNanos::default(),
+        Some(price),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap()
+}
+
+/// Returns an `OrderFilled` event for a taker fill of a limit order on AUD/USD.SIM, with
+/// commission charged at the instrument taker fee.
+#[must_use]
+pub fn order_filled(order_side: OrderSide, last_qty: Quantity, last_px: Price) -> OrderFilled {
+    let instrument = audusd_sim();
+    let commission = calculate_commission(instrument, last_qty, last_px, None).unwrap();
+    OrderFilled::new(
+        trader_id(),
+        strategy_id_ema_cross(),
+        instrument.id,
+        client_order_id(),
+        venue_order_id(),
+        account_id(),
+        trade_id(),
+        order_side,
+        OrderType::Limit,
+        last_qty,
+        last_px,
+        instrument.quote_currency,
+        LiquiditySide::Taker,
+        uuid4(),
+        UnixNanos::default(),
+        UnixNanos::default(),
+        false,
+        None,
+        Some(commission),
+    )
+    .unwrap()
+}
+
+/// Returns a `QuoteTick` for AUD/USD.SIM with 100,000 on each side.
+#[must_use]
+pub fn quote_tick(bid_price: Price, ask_price: Price) -> QuoteTick {
+    QuoteTick::new(
+        audusd_sim_id(),
+        bid_price,
+        ask_price,
+        Quantity::from(100_000),
+        Quantity::from(100_000),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+    .unwrap()
+}
 
 /// Calculate commission for testing
 pub fn calculate_commission<T: Instrument>(
@@ -182,3 +286,56 @@ pub fn stub_order_book_mbp(
 
     book
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_order_initialized_limit() {
+        let event = order_initialized_limit(
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Price::from("1.00000"),
+        );
+
+        assert_eq!(event.trader_id, trader_id());
+        assert_eq!(event.strategy_id, strategy_id_ema_cross());
+        assert_eq!(event.instrument_id, audusd_sim().id);
+        assert_eq!(event.client_order_id, client_order_id());
+        assert_eq!(event.time_in_force, TimeInForce::Gtc);
+        assert_eq!(event.price, Some(Price::from("1.00000")));
+        assert_eq!(event.event_id, uuid4());
+    }
+
+    #[rstest]
+    fn test_order_filled_charges_taker_fee() {
+        let event = order_filled(
+            OrderSide::Sell,
+            Quantity::from(100_000),
+            Price::from("1.00000"),
+        );
+
+        assert_eq!(event.account_id, account_id());
+        assert_eq!(event.trade_id, trade_id());
+        assert_eq!(event.commission, Some(Money::from("2.00 USD")));
+        assert_eq!(
+            event,
+            order_filled(OrderSide::Sell, event.last_qty, event.last_px)
+        );
+    }
+
+    #[rstest]
+    fn test_quote_tick() {
+        let quote = quote_tick(Price::from("1.00000"), Price::from("1.00001"));
+
+        assert_eq!(quote.instrument_id, audusd_sim_id());
+        assert_eq!(quote.bid_size, Quantity::from(100_000));
+        assert_eq!(quote.ts_event, UnixNanos::default());
+    }
+}
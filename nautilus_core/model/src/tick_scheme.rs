@@ -0,0 +1,150 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides tick schemes which define the valid prices for an instrument.
+
+use nautilus_core::correctness::check_predicate_true;
+
+use crate::types::{
+    fixed::{f64_to_fixed_i64, FIXED_PRECISION},
+    price::Price,
+};
+
+/// Represents an instrument tick scheme, mapping arbitrary values onto valid prices.
+pub trait TickScheme {
+    /// Returns the price `n` ticks above the nearest valid price at or above `value`.
+    fn next_ask_price(&self, value: f64, n: u32) -> Option<Price>;
+    /// Returns the price `n` ticks below the nearest valid price at or below `value`.
+    fn next_bid_price(&self, value: f64, n: u32) -> Option<Price>;
+}
+
+/// Represents a tick scheme with a single fixed price increment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedTickScheme {
+    pub increment: Price,
+    pub min_price: Price,
+    pub max_price: Price,
+}
+
+impl FixedTickScheme {
+    pub fn new(increment: Price, min_price: Price, max_price: Price) -> anyhow::Result<Self> {
+        check_predicate_true(increment.raw > 0, "`increment` was not positive")?;
+        check_predicate_true(
+            min_price <= max_price,
+            "`min_price` was greater than `max_price`",
+        )?;
+
+        Ok(Self {
+            increment,
+            min_price,
+            max_price,
+        })
+    }
+
+    fn checked_price(&self, raw: i64) -> Option<Price> {
+        if raw < self.min_price.raw || raw > self.max_price.raw {
+            return None;
+        }
+        Price::from_raw(raw, self.increment.precision).ok()
+    }
+}
+
+impl TickScheme for FixedTickScheme {
+    fn next_ask_price(&self, value: f64, n: u32) -> Option<Price> {
+        let raw = f64_to_fixed_i64(value, FIXED_PRECISION);
+        let increment = self.increment.raw;
+        let base = -(-raw).div_euclid(increment) * increment;
+        let raw = base.checked_add(i64::from(n).checked_mul(increment)?)?;
+        self.checked_price(raw)
+    }
+
+    fn next_bid_price(&self, value: f64, n: u32) -> Option<Price> {
+        let raw = f64_to_fixed_i64(value, FIXED_PRECISION);
+        let increment = self.increment.raw;
+        let base = raw.div_euclid(increment) * increment;
+        let raw = base.checked_sub(i64::from(n).checked_mul(increment)?)?;
+        self.checked_price(raw)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn tick_scheme() -> FixedTickScheme {
+        FixedTickScheme::new(
+            Price::from("0.05"),
+            Price::from("0.05"),
+            Price::from("100.00"),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_new_with_zero_increment_is_err() {
+        let result = FixedTickScheme::new(
+            Price::from("0.00"),
+            Price::from("0.05"),
+            Price::from("100.00"),
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(10.01, 0, "10.05")]
+    #[case(10.05, 0, "10.05")]
+    #[case(10.05, 1, "10.10")]
+    #[case(10.06, 2, "10.20")]
+    fn test_next_ask_price(
+        tick_scheme: FixedTickScheme,
+        #[case] value: f64,
+        #[case] n: u32,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            tick_scheme.next_ask_price(value, n),
+            Some(Price::from(expected))
+        );
+    }
+
+    #[rstest]
+    #[case(10.04, 0, "10.00")]
+    #[case(10.05, 0, "10.05")]
+    #[case(10.05, 1, "10.00")]
+    #[case(10.09, 2, "9.95")]
+    fn test_next_bid_price(
+        tick_scheme: FixedTickScheme,
+        #[case] value: f64,
+        #[case] n: u32,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            tick_scheme.next_bid_price(value, n),
+            Some(Price::from(expected))
+        );
+    }
+
+    #[rstest]
+    fn test_prices_outside_range_are_none(tick_scheme: FixedTickScheme) {
+        assert_eq!(tick_scheme.next_ask_price(100.01, 0), None);
+        assert_eq!(tick_scheme.next_bid_price(0.04, 0), None);
+    }
+}
@@ -36,7 +36,7 @@ pub struct AccountBalance {
 
 impl AccountBalance {
     pub fn new(total: Money, locked: Money, free: Money) -> anyhow::Result<Self> {
-        assert!(total == locked + free,
+        assert!(locked + free == Ok(total),
                 "Total balance is not equal to the sum of locked and free balances: {total} != {locked} + {free}"
             );
         Ok(Self {
@@ -19,7 +19,7 @@ use std::{
     str::FromStr,
 };
 
-use nautilus_core::correctness::check_valid_string;
+use nautilus_core::{correctness::check_valid_string, serialization::deserialize_from_str};
 use serde::{Deserialize, Serialize, Serializer};
 use ustr::Ustr;
 
@@ -159,8 +159,7 @@ impl<'de> Deserialize<'de> for Currency {
     where
         D: serde::Deserializer<'de>,
     {
-        let currency_str: &str = Deserialize::deserialize(deserializer)?;
-        Self::from_str(currency_str).map_err(serde::de::Error::custom)
+        deserialize_from_str(deserializer)
     }
 }
 
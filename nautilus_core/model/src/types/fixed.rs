@@ -13,9 +13,75 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use nautilus_core::serialization::{get_serialization_config, DecimalFormat};
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
+use thiserror::Error;
+
+use super::currency::Currency;
+use crate::enums::RoundingMode;
+
 pub const FIXED_PRECISION: u8 = 9;
 pub const FIXED_SCALAR: f64 = 1_000_000_000.0; // 10.0**FIXED_PRECISION
 
+/// An error from checked arithmetic on fixed precision values.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum ArithmeticError {
+    #[error("Arithmetic overflow: `{lhs} {op} {rhs}` is outside the range of the raw value")]
+    Overflow {
+        op: &'static str,
+        lhs: String,
+        rhs: String,
+    },
+    #[error("Precision mismatch: `{lhs} {op} {rhs}` with precisions {lhs_precision} and {rhs_precision}")]
+    PrecisionMismatch {
+        op: &'static str,
+        lhs: String,
+        rhs: String,
+        lhs_precision: u8,
+        rhs_precision: u8,
+    },
+    #[error("Currency mismatch: {left} and {right}")]
+    CurrencyMismatch { left: Currency, right: Currency },
+}
+
+impl ArithmeticError {
+    pub(crate) fn overflow(op: &'static str, lhs: impl Display, rhs: impl Display) -> Self {
+        Self::Overflow {
+            op,
+            lhs: lhs.to_string(),
+            rhs: rhs.to_string(),
+        }
+    }
+}
+
+pub(crate) fn check_same_precision(
+    op: &'static str,
+    lhs: impl Display,
+    lhs_precision: u8,
+    rhs: impl Display,
+    rhs_precision: u8,
+) -> Result<(), ArithmeticError> {
+    if lhs_precision != rhs_precision {
+        return Err(ArithmeticError::PrecisionMismatch {
+            op,
+            lhs: lhs.to_string(),
+            rhs: rhs.to_string(),
+            lhs_precision,
+            rhs_precision,
+        });
+    }
+    Ok(())
+}
+
+/// Returns the product of two raw fixed precision values as a raw value, or `None` on overflow.
+pub(crate) fn checked_mul_fixed_raw(lhs: i128, rhs: i128) -> Option<i128> {
+    lhs.checked_mul(rhs)
+        .map(|product| product / FIXED_SCALAR as i128)
+}
+
 pub fn check_fixed_precision(precision: u8) -> anyhow::Result<()> {
     if precision > FIXED_PRECISION {
         anyhow::bail!("Condition failed: `precision` was greater than the maximum `FIXED_PRECISION` (9), was {precision}")
@@ -23,6 +89,37 @@ pub fn check_fixed_precision(precision: u8) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Rescales the given `raw` fixed-point value from `from_precision` to `to_precision` decimal
+/// places, e.g. a raw value of 1_234_560_000 at precision 9 is 123_456 at precision 5.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `from_precision` or `to_precision` is greater than [`FIXED_PRECISION`].
+/// - If rescaling to a lower precision would truncate non-zero digits.
+/// - If rescaling to a higher precision overflows an `i64`.
+pub fn fixed_point_rescale(raw: i64, from_precision: u8, to_precision: u8) -> anyhow::Result<i64> {
+    check_fixed_precision(from_precision)?;
+    check_fixed_precision(to_precision)?;
+
+    match from_precision.cmp(&to_precision) {
+        Ordering::Equal => Ok(raw),
+        Ordering::Less => {
+            let scale = 10_i64.pow(u32::from(to_precision - from_precision));
+            raw.checked_mul(scale).ok_or_else(|| {
+                anyhow::anyhow!("Condition failed: rescaling `raw` {raw} from precision {from_precision} to {to_precision} overflowed")
+            })
+        }
+        Ordering::Greater => {
+            let scale = 10_i64.pow(u32::from(from_precision - to_precision));
+            if raw % scale != 0 {
+                anyhow::bail!("Condition failed: rescaling `raw` {raw} from precision {from_precision} to {to_precision} would truncate non-zero digits")
+            }
+            Ok(raw / scale)
+        }
+    }
+}
+
 #[must_use]
 pub fn f64_to_fixed_i64(value: f64, precision: u8) -> i64 {
     assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
@@ -51,6 +148,147 @@ pub fn fixed_u64_to_f64(value: u64) -> f64 {
     (value as f64) / FIXED_SCALAR
 }
 
+/// Returns the minimal precision (capped at `max_precision`) for which the fixed-point grid has
+/// a point within 1 ULP of the given `value`, e.g. 1.25 infers a precision of 2.
+///
+/// Inference is a fallback for sources which provide only an `f64`; an explicit precision from
+/// instrument or venue metadata should always be preferred where available.
+#[must_use]
+pub fn infer_f64_precision(value: f64, max_precision: u8) -> u8 {
+    let max_precision = max_precision.min(FIXED_PRECISION);
+    if !value.is_finite() {
+        return max_precision;
+    }
+
+    let ulp = f64_ulp(value);
+    (0..max_precision)
+        .find(|precision| (round_to_precision(value, *precision) - value).abs() <= ulp)
+        .unwrap_or(max_precision)
+}
+
+/// Returns the closest `f64` to the given `value` rounded to `precision` decimal places.
+#[must_use]
+pub fn round_to_precision(value: f64, precision: u8) -> f64 {
+    let scale = 10_f64.powi(i32::from(precision));
+    (value * scale).round() / scale
+}
+
+impl From<RoundingMode> for RoundingStrategy {
+    fn from(mode: RoundingMode) -> Self {
+        match mode {
+            RoundingMode::HalfUp => Self::MidpointAwayFromZero,
+            RoundingMode::HalfEven => Self::MidpointNearestEven,
+            RoundingMode::Floor => Self::ToNegativeInfinity,
+            RoundingMode::Ceiling => Self::ToPositiveInfinity,
+            RoundingMode::Down => Self::ToZero,
+            RoundingMode::Up => Self::AwayFromZero,
+        }
+    }
+}
+
+/// Returns the raw fixed precision value of `value` rounded to `precision` decimal places with
+/// the given rounding `mode`, or `None` if the value is not representable as a `Decimal`.
+///
+/// Rounding is applied to the shortest decimal representation which round trips to `value`, so
+/// a value such as 1.005 (stored as 1.00499999999999989...) is treated as exactly halfway.
+pub(crate) fn f64_to_fixed_i128_with_rounding(
+    value: f64,
+    precision: u8,
+    mode: RoundingMode,
+) -> Option<i128> {
+    let decimal = Decimal::from_str(&value.to_string()).ok()?;
+    decimal_to_fixed_i128(decimal, precision, mode)
+}
+
+/// Returns the raw fixed precision value of the given `decimal` rounded to `precision` decimal
+/// places with the given rounding `mode`, or `None` on overflow.
+pub(crate) fn decimal_to_fixed_i128(
+    decimal: Decimal,
+    precision: u8,
+    mode: RoundingMode,
+) -> Option<i128> {
+    assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+    let rounded = decimal.round_dp_with_strategy(u32::from(precision), mode.into());
+    rounded
+        .checked_mul(Decimal::from(FIXED_SCALAR as i64))?
+        .to_i128()
+}
+
+/// Returns the given `raw` value rounded to a multiple of `step` with the given rounding `mode`.
+///
+/// # Panics
+///
+/// This function panics if `step` is not positive.
+pub(crate) fn round_raw_to_multiple(raw: i128, step: i128, mode: RoundingMode) -> i128 {
+    assert!(step > 0, "`step` was not positive, was {step}");
+    let floor = raw.div_euclid(step) * step;
+    let remainder = raw - floor;
+    if remainder == 0 {
+        return raw;
+    }
+
+    let ceiling = floor + step;
+    let away_from_zero = if raw > 0 { ceiling } else { floor };
+    let towards_zero = if raw > 0 { floor } else { ceiling };
+    match mode {
+        RoundingMode::Floor => floor,
+        RoundingMode::Ceiling => ceiling,
+        RoundingMode::Down => towards_zero,
+        RoundingMode::Up => away_from_zero,
+        RoundingMode::HalfUp | RoundingMode::HalfEven => match (2 * remainder).cmp(&step) {
+            Ordering::Less => floor,
+            Ordering::Greater => ceiling,
+            Ordering::Equal if mode == RoundingMode::HalfUp => away_from_zero,
+            Ordering::Equal if (floor / step) % 2 == 0 => floor,
+            Ordering::Equal => ceiling,
+        },
+    }
+}
+
+fn f64_ulp(value: f64) -> f64 {
+    let value = value.abs();
+    f64::from_bits(value.to_bits() + 1) - value
+}
+
+/// Serializes a fixed precision value in the `DecimalFormat` configured for the current thread.
+pub(crate) fn serialize_fixed<S, R, T>(
+    serializer: S,
+    name: &'static str,
+    raw: R,
+    precision: u8,
+    value: &T,
+    as_f64: f64,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    R: Serialize,
+    T: Display,
+{
+    match get_serialization_config().decimal_format {
+        DecimalFormat::Number => serializer.serialize_f64(as_f64),
+        DecimalFormat::StringCanonical => serializer.serialize_str(&value.to_string()),
+        DecimalFormat::RawParts => {
+            let mut state = serializer.serialize_struct(name, 2)?;
+            state.serialize_field("raw", &raw)?;
+            state.serialize_field("precision", &precision)?;
+            state.end()
+        }
+    }
+}
+
+/// The serialized representation of a fixed precision value, accepting any `DecimalFormat`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum FixedRepr<R> {
+    String(String),
+    /// The precision is inferred from the shortest representation of the number.
+    Number(f64),
+    RawParts {
+        raw: R,
+        precision: u8,
+    },
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -60,6 +298,37 @@ mod tests {
 
     use super::*;
 
+    #[rstest]
+    #[case(1_234_560_000, 9, 5, 123_456)]
+    #[case(-1_234_560_000, 9, 5, -123_456)]
+    #[case(123_456, 5, 9, 1_234_560_000)]
+    #[case(123_456, 5, 5, 123_456)]
+    #[case(0, 9, 0, 0)]
+    #[case(i64::MAX, 9, 9, i64::MAX)]
+    fn test_fixed_point_rescale(
+        #[case] raw: i64,
+        #[case] from_precision: u8,
+        #[case] to_precision: u8,
+        #[case] expected: i64,
+    ) {
+        let result = fixed_point_rescale(raw, from_precision, to_precision).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(1_234_567_890, 9, 5)] // Would truncate 7_890
+    #[case(-1, 9, 8)] // Would truncate the last digit
+    #[case(i64::MAX / 10, 0, 2)] // Overflows
+    #[case(1, 10, 9)] // Invalid from precision
+    #[case(1, 9, 10)] // Invalid to precision
+    fn test_fixed_point_rescale_error(
+        #[case] raw: i64,
+        #[case] from_precision: u8,
+        #[case] to_precision: u8,
+    ) {
+        assert!(fixed_point_rescale(raw, from_precision, to_precision).is_err());
+    }
+
     #[rstest]
     #[case(0)]
     #[case(FIXED_PRECISION)]
@@ -68,6 +337,27 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[rstest]
+    #[case(0.0, 0)]
+    #[case(1.0, 0)]
+    #[case(1.25, 2)]
+    #[case(-1.25, 2)]
+    #[case(0.1, 1)]
+    #[case(0.1 + 0.2, 1)] // 0.30000000000000004 is within 1 ULP of 0.3
+    #[case(123_456.789, 3)]
+    #[case(0.000_000_001, 9)]
+    #[case(0.000_000_000_1, 9)] // Capped
+    #[case(f64::NAN, 9)]
+    fn test_infer_f64_precision(#[case] value: f64, #[case] expected: u8) {
+        assert_eq!(infer_f64_precision(value, FIXED_PRECISION), expected);
+    }
+
+    #[rstest]
+    fn test_infer_f64_precision_capped_by_max_precision() {
+        assert_eq!(infer_f64_precision(1.2345, 2), 2);
+        assert_eq!(infer_f64_precision(1.2345, 20), 4);
+    }
+
     #[rstest]
     fn test_invalid_precision() {
         let precision = FIXED_PRECISION + 1;
@@ -21,15 +21,24 @@ use std::{
     str::FromStr,
 };
 
-use nautilus_core::correctness::check_in_range_inclusive_f64;
+use nautilus_core::{
+    correctness::{check_in_range_inclusive_f64, check_non_negative_f64},
+    serialization::deserialize_from_str,
+};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
-use super::fixed::FIXED_PRECISION;
-use crate::types::{
-    currency::Currency,
-    fixed::{f64_to_fixed_i64, fixed_i64_to_f64},
+use super::{
+    fixed::{checked_mul_fixed_raw, decimal_to_fixed_i128, ArithmeticError, FIXED_PRECISION},
+    quantity::Quantity,
+};
+use crate::{
+    enums::RoundingMode,
+    types::{
+        currency::Currency,
+        fixed::{f64_to_fixed_i64, fixed_i64_to_f64},
+    },
 };
 
 pub const MONEY_MAX: f64 = 9_223_372_036.0;
@@ -56,6 +65,25 @@ impl Money {
         })
     }
 
+    /// Creates a new [`Money`] from the given decimal `amount` rounded to the precision of the
+    /// `currency` (with an amount exactly halfway rounded away from zero).
+    ///
+    /// Unlike [`Money::new`] the amount is never converted through an `f64`, so all digits up
+    /// to the precision are kept exactly.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `amount` is not within the valid range.
+    pub fn from_decimal(amount: Decimal, currency: Currency) -> anyhow::Result<Self> {
+        let raw = decimal_to_fixed_i128(amount, currency.precision, RoundingMode::HalfUp)
+            .and_then(|raw| i64::try_from(raw).ok())
+            .filter(|raw| (MONEY_MIN..=MONEY_MAX).contains(&fixed_i64_to_f64(*raw)))
+            .ok_or_else(|| {
+                anyhow::anyhow!("Condition failed: invalid decimal for 'amount' not in range [{MONEY_MIN}, {MONEY_MAX}], was {amount}")
+            })?;
+        Ok(Self { raw, currency })
+    }
+
     #[must_use]
     pub fn from_raw(raw: i64, currency: Currency) -> Self {
         Self { raw, currency }
@@ -71,6 +99,90 @@ impl Money {
         fixed_i64_to_f64(self.raw)
     }
 
+    /// Returns the sum of `self` and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The currencies of `self` and `rhs` differ.
+    /// - The sum overflows the raw value.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.check_same_currency(rhs)?;
+        let raw = self
+            .raw
+            .checked_add(rhs.raw)
+            .ok_or_else(|| ArithmeticError::overflow("+", self, rhs))?;
+        Ok(Self { raw, ..self })
+    }
+
+    /// Returns the difference of `self` and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The currencies of `self` and `rhs` differ.
+    /// - The difference overflows the raw value.
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.check_same_currency(rhs)?;
+        let raw = self
+            .raw
+            .checked_sub(rhs.raw)
+            .ok_or_else(|| ArithmeticError::overflow("-", self, rhs))?;
+        Ok(Self { raw, ..self })
+    }
+
+    /// Returns `self` multiplied by the given `qty`, in the currency of `self`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the product overflows the raw value.
+    pub fn checked_mul_qty(self, qty: Quantity) -> Result<Self, ArithmeticError> {
+        checked_mul_fixed_raw(i128::from(self.raw), i128::from(qty.raw))
+            .and_then(|raw| i64::try_from(raw).ok())
+            .map(|raw| Self { raw, ..self })
+            .ok_or_else(|| ArithmeticError::overflow("*", self, qty))
+    }
+
+    /// Returns `self` converted into the `target` currency at the given exchange `rate` (units
+    /// of `target` per unit of the currency of `self`), rounded to the precision of `target`
+    /// with the given `precision_mode`.
+    ///
+    /// The conversion is computed in decimal arithmetic, so the result is exact before rounding.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if:
+    /// - `rate` is negative or not finite.
+    /// - The converted amount is outside the valid range.
+    #[must_use]
+    pub fn convert(&self, rate: f64, target: Currency, precision_mode: RoundingMode) -> Self {
+        check_non_negative_f64(rate, "rate").unwrap();
+        let rate = Decimal::from_str(&rate.to_string()).expect("finite `f64` should parse");
+        let raw = self
+            .as_decimal()
+            .checked_mul(rate)
+            .and_then(|amount| decimal_to_fixed_i128(amount, target.precision, precision_mode))
+            .and_then(|raw| i64::try_from(raw).ok())
+            .filter(|raw| fixed_i64_to_f64(*raw).abs() <= MONEY_MAX)
+            .unwrap_or_else(|| {
+                panic!("Converted amount of {self} at rate {rate} was out of range")
+            });
+        Self {
+            raw,
+            currency: target,
+        }
+    }
+
+    fn check_same_currency(&self, rhs: Self) -> Result<(), ArithmeticError> {
+        if self.currency != rhs.currency {
+            return Err(ArithmeticError::CurrencyMismatch {
+                left: self.currency,
+                right: rhs.currency,
+            });
+        }
+        Ok(())
+    }
+
     #[must_use]
     pub fn as_decimal(&self) -> Decimal {
         // Scale down the raw value to match the precision
@@ -119,6 +231,12 @@ impl From<&str> for Money {
     }
 }
 
+impl From<Money> for Decimal {
+    fn from(money: Money) -> Self {
+        money.as_decimal()
+    }
+}
+
 impl From<Money> for f64 {
     fn from(money: Money) -> Self {
         money.as_f64()
@@ -187,39 +305,39 @@ impl Neg for Money {
     }
 }
 
+/// Adding amounts of different currencies returns an [`ArithmeticError::CurrencyMismatch`].
 impl Add for Money {
-    type Output = Self;
+    type Output = Result<Self, ArithmeticError>;
     fn add(self, rhs: Self) -> Self::Output {
-        assert_eq!(self.currency, rhs.currency);
-        Self {
-            raw: self.raw + rhs.raw,
-            currency: self.currency,
-        }
+        self.checked_add(rhs)
     }
 }
 
+/// Subtracting amounts of different currencies returns an [`ArithmeticError::CurrencyMismatch`].
 impl Sub for Money {
-    type Output = Self;
+    type Output = Result<Self, ArithmeticError>;
     fn sub(self, rhs: Self) -> Self::Output {
-        assert_eq!(self.currency, rhs.currency);
-        Self {
-            raw: self.raw - rhs.raw,
-            currency: self.currency,
-        }
+        self.checked_sub(rhs)
     }
 }
 
+/// # Panics
+///
+/// Panics if the currencies differ or the sum overflows, use [`Money::checked_add`] to handle
+/// these as errors.
 impl AddAssign for Money {
     fn add_assign(&mut self, other: Self) {
-        assert_eq!(self.currency, other.currency);
-        self.raw += other.raw;
+        *self = (*self + other).unwrap_or_else(|e| panic!("{e}"));
     }
 }
 
+/// # Panics
+///
+/// Panics if the currencies differ or the difference overflows, use [`Money::checked_sub`] to
+/// handle these as errors.
 impl SubAssign for Money {
     fn sub_assign(&mut self, other: Self) {
-        assert_eq!(self.currency, other.currency);
-        self.raw -= other.raw;
+        *self = (*self - other).unwrap_or_else(|e| panic!("{e}"));
     }
 }
 
@@ -291,9 +409,7 @@ impl<'de> Deserialize<'de> for Money {
     where
         D: Deserializer<'de>,
     {
-        let money_str: &str = Deserialize::deserialize(deserializer)?;
-        Money::from_str(money_str)
-            .map_err(|_| serde::de::Error::custom("Failed to parse Money amount"))
+        deserialize_from_str(deserializer)
     }
 }
 
@@ -303,6 +419,7 @@ impl<'de> Deserialize<'de> for Money {
 #[cfg(test)]
 mod tests {
     use float_cmp::approx_eq;
+    use proptest::prelude::*;
     use rstest::rstest;
     use rust_decimal_macros::dec;
 
@@ -325,11 +442,102 @@ mod tests {
     }
 
     #[rstest]
-    #[should_panic]
     fn test_money_different_currency_addition() {
         let usd = Money::new(1000.0, Currency::USD()).unwrap();
         let btc = Money::new(1.0, Currency::BTC()).unwrap();
-        let _result = usd + btc; // This should panic since currencies are different
+
+        let expected = ArithmeticError::CurrencyMismatch {
+            left: Currency::USD(),
+            right: Currency::BTC(),
+        };
+        assert_eq!(usd + btc, Err(expected.clone()));
+        assert_eq!(usd - btc, Err(expected));
+    }
+
+    #[rstest]
+    fn test_money_same_currency_addition_and_subtraction() {
+        let a = Money::from("1000.50 USD");
+        let b = Money::from("0.25 USD");
+
+        assert_eq!(a + b, Ok(Money::from("1000.75 USD")));
+        assert_eq!(a - b, Ok(Money::from("1000.25 USD")));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Currency mismatch: USD and BTC")]
+    fn test_money_different_currency_add_assign_panics() {
+        let mut usd = Money::new(1000.0, Currency::USD()).unwrap();
+        usd += Money::new(1.0, Currency::BTC()).unwrap();
+    }
+
+    #[rstest]
+    #[case("1_000.00 USD", 1_000.0, "USD")]
+    #[case("1_234_567 JPY", 1_234_567.0, "JPY")]
+    #[case("-0.00012345 BTC", -0.000_123_45, "BTC")]
+    fn test_from_str_formatted(#[case] input: &str, #[case] amount: f64, #[case] code: &str) {
+        let money = Money::from_str(input).unwrap();
+
+        assert_eq!(money, Money::new(amount, Currency::from(code)).unwrap());
+        assert_eq!(money.to_formatted_string(), input);
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfUp, "1501 JPY")]
+    #[case(RoundingMode::HalfEven, "1500 JPY")]
+    #[case(RoundingMode::Floor, "1500 JPY")]
+    #[case(RoundingMode::Ceiling, "1501 JPY")]
+    #[case(RoundingMode::Down, "1500 JPY")]
+    #[case(RoundingMode::Up, "1501 JPY")]
+    fn test_convert_to_jpy(#[case] mode: RoundingMode, #[case] expected: &str) {
+        // 10.00 USD at 150.05 is exactly 1500.5 JPY
+        let usd = Money::from("10.00 USD");
+        assert_eq!(
+            usd.convert(150.05, Currency::JPY(), mode),
+            Money::from(expected)
+        );
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfUp, "-0.00000013 BTC")]
+    #[case(RoundingMode::HalfEven, "-0.00000012 BTC")]
+    #[case(RoundingMode::Floor, "-0.00000013 BTC")]
+    #[case(RoundingMode::Ceiling, "-0.00000012 BTC")]
+    #[case(RoundingMode::Down, "-0.00000012 BTC")]
+    #[case(RoundingMode::Up, "-0.00000013 BTC")]
+    fn test_convert_to_btc(#[case] mode: RoundingMode, #[case] expected: &str) {
+        // -0.01 USD at 0.0000125 is exactly -0.000000125 BTC
+        let usd = Money::from("-0.01 USD");
+        assert_eq!(
+            usd.convert(0.000_012_5, Currency::BTC(), mode),
+            Money::from(expected)
+        );
+    }
+
+    #[rstest]
+    fn test_convert_from_btc_aggregates_commissions() {
+        let commissions = [
+            Money::from("0.00012345 BTC").convert(
+                65_000.5,
+                Currency::USD(),
+                RoundingMode::HalfEven,
+            ),
+            Money::from("1_500 JPY").convert(0.0066, Currency::USD(), RoundingMode::HalfEven),
+            Money::from("2.50 USD"),
+        ];
+
+        let total = commissions
+            .into_iter()
+            .try_fold(Money::from("0 USD"), |total, commission| total + commission)
+            .unwrap();
+
+        // 8.024311725 rounds to 8.02, then 8.02 + 9.90 + 2.50
+        assert_eq!(total, Money::from("20.42 USD"));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "rate")]
+    fn test_convert_with_negative_rate() {
+        let _ = Money::from("1.00 USD").convert(-1.0, Currency::JPY(), RoundingMode::HalfUp);
     }
 
     #[rstest]
@@ -413,4 +621,89 @@ mod tests {
         assert_eq!(money.currency, expected_currency);
         assert_eq!(money.as_decimal(), expected_dec);
     }
+
+    #[rstest]
+    fn test_checked_add_and_sub() {
+        let a = Money::from("100.50 USD");
+        let b = Money::from("0.25 USD");
+
+        assert_eq!(a.checked_add(b).unwrap(), Money::from("100.75 USD"));
+        assert_eq!(a.checked_sub(b).unwrap(), Money::from("100.25 USD"));
+    }
+
+    #[rstest]
+    fn test_checked_add_currency_mismatch() {
+        let a = Money::from("1.00 USD");
+        let b = Money::from("1.00 AUD");
+
+        assert_eq!(
+            a.checked_add(b),
+            Err(ArithmeticError::CurrencyMismatch {
+                left: Currency::USD(),
+                right: Currency::AUD(),
+            })
+        );
+        assert!(a.checked_sub(b).is_err());
+    }
+
+    #[rstest]
+    fn test_checked_add_overflow() {
+        let a = Money::from_raw(i64::MAX, Currency::USD());
+        let b = Money::from_raw(1, Currency::USD());
+
+        assert!(matches!(
+            a.checked_add(b),
+            Err(ArithmeticError::Overflow { op: "+", .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_checked_mul_qty() {
+        let commission = Money::from("0.50 USD");
+
+        assert_eq!(
+            commission.checked_mul_qty(Quantity::from(3)).unwrap(),
+            Money::from("1.50 USD")
+        );
+        assert!(Money::from_raw(i64::MIN, Currency::USD())
+            .checked_mul_qty(Quantity::from(2))
+            .is_err());
+    }
+
+    #[rstest]
+    #[case(dec!(0.00000001), "BTC", "0.00000001 BTC")]
+    #[case(dec!(1500.5), "JPY", "1501 JPY")]
+    #[case(dec!(-1000.125), "USD", "-1000.13 USD")]
+    fn test_from_decimal(#[case] amount: Decimal, #[case] code: &str, #[case] expected: &str) {
+        let money = Money::from_decimal(amount, Currency::from(code)).unwrap();
+        assert_eq!(money, Money::from(expected));
+    }
+
+    #[rstest]
+    fn test_from_decimal_out_of_range() {
+        assert!(Money::from_decimal(dec!(9223372037), Currency::USD()).is_err());
+    }
+
+    #[rstest]
+    fn test_decimal_round_trip() {
+        let money = Money::from_decimal(dec!(0.00000001), Currency::BTC()).unwrap();
+        assert_eq!(Decimal::from(money), dec!(0.00000001));
+    }
+
+    proptest! {
+        #[test]
+        fn test_checked_add_sub_match_raw(a in any::<i64>(), b in any::<i64>()) {
+            let lhs = Money::from_raw(a, Currency::USD());
+            let rhs = Money::from_raw(b, Currency::USD());
+
+            match a.checked_add(b) {
+                Some(raw) => prop_assert_eq!(lhs.checked_add(rhs).unwrap().raw, raw),
+                None => prop_assert!(lhs.checked_add(rhs).is_err()),
+            }
+            match a.checked_sub(b) {
+                Some(raw) => prop_assert_eq!(lhs.checked_sub(rhs).unwrap().raw, raw),
+                None => prop_assert!(lhs.checked_sub(rhs).is_err()),
+            }
+        }
+    }
 }
@@ -26,8 +26,20 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
-use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
-use crate::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
+use super::{
+    fixed::{
+        check_fixed_precision, check_same_precision, checked_mul_fixed_raw, serialize_fixed,
+        ArithmeticError, FixedRepr, FIXED_PRECISION, FIXED_SCALAR,
+    },
+    quantity::Quantity,
+};
+use crate::{
+    enums::RoundingMode,
+    types::fixed::{
+        decimal_to_fixed_i128, f64_to_fixed_i128_with_rounding, f64_to_fixed_i64, fixed_i64_to_f64,
+        infer_f64_precision, round_raw_to_multiple, round_to_precision,
+    },
+};
 
 pub const PRICE_MAX: f64 = 9_223_372_036.0;
 pub const PRICE_MIN: f64 = -9_223_372_036.0;
@@ -60,11 +72,99 @@ impl Price {
         })
     }
 
+    /// Creates a new [`Price`] from the given `value` rounded to `precision` with the given
+    /// rounding `mode`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `value` is not within the valid range.
+    /// - If `precision` is greater than [`FIXED_PRECISION`].
+    pub fn new_with_rounding(
+        value: f64,
+        precision: u8,
+        mode: RoundingMode,
+    ) -> anyhow::Result<Self> {
+        check_in_range_inclusive_f64(value, PRICE_MIN, PRICE_MAX, "value")?;
+        check_fixed_precision(precision)?;
+
+        let raw = f64_to_fixed_i128_with_rounding(value, precision, mode)
+            .and_then(|raw| i64::try_from(raw).ok())
+            .ok_or_else(|| anyhow::anyhow!("Condition failed: `value` {value} was not representable at precision {precision}"))?;
+        Ok(Self { raw, precision })
+    }
+
+    /// Creates a new [`Price`] from the given `value`, inferring the minimal precision (up to
+    /// `max_precision`) which represents the value within 1 ULP of the fixed-point grid.
+    ///
+    /// Inference is a fallback for sources providing only an `f64`; prefer [`Price::new`] with
+    /// the instrument precision wherever it is known.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `max_precision` is greater than [`FIXED_PRECISION`].
+    /// - If `value` is not within the valid range.
+    pub fn from_f64_infer(value: f64, max_precision: u8) -> anyhow::Result<Self> {
+        check_fixed_precision(max_precision)?;
+        Self::new(value, infer_f64_precision(value, max_precision))
+    }
+
+    /// Creates a new [`Price`] from the given `value` as for [`Price::from_f64_infer`], but
+    /// requires the value to be exactly representable at the inferred precision.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `max_precision` is greater than [`FIXED_PRECISION`].
+    /// - If `value` is not within the valid range.
+    /// - If `value` is not exactly representable at the inferred precision.
+    pub fn from_f64_exact(value: f64, max_precision: u8) -> anyhow::Result<Self> {
+        check_fixed_precision(max_precision)?;
+        let precision = infer_f64_precision(value, max_precision);
+        if round_to_precision(value, precision) != value {
+            anyhow::bail!(
+                "Condition failed: `value` {value} was not exactly representable at precision {precision}"
+            );
+        }
+        Self::new(value, precision)
+    }
+
+    /// Creates a new [`Price`] from the given decimal `value` rounded to `precision` (with a
+    /// value exactly halfway rounded away from zero).
+    ///
+    /// Unlike [`Price::new`] the value is never converted through an `f64`, so all digits up to
+    /// the precision are kept exactly.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `precision` is greater than [`FIXED_PRECISION`].
+    /// - If `value` is not within the valid range.
+    pub fn from_decimal(value: Decimal, precision: u8) -> anyhow::Result<Self> {
+        check_fixed_precision(precision)?;
+
+        let raw = decimal_to_fixed_i128(value, precision, RoundingMode::HalfUp)
+            .and_then(|raw| i64::try_from(raw).ok())
+            .filter(|raw| (PRICE_MIN..=PRICE_MAX).contains(&fixed_i64_to_f64(*raw)))
+            .ok_or_else(|| {
+                anyhow::anyhow!("Condition failed: invalid decimal for 'value' not in range [{PRICE_MIN}, {PRICE_MAX}], was {value}")
+            })?;
+        Ok(Self { raw, precision })
+    }
+
     pub fn from_raw(raw: i64, precision: u8) -> anyhow::Result<Self> {
         check_fixed_precision(precision)?;
         Ok(Self { raw, precision })
     }
 
+    /// Returns the raw fixed-point value, scaled by 10^[`FIXED_PRECISION`] regardless of the
+    /// precision of the price.
+    #[must_use]
+    pub fn raw(&self) -> i64 {
+        self.raw
+    }
+
     #[must_use]
     pub fn max(precision: u8) -> Self {
         check_fixed_precision(precision).unwrap();
@@ -94,11 +194,81 @@ impl Price {
         self.raw == 0
     }
 
+    #[must_use]
+    pub fn is_positive(&self) -> bool {
+        self.raw > 0
+    }
+
     #[must_use]
     pub fn as_f64(&self) -> f64 {
         fixed_i64_to_f64(self.raw)
     }
 
+    /// Returns the sum of `self` and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The precisions of `self` and `rhs` differ.
+    /// - The sum overflows the raw value.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        check_same_precision("+", self, self.precision, rhs, rhs.precision)?;
+        let raw = self
+            .raw
+            .checked_add(rhs.raw)
+            .ok_or_else(|| ArithmeticError::overflow("+", self, rhs))?;
+        Ok(Self { raw, ..self })
+    }
+
+    /// Returns the difference of `self` and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The precisions of `self` and `rhs` differ.
+    /// - The difference overflows the raw value.
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        check_same_precision("-", self, self.precision, rhs, rhs.precision)?;
+        let raw = self
+            .raw
+            .checked_sub(rhs.raw)
+            .ok_or_else(|| ArithmeticError::overflow("-", self, rhs))?;
+        Ok(Self { raw, ..self })
+    }
+
+    /// Returns `self` multiplied by the given `qty`, at the precision of `self`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the product overflows the raw value.
+    pub fn checked_mul_qty(self, qty: Quantity) -> Result<Self, ArithmeticError> {
+        checked_mul_fixed_raw(i128::from(self.raw), i128::from(qty.raw))
+            .and_then(|raw| i64::try_from(raw).ok())
+            .map(|raw| Self { raw, ..self })
+            .ok_or_else(|| ArithmeticError::overflow("*", self, qty))
+    }
+
+    /// Returns `self` rounded to a multiple of the given `tick` with the given rounding `mode`,
+    /// at the precision of `tick`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `tick` is not positive.
+    /// - If the rounded price overflows the raw value.
+    pub fn round_to_tick(&self, tick: Self, mode: RoundingMode) -> anyhow::Result<Self> {
+        if !tick.is_positive() {
+            anyhow::bail!("Condition failed: `tick` was not positive, was {tick}");
+        }
+
+        let raw = round_raw_to_multiple(i128::from(self.raw), i128::from(tick.raw), mode);
+        let raw = i64::try_from(raw).map_err(|_| ArithmeticError::overflow("round", self, tick))?;
+        Ok(Self {
+            raw,
+            precision: tick.precision,
+        })
+    }
+
     #[must_use]
     pub fn as_decimal(&self) -> Decimal {
         // Scale down the raw value to match the precision
@@ -132,6 +302,22 @@ impl From<&str> for Price {
     }
 }
 
+/// Converts exactly from the given decimal, at a precision of the decimal scale.
+impl TryFrom<Decimal> for Price {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Decimal) -> anyhow::Result<Self> {
+        let precision = u8::try_from(value.scale())?;
+        Self::from_decimal(value, precision)
+    }
+}
+
+impl From<Price> for Decimal {
+    fn from(price: Price) -> Self {
+        price.as_decimal()
+    }
+}
+
 impl From<Price> for f64 {
     fn from(price: Price) -> Self {
         price.as_f64()
@@ -205,8 +391,13 @@ impl Neg for Price {
 impl Add for Price {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
+        debug_assert!(
+            self.raw.checked_add(rhs.raw).is_some(),
+            "{}",
+            ArithmeticError::overflow("+", self, rhs)
+        );
         Self {
-            raw: self.raw + rhs.raw,
+            raw: self.raw.wrapping_add(rhs.raw),
             precision: self.precision,
         }
     }
@@ -215,8 +406,13 @@ impl Add for Price {
 impl Sub for Price {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
+        debug_assert!(
+            self.raw.checked_sub(rhs.raw).is_some(),
+            "{}",
+            ArithmeticError::overflow("-", self, rhs)
+        );
         Self {
-            raw: self.raw - rhs.raw,
+            raw: self.raw.wrapping_sub(rhs.raw),
             precision: self.precision,
         }
     }
@@ -224,13 +420,13 @@ impl Sub for Price {
 
 impl AddAssign for Price {
     fn add_assign(&mut self, other: Self) {
-        self.raw += other.raw;
+        *self = *self + other;
     }
 }
 
 impl SubAssign for Price {
     fn sub_assign(&mut self, other: Self) {
-        self.raw -= other.raw;
+        *self = *self - other;
     }
 }
 
@@ -278,18 +474,30 @@ impl Serialize for Price {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        serialize_fixed(
+            serializer,
+            stringify!(Price),
+            self.raw,
+            self.precision,
+            self,
+            self.as_f64(),
+        )
     }
 }
 
 impl<'de> Deserialize<'de> for Price {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let price_str: &str = Deserialize::deserialize(_deserializer)?;
-        let price: Self = price_str.into();
-        Ok(price)
+        let price = match FixedRepr::<i64>::deserialize(deserializer)? {
+            FixedRepr::String(value) => Self::from_str(&value),
+            FixedRepr::Number(value) => Self::from_str(&value.to_string()),
+            FixedRepr::RawParts { raw, precision } => {
+                Self::from_raw(raw, precision).map_err(|e| e.to_string())
+            }
+        };
+        price.map_err(serde::de::Error::custom)
     }
 }
 
@@ -301,10 +509,16 @@ mod tests {
     use std::str::FromStr;
 
     use float_cmp::approx_eq;
+    use nautilus_core::serialization::{
+        with_serialization_config, DecimalFormat, SerializationConfig,
+    };
+    use proptest::prelude::*;
     use rstest::rstest;
     use rust_decimal_macros::dec;
+    use strum::IntoEnumIterator;
 
     use super::*;
+    use crate::types::fixed::fixed_point_rescale;
 
     #[rstest]
     #[should_panic(expected = "Condition failed: `precision` was greater than the maximum ")]
@@ -518,4 +732,336 @@ mod tests {
         let result = format!("{price}");
         assert_eq!(result, "44.12");
     }
+
+    #[rstest]
+    #[case(DecimalFormat::Number, "1.1", 1)]
+    #[case(DecimalFormat::StringCanonical, "\"1.1000\"", 4)]
+    #[case(DecimalFormat::RawParts, r#"{"raw":1100000000,"precision":4}"#, 4)]
+    fn test_serde_json_decimal_formats(
+        #[case] decimal_format: DecimalFormat,
+        #[case] expected_json: &str,
+        #[case] expected_precision: u8,
+    ) {
+        let price = Price::from("1.1000");
+        let config = SerializationConfig::new(decimal_format);
+
+        let json = with_serialization_config(config, || serde_json::to_string(&price).unwrap());
+        let deserialized: Price = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, expected_json);
+        assert_eq!(deserialized, price);
+        assert_eq!(deserialized.precision, expected_precision);
+    }
+
+    #[rstest]
+    fn test_serde_json_invalid_raw_parts_precision() {
+        let result: Result<Price, _> = serde_json::from_str(r#"{"raw":1,"precision":10}"#);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(1.25, 2, "1.25")]
+    #[case(100.0, 2, "100")]
+    #[case(0.1 + 0.2, 9, "0.3")]
+    #[case(1.234_567, 4, "1.2346")] // Capped
+    fn test_from_f64_infer(#[case] value: f64, #[case] max_precision: u8, #[case] expected: &str) {
+        let price = Price::from_f64_infer(value, max_precision).unwrap();
+        assert_eq!(price, Price::from(expected));
+        assert_eq!(price.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_from_f64_infer_invalid_max_precision() {
+        assert!(Price::from_f64_infer(1.0, FIXED_PRECISION + 1).is_err());
+        assert!(Price::from_f64_exact(1.0, FIXED_PRECISION + 1).is_err());
+    }
+
+    #[rstest]
+    fn test_from_f64_exact() {
+        assert_eq!(
+            Price::from_f64_exact(1.25, FIXED_PRECISION).unwrap(),
+            Price::from("1.25")
+        );
+        assert!(Price::from_f64_exact(0.1 + 0.2, FIXED_PRECISION).is_err());
+        assert!(Price::from_f64_exact(1.234_567, 4).is_err());
+    }
+
+    #[rstest]
+    fn test_checked_add_and_sub() {
+        let a = Price::from("1.25");
+        let b = Price::from("0.50");
+
+        assert_eq!(a.checked_add(b).unwrap(), Price::from("1.75"));
+        assert_eq!(a.checked_sub(b).unwrap(), Price::from("0.75"));
+        assert_eq!(b.checked_sub(a).unwrap(), Price::from("-0.75"));
+    }
+
+    #[rstest]
+    fn test_checked_add_overflow() {
+        let a = Price::from_raw(i64::MAX - 1, 9).unwrap();
+        let b = Price::from_raw(2, 9).unwrap();
+
+        let result = a.checked_add(b);
+
+        assert!(matches!(
+            result,
+            Err(ArithmeticError::Overflow { op: "+", .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_checked_sub_overflow() {
+        let a = Price::from_raw(i64::MIN + 1, 9).unwrap();
+        let b = Price::from_raw(2, 9).unwrap();
+
+        assert!(matches!(
+            a.checked_sub(b),
+            Err(ArithmeticError::Overflow { op: "-", .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_checked_add_precision_mismatch() {
+        let a = Price::from("1.00");
+        let b = Price::from("1.00000");
+
+        let result = a.checked_add(b);
+
+        assert_eq!(
+            result,
+            Err(ArithmeticError::PrecisionMismatch {
+                op: "+",
+                lhs: "1.00".to_string(),
+                rhs: "1.00000".to_string(),
+                lhs_precision: 2,
+                rhs_precision: 5,
+            })
+        );
+        assert!(a.checked_sub(b).is_err());
+    }
+
+    #[rstest]
+    fn test_checked_mul_qty() {
+        let price = Price::from("1.50");
+
+        assert_eq!(
+            price.checked_mul_qty(Quantity::from("2.5")).unwrap(),
+            Price::from("3.75")
+        );
+        assert!(Price::max(2).checked_mul_qty(Quantity::from(2)).is_err());
+    }
+
+    #[cfg(debug_assertions)]
+    #[rstest]
+    #[should_panic(expected = "Arithmetic overflow")]
+    fn test_add_overflow_panics_in_debug() {
+        let _ = Price::from_raw(i64::MAX, 9).unwrap() + Price::from_raw(1, 9).unwrap();
+    }
+
+    #[rstest]
+    #[case(1.005, RoundingMode::HalfUp, "1.01")]
+    #[case(1.005, RoundingMode::HalfEven, "1.00")]
+    #[case(1.015, RoundingMode::HalfEven, "1.02")]
+    #[case(1.005, RoundingMode::Floor, "1.00")]
+    #[case(1.005, RoundingMode::Ceiling, "1.01")]
+    #[case(1.005, RoundingMode::Down, "1.00")]
+    #[case(1.005, RoundingMode::Up, "1.01")]
+    #[case(-1.005, RoundingMode::HalfUp, "-1.01")]
+    #[case(-1.005, RoundingMode::HalfEven, "-1.00")]
+    #[case(-1.005, RoundingMode::Floor, "-1.01")]
+    #[case(-1.005, RoundingMode::Ceiling, "-1.00")]
+    #[case(-1.005, RoundingMode::Down, "-1.00")]
+    #[case(-1.005, RoundingMode::Up, "-1.01")]
+    fn test_new_with_rounding_at_halfway(
+        #[case] value: f64,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let price = Price::new_with_rounding(value, 2, mode).unwrap();
+
+        assert_eq!(price, Price::from(expected));
+        assert_eq!(price.precision, 2);
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfUp, "1.00")]
+    #[case(RoundingMode::HalfEven, "1.00")]
+    #[case(RoundingMode::Floor, "1.00")]
+    #[case(RoundingMode::Ceiling, "1.01")]
+    #[case(RoundingMode::Down, "1.00")]
+    #[case(RoundingMode::Up, "1.01")]
+    fn test_new_with_rounding_below_halfway(#[case] mode: RoundingMode, #[case] expected: &str) {
+        let price = Price::new_with_rounding(1.0049, 2, mode).unwrap();
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    fn test_new_with_rounding_invalid() {
+        assert!(Price::new_with_rounding(1.0, FIXED_PRECISION + 1, RoundingMode::HalfUp).is_err());
+        assert!(Price::new_with_rounding(f64::NAN, 2, RoundingMode::HalfUp).is_err());
+        assert!(Price::new_with_rounding(PRICE_MAX * 2.0, 2, RoundingMode::HalfUp).is_err());
+    }
+
+    #[rstest]
+    #[case("1.025", RoundingMode::HalfUp, "1.05")]
+    #[case("1.025", RoundingMode::HalfEven, "1.00")]
+    #[case("1.075", RoundingMode::HalfEven, "1.10")]
+    #[case("1.025", RoundingMode::Floor, "1.00")]
+    #[case("1.025", RoundingMode::Ceiling, "1.05")]
+    #[case("1.025", RoundingMode::Down, "1.00")]
+    #[case("1.025", RoundingMode::Up, "1.05")]
+    #[case("-1.025", RoundingMode::HalfUp, "-1.05")]
+    #[case("-1.025", RoundingMode::HalfEven, "-1.00")]
+    #[case("-1.025", RoundingMode::Floor, "-1.05")]
+    #[case("-1.025", RoundingMode::Ceiling, "-1.00")]
+    #[case("-1.025", RoundingMode::Down, "-1.00")]
+    #[case("-1.025", RoundingMode::Up, "-1.05")]
+    fn test_round_to_tick_at_halfway(
+        #[case] value: &str,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let price = Price::from(value)
+            .round_to_tick(Price::from("0.05"), mode)
+            .unwrap();
+
+        assert_eq!(price, Price::from(expected));
+        assert_eq!(price.precision, 2);
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfUp, "1.05")]
+    #[case(RoundingMode::HalfEven, "1.05")]
+    #[case(RoundingMode::Floor, "1.00")]
+    #[case(RoundingMode::Ceiling, "1.05")]
+    #[case(RoundingMode::Down, "1.00")]
+    #[case(RoundingMode::Up, "1.05")]
+    fn test_round_to_tick_above_halfway(#[case] mode: RoundingMode, #[case] expected: &str) {
+        let price = Price::from("1.026")
+            .round_to_tick(Price::from("0.05"), mode)
+            .unwrap();
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    fn test_round_to_tick_on_tick_is_unchanged() {
+        let price = Price::from("1.10");
+        for mode in RoundingMode::iter() {
+            assert_eq!(
+                price.round_to_tick(Price::from("0.05"), mode).unwrap(),
+                price
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("0.00")]
+    #[case("-0.05")]
+    fn test_round_to_tick_with_invalid_tick(#[case] tick: &str) {
+        let result = Price::from("1.00").round_to_tick(Price::from(tick), RoundingMode::HalfUp);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(1_234_560_000, "1.23456")]
+    #[case(-1_234_560_000, "-1.23456")]
+    #[case(4_500_250_000_000, "4500.25000")]
+    #[case(10_000, "0.00001")]
+    fn test_from_raw_with_rescaled_nanos(#[case] nanos: i64, #[case] expected: &str) {
+        // A 1e-9 fixed-point price (as from Databento) rescaled to 5 decimal places and back
+        let raw = fixed_point_rescale(nanos, 9, 5)
+            .and_then(|raw| fixed_point_rescale(raw, 5, FIXED_PRECISION))
+            .unwrap();
+        let price = Price::from_raw(raw, 5).unwrap();
+
+        assert_eq!(price, Price::from(expected));
+        assert_eq!(price.raw(), nanos);
+        assert_eq!(price.precision, 5);
+        assert_eq!(price.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_from_raw_with_rescaled_nanos_below_precision() {
+        assert!(fixed_point_rescale(1_234_567_890, 9, 5).is_err());
+    }
+
+    #[rstest]
+    #[case(dec!(1.23456), 5, "1.23456")]
+    #[case(dec!(-0.000000001), 9, "-0.000000001")]
+    #[case(dec!(1.005), 2, "1.01")]
+    #[case(dec!(1.23456), 0, "1")]
+    fn test_from_decimal(#[case] value: Decimal, #[case] precision: u8, #[case] expected: &str) {
+        let price = Price::from_decimal(value, precision).unwrap();
+
+        assert_eq!(price, Price::from(expected));
+        assert_eq!(price.precision, precision);
+    }
+
+    #[rstest]
+    fn test_decimal_round_trip() {
+        let value = dec!(12345.678901234);
+        let price = Price::try_from(value).unwrap();
+
+        assert_eq!(price.precision, 9);
+        assert_eq!(Decimal::from(price), value);
+    }
+
+    #[rstest]
+    #[case(dec!(1.0000000001))] // Scale greater than the maximum precision
+    #[case(dec!(9223372037))] // Greater than the maximum price
+    fn test_try_from_decimal_invalid(#[case] value: Decimal) {
+        assert!(Price::try_from(value).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_checked_add_matches_raw(a in any::<i64>(), b in any::<i64>()) {
+            let result = Price::from_raw(a, 9).unwrap().checked_add(Price::from_raw(b, 9).unwrap());
+            match a.checked_add(b) {
+                Some(raw) => prop_assert_eq!(result.unwrap().raw, raw),
+                None => prop_assert!(result.is_err()),
+            }
+        }
+
+        #[test]
+        fn test_checked_add_sub_near_bounds(a in (i64::MAX - 1_000)..=i64::MAX, b in 0..2_000_i64) {
+            let lhs = Price::from_raw(a, 9).unwrap();
+            let rhs = Price::from_raw(b, 9).unwrap();
+
+            prop_assert_eq!(lhs.checked_add(rhs).is_ok(), a <= i64::MAX - b);
+            prop_assert_eq!((-lhs).checked_sub(rhs).is_ok(), -a >= i64::MIN + b);
+            if let Ok(sum) = lhs.checked_add(rhs) {
+                prop_assert_eq!(sum.checked_sub(rhs).unwrap(), lhs);
+            }
+        }
+
+        #[test]
+        fn test_from_f64_infer_round_trips_decimal_strings(
+            precision in 0..10_usize,
+            units in 0..1_000_000_u64,
+            fraction in any::<u64>(),
+        ) {
+            let fraction = fraction % 10_u64.pow(precision as u32);
+            let input = if precision == 0 {
+                format!("{units}")
+            } else {
+                format!("{units}.{fraction:0precision$}")
+            };
+            let value: f64 = input.parse().unwrap();
+
+            // The inferred precision is that of the input with trailing zeros stripped
+            let expected = if input.contains('.') {
+                input.trim_end_matches('0').trim_end_matches('.')
+            } else {
+                input.as_str()
+            };
+            let price = Price::from_f64_infer(value, FIXED_PRECISION).unwrap();
+            prop_assert_eq!(price.to_string(), expected);
+
+            // Only values whose nearest f64 is exact at the inferred precision succeed
+            if let Ok(exact) = Price::from_f64_exact(value, FIXED_PRECISION) {
+                prop_assert_eq!(exact, price);
+            }
+        }
+    }
 }
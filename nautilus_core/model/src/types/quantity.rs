@@ -21,16 +21,30 @@ use std::{
     str::FromStr,
 };
 
-use nautilus_core::{correctness::check_in_range_inclusive_f64, parsing::precision_from_str};
+use nautilus_core::{
+    correctness::{check_in_range_inclusive_f64, check_non_negative_f64, check_positive_u64},
+    parsing::precision_from_str,
+};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
-use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
-use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
+use super::fixed::{
+    check_fixed_precision, check_same_precision, checked_mul_fixed_raw, serialize_fixed,
+    ArithmeticError, FixedRepr, FIXED_PRECISION, FIXED_SCALAR,
+};
+use crate::{
+    enums::RoundingMode,
+    types::fixed::{
+        decimal_to_fixed_i128, f64_to_fixed_i128_with_rounding, f64_to_fixed_u64, fixed_u64_to_f64,
+        round_raw_to_multiple,
+    },
+};
 
 pub const QUANTITY_MAX: f64 = 18_446_744_073.0;
 pub const QUANTITY_MIN: f64 = 0.0;
+/// The raw fixed-point representation of `QUANTITY_MAX`.
+pub const QUANTITY_MAX_RAW: u64 = 18_446_744_073_000_000_000;
 
 #[repr(C)]
 #[derive(Clone, Copy, Default, Eq)]
@@ -54,6 +68,51 @@ impl Quantity {
         })
     }
 
+    /// Creates a new [`Quantity`] from the given `value` rounded to `precision` with the given
+    /// rounding `mode`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `value` is not within the valid range.
+    /// - If `precision` is greater than [`FIXED_PRECISION`].
+    pub fn new_with_rounding(
+        value: f64,
+        precision: u8,
+        mode: RoundingMode,
+    ) -> anyhow::Result<Self> {
+        check_in_range_inclusive_f64(value, QUANTITY_MIN, QUANTITY_MAX, "value")?;
+        check_fixed_precision(precision)?;
+
+        let raw = f64_to_fixed_i128_with_rounding(value, precision, mode)
+            .and_then(|raw| u64::try_from(raw).ok())
+            .ok_or_else(|| anyhow::anyhow!("Condition failed: `value` {value} was not representable at precision {precision}"))?;
+        Ok(Self { raw, precision })
+    }
+
+    /// Creates a new [`Quantity`] from the given decimal `value` rounded to `precision` (with a
+    /// value exactly halfway rounded away from zero).
+    ///
+    /// Unlike [`Quantity::new`] the value is never converted through an `f64`, so all digits up to
+    /// the precision are kept exactly.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `precision` is greater than [`FIXED_PRECISION`].
+    /// - If `value` is not within the valid range.
+    pub fn from_decimal(value: Decimal, precision: u8) -> anyhow::Result<Self> {
+        check_fixed_precision(precision)?;
+
+        let raw = decimal_to_fixed_i128(value, precision, RoundingMode::HalfUp)
+            .and_then(|raw| u64::try_from(raw).ok())
+            .filter(|raw| *raw <= QUANTITY_MAX_RAW)
+            .ok_or_else(|| {
+                anyhow::anyhow!("Condition failed: invalid decimal for 'value' not in range [{QUANTITY_MIN}, {QUANTITY_MAX}], was {value}")
+            })?;
+        Ok(Self { raw, precision })
+    }
+
     pub fn from_raw(raw: u64, precision: u8) -> anyhow::Result<Self> {
         check_fixed_precision(precision)?;
         Ok(Self { raw, precision })
@@ -80,6 +139,139 @@ impl Quantity {
         fixed_u64_to_f64(self.raw)
     }
 
+    /// Returns the sum of `self` and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The precisions of `self` and `rhs` differ.
+    /// - The sum overflows the raw value.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        check_same_precision("+", self, self.precision, rhs, rhs.precision)?;
+        let raw = self
+            .raw
+            .checked_add(rhs.raw)
+            .ok_or_else(|| ArithmeticError::overflow("+", self, rhs))?;
+        Ok(Self { raw, ..self })
+    }
+
+    /// Returns the difference of `self` and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The precisions of `self` and `rhs` differ.
+    /// - The difference is negative.
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        check_same_precision("-", self, self.precision, rhs, rhs.precision)?;
+        let raw = self
+            .raw
+            .checked_sub(rhs.raw)
+            .ok_or_else(|| ArithmeticError::overflow("-", self, rhs))?;
+        Ok(Self { raw, ..self })
+    }
+
+    /// Returns `self` multiplied by the given `qty`, at the precision of `self`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the product overflows the raw value.
+    pub fn checked_mul_qty(self, qty: Self) -> Result<Self, ArithmeticError> {
+        checked_mul_fixed_raw(i128::from(self.raw), i128::from(qty.raw))
+            .and_then(|raw| u64::try_from(raw).ok())
+            .map(|raw| Self { raw, ..self })
+            .ok_or_else(|| ArithmeticError::overflow("*", self, qty))
+    }
+
+    /// Returns the smaller of `self` and `other`.
+    #[must_use]
+    pub const fn min(self, other: Self) -> Self {
+        if other.raw < self.raw {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of `self` and `other`.
+    #[must_use]
+    pub const fn max(self, other: Self) -> Self {
+        if other.raw >= self.raw {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns `self` multiplied by the given `factor`, rounded down to a multiple of
+    /// `size_increment` (at the precision of `size_increment`).
+    ///
+    /// For example 25% of a position of 10.7 with a size increment of 0.5 is 2.5.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if:
+    /// - `factor` is negative or not finite.
+    /// - `size_increment` is zero.
+    /// - The product overflows the raw value.
+    #[must_use]
+    pub fn mul_f64_floor(&self, factor: f64, size_increment: Self) -> Self {
+        check_non_negative_f64(factor, "factor").unwrap();
+        check_positive_u64(size_increment.raw, "size_increment").unwrap();
+
+        let factor = Decimal::from_str(&factor.to_string()).expect("finite `f64` should parse");
+        let raw = Decimal::from_i128_with_scale(i128::from(self.raw), u32::from(FIXED_PRECISION))
+            .checked_mul(factor)
+            .and_then(|value| decimal_to_fixed_i128(value, FIXED_PRECISION, RoundingMode::Floor))
+            .map(|raw| {
+                round_raw_to_multiple(raw, i128::from(size_increment.raw), RoundingMode::Floor)
+            })
+            .and_then(|raw| u64::try_from(raw).ok())
+            .unwrap_or_else(|| panic!("{}", ArithmeticError::overflow("*", self, factor)));
+        Self {
+            raw,
+            precision: size_increment.precision,
+        }
+    }
+
+    /// Splits `self` into the given number of `parts`, each a multiple of `size_increment`.
+    ///
+    /// The increments are distributed as evenly as possible, with the remainder distributed one
+    /// increment each across the first parts, so that the parts always sum exactly to `self`.
+    /// If `self` is not a multiple of `size_increment` the residual is added to the first part.
+    /// Parts are at the precision of `self`, and may be zero when there are fewer increments
+    /// than parts.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `parts` is zero or `size_increment` is zero.
+    #[must_use]
+    pub fn split(&self, parts: usize, size_increment: Self) -> Vec<Self> {
+        check_positive_u64(parts as u64, "parts").unwrap();
+        check_positive_u64(size_increment.raw, "size_increment").unwrap();
+
+        let increments = self.raw / size_increment.raw;
+        let residual = self.raw % size_increment.raw;
+        let base = increments / parts as u64;
+        let extra = increments % parts as u64;
+
+        (0..parts as u64)
+            .map(|i| {
+                let mut raw = base * size_increment.raw;
+                if i < extra {
+                    raw += size_increment.raw;
+                }
+                if i == 0 {
+                    raw += residual;
+                }
+                Self {
+                    raw,
+                    precision: self.precision,
+                }
+            })
+            .collect()
+    }
+
     #[must_use]
     pub fn as_decimal(&self) -> Decimal {
         // Scale down the raw value to match the precision
@@ -125,6 +317,22 @@ impl From<&str> for Quantity {
     }
 }
 
+/// Converts exactly from the given decimal, at a precision of the decimal scale.
+impl TryFrom<Decimal> for Quantity {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Decimal) -> anyhow::Result<Self> {
+        let precision = u8::try_from(value.scale())?;
+        Self::from_decimal(value, precision)
+    }
+}
+
+impl From<Quantity> for Decimal {
+    fn from(qty: Quantity) -> Self {
+        qty.as_decimal()
+    }
+}
+
 impl From<i64> for Quantity {
     fn from(input: i64) -> Self {
         Self::new(input as f64, 0).unwrap()
@@ -182,8 +390,13 @@ impl Deref for Quantity {
 impl Add for Quantity {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
+        debug_assert!(
+            self.raw.checked_add(rhs.raw).is_some(),
+            "{}",
+            ArithmeticError::overflow("+", self, rhs)
+        );
         Self {
-            raw: self.raw + rhs.raw,
+            raw: self.raw.wrapping_add(rhs.raw),
             precision: self.precision,
         }
     }
@@ -192,8 +405,13 @@ impl Add for Quantity {
 impl Sub for Quantity {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
+        debug_assert!(
+            self.raw.checked_sub(rhs.raw).is_some(),
+            "{}",
+            ArithmeticError::overflow("-", self, rhs)
+        );
         Self {
-            raw: self.raw - rhs.raw,
+            raw: self.raw.wrapping_sub(rhs.raw),
             precision: self.precision,
         }
     }
@@ -269,18 +487,104 @@ impl Serialize for Quantity {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        serialize_fixed(
+            serializer,
+            stringify!(Quantity),
+            self.raw,
+            self.precision,
+            self,
+            self.as_f64(),
+        )
     }
 }
 
 impl<'de> Deserialize<'de> for Quantity {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let qty_str: &str = Deserialize::deserialize(_deserializer)?;
-        let qty: Self = qty_str.into();
-        Ok(qty)
+        let quantity = match FixedRepr::<u64>::deserialize(deserializer)? {
+            FixedRepr::String(value) => Self::from_str(&value),
+            FixedRepr::Number(value) => Self::from_str(&value.to_string()),
+            FixedRepr::RawParts { raw, precision } => {
+                Self::from_raw(raw, precision).map_err(|e| e.to_string())
+            }
+        };
+        quantity.map_err(serde::de::Error::custom)
+    }
+}
+
+/// The error returned when an accumulated quantity total exceeds `QUANTITY_MAX`.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Quantity overflow: accumulated raw total {raw} exceeds maximum {QUANTITY_MAX_RAW}")]
+pub struct QuantityOverflow {
+    pub raw: u128,
+}
+
+/// Accumulates `Quantity` values without overflow, for summing sizes over many book levels
+/// or orders.
+///
+/// The raw total is held as a `u128`, and the total precision is the maximum precision
+/// of the added quantities.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuantityAccumulator {
+    raw: u128,
+    precision: u8,
+}
+
+impl QuantityAccumulator {
+    #[must_use]
+    pub fn new(precision: u8) -> Self {
+        Self { raw: 0, precision }
+    }
+
+    pub fn add(&mut self, qty: &Quantity) {
+        self.raw += u128::from(qty.raw);
+        self.precision = self.precision.max(qty.precision);
+    }
+
+    #[must_use]
+    pub fn raw(&self) -> u128 {
+        self.raw
+    }
+
+    #[must_use]
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns the accumulated total as a `Quantity`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the total exceeds `QUANTITY_MAX`.
+    pub fn total(&self) -> Result<Quantity, QuantityOverflow> {
+        if self.raw > u128::from(QUANTITY_MAX_RAW) {
+            return Err(QuantityOverflow { raw: self.raw });
+        }
+        Ok(Quantity {
+            raw: self.raw as u64,
+            precision: self.precision,
+        })
+    }
+
+    /// Returns the accumulated total as an `f64` (approximate for large totals).
+    #[must_use]
+    pub fn total_f64(&self) -> f64 {
+        (self.raw as f64) / FIXED_SCALAR
+    }
+}
+
+impl<'a> Extend<&'a Quantity> for QuantityAccumulator {
+    fn extend<I: IntoIterator<Item = &'a Quantity>>(&mut self, iter: I) {
+        for qty in iter {
+            self.add(qty);
+        }
     }
 }
 
@@ -299,6 +603,7 @@ mod tests {
     use std::str::FromStr;
 
     use float_cmp::approx_eq;
+    use proptest::prelude::*;
     use rstest::rstest;
     use rust_decimal_macros::dec;
 
@@ -332,6 +637,57 @@ mod tests {
         let _ = Quantity::zero(10);
     }
 
+    #[rstest]
+    fn test_accumulator_empty() {
+        let acc = QuantityAccumulator::new(2);
+        assert!(acc.is_zero());
+        assert_eq!(acc.total().unwrap(), Quantity::zero(2));
+        assert_eq!(acc.total_f64(), 0.0);
+    }
+
+    #[rstest]
+    fn test_accumulator_preserves_max_precision() {
+        let mut acc = QuantityAccumulator::new(0);
+        acc.extend(&[Quantity::from("1.5"), Quantity::from("0.001")]);
+
+        let total = acc.total().unwrap();
+        assert_eq!(total, Quantity::from("1.501"));
+        assert_eq!(total.precision, 3);
+    }
+
+    #[rstest]
+    fn test_accumulator_beyond_u64() {
+        let qty = Quantity::new(10_000_000_000.0, 0).unwrap();
+        assert!(qty.raw.checked_add(qty.raw).is_none()); // Naive raw sum would overflow
+
+        let mut acc = QuantityAccumulator::new(0);
+        acc.extend(&[qty, qty]);
+
+        assert_eq!(acc.raw(), 20_000_000_000_000_000_000);
+        assert_eq!(acc.total_f64(), 20_000_000_000.0);
+        assert_eq!(
+            acc.total(),
+            Err(QuantityOverflow {
+                raw: 20_000_000_000_000_000_000
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_accumulator_at_maximum() {
+        let mut acc = QuantityAccumulator::new(0);
+        acc.add(&Quantity::new(QUANTITY_MAX - 1.0, 0).unwrap());
+        acc.add(&Quantity::from(1));
+
+        assert_eq!(
+            acc.total().unwrap(),
+            Quantity::new(QUANTITY_MAX, 0).unwrap()
+        );
+
+        acc.add(&Quantity::from_raw(1, 9).unwrap());
+        assert!(acc.total().is_err());
+    }
+
     #[rstest]
     fn test_new() {
         let qty = Quantity::new(0.00812, 8).unwrap();
@@ -511,4 +867,291 @@ mod tests {
         let result = format!("{quantity}");
         assert_eq!(result, "44.12");
     }
+
+    #[rstest]
+    fn test_checked_add_and_sub() {
+        let a = Quantity::from("1.5");
+        let b = Quantity::from("0.5");
+
+        assert_eq!(a.checked_add(b).unwrap(), Quantity::from("2.0"));
+        assert_eq!(a.checked_sub(b).unwrap(), Quantity::from("1.0"));
+    }
+
+    #[rstest]
+    fn test_checked_sub_below_zero() {
+        let a = Quantity::from("0.5");
+        let b = Quantity::from("1.5");
+
+        assert!(matches!(
+            a.checked_sub(b),
+            Err(ArithmeticError::Overflow { op: "-", .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_checked_add_overflow() {
+        let a = Quantity::from_raw(u64::MAX, 9).unwrap();
+        let b = Quantity::from_raw(1, 9).unwrap();
+
+        assert!(matches!(
+            a.checked_add(b),
+            Err(ArithmeticError::Overflow { op: "+", .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_checked_add_precision_mismatch() {
+        let a = Quantity::from("1.0");
+        let b = Quantity::from("1.00");
+
+        assert!(matches!(
+            a.checked_add(b),
+            Err(ArithmeticError::PrecisionMismatch {
+                lhs_precision: 1,
+                rhs_precision: 2,
+                ..
+            })
+        ));
+    }
+
+    #[rstest]
+    fn test_checked_mul_qty() {
+        let qty = Quantity::from("1.5");
+
+        assert_eq!(
+            qty.checked_mul_qty(Quantity::from(2)).unwrap(),
+            Quantity::from("3.0")
+        );
+        assert!(Quantity::from_raw(u64::MAX, 0)
+            .unwrap()
+            .checked_mul_qty(Quantity::from(2))
+            .is_err());
+    }
+
+    #[cfg(debug_assertions)]
+    #[rstest]
+    #[should_panic(expected = "Arithmetic overflow")]
+    fn test_sub_below_zero_panics_in_debug() {
+        let _ = Quantity::from("0.5") - Quantity::from("1.5");
+    }
+
+    #[rstest]
+    #[case(2.5, 0, RoundingMode::HalfUp, "3")]
+    #[case(2.5, 0, RoundingMode::HalfEven, "2")]
+    #[case(3.5, 0, RoundingMode::HalfEven, "4")]
+    #[case(2.5, 0, RoundingMode::Floor, "2")]
+    #[case(2.5, 0, RoundingMode::Ceiling, "3")]
+    #[case(2.5, 0, RoundingMode::Down, "2")]
+    #[case(2.5, 0, RoundingMode::Up, "3")]
+    #[case(0.125, 2, RoundingMode::HalfUp, "0.13")]
+    #[case(0.125, 2, RoundingMode::HalfEven, "0.12")]
+    #[case(0.125, 2, RoundingMode::Floor, "0.12")]
+    #[case(0.125, 2, RoundingMode::Ceiling, "0.13")]
+    #[case(0.125, 2, RoundingMode::Down, "0.12")]
+    #[case(0.125, 2, RoundingMode::Up, "0.13")]
+    fn test_new_with_rounding_at_halfway(
+        #[case] value: f64,
+        #[case] precision: u8,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let qty = Quantity::new_with_rounding(value, precision, mode).unwrap();
+
+        assert_eq!(qty, Quantity::from(expected));
+        assert_eq!(qty.precision, precision);
+    }
+
+    #[rstest]
+    fn test_new_with_rounding_invalid() {
+        assert!(Quantity::new_with_rounding(-1.0, 0, RoundingMode::HalfUp).is_err());
+        assert!(
+            Quantity::new_with_rounding(1.0, FIXED_PRECISION + 1, RoundingMode::HalfUp).is_err()
+        );
+    }
+
+    #[rstest]
+    fn test_min_max() {
+        const ONE: Quantity = Quantity {
+            raw: 1,
+            precision: 9,
+        };
+        const TWO: Quantity = Quantity {
+            raw: 2,
+            precision: 9,
+        };
+        const MIN: Quantity = ONE.min(TWO);
+        let a = Quantity::from("1.5");
+        let b = Quantity::from("2.0");
+
+        assert_eq!(a.min(b), a);
+        assert_eq!(b.min(a), a);
+        assert_eq!(a.max(b), b);
+        assert_eq!(b.max(a), b);
+        assert_eq!(MIN.raw, 1);
+    }
+
+    #[rstest]
+    #[case("10.7", 0.25, "0.5", "2.5")]
+    #[case("100", 0.333, "1", "33")]
+    #[case("1.00000", 0.5, "0.00001", "0.50000")]
+    #[case("3", 1.0, "0.1", "3.0")]
+    #[case("3", 0.0, "0.1", "0.0")]
+    #[case("0.3", 0.1, "0.01", "0.03")] // Exact in decimal, not in binary
+    fn test_mul_f64_floor(
+        #[case] qty: &str,
+        #[case] factor: f64,
+        #[case] size_increment: &str,
+        #[case] expected: &str,
+    ) {
+        let result = Quantity::from(qty).mul_f64_floor(factor, Quantity::from(size_increment));
+
+        assert_eq!(result, Quantity::from(expected));
+        assert_eq!(result.precision, Quantity::from(size_increment).precision);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "factor")]
+    fn test_mul_f64_floor_with_negative_factor() {
+        let _ = Quantity::from(1).mul_f64_floor(-0.5, Quantity::from(1));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "size_increment")]
+    fn test_mul_f64_floor_with_zero_increment() {
+        let _ = Quantity::from(1).mul_f64_floor(0.5, Quantity::from(0));
+    }
+
+    #[rstest]
+    #[case("10", 3, "1", vec!["4", "3", "3"])]
+    #[case("1.0", 4, "0.1", vec!["0.3", "0.3", "0.2", "0.2"])]
+    #[case("2", 3, "1", vec!["1", "1", "0"])]
+    #[case("10.05", 2, "1", vec!["5.05", "5.00"])] // Residual to the first part
+    #[case("7", 1, "1", vec!["7"])]
+    fn test_split(
+        #[case] qty: &str,
+        #[case] parts: usize,
+        #[case] size_increment: &str,
+        #[case] expected: Vec<&str>,
+    ) {
+        let result = Quantity::from(qty).split(parts, Quantity::from(size_increment));
+        let expected: Vec<Quantity> = expected.into_iter().map(Quantity::from).collect();
+
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "parts")]
+    fn test_split_into_zero_parts() {
+        let _ = Quantity::from(1).split(0, Quantity::from(1));
+    }
+
+    #[rstest]
+    fn test_decimal_round_trip_satoshi() {
+        let value = Decimal::from_str("0.00000001").unwrap();
+        let qty = Quantity::try_from(value).unwrap();
+
+        assert_eq!(qty.raw, 10);
+        assert_eq!(qty.precision, 8);
+        assert_eq!(qty.to_string(), "0.00000001");
+        assert_eq!(Decimal::from(qty), value);
+    }
+
+    #[rstest]
+    #[case(dec!(0.123456789), 8, "0.12345679")]
+    #[case(dec!(100), 2, "100.00")]
+    fn test_from_decimal(#[case] value: Decimal, #[case] precision: u8, #[case] expected: &str) {
+        let qty = Quantity::from_decimal(value, precision).unwrap();
+
+        assert_eq!(qty, Quantity::from(expected));
+        assert_eq!(qty.precision, precision);
+    }
+
+    #[rstest]
+    #[case(dec!(-0.1))]
+    #[case(dec!(18446744074))]
+    #[case(dec!(1.0000000001))]
+    fn test_try_from_decimal_invalid(#[case] value: Decimal) {
+        assert!(Quantity::try_from(value).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_checked_add_sub_match_raw(a in any::<u64>(), b in any::<u64>()) {
+            let lhs = Quantity::from_raw(a, 9).unwrap();
+            let rhs = Quantity::from_raw(b, 9).unwrap();
+
+            match a.checked_add(b) {
+                Some(raw) => prop_assert_eq!(lhs.checked_add(rhs).unwrap().raw, raw),
+                None => prop_assert!(lhs.checked_add(rhs).is_err()),
+            }
+            match a.checked_sub(b) {
+                Some(raw) => prop_assert_eq!(lhs.checked_sub(rhs).unwrap().raw, raw),
+                None => prop_assert!(lhs.checked_sub(rhs).is_err()),
+            }
+        }
+
+        #[test]
+        fn test_checked_add_near_bounds(a in (u64::MAX - 1_000)..=u64::MAX, b in 0..2_000_u64) {
+            let lhs = Quantity::from_raw(a, 9).unwrap();
+            let rhs = Quantity::from_raw(b, 9).unwrap();
+
+            prop_assert_eq!(lhs.checked_add(rhs).is_ok(), a <= u64::MAX - b);
+        }
+
+        #[test]
+        fn test_checked_mul_qty_matches_wide_product(a in any::<u64>(), b in any::<u64>()) {
+            let lhs = Quantity::from_raw(a, 9).unwrap();
+            let rhs = Quantity::from_raw(b, 9).unwrap();
+            let expected = u128::from(a) * u128::from(b) / 1_000_000_000;
+
+            match u64::try_from(expected) {
+                Ok(raw) => prop_assert_eq!(lhs.checked_mul_qty(rhs).unwrap().raw, raw),
+                Err(_) => prop_assert!(lhs.checked_mul_qty(rhs).is_err()),
+            }
+        }
+
+        #[test]
+        fn test_split_sums_to_original(
+            raw in 0..QUANTITY_MAX_RAW,
+            parts in 1..200_usize,
+            increment_precision in 0..=FIXED_PRECISION,
+        ) {
+            let qty = Quantity::from_raw(raw, FIXED_PRECISION).unwrap();
+            let size_increment = Quantity::from_raw(
+                10_u64.pow(u32::from(FIXED_PRECISION - increment_precision)),
+                increment_precision,
+            )
+            .unwrap();
+
+            let split = qty.split(parts, size_increment);
+            let residual = raw % size_increment.raw;
+
+            prop_assert_eq!(split.len(), parts);
+            prop_assert_eq!(split.iter().map(|part| u128::from(part.raw)).sum::<u128>(), u128::from(raw));
+            prop_assert_eq!((split[0].raw - residual) % size_increment.raw, 0);
+            prop_assert!(split[1..].iter().all(|part| part.raw % size_increment.raw == 0));
+            prop_assert!(split.windows(2).all(|w| w[0].raw - w[1].raw <= size_increment.raw + residual));
+        }
+
+        #[test]
+        fn test_mul_f64_floor_bounds(
+            raw in 0..1_000_000_000_000_000_u64,
+            factor in 0.0..=1.0_f64,
+            increment_precision in 0..=FIXED_PRECISION,
+        ) {
+            let qty = Quantity::from_raw(raw, FIXED_PRECISION).unwrap();
+            let size_increment = Quantity::from_raw(
+                10_u64.pow(u32::from(FIXED_PRECISION - increment_precision)),
+                increment_precision,
+            )
+            .unwrap();
+
+            let result = qty.mul_f64_floor(factor, size_increment);
+            let exact = qty.as_decimal() * Decimal::from_str(&factor.to_string()).unwrap();
+
+            prop_assert_eq!(result.raw % size_increment.raw, 0);
+            prop_assert!(result.as_decimal() <= exact);
+            prop_assert!(result.as_decimal() + size_increment.as_decimal() > exact);
+        }
+    }
 }
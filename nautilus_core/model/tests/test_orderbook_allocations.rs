@@ -0,0 +1,113 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Counts heap allocations made while iterating order book levels.
+//!
+//! Kept as a single test in its own binary, as the counting allocator is process wide.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use nautilus_model::{
+    data::order::BookOrder,
+    enums::{BookType, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+    orderbook::book::OrderBook,
+    types::{price::Price, quantity::Quantity},
+};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let result = f();
+    (result, ALLOCATIONS.load(Ordering::SeqCst) - before)
+}
+
+#[test]
+fn test_level_iteration_allocations() {
+    let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+    let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+    for i in 0..100 {
+        let bid = BookOrder::new(
+            OrderSide::Buy,
+            Price::new(100.0 - f64::from(i) * 0.01, 2).unwrap(),
+            Quantity::from("1.0"),
+            0,
+        );
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::new(100.01 + f64::from(i) * 0.01, 2).unwrap(),
+            Quantity::from("1.0"),
+            0,
+        );
+        book.add(bid, 0, 1, 1.into());
+        book.add(ask, 0, 1, 1.into());
+    }
+
+    let (total, allocations) = count_allocations(|| {
+        book.iter_bids(Some(10))
+            .chain(book.iter_asks(None))
+            .map(|(_, size)| size.as_f64())
+            .sum::<f64>()
+    });
+    assert_eq!(total, 110.0);
+    assert_eq!(allocations, 0);
+
+    let qty = Quantity::from("50.5");
+    let (levels, allocations) = count_allocations(|| book.levels_filled_by(qty, OrderSide::Buy));
+    assert_eq!(levels, 51);
+    assert_eq!(allocations, 0);
+
+    let (order_count, allocations) = count_allocations(|| {
+        book.bids(Some(10))
+            .chain(book.asks(Some(10)))
+            .map(|(_, _, count)| count)
+            .sum::<usize>()
+    });
+    assert_eq!(order_count, 20);
+    assert_eq!(allocations, 0);
+
+    let (depth, allocations) = count_allocations(|| book.snapshot(10));
+    assert_eq!(depth.bids[9].price, Price::from("99.91"));
+    assert_eq!(allocations, 0);
+
+    // The existing snapshot methods allocate per call
+    let (snapshot, allocations) = count_allocations(|| {
+        book.bid_levels()
+            .map(|level| (level.price.value, level.size()))
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(snapshot.len(), 100);
+    assert!(allocations > 0);
+}
@@ -31,6 +31,7 @@
 pub mod http;
 #[allow(dead_code)]
 mod ratelimiter;
+pub mod retry;
 pub mod socket;
 pub mod websocket;
 
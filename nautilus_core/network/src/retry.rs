@@ -0,0 +1,378 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Retry with exponential backoff for fallible async adapter operations.
+
+use std::{
+    fmt::{Debug, Display},
+    future::Future,
+    time::Duration,
+};
+
+use rand::Rng;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+/// The policy controlling how an operation is retried.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy<E> {
+    /// The maximum number of attempts (including the first).
+    pub max_attempts: u32,
+    /// The delay before the first retry.
+    pub initial_delay: Duration,
+    /// The factor the delay is multiplied by after each retry.
+    pub multiplier: f64,
+    /// The upper bound on the delay between attempts (before jitter).
+    pub max_delay: Duration,
+    /// The maximum random delay added to each backoff.
+    pub jitter: Duration,
+    /// The total time budget in nanoseconds, measured from the first attempt.
+    pub deadline_ns: Option<u64>,
+    /// Returns whether the given error is transient and the operation should be retried.
+    pub retry_on: fn(&E) -> bool,
+}
+
+impl<E> Default for RetryPolicy<E> {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(100),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(10),
+            jitter: Duration::from_millis(100),
+            deadline_ns: None,
+            retry_on: |_| true,
+        }
+    }
+}
+
+impl<E> RetryPolicy<E> {
+    /// Returns the backoff (before jitter) following the given 1-based `attempt`.
+    #[must_use]
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        if delay.is_finite() && delay < self.max_delay.as_secs_f64() {
+            Duration::from_secs_f64(delay)
+        } else {
+            self.max_delay
+        }
+    }
+}
+
+/// The reason an operation stopped being retried.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RetryStop {
+    /// The maximum number of attempts was made.
+    MaxAttempts,
+    /// The next attempt would have started after the deadline.
+    Deadline,
+    /// The last error was not retryable according to the policy.
+    NonRetryable,
+    /// The cancellation token was triggered.
+    Canceled,
+}
+
+/// The error returned when an operation did not succeed, carrying every attempt's error.
+#[derive(Debug)]
+pub struct RetriesExhausted<E> {
+    /// The errors from each attempt, in order.
+    pub errors: Vec<E>,
+    pub stop: RetryStop,
+}
+
+impl<E> RetriesExhausted<E> {
+    /// Returns the error from the final attempt (if any attempt was made).
+    #[must_use]
+    pub fn last_error(&self) -> Option<&E> {
+        self.errors.last()
+    }
+}
+
+impl<E: Display> Display for RetriesExhausted<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Operation failed after {} attempt(s) ({:?})",
+            self.errors.len(),
+            self.stop,
+        )?;
+        if let Some(e) = self.last_error() {
+            write!(f, ": {e}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Debug + Display> std::error::Error for RetriesExhausted<E> {}
+
+pub type AttemptHandler = Box<dyn Fn(u32) + Send + Sync>;
+
+/// Runs async operations with retries according to a [`RetryPolicy`].
+pub struct RetryManager<E> {
+    policy: RetryPolicy<E>,
+    on_attempt: Option<AttemptHandler>,
+}
+
+impl<E: Display> RetryManager<E> {
+    #[must_use]
+    pub fn new(policy: RetryPolicy<E>) -> Self {
+        Self {
+            policy,
+            on_attempt: None,
+        }
+    }
+
+    #[must_use]
+    pub fn policy(&self) -> &RetryPolicy<E> {
+        &self.policy
+    }
+
+    /// Sets the handler called with the 1-based attempt number before each attempt.
+    pub fn set_on_attempt(&mut self, handler: AttemptHandler) {
+        self.on_attempt = Some(handler);
+    }
+
+    /// Runs the `operation` until it succeeds or the policy stops retrying.
+    ///
+    /// Triggering the `cancel` token interrupts a pending backoff sleep (or an in-flight
+    /// attempt) so shutdown is not blocked.
+    pub async fn run_with_retry<T, F, Fut>(
+        &self,
+        name: &str,
+        mut operation: F,
+        cancel: &CancellationToken,
+    ) -> Result<T, RetriesExhausted<E>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let start = Instant::now();
+        let deadline = self
+            .policy
+            .deadline_ns
+            .map(|ns| start + Duration::from_nanos(ns));
+        let mut errors = Vec::new();
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            if let Some(handler) = &self.on_attempt {
+                handler(attempt);
+            }
+
+            let result = tokio::select! {
+                () = cancel.cancelled() => return Err(exhausted(errors, RetryStop::Canceled)),
+                result = operation() => result,
+            };
+            let e = match result {
+                Ok(value) => return Ok(value),
+                Err(e) => e,
+            };
+
+            if !(self.policy.retry_on)(&e) {
+                errors.push(e);
+                return Err(exhausted(errors, RetryStop::NonRetryable));
+            }
+            if attempt >= self.policy.max_attempts {
+                errors.push(e);
+                return Err(exhausted(errors, RetryStop::MaxAttempts));
+            }
+
+            let delay = self.policy.backoff(attempt) + self.jitter();
+            if deadline.is_some_and(|deadline| Instant::now() + delay > deadline) {
+                errors.push(e);
+                return Err(exhausted(errors, RetryStop::Deadline));
+            }
+
+            warn!("{name} failed on attempt {attempt}, retrying in {delay:?}: {e}");
+            errors.push(e);
+
+            tokio::select! {
+                () = cancel.cancelled() => return Err(exhausted(errors, RetryStop::Canceled)),
+                () = tokio::time::sleep(delay) => {}
+            }
+        }
+    }
+
+    fn jitter(&self) -> Duration {
+        let max = u64::try_from(self.policy.jitter.as_nanos()).unwrap_or(u64::MAX);
+        if max == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_nanos(rand::thread_rng().gen_range(0..=max))
+    }
+}
+
+fn exhausted<E>(errors: Vec<E>, stop: RetryStop) -> RetriesExhausted<E> {
+    RetriesExhausted { errors, stop }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn policy(max_attempts: u32) -> RetryPolicy<String> {
+        RetryPolicy {
+            max_attempts,
+            initial_delay: Duration::from_millis(10),
+            multiplier: 2.0,
+            max_delay: Duration::from_millis(50),
+            jitter: Duration::ZERO,
+            deadline_ns: None,
+            retry_on: |_| true,
+        }
+    }
+
+    /// Returns an operation which fails the first `failures` calls and then succeeds.
+    fn flaky(
+        failures: u32,
+        calls: Arc<AtomicU32>,
+    ) -> impl FnMut() -> std::pin::Pin<Box<dyn Future<Output = Result<u32, String>> + Send>> {
+        move || {
+            let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Box::pin(async move {
+                if call <= failures {
+                    Err(format!("error {call}"))
+                } else {
+                    Ok(call)
+                }
+            })
+        }
+    }
+
+    #[rstest]
+    fn test_backoff_is_capped() {
+        let policy = policy(10);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(10));
+        assert_eq!(policy.backoff(2), Duration::from_millis(20));
+        assert_eq!(policy.backoff(3), Duration::from_millis(40));
+        assert_eq!(policy.backoff(4), Duration::from_millis(50));
+        assert_eq!(policy.backoff(100), Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_succeeds_after_failures() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let attempts = Arc::new(AtomicU32::new(0));
+        let attempts_clone = attempts.clone();
+        let mut manager = RetryManager::new(policy(5));
+        manager.set_on_attempt(Box::new(move |attempt| {
+            attempts_clone.store(attempt, Ordering::SeqCst);
+        }));
+
+        let result = manager
+            .run_with_retry("test", flaky(2, calls.clone()), &CancellationToken::new())
+            .await;
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retries_exhausted_carries_every_error() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let manager = RetryManager::new(policy(3));
+
+        let err = manager
+            .run_with_retry("test", flaky(10, calls.clone()), &CancellationToken::new())
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.stop, RetryStop::MaxAttempts);
+        assert_eq!(err.errors, vec!["error 1", "error 2", "error 3"]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(
+            err.to_string(),
+            "Operation failed after 3 attempt(s) (MaxAttempts): error 3"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_error_stops_immediately() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let manager = RetryManager::new(RetryPolicy {
+            retry_on: |e: &String| !e.ends_with('1'),
+            ..policy(5)
+        });
+
+        let err = manager
+            .run_with_retry("test", flaky(10, calls.clone()), &CancellationToken::new())
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.stop, RetryStop::NonRetryable);
+        assert_eq!(err.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_deadline_expires_mid_backoff() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let manager = RetryManager::new(RetryPolicy {
+            // Backoffs of 10ms, 20ms, 40ms: the third would end beyond the deadline
+            deadline_ns: Some(Duration::from_millis(45).as_nanos() as u64),
+            ..policy(10)
+        });
+
+        let start = Instant::now();
+        let err = manager
+            .run_with_retry("test", flaky(10, calls.clone()), &CancellationToken::new())
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.stop, RetryStop::Deadline);
+        assert_eq!(err.errors.len(), 3);
+        assert!(start.elapsed() < Duration::from_millis(45));
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_during_sleep() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let manager = RetryManager::new(RetryPolicy {
+            initial_delay: Duration::from_secs(60),
+            max_delay: Duration::from_secs(60),
+            ..policy(5)
+        });
+        let cancel = CancellationToken::new();
+        let cancel_clone = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            cancel_clone.cancel();
+        });
+
+        let start = Instant::now();
+        let err = manager
+            .run_with_retry("test", flaky(10, calls.clone()), &cancel)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.stop, RetryStop::Canceled);
+        assert_eq!(err.errors, vec!["error 1"]);
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -33,6 +33,8 @@ use super::{
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
 impl ArrowSchemaProvider for Bar {
+    const TYPE_NAME: &'static str = "Bar";
+
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
         let fields = vec![
             Field::new("open", DataType::Int64, false),
@@ -105,7 +107,7 @@ impl EncodeToRecordBatch for Bar {
         let ts_init_array = ts_init_builder.finish();
 
         RecordBatch::try_new(
-            Self::get_schema(Some(metadata.clone())).into(),
+            Self::get_versioned_schema(metadata).into(),
             vec![
                 Arc::new(open_array),
                 Arc::new(high_array),
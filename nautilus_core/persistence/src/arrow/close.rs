@@ -0,0 +1,223 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Int64Array, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::close::InstrumentClose, enums::InstrumentCloseType,
+    identifiers::instrument_id::InstrumentId, types::price::Price,
+};
+
+use super::{
+    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
+    KEY_PRICE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for InstrumentClose {
+    const TYPE_NAME: &'static str = "InstrumentClose";
+
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("close_price", DataType::Int64, false),
+            Field::new("close_type", DataType::UInt8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<(InstrumentId, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let price_precision = metadata
+        .get(KEY_PRICE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_PRICE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_PRICE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, price_precision))
+}
+
+impl EncodeToRecordBatch for InstrumentClose {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut close_price_builder = Int64Array::builder(data.len());
+        let mut close_type_builder = UInt8Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for close in data {
+            close_price_builder.append_value(close.close_price.raw);
+            close_type_builder.append_value(close.close_type as u8);
+            ts_event_builder.append_value(close.ts_event.as_u64());
+            ts_init_builder.append_value(close.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_versioned_schema(metadata).into(),
+            vec![
+                Arc::new(close_price_builder.finish()),
+                Arc::new(close_type_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for InstrumentClose {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let close_price_values =
+            extract_column::<Int64Array>(cols, "close_price", 0, DataType::Int64)?;
+        let close_type_values =
+            extract_column::<UInt8Array>(cols, "close_type", 1, DataType::UInt8)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 2, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 3, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let close_price = Price::from_raw(close_price_values.value(i), price_precision)
+                    .map_err(|e| EncodingError::ParseError(stringify!(Price), e.to_string()))?;
+                let close_type_value = close_type_values.value(i);
+                let close_type = InstrumentCloseType::from_repr(close_type_value as usize)
+                    .ok_or_else(|| {
+                        EncodingError::ParseError(
+                            stringify!(InstrumentCloseType),
+                            format!("Invalid enum value, was {close_type_value}"),
+                        )
+                    })?;
+
+                Ok(Self {
+                    instrument_id,
+                    close_price,
+                    close_type,
+                    ts_event: ts_event_values.value(i).into(),
+                    ts_init: ts_init_values.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl DecodeDataFromRecordBatch for InstrumentClose {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let closes: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(closes.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn closes(instrument_id: InstrumentId) -> Vec<InstrumentClose> {
+        vec![
+            InstrumentClose::new(
+                instrument_id,
+                Price::from("5000.25"),
+                InstrumentCloseType::EndOfSession,
+                1.into(),
+                3.into(),
+            ),
+            InstrumentClose::new(
+                instrument_id,
+                Price::from("5010.50"),
+                InstrumentCloseType::ContractExpired,
+                2.into(),
+                4.into(),
+            ),
+        ]
+    }
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = InstrumentClose::get_schema_map();
+        let mut expected_map = HashMap::new();
+        expected_map.insert("close_price".to_string(), "Int64".to_string());
+        expected_map.insert("close_type".to_string(), "UInt8".to_string());
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(schema_map, expected_map);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let instrument_id = InstrumentId::from("ESZ4.GLBX");
+        let metadata = InstrumentClose::get_metadata(&instrument_id, 2);
+        let data = closes(instrument_id);
+
+        let record_batch = InstrumentClose::encode_batch(&metadata, &data).unwrap();
+        let close_type_values = record_batch.columns()[1]
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .unwrap();
+        assert_eq!(close_type_values.value(0), 1);
+        assert_eq!(close_type_values.value(1), 2);
+
+        let decoded = InstrumentClose::decode_batch(&metadata, record_batch).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_invalid_close_type_errors() {
+        let instrument_id = InstrumentId::from("ESZ4.GLBX");
+        let metadata = InstrumentClose::get_metadata(&instrument_id, 2);
+        let record_batch = RecordBatch::try_new(
+            InstrumentClose::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(Int64Array::from(vec![5_000_250_000_000])),
+                Arc::new(UInt8Array::from(vec![9])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![2])),
+            ],
+        )
+        .unwrap();
+
+        let result = InstrumentClose::decode_batch(&metadata, record_batch);
+        assert!(matches!(result, Err(EncodingError::ParseError(_, _))));
+    }
+}
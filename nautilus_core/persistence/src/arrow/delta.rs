@@ -35,6 +35,8 @@ use super::{
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
 impl ArrowSchemaProvider for OrderBookDelta {
+    const TYPE_NAME: &'static str = "OrderBookDelta";
+
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
         let fields = vec![
             Field::new("action", DataType::UInt8, false),
@@ -117,7 +119,7 @@ impl EncodeToRecordBatch for OrderBookDelta {
         let ts_init_array = ts_init_builder.finish();
 
         RecordBatch::try_new(
-            Self::get_schema(Some(metadata.clone())).into(),
+            Self::get_versioned_schema(metadata).into(),
             vec![
                 Arc::new(action_array),
                 Arc::new(side_array),
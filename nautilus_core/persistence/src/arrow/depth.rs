@@ -38,6 +38,8 @@ use super::{
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
 impl ArrowSchemaProvider for OrderBookDepth10 {
+    const TYPE_NAME: &'static str = "OrderBookDepth10";
+
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
         let fields = vec![
             Field::new("bid_price_0", DataType::Int64, false),
@@ -221,7 +223,7 @@ impl EncodeToRecordBatch for OrderBookDepth10 {
         columns.push(ts_event_array);
         columns.push(ts_init_array);
 
-        RecordBatch::try_new(Self::get_schema(Some(metadata.clone())).into(), columns)
+        RecordBatch::try_new(Self::get_versioned_schema(metadata).into(), columns)
     }
 }
 
@@ -0,0 +1,364 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides versioning of the Arrow schemas for Nautilus types, and migration of
+//! record batches encoded with an earlier version of a schema.
+//!
+//! Each encoded batch carries the [`ArrowSchemaProvider::SCHEMA_VERSION`] of its type under
+//! the [`KEY_SCHEMA_VERSION`] metadata key. On read, a batch is migrated one version at a
+//! time up to the current version, so a schema change only needs a single migration from
+//! the version before it. The migrations for the built-in types are registered by
+//! [`SchemaMigrations::builtin`].
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::ArrayRef,
+    datatypes::{Field, Schema},
+    record_batch::RecordBatch,
+};
+
+use nautilus_model::data::quote::QuoteTick;
+
+use super::{quote, ArrowSchemaProvider, EncodingError};
+
+pub const KEY_SCHEMA_VERSION: &str = "schema_version";
+
+/// The schema version of batches encoded before versioning was introduced.
+pub const UNVERSIONED_SCHEMA_VERSION: u32 = 1;
+
+/// Migrates a record batch from one schema version to the next.
+pub type MigrationFn = fn(RecordBatch) -> Result<RecordBatch, EncodingError>;
+
+/// Provides a registry of schema migrations, keyed by type name and the version migrated from.
+#[derive(Clone, Debug, Default)]
+pub struct SchemaMigrations {
+    migrations: HashMap<(&'static str, u32), MigrationFn>,
+}
+
+impl SchemaMigrations {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a registry of the migrations for the schemas of the built-in types.
+    #[must_use]
+    pub fn builtin() -> Self {
+        let mut migrations = Self::new();
+        migrations.register(QuoteTick::TYPE_NAME, 1, quote::migrate_v1);
+        migrations
+    }
+
+    /// Registers the `migration` of the schema for `type_name` from `from_version` to
+    /// `from_version + 1`, replacing any migration already registered for that step.
+    pub fn register(&mut self, type_name: &'static str, from_version: u32, migration: MigrationFn) {
+        self.migrations.insert((type_name, from_version), migration);
+    }
+
+    /// Checks a batch with the given `metadata` can be migrated to `current_version`,
+    /// returning the version it was encoded with.
+    pub fn validate(
+        &self,
+        type_name: &'static str,
+        current_version: u32,
+        metadata: &HashMap<String, String>,
+    ) -> Result<u32, EncodingError> {
+        let version = schema_version(metadata)?;
+        if version > current_version {
+            return Err(EncodingError::UnsupportedSchemaVersion(
+                type_name,
+                version,
+                current_version,
+            ));
+        }
+        if let Some(missing) =
+            (version..current_version).find(|v| !self.migrations.contains_key(&(type_name, *v)))
+        {
+            return Err(EncodingError::MissingMigration(type_name, missing));
+        }
+        Ok(version)
+    }
+
+    /// Migrates the `batch` to `current_version` of the schema for `type_name`.
+    ///
+    /// Batches already at the current version are returned unchanged.
+    pub fn migrate(
+        &self,
+        type_name: &'static str,
+        current_version: u32,
+        batch: RecordBatch,
+    ) -> Result<RecordBatch, EncodingError> {
+        let version = self.validate(type_name, current_version, batch.schema().metadata())?;
+
+        let mut batch = batch;
+        for from_version in version..current_version {
+            let migration = self.migrations[&(type_name, from_version)];
+            batch = with_schema_version(migration(batch)?, from_version + 1)?;
+        }
+        Ok(batch)
+    }
+
+    /// Migrates the `batch` to the current version of the schema for `T`.
+    pub fn migrate_batch<T: ArrowSchemaProvider>(
+        &self,
+        batch: RecordBatch,
+    ) -> Result<RecordBatch, EncodingError> {
+        self.migrate(T::TYPE_NAME, T::SCHEMA_VERSION, batch)
+    }
+}
+
+/// Returns the schema version recorded in the `metadata`, or [`UNVERSIONED_SCHEMA_VERSION`]
+/// if there is none.
+pub fn schema_version(metadata: &HashMap<String, String>) -> Result<u32, EncodingError> {
+    metadata
+        .get(KEY_SCHEMA_VERSION)
+        .map_or(Ok(UNVERSIONED_SCHEMA_VERSION), |version| {
+            version
+                .parse::<u32>()
+                .map_err(|e| EncodingError::ParseError(KEY_SCHEMA_VERSION, e.to_string()))
+        })
+}
+
+/// Returns the `batch` with `column` inserted at `index`, for use by migrations which add
+/// a column to a schema.
+pub fn insert_column(
+    batch: &RecordBatch,
+    index: usize,
+    field: Field,
+    column: ArrayRef,
+) -> Result<RecordBatch, EncodingError> {
+    let schema = batch.schema();
+    let mut fields: Vec<Field> = schema.fields().iter().map(|f| f.as_ref().clone()).collect();
+    let mut columns = batch.columns().to_vec();
+    fields.insert(index, field);
+    columns.insert(index, column);
+
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+}
+
+fn with_schema_version(batch: RecordBatch, version: u32) -> Result<RecordBatch, EncodingError> {
+    let mut metadata = batch.schema().metadata().clone();
+    metadata.insert(KEY_SCHEMA_VERSION.to_string(), version.to_string());
+    let schema = batch.schema().as_ref().clone().with_metadata(metadata);
+    Ok(RecordBatch::try_new(
+        Arc::new(schema),
+        batch.columns().to_vec(),
+    )?)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use datafusion::{
+        arrow::{
+            array::{Array, Int64Array, UInt64Array},
+            datatypes::DataType,
+        },
+        parquet::arrow::{arrow_reader::ParquetRecordBatchReaderBuilder, ArrowWriter},
+    };
+    use nautilus_model::{
+        data::Data, identifiers::instrument_id::InstrumentId, types::price::Price,
+    };
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::{arrow::EncodeToRecordBatch, backend::session::DataBackendSession};
+
+    /// A version 1 `QuoteTick` batch, from before version 2 added `ts_event`.
+    fn quotes_v1() -> RecordBatch {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut metadata = QuoteTick::get_metadata(&instrument_id, 2, 0);
+        metadata.insert(KEY_SCHEMA_VERSION.to_string(), "1".to_string());
+        let fields = vec![
+            Field::new("bid_price", DataType::Int64, false),
+            Field::new("ask_price", DataType::Int64, false),
+            Field::new("bid_size", DataType::UInt64, false),
+            Field::new("ask_size", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+        RecordBatch::try_new(
+            Arc::new(Schema::new_with_metadata(fields, metadata)),
+            vec![
+                Arc::new(Int64Array::from(vec![10000, 9900])),
+                Arc::new(Int64Array::from(vec![10100, 10000])),
+                Arc::new(UInt64Array::from(vec![100, 90])),
+                Arc::new(UInt64Array::from(vec![110, 100])),
+                Arc::new(UInt64Array::from(vec![3, 4])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn write_parquet(dir: &TempDir, batch: &RecordBatch) -> String {
+        let path = dir.path().join("part-0.parquet");
+        let mut writer =
+            ArrowWriter::try_new(File::create(&path).unwrap(), batch.schema(), None).unwrap();
+        writer.write(batch).unwrap();
+        writer.close().unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    fn write_and_read(dir: &TempDir, batch: &RecordBatch) -> RecordBatch {
+        let path = write_parquet(dir, batch);
+
+        // The reader only attaches the file schema metadata (holding the schema version) to the
+        // builder schema, so reattach it to the batch read
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+        let schema = builder.schema().clone();
+        let batch = builder.build().unwrap().next().unwrap().unwrap();
+        RecordBatch::try_new(schema, batch.columns().to_vec()).unwrap()
+    }
+
+    #[rstest]
+    fn test_schema_version_defaults_when_unversioned() {
+        assert_eq!(
+            schema_version(&HashMap::new()).unwrap(),
+            UNVERSIONED_SCHEMA_VERSION
+        );
+    }
+
+    #[rstest]
+    fn test_encode_batch_records_schema_version() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = QuoteTick::get_metadata(&instrument_id, 2, 0);
+        let batch = QuoteTick::encode_batch(&metadata, &[]).unwrap();
+
+        assert_eq!(
+            schema_version(batch.schema().metadata()).unwrap(),
+            QuoteTick::SCHEMA_VERSION
+        );
+    }
+
+    #[rstest]
+    fn test_migrate_current_version_is_unchanged() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = QuoteTick::get_metadata(&instrument_id, 2, 0);
+        let batch = QuoteTick::encode_batch(&metadata, &[]).unwrap();
+
+        let migrated = SchemaMigrations::new()
+            .migrate_batch::<QuoteTick>(batch.clone())
+            .unwrap();
+
+        assert_eq!(migrated, batch);
+    }
+
+    #[rstest]
+    fn test_read_v1_file_fills_missing_column() {
+        let dir = TempDir::new().unwrap();
+        let path = write_parquet(&dir, &quotes_v1());
+        let mut session = DataBackendSession::new(1_000);
+
+        session
+            .add_file::<QuoteTick>("quotes_v1", &path, None)
+            .unwrap();
+        let quotes: Vec<QuoteTick> = session
+            .get_query_result()
+            .map(|data| match data {
+                Data::Quote(quote) => quote,
+                _ => panic!("Expected quote"),
+            })
+            .collect();
+
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(quotes[0].bid_price, Price::from_raw(10000, 2).unwrap());
+        assert_eq!(quotes[0].ts_event, 3);
+        assert_eq!(quotes[1].ts_event, 4);
+        assert_eq!(quotes[1].ts_init, 4);
+    }
+
+    #[rstest]
+    fn test_migrate_v1_records_new_version() {
+        let migrated = SchemaMigrations::builtin()
+            .migrate_batch::<QuoteTick>(quotes_v1())
+            .unwrap();
+
+        assert_eq!(migrated.num_columns(), 6);
+        assert_eq!(migrated.column(4).len(), 2);
+        assert_eq!(schema_version(migrated.schema().metadata()).unwrap(), 2);
+    }
+
+    #[rstest]
+    fn test_migrate_unversioned_batch_with_column_is_unchanged() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = QuoteTick::get_metadata(&instrument_id, 2, 0);
+        let encoded = QuoteTick::encode_batch(&metadata, &[QuoteTick::default()]).unwrap();
+        // Written before versioning, so without a schema version
+        let batch = RecordBatch::try_new(
+            Arc::new(QuoteTick::get_schema(Some(metadata))),
+            encoded.columns().to_vec(),
+        )
+        .unwrap();
+
+        let migrated = SchemaMigrations::builtin()
+            .migrate_batch::<QuoteTick>(batch.clone())
+            .unwrap();
+
+        assert_eq!(migrated.columns(), batch.columns());
+        assert_eq!(schema_version(migrated.schema().metadata()).unwrap(), 2);
+    }
+
+    #[rstest]
+    fn test_session_uses_registered_migration() {
+        /// Drops every row, to observe the registered migration replaced the built-in one.
+        fn drop_rows(batch: RecordBatch) -> Result<RecordBatch, EncodingError> {
+            Ok(quote::migrate_v1(batch)?.slice(0, 0))
+        }
+
+        let dir = TempDir::new().unwrap();
+        let path = write_parquet(&dir, &quotes_v1());
+        let mut session = DataBackendSession::new(1_000);
+        session.register_migration(QuoteTick::TYPE_NAME, 1, drop_rows);
+
+        session
+            .add_file::<QuoteTick>("quotes_v1", &path, None)
+            .unwrap();
+
+        assert_eq!(session.get_query_result().count(), 0);
+    }
+
+    #[rstest]
+    fn test_migrate_missing_migration_errors() {
+        let result = SchemaMigrations::new().migrate_batch::<QuoteTick>(quotes_v1());
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::MissingMigration("QuoteTick", 1))
+        ));
+    }
+
+    #[rstest]
+    fn test_migrate_future_version_file_errors() {
+        let batch = with_schema_version(quotes_v1(), 99).unwrap();
+        let dir = TempDir::new().unwrap();
+        let batch = write_and_read(&dir, &batch);
+
+        let err = SchemaMigrations::new()
+            .migrate_batch::<QuoteTick>(batch)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unsupported schema version 99 for `QuoteTick`: the latest version known is 2, \
+             the data was likely written by a newer release"
+        );
+    }
+}
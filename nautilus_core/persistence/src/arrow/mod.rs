@@ -16,9 +16,14 @@
 //! Defines the Apache Arrow schema for Nautilus types.
 
 pub mod bar;
+pub mod close;
 pub mod delta;
 pub mod depth;
+pub mod migration;
+pub mod order_event;
+pub mod order_flow;
 pub mod quote;
+pub mod slippage;
 pub mod trade;
 
 use std::{
@@ -36,6 +41,8 @@ use datafusion::arrow::{
 use nautilus_model::data::Data;
 use pyo3::prelude::*;
 
+use self::migration::KEY_SCHEMA_VERSION;
+
 // Define metadata key constants constants
 const KEY_BAR_TYPE: &str = "bar_type";
 const KEY_INSTRUMENT_ID: &str = "instrument_id";
@@ -62,13 +69,33 @@ pub enum EncodingError {
     ParseError(&'static str, String),
     #[error("Invalid column type `{0}` at index {1}: expected {2}, found {3}")]
     InvalidColumnType(&'static str, usize, DataType, DataType),
+    #[error("Unsupported schema version {1} for `{0}`: the latest version known is {2}, the data was likely written by a newer release")]
+    UnsupportedSchemaVersion(&'static str, u32, u32),
+    #[error("Missing schema migration for `{0}` from version {1}")]
+    MissingMigration(&'static str, u32),
     #[error("Arrow error: {0}")]
     ArrowError(#[from] datafusion::arrow::error::ArrowError),
 }
 
 pub trait ArrowSchemaProvider {
+    /// The name of the encoded type, under which its schema migrations are registered.
+    const TYPE_NAME: &'static str;
+    /// The current version of the schema, incremented whenever its columns or metadata change.
+    const SCHEMA_VERSION: u32 = 1;
+
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema;
 
+    /// Returns the schema with the `metadata` and the current [`Self::SCHEMA_VERSION`].
+    #[must_use]
+    fn get_versioned_schema(metadata: &HashMap<String, String>) -> Schema {
+        let mut metadata = metadata.clone();
+        metadata.insert(
+            KEY_SCHEMA_VERSION.to_string(),
+            Self::SCHEMA_VERSION.to_string(),
+        );
+        Self::get_schema(Some(metadata))
+    }
+
     #[must_use]
     fn get_schema_map() -> HashMap<String, String> {
         let schema = Self::get_schema(None);
@@ -0,0 +1,309 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::{
+        Array, BooleanArray, BooleanBuilder, Float64Array, StringArray, StringBuilder, UInt64Array,
+    },
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::events::order::flat::FlatEventRecord;
+
+use crate::arrow::{extract_column, ArrowSchemaProvider, EncodeToRecordBatch, EncodingError};
+
+impl ArrowSchemaProvider for FlatEventRecord {
+    const TYPE_NAME: &'static str = "FlatEventRecord";
+
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("type", DataType::Utf8, false),
+            Field::new("trader_id", DataType::Utf8, false),
+            Field::new("strategy_id", DataType::Utf8, false),
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("client_order_id", DataType::Utf8, false),
+            Field::new("venue_order_id", DataType::Utf8, true),
+            Field::new("account_id", DataType::Utf8, true),
+            Field::new("trade_id", DataType::Utf8, true),
+            Field::new("position_id", DataType::Utf8, true),
+            Field::new("order_side", DataType::Utf8, true),
+            Field::new("order_type", DataType::Utf8, true),
+            Field::new("time_in_force", DataType::Utf8, true),
+            Field::new("quantity", DataType::Float64, true),
+            Field::new("price", DataType::Float64, true),
+            Field::new("trigger_price", DataType::Float64, true),
+            Field::new("released_price", DataType::Float64, true),
+            Field::new("last_qty", DataType::Float64, true),
+            Field::new("last_px", DataType::Float64, true),
+            Field::new("currency", DataType::Utf8, true),
+            Field::new("commission", DataType::Utf8, true),
+            Field::new("liquidity_side", DataType::Utf8, true),
+            Field::new("reason", DataType::Utf8, true),
+            Field::new("event_id", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+            Field::new("reconciliation", DataType::Boolean, true),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for FlatEventRecord {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut type_builder = StringBuilder::new();
+        let mut trader_id_builder = StringBuilder::new();
+        let mut strategy_id_builder = StringBuilder::new();
+        let mut instrument_id_builder = StringBuilder::new();
+        let mut client_order_id_builder = StringBuilder::new();
+        let mut venue_order_id_builder = StringBuilder::new();
+        let mut account_id_builder = StringBuilder::new();
+        let mut trade_id_builder = StringBuilder::new();
+        let mut position_id_builder = StringBuilder::new();
+        let mut order_side_builder = StringBuilder::new();
+        let mut order_type_builder = StringBuilder::new();
+        let mut time_in_force_builder = StringBuilder::new();
+        let mut quantity_builder = Float64Array::builder(data.len());
+        let mut price_builder = Float64Array::builder(data.len());
+        let mut trigger_price_builder = Float64Array::builder(data.len());
+        let mut released_price_builder = Float64Array::builder(data.len());
+        let mut last_qty_builder = Float64Array::builder(data.len());
+        let mut last_px_builder = Float64Array::builder(data.len());
+        let mut currency_builder = StringBuilder::new();
+        let mut commission_builder = StringBuilder::new();
+        let mut liquidity_side_builder = StringBuilder::new();
+        let mut reason_builder = StringBuilder::new();
+        let mut event_id_builder = StringBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+        let mut reconciliation_builder = BooleanBuilder::with_capacity(data.len());
+
+        for record in data {
+            type_builder.append_value(&record.event_type);
+            trader_id_builder.append_value(&record.trader_id);
+            strategy_id_builder.append_value(&record.strategy_id);
+            instrument_id_builder.append_value(&record.instrument_id);
+            client_order_id_builder.append_value(&record.client_order_id);
+            venue_order_id_builder.append_option(record.venue_order_id.as_ref());
+            account_id_builder.append_option(record.account_id.as_ref());
+            trade_id_builder.append_option(record.trade_id.as_ref());
+            position_id_builder.append_option(record.position_id.as_ref());
+            order_side_builder.append_option(record.order_side.as_ref());
+            order_type_builder.append_option(record.order_type.as_ref());
+            time_in_force_builder.append_option(record.time_in_force.as_ref());
+            quantity_builder.append_option(record.quantity);
+            price_builder.append_option(record.price);
+            trigger_price_builder.append_option(record.trigger_price);
+            released_price_builder.append_option(record.released_price);
+            last_qty_builder.append_option(record.last_qty);
+            last_px_builder.append_option(record.last_px);
+            currency_builder.append_option(record.currency.as_ref());
+            commission_builder.append_option(record.commission.as_ref());
+            liquidity_side_builder.append_option(record.liquidity_side.as_ref());
+            reason_builder.append_option(record.reason.as_ref());
+            event_id_builder.append_value(&record.event_id);
+            ts_event_builder.append_value(record.ts_event);
+            ts_init_builder.append_value(record.ts_init);
+            reconciliation_builder.append_option(record.reconciliation);
+        }
+
+        RecordBatch::try_new(
+            Self::get_versioned_schema(metadata).into(),
+            vec![
+                Arc::new(type_builder.finish()),
+                Arc::new(trader_id_builder.finish()),
+                Arc::new(strategy_id_builder.finish()),
+                Arc::new(instrument_id_builder.finish()),
+                Arc::new(client_order_id_builder.finish()),
+                Arc::new(venue_order_id_builder.finish()),
+                Arc::new(account_id_builder.finish()),
+                Arc::new(trade_id_builder.finish()),
+                Arc::new(position_id_builder.finish()),
+                Arc::new(order_side_builder.finish()),
+                Arc::new(order_type_builder.finish()),
+                Arc::new(time_in_force_builder.finish()),
+                Arc::new(quantity_builder.finish()),
+                Arc::new(price_builder.finish()),
+                Arc::new(trigger_price_builder.finish()),
+                Arc::new(released_price_builder.finish()),
+                Arc::new(last_qty_builder.finish()),
+                Arc::new(last_px_builder.finish()),
+                Arc::new(currency_builder.finish()),
+                Arc::new(commission_builder.finish()),
+                Arc::new(liquidity_side_builder.finish()),
+                Arc::new(reason_builder.finish()),
+                Arc::new(event_id_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+                Arc::new(reconciliation_builder.finish()),
+            ],
+        )
+    }
+}
+
+/// Decodes the rows of a `record_batch` (in the [`FlatEventRecord`] schema) into flat records.
+pub fn decode_flat_records(
+    record_batch: &RecordBatch,
+) -> Result<Vec<FlatEventRecord>, EncodingError> {
+    let cols = record_batch.columns();
+
+    let event_type = extract_column::<StringArray>(cols, "type", 0, DataType::Utf8)?;
+    let trader_id = extract_column::<StringArray>(cols, "trader_id", 1, DataType::Utf8)?;
+    let strategy_id = extract_column::<StringArray>(cols, "strategy_id", 2, DataType::Utf8)?;
+    let instrument_id = extract_column::<StringArray>(cols, "instrument_id", 3, DataType::Utf8)?;
+    let client_order_id =
+        extract_column::<StringArray>(cols, "client_order_id", 4, DataType::Utf8)?;
+    let venue_order_id = extract_column::<StringArray>(cols, "venue_order_id", 5, DataType::Utf8)?;
+    let account_id = extract_column::<StringArray>(cols, "account_id", 6, DataType::Utf8)?;
+    let trade_id = extract_column::<StringArray>(cols, "trade_id", 7, DataType::Utf8)?;
+    let position_id = extract_column::<StringArray>(cols, "position_id", 8, DataType::Utf8)?;
+    let order_side = extract_column::<StringArray>(cols, "order_side", 9, DataType::Utf8)?;
+    let order_type = extract_column::<StringArray>(cols, "order_type", 10, DataType::Utf8)?;
+    let time_in_force = extract_column::<StringArray>(cols, "time_in_force", 11, DataType::Utf8)?;
+    let quantity = extract_column::<Float64Array>(cols, "quantity", 12, DataType::Float64)?;
+    let price = extract_column::<Float64Array>(cols, "price", 13, DataType::Float64)?;
+    let trigger_price =
+        extract_column::<Float64Array>(cols, "trigger_price", 14, DataType::Float64)?;
+    let released_price =
+        extract_column::<Float64Array>(cols, "released_price", 15, DataType::Float64)?;
+    let last_qty = extract_column::<Float64Array>(cols, "last_qty", 16, DataType::Float64)?;
+    let last_px = extract_column::<Float64Array>(cols, "last_px", 17, DataType::Float64)?;
+    let currency = extract_column::<StringArray>(cols, "currency", 18, DataType::Utf8)?;
+    let commission = extract_column::<StringArray>(cols, "commission", 19, DataType::Utf8)?;
+    let liquidity_side = extract_column::<StringArray>(cols, "liquidity_side", 20, DataType::Utf8)?;
+    let reason = extract_column::<StringArray>(cols, "reason", 21, DataType::Utf8)?;
+    let event_id = extract_column::<StringArray>(cols, "event_id", 22, DataType::Utf8)?;
+    let ts_event = extract_column::<UInt64Array>(cols, "ts_event", 23, DataType::UInt64)?;
+    let ts_init = extract_column::<UInt64Array>(cols, "ts_init", 24, DataType::UInt64)?;
+    let reconciliation =
+        extract_column::<BooleanArray>(cols, "reconciliation", 25, DataType::Boolean)?;
+
+    let opt_str = |array: &StringArray, i: usize| -> Option<String> {
+        (!array.is_null(i)).then(|| array.value(i).to_string())
+    };
+    let opt_f64 = |array: &Float64Array, i: usize| -> Option<f64> {
+        (!array.is_null(i)).then(|| array.value(i))
+    };
+
+    let records = (0..record_batch.num_rows())
+        .map(|i| FlatEventRecord {
+            event_type: event_type.value(i).to_string(),
+            trader_id: trader_id.value(i).to_string(),
+            strategy_id: strategy_id.value(i).to_string(),
+            instrument_id: instrument_id.value(i).to_string(),
+            client_order_id: client_order_id.value(i).to_string(),
+            venue_order_id: opt_str(venue_order_id, i),
+            account_id: opt_str(account_id, i),
+            trade_id: opt_str(trade_id, i),
+            position_id: opt_str(position_id, i),
+            order_side: opt_str(order_side, i),
+            order_type: opt_str(order_type, i),
+            time_in_force: opt_str(time_in_force, i),
+            quantity: opt_f64(quantity, i),
+            price: opt_f64(price, i),
+            trigger_price: opt_f64(trigger_price, i),
+            released_price: opt_f64(released_price, i),
+            last_qty: opt_f64(last_qty, i),
+            last_px: opt_f64(last_px, i),
+            currency: opt_str(currency, i),
+            commission: opt_str(commission, i),
+            liquidity_side: opt_str(liquidity_side, i),
+            reason: opt_str(reason, i),
+            event_id: event_id.value(i).to_string(),
+            ts_event: ts_event.value(i),
+            ts_init: ts_init.value(i),
+            reconciliation: (!reconciliation.is_null(i)).then(|| reconciliation.value(i)),
+        })
+        .collect();
+
+    Ok(records)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::order::{
+        accepted::OrderAccepted, event::OrderEventAny, filled::OrderFilled,
+        flat::events_to_flat_records, rejected::OrderRejected,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema() {
+        let schema = FlatEventRecord::get_schema(None);
+        assert_eq!(schema.fields().len(), 26);
+        assert_eq!(schema.field(0).name(), "type");
+        assert!(!schema.field(0).is_nullable());
+        assert!(schema.field_with_name("reason").unwrap().is_nullable());
+    }
+
+    #[rstest]
+    fn test_encode_batch() {
+        let events = vec![
+            OrderEventAny::Accepted(OrderAccepted::default()),
+            OrderEventAny::Rejected(OrderRejected::default()),
+            OrderEventAny::Filled(OrderFilled::default()),
+        ];
+        let records = events_to_flat_records(&events);
+
+        let record_batch = FlatEventRecord::encode_batch(&HashMap::new(), &records).unwrap();
+        let columns = record_batch.columns();
+
+        assert_eq!(record_batch.num_rows(), 3);
+        assert_eq!(columns.len(), 26);
+
+        let type_values = columns[0].as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(type_values.value(0), "OrderAccepted");
+        assert_eq!(type_values.value(2), "OrderFilled");
+
+        let reason_values = columns[21].as_any().downcast_ref::<StringArray>().unwrap();
+        assert!(reason_values.is_null(0));
+        assert!(!reason_values.is_null(1));
+        assert!(reason_values.is_null(2));
+
+        let last_px_values = columns[17].as_any().downcast_ref::<Float64Array>().unwrap();
+        assert!(last_px_values.is_null(0));
+        assert!(last_px_values.is_null(1));
+        assert!(!last_px_values.is_null(2));
+    }
+
+    #[rstest]
+    fn test_decode_flat_records_round_trip() {
+        let events = vec![
+            OrderEventAny::Accepted(OrderAccepted::default()),
+            OrderEventAny::Rejected(OrderRejected::default()),
+            OrderEventAny::Filled(OrderFilled::default()),
+        ];
+        let records = events_to_flat_records(&events);
+        let record_batch = FlatEventRecord::encode_batch(&HashMap::new(), &records).unwrap();
+
+        let decoded = decode_flat_records(&record_batch).unwrap();
+
+        assert_eq!(decoded, records);
+    }
+}
@@ -0,0 +1,203 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Float64Array, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::order_flow::OrderFlowImbalance, identifiers::instrument_id::InstrumentId,
+    types::quantity::Quantity,
+};
+
+use super::{extract_column, EncodingError, KEY_INSTRUMENT_ID, KEY_SIZE_PRECISION};
+use crate::arrow::{ArrowSchemaProvider, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for OrderFlowImbalance {
+    const TYPE_NAME: &'static str = "OrderFlowImbalance";
+
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("buy_volume", DataType::UInt64, false),
+            Field::new("sell_volume", DataType::UInt64, false),
+            Field::new("no_aggressor_volume", DataType::UInt64, false),
+            Field::new("delta", DataType::Float64, false),
+            Field::new("cumulative_delta", DataType::Float64, false),
+            Field::new("trade_count", DataType::UInt64, false),
+            Field::new("ts_bucket", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<(InstrumentId, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let size_precision = metadata
+        .get(KEY_SIZE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_SIZE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_SIZE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, size_precision))
+}
+
+impl EncodeToRecordBatch for OrderFlowImbalance {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut buy_volume_builder = UInt64Array::builder(data.len());
+        let mut sell_volume_builder = UInt64Array::builder(data.len());
+        let mut no_aggressor_volume_builder = UInt64Array::builder(data.len());
+        let mut delta_builder = Float64Array::builder(data.len());
+        let mut cumulative_delta_builder = Float64Array::builder(data.len());
+        let mut trade_count_builder = UInt64Array::builder(data.len());
+        let mut ts_bucket_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for record in data {
+            buy_volume_builder.append_value(record.buy_volume.raw);
+            sell_volume_builder.append_value(record.sell_volume.raw);
+            no_aggressor_volume_builder.append_value(record.no_aggressor_volume.raw);
+            delta_builder.append_value(record.delta);
+            cumulative_delta_builder.append_value(record.cumulative_delta);
+            trade_count_builder.append_value(record.trade_count);
+            ts_bucket_builder.append_value(record.ts_bucket.as_u64());
+            ts_init_builder.append_value(record.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_versioned_schema(metadata).into(),
+            vec![
+                Arc::new(buy_volume_builder.finish()),
+                Arc::new(sell_volume_builder.finish()),
+                Arc::new(no_aggressor_volume_builder.finish()),
+                Arc::new(delta_builder.finish()),
+                Arc::new(cumulative_delta_builder.finish()),
+                Arc::new(trade_count_builder.finish()),
+                Arc::new(ts_bucket_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+}
+
+/// Decodes `OrderFlowImbalance` records from the given `record_batch`.
+///
+/// This is a free function as `OrderFlowImbalance` is not a built-in `Data` variant, so
+/// cannot implement `DecodeFromRecordBatch`.
+pub fn decode_order_flow_batch(
+    metadata: &HashMap<String, String>,
+    record_batch: RecordBatch,
+) -> Result<Vec<OrderFlowImbalance>, EncodingError> {
+    let (instrument_id, size_precision) = parse_metadata(metadata)?;
+    let cols = record_batch.columns();
+
+    let buy_volume_values = extract_column::<UInt64Array>(cols, "buy_volume", 0, DataType::UInt64)?;
+    let sell_volume_values =
+        extract_column::<UInt64Array>(cols, "sell_volume", 1, DataType::UInt64)?;
+    let no_aggressor_volume_values =
+        extract_column::<UInt64Array>(cols, "no_aggressor_volume", 2, DataType::UInt64)?;
+    let delta_values = extract_column::<Float64Array>(cols, "delta", 3, DataType::Float64)?;
+    let cumulative_delta_values =
+        extract_column::<Float64Array>(cols, "cumulative_delta", 4, DataType::Float64)?;
+    let trade_count_values =
+        extract_column::<UInt64Array>(cols, "trade_count", 5, DataType::UInt64)?;
+    let ts_bucket_values = extract_column::<UInt64Array>(cols, "ts_bucket", 6, DataType::UInt64)?;
+    let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 7, DataType::UInt64)?;
+
+    (0..record_batch.num_rows())
+        .map(|i| {
+            let quantity = |raw: u64| {
+                Quantity::from_raw(raw, size_precision)
+                    .map_err(|e| EncodingError::ParseError(stringify!(Quantity), e.to_string()))
+            };
+
+            Ok(OrderFlowImbalance {
+                instrument_id,
+                buy_volume: quantity(buy_volume_values.value(i))?,
+                sell_volume: quantity(sell_volume_values.value(i))?,
+                no_aggressor_volume: quantity(no_aggressor_volume_values.value(i))?,
+                delta: delta_values.value(i),
+                cumulative_delta: cumulative_delta_values.value(i),
+                trade_count: trade_count_values.value(i),
+                ts_bucket: ts_bucket_values.value(i).into(),
+                ts_init: ts_init_values.value(i).into(),
+            })
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn record(delta: f64, cumulative_delta: f64, ts_bucket: u64) -> OrderFlowImbalance {
+        OrderFlowImbalance {
+            instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            buy_volume: Quantity::from("2.000"),
+            sell_volume: Quantity::from("0.500"),
+            no_aggressor_volume: Quantity::from("1.000"),
+            delta,
+            cumulative_delta,
+            trade_count: 3,
+            ts_bucket: UnixNanos::from(ts_bucket),
+            ts_init: UnixNanos::from(ts_bucket + 1_000_000_000),
+        }
+    }
+
+    #[rstest]
+    fn test_get_schema() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let metadata = OrderFlowImbalance::get_metadata(&instrument_id, 3);
+        let schema = OrderFlowImbalance::get_schema(Some(metadata.clone()));
+
+        assert_eq!(schema.fields().len(), 8);
+        assert_eq!(schema.field(3).data_type(), &DataType::Float64);
+        assert_eq!(schema.metadata(), &metadata);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let metadata = OrderFlowImbalance::get_metadata(&instrument_id, 3);
+        let data = vec![record(1.5, 1.5, 0), record(1.5, 3.0, 1_000_000_000)];
+
+        let record_batch = OrderFlowImbalance::encode_batch(&metadata, &data).unwrap();
+        let decoded = decode_order_flow_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+}
@@ -28,12 +28,16 @@ use nautilus_model::{
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
-    KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+    extract_column, migration::insert_column, DecodeDataFromRecordBatch, EncodingError,
+    KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
 impl ArrowSchemaProvider for QuoteTick {
+    const TYPE_NAME: &'static str = "QuoteTick";
+    /// Version 2 added the `ts_event` column, see [`migrate_v1`].
+    const SCHEMA_VERSION: u32 = 2;
+
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
         let fields = vec![
             Field::new("bid_price", DataType::Int64, false),
@@ -51,6 +55,28 @@ impl ArrowSchemaProvider for QuoteTick {
     }
 }
 
+/// Migrates a version 1 batch, written before `ts_event` was recorded, by filling `ts_event`
+/// from `ts_init`.
+///
+/// Unversioned batches are also read as version 1, so a batch which already has `ts_event`
+/// is returned unchanged.
+pub fn migrate_v1(batch: RecordBatch) -> Result<RecordBatch, EncodingError> {
+    if batch.schema().column_with_name("ts_event").is_some() {
+        return Ok(batch);
+    }
+    let (index, _) = batch
+        .schema()
+        .column_with_name("ts_init")
+        .ok_or(EncodingError::MissingColumn("ts_init", 4))?;
+    let ts_init = batch.column(index).clone();
+    insert_column(
+        &batch,
+        index,
+        Field::new("ts_event", DataType::UInt64, false),
+        ts_init,
+    )
+}
+
 fn parse_metadata(
     metadata: &HashMap<String, String>,
 ) -> Result<(InstrumentId, u8, u8), EncodingError> {
@@ -104,7 +130,7 @@ impl EncodeToRecordBatch for QuoteTick {
         let ts_init_array = ts_init_builder.finish();
 
         RecordBatch::try_new(
-            Self::get_schema(Some(metadata.clone())).into(),
+            Self::get_versioned_schema(metadata).into(),
             vec![
                 Arc::new(bid_price_array),
                 Arc::new(ask_price_array),
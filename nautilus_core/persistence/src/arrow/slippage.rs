@@ -0,0 +1,158 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Float64Array, StringBuilder, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::events::order::slippage::SlippageRecord;
+
+use crate::arrow::{ArrowSchemaProvider, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for SlippageRecord {
+    const TYPE_NAME: &'static str = "SlippageRecord";
+
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("client_order_id", DataType::Utf8, false),
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("order_side", DataType::Utf8, false),
+            Field::new("reference_px", DataType::Float64, true),
+            Field::new("vwap_fill_px", DataType::Float64, false),
+            Field::new("filled_qty", DataType::Float64, false),
+            Field::new("fill_count", DataType::UInt64, false),
+            Field::new("slippage_ticks", DataType::Float64, true),
+            Field::new("slippage_bps", DataType::Float64, true),
+            Field::new("time_to_first_fill_ns", DataType::UInt64, true),
+            Field::new("flag", DataType::Utf8, true),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for SlippageRecord {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut client_order_id_builder = StringBuilder::new();
+        let mut instrument_id_builder = StringBuilder::new();
+        let mut order_side_builder = StringBuilder::new();
+        let mut reference_px_builder = Float64Array::builder(data.len());
+        let mut vwap_fill_px_builder = Float64Array::builder(data.len());
+        let mut filled_qty_builder = Float64Array::builder(data.len());
+        let mut fill_count_builder = UInt64Array::builder(data.len());
+        let mut slippage_ticks_builder = Float64Array::builder(data.len());
+        let mut slippage_bps_builder = Float64Array::builder(data.len());
+        let mut time_to_first_fill_ns_builder = UInt64Array::builder(data.len());
+        let mut flag_builder = StringBuilder::new();
+
+        for record in data {
+            client_order_id_builder.append_value(record.client_order_id.as_str());
+            instrument_id_builder.append_value(record.instrument_id.to_string());
+            order_side_builder.append_value(record.order_side.as_ref());
+            reference_px_builder.append_option(record.reference_px.map(|px| px.as_f64()));
+            vwap_fill_px_builder.append_value(record.vwap_fill_px);
+            filled_qty_builder.append_value(record.filled_qty);
+            fill_count_builder.append_value(record.fill_count);
+            slippage_ticks_builder.append_option(record.slippage_ticks);
+            slippage_bps_builder.append_option(record.slippage_bps);
+            time_to_first_fill_ns_builder.append_option(record.time_to_first_fill_ns);
+            flag_builder.append_option(record.flag.as_ref().map(AsRef::<str>::as_ref));
+        }
+
+        RecordBatch::try_new(
+            Self::get_versioned_schema(metadata).into(),
+            vec![
+                Arc::new(client_order_id_builder.finish()),
+                Arc::new(instrument_id_builder.finish()),
+                Arc::new(order_side_builder.finish()),
+                Arc::new(reference_px_builder.finish()),
+                Arc::new(vwap_fill_px_builder.finish()),
+                Arc::new(filled_qty_builder.finish()),
+                Arc::new(fill_count_builder.finish()),
+                Arc::new(slippage_ticks_builder.finish()),
+                Arc::new(slippage_bps_builder.finish()),
+                Arc::new(time_to_first_fill_ns_builder.finish()),
+                Arc::new(flag_builder.finish()),
+            ],
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::{Array, StringArray};
+    use nautilus_model::{
+        enums::OrderSide,
+        events::order::slippage::SlippageFlag,
+        identifiers::{client_order_id::ClientOrderId, instrument_id::InstrumentId},
+        types::price::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn record(client_order_id: &str, flag: Option<SlippageFlag>) -> SlippageRecord {
+        let reference_px =
+            (flag != Some(SlippageFlag::MissingDecision)).then(|| Price::from("100.00"));
+        SlippageRecord {
+            client_order_id: ClientOrderId::from(client_order_id),
+            instrument_id: InstrumentId::from("ETHUSDT.BINANCE"),
+            order_side: OrderSide::Buy,
+            reference_px,
+            vwap_fill_px: 100.25,
+            filled_qty: 4.0,
+            fill_count: 2,
+            slippage_ticks: reference_px.map(|_| 25.0),
+            slippage_bps: reference_px.map(|_| 25.0),
+            time_to_first_fill_ns: flag.is_none().then_some(50),
+            flag,
+        }
+    }
+
+    #[rstest]
+    fn test_encode_batch() {
+        let data = vec![
+            record("O-1", None),
+            record("O-2", Some(SlippageFlag::MissingDecision)),
+        ];
+
+        let record_batch = SlippageRecord::encode_batch(&HashMap::new(), &data).unwrap();
+        let columns = record_batch.columns();
+
+        assert_eq!(record_batch.num_rows(), 2);
+        assert_eq!(columns.len(), 11);
+
+        let reference_px_values = columns[3].as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(reference_px_values.value(0), 100.0);
+        assert!(reference_px_values.is_null(1));
+
+        let flag_values = columns[10].as_any().downcast_ref::<StringArray>().unwrap();
+        assert!(flag_values.is_null(0));
+        assert_eq!(flag_values.value(1), "MISSING_DECISION");
+    }
+}
@@ -35,6 +35,8 @@ use super::{
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
 impl ArrowSchemaProvider for TradeTick {
+    const TYPE_NAME: &'static str = "TradeTick";
+
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
         let fields = vec![
             Field::new("price", DataType::Int64, false),
@@ -105,7 +107,7 @@ impl EncodeToRecordBatch for TradeTick {
         let ts_init_array = ts_init_builder.finish();
 
         RecordBatch::try_new(
-            Self::get_schema(Some(metadata.clone())).into(),
+            Self::get_versioned_schema(metadata).into(),
             vec![
                 Arc::new(price_array),
                 Arc::new(size_array),
@@ -0,0 +1,735 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides import and maintenance operations over the Parquet files of a data catalog.
+
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    fs::{self, File},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use datafusion::{
+    arrow::{
+        array::{Array, ArrayRef, StringArray, UInt32Array, UInt64Array},
+        compute::{concat_batches, take},
+        datatypes::{DataType, Field, Schema},
+        record_batch::RecordBatch,
+        row::{RowConverter, SortField},
+    },
+    parquet::arrow::{arrow_reader::ParquetRecordBatchReaderBuilder, ArrowWriter},
+};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::Data, identifiers::instrument_id::InstrumentId, instruments::any::InstrumentAny,
+};
+
+use super::{
+    session::{DataBackendSession, QueryResult},
+    tick_reader::{ChunkedTickReader, TickRecord},
+};
+use crate::arrow::{DecodeDataFromRecordBatch, EncodeToRecordBatch};
+
+const CONSOLIDATED_FILE_NAME: &str = "part-0.parquet";
+const INSTRUMENT_VERSION_DATA_TYPE: &str = "instrument_version";
+const QUERY_CHUNK_SIZE: usize = 10_000;
+
+/// The outcome of consolidating the files for an instrument and data type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConsolidationReport {
+    pub files_read: usize,
+    pub rows_read: usize,
+    /// Rows with the same `ts_init` and payload as a row already kept.
+    pub exact_duplicates_removed: usize,
+    /// Rows with the same payload (other than `ts_init`) as a row with an earlier `ts_init`.
+    pub near_duplicates_removed: usize,
+    pub rows_written: usize,
+}
+
+impl ConsolidationReport {
+    #[must_use]
+    pub fn rows_removed(&self) -> usize {
+        self.exact_duplicates_removed + self.near_duplicates_removed
+    }
+}
+
+/// Provides access to a data catalog of Parquet files, laid out as
+/// `{base_path}/data/{data_type}/{instrument_id}/*.parquet`.
+#[derive(Clone, Debug)]
+pub struct ParquetDataCatalog {
+    pub base_path: PathBuf,
+}
+
+impl ParquetDataCatalog {
+    #[must_use]
+    pub fn new(base_path: PathBuf) -> Self {
+        Self { base_path }
+    }
+
+    /// Returns the directory of files for the given `instrument_id` and `data_type`
+    /// (such as "quote_tick").
+    #[must_use]
+    pub fn data_dir(&self, instrument_id: &InstrumentId, data_type: &str) -> PathBuf {
+        // Matches the Python catalog, which removes forward slashes for valid paths
+        let instrument_dir = instrument_id.to_string().replace('/', "");
+        self.base_path
+            .join("data")
+            .join(data_type)
+            .join(instrument_dir)
+    }
+
+    /// Queries the data in all files for the given `instrument_id` and `data_type`, with a
+    /// `ts_init` at or after `start`, in ascending `ts_init` order.
+    ///
+    /// The result is streamed in chunks, so it can back a replay of more data than fits in
+    /// memory (for example as the source of a `ReplayDriver` reopened from each seek time).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a file cannot be registered with the query session.
+    pub fn query<T>(
+        &self,
+        instrument_id: &InstrumentId,
+        data_type: &str,
+        start: UnixNanos,
+    ) -> anyhow::Result<QueryResult>
+    where
+        T: DecodeDataFromRecordBatch + Into<Data>,
+    {
+        let mut session = DataBackendSession::new(QUERY_CHUNK_SIZE);
+        let files = list_parquet_files(&self.data_dir(instrument_id, data_type))?;
+        for (i, file) in files.iter().enumerate() {
+            let table_name = format!("{data_type}_{i}");
+            let sql_query =
+                format!("SELECT * FROM {table_name} WHERE ts_init >= {start} ORDER BY ts_init");
+            session.add_file::<T>(&table_name, &file.to_string_lossy(), Some(&sql_query))?;
+        }
+        Ok(session.get_query_result())
+    }
+
+    /// Merges all files for the given `instrument_id` and `data_type` into a single file
+    /// sorted by `ts_init`, dropping exact duplicate rows.
+    ///
+    /// If `drop_near_duplicates` then rows which differ only by `ts_init` (such as the same
+    /// tick received twice) are also dropped, keeping the row with the earliest `ts_init`.
+    ///
+    /// The output is written to a temporary directory which is then swapped for the original.
+    /// When there is a single file and no rows are removed the files are left untouched.
+    pub fn consolidate(
+        &self,
+        instrument_id: &InstrumentId,
+        data_type: &str,
+        drop_near_duplicates: bool,
+    ) -> anyhow::Result<ConsolidationReport> {
+        let dir = self.data_dir(instrument_id, data_type);
+        let files = list_parquet_files(&dir)?;
+        let mut report = ConsolidationReport {
+            files_read: files.len(),
+            ..Default::default()
+        };
+
+        let Some(batch) = read_files(&files)? else {
+            return Ok(report);
+        };
+        report.rows_read = batch.num_rows();
+
+        let (indices, exact_removed, near_removed) =
+            deduplicated_indices(&batch, drop_near_duplicates)?;
+        report.exact_duplicates_removed = exact_removed;
+        report.near_duplicates_removed = near_removed;
+        report.rows_written = indices.len();
+
+        if files.len() == 1 && report.rows_removed() == 0 && is_sorted(&batch)? {
+            return Ok(report);
+        }
+
+        let indices = UInt32Array::from(indices);
+        let columns = batch
+            .columns()
+            .iter()
+            .map(|column| take(column.as_ref(), &indices, None))
+            .collect::<Result<Vec<ArrayRef>, _>>()?;
+        let consolidated = RecordBatch::try_new(batch.schema(), columns)?;
+
+        swap_dir(&dir, &consolidated)?;
+
+        Ok(report)
+    }
+
+    /// Imports the ticks from the `reader` into a new file per instrument, returning the number
+    /// of ticks written.
+    ///
+    /// Batches are written as they are read, so files larger than memory can be imported. If
+    /// the read fails the ticks already imported are kept, and the error is returned.
+    pub fn import_ticks<T>(&self, reader: &mut ChunkedTickReader<T>) -> anyhow::Result<usize>
+    where
+        T: TickRecord + EncodeToRecordBatch,
+    {
+        let mut writers: HashMap<InstrumentId, (HashMap<String, String>, ArrowWriter<File>)> =
+            HashMap::new();
+        let mut count = 0;
+
+        let result = reader.try_for_each(|batch| {
+            let mut by_instrument: HashMap<InstrumentId, Vec<T>> = HashMap::new();
+            for tick in batch? {
+                by_instrument
+                    .entry(tick.instrument_id())
+                    .or_default()
+                    .push(tick);
+            }
+
+            for (instrument_id, ticks) in by_instrument {
+                let (metadata, writer) = match writers.entry(instrument_id) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        let metadata = ticks[0].metadata();
+                        let dir = self.data_dir(&instrument_id, T::DATA_TYPE);
+                        fs::create_dir_all(&dir)?;
+                        let file = File::create(next_part_path(&dir))?;
+                        let schema = Arc::new(T::get_versioned_schema(&metadata));
+                        let writer = ArrowWriter::try_new(file, schema, None)?;
+                        entry.insert((metadata, writer))
+                    }
+                };
+                writer.write(&T::encode_batch(metadata, &ticks)?)?;
+                count += ticks.len();
+            }
+            anyhow::Ok(())
+        });
+
+        for (_, writer) in writers.into_values() {
+            writer.close()?;
+        }
+        result.map(|()| count)
+    }
+
+    /// Writes the given instrument `versions` to the catalog with their effective `ts_init`.
+    ///
+    /// Versions are merged with those already stored for each instrument, a version replacing
+    /// any stored version with the same `ts_init`.
+    pub fn write_instrument_versions(&self, versions: &[InstrumentAny]) -> anyhow::Result<()> {
+        let mut by_instrument: HashMap<InstrumentId, Vec<InstrumentAny>> = HashMap::new();
+        for instrument in versions {
+            by_instrument
+                .entry(instrument.id())
+                .or_default()
+                .push(instrument.clone());
+        }
+
+        for (instrument_id, new_versions) in by_instrument {
+            let mut merged = self.instrument_versions(&instrument_id)?;
+            for instrument in new_versions {
+                match merged.binary_search_by_key(&instrument.ts_init(), InstrumentAny::ts_init) {
+                    Ok(index) => merged[index] = instrument,
+                    Err(index) => merged.insert(index, instrument),
+                }
+            }
+
+            let dir = self.data_dir(&instrument_id, INSTRUMENT_VERSION_DATA_TYPE);
+            fs::create_dir_all(&dir)?;
+            let tmp_path = dir.join(format!("{CONSOLIDATED_FILE_NAME}.tmp"));
+            let batch = encode_instrument_versions(&merged)?;
+            let mut writer = ArrowWriter::try_new(File::create(&tmp_path)?, batch.schema(), None)?;
+            writer.write(&batch)?;
+            writer.close()?;
+            fs::rename(tmp_path, dir.join(CONSOLIDATED_FILE_NAME))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the stored versions of the instrument in ascending `ts_init` order.
+    pub fn instrument_versions(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<Vec<InstrumentAny>> {
+        let dir = self.data_dir(instrument_id, INSTRUMENT_VERSION_DATA_TYPE);
+        match read_files(&list_parquet_files(&dir)?)? {
+            Some(batch) => decode_instrument_versions(&batch),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the stored version of the instrument effective at `ts`, being the latest
+    /// version with a `ts_init` at or before `ts`.
+    pub fn instrument_as_of(
+        &self,
+        instrument_id: &InstrumentId,
+        ts: UnixNanos,
+    ) -> anyhow::Result<Option<InstrumentAny>> {
+        Ok(self
+            .instrument_versions(instrument_id)?
+            .into_iter()
+            .rev()
+            .find(|instrument| instrument.ts_init() <= ts))
+    }
+}
+
+/// Encodes instrument versions as rows of their effective timestamps and JSON specification.
+fn encode_instrument_versions(versions: &[InstrumentAny]) -> anyhow::Result<RecordBatch> {
+    let schema = Schema::new(vec![
+        Field::new("ts_event", DataType::UInt64, false),
+        Field::new("ts_init", DataType::UInt64, false),
+        Field::new("instrument", DataType::Utf8, false),
+    ]);
+    let ts_event: UInt64Array = versions.iter().map(|i| i.ts_event().as_u64()).collect();
+    let ts_init: UInt64Array = versions.iter().map(|i| i.ts_init().as_u64()).collect();
+    let instrument = versions
+        .iter()
+        .map(serde_json::to_string)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(ts_event),
+            Arc::new(ts_init),
+            Arc::new(StringArray::from(instrument)),
+        ],
+    )?)
+}
+
+fn decode_instrument_versions(batch: &RecordBatch) -> anyhow::Result<Vec<InstrumentAny>> {
+    let instrument = batch
+        .column_by_name("instrument")
+        .and_then(|column| column.as_any().downcast_ref::<StringArray>())
+        .ok_or_else(|| anyhow::anyhow!("Missing `instrument` Utf8 column"))?;
+    instrument
+        .iter()
+        .map(|json| {
+            let json = json.ok_or_else(|| anyhow::anyhow!("Null `instrument` value"))?;
+            Ok(serde_json::from_str(json)?)
+        })
+        .collect()
+}
+
+/// Returns the first `part-{n}.parquet` path in `dir` which does not already exist.
+pub(crate) fn next_part_path(dir: &Path) -> PathBuf {
+    (0..)
+        .map(|n| dir.join(format!("part-{n}.parquet")))
+        .find(|path| !path.exists())
+        .expect("Unbounded range")
+}
+
+fn list_parquet_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    files.retain(|path| path.extension().is_some_and(|ext| ext == "parquet"));
+    files.sort();
+    Ok(files)
+}
+
+/// Reads and concatenates all record batches from the given `files` (in order).
+fn read_files(files: &[PathBuf]) -> anyhow::Result<Option<RecordBatch>> {
+    let mut schema = None;
+    let mut batches = Vec::new();
+
+    for path in files {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?;
+        schema.get_or_insert_with(|| builder.schema().clone());
+        for batch in builder.build()? {
+            batches.push(batch?);
+        }
+    }
+
+    match schema {
+        Some(schema) => Ok(Some(concat_batches(&schema, &batches)?)),
+        None => Ok(None),
+    }
+}
+
+fn ts_init_column(batch: &RecordBatch) -> anyhow::Result<&UInt64Array> {
+    batch
+        .column_by_name("ts_init")
+        .and_then(|column| column.as_any().downcast_ref::<UInt64Array>())
+        .ok_or_else(|| anyhow::anyhow!("Missing `ts_init` UInt64 column"))
+}
+
+fn is_sorted(batch: &RecordBatch) -> anyhow::Result<bool> {
+    let ts_init = ts_init_column(batch)?;
+    Ok(ts_init.values().windows(2).all(|w| w[0] <= w[1]))
+}
+
+/// Returns the row indices to keep in `ts_init` order, with the counts of exact and near
+/// duplicates removed.
+fn deduplicated_indices(
+    batch: &RecordBatch,
+    drop_near_duplicates: bool,
+) -> anyhow::Result<(Vec<u32>, usize, usize)> {
+    let ts_init = ts_init_column(batch)?;
+    let ts_init_index = batch.schema().index_of("ts_init")?;
+
+    // The row format encodes each row as comparable bytes, so serves as the payload hash key
+    let payload_columns: Vec<ArrayRef> = batch
+        .columns()
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != ts_init_index)
+        .map(|(_, column)| column.clone())
+        .collect();
+    let converter = RowConverter::new(
+        payload_columns
+            .iter()
+            .map(|column| SortField::new(column.data_type().clone()))
+            .collect(),
+    )?;
+    let payloads = converter.convert_columns(&payload_columns)?;
+
+    // Stable sort, so rows with equal `ts_init` keep their file order
+    let mut order: Vec<usize> = (0..batch.num_rows()).collect();
+    order.sort_by_key(|&i| ts_init.value(i));
+
+    let mut seen_exact = HashSet::new();
+    let mut seen_payload = HashSet::new();
+    let mut exact_removed = 0;
+    let mut near_removed = 0;
+    let mut indices = Vec::with_capacity(order.len());
+
+    for i in order {
+        let payload = payloads.row(i);
+        if !seen_exact.insert((ts_init.value(i), payload)) {
+            exact_removed += 1;
+            continue;
+        }
+        if !seen_payload.insert(payload) && drop_near_duplicates {
+            near_removed += 1;
+            continue;
+        }
+        indices.push(u32::try_from(i)?);
+    }
+
+    Ok((indices, exact_removed, near_removed))
+}
+
+/// Returns the `path` with the `suffix` appended (instrument directories contain a '.').
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// Writes the `batch` to a temporary sibling of `dir`, then swaps it in place of `dir`.
+fn swap_dir(dir: &Path, batch: &RecordBatch) -> anyhow::Result<()> {
+    let tmp_dir = with_suffix(dir, ".consolidate-tmp");
+    let old_dir = with_suffix(dir, ".consolidate-old");
+    for path in [&tmp_dir, &old_dir] {
+        if path.exists() {
+            fs::remove_dir_all(path)?;
+        }
+    }
+
+    fs::create_dir_all(&tmp_dir)?;
+    let file = File::create(tmp_dir.join(CONSOLIDATED_FILE_NAME))?;
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None)?;
+    writer.write(batch)?;
+    writer.close()?;
+
+    fs::rename(dir, &old_dir)?;
+    if let Err(e) = fs::rename(&tmp_dir, dir) {
+        // Restore the original files before reporting the error
+        fs::rename(&old_dir, dir)?;
+        return Err(e.into());
+    }
+    fs::remove_dir_all(&old_dir)?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        polymorphism::GetTsInit,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::{
+        arrow::{DecodeFromRecordBatch, EncodeToRecordBatch},
+        backend::tick_reader::{TickFileFormat, TickReaderConfig},
+    };
+
+    const DATA_TYPE: &str = "quote_tick";
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AUD/USD.SIM")
+    }
+
+    fn quote(bid: &str, ts_event: u64, ts_init: u64) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id(),
+            Price::from(bid),
+            Price::from("1.00010"),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            ts_event.into(),
+            ts_init.into(),
+        )
+        .unwrap()
+    }
+
+    fn write_file(catalog: &ParquetDataCatalog, name: &str, quotes: &[QuoteTick]) {
+        let dir = catalog.data_dir(&instrument_id(), DATA_TYPE);
+        fs::create_dir_all(&dir).unwrap();
+        let metadata = QuoteTick::get_metadata(&instrument_id(), 5, 0);
+        let batch = QuoteTick::encode_batch(&metadata, quotes).unwrap();
+        let file = File::create(dir.join(name)).unwrap();
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    fn read_quotes(catalog: &ParquetDataCatalog) -> Vec<QuoteTick> {
+        let dir = catalog.data_dir(&instrument_id(), DATA_TYPE);
+        let files = list_parquet_files(&dir).unwrap();
+        let batch = read_files(&files).unwrap().unwrap();
+        QuoteTick::decode_batch(batch.schema().metadata(), batch).unwrap()
+    }
+
+    /// Two downloads overlapping on the quotes at ts 2 and 3, with the quote at ts_event 3
+    /// received again later (a near duplicate).
+    fn overlapping_catalog() -> (TempDir, ParquetDataCatalog) {
+        let temp_dir = TempDir::new().unwrap();
+        let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf());
+        write_file(
+            &catalog,
+            "2024-01-01.parquet",
+            &[
+                quote("1.00001", 1, 1),
+                quote("1.00002", 2, 2),
+                quote("1.00003", 3, 3),
+            ],
+        );
+        write_file(
+            &catalog,
+            "2024-01-02.parquet",
+            &[
+                quote("1.00002", 2, 2),
+                quote("1.00003", 3, 3),
+                quote("1.00003", 3, 5),
+                quote("1.00004", 4, 4),
+            ],
+        );
+        (temp_dir, catalog)
+    }
+
+    #[rstest]
+    fn test_query_from_start_merges_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf());
+        write_file(
+            &catalog,
+            "2024-01-01.parquet",
+            &[quote("1.00001", 1, 1), quote("1.00003", 3, 3)],
+        );
+        write_file(
+            &catalog,
+            "2024-01-02.parquet",
+            &[
+                quote("1.00002", 2, 2),
+                quote("1.00004", 4, 4),
+                quote("1.00005", 5, 5),
+            ],
+        );
+
+        let data: Vec<Data> = catalog
+            .query::<QuoteTick>(&instrument_id(), DATA_TYPE, UnixNanos::from(3))
+            .unwrap()
+            .collect();
+
+        let ts_inits: Vec<u64> = data.iter().map(|data| data.ts_init().as_u64()).collect();
+        assert_eq!(ts_inits, vec![3, 4, 5]);
+    }
+
+    #[rstest]
+    fn test_query_missing_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf());
+
+        let mut result = catalog
+            .query::<QuoteTick>(&instrument_id(), DATA_TYPE, UnixNanos::default())
+            .unwrap();
+
+        assert!(result.next().is_none());
+    }
+
+    #[rstest]
+    fn test_consolidate_missing_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf());
+
+        let report = catalog
+            .consolidate(&instrument_id(), DATA_TYPE, true)
+            .unwrap();
+
+        assert_eq!(report, ConsolidationReport::default());
+    }
+
+    #[rstest]
+    fn test_consolidate_exact_duplicates() {
+        let (_temp_dir, catalog) = overlapping_catalog();
+
+        let report = catalog
+            .consolidate(&instrument_id(), DATA_TYPE, false)
+            .unwrap();
+        let ts_inits: Vec<u64> = read_quotes(&catalog)
+            .iter()
+            .map(|q| q.ts_init.as_u64())
+            .collect();
+
+        assert_eq!(report.files_read, 2);
+        assert_eq!(report.rows_read, 7);
+        assert_eq!(report.exact_duplicates_removed, 2);
+        assert_eq!(report.near_duplicates_removed, 0);
+        assert_eq!(report.rows_written, 5);
+        assert_eq!(ts_inits, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[rstest]
+    fn test_consolidate_near_duplicates_keeps_earliest() {
+        let (_temp_dir, catalog) = overlapping_catalog();
+
+        let report = catalog
+            .consolidate(&instrument_id(), DATA_TYPE, true)
+            .unwrap();
+        let ts_inits: Vec<u64> = read_quotes(&catalog)
+            .iter()
+            .map(|q| q.ts_init.as_u64())
+            .collect();
+
+        assert_eq!(report.exact_duplicates_removed, 2);
+        assert_eq!(report.near_duplicates_removed, 1);
+        assert_eq!(report.rows_written, 4);
+        assert_eq!(ts_inits, vec![1, 2, 3, 4]);
+    }
+
+    #[rstest]
+    fn test_consolidate_is_idempotent() {
+        let (_temp_dir, catalog) = overlapping_catalog();
+        let dir = catalog.data_dir(&instrument_id(), DATA_TYPE);
+
+        catalog
+            .consolidate(&instrument_id(), DATA_TYPE, true)
+            .unwrap();
+        let quotes = read_quotes(&catalog);
+        let report = catalog
+            .consolidate(&instrument_id(), DATA_TYPE, true)
+            .unwrap();
+
+        assert_eq!(report.files_read, 1);
+        assert_eq!(report.rows_removed(), 0);
+        assert_eq!(read_quotes(&catalog), quotes);
+        assert_eq!(
+            list_parquet_files(&dir).unwrap(),
+            vec![dir.join(CONSOLIDATED_FILE_NAME)]
+        );
+        assert!(!with_suffix(&dir, ".consolidate-tmp").exists());
+        assert!(!with_suffix(&dir, ".consolidate-old").exists());
+    }
+
+    #[rstest]
+    fn test_import_ticks_writes_new_file_per_instrument() {
+        let (_temp_dir, catalog) = overlapping_catalog();
+        let csv = "\
+instrument_id,ts_event,bid_price,ask_price,bid_size,ask_size
+AUD/USD.SIM,10,1.00005,1.00010,100000,100000
+EUR/USD.SIM,10,1.10000,1.10010,200000,200000
+AUD/USD.SIM,11,1.00006,1.00010,100000,100000
+";
+        let config = TickReaderConfig {
+            chunk_size: 16,
+            batch_size: 2,
+            ..TickReaderConfig::new(TickFileFormat::Csv)
+        };
+        let mut reader = ChunkedTickReader::<QuoteTick>::new(csv.as_bytes(), config).unwrap();
+
+        let count = catalog.import_ticks(&mut reader).unwrap();
+
+        let dir = catalog.data_dir(&instrument_id(), DATA_TYPE);
+        let eurusd_dir = catalog.data_dir(&InstrumentId::from("EUR/USD.SIM"), DATA_TYPE);
+        assert_eq!(count, 3);
+        assert_eq!(list_parquet_files(&dir).unwrap().len(), 3);
+        assert!(dir.join("part-0.parquet").exists());
+        assert_eq!(
+            list_parquet_files(&eurusd_dir).unwrap(),
+            vec![eurusd_dir.join("part-0.parquet")]
+        );
+        let ts_events: Vec<u64> = read_quotes(&catalog)
+            .iter()
+            .map(|quote| quote.ts_event.as_u64())
+            .collect();
+        assert_eq!(ts_events, vec![1, 2, 3, 2, 3, 3, 4, 10, 11]);
+    }
+
+    #[rstest]
+    fn test_instrument_versions_round_trip_by_effective_time(audusd_sim: CurrencyPair) {
+        let temp_dir = TempDir::new().unwrap();
+        let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf());
+        let updated = CurrencyPair {
+            price_precision: 4,
+            price_increment: Price::from("0.0001"),
+            ts_event: UnixNanos::from(1_000),
+            ts_init: UnixNanos::from(1_000),
+            ..audusd_sim
+        };
+
+        // Write the later version first, then merge the earlier version in
+        catalog
+            .write_instrument_versions(&[InstrumentAny::CurrencyPair(updated)])
+            .unwrap();
+        catalog
+            .write_instrument_versions(&[InstrumentAny::CurrencyPair(audusd_sim)])
+            .unwrap();
+
+        let versions = catalog.instrument_versions(&audusd_sim.id).unwrap();
+        let before = catalog
+            .instrument_as_of(&audusd_sim.id, UnixNanos::from(999))
+            .unwrap()
+            .unwrap();
+        let after = catalog
+            .instrument_as_of(&audusd_sim.id, UnixNanos::from(1_000))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(versions.len(), 2);
+        assert_eq!(before.price_increment(), audusd_sim.price_increment);
+        assert_eq!(after.price_increment(), Price::from("0.0001"));
+        assert!(
+            InstrumentAny::diff(&versions[1], &InstrumentAny::CurrencyPair(updated)).is_empty()
+        );
+    }
+
+    #[rstest]
+    fn test_instrument_versions_when_none_stored() {
+        let temp_dir = TempDir::new().unwrap();
+        let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf());
+
+        assert!(catalog
+            .instrument_versions(&instrument_id())
+            .unwrap()
+            .is_empty());
+    }
+}
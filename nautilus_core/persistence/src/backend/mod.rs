@@ -15,5 +15,8 @@
 
 //! Provides an Apache Parquet backend powered by [DataFusion](https://arrow.apache.org/datafusion).
 
+pub mod catalog;
 pub mod kmerge_batch;
+pub mod order_store;
 pub mod session;
+pub mod tick_reader;
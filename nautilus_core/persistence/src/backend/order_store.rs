@@ -0,0 +1,404 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a Parquet store of order events, laid out for loading the events of a single order.
+//!
+//! Events are written sorted by `client_order_id` then `ts_event`, with each order's events in
+//! their own row group. An index file maps the `client_order_id` range of every row group, so an
+//! order can be loaded by reading only the row groups which may contain it. Where an order's
+//! events span a file boundary, its row groups are merged (and de-duplicated) on read.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use datafusion::{
+    arrow::{
+        array::{StringArray, StringBuilder, UInt32Array, UInt32Builder},
+        datatypes::{DataType, Field, Schema},
+        record_batch::RecordBatch,
+    },
+    parquet::{
+        arrow::{arrow_reader::ParquetRecordBatchReaderBuilder, ArrowWriter},
+        file::properties::WriterProperties,
+    },
+};
+use nautilus_core::correctness::check_positive_u64;
+use nautilus_model::{
+    events::order::{
+        event::OrderEventAny,
+        flat::{events_to_flat_records, FlatEventRecord},
+    },
+    identifiers::client_order_id::ClientOrderId,
+};
+
+use super::catalog::next_part_path;
+use crate::arrow::{
+    extract_column, order_event::decode_flat_records, ArrowSchemaProvider, EncodeToRecordBatch,
+};
+
+const INDEX_FILE_NAME: &str = "_index.parquet";
+const INDEX_TMP_FILE_NAME: &str = "_index.parquet.tmp";
+
+/// An entry of the order store index, mapping a range of client order IDs to a row group.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowGroupIndexEntry {
+    pub file_name: String,
+    pub row_group: usize,
+    pub min_client_order_id: String,
+    pub max_client_order_id: String,
+}
+
+impl RowGroupIndexEntry {
+    fn may_contain(&self, client_order_id: &str) -> bool {
+        self.min_client_order_id.as_str() <= client_order_id
+            && client_order_id <= self.max_client_order_id.as_str()
+    }
+}
+
+/// Provides a store of order events as Parquet files in `dir`, with one row group per order.
+#[derive(Clone, Debug)]
+pub struct ParquetOrderStore {
+    pub dir: PathBuf,
+    /// The maximum number of rows per file, an order's events will span files beyond this.
+    pub max_rows_per_file: usize,
+}
+
+impl ParquetOrderStore {
+    /// Creates a new [`ParquetOrderStore`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `max_rows_per_file` is not positive.
+    pub fn new(dir: PathBuf, max_rows_per_file: usize) -> anyhow::Result<Self> {
+        check_positive_u64(max_rows_per_file as u64, "max_rows_per_file")?;
+        Ok(Self {
+            dir,
+            max_rows_per_file,
+        })
+    }
+
+    /// Writes the given `events` to new files sorted by client order ID then `ts_event`, and
+    /// updates the index. Returns the number of rows written.
+    pub fn write_order_sorted(&self, events: &[OrderEventAny]) -> anyhow::Result<usize> {
+        if events.is_empty() {
+            return Ok(0);
+        }
+
+        let mut records = events_to_flat_records(events);
+        records.sort_by(|a, b| {
+            a.client_order_id
+                .cmp(&b.client_order_id)
+                .then(a.ts_event.cmp(&b.ts_event))
+        });
+
+        fs::create_dir_all(&self.dir)?;
+        let mut index = self.read_index()?;
+
+        let schema = Arc::new(FlatEventRecord::get_schema(None));
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(self.max_rows_per_file)
+            .build();
+        let metadata = HashMap::new();
+
+        let mut current: Option<PartWriter> = None;
+        for order_records in records.chunk_by(|a, b| a.client_order_id == b.client_order_id) {
+            let mut remaining = order_records;
+            while !remaining.is_empty() {
+                if current
+                    .as_ref()
+                    .is_some_and(|part| part.rows == self.max_rows_per_file)
+                {
+                    current.take().expect("Checked above").writer.close()?;
+                }
+                if current.is_none() {
+                    current = Some(PartWriter::create(
+                        &self.dir,
+                        schema.clone(),
+                        props.clone(),
+                    )?);
+                }
+                let part = current.as_mut().expect("Part writer created above");
+
+                let capacity = self.max_rows_per_file - part.rows;
+                let (chunk, rest) = remaining.split_at(remaining.len().min(capacity));
+
+                // Flushing after each write closes the row group, so it holds a single order
+                part.writer
+                    .write(&FlatEventRecord::encode_batch(&metadata, chunk)?)?;
+                part.writer.flush()?;
+
+                index.push(RowGroupIndexEntry {
+                    file_name: part.file_name.clone(),
+                    row_group: part.row_groups,
+                    min_client_order_id: chunk[0].client_order_id.clone(),
+                    max_client_order_id: chunk[chunk.len() - 1].client_order_id.clone(),
+                });
+                part.row_groups += 1;
+                part.rows += chunk.len();
+                remaining = rest;
+            }
+        }
+
+        if let Some(part) = current {
+            part.writer.close()?;
+        }
+        self.write_index(&index)?;
+
+        Ok(records.len())
+    }
+
+    /// Reads the events for the given `client_order_id` in `ts_event` order, reading only the
+    /// row groups whose index range contains it.
+    ///
+    /// Events duplicated across row groups (by event ID) are merged, keeping the first.
+    pub fn read_order(
+        &self,
+        client_order_id: &ClientOrderId,
+    ) -> anyhow::Result<Vec<FlatEventRecord>> {
+        let client_order_id = client_order_id.as_str();
+        let mut records = Vec::new();
+
+        for entry in self
+            .read_index()?
+            .iter()
+            .filter(|entry| entry.may_contain(client_order_id))
+        {
+            let file = File::open(self.dir.join(&entry.file_name))?;
+            let reader = ParquetRecordBatchReaderBuilder::try_new(file)?
+                .with_row_groups(vec![entry.row_group])
+                .build()?;
+            for batch in reader {
+                records.extend(
+                    decode_flat_records(&batch?)?
+                        .into_iter()
+                        .filter(|record| record.client_order_id == client_order_id),
+                );
+            }
+        }
+
+        let mut seen = HashSet::new();
+        records.retain(|record| seen.insert(record.event_id.clone()));
+        records.sort_by_key(|record| record.ts_event);
+
+        Ok(records)
+    }
+
+    /// Returns the index entries for all row groups in the store.
+    pub fn read_index(&self) -> anyhow::Result<Vec<RowGroupIndexEntry>> {
+        let path = self.dir.join(INDEX_FILE_NAME);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = Vec::new();
+        for batch in ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?.build()? {
+            let batch = batch?;
+            let cols = batch.columns();
+            let file_name = extract_column::<StringArray>(cols, "file_name", 0, DataType::Utf8)?;
+            let row_group = extract_column::<UInt32Array>(cols, "row_group", 1, DataType::UInt32)?;
+            let min_client_order_id =
+                extract_column::<StringArray>(cols, "min_client_order_id", 2, DataType::Utf8)?;
+            let max_client_order_id =
+                extract_column::<StringArray>(cols, "max_client_order_id", 3, DataType::Utf8)?;
+
+            for i in 0..batch.num_rows() {
+                entries.push(RowGroupIndexEntry {
+                    file_name: file_name.value(i).to_string(),
+                    row_group: row_group.value(i) as usize,
+                    min_client_order_id: min_client_order_id.value(i).to_string(),
+                    max_client_order_id: max_client_order_id.value(i).to_string(),
+                });
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Replaces the index file via a rename, so readers never observe a partial index.
+    fn write_index(&self, entries: &[RowGroupIndexEntry]) -> anyhow::Result<()> {
+        let mut file_name = StringBuilder::new();
+        let mut row_group = UInt32Builder::with_capacity(entries.len());
+        let mut min_client_order_id = StringBuilder::new();
+        let mut max_client_order_id = StringBuilder::new();
+
+        for entry in entries {
+            file_name.append_value(&entry.file_name);
+            row_group.append_value(u32::try_from(entry.row_group)?);
+            min_client_order_id.append_value(&entry.min_client_order_id);
+            max_client_order_id.append_value(&entry.max_client_order_id);
+        }
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("file_name", DataType::Utf8, false),
+            Field::new("row_group", DataType::UInt32, false),
+            Field::new("min_client_order_id", DataType::Utf8, false),
+            Field::new("max_client_order_id", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(file_name.finish()),
+                Arc::new(row_group.finish()),
+                Arc::new(min_client_order_id.finish()),
+                Arc::new(max_client_order_id.finish()),
+            ],
+        )?;
+
+        let tmp_path = self.dir.join(INDEX_TMP_FILE_NAME);
+        let mut writer = ArrowWriter::try_new(File::create(&tmp_path)?, schema, None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+        fs::rename(tmp_path, self.dir.join(INDEX_FILE_NAME))?;
+        Ok(())
+    }
+}
+
+/// The file currently being written, with its counts of rows and row groups.
+struct PartWriter {
+    file_name: String,
+    writer: ArrowWriter<File>,
+    rows: usize,
+    row_groups: usize,
+}
+
+impl PartWriter {
+    fn create(dir: &Path, schema: Arc<Schema>, props: WriterProperties) -> anyhow::Result<Self> {
+        let path = next_part_path(dir);
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| anyhow::anyhow!("Invalid part path {}", path.display()))?
+            .to_string();
+        let writer = ArrowWriter::try_new(File::create(&path)?, schema, Some(props))?;
+        Ok(Self {
+            file_name,
+            writer,
+            rows: 0,
+            row_groups: 0,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::events::order::accepted::OrderAccepted;
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn accepted(client_order_id: &str, ts_event: u64) -> OrderEventAny {
+        OrderEventAny::Accepted(OrderAccepted {
+            client_order_id: ClientOrderId::new(client_order_id).unwrap(),
+            event_id: UUID4::new(),
+            ts_event: UnixNanos::from(ts_event),
+            ts_init: UnixNanos::from(ts_event),
+            ..Default::default()
+        })
+    }
+
+    fn ts_events(records: &[FlatEventRecord]) -> Vec<u64> {
+        records.iter().map(|record| record.ts_event).collect()
+    }
+
+    #[rstest]
+    fn test_new_with_zero_max_rows_per_file() {
+        assert!(ParquetOrderStore::new(PathBuf::from("orders"), 0).is_err());
+    }
+
+    #[rstest]
+    fn test_read_order_point_lookup() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = ParquetOrderStore::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        let events = vec![
+            accepted("O-3", 5),
+            accepted("O-1", 2),
+            accepted("O-2", 4),
+            accepted("O-1", 1),
+            accepted("O-2", 3),
+        ];
+
+        let rows = store.write_order_sorted(&events).unwrap();
+
+        assert_eq!(rows, 5);
+        let index = store.read_index().unwrap();
+        assert_eq!(index.len(), 3);
+        assert!(index
+            .iter()
+            .all(|entry| entry.min_client_order_id == entry.max_client_order_id));
+        assert_eq!(
+            index
+                .iter()
+                .map(|entry| entry.row_group)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+
+        let records = store.read_order(&ClientOrderId::from("O-2")).unwrap();
+        assert_eq!(ts_events(&records), vec![3, 4]);
+        assert!(records.iter().all(|record| record.client_order_id == "O-2"));
+
+        let records = store.read_order(&ClientOrderId::from("O-4")).unwrap();
+        assert!(records.is_empty());
+    }
+
+    #[rstest]
+    fn test_read_order_spanning_file_boundary() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = ParquetOrderStore::new(temp_dir.path().to_path_buf(), 4).unwrap();
+        let events = vec![
+            accepted("O-1", 1),
+            accepted("O-1", 2),
+            accepted("O-1", 3),
+            accepted("O-2", 6),
+            accepted("O-2", 4),
+            accepted("O-2", 5),
+        ];
+
+        store.write_order_sorted(&events).unwrap();
+
+        let index = store.read_index().unwrap();
+        let o2_entries: Vec<_> = index
+            .iter()
+            .filter(|entry| entry.may_contain("O-2"))
+            .collect();
+        assert_eq!(o2_entries.len(), 2);
+        assert_ne!(o2_entries[0].file_name, o2_entries[1].file_name);
+
+        let records = store.read_order(&ClientOrderId::from("O-2")).unwrap();
+        assert_eq!(ts_events(&records), vec![4, 5, 6]);
+    }
+
+    #[rstest]
+    fn test_read_order_merges_duplicate_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = ParquetOrderStore::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        let events = vec![accepted("O-1", 1), accepted("O-1", 2)];
+
+        store.write_order_sorted(&events).unwrap();
+        store.write_order_sorted(&events[1..]).unwrap();
+
+        assert_eq!(store.read_index().unwrap().len(), 2);
+        let records = store.read_order(&ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(ts_events(&records), vec![1, 2]);
+    }
+}
@@ -17,7 +17,10 @@ use std::{collections::HashMap, sync::Arc, vec::IntoIter};
 
 use compare::Compare;
 use datafusion::{
-    error::Result, logical_expr::expr::Sort, physical_plan::SendableRecordBatchStream, prelude::*,
+    error::{DataFusionError, Result},
+    logical_expr::expr::Sort,
+    physical_plan::SendableRecordBatchStream,
+    prelude::*,
 };
 use futures::StreamExt;
 use nautilus_core::ffi::cvec::CVec;
@@ -25,6 +28,7 @@ use nautilus_model::{data::Data, polymorphism::GetTsInit};
 
 use super::kmerge_batch::{EagerStream, ElementBatchIter, KMerge};
 use crate::arrow::{
+    migration::{MigrationFn, SchemaMigrations},
     DataStreamingError, DecodeDataFromRecordBatch, EncodeToRecordBatch, WriteStream,
 };
 
@@ -51,7 +55,8 @@ pub type QueryResult = KMerge<EagerStream<std::vec::IntoIter<Data>>, Data, TsIni
 ///
 /// The session is used to register data sources and make queries on them. A
 /// query returns a Chunk of Arrow records. It is decoded and converted into
-/// a Vec of data by types that implement [`DecodeDataFromRecordBatch`].
+/// a Vec of data by types that implement [`DecodeDataFromRecordBatch`], after migrating
+/// records encoded with an earlier schema version to the current version.
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.persistence")
@@ -61,6 +66,7 @@ pub struct DataBackendSession {
     pub runtime: Arc<tokio::runtime::Runtime>,
     session_ctx: SessionContext,
     batch_streams: Vec<EagerStream<IntoIter<Data>>>,
+    migrations: SchemaMigrations,
 }
 
 impl DataBackendSession {
@@ -73,11 +79,23 @@ impl DataBackendSession {
         Self {
             session_ctx: SessionContext::default(),
             batch_streams: Vec::default(),
+            migrations: SchemaMigrations::builtin(),
             chunk_size,
             runtime: Arc::new(runtime),
         }
     }
 
+    /// Registers the `migration` of the schema for `type_name` from `from_version` to
+    /// `from_version + 1`, applied to files added after registering it.
+    pub fn register_migration(
+        &mut self,
+        type_name: &'static str,
+        from_version: u32,
+        migration: MigrationFn,
+    ) {
+        self.migrations.register(type_name, from_version, migration);
+    }
+
     pub fn write_data<T: EncodeToRecordBatch>(
         data: &[T],
         metadata: &HashMap<String, String>,
@@ -101,6 +119,11 @@ impl DataBackendSession {
     ///
     /// The file data must be ordered by the `ts_init` in ascending order for this
     /// to work correctly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file was encoded with a schema version newer than the current
+    /// version for `T`, or an earlier version which cannot be migrated.
     pub fn add_file<T>(
         &mut self,
         table_name: &str,
@@ -128,6 +151,9 @@ impl DataBackendSession {
         let default_query = format!("SELECT * FROM {} ORDER BY ts_init", &table_name);
         let sql_query = sql_query.unwrap_or(&default_query);
         let query = self.runtime.block_on(self.session_ctx.sql(sql_query))?;
+        self.migrations
+            .validate(T::TYPE_NAME, T::SCHEMA_VERSION, query.schema().metadata())
+            .map_err(|e| DataFusionError::External(Box::new(e)))?;
 
         let batch_stream = self.runtime.block_on(query.execute_stream())?;
 
@@ -139,10 +165,14 @@ impl DataBackendSession {
     where
         T: DecodeDataFromRecordBatch + Into<Data>,
     {
-        let transform = stream.map(|result| match result {
-            Ok(batch) => T::decode_data_batch(batch.schema().metadata(), batch)
-                .unwrap()
-                .into_iter(),
+        let migrations = self.migrations.clone();
+        let transform = stream.map(move |result| match result {
+            Ok(batch) => {
+                let batch = migrations.migrate_batch::<T>(batch).unwrap();
+                T::decode_data_batch(batch.schema().metadata(), batch)
+                    .unwrap()
+                    .into_iter()
+            }
             Err(_err) => panic!("Error getting next batch from RecordBatchStream"),
         });
 
@@ -0,0 +1,714 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a streaming reader of trade and quote ticks from CSV or JSON lines files.
+//!
+//! Files are read in fixed-size byte chunks rather than all at once, so arbitrarily large files
+//! can be loaded in bounded memory. Records split across a chunk boundary are carried over to
+//! the next chunk, and gzip or zstd compressed files are decompressed transparently, detected
+//! from their magic bytes.
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    marker::PhantomData,
+    path::Path,
+    str::FromStr,
+};
+
+use flate2::read::MultiGzDecoder;
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::AggressorSide,
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    types::{price::Price, quantity::Quantity},
+};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// The maximum number of skipped lines recorded individually in a [`TickReadReport`].
+pub const MAX_REPORTED_SKIPS: usize = 100;
+
+/// The record format of a tick file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TickFileFormat {
+    /// Comma-separated values with a header row naming the columns.
+    Csv,
+    /// One JSON object per line, in the serialized form of the tick type.
+    JsonLines,
+}
+
+impl TickFileFormat {
+    /// Infers the format from the extension of the `path`, ignoring any `.gz` or `.zst` suffix.
+    pub fn from_path(path: &Path) -> anyhow::Result<Self> {
+        let name = path.to_string_lossy().to_lowercase();
+        let name = name
+            .strip_suffix(".gz")
+            .or_else(|| name.strip_suffix(".zst"))
+            .unwrap_or(&name);
+
+        if name.ends_with(".csv") {
+            Ok(Self::Csv)
+        } else if name.ends_with(".jsonl") || name.ends_with(".ndjson") || name.ends_with(".json") {
+            Ok(Self::JsonLines)
+        } else {
+            anyhow::bail!("Cannot infer tick file format from {}", path.display())
+        }
+    }
+}
+
+/// The handling of lines which cannot be decoded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CorruptLinePolicy {
+    /// Fail the read on the first corrupt line.
+    #[default]
+    Fail,
+    /// Skip corrupt lines, recording them in the [`TickReadReport`].
+    SkipWithReport,
+}
+
+#[derive(Clone, Debug)]
+pub struct TickReaderConfig {
+    pub format: TickFileFormat,
+    /// The instrument ID for CSV files without an `instrument_id` column.
+    pub instrument_id: Option<InstrumentId>,
+    /// The number of bytes read from the file at a time.
+    pub chunk_size: usize,
+    /// The maximum number of ticks in each batch.
+    pub batch_size: usize,
+    pub corrupt_line_policy: CorruptLinePolicy,
+}
+
+impl TickReaderConfig {
+    #[must_use]
+    pub fn new(format: TickFileFormat) -> Self {
+        Self {
+            format,
+            instrument_id: None,
+            chunk_size: 8 * 1024 * 1024, // 8 MiB
+            batch_size: 10_000,
+            corrupt_line_policy: CorruptLinePolicy::default(),
+        }
+    }
+}
+
+/// A line skipped under [`CorruptLinePolicy::SkipWithReport`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkippedLine {
+    /// The line number in the (decompressed) file, starting from 1.
+    pub line_number: u64,
+    pub reason: String,
+}
+
+/// The progress of a read, including any corrupt lines skipped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TickReadReport {
+    pub lines_read: u64,
+    pub ticks_decoded: u64,
+    pub skipped_count: u64,
+    /// The first [`MAX_REPORTED_SKIPS`] skipped lines.
+    pub skipped: Vec<SkippedLine>,
+}
+
+/// A tick type which can be decoded from a line of a tick file.
+pub trait TickRecord: Sized {
+    /// The catalog data type directory name, such as "trade_tick".
+    const DATA_TYPE: &'static str;
+
+    /// The column indices of the fields within a CSV record.
+    type CsvColumns;
+
+    /// Resolves the column indices from the CSV `header`.
+    fn resolve_columns(
+        header: &[&str],
+        instrument_id: Option<InstrumentId>,
+    ) -> anyhow::Result<Self::CsvColumns>;
+
+    /// Decodes a tick from the `fields` of a CSV record.
+    fn from_csv(columns: &Self::CsvColumns, fields: &[&str]) -> anyhow::Result<Self>;
+
+    /// Decodes a tick from a JSON object.
+    fn from_json(line: &str) -> anyhow::Result<Self>;
+
+    fn instrument_id(&self) -> InstrumentId;
+
+    /// Returns the Arrow schema metadata for encoding ticks like this one.
+    fn metadata(&self) -> HashMap<String, String>;
+}
+
+/// The column of the instrument ID, or a fixed instrument ID where the file has no such column.
+#[derive(Clone, Copy, Debug)]
+pub enum InstrumentColumn {
+    Index(usize),
+    Fixed(InstrumentId),
+}
+
+impl InstrumentColumn {
+    fn resolve(header: &[&str], instrument_id: Option<InstrumentId>) -> anyhow::Result<Self> {
+        match (find_column(header, "instrument_id"), instrument_id) {
+            (Some(index), _) => Ok(Self::Index(index)),
+            (None, Some(instrument_id)) => Ok(Self::Fixed(instrument_id)),
+            (None, None) => {
+                anyhow::bail!("No `instrument_id` column, and no instrument ID configured")
+            }
+        }
+    }
+
+    fn parse(&self, fields: &[&str]) -> anyhow::Result<InstrumentId> {
+        match self {
+            Self::Index(index) => InstrumentId::from_str(field(fields, *index)?),
+            Self::Fixed(instrument_id) => Ok(*instrument_id),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TradeTickColumns {
+    instrument_id: InstrumentColumn,
+    price: usize,
+    size: usize,
+    aggressor_side: usize,
+    trade_id: usize,
+    ts_event: usize,
+    ts_init: Option<usize>,
+}
+
+impl TickRecord for TradeTick {
+    const DATA_TYPE: &'static str = "trade_tick";
+    type CsvColumns = TradeTickColumns;
+
+    fn resolve_columns(
+        header: &[&str],
+        instrument_id: Option<InstrumentId>,
+    ) -> anyhow::Result<TradeTickColumns> {
+        Ok(TradeTickColumns {
+            instrument_id: InstrumentColumn::resolve(header, instrument_id)?,
+            price: require_column(header, "price")?,
+            size: require_column(header, "size")?,
+            aggressor_side: require_column(header, "aggressor_side")?,
+            trade_id: require_column(header, "trade_id")?,
+            ts_event: require_column(header, "ts_event")?,
+            ts_init: find_column(header, "ts_init"),
+        })
+    }
+
+    fn from_csv(columns: &TradeTickColumns, fields: &[&str]) -> anyhow::Result<Self> {
+        let ts_event = parse_field(fields, columns.ts_event)?;
+        let ts_init = columns
+            .ts_init
+            .map_or(Ok(ts_event), |index| parse_field(fields, index))?;
+        Ok(Self::new(
+            columns.instrument_id.parse(fields)?,
+            Price::from_str(field(fields, columns.price)?).map_err(anyhow::Error::msg)?,
+            Quantity::from_str(field(fields, columns.size)?).map_err(anyhow::Error::msg)?,
+            AggressorSide::from_str(field(fields, columns.aggressor_side)?)?,
+            TradeId::new(field(fields, columns.trade_id)?)?,
+            ts_event.into(),
+            ts_init.into(),
+        )?)
+    }
+
+    fn from_json(line: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(line)?)
+    }
+
+    fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(
+            &self.instrument_id,
+            self.price.precision,
+            self.size.precision,
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct QuoteTickColumns {
+    instrument_id: InstrumentColumn,
+    bid_price: usize,
+    ask_price: usize,
+    bid_size: usize,
+    ask_size: usize,
+    ts_event: usize,
+    ts_init: Option<usize>,
+}
+
+impl TickRecord for QuoteTick {
+    const DATA_TYPE: &'static str = "quote_tick";
+    type CsvColumns = QuoteTickColumns;
+
+    fn resolve_columns(
+        header: &[&str],
+        instrument_id: Option<InstrumentId>,
+    ) -> anyhow::Result<QuoteTickColumns> {
+        Ok(QuoteTickColumns {
+            instrument_id: InstrumentColumn::resolve(header, instrument_id)?,
+            bid_price: require_column(header, "bid_price")?,
+            ask_price: require_column(header, "ask_price")?,
+            bid_size: require_column(header, "bid_size")?,
+            ask_size: require_column(header, "ask_size")?,
+            ts_event: require_column(header, "ts_event")?,
+            ts_init: find_column(header, "ts_init"),
+        })
+    }
+
+    fn from_csv(columns: &QuoteTickColumns, fields: &[&str]) -> anyhow::Result<Self> {
+        let ts_event = parse_field(fields, columns.ts_event)?;
+        let ts_init = columns
+            .ts_init
+            .map_or(Ok(ts_event), |index| parse_field(fields, index))?;
+        Ok(Self::new(
+            columns.instrument_id.parse(fields)?,
+            Price::from_str(field(fields, columns.bid_price)?).map_err(anyhow::Error::msg)?,
+            Price::from_str(field(fields, columns.ask_price)?).map_err(anyhow::Error::msg)?,
+            Quantity::from_str(field(fields, columns.bid_size)?).map_err(anyhow::Error::msg)?,
+            Quantity::from_str(field(fields, columns.ask_size)?).map_err(anyhow::Error::msg)?,
+            ts_event.into(),
+            ts_init.into(),
+        )?)
+    }
+
+    fn from_json(line: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(line)?)
+    }
+
+    fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(
+            &self.instrument_id,
+            self.bid_price.precision,
+            self.bid_size.precision,
+        )
+    }
+}
+
+fn find_column(header: &[&str], name: &str) -> Option<usize> {
+    header.iter().position(|column| column.trim() == name)
+}
+
+fn require_column(header: &[&str], name: &str) -> anyhow::Result<usize> {
+    find_column(header, name).ok_or_else(|| anyhow::anyhow!("Missing `{name}` column"))
+}
+
+fn field<'a>(fields: &[&'a str], index: usize) -> anyhow::Result<&'a str> {
+    fields
+        .get(index)
+        .map(|field| field.trim())
+        .ok_or_else(|| anyhow::anyhow!("Missing field at column {index}"))
+}
+
+fn parse_field(fields: &[&str], index: usize) -> anyhow::Result<u64> {
+    Ok(field(fields, index)?.parse()?)
+}
+
+/// Wraps the `source` in a decoder if it starts with gzip or zstd magic bytes.
+fn decompressed(source: impl Read + Send + 'static) -> io::Result<Box<dyn Read + Send>> {
+    let mut source = BufReader::new(source);
+    let magic = source.fill_buf()?;
+    if magic.starts_with(&GZIP_MAGIC) {
+        Ok(Box::new(MultiGzDecoder::new(source)))
+    } else if magic.starts_with(&ZSTD_MAGIC) {
+        Ok(Box::new(zstd::stream::read::Decoder::with_buffer(source)?))
+    } else {
+        Ok(Box::new(source))
+    }
+}
+
+/// Reads ticks of type `T` from a CSV or JSON lines file in batches.
+///
+/// The reader is an iterator of batches of at most `batch_size` ticks, and holds at most one
+/// chunk of the file (plus any partial line) in memory at a time.
+pub struct ChunkedTickReader<T: TickRecord> {
+    source: Box<dyn Read + Send>,
+    config: TickReaderConfig,
+    /// Bytes read from the source which have not yet been decoded.
+    buffer: Vec<u8>,
+    /// The position of the next line within `buffer`.
+    position: usize,
+    columns: Option<T::CsvColumns>,
+    report: TickReadReport,
+    is_eof: bool,
+    is_done: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T: TickRecord> ChunkedTickReader<T> {
+    /// Creates a new reader of the given `source`, which may be gzip or zstd compressed.
+    pub fn new(
+        source: impl Read + Send + 'static,
+        config: TickReaderConfig,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(config.chunk_size > 0, "`chunk_size` must be positive");
+        anyhow::ensure!(config.batch_size > 0, "`batch_size` must be positive");
+
+        Ok(Self {
+            source: decompressed(source)?,
+            buffer: Vec::with_capacity(config.chunk_size),
+            config,
+            position: 0,
+            columns: None,
+            report: TickReadReport::default(),
+            is_eof: false,
+            is_done: false,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Creates a new reader of the file at `path`, which may be gzip or zstd compressed.
+    pub fn from_path(path: &Path, config: TickReaderConfig) -> anyhow::Result<Self> {
+        Self::new(File::open(path)?, config)
+    }
+
+    /// Returns the progress of the read so far.
+    #[must_use]
+    pub fn report(&self) -> &TickReadReport {
+        &self.report
+    }
+
+    /// Returns the next complete line, reading further chunks as needed.
+    ///
+    /// At the end of the source any remaining bytes are returned as the final line.
+    fn next_line(&mut self) -> anyhow::Result<Option<(usize, usize)>> {
+        loop {
+            let unread = &self.buffer[self.position..];
+            if let Some(offset) = unread.iter().position(|byte| *byte == b'\n') {
+                let start = self.position;
+                self.position += offset + 1;
+                return Ok(Some((start, start + offset)));
+            }
+
+            if self.is_eof {
+                if unread.is_empty() {
+                    return Ok(None);
+                }
+                let start = self.position;
+                self.position = self.buffer.len();
+                return Ok(Some((start, self.buffer.len())));
+            }
+
+            // Carry the partial line over to the start of the next chunk
+            self.buffer.drain(..self.position);
+            self.position = 0;
+            self.read_chunk()?;
+        }
+    }
+
+    fn read_chunk(&mut self) -> io::Result<()> {
+        let len = self.buffer.len();
+        self.buffer.resize(len + self.config.chunk_size, 0);
+        let result = loop {
+            match self.source.read(&mut self.buffer[len..]) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                result => break result,
+            }
+        };
+        let read = result.as_ref().copied().unwrap_or(0);
+        self.buffer.truncate(len + read);
+        self.is_eof = read == 0;
+        result.map(|_| ())
+    }
+
+    /// Decodes the line, returning `None` for blank lines and the CSV header.
+    fn decode_line(&mut self, line: &[u8]) -> anyhow::Result<Option<T>> {
+        let line = std::str::from_utf8(line)?.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+        anyhow::ensure!(!line.contains('\0'), "Line contains NUL bytes");
+
+        match self.config.format {
+            TickFileFormat::JsonLines => T::from_json(line).map(Some),
+            TickFileFormat::Csv => {
+                let fields: Vec<&str> = line.split(',').collect();
+                match &self.columns {
+                    Some(columns) => T::from_csv(columns, &fields).map(Some),
+                    None => {
+                        self.columns =
+                            Some(T::resolve_columns(&fields, self.config.instrument_id)?);
+                        Ok(None)
+                    }
+                }
+            }
+        }
+    }
+
+    fn next_batch(&mut self) -> anyhow::Result<Vec<T>> {
+        let mut batch = Vec::with_capacity(self.config.batch_size);
+
+        while batch.len() < self.config.batch_size {
+            let Some((start, end)) = self.next_line()? else {
+                break;
+            };
+            self.report.lines_read += 1;
+            let line_number = self.report.lines_read;
+
+            let line = std::mem::take(&mut self.buffer);
+            let result = self.decode_line(&line[start..end]);
+            self.buffer = line;
+
+            match result {
+                Ok(Some(tick)) => {
+                    batch.push(tick);
+                    self.report.ticks_decoded += 1;
+                }
+                Ok(None) => {}
+                Err(e) if self.columns.is_none() && self.config.format == TickFileFormat::Csv => {
+                    anyhow::bail!("Invalid CSV header: {e}")
+                }
+                Err(e) => match self.config.corrupt_line_policy {
+                    CorruptLinePolicy::Fail => anyhow::bail!("Line {line_number}: {e}"),
+                    CorruptLinePolicy::SkipWithReport => {
+                        log::warn!("Skipping corrupt line {line_number}: {e}");
+                        self.report.skipped_count += 1;
+                        if self.report.skipped.len() < MAX_REPORTED_SKIPS {
+                            self.report.skipped.push(SkippedLine {
+                                line_number,
+                                reason: e.to_string(),
+                            });
+                        }
+                    }
+                },
+            }
+        }
+
+        Ok(batch)
+    }
+}
+
+impl<T: TickRecord> Iterator for ChunkedTickReader<T> {
+    type Item = anyhow::Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        match self.next_batch() {
+            Ok(batch) if batch.is_empty() => {
+                self.is_done = true;
+                None
+            }
+            Ok(batch) => Some(Ok(batch)),
+            Err(e) => {
+                self.is_done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use flate2::{write::GzEncoder, Compression};
+    use rstest::rstest;
+
+    use super::*;
+
+    const TRADES_CSV: &str = "\
+ts_event,price,size,aggressor_side,trade_id
+1,1.00010,100000,BUYER,T-1
+2,1.00020,200000,SELLER,T-2
+3,1.00030,300000,BUYER,T-3
+4,1.00040,400000,NO_AGGRESSOR,T-4
+5,1.00050,500000,SELLER,T-5
+";
+
+    fn config(chunk_size: usize, batch_size: usize) -> TickReaderConfig {
+        TickReaderConfig {
+            instrument_id: Some(InstrumentId::from("AUD/USD.SIM")),
+            chunk_size,
+            batch_size,
+            ..TickReaderConfig::new(TickFileFormat::Csv)
+        }
+    }
+
+    fn read_all<T: TickRecord>(
+        data: Vec<u8>,
+        config: TickReaderConfig,
+    ) -> (anyhow::Result<Vec<T>>, TickReadReport) {
+        let mut reader = ChunkedTickReader::<T>::new(Cursor::new(data), config).unwrap();
+        let mut ticks = Vec::new();
+        for batch in reader.by_ref() {
+            match batch {
+                Ok(batch) => ticks.extend(batch),
+                Err(e) => return (Err(e), reader.report().clone()),
+            }
+        }
+        (Ok(ticks), reader.report().clone())
+    }
+
+    fn expected_trades() -> Vec<TradeTick> {
+        let (ticks, _) = read_all(TRADES_CSV.as_bytes().to_vec(), config(1024, 1024));
+        ticks.unwrap()
+    }
+
+    #[rstest]
+    fn test_read_trades_csv() {
+        let trades = expected_trades();
+
+        assert_eq!(trades.len(), 5);
+        assert_eq!(trades[1].price, Price::from("1.00020"));
+        assert_eq!(trades[1].aggressor_side, AggressorSide::Seller);
+        assert_eq!(trades[1].trade_id, TradeId::from("T-2"));
+        assert_eq!(trades[1].ts_init, trades[1].ts_event); // No `ts_init` column
+    }
+
+    #[rstest]
+    fn test_record_straddling_chunk_boundary(#[values(1, 7, 16, 29, 30, 31)] chunk_size: usize) {
+        // Lines are 27-29 bytes, so most chunk sizes split records mid-line
+        let (trades, report) =
+            read_all::<TradeTick>(TRADES_CSV.as_bytes().to_vec(), config(chunk_size, 2));
+
+        assert_eq!(trades.unwrap(), expected_trades());
+        assert_eq!(report.lines_read, 6);
+        assert_eq!(report.ticks_decoded, 5);
+    }
+
+    #[rstest]
+    fn test_batches_limited_to_batch_size() {
+        let reader =
+            ChunkedTickReader::<TradeTick>::new(Cursor::new(TRADES_CSV), config(16, 2)).unwrap();
+
+        let sizes: Vec<usize> = reader.map(|batch| batch.unwrap().len()).collect();
+
+        assert_eq!(sizes, vec![2, 2, 1]);
+    }
+
+    #[rstest]
+    fn test_final_line_without_newline() {
+        let data = TRADES_CSV.trim_end().replace('\n', "\r\n");
+
+        let (trades, _) = read_all::<TradeTick>(data.into_bytes(), config(8, 10));
+
+        assert_eq!(trades.unwrap(), expected_trades());
+    }
+
+    #[rstest]
+    fn test_corrupt_line_skipped_with_report() {
+        let mut data = TRADES_CSV.as_bytes().to_vec();
+        // Corrupt the third record with a truncated write and garbage bytes
+        let start = TRADES_CSV.find("3,").unwrap();
+        data.splice(start..start + 10, [0x00, 0xff, b',', b'x']);
+        let mut config = config(11, 2);
+        config.corrupt_line_policy = CorruptLinePolicy::SkipWithReport;
+
+        let (trades, report) = read_all::<TradeTick>(data, config);
+
+        let mut expected = expected_trades();
+        expected.remove(2);
+        assert_eq!(trades.unwrap(), expected);
+        assert_eq!(report.lines_read, 6);
+        assert_eq!(report.ticks_decoded, 4);
+        assert_eq!(report.skipped_count, 1);
+        assert_eq!(report.skipped[0].line_number, 4);
+    }
+
+    #[rstest]
+    fn test_corrupt_line_fails_read_by_default() {
+        let data = TRADES_CSV.replace("1.00030", "1.0oo30");
+
+        let (trades, report) = read_all::<TradeTick>(data.into_bytes(), config(11, 10));
+
+        let error = trades.unwrap_err().to_string();
+        assert!(error.starts_with("Line 4:"), "{error}");
+        assert_eq!(report.ticks_decoded, 2);
+    }
+
+    #[rstest]
+    fn test_missing_instrument_id_fails_on_header() {
+        let mut config = config(1024, 10);
+        config.instrument_id = None;
+        config.corrupt_line_policy = CorruptLinePolicy::SkipWithReport;
+
+        let (trades, _) = read_all::<TradeTick>(TRADES_CSV.as_bytes().to_vec(), config);
+
+        assert!(trades
+            .unwrap_err()
+            .to_string()
+            .contains("No `instrument_id` column"));
+    }
+
+    #[rstest]
+    fn test_read_compressed(#[values("gzip", "zstd")] compression: &str) {
+        let data = match compression {
+            "gzip" => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(TRADES_CSV.as_bytes()).unwrap();
+                encoder.finish().unwrap()
+            }
+            _ => zstd::encode_all(TRADES_CSV.as_bytes(), 0).unwrap(),
+        };
+
+        let (trades, _) = read_all::<TradeTick>(data, config(7, 2));
+
+        assert_eq!(trades.unwrap(), expected_trades());
+    }
+
+    #[rstest]
+    fn test_read_quotes_json_lines() {
+        let quotes: Vec<QuoteTick> = (0..5_u64)
+            .map(|i| {
+                QuoteTick::new(
+                    InstrumentId::from("EUR/USD.SIM"),
+                    Price::from("1.10000"),
+                    Price::from("1.10010"),
+                    Quantity::from(1_000_000),
+                    Quantity::from(1_000_000),
+                    i.into(),
+                    i.into(),
+                )
+                .unwrap()
+            })
+            .collect();
+        let data: String = quotes
+            .iter()
+            .map(|quote| serde_json::to_string(quote).unwrap() + "\n")
+            .collect();
+        let config = TickReaderConfig {
+            chunk_size: 50,
+            batch_size: 3,
+            ..TickReaderConfig::new(TickFileFormat::JsonLines)
+        };
+
+        let (decoded, _) = read_all::<QuoteTick>(data.into_bytes(), config);
+
+        assert_eq!(decoded.unwrap(), quotes);
+    }
+
+    #[rstest]
+    #[case("trades.csv", TickFileFormat::Csv)]
+    #[case("trades.csv.gz", TickFileFormat::Csv)]
+    #[case("quotes.jsonl.zst", TickFileFormat::JsonLines)]
+    #[case("QUOTES.NDJSON", TickFileFormat::JsonLines)]
+    fn test_format_from_path(#[case] path: &str, #[case] expected: TickFileFormat) {
+        assert_eq!(
+            TickFileFormat::from_path(Path::new(path)).unwrap(),
+            expected
+        );
+    }
+}
@@ -14,4 +14,5 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod session;
+pub mod tick_reader;
 pub mod transformer;
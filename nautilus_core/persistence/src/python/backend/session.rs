@@ -15,7 +15,8 @@
 
 use nautilus_core::{ffi::cvec::CVec, python::to_pyruntime_err};
 use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
+    bar::Bar, close::InstrumentClose, delta::OrderBookDelta, depth::OrderBookDepth10,
+    quote::QuoteTick, trade::TradeTick,
 };
 use pyo3::{prelude::*, types::PyCapsule};
 
@@ -31,6 +32,7 @@ pub enum NautilusDataType {
     QuoteTick = 3,
     TradeTick = 4,
     Bar = 5,
+    InstrumentClose = 6,
 }
 
 #[pymethods]
@@ -80,6 +82,9 @@ impl DataBackendSession {
             NautilusDataType::Bar => slf
                 .add_file::<Bar>(table_name, file_path, sql_query)
                 .map_err(to_pyruntime_err),
+            NautilusDataType::InstrumentClose => slf
+                .add_file::<InstrumentClose>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
         }
     }
 
@@ -0,0 +1,149 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{path::Path, str::FromStr};
+
+use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    identifiers::instrument_id::InstrumentId,
+};
+use pyo3::prelude::*;
+
+use super::session::NautilusDataType;
+use crate::backend::tick_reader::{
+    ChunkedTickReader, CorruptLinePolicy, TickFileFormat, TickReadReport, TickReaderConfig,
+};
+
+enum TickReaderAny {
+    Quotes(ChunkedTickReader<QuoteTick>),
+    Trades(ChunkedTickReader<TradeTick>),
+}
+
+/// Reads ticks from a (possibly compressed) CSV or JSON lines file as an iterator of lists.
+#[pyclass(
+    module = "nautilus_trader.core.nautilus_pyo3.persistence",
+    name = "ChunkedTickReader"
+)]
+pub struct PyChunkedTickReader {
+    reader: TickReaderAny,
+}
+
+impl PyChunkedTickReader {
+    fn report(&self) -> &TickReadReport {
+        match &self.reader {
+            TickReaderAny::Quotes(reader) => reader.report(),
+            TickReaderAny::Trades(reader) => reader.report(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyChunkedTickReader {
+    #[new]
+    #[pyo3(signature = (
+        file_path,
+        data_type,
+        instrument_id=None,
+        chunk_size=8_388_608,
+        batch_size=10_000,
+        skip_corrupt_lines=false,
+    ))]
+    fn py_new(
+        file_path: &str,
+        data_type: NautilusDataType,
+        instrument_id: Option<&str>,
+        chunk_size: usize,
+        batch_size: usize,
+        skip_corrupt_lines: bool,
+    ) -> PyResult<Self> {
+        let path = Path::new(file_path);
+        let config = TickReaderConfig {
+            format: TickFileFormat::from_path(path).map_err(to_pyvalue_err)?,
+            instrument_id: instrument_id
+                .map(InstrumentId::from_str)
+                .transpose()
+                .map_err(to_pyvalue_err)?,
+            chunk_size,
+            batch_size,
+            corrupt_line_policy: if skip_corrupt_lines {
+                CorruptLinePolicy::SkipWithReport
+            } else {
+                CorruptLinePolicy::Fail
+            },
+        };
+
+        let reader = match data_type {
+            NautilusDataType::QuoteTick => TickReaderAny::Quotes(
+                ChunkedTickReader::from_path(path, config).map_err(to_pyvalue_err)?,
+            ),
+            NautilusDataType::TradeTick => TickReaderAny::Trades(
+                ChunkedTickReader::from_path(path, config).map_err(to_pyvalue_err)?,
+            ),
+            _ => {
+                return Err(to_pyvalue_err(format!(
+                    "Unsupported data type {data_type:?}, expected `QuoteTick` or `TradeTick`"
+                )))
+            }
+        };
+
+        Ok(Self { reader })
+    }
+
+    #[getter]
+    fn lines_read(&self) -> u64 {
+        self.report().lines_read
+    }
+
+    #[getter]
+    fn ticks_decoded(&self) -> u64 {
+        self.report().ticks_decoded
+    }
+
+    #[getter]
+    fn skipped_count(&self) -> u64 {
+        self.report().skipped_count
+    }
+
+    /// Returns the (line number, reason) of the first skipped lines.
+    #[getter]
+    fn skipped(&self) -> Vec<(u64, String)> {
+        self.report()
+            .skipped
+            .iter()
+            .map(|skipped| (skipped.line_number, skipped.reason.clone()))
+            .collect()
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Each iteration returns a list of up to `batch_size` ticks.
+    fn __next__(&mut self, py: Python) -> PyResult<Option<Vec<PyObject>>> {
+        match &mut self.reader {
+            TickReaderAny::Quotes(reader) => reader
+                .next()
+                .transpose()
+                .map(|batch| batch.map(|ticks| ticks.into_iter().map(|t| t.into_py(py)).collect()))
+                .map_err(to_pyruntime_err),
+            TickReaderAny::Trades(reader) => reader
+                .next()
+                .transpose()
+                .map(|batch| batch.map(|ticks| ticks.into_iter().map(|t| t.into_py(py)).collect()))
+                .map_err(to_pyruntime_err),
+        }
+    }
+}
@@ -13,15 +13,19 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::io::Cursor;
+use std::{collections::HashMap, io::Cursor};
 
 use datafusion::arrow::{
     datatypes::Schema, error::ArrowError, ipc::writer::StreamWriter, record_batch::RecordBatch,
 };
 use nautilus_core::python::to_pyvalue_err;
-use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, is_monotonically_increasing_by_init,
-    quote::QuoteTick, trade::TradeTick,
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10,
+        is_monotonically_increasing_by_init, quote::QuoteTick, trade::TradeTick,
+    },
+    events::order::flat::{events_to_flat_records, FlatEventRecord},
+    python::events::order::pyobject_to_order_event,
 };
 use pyo3::{
     exceptions::{PyRuntimeError, PyTypeError, PyValueError},
@@ -229,7 +233,7 @@ impl DataTransformer {
 
         match result {
             Ok(batch) => {
-                let schema = OrderBookDelta::get_schema(Some(metadata));
+                let schema = OrderBookDelta::get_versioned_schema(&metadata);
                 Self::record_batch_to_pybytes(py, batch, schema)
             }
             Err(e) => Err(to_pyvalue_err(e)),
@@ -259,7 +263,7 @@ impl DataTransformer {
 
         match result {
             Ok(batch) => {
-                let schema = OrderBookDepth10::get_schema(Some(metadata));
+                let schema = OrderBookDepth10::get_versioned_schema(&metadata);
                 Self::record_batch_to_pybytes(py, batch, schema)
             }
             Err(e) => Err(to_pyvalue_err(e)),
@@ -288,7 +292,7 @@ impl DataTransformer {
 
         match result {
             Ok(batch) => {
-                let schema = QuoteTick::get_schema(Some(metadata));
+                let schema = QuoteTick::get_versioned_schema(&metadata);
                 Self::record_batch_to_pybytes(py, batch, schema)
             }
             Err(e) => Err(to_pyvalue_err(e)),
@@ -317,7 +321,7 @@ impl DataTransformer {
 
         match result {
             Ok(batch) => {
-                let schema = TradeTick::get_schema(Some(metadata));
+                let schema = TradeTick::get_versioned_schema(&metadata);
                 Self::record_batch_to_pybytes(py, batch, schema)
             }
             Err(e) => Err(to_pyvalue_err(e)),
@@ -346,7 +350,34 @@ impl DataTransformer {
 
         match result {
             Ok(batch) => {
-                let schema = Bar::get_schema(Some(metadata));
+                let schema = Bar::get_versioned_schema(&metadata);
+                Self::record_batch_to_pybytes(py, batch, schema)
+            }
+            Err(e) => Err(to_pyvalue_err(e)),
+        }
+    }
+
+    /// Return Python `bytes` from the given list of order events flattened into a single table
+    /// schema, which can be passed to `pa.ipc.open_stream` to create a `RecordBatchReader`.
+    #[staticmethod]
+    pub fn pyo3_order_events_to_record_batch_bytes(
+        py: Python<'_>,
+        events: Vec<PyObject>,
+    ) -> PyResult<Py<PyBytes>> {
+        if events.is_empty() {
+            return Err(to_pyvalue_err(ERROR_EMPTY_DATA));
+        }
+
+        let events = events
+            .into_iter()
+            .map(|event| pyobject_to_order_event(py, event))
+            .collect::<PyResult<Vec<_>>>()?;
+        let records = events_to_flat_records(&events);
+        let metadata = HashMap::new();
+
+        match FlatEventRecord::encode_batch(&metadata, &records) {
+            Ok(batch) => {
+                let schema = FlatEventRecord::get_versioned_schema(&metadata);
                 Self::record_batch_to_pybytes(py, batch, schema)
             }
             Err(e) => Err(to_pyvalue_err(e)),
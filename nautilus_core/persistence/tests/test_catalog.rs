@@ -13,12 +13,19 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{fs::File, sync::Arc};
+
+use datafusion::{arrow::record_batch::RecordBatch, parquet::arrow::ArrowWriter};
 use nautilus_core::ffi::cvec::CVec;
-use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, is_monotonically_increasing_by_init, quote::QuoteTick,
-    trade::TradeTick, Data,
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, is_monotonically_increasing_by_init, quote::QuoteTick,
+        stubs::quote_tick_ethusdt_binance, trade::TradeTick, Data,
+    },
+    identifiers::instrument_id::InstrumentId,
 };
 use nautilus_persistence::{
+    arrow::{migration::KEY_SCHEMA_VERSION, EncodeToRecordBatch},
     backend::session::{DataBackendSession, DataQueryResult, QueryResult},
     python::backend::session::NautilusDataType,
 };
@@ -299,3 +306,30 @@ fn test_bar_query() {
     assert_eq!(ticks.len(), expected_length);
     assert!(is_monotonically_increasing_by_init(&ticks));
 }
+
+#[rstest]
+fn test_future_schema_version_query_errors() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("quotes.parquet");
+    let quote = quote_tick_ethusdt_binance();
+    let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+    let metadata = QuoteTick::get_metadata(&instrument_id, 4, 8);
+    let batch = QuoteTick::encode_batch(&metadata, &[quote]).unwrap();
+
+    let mut metadata = batch.schema().metadata().clone();
+    metadata.insert(KEY_SCHEMA_VERSION.to_string(), "99".to_string());
+    let schema = Arc::new(batch.schema().as_ref().clone().with_metadata(metadata));
+    let batch = RecordBatch::try_new(schema.clone(), batch.columns().to_vec()).unwrap();
+    let mut writer = ArrowWriter::try_new(File::create(&file_path).unwrap(), schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let mut catalog = DataBackendSession::new(10_000);
+    let err = catalog
+        .add_file::<QuoteTick>("quote_099", file_path.to_str().unwrap(), None)
+        .unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("Unsupported schema version 99 for `QuoteTick`"));
+}
@@ -0,0 +1,559 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `Portfolio` for reporting on the state of accounts, positions and orders.
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::Arc};
+
+use log::warn;
+use nautilus_common::{cache::Cache, interface::account::Account, xrate::ExchangeRateCache};
+use nautilus_model::{
+    enums::{OrderSide, PositionSide, PriceType},
+    identifiers::instrument_id::InstrumentId,
+    orders::any::OrderAny,
+    polymorphism::{
+        GetAccountId, GetClientOrderId, GetInstrumentId, GetOrderLeavesQty, GetOrderSide,
+    },
+    position::Position,
+    types::{currency::Currency, money::Money, price::Price},
+};
+use rust_decimal::prelude::ToPrimitive;
+
+/// Represents the exposure of the portfolio to a single currency.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CurrencyExposure {
+    pub currency: Currency,
+    /// The net (signed) value of open positions, converted to the account base currency where
+    /// the account has one.
+    pub position_value: Money,
+    /// The cash reserved by resting orders.
+    pub locked_by_orders: Money,
+    /// The total account balance less the cash reserved by resting orders.
+    pub free: Money,
+    /// If some position value could not be included exactly, due to a missing exchange rate or
+    /// mark price.
+    pub is_partial: bool,
+}
+
+impl CurrencyExposure {
+    fn new(currency: Currency) -> Self {
+        let zero = Money::new(0.0, currency).unwrap();
+        Self {
+            currency,
+            position_value: zero,
+            locked_by_orders: zero,
+            free: zero,
+            is_partial: false,
+        }
+    }
+}
+
+pub struct Portfolio {
+    cache: Rc<RefCell<Cache>>,
+    xrates: Arc<ExchangeRateCache>,
+    stale_quote_threshold_ns: Option<u64>,
+}
+
+impl Portfolio {
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>, xrates: Arc<ExchangeRateCache>) -> Self {
+        Self {
+            cache,
+            xrates,
+            stale_quote_threshold_ns: None,
+        }
+    }
+
+    /// Sets the threshold (nanoseconds) beyond which market prices are stale relative to the
+    /// latest instrument close, so positions are marked at the close price instead.
+    ///
+    /// If `None` (the default), instrument closes are never used to mark positions.
+    pub fn set_stale_quote_threshold(&mut self, threshold_ns: Option<u64>) {
+        self.stale_quote_threshold_ns = threshold_ns;
+    }
+
+    /// Returns the total unrealized PnL of the open positions for the given `instrument_id`.
+    ///
+    /// Returns `None` if there are no open positions for the instrument, no mark price, or the
+    /// positions have mixed settlement currencies.
+    #[must_use]
+    pub fn unrealized_pnl(&self, instrument_id: &InstrumentId) -> Option<Money> {
+        let cache = self.cache.borrow();
+        let positions = cache.positions_open(None, Some(instrument_id), None, None);
+        let first = positions.first()?;
+        let Some(mark) = self.mark_price(&cache, instrument_id) else {
+            warn!("Cannot calculate unrealized PnL for {instrument_id}: no mark price");
+            return None;
+        };
+
+        let zero = Money::new(0.0, first.settlement_currency).unwrap();
+        match positions
+            .iter()
+            .map(|position| position.unrealized_pnl(mark))
+            .try_fold(zero, |total, pnl| total + pnl)
+        {
+            Ok(total) => Some(total),
+            Err(e) => {
+                warn!("Cannot calculate unrealized PnL for {instrument_id}: {e}");
+                None
+            }
+        }
+    }
+
+    /// Returns the exposure per currency, sorted by currency code.
+    ///
+    /// Combines the total account balances, the value of open positions (marked at the mid,
+    /// otherwise last price) and the cash locked by resting orders. Buy orders lock the notional
+    /// value in the quote currency, and sell orders on cash accounts lock the quantity in the
+    /// base currency.
+    ///
+    /// A position value which cannot be converted to its account base currency is reported in
+    /// its own currency, and a position without a mark price is valued at its average open
+    /// price. Either way the row is marked partial rather than the value being omitted.
+    #[must_use]
+    pub fn currency_exposures(&self) -> Vec<CurrencyExposure> {
+        let cache = self.cache.borrow();
+        let mut exposures: HashMap<Currency, CurrencyExposure> = HashMap::new();
+
+        for account in cache.accounts() {
+            for (currency, total) in account.balances_total() {
+                let exposure = exposures
+                    .entry(currency)
+                    .or_insert_with(|| CurrencyExposure::new(currency));
+                exposure.free += total;
+            }
+        }
+
+        for position in cache.positions_open(None, None, None, None) {
+            let account = cache.account(&position.account_id);
+            let (value, is_partial) = self.position_value(&cache, position, account);
+            let exposure = exposures
+                .entry(value.currency)
+                .or_insert_with(|| CurrencyExposure::new(value.currency));
+            exposure.position_value += value;
+            exposure.is_partial |= is_partial;
+        }
+
+        for order in cache.orders_open(None, None, None, None) {
+            let account = order
+                .account_id()
+                .and_then(|account_id| cache.account(&account_id))
+                .or_else(|| cache.account_for_venue(&order.instrument_id().venue));
+            if let Some(locked) = Self::order_locked(&cache, order, account) {
+                let exposure = exposures
+                    .entry(locked.currency)
+                    .or_insert_with(|| CurrencyExposure::new(locked.currency));
+                exposure.locked_by_orders += locked;
+                exposure.free -= locked;
+            }
+        }
+
+        let mut exposures: Vec<CurrencyExposure> = exposures.into_values().collect();
+        exposures.sort_by(|a, b| a.currency.code.as_str().cmp(b.currency.code.as_str()));
+        exposures
+    }
+
+    /// Returns the signed value of the `position`, and whether the value is partial.
+    fn position_value(
+        &self,
+        cache: &Cache,
+        position: &Position,
+        account: Option<&dyn Account>,
+    ) -> (Money, bool) {
+        let mark = self.mark_price(cache, &position.instrument_id);
+        let mut is_partial = mark.is_none();
+        let mark = mark.unwrap_or_else(|| {
+            warn!(
+                "No mark price for {}, using average open price",
+                position.id
+            );
+            Price::new(position.avg_px_open, position.price_precision).unwrap()
+        });
+
+        let mut value = position.notional_value(mark);
+        match PositionSide::from_signed_qty(position.signed_qty) {
+            PositionSide::Long => {}
+            PositionSide::Short => value = -value,
+            PositionSide::Flat | PositionSide::NoPositionSide => {
+                value = Money::new(0.0, value.currency).unwrap();
+            }
+        }
+
+        let base_currency = account.and_then(Account::base_currency);
+        if let Some(base_currency) = base_currency.filter(|c| *c != value.currency) {
+            match self
+                .xrates
+                .rate(value.currency, base_currency, PriceType::Mid)
+                .and_then(|rate| rate.to_f64())
+            {
+                Some(rate) => value = Money::new(value.as_f64() * rate, base_currency).unwrap(),
+                None => {
+                    warn!(
+                        "No exchange rate for {}/{}, reporting {} value unconverted",
+                        value.currency, base_currency, position.id
+                    );
+                    is_partial = true;
+                }
+            }
+        }
+
+        (value, is_partial)
+    }
+
+    /// Returns the mark price for the `instrument_id`, at the mid, otherwise last price.
+    ///
+    /// With a stale quote threshold set, the latest instrument close is preferred where there
+    /// is no market price, or the market price is older than the close by more than the
+    /// threshold.
+    fn mark_price(&self, cache: &Cache, instrument_id: &InstrumentId) -> Option<Price> {
+        let market = match cache.quote_tick(instrument_id) {
+            Some(quote) => cache
+                .price(instrument_id, PriceType::Mid)
+                .map(|price| (price, quote.ts_event)),
+            None => cache
+                .trade_tick(instrument_id)
+                .map(|trade| (trade.price, trade.ts_event)),
+        };
+
+        let close = self
+            .stale_quote_threshold_ns
+            .and_then(|threshold_ns| Some((threshold_ns, cache.instrument_close(instrument_id)?)));
+        let Some((threshold_ns, close)) = close else {
+            return market.map(|(price, _)| price);
+        };
+
+        match market {
+            Some((price, ts_market))
+                if close.ts_event.as_u64() <= ts_market.as_u64().saturating_add(threshold_ns) =>
+            {
+                Some(price)
+            }
+            _ => Some(close.close_price),
+        }
+    }
+
+    /// Returns the cash locked by the resting `order` (if any).
+    fn order_locked(
+        cache: &Cache,
+        order: &OrderAny,
+        account: Option<&dyn Account>,
+    ) -> Option<Money> {
+        let price = resting_price(order)?;
+        let Some(instrument) = cache.instrument(&order.instrument_id()) else {
+            warn!(
+                "Cannot calculate locked balance for {}: no instrument {}",
+                order.client_order_id(),
+                order.instrument_id()
+            );
+            return None;
+        };
+
+        match order.order_side() {
+            OrderSide::Buy => {
+                Some(instrument.calculate_notional_value(order.leaves_qty(), price, None))
+            }
+            OrderSide::Sell if account.is_some_and(Account::is_cash_account) => instrument
+                .base_currency()
+                .map(|base| Money::new(order.leaves_qty().as_f64(), base).unwrap()),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the price the `order` would execute at, using the trigger price where the order
+/// becomes a market order once triggered.
+fn resting_price(order: &OrderAny) -> Option<Price> {
+    match order {
+        OrderAny::Limit(order) => Some(order.price),
+        OrderAny::LimitIfTouched(order) => Some(order.price),
+        OrderAny::MarketToLimit(order) => order.price,
+        OrderAny::StopLimit(order) => Some(order.price),
+        OrderAny::TrailingStopLimit(order) => Some(order.price),
+        OrderAny::MarketIfTouched(order) => Some(order.trigger_price),
+        OrderAny::StopMarket(order) => Some(order.trigger_price),
+        OrderAny::TrailingStopMarket(order) => Some(order.trigger_price),
+        OrderAny::Market(_) => None,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_accounting::account::cash::CashAccount;
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::{
+        data::{close::InstrumentClose, quote::QuoteTick},
+        enums::{InstrumentCloseType, OmsType},
+        events::{account::stubs::cash_account_state_million_usd, order::event::OrderEventAny},
+        identifiers::{
+            client_order_id::ClientOrderId, position_id::PositionId, stubs::account_id,
+            symbol::Symbol, venue::Venue,
+        },
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::default_fx_ccy},
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn fx_pair(symbol: &str) -> CurrencyPair {
+        default_fx_ccy(Symbol::from(symbol), Some(Venue::from("SIM")))
+    }
+
+    fn cache_with_account() -> Cache {
+        let mut cache = Cache::default();
+        let account = CashAccount::new(cash_account_state_million_usd(), true).unwrap();
+        cache.add_account(Box::new(account)).unwrap();
+        cache
+    }
+
+    fn add_quote(cache: &mut Cache, instrument: &CurrencyPair, bid: &str, ask: &str) {
+        let quote = QuoteTick::new(
+            instrument.id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        cache.add_quote(quote).unwrap();
+    }
+
+    fn add_position(
+        cache: &mut Cache,
+        instrument: CurrencyPair,
+        side: OrderSide,
+        quantity: i64,
+        price: &str,
+    ) {
+        let position_id = PositionId::from(format!("P-{}", instrument.id).as_str());
+        let client_order_id = ClientOrderId::from(format!("O-{}", instrument.id).as_str());
+        let order = TestOrderStubs::market_order(
+            instrument.id,
+            side,
+            Quantity::from(quantity),
+            Some(client_order_id),
+            None,
+        );
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            Some(position_id),
+            Some(Price::from(price)),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(instrument))
+            .unwrap();
+        let position = Position::new(instrument, fill).unwrap();
+        cache.add_position(position, OmsType::Netting).unwrap();
+    }
+
+    fn add_resting_order(
+        cache: &mut Cache,
+        instrument: &CurrencyPair,
+        side: OrderSide,
+        quantity: i64,
+        price: &str,
+    ) {
+        let order = TestOrderStubs::limit_order(
+            instrument.id,
+            side,
+            Price::from(price),
+            Quantity::from(quantity),
+            Some(ClientOrderId::from("O-RESTING")),
+            None,
+        );
+        let submitted = TestOrderEventStubs::order_submitted(&order, account_id()).unwrap();
+        let accepted =
+            TestOrderEventStubs::order_accepted(&order, account_id(), "V-1".into()).unwrap();
+        cache
+            .add_order(OrderAny::Limit(order), None, None, false)
+            .unwrap();
+        cache
+            .apply_order_event(OrderEventAny::Submitted(submitted))
+            .unwrap();
+        cache
+            .apply_order_event(OrderEventAny::Accepted(accepted))
+            .unwrap();
+    }
+
+    fn add_close(cache: &mut Cache, instrument: &CurrencyPair, price: &str, ts_event: u64) {
+        let close = InstrumentClose::new(
+            instrument.id,
+            Price::from(price),
+            InstrumentCloseType::EndOfSession,
+            UnixNanos::from(ts_event),
+            UnixNanos::from(ts_event),
+        );
+        cache.add_instrument_close(close).unwrap();
+    }
+
+    fn portfolio(cache: Cache, xrates: ExchangeRateCache) -> Portfolio {
+        Portfolio::new(Rc::new(RefCell::new(cache)), Arc::new(xrates))
+    }
+
+    #[rstest]
+    fn test_currency_exposures_with_shared_quote_currency_and_resting_order() {
+        let mut cache = cache_with_account();
+        let audusd = fx_pair("AUD/USD");
+        let eurusd = fx_pair("EUR/USD");
+        add_position(&mut cache, audusd, OrderSide::Buy, 100_000, "0.70000");
+        add_position(&mut cache, eurusd, OrderSide::Sell, 50_000, "1.10000");
+        add_quote(&mut cache, &audusd, "0.69990", "0.70010");
+        add_quote(&mut cache, &eurusd, "1.09990", "1.10010");
+        add_resting_order(&mut cache, &eurusd, OrderSide::Buy, 10_000, "1.09000");
+
+        let exposures = portfolio(cache, ExchangeRateCache::new()).currency_exposures();
+
+        // Long 70,000 USD of AUD/USD less short 55,000 USD of EUR/USD, netted in one row
+        assert_eq!(
+            exposures,
+            vec![CurrencyExposure {
+                currency: Currency::USD(),
+                position_value: Money::from("15000 USD"),
+                locked_by_orders: Money::from("10900 USD"),
+                free: Money::from("989100 USD"),
+                is_partial: false,
+            }]
+        );
+    }
+
+    #[rstest]
+    fn test_sell_order_on_cash_account_locks_base_quantity() {
+        let mut cache = cache_with_account();
+        let audusd = fx_pair("AUD/USD");
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd))
+            .unwrap();
+        add_resting_order(&mut cache, &audusd, OrderSide::Sell, 20_000, "0.71000");
+
+        let exposures = portfolio(cache, ExchangeRateCache::new()).currency_exposures();
+
+        assert_eq!(exposures.len(), 2);
+        assert_eq!(exposures[0].currency, Currency::AUD());
+        assert_eq!(exposures[0].locked_by_orders, Money::from("20000 AUD"));
+        assert_eq!(exposures[0].free, Money::from("-20000 AUD"));
+        assert_eq!(exposures[1].currency, Currency::USD());
+        assert_eq!(exposures[1].locked_by_orders, Money::from("0 USD"));
+    }
+
+    #[rstest]
+    fn test_position_value_converted_to_account_base_currency() {
+        let mut cache = cache_with_account();
+        let usdjpy = fx_pair("USD/JPY");
+        add_position(&mut cache, usdjpy, OrderSide::Buy, 1_000, "150.000");
+        add_quote(&mut cache, &usdjpy, "150.000", "150.000");
+        let xrates = ExchangeRateCache::new();
+        xrates.update_quote(
+            Symbol::from("USD/JPY"),
+            dec!(150),
+            dec!(150),
+            UnixNanos::default(),
+        );
+
+        let exposures = portfolio(cache, xrates).currency_exposures();
+
+        assert_eq!(exposures.len(), 1);
+        assert_eq!(exposures[0].position_value, Money::from("1000 USD"));
+        assert!(!exposures[0].is_partial);
+    }
+
+    #[rstest]
+    fn test_missing_exchange_rate_marks_row_partial() {
+        let mut cache = cache_with_account();
+        let usdjpy = fx_pair("USD/JPY");
+        add_position(&mut cache, usdjpy, OrderSide::Buy, 1_000, "150.000");
+        add_quote(&mut cache, &usdjpy, "150.000", "150.000");
+
+        let exposures = portfolio(cache, ExchangeRateCache::new()).currency_exposures();
+
+        assert_eq!(exposures.len(), 2);
+        assert_eq!(exposures[0].currency, Currency::JPY());
+        assert_eq!(exposures[0].position_value, Money::from("150000 JPY"));
+        assert!(exposures[0].is_partial);
+        assert!(!exposures[1].is_partial);
+    }
+
+    #[rstest]
+    fn test_missing_mark_price_uses_average_open_price() {
+        let mut cache = cache_with_account();
+        add_position(
+            &mut cache,
+            fx_pair("AUD/USD"),
+            OrderSide::Buy,
+            100_000,
+            "0.70000",
+        );
+
+        let exposures = portfolio(cache, ExchangeRateCache::new()).currency_exposures();
+
+        assert_eq!(exposures[0].position_value, Money::from("70000 USD"));
+        assert!(exposures[0].is_partial);
+    }
+
+    #[rstest]
+    #[case::no_threshold(None, "500 USD")]
+    #[case::quote_within_threshold(Some(20_000_000_000), "500 USD")]
+    #[case::quote_stale(Some(5_000_000_000), "1000 USD")]
+    fn test_unrealized_pnl_prefers_close_when_quote_stale(
+        #[case] threshold_ns: Option<u64>,
+        #[case] expected: &str,
+    ) {
+        let mut cache = cache_with_account();
+        let audusd = fx_pair("AUD/USD");
+        add_position(&mut cache, audusd, OrderSide::Buy, 100_000, "0.70000");
+        add_quote(&mut cache, &audusd, "0.70490", "0.70510"); // At ts 0
+        add_close(&mut cache, &audusd, "0.71000", 10_000_000_000);
+
+        let mut portfolio = portfolio(cache, ExchangeRateCache::new());
+        portfolio.set_stale_quote_threshold(threshold_ns);
+
+        assert_eq!(
+            portfolio.unrealized_pnl(&audusd.id),
+            Some(Money::from(expected))
+        );
+    }
+
+    #[rstest]
+    fn test_unrealized_pnl_uses_close_without_market_price() {
+        let mut cache = cache_with_account();
+        let audusd = fx_pair("AUD/USD");
+        add_position(&mut cache, audusd, OrderSide::Sell, 100_000, "0.70000");
+        add_close(&mut cache, &audusd, "0.69000", 1);
+
+        let mut portfolio = portfolio(cache, ExchangeRateCache::new());
+        assert_eq!(portfolio.unrealized_pnl(&audusd.id), None);
+
+        portfolio.set_stale_quote_threshold(Some(0));
+        assert_eq!(
+            portfolio.unrealized_pnl(&audusd.id),
+            Some(Money::from("1000 USD"))
+        );
+        assert_eq!(portfolio.unrealized_pnl(&fx_pair("EUR/USD").id), None);
+    }
+}
@@ -0,0 +1,1384 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides pre-trade risk checks for orders, with support for running checks in shadow mode.
+//!
+//! In shadow mode every check runs, but violations are only recorded (and published on the
+//! message bus) rather than denying the order. This allows new limits to be dry-run
+//! in production before being enforced.
+//!
+//! Fills can also arrive without passing the pre-trade checks (e.g. external fills, or fills
+//! generated by reconciliation), so post-trade position limits are checked once each fill has
+//! been applied to the cached positions. A breach cannot undo the fill, but it is recorded as a
+//! violation and can flip the strategy into reduce-only mode, which the pre-trade checks honor.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    rc::Rc,
+    sync::Arc,
+};
+
+use log::{error, warn};
+use nautilus_common::{cache::Cache, msgbus::MessageBus, xrate::ExchangeRateCache};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
+use nautilus_model::{
+    enums::{OrderSide, PriceType, TradingState},
+    events::order::{event::OrderEventAny, filled::OrderFilled},
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, strategy_id::StrategyId,
+        trader_id::TraderId,
+    },
+    instruments::Instrument,
+    orders::base::{check_expire_time, Order},
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString};
+
+use crate::reference::{ReferencePriceConfig, ReferencePriceTracker};
+
+/// The default capacity of the violation ring buffer.
+pub const DEFAULT_VIOLATION_BUFFER_CAPACITY: usize = 10_000;
+
+/// The topic prefix on which violations are published, suffixed with the strategy ID.
+pub const RISK_VIOLATION_TOPIC: &str = "events.risk.violations";
+
+/// The default maximum age of a reference price used to anchor price band checks (60 seconds).
+pub const DEFAULT_MAX_REFERENCE_AGE_NS: u64 = 60_000_000_000;
+
+/// The default minimum lifetime of a `GTD` order beyond the current time (1 second).
+pub const DEFAULT_MIN_EXPIRE_LIFETIME_NS: u64 = 1_000_000_000;
+
+/// The mode in which a risk check operates.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Display, EnumString, Serialize, Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum RiskMode {
+    /// Violations deny the order.
+    #[default]
+    Active,
+    /// Violations are recorded but the order is allowed through.
+    Shadow,
+    /// The check is not run.
+    Bypassed,
+}
+
+/// The pre-trade risk checks performed for an order.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumIter, EnumString, Serialize, Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum RiskCheck {
+    /// The current trading state permits new orders.
+    TradingState,
+    /// The order does not increase the position of a strategy in reduce-only mode.
+    ReduceOnly,
+    /// The order price and trigger price are valid for the instrument.
+    Price,
+    /// The order price is within the configured band around the instrument reference price.
+    PriceBand,
+    /// The order quantity is valid for the instrument.
+    Quantity,
+    /// The `expire_time` of a `GTD` order leaves at least the configured minimum lifetime.
+    ExpireTime,
+    /// The order notional does not exceed the configured maximum per order.
+    MaxNotionalPerOrder,
+    /// The net quantity of the open positions for an instrument does not exceed the configured
+    /// maximum (post-trade).
+    MaxNetQuantity,
+    /// The gross notional of the open positions for a strategy does not exceed the configured
+    /// maximum (post-trade).
+    MaxGrossNotional,
+}
+
+/// Configuration for the post-trade position limits.
+#[derive(Clone, Debug, Default)]
+pub struct PositionLimits {
+    /// The maximum absolute net quantity of all open positions, by instrument.
+    pub max_net_qty: HashMap<InstrumentId, Quantity>,
+    /// The maximum gross notional value of all open positions, by strategy.
+    ///
+    /// Position notionals in other currencies are converted at the mid exchange rate, and the
+    /// limit is breached when no rate is available.
+    pub max_gross_notional: HashMap<StrategyId, Money>,
+    /// If a breach in active mode flips the strategy of the fill into reduce-only mode.
+    pub reduce_only_on_breach: bool,
+}
+
+/// Configuration for `RiskEngine` instances.
+#[derive(Clone, Debug)]
+pub struct RiskEngineConfig {
+    /// The default mode for all checks.
+    pub mode: RiskMode,
+    /// Per-check mode overrides (ignored when `mode` is `Bypassed`).
+    pub check_modes: HashMap<RiskCheck, RiskMode>,
+    /// The maximum notional value per order, by instrument.
+    pub max_notional_per_order: HashMap<InstrumentId, Money>,
+    /// The maximum fractional deviation of an order price from the reference price, by instrument.
+    pub max_price_deviation: HashMap<InstrumentId, f64>,
+    /// The maximum age of a reference price for it to anchor a price band check (orders are
+    /// denied when no reference source has a price within this age).
+    pub max_reference_age_ns: u64,
+    /// The configuration for tracking reference prices.
+    pub reference_prices: ReferencePriceConfig,
+    /// The post-trade position limits.
+    pub position_limits: PositionLimits,
+    /// The minimum lifetime of a `GTD` order beyond the current time.
+    pub min_expire_lifetime_ns: u64,
+    /// The maximum number of violations retained in the ring buffer.
+    pub violation_buffer_capacity: usize,
+}
+
+impl Default for RiskEngineConfig {
+    fn default() -> Self {
+        Self {
+            mode: RiskMode::Active,
+            check_modes: HashMap::new(),
+            max_notional_per_order: HashMap::new(),
+            max_price_deviation: HashMap::new(),
+            max_reference_age_ns: DEFAULT_MAX_REFERENCE_AGE_NS,
+            reference_prices: ReferencePriceConfig::default(),
+            position_limits: PositionLimits::default(),
+            min_expire_lifetime_ns: DEFAULT_MIN_EXPIRE_LIFETIME_NS,
+            violation_buffer_capacity: DEFAULT_VIOLATION_BUFFER_CAPACITY,
+        }
+    }
+}
+
+/// Represents a risk check violation, either of a pre-trade check in shadow mode or of a
+/// post-trade position limit.
+///
+/// For post-trade violations the `client_order_id` is that of the breaching fill.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskViolation {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub check: RiskCheck,
+    /// The reason an active check would have used to deny the order.
+    pub reason: String,
+    pub ts_event: UnixNanos,
+}
+
+/// The outcome of running the risk checks for an order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RiskDecision {
+    /// The order may proceed (any shadow violations were recorded).
+    Approved,
+    /// The order must be denied for the given reason.
+    Denied(String),
+}
+
+/// Provides pre-trade risk checks for orders.
+pub struct RiskEngine {
+    clock: &'static AtomicTime,
+    config: RiskEngineConfig,
+    trading_state: TradingState,
+    cache: Option<Rc<RefCell<Cache>>>,
+    msgbus: Option<Rc<RefCell<MessageBus>>>,
+    xrates: Option<Arc<ExchangeRateCache>>,
+    reference_prices: ReferencePriceTracker,
+    violations: VecDeque<RiskViolation>,
+}
+
+impl RiskEngine {
+    #[must_use]
+    pub fn new(clock: &'static AtomicTime, config: RiskEngineConfig) -> Self {
+        let reference_prices = ReferencePriceTracker::new(clock, config.reference_prices.clone());
+        Self {
+            clock,
+            config,
+            trading_state: TradingState::Active,
+            cache: None,
+            msgbus: None,
+            xrates: None,
+            reference_prices,
+            violations: VecDeque::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(&self) -> &RiskEngineConfig {
+        &self.config
+    }
+
+    #[must_use]
+    pub fn trading_state(&self) -> TradingState {
+        self.trading_state
+    }
+
+    pub fn set_trading_state(&mut self, trading_state: TradingState) {
+        self.trading_state = trading_state;
+    }
+
+    /// Registers the `cache` holding the positions and reduce-only flags used by the
+    /// [`RiskCheck::ReduceOnly`] check and the post-trade position limits.
+    pub fn register_cache(&mut self, cache: Rc<RefCell<Cache>>) {
+        self.cache = Some(cache);
+    }
+
+    /// Registers the exchange rates used to convert position notionals into the currency of the
+    /// [`RiskCheck::MaxGrossNotional`] limit.
+    pub fn register_xrates(&mut self, xrates: Arc<ExchangeRateCache>) {
+        self.xrates = Some(xrates);
+    }
+
+    /// Returns the reference price tracker anchoring price band checks.
+    #[must_use]
+    pub fn reference_prices(&self) -> &ReferencePriceTracker {
+        &self.reference_prices
+    }
+
+    /// Returns the reference price tracker, to be fed with market data.
+    pub fn reference_prices_mut(&mut self) -> &mut ReferencePriceTracker {
+        &mut self.reference_prices
+    }
+
+    /// Sets the mode for the given `check`, overriding the default mode.
+    pub fn set_check_mode(&mut self, check: RiskCheck, mode: RiskMode) {
+        self.config.check_modes.insert(check, mode);
+    }
+
+    /// Returns the mode the given `check` currently runs in.
+    #[must_use]
+    pub fn check_mode(&self, check: RiskCheck) -> RiskMode {
+        if self.config.mode == RiskMode::Bypassed {
+            return RiskMode::Bypassed;
+        }
+        self.config
+            .check_modes
+            .get(&check)
+            .copied()
+            .unwrap_or(self.config.mode)
+    }
+
+    /// Registers the `msgbus` on which each recorded violation is published as JSON, on the
+    /// topic `events.risk.violations.{strategy_id}`.
+    pub fn register_msgbus(&mut self, msgbus: Rc<RefCell<MessageBus>>) {
+        self.msgbus = Some(msgbus);
+    }
+
+    /// Returns the recorded violations, oldest first.
+    #[must_use]
+    pub fn violations(&self) -> &VecDeque<RiskViolation> {
+        &self.violations
+    }
+
+    /// Returns the recorded violations for the given `strategy_id`, oldest first.
+    pub fn violations_for_strategy(
+        &self,
+        strategy_id: StrategyId,
+    ) -> impl Iterator<Item = &RiskViolation> {
+        self.violations
+            .iter()
+            .filter(move |violation| violation.strategy_id == strategy_id)
+    }
+
+    pub fn clear_violations(&mut self) {
+        self.violations.clear();
+    }
+
+    /// Runs the risk checks for the given `order`.
+    ///
+    /// Checks run in a fixed order. The first violation of an active check denies the order,
+    /// while violations of shadowed checks are recorded and checking continues.
+    pub fn check_order<T: Order>(
+        &mut self,
+        order: &T,
+        instrument: &dyn Instrument,
+    ) -> RiskDecision {
+        for check in [
+            RiskCheck::TradingState,
+            RiskCheck::ReduceOnly,
+            RiskCheck::Price,
+            RiskCheck::PriceBand,
+            RiskCheck::Quantity,
+            RiskCheck::ExpireTime,
+            RiskCheck::MaxNotionalPerOrder,
+        ] {
+            let mode = self.check_mode(check);
+            if mode == RiskMode::Bypassed {
+                continue;
+            }
+
+            if let Some(reason) = self.run_check(check, order, instrument) {
+                match mode {
+                    RiskMode::Active => return RiskDecision::Denied(reason),
+                    RiskMode::Shadow => self.record_violation(order, check, reason),
+                    RiskMode::Bypassed => unreachable!(),
+                }
+            }
+        }
+
+        RiskDecision::Approved
+    }
+
+    /// Processes the given order `event`, once it has been applied to the cached orders and
+    /// positions, returning any post-trade violations.
+    ///
+    /// Every fill, including external and reconciliation fills which never passed the
+    /// pre-trade checks, is checked against the post-trade position limits.
+    pub fn process(&mut self, event: &OrderEventAny) -> Vec<RiskViolation> {
+        match event {
+            OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => {
+                self.check_position_limits(fill)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Checks the post-trade position limits for the given `fill`.
+    ///
+    /// The fill has already occurred so it is never rejected. Each breach is recorded as a
+    /// violation and, in active mode with `reduce_only_on_breach` configured, the strategy of
+    /// the fill is flipped into reduce-only mode in the cache.
+    fn check_position_limits(&mut self, fill: &OrderFilled) -> Vec<RiskViolation> {
+        let Some(cache) = self.cache.clone() else {
+            error!("Cannot check position limits for {fill}: no cache registered");
+            return Vec::new();
+        };
+
+        let mut violations = Vec::new();
+        for check in [RiskCheck::MaxNetQuantity, RiskCheck::MaxGrossNotional] {
+            let mode = self.check_mode(check);
+            if mode == RiskMode::Bypassed {
+                continue;
+            }
+
+            let reason = match check {
+                RiskCheck::MaxNetQuantity => self.check_net_quantity(&cache.borrow(), fill),
+                _ => self.check_gross_notional(&cache.borrow(), fill),
+            };
+            let Some(reason) = reason else {
+                continue;
+            };
+
+            let violation = RiskViolation {
+                trader_id: fill.trader_id,
+                strategy_id: fill.strategy_id,
+                instrument_id: fill.instrument_id,
+                client_order_id: fill.client_order_id,
+                check,
+                reason,
+                ts_event: self.clock.get_time_ns(),
+            };
+            warn!(
+                "Position limit {check} breached by {}: {}",
+                violation.client_order_id, violation.reason
+            );
+
+            if mode == RiskMode::Active && self.config.position_limits.reduce_only_on_breach {
+                cache
+                    .borrow_mut()
+                    .set_strategy_reduce_only(fill.strategy_id, true);
+            }
+
+            self.push_violation(violation.clone());
+            violations.push(violation);
+        }
+
+        violations
+    }
+
+    fn record_violation<T: Order>(&mut self, order: &T, check: RiskCheck, reason: String) {
+        let violation = RiskViolation {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            check,
+            reason,
+            ts_event: self.clock.get_time_ns(),
+        };
+        warn!(
+            "Risk check {check} shadowed for {}: {}",
+            violation.client_order_id, violation.reason
+        );
+
+        self.push_violation(violation);
+    }
+
+    fn push_violation(&mut self, violation: RiskViolation) {
+        if let Some(msgbus) = &self.msgbus {
+            match serde_json::to_vec(&violation) {
+                Ok(payload) => {
+                    let topic = format!("{RISK_VIOLATION_TOPIC}.{}", violation.strategy_id);
+                    msgbus.borrow_mut().publish(&topic, payload);
+                }
+                Err(e) => error!("Cannot publish {violation:?}: {e}"),
+            }
+        }
+
+        if self.config.violation_buffer_capacity == 0 {
+            return;
+        }
+        if self.violations.len() == self.config.violation_buffer_capacity {
+            self.violations.pop_front();
+        }
+        self.violations.push_back(violation);
+    }
+
+    // -- CHECKS ----------------------------------------------------------------------------------
+
+    fn run_check<T: Order>(
+        &self,
+        check: RiskCheck,
+        order: &T,
+        instrument: &dyn Instrument,
+    ) -> Option<String> {
+        match check {
+            RiskCheck::TradingState => self.check_trading_state(),
+            RiskCheck::ReduceOnly => self.check_reduce_only(order),
+            RiskCheck::Price => self.check_order_price(order, instrument),
+            RiskCheck::PriceBand => self.check_order_price_band(order),
+            RiskCheck::Quantity => self.check_order_quantity(order, instrument),
+            RiskCheck::ExpireTime => self.check_order_expire_time(order),
+            RiskCheck::MaxNotionalPerOrder => self.check_order_notional(order, instrument),
+            // Post-trade checks are run by `check_position_limits`
+            RiskCheck::MaxNetQuantity | RiskCheck::MaxGrossNotional => None,
+        }
+    }
+
+    fn check_trading_state(&self) -> Option<String> {
+        match self.trading_state {
+            TradingState::Halted => Some("TradingState.HALTED".to_string()),
+            _ => None,
+        }
+    }
+
+    fn check_reduce_only<T: Order>(&self, order: &T) -> Option<String> {
+        let cache = self.cache.as_ref()?.borrow();
+        let strategy_id = order.strategy_id();
+        if !cache.is_strategy_reduce_only(&strategy_id) {
+            return None;
+        }
+
+        let instrument_id = order.instrument_id();
+        let net_qty: f64 = cache
+            .positions_open(None, Some(&instrument_id), Some(&strategy_id), None)
+            .iter()
+            .map(|position| position.signed_qty)
+            .sum();
+        let is_reducing = match order.side() {
+            OrderSide::Buy => net_qty < 0.0 && order.quantity().as_f64() <= -net_qty,
+            OrderSide::Sell => net_qty > 0.0 && order.quantity().as_f64() <= net_qty,
+            OrderSide::NoOrderSide => false,
+        };
+        if is_reducing {
+            return None;
+        }
+
+        Some(format!(
+            "REDUCE_ONLY: {strategy_id} may only reduce its {instrument_id} position (net_qty={net_qty})"
+        ))
+    }
+
+    fn check_order_price<T: Order>(
+        &self,
+        order: &T,
+        instrument: &dyn Instrument,
+    ) -> Option<String> {
+        if let Some(reason) = order
+            .price()
+            .and_then(|price| check_price(instrument, price))
+        {
+            return Some(reason);
+        }
+        order
+            .trigger_price()
+            .and_then(|trigger_price| check_price(instrument, trigger_price))
+            .map(|reason| format!("trigger {reason}"))
+    }
+
+    fn check_order_price_band<T: Order>(&self, order: &T) -> Option<String> {
+        let instrument_id = order.instrument_id();
+        let max_deviation = self.config.max_price_deviation.get(&instrument_id)?;
+        let price = order.price().or(order.trigger_price())?;
+
+        let max_age_ns = self.config.max_reference_age_ns;
+        let Some((reference, _)) = self.reference_prices.reference(&instrument_id, max_age_ns)
+        else {
+            return Some(format!(
+                "NO_FRESH_REFERENCE: no reference price for {instrument_id} within {max_age_ns}ns"
+            ));
+        };
+
+        let deviation = (price.as_f64() - reference.as_f64()).abs();
+        if deviation > reference.as_f64().abs() * max_deviation {
+            return Some(format!(
+                "PRICE_OUTSIDE_BAND: price={price}, reference={reference}, max_deviation={max_deviation}"
+            ));
+        }
+
+        None
+    }
+
+    fn check_order_quantity<T: Order>(
+        &self,
+        order: &T,
+        instrument: &dyn Instrument,
+    ) -> Option<String> {
+        check_quantity(instrument, order.quantity())
+    }
+
+    fn check_order_expire_time<T: Order>(&self, order: &T) -> Option<String> {
+        check_expire_time(
+            order.time_in_force(),
+            order.expire_time(),
+            self.clock.get_time_ns(),
+            self.config.min_expire_lifetime_ns,
+        )
+        .err()
+        .map(|e| e.to_string())
+    }
+
+    fn check_order_notional<T: Order>(
+        &self,
+        order: &T,
+        instrument: &dyn Instrument,
+    ) -> Option<String> {
+        let max_notional = self
+            .config
+            .max_notional_per_order
+            .get(&order.instrument_id())?;
+
+        let Some(last_px) = order.price().or(order.trigger_price()) else {
+            warn!(
+                "Cannot check {} order risk: no price for {}",
+                order.order_type(),
+                order.instrument_id()
+            );
+            return None;
+        };
+
+        let notional = instrument.calculate_notional_value(order.quantity(), last_px, Some(true));
+        if notional.currency == max_notional.currency && notional.raw > max_notional.raw {
+            return Some(format!(
+                "NOTIONAL_EXCEEDS_MAX_PER_ORDER: max_notional={max_notional}, notional={notional}"
+            ));
+        }
+
+        None
+    }
+
+    fn check_net_quantity(&self, cache: &Cache, fill: &OrderFilled) -> Option<String> {
+        let max_net_qty = self
+            .config
+            .position_limits
+            .max_net_qty
+            .get(&fill.instrument_id)?;
+
+        let net_qty: f64 = cache
+            .positions_open(None, Some(&fill.instrument_id), None, None)
+            .iter()
+            .map(|position| position.signed_qty)
+            .sum();
+        if net_qty.abs() > max_net_qty.as_f64() {
+            return Some(format!(
+                "NET_QTY_EXCEEDS_MAX: max_net_qty={max_net_qty}, net_qty={net_qty}"
+            ));
+        }
+
+        None
+    }
+
+    /// Open positions are marked at the fill price for the filled instrument, and otherwise at
+    /// the price of their last fill.
+    fn check_gross_notional(&self, cache: &Cache, fill: &OrderFilled) -> Option<String> {
+        let max_notional = self
+            .config
+            .position_limits
+            .max_gross_notional
+            .get(&fill.strategy_id)?;
+
+        let mut gross_notional = 0.0;
+        for position in cache.positions_open(None, None, Some(&fill.strategy_id), None) {
+            let last_px = if position.instrument_id == fill.instrument_id {
+                fill.last_px
+            } else {
+                position.last_event().last_px
+            };
+            let notional = position.notional_value(last_px);
+            if notional.currency == max_notional.currency {
+                gross_notional += notional.as_f64();
+                continue;
+            }
+
+            let Some(rate) = self.xrates.as_ref().and_then(|xrates| {
+                xrates
+                    .rate(notional.currency, max_notional.currency, PriceType::Mid)
+                    .and_then(|rate| rate.to_f64())
+            }) else {
+                return Some(format!(
+                    "GROSS_NOTIONAL_UNCONVERTIBLE: no exchange rate for {}/{} to convert {} notional={notional}",
+                    notional.currency, max_notional.currency, position.id
+                ));
+            };
+            gross_notional += notional.as_f64() * rate;
+        }
+        if gross_notional > max_notional.as_f64() {
+            let gross_notional = Money::new(gross_notional, max_notional.currency).ok()?;
+            return Some(format!(
+                "GROSS_NOTIONAL_EXCEEDS_MAX: max_notional={max_notional}, notional={gross_notional}"
+            ));
+        }
+
+        None
+    }
+}
+
+fn check_price(instrument: &dyn Instrument, price: Price) -> Option<String> {
+    if price.precision > instrument.price_precision() {
+        return Some(format!(
+            "price {price} invalid (precision {} > {})",
+            price.precision,
+            instrument.price_precision()
+        ));
+    }
+    if instrument.option_kind().is_none() && price.raw <= 0 {
+        return Some(format!("price {price} invalid (not positive)"));
+    }
+    None
+}
+
+fn check_quantity(instrument: &dyn Instrument, quantity: Quantity) -> Option<String> {
+    if quantity.precision > instrument.size_precision() {
+        return Some(format!(
+            "quantity {quantity} invalid (precision {} > {})",
+            quantity.precision,
+            instrument.size_precision()
+        ));
+    }
+    if let Some(max_quantity) = instrument.max_quantity() {
+        if quantity > max_quantity {
+            return Some(format!(
+                "quantity {quantity} invalid (> maximum trade size of {max_quantity})"
+            ));
+        }
+    }
+    if let Some(min_quantity) = instrument.min_quantity() {
+        if quantity < min_quantity {
+            return Some(format!(
+                "quantity {quantity} invalid (< minimum trade size of {min_quantity})"
+            ));
+        }
+    }
+    None
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use nautilus_common::{
+        handlers::{MessageHandler, SafeBusMessageCallback},
+        msgbus::BusMessage,
+    };
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        enums::OmsType,
+        events::order::filled::OrderFilledBuilder,
+        identifiers::{
+            position_id::PositionId, stubs::strategy_id_ema_cross, symbol::Symbol,
+            trade_id::TradeId,
+        },
+        instruments::{
+            currency_pair::CurrencyPair,
+            stubs::{audusd_sim, usdjpy_idealpro},
+        },
+        orders::stubs::TestOrderStubs,
+        position::Position,
+        types::currency::Currency,
+    };
+    use rstest::{fixture, rstest};
+    use rust_decimal::Decimal;
+    use ustr::Ustr;
+
+    use super::*;
+
+    #[fixture]
+    fn clock() -> &'static AtomicTime {
+        Box::leak(Box::new(AtomicTime::new(false, UnixNanos::from(1_000))))
+    }
+
+    fn engine(clock: &'static AtomicTime, mode: RiskMode) -> RiskEngine {
+        let mut config = RiskEngineConfig {
+            mode,
+            ..Default::default()
+        };
+        config.max_notional_per_order.insert(
+            audusd_sim().id,
+            Money::new(100_000.0, Currency::USD()).unwrap(),
+        );
+        RiskEngine::new(clock, config)
+    }
+
+    #[rstest]
+    fn test_order_passing_all_checks_is_approved(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let mut engine = engine(clock, RiskMode::Active);
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("0.80000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Approved
+        );
+        assert!(engine.violations().is_empty());
+    }
+
+    #[rstest]
+    fn test_violating_order_denied_in_active_mode(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let mut engine = engine(clock, RiskMode::Active);
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(200_000),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Denied(
+                "NOTIONAL_EXCEEDS_MAX_PER_ORDER: max_notional=100000.00 USD, notional=200000.00 USD"
+                    .to_string()
+            )
+        );
+        assert!(engine.violations().is_empty());
+    }
+
+    #[rstest]
+    fn test_violating_order_passes_in_shadow_mode_with_same_reason(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(200_000),
+            None,
+            None,
+        );
+        let mut active = engine(clock, RiskMode::Active);
+        let RiskDecision::Denied(active_reason) = active.check_order(&order, &audusd_sim) else {
+            panic!("expected denial in active mode");
+        };
+
+        let mut shadow = engine(clock, RiskMode::Shadow);
+        let decision = shadow.check_order(&order, &audusd_sim);
+
+        assert_eq!(decision, RiskDecision::Approved);
+        assert_eq!(shadow.violations().len(), 1);
+        let violation = &shadow.violations()[0];
+        assert_eq!(violation.check, RiskCheck::MaxNotionalPerOrder);
+        assert_eq!(violation.reason, active_reason);
+        assert_eq!(violation.client_order_id, order.client_order_id());
+        assert_eq!(violation.ts_event, UnixNanos::from(1_000));
+    }
+
+    #[rstest]
+    fn test_shadow_mode_runs_all_checks(clock: &'static AtomicTime, audusd_sim: CurrencyPair) {
+        let mut engine = engine(clock, RiskMode::Shadow);
+        engine.set_trading_state(TradingState::Halted);
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.000000"),
+            Quantity::from(200_000),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Approved
+        );
+        let checks: Vec<RiskCheck> = engine.violations().iter().map(|v| v.check).collect();
+        assert_eq!(
+            checks,
+            vec![
+                RiskCheck::TradingState,
+                RiskCheck::Price,
+                RiskCheck::MaxNotionalPerOrder
+            ]
+        );
+        assert_eq!(engine.violations()[0].reason, "TradingState.HALTED");
+        assert_eq!(
+            engine.violations()[1].reason,
+            "price 1.000000 invalid (precision 6 > 5)"
+        );
+    }
+
+    #[rstest]
+    fn test_single_check_shadowed_while_others_active(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let mut engine = engine(clock, RiskMode::Active);
+        engine.set_check_mode(RiskCheck::MaxNotionalPerOrder, RiskMode::Shadow);
+        let notional_breach = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(200_000),
+            None,
+            None,
+        );
+        let price_breach = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.000000"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&notional_breach, &audusd_sim),
+            RiskDecision::Approved
+        );
+        assert_eq!(
+            engine.check_order(&price_breach, &audusd_sim),
+            RiskDecision::Denied("price 1.000000 invalid (precision 6 > 5)".to_string())
+        );
+        assert_eq!(engine.violations().len(), 1);
+        assert_eq!(engine.check_mode(RiskCheck::Price), RiskMode::Active);
+    }
+
+    #[rstest]
+    fn test_bypassed_check_is_not_run(clock: &'static AtomicTime, audusd_sim: CurrencyPair) {
+        let mut engine = engine(clock, RiskMode::Active);
+        engine.set_check_mode(RiskCheck::TradingState, RiskMode::Bypassed);
+        engine.set_trading_state(TradingState::Halted);
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("0.80000"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Approved
+        );
+    }
+
+    #[rstest]
+    #[case(1_000, "Order `expire_time` 1000 is not after the current time 1000")]
+    #[case(
+        1_000_001_000,
+        "Order `expire_time` 1000001000 is less than the minimum lifetime of 1000000000ns from the current time 1000"
+    )]
+    fn test_gtd_order_expiring_too_soon_is_denied(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+        #[case] expire_time: u64,
+        #[case] expected: &str,
+    ) {
+        let mut engine = engine(clock, RiskMode::Active);
+        let order = TestOrderStubs::limit_order_gtd(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("0.80000"),
+            Quantity::from(100),
+            Some(UnixNanos::from(expire_time)),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Denied(expected.to_string())
+        );
+    }
+
+    #[rstest]
+    fn test_gtd_order_beyond_minimum_lifetime_is_approved(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let mut engine = engine(clock, RiskMode::Active);
+        let order = TestOrderStubs::limit_order_gtd(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("0.80000"),
+            Quantity::from(100),
+            Some(UnixNanos::from(1_000_001_001)),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Approved
+        );
+    }
+
+    #[rstest]
+    fn test_global_bypass_overrides_check_modes(clock: &'static AtomicTime) {
+        let mut engine = engine(clock, RiskMode::Bypassed);
+        engine.set_check_mode(RiskCheck::Price, RiskMode::Active);
+
+        assert_eq!(engine.check_mode(RiskCheck::Price), RiskMode::Bypassed);
+    }
+
+    #[rstest]
+    fn test_violations_published_on_msgbus_and_ring_buffer(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let mut engine = engine(clock, RiskMode::Shadow);
+        engine.config.violation_buffer_capacity = 2;
+        let msgbus = Rc::new(RefCell::new(
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap(),
+        ));
+        let published = Arc::new(Mutex::new(Vec::new()));
+        let published_clone = published.clone();
+        let handler = MessageHandler::with_bus_callback(
+            Ustr::from("violations"),
+            SafeBusMessageCallback {
+                callback: Arc::new(move |message: &BusMessage| {
+                    published_clone.lock().unwrap().push(message.clone());
+                }),
+            },
+        );
+        msgbus
+            .borrow_mut()
+            .subscribe("events.risk.violations.*", handler, None);
+        engine.register_msgbus(msgbus.clone());
+        engine.set_trading_state(TradingState::Halted);
+
+        let mut orders = Vec::new();
+        for _ in 0..3 {
+            let order = TestOrderStubs::limit_order(
+                audusd_sim.id,
+                OrderSide::Buy,
+                Price::from("0.80000"),
+                Quantity::from(100),
+                None,
+                None,
+            );
+            engine.check_order(&order, &audusd_sim);
+            orders.push(order);
+        }
+
+        let published = published.lock().unwrap();
+        assert_eq!(published.len(), 3);
+        assert_eq!(msgbus.borrow().pub_count, 3);
+        assert_eq!(
+            published[2].topic,
+            format!("events.risk.violations.{}", orders[2].strategy_id())
+        );
+        let violation: RiskViolation = serde_json::from_slice(&published[2].payload).unwrap();
+        assert_eq!(violation.check, RiskCheck::TradingState);
+        assert_eq!(violation.client_order_id, orders[2].client_order_id());
+        assert_eq!(engine.violations().len(), 2);
+        assert_eq!(
+            engine.violations()[0].client_order_id,
+            orders[1].client_order_id()
+        );
+        let strategy_id = orders[0].strategy_id();
+        assert_eq!(engine.violations_for_strategy(strategy_id).count(), 2);
+
+        engine.clear_violations();
+        assert!(engine.violations().is_empty());
+    }
+
+    fn banded_engine(clock: &'static AtomicTime) -> RiskEngine {
+        let mut engine = engine(clock, RiskMode::Active);
+        engine
+            .config
+            .max_price_deviation
+            .insert(audusd_sim().id, 0.05);
+        engine.config.max_reference_age_ns = 1_000;
+        engine
+    }
+
+    fn feed_quote(engine: &mut RiskEngine, bid: &str, ask: &str, ts: u64) {
+        let quote = QuoteTick::new(
+            audusd_sim().id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+        .unwrap();
+        engine.reference_prices_mut().handle_quote(&quote);
+    }
+
+    #[rstest]
+    fn test_off_market_quote_does_not_let_bad_order_through(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let mut engine = banded_engine(clock);
+        for _ in 0..4 {
+            feed_quote(&mut engine, "0.80000", "0.80002", 900);
+        }
+        feed_quote(&mut engine, "1.20000", "1.20002", 1_000);
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.19000"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Denied(
+                "PRICE_OUTSIDE_BAND: price=1.19000, reference=0.800010, max_deviation=0.05"
+                    .to_string()
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_order_within_band_is_approved(clock: &'static AtomicTime, audusd_sim: CurrencyPair) {
+        let mut engine = banded_engine(clock);
+        feed_quote(&mut engine, "0.80000", "0.80002", 900);
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("0.83000"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Approved
+        );
+    }
+
+    #[rstest]
+    fn test_stale_reference_denies_order(clock: &'static AtomicTime, audusd_sim: CurrencyPair) {
+        let mut engine = banded_engine(clock);
+        feed_quote(&mut engine, "0.80000", "0.80002", 0);
+        clock.set_time(UnixNanos::from(2_000));
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.19000"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Denied(
+                "NO_FRESH_REFERENCE: no reference price for AUD/USD.SIM within 1000ns".to_string()
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_stale_mark_price_falls_through_to_fresh_quote(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let mut engine = banded_engine(clock);
+        engine.reference_prices_mut().handle_mark_price(
+            audusd_sim.id,
+            Price::from("1.20000"),
+            UnixNanos::from(0),
+        );
+        clock.set_time(UnixNanos::from(2_000));
+        feed_quote(&mut engine, "0.80000", "0.80002", 1_500);
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.19000"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Denied(
+                "PRICE_OUTSIDE_BAND: price=1.19000, reference=0.800010, max_deviation=0.05"
+                    .to_string()
+            )
+        );
+    }
+
+    fn apply_fill(cache: &Rc<RefCell<Cache>>, fill: &OrderFilled) {
+        let mut cache = cache.borrow_mut();
+        let position_id = fill.position_id.unwrap();
+        if let Some(position) = cache.position(&position_id) {
+            let mut position = position.clone();
+            position.apply(fill);
+            cache.update_position(&position).unwrap();
+        } else {
+            let instrument = if fill.instrument_id == usdjpy_idealpro().id {
+                usdjpy_idealpro()
+            } else {
+                audusd_sim()
+            };
+            let position = Position::new(instrument, *fill).unwrap();
+            cache.add_position(position, OmsType::Netting).unwrap();
+        }
+    }
+
+    fn fill(
+        trade_id: &str,
+        order_side: OrderSide,
+        quantity: i64,
+        reconciliation: bool,
+    ) -> OrderFilled {
+        OrderFilledBuilder::default()
+            .strategy_id(strategy_id_ema_cross())
+            .instrument_id(audusd_sim().id)
+            .trade_id(TradeId::from(trade_id))
+            .position_id(Some(PositionId::from("P-1")))
+            .order_side(order_side)
+            .last_qty(Quantity::from(quantity))
+            .last_px(Price::from("0.80000"))
+            .commission(Some(Money::from("2 USD")))
+            .reconciliation(reconciliation)
+            .build()
+            .unwrap()
+    }
+
+    /// Returns an engine whose strategy has breached its position limit through an external fill.
+    fn breached_engine(clock: &'static AtomicTime) -> (RiskEngine, Rc<RefCell<Cache>>) {
+        let mut engine = engine(clock, RiskMode::Active);
+        engine
+            .config
+            .position_limits
+            .max_net_qty
+            .insert(audusd_sim().id, Quantity::from(150_000));
+        engine.config.position_limits.reduce_only_on_breach = true;
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        engine.register_cache(cache.clone());
+
+        let fill1 = fill("T-1", OrderSide::Buy, 100_000, false);
+        apply_fill(&cache, &fill1);
+        assert!(engine.process(&OrderEventAny::Filled(fill1)).is_empty());
+
+        let fill2 = fill("T-2", OrderSide::Buy, 100_000, true);
+        apply_fill(&cache, &fill2);
+        let violations = engine.process(&OrderEventAny::PartiallyFilled(fill2));
+        assert_eq!(violations.len(), 1);
+
+        (engine, cache)
+    }
+
+    #[rstest]
+    fn test_external_fill_breaching_limit_records_violation(clock: &'static AtomicTime) {
+        let (engine, cache) = breached_engine(clock);
+
+        assert_eq!(engine.violations().len(), 1);
+        let violation = &engine.violations()[0];
+        assert_eq!(violation.check, RiskCheck::MaxNetQuantity);
+        assert_eq!(
+            violation.reason,
+            "NET_QTY_EXCEEDS_MAX: max_net_qty=150000, net_qty=200000"
+        );
+        assert_eq!(violation.strategy_id, strategy_id_ema_cross());
+        assert!(cache
+            .borrow()
+            .is_strategy_reduce_only(&strategy_id_ema_cross()));
+    }
+
+    #[rstest]
+    fn test_shadowed_position_limit_does_not_set_reduce_only(clock: &'static AtomicTime) {
+        let mut engine = engine(clock, RiskMode::Shadow);
+        engine.config.position_limits.max_gross_notional.insert(
+            strategy_id_ema_cross(),
+            Money::new(50_000.0, Currency::USD()).unwrap(),
+        );
+        engine.config.position_limits.reduce_only_on_breach = true;
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        engine.register_cache(cache.clone());
+
+        let fill = fill("T-1", OrderSide::Sell, 100_000, true);
+        apply_fill(&cache, &fill);
+        let violations = engine.process(&OrderEventAny::Filled(fill));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].check, RiskCheck::MaxGrossNotional);
+        assert_eq!(
+            violations[0].reason,
+            "GROSS_NOTIONAL_EXCEEDS_MAX: max_notional=50000.00 USD, notional=80000.00 USD"
+        );
+        assert!(!cache
+            .borrow()
+            .is_strategy_reduce_only(&strategy_id_ema_cross()));
+    }
+
+    #[rstest]
+    fn test_reduce_only_denies_increasing_order(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let (mut engine, _cache) = breached_engine(clock);
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("0.80000"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&order, &audusd_sim),
+            RiskDecision::Denied(
+                "REDUCE_ONLY: EMACross-001 may only reduce its AUD/USD.SIM position (net_qty=200000)"
+                    .to_string()
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_reduce_only_approves_reducing_order(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let (mut engine, _cache) = breached_engine(clock);
+        let reducing = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Sell,
+            Price::from("0.80000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+        let flipping = TestOrderStubs::market_order(
+            audusd_sim.id,
+            OrderSide::Sell,
+            Quantity::from(300_000),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            engine.check_order(&reducing, &audusd_sim),
+            RiskDecision::Approved
+        );
+        assert!(matches!(
+            engine.check_order(&flipping, &audusd_sim),
+            RiskDecision::Denied(reason) if reason.starts_with("REDUCE_ONLY")
+        ));
+    }
+
+    /// Returns an engine limiting the gross notional to 80,500 USD, with an open AUD/USD position
+    /// of 80,000 USD and a fill opening a USD/JPY position of 150,000 JPY.
+    fn cross_currency_engine(clock: &'static AtomicTime) -> (RiskEngine, OrderFilled) {
+        let mut engine = engine(clock, RiskMode::Active);
+        engine.config.position_limits.max_gross_notional.insert(
+            strategy_id_ema_cross(),
+            Money::new(80_500.0, Currency::USD()).unwrap(),
+        );
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        engine.register_cache(cache.clone());
+
+        apply_fill(&cache, &fill("T-1", OrderSide::Buy, 100_000, false));
+        let jpy_fill = OrderFilledBuilder::default()
+            .strategy_id(strategy_id_ema_cross())
+            .instrument_id(usdjpy_idealpro().id)
+            .trade_id(TradeId::from("T-2"))
+            .position_id(Some(PositionId::from("P-2")))
+            .order_side(OrderSide::Buy)
+            .last_qty(Quantity::from(1_000))
+            .last_px(Price::from("150.000"))
+            .currency(Currency::JPY())
+            .commission(Some(Money::from("0 JPY")))
+            .reconciliation(true)
+            .build()
+            .unwrap();
+        apply_fill(&cache, &jpy_fill);
+
+        (engine, jpy_fill)
+    }
+
+    #[rstest]
+    fn test_gross_notional_converts_other_currencies(clock: &'static AtomicTime) {
+        let (mut engine, jpy_fill) = cross_currency_engine(clock);
+        let xrates = ExchangeRateCache::new();
+        xrates.update_quote(
+            Symbol::from("USD/JPY"),
+            Decimal::from(150),
+            Decimal::from(150),
+            UnixNanos::default(),
+        );
+        engine.register_xrates(Arc::new(xrates));
+
+        let violations = engine.process(&OrderEventAny::Filled(jpy_fill));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].reason,
+            "GROSS_NOTIONAL_EXCEEDS_MAX: max_notional=80500.00 USD, notional=81000.00 USD"
+        );
+    }
+
+    #[rstest]
+    fn test_gross_notional_without_exchange_rate_breaches(clock: &'static AtomicTime) {
+        let (mut engine, jpy_fill) = cross_currency_engine(clock);
+
+        let violations = engine.process(&OrderEventAny::Filled(jpy_fill));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].check, RiskCheck::MaxGrossNotional);
+        assert!(violations[0]
+            .reason
+            .starts_with("GROSS_NOTIONAL_UNCONVERTIBLE: no exchange rate for JPY/USD"));
+    }
+
+    #[rstest]
+    fn test_process_ignores_events_other_than_fills(clock: &'static AtomicTime) {
+        let (mut engine, _cache) = breached_engine(clock);
+        engine.clear_violations();
+
+        let violations = engine.process(&OrderEventAny::Accepted(Default::default()));
+
+        assert!(violations.is_empty());
+        assert!(engine.violations().is_empty());
+    }
+}
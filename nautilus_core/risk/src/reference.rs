@@ -0,0 +1,321 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides rolling reference prices per instrument, used to anchor price band checks.
+//!
+//! The reference for a source is the median of its last N prices, so a single bad print (such as
+//! an off-market quote) cannot move the reference by itself.
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+    identifiers::instrument_id::InstrumentId,
+    types::price::Price,
+};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString};
+
+/// The default number of recent prices per source a reference is taken over.
+pub const DEFAULT_REFERENCE_WINDOW: usize = 5;
+
+/// The source of prices for a reference.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Display, EnumIter, EnumString, Serialize, Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReferenceSource {
+    /// Mark prices published by the venue.
+    MarkPrice,
+    /// Last trade prices.
+    Trade,
+    /// Quote mid prices.
+    Quote,
+}
+
+/// Configuration for `ReferencePriceTracker` instances.
+#[derive(Clone, Debug)]
+pub struct ReferencePriceConfig {
+    /// The sources a reference is taken from, highest priority first.
+    pub sources: Vec<ReferenceSource>,
+    /// The number of recent prices per source the median is taken over.
+    pub window: usize,
+}
+
+impl Default for ReferencePriceConfig {
+    fn default() -> Self {
+        Self {
+            sources: vec![
+                ReferenceSource::MarkPrice,
+                ReferenceSource::Trade,
+                ReferenceSource::Quote,
+            ],
+            window: DEFAULT_REFERENCE_WINDOW,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct PriceWindow {
+    prices: VecDeque<Price>,
+    ts_last: UnixNanos,
+}
+
+impl PriceWindow {
+    fn push(&mut self, price: Price, ts_event: UnixNanos, capacity: usize) {
+        if self.prices.len() == capacity {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(price);
+        self.ts_last = self.ts_last.max(ts_event);
+    }
+
+    /// Returns the median price (the lower of the two middle prices for an even count).
+    fn median(&self) -> Option<Price> {
+        let mut sorted: Vec<Price> = self.prices.iter().copied().collect();
+        sorted.sort();
+        sorted.get(sorted.len().checked_sub(1)? / 2).copied()
+    }
+}
+
+/// Tracks rolling reference prices per instrument from mark prices, trades and quotes.
+pub struct ReferencePriceTracker {
+    clock: &'static AtomicTime,
+    config: ReferencePriceConfig,
+    windows: HashMap<(InstrumentId, ReferenceSource), PriceWindow>,
+}
+
+impl ReferencePriceTracker {
+    #[must_use]
+    pub fn new(clock: &'static AtomicTime, config: ReferencePriceConfig) -> Self {
+        Self {
+            clock,
+            config,
+            windows: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(&self) -> &ReferencePriceConfig {
+        &self.config
+    }
+
+    /// Adds the given `price` from `source` to the window for `instrument_id`.
+    pub fn update(
+        &mut self,
+        instrument_id: InstrumentId,
+        source: ReferenceSource,
+        price: Price,
+        ts_event: UnixNanos,
+    ) {
+        if self.config.window == 0 {
+            return;
+        }
+        self.windows
+            .entry((instrument_id, source))
+            .or_default()
+            .push(price, ts_event, self.config.window);
+    }
+
+    pub fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update(
+            quote.instrument_id,
+            ReferenceSource::Quote,
+            quote.extract_price(PriceType::Mid),
+            quote.ts_event,
+        );
+    }
+
+    pub fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update(
+            trade.instrument_id,
+            ReferenceSource::Trade,
+            trade.price,
+            trade.ts_event,
+        );
+    }
+
+    pub fn handle_mark_price(
+        &mut self,
+        instrument_id: InstrumentId,
+        price: Price,
+        ts_event: UnixNanos,
+    ) {
+        self.update(instrument_id, ReferenceSource::MarkPrice, price, ts_event);
+    }
+
+    /// Returns the reference price for `instrument_id` from the highest priority source with
+    /// fresh prices, along with its age in nanoseconds (since the latest price from that source).
+    ///
+    /// A source is stale if its latest price is older than `max_age_ns`, in which case the next
+    /// source is used. Returns `None` if no source has fresh prices.
+    #[must_use]
+    pub fn reference(&self, instrument_id: &InstrumentId, max_age_ns: u64) -> Option<(Price, u64)> {
+        let ts_now = self.clock.get_time_ns().as_u64();
+        self.config.sources.iter().find_map(|source| {
+            let window = self.windows.get(&(*instrument_id, *source))?;
+            let age_ns = ts_now.saturating_sub(window.ts_last.as_u64());
+            if age_ns > max_age_ns {
+                return None;
+            }
+            Some((window.median()?, age_ns))
+        })
+    }
+
+    /// Clears the prices for `instrument_id` from all sources.
+    pub fn reset(&mut self, instrument_id: &InstrumentId) {
+        self.windows.retain(|(id, _), _| id != instrument_id);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::AggressorSide,
+        identifiers::trade_id::TradeId,
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        types::quantity::Quantity,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn clock() -> &'static AtomicTime {
+        Box::leak(Box::new(AtomicTime::new(false, UnixNanos::from(1_000))))
+    }
+
+    fn trade(instrument_id: InstrumentId, price: &str, ts: u64) -> TradeTick {
+        TradeTick::new(
+            instrument_id,
+            Price::from(price),
+            Quantity::from(1_000),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+        .unwrap()
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ask: &str, ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_reference_with_no_prices(clock: &'static AtomicTime, audusd_sim: CurrencyPair) {
+        let tracker = ReferencePriceTracker::new(clock, ReferencePriceConfig::default());
+
+        assert!(tracker.reference(&audusd_sim.id, u64::MAX).is_none());
+    }
+
+    #[rstest]
+    fn test_single_off_market_quote_does_not_move_reference(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let mut tracker = ReferencePriceTracker::new(clock, ReferencePriceConfig::default());
+        for (bid, ask) in [
+            ("0.80000", "0.80002"),
+            ("0.80001", "0.80003"),
+            ("0.80002", "0.80004"),
+            ("0.80001", "0.80003"),
+        ] {
+            tracker.handle_quote(&quote(audusd_sim.id, bid, ask, 500));
+        }
+        tracker.handle_quote(&quote(audusd_sim.id, "1.20000", "1.20002", 900));
+
+        let (reference, age_ns) = tracker.reference(&audusd_sim.id, u64::MAX).unwrap();
+
+        assert_eq!(reference, Price::from("0.800020"));
+        assert_eq!(age_ns, 100);
+    }
+
+    #[rstest]
+    fn test_window_rolls_off_oldest_prices(clock: &'static AtomicTime, audusd_sim: CurrencyPair) {
+        let config = ReferencePriceConfig {
+            window: 3,
+            ..Default::default()
+        };
+        let mut tracker = ReferencePriceTracker::new(clock, config);
+        for price in ["0.70000", "0.80000", "0.81000", "0.82000"] {
+            tracker.handle_mark_price(audusd_sim.id, Price::from(price), UnixNanos::from(1_000));
+        }
+
+        let (reference, _) = tracker.reference(&audusd_sim.id, u64::MAX).unwrap();
+
+        assert_eq!(reference, Price::from("0.81000"));
+    }
+
+    #[rstest]
+    fn test_reference_uses_source_priority(clock: &'static AtomicTime, audusd_sim: CurrencyPair) {
+        let mut tracker = ReferencePriceTracker::new(clock, ReferencePriceConfig::default());
+        tracker.handle_quote(&quote(audusd_sim.id, "0.80000", "0.80002", 1_000));
+        tracker.handle_trade(&trade(audusd_sim.id, "0.80500", 400));
+
+        assert_eq!(
+            tracker.reference(&audusd_sim.id, u64::MAX),
+            Some((Price::from("0.80500"), 600))
+        );
+
+        tracker.handle_mark_price(audusd_sim.id, Price::from("0.80300"), UnixNanos::from(800));
+        assert_eq!(
+            tracker.reference(&audusd_sim.id, u64::MAX),
+            Some((Price::from("0.80300"), 200))
+        );
+
+        tracker.reset(&audusd_sim.id);
+        assert!(tracker.reference(&audusd_sim.id, u64::MAX).is_none());
+    }
+
+    #[rstest]
+    fn test_stale_source_falls_through_to_next_fresh_source(
+        clock: &'static AtomicTime,
+        audusd_sim: CurrencyPair,
+    ) {
+        let config = ReferencePriceConfig {
+            sources: vec![ReferenceSource::Quote, ReferenceSource::Trade],
+            ..Default::default()
+        };
+        let mut tracker = ReferencePriceTracker::new(clock, config);
+        tracker.handle_quote(&quote(audusd_sim.id, "0.80000", "0.80002", 0));
+        tracker.handle_trade(&trade(audusd_sim.id, "0.80500", 900));
+
+        assert_eq!(
+            tracker.reference(&audusd_sim.id, 500),
+            Some((Price::from("0.80500"), 100))
+        );
+        assert_eq!(
+            tracker.reference(&audusd_sim.id, 1_000),
+            Some((Price::from("0.800010"), 1_000))
+        );
+        assert!(tracker.reference(&audusd_sim.id, 50).is_none());
+    }
+}
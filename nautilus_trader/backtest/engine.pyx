@@ -1027,6 +1027,7 @@ cdef class BacktestEngine:
         cdef TestClock clock
         for clock in get_component_clocks(self._instance_id):
             clock.set_time(start_ns)
+            clock.set_schedule_end(end_ns)
 
         cdef SimulatedExchange exchange
         if self._iteration == 0:
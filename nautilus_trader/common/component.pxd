@@ -91,6 +91,7 @@ cdef class TestClock(Clock):
     cdef TestClock_API _mem
 
     cpdef void set_time(self, uint64_t to_time_ns)
+    cpdef void set_schedule_end(self, uint64_t end_ns)
     cdef CVec advance_time_c(self, uint64_t to_time_ns, bint set_time=*)
     cpdef list advance_time(self, uint64_t to_time_ns, bint set_time=*)
 
@@ -97,6 +97,7 @@ from nautilus_trader.core.rust.common cimport test_clock_drop
 from nautilus_trader.core.rust.common cimport test_clock_new
 from nautilus_trader.core.rust.common cimport test_clock_next_time
 from nautilus_trader.core.rust.common cimport test_clock_register_default_handler
+from nautilus_trader.core.rust.common cimport test_clock_set_schedule_end
 from nautilus_trader.core.rust.common cimport test_clock_set_time
 from nautilus_trader.core.rust.common cimport test_clock_set_time_alert
 from nautilus_trader.core.rust.common cimport test_clock_set_timer
@@ -639,6 +640,19 @@ cdef class TestClock(Clock):
         """
         test_clock_set_time(&self._mem, to_time_ns)
 
+    cpdef void set_schedule_end(self, uint64_t end_ns):
+        """
+        Precompute the timer fire times up to the given end time, so that advancing the
+        clock only checks the timers when a fire time is crossed.
+
+        Parameters
+        ----------
+        end_ns : uint64_t
+            The UNIX time (nanoseconds) to precompute the fire times up to.
+
+        """
+        test_clock_set_schedule_end(&self._mem, end_ns)
+
     cdef CVec advance_time_c(self, uint64_t to_time_ns, bint set_time=True):
         Condition.true(to_time_ns >= test_clock_timestamp_ns(&self._mem), "to_time_ns was < time_ns (not monotonic)")
 
@@ -326,6 +326,8 @@ void test_clock_register_default_handler(struct TestClock_API *clock, PyObject *
 
 void test_clock_set_time(const struct TestClock_API *clock, uint64_t to_time_ns);
 
+void test_clock_set_schedule_end(struct TestClock_API *clock, uint64_t end_ns);
+
 double test_clock_timestamp(const struct TestClock_API *clock);
 
 uint64_t test_clock_timestamp_ms(const struct TestClock_API *clock);
@@ -13,6 +13,44 @@
 
 #define NANOSECONDS_IN_MICROSECOND 1000
 
+/**
+ * The length of a record envelope header (marker, payload length and checksum).
+ */
+#define RECORD_HEADER_LEN 12
+
+/**
+ * The maximum payload length of a record (16 MiB).
+ */
+#define MAX_RECORD_LEN ((16 * 1024) * 1024)
+
+/**
+ * The maximum length (bytes) of an identifier C string.
+ */
+#define IDENTIFIER_MAX_LEN 256
+
+/**
+ * The maximum length (bytes) of a free text C string, such as a reason.
+ */
+#define TEXT_MAX_LEN 4096
+
+/**
+ * The character set policy for validating a C string received over FFI.
+ */
+typedef enum CharsetPolicy {
+    /**
+     * Printable ASCII with no whitespace.
+     */
+    IDENTIFIER = 1,
+    /**
+     * Printable ASCII with interior spaces only (such as option symbols).
+     */
+    SYMBOL = 2,
+    /**
+     * Any valid UTF-8.
+     */
+    TEXT = 3,
+} CharsetPolicy;
+
 /**
  * `CVec` is a C compatible struct that stores an opaque pointer to a block of
  * memory, it's length and the capacity of the vector it was allocated from.
@@ -105,6 +143,16 @@ const char *unix_nanos_to_iso8601_cstr(uint64_t timestamp_ns);
  */
 uint8_t precision_from_cstr(const char *ptr);
 
+/**
+ * Returns any validation error for the C string pointer against the `max_len` (bytes) and
+ * `charset` policy, otherwise an empty string.
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ */
+const char *cstr_check_valid(const char *ptr, uintptr_t max_len, enum CharsetPolicy charset);
+
 /**
  * Drops the C string memory at the pointer.
  *
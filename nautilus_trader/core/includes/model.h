@@ -7,6 +7,16 @@
 
 #define DEPTH10_LEN 10
 
+/**
+ * The default bucket granularity (1 second).
+ */
+#define DEFAULT_GRANULARITY_NS 1000000000
+
+/**
+ * The current version of the binary encoding.
+ */
+#define LADDER_SNAPSHOT_VERSION 1
+
 #define FIXED_PRECISION 9
 
 #define FIXED_SCALAR 1000000000.0
@@ -23,6 +33,11 @@
 
 #define QUANTITY_MIN 0.0
 
+/**
+ * The raw fixed-point representation of `QUANTITY_MAX`.
+ */
+#define QUANTITY_MAX_RAW 18446744073000000000ull
+
 /**
  * An account type provided by a trading venue or broker.
  */
@@ -721,6 +736,16 @@ typedef struct OrderBook OrderBook;
  */
 typedef struct OrderBookDeltas_t OrderBookDeltas_t;
 
+typedef struct OrderCanceled_t OrderCanceled_t;
+
+typedef struct OrderExpired_t OrderExpired_t;
+
+typedef struct OrderFilled_t OrderFilled_t;
+
+typedef struct OrderTriggered_t OrderTriggered_t;
+
+typedef struct OrderUpdated_t OrderUpdated_t;
+
 /**
  * Represents a synthetic instrument with prices derived from component instruments using a
  * formula.
@@ -1048,6 +1073,33 @@ typedef struct Bar_t {
     uint64_t ts_init;
 } Bar_t;
 
+/**
+ * Represents an official close price for an instrument, such as a daily settlement price or
+ * the final settlement price at contract expiry (distinct from the last traded price).
+ */
+typedef struct InstrumentClose {
+    /**
+     * The instrument ID for the close.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The close price for the instrument.
+     */
+    struct Price_t close_price;
+    /**
+     * The type of the close.
+     */
+    enum InstrumentCloseType close_type;
+    /**
+     * The UNIX timestamp (nanoseconds) when the close event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * The UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} InstrumentClose;
+
 /**
  * A built-in Nautilus data type.
  *
@@ -1061,6 +1113,7 @@ typedef enum Data_t_Tag {
     QUOTE,
     TRADE,
     BAR,
+    CLOSE,
 } Data_t_Tag;
 
 typedef struct Data_t {
@@ -1084,6 +1137,9 @@ typedef struct Data_t {
         struct {
             struct Bar_t bar;
         };
+        struct {
+            struct InstrumentClose close;
+        };
     };
 } Data_t;
 
@@ -1136,6 +1192,17 @@ typedef struct OrderDenied_t {
     uint64_t ts_init;
 } OrderDenied_t;
 
+/**
+ * The result of constructing an `OrderDenied` across the FFI boundary.
+ *
+ * On failure `value` is a default event and `error` points to a C string describing the
+ * failure, which must be freed with `cstr_drop`. On success `error` is null.
+ */
+typedef struct OrderDeniedResult {
+    struct OrderDenied_t value;
+    const char *error;
+} OrderDeniedResult;
+
 typedef struct OrderEmulated_t {
     struct TraderId_t trader_id;
     struct StrategyId_t strategy_id;
@@ -1170,6 +1237,23 @@ typedef struct AccountId_t {
     char* _0;
 } AccountId_t;
 
+/**
+ * Represents an optional ID correlating an order event with the command which originated it.
+ *
+ * `Option<UUID4>` has no C layout, so the value is held alongside an explicit flag which
+ * allows the order event structs carrying it to cross the FFI boundary.
+ */
+typedef struct CorrelationId_t {
+    /**
+     * The correlation ID value (only meaningful when `is_set` is true).
+     */
+    UUID4_t value;
+    /**
+     * If the correlation ID is set.
+     */
+    bool is_set;
+} CorrelationId_t;
+
 typedef struct OrderSubmitted_t {
     struct TraderId_t trader_id;
     struct StrategyId_t strategy_id;
@@ -1179,6 +1263,10 @@ typedef struct OrderSubmitted_t {
     UUID4_t event_id;
     uint64_t ts_event;
     uint64_t ts_init;
+    /**
+     * The ID correlating the event with the command which originated it.
+     */
+    struct CorrelationId_t correlation_id;
 } OrderSubmitted_t;
 
 /**
@@ -1199,6 +1287,10 @@ typedef struct OrderAccepted_t {
     uint64_t ts_event;
     uint64_t ts_init;
     uint8_t reconciliation;
+    /**
+     * The ID correlating the event with the command which originated it.
+     */
+    struct CorrelationId_t correlation_id;
 } OrderAccepted_t;
 
 typedef struct OrderRejected_t {
@@ -1212,8 +1304,105 @@ typedef struct OrderRejected_t {
     uint64_t ts_event;
     uint64_t ts_init;
     uint8_t reconciliation;
+    /**
+     * The ID correlating the event with the command which originated it.
+     */
+    struct CorrelationId_t correlation_id;
 } OrderRejected_t;
 
+/**
+ * The result of constructing an `OrderRejected` across the FFI boundary.
+ *
+ * On failure `value` is a default event and `error` points to a C string describing the
+ * failure, which must be freed with `cstr_drop`. On success `error` is null.
+ */
+typedef struct OrderRejectedResult {
+    struct OrderRejected_t value;
+    const char *error;
+} OrderRejectedResult;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderCanceled`.
+ *
+ * The `Option` fields of `OrderCanceled` have no C layout, so the event is held behind a pointer
+ * and must be freed with `order_canceled_drop`.
+ */
+typedef struct OrderCanceled_API {
+    struct OrderCanceled_t *_0;
+} OrderCanceled_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderExpired`.
+ *
+ * The `Option` fields of `OrderExpired` have no C layout, so the event is held behind a pointer
+ * and must be freed with `order_expired_drop`.
+ */
+typedef struct OrderExpired_API {
+    struct OrderExpired_t *_0;
+} OrderExpired_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderTriggered`.
+ *
+ * The `Option` fields of `OrderTriggered` have no C layout, so the event is held behind a pointer
+ * and must be freed with `order_triggered_drop`.
+ */
+typedef struct OrderTriggered_API {
+    struct OrderTriggered_t *_0;
+} OrderTriggered_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderUpdated`.
+ *
+ * The `Option` fields of `OrderUpdated` have no C layout, so the event is held behind a pointer
+ * and must be freed with `order_updated_drop`.
+ */
+typedef struct OrderUpdated_API {
+    struct OrderUpdated_t *_0;
+} OrderUpdated_API;
+
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderFilled`.
+ *
+ * The `Option` fields of `OrderFilled` have no C layout, so the event is held behind a pointer
+ * and must be freed with `order_filled_drop`.
+ */
+typedef struct OrderFilled_API {
+    struct OrderFilled_t *_0;
+} OrderFilled_API;
+
+/**
+ * The result of creating an `OrderFilled` across the FFI boundary.
+ *
+ * On failure `value` holds a default event and `error` points to a C string describing the
+ * failure, which must be freed with `cstr_drop`. On success `error` is null. In both cases
+ * `value` must be freed with `order_filled_drop`.
+ */
+typedef struct OrderFilledResult {
+    struct OrderFilled_API value;
+    const char *error;
+} OrderFilledResult;
+
+typedef struct Currency_t {
+    char* code;
+    uint8_t precision;
+    uint16_t iso4217;
+    char* name;
+    enum CurrencyType currency_type;
+} Currency_t;
+
+/**
+ * Represents a valid position ID.
+ */
+typedef struct PositionId_t {
+    char* _0;
+} PositionId_t;
+
+typedef struct Money_t {
+    int64_t raw;
+    struct Currency_t currency;
+} Money_t;
+
 /**
  * Represents a system client ID.
  */
@@ -1242,13 +1431,6 @@ typedef struct OrderListId_t {
     char* _0;
 } OrderListId_t;
 
-/**
- * Represents a valid position ID.
- */
-typedef struct PositionId_t {
-    char* _0;
-} PositionId_t;
-
 /**
  * Provides a C compatible Foreign Function Interface (FFI) for an underlying
  * [`SyntheticInstrument`].
@@ -1292,19 +1474,6 @@ typedef struct Level_API {
     struct Level *_0;
 } Level_API;
 
-typedef struct Currency_t {
-    char* code;
-    uint8_t precision;
-    uint16_t iso4217;
-    char* name;
-    enum CurrencyType currency_type;
-} Currency_t;
-
-typedef struct Money_t {
-    int64_t raw;
-    struct Currency_t currency;
-} Money_t;
-
 #define NULL_ORDER (BookOrder_t){ .side = OrderSide_NoOrderSide, .price = (Price_t){ .raw = 0, .precision = 0 }, .size = (Quantity_t){ .raw = 0, .precision = 0 }, .order_id = 0 }
 
 /**
@@ -1507,17 +1676,32 @@ const char *book_order_display_to_cstr(const struct BookOrder_t *order);
  */
 const char *book_order_debug_to_cstr(const struct BookOrder_t *order);
 
-struct QuoteTick_t quote_tick_new(struct InstrumentId_t instrument_id,
-                                  int64_t bid_price_raw,
-                                  int64_t ask_price_raw,
-                                  uint8_t bid_price_prec,
-                                  uint8_t ask_price_prec,
-                                  uint64_t bid_size_raw,
-                                  uint64_t ask_size_raw,
-                                  uint8_t bid_size_prec,
-                                  uint8_t ask_size_prec,
-                                  uint64_t ts_event,
-                                  uint64_t ts_init);
+/**
+ * Writes a new `QuoteTick` constructed from the given raw values to `out`.
+ *
+ * The quote invariants are only checked when `validate` is non-zero, so quotes with an empty
+ * side or a crossed top level can still be loaded from raw data.
+ *
+ * Returns null on success, otherwise a C string describing why the quote is invalid, which must
+ * be freed with `cstr_drop`.
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to write a `QuoteTick` to.
+ */
+const char *quote_tick_new(struct InstrumentId_t instrument_id,
+                           int64_t bid_price_raw,
+                           int64_t ask_price_raw,
+                           uint8_t bid_price_prec,
+                           uint8_t ask_price_prec,
+                           uint64_t bid_size_raw,
+                           uint64_t ask_size_raw,
+                           uint8_t bid_size_prec,
+                           uint8_t ask_size_prec,
+                           uint64_t ts_event,
+                           uint64_t ts_init,
+                           uint8_t validate,
+                           struct QuoteTick_t *out);
 
 uint8_t quote_tick_eq(const struct QuoteTick_t *lhs, const struct QuoteTick_t *rhs);
 
@@ -1528,15 +1712,29 @@ uint64_t quote_tick_hash(const struct QuoteTick_t *delta);
  */
 const char *quote_tick_to_cstr(const struct QuoteTick_t *tick);
 
-struct TradeTick_t trade_tick_new(struct InstrumentId_t instrument_id,
-                                  int64_t price_raw,
-                                  uint8_t price_prec,
-                                  uint64_t size_raw,
-                                  uint8_t size_prec,
-                                  enum AggressorSide aggressor_side,
-                                  struct TradeId_t trade_id,
-                                  uint64_t ts_event,
-                                  uint64_t ts_init);
+/**
+ * Writes a new `TradeTick` constructed from the given raw values to `out`.
+ *
+ * The trade invariants are only checked when `validate` is non-zero.
+ *
+ * Returns null on success, otherwise a C string describing why the trade is invalid, which must
+ * be freed with `cstr_drop`.
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to write a `TradeTick` to.
+ */
+const char *trade_tick_new(struct InstrumentId_t instrument_id,
+                           int64_t price_raw,
+                           uint8_t price_prec,
+                           uint64_t size_raw,
+                           uint8_t size_prec,
+                           enum AggressorSide aggressor_side,
+                           struct TradeId_t trade_id,
+                           uint64_t ts_event,
+                           uint64_t ts_init,
+                           uint8_t validate,
+                           struct TradeTick_t *out);
 
 uint8_t trade_tick_eq(const struct TradeTick_t *lhs, const struct TradeTick_t *rhs);
 
@@ -1834,18 +2032,20 @@ const char *trigger_type_to_cstr(enum TriggerType value);
 enum TriggerType trigger_type_from_cstr(const char *ptr);
 
 /**
+ * Returns a new `OrderDenied` event, or an error if `reason_ptr` is not a valid reason.
+ *
  * # Safety
  *
- * - Assumes `reason_ptr` is a valid C string pointer.
+ * - Assumes `reason_ptr` is a valid C string pointer or null.
  */
-struct OrderDenied_t order_denied_new(struct TraderId_t trader_id,
-                                      struct StrategyId_t strategy_id,
-                                      struct InstrumentId_t instrument_id,
-                                      struct ClientOrderId_t client_order_id,
-                                      const char *reason_ptr,
-                                      UUID4_t event_id,
-                                      uint64_t ts_event,
-                                      uint64_t ts_init);
+struct OrderDeniedResult order_denied_new(struct TraderId_t trader_id,
+                                          struct StrategyId_t strategy_id,
+                                          struct InstrumentId_t instrument_id,
+                                          struct ClientOrderId_t client_order_id,
+                                          const char *reason_ptr,
+                                          UUID4_t event_id,
+                                          uint64_t ts_event,
+                                          uint64_t ts_init);
 
 struct OrderEmulated_t order_emulated_new(struct TraderId_t trader_id,
                                           struct StrategyId_t strategy_id,
@@ -1884,74 +2084,465 @@ struct OrderAccepted_t order_accepted_new(struct TraderId_t trader_id,
                                           uint64_t ts_init,
                                           uint8_t reconciliation);
 
+/**
+ * Returns a new `OrderRejected` event, or an error if `reason_ptr` is not a valid reason.
+ *
+ * # Safety
+ *
+ * - Assumes `reason_ptr` is a valid C string pointer or null.
+ */
+struct OrderRejectedResult order_rejected_new(struct TraderId_t trader_id,
+                                              struct StrategyId_t strategy_id,
+                                              struct InstrumentId_t instrument_id,
+                                              struct ClientOrderId_t client_order_id,
+                                              struct AccountId_t account_id,
+                                              const char *reason_ptr,
+                                              UUID4_t event_id,
+                                              uint64_t ts_event,
+                                              uint64_t ts_init,
+                                              uint8_t reconciliation);
+
+/**
+ * # Safety
+ *
+ * - Assumes `venue_order_id_ptr` and `account_id_ptr` are either null (for `None`) or valid
+ *   pointers.
+ */
+struct OrderCanceled_API order_canceled_new(struct TraderId_t trader_id,
+                                            struct StrategyId_t strategy_id,
+                                            struct InstrumentId_t instrument_id,
+                                            struct ClientOrderId_t client_order_id,
+                                            const struct VenueOrderId_t *venue_order_id_ptr,
+                                            const struct AccountId_t *account_id_ptr,
+                                            UUID4_t event_id,
+                                            uint64_t ts_event,
+                                            uint64_t ts_init,
+                                            uint8_t reconciliation);
+
+void order_canceled_drop(struct OrderCanceled_API event);
+
+struct OrderCanceled_API order_canceled_clone(const struct OrderCanceled_API *event);
+
+struct TraderId_t order_canceled_trader_id(const struct OrderCanceled_API *event);
+
+struct StrategyId_t order_canceled_strategy_id(const struct OrderCanceled_API *event);
+
+struct InstrumentId_t order_canceled_instrument_id(const struct OrderCanceled_API *event);
+
+struct ClientOrderId_t order_canceled_client_order_id(const struct OrderCanceled_API *event);
+
+UUID4_t order_canceled_event_id(const struct OrderCanceled_API *event);
+
+uint64_t order_canceled_ts_event(const struct OrderCanceled_API *event);
+
+uint64_t order_canceled_ts_init(const struct OrderCanceled_API *event);
+
+uint8_t order_canceled_reconciliation(const struct OrderCanceled_API *event);
+
+/**
+ * Returns a pointer to the venue order ID of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct VenueOrderId_t *order_canceled_venue_order_id(const struct OrderCanceled_API *event);
+
+/**
+ * Returns a pointer to the account ID of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct AccountId_t *order_canceled_account_id(const struct OrderCanceled_API *event);
+
 /**
  * # Safety
  *
- * - Assumes `reason_ptr` is a valid C string pointer.
+ * - Assumes `venue_order_id_ptr` and `account_id_ptr` are either null (for `None`) or valid
+ *   pointers.
  */
-struct OrderRejected_t order_rejected_new(struct TraderId_t trader_id,
+struct OrderExpired_API order_expired_new(struct TraderId_t trader_id,
                                           struct StrategyId_t strategy_id,
                                           struct InstrumentId_t instrument_id,
                                           struct ClientOrderId_t client_order_id,
-                                          struct AccountId_t account_id,
-                                          const char *reason_ptr,
+                                          const struct VenueOrderId_t *venue_order_id_ptr,
+                                          const struct AccountId_t *account_id_ptr,
+                                          UUID4_t event_id,
+                                          uint64_t ts_event,
+                                          uint64_t ts_init,
+                                          uint8_t reconciliation);
+
+void order_expired_drop(struct OrderExpired_API event);
+
+struct OrderExpired_API order_expired_clone(const struct OrderExpired_API *event);
+
+struct TraderId_t order_expired_trader_id(const struct OrderExpired_API *event);
+
+struct StrategyId_t order_expired_strategy_id(const struct OrderExpired_API *event);
+
+struct InstrumentId_t order_expired_instrument_id(const struct OrderExpired_API *event);
+
+struct ClientOrderId_t order_expired_client_order_id(const struct OrderExpired_API *event);
+
+UUID4_t order_expired_event_id(const struct OrderExpired_API *event);
+
+uint64_t order_expired_ts_event(const struct OrderExpired_API *event);
+
+uint64_t order_expired_ts_init(const struct OrderExpired_API *event);
+
+uint8_t order_expired_reconciliation(const struct OrderExpired_API *event);
+
+/**
+ * Returns a pointer to the venue order ID of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct VenueOrderId_t *order_expired_venue_order_id(const struct OrderExpired_API *event);
+
+/**
+ * Returns a pointer to the account ID of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct AccountId_t *order_expired_account_id(const struct OrderExpired_API *event);
+
+/**
+ * # Safety
+ *
+ * - Assumes `venue_order_id_ptr` and `account_id_ptr` are either null (for `None`) or valid
+ *   pointers.
+ */
+struct OrderTriggered_API order_triggered_new(struct TraderId_t trader_id,
+                                              struct StrategyId_t strategy_id,
+                                              struct InstrumentId_t instrument_id,
+                                              struct ClientOrderId_t client_order_id,
+                                              const struct VenueOrderId_t *venue_order_id_ptr,
+                                              const struct AccountId_t *account_id_ptr,
+                                              UUID4_t event_id,
+                                              uint64_t ts_event,
+                                              uint64_t ts_init,
+                                              uint8_t reconciliation);
+
+void order_triggered_drop(struct OrderTriggered_API event);
+
+struct OrderTriggered_API order_triggered_clone(const struct OrderTriggered_API *event);
+
+struct TraderId_t order_triggered_trader_id(const struct OrderTriggered_API *event);
+
+struct StrategyId_t order_triggered_strategy_id(const struct OrderTriggered_API *event);
+
+struct InstrumentId_t order_triggered_instrument_id(const struct OrderTriggered_API *event);
+
+struct ClientOrderId_t order_triggered_client_order_id(const struct OrderTriggered_API *event);
+
+UUID4_t order_triggered_event_id(const struct OrderTriggered_API *event);
+
+uint64_t order_triggered_ts_event(const struct OrderTriggered_API *event);
+
+uint64_t order_triggered_ts_init(const struct OrderTriggered_API *event);
+
+uint8_t order_triggered_reconciliation(const struct OrderTriggered_API *event);
+
+/**
+ * Returns a pointer to the venue order ID of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct VenueOrderId_t *order_triggered_venue_order_id(const struct OrderTriggered_API *event);
+
+/**
+ * Returns a pointer to the account ID of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct AccountId_t *order_triggered_account_id(const struct OrderTriggered_API *event);
+
+/**
+ * # Safety
+ *
+ * - Assumes `venue_order_id_ptr`, `account_id_ptr`, `price_ptr`, `trigger_price_ptr`,
+ *   `prev_quantity_ptr`, `prev_price_ptr` and `prev_trigger_price_ptr` are either null
+ *   (for `None`) or valid pointers.
+ */
+struct OrderUpdated_API order_updated_new(struct TraderId_t trader_id,
+                                          struct StrategyId_t strategy_id,
+                                          struct InstrumentId_t instrument_id,
+                                          struct ClientOrderId_t client_order_id,
+                                          const struct VenueOrderId_t *venue_order_id_ptr,
+                                          const struct AccountId_t *account_id_ptr,
+                                          struct Quantity_t quantity,
+                                          const struct Price_t *price_ptr,
+                                          const struct Price_t *trigger_price_ptr,
+                                          const struct Quantity_t *prev_quantity_ptr,
+                                          const struct Price_t *prev_price_ptr,
+                                          const struct Price_t *prev_trigger_price_ptr,
                                           UUID4_t event_id,
                                           uint64_t ts_event,
                                           uint64_t ts_init,
                                           uint8_t reconciliation);
 
+void order_updated_drop(struct OrderUpdated_API event);
+
+struct OrderUpdated_API order_updated_clone(const struct OrderUpdated_API *event);
+
+struct TraderId_t order_updated_trader_id(const struct OrderUpdated_API *event);
+
+struct StrategyId_t order_updated_strategy_id(const struct OrderUpdated_API *event);
+
+struct InstrumentId_t order_updated_instrument_id(const struct OrderUpdated_API *event);
+
+struct ClientOrderId_t order_updated_client_order_id(const struct OrderUpdated_API *event);
+
+struct Quantity_t order_updated_quantity(const struct OrderUpdated_API *event);
+
+UUID4_t order_updated_event_id(const struct OrderUpdated_API *event);
+
+uint64_t order_updated_ts_event(const struct OrderUpdated_API *event);
+
+uint64_t order_updated_ts_init(const struct OrderUpdated_API *event);
+
+uint8_t order_updated_reconciliation(const struct OrderUpdated_API *event);
+
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Returns a pointer to the venue order ID of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct VenueOrderId_t *order_updated_venue_order_id(const struct OrderUpdated_API *event);
+
+/**
+ * Returns a pointer to the account ID of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct AccountId_t *order_updated_account_id(const struct OrderUpdated_API *event);
+
+/**
+ * Returns a pointer to the price of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct Price_t *order_updated_price(const struct OrderUpdated_API *event);
+
+/**
+ * Returns a pointer to the trigger price of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct Price_t *order_updated_trigger_price(const struct OrderUpdated_API *event);
+
+/**
+ * Returns a pointer to the prev quantity of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct Quantity_t *order_updated_prev_quantity(const struct OrderUpdated_API *event);
+
+/**
+ * Returns a pointer to the prev price of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct Price_t *order_updated_prev_price(const struct OrderUpdated_API *event);
+
+/**
+ * Returns a pointer to the prev trigger price of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct Price_t *order_updated_prev_trigger_price(const struct OrderUpdated_API *event);
+
+/**
+ * Returns the result of creating an `OrderFilled`, reporting a fill which fails validation
+ * (see `OrderFilled::new`) rather than panicking.
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `position_id_ptr` and `commission_ptr` are either null (for `None`) or valid
+ *   pointers.
  */
-struct AccountId_t account_id_new(const char *ptr);
+struct OrderFilledResult order_filled_new(struct TraderId_t trader_id,
+                                          struct StrategyId_t strategy_id,
+                                          struct InstrumentId_t instrument_id,
+                                          struct ClientOrderId_t client_order_id,
+                                          struct VenueOrderId_t venue_order_id,
+                                          struct AccountId_t account_id,
+                                          struct TradeId_t trade_id,
+                                          enum OrderSide order_side,
+                                          enum OrderType order_type,
+                                          struct Quantity_t last_qty,
+                                          struct Price_t last_px,
+                                          struct Currency_t currency,
+                                          enum LiquiditySide liquidity_side,
+                                          UUID4_t event_id,
+                                          uint64_t ts_event,
+                                          uint64_t ts_init,
+                                          uint8_t reconciliation,
+                                          const struct PositionId_t *position_id_ptr,
+                                          const struct Money_t *commission_ptr);
+
+void order_filled_drop(struct OrderFilled_API event);
+
+struct OrderFilled_API order_filled_clone(const struct OrderFilled_API *event);
+
+struct TraderId_t order_filled_trader_id(const struct OrderFilled_API *event);
+
+struct StrategyId_t order_filled_strategy_id(const struct OrderFilled_API *event);
+
+struct InstrumentId_t order_filled_instrument_id(const struct OrderFilled_API *event);
+
+struct ClientOrderId_t order_filled_client_order_id(const struct OrderFilled_API *event);
+
+struct VenueOrderId_t order_filled_venue_order_id(const struct OrderFilled_API *event);
+
+struct AccountId_t order_filled_account_id(const struct OrderFilled_API *event);
+
+struct TradeId_t order_filled_trade_id(const struct OrderFilled_API *event);
+
+enum OrderSide order_filled_order_side(const struct OrderFilled_API *event);
+
+enum OrderType order_filled_order_type(const struct OrderFilled_API *event);
+
+struct Quantity_t order_filled_last_qty(const struct OrderFilled_API *event);
+
+struct Price_t order_filled_last_px(const struct OrderFilled_API *event);
+
+struct Currency_t order_filled_currency(const struct OrderFilled_API *event);
+
+enum LiquiditySide order_filled_liquidity_side(const struct OrderFilled_API *event);
+
+UUID4_t order_filled_event_id(const struct OrderFilled_API *event);
+
+uint64_t order_filled_ts_event(const struct OrderFilled_API *event);
+
+uint64_t order_filled_ts_init(const struct OrderFilled_API *event);
+
+uint8_t order_filled_reconciliation(const struct OrderFilled_API *event);
+
+/**
+ * Returns a pointer to the position ID of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct PositionId_t *order_filled_position_id(const struct OrderFilled_API *event);
+
+/**
+ * Returns a pointer to the commission of the event, or null if it has none.
+ *
+ * The pointer is only valid while `event` is alive.
+ */
+const struct Money_t *order_filled_commission(const struct OrderFilled_API *event);
+
+/**
+ * Returns the given `event` serialized as a JSON C string, to be freed with `cstr_drop`.
+ *
+ * # Panics
+ *
+ * - If serialization fails.
+ */
+const char *order_denied_to_json(const struct OrderDenied_t *event);
+
+/**
+ * Returns the given `event` serialized as `MsgPack` bytes, to be freed with `cvec_drop`.
+ *
+ * # Panics
+ *
+ * - If serialization fails.
+ */
+CVec order_denied_to_msgpack(const struct OrderDenied_t *event);
+
+/**
+ * Decodes an `OrderDenied` from the given JSON C string into `out`, returning null on success,
+ * otherwise an error C string to be freed with `cstr_drop` (leaving `out` unchanged).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is null or a valid C string pointer.
+ * - Assumes `out` is a valid pointer.
+ */
+const char *order_denied_from_json(const char *ptr, struct OrderDenied_t *out);
+
+/**
+ * Decodes an `OrderDenied` from the given `MsgPack` bytes into `out`, returning null on success,
+ * otherwise an error C string to be freed with `cstr_drop` (leaving `out` unchanged).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is null or points to `len` readable bytes.
+ * - Assumes `out` is a valid pointer.
+ */
+const char *order_denied_from_msgpack(const uint8_t *ptr, uintptr_t len, struct OrderDenied_t *out);
+
+/**
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
+ */
+const char *account_id_new(const char *ptr, struct AccountId_t *out);
 
 uint64_t account_id_hash(const struct AccountId_t *id);
 
 /**
- * Returns a Nautilus identifier from C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct ClientId_t client_id_new(const char *ptr);
+const char *client_id_new(const char *ptr, struct ClientId_t *out);
 
 uint64_t client_id_hash(const struct ClientId_t *id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct ClientOrderId_t client_order_id_new(const char *ptr);
+const char *client_order_id_new(const char *ptr, struct ClientOrderId_t *out);
 
 uint64_t client_order_id_hash(const struct ClientOrderId_t *id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct ComponentId_t component_id_new(const char *ptr);
+const char *component_id_new(const char *ptr, struct ComponentId_t *out);
 
 uint64_t component_id_hash(const struct ComponentId_t *id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct ExecAlgorithmId_t exec_algorithm_id_new(const char *ptr);
+const char *exec_algorithm_id_new(const char *ptr, struct ExecAlgorithmId_t *out);
 
 uint64_t exec_algorithm_id_hash(const struct ExecAlgorithmId_t *id);
 
@@ -1967,13 +2558,17 @@ struct InstrumentId_t instrument_id_new(struct Symbol_t symbol, struct Venue_t v
 const char *instrument_id_check_parsing(const char *ptr);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the [`InstrumentId`] parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * instrument ID, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct InstrumentId_t instrument_id_from_cstr(const char *ptr);
+const char *instrument_id_from_cstr(const char *ptr, struct InstrumentId_t *out);
 
 /**
  * Returns an [`InstrumentId`] as a C string pointer.
@@ -1985,81 +2580,109 @@ uint64_t instrument_id_hash(const struct InstrumentId_t *instrument_id);
 uint8_t instrument_id_is_synthetic(const struct InstrumentId_t *instrument_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct OrderListId_t order_list_id_new(const char *ptr);
+const char *order_list_id_new(const char *ptr, struct OrderListId_t *out);
 
 uint64_t order_list_id_hash(const struct OrderListId_t *id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct PositionId_t position_id_new(const char *ptr);
+const char *position_id_new(const char *ptr, struct PositionId_t *out);
 
 uint64_t position_id_hash(const struct PositionId_t *id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct StrategyId_t strategy_id_new(const char *ptr);
+const char *strategy_id_new(const char *ptr, struct StrategyId_t *out);
 
 uint64_t strategy_id_hash(const struct StrategyId_t *id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct Symbol_t symbol_new(const char *ptr);
+const char *symbol_new(const char *ptr, struct Symbol_t *out);
 
 uint64_t symbol_hash(const struct Symbol_t *id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct TradeId_t trade_id_new(const char *ptr);
+const char *trade_id_new(const char *ptr, struct TradeId_t *out);
 
 uint64_t trade_id_hash(const struct TradeId_t *id);
 
 const char *trade_id_to_cstr(const struct TradeId_t *trade_id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct TraderId_t trader_id_new(const char *ptr);
+const char *trader_id_new(const char *ptr, struct TraderId_t *out);
 
 uint64_t trader_id_hash(const struct TraderId_t *id);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct Venue_t venue_new(const char *ptr);
+const char *venue_new(const char *ptr, struct Venue_t *out);
 
 uint64_t venue_hash(const struct Venue_t *id);
 
@@ -2080,13 +2703,17 @@ uint8_t venue_code_exists(const char *code_ptr);
 struct Venue_t venue_from_cstr_code(const char *code_ptr);
 
 /**
- * Returns a Nautilus identifier from a C string pointer.
+ * Writes the Nautilus identifier parsed from a C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+ * identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct VenueOrderId_t venue_order_id_new(const char *ptr);
+const char *venue_order_id_new(const char *ptr, struct VenueOrderId_t *out);
 
 uint64_t venue_order_id_hash(const struct VenueOrderId_t *id);
 
@@ -2150,6 +2777,13 @@ struct InstrumentId_t orderbook_instrument_id(const struct OrderBook_API *book);
 
 enum BookType orderbook_book_type(const struct OrderBook_API *book);
 
+uint8_t orderbook_validate(const struct OrderBook_API *book);
+
+/**
+ * Sets whether the `book` checks its integrity after applying deltas.
+ */
+void orderbook_set_validate(struct OrderBook_API *book, uint8_t validate);
+
 uint64_t orderbook_sequence(const struct OrderBook_API *book);
 
 uint64_t orderbook_ts_last(const struct OrderBook_API *book);
@@ -2180,9 +2814,22 @@ void orderbook_clear_bids(struct OrderBook_API *book, uint64_t sequence, uint64_
 
 void orderbook_clear_asks(struct OrderBook_API *book, uint64_t sequence, uint64_t ts_event);
 
-void orderbook_apply_delta(struct OrderBook_API *book, struct OrderBookDelta_t delta);
+/**
+ * Applies the `delta` to the `book`.
+ *
+ * Returns null on success, otherwise a C string describing the integrity check failure of a
+ * validating book, which must be freed with `cstr_drop`.
+ */
+const char *orderbook_apply_delta(struct OrderBook_API *book, struct OrderBookDelta_t delta);
 
-void orderbook_apply_deltas(struct OrderBook_API *book, const struct OrderBookDeltas_API *deltas);
+/**
+ * Applies the `deltas` to the `book`.
+ *
+ * Returns null on success, otherwise a C string describing the integrity check failure of a
+ * validating book, which must be freed with `cstr_drop`.
+ */
+const char *orderbook_apply_deltas(struct OrderBook_API *book,
+                                   const struct OrderBookDeltas_API *deltas);
 
 void orderbook_apply_depth(struct OrderBook_API *book, struct OrderBookDepth10_t depth);
 
@@ -2190,6 +2837,8 @@ CVec orderbook_bids(struct OrderBook_API *book);
 
 CVec orderbook_asks(struct OrderBook_API *book);
 
+struct OrderBookDepth10_t orderbook_snapshot(const struct OrderBook_API *book, uintptr_t depth);
+
 uint8_t orderbook_has_bid(struct OrderBook_API *book);
 
 uint8_t orderbook_has_ask(struct OrderBook_API *book);
@@ -2206,6 +2855,10 @@ double orderbook_spread(struct OrderBook_API *book);
 
 double orderbook_midpoint(struct OrderBook_API *book);
 
+/**
+ * Returns the average price for the available portion of `qty`, or 0.0 if the opposite
+ * side of the book is empty (as expected by the Cython `OrderBook`).
+ */
 double orderbook_get_avg_px_for_quantity(struct OrderBook_API *book,
                                          struct Quantity_t qty,
                                          enum OrderSide order_side);
@@ -2262,18 +2915,23 @@ void vec_levels_drop(CVec v);
 void vec_orders_drop(CVec v);
 
 /**
- * Returns a [`Currency`] from pointers and primitives.
+ * Writes the [`Currency`] created from pointers and primitives to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why the currency is not
+ * valid, to be freed with `cstr_drop` (leaving `out` unchanged).
  *
  * # Safety
  *
- * - Assumes `code_ptr` is a valid C string pointer.
- * - Assumes `name_ptr` is a valid C string pointer.
+ * - Assumes `code_ptr` is a valid C string pointer or NULL.
+ * - Assumes `name_ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
  */
-struct Currency_t currency_from_py(const char *code_ptr,
-                                   uint8_t precision,
-                                   uint16_t iso4217,
-                                   const char *name_ptr,
-                                   enum CurrencyType currency_type);
+const char *currency_from_py(const char *code_ptr,
+                             uint8_t precision,
+                             uint16_t iso4217,
+                             const char *name_ptr,
+                             enum CurrencyType currency_type,
+                             struct Currency_t *out);
 
 const char *currency_to_cstr(const struct Currency_t *currency);
 
@@ -2293,11 +2951,34 @@ void currency_register(struct Currency_t currency);
 uint8_t currency_exists(const char *code_ptr);
 
 /**
+ * Writes the registered [`Currency`] for the code C string pointer to `out`.
+ *
+ * Returns a null pointer on success, otherwise a C string describing why the code is not
+ * valid or not registered, to be freed with `cstr_drop` (leaving `out` unchanged).
+ *
  * # Safety
  *
- * - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
+ * - Assumes `code_ptr` is a valid C string pointer or NULL.
+ * - Assumes `out` is a valid pointer.
+ */
+const char *currency_from_cstr(const char *code_ptr, struct Currency_t *out);
+
+/**
+ * Writes the given `raw` fixed-point value rescaled from `from_precision` to `to_precision`
+ * decimal places to `out`.
+ *
+ * Returns null on success, otherwise a C string describing why the rescaling failed (if it would
+ * truncate non-zero digits or overflow, or if either precision is greater than the maximum fixed
+ * precision), which must be freed with `cstr_drop`.
+ *
+ * # Safety
+ *
+ * - Assumes `out` is a valid pointer to write an `i64` to.
  */
-struct Currency_t currency_from_cstr(const char *code_ptr);
+const char *fixed_point_rescale(int64_t raw,
+                                uint8_t from_precision,
+                                uint8_t to_precision,
+                                int64_t *out);
 
 struct Money_t money_new(double amount, struct Currency_t currency);
 
@@ -2313,6 +2994,8 @@ struct Price_t price_new(double value, uint8_t precision);
 
 struct Price_t price_from_raw(int64_t raw, uint8_t precision);
 
+int64_t price_raw(const struct Price_t *price);
+
 double price_as_f64(const struct Price_t *price);
 
 void price_add_assign(struct Price_t a, struct Price_t b);
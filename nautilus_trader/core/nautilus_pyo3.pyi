@@ -546,6 +546,11 @@ class OrderBookDeltas:
     def ts_event(self) -> int: ...
     @property
     def ts_init(self) -> int: ...
+    def is_snapshot(self) -> bool: ...
+    def is_last(self) -> bool: ...
+    def as_dict(self) -> dict[str, Any]: ...
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> OrderBookDeltas: ...
 
 class OrderBookDepth10:
     def __init__(
@@ -766,6 +771,14 @@ class RecordFlag(Enum):
     F_SNAPSHOT = "F_SNAPSHOT"
     F_MBP = "F_MBP"
 
+class RoundingMode(Enum):
+    HALF_UP = "HALF_UP"
+    HALF_EVEN = "HALF_EVEN"
+    FLOOR = "FLOOR"
+    CEILING = "CEILING"
+    DOWN = "DOWN"
+    UP = "UP"
+
 class TimeInForce(Enum):
     GTC = "GTC"
     IOC = "IOC"
@@ -996,6 +1009,7 @@ class LimitOrder:
     @classmethod
     def from_dict(cls, values: dict[str, str]) -> LimitOrder: ...
     def apply(self, event: object) -> None: ...
+    def timeline(self) -> str: ...
 
 
 class LimitIfTouchedOrder:
@@ -1032,6 +1046,7 @@ class LimitIfTouchedOrder:
     @classmethod
     def create(cls, init: OrderInitialized) -> LimitIfTouchedOrder: ...
     def apply(self, event: object) -> None: ...
+    def timeline(self) -> str: ...
 
 class MarketOrder:
     def __init__(
@@ -1094,6 +1109,7 @@ class MarketOrder:
     @property
     def price(self) -> Price | None: ...
     def apply(self, event: object) -> None: ...
+    def timeline(self) -> str: ...
 
 class MarketToLimitOrder:
     def __init__(
@@ -1124,6 +1140,7 @@ class MarketToLimitOrder:
     @classmethod
     def create(cls, init: OrderInitialized) -> MarketToLimitOrder: ...
     def apply(self, event: object) -> None: ...
+    def timeline(self) -> str: ...
 
 class MarketIfTouchedOrder:
     def __init__(
@@ -1157,6 +1174,7 @@ class MarketIfTouchedOrder:
     @classmethod
     def create(cls, init: OrderInitialized) -> MarketIfTouchedOrder: ...
     def apply(self, event: object) -> None: ...
+    def timeline(self) -> str: ...
 
 class StopLimitOrder:
     def __init__(
@@ -1239,6 +1257,7 @@ class StopLimitOrder:
     @property
     def expire_time(self) -> int | None: ...
     def apply(self, event: object) -> None: ...
+    def timeline(self) -> str: ...
 
 class StopMarketOrder:
     def __init__(
@@ -1272,6 +1291,7 @@ class StopMarketOrder:
     @classmethod
     def create(cls, init: OrderInitialized) -> StopMarketOrder: ...
     def apply(self, event: object) -> None: ...
+    def timeline(self) -> str: ...
 
 class TrailingStopLimitOrder:
     def __init__(
@@ -1310,6 +1330,7 @@ class TrailingStopLimitOrder:
     @classmethod
     def create(cls, init: OrderInitialized) -> TrailingStopLimitOrder: ...
     def apply(self, event: object) -> None: ...
+    def timeline(self) -> str: ...
 
 class TrailingStopMarketOrder:
     def __init__(
@@ -1345,6 +1366,7 @@ class TrailingStopMarketOrder:
     @classmethod
     def create(cls, init: OrderInitialized) -> TrailingStopMarketOrder: ...
     def apply(self, event: object) -> None: ...
+    def timeline(self) -> str: ...
 
 Order: TypeAlias = Union[
     LimitOrder,
@@ -1391,7 +1413,7 @@ class Currency:
     def register(currency: Currency, overwrite: bool = False) -> None: ...
 
 class Money:
-    def __init__(self, value: float, currency: Currency) -> None: ...
+    def __init__(self, value: float | Decimal | int | str, currency: Currency) -> None: ...
     @property
     def raw(self) -> int: ...
     @property
@@ -1408,7 +1430,7 @@ class Money:
     def to_formatted_str(self) -> str: ...
 
 class Price:
-    def __init__(self, value: float, precision: int) -> None: ...
+    def __init__(self, value: float | Decimal | int | str, precision: int) -> None: ...
     @property
     def raw(self) -> int: ...
     @property
@@ -1428,7 +1450,7 @@ class Price:
     def to_formatted_str(self) -> str: ...
 
 class Quantity:
-    def __init__(self, value: float, precision: int) -> None: ...
+    def __init__(self, value: float | Decimal | int | str, precision: int) -> None: ...
     @property
     def raw(self) -> int: ...
     @property
@@ -1880,9 +1902,29 @@ class OrderDenied:
         ts_event: int,
         ts_init: int,
     ) -> None: ...
+    def __hash__(self) -> int: ...
+    @property
+    def trader_id(self) -> TraderId: ...
+    @property
+    def strategy_id(self) -> StrategyId: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def client_order_id(self) -> ClientOrderId: ...
+    @property
+    def reason(self) -> str: ...
+    @property
+    def event_id(self) -> UUID4: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
     @classmethod
-    def from_dict(cls, values: dict[str, str]) -> OrderDenied: ...
-    def to_dict(self) -> dict[str, str]: ...
+    def from_dict(cls, values: dict[str, Any]) -> OrderDenied: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @classmethod
+    def from_msgpack(cls, data: bytes) -> OrderDenied: ...
+    def to_msgpack(self) -> bytes: ...
 
 class OrderTriggered:
     def __init__(
@@ -1916,9 +1958,35 @@ class OrderRejected:
         ts_init: int,
         reconciliation: bool,
     ) -> None: ...
+    def __hash__(self) -> int: ...
+    @property
+    def trader_id(self) -> TraderId: ...
+    @property
+    def strategy_id(self) -> StrategyId: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def client_order_id(self) -> ClientOrderId: ...
+    @property
+    def account_id(self) -> AccountId: ...
+    @property
+    def reason(self) -> str: ...
+    @property
+    def event_id(self) -> UUID4: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @property
+    def reconciliation(self) -> bool: ...
+    @property
+    def correlation_id(self) -> UUID4 | None: ...
     @classmethod
-    def from_dict(cls, values: dict[str, str]) -> OrderRejected: ...
-    def to_dict(self) -> dict[str, str]: ...
+    def from_dict(cls, values: dict[str, Any]) -> OrderRejected: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @classmethod
+    def from_msgpack(cls, data: bytes) -> OrderRejected: ...
+    def to_msgpack(self) -> bytes: ...
 
 class OrderFilled:
     def __init__(
@@ -1942,20 +2010,56 @@ class OrderFilled:
         reconciliation: bool,
         position_id: PositionId | None = None,
         commission: Money | None = None,
-    )-> None: ...
+    ) -> None: ...
+    def __hash__(self) -> int: ...
     @property
     def is_buy(self) -> bool: ...
     @property
     def is_sell(self) -> bool: ...
-    @classmethod
-    def from_dict(cls, values: dict[str, str]) -> OrderFilled: ...
-    def to_dict(self) -> dict[str, str]: ...
+    @property
+    def trader_id(self) -> TraderId: ...
+    @property
+    def strategy_id(self) -> StrategyId: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def client_order_id(self) -> ClientOrderId: ...
+    @property
+    def venue_order_id(self) -> VenueOrderId: ...
+    @property
+    def account_id(self) -> AccountId: ...
+    @property
+    def trade_id(self) -> TradeId: ...
     @property
     def order_side(self) -> OrderSide: ...
     @property
     def order_type(self) -> OrderType: ...
     @property
-    def client_order_id(self) -> ClientOrderId: ...
+    def last_qty(self) -> Quantity: ...
+    @property
+    def last_px(self) -> Price: ...
+    @property
+    def currency(self) -> Currency: ...
+    @property
+    def liquidity_side(self) -> LiquiditySide: ...
+    @property
+    def event_id(self) -> UUID4: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @property
+    def reconciliation(self) -> bool: ...
+    @property
+    def position_id(self) -> PositionId | None: ...
+    @property
+    def commission(self) -> Money | None: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, Any]) -> OrderFilled: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @classmethod
+    def from_msgpack(cls, data: bytes) -> OrderFilled: ...
+    def to_msgpack(self) -> bytes: ...
 
 class OrderInitialized:
     def __init__(
@@ -1994,11 +2098,79 @@ class OrderInitialized:
         exec_spawn_id: ClientOrderId | None = None,
         tags: list[str] | None = None,
     ) -> None: ...
-    @classmethod
-    def from_dict(cls, values: dict[str, str]) -> OrderInitialized: ...
-    def to_dict(self) -> dict[str, str]: ...
+    def __hash__(self) -> int: ...
+    @property
+    def trader_id(self) -> TraderId: ...
+    @property
+    def strategy_id(self) -> StrategyId: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def client_order_id(self) -> ClientOrderId: ...
+    @property
+    def order_side(self) -> OrderSide: ...
     @property
     def order_type(self) -> OrderType: ...
+    @property
+    def quantity(self) -> Quantity: ...
+    @property
+    def time_in_force(self) -> TimeInForce: ...
+    @property
+    def post_only(self) -> bool: ...
+    @property
+    def reduce_only(self) -> bool: ...
+    @property
+    def quote_quantity(self) -> bool: ...
+    @property
+    def reconciliation(self) -> bool: ...
+    @property
+    def event_id(self) -> UUID4: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @property
+    def price(self) -> Price | None: ...
+    @property
+    def trigger_price(self) -> Price | None: ...
+    @property
+    def trigger_type(self) -> TriggerType | None: ...
+    @property
+    def limit_offset(self) -> Price | None: ...
+    @property
+    def trailing_offset(self) -> Price | None: ...
+    @property
+    def trailing_offset_type(self) -> TrailingOffsetType | None: ...
+    @property
+    def expire_time(self) -> int | None: ...
+    @property
+    def display_qty(self) -> Quantity | None: ...
+    @property
+    def emulation_trigger(self) -> TriggerType | None: ...
+    @property
+    def trigger_instrument_id(self) -> InstrumentId | None: ...
+    @property
+    def contingency_type(self) -> ContingencyType | None: ...
+    @property
+    def order_list_id(self) -> OrderListId | None: ...
+    @property
+    def linked_order_ids(self) -> list[ClientOrderId] | None: ...
+    @property
+    def parent_order_id(self) -> ClientOrderId | None: ...
+    @property
+    def exec_algorithm_id(self) -> ExecAlgorithmId | None: ...
+    @property
+    def exec_algorithm_params(self) -> dict[str, str] | None: ...
+    @property
+    def exec_spawn_id(self) -> ClientOrderId | None: ...
+    @property
+    def tags(self) -> list[str] | None: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, Any]) -> OrderInitialized: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @classmethod
+    def from_msgpack(cls, data: bytes) -> OrderInitialized: ...
+    def to_msgpack(self) -> bytes: ...
 
 class OrderSubmitted:
     def __init__(
@@ -2012,11 +2184,31 @@ class OrderSubmitted:
         ts_event: int,
         ts_init: int,
     ) -> None: ...
-    @classmethod
-    def from_dict(cls, values: dict[str, str]) -> OrderSubmitted: ...
-    def to_dict(self) -> dict[str, str]: ...
+    def __hash__(self) -> int: ...
+    @property
+    def trader_id(self) -> TraderId: ...
+    @property
+    def strategy_id(self) -> StrategyId: ...
     @property
-    def order_type(self) -> str: ...
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def client_order_id(self) -> ClientOrderId: ...
+    @property
+    def account_id(self) -> AccountId: ...
+    @property
+    def event_id(self) -> UUID4: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @property
+    def correlation_id(self) -> UUID4 | None: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, Any]) -> OrderSubmitted: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @classmethod
+    def from_msgpack(cls, data: bytes) -> OrderSubmitted: ...
+    def to_msgpack(self) -> bytes: ...
 
 class OrderEmulated:
     def __init__(
@@ -2065,6 +2257,9 @@ class OrderUpdated:
         account_id: AccountId | None = None,
         price: Price | None = None,
         trigger_price: Price | None = None,
+        prev_quantity: Quantity | None = None,
+        prev_price: Price | None = None,
+        prev_trigger_price: Price | None = None,
     ) -> None: ...
     @classmethod
     def from_dict(cls, values: dict[str, str]) -> OrderUpdated: ...
@@ -2139,9 +2334,35 @@ class OrderAccepted:
         ts_init: int,
         reconciliation: bool,
     ) -> None: ...
+    def __hash__(self) -> int: ...
+    @property
+    def trader_id(self) -> TraderId: ...
+    @property
+    def strategy_id(self) -> StrategyId: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def client_order_id(self) -> ClientOrderId: ...
+    @property
+    def venue_order_id(self) -> VenueOrderId: ...
+    @property
+    def account_id(self) -> AccountId: ...
+    @property
+    def event_id(self) -> UUID4: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @property
+    def reconciliation(self) -> bool: ...
+    @property
+    def correlation_id(self) -> UUID4 | None: ...
     @classmethod
-    def from_dict(cls, values: dict[str, str]) -> OrderAccepted: ...
-    def to_dict(self) -> dict[str, str]: ...
+    def from_dict(cls, values: dict[str, Any]) -> OrderAccepted: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @classmethod
+    def from_msgpack(cls, data: bytes) -> OrderAccepted: ...
+    def to_msgpack(self) -> bytes: ...
 
 class OrderCancelRejected:
     def __init__(
@@ -2176,10 +2397,33 @@ class OrderCanceled:
         venue_order_id: VenueOrderId | None = None,
         account_id: AccountId | None = None,
     ) -> None: ...
-
+    def __hash__(self) -> int: ...
+    @property
+    def trader_id(self) -> TraderId: ...
+    @property
+    def strategy_id(self) -> StrategyId: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def client_order_id(self) -> ClientOrderId: ...
+    @property
+    def event_id(self) -> UUID4: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @property
+    def reconciliation(self) -> bool: ...
+    @property
+    def venue_order_id(self) -> VenueOrderId | None: ...
+    @property
+    def account_id(self) -> AccountId | None: ...
     @classmethod
-    def from_dict(cls, values: dict[str, str]) -> OrderCanceled: ...
-    def to_dict(self) -> dict[str, str]: ...
+    def from_dict(cls, values: dict[str, Any]) -> OrderCanceled: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @classmethod
+    def from_msgpack(cls, data: bytes) -> OrderCanceled: ...
+    def to_msgpack(self) -> bytes: ...
 
 class OrderExpired:
     def __init__(
@@ -2199,6 +2443,25 @@ class OrderExpired:
     def from_dict(cls, values: dict[str, str]) -> OrderExpired: ...
     def to_dict(self) -> dict[str, str]: ...
 
+OrderEvent: TypeAlias = Union[
+    OrderInitialized,
+    OrderDenied,
+    OrderEmulated,
+    OrderReleased,
+    OrderSubmitted,
+    OrderAccepted,
+    OrderRejected,
+    OrderCanceled,
+    OrderExpired,
+    OrderTriggered,
+    OrderPendingUpdate,
+    OrderPendingCancel,
+    OrderModifyRejected,
+    OrderCancelRejected,
+    OrderUpdated,
+    OrderFilled,
+]
+
 class Level:
     @property
     def price(self) -> Price: ...
@@ -2211,6 +2474,10 @@ class Level:
     def first(self) -> BookOrder | None: ...
     def get_orders(self) -> list[BookOrder]: ...
 
+class BookLevelIterator:
+    def __iter__(self) -> BookLevelIterator: ...
+    def __next__(self) -> tuple[Price, Quantity]: ...
+
 class OrderBook:
     def __init__(
         self,
@@ -2231,6 +2498,10 @@ class OrderBook:
     def ts_last(self) -> int: ...
     @property
     def count(self) -> int: ...
+    @property
+    def validate(self) -> bool: ...
+    @validate.setter
+    def validate(self, value: bool) -> None: ...
     def reset(self) -> None: ...
     def add(self, order: BookOrder, flags: int, sequence: int, ts_event: int) -> None: ...
     def update(self, order: BookOrder, flags: int, sequence: int, ts_event: int) -> None: ...
@@ -2241,16 +2512,23 @@ class OrderBook:
     def apply_delta(self, delta: OrderBookDelta) -> None: ...
     def apply_deltas(self, deltas: OrderBookDeltas) -> None: ...
     def apply_depth(self, depth: OrderBookDepth10) -> None: ...
+    def check_integrity(self) -> None: ...
+    def to_quote_tick(self) -> QuoteTick | None: ...
     def bids(self) -> list[Level]: ...
     def asks(self) -> list[Level]: ...
+    def snapshot(self, depth: int = 10) -> OrderBookDepth10: ...
+    def iter_bids(self, depth: int | None = None) -> BookLevelIterator: ...
+    def iter_asks(self, depth: int | None = None) -> BookLevelIterator: ...
+    def levels_filled_by(self, qty: Quantity, order_side: OrderSide) -> int: ...
+    def to_ladder_snapshot(self, depth: int | None = None) -> bytes: ...
     def best_bid_price(self) -> Price | None: ...
     def best_ask_price(self) -> Price | None: ...
     def best_bid_size(self) -> Quantity | None: ...
     def best_ask_size(self) -> Quantity | None: ...
     def spread(self) -> float | None: ...
     def midpoint(self) -> float | None: ...
-    def get_avg_px_for_quantity(self, qty: Quantity, order_side: OrderSide) -> float: ...
-    def get_quantity_for_price(self, price: Price, order_side: OrderSide) -> float: ...
+    def get_avg_px_for_quantity(self, qty: Quantity, order_side: OrderSide) -> float | None: ...
+    def get_quantity_for_price(self, price: Price, order_side: OrderSide) -> Quantity: ...
     def simulate_fills(self, order: BookOrder) -> list[tuple[Price, Quantity]]: ...
     def pprint(self, num_levels: int) -> str: ...
 
@@ -2443,6 +2721,8 @@ class DataTransformer:
     def pyo3_trade_ticks_to_record_batch_bytes(data: list[TradeTick]) -> bytes: ...
     @staticmethod
     def pyo3_bars_to_record_batch_bytes(data: list[Bar]) -> bytes: ...
+    @staticmethod
+    def pyo3_order_events_to_record_batch_bytes(events: list[OrderEvent]) -> bytes: ...
 
 class OrderBookDeltaDataWrangler:
     def __init__(
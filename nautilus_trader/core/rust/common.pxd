@@ -194,6 +194,8 @@ cdef extern from "../includes/common.h":
 
     void test_clock_set_time(const TestClock_API *clock, uint64_t to_time_ns);
 
+    void test_clock_set_schedule_end(TestClock_API *clock, uint64_t end_ns);
+
     double test_clock_timestamp(const TestClock_API *clock);
 
     uint64_t test_clock_timestamp_ms(const TestClock_API *clock);
@@ -12,6 +12,27 @@ cdef extern from "../includes/core.h":
 
     const uint64_t NANOSECONDS_IN_MICROSECOND # = 1000
 
+    # The length of a record envelope header (marker, payload length and checksum).
+    const uintptr_t RECORD_HEADER_LEN # = 12
+
+    # The maximum payload length of a record (16 MiB).
+    const uintptr_t MAX_RECORD_LEN # = ((16 * 1024) * 1024)
+
+    # The maximum length (bytes) of an identifier C string.
+    const uintptr_t IDENTIFIER_MAX_LEN # = 256
+
+    # The maximum length (bytes) of a free text C string, such as a reason.
+    const uintptr_t TEXT_MAX_LEN # = 4096
+
+    # The character set policy for validating a C string received over FFI.
+    cpdef enum CharsetPolicy:
+        # Printable ASCII with no whitespace.
+        IDENTIFIER # = 1,
+        # Printable ASCII with interior spaces only (such as option symbols).
+        SYMBOL # = 2,
+        # Any valid UTF-8.
+        TEXT # = 3,
+
     # `CVec` is a C compatible struct that stores an opaque pointer to a block of
     # memory, it's length and the capacity of the vector it was allocated from.
     #
@@ -72,6 +93,14 @@ cdef extern from "../includes/core.h":
     # - If `ptr` is null.
     uint8_t precision_from_cstr(const char *ptr);
 
+    # Returns any validation error for the C string pointer against the `max_len` (bytes) and
+    # `charset` policy, otherwise an empty string.
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    const char *cstr_check_valid(const char *ptr, uintptr_t max_len, CharsetPolicy charset);
+
     # Drops the C string memory at the pointer.
     #
     # # Safety
@@ -7,6 +7,12 @@ cdef extern from "../includes/model.h":
 
     const uintptr_t DEPTH10_LEN # = 10
 
+    # The default bucket granularity (1 second).
+    const uint64_t DEFAULT_GRANULARITY_NS # = 1000000000
+
+    # The current version of the binary encoding.
+    const uint8_t LADDER_SNAPSHOT_VERSION # = 1
+
     const uint8_t FIXED_PRECISION # = 9
 
     const double FIXED_SCALAR # = 1000000000.0
@@ -23,6 +29,9 @@ cdef extern from "../includes/model.h":
 
     const double QUANTITY_MIN # = 0.0
 
+    # The raw fixed-point representation of `QUANTITY_MAX`.
+    const uint64_t QUANTITY_MAX_RAW # = 18446744073000000000ull
+
     # An account type provided by a trading venue or broker.
     cpdef enum AccountType:
         # An account with unleveraged cash assets only.
@@ -393,6 +402,21 @@ cdef extern from "../includes/model.h":
     cdef struct OrderBookDeltas_t:
         pass
 
+    cdef struct OrderCanceled_t:
+        pass
+
+    cdef struct OrderExpired_t:
+        pass
+
+    cdef struct OrderFilled_t:
+        pass
+
+    cdef struct OrderTriggered_t:
+        pass
+
+    cdef struct OrderUpdated_t:
+        pass
+
     # Represents a synthetic instrument with prices derived from component instruments using a
     # formula.
     cdef struct SyntheticInstrument:
@@ -576,6 +600,20 @@ cdef extern from "../includes/model.h":
         # The UNIX timestamp (nanoseconds) when the struct was initialized.
         uint64_t ts_init;
 
+    # Represents an official close price for an instrument, such as a daily settlement price or
+    # the final settlement price at contract expiry (distinct from the last traded price).
+    cdef struct InstrumentClose:
+        # The instrument ID for the close.
+        InstrumentId_t instrument_id;
+        # The close price for the instrument.
+        Price_t close_price;
+        # The type of the close.
+        InstrumentCloseType close_type;
+        # The UNIX timestamp (nanoseconds) when the close event occurred.
+        uint64_t ts_event;
+        # The UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
     # A built-in Nautilus data type.
     #
     # Not recommended for storing large amounts of data, as the largest variant is significantly
@@ -587,6 +625,7 @@ cdef extern from "../includes/model.h":
         QUOTE,
         TRADE,
         BAR,
+        CLOSE,
 
     cdef struct Data_t:
         Data_t_Tag tag;
@@ -596,6 +635,7 @@ cdef extern from "../includes/model.h":
         QuoteTick_t quote;
         TradeTick_t trade;
         Bar_t bar;
+        InstrumentClose close;
 
     # Represents a valid trader ID.
     #
@@ -636,6 +676,14 @@ cdef extern from "../includes/model.h":
         uint64_t ts_event;
         uint64_t ts_init;
 
+    # The result of constructing an `OrderDenied` across the FFI boundary.
+    #
+    # On failure `value` is a default event and `error` points to a C string describing the
+    # failure, which must be freed with `cstr_drop`. On success `error` is null.
+    cdef struct OrderDeniedResult:
+        OrderDenied_t value;
+        const char *error;
+
     cdef struct OrderEmulated_t:
         TraderId_t trader_id;
         StrategyId_t strategy_id;
@@ -665,6 +713,16 @@ cdef extern from "../includes/model.h":
     cdef struct AccountId_t:
         char* _0;
 
+    # Represents an optional ID correlating an order event with the command which originated it.
+    #
+    # `Option<UUID4>` has no C layout, so the value is held alongside an explicit flag which
+    # allows the order event structs carrying it to cross the FFI boundary.
+    cdef struct CorrelationId_t:
+        # The correlation ID value (only meaningful when `is_set` is true).
+        UUID4_t value;
+        # If the correlation ID is set.
+        bool is_set;
+
     cdef struct OrderSubmitted_t:
         TraderId_t trader_id;
         StrategyId_t strategy_id;
@@ -674,6 +732,8 @@ cdef extern from "../includes/model.h":
         UUID4_t event_id;
         uint64_t ts_event;
         uint64_t ts_init;
+        # The ID correlating the event with the command which originated it.
+        CorrelationId_t correlation_id;
 
     # Represents a valid venue order ID (assigned by a trading venue).
     cdef struct VenueOrderId_t:
@@ -690,6 +750,8 @@ cdef extern from "../includes/model.h":
         uint64_t ts_event;
         uint64_t ts_init;
         uint8_t reconciliation;
+        # The ID correlating the event with the command which originated it.
+        CorrelationId_t correlation_id;
 
     cdef struct OrderRejected_t:
         TraderId_t trader_id;
@@ -702,6 +764,75 @@ cdef extern from "../includes/model.h":
         uint64_t ts_event;
         uint64_t ts_init;
         uint8_t reconciliation;
+        # The ID correlating the event with the command which originated it.
+        CorrelationId_t correlation_id;
+
+    # The result of constructing an `OrderRejected` across the FFI boundary.
+    #
+    # On failure `value` is a default event and `error` points to a C string describing the
+    # failure, which must be freed with `cstr_drop`. On success `error` is null.
+    cdef struct OrderRejectedResult:
+        OrderRejected_t value;
+        const char *error;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderCanceled`.
+    #
+    # The `Option` fields of `OrderCanceled` have no C layout, so the event is held behind a pointer
+    # and must be freed with `order_canceled_drop`.
+    cdef struct OrderCanceled_API:
+        OrderCanceled_t *_0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderExpired`.
+    #
+    # The `Option` fields of `OrderExpired` have no C layout, so the event is held behind a pointer
+    # and must be freed with `order_expired_drop`.
+    cdef struct OrderExpired_API:
+        OrderExpired_t *_0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderTriggered`.
+    #
+    # The `Option` fields of `OrderTriggered` have no C layout, so the event is held behind a pointer
+    # and must be freed with `order_triggered_drop`.
+    cdef struct OrderTriggered_API:
+        OrderTriggered_t *_0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderUpdated`.
+    #
+    # The `Option` fields of `OrderUpdated` have no C layout, so the event is held behind a pointer
+    # and must be freed with `order_updated_drop`.
+    cdef struct OrderUpdated_API:
+        OrderUpdated_t *_0;
+
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying `OrderFilled`.
+    #
+    # The `Option` fields of `OrderFilled` have no C layout, so the event is held behind a pointer
+    # and must be freed with `order_filled_drop`.
+    cdef struct OrderFilled_API:
+        OrderFilled_t *_0;
+
+    # The result of creating an `OrderFilled` across the FFI boundary.
+    #
+    # On failure `value` holds a default event and `error` points to a C string describing the
+    # failure, which must be freed with `cstr_drop`. On success `error` is null. In both cases
+    # `value` must be freed with `order_filled_drop`.
+    cdef struct OrderFilledResult:
+        OrderFilled_API value;
+        const char *error;
+
+    cdef struct Currency_t:
+        char* code;
+        uint8_t precision;
+        uint16_t iso4217;
+        char* name;
+        CurrencyType currency_type;
+
+    # Represents a valid position ID.
+    cdef struct PositionId_t:
+        char* _0;
+
+    cdef struct Money_t:
+        int64_t raw;
+        Currency_t currency;
 
     # Represents a system client ID.
     cdef struct ClientId_t:
@@ -719,10 +850,6 @@ cdef extern from "../includes/model.h":
     cdef struct OrderListId_t:
         char* _0;
 
-    # Represents a valid position ID.
-    cdef struct PositionId_t:
-        char* _0;
-
     # Provides a C compatible Foreign Function Interface (FFI) for an underlying
     # [`SyntheticInstrument`].
     #
@@ -757,17 +884,6 @@ cdef extern from "../includes/model.h":
     cdef struct Level_API:
         Level *_0;
 
-    cdef struct Currency_t:
-        char* code;
-        uint8_t precision;
-        uint16_t iso4217;
-        char* name;
-        CurrencyType currency_type;
-
-    cdef struct Money_t:
-        int64_t raw;
-        Currency_t currency;
-
     const BookOrder_t NULL_ORDER # = <BookOrder_t>{ OrderSide_NoOrderSide, <Price_t>{ 0, 0 }, <Quantity_t>{ 0, 0 }, 0 }
 
     # Sentinel Price for errors.
@@ -944,7 +1060,18 @@ cdef extern from "../includes/model.h":
     # Returns a [`BookOrder`] debug string as a C string pointer.
     const char *book_order_debug_to_cstr(const BookOrder_t *order);
 
-    QuoteTick_t quote_tick_new(InstrumentId_t instrument_id,
+    # Writes a new `QuoteTick` constructed from the given raw values to `out`.
+    #
+    # The quote invariants are only checked when `validate` is non-zero, so quotes with an empty
+    # side or a crossed top level can still be loaded from raw data.
+    #
+    # Returns null on success, otherwise a C string describing why the quote is invalid, which must
+    # be freed with `cstr_drop`.
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to write a `QuoteTick` to.
+    const char *quote_tick_new(InstrumentId_t instrument_id,
                                int64_t bid_price_raw,
                                int64_t ask_price_raw,
                                uint8_t bid_price_prec,
@@ -954,7 +1081,9 @@ cdef extern from "../includes/model.h":
                                uint8_t bid_size_prec,
                                uint8_t ask_size_prec,
                                uint64_t ts_event,
-                               uint64_t ts_init);
+                               uint64_t ts_init,
+                               uint8_t validate,
+                               QuoteTick_t *out);
 
     uint8_t quote_tick_eq(const QuoteTick_t *lhs, const QuoteTick_t *rhs);
 
@@ -963,7 +1092,17 @@ cdef extern from "../includes/model.h":
     # Returns a [`QuoteTick`] as a C string pointer.
     const char *quote_tick_to_cstr(const QuoteTick_t *tick);
 
-    TradeTick_t trade_tick_new(InstrumentId_t instrument_id,
+    # Writes a new `TradeTick` constructed from the given raw values to `out`.
+    #
+    # The trade invariants are only checked when `validate` is non-zero.
+    #
+    # Returns null on success, otherwise a C string describing why the trade is invalid, which must
+    # be freed with `cstr_drop`.
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to write a `TradeTick` to.
+    const char *trade_tick_new(InstrumentId_t instrument_id,
                                int64_t price_raw,
                                uint8_t price_prec,
                                uint64_t size_raw,
@@ -971,7 +1110,9 @@ cdef extern from "../includes/model.h":
                                AggressorSide aggressor_side,
                                TradeId_t trade_id,
                                uint64_t ts_event,
-                               uint64_t ts_init);
+                               uint64_t ts_init,
+                               uint8_t validate,
+                               TradeTick_t *out);
 
     uint8_t trade_tick_eq(const TradeTick_t *lhs, const TradeTick_t *rhs);
 
@@ -1214,17 +1355,19 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     TriggerType trigger_type_from_cstr(const char *ptr);
 
+    # Returns a new `OrderDenied` event, or an error if `reason_ptr` is not a valid reason.
+    #
     # # Safety
     #
-    # - Assumes `reason_ptr` is a valid C string pointer.
-    OrderDenied_t order_denied_new(TraderId_t trader_id,
-                                   StrategyId_t strategy_id,
-                                   InstrumentId_t instrument_id,
-                                   ClientOrderId_t client_order_id,
-                                   const char *reason_ptr,
-                                   UUID4_t event_id,
-                                   uint64_t ts_event,
-                                   uint64_t ts_init);
+    # - Assumes `reason_ptr` is a valid C string pointer or null.
+    OrderDeniedResult order_denied_new(TraderId_t trader_id,
+                                       StrategyId_t strategy_id,
+                                       InstrumentId_t instrument_id,
+                                       ClientOrderId_t client_order_id,
+                                       const char *reason_ptr,
+                                       UUID4_t event_id,
+                                       uint64_t ts_event,
+                                       uint64_t ts_init);
 
     OrderEmulated_t order_emulated_new(TraderId_t trader_id,
                                        StrategyId_t strategy_id,
@@ -1263,62 +1406,405 @@ cdef extern from "../includes/model.h":
                                        uint64_t ts_init,
                                        uint8_t reconciliation);
 
+    # Returns a new `OrderRejected` event, or an error if `reason_ptr` is not a valid reason.
+    #
+    # # Safety
+    #
+    # - Assumes `reason_ptr` is a valid C string pointer or null.
+    OrderRejectedResult order_rejected_new(TraderId_t trader_id,
+                                           StrategyId_t strategy_id,
+                                           InstrumentId_t instrument_id,
+                                           ClientOrderId_t client_order_id,
+                                           AccountId_t account_id,
+                                           const char *reason_ptr,
+                                           UUID4_t event_id,
+                                           uint64_t ts_event,
+                                           uint64_t ts_init,
+                                           uint8_t reconciliation);
+
+    # # Safety
+    #
+    # - Assumes `venue_order_id_ptr` and `account_id_ptr` are either null (for `None`) or valid
+    #   pointers.
+    OrderCanceled_API order_canceled_new(TraderId_t trader_id,
+                                         StrategyId_t strategy_id,
+                                         InstrumentId_t instrument_id,
+                                         ClientOrderId_t client_order_id,
+                                         const VenueOrderId_t *venue_order_id_ptr,
+                                         const AccountId_t *account_id_ptr,
+                                         UUID4_t event_id,
+                                         uint64_t ts_event,
+                                         uint64_t ts_init,
+                                         uint8_t reconciliation);
+
+    void order_canceled_drop(OrderCanceled_API event);
+
+    OrderCanceled_API order_canceled_clone(const OrderCanceled_API *event);
+
+    TraderId_t order_canceled_trader_id(const OrderCanceled_API *event);
+
+    StrategyId_t order_canceled_strategy_id(const OrderCanceled_API *event);
+
+    InstrumentId_t order_canceled_instrument_id(const OrderCanceled_API *event);
+
+    ClientOrderId_t order_canceled_client_order_id(const OrderCanceled_API *event);
+
+    UUID4_t order_canceled_event_id(const OrderCanceled_API *event);
+
+    uint64_t order_canceled_ts_event(const OrderCanceled_API *event);
+
+    uint64_t order_canceled_ts_init(const OrderCanceled_API *event);
+
+    uint8_t order_canceled_reconciliation(const OrderCanceled_API *event);
+
+    # Returns a pointer to the venue order ID of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const VenueOrderId_t *order_canceled_venue_order_id(const OrderCanceled_API *event);
+
+    # Returns a pointer to the account ID of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const AccountId_t *order_canceled_account_id(const OrderCanceled_API *event);
+
     # # Safety
     #
-    # - Assumes `reason_ptr` is a valid C string pointer.
-    OrderRejected_t order_rejected_new(TraderId_t trader_id,
+    # - Assumes `venue_order_id_ptr` and `account_id_ptr` are either null (for `None`) or valid
+    #   pointers.
+    OrderExpired_API order_expired_new(TraderId_t trader_id,
                                        StrategyId_t strategy_id,
                                        InstrumentId_t instrument_id,
                                        ClientOrderId_t client_order_id,
-                                       AccountId_t account_id,
-                                       const char *reason_ptr,
+                                       const VenueOrderId_t *venue_order_id_ptr,
+                                       const AccountId_t *account_id_ptr,
                                        UUID4_t event_id,
                                        uint64_t ts_event,
                                        uint64_t ts_init,
                                        uint8_t reconciliation);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    void order_expired_drop(OrderExpired_API event);
+
+    OrderExpired_API order_expired_clone(const OrderExpired_API *event);
+
+    TraderId_t order_expired_trader_id(const OrderExpired_API *event);
+
+    StrategyId_t order_expired_strategy_id(const OrderExpired_API *event);
+
+    InstrumentId_t order_expired_instrument_id(const OrderExpired_API *event);
+
+    ClientOrderId_t order_expired_client_order_id(const OrderExpired_API *event);
+
+    UUID4_t order_expired_event_id(const OrderExpired_API *event);
+
+    uint64_t order_expired_ts_event(const OrderExpired_API *event);
+
+    uint64_t order_expired_ts_init(const OrderExpired_API *event);
+
+    uint8_t order_expired_reconciliation(const OrderExpired_API *event);
+
+    # Returns a pointer to the venue order ID of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const VenueOrderId_t *order_expired_venue_order_id(const OrderExpired_API *event);
+
+    # Returns a pointer to the account ID of the event, or null if it has none.
     #
+    # The pointer is only valid while `event` is alive.
+    const AccountId_t *order_expired_account_id(const OrderExpired_API *event);
+
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    AccountId_t account_id_new(const char *ptr);
+    # - Assumes `venue_order_id_ptr` and `account_id_ptr` are either null (for `None`) or valid
+    #   pointers.
+    OrderTriggered_API order_triggered_new(TraderId_t trader_id,
+                                           StrategyId_t strategy_id,
+                                           InstrumentId_t instrument_id,
+                                           ClientOrderId_t client_order_id,
+                                           const VenueOrderId_t *venue_order_id_ptr,
+                                           const AccountId_t *account_id_ptr,
+                                           UUID4_t event_id,
+                                           uint64_t ts_event,
+                                           uint64_t ts_init,
+                                           uint8_t reconciliation);
+
+    void order_triggered_drop(OrderTriggered_API event);
+
+    OrderTriggered_API order_triggered_clone(const OrderTriggered_API *event);
+
+    TraderId_t order_triggered_trader_id(const OrderTriggered_API *event);
+
+    StrategyId_t order_triggered_strategy_id(const OrderTriggered_API *event);
+
+    InstrumentId_t order_triggered_instrument_id(const OrderTriggered_API *event);
+
+    ClientOrderId_t order_triggered_client_order_id(const OrderTriggered_API *event);
+
+    UUID4_t order_triggered_event_id(const OrderTriggered_API *event);
+
+    uint64_t order_triggered_ts_event(const OrderTriggered_API *event);
+
+    uint64_t order_triggered_ts_init(const OrderTriggered_API *event);
+
+    uint8_t order_triggered_reconciliation(const OrderTriggered_API *event);
+
+    # Returns a pointer to the venue order ID of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const VenueOrderId_t *order_triggered_venue_order_id(const OrderTriggered_API *event);
+
+    # Returns a pointer to the account ID of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const AccountId_t *order_triggered_account_id(const OrderTriggered_API *event);
+
+    # # Safety
+    #
+    # - Assumes `venue_order_id_ptr`, `account_id_ptr`, `price_ptr`, `trigger_price_ptr`,
+    #   `prev_quantity_ptr`, `prev_price_ptr` and `prev_trigger_price_ptr` are either null
+    #   (for `None`) or valid pointers.
+    OrderUpdated_API order_updated_new(TraderId_t trader_id,
+                                       StrategyId_t strategy_id,
+                                       InstrumentId_t instrument_id,
+                                       ClientOrderId_t client_order_id,
+                                       const VenueOrderId_t *venue_order_id_ptr,
+                                       const AccountId_t *account_id_ptr,
+                                       Quantity_t quantity,
+                                       const Price_t *price_ptr,
+                                       const Price_t *trigger_price_ptr,
+                                       const Quantity_t *prev_quantity_ptr,
+                                       const Price_t *prev_price_ptr,
+                                       const Price_t *prev_trigger_price_ptr,
+                                       UUID4_t event_id,
+                                       uint64_t ts_event,
+                                       uint64_t ts_init,
+                                       uint8_t reconciliation);
+
+    void order_updated_drop(OrderUpdated_API event);
+
+    OrderUpdated_API order_updated_clone(const OrderUpdated_API *event);
+
+    TraderId_t order_updated_trader_id(const OrderUpdated_API *event);
+
+    StrategyId_t order_updated_strategy_id(const OrderUpdated_API *event);
+
+    InstrumentId_t order_updated_instrument_id(const OrderUpdated_API *event);
+
+    ClientOrderId_t order_updated_client_order_id(const OrderUpdated_API *event);
+
+    Quantity_t order_updated_quantity(const OrderUpdated_API *event);
+
+    UUID4_t order_updated_event_id(const OrderUpdated_API *event);
+
+    uint64_t order_updated_ts_event(const OrderUpdated_API *event);
+
+    uint64_t order_updated_ts_init(const OrderUpdated_API *event);
+
+    uint8_t order_updated_reconciliation(const OrderUpdated_API *event);
+
+    # Returns a pointer to the venue order ID of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const VenueOrderId_t *order_updated_venue_order_id(const OrderUpdated_API *event);
+
+    # Returns a pointer to the account ID of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const AccountId_t *order_updated_account_id(const OrderUpdated_API *event);
+
+    # Returns a pointer to the price of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const Price_t *order_updated_price(const OrderUpdated_API *event);
+
+    # Returns a pointer to the trigger price of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const Price_t *order_updated_trigger_price(const OrderUpdated_API *event);
+
+    # Returns a pointer to the prev quantity of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const Quantity_t *order_updated_prev_quantity(const OrderUpdated_API *event);
+
+    # Returns a pointer to the prev price of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const Price_t *order_updated_prev_price(const OrderUpdated_API *event);
+
+    # Returns a pointer to the prev trigger price of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const Price_t *order_updated_prev_trigger_price(const OrderUpdated_API *event);
+
+    # Returns the result of creating an `OrderFilled`, reporting a fill which fails validation
+    # (see `OrderFilled::new`) rather than panicking.
+    #
+    # # Safety
+    #
+    # - Assumes `position_id_ptr` and `commission_ptr` are either null (for `None`) or valid
+    #   pointers.
+    OrderFilledResult order_filled_new(TraderId_t trader_id,
+                                       StrategyId_t strategy_id,
+                                       InstrumentId_t instrument_id,
+                                       ClientOrderId_t client_order_id,
+                                       VenueOrderId_t venue_order_id,
+                                       AccountId_t account_id,
+                                       TradeId_t trade_id,
+                                       OrderSide order_side,
+                                       OrderType order_type,
+                                       Quantity_t last_qty,
+                                       Price_t last_px,
+                                       Currency_t currency,
+                                       LiquiditySide liquidity_side,
+                                       UUID4_t event_id,
+                                       uint64_t ts_event,
+                                       uint64_t ts_init,
+                                       uint8_t reconciliation,
+                                       const PositionId_t *position_id_ptr,
+                                       const Money_t *commission_ptr);
+
+    void order_filled_drop(OrderFilled_API event);
+
+    OrderFilled_API order_filled_clone(const OrderFilled_API *event);
+
+    TraderId_t order_filled_trader_id(const OrderFilled_API *event);
+
+    StrategyId_t order_filled_strategy_id(const OrderFilled_API *event);
+
+    InstrumentId_t order_filled_instrument_id(const OrderFilled_API *event);
+
+    ClientOrderId_t order_filled_client_order_id(const OrderFilled_API *event);
+
+    VenueOrderId_t order_filled_venue_order_id(const OrderFilled_API *event);
+
+    AccountId_t order_filled_account_id(const OrderFilled_API *event);
+
+    TradeId_t order_filled_trade_id(const OrderFilled_API *event);
+
+    OrderSide order_filled_order_side(const OrderFilled_API *event);
+
+    OrderType order_filled_order_type(const OrderFilled_API *event);
+
+    Quantity_t order_filled_last_qty(const OrderFilled_API *event);
+
+    Price_t order_filled_last_px(const OrderFilled_API *event);
+
+    Currency_t order_filled_currency(const OrderFilled_API *event);
+
+    LiquiditySide order_filled_liquidity_side(const OrderFilled_API *event);
+
+    UUID4_t order_filled_event_id(const OrderFilled_API *event);
+
+    uint64_t order_filled_ts_event(const OrderFilled_API *event);
+
+    uint64_t order_filled_ts_init(const OrderFilled_API *event);
+
+    uint8_t order_filled_reconciliation(const OrderFilled_API *event);
+
+    # Returns a pointer to the position ID of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const PositionId_t *order_filled_position_id(const OrderFilled_API *event);
+
+    # Returns a pointer to the commission of the event, or null if it has none.
+    #
+    # The pointer is only valid while `event` is alive.
+    const Money_t *order_filled_commission(const OrderFilled_API *event);
+
+    # Returns the given `event` serialized as a JSON C string, to be freed with `cstr_drop`.
+    #
+    # # Panics
+    #
+    # - If serialization fails.
+    const char *order_denied_to_json(const OrderDenied_t *event);
+
+    # Returns the given `event` serialized as `MsgPack` bytes, to be freed with `cvec_drop`.
+    #
+    # # Panics
+    #
+    # - If serialization fails.
+    CVec order_denied_to_msgpack(const OrderDenied_t *event);
+
+    # Decodes an `OrderDenied` from the given JSON C string into `out`, returning null on success,
+    # otherwise an error C string to be freed with `cstr_drop` (leaving `out` unchanged).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is null or a valid C string pointer.
+    # - Assumes `out` is a valid pointer.
+    const char *order_denied_from_json(const char *ptr, OrderDenied_t *out);
+
+    # Decodes an `OrderDenied` from the given `MsgPack` bytes into `out`, returning null on success,
+    # otherwise an error C string to be freed with `cstr_drop` (leaving `out` unchanged).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is null or points to `len` readable bytes.
+    # - Assumes `out` is a valid pointer.
+    const char *order_denied_from_msgpack(const uint8_t *ptr, uintptr_t len, OrderDenied_t *out);
+
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *account_id_new(const char *ptr, AccountId_t *out);
 
     uint64_t account_id_hash(const AccountId_t *id);
 
-    # Returns a Nautilus identifier from C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    ClientId_t client_id_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *client_id_new(const char *ptr, ClientId_t *out);
 
     uint64_t client_id_hash(const ClientId_t *id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    ClientOrderId_t client_order_id_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *client_order_id_new(const char *ptr, ClientOrderId_t *out);
 
     uint64_t client_order_id_hash(const ClientOrderId_t *id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    ComponentId_t component_id_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *component_id_new(const char *ptr, ComponentId_t *out);
 
     uint64_t component_id_hash(const ComponentId_t *id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    ExecAlgorithmId_t exec_algorithm_id_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *exec_algorithm_id_new(const char *ptr, ExecAlgorithmId_t *out);
 
     uint64_t exec_algorithm_id_hash(const ExecAlgorithmId_t *id);
 
@@ -1331,12 +1817,16 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     const char *instrument_id_check_parsing(const char *ptr);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the [`InstrumentId`] parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # instrument ID, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    InstrumentId_t instrument_id_from_cstr(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *instrument_id_from_cstr(const char *ptr, InstrumentId_t *out);
 
     # Returns an [`InstrumentId`] as a C string pointer.
     const char *instrument_id_to_cstr(const InstrumentId_t *instrument_id);
@@ -1345,68 +1835,96 @@ cdef extern from "../includes/model.h":
 
     uint8_t instrument_id_is_synthetic(const InstrumentId_t *instrument_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    OrderListId_t order_list_id_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *order_list_id_new(const char *ptr, OrderListId_t *out);
 
     uint64_t order_list_id_hash(const OrderListId_t *id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    PositionId_t position_id_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *position_id_new(const char *ptr, PositionId_t *out);
 
     uint64_t position_id_hash(const PositionId_t *id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    StrategyId_t strategy_id_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *strategy_id_new(const char *ptr, StrategyId_t *out);
 
     uint64_t strategy_id_hash(const StrategyId_t *id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    Symbol_t symbol_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *symbol_new(const char *ptr, Symbol_t *out);
 
     uint64_t symbol_hash(const Symbol_t *id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    TradeId_t trade_id_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *trade_id_new(const char *ptr, TradeId_t *out);
 
     uint64_t trade_id_hash(const TradeId_t *id);
 
     const char *trade_id_to_cstr(const TradeId_t *trade_id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    TraderId_t trader_id_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *trader_id_new(const char *ptr, TraderId_t *out);
 
     uint64_t trader_id_hash(const TraderId_t *id);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    Venue_t venue_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *venue_new(const char *ptr, Venue_t *out);
 
     uint64_t venue_hash(const Venue_t *id);
 
@@ -1422,12 +1940,16 @@ cdef extern from "../includes/model.h":
     # - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
     Venue_t venue_from_cstr_code(const char *code_ptr);
 
-    # Returns a Nautilus identifier from a C string pointer.
+    # Writes the Nautilus identifier parsed from a C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why `ptr` is not a valid
+    # identifier, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `ptr` is a valid C string pointer.
-    VenueOrderId_t venue_order_id_new(const char *ptr);
+    # - Assumes `ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *venue_order_id_new(const char *ptr, VenueOrderId_t *out);
 
     uint64_t venue_order_id_hash(const VenueOrderId_t *id);
 
@@ -1484,6 +2006,11 @@ cdef extern from "../includes/model.h":
 
     BookType orderbook_book_type(const OrderBook_API *book);
 
+    uint8_t orderbook_validate(const OrderBook_API *book);
+
+    # Sets whether the `book` checks its integrity after applying deltas.
+    void orderbook_set_validate(OrderBook_API *book, uint8_t validate);
+
     uint64_t orderbook_sequence(const OrderBook_API *book);
 
     uint64_t orderbook_ts_last(const OrderBook_API *book);
@@ -1514,9 +2041,17 @@ cdef extern from "../includes/model.h":
 
     void orderbook_clear_asks(OrderBook_API *book, uint64_t sequence, uint64_t ts_event);
 
-    void orderbook_apply_delta(OrderBook_API *book, OrderBookDelta_t delta);
+    # Applies the `delta` to the `book`.
+    #
+    # Returns null on success, otherwise a C string describing the integrity check failure of a
+    # validating book, which must be freed with `cstr_drop`.
+    const char *orderbook_apply_delta(OrderBook_API *book, OrderBookDelta_t delta);
 
-    void orderbook_apply_deltas(OrderBook_API *book, const OrderBookDeltas_API *deltas);
+    # Applies the `deltas` to the `book`.
+    #
+    # Returns null on success, otherwise a C string describing the integrity check failure of a
+    # validating book, which must be freed with `cstr_drop`.
+    const char *orderbook_apply_deltas(OrderBook_API *book, const OrderBookDeltas_API *deltas);
 
     void orderbook_apply_depth(OrderBook_API *book, OrderBookDepth10_t depth);
 
@@ -1524,6 +2059,8 @@ cdef extern from "../includes/model.h":
 
     CVec orderbook_asks(OrderBook_API *book);
 
+    OrderBookDepth10_t orderbook_snapshot(const OrderBook_API *book, uintptr_t depth);
+
     uint8_t orderbook_has_bid(OrderBook_API *book);
 
     uint8_t orderbook_has_ask(OrderBook_API *book);
@@ -1540,6 +2077,8 @@ cdef extern from "../includes/model.h":
 
     double orderbook_midpoint(OrderBook_API *book);
 
+    # Returns the average price for the available portion of `qty`, or 0.0 if the opposite
+    # side of the book is empty (as expected by the Cython `OrderBook`).
     double orderbook_get_avg_px_for_quantity(OrderBook_API *book,
                                              Quantity_t qty,
                                              OrderSide order_side);
@@ -1589,17 +2128,22 @@ cdef extern from "../includes/model.h":
 
     void vec_orders_drop(CVec v);
 
-    # Returns a [`Currency`] from pointers and primitives.
+    # Writes the [`Currency`] created from pointers and primitives to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why the currency is not
+    # valid, to be freed with `cstr_drop` (leaving `out` unchanged).
     #
     # # Safety
     #
-    # - Assumes `code_ptr` is a valid C string pointer.
-    # - Assumes `name_ptr` is a valid C string pointer.
-    Currency_t currency_from_py(const char *code_ptr,
-                                uint8_t precision,
-                                uint16_t iso4217,
-                                const char *name_ptr,
-                                CurrencyType currency_type);
+    # - Assumes `code_ptr` is a valid C string pointer or NULL.
+    # - Assumes `name_ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *currency_from_py(const char *code_ptr,
+                                 uint8_t precision,
+                                 uint16_t iso4217,
+                                 const char *name_ptr,
+                                 CurrencyType currency_type,
+                                 Currency_t *out);
 
     const char *currency_to_cstr(const Currency_t *currency);
 
@@ -1616,10 +2160,31 @@ cdef extern from "../includes/model.h":
     # - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
     uint8_t currency_exists(const char *code_ptr);
 
+    # Writes the registered [`Currency`] for the code C string pointer to `out`.
+    #
+    # Returns a null pointer on success, otherwise a C string describing why the code is not
+    # valid or not registered, to be freed with `cstr_drop` (leaving `out` unchanged).
+    #
     # # Safety
     #
-    # - Assumes `code_ptr` is borrowed from a valid Python UTF-8 `str`.
-    Currency_t currency_from_cstr(const char *code_ptr);
+    # - Assumes `code_ptr` is a valid C string pointer or NULL.
+    # - Assumes `out` is a valid pointer.
+    const char *currency_from_cstr(const char *code_ptr, Currency_t *out);
+
+    # Writes the given `raw` fixed-point value rescaled from `from_precision` to `to_precision`
+    # decimal places to `out`.
+    #
+    # Returns null on success, otherwise a C string describing why the rescaling failed (if it would
+    # truncate non-zero digits or overflow, or if either precision is greater than the maximum fixed
+    # precision), which must be freed with `cstr_drop`.
+    #
+    # # Safety
+    #
+    # - Assumes `out` is a valid pointer to write an `i64` to.
+    const char *fixed_point_rescale(int64_t raw,
+                                    uint8_t from_precision,
+                                    uint8_t to_precision,
+                                    int64_t *out);
 
     Money_t money_new(double amount, Currency_t currency);
 
@@ -1635,6 +2200,8 @@ cdef extern from "../includes/model.h":
 
     Price_t price_from_raw(int64_t raw, uint8_t precision);
 
+    int64_t price_raw(const Price_t *price);
+
     double price_as_f64(const Price_t *price);
 
     void price_add_assign(Price_t a, Price_t b);
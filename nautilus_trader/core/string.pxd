@@ -67,6 +67,12 @@ cdef inline str cstr_to_pystr(const char* ptr, bint drop = True):
     return obj
 
 
+# Raise a `ValueError` from a Rust error string (if not null), assumes ownership of `error`
+cdef inline void raise_cstr_error(const char* error) except *:
+    if error != NULL:
+        raise ValueError(cstr_to_pystr(error))
+
+
 # Convert a Rust interned string to a Python string
 cdef inline str ustr_to_pystr(const char* ptr):
     return PyUnicode_FromString(ptr)
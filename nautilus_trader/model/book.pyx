@@ -70,12 +70,14 @@ from nautilus_trader.core.rust.model cimport orderbook_new
 from nautilus_trader.core.rust.model cimport orderbook_pprint_to_cstr
 from nautilus_trader.core.rust.model cimport orderbook_reset
 from nautilus_trader.core.rust.model cimport orderbook_sequence
+from nautilus_trader.core.rust.model cimport orderbook_set_validate
 from nautilus_trader.core.rust.model cimport orderbook_simulate_fills
 from nautilus_trader.core.rust.model cimport orderbook_spread
 from nautilus_trader.core.rust.model cimport orderbook_ts_last
 from nautilus_trader.core.rust.model cimport orderbook_update
 from nautilus_trader.core.rust.model cimport orderbook_update_quote_tick
 from nautilus_trader.core.rust.model cimport orderbook_update_trade_tick
+from nautilus_trader.core.rust.model cimport orderbook_validate
 from nautilus_trader.core.rust.model cimport vec_fills_drop
 from nautilus_trader.core.rust.model cimport vec_levels_drop
 from nautilus_trader.core.rust.model cimport vec_orders_drop
@@ -96,18 +98,30 @@ from nautilus_trader.model.objects cimport Quantity
 cdef class OrderBook(Data):
     """
     Provides an order book which can handle L1/L2/L3 granularity data.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID for the book.
+    book_type : BookType
+        The order book type.
+    validate : bool, default False
+        If the book integrity should be checked after applying deltas.
+
     """
 
     def __init__(
         self,
         InstrumentId instrument_id not None,
         BookType book_type,
+        bint validate = False,
     ) -> None:
         self._book_type = book_type
         self._mem = orderbook_new(
             instrument_id._mem,
             book_type,
         )
+        orderbook_set_validate(&self._mem, validate)
 
     def __repr__(self) -> str:
         return (
@@ -127,6 +141,7 @@ cdef class OrderBook(Data):
             self.ts_last,
             self.sequence,
             pickle.dumps(orders),
+            self.validate,
         )
 
     def __setstate__(self, state):
@@ -144,6 +159,8 @@ cdef class OrderBook(Data):
         for i in range(len(orders)):
             self.add(orders[i], ts_last, sequence)
 
+        orderbook_set_validate(&self._mem, state[5])
+
     @property
     def instrument_id(self) -> InstrumentId:
         """
@@ -168,6 +185,18 @@ cdef class OrderBook(Data):
         """
         return self._book_type
 
+    @property
+    def validate(self) -> bool:
+        """
+        Return whether the book integrity is checked after applying deltas.
+
+        Returns
+        -------
+        bool
+
+        """
+        return orderbook_validate(&self._mem)
+
     @property
     def sequence(self) -> int:
         """
@@ -333,11 +362,15 @@ cdef class OrderBook(Data):
         ------
         ValueError
             If `delta.book_type` is not equal to `self.type`.
+        RuntimeError
+            If the book is validating and fails its integrity check.
 
         """
         Condition.not_none(delta, "delta")
 
-        orderbook_apply_delta(&self._mem, delta._mem)
+        cdef const char* error = orderbook_apply_delta(&self._mem, delta._mem)
+        if error != NULL:
+            raise RuntimeError(cstr_to_pystr(error))
 
     cpdef void apply_deltas(self, OrderBookDeltas deltas):
         """
@@ -348,10 +381,17 @@ cdef class OrderBook(Data):
         deltas : OrderBookDeltas
             The deltas to apply.
 
+        Raises
+        ------
+        RuntimeError
+            If the book is validating and fails its integrity check.
+
         """
         Condition.not_none(deltas, "deltas")
 
-        orderbook_apply_deltas(&self._mem, &deltas._mem)
+        cdef const char* error = orderbook_apply_deltas(&self._mem, &deltas._mem)
+        if error != NULL:
+            raise RuntimeError(cstr_to_pystr(error))
 
     cpdef void apply_depth(self, OrderBookDepth10 depth):
         """
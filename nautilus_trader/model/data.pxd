@@ -99,6 +99,8 @@ cpdef enum BarAggregation:
     DAY = 14
     WEEK = 15
     MONTH = 16
+    RENKO = 17
+    RANGE = 18
 
 
 cdef class BarSpecification:
@@ -378,6 +380,7 @@ cdef class QuoteTick(Data):
         uint8_t ask_size_prec,
         uint64_t ts_event,
         uint64_t ts_init,
+        bint validate=*,
     )
 
     @staticmethod
@@ -391,6 +394,7 @@ cdef class QuoteTick(Data):
         uint64_t[:] ask_sizes_raw,
         uint64_t[:] ts_events,
         uint64_t[:] ts_inits,
+        bint validate=*,
     )
 
     @staticmethod
@@ -431,6 +435,7 @@ cdef class TradeTick(Data):
         TradeId trade_id,
         uint64_t ts_event,
         uint64_t ts_init,
+        bint validate=*,
     )
 
     @staticmethod
@@ -444,6 +449,7 @@ cdef class TradeTick(Data):
         list[str] trade_ids,
         uint64_t[:] ts_events,
         uint64_t[:] ts_inits,
+        bint validate=*,
     )
 
     @staticmethod
@@ -111,6 +111,7 @@ from nautilus_trader.core.rust.model cimport trade_tick_to_cstr
 from nautilus_trader.core.rust.model cimport venue_new
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
+from nautilus_trader.core.string cimport raise_cstr_error
 from nautilus_trader.core.string cimport ustr_to_pystr
 from nautilus_trader.model.data cimport BarAggregation
 from nautilus_trader.model.functions cimport aggregation_source_from_str
@@ -3343,6 +3344,10 @@ cdef class QuoteTick(Data):
         If `bid.precision` != `ask.precision`.
     ValueError
         If `bid_size.precision` != `ask_size.precision`.
+    ValueError
+        If `bid_price` > `ask_price`.
+    ValueError
+        If `bid_size` or `ask_size` is not positive.
 
     """
 
@@ -3359,7 +3364,7 @@ cdef class QuoteTick(Data):
         Condition.equal(bid_price._mem.precision, ask_price._mem.precision, "bid_price.precision", "ask_price.precision")
         Condition.equal(bid_size._mem.precision, ask_size._mem.precision, "bid_size.precision", "ask_size.precision")
 
-        self._mem = quote_tick_new(
+        raise_cstr_error(quote_tick_new(
             instrument_id._mem,
             bid_price._mem.raw,
             ask_price._mem.raw,
@@ -3371,7 +3376,9 @@ cdef class QuoteTick(Data):
             ask_size._mem.precision,
             ts_event,
             ts_init,
-        )
+            True,
+            &self._mem,
+        ))
 
     def __getstate__(self):
         return (
@@ -3390,7 +3397,7 @@ cdef class QuoteTick(Data):
 
     def __setstate__(self, state):
         cdef InstrumentId instrument_id = InstrumentId.from_str_c(state[0])
-        self._mem = quote_tick_new(
+        raise_cstr_error(quote_tick_new(
             instrument_id._mem,
             state[1],
             state[2],
@@ -3402,7 +3409,9 @@ cdef class QuoteTick(Data):
             state[8],
             state[9],
             state[10],
-        )
+            False,
+            &self._mem,
+        ))
 
     def __eq__(self, QuoteTick other) -> bool:
         return quote_tick_eq(&self._mem, &other._mem)
@@ -3555,9 +3564,10 @@ cdef class QuoteTick(Data):
         uint8_t ask_size_prec,
         uint64_t ts_event,
         uint64_t ts_init,
+        bint validate=False,
     ):
         cdef QuoteTick quote = QuoteTick.__new__(QuoteTick)
-        quote._mem = quote_tick_new(
+        raise_cstr_error(quote_tick_new(
             instrument_id._mem,
             bid_price_raw,
             ask_price_raw,
@@ -3569,7 +3579,9 @@ cdef class QuoteTick(Data):
             ask_size_prec,
             ts_event,
             ts_init,
-        )
+            validate,
+            &quote._mem,
+        ))
         return quote
 
     @staticmethod
@@ -3583,6 +3595,7 @@ cdef class QuoteTick(Data):
         uint64_t[:] ask_sizes_raw,
         uint64_t[:] ts_events,
         uint64_t[:] ts_inits,
+        bint validate=False,
     ):
         Condition.true(len(bid_prices_raw) == len(ask_prices_raw) == len(bid_sizes_raw) == len(ask_sizes_raw)
                        == len(ts_events) == len(ts_inits), "Array lengths must be equal")
@@ -3595,7 +3608,7 @@ cdef class QuoteTick(Data):
             QuoteTick quote
         for i in range(count):
             quote = QuoteTick.__new__(QuoteTick)
-            quote._mem = quote_tick_new(
+            raise_cstr_error(quote_tick_new(
                 instrument_id._mem,
                 bid_prices_raw[i],
                 ask_prices_raw[i],
@@ -3607,7 +3620,9 @@ cdef class QuoteTick(Data):
                 size_prec,
                 ts_events[i],
                 ts_inits[i],
-            )
+                validate,
+                &quote._mem,
+            ))
             ticks.append(quote)
 
         return ticks
@@ -3623,6 +3638,7 @@ cdef class QuoteTick(Data):
         ask_sizes_raw: np.ndarray,
         ts_events: np.ndarray,
         ts_inits: np.ndarray,
+        validate: bool = False,
     ) -> list[QuoteTick]:
         return QuoteTick.from_raw_arrays_to_list_c(
             instrument_id,
@@ -3634,6 +3650,7 @@ cdef class QuoteTick(Data):
             ask_sizes_raw,
             ts_events,
             ts_inits,
+            validate,
         )
 
     @staticmethod
@@ -3691,6 +3708,7 @@ cdef class QuoteTick(Data):
         uint8_t ask_size_prec,
         uint64_t ts_event,
         uint64_t ts_init,
+        bint validate=False,
     ) -> QuoteTick:
         """
         Return a quote tick from the given raw values.
@@ -3719,6 +3737,9 @@ cdef class QuoteTick(Data):
             The UNIX timestamp (nanoseconds) when the tick event occurred.
         ts_init : uint64_t
             The UNIX timestamp (nanoseconds) when the data object was initialized.
+        validate : bool, default False
+            If the quote invariants should be checked (an empty side or a crossed
+            top level is accepted by default).
 
         Returns
         -------
@@ -3730,6 +3751,10 @@ cdef class QuoteTick(Data):
             If `bid_price_prec` != `ask_price_prec`.
         ValueError
             If `bid_size_prec` != `ask_size_prec`.
+        ValueError
+            If `validate` and `bid_price_raw` > `ask_price_raw`.
+        ValueError
+            If `validate` and `bid_size_raw` or `ask_size_raw` is not positive.
 
         """
         Condition.equal(bid_price_prec, ask_price_prec, "bid_price_prec", "ask_price_prec")
@@ -3747,6 +3772,7 @@ cdef class QuoteTick(Data):
             ask_size_prec,
             ts_event,
             ts_init,
+            validate,
         )
 
     @staticmethod
@@ -3938,6 +3964,8 @@ cdef class TradeTick(Data):
     ------
     ValueError
         If `trade_id` is not a valid string.
+    ValueError
+        If `size` is not positive.
 
     """
 
@@ -3951,7 +3979,7 @@ cdef class TradeTick(Data):
         uint64_t ts_event,
         uint64_t ts_init,
     ) -> None:
-        self._mem = trade_tick_new(
+        raise_cstr_error(trade_tick_new(
             instrument_id._mem,
             price._mem.raw,
             price._mem.precision,
@@ -3961,7 +3989,9 @@ cdef class TradeTick(Data):
             trade_id._mem,
             ts_event,
             ts_init,
-        )
+            True,
+            &self._mem,
+        ))
 
     def __getstate__(self):
         return (
@@ -3978,7 +4008,7 @@ cdef class TradeTick(Data):
 
     def __setstate__(self, state):
         cdef InstrumentId instrument_id = InstrumentId.from_str_c(state[0])
-        self._mem = trade_tick_new(
+        raise_cstr_error(trade_tick_new(
             instrument_id._mem,
             state[1],
             state[2],
@@ -3988,7 +4018,9 @@ cdef class TradeTick(Data):
             TradeId(state[6])._mem,
             state[7],
             state[8],
-        )
+            False,
+            &self._mem,
+        ))
 
     def __eq__(self, TradeTick other) -> bool:
         return trade_tick_eq(&self._mem, &other._mem)
@@ -4112,9 +4144,10 @@ cdef class TradeTick(Data):
         TradeId trade_id,
         uint64_t ts_event,
         uint64_t ts_init,
+        bint validate=False,
     ):
         cdef TradeTick trade = TradeTick.__new__(TradeTick)
-        trade._mem = trade_tick_new(
+        raise_cstr_error(trade_tick_new(
             instrument_id._mem,
             price_raw,
             price_prec,
@@ -4124,7 +4157,9 @@ cdef class TradeTick(Data):
             trade_id._mem,
             ts_event,
             ts_init,
-        )
+            validate,
+            &trade._mem,
+        ))
         return trade
 
     @staticmethod
@@ -4138,6 +4173,7 @@ cdef class TradeTick(Data):
         list[str] trade_ids,
         uint64_t[:] ts_events,
         uint64_t[:] ts_inits,
+        bint validate=False,
     ):
         Condition.true(len(prices_raw) == len(sizes_raw) == len(aggressor_sides) == len(trade_ids) ==
                        len(ts_events) == len(ts_inits), "Array lengths must be equal")
@@ -4154,7 +4190,7 @@ cdef class TradeTick(Data):
             aggressor_side = <AggressorSide>aggressor_sides[i]
             trade_id = TradeId(trade_ids[i])
             trade = TradeTick.__new__(TradeTick)
-            trade._mem = trade_tick_new(
+            raise_cstr_error(trade_tick_new(
                 instrument_id._mem,
                 prices_raw[i],
                 price_prec,
@@ -4164,7 +4200,9 @@ cdef class TradeTick(Data):
                 trade_id._mem,
                 ts_events[i],
                 ts_inits[i],
-            )
+                validate,
+                &trade._mem,
+            ))
             trades.append(trade)
 
         return trades
@@ -4180,6 +4218,7 @@ cdef class TradeTick(Data):
         list[str] trade_ids,
         uint64_t[:] ts_events,
         uint64_t[:] ts_inits,
+        bint validate=False,
     ) -> list[TradeTick]:
         return TradeTick.from_raw_arrays_to_list_c(
             instrument_id,
@@ -4191,6 +4230,7 @@ cdef class TradeTick(Data):
             trade_ids,
             ts_events,
             ts_inits,
+            validate,
         )
 
     @staticmethod
@@ -4273,6 +4313,7 @@ cdef class TradeTick(Data):
         TradeId trade_id,
         uint64_t ts_event,
         uint64_t ts_init,
+        bint validate=False,
     ) -> TradeTick:
         """
         Return a trade tick from the given raw values.
@@ -4297,11 +4338,18 @@ cdef class TradeTick(Data):
             The UNIX timestamp (nanoseconds) when the tick event occurred.
         ts_init : uint64_t
             The UNIX timestamp (nanoseconds) when the data object was initialized.
+        validate : bool, default False
+            If the trade invariants should be checked.
 
         Returns
         -------
         TradeTick
 
+        Raises
+        ------
+        ValueError
+            If `validate` and `size_raw` is not positive.
+
         """
         return TradeTick.from_raw_c(
             instrument_id,
@@ -4313,6 +4361,7 @@ cdef class TradeTick(Data):
             trade_id,
             ts_event,
             ts_init,
+            validate,
         )
 
     @staticmethod
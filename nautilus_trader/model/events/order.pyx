@@ -21,6 +21,8 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.message cimport Event
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport LiquiditySide
+from nautilus_trader.core.rust.model cimport OrderDeniedResult
+from nautilus_trader.core.rust.model cimport OrderRejectedResult
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderType
 from nautilus_trader.core.rust.model cimport TimeInForce
@@ -36,6 +38,7 @@ from nautilus_trader.core.rust.model cimport trader_id_new
 from nautilus_trader.core.string cimport cstr_to_pybytes
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
+from nautilus_trader.core.string cimport raise_cstr_error
 from nautilus_trader.core.string cimport ustr_to_pystr
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.model.functions cimport contingency_type_from_str
@@ -660,7 +663,7 @@ cdef class OrderDenied(OrderEvent):
     ):
         Condition.valid_string(reason, "denied_reason")
 
-        self._mem = order_denied_new(
+        cdef OrderDeniedResult result = order_denied_new(
             trader_id._mem,
             strategy_id._mem,
             instrument_id._mem,
@@ -670,6 +673,8 @@ cdef class OrderDenied(OrderEvent):
             ts_init,
             ts_init,
         )
+        raise_cstr_error(result.error)
+        self._mem = result.value
 
     def __eq__(self, Event other) -> bool:
         return self.id == other.id
@@ -1977,7 +1982,7 @@ cdef class OrderRejected(OrderEvent):
     ):
         Condition.valid_string(reason, "reason")
 
-        self._mem = order_rejected_new(
+        cdef OrderRejectedResult result = order_rejected_new(
             trader_id._mem,
             strategy_id._mem,
             instrument_id._mem,
@@ -1989,6 +1994,8 @@ cdef class OrderRejected(OrderEvent):
             ts_init,
             reconciliation,
         )
+        raise_cstr_error(result.error)
+        self._mem = result.value
 
     def __eq__(self, Event other) -> bool:
         return self.id == other.id
@@ -55,6 +55,7 @@ from nautilus_trader.core.rust.model cimport venue_order_id_hash
 from nautilus_trader.core.rust.model cimport venue_order_id_new
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
+from nautilus_trader.core.string cimport raise_cstr_error
 from nautilus_trader.core.string cimport ustr_to_pystr
 
 
@@ -128,13 +129,13 @@ cdef class Symbol(Identifier):
 
     def __init__(self, str value not None) -> None:
         Condition.valid_string(value, "value")
-        self._mem = symbol_new(pystr_to_cstr(value))
+        raise_cstr_error(symbol_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = symbol_new(pystr_to_cstr(state))
+        raise_cstr_error(symbol_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, Symbol other) -> bool:
         if other is None:
@@ -171,13 +172,13 @@ cdef class Venue(Identifier):
 
     def __init__(self, str name not None) -> None:
         Condition.valid_string(name, "name")
-        self._mem = venue_new(pystr_to_cstr(name))
+        raise_cstr_error(venue_new(pystr_to_cstr(name), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = venue_new(pystr_to_cstr(state))
+        raise_cstr_error(venue_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, Venue other) -> bool:
         if other is None:
@@ -287,9 +288,7 @@ cdef class InstrumentId(Identifier):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = instrument_id_from_cstr(
-            pystr_to_cstr(state),
-        )
+        raise_cstr_error(instrument_id_from_cstr(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, InstrumentId other) -> bool:
         if other is None:
@@ -314,7 +313,7 @@ cdef class InstrumentId(Identifier):
             raise ValueError(parse_err)
 
         cdef InstrumentId instrument_id = InstrumentId.__new__(InstrumentId)
-        instrument_id._mem = instrument_id_from_cstr(pystr_to_cstr(value))
+        raise_cstr_error(instrument_id_from_cstr(pystr_to_cstr(value), &instrument_id._mem))
         return instrument_id
 
     cdef str to_str(self):
@@ -379,13 +378,13 @@ cdef class ComponentId(Identifier):
 
     def __init__(self, str value not None) -> None:
         Condition.valid_string(value, "value")
-        self._mem = component_id_new(pystr_to_cstr(value))
+        raise_cstr_error(component_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = component_id_new(pystr_to_cstr(state))
+        raise_cstr_error(component_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, ComponentId other) -> bool:
         if other is None:
@@ -426,13 +425,13 @@ cdef class ClientId(Identifier):
 
     def __init__(self, str value not None) -> None:
         Condition.valid_string(value, "value")
-        self._mem = client_id_new(pystr_to_cstr(value))
+        raise_cstr_error(client_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = client_id_new(pystr_to_cstr(state))
+        raise_cstr_error(client_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, ClientId other) -> bool:
         if other is None:
@@ -482,13 +481,13 @@ cdef class TraderId(Identifier):
 
     def __init__(self, str value not None) -> None:
         Condition.valid_string(value, "value")
-        self._mem = trader_id_new(pystr_to_cstr(value))
+        raise_cstr_error(trader_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = trader_id_new(pystr_to_cstr(state))
+        raise_cstr_error(trader_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, TraderId other) -> bool:
         if other is None:
@@ -555,13 +554,13 @@ cdef class StrategyId(Identifier):
         Condition.valid_string(value, "value")
         Condition.true(value == "EXTERNAL" or "-" in value, "value was malformed: did not contain a hyphen '-'")
 
-        self._mem = strategy_id_new(pystr_to_cstr(value))
+        raise_cstr_error(strategy_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = strategy_id_new(pystr_to_cstr(state))
+        raise_cstr_error(strategy_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, StrategyId other) -> bool:
         if other is None:
@@ -626,13 +625,13 @@ cdef class ExecAlgorithmId(Identifier):
 
     def __init__(self, str value not None) -> None:
         Condition.valid_string(value, "value")
-        self._mem = exec_algorithm_id_new(pystr_to_cstr(value))
+        raise_cstr_error(exec_algorithm_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = exec_algorithm_id_new(pystr_to_cstr(state))
+        raise_cstr_error(exec_algorithm_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, ExecAlgorithmId other) -> bool:
         if other is None:
@@ -681,13 +680,13 @@ cdef class AccountId(Identifier):
     def __init__(self, str value not None) -> None:
         Condition.valid_string(value, "value")
         Condition.true("-" in value, "value was malformed: did not contain a hyphen '-'")
-        self._mem = account_id_new(pystr_to_cstr(value))
+        raise_cstr_error(account_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = account_id_new(pystr_to_cstr(state))
+        raise_cstr_error(account_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, AccountId other) -> bool:
         if other is None:
@@ -750,13 +749,13 @@ cdef class ClientOrderId(Identifier):
 
     def __init__(self, str value not None) -> None:
         Condition.valid_string(value, "value")
-        self._mem = client_order_id_new(pystr_to_cstr(value))
+        raise_cstr_error(client_order_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = client_order_id_new(pystr_to_cstr(state))
+        raise_cstr_error(client_order_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, ClientOrderId other) -> bool:
         if other is None:
@@ -815,13 +814,13 @@ cdef class VenueOrderId(Identifier):
 
     def __init__(self, str value not None) -> None:
         Condition.valid_string(value, "value")
-        self._mem = venue_order_id_new(pystr_to_cstr(value))
+        raise_cstr_error(venue_order_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = venue_order_id_new(pystr_to_cstr(state))
+        raise_cstr_error(venue_order_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, VenueOrderId other) -> bool:
         if other is None:
@@ -858,13 +857,13 @@ cdef class OrderListId(Identifier):
 
     def __init__(self, str value not None) -> None:
         Condition.valid_string(value, "value")
-        self._mem = order_list_id_new(pystr_to_cstr(value))
+        raise_cstr_error(order_list_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = order_list_id_new(pystr_to_cstr(state))
+        raise_cstr_error(order_list_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, OrderListId other) -> bool:
         if other is None:
@@ -901,13 +900,13 @@ cdef class PositionId(Identifier):
 
     def __init__(self, str value not None) -> None:
         Condition.valid_string(value, "value")
-        self._mem = position_id_new(pystr_to_cstr(value))
+        raise_cstr_error(position_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = position_id_new(pystr_to_cstr(state))
+        raise_cstr_error(position_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, PositionId other) -> bool:
         if other is None:
@@ -962,13 +961,13 @@ cdef class TradeId(Identifier):
         if len(value) > 36:
             Condition.in_range_int(len(value), 1, 36, "value")
 
-        self._mem = trade_id_new(pystr_to_cstr(value))
+        raise_cstr_error(trade_id_new(pystr_to_cstr(value), &self._mem))
 
     def __getstate__(self):
         return self.to_str()
 
     def __setstate__(self, state):
-        self._mem = trade_id_new(pystr_to_cstr(state))
+        raise_cstr_error(trade_id_new(pystr_to_cstr(state), &self._mem))
 
     def __eq__(self, TradeId other) -> bool:
         if other is None:
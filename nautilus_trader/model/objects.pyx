@@ -56,6 +56,7 @@ from nautilus_trader.core.rust.model cimport quantity_from_raw
 from nautilus_trader.core.rust.model cimport quantity_new
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
+from nautilus_trader.core.string cimport raise_cstr_error
 from nautilus_trader.core.string cimport ustr_to_pystr
 
 
@@ -1291,13 +1292,14 @@ cdef class Currency:
         Condition.valid_string(name, "name")
         Condition.true(precision <= 9, f"invalid `precision` greater than max 9, was {precision}")
 
-        self._mem = currency_from_py(
+        raise_cstr_error(currency_from_py(
             pystr_to_cstr(code),
             precision,
             iso4217,
             pystr_to_cstr(name),
             currency_type,
-        )
+            &self._mem,
+        ))
 
     def __getstate__(self):
         return (
@@ -1309,13 +1311,14 @@ cdef class Currency:
         )
 
     def __setstate__(self, state):
-        self._mem = currency_from_py(
+        raise_cstr_error(currency_from_py(
             pystr_to_cstr(state[0]),
             state[1],
             state[2],
             pystr_to_cstr(state[3]),
             state[4],
-        )
+            &self._mem,
+        ))
 
     def __eq__(self, Currency other) -> bool:
         if other is None:
@@ -1407,7 +1410,7 @@ cdef class Currency:
         if not currency_exists(code_ptr):
             return None
         cdef Currency currency = Currency.__new__(Currency)
-        currency._mem = currency_from_cstr(code_ptr)
+        raise_cstr_error(currency_from_cstr(code_ptr, &currency._mem))
         return currency
 
     @staticmethod
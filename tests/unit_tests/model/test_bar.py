@@ -314,23 +314,23 @@ class TestBarType:
         [
             [
                 "AUD/USD.-0-0-0-0",
-                "Error parsing `BarType` from 'AUD/USD.-0-0-0-0', invalid token: 'AUD/USD.' at position 0",
+                "Error parsing `BarType` from 'AUD/USD.-0-0-0-0', invalid token: 'AUD/USD.' at position 0, expected an instrument ID `{symbol}.{venue}`",
             ],
             [
                 "AUD/USD.SIM-a-0-0-0",
-                "Error parsing `BarType` from 'AUD/USD.SIM-a-0-0-0', invalid token: 'a' at position 1",
+                "Error parsing `BarType` from 'AUD/USD.SIM-a-0-0-0', invalid token: 'a' at position 1, expected a positive integer step",
             ],
             [
                 "AUD/USD.SIM-1000-a-0-0",
-                "Error parsing `BarType` from 'AUD/USD.SIM-1000-a-0-0', invalid token: 'a' at position 2",
+                "Error parsing `BarType` from 'AUD/USD.SIM-1000-a-0-0', invalid token: 'a' at position 2, expected one of TICK, TICK_IMBALANCE, TICK_RUNS, VOLUME, VOLUME_IMBALANCE, VOLUME_RUNS, VALUE, VALUE_IMBALANCE, VALUE_RUNS, MILLISECOND, SECOND, MINUTE, HOUR, DAY, WEEK, MONTH, RENKO, RANGE",
             ],
             [
                 "AUD/USD.SIM-1000-TICK-a-0",
-                "Error parsing `BarType` from 'AUD/USD.SIM-1000-TICK-a-0', invalid token: 'a' at position 3",
+                "Error parsing `BarType` from 'AUD/USD.SIM-1000-TICK-a-0', invalid token: 'a' at position 3, expected one of BID, ASK, MID, LAST",
             ],
             [
                 "AUD/USD.SIM-1000-TICK-LAST-a",
-                "Error parsing `BarType` from 'AUD/USD.SIM-1000-TICK-LAST-a', invalid token: 'a' at position 4",
+                "Error parsing `BarType` from 'AUD/USD.SIM-1000-TICK-LAST-a', invalid token: 'a' at position 4, expected one of EXTERNAL, INTERNAL",
             ],
         ],
     )